@@ -5,8 +5,9 @@ use cargo_metadata::camino::Utf8PathBuf;
 
 use crate::{
     command::{docker::create_docker_command, local::create_local_command, utils::execute_command},
-    utils::{cargo_rerun_if_changed, copy_elf_to_output_dir, current_datetime},
-    BuildArgs,
+    patches::{PatchReport, PATCH_REPORT_FILE},
+    utils::{cargo_rerun_if_changed, copy_elf_to_output_dir, current_datetime, elf_output_path},
+    BuildArgs, HELPER_TARGET_SUBDIR,
 };
 
 /// Build a program with the specified [`BuildArgs`]. The `program_dir` is specified as an argument
@@ -35,7 +36,28 @@ pub fn execute_build_program(
     // Get the program metadata.
     let program_metadata_file = program_dir.join("Cargo.toml");
     let mut program_metadata_cmd = cargo_metadata::MetadataCommand::new();
-    let program_metadata = program_metadata_cmd.manifest_path(program_metadata_file).exec()?;
+    let mut program_metadata = program_metadata_cmd.manifest_path(program_metadata_file).exec()?;
+
+    // Report the dependencies that have SP1 patches, and apply them if requested.
+    let mut report = PatchReport::new(&program_metadata);
+    if args.auto_patch {
+        let manifest_path = program_metadata.workspace_root.join("Cargo.toml");
+        let added = report.apply(&manifest_path)?;
+        if added > 0 {
+            println!("Added {} SP1 patches to {}", added, manifest_path);
+            program_metadata = program_metadata_cmd.exec()?;
+            report = PatchReport::new(&program_metadata);
+        }
+    }
+    for entry in report.available() {
+        println!(
+            "{} v{} has an SP1 patch accelerating {}, apply it with --auto-patch",
+            entry.name, entry.version, entry.accelerates
+        );
+    }
+    report.write(
+        program_metadata.target_directory.join(HELPER_TARGET_SUBDIR).join(PATCH_REPORT_FILE),
+    )?;
 
     // Get the command corresponding to Docker or local build.
     let cmd = if args.docker {
@@ -58,6 +80,12 @@ pub(crate) fn build_program_internal(path: &str, args: Option<BuildArgs>) {
     let metadata = metadata_cmd.manifest_path(metadata_file).exec().unwrap();
     let root_package = metadata.root_package();
     let root_package_name = root_package.as_ref().map(|p| p.name.as_str()).unwrap_or("Program");
+    let args = args.unwrap_or_default();
+
+    // Expose the path of the ELF to the crate being built as `SP1_ELF_<package name>`, which
+    // `sp1_sdk::include_elf!` reads. It is set even when the build is skipped below, so that an ELF
+    // built earlier is still found.
+    println!("cargo:rustc-env=SP1_ELF_{}={}", root_package_name, elf_output_path(&args, &metadata));
 
     // Skip the program build if the SP1_SKIP_PROGRAM_BUILD environment variable is set to true.
     let skip_program_build = std::env::var("SP1_SKIP_PROGRAM_BUILD")
@@ -86,12 +114,17 @@ pub(crate) fn build_program_internal(path: &str, args: Option<BuildArgs>) {
         return;
     }
 
+    // Warn about the dependencies that could be accelerated with an SP1 patch.
+    let report_path = metadata.target_directory.join(HELPER_TARGET_SUBDIR).join(PATCH_REPORT_FILE);
+    for entry in PatchReport::new(&metadata).available() {
+        println!(
+            "cargo:warning={} v{} has an SP1 patch accelerating {}, see {}",
+            entry.name, entry.version, entry.accelerates, report_path
+        );
+    }
+
     // Build the program with the given arguments.
-    let path_output = if let Some(args) = args {
-        execute_build_program(&args, Some(program_dir.to_path_buf()))
-    } else {
-        execute_build_program(&BuildArgs::default(), Some(program_dir.to_path_buf()))
-    };
+    let path_output = execute_build_program(&args, Some(program_dir.to_path_buf()));
     if let Err(err) = path_output {
         panic!("Failed to build SP1 program: {}.", err);
     }
@@ -5,7 +5,10 @@ use cargo_metadata::camino::Utf8PathBuf;
 
 use crate::BuildArgs;
 
-use super::utils::{get_program_build_args, get_rust_compiler_flags};
+use super::utils::{
+    get_program_build_args, get_rust_compiler_flags, write_unwind_linker_script,
+    UNWIND_LINKER_SCRIPT_FILE,
+};
 
 /// Uses SP1_DOCKER_IMAGE environment variable if set, otherwise constructs the image to use based
 /// on the provided tag.
@@ -60,6 +63,21 @@ pub(crate) fn create_docker_command(
         "docker"
     );
 
+    // If the program unwinds, write the linker script for the unwinder into the mounted target
+    // directory, and refer to it by its path in the container.
+    let unwind_linker_script = if args.panic_unwind {
+        write_unwind_linker_script(
+            &program_metadata.target_directory.join(crate::HELPER_TARGET_SUBDIR).join("docker"),
+        )?;
+        Some(Utf8PathBuf::from(format!("{}/{}", target_dir, UNWIND_LINKER_SCRIPT_FILE)))
+    } else {
+        None
+    };
+    let bootstrap_args = match args.panic_unwind {
+        true => vec!["-e".to_string(), "RUSTC_BOOTSTRAP=1".to_string()],
+        false => vec![],
+    };
+
     // When executing the Docker command:
     // 1. Set the target directory to a subdirectory of the program's target directory to avoid
     //    build
@@ -82,12 +100,13 @@ pub(crate) fn create_docker_command(
         "-e".to_string(),
         "RUSTUP_TOOLCHAIN=succinct".to_string(),
         "-e".to_string(),
-        format!("CARGO_ENCODED_RUSTFLAGS={}", get_rust_compiler_flags()),
-        "--entrypoint".to_string(),
-        "".to_string(),
-        image,
-        "cargo".to_string(),
+        format!(
+            "CARGO_ENCODED_RUSTFLAGS={}",
+            get_rust_compiler_flags(unwind_linker_script.as_deref())
+        ),
     ];
+    docker_args.extend(bootstrap_args);
+    docker_args.extend(["--entrypoint".to_string(), "".to_string(), image, "cargo".to_string()]);
 
     // Add the SP1 program build arguments.
     docker_args.extend_from_slice(&get_program_build_args(args));
@@ -4,7 +4,10 @@ use crate::{BuildArgs, HELPER_TARGET_SUBDIR};
 use cargo_metadata::camino::Utf8PathBuf;
 use dirs::home_dir;
 
-use super::utils::{get_program_build_args, get_rust_compiler_flags};
+use super::utils::{
+    get_program_build_args, get_rust_compiler_flags, write_unwind_linker_script,
+    UNWIND_LINKER_SCRIPT_FILE,
+};
 
 /// Get the command to build the program locally.
 pub(crate) fn create_local_command(
@@ -27,6 +30,14 @@ pub(crate) fn create_local_command(
         }
     }
 
+    // If the program unwinds, write the linker script for the unwinder next to the build artifacts.
+    let target_dir = program_metadata.target_directory.join(HELPER_TARGET_SUBDIR);
+    let unwind_linker_script = args.panic_unwind.then(|| {
+        write_unwind_linker_script(&target_dir).expect("Failed to write unwind linker script");
+        command.env("RUSTC_BOOTSTRAP", "1");
+        target_dir.join(UNWIND_LINKER_SCRIPT_FILE)
+    });
+
     // When executing the local command:
     // 1. Set the target directory to a subdirectory of the program's target directory to avoid
     //    build
@@ -39,9 +50,9 @@ pub(crate) fn create_local_command(
     command
         .current_dir(canonicalized_program_dir)
         .env("RUSTUP_TOOLCHAIN", "succinct")
-        .env("CARGO_ENCODED_RUSTFLAGS", get_rust_compiler_flags())
+        .env("CARGO_ENCODED_RUSTFLAGS", get_rust_compiler_flags(unwind_linker_script.as_deref()))
         .env_remove("RUSTC")
-        .env("CARGO_TARGET_DIR", program_metadata.target_directory.join(HELPER_TARGET_SUBDIR))
+        .env("CARGO_TARGET_DIR", target_dir)
         .args(&get_program_build_args(args));
     command
 }
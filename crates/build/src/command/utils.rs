@@ -1,4 +1,5 @@
 use anyhow::{Context, Result};
+use cargo_metadata::camino::Utf8Path;
 use std::{
     io::{BufRead, BufReader},
     process::{exit, Command, Stdio},
@@ -38,19 +39,59 @@ pub(crate) fn get_program_build_args(args: &BuildArgs) -> Vec<String> {
         build_args.push("--locked".to_string());
     }
 
+    // The precompiled standard library of the toolchain is built with `panic=abort`, so it has to
+    // be rebuilt to unwind.
+    if args.panic_unwind {
+        build_args.push("-Zbuild-std=std,panic_unwind".to_string());
+    }
+
     build_args
 }
 
+/// The linker script that exposes the `.eh_frame_hdr` section to the unwinder of `sp1-zkvm`.
+pub(crate) const UNWIND_LINKER_SCRIPT: &str = r#"SECTIONS {
+  .eh_frame_hdr : {
+    __GNU_EH_FRAME_HDR = .;
+    KEEP(*(.eh_frame_hdr))
+  }
+}
+INSERT AFTER .rodata;
+"#;
+
+/// The file name of [`UNWIND_LINKER_SCRIPT`] in the helper target directory.
+pub(crate) const UNWIND_LINKER_SCRIPT_FILE: &str = "sp1-unwind.ld";
+
+/// Writes [`UNWIND_LINKER_SCRIPT`] to the given directory.
+pub(crate) fn write_unwind_linker_script(dir: &Utf8Path) -> Result<()> {
+    std::fs::create_dir_all(dir)?;
+    std::fs::write(dir.join(UNWIND_LINKER_SCRIPT_FILE), UNWIND_LINKER_SCRIPT)?;
+    Ok(())
+}
+
 /// Rust flags for compilation of C libraries.
-pub(crate) fn get_rust_compiler_flags() -> String {
-    let rust_flags = [
+///
+/// If `unwind_linker_script` is set, the program is compiled with `panic=unwind` and unwind tables,
+/// and linked with the given script.
+pub(crate) fn get_rust_compiler_flags(unwind_linker_script: Option<&Utf8Path>) -> String {
+    let mut rust_flags = vec![
         "-C".to_string(),
         "passes=loweratomic".to_string(),
         "-C".to_string(),
         "link-arg=-Ttext=0x00200800".to_string(),
-        "-C".to_string(),
-        "panic=abort".to_string(),
     ];
+    match unwind_linker_script {
+        Some(script) => rust_flags.extend([
+            "-C".to_string(),
+            "panic=unwind".to_string(),
+            "-C".to_string(),
+            "force-unwind-tables=yes".to_string(),
+            "-C".to_string(),
+            "link-arg=--eh-frame-hdr".to_string(),
+            "-C".to_string(),
+            format!("link-arg=-T{}", script),
+        ]),
+        None => rust_flags.extend(["-C".to_string(), "panic=abort".to_string()]),
+    }
     rust_flags.join("\x1f")
 }
 
@@ -90,3 +131,53 @@ pub(crate) fn execute_command(mut command: Command, docker: bool) -> Result<()>
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use cargo_metadata::camino::Utf8PathBuf;
+
+    use super::*;
+
+    fn flags(unwind_linker_script: Option<&Utf8Path>) -> Vec<String> {
+        get_rust_compiler_flags(unwind_linker_script).split('\x1f').map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_panic_abort_flags() {
+        let flags = flags(None);
+        assert!(flags.windows(2).any(|w| w == ["-C", "panic=abort"]));
+        assert!(!flags.iter().any(|flag| flag.contains("unwind")));
+        assert!(!get_program_build_args(&BuildArgs::default())
+            .iter()
+            .any(|arg| arg.starts_with("-Zbuild-std")));
+    }
+
+    #[test]
+    fn test_panic_unwind_flags() {
+        let script = Utf8Path::new("/target/sp1-unwind.ld");
+        let flags = flags(Some(script));
+        for flag in [
+            "panic=unwind",
+            "force-unwind-tables=yes",
+            "link-arg=--eh-frame-hdr",
+            "link-arg=-T/target/sp1-unwind.ld",
+        ] {
+            assert!(flags.windows(2).any(|w| w == ["-C", flag]), "missing -C {flag}");
+        }
+        assert!(!flags.iter().any(|flag| flag == "panic=abort"));
+
+        let args = BuildArgs { panic_unwind: true, ..Default::default() };
+        assert!(get_program_build_args(&args).contains(&"-Zbuild-std=std,panic_unwind".to_string()));
+    }
+
+    #[test]
+    fn test_write_unwind_linker_script() {
+        let dir = std::env::temp_dir().join(format!("sp1-build-unwind-{}", std::process::id()));
+        let dir = Utf8PathBuf::from_path_buf(dir).unwrap();
+        write_unwind_linker_script(&dir).unwrap();
+        let script = std::fs::read_to_string(dir.join(UNWIND_LINKER_SCRIPT_FILE)).unwrap();
+        assert!(script.contains("__GNU_EH_FRAME_HDR = .;"));
+        assert!(script.contains("KEEP(*(.eh_frame_hdr))"));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
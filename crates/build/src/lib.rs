@@ -1,8 +1,10 @@
 mod build;
 mod command;
+mod patches;
 mod utils;
 use build::build_program_internal;
 pub use build::execute_build_program;
+pub use patches::{PatchEntry, PatchReport, PatchReportEntry, PatchStatus, PATCH_REPORT_FILE};
 
 use clap::Parser;
 
@@ -43,6 +45,20 @@ pub struct BuildArgs {
     pub ignore_rust_version: bool,
     #[clap(long, action, help = "Assert that `Cargo.lock` will remain unchanged")]
     pub locked: bool,
+    #[clap(
+        long,
+        action,
+        help = "Build with `panic=unwind` so that `std::panic::catch_unwind` works in the zkVM. \
+                Requires the `unwind` feature of `sp1-zkvm`"
+    )]
+    pub panic_unwind: bool,
+    #[clap(
+        long,
+        action,
+        help = "Add the SP1 patches of the dependencies that have one to the `[patch.crates-io]` \
+                table of the workspace manifest"
+    )]
+    pub auto_patch: bool,
     #[clap(
         alias = "bin",
         long,
@@ -76,6 +92,8 @@ impl Default for BuildArgs {
             output_directory: DEFAULT_OUTPUT_DIR.to_string(),
             locked: false,
             no_default_features: false,
+            panic_unwind: false,
+            auto_patch: false,
         }
     }
 }
@@ -0,0 +1,352 @@
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result};
+use cargo_metadata::Metadata;
+use serde::{Deserialize, Serialize};
+use toml_edit::{value, DocumentMut, InlineTable, Item, Table};
+
+/// The name of the patch report written to the helper target directory.
+pub const PATCH_REPORT_FILE: &str = "sp1-patch-report.json";
+
+/// A fork of a crate maintained by SP1 that routes its hot paths to precompiles.
+struct KnownPatch {
+    /// The name of the crate.
+    name: &'static str,
+    /// The version of the crate the fork is based on.
+    version: &'static str,
+    /// The key of the patch in `[patch.crates-io]`.
+    key: &'static str,
+    /// The repository of the fork.
+    git: &'static str,
+    /// The branch of the fork.
+    branch: &'static str,
+    /// The operations the patch accelerates.
+    accelerates: &'static str,
+}
+
+/// The patches listed in the "Patched Crates" section of the book.
+const KNOWN_PATCHES: &[KnownPatch] = &[
+    KnownPatch {
+        name: "sha2",
+        version: "0.9.8",
+        key: "sha2-v0-9-8",
+        git: "https://github.com/sp1-patches/RustCrypto-hashes",
+        branch: "patch-sha2-v0.9.8",
+        accelerates: "sha256",
+    },
+    KnownPatch {
+        name: "sha2",
+        version: "0.10.6",
+        key: "sha2-v0-10-6",
+        git: "https://github.com/sp1-patches/RustCrypto-hashes",
+        branch: "patch-sha2-v0.10.6",
+        accelerates: "sha256",
+    },
+    KnownPatch {
+        name: "sha2",
+        version: "0.10.8",
+        key: "sha2-v0-10-8",
+        git: "https://github.com/sp1-patches/RustCrypto-hashes",
+        branch: "patch-sha2-v0.10.8",
+        accelerates: "sha256",
+    },
+    KnownPatch {
+        name: "sha3",
+        version: "0.9.8",
+        key: "sha3-v0-9-8",
+        git: "https://github.com/sp1-patches/RustCrypto-hashes",
+        branch: "patch-sha3-v0.9.8",
+        accelerates: "keccak256",
+    },
+    KnownPatch {
+        name: "sha3",
+        version: "0.10.6",
+        key: "sha3-v0-10-6",
+        git: "https://github.com/sp1-patches/RustCrypto-hashes",
+        branch: "patch-sha3-v0.10.6",
+        accelerates: "keccak256",
+    },
+    KnownPatch {
+        name: "sha3",
+        version: "0.10.8",
+        key: "sha3-v0-10-8",
+        git: "https://github.com/sp1-patches/RustCrypto-hashes",
+        branch: "patch-sha3-v0.10.8",
+        accelerates: "keccak256",
+    },
+    KnownPatch {
+        name: "crypto-bigint",
+        version: "0.5.5",
+        key: "crypto-bigint",
+        git: "https://github.com/sp1-patches/RustCrypto-bigint",
+        branch: "patch-v0.5.5",
+        accelerates: "bigint",
+    },
+    KnownPatch {
+        name: "tiny-keccak",
+        version: "2.0.2",
+        key: "tiny-keccak",
+        git: "https://github.com/sp1-patches/tiny-keccak",
+        branch: "patch-v2.0.2",
+        accelerates: "keccak256",
+    },
+    KnownPatch {
+        name: "curve25519-dalek",
+        version: "4.1.3",
+        key: "curve25519-dalek",
+        git: "https://github.com/sp1-patches/curve25519-dalek",
+        branch: "patch-curve25519-v4.1.3",
+        accelerates: "ed25519 verify",
+    },
+    KnownPatch {
+        name: "curve25519-dalek-ng",
+        version: "4.1.1",
+        key: "curve25519-dalek-ng",
+        git: "https://github.com/sp1-patches/curve25519-dalek-ng",
+        branch: "patch-v4.1.1",
+        accelerates: "ed25519 verify",
+    },
+    KnownPatch {
+        name: "ed25519-consensus",
+        version: "2.1.0",
+        key: "ed25519-consensus",
+        git: "https://github.com/sp1-patches/ed25519-consensus",
+        branch: "patch-v2.1.0",
+        accelerates: "ed25519 verify",
+    },
+    // `k256` verifies secp256k1 signatures through `ecdsa`, so this is the patch that
+    // accelerates it.
+    KnownPatch {
+        name: "ecdsa",
+        version: "0.16.9",
+        key: "ecdsa-core",
+        git: "https://github.com/sp1-patches/signatures",
+        branch: "patch-ecdsa-v0.16.9",
+        accelerates: "secp256k1 verify",
+    },
+    KnownPatch {
+        name: "secp256k1",
+        version: "0.29.0",
+        key: "secp256k1",
+        git: "https://github.com/sp1-patches/rust-secp256k1",
+        branch: "patch-secp256k1-v0.29.0",
+        accelerates: "secp256k1 verify",
+    },
+    KnownPatch {
+        name: "substrate-bn",
+        version: "0.6.0",
+        key: "substrate-bn",
+        git: "https://github.com/sp1-patches/bn",
+        branch: "patch-v0.6.0",
+        accelerates: "bn254",
+    },
+    KnownPatch {
+        name: "bls12_381",
+        version: "0.8.8",
+        key: "bls12_381",
+        git: "https://github.com/sp1-patches/bls12_381",
+        branch: "patch-v0.8.8",
+        accelerates: "bls12_381",
+    },
+];
+
+/// Whether a dependency of the program uses an SP1 patch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PatchStatus {
+    /// The dependency is resolved to an SP1 fork.
+    Patched,
+    /// The dependency comes from crates.io, and there is an SP1 fork of its version.
+    Available,
+    /// The dependency comes from crates.io, but there is no SP1 fork of its version.
+    Unavailable,
+}
+
+/// An entry of `[patch.crates-io]` replacing a dependency with its SP1 fork.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PatchEntry {
+    pub key: String,
+    pub git: String,
+    pub branch: String,
+    /// The name of the patched crate, if it differs from the key.
+    pub package: Option<String>,
+}
+
+/// A dependency of the program which SP1 maintains a fork of.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PatchReportEntry {
+    pub name: String,
+    pub version: String,
+    pub status: PatchStatus,
+    /// The operations the fork accelerates.
+    pub accelerates: String,
+    /// The patch to apply, if the status is [PatchStatus::Available].
+    pub patch: Option<PatchEntry>,
+}
+
+/// A report of the dependencies of a program that have SP1 patches.
+///
+/// The report is built from all the packages of the resolved dependency graph, including the ones
+/// that are only used on the host, such as the dependencies of build scripts.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PatchReport {
+    pub entries: Vec<PatchReportEntry>,
+}
+
+impl PatchReport {
+    /// Builds the report of the resolved dependencies of a program.
+    pub fn new(metadata: &Metadata) -> Self {
+        let mut entries = metadata
+            .packages
+            .iter()
+            .filter_map(|package| {
+                let name = package.name.as_str();
+                let version = package.version.to_string();
+                let known = KNOWN_PATCHES.iter().filter(|patch| patch.name == name);
+                let accelerates = known.clone().next()?.accelerates;
+                let source = package.source.as_ref()?;
+                let (status, patch) = if source.repr.contains("github.com/sp1-patches/") {
+                    (PatchStatus::Patched, None)
+                } else if !source.is_crates_io() {
+                    return None;
+                } else if let Some(known) = known.clone().find(|patch| patch.version == version) {
+                    (PatchStatus::Available, Some(PatchEntry::from(known)))
+                } else {
+                    (PatchStatus::Unavailable, None)
+                };
+                Some(PatchReportEntry {
+                    name: name.to_string(),
+                    version,
+                    status,
+                    accelerates: accelerates.to_string(),
+                    patch,
+                })
+            })
+            .collect::<Vec<_>>();
+        entries.sort_by(|a, b| (&a.name, &a.version).cmp(&(&b.name, &b.version)));
+        Self { entries }
+    }
+
+    /// Returns the dependencies that can be patched.
+    pub fn available(&self) -> impl Iterator<Item = &PatchReportEntry> {
+        self.entries.iter().filter(|entry| entry.status == PatchStatus::Available)
+    }
+
+    /// Writes the report as JSON to `path`.
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("failed to write the patch report to {}", path.display()))
+    }
+
+    /// Adds the available patches to the `[patch.crates-io]` table of the manifest at
+    /// `manifest_path`, returning the number of patches added.
+    ///
+    /// Patches whose key is already in the table are left untouched.
+    pub fn apply(&self, manifest_path: impl AsRef<Path>) -> Result<usize> {
+        let manifest_path = manifest_path.as_ref();
+        let manifest = fs::read_to_string(manifest_path)
+            .with_context(|| format!("failed to read {}", manifest_path.display()))?;
+        let (manifest, added) = self.apply_to_manifest(&manifest)?;
+        if added > 0 {
+            fs::write(manifest_path, manifest)?;
+        }
+        Ok(added)
+    }
+
+    fn apply_to_manifest(&self, manifest: &str) -> Result<(String, usize)> {
+        let mut document = manifest.parse::<DocumentMut>()?;
+        let patch = document
+            .entry("patch")
+            .or_insert_with(|| {
+                let mut table = Table::new();
+                table.set_implicit(true);
+                Item::Table(table)
+            })
+            .as_table_mut()
+            .context("`patch` is not a table")?;
+        let crates_io = patch
+            .entry("crates-io")
+            .or_insert(Item::Table(Table::new()))
+            .as_table_mut()
+            .context("`patch.crates-io` is not a table")?;
+
+        let mut added = 0;
+        for patch in self.available().filter_map(|entry| entry.patch.as_ref()) {
+            if crates_io.contains_key(&patch.key) {
+                continue;
+            }
+            let mut entry = InlineTable::new();
+            entry.insert("git", patch.git.as_str().into());
+            if let Some(package) = &patch.package {
+                entry.insert("package", package.as_str().into());
+            }
+            entry.insert("branch", patch.branch.as_str().into());
+            crates_io.insert(&patch.key, value(entry));
+            added += 1;
+        }
+        Ok((document.to_string(), added))
+    }
+}
+
+impl From<&KnownPatch> for PatchEntry {
+    fn from(patch: &KnownPatch) -> Self {
+        Self {
+            key: patch.key.to_string(),
+            git: patch.git.to_string(),
+            branch: patch.branch.to_string(),
+            package: (patch.key != patch.name).then(|| patch.name.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn available(name: &str, version: &str) -> PatchReportEntry {
+        let known = KNOWN_PATCHES
+            .iter()
+            .find(|patch| patch.name == name && patch.version == version)
+            .unwrap();
+        PatchReportEntry {
+            name: name.to_string(),
+            version: version.to_string(),
+            status: PatchStatus::Available,
+            accelerates: known.accelerates.to_string(),
+            patch: Some(PatchEntry::from(known)),
+        }
+    }
+
+    #[test]
+    fn test_apply_patches_to_manifest() {
+        let report = PatchReport {
+            entries: vec![available("sha2", "0.10.8"), available("tiny-keccak", "2.0.2")],
+        };
+        let manifest = "\
+[package]
+name = \"program\"
+
+[patch.crates-io]
+tiny-keccak = { git = \"https://example.com/tiny-keccak\" }
+";
+        let (patched, added) = report.apply_to_manifest(manifest).unwrap();
+        assert_eq!(added, 1);
+        let document = patched.parse::<DocumentMut>().unwrap();
+        let crates_io = &document["patch"]["crates-io"];
+        assert_eq!(crates_io["sha2-v0-10-8"]["package"].as_str(), Some("sha2"));
+        assert_eq!(crates_io["sha2-v0-10-8"]["branch"].as_str(), Some("patch-sha2-v0.10.8"));
+        assert_eq!(
+            crates_io["tiny-keccak"]["git"].as_str(),
+            Some("https://example.com/tiny-keccak")
+        );
+
+        let (patched, added) = report.apply_to_manifest("[package]\nname = \"program\"\n").unwrap();
+        assert_eq!(added, 2);
+        assert!(!patched.contains("[patch]"));
+        assert!(patched.contains("[patch.crates-io]"));
+    }
+}
@@ -2,9 +2,10 @@ use anyhow::Result;
 use clap::{Parser, Subcommand};
 use sp1_cli::{
     commands::{
-        build::BuildCmd, build_toolchain::BuildToolchainCmd,
-        install_toolchain::InstallToolchainCmd, new::NewCmd, prove::ProveCmd, trace::TraceCmd,
-        vkey::VkeyCmd,
+        bench::BenchCmd, build::BuildCmd, build_toolchain::BuildToolchainCmd,
+        constraints::ConstraintsCmd, disasm::DisasmCmd, inspect::InspectCmd,
+        install_toolchain::InstallToolchainCmd, new::NewCmd, prove::ProveCmd, shapes::ShapesCmd,
+        trace::TraceCmd, vkey::VkeyCmd,
     },
     SP1_VERSION_MESSAGE,
 };
@@ -34,6 +35,11 @@ pub enum ProveCliCommands {
     InstallToolchain(InstallToolchainCmd),
     Trace(TraceCmd),
     Vkey(VkeyCmd),
+    Disasm(DisasmCmd),
+    Inspect(InspectCmd),
+    Shapes(ShapesCmd),
+    Bench(BenchCmd),
+    Constraints(ConstraintsCmd),
 }
 
 fn main() -> Result<()> {
@@ -47,5 +53,10 @@ fn main() -> Result<()> {
         ProveCliCommands::InstallToolchain(cmd) => cmd.run(),
         ProveCliCommands::Trace(cmd) => cmd.run(),
         ProveCliCommands::Vkey(cmd) => cmd.run(),
+        ProveCliCommands::Disasm(cmd) => cmd.run(),
+        ProveCliCommands::Inspect(cmd) => cmd.run(),
+        ProveCliCommands::Shapes(cmd) => cmd.run(),
+        ProveCliCommands::Bench(cmd) => cmd.run(),
+        ProveCliCommands::Constraints(cmd) => cmd.run(),
     }
 }
@@ -0,0 +1,278 @@
+use std::{
+    fs,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Result};
+use clap::Parser;
+use prettytable::{format, Cell, Row, Table};
+use serde::Serialize;
+use sp1_core_executor::{syscalls::SyscallCode, Executor, Instruction, Opcode, Program};
+use sp1_core_machine::{io::SP1Stdin, utils::prove};
+use sp1_stark::{baby_bear_poseidon2::BabyBearPoseidon2, CpuProver, SP1CoreOpts};
+
+/// The size of the buffers copied to measure the memory bandwidth.
+const BANDWIDTH_BUFFER_SIZE: usize = 1 << 28;
+
+/// The number of copies of the buffer timed to measure the memory bandwidth.
+const BANDWIDTH_ROUNDS: usize = 4;
+
+/// The address of the buffer of the memory workload.
+const MEMORY_WORKLOAD_BASE: u32 = 0x0010_0000;
+
+/// The address of the Keccak state of the precompile workload.
+const KECCAK_STATE_PTR: u32 = 0x0020_0000;
+
+#[derive(Parser)]
+#[command(
+    name = "bench",
+    about = "Benchmark the prover on a standard suite and compute a prover score normalized by the \
+             hardware."
+)]
+pub struct BenchCmd {
+    /// Multiply the number of iterations of each workload, to benchmark longer executions.
+    #[arg(long, default_value_t = 1)]
+    scale: u32,
+
+    /// Only run the workloads with these names.
+    #[arg(long, value_delimiter = ',')]
+    workloads: Vec<String>,
+
+    /// Write the hardware, the results and the scores to this JSON file.
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+/// A program of the benchmark suite.
+struct Workload {
+    name: &'static str,
+    description: &'static str,
+    /// Builds the program running the given number of iterations.
+    program: fn(u32) -> Program,
+    /// The number of iterations at scale 1.
+    iterations: u32,
+}
+
+const WORKLOADS: &[Workload] = &[
+    Workload {
+        name: "alu",
+        description: "arithmetic, logic and comparison instructions",
+        program: alu_program,
+        iterations: 1 << 17,
+    },
+    Workload {
+        name: "memory",
+        description: "loads and stores over a 4 KiB buffer",
+        program: memory_program,
+        iterations: 1 << 17,
+    },
+    Workload {
+        name: "keccak",
+        description: "Keccak permutations with the precompile",
+        program: keccak_program,
+        iterations: 1 << 12,
+    },
+];
+
+/// The hardware the benchmark ran on.
+#[derive(Debug, Serialize)]
+pub struct Hardware {
+    /// The number of threads available to the prover.
+    pub cores: usize,
+    /// The single-threaded memory copy bandwidth, in GB/s.
+    pub memory_bandwidth_gbps: f64,
+}
+
+/// The results of a workload of the suite.
+#[derive(Debug, Serialize)]
+pub struct WorkloadResult {
+    pub name: String,
+    pub cycles: u64,
+    pub execution_secs: f64,
+    pub proving_secs: f64,
+    /// The proving throughput, in thousands of cycles per second.
+    pub proving_khz: f64,
+}
+
+/// The report of a benchmark run.
+#[derive(Debug, Serialize)]
+pub struct BenchReport {
+    pub sp1_version: String,
+    pub hardware: Hardware,
+    pub workloads: Vec<WorkloadResult>,
+    /// The geometric mean of the proving throughputs of the workloads, in kHz.
+    pub score: f64,
+    /// The score divided by the geometric mean of the cores and the memory bandwidth.
+    pub normalized_score: f64,
+}
+
+impl BenchCmd {
+    pub fn run(&self) -> Result<()> {
+        if self.scale == 0 {
+            bail!("the scale must be positive");
+        }
+        let workloads = WORKLOADS
+            .iter()
+            .filter(|workload| {
+                self.workloads.is_empty() || self.workloads.iter().any(|name| name == workload.name)
+            })
+            .collect::<Vec<_>>();
+        if workloads.is_empty() {
+            let names = WORKLOADS.iter().map(|workload| workload.name).collect::<Vec<_>>();
+            bail!("no workload selected, expected some of: {}", names.join(", "));
+        }
+
+        let hardware = Hardware {
+            cores: std::thread::available_parallelism().map_or(1, |cores| cores.get()),
+            memory_bandwidth_gbps: memory_bandwidth_gbps(),
+        };
+        println!(
+            "Hardware: {} cores, {:.2} GB/s memory bandwidth\n",
+            hardware.cores, hardware.memory_bandwidth_gbps
+        );
+
+        let mut results = Vec::new();
+        for workload in workloads {
+            println!("Running {} ({})...", workload.name, workload.description);
+            let program = (workload.program)(workload.iterations * self.scale);
+            results.push(run_workload(workload.name, program)?);
+        }
+
+        let score = geometric_mean(results.iter().map(|result| result.proving_khz));
+        let normalized_score =
+            score / (hardware.cores as f64 * hardware.memory_bandwidth_gbps).sqrt();
+        let report = BenchReport {
+            sp1_version: sp1_sdk::SP1_CIRCUIT_VERSION.to_string(),
+            hardware,
+            workloads: results,
+            score,
+            normalized_score,
+        };
+
+        let mut table = Table::new();
+        table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+        table.set_titles(Row::new(vec![
+            Cell::new("Workload"),
+            Cell::new("Cycles"),
+            Cell::new("Execution (s)"),
+            Cell::new("Proving (s)"),
+            Cell::new("Proving (kHz)"),
+        ]));
+        for result in &report.workloads {
+            table.add_row(Row::new(vec![
+                Cell::new(&result.name),
+                Cell::new(&result.cycles.to_string()),
+                Cell::new(&format!("{:.2}", result.execution_secs)),
+                Cell::new(&format!("{:.2}", result.proving_secs)),
+                Cell::new(&format!("{:.2}", result.proving_khz)),
+            ]));
+        }
+        println!();
+        table.printstd();
+        println!("\nProver score: {:.2}", report.score);
+        println!("Normalized score: {:.3} per sqrt(core * GB/s)", report.normalized_score);
+
+        if let Some(output) = &self.output {
+            fs::write(output, serde_json::to_string_pretty(&report)?)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Executes and proves the program, timing both.
+fn run_workload(name: &str, program: Program) -> Result<WorkloadResult> {
+    let opts = SP1CoreOpts::default();
+
+    let start = Instant::now();
+    let mut runtime = Executor::new(program.clone(), opts);
+    runtime.run()?;
+    let execution = start.elapsed();
+
+    let start = Instant::now();
+    let (_, _, cycles) =
+        prove::<_, CpuProver<_, _>>(program, &SP1Stdin::new(), BabyBearPoseidon2::new(), opts)?;
+    let proving = start.elapsed();
+
+    Ok(WorkloadResult {
+        name: name.to_string(),
+        cycles,
+        execution_secs: execution.as_secs_f64(),
+        proving_secs: proving.as_secs_f64(),
+        proving_khz: khz(cycles, proving),
+    })
+}
+
+fn khz(cycles: u64, duration: Duration) -> f64 {
+    cycles as f64 / duration.as_secs_f64() / 1000.0
+}
+
+fn geometric_mean(values: impl Iterator<Item = f64>) -> f64 {
+    let (sum, count) = values.fold((0.0, 0), |(sum, count), value| (sum + value.ln(), count + 1));
+    (sum / count as f64).exp()
+}
+
+/// Measures the bandwidth of copying a buffer much larger than the caches, counting both the reads
+/// and the writes.
+fn memory_bandwidth_gbps() -> f64 {
+    let source = vec![1u8; BANDWIDTH_BUFFER_SIZE];
+    let mut destination = vec![0u8; BANDWIDTH_BUFFER_SIZE];
+    // Warm up, so that the pages of the destination are mapped before the timed copies.
+    destination.copy_from_slice(&source);
+    let start = Instant::now();
+    for _ in 0..BANDWIDTH_ROUNDS {
+        destination.copy_from_slice(std::hint::black_box(&source));
+    }
+    let elapsed = start.elapsed().as_secs_f64();
+    std::hint::black_box(&destination);
+    (2 * BANDWIDTH_BUFFER_SIZE * BANDWIDTH_ROUNDS) as f64 / elapsed / 1e9
+}
+
+/// Builds a loop running `body` the given number of times, counting down in x6.
+fn counted_loop(iterations: u32, body: Vec<Instruction>) -> Vec<Instruction> {
+    let mut instructions = vec![Instruction::new(Opcode::ADD, 6, 0, iterations, false, true)];
+    let offset = -(4 * (body.len() as i32 + 1));
+    instructions.extend(body);
+    instructions.extend(vec![
+        Instruction::new(Opcode::ADD, 6, 6, -1i32 as u32, false, true),
+        Instruction::new(Opcode::BNE, 6, 0, offset as u32, false, true),
+    ]);
+    instructions
+}
+
+fn alu_program(iterations: u32) -> Program {
+    let body = vec![
+        Instruction::new(Opcode::ADD, 7, 7, 6, false, false),
+        Instruction::new(Opcode::MUL, 8, 7, 7, false, false),
+        Instruction::new(Opcode::XOR, 9, 8, 7, false, false),
+        Instruction::new(Opcode::SLL, 10, 9, 3, false, true),
+        Instruction::new(Opcode::SRL, 11, 10, 7, false, false),
+        Instruction::new(Opcode::SLTU, 12, 11, 9, false, false),
+        Instruction::new(Opcode::DIVU, 13, 8, 6, false, false),
+        Instruction::new(Opcode::SUB, 7, 13, 12, false, false),
+    ];
+    Program::new(counted_loop(iterations, body), 0, 0)
+}
+
+fn memory_program(iterations: u32) -> Program {
+    let body = vec![
+        Instruction::new(Opcode::AND, 7, 6, 1023, false, true),
+        Instruction::new(Opcode::SLL, 7, 7, 2, false, true),
+        Instruction::new(Opcode::ADD, 7, 7, MEMORY_WORKLOAD_BASE, false, true),
+        Instruction::new(Opcode::LW, 8, 7, 0, false, true),
+        Instruction::new(Opcode::ADD, 8, 8, 6, false, false),
+        Instruction::new(Opcode::SW, 8, 7, 0, false, true),
+    ];
+    Program::new(counted_loop(iterations, body), 0, 0)
+}
+
+fn keccak_program(iterations: u32) -> Program {
+    // The syscall returns in x5, so the code is set again at each iteration.
+    let body = vec![
+        Instruction::new(Opcode::ADD, 5, 0, SyscallCode::KECCAK_PERMUTE as u32, false, true),
+        Instruction::new(Opcode::ADD, 10, 0, KECCAK_STATE_PTR, false, true),
+        Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+    ];
+    Program::new(counted_loop(iterations, body), 0, 0)
+}
@@ -0,0 +1,82 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use sp1_core_machine::riscv::RiscvAir;
+use sp1_stark::{baby_bear_poseidon2::BabyBearPoseidon2, ConstraintLock};
+
+#[derive(Parser)]
+#[command(
+    name = "constraints",
+    about = "Export the constraints and interactions of every chip of the RISC-V machine, or \
+             check them against a lockfile."
+)]
+pub struct ConstraintsCmd {
+    /// Write the constraint system to this JSON file.
+    #[arg(long, required_unless_present_any = ["lock", "check"])]
+    output: Option<PathBuf>,
+
+    /// Also write the interactions of every chip to this CSV file.
+    #[arg(long)]
+    interactions: Option<PathBuf>,
+
+    /// Write the column counts, constraint counts and interaction signatures of every chip to
+    /// this lockfile.
+    #[arg(long)]
+    lock: Option<PathBuf>,
+
+    /// Compare the constraint system with this lockfile, and fail with the list of changes if it
+    /// differs, since the changes break the verifying keys.
+    #[arg(long)]
+    check: Option<PathBuf>,
+}
+
+impl ConstraintsCmd {
+    pub fn run(&self) -> Result<()> {
+        let machine = RiscvAir::machine(BabyBearPoseidon2::new());
+        let system = machine.constraint_system();
+
+        if let Some(path) = &self.output {
+            let json = serde_json::to_string_pretty(&system)?;
+            fs::write(path, json).with_context(|| format!("failed to write {}", path.display()))?;
+        }
+        if let Some(path) = &self.interactions {
+            fs::write(path, system.interactions_csv())
+                .with_context(|| format!("failed to write {}", path.display()))?;
+        }
+
+        let constraints = system.chips.iter().map(|chip| chip.constraints.len()).sum::<usize>();
+        let interactions =
+            system.chips.iter().map(|chip| chip.sends.len() + chip.receives.len()).sum::<usize>();
+        println!(
+            "The machine has {} chips with {} constraints and {} interactions.",
+            system.chips.len(),
+            constraints,
+            interactions
+        );
+
+        let lock = system.lock();
+        if let Some(path) = &self.lock {
+            fs::write(path, lock.to_string())
+                .with_context(|| format!("failed to write {}", path.display()))?;
+        }
+        if let Some(path) = &self.check {
+            let expected: ConstraintLock = fs::read_to_string(path)
+                .with_context(|| format!("failed to read {}", path.display()))?
+                .parse()?;
+            let changes = expected.diff(&lock);
+            if !changes.is_empty() {
+                for change in &changes {
+                    println!("{change}");
+                }
+                bail!(
+                    "the constraint system has {} changes from {}",
+                    changes.len(),
+                    path.display()
+                );
+            }
+            println!("The constraint system matches {}.", path.display());
+        }
+        Ok(())
+    }
+}
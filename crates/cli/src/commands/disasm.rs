@@ -0,0 +1,113 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    fs::{self, File},
+    path::PathBuf,
+};
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use goblin::elf::{sym::STT_FUNC, Elf};
+use rustc_demangle::demangle;
+use sp1_core_executor::Program;
+use sp1_sdk::ExecutionReport;
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "disasm",
+    about = "Disassemble a program, optionally annotated with the cycle counts of an execution."
+)]
+pub struct DisasmCmd {
+    /// Path to the ELF.
+    #[arg(long, required = true)]
+    elf: PathBuf,
+
+    /// Path to an `ExecutionReport` serialized as JSON, used to annotate each instruction with
+    /// the number of times it was executed.
+    #[arg(long)]
+    report: Option<PathBuf>,
+
+    /// Path to write the listing to. Defaults to stdout.
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+/// A function symbol of the ELF.
+struct Function {
+    name: String,
+    end: u32,
+}
+
+impl DisasmCmd {
+    pub fn run(&self) -> Result<()> {
+        let elf = fs::read(&self.elf).context("failed to read the ELF")?;
+        let program =
+            Program::from(&elf).map_err(|err| anyhow::anyhow!("failed to load the ELF: {err}"))?;
+        let functions = function_symbols(&elf)?;
+        let report: Option<ExecutionReport> = match &self.report {
+            Some(path) => Some(
+                serde_json::from_reader(File::open(path).context("failed to open the report")?)
+                    .context("failed to parse the report")?,
+            ),
+            None => None,
+        };
+
+        let listing = listing(&program, &functions, report.as_ref());
+        match &self.output {
+            Some(path) => fs::write(path, listing)?,
+            None => print!("{listing}"),
+        }
+        Ok(())
+    }
+}
+
+/// Returns the function symbols of the ELF, by start address.
+fn function_symbols(elf: &[u8]) -> Result<BTreeMap<u32, Function>> {
+    let elf = Elf::parse(elf)?;
+    let mut functions = BTreeMap::new();
+    for sym in elf.syms.iter().filter(|sym| sym.st_type() == STT_FUNC && sym.st_size > 0) {
+        let name = elf.strtab.get_at(sym.st_name).unwrap_or("");
+        functions.insert(
+            sym.st_value as u32,
+            Function {
+                name: format!("{:#}", demangle(name)),
+                end: (sym.st_value + sym.st_size) as u32,
+            },
+        );
+    }
+    Ok(functions)
+}
+
+/// Formats the assembly listing of the program, with the execution count of each instruction
+/// and the total cycles of each function if a report is given.
+fn listing(
+    program: &Program,
+    functions: &BTreeMap<u32, Function>,
+    report: Option<&ExecutionReport>,
+) -> String {
+    let pc = |i: usize| program.pc_base + 4 * i as u32;
+    let mut out = String::new();
+    for (i, instruction) in program.instructions.iter().enumerate() {
+        if let Some(function) = functions.get(&pc(i)) {
+            write!(out, "\n{:08x} <{}>:", pc(i), function.name).unwrap();
+            if let Some(report) = report {
+                let cycles: u64 =
+                    (pc(i)..function.end).step_by(4).map(|pc| report.pc_count(pc)).sum();
+                write!(out, " {cycles} cycles").unwrap();
+            }
+            writeln!(out).unwrap();
+        }
+        write!(out, "{:8x}:   {:?}", pc(i), instruction).unwrap();
+        if let Some(report) = report {
+            let count = report.pc_count(pc(i));
+            if count > 0 {
+                write!(out, " {count:>12}").unwrap();
+            }
+        }
+        writeln!(out).unwrap();
+    }
+    if let Some(report) = report {
+        writeln!(out, "\ntotal: {} cycles", report.pc_counts.iter().sum::<u64>()).unwrap();
+    }
+    out
+}
@@ -0,0 +1,263 @@
+use std::{
+    borrow::Borrow,
+    io::{self, BufRead, IsTerminal, Write},
+    path::PathBuf,
+};
+
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use p3_field::PrimeField32;
+use prettytable::{format, Cell, Row, Table};
+use sp1_sdk::{CoreSC, SP1Proof, SP1ProofWithPublicValues};
+use sp1_stark::{air::PublicValues, ShardProof, StarkGenericConfig, Word};
+use yansi::Paint;
+
+#[derive(Parser)]
+#[command(
+    name = "inspect",
+    about = "Inspect the shards, chip heights and public values of a saved proof."
+)]
+pub struct InspectCmd {
+    /// Path to a proof saved with `SP1ProofWithPublicValues::save`.
+    proof: PathBuf,
+
+    /// Print the summary, the shards and the public values instead of prompting for commands.
+    #[arg(long)]
+    no_interactive: bool,
+}
+
+const HELP: &str = "\
+commands:
+  summary            the proof kind, the number of shards and the metadata of the proof
+  shards             the public values of each shard
+  shard <index>      the log heights of the chips of a shard
+  pv [hex|u32|u64|utf8]
+                     the public values committed by the program, in hex by default
+  help               this message
+  quit               exit the explorer";
+
+impl InspectCmd {
+    pub fn run(&self) -> Result<()> {
+        std::fs::metadata(&self.proof).context("failed to open the proof")?;
+        let proof =
+            SP1ProofWithPublicValues::load(&self.proof).context("failed to load the proof")?;
+
+        if self.no_interactive || !io::stdin().is_terminal() {
+            print_summary(&proof);
+            println!();
+            print_shards(&proof);
+            println!();
+            print_public_values(&proof, "hex")?;
+            return Ok(());
+        }
+
+        print_summary(&proof);
+        println!("\n{HELP}");
+        let stdin = io::stdin();
+        loop {
+            print!("{} ", Paint::green("inspect>").bold());
+            io::stdout().flush()?;
+            let mut line = String::new();
+            if stdin.lock().read_line(&mut line)? == 0 {
+                break;
+            }
+            let words = line.split_whitespace().collect::<Vec<_>>();
+            let result = match words.as_slice() {
+                [] => Ok(()),
+                ["summary"] => {
+                    print_summary(&proof);
+                    Ok(())
+                }
+                ["shards"] => {
+                    print_shards(&proof);
+                    Ok(())
+                }
+                ["shard", index] => index
+                    .parse::<usize>()
+                    .context("the shard index must be a number")
+                    .and_then(|index| print_shard(&proof, index)),
+                ["pv"] => print_public_values(&proof, "hex"),
+                ["pv", view] => print_public_values(&proof, view),
+                ["help"] => {
+                    println!("{HELP}");
+                    Ok(())
+                }
+                ["quit" | "exit" | "q"] => break,
+                _ => Err(anyhow::anyhow!("unknown command, type `help` for the commands")),
+            };
+            if let Err(err) = result {
+                println!("{} {err}", Paint::red("error:"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The number of shard proofs of the proof, zero for the proofs wrapped in a SNARK.
+fn shard_count(proof: &SP1ProofWithPublicValues) -> usize {
+    match &proof.proof {
+        SP1Proof::Core(shards) => shards.len(),
+        SP1Proof::Compressed(_) | SP1Proof::Tiny(_) => 1,
+        SP1Proof::Plonk(_) | SP1Proof::Groth16(_) => 0,
+    }
+}
+
+fn print_summary(proof: &SP1ProofWithPublicValues) {
+    let kind = match &proof.proof {
+        SP1Proof::Core(_) => "core",
+        SP1Proof::Compressed(_) => "compressed",
+        SP1Proof::Plonk(_) => "plonk",
+        SP1Proof::Groth16(_) => "groth16",
+        SP1Proof::Tiny(_) => "tiny",
+    };
+    let buffers = &proof.stdin.buffer;
+    let public_values = proof.public_values.as_slice();
+
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_LINESEP);
+    let mut row = |key: &str, value: String| {
+        table.add_row(Row::new(vec![Cell::new(key), Cell::new(&value)]));
+    };
+    row("kind", kind.to_string());
+    row("sp1 version", proof.sp1_version.clone());
+    row("shards", shard_count(proof).to_string());
+    row("public values", format!("{} bytes", public_values.len()));
+    row("public values hash", hex::encode(proof.public_values.hash()));
+    row(
+        "stdin",
+        format!("{} buffers, {} bytes", buffers.len(), buffers.iter().map(Vec::len).sum::<usize>()),
+    );
+    row("stdin proofs", proof.stdin.proofs.len().to_string());
+    // Only meaningful for the programs committing to the digest of their inputs.
+    if let Some(digest) = proof.public_values.input_digest() {
+        let matches = digest == proof.stdin.input_digest();
+        row("input digest committed", if matches { "yes" } else { "no" }.to_string());
+    }
+    match &proof.proof {
+        SP1Proof::Plonk(plonk) => {
+            row("vkey hash", plonk.public_inputs[0].clone());
+            row("committed values digest", plonk.public_inputs[1].clone());
+            row("plonk vkey hash", hex::encode(plonk.plonk_vkey_hash));
+            row("encoded proof", format!("{} bytes", plonk.encoded_proof.len() / 2));
+        }
+        SP1Proof::Groth16(groth16) => {
+            row("vkey hash", groth16.public_inputs[0].clone());
+            row("committed values digest", groth16.public_inputs[1].clone());
+            row("groth16 vkey hash", hex::encode(groth16.groth16_vkey_hash));
+            row("encoded proof", format!("{} bytes", groth16.encoded_proof.len() / 2));
+        }
+        SP1Proof::Core(_) | SP1Proof::Compressed(_) | SP1Proof::Tiny(_) => {}
+    }
+    table.printstd();
+}
+
+fn print_shards(proof: &SP1ProofWithPublicValues) {
+    match &proof.proof {
+        SP1Proof::Core(shards) => print_core_shards(shards),
+        SP1Proof::Tiny(shard) => print_core_shards(std::slice::from_ref(shard)),
+        SP1Proof::Compressed(shard) => {
+            println!("compressed proof of {} chips", shard.chip_ordering.len());
+        }
+        SP1Proof::Plonk(_) | SP1Proof::Groth16(_) => {
+            println!("the proof is wrapped in a snark and has no shards");
+        }
+    }
+}
+
+fn print_shard(proof: &SP1ProofWithPublicValues, index: usize) -> Result<()> {
+    match &proof.proof {
+        SP1Proof::Core(shards) => {
+            let Some(shard) = shards.get(index) else {
+                bail!("shard {index} out of range, the proof has {} shards", shards.len());
+            };
+            print_core_shard(shard);
+        }
+        SP1Proof::Tiny(shard) if index == 0 => print_core_shard(shard),
+        SP1Proof::Tiny(_) => bail!("a tiny proof only has shard 0"),
+        SP1Proof::Compressed(shard) if index == 0 => print_chip_heights(shard),
+        SP1Proof::Compressed(_) => bail!("a compressed proof only has shard 0"),
+        SP1Proof::Plonk(_) | SP1Proof::Groth16(_) => {
+            bail!("the proof is wrapped in a snark and has no shards")
+        }
+    }
+    Ok(())
+}
+
+fn print_core_shards(shards: &[ShardProof<CoreSC>]) {
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_LINESEP);
+    table.set_titles(Row::new(
+        ["index", "shard", "execution shard", "start pc", "next pc", "chips", "max log height"]
+            .into_iter()
+            .map(Cell::new)
+            .collect(),
+    ));
+    for (index, shard) in shards.iter().enumerate() {
+        let public_values: &PublicValues<Word<_>, _> = shard.public_values.as_slice().borrow();
+        let max_log_height =
+            shard.opened_values.chips.iter().map(|chip| chip.log_degree).max().unwrap_or_default();
+        table.add_row(Row::new(vec![
+            Cell::new(&index.to_string()),
+            Cell::new(&public_values.shard.to_string()),
+            Cell::new(&public_values.execution_shard.to_string()),
+            Cell::new(&format!("0x{:08x}", public_values.start_pc.as_canonical_u32())),
+            Cell::new(&format!("0x{:08x}", public_values.next_pc.as_canonical_u32())),
+            Cell::new(&shard.chip_ordering.len().to_string()),
+            Cell::new(&max_log_height.to_string()),
+        ]));
+    }
+    table.printstd();
+}
+
+fn print_core_shard(shard: &ShardProof<CoreSC>) {
+    print_chip_heights(shard);
+    let public_values: &PublicValues<Word<_>, _> = shard.public_values.as_slice().borrow();
+    println!("exit code: {}", public_values.exit_code);
+    println!("committed value digest: {}", hex::encode(public_values.commit_digest_bytes()));
+}
+
+/// Prints the log heights of the chips of the shard, highest first.
+fn print_chip_heights<SC: StarkGenericConfig>(shard: &ShardProof<SC>) {
+    let mut chips = shard
+        .chip_ordering
+        .iter()
+        .map(|(name, &i)| (name.as_str(), shard.opened_values.chips[i].log_degree))
+        .collect::<Vec<_>>();
+    chips.sort_by(|(a_name, a), (b_name, b)| b.cmp(a).then(a_name.cmp(b_name)));
+
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_LINESEP);
+    table.set_titles(Row::new(vec![Cell::new("chip"), Cell::new("log height")]));
+    for (name, log_degree) in chips {
+        table.add_row(Row::new(vec![Cell::new(name), Cell::new(&log_degree.to_string())]));
+    }
+    table.printstd();
+}
+
+fn print_public_values(proof: &SP1ProofWithPublicValues, view: &str) -> Result<()> {
+    let bytes = proof.public_values.as_slice();
+    match view {
+        "hex" => {
+            for (i, line) in bytes.chunks(16).enumerate() {
+                println!("{:08x}  {}", 16 * i, hex::encode(line));
+            }
+        }
+        "u32" => {
+            for (i, word) in bytes.chunks(4).enumerate() {
+                let mut le = [0u8; 4];
+                le[..word.len()].copy_from_slice(word);
+                println!("{:08x}  {}", 4 * i, u32::from_le_bytes(le));
+            }
+        }
+        "u64" => {
+            for (i, word) in bytes.chunks(8).enumerate() {
+                let mut le = [0u8; 8];
+                le[..word.len()].copy_from_slice(word);
+                println!("{:08x}  {}", 8 * i, u64::from_le_bytes(le));
+            }
+        }
+        "utf8" => println!("{}", String::from_utf8_lossy(bytes)),
+        _ => bail!("unknown view `{view}`, expected hex, u32, u64 or utf8"),
+    }
+    Ok(())
+}
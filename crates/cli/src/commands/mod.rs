@@ -1,7 +1,12 @@
+pub mod bench;
 pub mod build;
 pub mod build_toolchain;
+pub mod constraints;
+pub mod disasm;
+pub mod inspect;
 pub mod install_toolchain;
 pub mod new;
 pub mod prove;
+pub mod shapes;
 pub mod trace;
 pub mod vkey;
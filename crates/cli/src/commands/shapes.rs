@@ -0,0 +1,111 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use prettytable::{format, Cell, Row, Table};
+use sp1_core_executor::{Program, SP1Context};
+use sp1_core_machine::{
+    io::SP1Stdin,
+    utils::{shapes::ShapeStats, shard_heights},
+};
+use sp1_stark::SP1CoreOpts;
+
+#[derive(Parser)]
+#[command(
+    name = "shapes",
+    about = "Collect the chip heights of the shards of a corpus of executions and recommend the \
+             heights to allow for each chip."
+)]
+pub struct ShapesCmd {
+    /// Path to the ELF.
+    #[arg(long, required = true)]
+    elf: PathBuf,
+
+    /// Directory of recorded inputs, each a bincode-serialized `SP1Stdin`, optionally compressed
+    /// with zstd.
+    #[arg(long, required = true)]
+    stdins: PathBuf,
+
+    /// The fraction of the shards that the recommended heights should cover.
+    #[arg(long, default_value_t = 0.999)]
+    coverage: f64,
+
+    /// Write the statistics and the recommended log heights of each chip to this JSON file.
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+impl ShapesCmd {
+    pub fn run(&self) -> Result<()> {
+        if !(0.0..=1.0).contains(&self.coverage) {
+            bail!("the coverage must be between 0 and 1");
+        }
+        let elf = fs::read(&self.elf).context("failed to read the ELF")?;
+        let program = Program::from(&elf).context("failed to parse the ELF")?;
+
+        let mut paths = fs::read_dir(&self.stdins)
+            .context("failed to read the directory of inputs")?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()?;
+        paths.retain(|path| path.is_file());
+        paths.sort();
+        if paths.is_empty() {
+            bail!("no inputs found in {}", self.stdins.display());
+        }
+
+        let opts = SP1CoreOpts::default();
+        let mut stats = ShapeStats::default();
+        let mut shards = Vec::new();
+        for path in &paths {
+            let bytes = fs::read(path)?;
+            let stdin = SP1Stdin::from_bytes(&bytes)
+                .with_context(|| format!("failed to deserialize {}", path.display()))?;
+            let heights = shard_heights(program.clone(), &stdin, opts, SP1Context::default())
+                .with_context(|| format!("failed to execute {}", path.display()))?;
+            println!("{}: {} shards", path.display(), heights.len());
+            heights.iter().for_each(|shard| stats.record(shard));
+            shards.extend(heights);
+        }
+
+        let allowed = stats.recommend(self.coverage);
+        let mut table = Table::new();
+        table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+        table.set_titles(Row::new(vec![
+            Cell::new("Chip"),
+            Cell::new("Shards"),
+            Cell::new("Log heights (count)"),
+            Cell::new("Allowed log heights"),
+        ]));
+        for (name, counts) in &stats.log_heights {
+            let observed = counts
+                .iter()
+                .map(|(log_height, count)| format!("{log_height} ({count})"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let recommended =
+                allowed[name].iter().map(ToString::to_string).collect::<Vec<_>>().join(", ");
+            table.add_row(Row::new(vec![
+                Cell::new(name),
+                Cell::new(&counts.values().sum::<usize>().to_string()),
+                Cell::new(&observed),
+                Cell::new(&recommended),
+            ]));
+        }
+        println!();
+        table.printstd();
+        println!(
+            "\n{} inputs, {} shards, {:.2}% of the shards fit in the allowed heights",
+            paths.len(),
+            stats.num_shards,
+            100.0 * ShapeStats::coverage(&shards, &allowed)
+        );
+
+        if let Some(output) = &self.output {
+            let json = serde_json::json!({ "stats": stats, "allowed_log_heights": allowed });
+            fs::write(output, serde_json::to_string_pretty(&json)?)
+                .context("failed to write the output")?;
+        }
+
+        Ok(())
+    }
+}
@@ -22,8 +22,7 @@ impl VkeyCmd {
         file.read_to_end(&mut elf).unwrap();
 
         // Get the verification key
-        let prover = ProverClient::new();
-        let (_, vk) = prover.setup(&elf);
+        let vk = ProverClient::vkey_only(&elf);
 
         // Print the verification key hash
         println!("Verification Key Hash:\n{}", vk.vk.bytes32());
@@ -0,0 +1,195 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{syscalls::SyscallCode, Executor, Register};
+
+/// The default maximum number of bytes of memory dumped for each buffer referenced by a syscall.
+pub const DEFAULT_AUDIT_DUMP_BYTES: usize = 64;
+
+/// Where to write a syscall audit, and how much memory to dump for each syscall.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyscallAuditOpts {
+    /// The path of the audit log.
+    pub path: PathBuf,
+    /// The maximum number of bytes dumped for each buffer referenced by a syscall.
+    pub max_dump_bytes: usize,
+}
+
+impl SyscallAuditOpts {
+    /// Write the audit log to `path`, dumping [`DEFAULT_AUDIT_DUMP_BYTES`] of each buffer.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into(), max_dump_bytes: DEFAULT_AUDIT_DUMP_BYTES }
+    }
+}
+
+/// A syscall made by the program, as recorded in a syscall audit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyscallAuditEntry {
+    /// The shard in which the syscall was made.
+    pub shard: u32,
+    /// The clock of the shard at which the syscall was made.
+    pub clk: u32,
+    /// The program counter of the `ecall` instruction.
+    pub pc: u32,
+    /// The name of the syscall.
+    pub syscall: String,
+    /// The id of the syscall, read from `t0`.
+    pub syscall_id: u32,
+    /// The first argument, read from `a0`.
+    pub arg1: u32,
+    /// The second argument, read from `a1`.
+    pub arg2: u32,
+    /// The value written back to `t0`.
+    pub result: u32,
+    /// Whether the syscall was made in an unconstrained block.
+    pub unconstrained: bool,
+    /// The buffers referenced by the arguments, before the syscall.
+    pub memory_before: Vec<MemoryDump>,
+    /// The buffers referenced by the arguments, after the syscall.
+    pub memory_after: Vec<MemoryDump>,
+}
+
+/// A bounded dump of a buffer in memory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryDump {
+    /// The address of the buffer.
+    pub addr: u32,
+    /// The length of the buffer, if known.
+    pub len: Option<u32>,
+    /// The first bytes of the buffer, hex-encoded.
+    pub bytes: String,
+}
+
+/// A log of every syscall made by the program, with its arguments and a bounded dump of the
+/// memory they reference, written as JSON lines.
+///
+/// The log is an auditable record of the interactions of the program with the host, such as the
+/// hints it read and the hooks it invoked.
+pub struct SyscallAudit {
+    writer: BufWriter<File>,
+    max_dump_bytes: usize,
+}
+
+impl SyscallAudit {
+    /// Create the audit log at `path`, dumping at most `max_dump_bytes` of each buffer.
+    pub fn create(path: impl AsRef<Path>, max_dump_bytes: usize) -> std::io::Result<Self> {
+        Ok(Self { writer: BufWriter::new(File::create(path)?), max_dump_bytes })
+    }
+
+    /// Create the audit log described by `opts`.
+    pub fn from_opts(opts: &SyscallAuditOpts) -> std::io::Result<Self> {
+        Self::create(&opts.path, opts.max_dump_bytes)
+    }
+
+    /// Dump the buffers referenced by the arguments of a syscall.
+    pub(crate) fn dump(
+        &self,
+        rt: &Executor,
+        syscall: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Vec<MemoryDump> {
+        referenced_buffers(rt, syscall, arg1, arg2)
+            .into_iter()
+            .map(|(addr, len)| {
+                let bytes =
+                    (0..len.map_or(usize::MAX, |len| len as usize).min(self.max_dump_bytes))
+                        .map(|i| peek_byte(rt, addr.wrapping_add(i as u32)))
+                        .collect::<Vec<_>>();
+                MemoryDump { addr, len, bytes: hex::encode(bytes) }
+            })
+            .collect()
+    }
+
+    /// Append an entry to the log.
+    pub(crate) fn record(&mut self, entry: &SyscallAuditEntry) {
+        serde_json::to_writer(&mut self.writer, entry).unwrap();
+        self.writer.write_all(b"\n").unwrap();
+    }
+
+    /// Flush the log to its file.
+    pub(crate) fn flush(&mut self) {
+        self.writer.flush().unwrap();
+    }
+}
+
+/// The buffers referenced by the arguments of a syscall, as addresses and lengths.
+///
+/// The lengths of the buffers of precompiles are not known here, so any argument that is not a
+/// register address is assumed to point to a buffer of unknown length.
+fn referenced_buffers(
+    rt: &Executor,
+    syscall: SyscallCode,
+    arg1: u32,
+    arg2: u32,
+) -> Vec<(u32, Option<u32>)> {
+    match syscall {
+        SyscallCode::WRITE => vec![(arg2, Some(peek_word(rt, Register::X12 as u32)))],
+        SyscallCode::HINT_READ => vec![(arg1, Some(arg2))],
+        SyscallCode::HALT
+        | SyscallCode::ENTER_UNCONSTRAINED
+        | SyscallCode::EXIT_UNCONSTRAINED
+        | SyscallCode::HINT_LEN
+        | SyscallCode::COMMIT
+        | SyscallCode::COMMIT_DEFERRED_PROOFS => vec![],
+        _ => [arg1, arg2].into_iter().filter(|&arg| arg >= 32).map(|arg| (arg, None)).collect(),
+    }
+}
+
+/// Read a word of memory, or a register, without recording an access.
+fn peek_word(rt: &Executor, addr: u32) -> u32 {
+    rt.state.memory.get(addr).map_or(0, |record| record.value)
+}
+
+/// Read a byte of memory without recording an access.
+fn peek_byte(rt: &Executor, addr: u32) -> u8 {
+    (peek_word(rt, addr - addr % 4) >> ((addr % 4) * 8)) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Instruction, Opcode, Program};
+
+    #[test]
+    fn audit_records_syscalls() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.jsonl");
+
+        // Write 4 bytes at 0x100 to stdout.
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 29, 0, 0x6f6c6c65, false, true),
+            Instruction::new(Opcode::ADD, 30, 0, 0x100, false, true),
+            Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::WRITE as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 1, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 0x100, false, true),
+            Instruction::new(Opcode::ADD, 12, 0, 4, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut runtime = Executor::new(program, Default::default());
+        runtime.syscall_audit = Some(SyscallAudit::create(&path, 2).unwrap());
+        runtime.run().unwrap();
+
+        let log = std::fs::read_to_string(&path).unwrap();
+        let entries = log
+            .lines()
+            .map(|line| serde_json::from_str::<SyscallAuditEntry>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert_eq!(entry.syscall, "WRITE");
+        assert_eq!((entry.arg1, entry.arg2), (1, 0x100));
+        assert_eq!(entry.pc, 28);
+        assert_eq!(
+            entry.memory_before,
+            vec![MemoryDump { addr: 0x100, len: Some(4), bytes: "656c".to_string() }]
+        );
+    }
+}
@@ -1,10 +1,20 @@
 use core::mem::take;
-use std::sync::Arc;
+use std::{
+    num::NonZeroU64,
+    sync::{atomic::AtomicBool, mpsc::Sender, Arc},
+};
 
 use hashbrown::HashMap;
+use sp1_stark::{baby_bear_poseidon2::BabyBearPoseidon2, ShardProof};
 
 use crate::{
-    hook::{hookify, BoxedHook, HookEnv, HookRegistry},
+    audit::SyscallAuditOpts,
+    hook::{async_hookify, hookify, BoxedAsyncHook, BoxedHook, HookEnv, HookRegistry},
+    memo::{MemoTable, FD_MEMO_LOOKUP, FD_MEMO_STORE},
+    profiler::ProfilerOpts,
+    progress::{
+        ExecutionProgress, ProgressCallback, ProgressControl, ShardProgress, ShardProgressCallback,
+    },
     subproof::SubproofVerifier,
 };
 
@@ -21,6 +31,38 @@ pub struct SP1Context<'a> {
 
     /// The maximum number of cpu cycles to use for execution.
     pub max_cycles: Option<u64>,
+
+    /// The channel to send each core shard proof to, in order, as soon as it is generated.
+    pub shard_proof_sender: Option<Sender<ShardProof<BabyBearPoseidon2>>>,
+
+    /// The flag that cancels the execution when set.
+    pub cancel_flag: Option<Arc<AtomicBool>>,
+
+    /// Where to record every syscall made by the program, if the syscalls are audited.
+    pub syscall_audit: Option<SyscallAuditOpts>,
+
+    /// Where to write a profile of the program, if it is profiled.
+    pub profiler: Option<ProfilerOpts>,
+
+    /// The callback invoked periodically with a summary of the state of the execution.
+    pub progress_callback: Option<ProgressCallback<'a>>,
+
+    /// The callback invoked as the core shards of the execution are proven.
+    pub shard_progress_callback: Option<ShardProgressCallback>,
+
+    /// Whether the program may halt with a non-zero exit code.
+    pub allow_failure: bool,
+
+    /// The maximum number of bytes the program may commit to its public values.
+    pub max_public_values_size: Option<usize>,
+
+    /// The maximum number of bytes of input, including the hints written during the execution.
+    pub max_input_size: Option<usize>,
+
+    /// Whether to compile the hot blocks of the program to native code, when it is only executed.
+    ///
+    /// Ignored unless the executor is built with the `jit` feature.
+    pub jit: bool,
 }
 
 /// A builder for [`SP1Context`].
@@ -28,8 +70,19 @@ pub struct SP1Context<'a> {
 pub struct SP1ContextBuilder<'a> {
     no_default_hooks: bool,
     hook_registry_entries: Vec<(u32, BoxedHook<'a>)>,
+    async_hook_registry_entries: Vec<(u32, BoxedAsyncHook)>,
     subproof_verifier: Option<Arc<dyn SubproofVerifier + 'a>>,
     max_cycles: Option<u64>,
+    shard_proof_sender: Option<Sender<ShardProof<BabyBearPoseidon2>>>,
+    cancel_flag: Option<Arc<AtomicBool>>,
+    syscall_audit: Option<SyscallAuditOpts>,
+    profiler: Option<ProfilerOpts>,
+    progress_callback: Option<ProgressCallback<'a>>,
+    shard_progress_callback: Option<ShardProgressCallback>,
+    allow_failure: bool,
+    max_public_values_size: Option<usize>,
+    max_input_size: Option<usize>,
+    jit: bool,
 }
 
 impl<'a> SP1Context<'a> {
@@ -55,20 +108,96 @@ impl<'a> SP1ContextBuilder<'a> {
     pub fn build(&mut self) -> SP1Context<'a> {
         // If hook_registry_entries is nonempty or no_default_hooks true,
         // indicating a non-default value of hook_registry.
-        let hook_registry =
-            (!self.hook_registry_entries.is_empty() || self.no_default_hooks).then(|| {
-                let mut table = if take(&mut self.no_default_hooks) {
-                    HashMap::default()
+        let hook_registry = (!self.hook_registry_entries.is_empty()
+            || !self.async_hook_registry_entries.is_empty()
+            || self.no_default_hooks)
+            .then(|| {
+                let mut registry = if take(&mut self.no_default_hooks) {
+                    HookRegistry::empty()
                 } else {
-                    HookRegistry::default().table
+                    HookRegistry::default()
                 };
                 // Allows overwriting default hooks.
-                table.extend(take(&mut self.hook_registry_entries));
-                HookRegistry { table }
+                registry.table.extend(take(&mut self.hook_registry_entries));
+                registry.async_table.extend(take(&mut self.async_hook_registry_entries));
+                registry
             });
         let subproof_verifier = take(&mut self.subproof_verifier);
         let cycle_limit = take(&mut self.max_cycles);
-        SP1Context { hook_registry, subproof_verifier, max_cycles: cycle_limit }
+        let shard_proof_sender = take(&mut self.shard_proof_sender);
+        let cancel_flag = take(&mut self.cancel_flag);
+        let syscall_audit = take(&mut self.syscall_audit);
+        let profiler = take(&mut self.profiler);
+        let progress_callback = take(&mut self.progress_callback);
+        let shard_progress_callback = take(&mut self.shard_progress_callback);
+        let allow_failure = take(&mut self.allow_failure);
+        let max_public_values_size = take(&mut self.max_public_values_size);
+        let max_input_size = take(&mut self.max_input_size);
+        let jit = take(&mut self.jit);
+        SP1Context {
+            hook_registry,
+            subproof_verifier,
+            max_cycles: cycle_limit,
+            shard_proof_sender,
+            cancel_flag,
+            syscall_audit,
+            profiler,
+            progress_callback,
+            shard_progress_callback,
+            allow_failure,
+            max_public_values_size,
+            max_input_size,
+            jit,
+        }
+    }
+
+    /// Converts the builder to one that doesn't borrow from the caller, so that the context can be
+    /// built on another thread.
+    ///
+    /// Returns `None` if hooks, a subproof verifier or a progress callback were added, since they
+    /// may borrow from the caller. Asynchronous hooks are kept.
+    #[must_use]
+    pub fn into_owned(self) -> Option<SP1ContextBuilder<'static>> {
+        let Self {
+            no_default_hooks,
+            hook_registry_entries,
+            async_hook_registry_entries,
+            subproof_verifier,
+            max_cycles,
+            shard_proof_sender,
+            cancel_flag,
+            syscall_audit,
+            profiler,
+            progress_callback,
+            shard_progress_callback,
+            allow_failure,
+            max_public_values_size,
+            max_input_size,
+            jit,
+        } = self;
+        if !hook_registry_entries.is_empty()
+            || subproof_verifier.is_some()
+            || progress_callback.is_some()
+        {
+            return None;
+        }
+        Some(SP1ContextBuilder {
+            no_default_hooks,
+            hook_registry_entries: Vec::new(),
+            async_hook_registry_entries,
+            subproof_verifier: None,
+            max_cycles,
+            shard_proof_sender,
+            cancel_flag,
+            syscall_audit,
+            profiler,
+            progress_callback: None,
+            shard_progress_callback,
+            allow_failure,
+            max_public_values_size,
+            max_input_size,
+            jit,
+        })
     }
 
     /// Add a runtime [Hook](super::Hook) into the context.
@@ -85,6 +214,33 @@ impl<'a> SP1ContextBuilder<'a> {
         self
     }
 
+    /// Add an asynchronous hook into the context.
+    ///
+    /// The program requests a hint from the hook with `sp1_zkvm::io::hint_request(fd, key)`, which
+    /// runs `f(key)` on a separate thread while the program keeps executing, and later reads the
+    /// returned bytes with `sp1_zkvm::io::hint_await`. This overlaps slow host work, like RPC
+    /// calls, with the execution of the program.
+    pub fn async_hook(
+        &mut self,
+        fd: u32,
+        f: impl Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.async_hook_registry_entries.push((fd, async_hookify(f)));
+        self
+    }
+
+    /// Serve the memo lookups and stores of the program from `table`.
+    ///
+    /// Lookups only see the values committed to the table, and stores are kept pending until
+    /// [`MemoTable::commit`] is called, so that every execution of the program reads the same
+    /// values.
+    pub fn memo_table(&mut self, table: &MemoTable) -> &mut Self {
+        let lookup = table.clone();
+        let store = table.clone();
+        self.hook(FD_MEMO_LOOKUP, move |env, buf| lookup.lookup_hook(env, buf));
+        self.hook(FD_MEMO_STORE, move |env, buf| store.store_hook(env, buf))
+    }
+
     /// Avoid registering the default hooks in the runtime.
     ///
     /// It is not necessary to call this to override hooks --- instead, simply
@@ -110,6 +266,109 @@ impl<'a> SP1ContextBuilder<'a> {
         self.max_cycles = Some(max_cycles);
         self
     }
+
+    /// Send each core shard proof to `sender` as soon as it is generated.
+    ///
+    /// The proofs are sent in shard order, which lets the caller upload or aggregate them while
+    /// the rest of the execution is still being proven. Proofs are only streamed when proving
+    /// locally; if the receiver is dropped, proving continues without streaming.
+    pub fn shard_proof_sender(
+        &mut self,
+        sender: Sender<ShardProof<BabyBearPoseidon2>>,
+    ) -> &mut Self {
+        self.shard_proof_sender = Some(sender);
+        self
+    }
+
+    /// Cancel the execution once `flag` is set.
+    ///
+    /// The flag is checked at the start of each shard, after which the execution fails with
+    /// [`ExecutionError::Cancelled`](crate::ExecutionError::Cancelled).
+    pub fn cancel_flag(&mut self, flag: Arc<AtomicBool>) -> &mut Self {
+        self.cancel_flag = Some(flag);
+        self
+    }
+
+    /// Record every syscall made by the program, with its arguments and a bounded dump of the
+    /// memory they reference, to the log described by `opts`.
+    ///
+    /// The log is written by the execution the context is passed to; when proving, this is the
+    /// first execution, and the re-executions that generate the traces are not audited.
+    pub fn syscall_audit(&mut self, opts: SyscallAuditOpts) -> &mut Self {
+        self.syscall_audit = Some(opts);
+        self
+    }
+
+    /// Sample the call stack of the program while it executes, and write the samples to the
+    /// folded stacks profile described by `opts`, to be rendered as a flamegraph.
+    ///
+    /// As for the syscall audit, only the execution the context is passed to is profiled.
+    pub fn profiler(&mut self, opts: ProfilerOpts) -> &mut Self {
+        self.profiler = Some(opts);
+        self
+    }
+
+    /// Invoke `f` every `interval` cycles with a summary of the state of the execution.
+    ///
+    /// Returning [`ProgressControl::Abort`] stops the execution, which then fails with
+    /// [`ExecutionError::Aborted`](crate::ExecutionError::Aborted). This is meant for watchdogs
+    /// and progress reporting; when proving, only the first execution invokes the callback.
+    pub fn progress_callback(
+        &mut self,
+        interval: NonZeroU64,
+        f: impl Fn(ExecutionProgress) -> ProgressControl + Send + Sync + 'a,
+    ) -> &mut Self {
+        self.progress_callback = Some(ProgressCallback { interval, callback: Arc::new(f) });
+        self
+    }
+
+    /// Invoke `f` with the number of core shards proven so far and the number of shards of the
+    /// execution, once all the shards are committed to and after each batch of shards is proven.
+    ///
+    /// Only the local CPU prover invokes the callback.
+    pub fn shard_progress_callback(
+        &mut self,
+        f: impl Fn(ShardProgress) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.shard_progress_callback = Some(Arc::new(f));
+        self
+    }
+
+    /// Let the program halt with a non-zero exit code, such as when it panics.
+    ///
+    /// The execution then ends normally instead of failing with
+    /// [`ExecutionError::HaltWithNonZeroExitCode`](crate::ExecutionError::HaltWithNonZeroExitCode),
+    /// and the exit code is recorded in the public values of the shards from the one that halts
+    /// onwards, so that the failure itself can be proven.
+    pub fn allow_failure(&mut self) -> &mut Self {
+        self.allow_failure = true;
+        self
+    }
+
+    /// Fail the execution with
+    /// [`ExecutionError::PublicValuesTooLarge`](crate::ExecutionError::PublicValuesTooLarge) as
+    /// soon as the program commits more than `max` bytes of public values.
+    pub fn max_public_values_size(&mut self, max: usize) -> &mut Self {
+        self.max_public_values_size = Some(max);
+        self
+    }
+
+    /// Fail the execution with
+    /// [`ExecutionError::InputTooLarge`](crate::ExecutionError::InputTooLarge) if the stdin, with
+    /// the hints and hook results added during the execution, grows larger than `max` bytes.
+    pub fn max_input_size(&mut self, max: usize) -> &mut Self {
+        self.max_input_size = Some(max);
+        self
+    }
+
+    /// Compile the hot blocks of the program to native code with cranelift, when it is only
+    /// executed with [`Executor::run_fast`](crate::Executor::run_fast).
+    ///
+    /// Ignored unless the executor is built with the `jit` feature.
+    pub fn jit(&mut self) -> &mut Self {
+        self.jit = true;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -120,11 +379,34 @@ mod tests {
 
     #[test]
     fn defaults() {
-        let SP1Context { hook_registry, subproof_verifier, max_cycles: cycle_limit } =
-            SP1Context::builder().build();
+        let SP1Context {
+            hook_registry,
+            subproof_verifier,
+            max_cycles: cycle_limit,
+            shard_proof_sender,
+            cancel_flag,
+            syscall_audit,
+            profiler,
+            progress_callback,
+            shard_progress_callback,
+            allow_failure,
+            max_public_values_size,
+            max_input_size,
+            jit,
+        } = SP1Context::builder().build();
         assert!(hook_registry.is_none());
         assert!(subproof_verifier.is_none());
         assert!(cycle_limit.is_none());
+        assert!(shard_proof_sender.is_none());
+        assert!(cancel_flag.is_none());
+        assert!(syscall_audit.is_none());
+        assert!(profiler.is_none());
+        assert!(progress_callback.is_none());
+        assert!(shard_progress_callback.is_none());
+        assert!(!allow_failure);
+        assert!(max_public_values_size.is_none());
+        assert!(max_input_size.is_none());
+        assert!(!jit);
     }
 
     #[test]
@@ -148,6 +430,24 @@ mod tests {
         assert_eq!(&hook_registry.unwrap().table.into_keys().collect::<Vec<_>>(), &[30]);
     }
 
+    #[test]
+    fn with_async_hook() {
+        let SP1Context { hook_registry, .. } =
+            SP1Context::builder().async_hook(30, |key| key.to_vec()).build();
+        let hook_registry = hook_registry.unwrap();
+        assert!(hook_registry.async_table.contains_key(&30));
+        assert!(hook_registry.table.contains_key(&crate::hook::FD_ECRECOVER_HOOK));
+    }
+
+    #[test]
+    fn with_memo_table() {
+        let SP1Context { hook_registry, .. } =
+            SP1Context::builder().memo_table(&crate::MemoTable::new()).build();
+        let hook_registry = hook_registry.unwrap();
+        assert!(hook_registry.table.contains_key(&crate::FD_MEMO_LOOKUP));
+        assert!(hook_registry.table.contains_key(&crate::FD_MEMO_STORE));
+    }
+
     #[test]
     fn subproof_verifier() {
         let SP1Context { subproof_verifier, .. } = SP1Context::builder()
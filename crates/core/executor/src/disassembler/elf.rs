@@ -1,12 +1,61 @@
-use std::{cmp::min, collections::BTreeMap};
+use std::{cmp::min, collections::BTreeMap, fmt};
 
 use elf::{
-    abi::{EM_RISCV, ET_EXEC, PF_X, PT_LOAD},
+    abi::{EM_RISCV, ET_EXEC, PF_R, PF_W, PF_X, PT_LOAD},
     endian::LittleEndian,
     file::Class,
     ElfBytes,
 };
+use serde::{Deserialize, Serialize};
 use sp1_primitives::consts::{MAXIMUM_MEMORY_SIZE, WORD_SIZE};
+use thiserror::Error;
+
+/// The bits of the segment flags reserved for operating system and processor specific semantics.
+///
+/// Toolchains other than `rustc` sometimes set them, they don't affect how a segment is loaded.
+const PF_MASK_OS_AND_PROC: u32 = 0xfff0_0000;
+
+/// The maximum number of program headers of an ELF.
+const MAX_SEGMENTS: usize = 256;
+
+/// Limits on the resources spent loading an ELF.
+///
+/// The ELF is checked against the limits before the memory it asks for is allocated, so that
+/// adversarial ELFs, such as ones with a huge BSS segment, fail early. The [Default] limits only
+/// reject ELFs that can't run in the zkVM, while [ElfLimits::UNTRUSTED] also bounds the work of
+/// services loading programs uploaded by their users.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ElfLimits {
+    /// The maximum size of the ELF file in bytes.
+    pub max_file_size: usize,
+    /// The maximum number of program headers.
+    pub max_segments: usize,
+    /// The maximum number of bytes loaded in memory by the segments, including zero-filled ones.
+    pub max_memory_size: u64,
+    /// The maximum number of instructions.
+    pub max_instructions: usize,
+}
+
+impl ElfLimits {
+    /// Limits for ELFs from untrusted sources, well above the needs of real programs.
+    pub const UNTRUSTED: Self = Self {
+        max_file_size: 64 << 20,
+        max_segments: 32,
+        max_memory_size: 128 << 20,
+        max_instructions: 1 << 22,
+    };
+}
+
+impl Default for ElfLimits {
+    fn default() -> Self {
+        Self {
+            max_file_size: usize::MAX,
+            max_segments: MAX_SEGMENTS,
+            max_memory_size: u64::from(MAXIMUM_MEMORY_SIZE),
+            max_instructions: usize::MAX,
+        }
+    }
+}
 
 /// RISC-V 32IM ELF (Executable and Linkable Format) File.
 ///
@@ -29,6 +78,162 @@ pub(crate) struct Elf {
     pub(crate) memory_image: BTreeMap<u32, u32>,
 }
 
+/// A program header of an ELF, named by the section it starts with when the ELF has section
+/// headers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ElfSegment {
+    /// The index of the program header.
+    pub index: usize,
+    /// The name of the section containing the start of the segment.
+    pub section: Option<String>,
+}
+
+impl fmt::Display for ElfSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.section {
+            Some(section) => write!(f, "segment {} ({section})", self.index),
+            None => write!(f, "segment {}", self.index),
+        }
+    }
+}
+
+/// Errors that can occur when loading an ELF.
+#[derive(Error, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ElfError {
+    /// The ELF could not be parsed.
+    #[error("failed to parse the elf: {0}")]
+    Parse(String),
+
+    /// The ELF is not a 32-bit ELF.
+    #[error("must be a 32-bit elf")]
+    UnsupportedClass,
+
+    /// The ELF targets another machine than RISC-V.
+    #[error("must be a riscv machine, got machine {0}")]
+    UnsupportedMachine(u16),
+
+    /// The ELF is not an executable.
+    #[error("must be executable, got type {0}")]
+    UnsupportedType(u16),
+
+    /// The entrypoint is outside of the memory of the zkVM.
+    #[error("entrypoint 0x{0:x} exceeds the maximum address for guest programs")]
+    EntrypointOutOfRange(u64),
+
+    /// The entrypoint is not aligned to a word.
+    #[error("entrypoint 0x{0:08x} is not aligned to a word")]
+    MisalignedEntrypoint(u32),
+
+    /// The ELF has no program headers.
+    #[error("the elf has no program headers")]
+    MissingSegments,
+
+    /// The ELF file is larger than the limit.
+    #[error("the elf has {size} bytes, more than the limit of {max}")]
+    FileTooLarge {
+        /// The size of the file.
+        size: usize,
+        /// The maximum size of the file.
+        max: usize,
+    },
+
+    /// The ELF has more program headers than the limit.
+    #[error("too many program headers: {count} > {max}")]
+    TooManySegments {
+        /// The number of program headers.
+        count: usize,
+        /// The maximum number of program headers.
+        max: usize,
+    },
+
+    /// The segments load more bytes in memory than the limit.
+    #[error("{segment} loads more than the limit of {max} bytes in memory")]
+    MemoryLimitExceeded {
+        /// The segment exceeding the limit.
+        segment: ElfSegment,
+        /// The maximum number of bytes loaded in memory.
+        max: u64,
+    },
+
+    /// The executable segments hold more instructions than the limit.
+    #[error("{segment} holds more than the limit of {max} instructions")]
+    InstructionLimitExceeded {
+        /// The segment exceeding the limit.
+        segment: ElfSegment,
+        /// The maximum number of instructions.
+        max: usize,
+    },
+
+    /// A segment sets flags that are not supported.
+    #[error("{segment} has unsupported flags 0x{flags:x}")]
+    UnsupportedSegmentFlags {
+        /// The offending segment.
+        segment: ElfSegment,
+        /// The flags of the segment.
+        flags: u32,
+    },
+
+    /// A segment contains more bytes in the file than in memory.
+    #[error("{segment} has a file size of {file_size} bytes larger than its memory size of {mem_size} bytes")]
+    FileSizeExceedsMemSize {
+        /// The offending segment.
+        segment: ElfSegment,
+        /// The size of the segment in the file.
+        file_size: u64,
+        /// The size of the segment in memory.
+        mem_size: u64,
+    },
+
+    /// A segment extends past the memory of the zkVM.
+    #[error("{segment} at 0x{vaddr:x} with {mem_size} bytes exceeds the maximum address for guest programs")]
+    SegmentOutOfRange {
+        /// The offending segment.
+        segment: ElfSegment,
+        /// The virtual address of the segment.
+        vaddr: u64,
+        /// The size of the segment in memory.
+        mem_size: u64,
+    },
+
+    /// The bytes of a segment extend past the end of the file.
+    #[error("{segment} reads bytes past the end of the file")]
+    SegmentOutOfFile {
+        /// The offending segment.
+        segment: ElfSegment,
+    },
+
+    /// An executable segment is not aligned to a word.
+    #[error("executable {segment} at 0x{vaddr:08x} is not aligned to a word")]
+    MisalignedSegment {
+        /// The offending segment.
+        segment: ElfSegment,
+        /// The virtual address of the segment.
+        vaddr: u32,
+    },
+
+    /// An executable segment does not directly follow the previous executable segment.
+    #[error("executable {segment} at 0x{vaddr:08x} does not follow the previous executable segment ending at 0x{expected:08x}")]
+    NonContiguousExecutableSegments {
+        /// The offending segment.
+        segment: ElfSegment,
+        /// The virtual address of the segment.
+        vaddr: u32,
+        /// The address the previous executable segment ends at.
+        expected: u64,
+    },
+
+    /// Two segments load bytes to the same address.
+    #[error("{first} and {second} overlap at 0x{addr:08x}")]
+    OverlappingSegments {
+        /// The segment loaded first.
+        first: ElfSegment,
+        /// The segment loaded second.
+        second: ElfSegment,
+        /// The first address both segments load.
+        addr: u32,
+    },
+}
+
 impl Elf {
     /// Create a new [Elf].
     #[must_use]
@@ -44,100 +249,198 @@ impl Elf {
     /// Parse the ELF file into a vector of 32-bit encoded instructions and the first memory
     /// address.
     ///
+    /// Some quirks of toolchains other than `rustc` are accepted: empty segments are skipped,
+    /// operating system and processor specific segment flags are ignored, and segments which are
+    /// not executable may start at addresses which are not aligned to a word.
+    ///
     /// # Errors
     ///
-    /// This function may return an error if the ELF is not valid.
+    /// This function returns an [ElfError] naming the offending segment if the ELF is not valid.
     ///
     /// Reference: [Executable and Linkable Format](https://en.wikipedia.org/wiki/Executable_and_Linkable_Format)
-    pub(crate) fn decode(input: &[u8]) -> eyre::Result<Self> {
+    pub(crate) fn decode(input: &[u8]) -> Result<Self, ElfError> {
+        Self::decode_with_limits(input, &ElfLimits::default())
+    }
+
+    /// Parse the ELF file like [Elf::decode], failing with an [ElfError] if loading it exceeds
+    /// `limits`.
+    pub(crate) fn decode_with_limits(input: &[u8], limits: &ElfLimits) -> Result<Self, ElfError> {
+        if input.len() > limits.max_file_size {
+            return Err(ElfError::FileTooLarge { size: input.len(), max: limits.max_file_size });
+        }
+
         let mut image: BTreeMap<u32, u32> = BTreeMap::new();
 
         // Parse the ELF file assuming that it is little-endian..
-        let elf = ElfBytes::<LittleEndian>::minimal_parse(input)?;
+        let elf = ElfBytes::<LittleEndian>::minimal_parse(input)
+            .map_err(|err| ElfError::Parse(err.to_string()))?;
 
         // Some sanity checks to make sure that the ELF file is valid.
         if elf.ehdr.class != Class::ELF32 {
-            eyre::bail!("must be a 32-bit elf");
+            return Err(ElfError::UnsupportedClass);
         } else if elf.ehdr.e_machine != EM_RISCV {
-            eyre::bail!("must be a riscv machine");
+            return Err(ElfError::UnsupportedMachine(elf.ehdr.e_machine));
         } else if elf.ehdr.e_type != ET_EXEC {
-            eyre::bail!("must be executable");
+            return Err(ElfError::UnsupportedType(elf.ehdr.e_type));
         }
 
         // Get the entrypoint of the ELF file as an u32.
-        let entry: u32 = elf.ehdr.e_entry.try_into()?;
+        let entry: u32 = elf
+            .ehdr
+            .e_entry
+            .try_into()
+            .map_err(|_| ElfError::EntrypointOutOfRange(elf.ehdr.e_entry))?;
 
         // Make sure the entrypoint is valid.
-        if entry == MAXIMUM_MEMORY_SIZE || entry % WORD_SIZE as u32 != 0 {
-            eyre::bail!("invalid entrypoint");
+        if entry == MAXIMUM_MEMORY_SIZE {
+            return Err(ElfError::EntrypointOutOfRange(entry.into()));
+        } else if entry % WORD_SIZE as u32 != 0 {
+            return Err(ElfError::MisalignedEntrypoint(entry));
         }
 
+        // Collect the allocated sections, used to name the segments in errors.
+        let sections = allocated_sections(&elf);
+        let segment_at = |index: usize, addr: u64| ElfSegment {
+            index,
+            section: sections
+                .iter()
+                .find(|(start, end, _)| (*start..*end).contains(&addr))
+                .map(|(_, _, name)| name.clone()),
+        };
+
         // Get the segments of the ELF file.
-        let segments = elf.segments().ok_or_else(|| eyre::eyre!("failed to get segments"))?;
-        if segments.len() > 256 {
-            eyre::bail!("too many program headers");
+        let segments = elf.segments().ok_or(ElfError::MissingSegments)?;
+        let max_segments = min(limits.max_segments, MAX_SEGMENTS);
+        if segments.len() > max_segments {
+            return Err(ElfError::TooManySegments { count: segments.len(), max: max_segments });
         }
 
         let mut instructions: Vec<u32> = Vec::new();
         let mut base_address = u32::MAX;
+        let mut text_end: Option<u64> = None;
+        let mut loaded: Vec<(u64, u64, usize)> = Vec::new();
+        let mut memory_size = 0u64;
 
         // Only read segments that are executable instructions that are also PT_LOAD.
-        for segment in segments.iter().filter(|x| x.p_type == PT_LOAD) {
-            // Get the file size of the segment as an u32.
-            let file_size: u32 = segment.p_filesz.try_into()?;
-            if file_size == MAXIMUM_MEMORY_SIZE {
-                eyre::bail!("invalid segment file_size");
+        for (index, segment) in segments.iter().enumerate().filter(|(_, x)| x.p_type == PT_LOAD) {
+            let named = || segment_at(index, segment.p_vaddr);
+
+            // Empty segments don't load anything.
+            if segment.p_memsz == 0 {
+                continue;
             }
 
-            // Get the memory size of the segment as an u32.
-            let mem_size: u32 = segment.p_memsz.try_into()?;
-            if mem_size == MAXIMUM_MEMORY_SIZE {
-                eyre::bail!("Invalid segment mem_size");
+            let flags = segment.p_flags & !PF_MASK_OS_AND_PROC;
+            if flags & !(PF_R | PF_W | PF_X) != 0 {
+                return Err(ElfError::UnsupportedSegmentFlags {
+                    segment: named(),
+                    flags: segment.p_flags,
+                });
             }
+            let executable = (flags & PF_X) != 0;
 
-            // Get the virtual address of the segment as an u32.
-            let vaddr: u32 = segment.p_vaddr.try_into()?;
-            if vaddr % WORD_SIZE as u32 != 0 {
-                eyre::bail!("vaddr {vaddr:08x} is unaligned");
+            if segment.p_filesz > segment.p_memsz {
+                return Err(ElfError::FileSizeExceedsMemSize {
+                    segment: named(),
+                    file_size: segment.p_filesz,
+                    mem_size: segment.p_memsz,
+                });
             }
 
-            // If the virtual address is less than the first memory address, then update the first
-            // memory address.
-            if (segment.p_flags & PF_X) != 0 && base_address > vaddr {
-                base_address = vaddr;
+            // Get the virtual address and the sizes of the segment as u32s, the segment must end
+            // before the maximum address.
+            let segment_end = segment.p_vaddr.checked_add(segment.p_memsz);
+            if segment_end.map_or(true, |end| end > u64::from(MAXIMUM_MEMORY_SIZE)) {
+                return Err(ElfError::SegmentOutOfRange {
+                    segment: named(),
+                    vaddr: segment.p_vaddr,
+                    mem_size: segment.p_memsz,
+                });
             }
+            let vaddr = segment.p_vaddr as u32;
+            let mem_size = segment.p_memsz as u32;
+            let file_size = segment.p_filesz as u32;
 
-            // Get the offset to the segment.
-            let offset: u32 = segment.p_offset.try_into()?;
+            // Make sure the bytes of the segment are in the file.
+            let offset = usize::try_from(segment.p_offset)
+                .ok()
+                .filter(|offset| {
+                    offset.checked_add(file_size as usize).is_some_and(|end| end <= input.len())
+                })
+                .ok_or_else(|| ElfError::SegmentOutOfFile { segment: named() })?;
 
-            // Read the segment and decode each word as an instruction.
-            for i in (0..mem_size).step_by(WORD_SIZE) {
-                let addr = vaddr.checked_add(i).ok_or_else(|| eyre::eyre!("vaddr overflow"))?;
-                if addr == MAXIMUM_MEMORY_SIZE {
-                    eyre::bail!(
-                        "address [0x{addr:08x}] exceeds maximum address for guest programs [0x{MAXIMUM_MEMORY_SIZE:08x}]"
-                    );
-                }
+            // Make sure no other segment loads the same bytes.
+            let (start, end) = (u64::from(vaddr), u64::from(vaddr) + u64::from(mem_size));
+            if let Some(&(other_start, _, other)) = loaded
+                .iter()
+                .find(|(other_start, other_end, _)| start < *other_end && *other_start < end)
+            {
+                let addr = start.max(other_start);
+                return Err(ElfError::OverlappingSegments {
+                    first: segment_at(other, addr),
+                    second: segment_at(index, addr),
+                    addr: addr as u32,
+                });
+            }
+            loaded.push((start, end, index));
 
-                // If we are reading past the end of the file, then break.
-                if i >= file_size {
-                    image.insert(addr, 0);
-                    continue;
+            // Make sure the segment fits in the limits before loading it.
+            memory_size += u64::from(mem_size);
+            if memory_size > limits.max_memory_size {
+                return Err(ElfError::MemoryLimitExceeded {
+                    segment: named(),
+                    max: limits.max_memory_size,
+                });
+            }
+            if executable
+                && instructions.len() + mem_size.div_ceil(WORD_SIZE as u32) as usize
+                    > limits.max_instructions
+            {
+                return Err(ElfError::InstructionLimitExceeded {
+                    segment: named(),
+                    max: limits.max_instructions,
+                });
+            }
+
+            if executable {
+                // The instructions are laid out contiguously from the base address.
+                if vaddr % WORD_SIZE as u32 != 0 {
+                    return Err(ElfError::MisalignedSegment { segment: named(), vaddr });
+                }
+                if let Some(expected) = text_end.filter(|expected| *expected != start) {
+                    return Err(ElfError::NonContiguousExecutableSegments {
+                        segment: named(),
+                        vaddr,
+                        expected,
+                    });
                 }
+                text_end = Some(end.next_multiple_of(WORD_SIZE as u64));
+                base_address = min(base_address, vaddr);
+            }
 
-                // Get the word as an u32 but make sure we don't read past the end of the file.
-                let mut word = 0;
-                let len = min(file_size - i, WORD_SIZE as u32);
-                for j in 0..len {
-                    let offset = (offset + i + j) as usize;
-                    let byte = input
-                        .get(offset)
-                        .ok_or_else(|| eyre::eyre!("failed to read segment offset"))?;
-                    word |= u32::from(*byte) << (j * 8);
+            // Read the segment and decode each word of an executable segment as an instruction.
+            // The bytes past the end of the file are zero.
+            let bytes = &input[offset..offset + file_size as usize];
+            if vaddr % WORD_SIZE as u32 == 0 {
+                for i in (0..mem_size).step_by(WORD_SIZE) {
+                    let mut word = 0;
+                    let len = min(file_size.saturating_sub(i), WORD_SIZE as u32);
+                    for j in 0..len {
+                        word |= u32::from(bytes[(i + j) as usize]) << (j * 8);
+                    }
+                    *image.entry(vaddr + i).or_default() |= word;
+                    if executable {
+                        instructions.push(word);
+                    }
                 }
-                image.insert(addr, word);
-                if (segment.p_flags & PF_X) != 0 {
-                    instructions.push(word);
+            } else {
+                // The segment shares its first and last words with the neighbouring segments.
+                for i in 0..mem_size {
+                    let addr = vaddr + i;
+                    let byte = bytes.get(i as usize).copied().unwrap_or_default();
+                    let shift = (addr % WORD_SIZE as u32) * 8;
+                    *image.entry(addr - addr % WORD_SIZE as u32).or_default() |=
+                        u32::from(byte) << shift;
                 }
             }
         }
@@ -145,3 +448,160 @@ impl Elf {
         Ok(Elf::new(instructions, entry, base_address, image))
     }
 }
+
+/// The allocated sections of the ELF as `(start, end, name)`, empty if the ELF has no section
+/// headers.
+fn allocated_sections(elf: &ElfBytes<'_, LittleEndian>) -> Vec<(u64, u64, String)> {
+    let Ok((Some(headers), Some(strtab))) = elf.section_headers_with_strtab() else {
+        return Vec::new();
+    };
+    headers
+        .iter()
+        .filter(|header| header.sh_addr != 0 && header.sh_size != 0)
+        .filter_map(|header| {
+            let name = strtab.get(header.sh_name as usize).ok()?;
+            Some((header.sh_addr, header.sh_addr.saturating_add(header.sh_size), name.to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use elf::abi::{PF_R, PF_W, PF_X};
+
+    use super::{Elf, ElfError, ElfLimits, ElfSegment};
+
+    /// Builds a 32-bit RISC-V executable without section headers from `(flags, vaddr, bytes,
+    /// mem_size)` segments.
+    fn build_elf(entry: u32, segments: &[(u32, u32, &[u8], u32)]) -> Vec<u8> {
+        let mut elf = vec![0x7f, b'E', b'L', b'F', 1, 1, 1];
+        elf.resize(16, 0);
+        for half in [2u16, 243] {
+            elf.extend(half.to_le_bytes());
+        }
+        for word in [1, entry, 52, 0, 0] {
+            elf.extend(word.to_le_bytes());
+        }
+        for half in [52u16, 32, segments.len() as u16, 40, 0, 0] {
+            elf.extend(half.to_le_bytes());
+        }
+
+        let mut offset = 52 + 32 * segments.len() as u32;
+        for (flags, vaddr, bytes, mem_size) in segments {
+            let file_size = bytes.len() as u32;
+            for word in [1, offset, *vaddr, *vaddr, file_size, *mem_size, *flags, 4] {
+                elf.extend(word.to_le_bytes());
+            }
+            offset += file_size;
+        }
+        for (_, _, bytes, _) in segments {
+            elf.extend_from_slice(bytes);
+        }
+        elf
+    }
+
+    #[test]
+    fn test_decode() {
+        let text = [0x13, 0, 0, 0, 0x73, 0, 0, 0];
+        let elf = Elf::decode(&build_elf(
+            0x1000,
+            &[(PF_R | PF_X, 0x1000, &text, 8), (PF_R | PF_W, 0x2000, &[1, 2, 3], 8)],
+        ))
+        .unwrap();
+        assert_eq!(elf.pc_start, 0x1000);
+        assert_eq!(elf.pc_base, 0x1000);
+        assert_eq!(elf.instructions, vec![0x13, 0x73]);
+        assert_eq!(elf.memory_image.get(&0x2000), Some(&0x0003_0201));
+        assert_eq!(elf.memory_image.get(&0x2004), Some(&0));
+    }
+
+    #[test]
+    fn test_decode_permissive() {
+        // Unaligned data segments sharing a word, an empty segment and os specific flags.
+        let text = [0x13, 0, 0, 0];
+        let elf = Elf::decode(&build_elf(
+            0x1000,
+            &[
+                (PF_R | PF_X | 0x0010_0000, 0x1000, &text, 4),
+                (PF_R, 0x2001, &[0xaa, 0xbb], 2),
+                (PF_R, 0x2003, &[0xcc, 0xdd], 2),
+                (PF_R | PF_W, 0x3003, &[], 0),
+            ],
+        ))
+        .unwrap();
+        assert_eq!(elf.instructions, vec![0x13]);
+        assert_eq!(elf.memory_image.get(&0x2000), Some(&0xccbb_aa00));
+        assert_eq!(elf.memory_image.get(&0x2004), Some(&0x0000_00dd));
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let text = [0x13, 0, 0, 0, 0x73, 0, 0, 0];
+        let segment = |index| ElfSegment { index, section: None };
+
+        let err = Elf::decode(&build_elf(0x1002, &[(PF_X, 0x1000, &text, 8)])).unwrap_err();
+        assert_eq!(err, ElfError::MisalignedEntrypoint(0x1002));
+
+        let err = Elf::decode(&build_elf(0x1000, &[(PF_X, 0x1002, &text, 8)])).unwrap_err();
+        assert_eq!(err, ElfError::MisalignedSegment { segment: segment(0), vaddr: 0x1002 });
+
+        let err = Elf::decode(&build_elf(
+            0x1000,
+            &[(PF_X, 0x1000, &text, 8), (PF_R | PF_W, 0x1006, &[1, 2], 2)],
+        ))
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ElfError::OverlappingSegments { first: segment(0), second: segment(1), addr: 0x1006 }
+        );
+
+        let err = Elf::decode(&build_elf(0x1000, &[(PF_X, 0x1000, &text, 4)])).unwrap_err();
+        assert_eq!(
+            err,
+            ElfError::FileSizeExceedsMemSize { segment: segment(0), file_size: 8, mem_size: 4 }
+        );
+
+        let err = Elf::decode(&build_elf(0x1000, &[(PF_X | 0x8, 0x1000, &text, 8)])).unwrap_err();
+        assert_eq!(err, ElfError::UnsupportedSegmentFlags { segment: segment(0), flags: 0x9 });
+
+        let err =
+            Elf::decode(&build_elf(0x1000, &[(PF_X, 0x1000, &text, 8), (PF_X, 0x1010, &text, 8)]))
+                .unwrap_err();
+        assert_eq!(
+            err,
+            ElfError::NonContiguousExecutableSegments {
+                segment: segment(1),
+                vaddr: 0x1010,
+                expected: 0x1008,
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_limits() {
+        let text = [0x13, 0, 0, 0, 0x73, 0, 0, 0];
+        let segment = |index| ElfSegment { index, section: None };
+        let bss =
+            build_elf(0x1000, &[(PF_X, 0x1000, &text, 8), (PF_R | PF_W, 0x2000, &[], 1 << 30)]);
+
+        // A large BSS is rejected before it is loaded.
+        let limits = ElfLimits { max_memory_size: 1 << 20, ..ElfLimits::default() };
+        let err = Elf::decode_with_limits(&bss, &limits).unwrap_err();
+        assert_eq!(err, ElfError::MemoryLimitExceeded { segment: segment(1), max: 1 << 20 });
+
+        let elf = build_elf(0x1000, &[(PF_X, 0x1000, &text, 8)]);
+        let limits = ElfLimits { max_instructions: 1, ..ElfLimits::UNTRUSTED };
+        let err = Elf::decode_with_limits(&elf, &limits).unwrap_err();
+        assert_eq!(err, ElfError::InstructionLimitExceeded { segment: segment(0), max: 1 });
+
+        let limits = ElfLimits { max_file_size: 64, ..ElfLimits::UNTRUSTED };
+        let err = Elf::decode_with_limits(&elf, &limits).unwrap_err();
+        assert_eq!(err, ElfError::FileTooLarge { size: elf.len(), max: 64 });
+
+        let elf = build_elf(0x1000, &[(PF_X, 0x1000, &text, 8), (PF_R, 0x2000, &[1], 4)]);
+        let limits = ElfLimits { max_segments: 1, ..ElfLimits::UNTRUSTED };
+        let err = Elf::decode_with_limits(&elf, &limits).unwrap_err();
+        assert_eq!(err, ElfError::TooManySegments { count: 2, max: 1 });
+        assert!(Elf::decode_with_limits(&elf, &ElfLimits::UNTRUSTED).is_ok());
+    }
+}
@@ -4,4 +4,5 @@ mod elf;
 mod rrs;
 
 pub(crate) use elf::*;
+pub use elf::{ElfError, ElfLimits, ElfSegment};
 pub(crate) use rrs::*;
@@ -124,6 +124,18 @@ impl Instruction {
     }
 }
 
+/// The address of the `cycle` CSR.
+const CSR_CYCLE: u32 = 0xC00;
+
+/// The address of the `instret` CSR.
+const CSR_INSTRET: u32 = 0xC02;
+
+/// The address of the `cycleh` CSR.
+const CSR_CYCLEH: u32 = 0xC80;
+
+/// The address of the `instreth` CSR.
+const CSR_INSTRETH: u32 = 0xC82;
+
 /// A transpiler that converts the 32-bit encoded instructions into instructions.
 pub(crate) struct InstructionTranspiler;
 
@@ -352,8 +364,23 @@ impl InstructionProcessor for InstructionTranspiler {
         Instruction::unimp()
     }
 
-    fn process_csrrs(&mut self, _: ITypeCSR) -> Self::InstructionResult {
-        Instruction::unimp()
+    fn process_csrrs(&mut self, dec_insn: ITypeCSR) -> Self::InstructionResult {
+        // Only the reads of the `cycle` and `instret` counters, that is `rdcycle` and `rdinstret`,
+        // are supported. The counters are reset at the start of each shard, so their high halves
+        // are always zero.
+        if dec_insn.rs1 != 0 {
+            return Instruction::unimp();
+        }
+        match dec_insn.csr {
+            CSR_CYCLE => Instruction::new(Opcode::RDCYCLE, dec_insn.rd as u32, 0, 0, true, true),
+            CSR_INSTRET => {
+                Instruction::new(Opcode::RDINSTRET, dec_insn.rd as u32, 0, 0, true, true)
+            }
+            CSR_CYCLEH | CSR_INSTRETH => {
+                Instruction::new(Opcode::ADD, dec_insn.rd as u32, 0, 0, false, true)
+            }
+            _ => Instruction::unimp(),
+        }
     }
 
     fn process_csrrsi(&mut self, _: ITypeCSR) -> Self::InstructionResult {
@@ -11,7 +11,7 @@ use serde::{Deserialize, Serialize};
 use crate::{ByteOpcode, Opcode};
 
 /// The number of different byte operations.
-pub const NUM_BYTE_OPS: usize = 9;
+pub const NUM_BYTE_OPS: usize = 12;
 
 /// Byte Lookup Event.
 ///
@@ -216,6 +216,81 @@ pub(crate) fn add_sharded_byte_lookup_events(
     }
 }
 
+/// A summary of the byte lookup multiplicities of a single shard.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShardByteLookupStats {
+    /// The number of distinct byte lookup events, i.e. the number of nonzero table entries.
+    pub distinct_events: usize,
+    /// The total number of byte lookups, summed over all multiplicities.
+    pub total_lookups: usize,
+    /// The largest multiplicity of a single byte lookup event.
+    pub max_multiplicity: usize,
+    /// The event with the largest multiplicity, if any.
+    pub max_event: Option<ByteLookupEvent>,
+    /// The total number of lookups per opcode, indexed by the opcode.
+    pub lookups_per_opcode: [usize; NUM_BYTE_OPS],
+    /// The total number of lookups per channel, indexed by the channel.
+    pub lookups_per_channel: Vec<usize>,
+}
+
+/// An audit of the byte lookup multiplicities of a set of shards.
+///
+/// Multiplicities in the byte table are field elements, so a lookup whose multiplicity exceeds
+/// the field order silently wraps around and only surfaces as a failed lookup argument. This
+/// audit makes such shards visible before proving.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ByteLookupAudit {
+    /// The per-shard statistics.
+    pub shards: HashMap<u32, ShardByteLookupStats>,
+}
+
+impl ByteLookupAudit {
+    /// Computes the audit from the byte lookups of a record.
+    #[must_use]
+    pub fn new(byte_lookups: &HashMap<u32, HashMap<ByteLookupEvent, usize>>) -> Self {
+        let shards = byte_lookups
+            .iter()
+            .map(|(shard, events)| {
+                let mut stats = ShardByteLookupStats::default();
+                for (event, &mult) in events {
+                    stats.distinct_events += 1;
+                    stats.total_lookups += mult;
+                    stats.lookups_per_opcode[event.opcode as usize] += mult;
+                    let channel = event.channel as usize;
+                    if stats.lookups_per_channel.len() <= channel {
+                        stats.lookups_per_channel.resize(channel + 1, 0);
+                    }
+                    stats.lookups_per_channel[channel] += mult;
+                    if mult > stats.max_multiplicity {
+                        stats.max_multiplicity = mult;
+                        stats.max_event = Some(*event);
+                    }
+                }
+                (*shard, stats)
+            })
+            .collect();
+        Self { shards }
+    }
+
+    /// Returns the largest multiplicity over all shards.
+    #[must_use]
+    pub fn max_multiplicity(&self) -> usize {
+        self.shards.values().map(|stats| stats.max_multiplicity).max().unwrap_or(0)
+    }
+
+    /// Returns the shards, in increasing order, that contain a multiplicity greater than or equal
+    /// to `bound`.
+    #[must_use]
+    pub fn overflowing_shards(&self, bound: usize) -> Vec<u32> {
+        self.shards
+            .iter()
+            .filter(|(_, stats)| stats.max_multiplicity >= bound)
+            .map(|(shard, _)| *shard)
+            .sorted()
+            .collect()
+    }
+}
+
 impl From<Opcode> for ByteOpcode {
     /// Convert an opcode to a byte opcode.
     fn from(value: Opcode) -> Self {
@@ -243,6 +318,9 @@ impl ByteOpcode {
             ByteOpcode::LTU,
             ByteOpcode::MSB,
             ByteOpcode::U16Range,
+            ByteOpcode::AesSbox,
+            ByteOpcode::HexChar,
+            ByteOpcode::Base64Char,
         ];
         assert_eq!(opcodes.len(), NUM_BYTE_OPS);
         opcodes
@@ -254,3 +332,51 @@ impl ByteOpcode {
         F::from_canonical_u8(self as u8)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_lookup_audit() {
+        let mut byte_lookups = HashMap::new();
+        for _ in 0..3 {
+            byte_lookups.add_u8_range_check(1, 0, 1, 2);
+        }
+        byte_lookups.add_u16_range_check(1, 2, 7);
+        byte_lookups.lookup_or(2, 1, 1, 2);
+
+        let audit = ByteLookupAudit::new(&byte_lookups);
+        let stats = &audit.shards[&1];
+        assert_eq!(stats.distinct_events, 2);
+        assert_eq!(stats.total_lookups, 4);
+        assert_eq!(stats.max_multiplicity, 3);
+        assert_eq!(stats.lookups_per_opcode[ByteOpcode::U8Range as usize], 3);
+        assert_eq!(stats.lookups_per_channel, vec![3, 0, 1]);
+        assert_eq!(audit.max_multiplicity(), 3);
+        assert_eq!(audit.overflowing_shards(2), vec![1]);
+        assert!(audit.overflowing_shards(4).is_empty());
+    }
+
+    #[test]
+    fn test_split_byte_lookups() {
+        let mut record = crate::ExecutionRecord::default();
+        for _ in 0..7 {
+            record.add_u8_range_check(1, 0, 1, 2);
+        }
+        record.add_u16_range_check(1, 0, 7);
+
+        let split = record.split_byte_lookups(3);
+        assert_eq!(split.len(), 2);
+        assert_eq!(record.byte_lookup_audit().max_multiplicity(), 3);
+        assert_eq!(record.byte_lookup_audit().shards[&1].total_lookups, 4);
+        let multiplicities = split
+            .iter()
+            .map(|record| {
+                assert_eq!(record.byte_lookup_shard(), 1);
+                record.byte_lookup_audit().shards[&1].total_lookups
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(multiplicities, vec![3, 1]);
+    }
+}
@@ -38,6 +38,8 @@ pub struct CpuEvent {
     pub memory: Option<u32>,
     /// The memory record.
     pub memory_record: Option<MemoryRecordEnum>,
+    /// The memory record of the next word, for misaligned accesses that span two words.
+    pub memory_next_record: Option<MemoryRecordEnum>,
     /// The exit code.
     pub exit_code: u32,
     /// The ALU lookup id.
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId,
+};
+
+/// The number of words in an AES block or round key.
+pub const AES_BLOCK_WORDS: usize = 4;
+
+/// AES Encrypt Block Event.
+///
+/// This event is emitted when a block is encrypted with AES-128 or AES-256 from an expanded key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AesEncryptBlockEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the block, which is overwritten with the ciphertext.
+    pub block_ptr: u32,
+    /// The pointer to the key schedule, laid out as `[rounds, round_keys..]`.
+    pub key_ptr: u32,
+    /// The number of rounds, 10 for AES-128 and 14 for AES-256.
+    pub rounds: u32,
+    /// The plaintext block.
+    pub input: [u32; AES_BLOCK_WORDS],
+    /// The `rounds + 1` round keys.
+    pub round_keys: Vec<[u32; AES_BLOCK_WORDS]>,
+    /// The ciphertext block.
+    pub output: [u32; AES_BLOCK_WORDS],
+    /// The memory record for the number of rounds.
+    pub header_read_record: MemoryReadRecord,
+    /// The memory records for the plaintext.
+    pub input_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for the round keys, one list per round key.
+    pub round_key_read_records: Vec<Vec<MemoryReadRecord>>,
+    /// The memory records for the ciphertext.
+    pub output_write_records: Vec<MemoryWriteRecord>,
+}
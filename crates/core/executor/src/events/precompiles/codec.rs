@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{memory::MemoryRecordEnum, LookupId};
+
+/// A text encoding of binary data.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Serialize, Deserialize)]
+pub enum Codec {
+    /// Hexadecimal, which encodes a byte as two characters. The encoding is lowercase and the
+    /// decoding accepts both cases.
+    Hex,
+    /// The standard base64 alphabet, ending with `+` and `/`, without padding.
+    Base64,
+    /// The URL-safe base64 alphabet, ending with `-` and `_`, without padding.
+    Base64Url,
+}
+
+impl Codec {
+    /// The number of words of binary data in a block.
+    #[must_use]
+    pub const fn binary_words(self) -> usize {
+        match self {
+            Codec::Hex => 1,
+            Codec::Base64 | Codec::Base64Url => 3,
+        }
+    }
+
+    /// The number of words of text in a block.
+    #[must_use]
+    pub const fn text_words(self) -> usize {
+        match self {
+            Codec::Hex => 2,
+            Codec::Base64 | Codec::Base64Url => 4,
+        }
+    }
+}
+
+/// An operation of a codec precompile.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Serialize, Deserialize)]
+pub enum CodecOperation {
+    /// Encodes a block of binary data into text.
+    Encode,
+    /// Decodes a block of text into binary data.
+    Decode,
+}
+
+/// Codec Event.
+///
+/// This event is emitted when a block is encoded or decoded by the hex or base64 precompiles.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodecEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The encoding.
+    pub codec: Codec,
+    /// The operation.
+    pub op: CodecOperation,
+    /// The pointer to the output, the first argument of the syscall.
+    pub out_ptr: u32,
+    /// The pointer to the input, the second argument of the syscall.
+    pub in_ptr: u32,
+    /// The binary data as a list of words.
+    pub binary: Vec<u32>,
+    /// The text as a list of words.
+    pub text: Vec<u32>,
+    /// The memory records for the binary data, which are reads when encoding and writes when
+    /// decoding.
+    pub binary_memory_records: Vec<MemoryRecordEnum>,
+    /// The memory records for the text, which are writes when encoding and reads when decoding.
+    pub text_memory_records: Vec<MemoryRecordEnum>,
+}
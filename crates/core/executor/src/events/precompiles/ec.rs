@@ -1,8 +1,13 @@
 use serde::{Deserialize, Serialize};
 
+use num::BigUint;
 use sp1_curves::{
-    params::{NumLimbs, NumWords},
-    weierstrass::{bls12_381::bls12381_decompress, secp256k1::secp256k1_decompress},
+    params::{FieldParameters, NumLimbs, NumWords},
+    weierstrass::{
+        bls12_381::bls12381_decompress,
+        secp256k1::secp256k1_decompress,
+        sswu::{sswu_map, SswuParameters},
+    },
     AffinePoint, CurveType, EllipticCurve,
 };
 use sp1_primitives::consts::{bytes_to_words_le_vec, words_to_bytes_le_vec};
@@ -63,6 +68,28 @@ pub struct EllipticCurveDoubleEvent {
     pub p_memory_records: Vec<MemoryWriteRecord>,
 }
 
+/// Elliptic Curve Map Event.
+///
+/// This event is emitted when a field element is mapped to a point of the curve isogenous to an
+/// elliptic curve with the simplified SWU map.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EllipticCurveMapEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the field element, which is overwritten with the point.
+    pub p_ptr: u32,
+    /// The field element as a list of words.
+    pub u: Vec<u32>,
+    /// The memory records for the point.
+    pub p_memory_records: Vec<MemoryWriteRecord>,
+}
+
 /// Elliptic Curve Point Decompress Event.
 ///
 /// This event is emitted when an elliptic curve point decompression operation is performed.
@@ -229,3 +256,38 @@ pub fn create_ec_decompress_event<E: EllipticCurve>(
         y_memory_records,
     }
 }
+
+/// Create an elliptic curve map event.
+///
+/// It takes a pointer to a memory location, reads the field element `u` from the first half of
+/// it, maps `u` to a point of the curve isogenous to `E` with the simplified SWU map, and writes
+/// the point to the memory location.
+pub fn create_ec_map_event<E: EllipticCurve + SswuParameters>(
+    rt: &mut SyscallContext,
+    arg1: u32,
+    _: u32,
+) -> EllipticCurveMapEvent {
+    let start_clk = rt.clk;
+    let p_ptr = arg1;
+    assert!(p_ptr % 4 == 0, "p_ptr must be 4-byte aligned");
+
+    let num_words_field_element = <E::BaseField as NumWords>::WordsFieldElement::USIZE;
+    let u = rt.slice_unsafe(p_ptr, num_words_field_element);
+
+    let u_int = BigUint::from_slice(&u);
+    assert!(u_int < E::BaseField::modulus(), "u must be reduced");
+    let (x, y) = sswu_map::<E>(&u_int).expect("u is an exceptional input of the map");
+    let result_words = AffinePoint::<E>::new(x, y).to_words_le();
+
+    let p_memory_records = rt.mw_slice(p_ptr, &result_words);
+
+    EllipticCurveMapEvent {
+        lookup_id: rt.syscall_lookup_id,
+        shard: rt.current_shard(),
+        channel: rt.current_channel(),
+        clk: start_clk,
+        p_ptr,
+        u,
+        p_memory_records,
+    }
+}
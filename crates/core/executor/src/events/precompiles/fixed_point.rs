@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{memory::MemoryWriteRecord, LookupId};
+
+/// A fixed-point math function, evaluated by a lookup into a preprocessed table of its values on
+/// all 16-bit inputs.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash, Serialize, Deserialize)]
+pub enum FixedPointFunction {
+    /// The reciprocal `2^29 / x` of an integer, rounded down, and 0 for `x = 0`.
+    Reciprocal = 0,
+    /// The square root of an integer in 16.16 fixed point, rounded down.
+    Sqrt = 1,
+    /// The sine of an angle in units of `2π / 2^16`, in signed 2.29 fixed point, rounded to
+    /// nearest and offset by `2^29`.
+    Sin = 2,
+    /// The cosine of an angle in units of `2π / 2^16`, in signed 2.29 fixed point, rounded to
+    /// nearest and offset by `2^29`.
+    Cos = 3,
+}
+
+impl FixedPointFunction {
+    /// All the fixed-point functions, in the order of their tables.
+    pub const ALL: [Self; 4] = [Self::Reciprocal, Self::Sqrt, Self::Sin, Self::Cos];
+}
+
+/// Fixed Point Event.
+///
+/// This event is emitted when a fixed-point function is evaluated by the fixed-point precompile.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FixedPointEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The function.
+    pub function: FixedPointFunction,
+    /// The pointer to the word holding the input in its low 16 bits.
+    pub ptr: u32,
+    /// The input, the low 16 bits of the word.
+    pub input: u16,
+    /// The result.
+    pub result: u32,
+    /// The memory record of the word, which is overwritten with the result.
+    pub memory_record: MemoryWriteRecord,
+}
@@ -1,15 +1,33 @@
+mod aes;
+mod clmul;
+mod codec;
 mod ec;
 mod edwards;
+mod fixed_point;
 mod fptower;
 mod keccak256_permute;
+mod poseidon2_merkle;
+mod range_check;
+mod registered_curve;
 mod sha256_compress;
 mod sha256_extend;
 mod uint256;
+mod wide_alu;
+mod x25519;
 
+pub use aes::*;
+pub use clmul::*;
+pub use codec::*;
 pub use ec::*;
 pub use edwards::*;
+pub use fixed_point::*;
 pub use fptower::*;
 pub use keccak256_permute::*;
+pub use poseidon2_merkle::*;
+pub use range_check::*;
+pub use registered_curve::*;
 pub use sha256_compress::*;
 pub use sha256_extend::*;
 pub use uint256::*;
+pub use wide_alu::*;
+pub use x25519::*;
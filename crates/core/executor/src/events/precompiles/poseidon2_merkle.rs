@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{memory::MemoryReadRecord, LookupId};
+
+/// The number of words in a Poseidon2 digest.
+pub const POSEIDON2_DIGEST_WORDS: usize = 8;
+
+/// The maximum number of levels of a Merkle path, so that the index of the leaf fits in a word.
+pub const POSEIDON2_MERKLE_MAX_DEPTH: usize = 32;
+
+/// Poseidon2 Merkle Verify Event.
+///
+/// This event is emitted when a Merkle path is hashed up to its root with the Poseidon2
+/// compression function over BabyBear.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Poseidon2MerkleVerifyEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the leaf digest, followed by the expected root digest.
+    pub state_ptr: u32,
+    /// The pointer to the path, laid out as `[index, depth, siblings..]`.
+    pub path_ptr: u32,
+    /// The index of the leaf.
+    pub index: u32,
+    /// The number of levels of the path.
+    pub depth: u32,
+    /// The leaf digest.
+    pub leaf: [u32; POSEIDON2_DIGEST_WORDS],
+    /// The expected root digest.
+    pub root: [u32; POSEIDON2_DIGEST_WORDS],
+    /// The sibling digests, from the leaf level upwards.
+    pub siblings: Vec<[u32; POSEIDON2_DIGEST_WORDS]>,
+    /// The node digests at every level, starting with the leaf and ending with the root.
+    pub nodes: Vec<[u32; POSEIDON2_DIGEST_WORDS]>,
+    /// The memory records for the leaf.
+    pub leaf_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for the expected root.
+    pub root_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for the index and depth.
+    pub header_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for the siblings, one list per level.
+    pub sibling_read_records: Vec<Vec<MemoryReadRecord>>,
+}
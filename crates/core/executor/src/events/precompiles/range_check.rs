@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{memory::MemoryReadRecord, LookupId};
+
+/// Range Check Event.
+///
+/// This event is emitted when a word of memory is checked to fit in a number of bits.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RangeCheckEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the checked word.
+    pub ptr: u32,
+    /// The number of bits the word fits in.
+    pub bits: u32,
+    /// The checked word.
+    pub value: u32,
+    /// The memory record for the checked word.
+    pub memory_record: MemoryReadRecord,
+}
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId,
+};
+
+/// Registered Curve Add Event.
+///
+/// This event is emitted when two points of a registered Weierstrass curve are added.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisteredCurveAddEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The id of the curve.
+    pub curve_id: u32,
+    /// The pointer to the curve id, followed by the first point.
+    pub p_ptr: u32,
+    /// The first point as a list of words.
+    pub p: Vec<u32>,
+    /// The pointer to the second point.
+    pub q_ptr: u32,
+    /// The second point as a list of words.
+    pub q: Vec<u32>,
+    /// The memory record for the curve id.
+    pub curve_id_memory_record: MemoryReadRecord,
+    /// The memory records for the first point.
+    pub p_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the second point.
+    pub q_memory_records: Vec<MemoryReadRecord>,
+}
+
+/// Registered Curve Double Event.
+///
+/// This event is emitted when a point of a registered Weierstrass curve is doubled.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisteredCurveDoubleEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The id of the curve.
+    pub curve_id: u32,
+    /// The pointer to the curve id, followed by the point.
+    pub p_ptr: u32,
+    /// The point as a list of words.
+    pub p: Vec<u32>,
+    /// The memory record for the curve id.
+    pub curve_id_memory_record: MemoryReadRecord,
+    /// The memory records for the point.
+    pub p_memory_records: Vec<MemoryWriteRecord>,
+}
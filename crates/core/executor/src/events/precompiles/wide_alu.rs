@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId,
+};
+
+/// An operation of the wide ALU on 64-bit unsigned integers.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Serialize, Deserialize)]
+pub enum WideAluOperation {
+    /// Wrapping addition.
+    Add,
+    /// Wrapping subtraction.
+    Sub,
+    /// Wrapping multiplication, which keeps the low 64 bits of the product.
+    Mul,
+    /// Unsigned less than, which yields 1 or 0.
+    Ltu,
+}
+
+/// Wide ALU Event.
+///
+/// This event is emitted when a 64-bit operation is performed by the wide ALU precompile.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WideAluEvent {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The operation.
+    pub op: WideAluOperation,
+    /// The pointer to the x value.
+    pub x_ptr: u32,
+    /// The x value as a list of words.
+    pub x: Vec<u32>,
+    /// The pointer to the y value.
+    pub y_ptr: u32,
+    /// The y value as a list of words.
+    pub y: Vec<u32>,
+    /// The memory records for the x value, which is overwritten with the result.
+    pub x_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the y value.
+    pub y_memory_records: Vec<MemoryReadRecord>,
+}
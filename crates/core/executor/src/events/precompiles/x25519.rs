@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    LookupId,
+};
+
+/// X25519 Scalar Multiplication Event.
+///
+/// This event is emitted when an X25519 scalar multiplication is performed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct X25519Event {
+    /// The lookup identifer.
+    pub lookup_id: LookupId,
+    /// The shard number.
+    pub shard: u32,
+    /// The channel number.
+    pub channel: u8,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the u-coordinate.
+    pub u_ptr: u32,
+    /// The u-coordinate as a list of words.
+    pub u: Vec<u32>,
+    /// The pointer to the scalar.
+    pub k_ptr: u32,
+    /// The scalar as a list of words.
+    pub k: Vec<u32>,
+    /// The memory records for reading the u-coordinate.
+    pub u_read_records: Vec<MemoryReadRecord>,
+    /// The memory records for writing the resulting u-coordinate.
+    pub u_write_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the scalar.
+    pub k_read_records: Vec<MemoryReadRecord>,
+}
@@ -1,15 +1,20 @@
 use std::{
     fs::File,
     io::{BufWriter, Write},
-    sync::Arc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
 };
 
 use hashbrown::HashMap;
 use serde::{Deserialize, Serialize};
+use sp1_curves::weierstrass::registered::registered_curve;
 use sp1_stark::SP1CoreOpts;
 use thiserror::Error;
 
 use crate::{
+    audit::{MemoryDump, SyscallAudit, SyscallAuditEntry},
     context::SP1Context,
     events::{
         create_alu_lookup_id, create_alu_lookups, AluEvent, CpuEvent, LookupId,
@@ -18,14 +23,26 @@ use crate::{
     },
     hook::{HookEnv, HookRegistry},
     memory::{Entry, PagedMemory},
+    profiler::Profiler,
+    progress::{ExecutionProgress, ProgressCallback, ProgressControl},
     record::{ExecutionRecord, MemoryAccessRecord},
     report::ExecutionReport,
     state::{ExecutionState, ForkState},
     subproof::{DefaultSubproofVerifier, SubproofVerifier},
-    syscalls::{default_syscall_map, Syscall, SyscallCode, SyscallContext},
-    Instruction, Opcode, Program, Register,
+    syscalls::{default_syscall_map, Syscall, SyscallCode, SyscallContext, X25519_LADDER_STEPS},
+    ElfError, Instruction, Opcode, Program, Register,
 };
 
+#[cfg(feature = "jit")]
+use crate::jit::JitCache;
+
+/// The end of the guard region below the stack of guest programs.
+///
+/// The stack of `sp1-zkvm` programs starts at `0x0020_0400` and grows down. Loads and stores below
+/// this address while the stack pointer is below it too make the executor trap with
+/// [`ExecutionError::StackOverflow`], instead of letting the stack overwrite low memory.
+pub const STACK_GUARD_END: u32 = 0x0001_0000;
+
 /// An executor for the SP1 RISC-V zkVM.
 ///
 /// The exeuctor is responsible for executing a user program and tracing important events which
@@ -61,6 +78,9 @@ pub struct Executor<'a> {
     /// A buffer for writing trace events to a file.
     pub trace_buf: Option<BufWriter<File>>,
 
+    /// The profiler sampling the call stack of the program, if it is profiled.
+    pub profiler: Option<Profiler>,
+
     /// Whether the runtime is in constrained mode or not.
     ///
     /// In unconstrained mode, any events, clock, register, or memory changes are reset after
@@ -98,9 +118,47 @@ pub struct Executor<'a> {
     /// The maximum number of cpu cycles to use for execution.
     pub max_cycles: Option<u64>,
 
+    /// The flag that cancels the execution when set, checked at the start of each shard.
+    pub cancel_flag: Option<Arc<AtomicBool>>,
+
     /// Memory addresses that were touched in this batch of shards. Used to minimize the size of
     /// checkpoints.
     pub memory_checkpoint: PagedMemory<Option<MemoryRecord>>,
+
+    /// The number of deferred proofs verified in the current shard.
+    pub deferred_proofs_in_shard: usize,
+
+    /// Whether execution pauses when the program yields to the host.
+    pub pause_on_yield: bool,
+
+    /// Whether the program yielded to the host since execution was last paused.
+    pub yielded: bool,
+
+    /// Hints requested by the program from asynchronous hooks that have not yet been awaited,
+    /// indexed by their handles.
+    pub pending_hints: HashMap<u32, std::thread::JoinHandle<Vec<u8>>>,
+
+    /// The log to which every syscall is recorded, if the syscalls are audited.
+    pub syscall_audit: Option<SyscallAudit>,
+
+    /// The callback invoked periodically with a summary of the state of the execution.
+    pub progress_callback: Option<ProgressCallback<'a>>,
+
+    /// Whether the program may halt with a non-zero exit code.
+    pub allow_failure: bool,
+
+    /// The message of the `assert_host!` that failed, reported by the program before it halts.
+    pub host_assertion: Option<String>,
+
+    /// The maximum number of bytes the program may commit to its public values.
+    pub max_public_values_size: Option<usize>,
+
+    /// The maximum number of bytes of input, including the hints written during the execution.
+    pub max_input_size: Option<usize>,
+
+    /// The blocks of the program compiled to native code, if the context enables the JIT.
+    #[cfg(feature = "jit")]
+    pub jit: Option<JitCache>,
 }
 
 /// The different modes the executor can run in.
@@ -137,6 +195,14 @@ pub enum ExecutionError {
     #[error("exceeded cycle limit of {0}")]
     ExceededCycleLimit(u64),
 
+    /// The execution was cancelled through the cancellation flag of its context.
+    #[error("execution cancelled")]
+    Cancelled(),
+
+    /// The execution was aborted by the progress callback of its context, at the given cycle.
+    #[error("execution aborted by the progress callback at cycle {0}")]
+    Aborted(u64),
+
     /// The execution failed because the syscall was called in unconstrained mode.
     #[error("syscall called in unconstrained mode")]
     InvalidSyscallUsage(u64),
@@ -148,6 +214,81 @@ pub enum ExecutionError {
     /// The program ended in unconstrained mode.
     #[error("program ended in unconstrained mode")]
     EndInUnconstrained(),
+
+    /// The program verified more deferred proofs than were written to the runtime.
+    #[error(
+        "program tried to verify deferred proof {index}, but only {provided} proofs were written \
+         to stdin"
+    )]
+    NotEnoughDeferredProofs {
+        /// The index of the proof the program tried to verify.
+        index: usize,
+        /// The number of proofs written to stdin.
+        provided: usize,
+    },
+
+    /// The stack grew into the guard region below it.
+    #[error("stack overflow at pc {pc:#x} with sp {sp:#x}")]
+    StackOverflow {
+        /// The program counter of the load or store touching the guard region.
+        pc: u32,
+        /// The stack pointer at that point.
+        sp: u32,
+    },
+
+    /// The program failed an `assert_host!` while it was executed without being proven.
+    #[error("assertion failed at {0}")]
+    HostAssertionFailed(String),
+
+    /// The program counter is not aligned to a word, for example after an indirect jump.
+    #[error("misaligned program counter {0:#x}")]
+    MisalignedPc(u32),
+
+    /// The program could not be loaded from its ELF.
+    #[error("invalid elf: {0}")]
+    InvalidElf(#[from] ElfError),
+
+    /// The program committed more public values than its context allows.
+    #[error("public values of {size} bytes exceed the maximum of {max} bytes at pc {pc:#x}")]
+    PublicValuesTooLarge {
+        /// The size of the public values after the write at `pc`.
+        size: usize,
+        /// The maximum size of the public values.
+        max: usize,
+        /// The program counter of the write.
+        pc: u32,
+    },
+
+    /// The program called a registered curve precompile with a curve id which is not registered.
+    #[error("the curve id {0} is not registered")]
+    UnregisteredCurve(u32),
+
+    /// The program called a fixed-point precompile, whose tables are not enabled in the options.
+    #[error(
+        "syscall {0} needs the fixed-point tables, which are not in the default machine: enable \
+         SP1CoreOpts::fixed_point_tables and prove with RiscvAir::machine_with_fixed_point_tables"
+    )]
+    FixedPointTablesDisabled(SyscallCode),
+
+    /// The program called a syscall with invalid arguments.
+    #[error("invalid arguments to syscall {syscall}: {message}")]
+    InvalidSyscallArguments {
+        /// The syscall.
+        syscall: SyscallCode,
+        /// What is wrong with the arguments.
+        message: String,
+    },
+
+    /// The input of the program grew larger than its context allows.
+    #[error("input of {size} bytes exceeds the maximum of {max} bytes at pc {pc:#x}")]
+    InputTooLarge {
+        /// The size of the input after the write at `pc`, or of the stdin at the entry point.
+        size: usize,
+        /// The maximum size of the input.
+        max: usize,
+        /// The program counter of the write, or the entry point.
+        pc: u32,
+    },
 }
 
 macro_rules! assert_valid_memory_access {
@@ -168,7 +309,8 @@ impl<'a> Executor<'a> {
     ///
     /// # Panics
     ///
-    /// This function may panic if it fails to create the trace file if `TRACE_FILE` is set.
+    /// This function may panic if it fails to create the trace file if `TRACE_FILE` is set, or the
+    /// syscall audit log or the profile if the context asks for them.
     #[must_use]
     pub fn with_context(program: Program, opts: SP1CoreOpts, context: SP1Context<'a>) -> Self {
         // Create a shared reference to the program.
@@ -185,6 +327,16 @@ impl<'a> Executor<'a> {
             None
         };
 
+        // If the context asks for it, initialize the syscall audit log.
+        let syscall_audit = context.syscall_audit.map(|opts| {
+            SyscallAudit::from_opts(&opts).expect("failed to create the syscall audit")
+        });
+
+        // If the context asks for it, create the profiler.
+        let profiler = context
+            .profiler
+            .map(|opts| Profiler::from_opts(&opts).expect("failed to create the profiler"));
+
         // Determine the maximum number of cycles for any syscall.
         let syscall_map = default_syscall_map();
         let max_syscall_cycles =
@@ -193,6 +345,14 @@ impl<'a> Executor<'a> {
         let subproof_verifier =
             context.subproof_verifier.unwrap_or_else(|| Arc::new(DefaultSubproofVerifier::new()));
         let hook_registry = context.hook_registry.unwrap_or_default();
+        let report = ExecutionReport::new(&program);
+
+        // If the context asks for it, create the JIT, falling back to the interpreter on hosts
+        // that cranelift does not support.
+        #[cfg(feature = "jit")]
+        let jit = context.jit.then(|| JitCache::new(&program)).and_then(|jit| {
+            jit.map_err(|err| tracing::warn!("failed to create the JIT: {err}")).ok()
+        });
 
         Self {
             record,
@@ -205,18 +365,32 @@ impl<'a> Executor<'a> {
             cycle_tracker: HashMap::new(),
             io_buf: HashMap::new(),
             trace_buf,
+            profiler,
             unconstrained: false,
             unconstrained_state: ForkState::default(),
             syscall_map,
             executor_mode: ExecutorMode::Trace,
             max_syscall_cycles,
-            report: ExecutionReport::default(),
+            report,
             print_report: false,
             subproof_verifier,
             hook_registry,
             opts,
             max_cycles: context.max_cycles,
+            cancel_flag: context.cancel_flag,
             memory_checkpoint: PagedMemory::new_preallocated(),
+            deferred_proofs_in_shard: 0,
+            pause_on_yield: false,
+            yielded: false,
+            pending_hints: HashMap::new(),
+            syscall_audit,
+            progress_callback: context.progress_callback,
+            allow_failure: context.allow_failure,
+            host_assertion: None,
+            max_public_values_size: context.max_public_values_size,
+            max_input_size: context.max_input_size,
+            #[cfg(feature = "jit")]
+            jit,
         }
     }
 
@@ -500,6 +674,64 @@ impl<'a> Executor<'a> {
         }
     }
 
+    /// Read the word after the one accessed at [`MemoryAccessPosition::Memory`], for a misaligned
+    /// load that spans two words.
+    fn mr_next(&mut self, addr: u32) -> u32 {
+        let record = self.mr(addr, self.shard(), self.timestamp(&MemoryAccessPosition::Memory));
+        if !self.unconstrained && self.executor_mode == ExecutorMode::Trace {
+            self.memory_accesses.memory_next = Some(record.into());
+        }
+        record.value
+    }
+
+    /// Write the word after the one accessed at [`MemoryAccessPosition::Memory`], for a misaligned
+    /// store that spans two words.
+    fn mw_next(&mut self, addr: u32, value: u32) {
+        let record =
+            self.mw(addr, value, self.shard(), self.timestamp(&MemoryAccessPosition::Memory));
+        if !self.unconstrained && self.executor_mode == ExecutorMode::Trace {
+            assert!(self.memory_accesses.memory_next.is_none());
+            self.memory_accesses.memory_next = Some(record.into());
+        }
+    }
+
+    /// The `size` bytes at `addr`, zero-extended, given the value of the word containing `addr`.
+    ///
+    /// If the bytes span two words, the next word is read as well.
+    fn load_value(&mut self, addr: u32, memory_read_value: u32, size: u32) -> u32 {
+        let offset = (addr % 4) as usize;
+        let mut bytes = [0u8; 8];
+        bytes[..4].copy_from_slice(&memory_read_value.to_le_bytes());
+        if offset + size as usize > 4 {
+            let next = self.mr_next(align(addr).wrapping_add(4));
+            bytes[4..].copy_from_slice(&next.to_le_bytes());
+        }
+        let mut value = [0u8; 4];
+        value[..size as usize].copy_from_slice(&bytes[offset..offset + size as usize]);
+        u32::from_le_bytes(value)
+    }
+
+    /// Stores the low `size` bytes of `value` at `addr`, given the value of the word containing
+    /// `addr`, and returns the new value of that word.
+    ///
+    /// If the bytes span two words, the next word is written as well.
+    fn store_value(&mut self, addr: u32, value: u32, memory_read_value: u32, size: u32) -> u32 {
+        let offset = (addr % 4) as usize;
+        let spans_words = offset + size as usize > 4;
+        let next_addr = align(addr).wrapping_add(4);
+        let mut bytes = [0u8; 8];
+        bytes[..4].copy_from_slice(&memory_read_value.to_le_bytes());
+        if spans_words {
+            bytes[4..].copy_from_slice(&self.word(next_addr).to_le_bytes());
+        }
+        bytes[offset..offset + size as usize]
+            .copy_from_slice(&value.to_le_bytes()[..size as usize]);
+        if spans_words {
+            self.mw_next(next_addr, u32::from_le_bytes(bytes[4..].try_into().unwrap()));
+        }
+        u32::from_le_bytes(bytes[..4].try_into().unwrap())
+    }
+
     /// Read from a register.
     pub fn rr(&mut self, register: Register, position: MemoryAccessPosition) -> u32 {
         self.mr_cpu(register as u32, position)
@@ -551,6 +783,7 @@ impl<'a> Executor<'a> {
             c_record: record.c,
             memory: memory_store_value,
             memory_record: record.memory,
+            memory_next_record: record.memory_next,
             exit_code,
             alu_lookup_id: lookup_id,
             syscall_lookup_id,
@@ -645,23 +878,45 @@ impl<'a> Executor<'a> {
     }
 
     /// Fetch the input operand values for a load instruction.
-    fn load_rr(&mut self, instruction: &Instruction) -> (Register, u32, u32, u32, u32) {
+    fn load_rr(
+        &mut self,
+        instruction: &Instruction,
+    ) -> Result<(Register, u32, u32, u32, u32), ExecutionError> {
         let (rd, rs1, imm) = instruction.i_type();
         let (b, c) = (self.rr(rs1, MemoryAccessPosition::B), imm);
         let addr = b.wrapping_add(c);
+        self.check_stack_guard(addr)?;
         let memory_value = self.mr_cpu(align(addr), MemoryAccessPosition::Memory);
-        (rd, b, c, addr, memory_value)
+        Ok((rd, b, c, addr, memory_value))
     }
 
     /// Fetch the input operand values for a store instruction.
-    fn store_rr(&mut self, instruction: &Instruction) -> (u32, u32, u32, u32, u32) {
+    fn store_rr(
+        &mut self,
+        instruction: &Instruction,
+    ) -> Result<(u32, u32, u32, u32, u32), ExecutionError> {
         let (rs1, rs2, imm) = instruction.s_type();
         let c = imm;
         let b = self.rr(rs2, MemoryAccessPosition::B);
         let a = self.rr(rs1, MemoryAccessPosition::A);
         let addr = b.wrapping_add(c);
+        self.check_stack_guard(addr)?;
         let memory_value = self.word(align(addr));
-        (a, b, c, addr, memory_value)
+        Ok((a, b, c, addr, memory_value))
+    }
+
+    /// Traps with [`ExecutionError::StackOverflow`] if a load or store at `addr` touches the stack
+    /// guard, below the bottom of the stack, while the stack pointer is in it.
+    ///
+    /// Checking the stack pointer distinguishes overflows from other accesses to low addresses,
+    /// like null pointer dereferences.
+    fn check_stack_guard(&self, addr: u32) -> Result<(), ExecutionError> {
+        let sp = self.state.memory.get(Register::X2 as u32).map_or(0, |record| record.value);
+        // The stack pointer is zero until the entrypoint of the program sets it.
+        if addr < STACK_GUARD_END && sp != 0 && sp < STACK_GUARD_END {
+            return Err(ExecutionError::StackOverflow { pc: self.state.pc, sp });
+        }
+        Ok(())
     }
 
     /// Fetch the input operand values for a branch instruction.
@@ -709,6 +964,8 @@ impl<'a> Executor<'a> {
 
         if self.print_report && !self.unconstrained {
             self.report.opcode_counts[instruction.opcode] += 1;
+            let index = ((pc - self.program.pc_base) / 4) as usize;
+            self.report.count_pc(index, self.program.instructions.len());
         }
 
         match instruction.opcode {
@@ -766,60 +1023,42 @@ impl<'a> Executor<'a> {
 
             // Load instructions.
             Opcode::LB => {
-                (rd, b, c, addr, memory_read_value) = self.load_rr(instruction);
+                (rd, b, c, addr, memory_read_value) = self.load_rr(instruction)?;
                 let value = (memory_read_value).to_le_bytes()[(addr % 4) as usize];
                 a = ((value as i8) as i32) as u32;
                 memory_store_value = Some(memory_read_value);
                 self.rw(rd, a);
             }
             Opcode::LH => {
-                (rd, b, c, addr, memory_read_value) = self.load_rr(instruction);
-                if addr % 2 != 0 {
-                    return Err(ExecutionError::InvalidMemoryAccess(Opcode::LH, addr));
-                }
-                let value = match (addr >> 1) % 2 {
-                    0 => memory_read_value & 0x0000_FFFF,
-                    1 => (memory_read_value & 0xFFFF_0000) >> 16,
-                    _ => unreachable!(),
-                };
+                (rd, b, c, addr, memory_read_value) = self.load_rr(instruction)?;
+                let value = self.load_value(addr, memory_read_value, 2);
                 a = ((value as i16) as i32) as u32;
                 memory_store_value = Some(memory_read_value);
                 self.rw(rd, a);
             }
             Opcode::LW => {
-                (rd, b, c, addr, memory_read_value) = self.load_rr(instruction);
-                if addr % 4 != 0 {
-                    return Err(ExecutionError::InvalidMemoryAccess(Opcode::LW, addr));
-                }
-                a = memory_read_value;
+                (rd, b, c, addr, memory_read_value) = self.load_rr(instruction)?;
+                a = self.load_value(addr, memory_read_value, 4);
                 memory_store_value = Some(memory_read_value);
                 self.rw(rd, a);
             }
             Opcode::LBU => {
-                (rd, b, c, addr, memory_read_value) = self.load_rr(instruction);
+                (rd, b, c, addr, memory_read_value) = self.load_rr(instruction)?;
                 let value = (memory_read_value).to_le_bytes()[(addr % 4) as usize];
                 a = value as u32;
                 memory_store_value = Some(memory_read_value);
                 self.rw(rd, a);
             }
             Opcode::LHU => {
-                (rd, b, c, addr, memory_read_value) = self.load_rr(instruction);
-                if addr % 2 != 0 {
-                    return Err(ExecutionError::InvalidMemoryAccess(Opcode::LHU, addr));
-                }
-                let value = match (addr >> 1) % 2 {
-                    0 => memory_read_value & 0x0000_FFFF,
-                    1 => (memory_read_value & 0xFFFF_0000) >> 16,
-                    _ => unreachable!(),
-                };
-                a = (value as u16) as u32;
+                (rd, b, c, addr, memory_read_value) = self.load_rr(instruction)?;
+                a = self.load_value(addr, memory_read_value, 2);
                 memory_store_value = Some(memory_read_value);
                 self.rw(rd, a);
             }
 
             // Store instructions.
             Opcode::SB => {
-                (a, b, c, addr, memory_read_value) = self.store_rr(instruction);
+                (a, b, c, addr, memory_read_value) = self.store_rr(instruction)?;
                 let value = match addr % 4 {
                     0 => (a & 0x0000_00FF) + (memory_read_value & 0xFFFF_FF00),
                     1 => ((a & 0x0000_00FF) << 8) + (memory_read_value & 0xFFFF_00FF),
@@ -831,24 +1070,14 @@ impl<'a> Executor<'a> {
                 self.mw_cpu(align(addr), value, MemoryAccessPosition::Memory);
             }
             Opcode::SH => {
-                (a, b, c, addr, memory_read_value) = self.store_rr(instruction);
-                if addr % 2 != 0 {
-                    return Err(ExecutionError::InvalidMemoryAccess(Opcode::SH, addr));
-                }
-                let value = match (addr >> 1) % 2 {
-                    0 => (a & 0x0000_FFFF) + (memory_read_value & 0xFFFF_0000),
-                    1 => ((a & 0x0000_FFFF) << 16) + (memory_read_value & 0x0000_FFFF),
-                    _ => unreachable!(),
-                };
+                (a, b, c, addr, memory_read_value) = self.store_rr(instruction)?;
+                let value = self.store_value(addr, a, memory_read_value, 2);
                 memory_store_value = Some(value);
                 self.mw_cpu(align(addr), value, MemoryAccessPosition::Memory);
             }
             Opcode::SW => {
-                (a, b, c, addr, _) = self.store_rr(instruction);
-                if addr % 4 != 0 {
-                    return Err(ExecutionError::InvalidMemoryAccess(Opcode::SW, addr));
-                }
-                let value = a;
+                (a, b, c, addr, memory_read_value) = self.store_rr(instruction)?;
+                let value = self.store_value(addr, a, memory_read_value, 4);
                 memory_store_value = Some(value);
                 self.mw_cpu(align(addr), value, MemoryAccessPosition::Memory);
             }
@@ -915,6 +1144,14 @@ impl<'a> Executor<'a> {
                 self.rw(rd, a);
             }
 
+            // Counter instructions.
+            Opcode::RDCYCLE | Opcode::RDINSTRET => {
+                let (rd, _) = instruction.u_type();
+                (b, c) = (0, 0);
+                a = if instruction.opcode == Opcode::RDCYCLE { clk } else { self.state.instret };
+                self.rw(rd, a);
+            }
+
             // System instructions.
             Opcode::ECALL => {
                 // We peek at register x5 to get the syscall id. The reason we don't `self.rr` this
@@ -923,7 +1160,8 @@ impl<'a> Executor<'a> {
                 let syscall_id = self.register(t0);
                 c = self.rr(Register::X11, MemoryAccessPosition::C);
                 b = self.rr(Register::X10, MemoryAccessPosition::B);
-                let syscall = SyscallCode::from_u32(syscall_id);
+                let syscall = SyscallCode::try_from_u32(syscall_id)
+                    .ok_or(ExecutionError::UnsupportedSyscall(syscall_id))?;
 
                 if self.print_report && !self.unconstrained {
                     self.report.syscall_counts[syscall] += 1;
@@ -941,7 +1179,42 @@ impl<'a> Executor<'a> {
                     return Err(ExecutionError::InvalidSyscallUsage(syscall_id as u64));
                 }
 
+                if syscall == SyscallCode::VERIFY_SP1_PROOF {
+                    if self.state.proof_stream_ptr >= self.state.proof_stream.len() {
+                        return Err(ExecutionError::NotEnoughDeferredProofs {
+                            index: self.state.proof_stream_ptr,
+                            provided: self.state.proof_stream.len(),
+                        });
+                    }
+                    self.deferred_proofs_in_shard += 1;
+                }
+
+                if matches!(
+                    syscall,
+                    SyscallCode::REGISTERED_CURVE_ADD | SyscallCode::REGISTERED_CURVE_DOUBLE
+                ) {
+                    let curve_id = self.word(b);
+                    if registered_curve(curve_id).is_none() {
+                        return Err(ExecutionError::UnregisteredCurve(curve_id));
+                    }
+                }
+
+                if matches!(
+                    syscall,
+                    SyscallCode::FIXED_POINT_RECIP
+                        | SyscallCode::FIXED_POINT_SQRT
+                        | SyscallCode::FIXED_POINT_SIN
+                        | SyscallCode::FIXED_POINT_COS
+                ) && !self.opts.fixed_point_tables
+                {
+                    return Err(ExecutionError::FixedPointTablesDisabled(syscall));
+                }
+
+                let memory_before =
+                    self.syscall_audit.as_ref().map(|audit| audit.dump(self, syscall, b, c));
+
                 let syscall_impl = self.get_syscall(syscall).cloned();
+                let allow_failure = self.allow_failure;
                 let mut precompile_rt = SyscallContext::new(self);
                 precompile_rt.syscall_lookup_id = syscall_lookup_id;
                 let (precompile_next_pc, precompile_cycles, returned_exit_code) =
@@ -950,14 +1223,24 @@ impl<'a> Executor<'a> {
                         // register. If it returns None, we just keep the
                         // syscall_id in t0.
                         let res = syscall_impl.execute(&mut precompile_rt, b, c);
+                        if let Some(error) = precompile_rt.error.take() {
+                            return Err(error);
+                        }
                         if let Some(val) = res {
                             a = val;
                         } else {
                             a = syscall_id;
                         }
 
-                        // If the syscall is `HALT` and the exit code is non-zero, return an error.
-                        if syscall == SyscallCode::HALT && precompile_rt.exit_code != 0 {
+                        // If the syscall is `HALT` and the exit code is non-zero, return an error
+                        // unless failures are allowed.
+                        if syscall == SyscallCode::HALT
+                            && precompile_rt.exit_code != 0
+                            && !allow_failure
+                        {
+                            if let Some(message) = precompile_rt.rt.host_assertion.take() {
+                                return Err(ExecutionError::HostAssertionFailed(message));
+                            }
                             return Err(ExecutionError::HaltWithNonZeroExitCode(
                                 precompile_rt.exit_code,
                             ));
@@ -972,6 +1255,15 @@ impl<'a> Executor<'a> {
                         return Err(ExecutionError::UnsupportedSyscall(syscall_id));
                     };
 
+                if let Some(memory_before) = memory_before {
+                    self.audit_syscall(syscall, syscall_id, b, c, a, memory_before);
+                }
+
+                // Writes commit public values, and add hints and hook results to the input.
+                if syscall == SyscallCode::WRITE {
+                    self.check_io_limits(pc)?;
+                }
+
                 // Allow the syscall impl to modify state.clk/pc (exit unconstrained does this)
                 clk = self.state.clk;
                 pc = self.state.pc;
@@ -983,16 +1275,52 @@ impl<'a> Executor<'a> {
 
                 // Update the syscall counts.
                 let syscall_for_count = syscall.count_map();
-                let syscall_count = self.state.syscall_counts.entry(syscall_for_count).or_insert(0);
+                let syscall_count = match syscall_for_count {
+                    // Each registered curve has its own chips, so their events are counted per
+                    // curve. The curve id is still in memory, before the point the syscall wrote.
+                    SyscallCode::REGISTERED_CURVE_ADD | SyscallCode::REGISTERED_CURVE_DOUBLE => {
+                        let curve_id = self.word(b);
+                        self.state
+                            .registered_curve_counts
+                            .entry((syscall_for_count, curve_id))
+                            .or_insert(0)
+                    }
+                    _ => self.state.syscall_counts.entry(syscall_for_count).or_insert(0),
+                };
                 let (threshold, multiplier) = match syscall_for_count {
                     SyscallCode::KECCAK_PERMUTE => (self.opts.split_opts.keccak, 24),
                     SyscallCode::SHA_EXTEND => (self.opts.split_opts.sha_extend, 48),
                     SyscallCode::SHA_COMPRESS => (self.opts.split_opts.sha_compress, 80),
+                    SyscallCode::X25519_SCALAR_MULT => {
+                        (self.opts.split_opts.deferred, X25519_LADDER_STEPS)
+                    }
                     _ => (self.opts.split_opts.deferred, 1),
                 };
-                let nonce = (((*syscall_count as usize) % threshold) * multiplier) as u32;
-                self.record.nonce_lookup.insert(syscall_lookup_id, nonce);
+                let index = (*syscall_count as usize) % threshold;
                 *syscall_count += 1;
+                let nonce = match syscall_for_count {
+                    // The chips of these syscalls take a variable number of rows per event, so the
+                    // nonce of an event is the number of rows of the events before it in its shard.
+                    SyscallCode::POSEIDON2_MERKLE_VERIFY | SyscallCode::AES_ENCRYPT_BLOCK => {
+                        let rows = if syscall_for_count == SyscallCode::POSEIDON2_MERKLE_VERIFY {
+                            // A row per level of the path, whose depth follows the index.
+                            self.word(c + 4)
+                        } else {
+                            // A row per round, and one to add the first round key.
+                            self.word(c) + 1
+                        };
+                        let shard_rows =
+                            self.state.syscall_rows.entry(syscall_for_count).or_insert(0);
+                        if index == 0 {
+                            *shard_rows = 0;
+                        }
+                        let nonce = *shard_rows;
+                        *shard_rows += rows;
+                        nonce
+                    }
+                    _ => (index * multiplier) as u32,
+                };
+                self.record.nonce_lookup.insert(syscall_lookup_id, nonce);
             }
             Opcode::EBREAK => {
                 return Err(ExecutionError::Breakpoint());
@@ -1067,6 +1395,7 @@ impl<'a> Executor<'a> {
 
         // Update the clk to the next cycle.
         self.state.clk += 4;
+        self.state.instret += 1;
 
         let channel = self.channel();
 
@@ -1100,7 +1429,17 @@ impl<'a> Executor<'a> {
     /// Executes one cycle of the program, returning whether the program has finished.
     #[inline]
     fn execute_cycle(&mut self) -> Result<bool, ExecutionError> {
-        // Fetch the instruction at the current program counter.
+        // Run the compiled block at the current program counter instead, if there is one.
+        #[cfg(feature = "jit")]
+        if let Some(done) = self.execute_jit_block() {
+            return Ok(done);
+        }
+
+        // Fetch the instruction at the current program counter, which an indirect jump may have
+        // left misaligned.
+        if self.state.pc % 4 != 0 {
+            return Err(ExecutionError::MisalignedPc(self.state.pc));
+        }
         let instruction = self.fetch();
 
         // Log the current state of the runtime.
@@ -1112,14 +1451,41 @@ impl<'a> Executor<'a> {
         // Increment the clock.
         self.state.global_clk += 1;
 
-        // If there's not enough cycles left for another instruction, move to the next shard.
-        // We multiply by 4 because clk is incremented by 4 for each normal instruction.
-        if !self.unconstrained && self.max_syscall_cycles + self.state.clk >= self.shard_size {
+        // If there's not enough cycles left for another instruction, or the shard has verified the
+        // maximum number of deferred proofs, move to the next shard. We multiply by 4 because clk
+        // is incremented by 4 for each normal instruction.
+        if !self.unconstrained
+            && (self.max_syscall_cycles + self.state.clk >= self.shard_size
+                || self.deferred_proofs_limit_reached())
+        {
             self.state.current_shard += 1;
             self.state.clk = 0;
+            self.state.instret = 0;
             self.state.channel = 0;
+            self.deferred_proofs_in_shard = 0;
 
             self.bump_record();
+
+            if self.cancel_flag.as_ref().is_some_and(|flag| flag.load(Ordering::Relaxed)) {
+                return Err(ExecutionError::Cancelled());
+            }
+        }
+
+        // Report the progress of the execution, outside of unconstrained blocks whose state is
+        // discarded.
+        if let Some(progress) = &self.progress_callback {
+            if !self.unconstrained && self.state.global_clk % progress.interval.get() == 0 {
+                let summary = ExecutionProgress {
+                    pc: self.state.pc,
+                    global_clk: self.state.global_clk,
+                    shard: self.state.current_shard,
+                    clk: self.state.clk,
+                    committed_bytes: self.state.public_values_stream.len(),
+                };
+                if (progress.callback)(summary) == ProgressControl::Abort {
+                    return Err(ExecutionError::Aborted(self.state.global_clk));
+                }
+            }
         }
 
         // If the cycle limit is exceeded, return an error.
@@ -1139,6 +1505,96 @@ impl<'a> Executor<'a> {
         Ok(done)
     }
 
+    /// Executes the compiled block at the current program counter, if it is hot, returning whether
+    /// the program has finished.
+    ///
+    /// The block only runs if none of the checks made after each cycle by [`Self::execute_cycle`]
+    /// would trigger within it, so that skipping them leaves the same state.
+    #[cfg(feature = "jit")]
+    fn execute_jit_block(&mut self) -> Option<bool> {
+        if self.executor_mode != ExecutorMode::Simple
+            || self.unconstrained
+            || self.trace_buf.is_some()
+            || self.profiler.is_some()
+            || self.state.pc % 4 != 0
+        {
+            return None;
+        }
+        let mut jit = self.jit.take()?;
+        let index = (self.state.pc.wrapping_sub(self.program.pc_base) / 4) as usize;
+        let done = jit.enter(&self.program, index).and_then(|block| self.run_jit_block(block));
+        self.jit = Some(jit);
+        done
+    }
+
+    #[cfg(feature = "jit")]
+    fn run_jit_block(&mut self, block: &crate::jit::CompiledBlock) -> Option<bool> {
+        let n = block.len() as u32;
+        let global_clk = self.state.global_clk;
+        let end_clk = global_clk + u64::from(n);
+        if self.max_syscall_cycles + self.state.clk + 4 * n >= self.shard_size
+            || self.deferred_proofs_limit_reached()
+            || self.max_cycles.is_some_and(|max_cycles| end_clk >= max_cycles)
+            || self.progress_callback.as_ref().is_some_and(|progress| {
+                end_clk / progress.interval.get() != global_clk / progress.interval.get()
+            })
+        {
+            return None;
+        }
+
+        let mut registers = [0u32; 32];
+        for &register in &block.reads {
+            registers[register as usize] = match self.state.memory.get(register) {
+                Some(record) => record.value,
+                None => *self.state.uninitialized_memory.get(&register).unwrap_or(&0),
+            };
+        }
+        (block.func)(registers.as_mut_ptr());
+
+        // The registers are timestamped with the last cycle of the block, which only differs from
+        // the interpreter for the registers written earlier in the block, and traces are never
+        // generated in this mode.
+        let shard = self.shard();
+        let timestamp = self.state.clk + 4 * (n - 1);
+        for &register in block.reads.iter().chain(&block.writes) {
+            let record = self.state.memory.entry(register).or_insert(MemoryRecord {
+                value: 0,
+                shard: 0,
+                timestamp: 0,
+            });
+            record.value = registers[register as usize];
+            record.shard = shard;
+            record.timestamp = timestamp;
+        }
+
+        if self.print_report {
+            let first = ((self.state.pc - self.program.pc_base) / 4) as usize;
+            for (i, instruction) in block.instructions.iter().enumerate() {
+                self.report.opcode_counts[instruction.opcode] += 1;
+                self.report.count_pc(first + i, self.program.instructions.len());
+            }
+        }
+
+        self.state.pc += 4 * n;
+        self.state.clk += 4 * n;
+        self.state.instret += n;
+        self.state.global_clk = end_clk;
+        self.state.channel =
+            ((u32::from(self.state.channel) + n) % u32::from(NUM_BYTE_LOOKUP_CHANNELS)) as u8;
+
+        Some(
+            self.state.pc.wrapping_sub(self.program.pc_base)
+                >= (self.program.instructions.len() * 4) as u32,
+        )
+    }
+
+    /// Whether the current shard has verified the maximum number of deferred proofs, which is only
+    /// limited if [`SP1CoreOpts::max_deferred_proofs_per_shard`] is not 0.
+    fn deferred_proofs_limit_reached(&self) -> bool {
+        let limit = self.opts.max_deferred_proofs_per_shard;
+        limit != 0 && self.deferred_proofs_in_shard >= limit
+    }
+
     /// Bump the record.
     pub fn bump_record(&mut self) {
         let removed_record =
@@ -1204,6 +1660,7 @@ impl<'a> Executor<'a> {
 
     fn initialize(&mut self) {
         self.state.clk = 0;
+        self.state.instret = 0;
         self.state.channel = 0;
 
         tracing::debug!("loading memory image");
@@ -1236,6 +1693,49 @@ impl<'a> Executor<'a> {
         Ok(())
     }
 
+    /// Executes the program without tracing until it yields to the host or halts, returning
+    /// whether the program halted.
+    ///
+    /// When the program yields, the host may read the public values committed so far from
+    /// `self.state.public_values_stream`, write more input with [`Self::write_stdin`], and call
+    /// this function again to resume the execution.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the program execution fails.
+    pub fn run_until_yield(&mut self) -> Result<bool, ExecutionError> {
+        self.executor_mode = ExecutorMode::Simple;
+        self.print_report = true;
+        self.pause_on_yield = true;
+        loop {
+            if self.execute()? {
+                return Ok(true);
+            }
+            if std::mem::take(&mut self.yielded) {
+                return Ok(false);
+            }
+        }
+    }
+
+    /// Checks the public values and the input against the limits of the context, blaming the
+    /// instruction at `pc` for exceeding them.
+    fn check_io_limits(&self, pc: u32) -> Result<(), ExecutionError> {
+        if let Some(max) = self.max_public_values_size {
+            let size = self.state.public_values_stream.len();
+            if size > max {
+                return Err(ExecutionError::PublicValuesTooLarge { size, max, pc });
+            }
+        }
+        if let Some(max) = self.max_input_size {
+            let size = self.state.input_stream.iter().map(Vec::len).sum::<usize>()
+                + self.state.input_streams.values().flatten().map(Vec::len).sum::<usize>();
+            if size > max {
+                return Err(ExecutionError::InputTooLarge { size, max, pc });
+            }
+        }
+        Ok(())
+    }
+
     /// Executes up to `self.shard_batch_size` cycles of the program, returning whether the program
     /// has finished.
     fn execute(&mut self) -> Result<bool, ExecutionError> {
@@ -1248,6 +1748,7 @@ impl<'a> Executor<'a> {
         // If it's the first cycle, initialize the program.
         if self.state.global_clk == 0 {
             self.initialize();
+            self.check_io_limits(self.state.pc)?;
         }
 
         // Loop until we've executed `self.shard_batch_size` shards if `self.shard_batch_size` is
@@ -1261,6 +1762,10 @@ impl<'a> Executor<'a> {
                 break;
             }
 
+            if self.pause_on_yield && self.yielded {
+                break;
+            }
+
             if self.shard_batch_size > 0 && current_shard != self.state.current_shard {
                 num_shards_executed += 1;
                 current_shard = self.state.current_shard;
@@ -1330,6 +1835,12 @@ impl<'a> Executor<'a> {
         if let Some(ref mut buf) = self.trace_buf {
             buf.flush().unwrap();
         }
+        if let Some(audit) = &mut self.syscall_audit {
+            audit.flush();
+        }
+        if let Some(profiler) = &mut self.profiler {
+            profiler.write().expect("failed to write the profile");
+        }
 
         // Ensure that all proofs and input bytes were read, otherwise warn the user.
         // if self.state.proof_stream_ptr != self.state.proof_stream.len() {
@@ -1387,12 +1898,41 @@ impl<'a> Executor<'a> {
         }
     }
 
+    /// Record a syscall to the audit log, once it has been executed.
+    fn audit_syscall(
+        &mut self,
+        syscall: SyscallCode,
+        syscall_id: u32,
+        arg1: u32,
+        arg2: u32,
+        result: u32,
+        memory_before: Vec<MemoryDump>,
+    ) {
+        let Some(audit) = &self.syscall_audit else {
+            return;
+        };
+        let entry = SyscallAuditEntry {
+            shard: self.shard(),
+            clk: self.state.clk,
+            pc: self.state.pc,
+            syscall: syscall.to_string(),
+            syscall_id,
+            arg1,
+            arg2,
+            result,
+            unconstrained: self.unconstrained,
+            memory_before,
+            memory_after: audit.dump(self, syscall, arg1, arg2),
+        };
+        self.syscall_audit.as_mut().unwrap().record(&entry);
+    }
+
     fn get_syscall(&mut self, code: SyscallCode) -> Option<&Arc<dyn Syscall>> {
         self.syscall_map.get(&code)
     }
 
     #[inline]
-    fn log(&mut self, _: &Instruction) {
+    fn log(&mut self, instruction: &Instruction) {
         // Write the current program counter to the trace buffer for the cycle tracer.
         if let Some(ref mut buf) = self.trace_buf {
             if !self.unconstrained {
@@ -1400,6 +1940,13 @@ impl<'a> Executor<'a> {
             }
         }
 
+        // Sample the call stack for the profiler.
+        if let Some(profiler) = &mut self.profiler {
+            if !self.unconstrained {
+                profiler.record(self.state.global_clk, self.state.pc, instruction);
+            }
+        }
+
         if !self.unconstrained && self.state.global_clk % 10_000_000 == 0 {
             log::info!("clk = {} pc = 0x{:x?}", self.state.global_clk, self.state.pc);
         }
@@ -1429,13 +1976,25 @@ mod tests {
     use sp1_stark::SP1CoreOpts;
 
     use crate::programs::tests::{
-        fibonacci_program, panic_program, simple_memory_program, simple_program,
-        ssz_withdrawals_program,
+        fibonacci_program, misaligned_memory_program, panic_program, simple_memory_program,
+        simple_program, ssz_withdrawals_program,
     };
 
-    use crate::Register;
+    use std::{
+        num::NonZeroU64,
+        sync::{
+            atomic::{AtomicBool, AtomicU64, Ordering},
+            Arc,
+        },
+    };
 
-    use super::{Executor, Instruction, Opcode, Program};
+    use crate::{
+        syscalls::SyscallCode, HeapStats, OpcodeClass, ProgressControl, Register, SP1Context,
+        FD_HOST_ASSERT,
+    };
+
+    use super::{ExecutionError, Executor, Instruction, Opcode, Program};
+    use crate::disassembler::transpile;
 
     fn _assert_send<T: Send>() {}
 
@@ -1452,6 +2011,42 @@ mod tests {
         assert_eq!(runtime.register(Register::X31), 42);
     }
 
+    #[test]
+    fn test_report_pc_counts() {
+        // Skip the middle instruction with a jump.
+        let instructions = vec![
+            Instruction::new(Opcode::JAL, 0, 8, 0, true, true),
+            Instruction::new(Opcode::ADD, 29, 0, 5, false, true),
+            Instruction::new(Opcode::ADD, 30, 0, 37, false, true),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        assert!(runtime.report.pc_counts.is_empty());
+        runtime.run().unwrap();
+        assert_eq!(runtime.report.pc_counts, vec![1, 0, 1]);
+        assert_eq!(runtime.report.pc_count(8), 1);
+        assert_eq!(runtime.report.pc_count(12), 0);
+    }
+
+    #[test]
+    fn test_report_opcode_class_counts() {
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 29, 0, 5, false, true),
+            Instruction::new(Opcode::SW, 29, 0, 0x100, false, true),
+            Instruction::new(Opcode::LW, 30, 0, 0x100, false, true),
+            Instruction::new(Opcode::BEQ, 29, 30, 4, false, true),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+        let counts = runtime.report.opcode_class_counts();
+        assert_eq!(counts[OpcodeClass::Alu], 1);
+        assert_eq!(counts[OpcodeClass::Memory], 2);
+        assert_eq!(counts[OpcodeClass::Branch], 1);
+        assert_eq!(counts[OpcodeClass::Jump], 0);
+        assert_eq!(counts.values().sum::<u64>(), runtime.report.total_instruction_count());
+    }
+
     #[test]
     fn test_fibonacci_program_run() {
         let program = fibonacci_program();
@@ -1474,6 +2069,309 @@ mod tests {
         runtime.run().unwrap();
     }
 
+    #[test]
+    fn test_panic_allow_failure() {
+        let context = SP1Context::builder().allow_failure().build();
+        let mut runtime = Executor::with_context(panic_program(), SP1CoreOpts::default(), context);
+        runtime.run().unwrap();
+        assert_eq!(runtime.records.last().unwrap().public_values.exit_code, 1);
+        assert_eq!(runtime.state.pc, 0);
+    }
+
+    #[test]
+    fn test_adversarial_program() {
+        // An unknown syscall code fails the execution instead of panicking.
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 5, 0, 0xdead, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        let mut runtime = Executor::new(Program::new(instructions, 0, 0), SP1CoreOpts::default());
+        assert!(matches!(runtime.run(), Err(ExecutionError::UnsupportedSyscall(0xdead))));
+
+        // So does an indirect jump to a misaligned address inside the program.
+        let instructions = vec![
+            Instruction::new(Opcode::JALR, 1, 0, 6, false, true),
+            Instruction::new(Opcode::ADD, 29, 0, 5, false, true),
+            Instruction::new(Opcode::ADD, 30, 0, 37, false, true),
+        ];
+        let mut runtime = Executor::new(Program::new(instructions, 0, 0), SP1CoreOpts::default());
+        assert!(matches!(runtime.run(), Err(ExecutionError::MisalignedPc(6))));
+    }
+
+    #[test]
+    fn test_host_assertion() {
+        // The program reports a failed assertion with the message "A" and halts with exit code 1.
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 6, 0, 0x41, false, true),
+            Instruction::new(Opcode::ADD, 7, 0, 0x10_0000, false, true),
+            Instruction::new(Opcode::SW, 6, 7, 0, false, true),
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::WRITE as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, FD_HOST_ASSERT, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 0x10_0000, false, true),
+            Instruction::new(Opcode::ADD, 12, 0, 1, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::HALT as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 1, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        let program = Program::new(instructions, 0, 0);
+
+        // Executing the program reports the assertion.
+        let mut runtime = Executor::new(program.clone(), SP1CoreOpts::default());
+        assert!(matches!(
+            runtime.run_fast(),
+            Err(ExecutionError::HostAssertionFailed(message)) if message == "A"
+        ));
+
+        // Tracing it for a proof only sees the non-zero exit code.
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        assert!(matches!(runtime.run(), Err(ExecutionError::HaltWithNonZeroExitCode(1))));
+    }
+
+    #[test]
+    fn test_io_limits() {
+        // The program commits the 8 bytes at 0x100000 to the public values.
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::WRITE as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 3, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 0x10_0000, false, true),
+            Instruction::new(Opcode::ADD, 12, 0, 8, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        let program = Program::new(instructions, 0, 0);
+
+        let context = SP1Context::builder().max_public_values_size(8).build();
+        let mut runtime = Executor::with_context(program.clone(), SP1CoreOpts::default(), context);
+        runtime.run_fast().unwrap();
+
+        let context = SP1Context::builder().max_public_values_size(4).build();
+        let mut runtime = Executor::with_context(program.clone(), SP1CoreOpts::default(), context);
+        assert!(matches!(
+            runtime.run_fast(),
+            Err(ExecutionError::PublicValuesTooLarge { size: 8, max: 4, pc: 16 })
+        ));
+
+        let context = SP1Context::builder().max_input_size(4).build();
+        let mut runtime = Executor::with_context(program, SP1CoreOpts::default(), context);
+        runtime.write_stdin_slice(&[0; 5]);
+        assert!(matches!(
+            runtime.run_fast(),
+            Err(ExecutionError::InputTooLarge { size: 5, max: 4, pc: 0 })
+        ));
+    }
+
+    #[cfg(feature = "jit")]
+    #[test]
+    fn test_jit_matches_interpreter() {
+        let program = ssz_withdrawals_program();
+        let mut interpreter = Executor::new(program.clone(), SP1CoreOpts::default());
+        interpreter.run_fast().unwrap();
+
+        let context = SP1Context::builder().jit().build();
+        let mut runtime = Executor::with_context(program, SP1CoreOpts::default(), context);
+        runtime.run_fast().unwrap();
+
+        assert_eq!(runtime.state.global_clk, interpreter.state.global_clk);
+        assert_eq!(runtime.state.current_shard, interpreter.state.current_shard);
+        assert_eq!(runtime.report.opcode_counts, interpreter.report.opcode_counts);
+        assert_eq!(runtime.report.pc_counts, interpreter.report.pc_counts);
+        for register in 0..32 {
+            let value =
+                |executor: &Executor| executor.state.memory.get(register).map_or(0, |r| r.value);
+            assert_eq!(value(&runtime), value(&interpreter), "x{register}");
+        }
+    }
+
+    #[test]
+    fn test_stack_overflow() {
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 2, 0, 0x100, false, true),
+            Instruction::new(Opcode::SW, 10, 2, -4i32 as u32, false, true),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        assert!(matches!(runtime.run(), Err(ExecutionError::StackOverflow { pc: 4, sp: 0x100 })));
+    }
+
+    #[test]
+    fn test_cancelled() {
+        let program = fibonacci_program();
+        let mut opts = SP1CoreOpts::default();
+        opts.shard_size = 1 << 10;
+        let context = SP1Context::builder().cancel_flag(Arc::new(AtomicBool::new(true))).build();
+        let mut runtime = Executor::with_context(program, opts, context);
+        assert!(matches!(runtime.run(), Err(ExecutionError::Cancelled())));
+    }
+
+    #[test]
+    fn test_progress_callback() {
+        let program = fibonacci_program();
+        let ticks = Arc::new(AtomicU64::new(0));
+        let counter = ticks.clone();
+        let context = SP1Context::builder()
+            .progress_callback(NonZeroU64::new(100).unwrap(), move |progress| {
+                assert_eq!(progress.global_clk % 100, 0);
+                counter.fetch_add(1, Ordering::Relaxed);
+                ProgressControl::Continue
+            })
+            .build();
+        let mut runtime = Executor::with_context(program, SP1CoreOpts::default(), context);
+        runtime.run().unwrap();
+        assert_eq!(ticks.load(Ordering::Relaxed), runtime.state.global_clk / 100);
+
+        let context = SP1Context::builder()
+            .progress_callback(NonZeroU64::new(100).unwrap(), |progress| {
+                if progress.global_clk >= 300 {
+                    ProgressControl::Abort
+                } else {
+                    ProgressControl::Continue
+                }
+            })
+            .build();
+        let mut runtime =
+            Executor::with_context(fibonacci_program(), SP1CoreOpts::default(), context);
+        assert!(matches!(runtime.run(), Err(ExecutionError::Aborted(300))));
+    }
+
+    #[test]
+    fn test_low_address_with_valid_stack() {
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 2, 0, 0x0020_0400, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 7, false, true),
+            Instruction::new(Opcode::SW, 10, 0, 0x100, false, true),
+            Instruction::new(Opcode::LW, 11, 0, 0x100, false, true),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+        assert_eq!(runtime.register(Register::X11), 7);
+    }
+
+    #[test]
+    fn test_not_enough_deferred_proofs() {
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::VERIFY_SP1_PROOF as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 100, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 200, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        assert!(matches!(
+            runtime.run(),
+            Err(ExecutionError::NotEnoughDeferredProofs { index: 0, provided: 0 })
+        ));
+    }
+
+    #[test]
+    fn test_misaligned_memory_emulated() {
+        let mut runtime = Executor::new(misaligned_memory_program(), SP1CoreOpts::default());
+        runtime.run().unwrap();
+        assert_eq!(runtime.register(Register::X28), 0x12345678);
+        assert_eq!(runtime.register(Register::X27), 0x1234);
+        assert_eq!(runtime.register(Register::X26), 0x5678);
+        assert_eq!(runtime.register(Register::X25), 0xBEEF);
+        assert_eq!(runtime.register(Register::X24), 0xFFFF_BEEF);
+        assert_eq!(runtime.register(Register::X23), 0x3456_7800);
+        assert_eq!(runtime.register(Register::X22), 0xEF00_0012);
+        assert_eq!(runtime.register(Register::X21), 0x0000_00BE);
+    }
+
+    fn yield_program() -> Program {
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::YIELD as u32, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            Instruction::new(Opcode::ADD, 29, 0, 5, false, true),
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::YIELD as u32, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            Instruction::new(Opcode::ADD, 30, 0, 37, false, true),
+        ];
+        Program::new(instructions, 0, 0)
+    }
+
+    #[test]
+    fn test_run_until_yield() {
+        let mut runtime = Executor::new(yield_program(), SP1CoreOpts::default());
+        assert!(!runtime.run_until_yield().unwrap());
+        assert_eq!(runtime.register(Register::X29), 0);
+        assert!(!runtime.run_until_yield().unwrap());
+        assert_eq!(runtime.register(Register::X29), 5);
+        assert_eq!(runtime.register(Register::X30), 0);
+        assert!(runtime.run_until_yield().unwrap());
+        assert_eq!(runtime.register(Register::X30), 37);
+    }
+
+    #[test]
+    fn test_run_ignores_yield() {
+        let mut runtime = Executor::new(yield_program(), SP1CoreOpts::default());
+        runtime.run().unwrap();
+        assert_eq!(runtime.register(Register::X29), 5);
+        assert_eq!(runtime.register(Register::X30), 37);
+    }
+
+    #[test]
+    fn test_counter_csrs() {
+        // rdcycle x10; rdinstret x11; rdcycleh x12; rdinstreth x13
+        let instructions = transpile(&[0xC000_2573, 0xC020_25F3, 0xC800_2673, 0xC820_26F3]);
+        assert_eq!(instructions[0].opcode, Opcode::RDCYCLE);
+        assert_eq!(instructions[1].opcode, Opcode::RDINSTRET);
+
+        let mut instructions =
+            [vec![Instruction::new(Opcode::ADD, 29, 0, 5, false, true); 3], instructions].concat();
+        instructions.push(Instruction::new(Opcode::ADD, 12, 0, 1, false, true));
+        instructions.push(Instruction::new(Opcode::ADD, 13, 0, 1, false, true));
+        instructions.extend(transpile(&[0xC800_2673, 0xC820_26F3]));
+        let program = Program::new(instructions, 0, 0);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+        assert_eq!(runtime.register(Register::X10), 12);
+        assert_eq!(runtime.register(Register::X11), 4);
+        assert_eq!(runtime.register(Register::X12), 0);
+        assert_eq!(runtime.register(Register::X13), 0);
+    }
+
+    #[test]
+    fn test_counter_csrs_reset_each_shard() {
+        let mut instructions = vec![Instruction::new(Opcode::ADD, 29, 0, 5, false, true); 20];
+        instructions.push(Instruction::new(Opcode::RDCYCLE, 10, 0, 0, true, true));
+        instructions.push(Instruction::new(Opcode::RDINSTRET, 11, 0, 0, true, true));
+        let program = Program::new(instructions, 0, 0);
+        let mut opts = SP1CoreOpts::default();
+        opts.shard_size = 8;
+        let mut runtime = Executor::new(program, opts);
+        runtime.max_syscall_cycles = 0;
+        runtime.run().unwrap();
+        // The shards hold 8 instructions each, so the reads are the 5th and 6th of the third one.
+        assert_eq!(runtime.register(Register::X10), 16);
+        assert_eq!(runtime.register(Register::X11), 5);
+    }
+
+    #[test]
+    fn test_heap_stats() {
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 29, 0, 0x2000, false, true),
+            Instruction::new(Opcode::SW, 29, 0, 0x100, false, true),
+            Instruction::new(Opcode::ADD, 29, 0, 0x3000, false, true),
+            Instruction::new(Opcode::SW, 29, 0, 0x104, false, true),
+            Instruction::new(Opcode::ADD, 29, 0, 12, false, true),
+            Instruction::new(Opcode::SW, 29, 0, 0x108, false, true),
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::HEAP_STATS as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 0x100, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+        assert_eq!(
+            runtime.report.heap_stats,
+            Some(HeapStats {
+                peak_heap_bytes: 0x2000,
+                total_allocated_bytes: 0x3000,
+                allocation_count: 12,
+            })
+        );
+    }
+
     #[test]
     fn test_add() {
         // main:
@@ -1,18 +1,49 @@
 use core::fmt::Debug;
 
-use std::sync::{Arc, RwLock, RwLockWriteGuard};
+use std::{
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, RwLock, RwLockWriteGuard,
+    },
+    thread,
+    time::Duration,
+};
 
 use hashbrown::HashMap;
 use sp1_curves::k256::{Invert, RecoveryId, Signature, VerifyingKey};
 
-use crate::Executor;
+use crate::{
+    memo::{hook_memo_drop, hook_memo_miss, FD_MEMO_LOOKUP, FD_MEMO_STORE},
+    Executor,
+};
 
 /// A runtime hook, wrapped in a smart pointer.
 pub type BoxedHook<'a> = Arc<RwLock<dyn Hook + Send + Sync + 'a>>;
 
+/// An asynchronous runtime hook, wrapped in a smart pointer.
+pub type BoxedAsyncHook = Arc<dyn Fn(&[u8]) -> Vec<u8> + Send + Sync>;
+
 /// The file descriptor through which to access `hook_ecrecover`.
 pub const FD_ECRECOVER_HOOK: u32 = 5;
 
+/// The file descriptor through which the program requests a hint from an asynchronous hook.
+///
+/// The request is the handle of the hint and the file descriptor of the hook, as little-endian
+/// `u32`s, followed by the key passed to the hook.
+pub const FD_HINT_REQUEST: u32 = 6;
+
+/// The file descriptor through which the program awaits a hint it requested, given its handle as
+/// a little-endian `u32`.
+pub const FD_HINT_AWAIT: u32 = 7;
+
+/// The file descriptor through which the program reports the location and message of a failed
+/// `assert_host!` before it panics.
+pub const FD_HOST_ASSERT: u32 = 10;
+
+/// The file descriptor through which the program reads from a numbered input stream, given its id
+/// as a little-endian `u32`. The next entry of the stream is added to the input stream.
+pub const FD_STREAM_READ: u32 = 11;
+
 /// A runtime hook. May be called during execution by writing to a specified file descriptor,
 /// accepting and returning arbitrary data.
 pub trait Hook {
@@ -37,12 +68,20 @@ pub fn hookify<'a>(
     Arc::new(RwLock::new(f))
 }
 
+/// Wrap a function in a smart pointer so it may be registered as an asynchronous hook.
+pub fn async_hookify(f: impl Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static) -> BoxedAsyncHook {
+    Arc::new(f)
+}
+
 /// A registry of hooks to call, indexed by the file descriptors through which they are accessed.
 #[derive(Clone)]
 pub struct HookRegistry<'a> {
     /// Table of registered hooks. Prefer using `Runtime::hook`, ` Runtime::hook_env`,
     /// and `HookRegistry::get` over interacting with this field directly.
     pub(crate) table: HashMap<u32, BoxedHook<'a>>,
+    /// Table of registered asynchronous hooks, which run on a separate thread while the program
+    /// keeps executing.
+    pub(crate) async_table: HashMap<u32, BoxedAsyncHook>,
 }
 
 impl<'a> HookRegistry<'a> {
@@ -55,7 +94,7 @@ impl<'a> HookRegistry<'a> {
     /// Create an empty [`HookRegistry`].
     #[must_use]
     pub fn empty() -> Self {
-        Self { table: HashMap::default() }
+        Self { table: HashMap::default(), async_table: HashMap::default() }
     }
 
     /// Get a hook with exclusive write access, if it exists.
@@ -67,6 +106,29 @@ impl<'a> HookRegistry<'a> {
         // Calling `.unwrap()` panics on a poisoned lock. Should never happen normally.
         self.table.get(&fd).map(|x| x.write().unwrap())
     }
+
+    /// Get an asynchronous hook, if it exists.
+    #[must_use]
+    pub fn get_async(&self, fd: u32) -> Option<BoxedAsyncHook> {
+        self.async_table.get(&fd).cloned()
+    }
+
+    /// Delays every call of the asynchronous hooks by a varying duration of at most `max_delay`,
+    /// which changes the order in which concurrent requests complete.
+    ///
+    /// The delays follow a fixed sequence per hook, so that the schedule differs from an
+    /// undelayed run without making the perturbation itself random.
+    pub fn jitter_async_hooks(&mut self, max_delay: Duration) {
+        for hook in self.async_table.values_mut() {
+            let inner = hook.clone();
+            let calls = AtomicU32::new(0);
+            *hook = Arc::new(move |key: &[u8]| {
+                let call = calls.fetch_add(1, Ordering::Relaxed);
+                thread::sleep(max_delay * (call.wrapping_mul(7) % 16) / 15);
+                inner(key)
+            });
+        }
+    }
 }
 
 impl<'a> Default for HookRegistry<'a> {
@@ -76,9 +138,12 @@ impl<'a> Default for HookRegistry<'a> {
             // Note: To ensure any `fd` value is synced with `zkvm/precompiles/src/io.rs`,
             // add an assertion to the test `hook_fds_match` below.
             (FD_ECRECOVER_HOOK, hookify(hook_ecrecover)),
+            // Programs looking up memoized values without a memo table always miss.
+            (FD_MEMO_LOOKUP, hookify(hook_memo_miss)),
+            (FD_MEMO_STORE, hookify(hook_memo_drop)),
         ]);
 
-        Self { table }
+        Self { table, async_table: HashMap::default() }
     }
 }
 
@@ -86,11 +151,21 @@ impl<'a> Debug for HookRegistry<'a> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut keys = self.table.keys().collect::<Vec<_>>();
         keys.sort_unstable();
+        let mut async_keys = self.async_table.keys().collect::<Vec<_>>();
+        async_keys.sort_unstable();
         f.debug_struct("HookRegistry")
             .field(
                 "table",
                 &format_args!("{{{} hooks registered at {:?}}}", self.table.len(), keys),
             )
+            .field(
+                "async_table",
+                &format_args!(
+                    "{{{} hooks registered at {:?}}}",
+                    self.async_table.len(),
+                    async_keys
+                ),
+            )
             .finish()
     }
 }
@@ -149,6 +224,11 @@ pub mod tests {
     pub fn hook_fds_match() {
         use sp1_zkvm::lib::io;
         assert_eq!(FD_ECRECOVER_HOOK, io::FD_ECRECOVER_HOOK);
+        assert_eq!(FD_HINT_REQUEST, io::FD_HINT_REQUEST);
+        assert_eq!(FD_HINT_AWAIT, io::FD_HINT_AWAIT);
+        assert_eq!(FD_MEMO_LOOKUP, io::FD_MEMO_LOOKUP);
+        assert_eq!(FD_MEMO_STORE, io::FD_MEMO_STORE);
+        assert_eq!(FD_STREAM_READ, io::FD_STREAM_READ);
     }
 
     #[test]
@@ -161,4 +241,15 @@ pub mod tests {
     pub fn registry_empty_is_empty() {
         assert_eq!(HookRegistry::empty().table.len(), 0);
     }
+
+    #[test]
+    pub fn jittered_async_hooks_keep_their_output() {
+        let mut registry = HookRegistry::empty();
+        registry.async_table.insert(7, async_hookify(|key| key.iter().rev().copied().collect()));
+        registry.jitter_async_hooks(Duration::from_millis(2));
+        let hook = registry.get_async(7).unwrap();
+        for _ in 0..3 {
+            assert_eq!(hook(&[1, 2, 3]), vec![3, 2, 1]);
+        }
+    }
 }
@@ -97,6 +97,12 @@ impl Instruction {
         )
     }
 
+    /// Returns if the instruction reads a counter CSR.
+    #[must_use]
+    pub const fn is_counter_instruction(&self) -> bool {
+        matches!(self.opcode, Opcode::RDCYCLE | Opcode::RDINSTRET)
+    }
+
     /// Returns if the instruction is a jump instruction.
     #[must_use]
     pub const fn is_jump_instruction(&self) -> bool {
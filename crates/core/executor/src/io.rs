@@ -32,6 +32,16 @@ impl<'a> Executor<'a> {
         }
     }
 
+    /// Write a slice of vecs to the numbered input stream `stream_id`, which the program reads
+    /// with `sp1_zkvm::io::read_from`. The stream 0 is the standard input stream.
+    pub fn write_stream(&mut self, stream_id: u32, inputs: &[Vec<u8>]) {
+        if stream_id == 0 {
+            self.write_vecs(inputs);
+        } else {
+            self.state.input_streams.entry(stream_id).or_default().extend(inputs.iter().cloned());
+        }
+    }
+
     /// Write a proof and verifying key to the proof stream.
     pub fn write_proof(
         &mut self,
@@ -0,0 +1,368 @@
+//! A just-in-time compiler of hot guest blocks to native code, for execution without proving.
+//!
+//! The interpreter decodes, dispatches and reads its register operands from the memory map on
+//! every cycle. When a program is only executed, for example to estimate its cycle count, the
+//! straight-line runs of ALU instructions that dominate most programs can instead be compiled
+//! with cranelift into native functions over a register file, once they have been entered
+//! [`JIT_HOT_THRESHOLD`] times. A compiled block runs all its instructions in a single call, and
+//! the executor then advances the clocks, the program counter and the report by the length of the
+//! block at once.
+//!
+//! Only ALU instructions are compiled, so that a block never touches memory, traps or makes a
+//! syscall. The executor only runs a compiled block in [`ExecutorMode::Simple`](crate::ExecutorMode),
+//! outside of unconstrained mode, and when the block does not cross a shard boundary, a progress
+//! report or the cycle limit, so that every check of the interpreter sees the same state. The
+//! timestamps of the registers accessed by a block are set to its last cycle, which only matters
+//! for traces.
+
+use cranelift_codegen::{
+    ir::{condcodes::IntCC, types, AbiParam, InstBuilder, MemFlags, Value},
+    settings::{self, Configurable},
+};
+use cranelift_frontend::{FunctionBuilder, FunctionBuilderContext};
+use cranelift_jit::{JITBuilder, JITModule};
+use cranelift_module::{default_libcall_names, Module};
+
+use crate::{Instruction, Opcode, Program};
+
+/// The number of times a block is entered before it is compiled.
+pub const JIT_HOT_THRESHOLD: u32 = 16;
+
+/// The minimum number of instructions of a compiled block, below which the interpreter is faster
+/// than the handoff of the registers.
+pub const JIT_MIN_BLOCK_LEN: usize = 4;
+
+/// The maximum number of instructions of a compiled block.
+pub const JIT_MAX_BLOCK_LEN: usize = 256;
+
+/// A block of ALU instructions compiled to a native function.
+pub struct CompiledBlock {
+    /// The native function, which runs the block over a register file of 32 words.
+    pub func: extern "C" fn(*mut u32),
+    /// The instructions of the block.
+    pub instructions: Vec<Instruction>,
+    /// The registers the block reads before writing them.
+    pub reads: Vec<u32>,
+    /// The registers the block writes, other than `x0`.
+    pub writes: Vec<u32>,
+}
+
+impl CompiledBlock {
+    /// The number of instructions of the block.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.instructions.len()
+    }
+
+    /// Returns whether the block has no instructions, which never happens for a compiled block.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.instructions.is_empty()
+    }
+}
+
+/// The state of the block starting at an instruction.
+enum Slot {
+    /// The block has been entered this many times.
+    Cold(u32),
+    /// The block is compiled.
+    Compiled(Box<CompiledBlock>),
+    /// The block is too short or could not be compiled.
+    Rejected,
+}
+
+/// The compiled blocks of a program, indexed by the instruction they start at.
+pub struct JitCache {
+    module: JITModule,
+    slots: Vec<Slot>,
+}
+
+// SAFETY: the module owns the memory of the compiled functions, which are only called by the
+// executor that owns the cache, one at a time.
+unsafe impl Send for JitCache {}
+
+impl JitCache {
+    /// Creates an empty cache for a program, compiling for the host.
+    pub fn new(program: &Program) -> Result<Self, String> {
+        let mut flags = settings::builder();
+        flags.set("opt_level", "speed").map_err(|err| err.to_string())?;
+        let isa = cranelift_native::builder()
+            .map_err(str::to_string)?
+            .finish(settings::Flags::new(flags))
+            .map_err(|err| err.to_string())?;
+        let module = JITModule::new(JITBuilder::with_isa(isa, default_libcall_names()));
+        let slots = program.instructions.iter().map(|_| Slot::Cold(0)).collect();
+        Ok(Self { module, slots })
+    }
+
+    /// Records an entry into the block starting at instruction `index`, and returns the compiled
+    /// block if it is hot.
+    pub fn enter(&mut self, program: &Program, index: usize) -> Option<&CompiledBlock> {
+        let slot = self.slots.get_mut(index)?;
+        if let Slot::Cold(hits) = slot {
+            *hits += 1;
+            if *hits < JIT_HOT_THRESHOLD {
+                return None;
+            }
+            *slot = match self.compile(&program.instructions[index..]) {
+                Some(block) => Slot::Compiled(Box::new(block)),
+                None => Slot::Rejected,
+            };
+        }
+        match &self.slots[index] {
+            Slot::Compiled(block) => Some(block),
+            _ => None,
+        }
+    }
+
+    /// Compiles the longest run of ALU instructions at the start of `instructions`, if it is long
+    /// enough.
+    fn compile(&mut self, instructions: &[Instruction]) -> Option<CompiledBlock> {
+        let instructions = instructions
+            .iter()
+            .take(JIT_MAX_BLOCK_LEN)
+            .take_while(|instruction| instruction.is_alu_instruction())
+            .copied()
+            .collect::<Vec<_>>();
+        if instructions.len() < JIT_MIN_BLOCK_LEN {
+            return None;
+        }
+        match self.translate(&instructions) {
+            Ok((func, reads, writes)) => Some(CompiledBlock { func, instructions, reads, writes }),
+            Err(err) => {
+                tracing::warn!(
+                    "failed to compile a block of {} instructions: {err}",
+                    instructions.len()
+                );
+                None
+            }
+        }
+    }
+
+    /// Translates the instructions to a native function over the register file.
+    #[allow(clippy::type_complexity)]
+    fn translate(
+        &mut self,
+        instructions: &[Instruction],
+    ) -> Result<(extern "C" fn(*mut u32), Vec<u32>, Vec<u32>), String> {
+        let mut ctx = self.module.make_context();
+        let pointer = self.module.target_config().pointer_type();
+        ctx.func.signature.params.push(AbiParam::new(pointer));
+
+        let mut builder_ctx = FunctionBuilderContext::new();
+        let mut builder = FunctionBuilder::new(&mut ctx.func, &mut builder_ctx);
+        let entry = builder.create_block();
+        builder.append_block_params_for_function_params(entry);
+        builder.switch_to_block(entry);
+        builder.seal_block(entry);
+        let file = builder.block_params(entry)[0];
+
+        let mut translator =
+            Translator { builder, file, values: [None; 32], reads: Vec::new(), writes: Vec::new() };
+        for instruction in instructions {
+            translator.instruction(instruction);
+        }
+        let Translator { mut builder, reads, writes, values, .. } = translator;
+        for &register in &writes {
+            let value = values[register as usize].unwrap();
+            builder.ins().store(MemFlags::trusted(), value, file, 4 * register as i32);
+        }
+        builder.ins().return_(&[]);
+        builder.finalize();
+
+        let id = self
+            .module
+            .declare_anonymous_function(&ctx.func.signature)
+            .map_err(|err| err.to_string())?;
+        self.module.define_function(id, &mut ctx).map_err(|err| err.to_string())?;
+        self.module.clear_context(&mut ctx);
+        self.module.finalize_definitions().map_err(|err| err.to_string())?;
+        let code = self.module.get_finalized_function(id);
+        // SAFETY: the function was declared with a single pointer parameter and no return value.
+        let func = unsafe { std::mem::transmute::<*const u8, extern "C" fn(*mut u32)>(code) };
+        Ok((func, reads, writes))
+    }
+}
+
+/// The translation of a block, which keeps the registers in SSA values and only loads them from
+/// the register file on their first read.
+struct Translator<'a> {
+    builder: FunctionBuilder<'a>,
+    file: Value,
+    values: [Option<Value>; 32],
+    reads: Vec<u32>,
+    writes: Vec<u32>,
+}
+
+impl Translator<'_> {
+    fn constant(&mut self, value: u32) -> Value {
+        self.builder.ins().iconst(types::I32, i64::from(value as i32))
+    }
+
+    fn read(&mut self, register: u32) -> Value {
+        if register == 0 {
+            return self.constant(0);
+        }
+        if let Some(value) = self.values[register as usize] {
+            return value;
+        }
+        let value = self.builder.ins().load(
+            types::I32,
+            MemFlags::trusted(),
+            self.file,
+            4 * register as i32,
+        );
+        self.values[register as usize] = Some(value);
+        self.reads.push(register);
+        value
+    }
+
+    fn write(&mut self, register: u32, value: Value) {
+        if register == 0 {
+            return;
+        }
+        if !self.writes.contains(&register) {
+            self.writes.push(register);
+        }
+        self.values[register as usize] = Some(value);
+    }
+
+    /// Translates an instruction, with the operands decoded like `Executor::alu_rr`.
+    fn instruction(&mut self, instruction: &Instruction) {
+        let b = if instruction.imm_b {
+            self.constant(instruction.op_b)
+        } else {
+            self.read(instruction.op_b)
+        };
+        let c = if instruction.imm_c {
+            self.constant(instruction.op_c)
+        } else {
+            self.read(instruction.op_c)
+        };
+        let a = self.alu(instruction.opcode, b, c);
+        self.write(instruction.op_a, a);
+    }
+
+    fn alu(&mut self, opcode: Opcode, b: Value, c: Value) -> Value {
+        let ins = self.builder.ins();
+        match opcode {
+            Opcode::ADD => ins.iadd(b, c),
+            Opcode::SUB => ins.isub(b, c),
+            Opcode::XOR => ins.bxor(b, c),
+            Opcode::OR => ins.bor(b, c),
+            Opcode::AND => ins.band(b, c),
+            // Cranelift masks the shift amount to the width of the type, like the interpreter.
+            Opcode::SLL => ins.ishl(b, c),
+            Opcode::SRL => ins.ushr(b, c),
+            Opcode::SRA => ins.sshr(b, c),
+            Opcode::SLT => self.compare(IntCC::SignedLessThan, b, c),
+            Opcode::SLTU => self.compare(IntCC::UnsignedLessThan, b, c),
+            Opcode::MUL => ins.imul(b, c),
+            Opcode::MULH => ins.smulhi(b, c),
+            Opcode::MULHU => ins.umulhi(b, c),
+            Opcode::MULHSU => {
+                let b = ins.sextend(types::I64, b);
+                let c = self.builder.ins().uextend(types::I64, c);
+                let product = self.builder.ins().imul(b, c);
+                let high = self.builder.ins().sshr_imm(product, 32);
+                self.builder.ins().ireduce(types::I32, high)
+            }
+            Opcode::DIV | Opcode::DIVU | Opcode::REM | Opcode::REMU => self.divrem(opcode, b, c),
+            _ => unreachable!("{opcode:?} is not an ALU opcode"),
+        }
+    }
+
+    fn compare(&mut self, cc: IntCC, b: Value, c: Value) -> Value {
+        let flag = self.builder.ins().icmp(cc, b, c);
+        self.builder.ins().uextend(types::I32, flag)
+    }
+
+    /// Divisions trap on a zero divisor and on `i32::MIN / -1` in native code, so the divisor is
+    /// replaced by one in these cases and the result is selected like the interpreter does.
+    fn divrem(&mut self, opcode: Opcode, b: Value, c: Value) -> Value {
+        let one = self.constant(1);
+        let is_zero = self.builder.ins().icmp_imm(IntCC::Equal, c, 0);
+        let mut unsafe_divisor = is_zero;
+        if matches!(opcode, Opcode::DIV | Opcode::REM) {
+            let is_min = self.builder.ins().icmp_imm(IntCC::Equal, b, i64::from(i32::MIN));
+            let is_minus_one = self.builder.ins().icmp_imm(IntCC::Equal, c, -1);
+            let overflows = self.builder.ins().band(is_min, is_minus_one);
+            unsafe_divisor = self.builder.ins().bor(is_zero, overflows);
+        }
+        let divisor = self.builder.ins().select(unsafe_divisor, one, c);
+        let (result, on_zero) = match opcode {
+            Opcode::DIV => (self.builder.ins().sdiv(b, divisor), self.constant(u32::MAX)),
+            Opcode::DIVU => (self.builder.ins().udiv(b, divisor), self.constant(u32::MAX)),
+            Opcode::REM => (self.builder.ins().srem(b, divisor), b),
+            Opcode::REMU => (self.builder.ins().urem(b, divisor), b),
+            _ => unreachable!(),
+        };
+        self.builder.ins().select(is_zero, on_zero, result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(block: &CompiledBlock, registers: &mut [u32; 32]) {
+        (block.func)(registers.as_mut_ptr());
+    }
+
+    #[test]
+    fn test_compile_block() {
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 5, 0, 7, false, true),
+            Instruction::new(Opcode::MUL, 6, 5, 10, false, false),
+            Instruction::new(Opcode::SLL, 7, 6, 33, false, true),
+            Instruction::new(Opcode::SLT, 8, 10, 5, false, false),
+            Instruction::new(Opcode::ADD, 0, 5, 5, false, false),
+            Instruction::new(Opcode::BEQ, 0, 0, 8, false, true),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut cache = JitCache::new(&program).unwrap();
+        for _ in 1..JIT_HOT_THRESHOLD {
+            assert!(cache.enter(&program, 0).is_none());
+        }
+        let block = cache.enter(&program, 0).unwrap();
+        assert_eq!(block.len(), 5);
+        assert_eq!(block.reads, vec![10]);
+        assert_eq!(block.writes, vec![5, 6, 7, 8]);
+
+        let mut registers = [0u32; 32];
+        registers[10] = 3;
+        run(block, &mut registers);
+        assert_eq!(&registers[5..9], &[7, 21, 42, 1]);
+        assert_eq!(registers[0], 0);
+
+        // The block starting at the branch is too short to be compiled.
+        for _ in 0..JIT_HOT_THRESHOLD {
+            assert!(cache.enter(&program, 4).is_none());
+        }
+    }
+
+    #[test]
+    fn test_division_edge_cases() {
+        let mut instructions = vec![Instruction::new(Opcode::ADD, 0, 0, 0, false, false); 3];
+        for (i, opcode) in [Opcode::DIV, Opcode::DIVU, Opcode::REM, Opcode::REMU].iter().enumerate()
+        {
+            instructions.push(Instruction::new(*opcode, 12 + i as u32, 10, 11, false, false));
+        }
+        let program = Program::new(instructions, 0, 0);
+        let mut cache = JitCache::new(&program).unwrap();
+        let block = cache.compile(&program.instructions).unwrap();
+
+        let cases: [(u32, u32, [u32; 4]); 3] = [
+            (7, 0, [u32::MAX, u32::MAX, 7, 7]),
+            (i32::MIN as u32, -1i32 as u32, [i32::MIN as u32, 0, 0, i32::MIN as u32]),
+            (-7i32 as u32, 2, [-3i32 as u32, (-7i32 as u32) / 2, -1i32 as u32, 1]),
+        ];
+        for (b, c, expected) in cases {
+            let mut registers = [0u32; 32];
+            registers[10] = b;
+            registers[11] = c;
+            run(&block, &mut registers);
+            assert_eq!(&registers[12..16], &expected, "{b} / {c}");
+        }
+    }
+}
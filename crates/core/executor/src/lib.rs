@@ -19,6 +19,7 @@
 #![allow(clippy::explicit_iter_loop)]
 #![warn(missing_docs)]
 
+mod audit;
 mod context;
 mod disassembler;
 pub mod events;
@@ -26,11 +27,16 @@ mod executor;
 mod hook;
 mod instruction;
 mod io;
+#[cfg(feature = "jit")]
+pub mod jit;
+mod memo;
 mod memory;
 mod opcode;
+mod profiler;
 mod program;
 #[cfg(any(test, feature = "programs"))]
 pub mod programs;
+mod progress;
 mod record;
 mod register;
 mod report;
@@ -39,12 +45,17 @@ pub mod subproof;
 pub mod syscalls;
 mod utils;
 
+pub use audit::*;
 pub use context::*;
+pub use disassembler::{ElfError, ElfLimits, ElfSegment};
 pub use executor::*;
 pub use hook::*;
 pub use instruction::*;
+pub use memo::*;
 pub use opcode::*;
+pub use profiler::*;
 pub use program::*;
+pub use progress::*;
 pub use record::*;
 pub use register::*;
 pub use report::*;
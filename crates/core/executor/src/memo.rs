@@ -0,0 +1,187 @@
+use std::{
+    io::{Read, Write},
+    sync::{Arc, RwLock},
+};
+
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::hook::HookEnv;
+
+/// The file descriptor through which the program looks up a value in the memo table.
+///
+/// The request is the key. The response is a single hint, which is empty if the key is missing and
+/// otherwise the value prefixed by a `1` byte.
+pub const FD_MEMO_LOOKUP: u32 = 8;
+
+/// The file descriptor through which the program stores a value in the memo table.
+///
+/// The request is the length of the key as a little-endian `u32`, followed by the key and the
+/// value. There is no response.
+pub const FD_MEMO_STORE: u32 = 9;
+
+/// A table of values memoized by programs, shared across executions by the host.
+///
+/// Programs look values up with `sp1_zkvm::io::memo_lookup` and store them with
+/// `sp1_zkvm::io::memo_store`. Lookups only see the values committed with [`MemoTable::commit`],
+/// so that every execution of a program (including the re-executions done while proving) reads
+/// the same values, while stores are kept pending until the host commits them.
+///
+/// The values are untrusted hints: programs must verify a value they look up before using it.
+#[derive(Clone, Debug, Default)]
+pub struct MemoTable {
+    entries: Arc<RwLock<MemoEntries>>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct MemoEntries {
+    committed: HashMap<Vec<u8>, Vec<u8>>,
+    #[serde(skip)]
+    pending: HashMap<Vec<u8>, Vec<u8>>,
+}
+
+impl MemoTable {
+    /// Create an empty [`MemoTable`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the committed value of `key`, if any.
+    #[must_use]
+    pub fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        self.entries.read().unwrap().committed.get(key).cloned()
+    }
+
+    /// Insert a committed value, for example to seed the table from the host.
+    pub fn insert(&self, key: Vec<u8>, value: Vec<u8>) {
+        self.entries.write().unwrap().committed.insert(key, value);
+    }
+
+    /// The number of committed values.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.read().unwrap().committed.len()
+    }
+
+    /// Whether there are no committed values.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number of values stored by programs since the last commit.
+    #[must_use]
+    pub fn num_pending(&self) -> usize {
+        self.entries.read().unwrap().pending.len()
+    }
+
+    /// Make the values stored by programs since the last commit visible to lookups.
+    pub fn commit(&self) {
+        let mut entries = self.entries.write().unwrap();
+        let pending = std::mem::take(&mut entries.pending);
+        entries.committed.extend(pending);
+    }
+
+    /// Drop the values stored by programs since the last commit.
+    pub fn discard_pending(&self) {
+        self.entries.write().unwrap().pending.clear();
+    }
+
+    /// Serialize the committed values to `writer`.
+    pub fn save<W: Write>(&self, writer: W) -> bincode::Result<()> {
+        bincode::serialize_into(writer, &*self.entries.read().unwrap())
+    }
+
+    /// Deserialize a table saved with [`MemoTable::save`] from `reader`.
+    pub fn load<R: Read>(reader: R) -> bincode::Result<Self> {
+        let entries: MemoEntries = bincode::deserialize_from(reader)?;
+        Ok(Self { entries: Arc::new(RwLock::new(entries)) })
+    }
+
+    /// Answer a lookup request from the program.
+    pub(crate) fn lookup_hook(&self, _: HookEnv, key: &[u8]) -> Vec<Vec<u8>> {
+        vec![memo_lookup_response(self.get(key))]
+    }
+
+    /// Record a store request from the program as pending.
+    pub(crate) fn store_hook(&self, _: HookEnv, buf: &[u8]) -> Vec<Vec<u8>> {
+        let key_len = buf.get(0..4).map(|len| u32::from_le_bytes(len.try_into().unwrap()));
+        match key_len.filter(|&len| 4 + len as usize <= buf.len()) {
+            Some(len) => {
+                let (key, value) = buf[4..].split_at(len as usize);
+                self.entries.write().unwrap().pending.insert(key.to_vec(), value.to_vec());
+            }
+            None => tracing::warn!("malformed memo store request of {} bytes", buf.len()),
+        }
+        vec![]
+    }
+}
+
+/// Encode the response to a lookup request.
+fn memo_lookup_response(value: Option<Vec<u8>>) -> Vec<u8> {
+    match value {
+        Some(value) => [&[1][..], &value].concat(),
+        None => Vec::new(),
+    }
+}
+
+/// The lookup hook registered by default, for which every key is missing.
+#[must_use]
+pub fn hook_memo_miss(_: HookEnv, _: &[u8]) -> Vec<Vec<u8>> {
+    vec![memo_lookup_response(None)]
+}
+
+/// The store hook registered by default, which drops the value.
+#[must_use]
+pub fn hook_memo_drop(_: HookEnv, _: &[u8]) -> Vec<Vec<u8>> {
+    vec![]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{programs::tests::simple_program, Executor};
+
+    fn store_request(key: &[u8], value: &[u8]) -> Vec<u8> {
+        [&(key.len() as u32).to_le_bytes()[..], key, value].concat()
+    }
+
+    #[test]
+    fn stores_are_pending_until_committed() {
+        let runtime = Executor::new(simple_program(), Default::default());
+        let table = MemoTable::new();
+
+        assert_eq!(table.lookup_hook(runtime.hook_env(), b"key"), vec![Vec::<u8>::new()]);
+        assert!(table.store_hook(runtime.hook_env(), &store_request(b"key", b"value")).is_empty());
+        assert_eq!(table.num_pending(), 1);
+        assert_eq!(table.lookup_hook(runtime.hook_env(), b"key"), vec![Vec::<u8>::new()]);
+
+        table.commit();
+        assert_eq!(table.num_pending(), 0);
+        assert_eq!(table.get(b"key"), Some(b"value".to_vec()));
+        assert_eq!(table.lookup_hook(runtime.hook_env(), b"key"), vec![b"\x01value".to_vec()]);
+    }
+
+    #[test]
+    fn malformed_store_is_dropped() {
+        let runtime = Executor::new(simple_program(), Default::default());
+        let table = MemoTable::new();
+        table.store_hook(runtime.hook_env(), &[8, 0, 0, 0, 1]);
+        table.store_hook(runtime.hook_env(), &[1, 0]);
+        assert_eq!(table.num_pending(), 0);
+    }
+
+    #[test]
+    fn save_and_load() {
+        let table = MemoTable::new();
+        table.insert(b"committed".to_vec(), b"1".to_vec());
+        table.entries.write().unwrap().pending.insert(b"pending".to_vec(), b"2".to_vec());
+
+        let mut bytes = Vec::new();
+        table.save(&mut bytes).unwrap();
+        let loaded = MemoTable::load(bytes.as_slice()).unwrap();
+        assert_eq!(loaded.get(b"committed"), Some(b"1".to_vec()));
+        assert_eq!(loaded.num_pending(), 0);
+    }
+}
@@ -101,6 +101,42 @@ pub enum Opcode {
     REMU = 37,
     /// Unimplemented instruction.
     UNIMP = 39,
+    /// rd ← clk, the clock cycle value within the current shard, pc ← pc + 4
+    RDCYCLE = 40,
+    /// rd ← the number of instructions executed in the current shard, pc ← pc + 4
+    RDINSTRET = 41,
+}
+
+/// The class of an opcode, grouping the instructions by the kind of work they do.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, PartialOrd, Ord, Enum,
+)]
+pub enum OpcodeClass {
+    /// Arithmetic, logic, shift, comparison, multiplication and division instructions.
+    Alu,
+    /// Loads and stores.
+    Memory,
+    /// Conditional branches.
+    Branch,
+    /// Jumps, `JAL` and `JALR`.
+    Jump,
+    /// `AUIPC`.
+    UpperImmediate,
+    /// `ECALL`, `EBREAK`, the counter reads and `UNIMP`.
+    System,
+}
+
+impl Display for OpcodeClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            OpcodeClass::Alu => "alu",
+            OpcodeClass::Memory => "memory",
+            OpcodeClass::Branch => "branch",
+            OpcodeClass::Jump => "jump",
+            OpcodeClass::UpperImmediate => "upper immediate",
+            OpcodeClass::System => "system",
+        })
+    }
 }
 
 /// Byte Opcode.
@@ -129,6 +165,12 @@ pub enum ByteOpcode {
     MSB = 7,
     /// Unsigned 16-bit Range Check.
     U16Range = 8,
+    /// The S-box of AES.
+    AesSbox = 9,
+    /// A hexadecimal digit, lowercase or uppercase.
+    HexChar = 10,
+    /// A character of the standard or URL-safe base64 alphabet.
+    Base64Char = 11,
 }
 
 impl Opcode {
@@ -174,6 +216,51 @@ impl Opcode {
             Opcode::REM => "rem",
             Opcode::REMU => "remu",
             Opcode::UNIMP => "unimp",
+            Opcode::RDCYCLE => "rdcycle",
+            Opcode::RDINSTRET => "rdinstret",
+        }
+    }
+
+    /// Get the class of the opcode.
+    #[must_use]
+    pub const fn class(&self) -> OpcodeClass {
+        match self {
+            Opcode::ADD
+            | Opcode::SUB
+            | Opcode::XOR
+            | Opcode::OR
+            | Opcode::AND
+            | Opcode::SLL
+            | Opcode::SRL
+            | Opcode::SRA
+            | Opcode::SLT
+            | Opcode::SLTU
+            | Opcode::MUL
+            | Opcode::MULH
+            | Opcode::MULHU
+            | Opcode::MULHSU
+            | Opcode::DIV
+            | Opcode::DIVU
+            | Opcode::REM
+            | Opcode::REMU => OpcodeClass::Alu,
+            Opcode::LB
+            | Opcode::LH
+            | Opcode::LW
+            | Opcode::LBU
+            | Opcode::LHU
+            | Opcode::SB
+            | Opcode::SH
+            | Opcode::SW => OpcodeClass::Memory,
+            Opcode::BEQ | Opcode::BNE | Opcode::BLT | Opcode::BGE | Opcode::BLTU | Opcode::BGEU => {
+                OpcodeClass::Branch
+            }
+            Opcode::JAL | Opcode::JALR => OpcodeClass::Jump,
+            Opcode::AUIPC => OpcodeClass::UpperImmediate,
+            Opcode::ECALL
+            | Opcode::EBREAK
+            | Opcode::UNIMP
+            | Opcode::RDCYCLE
+            | Opcode::RDINSTRET => OpcodeClass::System,
         }
     }
 
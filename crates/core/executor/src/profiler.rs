@@ -0,0 +1,223 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    num::NonZeroU64,
+    path::PathBuf,
+    sync::Arc,
+};
+
+use elf::{abi::STT_FUNC, endian::LittleEndian, ElfBytes};
+use hashbrown::HashMap;
+use rustc_demangle::demangle;
+use thiserror::Error;
+
+use crate::{ElfError, Instruction, Opcode, Register};
+
+/// The default number of cycles between two samples of the profiler.
+pub const DEFAULT_PROFILER_SAMPLE_RATE: u64 = 10;
+
+/// The program to profile, where to write the profile, and how often to sample the program.
+#[derive(Clone)]
+pub struct ProfilerOpts {
+    /// The ELF of the program, whose symbols name the functions of the profile.
+    pub elf: Arc<[u8]>,
+    /// The path of the profile.
+    pub path: PathBuf,
+    /// The number of cycles between two samples.
+    pub sample_rate: NonZeroU64,
+}
+
+impl ProfilerOpts {
+    /// Profile the program `elf` to `path`, sampling it every [`DEFAULT_PROFILER_SAMPLE_RATE`]
+    /// cycles.
+    pub fn new(elf: &[u8], path: impl Into<PathBuf>) -> Self {
+        Self {
+            elf: elf.into(),
+            path: path.into(),
+            sample_rate: NonZeroU64::new(DEFAULT_PROFILER_SAMPLE_RATE).unwrap(),
+        }
+    }
+
+    /// Sample the program every `sample_rate` cycles instead.
+    #[must_use]
+    pub fn with_sample_rate(mut self, sample_rate: NonZeroU64) -> Self {
+        self.sample_rate = sample_rate;
+        self
+    }
+}
+
+/// An error creating a [Profiler].
+#[derive(Error, Debug)]
+pub enum ProfilerError {
+    /// The symbols of the ELF could not be read.
+    #[error(transparent)]
+    Elf(#[from] ElfError),
+
+    /// The profile could not be created.
+    #[error("failed to create the profile: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// A sampling profiler of the program, writing the call stacks it samples as folded stacks.
+///
+/// Every `sample_rate` cycles, the profiler counts a sample of the current call stack. The call
+/// stack is not unwound from the memory of the program, which would need frame pointers or the
+/// unwinding tables of the ELF; instead, the profiler keeps a shadow stack, pushing a frame when
+/// a call (`jal` or `jalr` linking `ra`) enters a function and popping it on `ret`. Jumps to the
+/// start of a function without linking `ra` are tail calls, and replace the top frame.
+///
+/// The profile has a line per call stack, with the functions from the outermost to the innermost
+/// separated by `;`, followed by the number of samples. This is the format read by
+/// [inferno](https://github.com/jonhoo/inferno) and `flamegraph.pl` to render a flamegraph.
+pub struct Profiler {
+    writer: BufWriter<File>,
+    sample_rate: u64,
+    /// The demangled names of the functions.
+    names: Vec<String>,
+    /// The functions by start address.
+    functions: HashMap<u32, usize>,
+    /// The call stack, as indices in [Self::names].
+    stack: Vec<usize>,
+    /// Whether the previous instruction was a call.
+    called: bool,
+    /// The number of samples of each call stack.
+    samples: HashMap<Vec<usize>, u64>,
+}
+
+impl Profiler {
+    /// Create the profile described by `opts`.
+    pub fn from_opts(opts: &ProfilerOpts) -> Result<Self, ProfilerError> {
+        let symbols = function_symbols(&opts.elf)?;
+        Ok(Self::create(&opts.path, symbols, opts.sample_rate.get())?)
+    }
+
+    /// Create a profile at `path`, naming the functions from `(address, name)` symbols, and
+    /// sampling every `sample_rate` cycles.
+    pub fn create(
+        path: impl AsRef<std::path::Path>,
+        symbols: impl IntoIterator<Item = (u32, String)>,
+        sample_rate: u64,
+    ) -> std::io::Result<Self> {
+        let mut names = Vec::new();
+        let mut functions = HashMap::new();
+        for (addr, name) in symbols {
+            functions.entry(addr).or_insert_with(|| {
+                names.push(name);
+                names.len() - 1
+            });
+        }
+        Ok(Self {
+            writer: BufWriter::new(File::create(path)?),
+            sample_rate: sample_rate.max(1),
+            names,
+            functions,
+            stack: Vec::new(),
+            called: false,
+            samples: HashMap::new(),
+        })
+    }
+
+    /// Record the cycle `clk`, which runs `instruction` at `pc`.
+    pub(crate) fn record(&mut self, clk: u64, pc: u32, instruction: &Instruction) {
+        if let Some(&function) = self.functions.get(&pc) {
+            match self.stack.last_mut() {
+                Some(top) if !self.called => *top = function,
+                _ => self.stack.push(function),
+            }
+        }
+
+        if clk % self.sample_rate == 0 {
+            *self.samples.entry_ref(self.stack.as_slice()).or_insert(0) += 1;
+        }
+
+        let links_ra = instruction.op_a == Register::X1 as u32;
+        self.called = matches!(instruction.opcode, Opcode::JAL | Opcode::JALR) && links_ra;
+        if is_return(instruction) {
+            self.stack.pop();
+        }
+    }
+
+    /// Write the folded stacks of the samples to the profile.
+    pub(crate) fn write(&mut self) -> std::io::Result<()> {
+        let mut lines = self
+            .samples
+            .iter()
+            .map(|(stack, count)| {
+                let stack = if stack.is_empty() {
+                    "[unknown]".to_string()
+                } else {
+                    stack.iter().map(|&i| self.names[i].as_str()).collect::<Vec<_>>().join(";")
+                };
+                format!("{stack} {count}")
+            })
+            .collect::<Vec<_>>();
+        lines.sort_unstable();
+        for line in lines {
+            writeln!(self.writer, "{line}")?;
+        }
+        self.writer.flush()
+    }
+}
+
+/// Returns whether the instruction is a `ret`, that is `jalr x0, 0(ra)`.
+fn is_return(instruction: &Instruction) -> bool {
+    instruction.opcode == Opcode::JALR
+        && instruction.op_a == Register::X0 as u32
+        && instruction.op_b == Register::X1 as u32
+        && instruction.op_c == 0
+}
+
+/// Returns the start address and demangled name of the function symbols of the ELF.
+fn function_symbols(elf: &[u8]) -> Result<Vec<(u32, String)>, ElfError> {
+    let elf = ElfBytes::<LittleEndian>::minimal_parse(elf)
+        .map_err(|err| ElfError::Parse(err.to_string()))?;
+    let Some((symbols, strtab)) =
+        elf.symbol_table().map_err(|err| ElfError::Parse(err.to_string()))?
+    else {
+        return Ok(Vec::new());
+    };
+    symbols
+        .iter()
+        .filter(|sym| sym.st_symtype() == STT_FUNC && sym.st_size > 0)
+        .map(|sym| {
+            let name =
+                strtab.get(sym.st_name as usize).map_err(|err| ElfError::Parse(err.to_string()))?;
+            Ok((sym.st_value as u32, format!("{:#}", demangle(name))))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Executor, Program};
+
+    #[test]
+    fn profiler_samples_call_stacks() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("profile.folded");
+
+        // `main` calls `leaf` twice, then halts; `leaf` tail calls `tail`.
+        let instructions = vec![
+            // main, at 0.
+            Instruction::new(Opcode::JAL, 1, 20, 0, true, true),
+            Instruction::new(Opcode::JAL, 1, 16, 0, true, true),
+            Instruction::new(Opcode::ADD, 5, 0, 0, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 0, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            // leaf, at 20.
+            Instruction::new(Opcode::ADD, 29, 29, 1, false, true),
+            Instruction::new(Opcode::JAL, 0, 4, 0, true, true),
+            // tail, at 28.
+            Instruction::new(Opcode::JALR, 0, 1, 0, false, true),
+        ];
+        let program = Program::new(instructions, 0, 0);
+        let mut runtime = Executor::new(program, Default::default());
+        let symbols = [(0, "main".to_string()), (20, "leaf".to_string()), (28, "tail".to_string())];
+        runtime.profiler = Some(Profiler::create(&path, symbols, 1).unwrap());
+        runtime.run().unwrap();
+
+        let profile = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(profile, "main 5\nmain;leaf 4\nmain;tail 2\n");
+    }
+}
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use sp1_stark::air::MachineProgram;
 
 use crate::{
-    disassembler::{transpile, Elf},
+    disassembler::{transpile, Elf, ElfError, ElfLimits},
     instruction::Instruction,
 };
 
@@ -38,10 +38,22 @@ impl Program {
     ///
     /// # Errors
     ///
-    /// This function may return an error if the ELF is not valid.
-    pub fn from(input: &[u8]) -> eyre::Result<Self> {
+    /// This function returns an [ElfError] describing the issue if the ELF is not valid.
+    pub fn from(input: &[u8]) -> Result<Self, ElfError> {
+        Self::from_with_limits(input, &ElfLimits::default())
+    }
+
+    /// Disassemble a RV32IM ELF like [Program::from], bounding the resources spent loading it.
+    ///
+    /// Use [ElfLimits::UNTRUSTED] for ELFs that come from untrusted sources.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an [ElfError] describing the issue if the ELF is not valid or exceeds
+    /// `limits`.
+    pub fn from_with_limits(input: &[u8], limits: &ElfLimits) -> Result<Self, ElfError> {
         // Decode the bytes as an ELF.
-        let elf = Elf::decode(input)?;
+        let elf = Elf::decode_with_limits(input, limits)?;
 
         // Transpile the RV32IM instructions.
         let instructions = transpile(&elf.instructions);
@@ -63,7 +75,7 @@ impl Program {
     pub fn from_elf(path: &str) -> eyre::Result<Self> {
         let mut elf_code = Vec::new();
         File::open(path)?.read_to_end(&mut elf_code)?;
-        Program::from(&elf_code)
+        Ok(Program::from(&elf_code)?)
     }
 }
 
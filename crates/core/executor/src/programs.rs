@@ -1,4 +1,4 @@
-//! RV32IM ELFs used for testing.
+//! RV32IM ELFs and programs used for testing.
 
 #[allow(dead_code)]
 #[allow(missing_docs)]
@@ -204,4 +204,257 @@ pub mod tests {
         ];
         Program::new(instructions, 0, 0)
     }
+
+    /// A program with loads and stores that are not aligned to their size, some of which span two
+    /// words.
+    pub fn misaligned_memory_program() -> Program {
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 29, 0, 0x12345678, false, true),
+            Instruction::new(Opcode::ADD, 17, 0, 0xBEEF, false, true),
+            // SW and LW across two words.
+            Instruction::new(Opcode::SW, 29, 0, 0x27654321, false, true),
+            Instruction::new(Opcode::LW, 28, 0, 0x27654321, false, true),
+            // LHU and LH within a word and across two words.
+            Instruction::new(Opcode::LHU, 27, 0, 0x27654323, false, true),
+            Instruction::new(Opcode::LH, 26, 0, 0x27654321, false, true),
+            // SH across two words.
+            Instruction::new(Opcode::SH, 17, 0, 0x27654327, false, true),
+            Instruction::new(Opcode::LHU, 25, 0, 0x27654327, false, true),
+            Instruction::new(Opcode::LH, 24, 0, 0x27654327, false, true),
+            // The aligned words around the misaligned stores.
+            Instruction::new(Opcode::LW, 23, 0, 0x27654320, false, true),
+            Instruction::new(Opcode::LW, 22, 0, 0x27654324, false, true),
+            Instruction::new(Opcode::LW, 21, 0, 0x27654328, false, true),
+        ];
+        Program::new(instructions, 0, 0)
+    }
+}
+
+/// A generator of random, valid RV32IM programs for fuzzing the executor and the chips.
+///
+/// The programs are produced as encoded instruction words and transpiled with the disassembler,
+/// so running them also exercises the decoder. Every generated program terminates: all branches
+/// and jumps go forward, and loads and stores only touch an aligned scratch region.
+pub mod random {
+    use rand::Rng;
+
+    use crate::{disassembler::transpile, Program};
+
+    /// The register holding the base address of the scratch memory region.
+    ///
+    /// It is set by the first instruction of every program and never written afterwards.
+    pub const BASE_REGISTER: u32 = 31;
+
+    /// The base address of the scratch memory region used by loads and stores.
+    pub const SCRATCH_BASE: u32 = 0x1000_0000;
+
+    /// The largest forward jump, in instructions, that a branch can encode.
+    const MAX_BRANCH_OFFSET: usize = 1023;
+
+    const OPCODE_OP: u32 = 0b011_0011;
+    const OPCODE_OP_IMM: u32 = 0b001_0011;
+    const OPCODE_LOAD: u32 = 0b000_0011;
+    const OPCODE_STORE: u32 = 0b010_0011;
+    const OPCODE_BRANCH: u32 = 0b110_0011;
+    const OPCODE_LUI: u32 = 0b011_0111;
+    const OPCODE_AUIPC: u32 = 0b001_0111;
+    const OPCODE_JAL: u32 = 0b110_1111;
+
+    /// The (funct3, funct7) pairs of the R-type ALU instructions, including the M extension.
+    const OP_FUNCTS: [(u32, u32); 18] = [
+        (0b000, 0b000_0000), // ADD
+        (0b000, 0b010_0000), // SUB
+        (0b001, 0b000_0000), // SLL
+        (0b010, 0b000_0000), // SLT
+        (0b011, 0b000_0000), // SLTU
+        (0b100, 0b000_0000), // XOR
+        (0b101, 0b000_0000), // SRL
+        (0b101, 0b010_0000), // SRA
+        (0b110, 0b000_0000), // OR
+        (0b111, 0b000_0000), // AND
+        (0b000, 0b000_0001), // MUL
+        (0b001, 0b000_0001), // MULH
+        (0b010, 0b000_0001), // MULHSU
+        (0b011, 0b000_0001), // MULHU
+        (0b100, 0b000_0001), // DIV
+        (0b101, 0b000_0001), // DIVU
+        (0b110, 0b000_0001), // REM
+        (0b111, 0b000_0001), // REMU
+    ];
+
+    /// The funct3 of the I-type ALU instructions that take a 12-bit immediate.
+    const OP_IMM_FUNCTS: [u32; 6] = [0b000, 0b010, 0b011, 0b100, 0b110, 0b111];
+
+    /// The funct3 of the branch instructions.
+    const BRANCH_FUNCTS: [u32; 6] = [0b000, 0b001, 0b100, 0b101, 0b110, 0b111];
+
+    /// Generates a random program of `len` instructions, plus the instruction that sets up
+    /// [`BASE_REGISTER`].
+    pub fn random_program<R: Rng>(rng: &mut R, len: usize) -> Program {
+        Program::new(transpile(&random_program_words(rng, len)), 0, 0)
+    }
+
+    /// Generates the encoded instruction words of a random program of `len` instructions, plus
+    /// the instruction that sets up [`BASE_REGISTER`].
+    pub fn random_program_words<R: Rng>(rng: &mut R, len: usize) -> Vec<u32> {
+        let mut words = Vec::with_capacity(len + 1);
+        words.push(u_type(OPCODE_LUI, BASE_REGISTER, SCRATCH_BASE));
+        for i in 0..len {
+            // The number of instructions from this one to the end of the program.
+            let remaining = len - i;
+            words.push(random_instruction(rng, remaining));
+        }
+        words
+    }
+
+    /// Generates a random instruction that is at most `remaining` instructions from the end of
+    /// the program.
+    fn random_instruction<R: Rng>(rng: &mut R, remaining: usize) -> u32 {
+        match rng.gen_range(0..8) {
+            0 | 1 => {
+                let (funct3, funct7) = OP_FUNCTS[rng.gen_range(0..OP_FUNCTS.len())];
+                r_type(OPCODE_OP, rd(rng), rs(rng), rs(rng), funct3, funct7)
+            }
+            2 => {
+                let funct3 = OP_IMM_FUNCTS[rng.gen_range(0..OP_IMM_FUNCTS.len())];
+                i_type(OPCODE_OP_IMM, rd(rng), rs(rng), funct3, rng.gen_range(-2048..2048))
+            }
+            3 => {
+                // SLLI, SRLI and SRAI.
+                let (funct3, funct7) =
+                    [(0b001, 0), (0b101, 0), (0b101, 0b010_0000)][rng.gen_range(0..3)];
+                r_type(OPCODE_OP_IMM, rd(rng), rs(rng), rng.gen_range(0..32), funct3, funct7)
+            }
+            4 => {
+                let upper = rng.gen::<u32>() & 0xffff_f000;
+                let opcode = if rng.gen() { OPCODE_LUI } else { OPCODE_AUIPC };
+                u_type(opcode, rd(rng), upper)
+            }
+            5 => {
+                // LB, LH, LW, LBU and LHU, aligned to the access size.
+                let (funct3, align) = [(0b000, 1), (0b001, 2), (0b010, 4), (0b100, 1), (0b101, 2)]
+                    [rng.gen_range(0..5)];
+                i_type(OPCODE_LOAD, rd(rng), BASE_REGISTER, funct3, scratch_offset(rng, align))
+            }
+            6 => {
+                // SB, SH and SW, aligned to the access size.
+                let (funct3, align) = [(0b000, 1), (0b001, 2), (0b010, 4)][rng.gen_range(0..3)];
+                s_type(BASE_REGISTER, rs(rng), funct3, scratch_offset(rng, align))
+            }
+            _ => {
+                // A forward branch or jump, which may land just past the last instruction.
+                let offset = 4 * rng.gen_range(1..=remaining.min(MAX_BRANCH_OFFSET)) as i32;
+                if rng.gen_bool(0.25) {
+                    j_type(rd(rng), offset)
+                } else {
+                    let funct3 = BRANCH_FUNCTS[rng.gen_range(0..BRANCH_FUNCTS.len())];
+                    b_type(rs(rng), rs(rng), funct3, offset)
+                }
+            }
+        }
+    }
+
+    /// A random destination register, which is never [`BASE_REGISTER`].
+    fn rd<R: Rng>(rng: &mut R) -> u32 {
+        rng.gen_range(0..BASE_REGISTER)
+    }
+
+    /// A random source register.
+    fn rs<R: Rng>(rng: &mut R) -> u32 {
+        rng.gen_range(0..32)
+    }
+
+    /// A random offset into the scratch region that is a multiple of `align`.
+    fn scratch_offset<R: Rng>(rng: &mut R, align: i32) -> i32 {
+        rng.gen_range(0..2048 / align) * align
+    }
+
+    fn r_type(opcode: u32, rd: u32, rs1: u32, rs2: u32, funct3: u32, funct7: u32) -> u32 {
+        (funct7 << 25) | (rs2 << 20) | (rs1 << 15) | (funct3 << 12) | (rd << 7) | opcode
+    }
+
+    fn i_type(opcode: u32, rd: u32, rs1: u32, funct3: u32, imm: i32) -> u32 {
+        ((imm as u32 & 0xfff) << 20) | (rs1 << 15) | (funct3 << 12) | (rd << 7) | opcode
+    }
+
+    fn s_type(rs1: u32, rs2: u32, funct3: u32, imm: i32) -> u32 {
+        let imm = imm as u32;
+        (((imm >> 5) & 0x7f) << 25)
+            | (rs2 << 20)
+            | (rs1 << 15)
+            | (funct3 << 12)
+            | ((imm & 0x1f) << 7)
+            | OPCODE_STORE
+    }
+
+    fn b_type(rs1: u32, rs2: u32, funct3: u32, imm: i32) -> u32 {
+        let imm = imm as u32;
+        (((imm >> 12) & 0x1) << 31)
+            | (((imm >> 5) & 0x3f) << 25)
+            | (rs2 << 20)
+            | (rs1 << 15)
+            | (funct3 << 12)
+            | (((imm >> 1) & 0xf) << 8)
+            | (((imm >> 11) & 0x1) << 7)
+            | OPCODE_BRANCH
+    }
+
+    fn u_type(opcode: u32, rd: u32, upper: u32) -> u32 {
+        (upper & 0xffff_f000) | (rd << 7) | opcode
+    }
+
+    fn j_type(rd: u32, imm: i32) -> u32 {
+        let imm = imm as u32;
+        (((imm >> 20) & 0x1) << 31)
+            | (((imm >> 1) & 0x3ff) << 21)
+            | (((imm >> 11) & 0x1) << 20)
+            | (imm & 0x000f_f000)
+            | (rd << 7)
+            | OPCODE_JAL
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use rand::{rngs::StdRng, SeedableRng};
+        use sp1_stark::SP1CoreOpts;
+
+        use super::*;
+        use crate::{Executor, Opcode};
+
+        #[test]
+        fn test_encodings_roundtrip() {
+            let instructions = transpile(&[
+                r_type(OPCODE_OP, 3, 1, 2, 0b000, 0b010_0000),
+                i_type(OPCODE_LOAD, 5, BASE_REGISTER, 0b010, 8),
+                s_type(BASE_REGISTER, 6, 0b001, 2046),
+                b_type(7, 8, 0b100, 4092),
+                j_type(9, 8),
+            ]);
+            assert_eq!(instructions[0].opcode, Opcode::SUB);
+            assert_eq!(
+                (instructions[0].op_a, instructions[0].op_b, instructions[0].op_c),
+                (3, 1, 2)
+            );
+            assert_eq!(instructions[1].opcode, Opcode::LW);
+            assert_eq!(instructions[1].op_c, 8);
+            assert_eq!(instructions[2].opcode, Opcode::SH);
+            assert_eq!((instructions[2].op_a, instructions[2].op_c), (6, 2046));
+            assert_eq!(instructions[3].opcode, Opcode::BLT);
+            assert_eq!(instructions[3].op_c, 4092);
+            assert_eq!(instructions[4].opcode, Opcode::JAL);
+            assert_eq!(instructions[4].op_b, 8);
+        }
+
+        #[test]
+        fn test_random_programs_execute() {
+            let mut rng = StdRng::seed_from_u64(0);
+            for _ in 0..100 {
+                let program = random_program(&mut rng, 200);
+                assert_eq!(program.instructions.len(), 201);
+                let mut runtime = Executor::new(program, SP1CoreOpts::default());
+                runtime.run().unwrap();
+                assert!(runtime.state.global_clk <= 201);
+            }
+        }
+    }
 }
@@ -0,0 +1,51 @@
+use std::{num::NonZeroU64, sync::Arc};
+
+/// A summary of the state of an execution, passed to its progress callback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutionProgress {
+    /// The program counter of the next instruction.
+    pub pc: u32,
+    /// The number of cycles executed so far.
+    pub global_clk: u64,
+    /// The current shard.
+    pub shard: u32,
+    /// The clock within the current shard.
+    pub clk: u32,
+    /// The number of public value bytes committed so far.
+    pub committed_bytes: usize,
+}
+
+/// Whether an execution continues after its progress callback returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressControl {
+    /// Keep executing.
+    Continue,
+    /// Stop the execution, which fails with
+    /// [`ExecutionError::Aborted`](crate::ExecutionError::Aborted).
+    Abort,
+}
+
+/// A progress callback, wrapped in a smart pointer.
+pub type BoxedProgressCallback<'a> =
+    Arc<dyn Fn(ExecutionProgress) -> ProgressControl + Send + Sync + 'a>;
+
+/// A callback invoked every `interval` cycles of an execution.
+#[derive(Clone)]
+pub struct ProgressCallback<'a> {
+    /// The number of cycles between two invocations of the callback.
+    pub interval: NonZeroU64,
+    /// The callback.
+    pub callback: BoxedProgressCallback<'a>,
+}
+
+/// The progress of the core proof of an execution, passed to its shard progress callback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShardProgress {
+    /// The number of shards proven so far.
+    pub proven: usize,
+    /// The number of shards of the execution.
+    pub total: usize,
+}
+
+/// A callback invoked as the core shards of an execution are proven.
+pub type ShardProgressCallback = Arc<dyn Fn(ShardProgress) + Send + Sync>;
@@ -8,11 +8,14 @@ use serde::{Deserialize, Serialize};
 
 use super::{program::Program, Opcode};
 use crate::events::{
-    add_sharded_byte_lookup_events, AluEvent, ByteLookupEvent, ByteRecord, CpuEvent,
-    EdDecompressEvent, EllipticCurveAddEvent, EllipticCurveDecompressEvent,
-    EllipticCurveDoubleEvent, Fp2AddSubEvent, Fp2MulEvent, FpOpEvent, KeccakPermuteEvent, LookupId,
-    MemoryInitializeFinalizeEvent, MemoryRecordEnum, ShaCompressEvent, ShaExtendEvent,
-    Uint256MulEvent,
+    add_sharded_byte_lookup_events, AesEncryptBlockEvent, AluEvent, ByteLookupAudit,
+    ByteLookupEvent, ByteRecord, ClmulEvent, CodecEvent, CpuEvent, EdDecompressEvent,
+    EllipticCurveAddEvent, EllipticCurveDecompressEvent, EllipticCurveDoubleEvent,
+    EllipticCurveMapEvent, FixedPointEvent, Fp2AddSubEvent, Fp2MulEvent, FpOpEvent,
+    KeccakPermuteEvent, LookupId, MemoryInitializeFinalizeEvent, MemoryRecordEnum,
+    Poseidon2MerkleVerifyEvent, RangeCheckEvent, RegisteredCurveAddEvent,
+    RegisteredCurveDoubleEvent, ShaCompressEvent, ShaExtendEvent, Uint256MulEvent, WideAluEvent,
+    X25519Event,
 };
 
 /// A record of the execution of a program.
@@ -42,6 +45,9 @@ pub struct ExecutionRecord {
     pub lt_events: Vec<AluEvent>,
     /// A trace of the byte lookups that are needed.
     pub byte_lookups: HashMap<u32, HashMap<ByteLookupEvent, usize>>,
+    /// The shard whose byte lookups are received by the byte table of this record, if they were
+    /// split from the record of that shard, see [`ExecutionRecord::split_byte_lookups`].
+    pub byte_lookup_shard: Option<u32>,
     /// A trace of the sha256 extend events.
     pub sha_extend_events: Vec<ShaExtendEvent>,
     /// A trace of the sha256 compress events.
@@ -50,6 +56,8 @@ pub struct ExecutionRecord {
     pub keccak_permute_events: Vec<KeccakPermuteEvent>,
     /// A trace of the edwards add events.
     pub ed_add_events: Vec<EllipticCurveAddEvent>,
+    /// A trace of the Baby Jubjub add events.
+    pub babyjubjub_add_events: Vec<EllipticCurveAddEvent>,
     /// A trace of the edwards decompress events.
     pub ed_decompress_events: Vec<EdDecompressEvent>,
     /// A trace of the secp256k1 add events.
@@ -66,14 +74,44 @@ pub struct ExecutionRecord {
     pub bls12381_add_events: Vec<EllipticCurveAddEvent>,
     /// A trace of the bls12381 double events.
     pub bls12381_double_events: Vec<EllipticCurveDoubleEvent>,
+    /// A trace of the bls12381 G2 add events.
+    pub bls12381_g2_add_events: Vec<EllipticCurveAddEvent>,
+    /// A trace of the bls12381 G2 double events.
+    pub bls12381_g2_double_events: Vec<EllipticCurveDoubleEvent>,
     /// A trace of the uint256 mul events.
     pub uint256_mul_events: Vec<Uint256MulEvent>,
+    /// A trace of the poseidon2 merkle verify events.
+    pub poseidon2_merkle_verify_events: Vec<Poseidon2MerkleVerifyEvent>,
+    /// A trace of the carry-less multiplication events.
+    pub clmul_events: Vec<ClmulEvent>,
+    /// A trace of the X25519 scalar multiplication events.
+    pub x25519_events: Vec<X25519Event>,
+    /// A trace of the AES block encryption events.
+    pub aes_encrypt_block_events: Vec<AesEncryptBlockEvent>,
+    /// A trace of the range check events.
+    pub range_check_events: Vec<RangeCheckEvent>,
+    /// A trace of the registered curve add events.
+    pub registered_curve_add_events: Vec<RegisteredCurveAddEvent>,
+    /// A trace of the registered curve double events.
+    pub registered_curve_double_events: Vec<RegisteredCurveDoubleEvent>,
+    /// A trace of the wide ALU events.
+    pub wide_alu_events: Vec<WideAluEvent>,
+    /// A trace of the fixed-point events.
+    pub fixed_point_events: Vec<FixedPointEvent>,
+    /// A trace of the hex encode and decode events.
+    pub hex_events: Vec<CodecEvent>,
+    /// A trace of the base64 encode and decode events.
+    pub base64_events: Vec<CodecEvent>,
     /// A trace of the memory initialize events.
     pub memory_initialize_events: Vec<MemoryInitializeFinalizeEvent>,
     /// A trace of the memory finalize events.
     pub memory_finalize_events: Vec<MemoryInitializeFinalizeEvent>,
     /// A trace of the bls12381 decompress events.
     pub bls12381_decompress_events: Vec<EllipticCurveDecompressEvent>,
+    /// A trace of the secp256k1 map to curve events.
+    pub secp256k1_map_events: Vec<EllipticCurveMapEvent>,
+    /// A trace of the bls12381 map to curve events.
+    pub bls12381_map_events: Vec<EllipticCurveMapEvent>,
     /// A trace of the bls12381 fp events.
     pub bls12381_fp_events: Vec<FpOpEvent>,
     /// A trace of the bls12381 fp2 add/sub events.
@@ -99,6 +137,56 @@ impl ExecutionRecord {
         Self { program, ..Default::default() }
     }
 
+    /// Summarize the byte lookup multiplicities of every shard in the record.
+    #[must_use]
+    pub fn byte_lookup_audit(&self) -> ByteLookupAudit {
+        ByteLookupAudit::new(&self.byte_lookups)
+    }
+
+    /// Returns the shard whose byte lookups are received by the byte table of this record.
+    #[must_use]
+    pub fn byte_lookup_shard(&self) -> u32 {
+        self.byte_lookup_shard.unwrap_or(self.public_values.execution_shard)
+    }
+
+    /// Caps the multiplicity of every byte lookup of this record at `threshold`, and moves the
+    /// excess into new [`ExecutionRecord`]s whose byte tables receive it for the same shard.
+    ///
+    /// The multiplicities of the byte table are field elements, so a shard which repeats a lookup
+    /// at least as many times as the order of the field cannot be proven with a single byte table.
+    /// The new records only contain byte lookups, and are proven as shards without "cpu events".
+    #[must_use]
+    pub fn split_byte_lookups(&mut self, threshold: usize) -> Vec<ExecutionRecord> {
+        let threshold = threshold.max(1);
+        let program = self.program.clone();
+        let mut shards = Vec::new();
+        for shard in self.byte_lookups.keys().copied().sorted().collect::<Vec<_>>() {
+            let lookups = self.byte_lookups.get_mut(&shard).unwrap();
+            let mut split: Vec<HashMap<ByteLookupEvent, usize>> = Vec::new();
+            for (event, mult) in lookups.iter_mut().filter(|(_, mult)| **mult > threshold) {
+                let mut excess = *mult - threshold;
+                *mult = threshold;
+                let mut i = 0;
+                while excess > 0 {
+                    if split.len() == i {
+                        split.push(HashMap::new());
+                    }
+                    let count = excess.min(threshold);
+                    split[i].insert(*event, count);
+                    excess -= count;
+                    i += 1;
+                }
+            }
+            shards.extend(split.into_iter().map(|lookups| ExecutionRecord {
+                program: program.clone(),
+                byte_lookups: HashMap::from_iter([(shard, lookups)]),
+                byte_lookup_shard: Some(shard),
+                ..Default::default()
+            }));
+        }
+        shards
+    }
+
     /// Add a mul event to the execution record.
     pub fn add_mul_event(&mut self, mul_event: AluEvent) {
         self.mul_events.push(mul_event);
@@ -158,16 +246,36 @@ impl ExecutionRecord {
             bn254_double_events: std::mem::take(&mut self.bn254_double_events),
             bls12381_add_events: std::mem::take(&mut self.bls12381_add_events),
             bls12381_double_events: std::mem::take(&mut self.bls12381_double_events),
+            bls12381_g2_add_events: std::mem::take(&mut self.bls12381_g2_add_events),
+            bls12381_g2_double_events: std::mem::take(&mut self.bls12381_g2_double_events),
             sha_extend_events: std::mem::take(&mut self.sha_extend_events),
             sha_compress_events: std::mem::take(&mut self.sha_compress_events),
             ed_add_events: std::mem::take(&mut self.ed_add_events),
+            babyjubjub_add_events: std::mem::take(&mut self.babyjubjub_add_events),
             ed_decompress_events: std::mem::take(&mut self.ed_decompress_events),
             k256_decompress_events: std::mem::take(&mut self.k256_decompress_events),
             uint256_mul_events: std::mem::take(&mut self.uint256_mul_events),
+            poseidon2_merkle_verify_events: std::mem::take(
+                &mut self.poseidon2_merkle_verify_events,
+            ),
+            clmul_events: std::mem::take(&mut self.clmul_events),
+            x25519_events: std::mem::take(&mut self.x25519_events),
+            aes_encrypt_block_events: std::mem::take(&mut self.aes_encrypt_block_events),
+            range_check_events: std::mem::take(&mut self.range_check_events),
+            registered_curve_add_events: std::mem::take(&mut self.registered_curve_add_events),
+            registered_curve_double_events: std::mem::take(
+                &mut self.registered_curve_double_events,
+            ),
+            wide_alu_events: std::mem::take(&mut self.wide_alu_events),
+            fixed_point_events: std::mem::take(&mut self.fixed_point_events),
+            hex_events: std::mem::take(&mut self.hex_events),
+            base64_events: std::mem::take(&mut self.base64_events),
             bls12381_fp_events: std::mem::take(&mut self.bls12381_fp_events),
             bls12381_fp2_addsub_events: std::mem::take(&mut self.bls12381_fp2_addsub_events),
             bls12381_fp2_mul_events: std::mem::take(&mut self.bls12381_fp2_mul_events),
             bls12381_decompress_events: std::mem::take(&mut self.bls12381_decompress_events),
+            secp256k1_map_events: std::mem::take(&mut self.secp256k1_map_events),
+            bls12381_map_events: std::mem::take(&mut self.bls12381_map_events),
             memory_initialize_events: std::mem::take(&mut self.memory_initialize_events),
             memory_finalize_events: std::mem::take(&mut self.memory_finalize_events),
             ..Default::default()
@@ -206,6 +314,25 @@ impl ExecutionRecord {
             };
         }
 
+        // Each registered curve has its own chips, whose nonces are counted per curve, so the
+        // events of each curve are split on their own.
+        macro_rules! split_registered_curve_events {
+            ($self:ident, $events:ident, $shards:ident, $threshold:expr, $exact:expr) => {
+                let events = std::mem::take(&mut $self.$events);
+                let mut curve_ids = events.iter().map(|event| event.curve_id).collect::<Vec<_>>();
+                curve_ids.sort_unstable();
+                curve_ids.dedup();
+                let mut remainders = Vec::new();
+                for curve_id in curve_ids {
+                    $self.$events =
+                        events.iter().filter(|event| event.curve_id == curve_id).cloned().collect();
+                    split_events!($self, $events, $shards, $threshold, $exact);
+                    remainders.append(&mut $self.$events);
+                }
+                $self.$events = remainders;
+            };
+        }
+
         split_events!(self, keccak_permute_events, shards, opts.keccak, last);
         split_events!(self, secp256k1_add_events, shards, opts.deferred, last);
         split_events!(self, secp256k1_double_events, shards, opts.deferred, last);
@@ -213,13 +340,41 @@ impl ExecutionRecord {
         split_events!(self, bn254_double_events, shards, opts.deferred, last);
         split_events!(self, bls12381_add_events, shards, opts.deferred, last);
         split_events!(self, bls12381_double_events, shards, opts.deferred, last);
+        split_events!(self, bls12381_g2_add_events, shards, opts.deferred, last);
+        split_events!(self, bls12381_g2_double_events, shards, opts.deferred, last);
         split_events!(self, sha_extend_events, shards, opts.sha_extend, last);
         split_events!(self, sha_compress_events, shards, opts.sha_compress, last);
         split_events!(self, ed_add_events, shards, opts.deferred, last);
+        split_events!(self, babyjubjub_add_events, shards, opts.deferred, last);
         split_events!(self, ed_decompress_events, shards, opts.deferred, last);
         split_events!(self, k256_decompress_events, shards, opts.deferred, last);
         split_events!(self, uint256_mul_events, shards, opts.deferred, last);
+        split_events!(self, poseidon2_merkle_verify_events, shards, opts.deferred, last);
+        split_events!(self, clmul_events, shards, opts.deferred, last);
+        split_events!(self, x25519_events, shards, opts.deferred, last);
+        split_events!(self, aes_encrypt_block_events, shards, opts.deferred, last);
+        split_events!(self, range_check_events, shards, opts.deferred, last);
+        split_registered_curve_events!(
+            self,
+            registered_curve_add_events,
+            shards,
+            opts.deferred,
+            last
+        );
+        split_registered_curve_events!(
+            self,
+            registered_curve_double_events,
+            shards,
+            opts.deferred,
+            last
+        );
+        split_events!(self, wide_alu_events, shards, opts.deferred, last);
+        split_events!(self, fixed_point_events, shards, opts.deferred, last);
+        split_events!(self, hex_events, shards, opts.deferred, last);
+        split_events!(self, base64_events, shards, opts.deferred, last);
         split_events!(self, bls12381_decompress_events, shards, opts.deferred, last);
+        split_events!(self, secp256k1_map_events, shards, opts.deferred, last);
+        split_events!(self, bls12381_map_events, shards, opts.deferred, last);
         split_events!(self, bls12381_fp_events, shards, opts.deferred, last);
         split_events!(self, bls12381_fp2_addsub_events, shards, opts.deferred, last);
         split_events!(self, bls12381_fp2_mul_events, shards, opts.deferred, last);
@@ -286,6 +441,8 @@ pub struct MemoryAccessRecord {
     pub c: Option<MemoryRecordEnum>,
     /// The memory access of the `memory` register.
     pub memory: Option<MemoryRecordEnum>,
+    /// The access to the word after the `memory` one, for misaligned accesses that span two words.
+    pub memory_next: Option<MemoryRecordEnum>,
 }
 
 impl MachineRecord for ExecutionRecord {
@@ -306,6 +463,7 @@ impl MachineRecord for ExecutionRecord {
         stats.insert("sha_compress_events".to_string(), self.sha_compress_events.len());
         stats.insert("keccak_permute_events".to_string(), self.keccak_permute_events.len());
         stats.insert("ed_add_events".to_string(), self.ed_add_events.len());
+        stats.insert("babyjubjub_add_events".to_string(), self.babyjubjub_add_events.len());
         stats.insert("ed_decompress_events".to_string(), self.ed_decompress_events.len());
         stats.insert("secp256k1_add_events".to_string(), self.secp256k1_add_events.len());
         stats.insert("secp256k1_double_events".to_string(), self.secp256k1_double_events.len());
@@ -314,7 +472,29 @@ impl MachineRecord for ExecutionRecord {
         stats.insert("k256_decompress_events".to_string(), self.k256_decompress_events.len());
         stats.insert("bls12381_add_events".to_string(), self.bls12381_add_events.len());
         stats.insert("bls12381_double_events".to_string(), self.bls12381_double_events.len());
+        stats.insert("bls12381_g2_add_events".to_string(), self.bls12381_g2_add_events.len());
+        stats.insert("bls12381_g2_double_events".to_string(), self.bls12381_g2_double_events.len());
         stats.insert("uint256_mul_events".to_string(), self.uint256_mul_events.len());
+        stats.insert(
+            "poseidon2_merkle_verify_events".to_string(),
+            self.poseidon2_merkle_verify_events.len(),
+        );
+        stats.insert("clmul_events".to_string(), self.clmul_events.len());
+        stats.insert("x25519_events".to_string(), self.x25519_events.len());
+        stats.insert("aes_encrypt_block_events".to_string(), self.aes_encrypt_block_events.len());
+        stats.insert("range_check_events".to_string(), self.range_check_events.len());
+        stats.insert(
+            "registered_curve_add_events".to_string(),
+            self.registered_curve_add_events.len(),
+        );
+        stats.insert(
+            "registered_curve_double_events".to_string(),
+            self.registered_curve_double_events.len(),
+        );
+        stats.insert("wide_alu_events".to_string(), self.wide_alu_events.len());
+        stats.insert("fixed_point_events".to_string(), self.fixed_point_events.len());
+        stats.insert("hex_events".to_string(), self.hex_events.len());
+        stats.insert("base64_events".to_string(), self.base64_events.len());
         stats.insert("bls12381_fp_event".to_string(), self.bls12381_fp_events.len());
         stats.insert(
             "bls12381_fp2_addsub_events".to_string(),
@@ -328,6 +508,8 @@ impl MachineRecord for ExecutionRecord {
             "bls12381_decompress_events".to_string(),
             self.bls12381_decompress_events.len(),
         );
+        stats.insert("secp256k1_map_events".to_string(), self.secp256k1_map_events.len());
+        stats.insert("bls12381_map_events".to_string(), self.bls12381_map_events.len());
         stats.insert("memory_initialize_events".to_string(), self.memory_initialize_events.len());
         stats.insert("memory_finalize_events".to_string(), self.memory_finalize_events.len());
         if !self.cpu_events.is_empty() {
@@ -356,6 +538,7 @@ impl MachineRecord for ExecutionRecord {
         self.sha_compress_events.append(&mut other.sha_compress_events);
         self.keccak_permute_events.append(&mut other.keccak_permute_events);
         self.ed_add_events.append(&mut other.ed_add_events);
+        self.babyjubjub_add_events.append(&mut other.babyjubjub_add_events);
         self.ed_decompress_events.append(&mut other.ed_decompress_events);
         self.secp256k1_add_events.append(&mut other.secp256k1_add_events);
         self.secp256k1_double_events.append(&mut other.secp256k1_double_events);
@@ -364,7 +547,20 @@ impl MachineRecord for ExecutionRecord {
         self.k256_decompress_events.append(&mut other.k256_decompress_events);
         self.bls12381_add_events.append(&mut other.bls12381_add_events);
         self.bls12381_double_events.append(&mut other.bls12381_double_events);
+        self.bls12381_g2_add_events.append(&mut other.bls12381_g2_add_events);
+        self.bls12381_g2_double_events.append(&mut other.bls12381_g2_double_events);
         self.uint256_mul_events.append(&mut other.uint256_mul_events);
+        self.poseidon2_merkle_verify_events.append(&mut other.poseidon2_merkle_verify_events);
+        self.clmul_events.append(&mut other.clmul_events);
+        self.x25519_events.append(&mut other.x25519_events);
+        self.aes_encrypt_block_events.append(&mut other.aes_encrypt_block_events);
+        self.range_check_events.append(&mut other.range_check_events);
+        self.registered_curve_add_events.append(&mut other.registered_curve_add_events);
+        self.registered_curve_double_events.append(&mut other.registered_curve_double_events);
+        self.wide_alu_events.append(&mut other.wide_alu_events);
+        self.fixed_point_events.append(&mut other.fixed_point_events);
+        self.hex_events.append(&mut other.hex_events);
+        self.base64_events.append(&mut other.base64_events);
         self.bls12381_fp_events.append(&mut other.bls12381_fp_events);
         self.bls12381_fp2_addsub_events.append(&mut other.bls12381_fp2_addsub_events);
         self.bls12381_fp2_mul_events.append(&mut other.bls12381_fp2_mul_events);
@@ -374,6 +570,8 @@ impl MachineRecord for ExecutionRecord {
         self.bls12381_decompress_events.append(&mut other.bls12381_decompress_events);
 
         self.bls12381_decompress_events.append(&mut other.bls12381_decompress_events);
+        self.secp256k1_map_events.append(&mut other.secp256k1_map_events);
+        self.bls12381_map_events.append(&mut other.bls12381_map_events);
 
         if self.byte_lookups.is_empty() {
             self.byte_lookups = std::mem::take(&mut other.byte_lookups);
@@ -5,11 +5,12 @@ use std::{
 
 use enum_map::{EnumArray, EnumMap};
 use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
 
-use crate::{events::sorted_table_lines, syscalls::SyscallCode, Opcode};
+use crate::{events::sorted_table_lines, syscalls::SyscallCode, Opcode, OpcodeClass, Program};
 
 /// An execution report.
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ExecutionReport {
     /// The opcode counts.
     pub opcode_counts: Box<EnumMap<Opcode, u64>>,
@@ -19,15 +20,72 @@ pub struct ExecutionReport {
     pub cycle_tracker: HashMap<String, u64>,
     /// The unique memory address counts.
     pub touched_memory_addresses: u64,
+    /// The number of times each instruction was executed, indexed by `(pc - pc_base) / 4`, or
+    /// empty if no instruction was counted.
+    pub pc_counts: Vec<u64>,
+    /// The program counter of the first instruction of the program.
+    pub pc_base: u32,
+    /// The heap usage reported by the program when it halted, if its allocator tracks it.
+    pub heap_stats: Option<HeapStats>,
+}
+
+/// The heap usage of a program, as tracked by the allocator of `sp1_zkvm`.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HeapStats {
+    /// The size of the heap at its peak, in bytes.
+    pub peak_heap_bytes: u64,
+    /// The total number of bytes allocated.
+    pub total_allocated_bytes: u64,
+    /// The number of allocations.
+    pub allocation_count: u64,
 }
 
 impl ExecutionReport {
+    /// Create an empty report for the execution of the given program.
+    #[must_use]
+    pub fn new(program: &Program) -> Self {
+        Self { pc_base: program.pc_base, ..Default::default() }
+    }
+
+    /// Count an execution of the instruction at `index` of a program of `len` instructions.
+    ///
+    /// The counts of the instructions are allocated when the first one is counted, so that
+    /// executions which do not report their counts do not pay for them.
+    #[inline]
+    pub(crate) fn count_pc(&mut self, index: usize, len: usize) {
+        if self.pc_counts.is_empty() {
+            self.pc_counts = vec![0; len];
+        }
+        self.pc_counts[index] += 1;
+    }
+
+    /// The number of times the instruction at `pc` was executed.
+    #[must_use]
+    pub fn pc_count(&self, pc: u32) -> u64 {
+        pc.checked_sub(self.pc_base)
+            .and_then(|offset| self.pc_counts.get((offset / 4) as usize))
+            .copied()
+            .unwrap_or_default()
+    }
+
     /// Compute the total number of instructions run during the execution.
     #[must_use]
     pub fn total_instruction_count(&self) -> u64 {
         self.opcode_counts.values().sum()
     }
 
+    /// Compute the number of instructions run during the execution in each class of opcodes.
+    ///
+    /// Every instruction takes one cycle, so these are also the cycles spent in each class.
+    #[must_use]
+    pub fn opcode_class_counts(&self) -> EnumMap<OpcodeClass, u64> {
+        let mut counts = EnumMap::default();
+        for (opcode, count) in self.opcode_counts.iter() {
+            counts[opcode.class()] += count;
+        }
+        counts
+    }
+
     /// Compute the total number of syscalls made during the execution.
     #[must_use]
     pub fn total_syscall_count(&self) -> u64 {
@@ -51,6 +109,19 @@ impl AddAssign for ExecutionReport {
         counts_add_assign(&mut self.opcode_counts, *rhs.opcode_counts);
         counts_add_assign(&mut self.syscall_counts, *rhs.syscall_counts);
         self.touched_memory_addresses += rhs.touched_memory_addresses;
+        if self.pc_counts.is_empty() {
+            self.pc_base = rhs.pc_base;
+        }
+        if self.pc_counts.len() < rhs.pc_counts.len() {
+            self.pc_counts.resize(rhs.pc_counts.len(), 0);
+        }
+        for (lhs, rhs) in self.pc_counts.iter_mut().zip(rhs.pc_counts) {
+            *lhs += rhs;
+        }
+        // The program reports its heap usage once, when it halts.
+        if rhs.heap_stats.is_some() {
+            self.heap_stats = rhs.heap_stats;
+        }
     }
 }
 
@@ -70,11 +141,23 @@ impl Display for ExecutionReport {
             writeln!(f, "  {line}")?;
         }
 
+        writeln!(f, "cycles by opcode class:")?;
+        for line in sorted_table_lines(self.opcode_class_counts()) {
+            writeln!(f, "  {line}")?;
+        }
+
         writeln!(f, "syscall counts ({} total syscall instructions):", self.total_syscall_count())?;
         for line in sorted_table_lines(self.syscall_counts.as_ref()) {
             writeln!(f, "  {line}")?;
         }
 
+        if let Some(heap_stats) = &self.heap_stats {
+            writeln!(f, "heap usage:")?;
+            writeln!(f, "  peak heap size: {} bytes", heap_stats.peak_heap_bytes)?;
+            writeln!(f, "  total allocated: {} bytes", heap_stats.total_allocated_bytes)?;
+            writeln!(f, "  allocations: {}", heap_stats.allocation_count)?;
+        }
+
         Ok(())
     }
 }
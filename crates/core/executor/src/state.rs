@@ -1,4 +1,5 @@
 use std::{
+    collections::{BTreeMap, VecDeque},
     fs::File,
     io::{Seek, Write},
 };
@@ -30,6 +31,9 @@ pub struct ExecutionState {
     /// executed in this shard.
     pub clk: u32,
 
+    /// The number of instructions that have been executed in this shard.
+    pub instret: u32,
+
     /// The channel alternates between 0 and [`crate::bytes::NUM_BYTE_LOOKUP_CHANNELS`],
     /// used to controll byte lookup multiplicity.
     pub channel: u8,
@@ -55,6 +59,10 @@ pub struct ExecutionState {
     /// A ptr to the current position in the input stream incremented by `HINT_READ` opcode.
     pub input_stream_ptr: usize,
 
+    /// The numbered input streams, other than the standard input stream, by id. The program moves
+    /// their entries to the input stream one at a time as it reads them.
+    pub input_streams: BTreeMap<u32, VecDeque<Vec<u8>>>,
+
     /// A stream of proofs inputted to the program.
     pub proof_stream: Vec<(ShardProof<BabyBearPoseidon2>, StarkVerifyingKey<BabyBearPoseidon2>)>,
 
@@ -70,6 +78,13 @@ pub struct ExecutionState {
 
     /// Keeps track of how many times a certain syscall has been called.
     pub syscall_counts: HashMap<SyscallCode, u64>,
+
+    /// Keeps track of how many times the registered curve syscalls have been called, per curve id.
+    pub registered_curve_counts: HashMap<(SyscallCode, u32), u64>,
+
+    /// The number of rows of the events since the start of their shard, for the syscalls whose
+    /// chips take a variable number of rows per event.
+    pub syscall_rows: HashMap<SyscallCode, u32>,
 }
 
 impl ExecutionState {
@@ -81,17 +96,21 @@ impl ExecutionState {
             // Start at shard 1 since shard 0 is reserved for memory initialization.
             current_shard: 1,
             clk: 0,
+            instret: 0,
             channel: 0,
             pc: pc_start,
             memory: PagedMemory::new_preallocated(),
             uninitialized_memory: HashMap::default(),
             input_stream: Vec::new(),
             input_stream_ptr: 0,
+            input_streams: BTreeMap::new(),
             public_values_stream: Vec::new(),
             public_values_stream_ptr: 0,
             proof_stream: Vec::new(),
             proof_stream_ptr: 0,
             syscall_counts: HashMap::new(),
+            registered_curve_counts: HashMap::new(),
+            syscall_rows: HashMap::new(),
         }
     }
 }
@@ -104,6 +123,8 @@ pub struct ForkState {
     pub global_clk: u64,
     /// The original `clk` value at the fork point.
     pub clk: u32,
+    /// The original `instret` value at the fork point.
+    pub instret: u32,
     /// The original `pc` value at the fork point.
     pub pc: u32,
     /// All memory changes since the fork point.
@@ -128,49 +128,171 @@ pub enum SyscallCode {
 
     /// Executes the `BN254_FP2_MUL` precompile.
     BN254_FP2_MUL = 0x00_01_01_2B,
+
+    /// Executes the `POSEIDON2_MERKLE_VERIFY` precompile.
+    POSEIDON2_MERKLE_VERIFY = 0x00_01_01_2C,
+
+    /// Executes the `CLMUL` precompile.
+    CLMUL = 0x00_01_01_2D,
+
+    /// Executes the `X25519_SCALAR_MULT` precompile.
+    X25519_SCALAR_MULT = 0x00_01_01_2E,
+
+    /// Executes the `BABYJUBJUB_ADD` precompile.
+    BABYJUBJUB_ADD = 0x00_01_01_2F,
+
+    /// Executes the `AES_ENCRYPT_BLOCK` precompile.
+    AES_ENCRYPT_BLOCK = 0x00_01_01_30,
+
+    /// Executes the `RANGE_CHECK` precompile.
+    RANGE_CHECK = 0x00_00_01_31,
+
+    /// Executes the `REGISTERED_CURVE_ADD` precompile.
+    REGISTERED_CURVE_ADD = 0x00_01_01_32,
+
+    /// Executes the `REGISTERED_CURVE_DOUBLE` precompile.
+    REGISTERED_CURVE_DOUBLE = 0x00_00_01_33,
+
+    /// Yields control to the host, which may inspect the public values and supply more input.
+    YIELD = 0x00_00_00_34,
+
+    /// Reports the heap usage of the program to the host.
+    HEAP_STATS = 0x00_00_00_35,
+
+    /// Executes the `U64_ADD` precompile.
+    U64_ADD = 0x00_01_01_36,
+
+    /// Executes the `U64_SUB` precompile.
+    U64_SUB = 0x00_01_01_37,
+
+    /// Executes the `U64_MUL` precompile.
+    U64_MUL = 0x00_01_01_38,
+
+    /// Executes the `U64_LTU` precompile.
+    U64_LTU = 0x00_01_01_39,
+
+    /// Executes the `FIXED_POINT_RECIP` precompile.
+    FIXED_POINT_RECIP = 0x00_01_01_3A,
+
+    /// Executes the `FIXED_POINT_SQRT` precompile.
+    FIXED_POINT_SQRT = 0x00_01_01_3B,
+
+    /// Executes the `FIXED_POINT_SIN` precompile.
+    FIXED_POINT_SIN = 0x00_01_01_3C,
+
+    /// Executes the `FIXED_POINT_COS` precompile.
+    FIXED_POINT_COS = 0x00_01_01_3D,
+
+    /// Executes the `BLS12381_G2_ADD` precompile.
+    BLS12381_G2_ADD = 0x00_01_01_3E,
+
+    /// Executes the `BLS12381_G2_DOUBLE` precompile.
+    BLS12381_G2_DOUBLE = 0x00_00_01_3F,
+
+    /// Executes the `SECP256K1_MAP_TO_CURVE` precompile.
+    SECP256K1_MAP_TO_CURVE = 0x00_00_01_40,
+
+    /// Executes the `BLS12381_MAP_TO_CURVE` precompile.
+    BLS12381_MAP_TO_CURVE = 0x00_00_01_41,
+
+    /// Executes the `HEX_ENCODE` precompile.
+    HEX_ENCODE = 0x00_01_01_42,
+
+    /// Executes the `HEX_DECODE` precompile.
+    HEX_DECODE = 0x00_01_01_43,
+
+    /// Executes the `BASE64_ENCODE` precompile.
+    BASE64_ENCODE = 0x00_01_01_44,
+
+    /// Executes the `BASE64_URL_ENCODE` precompile.
+    BASE64_URL_ENCODE = 0x00_01_01_45,
+
+    /// Executes the `BASE64_DECODE` precompile.
+    BASE64_DECODE = 0x00_01_01_46,
+
+    /// Executes the `BASE64_URL_DECODE` precompile.
+    BASE64_URL_DECODE = 0x00_01_01_47,
 }
 
 impl SyscallCode {
     /// Create a [`SyscallCode`] from a u32.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the value is not a valid syscall code.
     #[must_use]
     pub fn from_u32(value: u32) -> Self {
+        Self::try_from_u32(value).unwrap_or_else(|| panic!("invalid syscall number: {value}"))
+    }
+
+    /// Create a [`SyscallCode`] from a u32, or `None` if the value is not a valid syscall code.
+    #[must_use]
+    pub fn try_from_u32(value: u32) -> Option<Self> {
         match value {
-            0x00_00_00_00 => SyscallCode::HALT,
-            0x00_00_00_02 => SyscallCode::WRITE,
-            0x00_00_00_03 => SyscallCode::ENTER_UNCONSTRAINED,
-            0x00_00_00_04 => SyscallCode::EXIT_UNCONSTRAINED,
-            0x00_30_01_05 => SyscallCode::SHA_EXTEND,
-            0x00_01_01_06 => SyscallCode::SHA_COMPRESS,
-            0x00_01_01_07 => SyscallCode::ED_ADD,
-            0x00_00_01_08 => SyscallCode::ED_DECOMPRESS,
-            0x00_01_01_09 => SyscallCode::KECCAK_PERMUTE,
-            0x00_01_01_0A => SyscallCode::SECP256K1_ADD,
-            0x00_00_01_0B => SyscallCode::SECP256K1_DOUBLE,
-            0x00_00_01_0C => SyscallCode::SECP256K1_DECOMPRESS,
-            0x00_01_01_0E => SyscallCode::BN254_ADD,
-            0x00_00_01_0F => SyscallCode::BN254_DOUBLE,
-            0x00_01_01_1E => SyscallCode::BLS12381_ADD,
-            0x00_00_01_1F => SyscallCode::BLS12381_DOUBLE,
-            0x00_00_00_10 => SyscallCode::COMMIT,
-            0x00_00_00_1A => SyscallCode::COMMIT_DEFERRED_PROOFS,
-            0x00_00_00_1B => SyscallCode::VERIFY_SP1_PROOF,
-            0x00_00_00_F0 => SyscallCode::HINT_LEN,
-            0x00_00_00_F1 => SyscallCode::HINT_READ,
-            0x00_01_01_1D => SyscallCode::UINT256_MUL,
-            0x00_01_01_20 => SyscallCode::BLS12381_FP_ADD,
-            0x00_01_01_21 => SyscallCode::BLS12381_FP_SUB,
-            0x00_01_01_22 => SyscallCode::BLS12381_FP_MUL,
-            0x00_01_01_23 => SyscallCode::BLS12381_FP2_ADD,
-            0x00_01_01_24 => SyscallCode::BLS12381_FP2_SUB,
-            0x00_01_01_25 => SyscallCode::BLS12381_FP2_MUL,
-            0x00_01_01_26 => SyscallCode::BN254_FP_ADD,
-            0x00_01_01_27 => SyscallCode::BN254_FP_SUB,
-            0x00_01_01_28 => SyscallCode::BN254_FP_MUL,
-            0x00_01_01_29 => SyscallCode::BN254_FP2_ADD,
-            0x00_01_01_2A => SyscallCode::BN254_FP2_SUB,
-            0x00_01_01_2B => SyscallCode::BN254_FP2_MUL,
-            0x00_00_01_1C => SyscallCode::BLS12381_DECOMPRESS,
-            _ => panic!("invalid syscall number: {value}"),
+            0x00_00_00_00 => Some(SyscallCode::HALT),
+            0x00_00_00_02 => Some(SyscallCode::WRITE),
+            0x00_00_00_03 => Some(SyscallCode::ENTER_UNCONSTRAINED),
+            0x00_00_00_04 => Some(SyscallCode::EXIT_UNCONSTRAINED),
+            0x00_30_01_05 => Some(SyscallCode::SHA_EXTEND),
+            0x00_01_01_06 => Some(SyscallCode::SHA_COMPRESS),
+            0x00_01_01_07 => Some(SyscallCode::ED_ADD),
+            0x00_00_01_08 => Some(SyscallCode::ED_DECOMPRESS),
+            0x00_01_01_09 => Some(SyscallCode::KECCAK_PERMUTE),
+            0x00_01_01_0A => Some(SyscallCode::SECP256K1_ADD),
+            0x00_00_01_0B => Some(SyscallCode::SECP256K1_DOUBLE),
+            0x00_00_01_0C => Some(SyscallCode::SECP256K1_DECOMPRESS),
+            0x00_01_01_0E => Some(SyscallCode::BN254_ADD),
+            0x00_00_01_0F => Some(SyscallCode::BN254_DOUBLE),
+            0x00_01_01_1E => Some(SyscallCode::BLS12381_ADD),
+            0x00_00_01_1F => Some(SyscallCode::BLS12381_DOUBLE),
+            0x00_00_00_10 => Some(SyscallCode::COMMIT),
+            0x00_00_00_1A => Some(SyscallCode::COMMIT_DEFERRED_PROOFS),
+            0x00_00_00_1B => Some(SyscallCode::VERIFY_SP1_PROOF),
+            0x00_00_00_F0 => Some(SyscallCode::HINT_LEN),
+            0x00_00_00_F1 => Some(SyscallCode::HINT_READ),
+            0x00_01_01_1D => Some(SyscallCode::UINT256_MUL),
+            0x00_01_01_20 => Some(SyscallCode::BLS12381_FP_ADD),
+            0x00_01_01_21 => Some(SyscallCode::BLS12381_FP_SUB),
+            0x00_01_01_22 => Some(SyscallCode::BLS12381_FP_MUL),
+            0x00_01_01_23 => Some(SyscallCode::BLS12381_FP2_ADD),
+            0x00_01_01_24 => Some(SyscallCode::BLS12381_FP2_SUB),
+            0x00_01_01_25 => Some(SyscallCode::BLS12381_FP2_MUL),
+            0x00_01_01_26 => Some(SyscallCode::BN254_FP_ADD),
+            0x00_01_01_27 => Some(SyscallCode::BN254_FP_SUB),
+            0x00_01_01_28 => Some(SyscallCode::BN254_FP_MUL),
+            0x00_01_01_29 => Some(SyscallCode::BN254_FP2_ADD),
+            0x00_01_01_2A => Some(SyscallCode::BN254_FP2_SUB),
+            0x00_01_01_2B => Some(SyscallCode::BN254_FP2_MUL),
+            0x00_01_01_2C => Some(SyscallCode::POSEIDON2_MERKLE_VERIFY),
+            0x00_01_01_2D => Some(SyscallCode::CLMUL),
+            0x00_01_01_2E => Some(SyscallCode::X25519_SCALAR_MULT),
+            0x00_01_01_2F => Some(SyscallCode::BABYJUBJUB_ADD),
+            0x00_01_01_30 => Some(SyscallCode::AES_ENCRYPT_BLOCK),
+            0x00_00_01_31 => Some(SyscallCode::RANGE_CHECK),
+            0x00_01_01_32 => Some(SyscallCode::REGISTERED_CURVE_ADD),
+            0x00_00_01_33 => Some(SyscallCode::REGISTERED_CURVE_DOUBLE),
+            0x00_00_00_34 => Some(SyscallCode::YIELD),
+            0x00_00_00_35 => Some(SyscallCode::HEAP_STATS),
+            0x00_01_01_36 => Some(SyscallCode::U64_ADD),
+            0x00_01_01_37 => Some(SyscallCode::U64_SUB),
+            0x00_01_01_38 => Some(SyscallCode::U64_MUL),
+            0x00_01_01_39 => Some(SyscallCode::U64_LTU),
+            0x00_01_01_3A => Some(SyscallCode::FIXED_POINT_RECIP),
+            0x00_01_01_3B => Some(SyscallCode::FIXED_POINT_SQRT),
+            0x00_01_01_3C => Some(SyscallCode::FIXED_POINT_SIN),
+            0x00_01_01_3D => Some(SyscallCode::FIXED_POINT_COS),
+            0x00_01_01_3E => Some(SyscallCode::BLS12381_G2_ADD),
+            0x00_00_01_3F => Some(SyscallCode::BLS12381_G2_DOUBLE),
+            0x00_00_01_40 => Some(SyscallCode::SECP256K1_MAP_TO_CURVE),
+            0x00_00_01_41 => Some(SyscallCode::BLS12381_MAP_TO_CURVE),
+            0x00_01_01_42 => Some(SyscallCode::HEX_ENCODE),
+            0x00_01_01_43 => Some(SyscallCode::HEX_DECODE),
+            0x00_01_01_44 => Some(SyscallCode::BASE64_ENCODE),
+            0x00_01_01_45 => Some(SyscallCode::BASE64_URL_ENCODE),
+            0x00_01_01_46 => Some(SyscallCode::BASE64_DECODE),
+            0x00_01_01_47 => Some(SyscallCode::BASE64_URL_DECODE),
+            0x00_00_01_1C => Some(SyscallCode::BLS12381_DECOMPRESS),
+            _ => None,
         }
     }
 
@@ -203,6 +325,16 @@ impl SyscallCode {
             SyscallCode::BLS12381_FP_SUB => SyscallCode::BLS12381_FP_ADD,
             SyscallCode::BLS12381_FP_MUL => SyscallCode::BLS12381_FP_ADD,
             SyscallCode::BLS12381_FP2_SUB => SyscallCode::BLS12381_FP2_ADD,
+            SyscallCode::U64_SUB => SyscallCode::U64_ADD,
+            SyscallCode::U64_MUL => SyscallCode::U64_ADD,
+            SyscallCode::U64_LTU => SyscallCode::U64_ADD,
+            SyscallCode::FIXED_POINT_SQRT => SyscallCode::FIXED_POINT_RECIP,
+            SyscallCode::FIXED_POINT_SIN => SyscallCode::FIXED_POINT_RECIP,
+            SyscallCode::FIXED_POINT_COS => SyscallCode::FIXED_POINT_RECIP,
+            SyscallCode::HEX_DECODE => SyscallCode::HEX_ENCODE,
+            SyscallCode::BASE64_URL_ENCODE => SyscallCode::BASE64_ENCODE,
+            SyscallCode::BASE64_DECODE => SyscallCode::BASE64_ENCODE,
+            SyscallCode::BASE64_URL_DECODE => SyscallCode::BASE64_ENCODE,
             _ => *self,
         }
     }
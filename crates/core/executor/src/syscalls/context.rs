@@ -1,7 +1,7 @@
 use crate::{
     events::{LookupId, MemoryReadRecord, MemoryWriteRecord},
     record::ExecutionRecord,
-    Executor, Register,
+    ExecutionError, Executor, Register,
 };
 
 /// A runtime for syscalls that is protected so that developers cannot arbitrarily modify the
@@ -20,6 +20,9 @@ pub struct SyscallContext<'a, 'b: 'a> {
     pub rt: &'a mut Executor<'b>,
     /// The syscall lookup id.
     pub syscall_lookup_id: LookupId,
+    /// The error of a syscall called with invalid arguments, returned by the executor once the
+    /// syscall returns.
+    pub error: Option<ExecutionError>,
 }
 
 impl<'a, 'b> SyscallContext<'a, 'b> {
@@ -34,6 +37,7 @@ impl<'a, 'b> SyscallContext<'a, 'b> {
             exit_code: 0,
             rt: runtime,
             syscall_lookup_id: LookupId::default(),
+            error: None,
         }
     }
 
@@ -0,0 +1,21 @@
+use crate::report::HeapStats;
+
+use super::{context::SyscallContext, Syscall};
+
+pub(crate) struct HeapStatsSyscall;
+
+impl Syscall for HeapStatsSyscall {
+    /// Records the heap usage reported by the program in the execution report.
+    ///
+    /// `arg1` points to the peak heap size in bytes, the total number of bytes allocated and the
+    /// number of allocations, as three consecutive words.
+    fn execute(&self, ctx: &mut SyscallContext, arg1: u32, _: u32) -> Option<u32> {
+        let stats = ctx.slice_unsafe(arg1, 3);
+        ctx.rt.report.heap_stats = Some(HeapStats {
+            peak_heap_bytes: stats[0].into(),
+            total_allocated_bytes: stats[1].into(),
+            allocation_count: stats[2].into(),
+        });
+        None
+    }
+}
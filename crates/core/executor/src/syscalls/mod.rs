@@ -5,11 +5,13 @@ mod commit;
 mod context;
 mod deferred;
 mod halt;
+mod heap_stats;
 mod hint;
 mod precompiles;
 mod unconstrained;
 mod verify;
 mod write;
+mod yield_to_host;
 
 use std::sync::Arc;
 
@@ -17,24 +19,53 @@ use commit::CommitSyscall;
 use deferred::CommitDeferredSyscall;
 use halt::HaltSyscall;
 use hashbrown::HashMap;
+use heap_stats::HeapStatsSyscall;
 
 pub use code::*;
 pub use context::*;
 use hint::{HintLenSyscall, HintReadSyscall};
 use precompiles::{
+    aes::AesEncryptBlockSyscall,
+    bls12381_g2::{Bls12381G2AddSyscall, Bls12381G2DoubleSyscall},
+    clmul::ClmulSyscall,
+    codec::CodecSyscall,
     edwards::{add::EdwardsAddAssignSyscall, decompress::EdwardsDecompressSyscall},
+    fixed_point::FixedPointSyscall,
     fptower::{Fp2AddSubSyscall, Fp2MulSyscall, FpOpSyscall},
     keccak256::permute::Keccak256PermuteSyscall,
+    poseidon2_merkle::Poseidon2MerkleVerifySyscall,
+    range_check::RangeCheckSyscall,
     sha256::{compress::Sha256CompressSyscall, extend::Sha256ExtendSyscall},
     uint256::Uint256MulSyscall,
     weierstrass::{
-        add::WeierstrassAddAssignSyscall, decompress::WeierstrassDecompressSyscall,
+        add::WeierstrassAddAssignSyscall,
+        decompress::WeierstrassDecompressSyscall,
         double::WeierstrassDoubleAssignSyscall,
+        map::WeierstrassMapToCurveSyscall,
+        registered::{RegisteredCurveAddSyscall, RegisteredCurveDoubleSyscall},
     },
+    wide_alu::WideAluSyscall,
+    x25519::X25519Syscall,
+};
+pub use precompiles::{
+    aes::{aes_encrypt_block, aes_round, aes_xtime, AES_128_ROUNDS, AES_256_ROUNDS, AES_SBOX},
+    clmul::clmul128,
+    codec::{
+        base64_char, base64_value, codec_decode, codec_encode, hex_char, hex_value,
+        BASE64_ALPHABETS, HEX_ALPHABETS,
+    },
+    fixed_point::{fixed_point, FIXED_POINT_TRIG_OFFSET},
+    poseidon2_merkle::poseidon2_compress,
+    range_check::RANGE_CHECK_MAX_BITS,
+    wide_alu::{wide_alu, WIDE_ALU_WORDS},
+    x25519::{x25519_scalar_mult, X25519_A24, X25519_LADDER_STEPS, X25519_WORDS},
 };
 
 use sp1_curves::{
-    edwards::ed25519::{Ed25519, Ed25519Parameters},
+    edwards::{
+        babyjubjub::BabyJubjub,
+        ed25519::{Ed25519, Ed25519Parameters},
+    },
     weierstrass::{
         bls12_381::{Bls12381, Bls12381BaseField},
         bn254::{Bn254, Bn254BaseField},
@@ -44,8 +75,9 @@ use sp1_curves::{
 use unconstrained::{EnterUnconstrainedSyscall, ExitUnconstrainedSyscall};
 use verify::VerifySyscall;
 use write::WriteSyscall;
+use yield_to_host::YieldSyscall;
 
-use crate::events::FieldOperation;
+use crate::events::{Codec, CodecOperation, FieldOperation, FixedPointFunction, WideAluOperation};
 
 /// A system call in the SP1 RISC-V zkVM.
 ///
@@ -120,8 +152,55 @@ pub fn default_syscall_map() -> HashMap<SyscallCode, Arc<dyn Syscall>> {
         Arc::new(WeierstrassDoubleAssignSyscall::<Bls12381>::new()),
     );
 
+    syscall_map.insert(SyscallCode::BLS12381_G2_ADD, Arc::new(Bls12381G2AddSyscall));
+
+    syscall_map.insert(SyscallCode::BLS12381_G2_DOUBLE, Arc::new(Bls12381G2DoubleSyscall));
+
     syscall_map.insert(SyscallCode::UINT256_MUL, Arc::new(Uint256MulSyscall));
 
+    syscall_map
+        .insert(SyscallCode::POSEIDON2_MERKLE_VERIFY, Arc::new(Poseidon2MerkleVerifySyscall));
+
+    syscall_map.insert(SyscallCode::CLMUL, Arc::new(ClmulSyscall));
+
+    syscall_map.insert(SyscallCode::X25519_SCALAR_MULT, Arc::new(X25519Syscall));
+
+    syscall_map.insert(
+        SyscallCode::BABYJUBJUB_ADD,
+        Arc::new(EdwardsAddAssignSyscall::<BabyJubjub>::new()),
+    );
+
+    syscall_map.insert(SyscallCode::AES_ENCRYPT_BLOCK, Arc::new(AesEncryptBlockSyscall));
+
+    syscall_map.insert(SyscallCode::RANGE_CHECK, Arc::new(RangeCheckSyscall));
+
+    syscall_map.insert(SyscallCode::REGISTERED_CURVE_ADD, Arc::new(RegisteredCurveAddSyscall));
+
+    syscall_map
+        .insert(SyscallCode::REGISTERED_CURVE_DOUBLE, Arc::new(RegisteredCurveDoubleSyscall));
+
+    syscall_map.insert(SyscallCode::U64_ADD, Arc::new(WideAluSyscall::new(WideAluOperation::Add)));
+    syscall_map.insert(SyscallCode::U64_SUB, Arc::new(WideAluSyscall::new(WideAluOperation::Sub)));
+    syscall_map.insert(SyscallCode::U64_MUL, Arc::new(WideAluSyscall::new(WideAluOperation::Mul)));
+    syscall_map.insert(SyscallCode::U64_LTU, Arc::new(WideAluSyscall::new(WideAluOperation::Ltu)));
+
+    syscall_map.insert(
+        SyscallCode::FIXED_POINT_RECIP,
+        Arc::new(FixedPointSyscall::new(FixedPointFunction::Reciprocal)),
+    );
+    syscall_map.insert(
+        SyscallCode::FIXED_POINT_SQRT,
+        Arc::new(FixedPointSyscall::new(FixedPointFunction::Sqrt)),
+    );
+    syscall_map.insert(
+        SyscallCode::FIXED_POINT_SIN,
+        Arc::new(FixedPointSyscall::new(FixedPointFunction::Sin)),
+    );
+    syscall_map.insert(
+        SyscallCode::FIXED_POINT_COS,
+        Arc::new(FixedPointSyscall::new(FixedPointFunction::Cos)),
+    );
+
     syscall_map.insert(
         SyscallCode::BLS12381_FP_ADD,
         Arc::new(FpOpSyscall::<Bls12381BaseField>::new(FieldOperation::Add)),
@@ -194,10 +273,54 @@ pub fn default_syscall_map() -> HashMap<SyscallCode, Arc<dyn Syscall>> {
 
     syscall_map.insert(SyscallCode::HINT_READ, Arc::new(HintReadSyscall));
 
+    syscall_map.insert(SyscallCode::YIELD, Arc::new(YieldSyscall));
+
+    syscall_map.insert(SyscallCode::HEAP_STATS, Arc::new(HeapStatsSyscall));
+
     syscall_map.insert(
         SyscallCode::BLS12381_DECOMPRESS,
         Arc::new(WeierstrassDecompressSyscall::<Bls12381>::new()),
     );
 
+    syscall_map.insert(
+        SyscallCode::SECP256K1_MAP_TO_CURVE,
+        Arc::new(WeierstrassMapToCurveSyscall::<Secp256k1>::new()),
+    );
+
+    syscall_map.insert(
+        SyscallCode::BLS12381_MAP_TO_CURVE,
+        Arc::new(WeierstrassMapToCurveSyscall::<Bls12381>::new()),
+    );
+
+    syscall_map.insert(
+        SyscallCode::HEX_ENCODE,
+        Arc::new(CodecSyscall::new(Codec::Hex, CodecOperation::Encode)),
+    );
+
+    syscall_map.insert(
+        SyscallCode::HEX_DECODE,
+        Arc::new(CodecSyscall::new(Codec::Hex, CodecOperation::Decode)),
+    );
+
+    syscall_map.insert(
+        SyscallCode::BASE64_ENCODE,
+        Arc::new(CodecSyscall::new(Codec::Base64, CodecOperation::Encode)),
+    );
+
+    syscall_map.insert(
+        SyscallCode::BASE64_URL_ENCODE,
+        Arc::new(CodecSyscall::new(Codec::Base64Url, CodecOperation::Encode)),
+    );
+
+    syscall_map.insert(
+        SyscallCode::BASE64_DECODE,
+        Arc::new(CodecSyscall::new(Codec::Base64, CodecOperation::Decode)),
+    );
+
+    syscall_map.insert(
+        SyscallCode::BASE64_URL_DECODE,
+        Arc::new(CodecSyscall::new(Codec::Base64Url, CodecOperation::Decode)),
+    );
+
     syscall_map
 }
@@ -0,0 +1,188 @@
+use crate::{
+    events::{AesEncryptBlockEvent, AES_BLOCK_WORDS},
+    syscalls::{Syscall, SyscallContext},
+};
+
+pub(crate) struct AesEncryptBlockSyscall;
+
+/// The number of rounds of AES-128.
+pub const AES_128_ROUNDS: u32 = 10;
+
+/// The number of rounds of AES-256.
+pub const AES_256_ROUNDS: u32 = 14;
+
+/// The S-box of AES.
+pub const AES_SBOX: [u8; 256] = [
+    0x63, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5, 0x30, 0x01, 0x67, 0x2b, 0xfe, 0xd7, 0xab, 0x76,
+    0xca, 0x82, 0xc9, 0x7d, 0xfa, 0x59, 0x47, 0xf0, 0xad, 0xd4, 0xa2, 0xaf, 0x9c, 0xa4, 0x72, 0xc0,
+    0xb7, 0xfd, 0x93, 0x26, 0x36, 0x3f, 0xf7, 0xcc, 0x34, 0xa5, 0xe5, 0xf1, 0x71, 0xd8, 0x31, 0x15,
+    0x04, 0xc7, 0x23, 0xc3, 0x18, 0x96, 0x05, 0x9a, 0x07, 0x12, 0x80, 0xe2, 0xeb, 0x27, 0xb2, 0x75,
+    0x09, 0x83, 0x2c, 0x1a, 0x1b, 0x6e, 0x5a, 0xa0, 0x52, 0x3b, 0xd6, 0xb3, 0x29, 0xe3, 0x2f, 0x84,
+    0x53, 0xd1, 0x00, 0xed, 0x20, 0xfc, 0xb1, 0x5b, 0x6a, 0xcb, 0xbe, 0x39, 0x4a, 0x4c, 0x58, 0xcf,
+    0xd0, 0xef, 0xaa, 0xfb, 0x43, 0x4d, 0x33, 0x85, 0x45, 0xf9, 0x02, 0x7f, 0x50, 0x3c, 0x9f, 0xa8,
+    0x51, 0xa3, 0x40, 0x8f, 0x92, 0x9d, 0x38, 0xf5, 0xbc, 0xb6, 0xda, 0x21, 0x10, 0xff, 0xf3, 0xd2,
+    0xcd, 0x0c, 0x13, 0xec, 0x5f, 0x97, 0x44, 0x17, 0xc4, 0xa7, 0x7e, 0x3d, 0x64, 0x5d, 0x19, 0x73,
+    0x60, 0x81, 0x4f, 0xdc, 0x22, 0x2a, 0x90, 0x88, 0x46, 0xee, 0xb8, 0x14, 0xde, 0x5e, 0x0b, 0xdb,
+    0xe0, 0x32, 0x3a, 0x0a, 0x49, 0x06, 0x24, 0x5c, 0xc2, 0xd3, 0xac, 0x62, 0x91, 0x95, 0xe4, 0x79,
+    0xe7, 0xc8, 0x37, 0x6d, 0x8d, 0xd5, 0x4e, 0xa9, 0x6c, 0x56, 0xf4, 0xea, 0x65, 0x7a, 0xae, 0x08,
+    0xba, 0x78, 0x25, 0x2e, 0x1c, 0xa6, 0xb4, 0xc6, 0xe8, 0xdd, 0x74, 0x1f, 0x4b, 0xbd, 0x8b, 0x8a,
+    0x70, 0x3e, 0xb5, 0x66, 0x48, 0x03, 0xf6, 0x0e, 0x61, 0x35, 0x57, 0xb9, 0x86, 0xc1, 0x1d, 0x9e,
+    0xe1, 0xf8, 0x98, 0x11, 0x69, 0xd9, 0x8e, 0x94, 0x9b, 0x1e, 0x87, 0xe9, 0xce, 0x55, 0x28, 0xdf,
+    0x8c, 0xa1, 0x89, 0x0d, 0xbf, 0xe6, 0x42, 0x68, 0x41, 0x99, 0x2d, 0x0f, 0xb0, 0x54, 0xbb, 0x16,
+];
+
+/// Multiplies `a` by `x` in the field `GF(2^8)` of AES.
+#[must_use]
+pub fn aes_xtime(a: u8) -> u8 {
+    (a << 1) ^ ((a >> 7) * 0x1b)
+}
+
+/// Applies SubBytes, ShiftRows and, unless `last`, MixColumns to an AES state.
+///
+/// Byte `4 * c + r` of the state is the byte in row `r` and column `c`, which is the byte order
+/// of a block in memory.
+#[must_use]
+pub fn aes_round(state: &[u8; 16], last: bool) -> [u8; 16] {
+    let shifted: [u8; 16] =
+        core::array::from_fn(|i| AES_SBOX[state[4 * ((i / 4 + i % 4) % 4) + i % 4] as usize]);
+    if last {
+        return shifted;
+    }
+    core::array::from_fn(|i| {
+        let (c, r) = (i / 4, i % 4);
+        let a = |r: usize| shifted[4 * c + r % 4];
+        let column = a(0) ^ a(1) ^ a(2) ^ a(3);
+        a(r) ^ column ^ aes_xtime(a(r) ^ a(r + 1))
+    })
+}
+
+/// Encrypts `block` with the `rounds + 1` round keys of an expanded AES key.
+#[must_use]
+pub fn aes_encrypt_block(block: &[u8; 16], round_keys: &[[u8; 16]]) -> [u8; 16] {
+    let rounds = round_keys.len() - 1;
+    let mut state: [u8; 16] = core::array::from_fn(|i| block[i] ^ round_keys[0][i]);
+    for (round, round_key) in round_keys.iter().enumerate().skip(1) {
+        state = aes_round(&state, round == rounds);
+        state.iter_mut().zip(round_key).for_each(|(byte, key)| *byte ^= key);
+    }
+    state
+}
+
+fn words_to_bytes(words: &[u32; AES_BLOCK_WORDS]) -> [u8; 16] {
+    core::array::from_fn(|i| words[i / 4].to_le_bytes()[i % 4])
+}
+
+fn bytes_to_words(bytes: &[u8; 16]) -> [u32; AES_BLOCK_WORDS] {
+    core::array::from_fn(|i| u32::from_le_bytes(bytes[4 * i..4 * i + 4].try_into().unwrap()))
+}
+
+impl Syscall for AesEncryptBlockSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+        let block_ptr = arg1;
+        let key_ptr = arg2;
+        assert!(block_ptr % 4 == 0, "block_ptr must be word aligned");
+        assert!(key_ptr % 4 == 0, "key_ptr must be word aligned");
+
+        let (header_read_record, rounds) = rt.mr(key_ptr);
+        assert!(
+            rounds == AES_128_ROUNDS || rounds == AES_256_ROUNDS,
+            "aes rounds must be {AES_128_ROUNDS} or {AES_256_ROUNDS}, got {rounds}"
+        );
+        let key_end = key_ptr + 4 + (rounds + 1) * 16;
+        let block_end = block_ptr + 16;
+        assert!(
+            key_end <= block_ptr || block_end <= key_ptr,
+            "aes key schedule must not overlap the block"
+        );
+
+        let (input_read_records, input) = rt.mr_slice(block_ptr, AES_BLOCK_WORDS);
+        let input: [u32; AES_BLOCK_WORDS] = input.try_into().unwrap();
+        let mut round_keys = Vec::with_capacity(rounds as usize + 1);
+        let mut round_key_read_records = Vec::with_capacity(rounds as usize + 1);
+        for round in 0..=rounds {
+            let (records, round_key) = rt.mr_slice(key_ptr + 4 + round * 16, AES_BLOCK_WORDS);
+            round_keys.push(round_key.try_into().unwrap());
+            round_key_read_records.push(records);
+        }
+
+        let round_key_bytes = round_keys.iter().map(words_to_bytes).collect::<Vec<_>>();
+        let output = bytes_to_words(&aes_encrypt_block(&words_to_bytes(&input), &round_key_bytes));
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        let output_write_records = rt.mw_slice(block_ptr, &output);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().aes_encrypt_block_events.push(AesEncryptBlockEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            block_ptr,
+            key_ptr,
+            rounds,
+            input,
+            round_keys,
+            output,
+            header_read_record,
+            input_read_records,
+            round_key_read_records,
+            output_write_records,
+        });
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{aes_encrypt_block, aes_xtime, AES_SBOX};
+
+    fn to_bytes<const N: usize>(hex: &str) -> [u8; N] {
+        hex::decode(hex).unwrap().try_into().unwrap()
+    }
+
+    /// Expands a key like FIPS 197, with round keys in the byte order of blocks.
+    fn expand_key(key: &[u8]) -> Vec<[u8; 16]> {
+        let nk = key.len() / 4;
+        let rounds = nk + 6;
+        let mut words: Vec<[u8; 4]> = key.chunks(4).map(|w| w.try_into().unwrap()).collect();
+        let mut rcon = 1u8;
+        for i in nk..4 * (rounds + 1) {
+            let mut temp = words[i - 1];
+            if i % nk == 0 {
+                temp = [temp[1], temp[2], temp[3], temp[0]].map(|b| AES_SBOX[b as usize]);
+                temp[0] ^= rcon;
+                rcon = aes_xtime(rcon);
+            } else if nk > 6 && i % nk == 4 {
+                temp = temp.map(|b| AES_SBOX[b as usize]);
+            }
+            words.push(core::array::from_fn(|j| words[i - nk][j] ^ temp[j]));
+        }
+        words.chunks(4).map(|rk| rk.concat().try_into().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_aes_128_fips197_vector() {
+        let key: [u8; 16] = to_bytes("000102030405060708090a0b0c0d0e0f");
+        let block = to_bytes("00112233445566778899aabbccddeeff");
+        let expected: [u8; 16] = to_bytes("69c4e0d86a7b0430d8cdb78070b4c55a");
+        assert_eq!(aes_encrypt_block(&block, &expand_key(&key)), expected);
+    }
+
+    #[test]
+    fn test_aes_256_fips197_vector() {
+        let key: [u8; 32] =
+            to_bytes("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+        let block = to_bytes("00112233445566778899aabbccddeeff");
+        let expected: [u8; 16] = to_bytes("8ea2b7ca516745bfeafc49904b496089");
+        assert_eq!(aes_encrypt_block(&block, &expand_key(&key)), expected);
+    }
+}
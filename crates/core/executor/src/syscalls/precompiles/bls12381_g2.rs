@@ -0,0 +1,85 @@
+use sp1_curves::weierstrass::bls12_381::{
+    bls12381_g2_add, bls12381_g2_double, bls12381_g2_from_words_le, bls12381_g2_to_words_le,
+    BLS12381_G2_POINT_WORDS,
+};
+
+use crate::{
+    events::{EllipticCurveAddEvent, EllipticCurveDoubleEvent},
+    syscalls::{Syscall, SyscallContext},
+};
+
+/// Adds two affine points of the BLS12-381 G2 curve, writing the sum to the first one.
+pub(crate) struct Bls12381G2AddSyscall;
+
+impl Syscall for Bls12381G2AddSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let start_clk = rt.clk;
+        let p_ptr = arg1;
+        assert!(p_ptr % 4 == 0, "p_ptr must be 4-byte aligned");
+        let q_ptr = arg2;
+        assert!(q_ptr % 4 == 0, "q_ptr must be 4-byte aligned");
+
+        let p = rt.slice_unsafe(p_ptr, BLS12381_G2_POINT_WORDS);
+        let (q_memory_records, q) = rt.mr_slice(q_ptr, BLS12381_G2_POINT_WORDS);
+
+        // When we write to p, we want the clk to be incremented because p and q could be the same.
+        rt.clk += 1;
+
+        let result =
+            bls12381_g2_add(&bls12381_g2_from_words_le(&p), &bls12381_g2_from_words_le(&q));
+        let p_memory_records = rt.mw_slice(p_ptr, &bls12381_g2_to_words_le(&result));
+
+        let event = EllipticCurveAddEvent {
+            lookup_id: rt.syscall_lookup_id,
+            shard: rt.current_shard(),
+            channel: rt.current_channel(),
+            clk: start_clk,
+            p_ptr,
+            p,
+            q_ptr,
+            q,
+            p_memory_records,
+            q_memory_records,
+        };
+        rt.record_mut().bls12381_g2_add_events.push(event);
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+/// Doubles an affine point of the BLS12-381 G2 curve in place.
+pub(crate) struct Bls12381G2DoubleSyscall;
+
+impl Syscall for Bls12381G2DoubleSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, _: u32) -> Option<u32> {
+        let start_clk = rt.clk;
+        let p_ptr = arg1;
+        assert!(p_ptr % 4 == 0, "p_ptr must be 4-byte aligned");
+
+        let p = rt.slice_unsafe(p_ptr, BLS12381_G2_POINT_WORDS);
+
+        let result = bls12381_g2_double(&bls12381_g2_from_words_le(&p));
+        let p_memory_records = rt.mw_slice(p_ptr, &bls12381_g2_to_words_le(&result));
+
+        let event = EllipticCurveDoubleEvent {
+            lookup_id: rt.syscall_lookup_id,
+            shard: rt.current_shard(),
+            channel: rt.current_channel(),
+            clk: start_clk,
+            p_ptr,
+            p,
+            p_memory_records,
+        };
+        rt.record_mut().bls12381_g2_double_events.push(event);
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        0
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{
+    events::ClmulEvent,
+    syscalls::{Syscall, SyscallContext},
+};
+
+/// The number of words in a carry-less multiplication input.
+pub const CLMUL_INPUT_WORDS: usize = 4;
+
+/// The number of words in a carry-less multiplication product.
+pub const CLMUL_OUTPUT_WORDS: usize = 2 * CLMUL_INPUT_WORDS;
+
+/// Computes the 256-bit carry-less product of two 128-bit values, as little endian words.
+#[must_use]
+pub fn clmul128(
+    x: &[u32; CLMUL_INPUT_WORDS],
+    y: &[u32; CLMUL_INPUT_WORDS],
+) -> [u32; CLMUL_OUTPUT_WORDS] {
+    let to_u128 = |words: &[u32; CLMUL_INPUT_WORDS]| {
+        words.iter().rev().fold(0u128, |acc, word| (acc << 32) | u128::from(*word))
+    };
+    let (x, y) = (to_u128(x), to_u128(y));
+    let (mut lo, mut hi) = (0u128, 0u128);
+    for i in 0..128 {
+        if (x >> i) & 1 == 1 {
+            lo ^= y << i;
+            if i > 0 {
+                hi ^= y >> (128 - i);
+            }
+        }
+    }
+    let mut product = [0u32; CLMUL_OUTPUT_WORDS];
+    for i in 0..CLMUL_INPUT_WORDS {
+        product[i] = (lo >> (32 * i)) as u32;
+        product[i + CLMUL_INPUT_WORDS] = (hi >> (32 * i)) as u32;
+    }
+    product
+}
+
+pub(crate) struct ClmulSyscall;
+
+impl Syscall for ClmulSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+
+        let x_ptr = arg1;
+        if x_ptr % 4 != 0 {
+            panic!();
+        }
+        let y_ptr = arg2;
+        if y_ptr % 4 != 0 {
+            panic!();
+        }
+
+        // The x value is in the low half of the output buffer. We can read a slice_unsafe here
+        // because we write the whole buffer later.
+        let x = rt.slice_unsafe(x_ptr, CLMUL_INPUT_WORDS);
+        let (y_memory_records, y) = rt.mr_slice(y_ptr, CLMUL_INPUT_WORDS);
+
+        let product = clmul128(&x.clone().try_into().unwrap(), &y.clone().try_into().unwrap());
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        let x_memory_records = rt.mw_slice(x_ptr, &product);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().clmul_events.push(ClmulEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            x_ptr,
+            x,
+            y_ptr,
+            y,
+            x_memory_records,
+            y_memory_records,
+        });
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::clmul128;
+
+    #[test]
+    fn test_clmul128() {
+        // (x + 1) * (x + 1) = x^2 + 1 over GF(2).
+        assert_eq!(clmul128(&[3, 0, 0, 0], &[3, 0, 0, 0]), [5, 0, 0, 0, 0, 0, 0, 0]);
+        // x^127 * x^127 = x^254.
+        let top = [0, 0, 0, 1 << 31];
+        assert_eq!(clmul128(&top, &top), [0, 0, 0, 0, 0, 0, 0, 1 << 30]);
+        // x^32 * (x^31 + x^0) spans a word boundary.
+        assert_eq!(
+            clmul128(&[0, 1, 0, 0], &[0x8000_0001, 0, 0, 0]),
+            [0, 0x8000_0001, 0, 0, 0, 0, 0, 0]
+        );
+    }
+}
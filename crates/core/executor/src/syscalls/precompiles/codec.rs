@@ -0,0 +1,264 @@
+use crate::{
+    events::{Codec, CodecEvent, CodecOperation, MemoryRecordEnum},
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+    ExecutionError,
+};
+
+/// The hexadecimal digits, in lowercase and uppercase.
+pub const HEX_ALPHABETS: [&[u8; 16]; 2] = [b"0123456789abcdef", b"0123456789ABCDEF"];
+
+/// The standard and URL-safe base64 alphabets.
+pub const BASE64_ALPHABETS: [&[u8; 64]; 2] = [
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_",
+];
+
+/// Returns the hexadecimal digit of a value, lowercase for alphabet 0 and uppercase for alphabet
+/// 1, or 0 if the value or the alphabet is out of range, so that no digit matches the lookup.
+#[must_use]
+pub fn hex_char(value: u8, alphabet: u8) -> u8 {
+    HEX_ALPHABETS
+        .get(alphabet as usize)
+        .and_then(|alphabet| alphabet.get(value as usize))
+        .copied()
+        .unwrap_or(0)
+}
+
+/// Returns the base64 character of a value, standard for alphabet 0 and URL-safe for alphabet 1,
+/// or 0 if the value or the alphabet is out of range, so that no character matches the lookup.
+#[must_use]
+pub fn base64_char(value: u8, alphabet: u8) -> u8 {
+    BASE64_ALPHABETS
+        .get(alphabet as usize)
+        .and_then(|alphabet| alphabet.get(value as usize))
+        .copied()
+        .unwrap_or(0)
+}
+
+/// Returns the value of a hexadecimal digit and whether it is uppercase.
+#[must_use]
+pub fn hex_value(char: u8) -> Option<(u8, bool)> {
+    match char {
+        b'0'..=b'9' => Some((char - b'0', false)),
+        b'a'..=b'f' => Some((char - b'a' + 10, false)),
+        b'A'..=b'F' => Some((char - b'A' + 10, true)),
+        _ => None,
+    }
+}
+
+/// Returns the value of a base64 character.
+#[must_use]
+pub fn base64_value(char: u8, url_safe: bool) -> Option<u8> {
+    BASE64_ALPHABETS[usize::from(url_safe)].iter().position(|&c| c == char).map(|v| v as u8)
+}
+
+/// Encodes binary data, whose length is a multiple of three bytes for base64.
+#[must_use]
+pub fn codec_encode(codec: Codec, bytes: &[u8]) -> Vec<u8> {
+    match codec {
+        Codec::Hex => bytes
+            .iter()
+            .flat_map(|&b| {
+                [HEX_ALPHABETS[0][(b >> 4) as usize], HEX_ALPHABETS[0][(b & 0xf) as usize]]
+            })
+            .collect(),
+        Codec::Base64 | Codec::Base64Url => {
+            let alphabet = BASE64_ALPHABETS[usize::from(codec == Codec::Base64Url)];
+            bytes
+                .chunks_exact(3)
+                .flat_map(|x| {
+                    [
+                        x[0] >> 2,
+                        ((x[0] & 3) << 4) | (x[1] >> 4),
+                        ((x[1] & 0xf) << 2) | (x[2] >> 6),
+                        x[2] & 0x3f,
+                    ]
+                    .map(|v| alphabet[v as usize])
+                })
+                .collect()
+        }
+    }
+}
+
+/// Decodes text, whose length is a multiple of four characters for base64, or returns `None` if
+/// it contains a character outside of the alphabet.
+#[must_use]
+pub fn codec_decode(codec: Codec, text: &[u8]) -> Option<Vec<u8>> {
+    match codec {
+        Codec::Hex => text
+            .chunks_exact(2)
+            .map(|c| Some((hex_value(c[0])?.0 << 4) | hex_value(c[1])?.0))
+            .collect(),
+        Codec::Base64 | Codec::Base64Url => {
+            let url_safe = codec == Codec::Base64Url;
+            let mut bytes = Vec::with_capacity(text.len() / 4 * 3);
+            for c in text.chunks_exact(4) {
+                let mut v = [0u8; 4];
+                for (v, &c) in v.iter_mut().zip(c) {
+                    *v = base64_value(c, url_safe)?;
+                }
+                bytes.extend([
+                    (v[0] << 2) | (v[1] >> 4),
+                    (v[1] << 4) | (v[2] >> 2),
+                    (v[2] << 6) | v[3],
+                ]);
+            }
+            Some(bytes)
+        }
+    }
+}
+
+/// Converts little endian words to bytes.
+fn words_to_bytes(words: &[u32]) -> Vec<u8> {
+    words.iter().flat_map(|word| word.to_le_bytes()).collect()
+}
+
+/// Converts bytes to little endian words.
+fn bytes_to_words(bytes: &[u8]) -> Vec<u32> {
+    bytes.chunks_exact(4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]])).collect()
+}
+
+pub(crate) struct CodecSyscall {
+    codec: Codec,
+    op: CodecOperation,
+}
+
+impl CodecSyscall {
+    pub const fn new(codec: Codec, op: CodecOperation) -> Self {
+        Self { codec, op }
+    }
+
+    /// The code of the syscall.
+    const fn syscall_code(&self) -> SyscallCode {
+        match (self.codec, self.op) {
+            (Codec::Hex, CodecOperation::Encode) => SyscallCode::HEX_ENCODE,
+            (Codec::Hex, CodecOperation::Decode) => SyscallCode::HEX_DECODE,
+            (Codec::Base64, CodecOperation::Encode) => SyscallCode::BASE64_ENCODE,
+            (Codec::Base64, CodecOperation::Decode) => SyscallCode::BASE64_DECODE,
+            (Codec::Base64Url, CodecOperation::Encode) => SyscallCode::BASE64_URL_ENCODE,
+            (Codec::Base64Url, CodecOperation::Decode) => SyscallCode::BASE64_URL_DECODE,
+        }
+    }
+}
+
+impl Syscall for CodecSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+
+        let out_ptr = arg1;
+        if out_ptr % 4 != 0 {
+            panic!();
+        }
+        let in_ptr = arg2;
+        if in_ptr % 4 != 0 {
+            panic!();
+        }
+
+        let (binary_words, text_words) = (self.codec.binary_words(), self.codec.text_words());
+        let (binary, text, binary_memory_records, text_memory_records) = match self.op {
+            CodecOperation::Encode => {
+                let (read_records, binary) = rt.mr_slice(in_ptr, binary_words);
+                let text = bytes_to_words(&codec_encode(self.codec, &words_to_bytes(&binary)));
+
+                // Increment clk so that the write is not at the same cycle as the read.
+                rt.clk += 1;
+                let write_records = rt.mw_slice(out_ptr, &text);
+                (binary, text, read_records, write_records)
+            }
+            CodecOperation::Decode => {
+                let (read_records, text) = rt.mr_slice(in_ptr, text_words);
+                let Some(bytes) = codec_decode(self.codec, &words_to_bytes(&text)) else {
+                    rt.error = Some(ExecutionError::InvalidSyscallArguments {
+                        syscall: self.syscall_code(),
+                        message: format!("invalid {:?} text at 0x{in_ptr:08x}", self.codec),
+                    });
+                    return None;
+                };
+                let binary = bytes_to_words(&bytes);
+
+                rt.clk += 1;
+                let write_records = rt.mw_slice(out_ptr, &binary);
+                (binary, text, write_records, read_records)
+            }
+        };
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        let event = CodecEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            codec: self.codec,
+            op: self.op,
+            out_ptr,
+            in_ptr,
+            binary,
+            text,
+            binary_memory_records: binary_memory_records
+                .into_iter()
+                .map(MemoryRecordEnum::from)
+                .collect(),
+            text_memory_records: text_memory_records
+                .into_iter()
+                .map(MemoryRecordEnum::from)
+                .collect(),
+        };
+        match self.codec {
+            Codec::Hex => rt.record_mut().hex_events.push(event),
+            Codec::Base64 | Codec::Base64Url => rt.record_mut().base64_events.push(event),
+        }
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codec_vectors() {
+        let bytes = [0x00, 0xfb, 0xff, 0x12, 0x9a, 0x3e];
+        assert_eq!(codec_encode(Codec::Hex, &bytes), b"00fbff129a3e");
+        assert_eq!(codec_encode(Codec::Base64, &bytes), b"APv/Epo+");
+        assert_eq!(codec_encode(Codec::Base64Url, &bytes), b"APv_Epo-");
+        for (codec, text) in [
+            (Codec::Hex, &b"00FBfF129A3e"[..]),
+            (Codec::Base64, b"APv/Epo+"),
+            (Codec::Base64Url, b"APv_Epo-"),
+        ] {
+            assert_eq!(codec_decode(codec, text).unwrap(), bytes);
+        }
+        assert_eq!(codec_decode(Codec::Hex, b"0g"), None);
+        assert_eq!(codec_decode(Codec::Base64, b"APv_"), None);
+        assert_eq!(codec_decode(Codec::Base64Url, b"AP=="), None);
+    }
+
+    #[test]
+    fn test_codec_chars() {
+        for value in 0..=255u8 {
+            for alphabet in 0..=255u8 {
+                let hex = hex_char(value, alphabet);
+                let expected_hex = (value < 16 && alphabet < 2)
+                    .then(|| HEX_ALPHABETS[alphabet as usize][value as usize]);
+                assert_eq!(hex, expected_hex.unwrap_or(0));
+                if let Some(c) = expected_hex {
+                    assert_eq!(hex_value(c), Some((value, alphabet == 1 && value >= 10)));
+                }
+
+                let base64 = base64_char(value, alphabet);
+                if value < 64 && alphabet < 2 {
+                    assert_eq!(base64_value(base64, alphabet == 1), Some(value));
+                } else {
+                    assert_eq!(base64, 0);
+                }
+            }
+        }
+    }
+}
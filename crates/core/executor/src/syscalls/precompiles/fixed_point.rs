@@ -0,0 +1,159 @@
+use std::f64::consts::FRAC_PI_2;
+
+use crate::{
+    events::{FixedPointEvent, FixedPointFunction},
+    syscalls::{Syscall, SyscallContext},
+};
+
+/// The offset added to the signed results of the trigonometric functions, which keeps them
+/// non-negative.
+pub const FIXED_POINT_TRIG_OFFSET: u32 = 1 << 29;
+
+/// Evaluates a fixed-point function on a 16-bit input.
+///
+/// The results are less than `2^30 + 1`. The trigonometric functions are computed with Taylor
+/// series using only basic floating-point operations, which are exactly rounded, so that the
+/// tables are the same on every platform.
+#[must_use]
+pub fn fixed_point(function: FixedPointFunction, x: u16) -> u32 {
+    match function {
+        FixedPointFunction::Reciprocal => {
+            if x == 0 {
+                0
+            } else {
+                (1 << 29) / u32::from(x)
+            }
+        }
+        FixedPointFunction::Sqrt => isqrt(u64::from(x) << 32) as u32,
+        FixedPointFunction::Sin => trig_to_fixed(sin_cos(x).0),
+        FixedPointFunction::Cos => trig_to_fixed(sin_cos(x).1),
+    }
+}
+
+/// The integer square root of `n`, rounded down.
+fn isqrt(n: u64) -> u64 {
+    let mut root = (n as f64).sqrt() as u64;
+    while root * root > n {
+        root -= 1;
+    }
+    while (root + 1) * (root + 1) <= n {
+        root += 1;
+    }
+    root
+}
+
+/// The sine and cosine of the angle `x * 2π / 2^16`.
+fn sin_cos(x: u16) -> (f64, f64) {
+    // Reduce the angle to the first quadrant.
+    let angle = f64::from(x & 0x3fff) * FRAC_PI_2 / f64::from(1 << 14);
+    let square = angle * angle;
+    let (mut sin, mut sin_term) = (angle, angle);
+    let (mut cos, mut cos_term) = (1.0, 1.0);
+    for k in 1..12 {
+        let k = f64::from(k);
+        sin_term *= -square / ((2.0 * k) * (2.0 * k + 1.0));
+        cos_term *= -square / ((2.0 * k - 1.0) * (2.0 * k));
+        sin += sin_term;
+        cos += cos_term;
+    }
+    match x >> 14 {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    }
+}
+
+/// Converts a value in `[-1, 1]` to signed 2.29 fixed point offset by [FIXED_POINT_TRIG_OFFSET].
+fn trig_to_fixed(value: f64) -> u32 {
+    let fixed = (value * f64::from(FIXED_POINT_TRIG_OFFSET)).round() as i64;
+    (i64::from(FIXED_POINT_TRIG_OFFSET) + fixed.clamp(-(1 << 29), 1 << 29)) as u32
+}
+
+pub(crate) struct FixedPointSyscall {
+    function: FixedPointFunction,
+}
+
+impl FixedPointSyscall {
+    pub const fn new(function: FixedPointFunction) -> Self {
+        Self { function }
+    }
+}
+
+impl Syscall for FixedPointSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+
+        let ptr = arg1;
+        if ptr % 4 != 0 {
+            panic!();
+        }
+        assert_eq!(arg2, 0, "the second argument of a fixed-point syscall must be 0");
+
+        // We can read the word unsafely here because we write it right after.
+        let input = rt.word_unsafe(ptr) as u16;
+        let result = fixed_point(self.function, input);
+        let memory_record = rt.mw(ptr, result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().fixed_point_events.push(FixedPointEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            function: self.function,
+            ptr,
+            input,
+            result,
+            memory_record,
+        });
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_point_values() {
+        use FixedPointFunction::*;
+
+        assert_eq!(fixed_point(Reciprocal, 0), 0);
+        assert_eq!(fixed_point(Reciprocal, 1), 1 << 29);
+        assert_eq!(fixed_point(Reciprocal, 3), (1 << 29) / 3);
+        assert_eq!(fixed_point(Sqrt, 4), 2 << 16);
+        assert_eq!(fixed_point(Sqrt, 2), 92681);
+        assert_eq!(fixed_point(Sqrt, u16::MAX), 16_776_960);
+
+        let offset = FIXED_POINT_TRIG_OFFSET;
+        assert_eq!(fixed_point(Sin, 0), offset);
+        assert_eq!(fixed_point(Cos, 0), 2 * offset);
+        assert_eq!(fixed_point(Sin, 1 << 14), 2 * offset);
+        assert_eq!(fixed_point(Cos, 1 << 15), 0);
+        assert_eq!(fixed_point(Sin, 3 << 14), 0);
+        // sin(π/6) = 1/2.
+        let x = ((1u32 << 16) / 12) as u16;
+        let expected = (f64::from(x) / 65536.0 * std::f64::consts::TAU).sin();
+        let actual = (f64::from(fixed_point(Sin, x)) - f64::from(offset)) / f64::from(offset);
+        assert!((actual - expected).abs() < 1e-8);
+    }
+
+    #[test]
+    fn fixed_point_trig_matches_std() {
+        for x in (0..=u16::MAX).step_by(97) {
+            let angle = f64::from(x) / 65536.0 * std::f64::consts::TAU;
+            for (function, expected) in
+                [(FixedPointFunction::Sin, angle.sin()), (FixedPointFunction::Cos, angle.cos())]
+            {
+                let expected = i64::from(FIXED_POINT_TRIG_OFFSET)
+                    + (expected * (1u64 << 29) as f64).round() as i64;
+                let actual = i64::from(fixed_point(function, x));
+                assert!((actual - expected).abs() <= 1, "{function:?}({x})");
+            }
+        }
+    }
+}
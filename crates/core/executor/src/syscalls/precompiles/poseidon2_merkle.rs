@@ -0,0 +1,132 @@
+use p3_baby_bear::BabyBear;
+use p3_field::{AbstractField, PrimeField32};
+use p3_symmetric::Permutation;
+use sp1_primitives::poseidon2_init;
+
+use crate::{
+    events::{Poseidon2MerkleVerifyEvent, POSEIDON2_DIGEST_WORDS, POSEIDON2_MERKLE_MAX_DEPTH},
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+    ExecutionError,
+};
+
+pub(crate) struct Poseidon2MerkleVerifySyscall;
+
+/// Compresses two digests with the Poseidon2 permutation, truncating the output to a digest.
+#[must_use]
+pub fn poseidon2_compress(
+    left: &[u32; POSEIDON2_DIGEST_WORDS],
+    right: &[u32; POSEIDON2_DIGEST_WORDS],
+) -> [u32; POSEIDON2_DIGEST_WORDS] {
+    let mut state = [BabyBear::zero(); 2 * POSEIDON2_DIGEST_WORDS];
+    for (i, word) in left.iter().chain(right.iter()).enumerate() {
+        state[i] = BabyBear::from_canonical_u32(*word);
+    }
+    let state = poseidon2_init().permute(state);
+    core::array::from_fn(|i| state[i].as_canonical_u32())
+}
+
+impl Syscall for Poseidon2MerkleVerifySyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+        let state_ptr = arg1;
+        let path_ptr = arg2;
+        if let Err(message) = check_arguments(rt, state_ptr, path_ptr) {
+            rt.error = Some(ExecutionError::InvalidSyscallArguments {
+                syscall: SyscallCode::POSEIDON2_MERKLE_VERIFY,
+                message,
+            });
+            return None;
+        }
+
+        let (leaf_read_records, leaf) = rt.mr_slice(state_ptr, POSEIDON2_DIGEST_WORDS);
+        let root_ptr = state_ptr + (POSEIDON2_DIGEST_WORDS as u32) * 4;
+        let (root_read_records, root) = rt.mr_slice(root_ptr, POSEIDON2_DIGEST_WORDS);
+        let (header_read_records, header) = rt.mr_slice(path_ptr, 2);
+        let (index, depth) = (header[0], header[1]);
+
+        let leaf: [u32; POSEIDON2_DIGEST_WORDS] = leaf.try_into().unwrap();
+        let root: [u32; POSEIDON2_DIGEST_WORDS] = root.try_into().unwrap();
+        let mut node = leaf;
+        let mut nodes = vec![node];
+        let mut siblings = Vec::with_capacity(depth as usize);
+        let mut sibling_read_records = Vec::with_capacity(depth as usize);
+        for level in 0..depth {
+            let sibling_ptr = path_ptr + 8 + level * (POSEIDON2_DIGEST_WORDS as u32) * 4;
+            let (records, sibling) = rt.mr_slice(sibling_ptr, POSEIDON2_DIGEST_WORDS);
+            let sibling: [u32; POSEIDON2_DIGEST_WORDS] = sibling.try_into().unwrap();
+            node = if (index >> level) & 1 == 0 {
+                poseidon2_compress(&node, &sibling)
+            } else {
+                poseidon2_compress(&sibling, &node)
+            };
+            nodes.push(node);
+            siblings.push(sibling);
+            sibling_read_records.push(records);
+        }
+        if node != root {
+            rt.error = Some(ExecutionError::InvalidSyscallArguments {
+                syscall: SyscallCode::POSEIDON2_MERKLE_VERIFY,
+                message: format!("the merkle path of the leaf at index {index} is not in the tree"),
+            });
+            return None;
+        }
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().poseidon2_merkle_verify_events.push(Poseidon2MerkleVerifyEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            state_ptr,
+            path_ptr,
+            index,
+            depth,
+            leaf,
+            root,
+            siblings,
+            nodes,
+            leaf_read_records,
+            root_read_records,
+            header_read_records,
+            sibling_read_records,
+        });
+
+        None
+    }
+}
+
+/// Checks the alignment and the bounds of the arguments, and that the digests are canonical, by
+/// reading the memory without recording the accesses.
+fn check_arguments(rt: &mut SyscallContext, state_ptr: u32, path_ptr: u32) -> Result<(), String> {
+    if state_ptr % 4 != 0 || path_ptr % 4 != 0 {
+        return Err("the state and the path must be word aligned".to_string());
+    }
+    let (index, depth) = (rt.word_unsafe(path_ptr), rt.word_unsafe(path_ptr + 4));
+    if depth == 0 || depth as usize > POSEIDON2_MERKLE_MAX_DEPTH {
+        return Err(format!(
+            "the depth of the path must be between 1 and {POSEIDON2_MERKLE_MAX_DEPTH}, got {depth}"
+        ));
+    }
+    if u64::from(index) >= 1 << depth {
+        return Err(format!("the index {index} is out of range for a path of depth {depth}"));
+    }
+
+    let digest_bytes = (POSEIDON2_DIGEST_WORDS as u32) * 4;
+    let (path_end, state_end) = (path_ptr + 8 + depth * digest_bytes, state_ptr + 2 * digest_bytes);
+    if path_end > state_ptr && state_end > path_ptr {
+        return Err("the path must not overlap the state".to_string());
+    }
+
+    let state = rt.slice_unsafe(state_ptr, 2 * POSEIDON2_DIGEST_WORDS);
+    let siblings = rt.slice_unsafe(path_ptr + 8, depth as usize * POSEIDON2_DIGEST_WORDS);
+    if !state.iter().chain(siblings.iter()).all(|word| *word < BabyBear::ORDER_U32) {
+        return Err("the digests must be canonical BabyBear elements".to_string());
+    }
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use crate::{
+    events::RangeCheckEvent,
+    syscalls::{Syscall, SyscallContext},
+};
+
+/// The maximum number of bits a word can be checked to fit in.
+pub const RANGE_CHECK_MAX_BITS: u32 = 32;
+
+pub(crate) struct RangeCheckSyscall;
+
+impl Syscall for RangeCheckSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+
+        let ptr = arg1;
+        if ptr % 4 != 0 {
+            panic!();
+        }
+        let bits = arg2;
+        assert!(
+            bits <= RANGE_CHECK_MAX_BITS,
+            "range check of {bits} bits, at most 32 are supported"
+        );
+
+        let (memory_record, value) = rt.mr(ptr);
+        assert!(
+            u64::from(value) < 1 << bits,
+            "range check failed: {value} at 0x{ptr:08x} does not fit in {bits} bits"
+        );
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().range_check_events.push(RangeCheckEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            ptr,
+            bits,
+            value,
+            memory_record,
+        });
+
+        None
+    }
+}
@@ -0,0 +1,35 @@
+use std::marker::PhantomData;
+
+use sp1_curves::{weierstrass::sswu::SswuParameters, CurveType, EllipticCurve};
+
+use crate::{
+    events::create_ec_map_event,
+    syscalls::{Syscall, SyscallContext},
+};
+
+pub(crate) struct WeierstrassMapToCurveSyscall<E: EllipticCurve> {
+    _phantom: PhantomData<E>,
+}
+
+impl<E: EllipticCurve> WeierstrassMapToCurveSyscall<E> {
+    /// Create a new instance of the [`WeierstrassMapToCurveSyscall`].
+    pub const fn new() -> Self {
+        Self { _phantom: PhantomData }
+    }
+}
+
+impl<E: EllipticCurve + SswuParameters> Syscall for WeierstrassMapToCurveSyscall<E> {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let event = create_ec_map_event::<E>(rt, arg1, arg2);
+        match E::CURVE_TYPE {
+            CurveType::Secp256k1 => rt.record_mut().secp256k1_map_events.push(event),
+            CurveType::Bls12381 => rt.record_mut().bls12381_map_events.push(event),
+            _ => panic!("Unsupported curve"),
+        }
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        0
+    }
+}
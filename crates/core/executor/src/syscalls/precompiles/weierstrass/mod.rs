@@ -1,3 +1,5 @@
 pub mod add;
 pub mod decompress;
 pub mod double;
+pub mod map;
+pub mod registered;
@@ -0,0 +1,98 @@
+use sp1_curves::weierstrass::registered::{
+    registered_curve, RegisteredCurve, REGISTERED_CURVE_POINT_WORDS,
+};
+
+use crate::{
+    events::{RegisteredCurveAddEvent, RegisteredCurveDoubleEvent},
+    syscalls::{Syscall, SyscallContext},
+};
+
+/// Reads the curve id which prefixes the point at `p_ptr` without recording the access, and
+/// returns the registered curve with that id.
+fn peek_curve(rt: &mut SyscallContext, p_ptr: u32) -> (u32, &'static RegisteredCurve) {
+    if p_ptr % 4 != 0 {
+        panic!();
+    }
+    let curve_id = rt.word_unsafe(p_ptr);
+    // The executor returns an error for a curve id which is not registered before the syscall.
+    let curve = registered_curve(curve_id).expect("the curve id is registered");
+    (curve_id, curve)
+}
+
+pub(crate) struct RegisteredCurveAddSyscall;
+
+impl Syscall for RegisteredCurveAddSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let start_clk = rt.clk;
+        let p_ptr = arg1;
+        let (curve_id, curve) = peek_curve(rt, p_ptr);
+        let q_ptr = arg2;
+        if q_ptr % 4 != 0 {
+            panic!();
+        }
+
+        let p = rt.slice_unsafe(p_ptr + 4, REGISTERED_CURVE_POINT_WORDS);
+        let (q_memory_records, q) = rt.mr_slice(q_ptr, REGISTERED_CURVE_POINT_WORDS);
+
+        // The curve id and p are accessed at the next cycle, since q could overlap them.
+        rt.clk += 1;
+        let (curve_id_memory_record, _) = rt.mr(p_ptr);
+
+        let result = curve.add(
+            &RegisteredCurve::point_from_words_le(&p),
+            &RegisteredCurve::point_from_words_le(&q),
+        );
+        let p_memory_records = rt.mw_slice(p_ptr + 4, &RegisteredCurve::point_to_words_le(&result));
+
+        let event = RegisteredCurveAddEvent {
+            lookup_id: rt.syscall_lookup_id,
+            shard: rt.current_shard(),
+            channel: rt.current_channel(),
+            clk: start_clk,
+            curve_id,
+            p_ptr,
+            p,
+            q_ptr,
+            q,
+            curve_id_memory_record,
+            p_memory_records,
+            q_memory_records,
+        };
+        rt.record_mut().registered_curve_add_events.push(event);
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+pub(crate) struct RegisteredCurveDoubleSyscall;
+
+impl Syscall for RegisteredCurveDoubleSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, _: u32) -> Option<u32> {
+        let p_ptr = arg1;
+        let (curve_id, curve) = peek_curve(rt, p_ptr);
+
+        let (curve_id_memory_record, _) = rt.mr(p_ptr);
+        let p = rt.slice_unsafe(p_ptr + 4, REGISTERED_CURVE_POINT_WORDS);
+        let result = curve.double(&RegisteredCurve::point_from_words_le(&p));
+        let p_memory_records = rt.mw_slice(p_ptr + 4, &RegisteredCurve::point_to_words_le(&result));
+
+        let event = RegisteredCurveDoubleEvent {
+            lookup_id: rt.syscall_lookup_id,
+            shard: rt.current_shard(),
+            channel: rt.current_channel(),
+            clk: rt.clk,
+            curve_id,
+            p_ptr,
+            p,
+            curve_id_memory_record,
+            p_memory_records,
+        };
+        rt.record_mut().registered_curve_double_events.push(event);
+
+        None
+    }
+}
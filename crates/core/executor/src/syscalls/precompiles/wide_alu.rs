@@ -0,0 +1,81 @@
+use crate::{
+    events::{WideAluEvent, WideAluOperation},
+    syscalls::{Syscall, SyscallContext},
+};
+
+/// The number of words in a wide ALU operand.
+pub const WIDE_ALU_WORDS: usize = 2;
+
+/// Computes the result of a wide ALU operation on two 64-bit values.
+#[must_use]
+pub fn wide_alu(op: WideAluOperation, x: u64, y: u64) -> u64 {
+    match op {
+        WideAluOperation::Add => x.wrapping_add(y),
+        WideAluOperation::Sub => x.wrapping_sub(y),
+        WideAluOperation::Mul => x.wrapping_mul(y),
+        WideAluOperation::Ltu => u64::from(x < y),
+    }
+}
+
+/// Converts little endian words to a 64-bit value.
+fn words_to_u64(words: &[u32]) -> u64 {
+    u64::from(words[0]) | (u64::from(words[1]) << 32)
+}
+
+pub(crate) struct WideAluSyscall {
+    op: WideAluOperation,
+}
+
+impl WideAluSyscall {
+    pub const fn new(op: WideAluOperation) -> Self {
+        Self { op }
+    }
+}
+
+impl Syscall for WideAluSyscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+
+        let x_ptr = arg1;
+        if x_ptr % 4 != 0 {
+            panic!();
+        }
+        let y_ptr = arg2;
+        if y_ptr % 4 != 0 {
+            panic!();
+        }
+
+        // We can read a slice_unsafe here because we write x later.
+        let x = rt.slice_unsafe(x_ptr, WIDE_ALU_WORDS);
+        let (y_memory_records, y) = rt.mr_slice(y_ptr, WIDE_ALU_WORDS);
+
+        let result = wide_alu(self.op, words_to_u64(&x), words_to_u64(&y));
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        let x_memory_records = rt.mw_slice(x_ptr, &[result as u32, (result >> 32) as u32]);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().wide_alu_events.push(WideAluEvent {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            op: self.op,
+            x_ptr,
+            x,
+            y_ptr,
+            y,
+            x_memory_records,
+            y_memory_records,
+        });
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
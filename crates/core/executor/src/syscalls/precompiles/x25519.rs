@@ -0,0 +1,169 @@
+use num::{BigUint, One, Zero};
+use sp1_curves::{edwards::ed25519::Ed25519BaseField, params::FieldParameters};
+
+use crate::{
+    events::X25519Event,
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+    ExecutionError,
+};
+
+/// The number of words in an X25519 scalar or u-coordinate.
+pub const X25519_WORDS: usize = 8;
+
+/// The number of Montgomery ladder steps in an X25519 scalar multiplication.
+pub const X25519_LADDER_STEPS: usize = 255;
+
+/// The constant `(A - 2) / 4` of the Montgomery form of curve25519, where `A = 486662`.
+pub const X25519_A24: u32 = 121665;
+
+/// Multiplies the point with u-coordinate `u` by the scalar `k` with the Montgomery ladder of
+/// RFC 7748, as little endian words.
+///
+/// The scalar is used as is, so callers must clamp it. Its top bit must be clear, and `u` is
+/// reduced modulo `2^255 - 19` without masking its top bit.
+#[must_use]
+pub fn x25519_scalar_mult(k: &[u32; X25519_WORDS], u: &[u32; X25519_WORDS]) -> [u32; X25519_WORDS] {
+    assert_eq!(k[X25519_WORDS - 1] >> 31, 0, "the top bit of the scalar must be clear");
+    let p = Ed25519BaseField::modulus();
+    let add = |a: &BigUint, b: &BigUint| (a + b) % &p;
+    let sub = |a: &BigUint, b: &BigUint| (a + &p - b) % &p;
+    let mul = |a: &BigUint, b: &BigUint| (a * b) % &p;
+
+    let k = BigUint::from_slice(k);
+    let x1 = BigUint::from_slice(u) % &p;
+    let (mut x2, mut z2, mut x3, mut z3) =
+        (BigUint::one(), BigUint::zero(), x1.clone(), BigUint::one());
+    let a24 = BigUint::from(X25519_A24);
+
+    let mut swap = false;
+    for t in (0..X25519_LADDER_STEPS).rev() {
+        let bit = k.bit(t as u64);
+        if swap ^ bit {
+            std::mem::swap(&mut x2, &mut x3);
+            std::mem::swap(&mut z2, &mut z3);
+        }
+        swap = bit;
+
+        let a = add(&x2, &z2);
+        let aa = mul(&a, &a);
+        let b = sub(&x2, &z2);
+        let bb = mul(&b, &b);
+        let e = sub(&aa, &bb);
+        let c = add(&x3, &z3);
+        let d = sub(&x3, &z3);
+        let da = mul(&d, &a);
+        let cb = mul(&c, &b);
+        let da_plus_cb = add(&da, &cb);
+        x3 = mul(&da_plus_cb, &da_plus_cb);
+        let da_minus_cb = sub(&da, &cb);
+        z3 = mul(&x1, &mul(&da_minus_cb, &da_minus_cb));
+        x2 = mul(&aa, &bb);
+        z2 = mul(&e, &add(&aa, &mul(&a24, &e)));
+    }
+    if swap {
+        std::mem::swap(&mut x2, &mut x3);
+        std::mem::swap(&mut z2, &mut z3);
+    }
+
+    // A zero denominator maps to zero, as z^(p - 2) = 0 when z = 0.
+    let result = mul(&x2, &z2.modpow(&(&p - 2u32), &p));
+    let mut words = result.to_u32_digits();
+    words.resize(X25519_WORDS, 0);
+    words.try_into().unwrap()
+}
+
+pub(crate) struct X25519Syscall;
+
+impl Syscall for X25519Syscall {
+    fn execute(&self, rt: &mut SyscallContext, arg1: u32, arg2: u32) -> Option<u32> {
+        let clk = rt.clk;
+
+        let u_ptr = arg1;
+        if u_ptr % 4 != 0 {
+            panic!();
+        }
+        let k_ptr = arg2;
+        if k_ptr % 4 != 0 {
+            panic!();
+        }
+
+        let (u_read_records, u) = rt.mr_slice(u_ptr, X25519_WORDS);
+        let (k_read_records, k) = rt.mr_slice(k_ptr, X25519_WORDS);
+        if k[X25519_WORDS - 1] >> 31 != 0 {
+            rt.error = Some(ExecutionError::InvalidSyscallArguments {
+                syscall: SyscallCode::X25519_SCALAR_MULT,
+                message: "the top bit of the scalar must be clear".to_string(),
+            });
+            return None;
+        }
+
+        let result =
+            x25519_scalar_mult(&k.clone().try_into().unwrap(), &u.clone().try_into().unwrap());
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        let u_write_records = rt.mw_slice(u_ptr, &result);
+
+        let lookup_id = rt.syscall_lookup_id;
+        let shard = rt.current_shard();
+        let channel = rt.current_channel();
+        rt.record_mut().x25519_events.push(X25519Event {
+            lookup_id,
+            shard,
+            channel,
+            clk,
+            u_ptr,
+            u,
+            k_ptr,
+            k,
+            u_read_records,
+            u_write_records,
+            k_read_records,
+        });
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{x25519_scalar_mult, X25519_WORDS};
+
+    fn to_words(hex: &str) -> [u32; X25519_WORDS] {
+        let bytes = hex::decode(hex).unwrap();
+        core::array::from_fn(|i| u32::from_le_bytes(bytes[4 * i..4 * i + 4].try_into().unwrap()))
+    }
+
+    /// Clamps a scalar and masks a u-coordinate as in the X25519 function of RFC 7748.
+    fn clamp(mut k: [u32; X25519_WORDS], mut u: [u32; X25519_WORDS]) -> ([u32; 8], [u32; 8]) {
+        k[0] &= !7;
+        k[7] &= 0x7fff_ffff;
+        k[7] |= 0x4000_0000;
+        u[7] &= 0x7fff_ffff;
+        (k, u)
+    }
+
+    #[test]
+    fn test_x25519_rfc7748_vector() {
+        let (k, u) = clamp(
+            to_words("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4"),
+            to_words("e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c"),
+        );
+        assert_eq!(
+            x25519_scalar_mult(&k, &u),
+            to_words("c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552")
+        );
+    }
+
+    #[test]
+    fn test_x25519_low_order_point() {
+        let (k, _) = clamp([0xffff_ffff; X25519_WORDS], [0; X25519_WORDS]);
+        for u in [[0; X25519_WORDS], [1, 0, 0, 0, 0, 0, 0, 0]] {
+            assert_eq!(x25519_scalar_mult(&k, &u), [0; X25519_WORDS]);
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use sp1_primitives::consts::num_to_comma_separated;
 
-use crate::{Executor, Register};
+use crate::{
+    hook::{FD_HINT_AWAIT, FD_HINT_REQUEST, FD_HOST_ASSERT, FD_STREAM_READ},
+    Executor, ExecutorMode, Register,
+};
 
 use super::{Syscall, SyscallContext};
 
@@ -23,6 +26,18 @@ impl Syscall for WriteSyscall {
     /// If fd = 4:
     /// - Update the input stream.
     ///
+    /// If fd = 6:
+    /// - Spawn a thread running the requested asynchronous hook.
+    ///
+    /// If fd = 7:
+    /// - Wait for the requested hint and add it to the input stream.
+    ///
+    /// If fd = 10:
+    /// - Record the failed assertion, if the program is executed without being proven.
+    ///
+    /// If fd = 11:
+    /// - Add the next entry of the requested input stream to the input stream.
+    ///
     /// If the fd matches a hook in the hook registry, invoke the hook.
     ///
     /// Else, log a warning.
@@ -58,6 +73,17 @@ impl Syscall for WriteSyscall {
             rt.state.public_values_stream.extend_from_slice(slice);
         } else if fd == 4 {
             rt.state.input_stream.push(slice.to_vec());
+        } else if fd == FD_HINT_REQUEST {
+            request_hint(rt, slice);
+        } else if fd == FD_HINT_AWAIT {
+            await_hint(rt, slice);
+        } else if fd == FD_HOST_ASSERT {
+            // Proving only sees the panic that follows, so that the assertion is proven the same.
+            if rt.executor_mode == ExecutorMode::Simple && !rt.unconstrained {
+                rt.host_assertion = Some(String::from_utf8_lossy(slice).into_owned());
+            }
+        } else if fd == FD_STREAM_READ {
+            read_stream(rt, slice);
         } else if let Some(mut hook) = rt.hook_registry.get(fd) {
             let res = hook.invoke_hook(rt.hook_env(), slice);
             // Add result vectors to the beginning of the stream.
@@ -70,6 +96,72 @@ impl Syscall for WriteSyscall {
     }
 }
 
+/// Start resolving a hint requested by the program on a separate thread.
+///
+/// The request is the handle of the hint and the file descriptor of the asynchronous hook, as
+/// little-endian `u32`s, followed by the key passed to the hook.
+fn request_hint(rt: &mut Executor, slice: &[u8]) {
+    let Some((handle, hook_fd, key)) = (slice.len() >= 8).then(|| {
+        let handle = u32::from_le_bytes(slice[0..4].try_into().unwrap());
+        let hook_fd = u32::from_le_bytes(slice[4..8].try_into().unwrap());
+        (handle, hook_fd, slice[8..].to_vec())
+    }) else {
+        tracing::warn!("malformed hint request of {} bytes", slice.len());
+        return;
+    };
+    let Some(hook) = rt.hook_registry.get_async(hook_fd) else {
+        tracing::warn!("tried to request a hint from unknown async hook {hook_fd}");
+        return;
+    };
+    let pending = std::thread::spawn(move || hook(&key));
+    if rt.pending_hints.insert(handle, pending).is_some() {
+        tracing::warn!("hint handle {handle} was requested again before being awaited");
+    }
+}
+
+/// Wait for a hint requested by the program and add it to the beginning of the input stream.
+///
+/// If the hint was never requested, or its hook panicked, an empty hint is added instead so that
+/// the program's next read does not consume unrelated input.
+fn await_hint(rt: &mut Executor, slice: &[u8]) {
+    let pending = slice
+        .try_into()
+        .ok()
+        .map(u32::from_le_bytes)
+        .and_then(|handle| rt.pending_hints.remove(&handle));
+    let res = match pending {
+        Some(pending) => pending.join().unwrap_or_else(|_| {
+            tracing::warn!("async hook panicked while resolving a hint");
+            Vec::new()
+        }),
+        None => {
+            tracing::warn!("tried to await a hint that was never requested");
+            Vec::new()
+        }
+    };
+    let ptr = rt.state.input_stream_ptr;
+    rt.state.input_stream.insert(ptr, res);
+}
+
+/// Move the next entry of the numbered input stream requested by the program to the beginning of
+/// the input stream.
+///
+/// If the stream has no entry left, an empty entry is added instead so that the program's next
+/// read does not consume unrelated input.
+fn read_stream(rt: &mut Executor, slice: &[u8]) {
+    let entry = slice
+        .try_into()
+        .ok()
+        .map(u32::from_le_bytes)
+        .and_then(|stream_id| rt.state.input_streams.get_mut(&stream_id)?.pop_front());
+    let entry = entry.unwrap_or_else(|| {
+        tracing::warn!("tried to read past the end of an input stream");
+        Vec::new()
+    });
+    let ptr = rt.state.input_stream_ptr;
+    rt.state.input_stream.insert(ptr, entry);
+}
+
 /// An enum representing the different cycle tracker commands.
 #[derive(Clone)]
 enum CycleTrackerCommand {
@@ -0,0 +1,15 @@
+use super::{context::SyscallContext, Syscall};
+
+pub(crate) struct YieldSyscall;
+
+impl Syscall for YieldSyscall {
+    /// Marks that the program yielded to the host.
+    ///
+    /// The yield only pauses execution when running with [`crate::Executor::run_until_yield`].
+    /// Otherwise it is a no-op, so that a proof of the whole session can be generated by executing
+    /// the program once with all the input that was supplied between yields.
+    fn execute(&self, ctx: &mut SyscallContext, _: u32, _: u32) -> Option<u32> {
+        ctx.rt.yielded = true;
+        None
+    }
+}
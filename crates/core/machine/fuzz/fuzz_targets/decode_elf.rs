@@ -0,0 +1,16 @@
+//! Loads arbitrary bytes as an ELF with the limits for untrusted programs, checking that parsing
+//! never panics and that loaded programs stay within the limits.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sp1_core_executor::{ElfLimits, Program};
+
+fuzz_target!(|data: &[u8]| {
+    let limits = ElfLimits::UNTRUSTED;
+    if let Ok(program) = Program::from_with_limits(data, &limits) {
+        assert!(program.instructions.len() <= limits.max_instructions);
+        assert!(program.memory_image.len() as u64 <= limits.max_memory_size);
+        assert_eq!(program.pc_start % 4, 0);
+    }
+});
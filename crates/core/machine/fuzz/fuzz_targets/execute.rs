@@ -0,0 +1,17 @@
+//! Executes random RV32IM programs, checking that every generated program decodes and runs to
+//! completion.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use sp1_core_executor::{programs::random::random_program, Executor};
+use sp1_stark::SP1CoreOpts;
+
+fuzz_target!(|seed: u64| {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let len = rng.gen_range(1..=4096);
+    let program = random_program(&mut rng, len);
+    let mut runtime = Executor::new(program, SP1CoreOpts::default());
+    runtime.run().expect("random program failed to execute");
+});
@@ -0,0 +1,17 @@
+//! Executes, proves and verifies small random RV32IM programs, checking that the executor and
+//! the chips agree on every instruction the decoder produces.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use sp1_core_executor::programs::random::random_program;
+use sp1_core_machine::utils::run_test;
+use sp1_stark::CpuProver;
+
+fuzz_target!(|seed: u64| {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let len = rng.gen_range(1..=256);
+    let program = random_program(&mut rng, len);
+    run_test::<CpuProver<_, _>>(program).expect("random program failed to prove");
+});
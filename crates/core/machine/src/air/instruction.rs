@@ -0,0 +1,68 @@
+use std::iter::once;
+
+use sp1_stark::{
+    air::{AirInteraction, BaseAirBuilder},
+    InteractionKind, Word,
+};
+
+/// A trait which contains methods related to the interactions between the CPU and the chips which
+/// prove some of its instructions on its behalf.
+pub trait InstructionAirBuilder: BaseAirBuilder {
+    /// Sends an instruction to be proven by another chip.
+    #[allow(clippy::too_many_arguments)]
+    fn send_instruction(
+        &mut self,
+        shard: impl Into<Self::Expr>,
+        channel: impl Into<Self::Expr>,
+        clk: impl Into<Self::Expr>,
+        opcode: impl Into<Self::Expr>,
+        a: Word<impl Into<Self::Expr>>,
+        b: Word<impl Into<Self::Expr>>,
+        c: Word<impl Into<Self::Expr>>,
+        op_a_0: impl Into<Self::Expr>,
+        multiplicity: impl Into<Self::Expr>,
+    ) {
+        let values = once(shard.into())
+            .chain(once(channel.into()))
+            .chain(once(clk.into()))
+            .chain(once(opcode.into()))
+            .chain(a.0.into_iter().map(Into::into))
+            .chain(b.0.into_iter().map(Into::into))
+            .chain(c.0.into_iter().map(Into::into))
+            .chain(once(op_a_0.into()))
+            .collect();
+
+        self.send(AirInteraction::new(values, multiplicity.into(), InteractionKind::Instruction));
+    }
+
+    /// Receives an instruction to be proven on behalf of the CPU.
+    #[allow(clippy::too_many_arguments)]
+    fn receive_instruction(
+        &mut self,
+        shard: impl Into<Self::Expr>,
+        channel: impl Into<Self::Expr>,
+        clk: impl Into<Self::Expr>,
+        opcode: impl Into<Self::Expr>,
+        a: Word<impl Into<Self::Expr>>,
+        b: Word<impl Into<Self::Expr>>,
+        c: Word<impl Into<Self::Expr>>,
+        op_a_0: impl Into<Self::Expr>,
+        multiplicity: impl Into<Self::Expr>,
+    ) {
+        let values = once(shard.into())
+            .chain(once(channel.into()))
+            .chain(once(clk.into()))
+            .chain(once(opcode.into()))
+            .chain(a.0.into_iter().map(Into::into))
+            .chain(b.0.into_iter().map(Into::into))
+            .chain(c.0.into_iter().map(Into::into))
+            .chain(once(op_a_0.into()))
+            .collect();
+
+        self.receive(AirInteraction::new(
+            values,
+            multiplicity.into(),
+            InteractionKind::Instruction,
+        ));
+    }
+}
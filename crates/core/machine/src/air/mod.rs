@@ -1,7 +1,9 @@
+mod instruction;
 mod memory;
 mod program;
 mod word;
 
+pub use instruction::*;
 pub use memory::*;
 pub use program::*;
 pub use word::*;
@@ -11,10 +13,11 @@ use sp1_stark::air::{BaseAirBuilder, SP1AirBuilder};
 /// A trait which contains methods related to memory interactions in an AIR.
 
 pub trait SP1CoreAirBuilder:
-    SP1AirBuilder + WordAirBuilder + MemoryAirBuilder + ProgramAirBuilder
+    SP1AirBuilder + WordAirBuilder + MemoryAirBuilder + ProgramAirBuilder + InstructionAirBuilder
 {
 }
 
+impl<AB: BaseAirBuilder> InstructionAirBuilder for AB {}
 impl<AB: BaseAirBuilder> MemoryAirBuilder for AB {}
 impl<AB: BaseAirBuilder> ProgramAirBuilder for AB {}
 impl<AB: BaseAirBuilder> WordAirBuilder for AB {}
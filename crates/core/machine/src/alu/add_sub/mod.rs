@@ -246,7 +246,7 @@ mod tests {
     use sp1_stark::{air::MachineAir, baby_bear_poseidon2::BabyBearPoseidon2, StarkGenericConfig};
 
     use super::AddSubChip;
-    use crate::utils::{uni_stark_prove as prove, uni_stark_verify as verify};
+    use crate::utils::{golden::check_chip, uni_stark_prove as prove, uni_stark_verify as verify};
 
     #[test]
     fn generate_trace() {
@@ -258,6 +258,20 @@ mod tests {
         println!("{:?}", trace.values)
     }
 
+    #[test]
+    fn test_golden_trace() {
+        let mut shard = ExecutionRecord::default();
+        shard.add_events = vec![
+            AluEvent::new(1, 0, 0, Opcode::ADD, 14, 8, 6),
+            AluEvent::new(1, 1, 0, Opcode::ADD, 0, u32::MAX, 1),
+        ];
+        shard.sub_events = vec![
+            AluEvent::new(1, 0, 0, Opcode::SUB, 7, 10, 3),
+            AluEvent::new(1, 1, 0, Opcode::SUB, u32::MAX, 0, 1),
+        ];
+        check_chip(&AddSubChip::default(), &shard, "add_sub");
+    }
+
     #[test]
     fn prove_babybear() {
         let config = BabyBearPoseidon2::new();
@@ -284,6 +284,11 @@ impl<F: PrimeField> MachineAir<F> for MulChip {
         trace
     }
 
+    fn single_pass(&self) -> bool {
+        // The multiplications are only sent by the cpu and the division chip, which precede this chip.
+        true
+    }
+
     fn included(&self, shard: &Self::Record) -> bool {
         !shard.mul_events.is_empty()
     }
@@ -87,6 +87,33 @@ impl<AB: SP1AirBuilder + PairBuilder> Air<AB> for ByteChip<AB::F> {
                         channel_f,
                         mult,
                     ),
+                    ByteOpcode::AesSbox => builder.receive_byte(
+                        field_op,
+                        local.aes_sbox,
+                        local.b,
+                        AB::F::zero(),
+                        shard,
+                        channel_f,
+                        mult,
+                    ),
+                    ByteOpcode::HexChar => builder.receive_byte(
+                        field_op,
+                        local.hex_char,
+                        local.b,
+                        local.c,
+                        shard,
+                        channel_f,
+                        mult,
+                    ),
+                    ByteOpcode::Base64Char => builder.receive_byte(
+                        field_op,
+                        local.base64_char,
+                        local.b,
+                        local.c,
+                        shard,
+                        channel_f,
+                        mult,
+                    ),
                 }
             }
         }
@@ -42,6 +42,15 @@ pub struct BytePreprocessedCols<T> {
 
     /// A u16 value used for `U16Range`.
     pub value_u16: T,
+
+    /// The AES S-box applied to `b`.
+    pub aes_sbox: T,
+
+    /// The hexadecimal digit of value `b` in case `c`, lowercase for 0 and uppercase for 1.
+    pub hex_char: T,
+
+    /// The base64 character of value `b` in alphabet `c`, standard for 0 and URL-safe for 1.
+    pub base64_char: T,
 }
 
 /// For each byte operation in the preprocessed table, a corresponding ByteMultCols row tracks the
@@ -5,7 +5,11 @@ pub mod columns;
 pub mod trace;
 pub mod utils;
 
-use sp1_core_executor::{events::ByteLookupEvent, ByteOpcode};
+use sp1_core_executor::{
+    events::ByteLookupEvent,
+    syscalls::{base64_char, hex_char, AES_SBOX},
+    ByteOpcode,
+};
 
 use core::borrow::BorrowMut;
 use std::marker::PhantomData;
@@ -21,7 +25,7 @@ use self::{
 use crate::bytes::trace::NUM_ROWS;
 
 /// The number of different byte operations.
-pub const NUM_BYTE_OPS: usize = 9;
+pub const NUM_BYTE_OPS: usize = 12;
 
 /// The number of different byte lookup channels.
 pub const NUM_BYTE_LOOKUP_CHANNELS: u8 = 16;
@@ -106,6 +110,21 @@ impl<F: Field> ByteChip<F> {
                             col.value_u16 = F::from_canonical_u32(v);
                             ByteLookupEvent::new(shard, channel, *opcode, v as u16, 0, 0, 0)
                         }
+                        ByteOpcode::AesSbox => {
+                            let sbox = AES_SBOX[b as usize];
+                            col.aes_sbox = F::from_canonical_u8(sbox);
+                            ByteLookupEvent::new(shard, channel, *opcode, sbox as u16, 0, b, 0)
+                        }
+                        ByteOpcode::HexChar => {
+                            let char = hex_char(b, c);
+                            col.hex_char = F::from_canonical_u8(char);
+                            ByteLookupEvent::new(shard, channel, *opcode, char as u16, 0, b, c)
+                        }
+                        ByteOpcode::Base64Char => {
+                            let char = base64_char(b, c);
+                            col.base64_char = F::from_canonical_u8(char);
+                            ByteLookupEvent::new(shard, channel, *opcode, char as u16, 0, b, c)
+                        }
                     };
                 }
             }
@@ -1,6 +1,7 @@
 use std::borrow::BorrowMut;
 
 use hashbrown::HashMap;
+use num::ToPrimitive;
 use p3_field::Field;
 use p3_matrix::dense::RowMajorMatrix;
 use sp1_core_executor::{ByteOpcode, ExecutionRecord, Program};
@@ -43,8 +44,19 @@ impl<F: Field> MachineAir<F> for ByteChip<F> {
         let mut trace =
             RowMajorMatrix::new(vec![F::zero(); NUM_BYTE_MULT_COLS * NUM_ROWS], NUM_BYTE_MULT_COLS);
 
-        let shard = input.public_values.execution_shard;
+        let shard = input.byte_lookup_shard();
+        let order = F::order().to_usize().unwrap_or(usize::MAX);
         for (lookup, mult) in input.byte_lookups.get(&shard).unwrap_or(&HashMap::new()).iter() {
+            // A multiplicity at least the field order would wrap around and make the lookup
+            // argument fail without pointing at the cause.
+            assert!(
+                *mult < order,
+                "byte lookup multiplicity overflow in shard {}: {:?} has multiplicity {}, lower \
+                 SplitOpts::byte_lookups to split the lookups of the shard",
+                shard,
+                lookup,
+                mult
+            );
             let row = if lookup.opcode != ByteOpcode::U16Range {
                 (((lookup.b as u16) << 8) + lookup.c as u16) as usize
             } else {
@@ -59,9 +59,7 @@ where
         self.eval_registers::<AB>(builder, local, is_branch_instruction.clone());
 
         // Memory instructions.
-        self.eval_memory_address_and_access::<AB>(builder, local, is_memory_instruction.clone());
-        self.eval_memory_load::<AB>(builder, local);
-        self.eval_memory_store::<AB>(builder, local);
+        self.eval_memory_instruction::<AB>(builder, local, is_memory_instruction);
 
         // Channel constraints.
         eval_channel_selectors(
@@ -94,6 +92,9 @@ where
         // AUIPC instruction.
         self.eval_auipc(builder, local);
 
+        // RDCYCLE and RDINSTRET instructions.
+        self.eval_counters(builder, local, next);
+
         // ECALL instruction.
         self.eval_ecall(builder, local);
 
@@ -254,6 +255,45 @@ impl CpuChip {
         );
     }
 
+    /// Constraints related to the RDCYCLE and RDINSTRET opcodes.
+    ///
+    /// The counters are local to the shard: RDCYCLE reads the clk, and RDINSTRET reads the index of
+    /// the row, which is tracked by the instret column.
+    pub(crate) fn eval_counters<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &CpuCols<AB::Var>,
+        next: &CpuCols<AB::Var>,
+    ) {
+        // Verify that instret starts at 0 and increments by one for each instruction.
+        builder.when_first_row().assert_zero(local.instret);
+        builder
+            .when_transition()
+            .when(next.is_real)
+            .assert_eq(local.instret + AB::Expr::one(), next.instret);
+
+        // Verify that op_a is the value of the counter. Both counters are less than 2^24, since clk
+        // is range checked to 24 bits and it increments by at least 4 for each instruction, and
+        // the bytes of op_a are range checked, so the most significant byte must be zero.
+        let op_a_val = local.op_a_val();
+        let value = op_a_val[0]
+            + op_a_val[1] * AB::Expr::from_canonical_u32(1 << 8)
+            + op_a_val[2] * AB::Expr::from_canonical_u32(1 << 16);
+        let is_counter = local.selectors.is_rdcycle + local.selectors.is_rdinstret;
+        builder
+            .when(is_counter.clone())
+            .when_not(local.instruction.op_a_0)
+            .assert_zero(op_a_val[3]);
+        builder
+            .when(local.selectors.is_rdcycle)
+            .when_not(local.instruction.op_a_0)
+            .assert_eq(value.clone(), local.clk);
+        builder
+            .when(local.selectors.is_rdinstret)
+            .when_not(local.instruction.op_a_0)
+            .assert_eq(value, local.instret);
+    }
+
     /// Constraints related to the shard and clk.
     ///
     /// This method ensures that all of the shard values are the same and that the clk starts at 0
@@ -4,7 +4,6 @@ mod channel;
 mod ecall;
 mod instruction;
 mod jump;
-mod memory;
 mod opcode;
 mod opcode_specific;
 
@@ -14,7 +13,6 @@ pub use channel::*;
 pub use ecall::*;
 pub use instruction::*;
 pub use jump::*;
-pub use memory::*;
 pub use opcode::*;
 pub use opcode_specific::*;
 
@@ -47,6 +45,9 @@ pub struct CpuCols<T: Copy> {
     /// The most significant 8 bit limb of clk.
     pub clk_8bit_limb: T,
 
+    /// The number of instructions executed in the shard before this one.
+    pub instret: T,
+
     /// The program counter value.
     pub pc: T,
 
@@ -88,28 +89,6 @@ pub struct CpuCols<T: Copy> {
     /// > (is_bge | is_bgeu) & !(a_eq_b | a_gt_b)
     pub not_branching: T,
 
-    /// Flag for load mem instructions where the value is negative and not writing to x0.
-    /// More formally, it is
-    ///
-    /// > (is_lb | is_lh) & (most_sig_byte_decomp[7] == 1) & (not writing to x0)
-    pub mem_value_is_neg_not_x0: T,
-
-    /// Flag for load mem instructions where the value is positive and not writing to x0.
-    /// More formally, it is
-    ///
-    /// (
-    ///     ((is_lb | is_lh) & (most_sig_byte_decomp[7] == 0)) |
-    ///     is_lbu | is_lhu | is_lw
-    /// ) &
-    /// (not writing to x0)
-    pub mem_value_is_pos_not_x0: T,
-
-    /// The unsigned memory value is the value after the offset logic is applied. Used for the load
-    /// memory opcodes (i.e. LB, LH, LW, LBU, and LHU).
-    pub unsigned_mem_val: Word<T>,
-
-    pub unsigned_mem_val_nonce: T,
-
     /// The result of selectors.is_ecall * the send_to_table column for the ECALL opcode.
     pub ecall_mul_send_to_table: T,
 
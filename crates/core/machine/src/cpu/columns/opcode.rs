@@ -57,6 +57,10 @@ pub struct OpcodeSelectorCols<T> {
     pub is_jalr: T,
     pub is_jal: T,
 
+    /// Counter Instructions.
+    pub is_rdcycle: T,
+    pub is_rdinstret: T,
+
     /// Miscellaneous.
     pub is_auipc: T,
     pub is_unimpl: T,
@@ -97,6 +101,10 @@ impl<F: PrimeField> OpcodeSelectorCols<F> {
             self.is_jal = F::one();
         } else if instruction.opcode == Opcode::JALR {
             self.is_jalr = F::one();
+        } else if instruction.opcode == Opcode::RDCYCLE {
+            self.is_rdcycle = F::one();
+        } else if instruction.opcode == Opcode::RDINSTRET {
+            self.is_rdinstret = F::one();
         } else if instruction.opcode == Opcode::AUIPC {
             self.is_auipc = F::one();
         } else if instruction.opcode == Opcode::UNIMP {
@@ -131,6 +139,8 @@ impl<T> IntoIterator for OpcodeSelectorCols<T> {
             self.is_bgeu,
             self.is_jalr,
             self.is_jal,
+            self.is_rdcycle,
+            self.is_rdinstret,
             self.is_auipc,
             self.is_unimpl,
         ];
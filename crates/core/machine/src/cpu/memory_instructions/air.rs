@@ -0,0 +1,412 @@
+use core::borrow::Borrow;
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::AbstractField;
+use p3_matrix::Matrix;
+use sp1_core_executor::{events::MemoryAccessPosition, ByteOpcode, Opcode};
+use sp1_stark::{
+    air::{recompose_bits, SP1AirBuilder},
+    Word,
+};
+
+use crate::{
+    air::{SP1CoreAirBuilder, WordAirBuilder},
+    memory::MemoryCols,
+    operations::BabyBearWordRangeChecker,
+};
+
+use super::{
+    columns::{MemoryInstructionCols, NUM_MEMORY_INSTRUCTION_COLS},
+    MemoryInstructionsChip,
+};
+
+impl<F> BaseAir<F> for MemoryInstructionsChip {
+    fn width(&self) -> usize {
+        NUM_MEMORY_INSTRUCTION_COLS
+    }
+}
+
+impl<AB> Air<AB> for MemoryInstructionsChip
+where
+    AB: SP1CoreAirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &MemoryInstructionCols<AB::Var> = (*local).borrow();
+
+        // The opcode selectors are boolean and at most one of them is set. The padding rows have
+        // none of them set.
+        let selectors = [
+            (local.is_lb, Opcode::LB),
+            (local.is_lbu, Opcode::LBU),
+            (local.is_lh, Opcode::LH),
+            (local.is_lhu, Opcode::LHU),
+            (local.is_lw, Opcode::LW),
+            (local.is_sb, Opcode::SB),
+            (local.is_sh, Opcode::SH),
+            (local.is_sw, Opcode::SW),
+        ];
+        let mut is_real = AB::Expr::zero();
+        let mut opcode = AB::Expr::zero();
+        for (selector, selector_opcode) in selectors {
+            builder.assert_bool(selector);
+            is_real += selector.into();
+            opcode += selector * selector_opcode.as_field::<AB::F>();
+        }
+        builder.assert_bool(is_real.clone());
+
+        // Receive the instruction from the CPU, which also checks the operand values.
+        builder.receive_instruction(
+            local.shard,
+            local.channel,
+            local.clk,
+            opcode,
+            local.op_a_value,
+            local.op_b_value,
+            local.op_c_value,
+            local.op_a_0,
+            is_real.clone(),
+        );
+        builder.assert_bool(local.op_a_0);
+
+        self.eval_memory_address_and_access::<AB>(builder, local, is_real.clone());
+        self.eval_memory_load::<AB>(builder, local, is_real.clone());
+        self.eval_memory_store::<AB>(builder, local);
+    }
+}
+
+impl MemoryInstructionsChip {
+    /// Computes whether the opcode is a load instruction.
+    pub(crate) fn is_load_instruction<AB: SP1AirBuilder>(
+        &self,
+        local: &MemoryInstructionCols<AB::Var>,
+    ) -> AB::Expr {
+        local.is_lb + local.is_lbu + local.is_lh + local.is_lhu + local.is_lw
+    }
+
+    /// Constrains the addr_aligned, addr_offset, and addr_word memory columns.
+    ///
+    /// This method will do the following:
+    /// 1. Calculate that the unaligned address is correctly computed to be op_b.value + op_c.value.
+    /// 2. Calculate that the address offset is address % 4.
+    /// 3. Assert the validity of the aligned address given the address offset and the unaligned
+    ///    address.
+    pub(crate) fn eval_memory_address_and_access<AB: SP1CoreAirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &MemoryInstructionCols<AB::Var>,
+        is_real: AB::Expr,
+    ) {
+        // Send to the ALU table to verify correct calculation of addr_word.
+        builder.send_alu(
+            AB::Expr::from_canonical_u32(Opcode::ADD as u32),
+            local.addr_word,
+            local.op_b_value,
+            local.op_c_value,
+            local.shard,
+            local.channel,
+            local.addr_word_nonce,
+            is_real.clone(),
+        );
+
+        // Range check the addr_word to be a valid babybear word.
+        BabyBearWordRangeChecker::<AB::F>::range_check(
+            builder,
+            local.addr_word,
+            local.addr_word_range_checker,
+            is_real.clone(),
+        );
+
+        // Check that each addr_word element is a byte.
+        builder.slice_range_check_u8(
+            &local.addr_word.0,
+            local.shard,
+            local.channel,
+            is_real.clone(),
+        );
+
+        // Evaluate the addr_offset column and offset flags.
+        self.eval_offset_value_flags(builder, local, is_real.clone());
+
+        // Assert that reduce(addr_word) == addr_aligned + addr_offset.
+        builder.when(is_real.clone()).assert_eq::<AB::Expr, AB::Expr>(
+            local.addr_aligned + local.addr_offset,
+            local.addr_word.reduce::<AB>(),
+        );
+
+        // Verify that the least significant byte of addr_word - addr_offset is divisible by 4.
+        let offset = [local.offset_is_one, local.offset_is_two, local.offset_is_three]
+            .iter()
+            .enumerate()
+            .fold(AB::Expr::zero(), |acc, (index, &value)| {
+                acc + AB::Expr::from_canonical_usize(index + 1) * value
+            });
+        let mut recomposed_byte = AB::Expr::zero();
+        local.aa_least_sig_byte_decomp.iter().enumerate().for_each(|(i, value)| {
+            builder.when(is_real.clone()).assert_bool(*value);
+
+            recomposed_byte =
+                recomposed_byte.clone() + AB::Expr::from_canonical_usize(1 << (i + 2)) * *value;
+        });
+
+        builder.when(is_real.clone()).assert_eq(local.addr_word[0] - offset, recomposed_byte);
+
+        // For operations that require reading from memory (not registers), we need to read the
+        // value into the memory columns.
+        builder.eval_memory_access(
+            local.shard,
+            local.channel,
+            local.clk + AB::F::from_canonical_u32(MemoryAccessPosition::Memory as u32),
+            local.addr_aligned,
+            &local.memory_access,
+            is_real,
+        );
+
+        // On memory load instructions, make sure that the memory value is not changed.
+        builder
+            .when(self.is_load_instruction::<AB>(local))
+            .assert_word_eq(*local.memory_access.value(), *local.memory_access.prev_value());
+
+        // A misaligned access spans two words when the bytes it accesses go past the end of the
+        // word at `addr_aligned`.
+        let [offset_is_zero, ..] = Self::offset_flags::<AB>(local);
+        builder.assert_eq(
+            local.is_split,
+            (local.is_lh + local.is_lhu + local.is_sh) * local.offset_is_three
+                + (local.is_lw + local.is_sw) * (AB::Expr::one() - offset_is_zero),
+        );
+
+        // The next word must be a valid address, so the most significant byte of the address is
+        // less than the one of the BabyBear modulus, 0x78.
+        builder.send_byte(
+            ByteOpcode::LTU.as_field::<AB::F>(),
+            AB::F::one(),
+            local.addr_word[3],
+            AB::F::from_canonical_u8(0x78),
+            local.shard,
+            local.channel,
+            local.is_split,
+        );
+
+        // Access the next word at the same timestamp as the first one.
+        builder.eval_memory_access(
+            local.shard,
+            local.channel,
+            local.clk + AB::F::from_canonical_u32(MemoryAccessPosition::Memory as u32),
+            local.addr_aligned + AB::F::from_canonical_u32(4),
+            &local.memory_access_next,
+            local.is_split,
+        );
+        builder.when(self.is_load_instruction::<AB>(local)).assert_word_eq(
+            *local.memory_access_next.value(),
+            *local.memory_access_next.prev_value(),
+        );
+    }
+
+    /// The eight bytes of the words at `addr_aligned` and `addr_aligned + 4`, before the access if
+    /// `prev` is set and after it otherwise.
+    fn access_bytes<AB: SP1AirBuilder>(
+        local: &MemoryInstructionCols<AB::Var>,
+        prev: bool,
+    ) -> [AB::Expr; 8] {
+        let (word, next_word) = if prev {
+            (*local.memory_access.prev_value(), *local.memory_access_next.prev_value())
+        } else {
+            (*local.memory_access.value(), *local.memory_access_next.value())
+        };
+        core::array::from_fn(|i| if i < 4 { word[i].into() } else { next_word[i - 4].into() })
+    }
+
+    /// The offset flags, indexed by the offset.
+    fn offset_flags<AB: SP1AirBuilder>(local: &MemoryInstructionCols<AB::Var>) -> [AB::Expr; 4] {
+        let offset_is_zero =
+            AB::Expr::one() - local.offset_is_one - local.offset_is_two - local.offset_is_three;
+        [
+            offset_is_zero,
+            local.offset_is_one.into(),
+            local.offset_is_two.into(),
+            local.offset_is_three.into(),
+        ]
+    }
+
+    /// Evaluates constraints related to loading from memory.
+    pub(crate) fn eval_memory_load<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &MemoryInstructionCols<AB::Var>,
+        is_real: AB::Expr,
+    ) {
+        // Verify the unsigned_mem_value column.
+        self.eval_unsigned_mem_value(builder, local);
+
+        // If it's a signed operation (such as LB or LH), then we need verify the bit decomposition
+        // of the most significant byte to get it's sign.
+        self.eval_most_sig_byte_bit_decomp(builder, local, is_real);
+
+        // Assert that correct value of `mem_value_is_neg_not_x0`.
+        builder.assert_eq(
+            local.mem_value_is_neg_not_x0,
+            (local.is_lb + local.is_lh)
+                * local.most_sig_byte_decomp[7]
+                * (AB::Expr::one() - local.op_a_0),
+        );
+
+        // When the memory value is negative and not writing to x0, use the SUB opcode to compute
+        // the signed value of the memory value and verify that the op_a value is correct.
+        let signed_value = Word([
+            AB::Expr::zero(),
+            AB::Expr::one() * local.is_lb,
+            AB::Expr::one() * local.is_lh,
+            AB::Expr::zero(),
+        ]);
+        builder.send_alu(
+            Opcode::SUB.as_field::<AB::F>(),
+            local.op_a_value,
+            local.unsigned_mem_val,
+            signed_value,
+            local.shard,
+            local.channel,
+            local.unsigned_mem_val_nonce,
+            local.mem_value_is_neg_not_x0,
+        );
+
+        // Assert that correct value of `mem_value_is_pos_not_x0`.
+        let mem_value_is_pos = (local.is_lb + local.is_lh)
+            * (AB::Expr::one() - local.most_sig_byte_decomp[7])
+            + local.is_lbu
+            + local.is_lhu
+            + local.is_lw;
+        builder.assert_eq(
+            local.mem_value_is_pos_not_x0,
+            mem_value_is_pos * (AB::Expr::one() - local.op_a_0),
+        );
+
+        // When the memory value is not positive and not writing to x0, assert that op_a value is
+        // equal to the unsigned memory value.
+        builder
+            .when(local.mem_value_is_pos_not_x0)
+            .assert_word_eq(local.unsigned_mem_val, local.op_a_value);
+    }
+
+    /// Evaluates constraints related to storing to memory.
+    ///
+    /// A store of `size` bytes at offset `o` replaces the bytes `o..o + size` of the two words
+    /// with the low bytes of `op_a`, and leaves the other bytes unchanged.
+    pub(crate) fn eval_memory_store<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &MemoryInstructionCols<AB::Var>,
+    ) {
+        // The offset flags are constrained in `eval_memory_address_and_access`.
+        let offsets = Self::offset_flags::<AB>(local);
+
+        let a_val = local.op_a_value;
+        let prev_bytes = Self::access_bytes::<AB>(local, true);
+        let bytes = Self::access_bytes::<AB>(local, false);
+        for (selector, size) in [(local.is_sb, 1), (local.is_sh, 2), (local.is_sw, 4)] {
+            for (j, (byte, prev_byte)) in bytes.iter().zip(prev_bytes.iter()).enumerate() {
+                // The offsets at which the store covers byte `j`, with the byte of `op_a` stored
+                // there.
+                let mut covered = AB::Expr::zero();
+                let mut stored = AB::Expr::zero();
+                for (o, flag) in offsets.iter().enumerate() {
+                    if o <= j && j < o + size {
+                        covered += flag.clone();
+                        stored += flag.clone() * a_val[j - o];
+                    }
+                }
+                builder.when(selector).assert_eq(
+                    byte.clone(),
+                    stored + (AB::Expr::one() - covered) * prev_byte.clone(),
+                );
+            }
+        }
+    }
+
+    /// This function is used to evaluate the unsigned memory value for the load memory
+    /// instructions.
+    ///
+    /// A load of `size` bytes at offset `o` reads the bytes `o..o + size` of the two words.
+    pub(crate) fn eval_unsigned_mem_value<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &MemoryInstructionCols<AB::Var>,
+    ) {
+        let offsets = Self::offset_flags::<AB>(local);
+        let bytes = Self::access_bytes::<AB>(local, false);
+
+        // The byte at `addr_word + i`.
+        let loaded_byte = |i: usize| {
+            offsets
+                .iter()
+                .enumerate()
+                .fold(AB::Expr::zero(), |acc, (o, flag)| acc + flag.clone() * bytes[o + i].clone())
+        };
+
+        // When the instruciton is LB or LBU, just use the lower byte.
+        let byte_value = Word::extend_expr::<AB>(loaded_byte(0));
+        builder
+            .when(local.is_lb + local.is_lbu)
+            .assert_word_eq(byte_value, local.unsigned_mem_val.map(|x| x.into()));
+
+        // When the instruction is LH or LHU, use the two bytes at the address.
+        let half_value = Word([loaded_byte(0), loaded_byte(1), AB::Expr::zero(), AB::Expr::zero()]);
+        builder
+            .when(local.is_lh + local.is_lhu)
+            .assert_word_eq(half_value, local.unsigned_mem_val.map(|x| x.into()));
+
+        // When the instruction is LW, use the four bytes at the address.
+        let word_value = Word(core::array::from_fn(loaded_byte));
+        builder
+            .when(local.is_lw)
+            .assert_word_eq(word_value, local.unsigned_mem_val.map(|x| x.into()));
+    }
+
+    /// Evaluates the decomposition of the most significant byte of the memory value.
+    pub(crate) fn eval_most_sig_byte_bit_decomp<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &MemoryInstructionCols<AB::Var>,
+        is_real: AB::Expr,
+    ) {
+        builder.when(is_real).assert_bits(local.most_sig_byte_decomp);
+        let recomposed_byte = recompose_bits::<AB, _>(local.most_sig_byte_decomp);
+        builder.when(local.is_lb).assert_eq(recomposed_byte.clone(), local.unsigned_mem_val[0]);
+        builder.when(local.is_lh).assert_eq(recomposed_byte, local.unsigned_mem_val[1]);
+    }
+
+    /// Evaluates the offset value flags.
+    pub(crate) fn eval_offset_value_flags<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &MemoryInstructionCols<AB::Var>,
+        is_real: AB::Expr,
+    ) {
+        let offset_is_zero =
+            AB::Expr::one() - local.offset_is_one - local.offset_is_two - local.offset_is_three;
+
+        let mut filtered_builder = builder.when(is_real);
+
+        // Assert that the value flags are boolean
+        filtered_builder.assert_bool(local.offset_is_one);
+        filtered_builder.assert_bool(local.offset_is_two);
+        filtered_builder.assert_bool(local.offset_is_three);
+
+        // Assert that only one of the value flags is true
+        filtered_builder.assert_one(
+            offset_is_zero.clone()
+                + local.offset_is_one
+                + local.offset_is_two
+                + local.offset_is_three,
+        );
+
+        // Assert that the correct value flag is set
+        filtered_builder.when(offset_is_zero).assert_zero(local.addr_offset);
+        filtered_builder.when(local.offset_is_one).assert_one(local.addr_offset);
+        filtered_builder.when(local.offset_is_two).assert_eq(local.addr_offset, AB::Expr::two());
+        filtered_builder
+            .when(local.offset_is_three)
+            .assert_eq(local.addr_offset, AB::Expr::from_canonical_u8(3));
+    }
+}
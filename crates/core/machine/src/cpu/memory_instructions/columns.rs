@@ -0,0 +1,91 @@
+use sp1_derive::AlignedBorrow;
+use sp1_stark::Word;
+use std::mem::size_of;
+
+use crate::{memory::MemoryReadWriteCols, operations::BabyBearWordRangeChecker};
+
+pub const NUM_MEMORY_INSTRUCTION_COLS: usize = size_of::<MemoryInstructionCols<u8>>();
+
+/// The column layout for memory instructions.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct MemoryInstructionCols<T> {
+    /// The shard of the instruction.
+    pub shard: T,
+    /// The channel of the instruction, used for byte lookup multiplicity.
+    pub channel: T,
+    /// The clock cycle of the instruction.
+    pub clk: T,
+
+    /// The value of the first operand, which is stored for stores and loaded for loads.
+    pub op_a_value: Word<T>,
+    /// The value of the second operand, the base address.
+    pub op_b_value: Word<T>,
+    /// The value of the third operand, the address offset.
+    pub op_c_value: Word<T>,
+    /// Whether the first operand is register 0.
+    pub op_a_0: T,
+
+    /// Selectors for the opcode.
+    pub is_lb: T,
+    pub is_lbu: T,
+    pub is_lh: T,
+    pub is_lhu: T,
+    pub is_lw: T,
+    pub is_sb: T,
+    pub is_sh: T,
+    pub is_sw: T,
+
+    // An addr that we are reading from or writing to as a word. We are guaranteed that this does
+    // not overflow the field when reduced.
+
+    // The relationships among addr_word, addr_aligned, and addr_offset is as follows:
+    // addr_aligned = addr_word - addr_offset
+    // addr_offset = addr_word % 4
+    // Note that this all needs to be verified in the AIR
+    pub addr_word: Word<T>,
+    pub addr_word_range_checker: BabyBearWordRangeChecker<T>,
+
+    pub addr_aligned: T,
+    /// The LE bit decomp of the least significant byte of address aligned.
+    pub aa_least_sig_byte_decomp: [T; 6],
+    pub addr_offset: T,
+    pub memory_access: MemoryReadWriteCols<T>,
+
+    /// Whether the access is misaligned and spans the words at `addr_aligned` and
+    /// `addr_aligned + 4`.
+    pub is_split: T,
+    /// The access to the word at `addr_aligned + 4`, if the access spans two words.
+    pub memory_access_next: MemoryReadWriteCols<T>,
+
+    pub offset_is_one: T,
+    pub offset_is_two: T,
+    pub offset_is_three: T,
+
+    // LE bit decomposition for the most significant byte of memory value.  This is used to
+    // determine the sign for that value (used for LB and LH).
+    pub most_sig_byte_decomp: [T; 8],
+
+    /// Flag for load mem instructions where the value is negative and not writing to x0.
+    /// More formally, it is
+    ///
+    /// > (is_lb | is_lh) & (most_sig_byte_decomp[7] == 1) & (not writing to x0)
+    pub mem_value_is_neg_not_x0: T,
+
+    /// Flag for load mem instructions where the value is positive and not writing to x0.
+    /// More formally, it is
+    ///
+    /// (
+    ///     ((is_lb | is_lh) & (most_sig_byte_decomp[7] == 0)) |
+    ///     is_lbu | is_lhu | is_lw
+    /// ) &
+    /// (not writing to x0)
+    pub mem_value_is_pos_not_x0: T,
+
+    /// The unsigned memory value is the value after the offset logic is applied. Used for the load
+    /// memory opcodes (i.e. LB, LH, LW, LBU, and LHU).
+    pub unsigned_mem_val: Word<T>,
+
+    pub addr_word_nonce: T,
+    pub unsigned_mem_val_nonce: T,
+}
@@ -0,0 +1,80 @@
+pub mod air;
+pub mod columns;
+pub mod trace;
+
+/// A chip which proves the load and store instructions on behalf of the CPU.
+///
+/// The address computation and memory access columns are only used by memory instructions, so
+/// keeping them out of the CPU shrinks every CPU row. The CPU sends each memory instruction with
+/// its operands, which this chip receives.
+#[derive(Default)]
+pub struct MemoryInstructionsChip;
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Borrow;
+
+    use p3_baby_bear::BabyBear;
+    use p3_field::AbstractField;
+    use p3_matrix::{dense::RowMajorMatrix, Matrix};
+    use sp1_core_executor::{
+        programs::tests::{misaligned_memory_program, simple_memory_program},
+        ExecutionRecord, Executor, Opcode,
+    };
+    use sp1_stark::{air::MachineAir, CpuProver, SP1CoreOpts};
+
+    use super::{columns::MemoryInstructionCols, MemoryInstructionsChip};
+    use crate::{
+        io::SP1Stdin,
+        utils::{run_test, run_test_core, setup_logger},
+    };
+
+    #[test]
+    fn generate_trace() {
+        let mut runtime = Executor::new(simple_memory_program(), SP1CoreOpts::default());
+        runtime.run().unwrap();
+        let events = runtime
+            .record
+            .cpu_events
+            .iter()
+            .filter(|event| event.instruction.is_memory_instruction())
+            .collect::<Vec<_>>();
+        assert!(!events.is_empty());
+
+        let trace: RowMajorMatrix<BabyBear> =
+            MemoryInstructionsChip.generate_trace(&runtime.record, &mut ExecutionRecord::default());
+        assert!(trace.height() >= events.len());
+        for (i, event) in events.iter().enumerate() {
+            let row = trace.row_slice(i);
+            let cols: &MemoryInstructionCols<BabyBear> = (*row).borrow();
+            let selector = match event.instruction.opcode {
+                Opcode::LB => cols.is_lb,
+                Opcode::LBU => cols.is_lbu,
+                Opcode::LH => cols.is_lh,
+                Opcode::LHU => cols.is_lhu,
+                Opcode::LW => cols.is_lw,
+                Opcode::SB => cols.is_sb,
+                Opcode::SH => cols.is_sh,
+                Opcode::SW => cols.is_sw,
+                _ => unreachable!(),
+            };
+            assert_eq!(selector, BabyBear::one());
+            assert_eq!(cols.clk, BabyBear::from_canonical_u32(event.clk));
+        }
+    }
+
+    #[test]
+    fn test_memory_instructions_prove() {
+        setup_logger();
+        run_test::<CpuProver<_, _>>(simple_memory_program()).unwrap();
+    }
+
+    #[test]
+    fn test_misaligned_memory_instructions_prove() {
+        setup_logger();
+        let mut runtime = Executor::new(misaligned_memory_program(), SP1CoreOpts::default());
+        runtime.run().unwrap();
+        assert!(runtime.record.cpu_events.iter().any(|event| event.memory_next_record.is_some()));
+        run_test_core::<CpuProver<_, _>>(runtime, SP1Stdin::new()).unwrap();
+    }
+}
@@ -0,0 +1,291 @@
+use hashbrown::HashMap;
+use itertools::Itertools;
+use sp1_core_executor::{
+    events::{create_alu_lookups, AluEvent, ByteLookupEvent, ByteRecord, CpuEvent, LookupId},
+    ByteOpcode, ExecutionRecord, Opcode, Program,
+    Register::X0,
+};
+use sp1_primitives::consts::WORD_SIZE;
+use sp1_stark::air::MachineAir;
+use std::{array, borrow::BorrowMut};
+
+use p3_field::{PrimeField, PrimeField32};
+use p3_matrix::dense::RowMajorMatrix;
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
+use tracing::instrument;
+
+use super::{
+    columns::{MemoryInstructionCols, NUM_MEMORY_INSTRUCTION_COLS},
+    MemoryInstructionsChip,
+};
+use crate::{memory::MemoryCols, utils::pad_to_power_of_two};
+
+impl<F: PrimeField32> MachineAir<F> for MemoryInstructionsChip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "MemoryInstructions".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        _: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let events = memory_instruction_events(input);
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+
+        let row_batches = events
+            .par_chunks(chunk_size)
+            .map(|events| {
+                events
+                    .iter()
+                    .map(|event| {
+                        let mut row = [F::zero(); NUM_MEMORY_INSTRUCTION_COLS];
+                        let cols: &mut MemoryInstructionCols<F> = row.as_mut_slice().borrow_mut();
+                        let mut blu = Vec::new();
+                        self.event_to_row(event, &input.nonce_lookup, cols, &mut blu);
+                        row
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            row_batches.into_iter().flatten().flatten().collect::<Vec<_>>(),
+            NUM_MEMORY_INSTRUCTION_COLS,
+        );
+
+        // Pad the trace to a power of two.
+        pad_to_power_of_two::<NUM_MEMORY_INSTRUCTION_COLS, F>(&mut trace.values);
+
+        trace
+    }
+
+    #[instrument(name = "generate memory instruction dependencies", level = "debug", skip_all)]
+    fn generate_dependencies(&self, input: &ExecutionRecord, output: &mut ExecutionRecord) {
+        let events = memory_instruction_events(input);
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+
+        let (alu_events, blu_events): (Vec<_>, Vec<_>) = events
+            .par_chunks(chunk_size)
+            .map(|events| {
+                let mut alu = HashMap::new();
+                // The blu map stores shard -> map(byte lookup event -> multiplicity).
+                let mut blu: HashMap<u32, HashMap<ByteLookupEvent, usize>> = HashMap::new();
+                events.iter().for_each(|event| {
+                    let mut row = [F::zero(); NUM_MEMORY_INSTRUCTION_COLS];
+                    let cols: &mut MemoryInstructionCols<F> = row.as_mut_slice().borrow_mut();
+                    let alu_events = self.event_to_row(event, &HashMap::new(), cols, &mut blu);
+                    alu_events.into_iter().for_each(|(key, value)| {
+                        alu.entry(key).or_insert(Vec::default()).extend(value);
+                    });
+                });
+                (alu, blu)
+            })
+            .unzip();
+
+        for alu_events_chunk in alu_events.into_iter() {
+            output.add_alu_events(alu_events_chunk);
+        }
+
+        output.add_sharded_byte_lookup_events(blu_events.iter().collect_vec());
+    }
+
+    fn included(&self, input: &Self::Record) -> bool {
+        input.cpu_events.iter().any(|event| event.instruction.is_memory_instruction())
+    }
+}
+
+/// The CPU events of the load and store instructions.
+fn memory_instruction_events(input: &ExecutionRecord) -> Vec<&CpuEvent> {
+    input.cpu_events.iter().filter(|event| event.instruction.is_memory_instruction()).collect()
+}
+
+impl MemoryInstructionsChip {
+    /// Create a row from the CPU event of a memory instruction.
+    fn event_to_row<F: PrimeField>(
+        &self,
+        event: &CpuEvent,
+        nonce_lookup: &HashMap<LookupId, u32>,
+        cols: &mut MemoryInstructionCols<F>,
+        blu_events: &mut impl ByteRecord,
+    ) -> HashMap<Opcode, Vec<AluEvent>> {
+        let mut new_alu_events = HashMap::new();
+
+        // Populate the instruction columns.
+        cols.shard = F::from_canonical_u32(event.shard);
+        cols.channel = F::from_canonical_u8(event.channel);
+        cols.clk = F::from_canonical_u32(event.clk);
+        cols.op_a_value = event.a.into();
+        cols.op_b_value = event.b.into();
+        cols.op_c_value = event.c.into();
+        cols.op_a_0 = F::from_bool(event.instruction.op_a == X0 as u32);
+        match event.instruction.opcode {
+            Opcode::LB => cols.is_lb = F::one(),
+            Opcode::LBU => cols.is_lbu = F::one(),
+            Opcode::LH => cols.is_lh = F::one(),
+            Opcode::LHU => cols.is_lhu = F::one(),
+            Opcode::LW => cols.is_lw = F::one(),
+            Opcode::SB => cols.is_sb = F::one(),
+            Opcode::SH => cols.is_sh = F::one(),
+            Opcode::SW => cols.is_sw = F::one(),
+            _ => unreachable!(),
+        }
+
+        // Populate memory accesses for reading from memory.
+        assert_eq!(event.memory_record.is_some(), event.memory.is_some());
+        if let Some(record) = event.memory_record {
+            cols.memory_access.populate(event.channel, record, blu_events)
+        }
+        if let Some(record) = event.memory_next_record {
+            cols.is_split = F::one();
+            cols.memory_access_next.populate(event.channel, record, blu_events);
+        }
+
+        // Populate addr_word and addr_aligned columns.
+        let memory_addr = event.b.wrapping_add(event.c);
+        let aligned_addr = memory_addr - memory_addr % WORD_SIZE as u32;
+        cols.addr_word = memory_addr.into();
+        cols.addr_word_range_checker.populate(memory_addr);
+        cols.addr_aligned = F::from_canonical_u32(aligned_addr);
+
+        // Populate the aa_least_sig_byte_decomp columns.
+        assert!(aligned_addr % 4 == 0);
+        let aligned_addr_ls_byte = (aligned_addr & 0x000000FF) as u8;
+        let bits: [bool; 8] = array::from_fn(|i| aligned_addr_ls_byte & (1 << i) != 0);
+        cols.aa_least_sig_byte_decomp = array::from_fn(|i| F::from_bool(bits[i + 2]));
+
+        // Add event to ALU check to check that addr == b + c
+        let add_event = AluEvent {
+            lookup_id: event.memory_add_lookup_id,
+            shard: event.shard,
+            channel: event.channel,
+            clk: event.clk,
+            opcode: Opcode::ADD,
+            a: memory_addr,
+            b: event.b,
+            c: event.c,
+            sub_lookups: create_alu_lookups(),
+        };
+        new_alu_events
+            .entry(Opcode::ADD)
+            .and_modify(|op_new_events: &mut Vec<AluEvent>| op_new_events.push(add_event))
+            .or_insert(vec![add_event]);
+        cols.addr_word_nonce = F::from_canonical_u32(
+            nonce_lookup.get(&event.memory_add_lookup_id).copied().unwrap_or_default(),
+        );
+
+        // Populate memory offsets.
+        let addr_offset = (memory_addr % WORD_SIZE as u32) as u8;
+        cols.addr_offset = F::from_canonical_u8(addr_offset);
+        cols.offset_is_one = F::from_bool(addr_offset == 1);
+        cols.offset_is_two = F::from_bool(addr_offset == 2);
+        cols.offset_is_three = F::from_bool(addr_offset == 3);
+
+        // If it is a load instruction, set the unsigned_mem_val column.
+        let mem_value = event.memory_record.unwrap().value();
+        if matches!(
+            event.instruction.opcode,
+            Opcode::LB | Opcode::LBU | Opcode::LH | Opcode::LHU | Opcode::LW
+        ) {
+            let mut bytes = [0u8; 8];
+            bytes[..4].copy_from_slice(&mem_value.to_le_bytes());
+            if let Some(record) = event.memory_next_record {
+                bytes[4..].copy_from_slice(&record.value().to_le_bytes());
+            }
+            let size = match event.instruction.opcode {
+                Opcode::LB | Opcode::LBU => 1,
+                Opcode::LH | Opcode::LHU => 2,
+                _ => 4,
+            };
+            let mut value = [0u8; 4];
+            value[..size]
+                .copy_from_slice(&bytes[addr_offset as usize..addr_offset as usize + size]);
+            cols.unsigned_mem_val = u32::from_le_bytes(value).into();
+
+            // For the signed load instructions, we need to check if the loaded value is negative.
+            if matches!(event.instruction.opcode, Opcode::LB | Opcode::LH) {
+                let most_sig_mem_value_byte: u8;
+                let sign_value: u32;
+                if matches!(event.instruction.opcode, Opcode::LB) {
+                    sign_value = 256;
+                    most_sig_mem_value_byte = cols.unsigned_mem_val.to_u32().to_le_bytes()[0];
+                } else {
+                    // LHU case
+                    sign_value = 65536;
+                    most_sig_mem_value_byte = cols.unsigned_mem_val.to_u32().to_le_bytes()[1];
+                };
+
+                for i in (0..8).rev() {
+                    cols.most_sig_byte_decomp[i] =
+                        F::from_canonical_u8(most_sig_mem_value_byte >> i & 0x01);
+                }
+                if cols.most_sig_byte_decomp[7] == F::one() {
+                    cols.mem_value_is_neg_not_x0 =
+                        F::from_bool(event.instruction.op_a != (X0 as u32));
+                    let sub_event = AluEvent {
+                        lookup_id: event.memory_sub_lookup_id,
+                        channel: event.channel,
+                        shard: event.shard,
+                        clk: event.clk,
+                        opcode: Opcode::SUB,
+                        a: event.a,
+                        b: cols.unsigned_mem_val.to_u32(),
+                        c: sign_value,
+                        sub_lookups: create_alu_lookups(),
+                    };
+                    cols.unsigned_mem_val_nonce = F::from_canonical_u32(
+                        nonce_lookup.get(&event.memory_sub_lookup_id).copied().unwrap_or_default(),
+                    );
+
+                    new_alu_events
+                        .entry(Opcode::SUB)
+                        .and_modify(|op_new_events| op_new_events.push(sub_event))
+                        .or_insert(vec![sub_event]);
+                }
+            }
+
+            // Set the `mem_value_is_pos_not_x0` composite flag.
+            cols.mem_value_is_pos_not_x0 = F::from_bool(
+                ((matches!(event.instruction.opcode, Opcode::LB | Opcode::LH)
+                    && (cols.most_sig_byte_decomp[7] == F::zero()))
+                    || matches!(event.instruction.opcode, Opcode::LBU | Opcode::LHU | Opcode::LW))
+                    && event.instruction.op_a != (X0 as u32),
+            );
+        }
+
+        let addr_bytes = memory_addr.to_le_bytes();
+
+        // Check that the next word of an access spanning two words is a valid address.
+        if event.memory_next_record.is_some() {
+            blu_events.add_byte_lookup_event(ByteLookupEvent {
+                shard: event.shard,
+                channel: event.channel,
+                opcode: ByteOpcode::LTU,
+                a1: 1,
+                a2: 0,
+                b: addr_bytes[3],
+                c: 0x78,
+            });
+        }
+
+        // Add event to byte lookup for byte range checking each byte in the memory addr
+        for byte_pair in addr_bytes.chunks_exact(2) {
+            blu_events.add_byte_lookup_event(ByteLookupEvent {
+                shard: event.shard,
+                channel: event.channel,
+                opcode: ByteOpcode::U8Range,
+                a1: 0,
+                a2: 0,
+                b: byte_pair[0],
+                c: byte_pair[1],
+            });
+        }
+
+        new_alu_events
+    }
+}
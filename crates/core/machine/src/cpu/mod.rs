@@ -1,5 +1,6 @@
 pub mod air;
 pub mod columns;
+pub mod memory_instructions;
 pub mod trace;
 
 /// The maximum log degree of the CPU chip to avoid lookup multiplicity overflow.
@@ -1,7 +1,14 @@
+use std::collections::BTreeMap;
+
 use crate::utils::Buffer;
+use elf::{endian::LittleEndian, ElfBytes};
 use k256::sha2::{Digest, Sha256};
 use num_bigint::BigUint;
+use p3_baby_bear::BabyBear;
+use p3_field::{AbstractField, PrimeField32};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sp1_core_executor::ElfError;
+use sp1_primitives::poseidon2_hash;
 use sp1_stark::{baby_bear_poseidon2::BabyBearPoseidon2, ShardProof, StarkVerifyingKey};
 
 /// Standard input for the prover.
@@ -12,8 +19,142 @@ pub struct SP1Stdin {
     pub buffer: Vec<Vec<u8>>,
     pub ptr: usize,
     pub proofs: Vec<(ShardProof<BabyBearPoseidon2>, StarkVerifyingKey<BabyBearPoseidon2>)>,
+    /// The numbered input streams written with [SP1Stdin::write_to], by id. The stream 0 is the
+    /// buffer.
+    pub streams: BTreeMap<u32, Vec<Vec<u8>>>,
+}
+
+/// The hash function a program uses to compute the digest of its public values.
+///
+/// The digest is what the proof commits to, and what the Groth16 and PLONK wrappers expose as a
+/// public input, so it should be cheap to recompute for the system that consumes the proof. The
+/// program selects it with the `public-values-keccak` or `public-values-poseidon2` feature of
+/// `sp1-zkvm`, which writes its id to the [PUBLIC_VALUES_HASH_SECTION] of the ELF, and the
+/// verifying key records the hash read from that section at setup. The section is loaded with the
+/// program, so the hash is bound by the preprocessed commitment of the verifying key.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PublicValuesHash {
+    /// SHA-256, which is the default and is native to Bitcoin-adjacent systems.
+    #[default]
+    Sha256,
+    /// Keccak-256, which is native to the EVM.
+    Keccak256,
+    /// Poseidon2 over BabyBear, which is cheap to recompute in other SNARK circuits.
+    ///
+    /// Each byte of the public values is absorbed as a field element, followed by the 4
+    /// little-endian bytes of their length. The digest is the 8 output elements as little-endian
+    /// `u32`s.
+    ///
+    /// There is no Poseidon2 over BabyBear on the EVM, so the Solidity verifiers cannot recompute
+    /// this digest from the public values: `verifyProofPoseidon2` takes the digest itself, which
+    /// the caller must derive from data it trusts.
+    Poseidon2,
 }
 
+/// The name of the ELF section holding the id of the [PublicValuesHash] of the program, as a
+/// single byte.
+pub const PUBLIC_VALUES_HASH_SECTION: &str = ".sp1.public_values_hash";
+
+impl PublicValuesHash {
+    /// The id of the hash in the [PUBLIC_VALUES_HASH_SECTION].
+    pub const fn id(&self) -> u8 {
+        match self {
+            Self::Sha256 => 0,
+            Self::Keccak256 => 1,
+            Self::Poseidon2 => 2,
+        }
+    }
+
+    /// The hash the program `elf` computes the digest of its public values with.
+    ///
+    /// Programs built against a `sp1-zkvm` without the [PUBLIC_VALUES_HASH_SECTION] use SHA-256.
+    pub fn from_elf(elf: &[u8]) -> Result<Self, ElfError> {
+        let elf = ElfBytes::<LittleEndian>::minimal_parse(elf)
+            .map_err(|err| ElfError::Parse(err.to_string()))?;
+        let Some(header) = elf
+            .section_header_by_name(PUBLIC_VALUES_HASH_SECTION)
+            .map_err(|err| ElfError::Parse(err.to_string()))?
+        else {
+            return Ok(Self::Sha256);
+        };
+        let (data, _) =
+            elf.section_data(&header).map_err(|err| ElfError::Parse(err.to_string()))?;
+        match data {
+            [0] => Ok(Self::Sha256),
+            [1] => Ok(Self::Keccak256),
+            [2] => Ok(Self::Poseidon2),
+            _ => Err(ElfError::Parse(format!(
+                "invalid {PUBLIC_VALUES_HASH_SECTION} section: {data:?}"
+            ))),
+        }
+    }
+
+    /// The digest of `data`.
+    pub fn digest(&self, data: &[u8]) -> [u8; 32] {
+        match self {
+            Self::Sha256 => Sha256::digest(data).into(),
+            Self::Keccak256 => {
+                use tiny_keccak::{Hasher, Keccak};
+
+                let mut hasher = Keccak::v256();
+                hasher.update(data);
+                let mut digest = [0u8; 32];
+                hasher.finalize(&mut digest);
+                digest
+            }
+            Self::Poseidon2 => {
+                let input = data
+                    .iter()
+                    .chain((data.len() as u32).to_le_bytes().iter())
+                    .map(|&byte| BabyBear::from_canonical_u8(byte))
+                    .collect();
+                let mut digest = [0u8; 32];
+                for (chunk, element) in digest.chunks_exact_mut(4).zip(poseidon2_hash(input)) {
+                    chunk.copy_from_slice(&element.as_canonical_u32().to_le_bytes());
+                }
+                digest
+            }
+        }
+    }
+}
+
+/// The name of the ELF section holding the largest number of bytes a program declared with
+/// `entrypoint!(main, max_stdin_size = N)` reads from its input buffer, as a little-endian `u64`.
+pub const MAX_STDIN_SIZE_SECTION: &str = ".sp1.max_stdin_size";
+
+/// The largest number of bytes the program `elf` reads from its input buffer, if it declares one
+/// in the [MAX_STDIN_SIZE_SECTION].
+pub fn max_stdin_size_from_elf(elf: &[u8]) -> Result<Option<u64>, ElfError> {
+    let elf = ElfBytes::<LittleEndian>::minimal_parse(elf)
+        .map_err(|err| ElfError::Parse(err.to_string()))?;
+    let Some(header) = elf
+        .section_header_by_name(MAX_STDIN_SIZE_SECTION)
+        .map_err(|err| ElfError::Parse(err.to_string()))?
+    else {
+        return Ok(None);
+    };
+    let (data, _) = elf.section_data(&header).map_err(|err| ElfError::Parse(err.to_string()))?;
+    let bytes = data.try_into().map_err(|_| {
+        ElfError::Parse(format!("invalid {MAX_STDIN_SIZE_SECTION} section: {data:?}"))
+    })?;
+    Ok(Some(u64::from_le_bytes(bytes)))
+}
+
+/// The bytes that end the section of the public values holding the values committed by name with
+/// `sp1_zkvm::io::commit_named`.
+pub const NAMED_PUBLIC_VALUES_MAGIC: [u8; 8] = *b"SP1NAMED";
+
+/// The tag that starts a stdin compressed with [SP1Stdin::to_compressed_bytes].
+///
+/// A bincode-serialized stdin starts with the number of entries of its buffer as a little-endian
+/// `u64`. The last byte of the tag makes it read as more than `i64::MAX` entries, which no buffer
+/// can have, so a plain stdin never starts with the tag.
+pub const COMPRESSED_STDIN_TAG: [u8; 8] = *b"SP1ZSTD\xff";
+
+/// The prefix of the names under which `sp1_zkvm::io::write_to` commits the numbered output
+/// streams, followed by the id of the stream in decimal.
+pub const OUTPUT_STREAM_KEY_PREFIX: &str = "sp1.stream.";
+
 /// Public values for the prover.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct SP1PublicValues {
@@ -23,12 +164,35 @@ pub struct SP1PublicValues {
 impl SP1Stdin {
     /// Create a new `SP1Stdin`.
     pub const fn new() -> Self {
-        Self { buffer: Vec::new(), ptr: 0, proofs: Vec::new() }
+        Self { buffer: Vec::new(), ptr: 0, proofs: Vec::new(), streams: BTreeMap::new() }
     }
 
     /// Create a `SP1Stdin` from a slice of bytes.
     pub fn from(data: &[u8]) -> Self {
-        Self { buffer: vec![data.to_vec()], ptr: 0, proofs: Vec::new() }
+        Self { buffer: vec![data.to_vec()], ptr: 0, proofs: Vec::new(), streams: BTreeMap::new() }
+    }
+
+    /// Serialize the stdin with bincode and compress it into a single zstd frame, which is the
+    /// body of an upload sent with `Content-Encoding: zstd`. The stdin itself is unchanged, so the
+    /// program and its proof are too.
+    pub fn to_zstd_bytes(&self) -> bincode::Result<Vec<u8>> {
+        let bytes = bincode::serialize(self)?;
+        Ok(zstd::encode_all(bytes.as_slice(), 0)?)
+    }
+
+    /// Serialize the stdin with bincode and compress it with zstd, after the
+    /// [COMPRESSED_STDIN_TAG], to store a large stdin that [SP1Stdin::from_bytes] reads back.
+    pub fn to_compressed_bytes(&self) -> bincode::Result<Vec<u8>> {
+        Ok([COMPRESSED_STDIN_TAG.as_slice(), self.to_zstd_bytes()?.as_slice()].concat())
+    }
+
+    /// Deserialize a stdin serialized with bincode, decompressing it first if it starts with the
+    /// [COMPRESSED_STDIN_TAG] of [SP1Stdin::to_compressed_bytes].
+    pub fn from_bytes(bytes: &[u8]) -> bincode::Result<Self> {
+        match bytes.strip_prefix(COMPRESSED_STDIN_TAG.as_slice()) {
+            Some(compressed) => bincode::deserialize(&zstd::decode_all(compressed)?),
+            None => bincode::deserialize(bytes),
+        }
     }
 
     /// Read a value from the buffer.
@@ -49,18 +213,66 @@ impl SP1Stdin {
     pub fn write<T: Serialize>(&mut self, data: &T) {
         let mut tmp = Vec::new();
         bincode::serialize_into(&mut tmp, data).expect("serialization failed");
-        self.buffer.push(tmp);
+        self.write_vec(tmp);
     }
 
     /// Write a slice of bytes to the buffer.
     pub fn write_slice(&mut self, slice: &[u8]) {
-        self.buffer.push(slice.to_vec());
+        self.write_vec(slice.to_vec());
     }
 
     pub fn write_vec(&mut self, vec: Vec<u8>) {
         self.buffer.push(vec);
     }
 
+    /// Write a value to the numbered input stream `stream_id`, which the program reads with
+    /// `sp1_zkvm::io::read_from`. The stream 0 is the buffer.
+    ///
+    /// The streams other than 0 are left out of [SP1Stdin::input_digest].
+    pub fn write_to<T: Serialize>(&mut self, stream_id: u32, data: &T) {
+        let mut tmp = Vec::new();
+        bincode::serialize_into(&mut tmp, data).expect("serialization failed");
+        self.write_vec_to(stream_id, tmp);
+    }
+
+    /// Write a slice of bytes to the numbered input stream `stream_id`.
+    pub fn write_slice_to(&mut self, stream_id: u32, slice: &[u8]) {
+        self.write_vec_to(stream_id, slice.to_vec());
+    }
+
+    fn write_vec_to(&mut self, stream_id: u32, vec: Vec<u8>) {
+        if stream_id == 0 {
+            self.write_vec(vec);
+        } else {
+            self.streams.entry(stream_id).or_default().push(vec);
+        }
+    }
+
+    /// The total number of bytes of the buffer and of the other streams.
+    pub fn input_size(&self) -> usize {
+        let streams = self.streams.values().flatten().map(Vec::len).sum::<usize>();
+        self.buffer.iter().map(Vec::len).sum::<usize>() + streams
+    }
+
+    /// The number of bytes of the buffer, which is what a program declared with
+    /// `entrypoint!(main, max_stdin_size = N)` limits. The other streams are left out.
+    pub fn buffer_size(&self) -> usize {
+        self.buffer.iter().map(Vec::len).sum()
+    }
+
+    /// The SHA-256 digest of the buffer that a program declared with
+    /// `entrypoint!(main, commit_input_digest)` commits to after reading all of it.
+    ///
+    /// Each entry is hashed as its length in 8 little-endian bytes followed by its bytes.
+    pub fn input_digest(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        for entry in self.buffer.iter() {
+            hasher.update((entry.len() as u64).to_le_bytes());
+            hasher.update(entry);
+        }
+        hasher.finalize().into()
+    }
+
     pub fn write_proof(
         &mut self,
         proof: ShardProof<BabyBearPoseidon2>,
@@ -103,6 +315,36 @@ impl SP1PublicValues {
         self.buffer.read_slice(slice);
     }
 
+    /// Read a value at `offset` without moving the cursor.
+    pub fn read_at<T: Serialize + DeserializeOwned>(&self, offset: usize) -> T {
+        bincode::deserialize(&self.buffer.data[offset..]).expect("failed to deserialize")
+    }
+
+    /// The `len` bytes at `offset`, without moving the cursor.
+    pub fn slice_at(&self, offset: usize, len: usize) -> &[u8] {
+        &self.buffer.data[offset..offset + len]
+    }
+
+    /// Move the cursor back to the start of the public values, so that they can be read again.
+    pub fn reset(&mut self) {
+        self.buffer.head();
+    }
+
+    /// Move the cursor to `offset`, panicking if it is past the end of the public values.
+    pub fn seek(&mut self, offset: usize) {
+        self.buffer.seek(offset);
+    }
+
+    /// The offset of the cursor.
+    pub fn position(&self) -> usize {
+        self.buffer.ptr
+    }
+
+    /// The bytes after the cursor, which have not been read yet.
+    pub fn remaining(&self) -> &[u8] {
+        self.buffer.remaining()
+    }
+
     /// Write a value to the buffer.
     pub fn write<T: Serialize>(&mut self, data: &T) {
         self.buffer.write(data);
@@ -113,25 +355,94 @@ impl SP1PublicValues {
         self.buffer.write_slice(slice);
     }
 
-    /// Hash the public values.
+    /// The digest of the inputs committed by a program declared with
+    /// `entrypoint!(main, commit_input_digest)`, which is the last 32 bytes of the public values.
+    ///
+    /// Compare it with [SP1Stdin::input_digest] to check that a proof is for the given inputs.
+    pub fn input_digest(&self) -> Option<[u8; 32]> {
+        let data = self.buffer.data.as_slice();
+        data.len().checked_sub(32).map(|start| data[start..].try_into().unwrap())
+    }
+
+    /// The value committed under `key` with `sp1_zkvm::io::commit_named`, if any.
+    ///
+    /// Panics if the value fails to deserialize as a `T`.
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        self.named()
+            .get(key)
+            .map(|value| bincode::deserialize(value).expect("failed to deserialize"))
+    }
+
+    /// The serialized values committed with `sp1_zkvm::io::commit_named`, by key.
+    ///
+    /// The program writes them after its other public values when it halts, followed by the
+    /// digest of its inputs if it commits to it. Each entry, in increasing order of the keys, is
+    /// the length of its key as 4 little-endian bytes, the key, the length of its value as 4
+    /// little-endian bytes and the value. The entries are followed by their number and their
+    /// total length in bytes, both as 4 little-endian bytes, and by [NAMED_PUBLIC_VALUES_MAGIC].
+    pub fn named(&self) -> BTreeMap<String, Vec<u8>> {
+        let data = self.buffer.data.as_slice();
+        [Some(data.len()), data.len().checked_sub(32)]
+            .into_iter()
+            .flatten()
+            .find_map(|end| decode_named_public_values(&data[..end]))
+            .unwrap_or_default()
+    }
+
+    /// The entries written to the numbered output stream `stream_id` with
+    /// `sp1_zkvm::io::write_to`, in order. The stream 0 is the public values themselves, read
+    /// with [SP1PublicValues::read].
+    ///
+    /// Panics if the stream is not encoded as entries prefixed by their length.
+    pub fn stream(&self, stream_id: u32) -> Vec<Vec<u8>> {
+        let Some(data) = self.named().remove(&format!("{OUTPUT_STREAM_KEY_PREFIX}{stream_id}"))
+        else {
+            return Vec::new();
+        };
+        let mut entries = Vec::new();
+        let mut rest = data.as_slice();
+        while !rest.is_empty() {
+            let len = u32::from_le_bytes(rest[..4].try_into().unwrap()) as usize;
+            entries.push(rest[4..4 + len].to_vec());
+            rest = &rest[4 + len..];
+        }
+        entries
+    }
+
+    /// The values written to the numbered output stream `stream_id`, in order.
+    ///
+    /// Panics if a value fails to deserialize as a `T`.
+    pub fn read_stream<T: DeserializeOwned>(&self, stream_id: u32) -> Vec<T> {
+        self.stream(stream_id)
+            .iter()
+            .map(|entry| bincode::deserialize(entry).expect("failed to deserialize"))
+            .collect()
+    }
+
+    /// Hash the public values with SHA-256.
     pub fn hash(&self) -> Vec<u8> {
-        let mut hasher = Sha256::new();
-        hasher.update(self.buffer.data.as_slice());
-        hasher.finalize().to_vec()
+        self.hash_with(PublicValuesHash::Sha256).to_vec()
     }
 
-    /// Hash the public values, mask the top 3 bits and return a BigUint. Matches the implementation
-    /// of `hashPublicValues` in the Solidity verifier.
+    /// Hash the public values with the given hash function.
+    pub fn hash_with(&self, hash: PublicValuesHash) -> [u8; 32] {
+        hash.digest(self.buffer.data.as_slice())
+    }
+
+    /// Hash the public values with SHA-256, mask the top 3 bits and return a BigUint. Matches the
+    /// implementation of `hashPublicValues` in the Solidity verifier.
     ///
     /// ```solidity
     /// sha256(publicValues) & bytes32(uint256((1 << 253) - 1));
     /// ```
     pub fn hash_bn254(&self) -> BigUint {
-        // Hash the public values.
-        let mut hasher = Sha256::new();
-        hasher.update(self.buffer.data.as_slice());
-        let hash_result = hasher.finalize();
-        let mut hash = hash_result.to_vec();
+        self.hash_bn254_with(PublicValuesHash::Sha256)
+    }
+
+    /// Hash the public values with the given hash function, mask the top 3 bits and return a
+    /// BigUint, as exposed by the Groth16 and PLONK proofs.
+    pub fn hash_bn254_with(&self, hash: PublicValuesHash) -> BigUint {
+        let mut hash = self.hash_with(hash);
 
         // Mask the top 3 bits.
         hash[0] &= 0b00011111;
@@ -141,6 +452,40 @@ impl SP1PublicValues {
     }
 }
 
+/// Decodes the section of values committed by name that ends `data`, if there is a valid one.
+fn decode_named_public_values(data: &[u8]) -> Option<BTreeMap<String, Vec<u8>>> {
+    let read_u32 = |bytes: &[u8]| u32::from_le_bytes(bytes.try_into().unwrap()) as usize;
+    let trailer = data.len().checked_sub(16)?;
+    if data[trailer + 8..] != NAMED_PUBLIC_VALUES_MAGIC {
+        return None;
+    }
+    let count = read_u32(&data[trailer..trailer + 4]);
+    let len = read_u32(&data[trailer + 4..trailer + 8]);
+    let mut entries = &data[trailer.checked_sub(len)?..trailer];
+
+    let mut named = BTreeMap::new();
+    let mut last_key: Option<String> = None;
+    while !entries.is_empty() {
+        let mut next = || {
+            let rest: &[u8] = entries;
+            let len = read_u32(rest.get(..4)?);
+            let bytes = rest.get(4..4usize.checked_add(len)?)?;
+            entries = &rest[4 + len..];
+            Some(bytes.to_vec())
+        };
+        let key = String::from_utf8(next()?).ok()?;
+        let value = next()?;
+
+        // The keys are strictly increasing in the canonical encoding.
+        if last_key.as_ref().is_some_and(|last| *last >= key) {
+            return None;
+        }
+        last_key = Some(key.clone());
+        named.insert(key, value);
+    }
+    (named.len() == count).then_some(named)
+}
+
 impl AsRef<[u8]> for SP1PublicValues {
     fn as_ref(&self) -> &[u8] {
         &self.buffer.data
@@ -188,6 +533,159 @@ pub mod proof_serde {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_compressed_stdin() {
+        let data = vec![7u8; 1 << 16];
+        let mut stdin = SP1Stdin::new();
+        stdin.write(&10usize);
+        stdin.write_slice(&data);
+
+        let plain = bincode::serialize(&stdin).unwrap();
+        let compressed = stdin.to_compressed_bytes().unwrap();
+        assert!(compressed.starts_with(&COMPRESSED_STDIN_TAG));
+        assert!(compressed.len() < plain.len() / 10);
+
+        // An untagged zstd frame is not mistaken for a compressed stdin.
+        assert!(SP1Stdin::from_bytes(&stdin.to_zstd_bytes().unwrap()).is_err());
+
+        for bytes in [plain, compressed] {
+            let mut loaded = SP1Stdin::from_bytes(&bytes).unwrap();
+            assert_eq!(loaded.buffer, stdin.buffer);
+            assert_eq!(loaded.read::<usize>(), 10);
+            let mut slice = vec![0u8; data.len()];
+            loaded.read_slice(&mut slice);
+            assert_eq!(slice, data);
+        }
+    }
+
+    #[test]
+    fn test_input_digest() {
+        let mut stdin = SP1Stdin::new();
+        stdin.write(&10u32);
+        stdin.write_slice(&[1, 2, 3]);
+
+        let mut hasher = Sha256::new();
+        hasher.update(4u64.to_le_bytes());
+        hasher.update(10u32.to_le_bytes());
+        hasher.update(3u64.to_le_bytes());
+        hasher.update([1, 2, 3]);
+        let expected: [u8; 32] = hasher.finalize().into();
+        assert_eq!(stdin.input_digest(), expected);
+
+        let mut compressed = stdin.clone();
+        compressed.compress();
+        assert_eq!(compressed.input_digest(), expected);
+
+        let mut public_values = SP1PublicValues::new();
+        assert_eq!(public_values.input_digest(), None);
+        public_values.write(&7u32);
+        public_values.write_slice(&expected);
+        assert_eq!(public_values.input_digest(), Some(expected));
+    }
+
+    #[test]
+    fn test_public_values_cursor() {
+        let mut public_values = SP1PublicValues::new();
+        public_values.write(&1u32);
+        public_values.write(&2u64);
+        public_values.write_slice(&[3, 4]);
+
+        assert_eq!(public_values.read::<u32>(), 1);
+        assert_eq!(public_values.position(), 4);
+        assert_eq!(public_values.remaining().len(), 10);
+        assert_eq!(public_values.read_at::<u64>(4), 2);
+        assert_eq!(public_values.slice_at(12, 2), &[3, 4]);
+        assert_eq!(public_values.position(), 4);
+
+        public_values.seek(12);
+        let mut slice = [0u8; 2];
+        public_values.read_slice(&mut slice);
+        assert_eq!(slice, [3, 4]);
+        assert!(public_values.remaining().is_empty());
+
+        public_values.reset();
+        assert_eq!(public_values.read::<u32>(), 1);
+        assert_eq!(public_values.read::<u64>(), 2);
+    }
+
+    #[test]
+    fn test_named_public_values() {
+        let encode = |entries: &[(&str, Vec<u8>)]| {
+            let mut section = Vec::new();
+            for (key, value) in entries {
+                section.extend_from_slice(&(key.len() as u32).to_le_bytes());
+                section.extend_from_slice(key.as_bytes());
+                section.extend_from_slice(&(value.len() as u32).to_le_bytes());
+                section.extend_from_slice(value);
+            }
+            let len = section.len() as u32;
+            section.extend_from_slice(&(entries.len() as u32).to_le_bytes());
+            section.extend_from_slice(&len.to_le_bytes());
+            section.extend_from_slice(&NAMED_PUBLIC_VALUES_MAGIC);
+            section
+        };
+        let block_number = bincode::serialize(&42u64).unwrap();
+        let state_root = bincode::serialize(&[7u8; 32]).unwrap();
+
+        let mut public_values = SP1PublicValues::new();
+        public_values.write(&1u32);
+        assert!(public_values.named().is_empty());
+        public_values.write_slice(&encode(&[
+            ("block_number", block_number.clone()),
+            ("state_root", state_root.clone()),
+        ]));
+        assert_eq!(public_values.get::<u64>("block_number"), Some(42));
+        assert_eq!(public_values.get::<[u8; 32]>("state_root"), Some([7u8; 32]));
+        assert_eq!(public_values.get::<u64>("missing"), None);
+        assert_eq!(public_values.read::<u32>(), 1);
+
+        // The section is found before the digest of the inputs.
+        public_values.write_slice(&[9u8; 32]);
+        assert_eq!(public_values.get::<u64>("block_number"), Some(42));
+        assert_eq!(public_values.input_digest(), Some([9u8; 32]));
+
+        // Keys out of order are not canonical.
+        let public_values = SP1PublicValues::from(&encode(&[
+            ("state_root", state_root),
+            ("block_number", block_number),
+        ]));
+        assert!(public_values.named().is_empty());
+    }
+
+    #[test]
+    fn test_streams() {
+        let mut stdin = SP1Stdin::new();
+        stdin.write(&1u32);
+        stdin.write_to(0, &2u32);
+        stdin.write_to(2, &3u64);
+        stdin.write_slice_to(2, &[4, 5]);
+        assert_eq!(stdin.buffer.len(), 2);
+        assert_eq!(stdin.streams[&2], vec![3u64.to_le_bytes().to_vec(), vec![4, 5]]);
+        assert_eq!(stdin.input_size(), 4 + 4 + 8 + 2);
+        assert_eq!(stdin.buffer_size(), 4 + 4);
+
+        // The program writes each entry of a stream prefixed by its length.
+        let mut stream = Vec::new();
+        for entry in [bincode::serialize(&6u32).unwrap(), bincode::serialize(&7u32).unwrap()] {
+            stream.extend_from_slice(&(entry.len() as u32).to_le_bytes());
+            stream.extend_from_slice(&entry);
+        }
+        let key = format!("{OUTPUT_STREAM_KEY_PREFIX}1");
+        let mut section = Vec::new();
+        section.extend_from_slice(&(key.len() as u32).to_le_bytes());
+        section.extend_from_slice(key.as_bytes());
+        section.extend_from_slice(&(stream.len() as u32).to_le_bytes());
+        section.extend_from_slice(&stream);
+        let len = section.len() as u32;
+        section.extend_from_slice(&1u32.to_le_bytes());
+        section.extend_from_slice(&len.to_le_bytes());
+        section.extend_from_slice(&NAMED_PUBLIC_VALUES_MAGIC);
+
+        let public_values = SP1PublicValues::from(&section);
+        assert_eq!(public_values.read_stream::<u32>(1), vec![6, 7]);
+        assert!(public_values.stream(2).is_empty());
+    }
+
     #[test]
     fn test_hash_public_values() {
         let test_hex = "1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef";
@@ -202,4 +700,32 @@ mod tests {
 
         assert_eq!(hash, expected_hash_biguint);
     }
+
+    #[test]
+    fn test_hash_public_values_with() {
+        let mut public_values = SP1PublicValues::new();
+        public_values.write_slice(b"abc");
+
+        assert_eq!(
+            public_values.hash_with(PublicValuesHash::Sha256).to_vec(),
+            public_values.hash()
+        );
+        assert_eq!(
+            hex::encode(public_values.hash_with(PublicValuesHash::Keccak256)),
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"
+        );
+
+        // Poseidon2 digests are canonical BabyBear elements, and the length is absorbed so that
+        // trailing zero bytes change the digest.
+        let digest = public_values.hash_with(PublicValuesHash::Poseidon2);
+        assert!(digest
+            .chunks_exact(4)
+            .all(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()) < BabyBear::ORDER_U32));
+        let mut padded = public_values.clone();
+        padded.write_slice(&[0]);
+        assert_ne!(padded.hash_with(PublicValuesHash::Poseidon2), digest);
+
+        let masked = public_values.hash_bn254_with(PublicValuesHash::Keccak256);
+        assert!(masked.bits() <= 253);
+    }
 }
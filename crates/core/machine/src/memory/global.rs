@@ -11,7 +11,7 @@ use sp1_core_executor::{events::MemoryInitializeFinalizeEvent, ExecutionRecord,
 use sp1_derive::AlignedBorrow;
 use sp1_stark::{
     air::{
-        AirInteraction, BaseAirBuilder, MachineAir, PublicValues, SP1AirBuilder,
+        recompose_bits, AirInteraction, BaseAirBuilder, MachineAir, PublicValues, SP1AirBuilder,
         SP1_PROOF_NUM_PV_ELTS,
     },
     InteractionKind, Word,
@@ -196,21 +196,10 @@ where
         let next: &MemoryInitCols<AB::Var> = (*next).borrow();
 
         builder.assert_bool(local.is_real);
-        for i in 0..32 {
-            builder.assert_bool(local.value[i]);
-        }
-
-        let mut byte1 = AB::Expr::zero();
-        let mut byte2 = AB::Expr::zero();
-        let mut byte3 = AB::Expr::zero();
-        let mut byte4 = AB::Expr::zero();
-        for i in 0..8 {
-            byte1 += local.value[i].into() * AB::F::from_canonical_u8(1 << i);
-            byte2 += local.value[i + 8].into() * AB::F::from_canonical_u8(1 << i);
-            byte3 += local.value[i + 16].into() * AB::F::from_canonical_u8(1 << i);
-            byte4 += local.value[i + 24].into() * AB::F::from_canonical_u8(1 << i);
-        }
-        let value = [byte1, byte2, byte3, byte4];
+        builder.assert_bits(local.value);
+        let value: [AB::Expr; 4] = array::from_fn(|i| {
+            recompose_bits::<AB, _>(local.value[8 * i..8 * i + 8].iter().copied())
+        });
 
         if self.kind == MemoryChipType::Initialize {
             let mut values = vec![AB::Expr::zero(), AB::Expr::zero(), local.addr.into()];
@@ -280,11 +269,7 @@ where
         // Since the previous address is either zero or constrained by a different shard, we know
         // it's an element of the field, so we can get an element from the bit decomposition with
         // no concern for overflow.
-        let prev_addr = prev_addr_bits
-            .iter()
-            .enumerate()
-            .map(|(i, bit)| bit.clone() * AB::F::from_wrapped_u32(1 << i))
-            .sum::<AB::Expr>();
+        let prev_addr = recompose_bits::<AB, _>(prev_addr_bits.iter().cloned());
 
         // Constrain the is_prev_addr_zero operation only in the first row.
         let is_first_row = builder.is_first_row();
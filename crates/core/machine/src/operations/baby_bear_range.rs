@@ -3,7 +3,7 @@ use std::array;
 use p3_air::AirBuilder;
 use p3_field::{AbstractField, Field};
 use sp1_derive::AlignedBorrow;
-use sp1_stark::air::SP1AirBuilder;
+use sp1_stark::air::{recompose_bits, SP1AirBuilder};
 
 #[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
 #[repr(C)]
@@ -38,14 +38,9 @@ impl<F: Field> BabyBearBitDecomposition<F> {
         cols: BabyBearBitDecomposition<AB::Var>,
         is_real: AB::Expr,
     ) {
-        let mut reconstructed_value = AB::Expr::zero();
-        for (i, bit) in cols.bits.iter().enumerate() {
-            builder.when(is_real.clone()).assert_bool(*bit);
-            reconstructed_value += AB::Expr::from_wrapped_u32(1 << i) * *bit;
-        }
-
         // Assert that bits2num(bits) == value.
-        builder.when(is_real.clone()).assert_eq(reconstructed_value, value);
+        builder.when(is_real.clone()).assert_bits(cols.bits);
+        builder.when(is_real.clone()).assert_eq(recompose_bits::<AB, _>(cols.bits), value);
 
         // Range check that value is less than baby bear modulus.  To do this, it is sufficient
         // to just do comparisons for the most significant byte. BabyBear's modulus is (in big
@@ -1,7 +1,7 @@
 use std::array;
 
 use p3_air::AirBuilder;
-use p3_field::{AbstractField, Field};
+use p3_field::Field;
 use sp1_derive::AlignedBorrow;
 use sp1_stark::{air::SP1AirBuilder, Word};
 
@@ -39,14 +39,9 @@ impl<F: Field> BabyBearWordRangeChecker<F> {
         cols: BabyBearWordRangeChecker<AB::Var>,
         is_real: AB::Expr,
     ) {
-        let mut recomposed_byte = AB::Expr::zero();
-        cols.most_sig_byte_decomp.iter().enumerate().for_each(|(i, value)| {
-            builder.when(is_real.clone()).assert_bool(*value);
-            recomposed_byte =
-                recomposed_byte.clone() + AB::Expr::from_canonical_usize(1 << i) * *value;
-        });
-
-        builder.when(is_real.clone()).assert_eq(recomposed_byte, value[3]);
+        builder
+            .when(is_real.clone())
+            .assert_byte_decomposition(value[3], &cols.most_sig_byte_decomp);
 
         // Range check that value is less than baby bear modulus.  To do this, it is sufficient
         // to just do comparisons for the most significant byte. BabyBear's modulus is (in big
@@ -142,6 +142,11 @@ impl<F: PrimeField> MachineAir<F> for ProgramChip {
         trace
     }
 
+    fn single_pass(&self) -> bool {
+        // The multiplicities only depend on the cpu events.
+        true
+    }
+
     fn included(&self, _: &Self::Record) -> bool {
         true
     }
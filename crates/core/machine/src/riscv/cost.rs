@@ -1,13 +1,19 @@
 use p3_baby_bear::BabyBear;
 use sp1_core_executor::{syscalls::SyscallCode, ExecutionReport, Opcode};
+use sp1_curves::weierstrass::registered::REGISTERED_CURVES;
 
 use crate::riscv::RiscvAirDiscriminants;
 
 use super::RiscvAir;
 
 pub trait CostEstimator {
+    /// Estimates the trace area of each chip of the [RiscvAir] for the execution.
+    fn estimate_chip_areas(&self) -> Vec<(RiscvAirDiscriminants, u64)>;
+
     /// Estimates the trace area of the execution.
-    fn estimate_area(&self) -> u64;
+    fn estimate_area(&self) -> u64 {
+        self.estimate_chip_areas().into_iter().map(|(_, area)| area).sum()
+    }
 
     /// Estimates the proving cost of the execution in terms of "gas".
     ///
@@ -21,157 +27,360 @@ pub trait CostEstimator {
         let total_gas = self.estimate_area();
         total_gas / cpu_gas
     }
+
+    /// Estimates the gas of the calls to each syscall made during the execution, sorted from the
+    /// most to the least expensive.
+    ///
+    /// The gas of a call is its `ecall` cycle plus the area of the precompile chip it adds a row
+    /// to, divided by the area of a cpu cycle, so that it reads as the number of cycles the call
+    /// costs.
+    fn estimate_syscall_gas(&self) -> Vec<(SyscallCode, u64)>;
+}
+
+/// The chip of the [RiscvAir] proving the calls to a syscall, if it is a precompile.
+#[must_use]
+pub fn syscall_chip(code: SyscallCode) -> Option<RiscvAirDiscriminants> {
+    let chip = match code {
+        SyscallCode::SHA_EXTEND => RiscvAirDiscriminants::Sha256Extend,
+        SyscallCode::SHA_COMPRESS => RiscvAirDiscriminants::Sha256Compress,
+        SyscallCode::ED_ADD => RiscvAirDiscriminants::Ed25519Add,
+        SyscallCode::ED_DECOMPRESS => RiscvAirDiscriminants::Ed25519Decompress,
+        SyscallCode::SECP256K1_DECOMPRESS => RiscvAirDiscriminants::K256Decompress,
+        SyscallCode::SECP256K1_ADD => RiscvAirDiscriminants::Secp256k1Add,
+        SyscallCode::SECP256K1_DOUBLE => RiscvAirDiscriminants::Secp256k1Double,
+        SyscallCode::KECCAK_PERMUTE => RiscvAirDiscriminants::KeccakP,
+        SyscallCode::BN254_ADD => RiscvAirDiscriminants::Bn254Add,
+        SyscallCode::BN254_DOUBLE => RiscvAirDiscriminants::Bn254Double,
+        SyscallCode::BLS12381_ADD => RiscvAirDiscriminants::Bls12381Add,
+        SyscallCode::BLS12381_DOUBLE => RiscvAirDiscriminants::Bls12381Double,
+        SyscallCode::BLS12381_G2_ADD => RiscvAirDiscriminants::Bls12381G2Add,
+        SyscallCode::BLS12381_G2_DOUBLE => RiscvAirDiscriminants::Bls12381G2Double,
+        SyscallCode::UINT256_MUL => RiscvAirDiscriminants::Uint256Mul,
+        SyscallCode::POSEIDON2_MERKLE_VERIFY => RiscvAirDiscriminants::Poseidon2MerkleVerify,
+        SyscallCode::CLMUL => RiscvAirDiscriminants::Clmul,
+        SyscallCode::X25519_SCALAR_MULT => RiscvAirDiscriminants::X25519,
+        SyscallCode::BABYJUBJUB_ADD => RiscvAirDiscriminants::BabyJubjubAdd,
+        SyscallCode::AES_ENCRYPT_BLOCK => RiscvAirDiscriminants::AesEncryptBlock,
+        SyscallCode::RANGE_CHECK => RiscvAirDiscriminants::RangeCheck,
+        SyscallCode::REGISTERED_CURVE_ADD => RiscvAirDiscriminants::WeierstrassRegisteredAdd,
+        SyscallCode::REGISTERED_CURVE_DOUBLE => RiscvAirDiscriminants::WeierstrassRegisteredDouble,
+        SyscallCode::U64_ADD
+        | SyscallCode::U64_SUB
+        | SyscallCode::U64_MUL
+        | SyscallCode::U64_LTU => RiscvAirDiscriminants::WideAlu,
+        SyscallCode::FIXED_POINT_RECIP
+        | SyscallCode::FIXED_POINT_SQRT
+        | SyscallCode::FIXED_POINT_SIN
+        | SyscallCode::FIXED_POINT_COS => RiscvAirDiscriminants::FixedPoint,
+        SyscallCode::BLS12381_FP_ADD
+        | SyscallCode::BLS12381_FP_SUB
+        | SyscallCode::BLS12381_FP_MUL => RiscvAirDiscriminants::Bls12381Fp,
+        SyscallCode::BLS12381_FP2_ADD | SyscallCode::BLS12381_FP2_SUB => {
+            RiscvAirDiscriminants::Bls12381Fp2AddSub
+        }
+        SyscallCode::BLS12381_FP2_MUL => RiscvAirDiscriminants::Bls12381Fp2Mul,
+        SyscallCode::BN254_FP_ADD | SyscallCode::BN254_FP_SUB | SyscallCode::BN254_FP_MUL => {
+            RiscvAirDiscriminants::Bn254Fp
+        }
+        SyscallCode::BN254_FP2_ADD | SyscallCode::BN254_FP2_SUB => {
+            RiscvAirDiscriminants::Bn254Fp2AddSub
+        }
+        SyscallCode::BN254_FP2_MUL => RiscvAirDiscriminants::Bn254Fp2Mul,
+        SyscallCode::BLS12381_DECOMPRESS => RiscvAirDiscriminants::Bls12381Decompress,
+        SyscallCode::SECP256K1_MAP_TO_CURVE => RiscvAirDiscriminants::Secp256k1MapToCurve,
+        SyscallCode::BLS12381_MAP_TO_CURVE => RiscvAirDiscriminants::Bls12381MapToCurve,
+        SyscallCode::HEX_ENCODE | SyscallCode::HEX_DECODE => RiscvAirDiscriminants::Hex,
+        SyscallCode::BASE64_ENCODE
+        | SyscallCode::BASE64_URL_ENCODE
+        | SyscallCode::BASE64_DECODE
+        | SyscallCode::BASE64_URL_DECODE => RiscvAirDiscriminants::Base64,
+        _ => return None,
+    };
+    Some(chip)
 }
 
 impl CostEstimator for ExecutionReport {
-    fn estimate_area(&self) -> u64 {
-        let mut total_area = 0;
+    fn estimate_chip_areas(&self) -> Vec<(RiscvAirDiscriminants, u64)> {
+        let mut areas = Vec::new();
         let mut total_chips = 3;
         let (chips, costs) = RiscvAir::<BabyBear>::get_chips_and_costs();
+        let mut add_area =
+            |chip: RiscvAirDiscriminants, events: u64| areas.push((chip, events * costs[&chip]));
 
         let cpu_events = self.total_instruction_count();
-        total_area += (cpu_events as u64) * costs[&RiscvAirDiscriminants::Cpu];
+        add_area(RiscvAirDiscriminants::Cpu, cpu_events);
+        total_chips += 1;
+
+        let memory_instruction_events = self.opcode_counts[Opcode::LB]
+            + self.opcode_counts[Opcode::LBU]
+            + self.opcode_counts[Opcode::LH]
+            + self.opcode_counts[Opcode::LHU]
+            + self.opcode_counts[Opcode::LW]
+            + self.opcode_counts[Opcode::SB]
+            + self.opcode_counts[Opcode::SH]
+            + self.opcode_counts[Opcode::SW];
+        add_area(RiscvAirDiscriminants::MemoryInstructions, memory_instruction_events);
         total_chips += 1;
 
         let sha_extend_events = self.syscall_counts[SyscallCode::SHA_EXTEND];
-        total_area += (sha_extend_events as u64) * costs[&RiscvAirDiscriminants::Sha256Extend];
+        add_area(RiscvAirDiscriminants::Sha256Extend, sha_extend_events);
         total_chips += 1;
 
         let sha_compress_events = self.syscall_counts[SyscallCode::SHA_COMPRESS];
-        total_area += (sha_compress_events as u64) * costs[&RiscvAirDiscriminants::Sha256Compress];
+        add_area(RiscvAirDiscriminants::Sha256Compress, sha_compress_events);
         total_chips += 1;
 
         let ed_add_events = self.syscall_counts[SyscallCode::ED_ADD];
-        total_area += (ed_add_events as u64) * costs[&RiscvAirDiscriminants::Ed25519Add];
+        add_area(RiscvAirDiscriminants::Ed25519Add, ed_add_events);
         total_chips += 1;
 
         let ed_decompress_events = self.syscall_counts[SyscallCode::ED_DECOMPRESS];
-        total_area +=
-            (ed_decompress_events as u64) * costs[&RiscvAirDiscriminants::Ed25519Decompress];
+        add_area(RiscvAirDiscriminants::Ed25519Decompress, ed_decompress_events);
         total_chips += 1;
 
         let k256_decompress_events = self.syscall_counts[SyscallCode::SECP256K1_DECOMPRESS];
-        total_area +=
-            (k256_decompress_events as u64) * costs[&RiscvAirDiscriminants::K256Decompress];
+        add_area(RiscvAirDiscriminants::K256Decompress, k256_decompress_events);
         total_chips += 1;
 
         let secp256k1_add_events = self.syscall_counts[SyscallCode::SECP256K1_ADD];
-        total_area += (secp256k1_add_events as u64) * costs[&RiscvAirDiscriminants::Secp256k1Add];
+        add_area(RiscvAirDiscriminants::Secp256k1Add, secp256k1_add_events);
         total_chips += 1;
 
         let secp256k1_double_events = self.syscall_counts[SyscallCode::SECP256K1_DOUBLE];
-        total_area +=
-            (secp256k1_double_events as u64) * costs[&RiscvAirDiscriminants::Secp256k1Double];
+        add_area(RiscvAirDiscriminants::Secp256k1Double, secp256k1_double_events);
         total_chips += 1;
 
         let keccak256_permute_events = self.syscall_counts[SyscallCode::KECCAK_PERMUTE];
-        total_area += (keccak256_permute_events as u64) * costs[&RiscvAirDiscriminants::KeccakP];
+        add_area(RiscvAirDiscriminants::KeccakP, keccak256_permute_events);
         total_chips += 1;
 
         let bn254_add_events = self.syscall_counts[SyscallCode::BN254_ADD];
-        total_area += (bn254_add_events as u64) * costs[&RiscvAirDiscriminants::Bn254Add];
+        add_area(RiscvAirDiscriminants::Bn254Add, bn254_add_events);
         total_chips += 1;
 
         let bn254_double_events = self.syscall_counts[SyscallCode::BN254_DOUBLE];
-        total_area += (bn254_double_events as u64) * costs[&RiscvAirDiscriminants::Bn254Double];
+        add_area(RiscvAirDiscriminants::Bn254Double, bn254_double_events);
         total_chips += 1;
 
         let bls12381_add_events = self.syscall_counts[SyscallCode::BLS12381_ADD];
-        total_area += (bls12381_add_events as u64) * costs[&RiscvAirDiscriminants::Bls12381Add];
+        add_area(RiscvAirDiscriminants::Bls12381Add, bls12381_add_events);
         total_chips += 1;
 
         let bls12381_double_events = self.syscall_counts[SyscallCode::BLS12381_DOUBLE];
-        total_area +=
-            (bls12381_double_events as u64) * costs[&RiscvAirDiscriminants::Bls12381Double];
+        add_area(RiscvAirDiscriminants::Bls12381Double, bls12381_double_events);
+        total_chips += 1;
+
+        let bls12381_g2_add_events = self.syscall_counts[SyscallCode::BLS12381_G2_ADD];
+        add_area(RiscvAirDiscriminants::Bls12381G2Add, bls12381_g2_add_events);
+        total_chips += 1;
+
+        let bls12381_g2_double_events = self.syscall_counts[SyscallCode::BLS12381_G2_DOUBLE];
+        add_area(RiscvAirDiscriminants::Bls12381G2Double, bls12381_g2_double_events);
         total_chips += 1;
 
         let uint256_mul_events = self.syscall_counts[SyscallCode::UINT256_MUL];
-        total_area += (uint256_mul_events as u64) * costs[&RiscvAirDiscriminants::Uint256Mul];
+        add_area(RiscvAirDiscriminants::Uint256Mul, uint256_mul_events);
+        total_chips += 1;
+
+        let poseidon2_merkle_events = self.syscall_counts[SyscallCode::POSEIDON2_MERKLE_VERIFY];
+        add_area(RiscvAirDiscriminants::Poseidon2MerkleVerify, poseidon2_merkle_events);
+        total_chips += 1;
+
+        let clmul_events = self.syscall_counts[SyscallCode::CLMUL];
+        add_area(RiscvAirDiscriminants::Clmul, clmul_events);
+        total_chips += 1;
+
+        let x25519_events = self.syscall_counts[SyscallCode::X25519_SCALAR_MULT];
+        add_area(RiscvAirDiscriminants::X25519, x25519_events);
+        total_chips += 1;
+
+        let babyjubjub_add_events = self.syscall_counts[SyscallCode::BABYJUBJUB_ADD];
+        add_area(RiscvAirDiscriminants::BabyJubjubAdd, babyjubjub_add_events);
+        total_chips += 1;
+
+        let aes_encrypt_block_events = self.syscall_counts[SyscallCode::AES_ENCRYPT_BLOCK];
+        add_area(RiscvAirDiscriminants::AesEncryptBlock, aes_encrypt_block_events);
+        total_chips += 1;
+
+        let range_check_events = self.syscall_counts[SyscallCode::RANGE_CHECK];
+        add_area(RiscvAirDiscriminants::RangeCheck, range_check_events);
+        total_chips += 1;
+
+        let registered_curve_add_events = self.syscall_counts[SyscallCode::REGISTERED_CURVE_ADD];
+        add_area(RiscvAirDiscriminants::WeierstrassRegisteredAdd, registered_curve_add_events);
+        total_chips += REGISTERED_CURVES.len();
+
+        let registered_curve_double_events =
+            self.syscall_counts[SyscallCode::REGISTERED_CURVE_DOUBLE];
+        add_area(
+            RiscvAirDiscriminants::WeierstrassRegisteredDouble,
+            registered_curve_double_events,
+        );
+        total_chips += REGISTERED_CURVES.len();
+
+        let wide_alu_events = self.syscall_counts[SyscallCode::U64_ADD]
+            + self.syscall_counts[SyscallCode::U64_SUB]
+            + self.syscall_counts[SyscallCode::U64_MUL]
+            + self.syscall_counts[SyscallCode::U64_LTU];
+        add_area(RiscvAirDiscriminants::WideAlu, wide_alu_events);
+        total_chips += 1;
+
+        let fixed_point_events = self.syscall_counts[SyscallCode::FIXED_POINT_RECIP]
+            + self.syscall_counts[SyscallCode::FIXED_POINT_SQRT]
+            + self.syscall_counts[SyscallCode::FIXED_POINT_SIN]
+            + self.syscall_counts[SyscallCode::FIXED_POINT_COS];
+        add_area(RiscvAirDiscriminants::FixedPoint, fixed_point_events);
         total_chips += 1;
 
         let bls12381_fp_events = self.syscall_counts[SyscallCode::BLS12381_FP_ADD]
             + self.syscall_counts[SyscallCode::BLS12381_FP_SUB]
             + self.syscall_counts[SyscallCode::BLS12381_FP_MUL];
-        total_area += (bls12381_fp_events as u64) * costs[&RiscvAirDiscriminants::Bls12381Fp];
+        add_area(RiscvAirDiscriminants::Bls12381Fp, bls12381_fp_events);
         total_chips += 1;
 
         let bls12381_fp2_addsub_events = self.syscall_counts[SyscallCode::BLS12381_FP2_ADD]
             + self.syscall_counts[SyscallCode::BLS12381_FP2_SUB];
-        total_area +=
-            (bls12381_fp2_addsub_events as u64) * costs[&RiscvAirDiscriminants::Bls12381Fp2AddSub];
+        add_area(RiscvAirDiscriminants::Bls12381Fp2AddSub, bls12381_fp2_addsub_events);
         total_chips += 1;
 
         let bls12381_fp2_mul_events = self.syscall_counts[SyscallCode::BLS12381_FP2_MUL];
-        total_area +=
-            (bls12381_fp2_mul_events as u64) * costs[&RiscvAirDiscriminants::Bls12381Fp2Mul];
+        add_area(RiscvAirDiscriminants::Bls12381Fp2Mul, bls12381_fp2_mul_events);
         total_chips += 1;
 
         let bn254_fp_events = self.syscall_counts[SyscallCode::BN254_FP_ADD]
             + self.syscall_counts[SyscallCode::BN254_FP_SUB]
             + self.syscall_counts[SyscallCode::BN254_FP_MUL];
-        total_area += (bn254_fp_events as u64) * costs[&RiscvAirDiscriminants::Bn254Fp];
+        add_area(RiscvAirDiscriminants::Bn254Fp, bn254_fp_events);
         total_chips += 1;
 
         let bn254_fp2_addsub_events = self.syscall_counts[SyscallCode::BN254_FP2_ADD]
             + self.syscall_counts[SyscallCode::BN254_FP2_SUB];
-        total_area +=
-            (bn254_fp2_addsub_events as u64) * costs[&RiscvAirDiscriminants::Bn254Fp2AddSub];
+        add_area(RiscvAirDiscriminants::Bn254Fp2AddSub, bn254_fp2_addsub_events);
         total_chips += 1;
 
         let bn254_fp2_mul_events = self.syscall_counts[SyscallCode::BN254_FP2_MUL];
-        total_area += (bn254_fp2_mul_events as u64) * costs[&RiscvAirDiscriminants::Bn254Fp2Mul];
+        add_area(RiscvAirDiscriminants::Bn254Fp2Mul, bn254_fp2_mul_events);
         total_chips += 1;
 
         let bls12381_decompress_events = self.syscall_counts[SyscallCode::BLS12381_DECOMPRESS];
-        total_area +=
-            (bls12381_decompress_events as u64) * costs[&RiscvAirDiscriminants::Bls12381Decompress];
+        add_area(RiscvAirDiscriminants::Bls12381Decompress, bls12381_decompress_events);
+        total_chips += 1;
+
+        let secp256k1_map_events = self.syscall_counts[SyscallCode::SECP256K1_MAP_TO_CURVE];
+        add_area(RiscvAirDiscriminants::Secp256k1MapToCurve, secp256k1_map_events);
+        total_chips += 1;
+
+        let bls12381_map_events = self.syscall_counts[SyscallCode::BLS12381_MAP_TO_CURVE];
+        add_area(RiscvAirDiscriminants::Bls12381MapToCurve, bls12381_map_events);
+        total_chips += 1;
+
+        let hex_events = self.syscall_counts[SyscallCode::HEX_ENCODE]
+            + self.syscall_counts[SyscallCode::HEX_DECODE];
+        add_area(RiscvAirDiscriminants::Hex, hex_events);
+        total_chips += 1;
+
+        let base64_events = self.syscall_counts[SyscallCode::BASE64_ENCODE]
+            + self.syscall_counts[SyscallCode::BASE64_URL_ENCODE]
+            + self.syscall_counts[SyscallCode::BASE64_DECODE]
+            + self.syscall_counts[SyscallCode::BASE64_URL_DECODE];
+        add_area(RiscvAirDiscriminants::Base64, base64_events);
         total_chips += 1;
 
         let divrem_events = self.opcode_counts[Opcode::DIV]
             + self.opcode_counts[Opcode::REM]
             + self.opcode_counts[Opcode::DIVU]
             + self.opcode_counts[Opcode::REMU];
-        total_area += (divrem_events as u64) * costs[&RiscvAirDiscriminants::DivRem];
+        add_area(RiscvAirDiscriminants::DivRem, divrem_events);
         total_chips += 1;
 
         let addsub_events = self.opcode_counts[Opcode::ADD] + self.opcode_counts[Opcode::SUB];
-        total_area += (addsub_events as u64) * costs[&RiscvAirDiscriminants::Add];
+        add_area(RiscvAirDiscriminants::Add, addsub_events);
         total_chips += 1;
 
         let bitwise_events = self.opcode_counts[Opcode::AND]
             + self.opcode_counts[Opcode::OR]
             + self.opcode_counts[Opcode::XOR];
-        total_area += (bitwise_events as u64) * costs[&RiscvAirDiscriminants::Bitwise];
+        add_area(RiscvAirDiscriminants::Bitwise, bitwise_events);
         total_chips += 1;
 
         let mul_events = self.opcode_counts[Opcode::MUL]
             + self.opcode_counts[Opcode::MULH]
             + self.opcode_counts[Opcode::MULHU]
             + self.opcode_counts[Opcode::MULHSU];
-        total_area += (mul_events as u64) * costs[&RiscvAirDiscriminants::Mul];
+        add_area(RiscvAirDiscriminants::Mul, mul_events);
         total_chips += 1;
 
         let shift_right_events = self.opcode_counts[Opcode::SRL] + self.opcode_counts[Opcode::SRA];
-        total_area += (shift_right_events as u64) * costs[&RiscvAirDiscriminants::ShiftRight];
+        add_area(RiscvAirDiscriminants::ShiftRight, shift_right_events);
         total_chips += 1;
 
         let shift_left_events = self.opcode_counts[Opcode::SLL];
-        total_area += (shift_left_events as u64) * costs[&RiscvAirDiscriminants::ShiftLeft];
+        add_area(RiscvAirDiscriminants::ShiftLeft, shift_left_events);
         total_chips += 1;
 
         let lt_events = self.opcode_counts[Opcode::SLT] + self.opcode_counts[Opcode::SLTU];
-        total_area += (lt_events as u64) * costs[&RiscvAirDiscriminants::Lt];
+        add_area(RiscvAirDiscriminants::Lt, lt_events);
         total_chips += 1;
 
         let memory_initialize_events = self.touched_memory_addresses;
-        total_area += (memory_initialize_events as u64) * costs[&RiscvAirDiscriminants::MemoryInit];
+        add_area(RiscvAirDiscriminants::MemoryInit, memory_initialize_events);
         total_chips += 1;
 
         let memory_finalize_events = self.touched_memory_addresses;
-        total_area += (memory_finalize_events as u64) * costs[&RiscvAirDiscriminants::MemoryFinal];
+        add_area(RiscvAirDiscriminants::MemoryFinal, memory_finalize_events);
         total_chips += 1;
 
         assert_eq!(total_chips, chips.len(), "chip count mismatch");
-        total_area
+        areas
+    }
+
+    fn estimate_syscall_gas(&self) -> Vec<(SyscallCode, u64)> {
+        let costs = RiscvAir::<BabyBear>::costs();
+        let cpu_gas = costs[&RiscvAirDiscriminants::Cpu];
+        let mut gas = self
+            .syscall_counts
+            .iter()
+            .filter(|&(_, &count)| count > 0)
+            .map(|(code, &count)| {
+                let chip_gas = syscall_chip(code).map_or(0, |chip| costs[&chip]);
+                (code, count * (cpu_gas + chip_gas) / cpu_gas)
+            })
+            .collect::<Vec<_>>();
+        gas.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        gas
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn test_syscall_chip_matches_chip_areas() {
+        for code in SyscallCode::iter() {
+            let mut report = ExecutionReport::default();
+            report.syscall_counts[code] = 1;
+            let chips = report
+                .estimate_chip_areas()
+                .into_iter()
+                .filter(|&(_, area)| area > 0)
+                .map(|(chip, _)| chip)
+                .collect::<Vec<_>>();
+            assert_eq!(chips, syscall_chip(code).into_iter().collect::<Vec<_>>(), "{code:?}");
+        }
+    }
+
+    #[test]
+    fn test_syscall_gas() {
+        let mut report = ExecutionReport::default();
+        report.syscall_counts[SyscallCode::WRITE] = 3;
+        report.syscall_counts[SyscallCode::KECCAK_PERMUTE] = 2;
+        let gas = report.estimate_syscall_gas();
+        assert_eq!(gas.len(), 2);
+        assert_eq!(gas[0].0, SyscallCode::KECCAK_PERMUTE);
+        assert!(gas[0].1 > 2);
+        assert_eq!(gas[1], (SyscallCode::WRITE, 3));
     }
 }
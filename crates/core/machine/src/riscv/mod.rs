@@ -20,24 +20,35 @@ pub(crate) mod riscv_chips {
     pub use crate::{
         alu::{AddSubChip, BitwiseChip, DivRemChip, LtChip, MulChip, ShiftLeft, ShiftRightChip},
         bytes::ByteChip,
-        cpu::CpuChip,
+        cpu::{memory_instructions::MemoryInstructionsChip, CpuChip},
         memory::MemoryChip,
         program::ProgramChip,
         syscall::precompiles::{
+            aes::AesEncryptBlockChip,
+            bls12381_g2::{Bls12381G2AddChip, Bls12381G2DoubleChip},
+            clmul::ClmulChip,
+            codec::{Base64Chip, HexChip},
             edwards::{EdAddAssignChip, EdDecompressChip},
+            fixed_point::{FixedPointChip, FixedPointTableChip},
             keccak256::KeccakPermuteChip,
+            poseidon2::Poseidon2MerkleVerifyChip,
+            range_check::RangeCheckChip,
             sha256::{ShaCompressChip, ShaExtendChip},
             uint256::Uint256MulChip,
             weierstrass::{
                 WeierstrassAddAssignChip, WeierstrassDecompressChip, WeierstrassDoubleAssignChip,
+                WeierstrassMapToCurveChip, WeierstrassRegisteredAddChip,
+                WeierstrassRegisteredDoubleChip,
             },
+            wide_alu::WideAluChip,
+            x25519::X25519Chip,
         },
     };
     pub use sp1_curves::{
-        edwards::{ed25519::Ed25519Parameters, EdwardsCurve},
+        edwards::{babyjubjub::BabyJubjubParameters, ed25519::Ed25519Parameters, EdwardsCurve},
         weierstrass::{
-            bls12_381::Bls12381Parameters, bn254::Bn254Parameters, secp256k1::Secp256k1Parameters,
-            SwCurve,
+            bls12_381::Bls12381Parameters, bn254::Bn254Parameters, registered::REGISTERED_CURVES,
+            secp256k1::Secp256k1Parameters, SwCurve,
         },
     };
 }
@@ -54,6 +65,8 @@ pub enum RiscvAir<F: PrimeField32> {
     Program(ProgramChip),
     /// An AIR for the RISC-V CPU. Each row represents a cpu cycle.
     Cpu(CpuChip),
+    /// An AIR for the load and store instructions, sent by the CPU.
+    MemoryInstructions(MemoryInstructionsChip),
     /// An AIR for the RISC-V Add and SUB instruction.
     Add(AddSubChip),
     /// An AIR for RISC-V Bitwise instructions.
@@ -100,10 +113,44 @@ pub enum RiscvAir<F: PrimeField32> {
     Bls12381Add(WeierstrassAddAssignChip<SwCurve<Bls12381Parameters>>),
     /// A precompile for doubling a point on the Elliptic curve bls12_381.
     Bls12381Double(WeierstrassDoubleAssignChip<SwCurve<Bls12381Parameters>>),
+    /// A precompile for addition on the G2 subgroup of the curve bls12_381.
+    Bls12381G2Add(Bls12381G2AddChip),
+    /// A precompile for doubling a point on the G2 subgroup of the curve bls12_381.
+    Bls12381G2Double(Bls12381G2DoubleChip),
     /// A precompile for uint256 mul.
     Uint256Mul(Uint256MulChip),
+    /// A precompile for verifying a Merkle path with Poseidon2.
+    Poseidon2MerkleVerify(Poseidon2MerkleVerifyChip),
+    /// A precompile for 128-bit carry-less multiplication.
+    Clmul(ClmulChip),
+    /// A precompile for X25519 scalar multiplication.
+    X25519(X25519Chip),
+    /// A precompile for addition on the Baby Jubjub curve.
+    BabyJubjubAdd(EdAddAssignChip<EdwardsCurve<BabyJubjubParameters>>),
+    /// A precompile for AES-128 and AES-256 block encryption.
+    AesEncryptBlock(AesEncryptBlockChip),
+    /// A precompile for checking that a word fits in a number of bits.
+    RangeCheck(RangeCheckChip),
+    /// A precompile for addition on a registered Weierstrass curve, one instance per curve.
+    WeierstrassRegisteredAdd(WeierstrassRegisteredAddChip),
+    /// A precompile for doubling on a registered Weierstrass curve, one instance per curve.
+    WeierstrassRegisteredDouble(WeierstrassRegisteredDoubleChip),
+    /// A precompile for 64-bit integer arithmetic.
+    WideAlu(WideAluChip),
+    /// A precompile for fixed-point reciprocal, square root, sine and cosine.
+    FixedPoint(FixedPointChip),
+    /// A preprocessed table of the fixed-point functions, looked up by the fixed-point precompile.
+    FixedPointTable(FixedPointTableChip),
     /// A precompile for decompressing a point on the BLS12-381 curve.
     Bls12381Decompress(WeierstrassDecompressChip<SwCurve<Bls12381Parameters>>),
+    /// A precompile for mapping a field element to the curve isogenous to secp256k1.
+    Secp256k1MapToCurve(WeierstrassMapToCurveChip<SwCurve<Secp256k1Parameters>>),
+    /// A precompile for mapping a field element to the curve isogenous to bls12_381.
+    Bls12381MapToCurve(WeierstrassMapToCurveChip<SwCurve<Bls12381Parameters>>),
+    /// A precompile for hex encoding and decoding.
+    Hex(HexChip),
+    /// A precompile for base64 encoding and decoding, with the standard or URL-safe alphabet.
+    Base64(Base64Chip),
     /// A precompile for BLS12-381 fp operation.
     Bls12381Fp(FpOpChip<Bls12381BaseField>),
     /// A precompile for BLS12-381 fp2 multiplication.
@@ -125,6 +172,20 @@ impl<F: PrimeField32> RiscvAir<F> {
         StarkMachine::new(config, chips, SP1_PROOF_NUM_PV_ELTS)
     }
 
+    /// Creates a machine with all the chips and the preprocessed table of the fixed-point
+    /// precompile.
+    ///
+    /// The table is left out of the default machine since, like other preprocessed tables, it is
+    /// included in every shard. Executions calling the fixed-point precompile need
+    /// `SP1CoreOpts::fixed_point_tables`, and must be proven and verified with this machine.
+    pub fn machine_with_fixed_point_tables<SC: StarkGenericConfig<Val = F>>(
+        config: SC,
+    ) -> StarkMachine<SC, Self> {
+        let mut chips = Self::chips();
+        chips.push(Chip::new(RiscvAir::FixedPointTable(FixedPointTableChip::new())));
+        StarkMachine::new(config, chips, SP1_PROOF_NUM_PV_ELTS)
+    }
+
     /// Get all the different RISC-V AIRs.
     pub fn chips() -> Vec<Chip<F, Self>> {
         let (chips, _) = Self::get_chips_and_costs();
@@ -147,6 +208,11 @@ impl<F: PrimeField32> RiscvAir<F> {
         costs.insert(RiscvAirDiscriminants::Cpu, cpu.cost());
         chips.push(cpu);
 
+        let memory_instructions =
+            Chip::new(RiscvAir::MemoryInstructions(MemoryInstructionsChip::default()));
+        costs.insert(RiscvAirDiscriminants::MemoryInstructions, memory_instructions.cost());
+        chips.push(memory_instructions);
+
         let program = Chip::new(RiscvAir::Program(ProgramChip::default()));
         chips.push(program);
 
@@ -217,10 +283,72 @@ impl<F: PrimeField32> RiscvAir<F> {
         costs.insert(RiscvAirDiscriminants::Bls12381Double, bls12381_double.cost());
         chips.push(bls12381_double);
 
+        let bls12381_g2_add = Chip::new(RiscvAir::Bls12381G2Add(Bls12381G2AddChip::new()));
+        costs.insert(RiscvAirDiscriminants::Bls12381G2Add, bls12381_g2_add.cost());
+        chips.push(bls12381_g2_add);
+
+        let bls12381_g2_double = Chip::new(RiscvAir::Bls12381G2Double(Bls12381G2DoubleChip::new()));
+        costs.insert(RiscvAirDiscriminants::Bls12381G2Double, bls12381_g2_double.cost());
+        chips.push(bls12381_g2_double);
+
         let uint256_mul = Chip::new(RiscvAir::Uint256Mul(Uint256MulChip::default()));
         costs.insert(RiscvAirDiscriminants::Uint256Mul, uint256_mul.cost());
         chips.push(uint256_mul);
 
+        let poseidon2_merkle_verify =
+            Chip::new(RiscvAir::Poseidon2MerkleVerify(Poseidon2MerkleVerifyChip::default()));
+        costs.insert(RiscvAirDiscriminants::Poseidon2MerkleVerify, poseidon2_merkle_verify.cost());
+        chips.push(poseidon2_merkle_verify);
+
+        let clmul = Chip::new(RiscvAir::Clmul(ClmulChip::default()));
+        costs.insert(RiscvAirDiscriminants::Clmul, clmul.cost());
+        chips.push(clmul);
+
+        let x25519 = Chip::new(RiscvAir::X25519(X25519Chip::default()));
+        costs.insert(RiscvAirDiscriminants::X25519, x25519.cost());
+        chips.push(x25519);
+
+        let babyjubjub_add = Chip::new(RiscvAir::BabyJubjubAdd(EdAddAssignChip::<
+            EdwardsCurve<BabyJubjubParameters>,
+        >::new()));
+        costs.insert(RiscvAirDiscriminants::BabyJubjubAdd, babyjubjub_add.cost());
+        chips.push(babyjubjub_add);
+
+        let aes_encrypt_block =
+            Chip::new(RiscvAir::AesEncryptBlock(AesEncryptBlockChip::default()));
+        costs.insert(RiscvAirDiscriminants::AesEncryptBlock, aes_encrypt_block.cost());
+        chips.push(aes_encrypt_block);
+
+        let range_check = Chip::new(RiscvAir::RangeCheck(RangeCheckChip::default()));
+        costs.insert(RiscvAirDiscriminants::RangeCheck, range_check.cost());
+        chips.push(range_check);
+
+        // Every instance of a registered curve chip has the same width, so they share a cost.
+        for curve in REGISTERED_CURVES {
+            let registered_add = Chip::new(RiscvAir::WeierstrassRegisteredAdd(
+                WeierstrassRegisteredAddChip::new(curve),
+            ));
+            costs.insert(RiscvAirDiscriminants::WeierstrassRegisteredAdd, registered_add.cost());
+            chips.push(registered_add);
+
+            let registered_double = Chip::new(RiscvAir::WeierstrassRegisteredDouble(
+                WeierstrassRegisteredDoubleChip::new(curve),
+            ));
+            costs.insert(
+                RiscvAirDiscriminants::WeierstrassRegisteredDouble,
+                registered_double.cost(),
+            );
+            chips.push(registered_double);
+        }
+
+        let wide_alu = Chip::new(RiscvAir::WideAlu(WideAluChip::default()));
+        costs.insert(RiscvAirDiscriminants::WideAlu, wide_alu.cost());
+        chips.push(wide_alu);
+
+        let fixed_point = Chip::new(RiscvAir::FixedPoint(FixedPointChip::default()));
+        costs.insert(RiscvAirDiscriminants::FixedPoint, fixed_point.cost());
+        chips.push(fixed_point);
+
         let bls12381_fp = Chip::new(RiscvAir::Bls12381Fp(FpOpChip::<Bls12381BaseField>::new()));
         costs.insert(RiscvAirDiscriminants::Bls12381Fp, bls12381_fp.cost());
         chips.push(bls12381_fp);
@@ -256,6 +384,28 @@ impl<F: PrimeField32> RiscvAir<F> {
         costs.insert(RiscvAirDiscriminants::Bls12381Decompress, bls12381_decompress.cost());
         chips.push(bls12381_decompress);
 
+        let secp256k1_map_to_curve =
+            Chip::new(RiscvAir::Secp256k1MapToCurve(WeierstrassMapToCurveChip::<
+                SwCurve<Secp256k1Parameters>,
+            >::new()));
+        costs.insert(RiscvAirDiscriminants::Secp256k1MapToCurve, secp256k1_map_to_curve.cost());
+        chips.push(secp256k1_map_to_curve);
+
+        let bls12381_map_to_curve =
+            Chip::new(RiscvAir::Bls12381MapToCurve(WeierstrassMapToCurveChip::<
+                SwCurve<Bls12381Parameters>,
+            >::new()));
+        costs.insert(RiscvAirDiscriminants::Bls12381MapToCurve, bls12381_map_to_curve.cost());
+        chips.push(bls12381_map_to_curve);
+
+        let hex = Chip::new(RiscvAir::Hex(HexChip::default()));
+        costs.insert(RiscvAirDiscriminants::Hex, hex.cost());
+        chips.push(hex);
+
+        let base64 = Chip::new(RiscvAir::Base64(Base64Chip::default()));
+        costs.insert(RiscvAirDiscriminants::Base64, base64.cost());
+        chips.push(base64);
+
         let div_rem = Chip::new(RiscvAir::DivRem(DivRemChip::default()));
         costs.insert(RiscvAirDiscriminants::DivRem, div_rem.cost());
         chips.push(div_rem);
@@ -324,22 +474,41 @@ impl<F: PrimeField32> core::hash::Hash for RiscvAir<F> {
 #[allow(non_snake_case)]
 pub mod tests {
 
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc,
+    };
+
     use crate::{
         io::SP1Stdin,
         riscv::RiscvAir,
         utils,
-        utils::{prove, run_test, setup_logger},
+        utils::{
+            generate_witness, golden::assert_constraint_lock, prove, prove_with_context,
+            prove_with_context_resumable, run_test, run_test_core, setup_logger, CoreProofOutcome,
+            CoreProofProgress, SP1CoreProverError,
+        },
     };
 
+    use p3_baby_bear::BabyBear;
+    use p3_challenger::FieldChallenger;
+    use p3_field::{AbstractExtensionField, AbstractField, PrimeField32};
+    use rand::{rngs::StdRng, SeedableRng};
     use sp1_core_executor::{
-        programs::tests::{
-            fibonacci_program, simple_memory_program, simple_program, ssz_withdrawals_program,
+        programs::{
+            random::random_program,
+            tests::{
+                fibonacci_program, simple_memory_program, simple_program, ssz_withdrawals_program,
+            },
         },
-        Instruction, Opcode, Program,
+        subproof::NoOpSubproofVerifier,
+        syscalls::SyscallCode,
+        Executor, Instruction, Opcode, Program, SP1Context,
     };
     use sp1_stark::{
-        baby_bear_poseidon2::BabyBearPoseidon2, CpuProver, SP1CoreOpts, StarkProvingKey,
-        StarkVerifyingKey,
+        baby_bear_poseidon2::{BabyBearPoseidon2, Challenge},
+        fri_plugin, CpuProver, FriParameters, MachineProver, PluginConfig, SP1CoreOpts,
+        StarkGenericConfig, StarkProvingKey, StarkVerifyingKey,
     };
 
     #[test]
@@ -349,6 +518,24 @@ pub mod tests {
         run_test::<CpuProver<_, _>>(program).unwrap();
     }
 
+    #[test]
+    fn test_counter_csrs_prove() {
+        utils::setup_logger();
+        let mut instructions = Vec::new();
+        for i in 0..64 {
+            instructions.push(Instruction::new(Opcode::ADD, 29, 0, i, false, true));
+            instructions.push(Instruction::new(Opcode::RDCYCLE, 10, 0, 0, true, true));
+            instructions.push(Instruction::new(Opcode::RDINSTRET, 11, 0, 0, true, true));
+            instructions.push(Instruction::new(Opcode::RDINSTRET, 0, 0, 0, true, true));
+        }
+        let program = Program::new(instructions, 0, 0);
+        let mut opts = SP1CoreOpts::default();
+        opts.shard_size = 64;
+        let mut runtime = Executor::new(program, opts);
+        runtime.run().unwrap();
+        run_test_core::<CpuProver<_, _>>(runtime, SP1Stdin::new()).unwrap();
+    }
+
     #[test]
     fn test_shift_prove() {
         utils::setup_logger();
@@ -466,6 +653,25 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn test_divrem_many_per_shard_prove() {
+        // The multiplications, additions and comparisons checking each division are looked up with
+        // the nonces of their rows, which are only non-zero when a shard has several divisions.
+        setup_logger();
+        let div_rem_ops = [Opcode::DIV, Opcode::DIVU, Opcode::REM, Opcode::REMU];
+        let mut instructions = vec![
+            Instruction::new(Opcode::ADD, 29, 0, 123 * 456 + 7, false, true),
+            Instruction::new(Opcode::ADD, 30, 0, 789, false, true),
+        ];
+        for i in 0..32 {
+            instructions.push(Instruction::new(div_rem_ops[i % 4], 31, 29, 30, false, false));
+            instructions.push(Instruction::new(Opcode::ADD, 29, 29, 1000, false, true));
+            instructions.push(Instruction::new(Opcode::MUL, 28, 29, 30, false, false));
+        }
+        let program = Program::new(instructions, 0, 0);
+        run_test::<CpuProver<_, _>>(program).unwrap();
+    }
+
     #[test]
     fn test_fibonacci_prove_simple() {
         setup_logger();
@@ -485,6 +691,172 @@ pub mod tests {
         prove::<_, CpuProver<_, _>>(program, &stdin, BabyBearPoseidon2::new(), opts).unwrap();
     }
 
+    #[test]
+    fn test_deferred_proofs_split_shards() {
+        setup_logger();
+
+        // A proof to verify, which the no-op subproof verifier accepts as is.
+        let program = simple_program();
+        let prover = CpuProver::new(RiscvAir::machine(BabyBearPoseidon2::new()));
+        let (pk, vk) = prover.setup(&program);
+        let opts = SP1CoreOpts::default();
+        let (proof, _, _) =
+            prove_with_context(&prover, &pk, program, &SP1Stdin::new(), opts, Default::default())
+                .unwrap();
+
+        // Verify 5 deferred proofs, with at most 2 per shard.
+        let verify = [
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::VERIFY_SP1_PROOF as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, 100, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 200, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        let program = Program::new(verify.repeat(5), 0, 0);
+        let execute = |max_deferred_proofs_per_shard| {
+            let opts = SP1CoreOpts { max_deferred_proofs_per_shard, ..opts };
+            let context =
+                SP1Context::builder().subproof_verifier(Arc::new(NoOpSubproofVerifier)).build();
+            let mut runtime = Executor::with_context(program.clone(), opts, context);
+            for _ in 0..5 {
+                runtime.write_proof(proof.shard_proofs[0].clone(), vk.clone());
+            }
+            runtime.run().unwrap();
+            runtime.records.iter().map(|record| record.cpu_events.len()).collect::<Vec<_>>()
+        };
+        assert_eq!(execute(2), vec![8, 8, 4]);
+
+        // A limit of 0 does not split the shards.
+        assert_eq!(execute(0), vec![20]);
+    }
+
+    #[test]
+    fn test_fibonacci_prove_resumable() {
+        setup_logger();
+        let program = fibonacci_program();
+        let stdin = SP1Stdin::new();
+        let mut opts = SP1CoreOpts::default();
+        opts.shard_size = 1024;
+        opts.shard_batch_size = 1;
+        let prover = CpuProver::new(RiscvAir::machine(BabyBearPoseidon2::new()));
+        let (pk, vk) = prover.setup(&program);
+
+        // Interrupt the proof once the first shard proof is generated.
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let (shard_proof_tx, shard_proof_rx) = mpsc::channel();
+        let flag = Arc::clone(&cancel_flag);
+        std::thread::spawn(move || {
+            if shard_proof_rx.recv().is_ok() {
+                flag.store(true, Ordering::Relaxed);
+            }
+        });
+        let context = SP1Context::builder().cancel_flag(cancel_flag).build();
+        let outcome = prove_with_context_resumable(
+            &prover,
+            &pk,
+            program.clone(),
+            &stdin,
+            opts,
+            context,
+            Some(shard_proof_tx),
+            None,
+        )
+        .unwrap();
+        let CoreProofOutcome::Interrupted(progress) = outcome else {
+            panic!("the proof was not interrupted");
+        };
+        assert!(!progress.shard_proofs.is_empty());
+        assert!(!progress.checkpoints.is_empty());
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("progress.bin");
+        progress.save(&path).unwrap();
+
+        // The progress is bound to the inputs of the proof.
+        let mut other_stdin = SP1Stdin::new();
+        other_stdin.write(&1u32);
+        let result = prove_with_context_resumable(
+            &prover,
+            &pk,
+            program.clone(),
+            &other_stdin,
+            opts,
+            SP1Context::default(),
+            None,
+            Some(CoreProofProgress::load(&path).unwrap()),
+        );
+        assert!(matches!(result, Err(SP1CoreProverError::ProgressMismatch)));
+
+        let outcome = prove_with_context_resumable(
+            &prover,
+            &pk,
+            program,
+            &stdin,
+            opts,
+            SP1Context::default(),
+            None,
+            Some(CoreProofProgress::load(&path).unwrap()),
+        )
+        .unwrap();
+        let CoreProofOutcome::Done(proof, _, _) = outcome else {
+            panic!("the resumed proof was interrupted");
+        };
+        let mut challenger = prover.config().challenger();
+        prover.machine().verify(&vk, &proof, &mut challenger).unwrap();
+    }
+
+    #[test]
+    fn test_fibonacci_generate_witness() {
+        setup_logger();
+        let program = fibonacci_program();
+        let stdin = SP1Stdin::new();
+        let mut opts = SP1CoreOpts::default();
+        opts.shard_size = 1024;
+        let prover = CpuProver::new(RiscvAir::machine(BabyBearPoseidon2::new()));
+        let (pk, _) = prover.setup(&program);
+
+        let mut challenger = prover.config().challenger();
+        let challenges: [Challenge; 2] =
+            [challenger.sample_ext_element(), challenger.sample_ext_element()];
+        let mut witnesses = Vec::new();
+        generate_witness(
+            &prover,
+            &pk,
+            program.clone(),
+            &stdin,
+            opts,
+            SP1Context::default(),
+            Some(&challenges),
+            |witness| witnesses.push(witness),
+        )
+        .unwrap();
+
+        // The shards are the same as the proven ones.
+        let (proof, _, _) =
+            prove::<_, CpuProver<_, _>>(program, &stdin, BabyBearPoseidon2::new(), opts).unwrap();
+        assert_eq!(witnesses.len(), proof.shard_proofs.len());
+        for (witness, shard_proof) in witnesses.iter().zip(proof.shard_proofs.iter()) {
+            assert_eq!(
+                witness.public_values,
+                shard_proof.public_values.iter().map(|v| v.as_canonical_u32()).collect::<Vec<_>>()
+            );
+            let mut names = witness.chips.iter().map(|chip| chip.name.clone()).collect::<Vec<_>>();
+            names.sort();
+            let mut expected = shard_proof.chip_ordering.keys().cloned().collect::<Vec<_>>();
+            expected.sort();
+            assert_eq!(names, expected);
+        }
+
+        // The interactions of all the shards are balanced.
+        let cumulative_sum = witnesses
+            .iter()
+            .flat_map(|witness| witness.chips.iter())
+            .map(|chip| {
+                let coefficients = chip.cumulative_sum.as_ref().unwrap();
+                Challenge::from_base_fn(|i| BabyBear::from_canonical_u32(coefficients[i]))
+            })
+            .sum::<Challenge>();
+        assert_eq!(cumulative_sum, Challenge::zero());
+    }
+
     #[test]
     fn test_fibonacci_prove_batch() {
         setup_logger();
@@ -499,6 +871,32 @@ pub mod tests {
         .unwrap();
     }
 
+    #[test]
+    fn test_fibonacci_prove_pcs_plugin() {
+        setup_logger();
+        let program = fibonacci_program();
+        let stdin = SP1Stdin::new();
+        let config = PluginConfig::new(fri_plugin(FriParameters::BLOWUP_4));
+        let (proof, _, _) = prove::<_, CpuProver<_, _>>(
+            program.clone(),
+            &stdin,
+            config.clone(),
+            SP1CoreOpts::default(),
+        )
+        .unwrap();
+
+        let machine = RiscvAir::machine(config);
+        let (_, vk) = machine.setup(&program);
+        let mut challenger = machine.config().challenger();
+        machine.verify(&vk, &proof, &mut challenger).unwrap();
+    }
+
+    #[test]
+    fn test_constraint_lock() {
+        let machine = RiscvAir::machine(BabyBearPoseidon2::new());
+        assert_constraint_lock("riscv", &machine.constraint_system().lock());
+    }
+
     #[test]
     fn test_simple_memory_program_prove() {
         setup_logger();
@@ -506,6 +904,16 @@ pub mod tests {
         run_test::<CpuProver<_, _>>(program).unwrap();
     }
 
+    #[test]
+    fn test_random_programs_prove() {
+        setup_logger();
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..4 {
+            let program = random_program(&mut rng, 64);
+            run_test::<CpuProver<_, _>>(program).unwrap();
+        }
+    }
+
     #[test]
     fn test_ssz_withdrawal() {
         setup_logger();
@@ -0,0 +1,297 @@
+use core::borrow::Borrow;
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::AbstractField;
+use p3_matrix::Matrix;
+use sp1_core_executor::{
+    syscalls::{SyscallCode, AES_128_ROUNDS, AES_256_ROUNDS},
+    ByteOpcode,
+};
+use sp1_stark::air::{BaseAirBuilder, SP1AirBuilder};
+
+use super::{
+    columns::{AesEncryptBlockCols, AesRoundCols, NUM_AES_ENCRYPT_BLOCK_COLS},
+    next_in_column, shifted_index, AesEncryptBlockChip, AES_BLOCK_BYTES, AES_BLOCK_WORDS,
+};
+use crate::{air::MemoryAirBuilder, memory::MemoryCols};
+
+impl<F> BaseAir<F> for AesEncryptBlockChip {
+    fn width(&self) -> usize {
+        NUM_AES_ENCRYPT_BLOCK_COLS
+    }
+}
+
+impl<AB> Air<AB> for AesEncryptBlockChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &AesEncryptBlockCols<AB::Var> = (*local).borrow();
+        let next: &AesEncryptBlockCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        self.eval_flags(builder, local, next);
+        self.eval_memory(builder, local);
+        self.eval_round(
+            builder,
+            local.shard,
+            local.channel,
+            &local.state,
+            &local.round_cols,
+            local.is_real,
+        );
+
+        // The key addition applies to the plaintext on the first row, to the shifted S-boxes on
+        // the last row and to the output of MixColumns otherwise.
+        for i in 0..AES_BLOCK_BYTES {
+            let shifted = local.round_cols.sbox[shifted_index(i)];
+            let mixed = local.round_cols.mixed[i];
+            builder.assert_eq(
+                local.round_input[i],
+                mixed
+                    + local.is_first * (local.state[i] - mixed)
+                    + local.is_last * (shifted - mixed),
+            );
+            let round_key = local.round_key[i / 4].value()[i % 4];
+            builder.send_byte(
+                ByteOpcode::XOR.as_field::<AB::F>(),
+                local.round_output[i],
+                local.round_input[i],
+                round_key,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+        }
+
+        // The output of a round is the state of the next round.
+        let is_continuing: AB::Expr = local.is_real - local.is_last;
+        for i in 0..AES_BLOCK_BYTES {
+            builder
+                .when_transition()
+                .when(is_continuing.clone())
+                .assert_eq(local.round_output[i], next.state[i]);
+        }
+
+        // Receive the arguments on the first row of the block.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::AES_ENCRYPT_BLOCK.syscall_id()),
+            local.block_ptr,
+            local.key_ptr,
+            local.is_first,
+        );
+    }
+}
+
+impl AesEncryptBlockChip {
+    /// Constrains the control flags and the rounds across the rows of a block.
+    fn eval_flags<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &AesEncryptBlockCols<AB::Var>,
+        next: &AesEncryptBlockCols<AB::Var>,
+    ) {
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_first);
+        builder.assert_bool(local.is_last);
+        builder.when(local.is_first).assert_one(local.is_real);
+        builder.when(local.is_last).assert_one(local.is_real);
+
+        // Real rows come before padding rows, and every block starts at a first row and ends at
+        // a last row.
+        builder.when_transition().when_not(local.is_real).assert_zero(next.is_real);
+        builder.when_first_row().assert_eq(local.is_first, local.is_real);
+        builder.when_transition().when(local.is_last).assert_eq(next.is_first, next.is_real);
+        builder.when_last_row().when(local.is_real).assert_one(local.is_last);
+
+        // The first row reads the number of rounds, which is 10 or 14.
+        let header = local.header.value();
+        builder.when(local.is_first).assert_zero(local.round);
+        builder.when(local.is_first).assert_eq(local.rounds, header[0]);
+        for i in 1..4 {
+            builder.when(local.is_first).assert_zero(header[i]);
+        }
+        builder.when(local.is_first).assert_zero(
+            (local.rounds - AB::F::from_canonical_u32(AES_128_ROUNDS))
+                * (local.rounds - AB::F::from_canonical_u32(AES_256_ROUNDS)),
+        );
+
+        // Copy over the inputs and increment the round until the last row.
+        let is_continuing: AB::Expr = local.is_real - local.is_last;
+        let mut when_continuing = builder.when_transition();
+        let mut when_continuing = when_continuing.when(is_continuing);
+        when_continuing.assert_one(next.is_real);
+        when_continuing.assert_zero(next.is_first);
+        when_continuing.assert_eq(local.shard, next.shard);
+        when_continuing.assert_eq(local.channel, next.channel);
+        when_continuing.assert_eq(local.clk, next.clk);
+        when_continuing.assert_eq(local.block_ptr, next.block_ptr);
+        when_continuing.assert_eq(local.key_ptr, next.key_ptr);
+        when_continuing.assert_eq(local.rounds, next.rounds);
+        when_continuing.assert_eq(local.round + AB::Expr::one(), next.round);
+
+        // The block has exactly `rounds + 1` rows.
+        builder.when(local.is_last).assert_eq(local.round, local.rounds);
+    }
+
+    /// Constrains the memory accesses of the header, the plaintext, the round keys and the
+    /// ciphertext.
+    fn eval_memory<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &AesEncryptBlockCols<AB::Var>,
+    ) {
+        let nb_bytes_in_word = AB::F::from_canonical_u32(4);
+        let block_bytes = AB::F::from_canonical_usize(AES_BLOCK_BYTES);
+
+        // Read the number of rounds and the plaintext on the first row.
+        builder.eval_memory_access(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.key_ptr,
+            &local.header,
+            local.is_first,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.block_ptr,
+            &local.input,
+            local.is_first,
+        );
+        for i in 0..AES_BLOCK_BYTES {
+            builder
+                .when(local.is_first)
+                .assert_eq(local.state[i], local.input[i / 4].value()[i % 4]);
+        }
+
+        // Read the round key of this round.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.key_ptr + nb_bytes_in_word + local.round * block_bytes,
+            &local.round_key,
+            local.is_real,
+        );
+
+        // Write the ciphertext on the last row, one cycle after the reads. Its bytes are outputs
+        // of XOR lookups, so they are range checked.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk + AB::Expr::one(),
+            local.block_ptr,
+            &local.output,
+            local.is_last,
+        );
+        for i in 0..AES_BLOCK_BYTES {
+            builder
+                .when(local.is_last)
+                .assert_eq(local.output[i / 4].value()[i % 4], local.round_output[i]);
+        }
+    }
+
+    /// Constrains SubBytes, ShiftRows and MixColumns of `state` with lookups into the byte table.
+    fn eval_round<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        shard: AB::Var,
+        channel: AB::Var,
+        state: &[AB::Var; AES_BLOCK_BYTES],
+        cols: &AesRoundCols<AB::Var>,
+        is_real: AB::Var,
+    ) {
+        let xor = ByteOpcode::XOR.as_field::<AB::F>();
+        for i in 0..AES_BLOCK_BYTES {
+            builder.send_byte(
+                ByteOpcode::AesSbox.as_field::<AB::F>(),
+                cols.sbox[i],
+                state[i],
+                AB::F::zero(),
+                shard,
+                channel,
+                is_real,
+            );
+        }
+        let shifted: [AB::Var; AES_BLOCK_BYTES] =
+            core::array::from_fn(|i| cols.sbox[shifted_index(i)]);
+
+        // Multiply the XOR of each pair of neighbouring bytes by `x`, which shifts it left and
+        // reduces it by `0x1b` if its most significant bit is set.
+        for i in 0..AES_BLOCK_BYTES {
+            builder.send_byte(
+                xor,
+                cols.pair[i],
+                shifted[i],
+                shifted[next_in_column(i)],
+                shard,
+                channel,
+                is_real,
+            );
+            builder.send_byte(
+                ByteOpcode::MSB.as_field::<AB::F>(),
+                cols.pair_msb[i],
+                cols.pair[i],
+                AB::F::zero(),
+                shard,
+                channel,
+                is_real,
+            );
+            builder.send_byte(
+                xor,
+                cols.pair_xtime[i],
+                cols.pair[i] * AB::F::two() - cols.pair_msb[i] * AB::F::from_canonical_u32(256),
+                cols.pair_msb[i] * AB::F::from_canonical_u8(0x1b),
+                shard,
+                channel,
+                is_real,
+            );
+        }
+
+        // The XOR of the four bytes of a column is the XOR of two disjoint pairs.
+        for c in 0..AES_BLOCK_WORDS {
+            builder.send_byte(
+                xor,
+                cols.column[c],
+                cols.pair[4 * c],
+                cols.pair[4 * c + 2],
+                shard,
+                channel,
+                is_real,
+            );
+        }
+
+        for i in 0..AES_BLOCK_BYTES {
+            builder.send_byte(
+                xor,
+                cols.partial[i],
+                shifted[i],
+                cols.column[i / 4],
+                shard,
+                channel,
+                is_real,
+            );
+            builder.send_byte(
+                xor,
+                cols.mixed[i],
+                cols.partial[i],
+                cols.pair_xtime[i],
+                shard,
+                channel,
+                is_real,
+            );
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use std::mem::size_of;
+
+use sp1_derive::AlignedBorrow;
+
+use super::{AES_BLOCK_BYTES, AES_BLOCK_WORDS};
+use crate::memory::{MemoryReadCols, MemoryWriteCols};
+
+pub const NUM_AES_ENCRYPT_BLOCK_COLS: usize = size_of::<AesEncryptBlockCols<u8>>();
+
+/// The columns of a single round of AES.
+///
+/// Byte `4 * c + r` of a state is the byte in row `r` and column `c`. MixColumns computes output
+/// byte `r` of a column `a` as `a[r] ^ (a[0] ^ a[1] ^ a[2] ^ a[3]) ^ xtime(a[r] ^ a[r + 1])`.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct AesRoundCols<T> {
+    /// The S-box of every byte of the state. ShiftRows only permutes these bytes.
+    pub sbox: [T; AES_BLOCK_BYTES],
+    /// The XOR of each shifted byte with the next byte of its column.
+    pub pair: [T; AES_BLOCK_BYTES],
+    /// The most significant bit of each byte of `pair`.
+    pub pair_msb: [T; AES_BLOCK_BYTES],
+    /// The product of each byte of `pair` with `x` in `GF(2^8)`.
+    pub pair_xtime: [T; AES_BLOCK_BYTES],
+    /// The XOR of the four shifted bytes of each column.
+    pub column: [T; AES_BLOCK_WORDS],
+    /// The XOR of each shifted byte with `column`.
+    pub partial: [T; AES_BLOCK_BYTES],
+    /// The output of MixColumns.
+    pub mixed: [T; AES_BLOCK_BYTES],
+}
+
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct AesEncryptBlockCols<T> {
+    /// Inputs.
+    pub shard: T,
+    pub channel: T,
+    pub nonce: T,
+    pub clk: T,
+    pub block_ptr: T,
+    pub key_ptr: T,
+
+    /// Control flags.
+    pub is_real: T,
+    /// Whether the row is the initial key addition of a block.
+    pub is_first: T,
+    /// Whether the row is the last round of a block, which skips MixColumns.
+    pub is_last: T,
+
+    /// The round, starting at zero for the initial key addition.
+    pub round: T,
+    /// The number of rounds of the block.
+    pub rounds: T,
+
+    /// The number of rounds and the plaintext, read on the first row.
+    pub header: MemoryReadCols<T>,
+    pub input: [MemoryReadCols<T>; AES_BLOCK_WORDS],
+
+    /// The round key of this round.
+    pub round_key: [MemoryReadCols<T>; AES_BLOCK_WORDS],
+
+    /// The ciphertext, written on the last row.
+    pub output: [MemoryWriteCols<T>; AES_BLOCK_WORDS],
+
+    /// The state at the start of the round.
+    pub state: [T; AES_BLOCK_BYTES],
+
+    /// SubBytes, ShiftRows and MixColumns of `state`.
+    pub round_cols: AesRoundCols<T>,
+
+    /// The state before the key addition: `state` on the first row, the shifted S-boxes on the
+    /// last row and the output of MixColumns otherwise.
+    pub round_input: [T; AES_BLOCK_BYTES],
+
+    /// The state at the end of the round.
+    pub round_output: [T; AES_BLOCK_BYTES],
+}
@@ -0,0 +1,142 @@
+mod air;
+pub mod columns;
+mod trace;
+
+pub use sp1_core_executor::events::AES_BLOCK_WORDS;
+
+/// The number of bytes in an AES block or round key.
+pub const AES_BLOCK_BYTES: usize = 4 * AES_BLOCK_WORDS;
+
+/// The index of the S-box that ShiftRows moves to byte `i` of the state.
+pub(crate) const fn shifted_index(i: usize) -> usize {
+    let (c, r) = (i / 4, i % 4);
+    4 * ((c + r) % 4) + r
+}
+
+/// The index of the byte after byte `i` in its column, wrapping around.
+pub(crate) const fn next_in_column(i: usize) -> usize {
+    4 * (i / 4) + (i + 1) % 4
+}
+
+/// Implements the AES block encryption precompile.
+///
+/// The syscall takes a pointer to a block and a pointer to an expanded key laid out as
+/// `[rounds, round_keys..]`, with 10 rounds for AES-128 and 14 rounds for AES-256, and overwrites
+/// the block with its encryption.
+///
+/// In the AIR, each syscall takes up `rounds + 1` rows. The first row adds the first round key to
+/// the plaintext and each of the next rows computes a round. The S-boxes are lookups into the byte
+/// table.
+#[derive(Default)]
+pub struct AesEncryptBlockChip;
+
+impl AesEncryptBlockChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+#[cfg(test)]
+pub mod aes_tests {
+    use sp1_core_executor::{
+        syscalls::{SyscallCode, AES_128_ROUNDS, AES_256_ROUNDS},
+        Executor, Instruction, Opcode, Program,
+    };
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{self, run_test};
+
+    /// The plaintext of the examples of FIPS 197, `00112233445566778899aabbccddeeff`.
+    const PLAINTEXT: [u32; 4] = [0x33221100, 0x77665544, 0xbbaa9988, 0xffeeddcc];
+
+    /// Expands the key `000102..` of the examples of FIPS 197 to `rounds + 1` round keys.
+    fn fips197_key_schedule(rounds: u32) -> Vec<u32> {
+        let sbox = sp1_core_executor::syscalls::AES_SBOX;
+        let nk = (rounds - 6) as usize;
+        let key = (0..4 * nk as u8).collect::<Vec<_>>();
+        let mut words: Vec<[u8; 4]> = key.chunks(4).map(|w| w.try_into().unwrap()).collect();
+        let mut rcon = 1u8;
+        for i in nk..4 * (rounds as usize + 1) {
+            let mut temp = words[i - 1];
+            if i % nk == 0 {
+                temp = [temp[1], temp[2], temp[3], temp[0]].map(|b| sbox[b as usize]);
+                temp[0] ^= rcon;
+                rcon = sp1_core_executor::syscalls::aes_xtime(rcon);
+            } else if nk > 6 && i % nk == 4 {
+                temp = temp.map(|b| sbox[b as usize]);
+            }
+            words.push(core::array::from_fn(|j| words[i - nk][j] ^ temp[j]));
+        }
+        let mut schedule = vec![rounds];
+        schedule.extend(words.into_iter().map(u32::from_le_bytes));
+        schedule
+    }
+
+    /// Builds a program that encrypts `block` in place with the key schedule `schedule`.
+    pub fn aes_program(block: [u32; 4], schedule: &[u32]) -> Program {
+        let block_ptr = 100;
+        let key_ptr = 200;
+        let mut words = vec![];
+        for (i, word) in block.iter().enumerate() {
+            words.push((block_ptr + 4 * i as u32, *word));
+        }
+        for (i, word) in schedule.iter().enumerate() {
+            words.push((key_ptr + 4 * i as u32, *word));
+        }
+
+        let mut instructions = vec![];
+        for (addr, value) in words {
+            instructions.extend(vec![
+                Instruction::new(Opcode::ADD, 29, 0, value, false, true),
+                Instruction::new(Opcode::ADD, 30, 0, addr, false, true),
+                Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+            ]);
+        }
+        instructions.extend(vec![
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::AES_ENCRYPT_BLOCK as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, block_ptr, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, key_ptr, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ]);
+        Program::new(instructions, 0, 0)
+    }
+
+    #[test]
+    fn test_aes_execute() {
+        // The ciphertexts of the examples of FIPS 197 for AES-128 and AES-256.
+        let vectors = [
+            (AES_128_ROUNDS, [0xd8e0c469, 0x30047b6a, 0x80b7cdd8, 0x5ac5b470]),
+            (AES_256_ROUNDS, [0xcab7a28e, 0xbf456751, 0x9049fcea, 0x8960494b]),
+        ];
+        for (rounds, expected) in vectors {
+            let program = aes_program(PLAINTEXT, &fips197_key_schedule(rounds));
+            let mut runtime = Executor::new(program, SP1CoreOpts::default());
+            runtime.run().unwrap();
+            for (i, word) in expected.iter().enumerate() {
+                assert_eq!(runtime.word(100 + 4 * i as u32), *word);
+            }
+        }
+    }
+
+    #[test]
+    fn test_aes_128_prove() {
+        utils::setup_logger();
+        run_test::<CpuProver<_, _>>(aes_program(PLAINTEXT, &fips197_key_schedule(AES_128_ROUNDS)))
+            .unwrap();
+    }
+
+    /// The nonce of each block is the index of its first row, which depends on the number of
+    /// rounds of the blocks before it.
+    #[test]
+    fn test_aes_prove_multiple_blocks() {
+        utils::setup_logger();
+        let mut program = aes_program(PLAINTEXT, &fips197_key_schedule(AES_256_ROUNDS));
+        program
+            .instructions
+            .extend(aes_program([1, 2, 3, 4], &fips197_key_schedule(AES_128_ROUNDS)).instructions);
+        program
+            .instructions
+            .extend(aes_program([5, 6, 7, 8], &fips197_key_schedule(AES_256_ROUNDS)).instructions);
+        run_test::<CpuProver<_, _>>(program).unwrap();
+    }
+}
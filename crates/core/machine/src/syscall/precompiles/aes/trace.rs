@@ -0,0 +1,232 @@
+use std::borrow::BorrowMut;
+
+use hashbrown::HashMap;
+use itertools::Itertools;
+use p3_field::PrimeField32;
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
+use sp1_core_executor::{
+    events::{AesEncryptBlockEvent, ByteLookupEvent, ByteRecord},
+    syscalls::AES_SBOX,
+    ByteOpcode, ExecutionRecord, Program,
+};
+use sp1_stark::air::MachineAir;
+
+use super::{
+    columns::{AesEncryptBlockCols, AesRoundCols, NUM_AES_ENCRYPT_BLOCK_COLS},
+    next_in_column, shifted_index, AesEncryptBlockChip, AES_BLOCK_BYTES, AES_BLOCK_WORDS,
+};
+
+impl<F: PrimeField32> MachineAir<F> for AesEncryptBlockChip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "AesEncryptBlock".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        _: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut rows = Some(Vec::new());
+        let mut new_byte_lookup_events = Vec::new();
+        for event in input.aes_encrypt_block_events.iter() {
+            self.event_to_rows(event, &mut rows, &mut new_byte_lookup_events);
+        }
+
+        let mut rows = rows.unwrap();
+        let nb_rows = rows.len();
+        let mut padded_nb_rows = nb_rows.next_power_of_two();
+        if padded_nb_rows < 4 {
+            padded_nb_rows = 4;
+        }
+        rows.resize(padded_nb_rows, [F::zero(); NUM_AES_ENCRYPT_BLOCK_COLS]);
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            NUM_AES_ENCRYPT_BLOCK_COLS,
+        );
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut AesEncryptBlockCols<F> = trace.values
+                [i * NUM_AES_ENCRYPT_BLOCK_COLS..(i + 1) * NUM_AES_ENCRYPT_BLOCK_COLS]
+                .borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn generate_dependencies(&self, input: &Self::Record, output: &mut Self::Record) {
+        let chunk_size = std::cmp::max(input.aes_encrypt_block_events.len() / num_cpus::get(), 1);
+
+        let blu_batches = input
+            .aes_encrypt_block_events
+            .par_chunks(chunk_size)
+            .map(|events| {
+                let mut blu: HashMap<u32, HashMap<ByteLookupEvent, usize>> = HashMap::new();
+                events.iter().for_each(|event| {
+                    self.event_to_rows::<F>(event, &mut None, &mut blu);
+                });
+                blu
+            })
+            .collect::<Vec<_>>();
+
+        output.add_sharded_byte_lookup_events(blu_batches.iter().collect_vec());
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.aes_encrypt_block_events.is_empty()
+    }
+}
+
+impl AesEncryptBlockChip {
+    fn event_to_rows<F: PrimeField32>(
+        &self,
+        event: &AesEncryptBlockEvent,
+        rows: &mut Option<Vec<[F; NUM_AES_ENCRYPT_BLOCK_COLS]>>,
+        blu: &mut impl ByteRecord,
+    ) {
+        let (shard, channel) = (event.shard, event.channel);
+        let rounds = event.rounds as usize;
+        let mut state = words_to_bytes(&event.input);
+        for round in 0..=rounds {
+            let mut row = [F::zero(); NUM_AES_ENCRYPT_BLOCK_COLS];
+            let cols: &mut AesEncryptBlockCols<F> = row.as_mut_slice().borrow_mut();
+            cols.shard = F::from_canonical_u32(shard);
+            cols.channel = F::from_canonical_u8(channel);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.block_ptr = F::from_canonical_u32(event.block_ptr);
+            cols.key_ptr = F::from_canonical_u32(event.key_ptr);
+
+            cols.is_real = F::one();
+            cols.is_first = F::from_bool(round == 0);
+            cols.is_last = F::from_bool(round == rounds);
+            cols.round = F::from_canonical_usize(round);
+            cols.rounds = F::from_canonical_u32(event.rounds);
+
+            if round == 0 {
+                cols.header.populate(channel, event.header_read_record, blu);
+                for i in 0..AES_BLOCK_WORDS {
+                    cols.input[i].populate(channel, event.input_read_records[i], blu);
+                }
+            }
+            for i in 0..AES_BLOCK_WORDS {
+                cols.round_key[i].populate(channel, event.round_key_read_records[round][i], blu);
+            }
+
+            cols.state = state.map(F::from_canonical_u8);
+            let (shifted, mixed) =
+                populate_round(&mut cols.round_cols, shard, channel, &state, blu);
+            let round_input = if round == 0 {
+                state
+            } else if round == rounds {
+                shifted
+            } else {
+                mixed
+            };
+            cols.round_input = round_input.map(F::from_canonical_u8);
+
+            let round_key = words_to_bytes(&event.round_keys[round]);
+            for i in 0..AES_BLOCK_BYTES {
+                state[i] = xor(blu, shard, channel, round_input[i], round_key[i]);
+            }
+            cols.round_output = state.map(F::from_canonical_u8);
+
+            if round == rounds {
+                debug_assert_eq!(state, words_to_bytes(&event.output));
+                for i in 0..AES_BLOCK_WORDS {
+                    cols.output[i].populate(channel, event.output_write_records[i], blu);
+                }
+            }
+
+            if let Some(rows) = rows.as_mut() {
+                rows.push(row);
+            }
+        }
+    }
+}
+
+/// Populates SubBytes, ShiftRows and MixColumns of `state`, and returns the shifted S-boxes and
+/// the output of MixColumns.
+fn populate_round<F: PrimeField32>(
+    cols: &mut AesRoundCols<F>,
+    shard: u32,
+    channel: u8,
+    state: &[u8; AES_BLOCK_BYTES],
+    blu: &mut impl ByteRecord,
+) -> ([u8; AES_BLOCK_BYTES], [u8; AES_BLOCK_BYTES]) {
+    let sbox = state.map(|byte| {
+        let sbox = AES_SBOX[byte as usize];
+        blu.add_byte_lookup_event(ByteLookupEvent::new(
+            shard,
+            channel,
+            ByteOpcode::AesSbox,
+            sbox as u16,
+            0,
+            byte,
+            0,
+        ));
+        sbox
+    });
+    let shifted: [u8; AES_BLOCK_BYTES] = core::array::from_fn(|i| sbox[shifted_index(i)]);
+
+    let mut pair = [0u8; AES_BLOCK_BYTES];
+    let mut pair_xtime = [0u8; AES_BLOCK_BYTES];
+    for i in 0..AES_BLOCK_BYTES {
+        pair[i] = xor(blu, shard, channel, shifted[i], shifted[next_in_column(i)]);
+        let msb = pair[i] >> 7;
+        blu.add_byte_lookup_event(ByteLookupEvent::new(
+            shard,
+            channel,
+            ByteOpcode::MSB,
+            msb as u16,
+            0,
+            pair[i],
+            0,
+        ));
+        pair_xtime[i] = xor(blu, shard, channel, pair[i] << 1, msb * 0x1b);
+        cols.pair_msb[i] = F::from_canonical_u8(msb);
+    }
+
+    let column: [u8; AES_BLOCK_WORDS] =
+        core::array::from_fn(|c| xor(blu, shard, channel, pair[4 * c], pair[4 * c + 2]));
+    let mut partial = [0u8; AES_BLOCK_BYTES];
+    let mut mixed = [0u8; AES_BLOCK_BYTES];
+    for i in 0..AES_BLOCK_BYTES {
+        partial[i] = xor(blu, shard, channel, shifted[i], column[i / 4]);
+        mixed[i] = xor(blu, shard, channel, partial[i], pair_xtime[i]);
+    }
+
+    cols.sbox = sbox.map(F::from_canonical_u8);
+    cols.pair = pair.map(F::from_canonical_u8);
+    cols.pair_xtime = pair_xtime.map(F::from_canonical_u8);
+    cols.column = column.map(F::from_canonical_u8);
+    cols.partial = partial.map(F::from_canonical_u8);
+    cols.mixed = mixed.map(F::from_canonical_u8);
+    (shifted, mixed)
+}
+
+/// Computes `b ^ c` and records the lookup.
+fn xor(blu: &mut impl ByteRecord, shard: u32, channel: u8, b: u8, c: u8) -> u8 {
+    let xor = b ^ c;
+    blu.add_byte_lookup_event(ByteLookupEvent::new(
+        shard,
+        channel,
+        ByteOpcode::XOR,
+        xor as u16,
+        0,
+        b,
+        c,
+    ));
+    xor
+}
+
+fn words_to_bytes(words: &[u32; AES_BLOCK_WORDS]) -> [u8; AES_BLOCK_BYTES] {
+    core::array::from_fn(|i| words[i / 4].to_le_bytes()[i % 4])
+}
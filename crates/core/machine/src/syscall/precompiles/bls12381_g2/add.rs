@@ -0,0 +1,402 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+use crate::air::MemoryAirBuilder;
+use num::{BigUint, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, FieldOperation},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::weierstrass::bls12_381::{
+    bls12381_g2_from_words_le, Bls12381BaseField, BLS12381_G2_POINT_WORDS,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{BaseAirBuilder, MachineAir, SP1AirBuilder};
+
+use super::{
+    fp2::{Fp2, Fp2AddSubCols, Fp2DivCols, Fp2MulCols},
+    g2_point_from_prev_access, NUM_WORDS_FIELD_ELEMENT,
+};
+use crate::{
+    memory::{value_as_limbs, MemoryReadCols, MemoryWriteCols},
+    utils::pad_rows,
+};
+
+pub const NUM_BLS12381_G2_ADD_COLS: usize = size_of::<Bls12381G2AddCols<u8>>();
+
+/// A set of columns to add two affine points of the BLS12-381 G2 curve.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Bls12381G2AddCols<T> {
+    pub is_real: T,
+    pub shard: T,
+    pub channel: T,
+    pub nonce: T,
+    pub clk: T,
+    pub p_ptr: T,
+    pub q_ptr: T,
+    pub p_access: [MemoryWriteCols<T>; BLS12381_G2_POINT_WORDS],
+    pub q_access: [MemoryReadCols<T>; BLS12381_G2_POINT_WORDS],
+    pub(crate) slope_denominator: Fp2AddSubCols<T, Bls12381BaseField>,
+    pub(crate) slope_numerator: Fp2AddSubCols<T, Bls12381BaseField>,
+    pub(crate) slope: Fp2DivCols<T, Bls12381BaseField>,
+    pub(crate) slope_squared: Fp2MulCols<T, Bls12381BaseField>,
+    pub(crate) p_x_plus_q_x: Fp2AddSubCols<T, Bls12381BaseField>,
+    pub(crate) x3_ins: Fp2AddSubCols<T, Bls12381BaseField>,
+    pub(crate) p_x_minus_x: Fp2AddSubCols<T, Bls12381BaseField>,
+    pub(crate) slope_times_p_x_minus_x: Fp2MulCols<T, Bls12381BaseField>,
+    pub(crate) y3_ins: Fp2AddSubCols<T, Bls12381BaseField>,
+}
+
+#[derive(Default)]
+pub struct Bls12381G2AddChip;
+
+impl Bls12381G2AddChip {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    fn populate_field_ops<F: PrimeField32>(
+        blu_events: &mut Vec<ByteLookupEvent>,
+        shard: u32,
+        channel: u8,
+        cols: &mut Bls12381G2AddCols<F>,
+        p: [BigUint; 4],
+        q: [BigUint; 4],
+    ) {
+        let [p_x0, p_x1, p_y0, p_y1] = p;
+        let [q_x0, q_x1, q_y0, q_y1] = q;
+        let (p_x, p_y): (Fp2<BigUint>, Fp2<BigUint>) = ([p_x0, p_x1], [p_y0, p_y1]);
+        let (q_x, q_y): (Fp2<BigUint>, Fp2<BigUint>) = ([q_x0, q_x1], [q_y0, q_y1]);
+
+        // slope = (q.y - p.y) / (q.x - p.x).
+        let slope = {
+            let slope_numerator = cols.slope_numerator.populate(
+                blu_events,
+                shard,
+                channel,
+                &q_y,
+                &p_y,
+                FieldOperation::Sub,
+            );
+            let slope_denominator = cols.slope_denominator.populate(
+                blu_events,
+                shard,
+                channel,
+                &q_x,
+                &p_x,
+                FieldOperation::Sub,
+            );
+            cols.slope.populate(blu_events, shard, channel, &slope_numerator, &slope_denominator)
+        };
+
+        // x = slope * slope - (p.x + q.x).
+        let x = {
+            let slope_squared =
+                cols.slope_squared.populate(blu_events, shard, channel, &slope, &slope);
+            let p_x_plus_q_x = cols.p_x_plus_q_x.populate(
+                blu_events,
+                shard,
+                channel,
+                &p_x,
+                &q_x,
+                FieldOperation::Add,
+            );
+            cols.x3_ins.populate(
+                blu_events,
+                shard,
+                channel,
+                &slope_squared,
+                &p_x_plus_q_x,
+                FieldOperation::Sub,
+            )
+        };
+
+        // y = slope * (p.x - x_3n) - p.y.
+        {
+            let p_x_minus_x = cols.p_x_minus_x.populate(
+                blu_events,
+                shard,
+                channel,
+                &p_x,
+                &x,
+                FieldOperation::Sub,
+            );
+            let slope_times_p_x_minus_x = cols.slope_times_p_x_minus_x.populate(
+                blu_events,
+                shard,
+                channel,
+                &slope,
+                &p_x_minus_x,
+            );
+            cols.y3_ins.populate(
+                blu_events,
+                shard,
+                channel,
+                &slope_times_p_x_minus_x,
+                &p_y,
+                FieldOperation::Sub,
+            );
+        }
+    }
+}
+
+impl<F: PrimeField32> MachineAir<F> for Bls12381G2AddChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Bls12381G2AddAssign".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for event in input.bls12381_g2_add_events.iter() {
+            let mut row = vec![F::zero(); NUM_BLS12381_G2_ADD_COLS];
+            let cols: &mut Bls12381G2AddCols<F> = row.as_mut_slice().borrow_mut();
+
+            // Populate basic columns.
+            cols.is_real = F::one();
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.channel = F::from_canonical_u8(event.channel);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.p_ptr = F::from_canonical_u32(event.p_ptr);
+            cols.q_ptr = F::from_canonical_u32(event.q_ptr);
+
+            Self::populate_field_ops(
+                &mut new_byte_lookup_events,
+                event.shard,
+                event.channel,
+                cols,
+                bls12381_g2_from_words_le(&event.p),
+                bls12381_g2_from_words_le(&event.q),
+            );
+
+            // Populate the memory access columns.
+            for i in 0..cols.q_access.len() {
+                cols.q_access[i].populate(
+                    event.channel,
+                    event.q_memory_records[i],
+                    &mut new_byte_lookup_events,
+                );
+            }
+            for i in 0..cols.p_access.len() {
+                cols.p_access[i].populate(
+                    event.channel,
+                    event.p_memory_records[i],
+                    &mut new_byte_lookup_events,
+                );
+            }
+
+            rows.push(row);
+        }
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows(&mut rows, || {
+            let mut row = vec![F::zero(); NUM_BLS12381_G2_ADD_COLS];
+            let cols: &mut Bls12381G2AddCols<F> = row.as_mut_slice().borrow_mut();
+            let zero = || core::array::from_fn(|_| BigUint::zero());
+            Self::populate_field_ops(&mut vec![], 0, 0, cols, zero(), zero());
+            row
+        });
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            NUM_BLS12381_G2_ADD_COLS,
+        );
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Bls12381G2AddCols<F> = trace.values
+                [i * NUM_BLS12381_G2_ADD_COLS..(i + 1) * NUM_BLS12381_G2_ADD_COLS]
+                .borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.bls12381_g2_add_events.is_empty()
+    }
+}
+
+impl<F> BaseAir<F> for Bls12381G2AddChip {
+    fn width(&self) -> usize {
+        NUM_BLS12381_G2_ADD_COLS
+    }
+}
+
+impl<AB> Air<AB> for Bls12381G2AddChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Bls12381G2AddCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &Bls12381G2AddCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        let (p_x, p_y) = g2_point_from_prev_access(&local.p_access);
+        let (q_x, q_y) = g2_point_from_prev_access(&local.q_access);
+
+        // slope = (q.y - p.y) / (q.x - p.x).
+        let slope = {
+            local.slope_numerator.eval(
+                builder,
+                &q_y,
+                &p_y,
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.slope_denominator.eval(
+                builder,
+                &q_x,
+                &p_x,
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.slope.eval(
+                builder,
+                &local.slope_numerator.result(),
+                &local.slope_denominator.result(),
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.slope.result()
+        };
+
+        // x = slope * slope - (p.x + q.x).
+        let x = {
+            local.slope_squared.eval(
+                builder,
+                &slope,
+                &slope,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.p_x_plus_q_x.eval(
+                builder,
+                &p_x,
+                &q_x,
+                FieldOperation::Add,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.x3_ins.eval(
+                builder,
+                &local.slope_squared.result(),
+                &local.p_x_plus_q_x.result(),
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.x3_ins.result()
+        };
+
+        // y = slope * (p.x - x_3n) - p.y.
+        {
+            local.p_x_minus_x.eval(
+                builder,
+                &p_x,
+                &x,
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.slope_times_p_x_minus_x.eval(
+                builder,
+                &slope,
+                &local.p_x_minus_x.result(),
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.y3_ins.eval(
+                builder,
+                &local.slope_times_p_x_minus_x.result(),
+                &p_y,
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+        }
+
+        // Constrain p_access.value to be [x3_ins.result, y3_ins.result], so that p is updated
+        // with the sum.
+        let result = x.into_iter().chain(local.y3_ins.result());
+        for (i, coordinate) in result.enumerate() {
+            builder.when(local.is_real).assert_all_eq(
+                coordinate,
+                value_as_limbs(
+                    &local.p_access[i * NUM_WORDS_FIELD_ELEMENT..(i + 1) * NUM_WORDS_FIELD_ELEMENT],
+                ),
+            );
+        }
+
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into(),
+            local.q_ptr,
+            &local.q_access,
+            local.is_real,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk + AB::F::from_canonical_u32(1), /* We read p at +1 since p, q could be the
+                                                       * same. */
+            local.p_ptr,
+            &local.p_access,
+            local.is_real,
+        );
+
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::BLS12381_G2_ADD.syscall_id()),
+            local.p_ptr,
+            local.q_ptr,
+            local.is_real,
+        );
+    }
+}
@@ -0,0 +1,418 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+use crate::air::MemoryAirBuilder;
+use num::{BigUint, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, FieldOperation},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::weierstrass::bls12_381::{
+    bls12381_g2_from_words_le, Bls12381BaseField, BLS12381_G2_POINT_WORDS,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{BaseAirBuilder, MachineAir, SP1AirBuilder};
+
+use super::{
+    fp2::{Fp2, Fp2AddSubCols, Fp2DivCols, Fp2MulCols},
+    g2_point_from_prev_access, NUM_WORDS_FIELD_ELEMENT,
+};
+use crate::{
+    memory::{value_as_limbs, MemoryWriteCols},
+    utils::pad_rows,
+};
+
+pub const NUM_BLS12381_G2_DOUBLE_COLS: usize = size_of::<Bls12381G2DoubleCols<u8>>();
+
+/// A set of columns to double an affine point of the BLS12-381 G2 curve.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Bls12381G2DoubleCols<T> {
+    pub is_real: T,
+    pub shard: T,
+    pub channel: T,
+    pub nonce: T,
+    pub clk: T,
+    pub p_ptr: T,
+    pub p_access: [MemoryWriteCols<T>; BLS12381_G2_POINT_WORDS],
+    pub(crate) p_x_squared: Fp2MulCols<T, Bls12381BaseField>,
+    pub(crate) p_x_squared_times_2: Fp2AddSubCols<T, Bls12381BaseField>,
+    pub(crate) slope_numerator: Fp2AddSubCols<T, Bls12381BaseField>,
+    pub(crate) slope_denominator: Fp2AddSubCols<T, Bls12381BaseField>,
+    pub(crate) slope: Fp2DivCols<T, Bls12381BaseField>,
+    pub(crate) slope_squared: Fp2MulCols<T, Bls12381BaseField>,
+    pub(crate) p_x_plus_p_x: Fp2AddSubCols<T, Bls12381BaseField>,
+    pub(crate) x3_ins: Fp2AddSubCols<T, Bls12381BaseField>,
+    pub(crate) p_x_minus_x: Fp2AddSubCols<T, Bls12381BaseField>,
+    pub(crate) slope_times_p_x_minus_x: Fp2MulCols<T, Bls12381BaseField>,
+    pub(crate) y3_ins: Fp2AddSubCols<T, Bls12381BaseField>,
+}
+
+#[derive(Default)]
+pub struct Bls12381G2DoubleChip;
+
+impl Bls12381G2DoubleChip {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    fn populate_field_ops<F: PrimeField32>(
+        blu_events: &mut Vec<ByteLookupEvent>,
+        shard: u32,
+        channel: u8,
+        cols: &mut Bls12381G2DoubleCols<F>,
+        p: [BigUint; 4],
+    ) {
+        let [p_x0, p_x1, p_y0, p_y1] = p;
+        let (p_x, p_y): (Fp2<BigUint>, Fp2<BigUint>) = ([p_x0, p_x1], [p_y0, p_y1]);
+
+        // slope = slope_numerator / slope_denominator.
+        let slope = {
+            // slope_numerator = (p.x * p.x) * 3, as the curve has a = 0.
+            let slope_numerator = {
+                let p_x_squared = cols.p_x_squared.populate(blu_events, shard, channel, &p_x, &p_x);
+                let p_x_squared_times_2 = cols.p_x_squared_times_2.populate(
+                    blu_events,
+                    shard,
+                    channel,
+                    &p_x_squared,
+                    &p_x_squared,
+                    FieldOperation::Add,
+                );
+                cols.slope_numerator.populate(
+                    blu_events,
+                    shard,
+                    channel,
+                    &p_x_squared_times_2,
+                    &p_x_squared,
+                    FieldOperation::Add,
+                )
+            };
+
+            // slope_denominator = 2 * y.
+            let slope_denominator = cols.slope_denominator.populate(
+                blu_events,
+                shard,
+                channel,
+                &p_y,
+                &p_y,
+                FieldOperation::Add,
+            );
+
+            cols.slope.populate(blu_events, shard, channel, &slope_numerator, &slope_denominator)
+        };
+
+        // x = slope * slope - (p.x + p.x).
+        let x = {
+            let slope_squared =
+                cols.slope_squared.populate(blu_events, shard, channel, &slope, &slope);
+            let p_x_plus_p_x = cols.p_x_plus_p_x.populate(
+                blu_events,
+                shard,
+                channel,
+                &p_x,
+                &p_x,
+                FieldOperation::Add,
+            );
+            cols.x3_ins.populate(
+                blu_events,
+                shard,
+                channel,
+                &slope_squared,
+                &p_x_plus_p_x,
+                FieldOperation::Sub,
+            )
+        };
+
+        // y = slope * (p.x - x) - p.y.
+        {
+            let p_x_minus_x = cols.p_x_minus_x.populate(
+                blu_events,
+                shard,
+                channel,
+                &p_x,
+                &x,
+                FieldOperation::Sub,
+            );
+            let slope_times_p_x_minus_x = cols.slope_times_p_x_minus_x.populate(
+                blu_events,
+                shard,
+                channel,
+                &slope,
+                &p_x_minus_x,
+            );
+            cols.y3_ins.populate(
+                blu_events,
+                shard,
+                channel,
+                &slope_times_p_x_minus_x,
+                &p_y,
+                FieldOperation::Sub,
+            );
+        }
+    }
+}
+
+impl<F: PrimeField32> MachineAir<F> for Bls12381G2DoubleChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Bls12381G2DoubleAssign".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for event in input.bls12381_g2_double_events.iter() {
+            let mut row = vec![F::zero(); NUM_BLS12381_G2_DOUBLE_COLS];
+            let cols: &mut Bls12381G2DoubleCols<F> = row.as_mut_slice().borrow_mut();
+
+            // Populate basic columns.
+            cols.is_real = F::one();
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.channel = F::from_canonical_u8(event.channel);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.p_ptr = F::from_canonical_u32(event.p_ptr);
+
+            Self::populate_field_ops(
+                &mut new_byte_lookup_events,
+                event.shard,
+                event.channel,
+                cols,
+                bls12381_g2_from_words_le(&event.p),
+            );
+
+            // Populate the memory access columns.
+            for i in 0..cols.p_access.len() {
+                cols.p_access[i].populate(
+                    event.channel,
+                    event.p_memory_records[i],
+                    &mut new_byte_lookup_events,
+                );
+            }
+
+            rows.push(row);
+        }
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows(&mut rows, || {
+            let mut row = vec![F::zero(); NUM_BLS12381_G2_DOUBLE_COLS];
+            let cols: &mut Bls12381G2DoubleCols<F> = row.as_mut_slice().borrow_mut();
+            let zero = core::array::from_fn(|_| BigUint::zero());
+            Self::populate_field_ops(&mut vec![], 0, 0, cols, zero);
+            row
+        });
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            NUM_BLS12381_G2_DOUBLE_COLS,
+        );
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Bls12381G2DoubleCols<F> = trace.values
+                [i * NUM_BLS12381_G2_DOUBLE_COLS..(i + 1) * NUM_BLS12381_G2_DOUBLE_COLS]
+                .borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.bls12381_g2_double_events.is_empty()
+    }
+}
+
+impl<F> BaseAir<F> for Bls12381G2DoubleChip {
+    fn width(&self) -> usize {
+        NUM_BLS12381_G2_DOUBLE_COLS
+    }
+}
+
+impl<AB> Air<AB> for Bls12381G2DoubleChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Bls12381G2DoubleCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &Bls12381G2DoubleCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        let (p_x, p_y) = g2_point_from_prev_access(&local.p_access);
+
+        // slope = slope_numerator / slope_denominator.
+        let slope = {
+            // slope_numerator = (p.x * p.x) * 3.
+            {
+                local.p_x_squared.eval(
+                    builder,
+                    &p_x,
+                    &p_x,
+                    local.shard,
+                    local.channel,
+                    local.is_real,
+                );
+
+                local.p_x_squared_times_2.eval(
+                    builder,
+                    &local.p_x_squared.result(),
+                    &local.p_x_squared.result(),
+                    FieldOperation::Add,
+                    local.shard,
+                    local.channel,
+                    local.is_real,
+                );
+
+                local.slope_numerator.eval(
+                    builder,
+                    &local.p_x_squared_times_2.result(),
+                    &local.p_x_squared.result(),
+                    FieldOperation::Add,
+                    local.shard,
+                    local.channel,
+                    local.is_real,
+                );
+            }
+
+            // slope_denominator = 2 * y.
+            local.slope_denominator.eval(
+                builder,
+                &p_y,
+                &p_y,
+                FieldOperation::Add,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.slope.eval(
+                builder,
+                &local.slope_numerator.result(),
+                &local.slope_denominator.result(),
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.slope.result()
+        };
+
+        // x = slope * slope - (p.x + p.x).
+        let x = {
+            local.slope_squared.eval(
+                builder,
+                &slope,
+                &slope,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.p_x_plus_p_x.eval(
+                builder,
+                &p_x,
+                &p_x,
+                FieldOperation::Add,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.x3_ins.eval(
+                builder,
+                &local.slope_squared.result(),
+                &local.p_x_plus_p_x.result(),
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.x3_ins.result()
+        };
+
+        // y = slope * (p.x - x) - p.y.
+        {
+            local.p_x_minus_x.eval(
+                builder,
+                &p_x,
+                &x,
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.slope_times_p_x_minus_x.eval(
+                builder,
+                &slope,
+                &local.p_x_minus_x.result(),
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.y3_ins.eval(
+                builder,
+                &local.slope_times_p_x_minus_x.result(),
+                &p_y,
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+        }
+
+        // Constrain p_access.value to be [x3_ins.result, y3_ins.result], so that p is updated
+        // with the double.
+        let result = x.into_iter().chain(local.y3_ins.result());
+        for (i, coordinate) in result.enumerate() {
+            builder.when(local.is_real).assert_all_eq(
+                coordinate,
+                value_as_limbs(
+                    &local.p_access[i * NUM_WORDS_FIELD_ELEMENT..(i + 1) * NUM_WORDS_FIELD_ELEMENT],
+                ),
+            );
+        }
+
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into(),
+            local.p_ptr,
+            &local.p_access,
+            local.is_real,
+        );
+
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::BLS12381_G2_DOUBLE.syscall_id()),
+            local.p_ptr,
+            AB::Expr::zero(),
+            local.is_real,
+        );
+    }
+}
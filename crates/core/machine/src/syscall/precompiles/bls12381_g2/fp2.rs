@@ -0,0 +1,269 @@
+use num::BigUint;
+use p3_field::PrimeField32;
+use sp1_core_executor::events::{ByteRecord, FieldOperation};
+use sp1_curves::params::{FieldParameters, Limbs};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::SP1AirBuilder;
+
+use crate::operations::field::{field_inner_product::FieldInnerProductCols, field_op::FieldOpCols};
+
+/// An element `c0 + c1 * u` of Fp2 = Fp[u] / (u^2 + 1), as a pair of field elements.
+pub type Fp2<T> = [T; 2];
+
+/// A set of columns to compute `a + b` or `a - b` over Fp2.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Fp2AddSubCols<T, P: FieldParameters> {
+    pub(crate) c0: FieldOpCols<T, P>,
+    pub(crate) c1: FieldOpCols<T, P>,
+}
+
+/// A set of columns to compute `a * b` over Fp2.
+///
+/// The result is `(a0 * b0 - a1 * b1) + (a0 * b1 + a1 * b0) * u`.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Fp2MulCols<T, P: FieldParameters> {
+    pub(crate) a0_mul_b0: FieldOpCols<T, P>,
+    pub(crate) a1_mul_b1: FieldOpCols<T, P>,
+    pub(crate) c0: FieldOpCols<T, P>,
+    pub(crate) c1: FieldInnerProductCols<T, P>,
+}
+
+/// A set of columns to compute `a / b` over Fp2.
+///
+/// The result is `a * conj(b) / norm(b)`, where `norm(b) = b0^2 + b1^2` is in Fp, and
+/// `a * conj(b) = (a0 * b0 + a1 * b1) + (a1 * b0 - a0 * b1) * u`.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Fp2DivCols<T, P: FieldParameters> {
+    pub(crate) norm: FieldInnerProductCols<T, P>,
+    pub(crate) numerator_c0: FieldInnerProductCols<T, P>,
+    pub(crate) a1_mul_b0: FieldOpCols<T, P>,
+    pub(crate) a0_mul_b1: FieldOpCols<T, P>,
+    pub(crate) numerator_c1: FieldOpCols<T, P>,
+    pub(crate) c0: FieldOpCols<T, P>,
+    pub(crate) c1: FieldOpCols<T, P>,
+}
+
+impl<F: PrimeField32, P: FieldParameters> Fp2AddSubCols<F, P> {
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        a: &Fp2<BigUint>,
+        b: &Fp2<BigUint>,
+        op: FieldOperation,
+    ) -> Fp2<BigUint> {
+        assert!(op == FieldOperation::Add || op == FieldOperation::Sub);
+        let c0 = self.c0.populate(record, shard, channel, &a[0], &b[0], op);
+        let c1 = self.c1.populate(record, shard, channel, &a[1], &b[1], op);
+        [c0, c1]
+    }
+}
+
+impl<F: PrimeField32, P: FieldParameters> Fp2MulCols<F, P> {
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        a: &Fp2<BigUint>,
+        b: &Fp2<BigUint>,
+    ) -> Fp2<BigUint> {
+        let a0_mul_b0 =
+            self.a0_mul_b0.populate(record, shard, channel, &a[0], &b[0], FieldOperation::Mul);
+        let a1_mul_b1 =
+            self.a1_mul_b1.populate(record, shard, channel, &a[1], &b[1], FieldOperation::Mul);
+        let c0 =
+            self.c0.populate(record, shard, channel, &a0_mul_b0, &a1_mul_b1, FieldOperation::Sub);
+        let c1 = self.c1.populate(
+            record,
+            shard,
+            channel,
+            &[a[0].clone(), a[1].clone()],
+            &[b[1].clone(), b[0].clone()],
+        );
+        [c0, c1]
+    }
+}
+
+impl<F: PrimeField32, P: FieldParameters> Fp2DivCols<F, P> {
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        a: &Fp2<BigUint>,
+        b: &Fp2<BigUint>,
+    ) -> Fp2<BigUint> {
+        let norm = self.norm.populate(record, shard, channel, b, b);
+        let numerator_c0 = self.numerator_c0.populate(record, shard, channel, a, b);
+        let a1_mul_b0 =
+            self.a1_mul_b0.populate(record, shard, channel, &a[1], &b[0], FieldOperation::Mul);
+        let a0_mul_b1 =
+            self.a0_mul_b1.populate(record, shard, channel, &a[0], &b[1], FieldOperation::Mul);
+        let numerator_c1 = self.numerator_c1.populate(
+            record,
+            shard,
+            channel,
+            &a1_mul_b0,
+            &a0_mul_b1,
+            FieldOperation::Sub,
+        );
+        let c0 =
+            self.c0.populate(record, shard, channel, &numerator_c0, &norm, FieldOperation::Div);
+        let c1 =
+            self.c1.populate(record, shard, channel, &numerator_c1, &norm, FieldOperation::Div);
+        [c0, c1]
+    }
+}
+
+impl<V: Copy, P: FieldParameters> Fp2AddSubCols<V, P>
+where
+    Limbs<V, P::Limbs>: Copy,
+{
+    /// The result of the operation.
+    pub fn result(&self) -> Fp2<Limbs<V, P::Limbs>> {
+        [self.c0.result, self.c1.result]
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn eval<AB: SP1AirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        a: &Fp2<Limbs<V, P::Limbs>>,
+        b: &Fp2<Limbs<V, P::Limbs>>,
+        op: FieldOperation,
+        shard: impl Into<AB::Expr> + Clone,
+        channel: impl Into<AB::Expr> + Clone,
+        is_real: impl Into<AB::Expr> + Clone,
+    ) where
+        V: Into<AB::Expr>,
+    {
+        self.c0.eval(builder, &a[0], &b[0], op, shard.clone(), channel.clone(), is_real.clone());
+        self.c1.eval(builder, &a[1], &b[1], op, shard, channel, is_real);
+    }
+}
+
+impl<V: Copy, P: FieldParameters> Fp2MulCols<V, P>
+where
+    Limbs<V, P::Limbs>: Copy,
+{
+    /// The result of the multiplication.
+    pub fn result(&self) -> Fp2<Limbs<V, P::Limbs>> {
+        [self.c0.result, self.c1.result]
+    }
+
+    pub fn eval<AB: SP1AirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        a: &Fp2<Limbs<V, P::Limbs>>,
+        b: &Fp2<Limbs<V, P::Limbs>>,
+        shard: impl Into<AB::Expr> + Clone,
+        channel: impl Into<AB::Expr> + Clone,
+        is_real: impl Into<AB::Expr> + Clone,
+    ) where
+        V: Into<AB::Expr>,
+    {
+        self.a0_mul_b0.eval(
+            builder,
+            &a[0],
+            &b[0],
+            FieldOperation::Mul,
+            shard.clone(),
+            channel.clone(),
+            is_real.clone(),
+        );
+        self.a1_mul_b1.eval(
+            builder,
+            &a[1],
+            &b[1],
+            FieldOperation::Mul,
+            shard.clone(),
+            channel.clone(),
+            is_real.clone(),
+        );
+        self.c0.eval(
+            builder,
+            &self.a0_mul_b0.result,
+            &self.a1_mul_b1.result,
+            FieldOperation::Sub,
+            shard.clone(),
+            channel.clone(),
+            is_real.clone(),
+        );
+        self.c1.eval(builder, &[a[0], a[1]], &[b[1], b[0]], shard, channel, is_real);
+    }
+}
+
+impl<V: Copy, P: FieldParameters> Fp2DivCols<V, P>
+where
+    Limbs<V, P::Limbs>: Copy,
+{
+    /// The result of the division.
+    pub fn result(&self) -> Fp2<Limbs<V, P::Limbs>> {
+        [self.c0.result, self.c1.result]
+    }
+
+    pub fn eval<AB: SP1AirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        a: &Fp2<Limbs<V, P::Limbs>>,
+        b: &Fp2<Limbs<V, P::Limbs>>,
+        shard: impl Into<AB::Expr> + Clone,
+        channel: impl Into<AB::Expr> + Clone,
+        is_real: impl Into<AB::Expr> + Clone,
+    ) where
+        V: Into<AB::Expr>,
+    {
+        self.norm.eval(builder, b, b, shard.clone(), channel.clone(), is_real.clone());
+        self.numerator_c0.eval(builder, a, b, shard.clone(), channel.clone(), is_real.clone());
+        self.a1_mul_b0.eval(
+            builder,
+            &a[1],
+            &b[0],
+            FieldOperation::Mul,
+            shard.clone(),
+            channel.clone(),
+            is_real.clone(),
+        );
+        self.a0_mul_b1.eval(
+            builder,
+            &a[0],
+            &b[1],
+            FieldOperation::Mul,
+            shard.clone(),
+            channel.clone(),
+            is_real.clone(),
+        );
+        self.numerator_c1.eval(
+            builder,
+            &self.a1_mul_b0.result,
+            &self.a0_mul_b1.result,
+            FieldOperation::Sub,
+            shard.clone(),
+            channel.clone(),
+            is_real.clone(),
+        );
+        self.c0.eval(
+            builder,
+            &self.numerator_c0.result,
+            &self.norm.result,
+            FieldOperation::Div,
+            shard.clone(),
+            channel.clone(),
+            is_real.clone(),
+        );
+        self.c1.eval(
+            builder,
+            &self.numerator_c1.result,
+            &self.norm.result,
+            FieldOperation::Div,
+            shard,
+            channel,
+            is_real,
+        );
+    }
+}
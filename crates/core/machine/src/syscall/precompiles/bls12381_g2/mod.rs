@@ -0,0 +1,89 @@
+mod add;
+mod double;
+mod fp2;
+
+pub use add::*;
+pub use double::*;
+pub use fp2::*;
+
+use sp1_curves::{
+    params::{Limbs, NumLimbs},
+    weierstrass::bls12_381::{Bls12381BaseField, BLS12381_G2_POINT_WORDS},
+};
+
+use crate::{memory::MemoryCols, utils::limbs_from_prev_access};
+
+/// The number of words of a coordinate of a G2 point over Fp.
+pub(crate) const NUM_WORDS_FIELD_ELEMENT: usize = BLS12381_G2_POINT_WORDS / 4;
+
+type Fp2Limbs<T> = Fp2<Limbs<T, <Bls12381BaseField as NumLimbs>::Limbs>>;
+
+/// Reads the coordinates `(x, y)` of a G2 point from the previous values of its memory accesses.
+pub(crate) fn g2_point_from_prev_access<T: Copy, M: MemoryCols<T>>(
+    access: &[M],
+) -> (Fp2Limbs<T>, Fp2Limbs<T>) {
+    let [x0, x1, y0, y1] = core::array::from_fn(|i| {
+        limbs_from_prev_access(
+            &access[i * NUM_WORDS_FIELD_ELEMENT..(i + 1) * NUM_WORDS_FIELD_ELEMENT],
+        )
+    });
+    ([x0, x1], [y0, y1])
+}
+
+#[cfg(test)]
+mod tests {
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Instruction, Opcode, Program};
+    use sp1_curves::weierstrass::bls12_381::{
+        bls12381_g2_add, bls12381_g2_double, bls12381_g2_generator, bls12381_g2_to_words_le,
+    };
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{run_test, setup_logger};
+
+    const P_PTR: u32 = 1000;
+    const Q_PTR: u32 = 2000;
+
+    /// Builds a program that writes the generator `g` to `p` and `q`, doubles `p`, then adds `q`
+    /// to it, leaving `3 * g` in `p`.
+    fn g2_program() -> Program {
+        let mut instructions = vec![];
+        let g = bls12381_g2_to_words_le(&bls12381_g2_generator());
+        for ptr in [P_PTR, Q_PTR] {
+            for (i, &word) in g.iter().enumerate() {
+                instructions.extend(vec![
+                    Instruction::new(Opcode::ADD, 29, 0, word, false, true),
+                    Instruction::new(Opcode::ADD, 30, 0, ptr + 4 * i as u32, false, true),
+                    Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+                ]);
+            }
+        }
+        for (code, arg2) in
+            [(SyscallCode::BLS12381_G2_DOUBLE, 0), (SyscallCode::BLS12381_G2_ADD, Q_PTR)]
+        {
+            instructions.extend(vec![
+                Instruction::new(Opcode::ADD, 5, 0, code as u32, false, true),
+                Instruction::new(Opcode::ADD, 10, 0, P_PTR, false, true),
+                Instruction::new(Opcode::ADD, 11, 0, arg2, false, true),
+                Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            ]);
+        }
+        Program::new(instructions, 0, 0)
+    }
+
+    #[test]
+    fn test_bls12381_g2_execute() {
+        let mut runtime = Executor::new(g2_program(), SP1CoreOpts::default());
+        runtime.run().unwrap();
+        let g = bls12381_g2_generator();
+        let expected = bls12381_g2_to_words_le(&bls12381_g2_add(&bls12381_g2_double(&g), &g));
+        for (i, word) in expected.into_iter().enumerate() {
+            assert_eq!(runtime.word(P_PTR + 4 * i as u32), word);
+        }
+    }
+
+    #[test]
+    fn test_bls12381_g2_prove() {
+        setup_logger();
+        run_test::<CpuProver<_, _>>(g2_program()).unwrap();
+    }
+}
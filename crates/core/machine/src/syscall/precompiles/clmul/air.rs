@@ -0,0 +1,293 @@
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
+    utils::pad_rows,
+};
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::ByteRecord,
+    syscalls::{clmul128, SyscallCode},
+    ExecutionRecord, Program,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{recompose_bits, MachineAir, SP1AirBuilder};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+/// The number of columns in the ClmulCols.
+const NUM_COLS: usize = size_of::<ClmulCols<u8>>();
+
+/// The number of words in each input.
+const INPUT_WORDS: usize = 4;
+
+/// The number of words in the product.
+const OUTPUT_WORDS: usize = 2 * INPUT_WORDS;
+
+/// The number of bits in each input.
+const INPUT_BITS: usize = 32 * INPUT_WORDS;
+
+/// The number of bits in the product. The top bit of the 256-bit output is always zero.
+const PRODUCT_BITS: usize = 2 * INPUT_BITS - 1;
+
+#[derive(Default)]
+pub struct ClmulChip;
+
+impl ClmulChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the Clmul operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct ClmulCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The byte lookup channel.
+    pub channel: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the first input, which is overwritten with the product.
+    pub x_ptr: T,
+
+    /// The pointer to the second input.
+    pub y_ptr: T,
+
+    // Memory columns.
+    // x_memory holds x in the "prev_value" of its low half and is written to with the product,
+    // which is why it is of type MemoryWriteCols.
+    pub x_memory: [MemoryWriteCols<T>; OUTPUT_WORDS],
+    pub y_memory: [MemoryReadCols<T>; INPUT_WORDS],
+
+    /// The little endian bits of x.
+    pub x_bits: [T; INPUT_BITS],
+
+    /// The little endian bits of y.
+    pub y_bits: [T; INPUT_BITS],
+
+    /// The little endian bits of the product.
+    pub product_bits: [T; PRODUCT_BITS],
+
+    /// For each product bit `k`, the sum of `x_i * y_j` over `i + j = k` is
+    /// `2 * carries[k] + product_bits[k]`.
+    pub carries: [T; PRODUCT_BITS],
+
+    pub is_real: T,
+}
+
+/// Returns the bits of the given words in little endian order.
+fn words_to_bits(words: &[u32]) -> Vec<u32> {
+    words.iter().flat_map(|word| (0..32).map(move |i| (word >> i) & 1)).collect()
+}
+
+impl<F: PrimeField32> MachineAir<F> for ClmulChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Clmul".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut new_byte_lookup_events = Vec::new();
+        let mut rows = input
+            .clmul_events
+            .iter()
+            .map(|event| {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut ClmulCols<F> = row.as_mut_slice().borrow_mut();
+
+                // Assign basic values to the columns.
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.channel = F::from_canonical_u8(event.channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.x_ptr = F::from_canonical_u32(event.x_ptr);
+                cols.y_ptr = F::from_canonical_u32(event.y_ptr);
+
+                // Populate memory columns.
+                for i in 0..OUTPUT_WORDS {
+                    cols.x_memory[i].populate(
+                        event.channel,
+                        event.x_memory_records[i],
+                        &mut new_byte_lookup_events,
+                    );
+                }
+                for i in 0..INPUT_WORDS {
+                    cols.y_memory[i].populate(
+                        event.channel,
+                        event.y_memory_records[i],
+                        &mut new_byte_lookup_events,
+                    );
+                }
+
+                // Populate the bits of the inputs and the product, and the carries of the
+                // bitwise sums.
+                let x_bits = words_to_bits(&event.x);
+                let y_bits = words_to_bits(&event.y);
+                let product = clmul128(
+                    &event.x.clone().try_into().unwrap(),
+                    &event.y.clone().try_into().unwrap(),
+                );
+                let product_bits = words_to_bits(&product);
+                let mut carries = Vec::with_capacity(PRODUCT_BITS);
+                for k in 0..PRODUCT_BITS {
+                    let sum = (k.saturating_sub(INPUT_BITS - 1)..=k.min(INPUT_BITS - 1))
+                        .map(|i| x_bits[i] * y_bits[k - i])
+                        .sum::<u32>();
+                    debug_assert_eq!(sum % 2, product_bits[k]);
+                    cols.product_bits[k] = F::from_canonical_u32(product_bits[k]);
+                    cols.carries[k] = F::from_canonical_u32(sum / 2);
+                    carries.push((sum / 2) as u8);
+                }
+                for i in 0..INPUT_BITS {
+                    cols.x_bits[i] = F::from_canonical_u32(x_bits[i]);
+                    cols.y_bits[i] = F::from_canonical_u32(y_bits[i]);
+                }
+                new_byte_lookup_events.add_u8_range_checks(event.shard, event.channel, &carries);
+
+                row
+            })
+            .collect::<Vec<_>>();
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows(&mut rows, || [F::zero(); NUM_COLS]);
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut ClmulCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.clmul_events.is_empty()
+    }
+}
+
+impl<F> BaseAir<F> for ClmulChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for ClmulChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &ClmulCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &ClmulCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // Assert that all the bits are booleans.
+        for bit in local.x_bits.iter().chain(local.y_bits.iter()).chain(local.product_bits.iter()) {
+            builder.assert_bool(*bit);
+        }
+
+        // Assert that the bits of x match the "prev_value" of the low half of x_memory, since we
+        // write the product to it later, and that the bits of y match y_memory.
+        for i in 0..INPUT_WORDS {
+            let x_word = local.x_memory[i].prev_value();
+            let y_word = local.y_memory[i].value();
+            for j in 0..4 {
+                let bits = 32 * i + 8 * j..32 * i + 8 * j + 8;
+                builder.assert_eq(x_word[j], bits_to_byte::<AB>(&local.x_bits[bits.clone()]));
+                builder.assert_eq(y_word[j], bits_to_byte::<AB>(&local.y_bits[bits]));
+            }
+        }
+
+        // Assert that each product bit is the parity of the sum of the partial products of its
+        // degree. The carries are range checked to be bytes, so the sum can not wrap around the
+        // field.
+        for k in 0..PRODUCT_BITS {
+            let sum = (k.saturating_sub(INPUT_BITS - 1)..=k.min(INPUT_BITS - 1))
+                .map(|i| local.x_bits[i] * local.y_bits[k - i])
+                .sum::<AB::Expr>();
+            builder.assert_eq(sum, local.carries[k] * AB::F::two() + local.product_bits[k]);
+        }
+        builder.slice_range_check_u8(&local.carries, local.shard, local.channel, local.is_real);
+
+        // Assert that the product is written to x_memory. The top bit of the product is zero.
+        for i in 0..OUTPUT_WORDS {
+            let product_word = local.x_memory[i].value();
+            for j in 0..4 {
+                let start = 32 * i + 8 * j;
+                let end = (start + 8).min(PRODUCT_BITS);
+                builder.when(local.is_real).assert_eq(
+                    product_word[j],
+                    bits_to_byte::<AB>(&local.product_bits[start..end]),
+                );
+            }
+        }
+
+        // Read and write x.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into() + AB::Expr::one(),
+            local.x_ptr,
+            &local.x_memory,
+            local.is_real,
+        );
+
+        // Read y.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into(),
+            local.y_ptr,
+            &local.y_memory,
+            local.is_real,
+        );
+
+        // Receive the arguments.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::CLMUL.syscall_id()),
+            local.x_ptr,
+            local.y_ptr,
+            local.is_real,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
+
+/// Recomposes a byte from its little endian bits.
+fn bits_to_byte<AB: SP1AirBuilder>(bits: &[AB::Var]) -> AB::Expr {
+    recompose_bits::<AB, _>(bits.iter().copied())
+}
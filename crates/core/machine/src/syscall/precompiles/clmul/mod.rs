@@ -0,0 +1,57 @@
+mod air;
+
+pub use air::*;
+
+#[cfg(test)]
+pub mod clmul_tests {
+    use sp1_core_executor::{
+        syscalls::{clmul128, SyscallCode},
+        Executor, Instruction, Opcode, Program,
+    };
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{self, run_test};
+
+    /// Builds a program that computes the carry-less product of `x` and `y` in place of `x`.
+    pub fn clmul_program(x: [u32; 4], y: [u32; 4]) -> Program {
+        let x_ptr = 100;
+        let y_ptr = 200;
+        let mut instructions = vec![];
+        for (ptr, words) in [(x_ptr, x), (y_ptr, y)] {
+            for (i, word) in words.into_iter().enumerate() {
+                instructions.extend(vec![
+                    Instruction::new(Opcode::ADD, 29, 0, word, false, true),
+                    Instruction::new(Opcode::ADD, 30, 0, ptr + 4 * i as u32, false, true),
+                    Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+                ]);
+            }
+        }
+        instructions.extend(vec![
+            Instruction::new(Opcode::ADD, 5, 0, SyscallCode::CLMUL as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, x_ptr, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, y_ptr, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ]);
+        Program::new(instructions, 0, 0)
+    }
+
+    #[test]
+    fn test_clmul_execute() {
+        let x = [0xdead_beef, 0x0123_4567, 0x89ab_cdef, 0xffff_ffff];
+        let y = [0xcafe_babe, 0x7654_3210, 0xfedc_ba98, 0x8000_0001];
+        let mut runtime = Executor::new(clmul_program(x, y), SP1CoreOpts::default());
+        runtime.run().unwrap();
+        let product = clmul128(&x, &y);
+        for (i, word) in product.iter().enumerate() {
+            assert_eq!(runtime.word(100 + 4 * i as u32), *word);
+        }
+    }
+
+    #[test]
+    fn test_clmul_prove() {
+        utils::setup_logger();
+        let x = [0xdead_beef, 0x0123_4567, 0x89ab_cdef, 0xffff_ffff];
+        let y = [0xcafe_babe, 0x7654_3210, 0xfedc_ba98, 0x8000_0001];
+        run_test::<CpuProver<_, _>>(clmul_program(x, y)).unwrap();
+    }
+}
@@ -0,0 +1,316 @@
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::{MemoryCols, MemoryReadWriteCols},
+    utils::pad_rows,
+};
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, Codec, CodecOperation},
+    syscalls::SyscallCode,
+    ByteOpcode, ExecutionRecord, Program,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{recompose_bits, MachineAir, SP1AirBuilder};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+use super::words_to_bytes;
+
+/// The number of columns in the Base64Cols.
+const NUM_COLS: usize = size_of::<Base64Cols<u8>>();
+
+/// The number of words of binary data in a block.
+const BINARY_WORDS: usize = Codec::Base64.binary_words();
+
+/// The number of words of text in a block.
+const TEXT_WORDS: usize = Codec::Base64.text_words();
+
+/// The number of bytes of binary data in a block.
+const NUM_BYTES: usize = 4 * BINARY_WORDS;
+
+#[derive(Default)]
+pub struct Base64Chip;
+
+impl Base64Chip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for encoding a block of binary data to base64, or decoding it back.
+///
+/// The binary data is read and the text written when encoding, and the other way around when
+/// decoding. Every three bytes are split into four 6-bit values, and each character is looked up
+/// in the byte table from the value it encodes.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Base64Cols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The byte lookup channel.
+    pub channel: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the output.
+    pub out_ptr: T,
+
+    /// The pointer to the input.
+    pub in_ptr: T,
+
+    /// The pointer to the binary data, the input when encoding and the output when decoding.
+    pub binary_ptr: T,
+
+    /// The pointer to the text, the output when encoding and the input when decoding.
+    pub text_ptr: T,
+
+    // Memory columns. The input is read with a write of its own value.
+    pub binary_memory: [MemoryReadWriteCols<T>; BINARY_WORDS],
+    pub text_memory: [MemoryReadWriteCols<T>; TEXT_WORDS],
+
+    /// The little endian bits of the binary data.
+    pub bits: [[T; 8]; NUM_BYTES],
+
+    /// Whether the operation decodes the text.
+    pub is_decode: T,
+
+    /// Whether the text uses the URL-safe alphabet.
+    pub is_url: T,
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for Base64Chip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Base64".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut new_byte_lookup_events = Vec::new();
+        let mut rows = input
+            .base64_events
+            .iter()
+            .map(|event| {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut Base64Cols<F> = row.as_mut_slice().borrow_mut();
+
+                // Assign basic values to the columns.
+                let is_decode = event.op == CodecOperation::Decode;
+                cols.is_real = F::one();
+                cols.is_decode = F::from_bool(is_decode);
+                let is_url = event.codec == Codec::Base64Url;
+                cols.is_url = F::from_bool(is_url);
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.channel = F::from_canonical_u8(event.channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.out_ptr = F::from_canonical_u32(event.out_ptr);
+                cols.in_ptr = F::from_canonical_u32(event.in_ptr);
+                let (binary_ptr, text_ptr) = if is_decode {
+                    (event.out_ptr, event.in_ptr)
+                } else {
+                    (event.in_ptr, event.out_ptr)
+                };
+                cols.binary_ptr = F::from_canonical_u32(binary_ptr);
+                cols.text_ptr = F::from_canonical_u32(text_ptr);
+
+                // Populate memory columns.
+                for i in 0..BINARY_WORDS {
+                    cols.binary_memory[i].populate(
+                        event.channel,
+                        event.binary_memory_records[i],
+                        &mut new_byte_lookup_events,
+                    );
+                }
+                for i in 0..TEXT_WORDS {
+                    cols.text_memory[i].populate(
+                        event.channel,
+                        event.text_memory_records[i],
+                        &mut new_byte_lookup_events,
+                    );
+                }
+
+                let bytes = words_to_bytes(&event.binary);
+                let chars = words_to_bytes(&event.text);
+                for (i, byte) in bytes.iter().enumerate() {
+                    for j in 0..8 {
+                        cols.bits[i][j] = F::from_canonical_u8((byte >> j) & 1);
+                    }
+                }
+                for (quantum, x) in bytes.chunks_exact(3).enumerate() {
+                    let values = [
+                        x[0] >> 2,
+                        ((x[0] & 3) << 4) | (x[1] >> 4),
+                        ((x[1] & 0xf) << 2) | (x[2] >> 6),
+                        x[2] & 0x3f,
+                    ];
+                    for (k, value) in values.into_iter().enumerate() {
+                        new_byte_lookup_events.add_byte_lookup_event(ByteLookupEvent::new(
+                            event.shard,
+                            event.channel,
+                            ByteOpcode::Base64Char,
+                            chars[4 * quantum + k] as u16,
+                            0,
+                            value,
+                            is_url as u8,
+                        ));
+                    }
+                }
+
+                row
+            })
+            .collect::<Vec<_>>();
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows(&mut rows, || [F::zero(); NUM_COLS]);
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Base64Cols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.base64_events.is_empty()
+    }
+}
+
+impl<F> BaseAir<F> for Base64Chip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for Base64Chip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Base64Cols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &Base64Cols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_decode);
+        builder.assert_bool(local.is_url);
+        builder.when_not(local.is_real).assert_zero(local.is_decode);
+        builder.when_not(local.is_real).assert_zero(local.is_url);
+        let is_encode: AB::Expr = local.is_real - local.is_decode;
+
+        // The binary data is the input when encoding, and the text the input when decoding.
+        builder.assert_eq(
+            local.binary_ptr,
+            local.in_ptr + local.is_decode * (local.out_ptr - local.in_ptr),
+        );
+        builder.assert_eq(
+            local.text_ptr,
+            local.out_ptr + local.is_decode * (local.in_ptr - local.out_ptr),
+        );
+
+        // The input is left unchanged.
+        for access in local.binary_memory.iter() {
+            builder.when(is_encode.clone()).assert_word_eq(*access.prev_value(), *access.value());
+        }
+        for access in local.text_memory.iter() {
+            builder.when(local.is_decode).assert_word_eq(*access.prev_value(), *access.value());
+        }
+        let bytes =
+            local.binary_memory.iter().flat_map(|access| access.value().0).collect::<Vec<_>>();
+        let chars =
+            local.text_memory.iter().flat_map(|access| access.value().0).collect::<Vec<_>>();
+
+        // Every three bytes are split into four 6-bit values, most significant bits first, each
+        // encoded by a character of the alphabet.
+        for (byte, bits) in bytes.iter().zip(local.bits.iter()) {
+            builder.assert_byte_decomposition(*byte, bits);
+        }
+        for (quantum, x) in local.bits.chunks_exact(3).enumerate() {
+            let values = [
+                x[0][2..8].to_vec(),
+                [&x[1][4..8], &x[0][0..2]].concat(),
+                [&x[2][6..8], &x[1][0..4]].concat(),
+                x[2][0..6].to_vec(),
+            ];
+            for (k, value) in values.into_iter().enumerate() {
+                builder.send_byte(
+                    ByteOpcode::Base64Char.as_field::<AB::F>(),
+                    chars[4 * quantum + k],
+                    recompose_bits::<AB, _>(value),
+                    local.is_url,
+                    local.shard,
+                    local.channel,
+                    local.is_real,
+                );
+            }
+        }
+
+        // Access the binary data, which is read at clk or written at clk + 1.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk + local.is_decode,
+            local.binary_ptr,
+            &local.binary_memory,
+            local.is_real,
+        );
+
+        // Access the text, which is written at clk + 1 or read at clk.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk + AB::Expr::one() - local.is_decode,
+            local.text_ptr,
+            &local.text_memory,
+            local.is_real,
+        );
+
+        // Receive the arguments. The syscall ids are laid out so that the URL-safe decoding is
+        // the sum of the offsets of the URL-safe encoding and of the standard decoding.
+        let encode_id = SyscallCode::BASE64_ENCODE.syscall_id();
+        let url_encode_id = SyscallCode::BASE64_URL_ENCODE.syscall_id();
+        let decode_id = SyscallCode::BASE64_DECODE.syscall_id();
+        let syscall_id = AB::Expr::from_canonical_u32(encode_id)
+            + local.is_url * AB::F::from_canonical_u32(url_encode_id - encode_id)
+            + local.is_decode * AB::F::from_canonical_u32(decode_id - encode_id);
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            syscall_id,
+            local.out_ptr,
+            local.in_ptr,
+            local.is_real,
+        );
+    }
+}
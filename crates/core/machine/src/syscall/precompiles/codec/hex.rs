@@ -0,0 +1,304 @@
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::{MemoryCols, MemoryReadWriteCols},
+    utils::pad_rows,
+};
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, Codec, CodecOperation},
+    syscalls::{hex_value, SyscallCode},
+    ByteOpcode, ExecutionRecord, Program,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{recompose_bits, MachineAir, SP1AirBuilder};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+use super::words_to_bytes;
+
+/// The number of columns in the HexCols.
+const NUM_COLS: usize = size_of::<HexCols<u8>>();
+
+/// The number of words of binary data in a block.
+const BINARY_WORDS: usize = Codec::Hex.binary_words();
+
+/// The number of words of text in a block.
+const TEXT_WORDS: usize = Codec::Hex.text_words();
+
+/// The number of bytes of binary data in a block.
+const NUM_BYTES: usize = 4 * BINARY_WORDS;
+
+/// The number of characters in a block.
+const NUM_CHARS: usize = 4 * TEXT_WORDS;
+
+#[derive(Default)]
+pub struct HexChip;
+
+impl HexChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for encoding a block of binary data to hexadecimal, or decoding it back.
+///
+/// The binary data is read and the text written when encoding, and the other way around when
+/// decoding. Each character is looked up in the byte table from the nibble it encodes.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct HexCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The byte lookup channel.
+    pub channel: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the output.
+    pub out_ptr: T,
+
+    /// The pointer to the input.
+    pub in_ptr: T,
+
+    /// The pointer to the binary data, the input when encoding and the output when decoding.
+    pub binary_ptr: T,
+
+    /// The pointer to the text, the output when encoding and the input when decoding.
+    pub text_ptr: T,
+
+    // Memory columns. The input is read with a write of its own value.
+    pub binary_memory: [MemoryReadWriteCols<T>; BINARY_WORDS],
+    pub text_memory: [MemoryReadWriteCols<T>; TEXT_WORDS],
+
+    /// The little endian bits of the binary data.
+    pub bits: [[T; 8]; NUM_BYTES],
+
+    /// Whether each character is an uppercase digit, which is only allowed when decoding.
+    pub upper: [T; NUM_CHARS],
+
+    /// Whether the operation decodes the text.
+    pub is_decode: T,
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for HexChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Hex".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut new_byte_lookup_events = Vec::new();
+        let mut rows = input
+            .hex_events
+            .iter()
+            .map(|event| {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut HexCols<F> = row.as_mut_slice().borrow_mut();
+
+                // Assign basic values to the columns.
+                let is_decode = event.op == CodecOperation::Decode;
+                cols.is_real = F::one();
+                cols.is_decode = F::from_bool(is_decode);
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.channel = F::from_canonical_u8(event.channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.out_ptr = F::from_canonical_u32(event.out_ptr);
+                cols.in_ptr = F::from_canonical_u32(event.in_ptr);
+                let (binary_ptr, text_ptr) = if is_decode {
+                    (event.out_ptr, event.in_ptr)
+                } else {
+                    (event.in_ptr, event.out_ptr)
+                };
+                cols.binary_ptr = F::from_canonical_u32(binary_ptr);
+                cols.text_ptr = F::from_canonical_u32(text_ptr);
+
+                // Populate memory columns.
+                for i in 0..BINARY_WORDS {
+                    cols.binary_memory[i].populate(
+                        event.channel,
+                        event.binary_memory_records[i],
+                        &mut new_byte_lookup_events,
+                    );
+                }
+                for i in 0..TEXT_WORDS {
+                    cols.text_memory[i].populate(
+                        event.channel,
+                        event.text_memory_records[i],
+                        &mut new_byte_lookup_events,
+                    );
+                }
+
+                let bytes = words_to_bytes(&event.binary);
+                let chars = words_to_bytes(&event.text);
+                for (i, byte) in bytes.iter().enumerate() {
+                    for j in 0..8 {
+                        cols.bits[i][j] = F::from_canonical_u8((byte >> j) & 1);
+                    }
+                    for (k, nibble) in [byte >> 4, byte & 0xf].into_iter().enumerate() {
+                        let char = chars[2 * i + k];
+                        let (_, upper) = hex_value(char).expect("invalid hex digit");
+                        cols.upper[2 * i + k] = F::from_bool(upper);
+                        new_byte_lookup_events.add_byte_lookup_event(ByteLookupEvent::new(
+                            event.shard,
+                            event.channel,
+                            ByteOpcode::HexChar,
+                            char as u16,
+                            0,
+                            nibble,
+                            upper as u8,
+                        ));
+                    }
+                }
+
+                row
+            })
+            .collect::<Vec<_>>();
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows(&mut rows, || [F::zero(); NUM_COLS]);
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut HexCols<F> = trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.hex_events.is_empty()
+    }
+}
+
+impl<F> BaseAir<F> for HexChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for HexChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &HexCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &HexCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_decode);
+        builder.when_not(local.is_real).assert_zero(local.is_decode);
+        let is_encode: AB::Expr = local.is_real - local.is_decode;
+
+        // The binary data is the input when encoding, and the text the input when decoding.
+        builder.assert_eq(
+            local.binary_ptr,
+            local.in_ptr + local.is_decode * (local.out_ptr - local.in_ptr),
+        );
+        builder.assert_eq(
+            local.text_ptr,
+            local.out_ptr + local.is_decode * (local.in_ptr - local.out_ptr),
+        );
+
+        // The input is left unchanged.
+        for access in local.binary_memory.iter() {
+            builder.when(is_encode.clone()).assert_word_eq(*access.prev_value(), *access.value());
+        }
+        for access in local.text_memory.iter() {
+            builder.when(local.is_decode).assert_word_eq(*access.prev_value(), *access.value());
+        }
+        let bytes =
+            local.binary_memory.iter().flat_map(|access| access.value().0).collect::<Vec<_>>();
+        let chars =
+            local.text_memory.iter().flat_map(|access| access.value().0).collect::<Vec<_>>();
+
+        // Each byte is encoded by the digits of its high and low nibbles.
+        for (i, (byte, bits)) in bytes.iter().zip(local.bits.iter()).enumerate() {
+            builder.assert_byte_decomposition(*byte, bits);
+            let high = recompose_bits::<AB, _>(bits[4..8].iter().copied());
+            let low = recompose_bits::<AB, _>(bits[0..4].iter().copied());
+            for (k, nibble) in [high, low].into_iter().enumerate() {
+                builder.send_byte(
+                    ByteOpcode::HexChar.as_field::<AB::F>(),
+                    chars[2 * i + k],
+                    nibble,
+                    local.upper[2 * i + k],
+                    local.shard,
+                    local.channel,
+                    local.is_real,
+                );
+            }
+        }
+
+        // The encoding is lowercase, while the decoding accepts both cases.
+        for upper in local.upper {
+            builder.assert_bool(upper);
+            builder.when(is_encode.clone()).assert_zero(upper);
+        }
+
+        // Access the binary data, which is read at clk or written at clk + 1.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk + local.is_decode,
+            local.binary_ptr,
+            &local.binary_memory,
+            local.is_real,
+        );
+
+        // Access the text, which is written at clk + 1 or read at clk.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk + AB::Expr::one() - local.is_decode,
+            local.text_ptr,
+            &local.text_memory,
+            local.is_real,
+        );
+
+        // Receive the arguments.
+        let encode_id = SyscallCode::HEX_ENCODE.syscall_id();
+        let decode_id = SyscallCode::HEX_DECODE.syscall_id();
+        let syscall_id = AB::Expr::from_canonical_u32(encode_id)
+            + local.is_decode * AB::F::from_canonical_u32(decode_id - encode_id);
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            syscall_id,
+            local.out_ptr,
+            local.in_ptr,
+            local.is_real,
+        );
+    }
+}
@@ -0,0 +1,129 @@
+mod base64;
+mod hex;
+
+pub use base64::*;
+pub use hex::*;
+
+/// Returns the little endian bytes of the given words.
+fn words_to_bytes(words: &[u32]) -> Vec<u8> {
+    words.iter().flat_map(|word| word.to_le_bytes()).collect()
+}
+
+#[cfg(test)]
+pub mod codec_tests {
+    use sp1_core_executor::{
+        events::{Codec, CodecOperation},
+        syscalls::{codec_decode, codec_encode, SyscallCode},
+        ExecutionError, Executor, Instruction, Opcode, Program,
+    };
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use super::words_to_bytes;
+    use crate::utils::{self, run_test};
+
+    const OPS: [(SyscallCode, Codec, CodecOperation); 6] = [
+        (SyscallCode::HEX_ENCODE, Codec::Hex, CodecOperation::Encode),
+        (SyscallCode::HEX_DECODE, Codec::Hex, CodecOperation::Decode),
+        (SyscallCode::BASE64_ENCODE, Codec::Base64, CodecOperation::Encode),
+        (SyscallCode::BASE64_URL_ENCODE, Codec::Base64Url, CodecOperation::Encode),
+        (SyscallCode::BASE64_DECODE, Codec::Base64, CodecOperation::Decode),
+        (SyscallCode::BASE64_URL_DECODE, Codec::Base64Url, CodecOperation::Decode),
+    ];
+
+    /// Converts bytes to little endian words, padding them with zeros.
+    fn bytes_to_words(bytes: &[u8]) -> Vec<u32> {
+        bytes
+            .chunks(4)
+            .map(|chunk| {
+                let mut word = [0u8; 4];
+                word[..chunk.len()].copy_from_slice(chunk);
+                u32::from_le_bytes(word)
+            })
+            .collect()
+    }
+
+    /// Builds a program that calls each syscall on its input, stored at `1000 + 100 * i`, and
+    /// writes the output to `5000 + 100 * i`.
+    pub fn codec_program(ops: &[(SyscallCode, &[u8])]) -> Program {
+        let mut instructions = vec![];
+        for (i, (_, input)) in ops.iter().enumerate() {
+            for (j, word) in bytes_to_words(input).into_iter().enumerate() {
+                instructions.extend(vec![
+                    Instruction::new(Opcode::ADD, 29, 0, word, false, true),
+                    Instruction::new(Opcode::ADD, 30, 0, 1000 + 100 * i as u32, false, true),
+                    Instruction::new(Opcode::SW, 29, 30, 4 * j as u32, false, true),
+                ]);
+            }
+        }
+        for (i, (code, _)) in ops.iter().enumerate() {
+            instructions.extend(vec![
+                Instruction::new(Opcode::ADD, 5, 0, *code as u32, false, true),
+                Instruction::new(Opcode::ADD, 10, 0, 5000 + 100 * i as u32, false, true),
+                Instruction::new(Opcode::ADD, 11, 0, 1000 + 100 * i as u32, false, true),
+                Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            ]);
+        }
+        Program::new(instructions, 0, 0)
+    }
+
+    /// Returns an input of the given syscall, built from the given block of binary data.
+    fn input(codec: Codec, op: CodecOperation, bytes: &[u8]) -> Vec<u8> {
+        let bytes = &bytes[..4 * codec.binary_words()];
+        match op {
+            CodecOperation::Encode => bytes.to_vec(),
+            CodecOperation::Decode => codec_encode(codec, bytes),
+        }
+    }
+
+    #[test]
+    fn test_codec_execute() {
+        let bytes = [0x00, 0xfb, 0xff, 0x12, 0x9a, 0x3e, 0x7f, 0x80, 0x01, 0xc3, 0x5d, 0xe4];
+        let inputs = OPS.map(|(_, codec, op)| input(codec, op, &bytes));
+        let ops = OPS.iter().zip(&inputs).map(|((code, ..), input)| (*code, &input[..]));
+        let mut runtime =
+            Executor::new(codec_program(&ops.collect::<Vec<_>>()), SP1CoreOpts::default());
+        runtime.run().unwrap();
+        for (i, ((_, codec, op), input)) in OPS.iter().zip(&inputs).enumerate() {
+            let expected = match op {
+                CodecOperation::Encode => codec_encode(*codec, input),
+                CodecOperation::Decode => codec_decode(*codec, input).unwrap(),
+            };
+            let output = (0..expected.len() as u32 / 4)
+                .map(|j| runtime.word(5000 + 100 * i as u32 + 4 * j))
+                .collect::<Vec<_>>();
+            assert_eq!(words_to_bytes(&output), expected, "{codec:?} {op:?}");
+        }
+    }
+
+    #[test]
+    fn test_hex_decode_mixed_case() {
+        let program = codec_program(&[(SyscallCode::HEX_DECODE, b"0aFb9C3e")]);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+        assert_eq!(runtime.word(5000).to_le_bytes(), [0x0a, 0xfb, 0x9c, 0x3e]);
+    }
+
+    #[test]
+    fn test_base64_decode_invalid() {
+        let program = codec_program(&[(SyscallCode::BASE64_DECODE, b"APv_Epo+AAAAAAAA")]);
+        let result = Executor::new(program, SP1CoreOpts::default()).run();
+        assert!(matches!(
+            result,
+            Err(ExecutionError::InvalidSyscallArguments {
+                syscall: SyscallCode::BASE64_DECODE,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_codec_prove() {
+        utils::setup_logger();
+        let bytes = [0x00, 0xfb, 0xff, 0x12, 0x9a, 0x3e, 0x7f, 0x80, 0x01, 0xc3, 0x5d, 0xe4];
+        let inputs = OPS.map(|(_, codec, op)| input(codec, op, &bytes));
+        let ops = OPS.iter().zip(&inputs).map(|((code, ..), input)| (*code, &input[..]));
+        run_test::<CpuProver<_, _>>(codec_program(&ops.collect::<Vec<_>>())).unwrap();
+        run_test::<CpuProver<_, _>>(codec_program(&[(SyscallCode::HEX_DECODE, b"0aFb9C3e")]))
+            .unwrap();
+    }
+}
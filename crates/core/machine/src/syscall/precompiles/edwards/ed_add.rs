@@ -19,9 +19,9 @@ use sp1_core_executor::{
     ExecutionRecord, Program,
 };
 use sp1_curves::{
-    edwards::{ed25519::Ed25519BaseField, EdwardsParameters, NUM_LIMBS, WORDS_CURVE_POINT},
-    params::FieldParameters,
-    AffinePoint, EllipticCurve,
+    edwards::{EdwardsParameters, NUM_LIMBS, WORDS_CURVE_POINT},
+    params::{FieldParameters, NumLimbs},
+    AffinePoint, CurveType, EllipticCurve,
 };
 use sp1_derive::AlignedBorrow;
 use sp1_stark::air::{BaseAirBuilder, MachineAir, SP1AirBuilder};
@@ -34,14 +34,16 @@ use crate::{
     utils::{limbs_from_prev_access, pad_rows},
 };
 
-pub const NUM_ED_ADD_COLS: usize = size_of::<EdAddAssignCols<u8>>();
+pub const fn num_ed_add_cols<P: FieldParameters + NumLimbs>() -> usize {
+    size_of::<EdAddAssignCols<u8, P>>()
+}
 
 /// A set of columns to compute `EdAdd` where a, b are field elements.
-/// Right now the number of limbs is assumed to be a constant, although this could be macro-ed
-/// or made generic in the future.
+///
+/// The base field must have 32-byte elements, like those of Ed25519 and Baby Jubjub.
 #[derive(Debug, Clone, AlignedBorrow)]
 #[repr(C)]
-pub struct EdAddAssignCols<T> {
+pub struct EdAddAssignCols<T, P: FieldParameters + NumLimbs> {
     pub is_real: T,
     pub shard: T,
     pub channel: T,
@@ -51,14 +53,14 @@ pub struct EdAddAssignCols<T> {
     pub q_ptr: T,
     pub p_access: [MemoryWriteCols<T>; WORDS_CURVE_POINT],
     pub q_access: [MemoryReadCols<T>; WORDS_CURVE_POINT],
-    pub(crate) x3_numerator: FieldInnerProductCols<T, Ed25519BaseField>,
-    pub(crate) y3_numerator: FieldInnerProductCols<T, Ed25519BaseField>,
-    pub(crate) x1_mul_y1: FieldOpCols<T, Ed25519BaseField>,
-    pub(crate) x2_mul_y2: FieldOpCols<T, Ed25519BaseField>,
-    pub(crate) f: FieldOpCols<T, Ed25519BaseField>,
-    pub(crate) d_mul_f: FieldOpCols<T, Ed25519BaseField>,
-    pub(crate) x3_ins: FieldDenCols<T, Ed25519BaseField>,
-    pub(crate) y3_ins: FieldDenCols<T, Ed25519BaseField>,
+    pub(crate) x3_numerator: FieldInnerProductCols<T, P>,
+    pub(crate) y3_numerator: FieldInnerProductCols<T, P>,
+    pub(crate) x1_mul_y1: FieldOpCols<T, P>,
+    pub(crate) x2_mul_y2: FieldOpCols<T, P>,
+    pub(crate) f: FieldOpCols<T, P>,
+    pub(crate) d_mul_f: FieldOpCols<T, P>,
+    pub(crate) x3_ins: FieldDenCols<T, P>,
+    pub(crate) y3_ins: FieldDenCols<T, P>,
 }
 
 #[derive(Default)]
@@ -76,7 +78,7 @@ impl<E: EllipticCurve + EdwardsParameters> EdAddAssignChip<E> {
         record: &mut impl ByteRecord,
         shard: u32,
         channel: u8,
-        cols: &mut EdAddAssignCols<F>,
+        cols: &mut EdAddAssignCols<F, E::BaseField>,
         p_x: BigUint,
         p_y: BigUint,
         q_x: BigUint,
@@ -117,7 +119,11 @@ impl<F: PrimeField32, E: EllipticCurve + EdwardsParameters> MachineAir<F> for Ed
     type Program = Program;
 
     fn name(&self) -> String {
-        "EdAddAssign".to_string()
+        match E::CURVE_TYPE {
+            CurveType::Ed25519 => "EdAddAssign".to_string(),
+            CurveType::BabyJubjub => "BabyJubjubAddAssign".to_string(),
+            _ => panic!("Unsupported curve"),
+        }
     }
 
     fn generate_trace(
@@ -125,12 +131,12 @@ impl<F: PrimeField32, E: EllipticCurve + EdwardsParameters> MachineAir<F> for Ed
         input: &ExecutionRecord,
         _: &mut ExecutionRecord,
     ) -> RowMajorMatrix<F> {
-        let mut rows = input
-            .ed_add_events
+        let num_cols = num_ed_add_cols::<E::BaseField>();
+        let mut rows = Self::events(input)
             .par_iter()
             .map(|event| {
-                let mut row = [F::zero(); NUM_ED_ADD_COLS];
-                let cols: &mut EdAddAssignCols<F> = row.as_mut_slice().borrow_mut();
+                let mut row = vec![F::zero(); num_cols];
+                let cols: &mut EdAddAssignCols<F, E::BaseField> = row.as_mut_slice().borrow_mut();
                 let mut blu = Vec::new();
                 self.event_to_row(event, cols, &mut blu);
                 row
@@ -138,8 +144,8 @@ impl<F: PrimeField32, E: EllipticCurve + EdwardsParameters> MachineAir<F> for Ed
             .collect::<Vec<_>>();
 
         pad_rows(&mut rows, || {
-            let mut row = [F::zero(); NUM_ED_ADD_COLS];
-            let cols: &mut EdAddAssignCols<F> = row.as_mut_slice().borrow_mut();
+            let mut row = vec![F::zero(); num_cols];
+            let cols: &mut EdAddAssignCols<F, E::BaseField> = row.as_mut_slice().borrow_mut();
             let zero = BigUint::zero();
             Self::populate_field_ops(
                 &mut vec![],
@@ -156,12 +162,12 @@ impl<F: PrimeField32, E: EllipticCurve + EdwardsParameters> MachineAir<F> for Ed
 
         // Convert the trace to a row major matrix.
         let mut trace =
-            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_ED_ADD_COLS);
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), num_cols);
 
         // Write the nonces to the trace.
         for i in 0..trace.height() {
-            let cols: &mut EdAddAssignCols<F> =
-                trace.values[i * NUM_ED_ADD_COLS..(i + 1) * NUM_ED_ADD_COLS].borrow_mut();
+            let cols: &mut EdAddAssignCols<F, E::BaseField> =
+                trace.values[i * num_cols..(i + 1) * num_cols].borrow_mut();
             cols.nonce = F::from_canonical_usize(i);
         }
 
@@ -169,16 +175,17 @@ impl<F: PrimeField32, E: EllipticCurve + EdwardsParameters> MachineAir<F> for Ed
     }
 
     fn generate_dependencies(&self, input: &Self::Record, output: &mut Self::Record) {
-        let chunk_size = std::cmp::max(input.ed_add_events.len() / num_cpus::get(), 1);
+        let events = Self::events(input);
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
 
-        let blu_batches = input
-            .ed_add_events
+        let blu_batches = events
             .par_chunks(chunk_size)
             .map(|events| {
                 let mut blu: HashMap<u32, HashMap<ByteLookupEvent, usize>> = HashMap::new();
                 events.iter().for_each(|event| {
-                    let mut row = [F::zero(); NUM_ED_ADD_COLS];
-                    let cols: &mut EdAddAssignCols<F> = row.as_mut_slice().borrow_mut();
+                    let mut row = vec![F::zero(); num_ed_add_cols::<E::BaseField>()];
+                    let cols: &mut EdAddAssignCols<F, E::BaseField> =
+                        row.as_mut_slice().borrow_mut();
                     self.event_to_row(event, cols, &mut blu);
                 });
                 blu
@@ -189,16 +196,25 @@ impl<F: PrimeField32, E: EllipticCurve + EdwardsParameters> MachineAir<F> for Ed
     }
 
     fn included(&self, shard: &Self::Record) -> bool {
-        !shard.ed_add_events.is_empty()
+        !Self::events(shard).is_empty()
     }
 }
 
 impl<E: EllipticCurve + EdwardsParameters> EdAddAssignChip<E> {
+    /// The add events of the curve of the chip.
+    fn events(record: &ExecutionRecord) -> &Vec<EllipticCurveAddEvent> {
+        match E::CURVE_TYPE {
+            CurveType::Ed25519 => &record.ed_add_events,
+            CurveType::BabyJubjub => &record.babyjubjub_add_events,
+            _ => panic!("Unsupported curve"),
+        }
+    }
+
     /// Create a row from an event.
     fn event_to_row<F: PrimeField32>(
         &self,
         event: &EllipticCurveAddEvent,
-        cols: &mut EdAddAssignCols<F>,
+        cols: &mut EdAddAssignCols<F, E::BaseField>,
         blu: &mut impl ByteRecord,
     ) {
         // Decode affine points.
@@ -231,7 +247,7 @@ impl<E: EllipticCurve + EdwardsParameters> EdAddAssignChip<E> {
 
 impl<F, E: EllipticCurve + EdwardsParameters> BaseAir<F> for EdAddAssignChip<E> {
     fn width(&self) -> usize {
-        NUM_ED_ADD_COLS
+        num_ed_add_cols::<E::BaseField>()
     }
 }
 
@@ -242,9 +258,9 @@ where
     fn eval(&self, builder: &mut AB) {
         let main = builder.main();
         let local = main.row_slice(0);
-        let local: &EdAddAssignCols<AB::Var> = (*local).borrow();
+        let local: &EdAddAssignCols<AB::Var, E::BaseField> = (*local).borrow();
         let next = main.row_slice(1);
-        let next: &EdAddAssignCols<AB::Var> = (*next).borrow();
+        let next: &EdAddAssignCols<AB::Var, E::BaseField> = (*next).borrow();
 
         // Constrain the incrementing nonce.
         builder.when_first_row().assert_zero(local.nonce);
@@ -373,12 +389,20 @@ where
             local.is_real,
         );
 
+        let syscall_id_felt = match E::CURVE_TYPE {
+            CurveType::Ed25519 => AB::F::from_canonical_u32(SyscallCode::ED_ADD.syscall_id()),
+            CurveType::BabyJubjub => {
+                AB::F::from_canonical_u32(SyscallCode::BABYJUBJUB_ADD.syscall_id())
+            }
+            _ => panic!("Unsupported curve"),
+        };
+
         builder.receive_syscall(
             local.shard,
             local.channel,
             local.clk,
             local.nonce,
-            AB::F::from_canonical_u32(SyscallCode::ED_ADD.syscall_id()),
+            syscall_id_felt,
             local.p_ptr,
             local.q_ptr,
             local.is_real,
@@ -0,0 +1,246 @@
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::{MemoryCols, MemoryWriteCols},
+    utils::pad_rows,
+};
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, FixedPointFunction},
+    syscalls::SyscallCode,
+    ByteOpcode, ExecutionRecord, Program,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::{
+    air::{AirInteraction, MachineAir, SP1AirBuilder},
+    InteractionKind,
+};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+/// The number of columns in the FixedPointCols.
+const NUM_COLS: usize = size_of::<FixedPointCols<u8>>();
+
+/// A bound on the most significant byte of the results, which are at most `2^30`, so that the
+/// result is determined by its bytes in the field.
+const RESULT_MSB_BOUND: u32 = 65;
+
+#[derive(Default)]
+pub struct FixedPointChip;
+
+impl FixedPointChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the fixed-point precompile.
+///
+/// The input is read from the low 16 bits of the word at `ptr`, which is overwritten with the
+/// result. The pair of the input and the result is looked up in the [FixedPointTableChip] of the
+/// selected function.
+///
+/// [FixedPointTableChip]: super::FixedPointTableChip
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct FixedPointCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The byte lookup channel.
+    pub channel: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the word holding the input, which is overwritten with the result.
+    pub ptr: T,
+
+    /// The word holds the input in its "prev_value" and is written to with the result.
+    pub access: MemoryWriteCols<T>,
+
+    /// Selectors of the function.
+    pub is_recip: T,
+    pub is_sqrt: T,
+    pub is_sin: T,
+    pub is_cos: T,
+
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for FixedPointChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "FixedPoint".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut new_byte_lookup_events = Vec::new();
+        let mut rows = input
+            .fixed_point_events
+            .iter()
+            .map(|event| {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut FixedPointCols<F> = row.as_mut_slice().borrow_mut();
+
+                // Assign basic values to the columns.
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.channel = F::from_canonical_u8(event.channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.ptr = F::from_canonical_u32(event.ptr);
+                cols.is_recip = F::from_bool(event.function == FixedPointFunction::Reciprocal);
+                cols.is_sqrt = F::from_bool(event.function == FixedPointFunction::Sqrt);
+                cols.is_sin = F::from_bool(event.function == FixedPointFunction::Sin);
+                cols.is_cos = F::from_bool(event.function == FixedPointFunction::Cos);
+
+                // Populate memory columns.
+                cols.access.populate(
+                    event.channel,
+                    event.memory_record,
+                    &mut new_byte_lookup_events,
+                );
+
+                // Range check the bytes of the result, and bound its most significant byte.
+                let result_bytes = event.result.to_le_bytes();
+                new_byte_lookup_events.add_u8_range_checks(
+                    event.shard,
+                    event.channel,
+                    &result_bytes,
+                );
+                new_byte_lookup_events.add_byte_lookup_event(ByteLookupEvent {
+                    opcode: ByteOpcode::LTU,
+                    shard: event.shard,
+                    channel: event.channel,
+                    a1: 1,
+                    a2: 0,
+                    b: result_bytes[3],
+                    c: RESULT_MSB_BOUND as u8,
+                });
+
+                row
+            })
+            .collect::<Vec<_>>();
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows(&mut rows, || [F::zero(); NUM_COLS]);
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut FixedPointCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.fixed_point_events.is_empty()
+    }
+}
+
+impl<F> BaseAir<F> for FixedPointChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for FixedPointChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &FixedPointCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &FixedPointCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // Assert that exactly one function is selected on the real rows.
+        builder.assert_bool(local.is_recip);
+        builder.assert_bool(local.is_sqrt);
+        builder.assert_bool(local.is_sin);
+        builder.assert_bool(local.is_cos);
+        builder.assert_bool(local.is_real);
+        builder
+            .assert_eq(local.is_recip + local.is_sqrt + local.is_sin + local.is_cos, local.is_real);
+
+        // The input is the low half of the "prev_value" of the word, since we write the result to
+        // it. Its high half is ignored.
+        let prev_value = local.access.prev_value();
+        let input: AB::Expr = prev_value[0] + prev_value[1] * AB::F::from_canonical_u32(256);
+
+        // Range check the bytes of the result, and bound its most significant byte so that the
+        // reduced result can not wrap around the field.
+        let result = local.access.value();
+        builder.slice_range_check_u8(&result.0, local.shard, local.channel, local.is_real);
+        builder.send_byte(
+            ByteOpcode::LTU.as_field::<AB::F>(),
+            AB::F::one(),
+            result[3],
+            AB::F::from_canonical_u32(RESULT_MSB_BOUND),
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // Look up the input and the result in the table of the function.
+        let function = local.is_sqrt * AB::F::from_canonical_u32(FixedPointFunction::Sqrt as u32)
+            + local.is_sin * AB::F::from_canonical_u32(FixedPointFunction::Sin as u32)
+            + local.is_cos * AB::F::from_canonical_u32(FixedPointFunction::Cos as u32);
+        builder.send(AirInteraction::new(
+            vec![function, input, result.reduce::<AB>()],
+            local.is_real.into(),
+            InteractionKind::FixedPoint,
+        ));
+
+        // Read and write the word.
+        builder.eval_memory_access(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.ptr,
+            &local.access,
+            local.is_real,
+        );
+
+        // Receive the arguments.
+        let syscall_id = local.is_recip
+            * AB::F::from_canonical_u32(SyscallCode::FIXED_POINT_RECIP.syscall_id())
+            + local.is_sqrt * AB::F::from_canonical_u32(SyscallCode::FIXED_POINT_SQRT.syscall_id())
+            + local.is_sin * AB::F::from_canonical_u32(SyscallCode::FIXED_POINT_SIN.syscall_id())
+            + local.is_cos * AB::F::from_canonical_u32(SyscallCode::FIXED_POINT_COS.syscall_id());
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            syscall_id,
+            local.ptr,
+            AB::Expr::zero(),
+            local.is_real,
+        );
+    }
+}
@@ -0,0 +1,101 @@
+mod air;
+mod table;
+
+pub use air::*;
+pub use table::*;
+
+#[cfg(test)]
+pub mod fixed_point_tests {
+    use sp1_core_executor::{
+        events::FixedPointFunction,
+        syscalls::{fixed_point, SyscallCode},
+        ExecutionError, Executor, Instruction, Opcode, Program, SP1Context,
+    };
+    use sp1_stark::{
+        baby_bear_poseidon2::BabyBearPoseidon2, CpuProver, MachineProver, SP1CoreOpts,
+        StarkGenericConfig,
+    };
+
+    use crate::{
+        io::SP1Stdin,
+        riscv::RiscvAir,
+        utils::{self, prove_with_context},
+    };
+
+    const FUNCTIONS: [(SyscallCode, FixedPointFunction); 4] = [
+        (SyscallCode::FIXED_POINT_RECIP, FixedPointFunction::Reciprocal),
+        (SyscallCode::FIXED_POINT_SQRT, FixedPointFunction::Sqrt),
+        (SyscallCode::FIXED_POINT_SIN, FixedPointFunction::Sin),
+        (SyscallCode::FIXED_POINT_COS, FixedPointFunction::Cos),
+    ];
+
+    /// Builds a program that evaluates each of the functions on each of the inputs, writing the
+    /// results to consecutive words after 1000.
+    pub fn fixed_point_program(inputs: &[u16]) -> Program {
+        let mut instructions = vec![];
+        let mut ptr = 1000;
+        for &x in inputs {
+            for (code, _) in FUNCTIONS {
+                instructions.extend(vec![
+                    Instruction::new(Opcode::ADD, 29, 0, u32::from(x), false, true),
+                    Instruction::new(Opcode::ADD, 30, 0, ptr, false, true),
+                    Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+                    Instruction::new(Opcode::ADD, 5, 0, code as u32, false, true),
+                    Instruction::new(Opcode::ADD, 10, 0, ptr, false, true),
+                    Instruction::new(Opcode::ADD, 11, 0, 0, false, true),
+                    Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+                ]);
+                ptr += 4;
+            }
+        }
+        Program::new(instructions, 0, 0)
+    }
+
+    fn opts() -> SP1CoreOpts {
+        SP1CoreOpts { fixed_point_tables: true, ..SP1CoreOpts::default() }
+    }
+
+    #[test]
+    fn test_fixed_point_execute() {
+        let inputs = [0, 1, 3, 1 << 14, 12345, u16::MAX];
+        let mut runtime = Executor::new(fixed_point_program(&inputs), opts());
+        runtime.run().unwrap();
+        let mut ptr = 1000;
+        for x in inputs {
+            for (_, function) in FUNCTIONS {
+                assert_eq!(runtime.word(ptr), fixed_point(function, x), "{function:?}({x})");
+                ptr += 4;
+            }
+        }
+    }
+
+    #[test]
+    fn test_fixed_point_prove() {
+        utils::setup_logger();
+        let program = fixed_point_program(&[0, 7, 1 << 15, 40000, 40000]);
+        let machine = RiscvAir::machine_with_fixed_point_tables(BabyBearPoseidon2::new());
+        let prover = CpuProver::new(machine);
+        let (pk, vk) = prover.setup(&program);
+        let (proof, _, _) = prove_with_context(
+            &prover,
+            &pk,
+            program,
+            &SP1Stdin::new(),
+            opts(),
+            SP1Context::default(),
+        )
+        .unwrap();
+        let mut challenger = prover.config().challenger();
+        prover.machine().verify(&vk, &proof, &mut challenger).unwrap();
+    }
+
+    #[test]
+    fn test_fixed_point_tables_disabled() {
+        let opts = SP1CoreOpts { fixed_point_tables: false, ..SP1CoreOpts::default() };
+        let mut runtime = Executor::new(fixed_point_program(&[1]), opts);
+        assert!(matches!(
+            runtime.run(),
+            Err(ExecutionError::FixedPointTablesDisabled(SyscallCode::FIXED_POINT_RECIP))
+        ));
+    }
+}
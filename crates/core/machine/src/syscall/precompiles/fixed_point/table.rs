@@ -0,0 +1,138 @@
+use core::borrow::{Borrow, BorrowMut};
+use std::mem::size_of;
+
+use p3_air::{Air, BaseAir, PairBuilder};
+use p3_field::{AbstractField, Field, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::FixedPointFunction, syscalls::fixed_point, ExecutionRecord, Program,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::{
+    air::{AirInteraction, MachineAir, SP1AirBuilder},
+    InteractionKind,
+};
+
+/// The number of preprocessed columns in the FixedPointTableChip.
+const NUM_PREPROCESSED_COLS: usize = size_of::<FixedPointPreprocessedCols<u8>>();
+
+/// The number of main columns in the FixedPointTableChip.
+const NUM_MULT_COLS: usize = size_of::<FixedPointMultCols<u8>>();
+
+/// The number of rows of the table, one for each 16-bit input.
+pub const NUM_ROWS: usize = 1 << 16;
+
+/// A chip holding a preprocessed table of the fixed-point functions on all 16-bit inputs.
+///
+/// The [FixedPointChip](super::FixedPointChip) looks up the result of each call in the table, and
+/// the main trace holds the number of lookups of each entry.
+#[derive(Default)]
+pub struct FixedPointTableChip;
+
+impl FixedPointTableChip {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Creates the preprocessed fixed-point table trace.
+    pub fn trace<F: Field>() -> RowMajorMatrix<F> {
+        let mut trace = RowMajorMatrix::new(
+            vec![F::zero(); NUM_ROWS * NUM_PREPROCESSED_COLS],
+            NUM_PREPROCESSED_COLS,
+        );
+        for x in 0..NUM_ROWS {
+            let cols: &mut FixedPointPreprocessedCols<F> = trace.row_mut(x).borrow_mut();
+            cols.x = F::from_canonical_usize(x);
+            for function in FixedPointFunction::ALL {
+                cols.values[function as usize] =
+                    F::from_canonical_u32(fixed_point(function, x as u16));
+            }
+        }
+        trace
+    }
+}
+
+#[derive(Debug, Clone, Copy, AlignedBorrow)]
+#[repr(C)]
+pub struct FixedPointPreprocessedCols<T> {
+    /// The input.
+    pub x: T,
+
+    /// The value of each function on the input, in the order of [FixedPointFunction::ALL].
+    pub values: [T; 4],
+}
+
+#[derive(Debug, Clone, Copy, AlignedBorrow)]
+#[repr(C)]
+pub struct FixedPointMultCols<T> {
+    /// The number of lookups of the value of each function on the input.
+    pub multiplicities: [T; 4],
+}
+
+impl<F: PrimeField32> MachineAir<F> for FixedPointTableChip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "FixedPointTable".to_string()
+    }
+
+    fn preprocessed_width(&self) -> usize {
+        NUM_PREPROCESSED_COLS
+    }
+
+    fn generate_preprocessed_trace(&self, _program: &Self::Program) -> Option<RowMajorMatrix<F>> {
+        Some(Self::trace())
+    }
+
+    fn generate_dependencies(&self, _input: &ExecutionRecord, _output: &mut ExecutionRecord) {
+        // Do nothing since this chip has no dependencies.
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        _output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut trace =
+            RowMajorMatrix::new(vec![F::zero(); NUM_MULT_COLS * NUM_ROWS], NUM_MULT_COLS);
+        for event in input.fixed_point_events.iter() {
+            let cols: &mut FixedPointMultCols<F> = trace.row_mut(event.input as usize).borrow_mut();
+            cols.multiplicities[event.function as usize] += F::one();
+        }
+        trace
+    }
+
+    fn included(&self, _shard: &Self::Record) -> bool {
+        true
+    }
+}
+
+impl<F> BaseAir<F> for FixedPointTableChip {
+    fn width(&self) -> usize {
+        NUM_MULT_COLS
+    }
+}
+
+impl<AB: SP1AirBuilder + PairBuilder> Air<AB> for FixedPointTableChip {
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local_mult = main.row_slice(0);
+        let local_mult: &FixedPointMultCols<AB::Var> = (*local_mult).borrow();
+
+        let prep = builder.preprocessed();
+        let prep = prep.row_slice(0);
+        let local: &FixedPointPreprocessedCols<AB::Var> = (*prep).borrow();
+
+        // Receive the lookups of each function.
+        for function in FixedPointFunction::ALL {
+            let i = function as usize;
+            builder.receive(AirInteraction::new(
+                vec![AB::Expr::from_canonical_usize(i), local.x.into(), local.values[i].into()],
+                local_mult.multiplicities[i].into(),
+                InteractionKind::FixedPoint,
+            ));
+        }
+    }
+}
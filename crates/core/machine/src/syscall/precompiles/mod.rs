@@ -1,6 +1,15 @@
+pub mod aes;
+pub mod bls12381_g2;
+pub mod clmul;
+pub mod codec;
 pub mod edwards;
+pub mod fixed_point;
 pub mod fptower;
 pub mod keccak256;
+pub mod poseidon2;
+pub mod range_check;
 pub mod sha256;
 pub mod uint256;
 pub mod weierstrass;
+pub mod wide_alu;
+pub mod x25519;
@@ -0,0 +1,287 @@
+use core::borrow::Borrow;
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::AbstractField;
+use p3_matrix::Matrix;
+use sp1_core_executor::syscalls::SyscallCode;
+use sp1_primitives::RC_16_30_U32;
+use sp1_stark::air::{BaseAirBuilder, SP1AirBuilder};
+
+use super::{
+    external_linear_layer, internal_linear_layer, Poseidon2MerkleCols, Poseidon2MerkleVerifyChip,
+    Poseidon2PermutationCols, DIGEST_WORDS, NUM_EXTERNAL_ROUNDS, NUM_INTERNAL_ROUNDS,
+    NUM_POSEIDON2_MERKLE_COLS, WIDTH,
+};
+use crate::{air::MemoryAirBuilder, memory::MemoryCols, operations::BabyBearWordRangeChecker};
+
+impl<F> BaseAir<F> for Poseidon2MerkleVerifyChip {
+    fn width(&self) -> usize {
+        NUM_POSEIDON2_MERKLE_COLS
+    }
+}
+
+impl<AB> Air<AB> for Poseidon2MerkleVerifyChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &Poseidon2MerkleCols<AB::Var> = (*local).borrow();
+        let next: &Poseidon2MerkleCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        self.eval_flags(builder, local, next);
+        self.eval_memory(builder, local);
+
+        // The permutation input is `node || sibling` if `node` is the left child and
+        // `sibling || node` otherwise.
+        let state = &local.permutation.external_rounds_state[0];
+        for i in 0..DIGEST_WORDS {
+            let node: AB::Expr = local.node[i].into();
+            let sibling = local.sibling[i].value().reduce::<AB>();
+            let left = node.clone() + local.index_bit * (sibling.clone() - node.clone());
+            let right = sibling.clone() + local.index_bit * (node - sibling);
+            builder.when(local.is_real).assert_eq(state[i], left);
+            builder.when(local.is_real).assert_eq(state[DIGEST_WORDS + i], right);
+        }
+        self.eval_permutation(builder, &local.permutation);
+
+        // The compression of this level is the node of the next level.
+        let is_continuing: AB::Expr = local.is_real - local.is_last;
+        for i in 0..DIGEST_WORDS {
+            builder
+                .when_transition()
+                .when(is_continuing.clone())
+                .assert_eq(local.permutation.output_state[i], next.node[i]);
+        }
+
+        // The expected root is the compression of the last level.
+        for i in 0..DIGEST_WORDS {
+            builder
+                .when(local.is_last)
+                .assert_eq(local.root[i].value().reduce::<AB>(), local.permutation.output_state[i]);
+        }
+
+        // Receive the arguments on the first row of the path.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::POSEIDON2_MERKLE_VERIFY.syscall_id()),
+            local.state_ptr,
+            local.path_ptr,
+            local.is_first,
+        );
+    }
+}
+
+impl Poseidon2MerkleVerifyChip {
+    /// Constrains the control flags, the level and the index bits across the rows of a path.
+    fn eval_flags<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &Poseidon2MerkleCols<AB::Var>,
+        next: &Poseidon2MerkleCols<AB::Var>,
+    ) {
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_first);
+        builder.assert_bool(local.is_last);
+        builder.assert_bool(local.index_bit);
+        builder.when(local.is_first).assert_one(local.is_real);
+        builder.when(local.is_last).assert_one(local.is_real);
+
+        // Real rows come before padding rows, and every path starts at a first row and ends at a
+        // last row.
+        builder.when_transition().when_not(local.is_real).assert_zero(next.is_real);
+        builder.when_first_row().assert_eq(local.is_first, local.is_real);
+        builder.when_transition().when(local.is_last).assert_eq(next.is_first, next.is_real);
+        builder.when_last_row().when(local.is_real).assert_one(local.is_last);
+
+        // The first level reads the index and the depth from the header.
+        builder.when(local.is_first).assert_zero(local.level);
+        builder.when(local.is_first).assert_eq(local.index, local.header[0].value().reduce::<AB>());
+        builder.when(local.is_first).assert_eq(local.depth, local.header[1].value().reduce::<AB>());
+
+        // Copy over the inputs and shift the index until the last level.
+        let is_continuing: AB::Expr = local.is_real - local.is_last;
+        let mut when_continuing = builder.when_transition();
+        let mut when_continuing = when_continuing.when(is_continuing);
+        when_continuing.assert_one(next.is_real);
+        when_continuing.assert_zero(next.is_first);
+        when_continuing.assert_eq(local.shard, next.shard);
+        when_continuing.assert_eq(local.channel, next.channel);
+        when_continuing.assert_eq(local.clk, next.clk);
+        when_continuing.assert_eq(local.state_ptr, next.state_ptr);
+        when_continuing.assert_eq(local.path_ptr, next.path_ptr);
+        when_continuing.assert_eq(local.depth, next.depth);
+        when_continuing.assert_eq(local.level + AB::Expr::one(), next.level);
+        when_continuing.assert_eq(local.index, next.index * AB::F::two() + local.index_bit);
+
+        // The path has exactly `depth` levels, and the index fits in `depth` bits.
+        builder.when(local.is_last).assert_eq(local.level + AB::Expr::one(), local.depth);
+        builder.when(local.is_last).assert_eq(local.index, local.index_bit);
+    }
+
+    /// Constrains the memory accesses and the range checks of the digests.
+    fn eval_memory<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &Poseidon2MerkleCols<AB::Var>,
+    ) {
+        let nb_bytes_in_word = AB::F::from_canonical_u32(4);
+        let digest_bytes = AB::F::from_canonical_usize(DIGEST_WORDS * 4);
+
+        // Read the leaf and the header on the first row.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.state_ptr,
+            &local.leaf,
+            local.is_first,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.path_ptr,
+            &local.header,
+            local.is_first,
+        );
+        for i in 0..DIGEST_WORDS {
+            builder
+                .when(local.is_first)
+                .assert_eq(local.node[i], local.leaf[i].value().reduce::<AB>());
+            BabyBearWordRangeChecker::<AB::F>::range_check::<AB>(
+                builder,
+                *local.leaf[i].value(),
+                local.leaf_range_checks[i],
+                local.is_first.into(),
+            );
+        }
+
+        // Read the sibling of this level.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.path_ptr + nb_bytes_in_word * AB::F::two() + local.level * digest_bytes,
+            &local.sibling,
+            local.is_real,
+        );
+        for i in 0..DIGEST_WORDS {
+            BabyBearWordRangeChecker::<AB::F>::range_check::<AB>(
+                builder,
+                *local.sibling[i].value(),
+                local.sibling_range_checks[i],
+                local.is_real.into(),
+            );
+        }
+
+        // Read the expected root, which follows the leaf, on the last row.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.state_ptr + digest_bytes,
+            &local.root,
+            local.is_last,
+        );
+        for i in 0..DIGEST_WORDS {
+            BabyBearWordRangeChecker::<AB::F>::range_check::<AB>(
+                builder,
+                *local.root[i].value(),
+                local.root_range_checks[i],
+                local.is_last.into(),
+            );
+        }
+    }
+
+    /// Constrains the Poseidon2 permutation from `external_rounds_state[0]` to `output_state`.
+    fn eval_permutation<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        perm: &Poseidon2PermutationCols<AB::Var>,
+    ) {
+        for r in 0..NUM_EXTERNAL_ROUNDS {
+            self.eval_external_round(builder, perm, r);
+        }
+        self.eval_internal_rounds(builder, perm);
+    }
+
+    fn eval_external_round<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        perm: &Poseidon2PermutationCols<AB::Var>,
+        r: usize,
+    ) {
+        let mut state: [AB::Expr; WIDTH] =
+            core::array::from_fn(|i| perm.external_rounds_state[r][i].into());
+
+        // For the first round, apply the linear layer.
+        if r == 0 {
+            external_linear_layer(&mut state);
+        }
+
+        // Add the round constants and apply the sboxes.
+        let round = if r < NUM_EXTERNAL_ROUNDS / 2 { r } else { r + NUM_INTERNAL_ROUNDS };
+        let mut sbox_deg_7: [AB::Expr; WIDTH] = core::array::from_fn(|_| AB::Expr::zero());
+        for i in 0..WIDTH {
+            let add_rc = state[i].clone() + AB::F::from_wrapped_u32(RC_16_30_U32[round][i]);
+            let sbox_deg_3 = perm.external_rounds_sbox[r][i];
+            builder.assert_eq(sbox_deg_3, add_rc.clone() * add_rc.clone() * add_rc.clone());
+            sbox_deg_7[i] = sbox_deg_3.into() * sbox_deg_3.into() * add_rc;
+        }
+
+        // Apply the linear layer.
+        external_linear_layer(&mut sbox_deg_7);
+
+        let next_state = if r == NUM_EXTERNAL_ROUNDS / 2 - 1 {
+            &perm.internal_rounds_state
+        } else if r == NUM_EXTERNAL_ROUNDS - 1 {
+            &perm.output_state
+        } else {
+            &perm.external_rounds_state[r + 1]
+        };
+        for i in 0..WIDTH {
+            builder.assert_eq(next_state[i], sbox_deg_7[i].clone());
+        }
+    }
+
+    fn eval_internal_rounds<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        perm: &Poseidon2PermutationCols<AB::Var>,
+    ) {
+        let mut state: [AB::Expr; WIDTH] =
+            core::array::from_fn(|i| perm.internal_rounds_state[i].into());
+        for r in 0..NUM_INTERNAL_ROUNDS {
+            // Add the round constant to the 0th state element and apply the sbox.
+            let round = r + NUM_EXTERNAL_ROUNDS / 2;
+            let add_rc =
+                if r == 0 { state[0].clone() } else { perm.internal_rounds_s0[r - 1].into() }
+                    + AB::Expr::from_wrapped_u32(RC_16_30_U32[round][0]);
+            let sbox_deg_3 = perm.internal_rounds_sbox[r];
+            builder.assert_eq(sbox_deg_3, add_rc.clone() * add_rc.clone() * add_rc.clone());
+            let sbox_deg_7 = sbox_deg_3.into() * sbox_deg_3.into() * add_rc;
+
+            // Apply the linear layer.
+            state[0] = sbox_deg_7;
+            internal_linear_layer(&mut state);
+
+            if r < NUM_INTERNAL_ROUNDS - 1 {
+                builder.assert_eq(perm.internal_rounds_s0[r], state[0].clone());
+            }
+        }
+
+        let external_state = &perm.external_rounds_state[NUM_EXTERNAL_ROUNDS / 2];
+        for i in 0..WIDTH {
+            builder.assert_eq(external_state[i], state[i].clone());
+        }
+    }
+}
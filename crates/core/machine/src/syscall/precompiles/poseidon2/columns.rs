@@ -0,0 +1,69 @@
+use std::mem::size_of;
+
+use sp1_derive::AlignedBorrow;
+
+use super::{DIGEST_WORDS, NUM_EXTERNAL_ROUNDS, NUM_INTERNAL_ROUNDS, WIDTH};
+use crate::{memory::MemoryReadCols, operations::BabyBearWordRangeChecker};
+
+pub const NUM_POSEIDON2_MERKLE_COLS: usize = size_of::<Poseidon2MerkleCols<u8>>();
+
+/// The columns of a single Poseidon2 permutation.
+///
+/// The sbox columns hold the cube of every sbox input, which keeps the constraints at degree 3.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Poseidon2PermutationCols<T> {
+    pub external_rounds_state: [[T; WIDTH]; NUM_EXTERNAL_ROUNDS],
+    pub external_rounds_sbox: [[T; WIDTH]; NUM_EXTERNAL_ROUNDS],
+    pub internal_rounds_state: [T; WIDTH],
+    pub internal_rounds_s0: [T; NUM_INTERNAL_ROUNDS - 1],
+    pub internal_rounds_sbox: [T; NUM_INTERNAL_ROUNDS],
+    pub output_state: [T; WIDTH],
+}
+
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Poseidon2MerkleCols<T> {
+    /// Inputs.
+    pub shard: T,
+    pub channel: T,
+    pub nonce: T,
+    pub clk: T,
+    pub state_ptr: T,
+    pub path_ptr: T,
+
+    /// Control flags.
+    pub is_real: T,
+    /// Whether the row is the first level of a path.
+    pub is_first: T,
+    /// Whether the row is the last level of a path.
+    pub is_last: T,
+
+    /// The level of the path, starting at zero for the leaf.
+    pub level: T,
+    /// The number of levels of the path.
+    pub depth: T,
+    /// The index of the leaf shifted right by `level`.
+    pub index: T,
+    /// The least significant bit of `index`, which is set if `node` is the right child.
+    pub index_bit: T,
+
+    /// The leaf and the header `[index, depth]`, read on the first row.
+    pub leaf: [MemoryReadCols<T>; DIGEST_WORDS],
+    pub leaf_range_checks: [BabyBearWordRangeChecker<T>; DIGEST_WORDS],
+    pub header: [MemoryReadCols<T>; 2],
+
+    /// The sibling of `node` at this level.
+    pub sibling: [MemoryReadCols<T>; DIGEST_WORDS],
+    pub sibling_range_checks: [BabyBearWordRangeChecker<T>; DIGEST_WORDS],
+
+    /// The expected root, read on the last row.
+    pub root: [MemoryReadCols<T>; DIGEST_WORDS],
+    pub root_range_checks: [BabyBearWordRangeChecker<T>; DIGEST_WORDS],
+
+    /// The digest of the node at this level.
+    pub node: [T; DIGEST_WORDS],
+
+    /// The permutation of `node || sibling`, ordered by `index_bit`.
+    pub permutation: Poseidon2PermutationCols<T>,
+}
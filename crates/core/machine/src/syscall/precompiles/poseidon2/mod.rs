@@ -0,0 +1,191 @@
+#![allow(clippy::needless_range_loop)]
+
+mod air;
+mod columns;
+mod trace;
+
+pub use columns::*;
+
+use p3_baby_bear::{MONTY_INVERSE, POSEIDON2_INTERNAL_MATRIX_DIAG_16_BABYBEAR_MONTY};
+use p3_field::{AbstractField, PrimeField32};
+use p3_poseidon2::matmul_internal;
+
+/// The width of the Poseidon2 permutation.
+pub const WIDTH: usize = 16;
+
+/// The number of words in a digest, i.e. the rate of the compression function.
+pub const DIGEST_WORDS: usize = WIDTH / 2;
+
+pub const NUM_EXTERNAL_ROUNDS: usize = 8;
+pub const NUM_INTERNAL_ROUNDS: usize = 13;
+
+/// Implements the Poseidon2 Merkle path verification precompile.
+///
+/// The syscall takes a pointer to a leaf digest followed by the expected root digest, and a pointer
+/// to a path laid out as `[index, depth, siblings..]`. It hashes the leaf up the path with the
+/// Poseidon2 compression function, and constrains the computed root to equal the expected root.
+///
+/// In the AIR, each syscall takes up `depth` rows, where each row corresponds to a single level
+/// of the path and contains a full Poseidon2 permutation.
+#[derive(Default)]
+pub struct Poseidon2MerkleVerifyChip;
+
+impl Poseidon2MerkleVerifyChip {
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+pub(crate) fn apply_m_4<AF: AbstractField>(x: &mut [AF]) {
+    let t01 = x[0].clone() + x[1].clone();
+    let t23 = x[2].clone() + x[3].clone();
+    let t0123 = t01.clone() + t23.clone();
+    let t01123 = t0123.clone() + x[1].clone();
+    let t01233 = t0123.clone() + x[3].clone();
+    // The order here is important. Need to overwrite x[0] and x[2] after x[1] and x[3].
+    x[3] = t01233.clone() + x[0].double(); // 3*x[0] + x[1] + x[2] + 2*x[3]
+    x[1] = t01123.clone() + x[2].double(); // x[0] + 2*x[1] + 3*x[2] + x[3]
+    x[0] = t01123 + t01; // 2*x[0] + 3*x[1] + x[2] + x[3]
+    x[2] = t01233 + t23; // x[0] + x[1] + 2*x[2] + 3*x[3]
+}
+
+pub(crate) fn external_linear_layer<AF: AbstractField>(state: &mut [AF; WIDTH]) {
+    for j in (0..WIDTH).step_by(4) {
+        apply_m_4(&mut state[j..j + 4]);
+    }
+    let sums: [AF; 4] =
+        core::array::from_fn(|k| (0..WIDTH).step_by(4).map(|j| state[j + k].clone()).sum::<AF>());
+    for j in 0..WIDTH {
+        state[j] += sums[j % 4].clone();
+    }
+}
+
+pub(crate) fn internal_linear_layer<AF: AbstractField>(state: &mut [AF; WIDTH]) {
+    let matmul_constants: [<AF as AbstractField>::F; WIDTH] = core::array::from_fn(|i| {
+        <AF as AbstractField>::F::from_wrapped_u32(
+            POSEIDON2_INTERNAL_MATRIX_DIAG_16_BABYBEAR_MONTY[i].as_canonical_u32(),
+        )
+    });
+    matmul_internal(state, matmul_constants);
+    let monty_inverse = AF::from_wrapped_u32(MONTY_INVERSE.as_canonical_u32());
+    state.iter_mut().for_each(|i| *i *= monty_inverse.clone());
+}
+
+#[cfg(test)]
+pub mod poseidon2_merkle_tests {
+    use p3_baby_bear::BabyBear;
+    use p3_field::{AbstractField, PrimeField32};
+    use p3_symmetric::Permutation;
+    use sp1_core_executor::{
+        syscalls::{poseidon2_compress, SyscallCode},
+        ExecutionError, Executor, Instruction, Opcode, Program,
+    };
+    use sp1_primitives::poseidon2_init;
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{self, run_test};
+
+    /// Builds a program that verifies a path of the given depth for a leaf at the given index,
+    /// against the root of the path.
+    pub fn poseidon2_merkle_program(index: u32, depth: u32) -> Program {
+        let leaf: [u32; 8] = core::array::from_fn(|i| i as u32 + 1);
+        let siblings = (0..depth)
+            .map(|level| core::array::from_fn(|i| 1000 + level * 8 + i as u32))
+            .collect::<Vec<[u32; 8]>>();
+        let mut root = leaf;
+        for (level, sibling) in siblings.iter().enumerate() {
+            root = if (index >> level) & 1 == 0 {
+                poseidon2_compress(&root, sibling)
+            } else {
+                poseidon2_compress(sibling, &root)
+            };
+        }
+        poseidon2_merkle_program_with_root(index, &leaf, &siblings, &root)
+    }
+
+    fn poseidon2_merkle_program_with_root(
+        index: u32,
+        leaf: &[u32; 8],
+        siblings: &[[u32; 8]],
+        root: &[u32; 8],
+    ) -> Program {
+        let state_ptr = 100;
+        let path_ptr = 200;
+        let mut words = vec![(path_ptr, index), (path_ptr + 4, siblings.len() as u32)];
+        for (i, word) in leaf.iter().chain(root.iter()).enumerate() {
+            words.push((state_ptr + i as u32 * 4, *word));
+        }
+        for (i, word) in siblings.iter().flatten().enumerate() {
+            words.push((path_ptr + 8 + i as u32 * 4, *word));
+        }
+
+        let mut instructions = vec![];
+        for (addr, value) in words {
+            instructions.extend(vec![
+                Instruction::new(Opcode::ADD, 29, 0, value, false, true),
+                Instruction::new(Opcode::ADD, 30, 0, addr, false, true),
+                Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+            ]);
+        }
+        instructions.extend(vec![
+            Instruction::new(
+                Opcode::ADD,
+                5,
+                0,
+                SyscallCode::POSEIDON2_MERKLE_VERIFY as u32,
+                false,
+                true,
+            ),
+            Instruction::new(Opcode::ADD, 10, 0, state_ptr, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, path_ptr, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ]);
+        Program::new(instructions, 0, 0)
+    }
+
+    #[test]
+    fn test_poseidon2_compress_matches_permutation() {
+        let left = [1, 2, 3, 4, 5, 6, 7, 8];
+        let right = [9, 10, 11, 12, 13, 14, 15, 16];
+        let state = left.iter().chain(right.iter()).map(|x| BabyBear::from_canonical_u32(*x));
+        let state: [BabyBear; 16] = state.collect::<Vec<_>>().try_into().unwrap();
+        let expected = poseidon2_init().permute(state);
+        let compressed = poseidon2_compress(&left, &right);
+        for i in 0..8 {
+            assert_eq!(compressed[i], expected[i].as_canonical_u32());
+        }
+    }
+
+    #[test]
+    fn test_poseidon2_merkle_prove() {
+        utils::setup_logger();
+        run_test::<CpuProver<_, _>>(poseidon2_merkle_program(0b101, 3)).unwrap();
+    }
+
+    /// The nonce of each path is the index of its first row, so the paths have different depths.
+    #[test]
+    fn test_poseidon2_merkle_prove_multiple_paths() {
+        utils::setup_logger();
+        let mut program = poseidon2_merkle_program(6, 4);
+        program.instructions.extend(poseidon2_merkle_program(1, 1).instructions);
+        program.instructions.extend(poseidon2_merkle_program(2, 3).instructions);
+        run_test::<CpuProver<_, _>>(program).unwrap();
+    }
+
+    #[test]
+    fn test_poseidon2_merkle_wrong_root() {
+        let leaf = [1, 2, 3, 4, 5, 6, 7, 8];
+        let sibling = [9, 10, 11, 12, 13, 14, 15, 16];
+        let mut root = poseidon2_compress(&leaf, &sibling);
+        root[0] = (root[0] + 1) % BabyBear::ORDER_U32;
+        let program = poseidon2_merkle_program_with_root(0, &leaf, &[sibling], &root);
+        let result = Executor::new(program, SP1CoreOpts::default()).run();
+        assert!(matches!(
+            result,
+            Err(ExecutionError::InvalidSyscallArguments {
+                syscall: SyscallCode::POSEIDON2_MERKLE_VERIFY,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,241 @@
+use std::borrow::BorrowMut;
+
+use hashbrown::HashMap;
+use itertools::Itertools;
+use p3_field::PrimeField32;
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, Poseidon2MerkleVerifyEvent},
+    ExecutionRecord, Program,
+};
+use sp1_primitives::RC_16_30_U32;
+use sp1_stark::air::MachineAir;
+
+use super::{
+    external_linear_layer, internal_linear_layer, Poseidon2MerkleCols, Poseidon2MerkleVerifyChip,
+    Poseidon2PermutationCols, DIGEST_WORDS, NUM_EXTERNAL_ROUNDS, NUM_INTERNAL_ROUNDS,
+    NUM_POSEIDON2_MERKLE_COLS, WIDTH,
+};
+
+impl<F: PrimeField32> MachineAir<F> for Poseidon2MerkleVerifyChip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Poseidon2MerkleVerify".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        _: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut rows = Some(Vec::new());
+        let mut new_byte_lookup_events = Vec::new();
+        for event in input.poseidon2_merkle_verify_events.iter() {
+            self.event_to_rows(event, &mut rows, &mut new_byte_lookup_events);
+        }
+
+        // The padding rows hold the permutation of the zero state, since the permutation
+        // constraints are not gated by `is_real`.
+        let mut padding_row = [F::zero(); NUM_POSEIDON2_MERKLE_COLS];
+        let cols: &mut Poseidon2MerkleCols<F> = padding_row.as_mut_slice().borrow_mut();
+        populate_permutation(&mut cols.permutation, [F::zero(); WIDTH]);
+
+        let mut rows = rows.unwrap();
+        let nb_rows = rows.len();
+        let mut padded_nb_rows = nb_rows.next_power_of_two();
+        if padded_nb_rows < 4 {
+            padded_nb_rows = 4;
+        }
+        rows.resize(padded_nb_rows, padding_row);
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            NUM_POSEIDON2_MERKLE_COLS,
+        );
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut Poseidon2MerkleCols<F> = trace.values
+                [i * NUM_POSEIDON2_MERKLE_COLS..(i + 1) * NUM_POSEIDON2_MERKLE_COLS]
+                .borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn generate_dependencies(&self, input: &Self::Record, output: &mut Self::Record) {
+        let chunk_size =
+            std::cmp::max(input.poseidon2_merkle_verify_events.len() / num_cpus::get(), 1);
+
+        let blu_batches = input
+            .poseidon2_merkle_verify_events
+            .par_chunks(chunk_size)
+            .map(|events| {
+                let mut blu: HashMap<u32, HashMap<ByteLookupEvent, usize>> = HashMap::new();
+                events.iter().for_each(|event| {
+                    self.event_to_rows::<F>(event, &mut None, &mut blu);
+                });
+                blu
+            })
+            .collect::<Vec<_>>();
+
+        output.add_sharded_byte_lookup_events(blu_batches.iter().collect_vec());
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.poseidon2_merkle_verify_events.is_empty()
+    }
+}
+
+impl Poseidon2MerkleVerifyChip {
+    fn event_to_rows<F: PrimeField32>(
+        &self,
+        event: &Poseidon2MerkleVerifyEvent,
+        rows: &mut Option<Vec<[F; NUM_POSEIDON2_MERKLE_COLS]>>,
+        blu: &mut impl ByteRecord,
+    ) {
+        let depth = event.depth as usize;
+        for level in 0..depth {
+            let mut row = [F::zero(); NUM_POSEIDON2_MERKLE_COLS];
+            let cols: &mut Poseidon2MerkleCols<F> = row.as_mut_slice().borrow_mut();
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.channel = F::from_canonical_u8(event.channel);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.state_ptr = F::from_canonical_u32(event.state_ptr);
+            cols.path_ptr = F::from_canonical_u32(event.path_ptr);
+
+            cols.is_real = F::one();
+            cols.is_first = F::from_bool(level == 0);
+            cols.is_last = F::from_bool(level == depth - 1);
+            cols.level = F::from_canonical_usize(level);
+            cols.depth = F::from_canonical_u32(event.depth);
+            let index = event.index >> level;
+            cols.index = F::from_canonical_u32(index);
+            cols.index_bit = F::from_canonical_u32(index & 1);
+
+            if level == 0 {
+                for i in 0..DIGEST_WORDS {
+                    cols.leaf[i].populate(event.channel, event.leaf_read_records[i], blu);
+                    cols.leaf_range_checks[i].populate(event.leaf[i]);
+                }
+                for i in 0..2 {
+                    cols.header[i].populate(event.channel, event.header_read_records[i], blu);
+                }
+            }
+
+            let sibling = event.siblings[level];
+            for i in 0..DIGEST_WORDS {
+                cols.sibling[i].populate(event.channel, event.sibling_read_records[level][i], blu);
+                cols.sibling_range_checks[i].populate(sibling[i]);
+            }
+
+            if level == depth - 1 {
+                for i in 0..DIGEST_WORDS {
+                    cols.root[i].populate(event.channel, event.root_read_records[i], blu);
+                    cols.root_range_checks[i].populate(event.root[i]);
+                }
+            }
+
+            let node = event.nodes[level];
+            let (left, right) = if index & 1 == 0 { (node, sibling) } else { (sibling, node) };
+            let mut input = [F::zero(); WIDTH];
+            for i in 0..DIGEST_WORDS {
+                cols.node[i] = F::from_canonical_u32(node[i]);
+                input[i] = F::from_canonical_u32(left[i]);
+                input[DIGEST_WORDS + i] = F::from_canonical_u32(right[i]);
+            }
+            let output = populate_permutation(&mut cols.permutation, input);
+            for i in 0..DIGEST_WORDS {
+                debug_assert_eq!(output[i].as_canonical_u32(), event.nodes[level + 1][i]);
+            }
+
+            if let Some(rows) = rows.as_mut() {
+                rows.push(row);
+            }
+        }
+    }
+}
+
+/// Populates the permutation columns with the permutation of `input`, and returns the output.
+fn populate_permutation<F: PrimeField32>(
+    perm: &mut Poseidon2PermutationCols<F>,
+    input: [F; WIDTH],
+) -> [F; WIDTH] {
+    perm.external_rounds_state[0] = input;
+
+    // Apply the first half of external rounds.
+    for r in 0..NUM_EXTERNAL_ROUNDS / 2 {
+        let next_state = populate_external_round(perm, r);
+        if r == NUM_EXTERNAL_ROUNDS / 2 - 1 {
+            perm.internal_rounds_state = next_state;
+        } else {
+            perm.external_rounds_state[r + 1] = next_state;
+        }
+    }
+
+    // Apply the internal rounds.
+    perm.external_rounds_state[NUM_EXTERNAL_ROUNDS / 2] = populate_internal_rounds(perm);
+
+    // Apply the second half of external rounds.
+    for r in NUM_EXTERNAL_ROUNDS / 2..NUM_EXTERNAL_ROUNDS {
+        let next_state = populate_external_round(perm, r);
+        if r == NUM_EXTERNAL_ROUNDS - 1 {
+            perm.output_state = next_state;
+        } else {
+            perm.external_rounds_state[r + 1] = next_state;
+        }
+    }
+
+    perm.output_state
+}
+
+fn populate_external_round<F: PrimeField32>(
+    perm: &mut Poseidon2PermutationCols<F>,
+    r: usize,
+) -> [F; WIDTH] {
+    let mut state = perm.external_rounds_state[r];
+
+    // For the first round, apply the linear layer.
+    if r == 0 {
+        external_linear_layer(&mut state);
+    }
+
+    // Add the round constants and apply the sboxes.
+    let round = if r < NUM_EXTERNAL_ROUNDS / 2 { r } else { r + NUM_INTERNAL_ROUNDS };
+    for i in 0..WIDTH {
+        let add_rc = state[i] + F::from_wrapped_u32(RC_16_30_U32[round][i]);
+        let sbox_deg_3 = add_rc * add_rc * add_rc;
+        perm.external_rounds_sbox[r][i] = sbox_deg_3;
+        state[i] = sbox_deg_3 * sbox_deg_3 * add_rc;
+    }
+
+    // Apply the linear layer.
+    external_linear_layer(&mut state);
+    state
+}
+
+fn populate_internal_rounds<F: PrimeField32>(perm: &mut Poseidon2PermutationCols<F>) -> [F; WIDTH] {
+    let mut state = perm.internal_rounds_state;
+    for r in 0..NUM_INTERNAL_ROUNDS {
+        // Add the round constant to the 0th state element and apply the sbox.
+        let round = r + NUM_EXTERNAL_ROUNDS / 2;
+        let add_rc = state[0] + F::from_wrapped_u32(RC_16_30_U32[round][0]);
+        let sbox_deg_3 = add_rc * add_rc * add_rc;
+        perm.internal_rounds_sbox[r] = sbox_deg_3;
+
+        // Apply the linear layer.
+        state[0] = sbox_deg_3 * sbox_deg_3 * add_rc;
+        internal_linear_layer(&mut state);
+
+        if r < NUM_INTERNAL_ROUNDS - 1 {
+            perm.internal_rounds_s0[r] = state[0];
+        }
+    }
+    state
+}
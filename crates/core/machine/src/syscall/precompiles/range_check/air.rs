@@ -0,0 +1,232 @@
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::{MemoryCols, MemoryReadCols},
+    utils::pad_rows,
+};
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::ByteRecord,
+    syscalls::{SyscallCode, RANGE_CHECK_MAX_BITS},
+    ExecutionRecord, Program,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{MachineAir, SP1AirBuilder};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+/// The number of columns in the RangeCheckCols.
+const NUM_COLS: usize = size_of::<RangeCheckCols<u8>>();
+
+/// The number of possible bit counts, from 0 to 32.
+const NUM_BIT_COUNTS: usize = RANGE_CHECK_MAX_BITS as usize + 1;
+
+#[derive(Default)]
+pub struct RangeCheckChip;
+
+impl RangeCheckChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the RangeCheck operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct RangeCheckCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The byte lookup channel.
+    pub channel: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the checked word.
+    pub ptr: T,
+
+    /// The number of bits the word fits in.
+    pub bits: T,
+
+    /// The checked word.
+    pub memory: MemoryReadCols<T>,
+
+    /// The one-hot encoding of `bits`.
+    pub bits_flags: [T; NUM_BIT_COUNTS],
+
+    /// Each byte of the word shifted to the top of a byte by the number of bits it may not use,
+    /// which is a byte if and only if the byte fits in its share of `bits`.
+    pub shifted_bytes: [T; 4],
+
+    pub is_real: T,
+}
+
+/// The factor which shifts byte `i` of a word checked to fit in `bits` bits to the top of a byte.
+///
+/// A byte which may not use any bit is multiplied by 256, so that only zero stays a byte.
+const fn byte_shift(i: usize, bits: usize) -> u32 {
+    let byte_bits = if bits <= 8 * i {
+        0
+    } else if bits >= 8 * (i + 1) {
+        8
+    } else {
+        bits - 8 * i
+    };
+    1 << (8 - byte_bits)
+}
+
+impl<F: PrimeField32> MachineAir<F> for RangeCheckChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "RangeCheck".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut new_byte_lookup_events = Vec::new();
+        let mut rows = input
+            .range_check_events
+            .iter()
+            .map(|event| {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut RangeCheckCols<F> = row.as_mut_slice().borrow_mut();
+
+                // Assign basic values to the columns.
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.channel = F::from_canonical_u8(event.channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.ptr = F::from_canonical_u32(event.ptr);
+                cols.bits = F::from_canonical_u32(event.bits);
+                cols.bits_flags[event.bits as usize] = F::one();
+
+                // Populate the memory columns.
+                cols.memory.populate(
+                    event.channel,
+                    event.memory_record,
+                    &mut new_byte_lookup_events,
+                );
+
+                // Populate the shifted bytes, which are range checked together with the bytes.
+                let bytes = event.value.to_le_bytes();
+                let mut checked = bytes.to_vec();
+                for (i, byte) in bytes.into_iter().enumerate() {
+                    let shifted = u32::from(byte) * byte_shift(i, event.bits as usize);
+                    cols.shifted_bytes[i] = F::from_canonical_u32(shifted);
+                    checked.push(shifted as u8);
+                }
+                new_byte_lookup_events.add_u8_range_checks(event.shard, event.channel, &checked);
+
+                row
+            })
+            .collect::<Vec<_>>();
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows(&mut rows, || [F::zero(); NUM_COLS]);
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut RangeCheckCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.range_check_events.is_empty()
+    }
+}
+
+impl<F> BaseAir<F> for RangeCheckChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for RangeCheckChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &RangeCheckCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &RangeCheckCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // Assert that exactly one bit count is selected on real rows, and that it is `bits`.
+        let mut num_flags = AB::Expr::zero();
+        let mut selected_bits = AB::Expr::zero();
+        for (bits, flag) in local.bits_flags.iter().enumerate() {
+            builder.assert_bool(*flag);
+            num_flags += (*flag).into();
+            selected_bits += *flag * AB::F::from_canonical_usize(bits);
+        }
+        builder.assert_eq(num_flags, local.is_real);
+        builder.assert_eq(selected_bits, local.bits);
+
+        // Assert that each shifted byte is the byte of the word multiplied by its shift for the
+        // selected bit count. A byte fits in its share of the bits if and only if its shifted
+        // value is a byte, given that the byte itself is one.
+        let word = local.memory.value();
+        for i in 0..4 {
+            let shift = local
+                .bits_flags
+                .iter()
+                .enumerate()
+                .map(|(bits, flag)| *flag * AB::F::from_canonical_u32(byte_shift(i, bits)))
+                .sum::<AB::Expr>();
+            builder.assert_eq(local.shifted_bytes[i], word[i] * shift);
+        }
+        let checked = word.0.iter().chain(local.shifted_bytes.iter()).copied().collect::<Vec<_>>();
+        builder.slice_range_check_u8(&checked, local.shard, local.channel, local.is_real);
+
+        // Read the word.
+        builder.eval_memory_access(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.ptr,
+            &local.memory,
+            local.is_real,
+        );
+
+        // Receive the arguments.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::RANGE_CHECK.syscall_id()),
+            local.ptr,
+            local.bits,
+            local.is_real,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
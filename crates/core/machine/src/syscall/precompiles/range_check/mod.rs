@@ -0,0 +1,60 @@
+mod air;
+
+pub use air::*;
+
+#[cfg(test)]
+pub mod range_check_tests {
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Instruction, Opcode, Program};
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{self, run_test};
+
+    /// Builds a program that checks that each value fits in its number of bits.
+    pub fn range_check_program(checks: &[(u32, u32)]) -> Program {
+        let ptr = 100;
+        let mut instructions = vec![];
+        for &(value, bits) in checks {
+            instructions.extend(vec![
+                Instruction::new(Opcode::ADD, 29, 0, value, false, true),
+                Instruction::new(Opcode::ADD, 30, 0, ptr, false, true),
+                Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+                Instruction::new(Opcode::ADD, 5, 0, SyscallCode::RANGE_CHECK as u32, false, true),
+                Instruction::new(Opcode::ADD, 10, 0, ptr, false, true),
+                Instruction::new(Opcode::ADD, 11, 0, bits, false, true),
+                Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            ]);
+        }
+        Program::new(instructions, 0, 0)
+    }
+
+    const CHECKS: [(u32, u32); 8] = [
+        (0, 0),
+        (1, 1),
+        (0xff, 8),
+        (0x1ff, 9),
+        (0x7fff, 15),
+        (0x00ff_ffff, 24),
+        (0x0123_4567, 25),
+        (0xffff_ffff, 32),
+    ];
+
+    #[test]
+    fn test_range_check_execute() {
+        let mut runtime = Executor::new(range_check_program(&CHECKS), SP1CoreOpts::default());
+        runtime.run().unwrap();
+        assert_eq!(runtime.record.range_check_events.len(), CHECKS.len());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_range_check_execute_out_of_range() {
+        let mut runtime = Executor::new(range_check_program(&[(0x100, 8)]), SP1CoreOpts::default());
+        runtime.run().unwrap();
+    }
+
+    #[test]
+    fn test_range_check_prove() {
+        utils::setup_logger();
+        run_test::<CpuProver<_, _>>(range_check_program(&CHECKS)).unwrap();
+    }
+}
@@ -1,7 +1,13 @@
 mod weierstrass_add;
 mod weierstrass_decompress;
 mod weierstrass_double;
+mod weierstrass_map;
+mod weierstrass_registered_add;
+mod weierstrass_registered_double;
 
 pub use weierstrass_add::*;
 pub use weierstrass_decompress::*;
 pub use weierstrass_double::*;
+pub use weierstrass_map::*;
+pub use weierstrass_registered_add::*;
+pub use weierstrass_registered_double::*;
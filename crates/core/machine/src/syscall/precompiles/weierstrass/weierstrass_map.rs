@@ -0,0 +1,755 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+use std::{fmt::Debug, marker::PhantomData};
+
+use crate::air::MemoryAirBuilder;
+use generic_array::GenericArray;
+use num::{BigUint, One, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, FieldOperation},
+    syscalls::SyscallCode,
+    ByteOpcode, ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::{limbs_from_vec, FieldParameters, Limbs, NumLimbs, NumWords},
+    weierstrass::{
+        sswu::{sswu_sqrt, SswuParameters},
+        WeierstrassParameters,
+    },
+    CurveType, EllipticCurve,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::{
+    air::{BaseAirBuilder, MachineAir, SP1AirBuilder},
+    MachineRecord,
+};
+
+use crate::{
+    memory::{MemoryCols, MemoryWriteCols},
+    operations::field::{field_op::FieldOpCols, field_sqrt::FieldSqrtCols, range::FieldLtCols},
+    utils::{limbs_from_access, limbs_from_prev_access, pad_rows},
+};
+
+pub const fn num_weierstrass_map_cols<P: FieldParameters + NumWords>() -> usize {
+    size_of::<WeierstrassMapToCurveCols<u8, P>>()
+}
+
+/// A set of columns to map a field element `u` to a point of the curve `E'` isogenous to a
+/// Weierstrass curve with the simplified SWU map.
+///
+/// The point is written over `u`, so the first half of `p_access` holds `u` as its previous value.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct WeierstrassMapToCurveCols<T, P: FieldParameters + NumWords> {
+    pub is_real: T,
+    pub shard: T,
+    pub channel: T,
+    pub nonce: T,
+    pub clk: T,
+    pub p_ptr: T,
+    pub p_access: GenericArray<MemoryWriteCols<T>, P::WordsCurvePoint>,
+    /// Whether `g(x1)` is a square, in which case the point has the abscissa `x1`.
+    pub is_square: T,
+    /// The least significant bit of `u`.
+    pub u_lsb: T,
+    /// Whether the square root of `g(x)` has the same sign as `u`.
+    pub is_y_sign_eq: T,
+    /// The right-hand side `g(x)` of the equation of `E'` at the abscissa of the point.
+    pub gx: Limbs<T, P::Limbs>,
+    pub(crate) range_u: FieldLtCols<T, P>,
+    pub(crate) u_squared: FieldOpCols<T, P>,
+    pub(crate) z_u_squared: FieldOpCols<T, P>,
+    pub(crate) z_u_squared_squared: FieldOpCols<T, P>,
+    pub(crate) den: FieldOpCols<T, P>,
+    pub(crate) den_inv: FieldOpCols<T, P>,
+    pub(crate) den_inv_plus_one: FieldOpCols<T, P>,
+    pub(crate) x1: FieldOpCols<T, P>,
+    pub(crate) x1_squared: FieldOpCols<T, P>,
+    pub(crate) x1_squared_plus_a: FieldOpCols<T, P>,
+    pub(crate) x1_cubed_plus_ax: FieldOpCols<T, P>,
+    pub(crate) gx1: FieldOpCols<T, P>,
+    pub(crate) gx1_inv: FieldOpCols<T, P>,
+    pub(crate) x2: FieldOpCols<T, P>,
+    pub(crate) x2_squared: FieldOpCols<T, P>,
+    pub(crate) x2_squared_plus_a: FieldOpCols<T, P>,
+    pub(crate) x2_cubed_plus_ax: FieldOpCols<T, P>,
+    pub(crate) gx2: FieldOpCols<T, P>,
+    pub(crate) y: FieldSqrtCols<T, P>,
+    pub(crate) neg_y: FieldOpCols<T, P>,
+    pub(crate) range_x: FieldLtCols<T, P>,
+    pub(crate) range_y: FieldLtCols<T, P>,
+}
+
+#[derive(Default)]
+pub struct WeierstrassMapToCurveChip<E> {
+    _marker: PhantomData<E>,
+}
+
+impl<E: EllipticCurve + WeierstrassParameters + SswuParameters> WeierstrassMapToCurveChip<E> {
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+
+    /// The constant `-B' / A'` of the map.
+    fn c1() -> BigUint {
+        let modulus = E::BaseField::modulus();
+        let a_inv = E::iso_a().modpow(&(&modulus - 2u32), &modulus);
+        (&modulus - E::iso_b()) * a_inv % &modulus
+    }
+
+    /// Populates the field operations mapping `u`, and returns the point `(x, y)`.
+    fn populate_field_ops<F: PrimeField32>(
+        blu_events: &mut Vec<ByteLookupEvent>,
+        shard: u32,
+        channel: u8,
+        cols: &mut WeierstrassMapToCurveCols<F, E::BaseField>,
+        u: BigUint,
+    ) -> (BigUint, BigUint) {
+        let modulus = E::BaseField::modulus();
+        let (a, b) = (E::iso_a(), E::iso_b());
+        let one = BigUint::one();
+        cols.range_u.populate(blu_events, shard, channel, &u, &modulus);
+
+        // den = Z^2 * u^4 + Z * u^2, which is non-zero for the inputs of the map.
+        let u_squared =
+            cols.u_squared.populate(blu_events, shard, channel, &u, &u, FieldOperation::Mul);
+        let z_u_squared = cols.z_u_squared.populate(
+            blu_events,
+            shard,
+            channel,
+            &E::z(),
+            &u_squared,
+            FieldOperation::Mul,
+        );
+        let z_u_squared_squared = cols.z_u_squared_squared.populate(
+            blu_events,
+            shard,
+            channel,
+            &z_u_squared,
+            &z_u_squared,
+            FieldOperation::Mul,
+        );
+        let den = cols.den.populate(
+            blu_events,
+            shard,
+            channel,
+            &z_u_squared_squared,
+            &z_u_squared,
+            FieldOperation::Add,
+        );
+
+        // x1 = (-B' / A') * (1 + 1 / den).
+        let den_inv =
+            cols.den_inv.populate(blu_events, shard, channel, &one, &den, FieldOperation::Div);
+        let den_inv_plus_one = cols.den_inv_plus_one.populate(
+            blu_events,
+            shard,
+            channel,
+            &den_inv,
+            &one,
+            FieldOperation::Add,
+        );
+        let x1 = cols.x1.populate(
+            blu_events,
+            shard,
+            channel,
+            &Self::c1(),
+            &den_inv_plus_one,
+            FieldOperation::Mul,
+        );
+
+        // gx1 = (x1^2 + A') * x1 + B'.
+        let x1_squared =
+            cols.x1_squared.populate(blu_events, shard, channel, &x1, &x1, FieldOperation::Mul);
+        let x1_squared_plus_a = cols.x1_squared_plus_a.populate(
+            blu_events,
+            shard,
+            channel,
+            &x1_squared,
+            &a,
+            FieldOperation::Add,
+        );
+        let x1_cubed_plus_ax = cols.x1_cubed_plus_ax.populate(
+            blu_events,
+            shard,
+            channel,
+            &x1_squared_plus_a,
+            &x1,
+            FieldOperation::Mul,
+        );
+        let gx1 = cols.gx1.populate(
+            blu_events,
+            shard,
+            channel,
+            &x1_cubed_plus_ax,
+            &b,
+            FieldOperation::Add,
+        );
+
+        // x2 = Z * u^2 * x1 and gx2 = (x2^2 + A') * x2 + B'.
+        let x2 =
+            cols.x2.populate(blu_events, shard, channel, &z_u_squared, &x1, FieldOperation::Mul);
+        let x2_squared =
+            cols.x2_squared.populate(blu_events, shard, channel, &x2, &x2, FieldOperation::Mul);
+        let x2_squared_plus_a = cols.x2_squared_plus_a.populate(
+            blu_events,
+            shard,
+            channel,
+            &x2_squared,
+            &a,
+            FieldOperation::Add,
+        );
+        let x2_cubed_plus_ax = cols.x2_cubed_plus_ax.populate(
+            blu_events,
+            shard,
+            channel,
+            &x2_squared_plus_a,
+            &x2,
+            FieldOperation::Mul,
+        );
+        let gx2 = cols.gx2.populate(
+            blu_events,
+            shard,
+            channel,
+            &x2_cubed_plus_ax,
+            &b,
+            FieldOperation::Add,
+        );
+
+        // When gx1 is not a square, its inverse shows that it is not zero either.
+        let is_square = sswu_sqrt::<E>(&gx1).is_some();
+        cols.is_square = F::from_bool(is_square);
+        cols.gx1_inv.populate(
+            blu_events,
+            shard,
+            channel,
+            &BigUint::from(!is_square as u32),
+            &gx1,
+            FieldOperation::Div,
+        );
+        let (x, gx) = if is_square { (x1, gx1) } else { (x2, gx2) };
+        cols.gx = E::BaseField::to_limbs_field::<F, _>(&gx);
+
+        // y = sqrt(gx), with the sign of u.
+        let y = cols.y.populate(blu_events, shard, channel, &gx, |gx| {
+            sswu_sqrt::<E>(gx).expect("g(x) should be a square")
+        });
+        let neg_y = cols.neg_y.populate(
+            blu_events,
+            shard,
+            channel,
+            &BigUint::zero(),
+            &y,
+            FieldOperation::Sub,
+        );
+        let u_byte = E::BaseField::to_limbs(&u)[0];
+        cols.u_lsb = F::from_canonical_u8(u_byte & 1);
+        blu_events.add_byte_lookup_event(ByteLookupEvent {
+            shard,
+            channel,
+            opcode: ByteOpcode::AND,
+            a1: (u_byte & 1) as u16,
+            a2: 0,
+            b: u_byte,
+            c: 1,
+        });
+        let is_y_sign_eq = y.bit(0) == u.bit(0);
+        cols.is_y_sign_eq = F::from_bool(is_y_sign_eq);
+        let y = if is_y_sign_eq { y } else { neg_y };
+
+        cols.range_x.populate(blu_events, shard, channel, &x, &modulus);
+        cols.range_y.populate(blu_events, shard, channel, &y, &modulus);
+        (x, y)
+    }
+}
+
+impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters + SswuParameters> MachineAir<F>
+    for WeierstrassMapToCurveChip<E>
+{
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        match E::CURVE_TYPE {
+            CurveType::Secp256k1 => "Secp256k1MapToCurve".to_string(),
+            CurveType::Bls12381 => "Bls12381MapToCurve".to_string(),
+            _ => panic!("Unsupported curve"),
+        }
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        // collects the events based on the curve type.
+        let events = match E::CURVE_TYPE {
+            CurveType::Secp256k1 => &input.secp256k1_map_events,
+            CurveType::Bls12381 => &input.bls12381_map_events,
+            _ => panic!("Unsupported curve"),
+        };
+
+        let width = num_weierstrass_map_cols::<E::BaseField>();
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+
+        // Generate the trace rows & corresponding records for each chunk of events in parallel.
+        let rows_and_records = events
+            .par_chunks(chunk_size)
+            .map(|events| {
+                let mut record = ExecutionRecord::default();
+                let mut new_byte_lookup_events = Vec::new();
+
+                let rows = events
+                    .iter()
+                    .map(|event| {
+                        let mut row = vec![F::zero(); width];
+                        let cols: &mut WeierstrassMapToCurveCols<F, E::BaseField> =
+                            row.as_mut_slice().borrow_mut();
+
+                        // Populate basic columns.
+                        cols.is_real = F::one();
+                        cols.shard = F::from_canonical_u32(event.shard);
+                        cols.channel = F::from_canonical_u8(event.channel);
+                        cols.clk = F::from_canonical_u32(event.clk);
+                        cols.p_ptr = F::from_canonical_u32(event.p_ptr);
+
+                        Self::populate_field_ops(
+                            &mut new_byte_lookup_events,
+                            event.shard,
+                            event.channel,
+                            cols,
+                            BigUint::from_slice(&event.u),
+                        );
+
+                        // Populate the memory access columns.
+                        for i in 0..cols.p_access.len() {
+                            cols.p_access[i].populate(
+                                event.channel,
+                                event.p_memory_records[i],
+                                &mut new_byte_lookup_events,
+                            );
+                        }
+                        row
+                    })
+                    .collect::<Vec<_>>();
+                record.add_byte_lookup_events(new_byte_lookup_events);
+                (rows, record)
+            })
+            .collect::<Vec<_>>();
+
+        // Generate the trace rows for each event.
+        let mut rows = Vec::new();
+        for mut row_and_record in rows_and_records {
+            rows.extend(row_and_record.0);
+            output.append(&mut row_and_record.1);
+        }
+
+        pad_rows(&mut rows, || {
+            let mut row = vec![F::zero(); width];
+            let cols: &mut WeierstrassMapToCurveCols<F, E::BaseField> =
+                row.as_mut_slice().borrow_mut();
+            // Map u = 1 as a dummy value, since the field operations are constrained on every row.
+            Self::populate_field_ops(&mut vec![], 0, 0, cols, BigUint::one());
+            row
+        });
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), width);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut WeierstrassMapToCurveCols<F, E::BaseField> =
+                trace.values[i * width..(i + 1) * width].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        match E::CURVE_TYPE {
+            CurveType::Secp256k1 => !shard.secp256k1_map_events.is_empty(),
+            CurveType::Bls12381 => !shard.bls12381_map_events.is_empty(),
+            _ => panic!("Unsupported curve"),
+        }
+    }
+}
+
+impl<F, E: EllipticCurve + WeierstrassParameters> BaseAir<F> for WeierstrassMapToCurveChip<E> {
+    fn width(&self) -> usize {
+        num_weierstrass_map_cols::<E::BaseField>()
+    }
+}
+
+impl<AB, E: EllipticCurve + WeierstrassParameters + SswuParameters> Air<AB>
+    for WeierstrassMapToCurveChip<E>
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &WeierstrassMapToCurveCols<AB::Var, E::BaseField> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &WeierstrassMapToCurveCols<AB::Var, E::BaseField> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        let num_words_field_element = E::BaseField::NB_LIMBS / 4;
+        let u: Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs> =
+            limbs_from_prev_access(&local.p_access[0..num_words_field_element]);
+        let x: Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs> =
+            limbs_from_access(&local.p_access[0..num_words_field_element]);
+        let y: Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs> =
+            limbs_from_access(&local.p_access[num_words_field_element..]);
+
+        let constant = |value: &BigUint| E::BaseField::to_limbs_field::<AB::Expr, _>(value);
+        let modulus = limbs_from_vec::<AB::Expr, <E::BaseField as NumLimbs>::Limbs, AB::F>(
+            E::BaseField::to_limbs_field_vec(&E::BaseField::modulus()),
+        );
+        let (a, b, one) = (constant(&E::iso_a()), constant(&E::iso_b()), constant(&BigUint::one()));
+
+        builder.assert_bool(local.is_square);
+        builder.assert_bool(local.u_lsb);
+        builder.assert_bool(local.is_y_sign_eq);
+
+        // The input is a reduced field element.
+        local.range_u.eval(builder, &u, &modulus, local.shard, local.channel, local.is_real);
+
+        // den = Z^2 * u^4 + Z * u^2.
+        local.u_squared.eval(
+            builder,
+            &u,
+            &u,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.z_u_squared.eval(
+            builder,
+            &constant(&E::z()),
+            &local.u_squared.result,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.z_u_squared_squared.eval(
+            builder,
+            &local.z_u_squared.result,
+            &local.z_u_squared.result,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.den.eval(
+            builder,
+            &local.z_u_squared_squared.result,
+            &local.z_u_squared.result,
+            FieldOperation::Add,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // x1 = (-B' / A') * (1 + 1 / den). The division also asserts that den is not zero, which
+        // rules out the exceptional inputs of the map.
+        local.den_inv.eval(
+            builder,
+            &one,
+            &local.den.result,
+            FieldOperation::Div,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.den_inv_plus_one.eval(
+            builder,
+            &local.den_inv.result,
+            &one,
+            FieldOperation::Add,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.x1.eval(
+            builder,
+            &constant(&Self::c1()),
+            &local.den_inv_plus_one.result,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // gx1 = (x1^2 + A') * x1 + B'.
+        local.x1_squared.eval(
+            builder,
+            &local.x1.result,
+            &local.x1.result,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.x1_squared_plus_a.eval(
+            builder,
+            &local.x1_squared.result,
+            &a,
+            FieldOperation::Add,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.x1_cubed_plus_ax.eval(
+            builder,
+            &local.x1_squared_plus_a.result,
+            &local.x1.result,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.gx1.eval(
+            builder,
+            &local.x1_cubed_plus_ax.result,
+            &b,
+            FieldOperation::Add,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // x2 = Z * u^2 * x1 and gx2 = (x2^2 + A') * x2 + B'.
+        local.x2.eval(
+            builder,
+            &local.z_u_squared.result,
+            &local.x1.result,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.x2_squared.eval(
+            builder,
+            &local.x2.result,
+            &local.x2.result,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.x2_squared_plus_a.eval(
+            builder,
+            &local.x2_squared.result,
+            &a,
+            FieldOperation::Add,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.x2_cubed_plus_ax.eval(
+            builder,
+            &local.x2_squared_plus_a.result,
+            &local.x2.result,
+            FieldOperation::Mul,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        local.gx2.eval(
+            builder,
+            &local.x2_cubed_plus_ax.result,
+            &b,
+            FieldOperation::Add,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+
+        // Since gx2 = Z^3 * u^6 * gx1 with Z a non-square, gx2 is not a square when gx1 is a
+        // non-zero square. So the square root of gx below pins the branch, as long as gx1 is shown
+        // to be non-zero when x2 is chosen: gx1 * gx1_inv = 1 - is_square.
+        local.gx1_inv.eval(
+            builder,
+            &[AB::Expr::one() - local.is_square].iter(),
+            &local.gx1.result,
+            FieldOperation::Div,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        builder.when(local.is_real).when(local.is_square).assert_all_eq(local.gx, local.gx1.result);
+        builder
+            .when(local.is_real)
+            .when_not(local.is_square)
+            .assert_all_eq(local.gx, local.gx2.result);
+        builder.when(local.is_real).when(local.is_square).assert_all_eq(x, local.x1.result);
+        builder.when(local.is_real).when_not(local.is_square).assert_all_eq(x, local.x2.result);
+
+        // y = sqrt(gx), negated unless its sign matches the sign of u.
+        local.y.eval(builder, &local.gx, local.y.lsb, local.shard, local.channel, local.is_real);
+        local.neg_y.eval(
+            builder,
+            &[AB::Expr::zero()].iter(),
+            &local.y.multiplication.result,
+            FieldOperation::Sub,
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        builder.send_byte(
+            ByteOpcode::AND.as_field::<AB::F>(),
+            local.u_lsb,
+            u[0],
+            AB::F::one(),
+            local.shard,
+            local.channel,
+            local.is_real,
+        );
+        builder.when(local.is_real).assert_eq(
+            local.is_y_sign_eq,
+            AB::Expr::one() - local.y.lsb - local.u_lsb
+                + AB::Expr::two() * local.y.lsb * local.u_lsb,
+        );
+        builder
+            .when(local.is_real)
+            .when(local.is_y_sign_eq)
+            .assert_all_eq(y, local.y.multiplication.result);
+        builder
+            .when(local.is_real)
+            .when_not(local.is_y_sign_eq)
+            .assert_all_eq(y, local.neg_y.result);
+
+        // The point is written with reduced coordinates.
+        local.range_x.eval(builder, &x, &modulus, local.shard, local.channel, local.is_real);
+        local.range_y.eval(builder, &y, &modulus, local.shard, local.channel, local.is_real);
+
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into(),
+            local.p_ptr,
+            &local.p_access,
+            local.is_real,
+        );
+
+        // Fetch the syscall id for the curve type.
+        let syscall_id_felt = match E::CURVE_TYPE {
+            CurveType::Secp256k1 => {
+                AB::F::from_canonical_u32(SyscallCode::SECP256K1_MAP_TO_CURVE.syscall_id())
+            }
+            CurveType::Bls12381 => {
+                AB::F::from_canonical_u32(SyscallCode::BLS12381_MAP_TO_CURVE.syscall_id())
+            }
+            _ => panic!("Unsupported curve"),
+        };
+
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            syscall_id_felt,
+            local.p_ptr,
+            AB::Expr::zero(),
+            local.is_real,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigUint;
+    use sp1_core_executor::{syscalls::SyscallCode, Executor, Instruction, Opcode, Program};
+    use sp1_curves::{
+        params::FieldParameters,
+        weierstrass::{
+            bls12_381::Bls12381Parameters, secp256k1::Secp256k1Parameters, sswu::sswu_map, SwCurve,
+        },
+        AffinePoint,
+    };
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{run_test, setup_logger};
+
+    const P_PTR: u32 = 1000;
+
+    /// Builds a program that writes each of `inputs` to `p` and maps it to the curve with the
+    /// syscall `code`.
+    fn map_program(code: SyscallCode, inputs: &[Vec<u32>]) -> Program {
+        let mut instructions = vec![];
+        for u in inputs {
+            for (i, &word) in u.iter().enumerate() {
+                instructions.extend(vec![
+                    Instruction::new(Opcode::ADD, 29, 0, word, false, true),
+                    Instruction::new(Opcode::ADD, 30, 0, P_PTR + 4 * i as u32, false, true),
+                    Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+                ]);
+            }
+            instructions.extend(vec![
+                Instruction::new(Opcode::ADD, 5, 0, code as u32, false, true),
+                Instruction::new(Opcode::ADD, 10, 0, P_PTR, false, true),
+                Instruction::new(Opcode::ADD, 11, 0, 0, false, true),
+                Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            ]);
+        }
+        Program::new(instructions, 0, 0)
+    }
+
+    /// The words of `u`, padded to the words of a field element of `P`.
+    fn field_words<P: FieldParameters>(u: &BigUint) -> Vec<u32> {
+        let mut words = u.to_u32_digits();
+        words.resize(P::NB_LIMBS / 4, 0);
+        words
+    }
+
+    #[test]
+    fn test_weierstrass_map_execute() {
+        let u = BigUint::from(0x1234_5678u32) << 100;
+        let (x, y) = sswu_map::<Secp256k1Parameters>(&u).unwrap();
+        let inputs = [field_words::<<Secp256k1Parameters as FieldParameters>::BaseField>(&u)];
+        let program = map_program(SyscallCode::SECP256K1_MAP_TO_CURVE, &inputs);
+        let mut runtime = Executor::new(program, SP1CoreOpts::default());
+        runtime.run().unwrap();
+        let expected = AffinePoint::<SwCurve<Secp256k1Parameters>>::new(x, y).to_words_le();
+        for (i, word) in expected.into_iter().enumerate() {
+            assert_eq!(runtime.word(P_PTR + 4 * i as u32), word);
+        }
+    }
+
+    #[test]
+    fn test_weierstrass_map_prove() {
+        setup_logger();
+        let inputs = [BigUint::from(1u32), BigUint::from(7u32) << 200, BigUint::from(0xabcdu32)];
+        let secp256k1_inputs = inputs
+            .iter()
+            .map(field_words::<<Secp256k1Parameters as FieldParameters>::BaseField>)
+            .collect::<Vec<_>>();
+        run_test::<CpuProver<_, _>>(map_program(
+            SyscallCode::SECP256K1_MAP_TO_CURVE,
+            &secp256k1_inputs,
+        ))
+        .unwrap();
+        let bls12381_inputs = inputs
+            .iter()
+            .map(field_words::<<Bls12381Parameters as FieldParameters>::BaseField>)
+            .collect::<Vec<_>>();
+        run_test::<CpuProver<_, _>>(map_program(
+            SyscallCode::BLS12381_MAP_TO_CURVE,
+            &bls12381_inputs,
+        ))
+        .unwrap();
+    }
+}
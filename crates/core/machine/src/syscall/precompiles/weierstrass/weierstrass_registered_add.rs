@@ -0,0 +1,616 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+use crate::air::MemoryAirBuilder;
+use generic_array::GenericArray;
+use num::{BigUint, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, FieldOperation},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::{FieldParameters, Limbs, NumLimbs, NumWords},
+    uint256::U256Field,
+    weierstrass::registered::RegisteredCurve,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{MachineAir, SP1AirBuilder};
+use typenum::Unsigned;
+
+use crate::{
+    memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
+    operations::field::field_op::FieldOpCols,
+    utils::{limbs_from_prev_access, pad_rows},
+};
+
+/// The limbs of the field elements of the registered curves, which only fix their size.
+type P = U256Field;
+
+pub const NUM_WEIERSTRASS_REGISTERED_ADD_COLS: usize =
+    size_of::<WeierstrassRegisteredAddCols<u8>>();
+
+/// A set of columns to add two points on a registered Weierstrass curve.
+///
+/// The first point is prefixed in memory by the id of its curve, which is checked against the
+/// curve of the chip.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct WeierstrassRegisteredAddCols<T> {
+    pub is_real: T,
+    pub shard: T,
+    pub channel: T,
+    pub nonce: T,
+    pub clk: T,
+    pub p_ptr: T,
+    pub q_ptr: T,
+    pub curve_id_access: MemoryReadCols<T>,
+    pub p_access: GenericArray<MemoryWriteCols<T>, <P as NumWords>::WordsCurvePoint>,
+    pub q_access: GenericArray<MemoryReadCols<T>, <P as NumWords>::WordsCurvePoint>,
+    pub(crate) slope_denominator: FieldOpCols<T, P>,
+    pub(crate) slope_numerator: FieldOpCols<T, P>,
+    pub(crate) slope: FieldOpCols<T, P>,
+    pub(crate) slope_squared: FieldOpCols<T, P>,
+    pub(crate) p_x_plus_q_x: FieldOpCols<T, P>,
+    pub(crate) x3_ins: FieldOpCols<T, P>,
+    pub(crate) p_x_minus_x: FieldOpCols<T, P>,
+    pub(crate) y3_ins: FieldOpCols<T, P>,
+    pub(crate) slope_times_p_x_minus_x: FieldOpCols<T, P>,
+}
+
+/// A chip adding points of a registered curve, whose parameters are constants of the chip.
+pub struct WeierstrassRegisteredAddChip {
+    curve: &'static RegisteredCurve,
+}
+
+impl WeierstrassRegisteredAddChip {
+    pub const fn new(curve: &'static RegisteredCurve) -> Self {
+        Self { curve }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn populate_field_ops<F: PrimeField32>(
+        blu_events: &mut Vec<ByteLookupEvent>,
+        shard: u32,
+        channel: u8,
+        cols: &mut WeierstrassRegisteredAddCols<F>,
+        modulus: &BigUint,
+        p_x: BigUint,
+        p_y: BigUint,
+        q_x: BigUint,
+        q_y: BigUint,
+    ) {
+        // slope = (q.y - p.y) / (q.x - p.x).
+        let slope = {
+            let slope_numerator = cols.slope_numerator.populate_with_modulus(
+                blu_events,
+                shard,
+                channel,
+                &q_y,
+                &p_y,
+                modulus,
+                FieldOperation::Sub,
+            );
+
+            let slope_denominator = cols.slope_denominator.populate_with_modulus(
+                blu_events,
+                shard,
+                channel,
+                &q_x,
+                &p_x,
+                modulus,
+                FieldOperation::Sub,
+            );
+
+            cols.slope.populate_with_modulus(
+                blu_events,
+                shard,
+                channel,
+                &slope_numerator,
+                &slope_denominator,
+                modulus,
+                FieldOperation::Div,
+            )
+        };
+
+        // x = slope * slope - (p.x + q.x).
+        let x = {
+            let slope_squared = cols.slope_squared.populate_with_modulus(
+                blu_events,
+                shard,
+                channel,
+                &slope,
+                &slope,
+                modulus,
+                FieldOperation::Mul,
+            );
+            let p_x_plus_q_x = cols.p_x_plus_q_x.populate_with_modulus(
+                blu_events,
+                shard,
+                channel,
+                &p_x,
+                &q_x,
+                modulus,
+                FieldOperation::Add,
+            );
+            cols.x3_ins.populate_with_modulus(
+                blu_events,
+                shard,
+                channel,
+                &slope_squared,
+                &p_x_plus_q_x,
+                modulus,
+                FieldOperation::Sub,
+            )
+        };
+
+        // y = slope * (p.x - x_3n) - p.y.
+        {
+            let p_x_minus_x = cols.p_x_minus_x.populate_with_modulus(
+                blu_events,
+                shard,
+                channel,
+                &p_x,
+                &x,
+                modulus,
+                FieldOperation::Sub,
+            );
+            let slope_times_p_x_minus_x = cols.slope_times_p_x_minus_x.populate_with_modulus(
+                blu_events,
+                shard,
+                channel,
+                &slope,
+                &p_x_minus_x,
+                modulus,
+                FieldOperation::Mul,
+            );
+            cols.y3_ins.populate_with_modulus(
+                blu_events,
+                shard,
+                channel,
+                &slope_times_p_x_minus_x,
+                &p_y,
+                modulus,
+                FieldOperation::Sub,
+            );
+        }
+    }
+}
+
+impl<F: PrimeField32> MachineAir<F> for WeierstrassRegisteredAddChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        format!("{}AddAssign", self.curve.name)
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let modulus = self.curve.modulus_int();
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for event in
+            input.registered_curve_add_events.iter().filter(|e| e.curve_id == self.curve.id)
+        {
+            let mut row = [F::zero(); NUM_WEIERSTRASS_REGISTERED_ADD_COLS];
+            let cols: &mut WeierstrassRegisteredAddCols<F> = row.as_mut_slice().borrow_mut();
+
+            // Decode affine points.
+            let (p_x, p_y) = RegisteredCurve::point_from_words_le(&event.p);
+            let (q_x, q_y) = RegisteredCurve::point_from_words_le(&event.q);
+
+            // Populate basic columns.
+            cols.is_real = F::one();
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.channel = F::from_canonical_u8(event.channel);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.p_ptr = F::from_canonical_u32(event.p_ptr);
+            cols.q_ptr = F::from_canonical_u32(event.q_ptr);
+
+            Self::populate_field_ops(
+                &mut new_byte_lookup_events,
+                event.shard,
+                event.channel,
+                cols,
+                &modulus,
+                p_x,
+                p_y,
+                q_x,
+                q_y,
+            );
+
+            // Populate the memory access columns.
+            cols.curve_id_access.populate(
+                event.channel,
+                event.curve_id_memory_record,
+                &mut new_byte_lookup_events,
+            );
+            for i in 0..cols.q_access.len() {
+                cols.q_access[i].populate(
+                    event.channel,
+                    event.q_memory_records[i],
+                    &mut new_byte_lookup_events,
+                );
+            }
+            for i in 0..cols.p_access.len() {
+                cols.p_access[i].populate(
+                    event.channel,
+                    event.p_memory_records[i],
+                    &mut new_byte_lookup_events,
+                );
+            }
+
+            rows.push(row);
+        }
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows(&mut rows, || {
+            let mut row = [F::zero(); NUM_WEIERSTRASS_REGISTERED_ADD_COLS];
+            let cols: &mut WeierstrassRegisteredAddCols<F> = row.as_mut_slice().borrow_mut();
+            let zero = BigUint::zero();
+            Self::populate_field_ops(
+                &mut vec![],
+                0,
+                0,
+                cols,
+                &modulus,
+                zero.clone(),
+                zero.clone(),
+                zero.clone(),
+                zero,
+            );
+            row
+        });
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            NUM_WEIERSTRASS_REGISTERED_ADD_COLS,
+        );
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut WeierstrassRegisteredAddCols<F> = trace.values[i
+                * NUM_WEIERSTRASS_REGISTERED_ADD_COLS
+                ..(i + 1) * NUM_WEIERSTRASS_REGISTERED_ADD_COLS]
+                .borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        shard.registered_curve_add_events.iter().any(|e| e.curve_id == self.curve.id)
+    }
+}
+
+impl<F> BaseAir<F> for WeierstrassRegisteredAddChip {
+    fn width(&self) -> usize {
+        NUM_WEIERSTRASS_REGISTERED_ADD_COLS
+    }
+}
+
+impl<AB> Air<AB> for WeierstrassRegisteredAddChip
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, <P as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &WeierstrassRegisteredAddCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &WeierstrassRegisteredAddCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        let num_words_field_element = <P as NumLimbs>::Limbs::USIZE / 4;
+
+        let p_x = limbs_from_prev_access(&local.p_access[0..num_words_field_element]);
+        let p_y = limbs_from_prev_access(&local.p_access[num_words_field_element..]);
+
+        let q_x = limbs_from_prev_access(&local.q_access[0..num_words_field_element]);
+        let q_y = limbs_from_prev_access(&local.q_access[num_words_field_element..]);
+
+        // The modulus of the curve is a constant of the chip.
+        let modulus = P::to_limbs_field::<AB::Expr, _>(&self.curve.modulus_int());
+
+        // slope = (q.y - p.y) / (q.x - p.x).
+        let slope = {
+            local.slope_numerator.eval_with_modulus(
+                builder,
+                &q_y,
+                &p_y,
+                &modulus,
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.slope_denominator.eval_with_modulus(
+                builder,
+                &q_x,
+                &p_x,
+                &modulus,
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.slope.eval_with_modulus(
+                builder,
+                &local.slope_numerator.result,
+                &local.slope_denominator.result,
+                &modulus,
+                FieldOperation::Div,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            &local.slope.result
+        };
+
+        // x = slope * slope - self.x - other.x.
+        let x = {
+            local.slope_squared.eval_with_modulus(
+                builder,
+                slope,
+                slope,
+                &modulus,
+                FieldOperation::Mul,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.p_x_plus_q_x.eval_with_modulus(
+                builder,
+                &p_x,
+                &q_x,
+                &modulus,
+                FieldOperation::Add,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.x3_ins.eval_with_modulus(
+                builder,
+                &local.slope_squared.result,
+                &local.p_x_plus_q_x.result,
+                &modulus,
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            &local.x3_ins.result
+        };
+
+        // y = slope * (p.x - x_3n) - p.y.
+        {
+            local.p_x_minus_x.eval_with_modulus(
+                builder,
+                &p_x,
+                x,
+                &modulus,
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.slope_times_p_x_minus_x.eval_with_modulus(
+                builder,
+                slope,
+                &local.p_x_minus_x.result,
+                &modulus,
+                FieldOperation::Mul,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.y3_ins.eval_with_modulus(
+                builder,
+                &local.slope_times_p_x_minus_x.result,
+                &p_y,
+                &modulus,
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+        }
+
+        // Constraint self.p_access.value = [self.x3_ins.result, self.y3_ins.result]. This is to
+        // ensure that p_access is updated with the new value.
+        for i in 0..P::NB_LIMBS {
+            builder
+                .when(local.is_real)
+                .assert_eq(local.x3_ins.result[i], local.p_access[i / 4].value()[i % 4]);
+            builder.when(local.is_real).assert_eq(
+                local.y3_ins.result[i],
+                local.p_access[num_words_field_element + i / 4].value()[i % 4],
+            );
+        }
+
+        // Constrain the curve id which prefixes p to be the curve of the chip.
+        for (byte, expected) in
+            local.curve_id_access.value().0.iter().zip(self.curve.id.to_le_bytes())
+        {
+            builder.when(local.is_real).assert_eq(*byte, AB::F::from_canonical_u8(expected));
+        }
+
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into(),
+            local.q_ptr,
+            &local.q_access,
+            local.is_real,
+        );
+        // The curve id and p are accessed at +1 since q could overlap them.
+        builder.eval_memory_access(
+            local.shard,
+            local.channel,
+            local.clk + AB::F::one(),
+            local.p_ptr,
+            &local.curve_id_access,
+            local.is_real,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk + AB::F::one(),
+            local.p_ptr + AB::F::from_canonical_u32(4),
+            &local.p_access,
+            local.is_real,
+        );
+
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::REGISTERED_CURVE_ADD.syscall_id()),
+            local.p_ptr,
+            local.q_ptr,
+            local.is_real,
+        );
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use sp1_core_executor::{
+        syscalls::SyscallCode, ExecutionError, Executor, Instruction, Opcode, Program,
+    };
+    use sp1_curves::weierstrass::registered::{RegisteredCurve, REGISTERED_CURVES};
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{run_test, setup_logger};
+
+    const P_PTR: u32 = 100;
+    const Q_PTR: u32 = 200;
+
+    fn store_words(instructions: &mut Vec<Instruction>, ptr: u32, words: &[u32]) {
+        for (i, word) in words.iter().enumerate() {
+            instructions.extend([
+                Instruction::new(Opcode::ADD, 29, 0, *word, false, true),
+                Instruction::new(Opcode::ADD, 30, 0, ptr + 4 * i as u32, false, true),
+                Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+            ]);
+        }
+    }
+
+    /// Builds the instructions which compute `3 * G` at `p_ptr` for the generator `G` of the curve,
+    /// by doubling `G` and adding `G` back from `q_ptr`.
+    fn registered_curve_instructions(
+        curve: &RegisteredCurve,
+        p_ptr: u32,
+        q_ptr: u32,
+    ) -> Vec<Instruction> {
+        let generator = RegisteredCurve::point_to_words_le(&curve.generator());
+        let mut instructions = vec![];
+        store_words(&mut instructions, p_ptr, &[curve.id]);
+        store_words(&mut instructions, p_ptr + 4, &generator);
+        store_words(&mut instructions, q_ptr, &generator);
+        instructions.extend([
+            Instruction::new(
+                Opcode::ADD,
+                5,
+                0,
+                SyscallCode::REGISTERED_CURVE_DOUBLE as u32,
+                false,
+                true,
+            ),
+            Instruction::new(Opcode::ADD, 10, 0, p_ptr, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, 0, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            Instruction::new(
+                Opcode::ADD,
+                5,
+                0,
+                SyscallCode::REGISTERED_CURVE_ADD as u32,
+                false,
+                true,
+            ),
+            Instruction::new(Opcode::ADD, 10, 0, p_ptr, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, q_ptr, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ]);
+        instructions
+    }
+
+    fn registered_curve_program(curve: &RegisteredCurve) -> Program {
+        Program::new(registered_curve_instructions(curve, P_PTR, Q_PTR), 0, 0)
+    }
+
+    #[test]
+    fn test_registered_curve_execute() {
+        for curve in REGISTERED_CURVES {
+            let mut runtime =
+                Executor::new(registered_curve_program(curve), SP1CoreOpts::default());
+            runtime.run().unwrap();
+
+            let g = curve.generator();
+            let expected = RegisteredCurve::point_to_words_le(&curve.add(&curve.double(&g), &g));
+            let result = (0..expected.len())
+                .map(|i| runtime.word(P_PTR + 4 + 4 * i as u32))
+                .collect::<Vec<_>>();
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_registered_curve_execute_unknown_id() {
+        let mut program = registered_curve_program(&REGISTERED_CURVES[0]);
+        program.instructions[0] = Instruction::new(Opcode::ADD, 29, 0, 0, false, true);
+        let result = Executor::new(program, SP1CoreOpts::default()).run();
+        assert!(matches!(result, Err(ExecutionError::UnregisteredCurve(0))));
+    }
+
+    #[test]
+    fn test_registered_curve_prove() {
+        setup_logger();
+        for curve in REGISTERED_CURVES {
+            run_test::<CpuProver<_, _>>(registered_curve_program(curve)).unwrap();
+        }
+    }
+
+    /// The curves have their own chips, so the nonces of their events are counted per curve.
+    #[test]
+    fn test_registered_curves_interleaved_prove() {
+        setup_logger();
+        let mut instructions = vec![];
+        for _ in 0..2 {
+            for (i, curve) in REGISTERED_CURVES.iter().enumerate() {
+                let offset = 1000 * i as u32;
+                instructions.extend(registered_curve_instructions(
+                    curve,
+                    P_PTR + offset,
+                    Q_PTR + offset,
+                ));
+            }
+        }
+        run_test::<CpuProver<_, _>>(Program::new(instructions, 0, 0)).unwrap();
+    }
+}
@@ -0,0 +1,516 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+use crate::air::MemoryAirBuilder;
+use generic_array::GenericArray;
+use num::BigUint;
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, FieldOperation},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use sp1_curves::{
+    params::{FieldParameters, Limbs, NumLimbs, NumWords},
+    uint256::U256Field,
+    weierstrass::registered::RegisteredCurve,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{MachineAir, SP1AirBuilder};
+
+use crate::{
+    memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
+    operations::field::field_op::FieldOpCols,
+    utils::{limbs_from_prev_access, pad_rows},
+};
+
+/// The limbs of the field elements of the registered curves, which only fix their size.
+type P = U256Field;
+
+pub const NUM_WEIERSTRASS_REGISTERED_DOUBLE_COLS: usize =
+    size_of::<WeierstrassRegisteredDoubleCols<u8>>();
+
+/// A set of columns to double a point on a registered Weierstrass curve.
+///
+/// The point is prefixed in memory by the id of its curve, which is checked against the curve of
+/// the chip.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct WeierstrassRegisteredDoubleCols<T> {
+    pub is_real: T,
+    pub shard: T,
+    pub channel: T,
+    pub nonce: T,
+    pub clk: T,
+    pub p_ptr: T,
+    pub curve_id_access: MemoryReadCols<T>,
+    pub p_access: GenericArray<MemoryWriteCols<T>, <P as NumWords>::WordsCurvePoint>,
+    pub(crate) slope_denominator: FieldOpCols<T, P>,
+    pub(crate) slope_numerator: FieldOpCols<T, P>,
+    pub(crate) slope: FieldOpCols<T, P>,
+    pub(crate) p_x_squared: FieldOpCols<T, P>,
+    pub(crate) p_x_squared_times_3: FieldOpCols<T, P>,
+    pub(crate) slope_squared: FieldOpCols<T, P>,
+    pub(crate) p_x_plus_p_x: FieldOpCols<T, P>,
+    pub(crate) x3_ins: FieldOpCols<T, P>,
+    pub(crate) p_x_minus_x: FieldOpCols<T, P>,
+    pub(crate) y3_ins: FieldOpCols<T, P>,
+    pub(crate) slope_times_p_x_minus_x: FieldOpCols<T, P>,
+}
+
+/// A chip doubling points of a registered curve, whose parameters are constants of the chip.
+pub struct WeierstrassRegisteredDoubleChip {
+    curve: &'static RegisteredCurve,
+}
+
+impl WeierstrassRegisteredDoubleChip {
+    pub const fn new(curve: &'static RegisteredCurve) -> Self {
+        Self { curve }
+    }
+
+    fn populate_field_ops<F: PrimeField32>(
+        &self,
+        blu_events: &mut Vec<ByteLookupEvent>,
+        shard: u32,
+        channel: u8,
+        cols: &mut WeierstrassRegisteredDoubleCols<F>,
+        p_x: BigUint,
+        p_y: BigUint,
+    ) {
+        let modulus = &self.curve.modulus_int();
+        let a = self.curve.a_int();
+
+        // slope = slope_numerator / slope_denominator.
+        let slope = {
+            // slope_numerator = a + (p.x * p.x) * 3.
+            let slope_numerator = {
+                let p_x_squared = cols.p_x_squared.populate_with_modulus(
+                    blu_events,
+                    shard,
+                    channel,
+                    &p_x,
+                    &p_x,
+                    modulus,
+                    FieldOperation::Mul,
+                );
+                let p_x_squared_times_3 = cols.p_x_squared_times_3.populate_with_modulus(
+                    blu_events,
+                    shard,
+                    channel,
+                    &p_x_squared,
+                    &BigUint::from(3u32),
+                    modulus,
+                    FieldOperation::Mul,
+                );
+                cols.slope_numerator.populate_with_modulus(
+                    blu_events,
+                    shard,
+                    channel,
+                    &a,
+                    &p_x_squared_times_3,
+                    modulus,
+                    FieldOperation::Add,
+                )
+            };
+
+            // slope_denominator = 2 * y.
+            let slope_denominator = cols.slope_denominator.populate_with_modulus(
+                blu_events,
+                shard,
+                channel,
+                &BigUint::from(2u32),
+                &p_y,
+                modulus,
+                FieldOperation::Mul,
+            );
+
+            cols.slope.populate_with_modulus(
+                blu_events,
+                shard,
+                channel,
+                &slope_numerator,
+                &slope_denominator,
+                modulus,
+                FieldOperation::Div,
+            )
+        };
+
+        // x = slope * slope - (p.x + p.x).
+        let x = {
+            let slope_squared = cols.slope_squared.populate_with_modulus(
+                blu_events,
+                shard,
+                channel,
+                &slope,
+                &slope,
+                modulus,
+                FieldOperation::Mul,
+            );
+            let p_x_plus_p_x = cols.p_x_plus_p_x.populate_with_modulus(
+                blu_events,
+                shard,
+                channel,
+                &p_x,
+                &p_x,
+                modulus,
+                FieldOperation::Add,
+            );
+            cols.x3_ins.populate_with_modulus(
+                blu_events,
+                shard,
+                channel,
+                &slope_squared,
+                &p_x_plus_p_x,
+                modulus,
+                FieldOperation::Sub,
+            )
+        };
+
+        // y = slope * (p.x - x) - p.y.
+        {
+            let p_x_minus_x = cols.p_x_minus_x.populate_with_modulus(
+                blu_events,
+                shard,
+                channel,
+                &p_x,
+                &x,
+                modulus,
+                FieldOperation::Sub,
+            );
+            let slope_times_p_x_minus_x = cols.slope_times_p_x_minus_x.populate_with_modulus(
+                blu_events,
+                shard,
+                channel,
+                &slope,
+                &p_x_minus_x,
+                modulus,
+                FieldOperation::Mul,
+            );
+            cols.y3_ins.populate_with_modulus(
+                blu_events,
+                shard,
+                channel,
+                &slope_times_p_x_minus_x,
+                &p_y,
+                modulus,
+                FieldOperation::Sub,
+            );
+        }
+    }
+}
+
+impl<F: PrimeField32> MachineAir<F> for WeierstrassRegisteredDoubleChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        format!("{}DoubleAssign", self.curve.name)
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for event in
+            input.registered_curve_double_events.iter().filter(|e| e.curve_id == self.curve.id)
+        {
+            let mut row = [F::zero(); NUM_WEIERSTRASS_REGISTERED_DOUBLE_COLS];
+            let cols: &mut WeierstrassRegisteredDoubleCols<F> = row.as_mut_slice().borrow_mut();
+
+            // Decode the affine point.
+            let (p_x, p_y) = RegisteredCurve::point_from_words_le(&event.p);
+
+            // Populate basic columns.
+            cols.is_real = F::one();
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.channel = F::from_canonical_u8(event.channel);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.p_ptr = F::from_canonical_u32(event.p_ptr);
+
+            self.populate_field_ops(
+                &mut new_byte_lookup_events,
+                event.shard,
+                event.channel,
+                cols,
+                p_x,
+                p_y,
+            );
+
+            // Populate the memory access columns.
+            cols.curve_id_access.populate(
+                event.channel,
+                event.curve_id_memory_record,
+                &mut new_byte_lookup_events,
+            );
+            for i in 0..cols.p_access.len() {
+                cols.p_access[i].populate(
+                    event.channel,
+                    event.p_memory_records[i],
+                    &mut new_byte_lookup_events,
+                );
+            }
+
+            rows.push(row);
+        }
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        // The padding rows double the generator, since doubling zero divides a non-zero `a` by
+        // zero.
+        let (generator_x, generator_y) = self.curve.generator();
+        pad_rows(&mut rows, || {
+            let mut row = [F::zero(); NUM_WEIERSTRASS_REGISTERED_DOUBLE_COLS];
+            let cols: &mut WeierstrassRegisteredDoubleCols<F> = row.as_mut_slice().borrow_mut();
+            self.populate_field_ops(
+                &mut vec![],
+                0,
+                0,
+                cols,
+                generator_x.clone(),
+                generator_y.clone(),
+            );
+            row
+        });
+
+        // Convert the trace to a row major matrix.
+        let mut trace = RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            NUM_WEIERSTRASS_REGISTERED_DOUBLE_COLS,
+        );
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut WeierstrassRegisteredDoubleCols<F> = trace.values[i
+                * NUM_WEIERSTRASS_REGISTERED_DOUBLE_COLS
+                ..(i + 1) * NUM_WEIERSTRASS_REGISTERED_DOUBLE_COLS]
+                .borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        shard.registered_curve_double_events.iter().any(|e| e.curve_id == self.curve.id)
+    }
+}
+
+impl<F> BaseAir<F> for WeierstrassRegisteredDoubleChip {
+    fn width(&self) -> usize {
+        NUM_WEIERSTRASS_REGISTERED_DOUBLE_COLS
+    }
+}
+
+impl<AB> Air<AB> for WeierstrassRegisteredDoubleChip
+where
+    AB: SP1AirBuilder,
+    Limbs<AB::Var, <P as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &WeierstrassRegisteredDoubleCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &WeierstrassRegisteredDoubleCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        let num_words_field_element = P::NB_LIMBS / 4;
+        let p_x = limbs_from_prev_access(&local.p_access[0..num_words_field_element]);
+        let p_y = limbs_from_prev_access(&local.p_access[num_words_field_element..]);
+
+        // The modulus and `a` in the Weierstrass form y^2 = x^3 + a * x + b are constants of the
+        // chip.
+        let modulus = P::to_limbs_field::<AB::Expr, _>(&self.curve.modulus_int());
+        let a = P::to_limbs_field::<AB::Expr, _>(&self.curve.a_int());
+
+        // slope = slope_numerator / slope_denominator.
+        let slope = {
+            // slope_numerator = a + (p.x * p.x) * 3.
+            {
+                local.p_x_squared.eval_with_modulus(
+                    builder,
+                    &p_x,
+                    &p_x,
+                    &modulus,
+                    FieldOperation::Mul,
+                    local.shard,
+                    local.channel,
+                    local.is_real,
+                );
+
+                local.p_x_squared_times_3.eval_with_modulus(
+                    builder,
+                    &local.p_x_squared.result,
+                    &P::to_limbs_field::<AB::Expr, _>(&BigUint::from(3u32)),
+                    &modulus,
+                    FieldOperation::Mul,
+                    local.shard,
+                    local.channel,
+                    local.is_real,
+                );
+
+                local.slope_numerator.eval_with_modulus(
+                    builder,
+                    &a,
+                    &local.p_x_squared_times_3.result,
+                    &modulus,
+                    FieldOperation::Add,
+                    local.shard,
+                    local.channel,
+                    local.is_real,
+                );
+            };
+
+            // slope_denominator = 2 * y.
+            local.slope_denominator.eval_with_modulus(
+                builder,
+                &P::to_limbs_field::<AB::Expr, _>(&BigUint::from(2u32)),
+                &p_y,
+                &modulus,
+                FieldOperation::Mul,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            local.slope.eval_with_modulus(
+                builder,
+                &local.slope_numerator.result,
+                &local.slope_denominator.result,
+                &modulus,
+                FieldOperation::Div,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+
+            &local.slope.result
+        };
+
+        // x = slope * slope - (p.x + p.x).
+        let x = {
+            local.slope_squared.eval_with_modulus(
+                builder,
+                slope,
+                slope,
+                &modulus,
+                FieldOperation::Mul,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+            local.p_x_plus_p_x.eval_with_modulus(
+                builder,
+                &p_x,
+                &p_x,
+                &modulus,
+                FieldOperation::Add,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+            local.x3_ins.eval_with_modulus(
+                builder,
+                &local.slope_squared.result,
+                &local.p_x_plus_p_x.result,
+                &modulus,
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+            &local.x3_ins.result
+        };
+
+        // y = slope * (p.x - x) - p.y.
+        {
+            local.p_x_minus_x.eval_with_modulus(
+                builder,
+                &p_x,
+                x,
+                &modulus,
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+            local.slope_times_p_x_minus_x.eval_with_modulus(
+                builder,
+                slope,
+                &local.p_x_minus_x.result,
+                &modulus,
+                FieldOperation::Mul,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+            local.y3_ins.eval_with_modulus(
+                builder,
+                &local.slope_times_p_x_minus_x.result,
+                &p_y,
+                &modulus,
+                FieldOperation::Sub,
+                local.shard,
+                local.channel,
+                local.is_real,
+            );
+        }
+
+        // Constraint self.p_access.value = [self.x3_ins.result, self.y3_ins.result]. This is to
+        // ensure that p_access is updated with the new value.
+        for i in 0..P::NB_LIMBS {
+            builder
+                .when(local.is_real)
+                .assert_eq(local.x3_ins.result[i], local.p_access[i / 4].value()[i % 4]);
+            builder.when(local.is_real).assert_eq(
+                local.y3_ins.result[i],
+                local.p_access[num_words_field_element + i / 4].value()[i % 4],
+            );
+        }
+
+        // Constrain the curve id which prefixes p to be the curve of the chip.
+        for (byte, expected) in
+            local.curve_id_access.value().0.iter().zip(self.curve.id.to_le_bytes())
+        {
+            builder.when(local.is_real).assert_eq(*byte, AB::F::from_canonical_u8(expected));
+        }
+
+        builder.eval_memory_access(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.p_ptr,
+            &local.curve_id_access,
+            local.is_real,
+        );
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into(),
+            local.p_ptr + AB::F::from_canonical_u32(4),
+            &local.p_access,
+            local.is_real,
+        );
+
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::REGISTERED_CURVE_DOUBLE.syscall_id()),
+            local.p_ptr,
+            AB::Expr::zero(),
+            local.is_real,
+        );
+    }
+}
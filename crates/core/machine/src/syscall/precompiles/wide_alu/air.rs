@@ -0,0 +1,340 @@
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
+    utils::pad_rows,
+};
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{
+    events::{ByteRecord, WideAluOperation},
+    syscalls::{wide_alu, SyscallCode, WIDE_ALU_WORDS},
+    ExecutionRecord, Program,
+};
+use sp1_derive::AlignedBorrow;
+use sp1_stark::air::{MachineAir, SP1AirBuilder};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+/// The number of columns in the WideAluCols.
+const NUM_COLS: usize = size_of::<WideAluCols<u8>>();
+
+/// The number of bytes in each operand.
+const NUM_BYTES: usize = 4 * WIDE_ALU_WORDS;
+
+#[derive(Default)]
+pub struct WideAluChip;
+
+impl WideAluChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the wide ALU operations.
+///
+/// Addition, subtraction and comparison share a 64-bit adder computing
+/// `sum_a + y = sum_c + 2^64 * sum_carries[7]`:
+/// - for `x + y`, `sum_a` is `x` and `sum_c` is the result.
+/// - for `x - y`, `sum_a` is the result and `sum_c` is `x`.
+/// - for `x < y`, `sum_a` is `x - y` and `sum_c` is `x`, so that the result is the carry out.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct WideAluCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The byte lookup channel.
+    pub channel: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The nonce of the operation.
+    pub nonce: T,
+
+    /// The pointer to the first operand, which is overwritten with the result.
+    pub x_ptr: T,
+
+    /// The pointer to the second operand.
+    pub y_ptr: T,
+
+    // Memory columns.
+    // x_memory holds x in its "prev_value" and is written to with the result, which is why it is
+    // of type MemoryWriteCols.
+    pub x_memory: [MemoryWriteCols<T>; WIDE_ALU_WORDS],
+    pub y_memory: [MemoryReadCols<T>; WIDE_ALU_WORDS],
+
+    /// Selectors of the operation.
+    pub is_add: T,
+    pub is_sub: T,
+    pub is_mul: T,
+    pub is_ltu: T,
+
+    /// The first summand of the adder.
+    pub sum_a: [T; NUM_BYTES],
+
+    /// The sum of the adder, modulo 2^64.
+    pub sum_c: [T; NUM_BYTES],
+
+    /// The carry out of each byte of the adder.
+    pub sum_carries: [T; NUM_BYTES],
+
+    /// The carry out of each byte of the product, which is at most 16 bits.
+    pub mul_carries: [T; NUM_BYTES],
+
+    pub is_real: T,
+}
+
+/// Returns the little endian bytes of the given words.
+fn words_to_bytes(words: &[u32]) -> [u8; NUM_BYTES] {
+    let mut bytes = [0u8; NUM_BYTES];
+    for (i, word) in words.iter().enumerate() {
+        bytes[4 * i..4 * i + 4].copy_from_slice(&word.to_le_bytes());
+    }
+    bytes
+}
+
+impl<F: PrimeField32> MachineAir<F> for WideAluChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "WideAlu".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut new_byte_lookup_events = Vec::new();
+        let mut rows = input
+            .wide_alu_events
+            .iter()
+            .map(|event| {
+                let mut row: [F; NUM_COLS] = [F::zero(); NUM_COLS];
+                let cols: &mut WideAluCols<F> = row.as_mut_slice().borrow_mut();
+
+                // Assign basic values to the columns.
+                cols.is_real = F::one();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.channel = F::from_canonical_u8(event.channel);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.x_ptr = F::from_canonical_u32(event.x_ptr);
+                cols.y_ptr = F::from_canonical_u32(event.y_ptr);
+                cols.is_add = F::from_bool(event.op == WideAluOperation::Add);
+                cols.is_sub = F::from_bool(event.op == WideAluOperation::Sub);
+                cols.is_mul = F::from_bool(event.op == WideAluOperation::Mul);
+                cols.is_ltu = F::from_bool(event.op == WideAluOperation::Ltu);
+
+                // Populate memory columns.
+                for i in 0..WIDE_ALU_WORDS {
+                    cols.x_memory[i].populate(
+                        event.channel,
+                        event.x_memory_records[i],
+                        &mut new_byte_lookup_events,
+                    );
+                    cols.y_memory[i].populate(
+                        event.channel,
+                        event.y_memory_records[i],
+                        &mut new_byte_lookup_events,
+                    );
+                }
+
+                let x = u64::from_le_bytes(words_to_bytes(&event.x));
+                let y = u64::from_le_bytes(words_to_bytes(&event.y));
+                let result = wide_alu(event.op, x, y);
+                let x_bytes = x.to_le_bytes();
+                let y_bytes = y.to_le_bytes();
+                let result_bytes = result.to_le_bytes();
+                new_byte_lookup_events.add_u8_range_checks(
+                    event.shard,
+                    event.channel,
+                    &result_bytes,
+                );
+
+                if event.op == WideAluOperation::Mul {
+                    // Propagate the carries of the convolution of the bytes of x and y.
+                    let mut carry = 0u32;
+                    let mut carries = [0u16; NUM_BYTES];
+                    for k in 0..NUM_BYTES {
+                        let sum = (0..=k)
+                            .map(|i| u32::from(x_bytes[i]) * u32::from(y_bytes[k - i]))
+                            .sum::<u32>()
+                            + carry;
+                        debug_assert_eq!(sum as u8, result_bytes[k]);
+                        carry = sum >> 8;
+                        carries[k] = carry as u16;
+                        cols.mul_carries[k] = F::from_canonical_u32(carry);
+                    }
+                    new_byte_lookup_events.add_u16_range_checks(
+                        event.shard,
+                        event.channel,
+                        &carries,
+                    );
+                } else {
+                    let sum_a = match event.op {
+                        WideAluOperation::Add => x,
+                        WideAluOperation::Sub => result,
+                        _ => x.wrapping_sub(y),
+                    }
+                    .to_le_bytes();
+                    let mut sum_c = [0u8; NUM_BYTES];
+                    let mut carry = 0u32;
+                    for i in 0..NUM_BYTES {
+                        let sum = u32::from(sum_a[i]) + u32::from(y_bytes[i]) + carry;
+                        sum_c[i] = sum as u8;
+                        carry = sum >> 8;
+                        cols.sum_a[i] = F::from_canonical_u8(sum_a[i]);
+                        cols.sum_c[i] = F::from_canonical_u8(sum_c[i]);
+                        cols.sum_carries[i] = F::from_canonical_u32(carry);
+                    }
+                    new_byte_lookup_events.add_u8_range_checks(event.shard, event.channel, &sum_a);
+                    new_byte_lookup_events.add_u8_range_checks(event.shard, event.channel, &sum_c);
+                }
+
+                row
+            })
+            .collect::<Vec<_>>();
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows(&mut rows, || [F::zero(); NUM_COLS]);
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut WideAluCols<F> =
+                trace.values[i * NUM_COLS..(i + 1) * NUM_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.wide_alu_events.is_empty()
+    }
+}
+
+impl<F> BaseAir<F> for WideAluChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for WideAluChip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &WideAluCols<AB::Var> = (*local).borrow();
+        let next = main.row_slice(1);
+        let next: &WideAluCols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        // Assert that exactly one operation is selected on the real rows.
+        builder.assert_bool(local.is_add);
+        builder.assert_bool(local.is_sub);
+        builder.assert_bool(local.is_mul);
+        builder.assert_bool(local.is_ltu);
+        builder.assert_bool(local.is_real);
+        builder.assert_eq(local.is_add + local.is_sub + local.is_mul + local.is_ltu, local.is_real);
+        let uses_adder: AB::Expr = local.is_add + local.is_sub + local.is_ltu;
+
+        // The bytes of x are the "prev_value" of x_memory, since we write the result to it.
+        let x = local.x_memory.iter().flat_map(|access| access.prev_value().0).collect::<Vec<_>>();
+        let y = local.y_memory.iter().flat_map(|access| access.value().0).collect::<Vec<_>>();
+        let result = local.x_memory.iter().flat_map(|access| access.value().0).collect::<Vec<_>>();
+        builder.slice_range_check_u8(&result, local.shard, local.channel, local.is_real);
+
+        // Wire the operands of the adder.
+        for i in 0..NUM_BYTES {
+            builder.when(local.is_add).assert_eq(local.sum_a[i], x[i]);
+            builder.when(local.is_add).assert_eq(local.sum_c[i], result[i]);
+            builder.when(local.is_sub).assert_eq(local.sum_a[i], result[i]);
+            builder.when(local.is_sub).assert_eq(local.sum_c[i], x[i]);
+            builder.when(local.is_ltu).assert_eq(local.sum_c[i], x[i]);
+        }
+
+        // The result of a comparison is the carry out of `(x - y) + y = x`, which is 1 exactly
+        // when the subtraction wraps around.
+        builder.when(local.is_ltu).assert_eq(result[0], local.sum_carries[NUM_BYTES - 1]);
+        for byte in &result[1..] {
+            builder.when(local.is_ltu).assert_zero(*byte);
+        }
+
+        // Constrain the adder byte by byte.
+        for i in 0..NUM_BYTES {
+            builder.assert_bool(local.sum_carries[i]);
+            let carry_in: AB::Expr =
+                if i == 0 { AB::Expr::zero() } else { local.sum_carries[i - 1].into() };
+            builder.when(uses_adder.clone()).assert_eq(
+                local.sum_a[i] + y[i] + carry_in,
+                local.sum_c[i] + local.sum_carries[i] * AB::F::from_canonical_u32(256),
+            );
+        }
+        builder.slice_range_check_u8(&local.sum_a, local.shard, local.channel, uses_adder.clone());
+        builder.slice_range_check_u8(&local.sum_c, local.shard, local.channel, uses_adder);
+
+        // Constrain the product byte by byte. Each convolution sum is less than 2^20 and each
+        // carry is range checked to be a u16, so the equations can not wrap around the field.
+        for k in 0..NUM_BYTES {
+            let carry_in: AB::Expr =
+                if k == 0 { AB::Expr::zero() } else { local.mul_carries[k - 1].into() };
+            let sum = (0..=k).map(|i| x[i] * y[k - i]).sum::<AB::Expr>() + carry_in;
+            builder
+                .when(local.is_mul)
+                .assert_eq(sum, result[k] + local.mul_carries[k] * AB::F::from_canonical_u32(256));
+        }
+        builder.slice_range_check_u16(&local.mul_carries, local.shard, local.channel, local.is_mul);
+
+        // Read and write x.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into() + AB::Expr::one(),
+            local.x_ptr,
+            &local.x_memory,
+            local.is_real,
+        );
+
+        // Read y.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk.into(),
+            local.y_ptr,
+            &local.y_memory,
+            local.is_real,
+        );
+
+        // Receive the arguments.
+        let syscall_id = local.is_add
+            * AB::F::from_canonical_u32(SyscallCode::U64_ADD.syscall_id())
+            + local.is_sub * AB::F::from_canonical_u32(SyscallCode::U64_SUB.syscall_id())
+            + local.is_mul * AB::F::from_canonical_u32(SyscallCode::U64_MUL.syscall_id())
+            + local.is_ltu * AB::F::from_canonical_u32(SyscallCode::U64_LTU.syscall_id());
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            syscall_id,
+            local.x_ptr,
+            local.y_ptr,
+            local.is_real,
+        );
+    }
+}
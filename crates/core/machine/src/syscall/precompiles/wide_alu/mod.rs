@@ -0,0 +1,80 @@
+mod air;
+
+pub use air::*;
+
+#[cfg(test)]
+pub mod wide_alu_tests {
+    use sp1_core_executor::{
+        events::WideAluOperation,
+        syscalls::{wide_alu, SyscallCode},
+        Executor, Instruction, Opcode, Program,
+    };
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{self, run_test};
+
+    const OPS: [(SyscallCode, WideAluOperation); 4] = [
+        (SyscallCode::U64_ADD, WideAluOperation::Add),
+        (SyscallCode::U64_SUB, WideAluOperation::Sub),
+        (SyscallCode::U64_MUL, WideAluOperation::Mul),
+        (SyscallCode::U64_LTU, WideAluOperation::Ltu),
+    ];
+
+    /// Builds a program that applies each of the given operations to `x` and `y`, writing the
+    /// result of the `i`-th one to the `i`-th 64-bit slot after 1000.
+    pub fn wide_alu_program(x: u64, y: u64, ops: &[SyscallCode]) -> Program {
+        let y_ptr = 100;
+        let mut instructions = vec![];
+        let mut store = |value: u64, ptr: u32| {
+            for (i, word) in [value as u32, (value >> 32) as u32].into_iter().enumerate() {
+                instructions.extend(vec![
+                    Instruction::new(Opcode::ADD, 29, 0, word, false, true),
+                    Instruction::new(Opcode::ADD, 30, 0, ptr + 4 * i as u32, false, true),
+                    Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+                ]);
+            }
+        };
+        store(y, y_ptr);
+        for i in 0..ops.len() {
+            store(x, 1000 + 8 * i as u32);
+        }
+        for (i, op) in ops.iter().enumerate() {
+            instructions.extend(vec![
+                Instruction::new(Opcode::ADD, 5, 0, *op as u32, false, true),
+                Instruction::new(Opcode::ADD, 10, 0, 1000 + 8 * i as u32, false, true),
+                Instruction::new(Opcode::ADD, 11, 0, y_ptr, false, true),
+                Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+            ]);
+        }
+        Program::new(instructions, 0, 0)
+    }
+
+    #[test]
+    fn test_wide_alu_execute() {
+        let cases = [
+            (0xffff_ffff_ffff_fffe, 3),
+            (0x0123_4567_89ab_cdef, 0xfedc_ba98_7654_3210),
+            (7, 7),
+            (0, u64::MAX),
+        ];
+        let codes = OPS.map(|(code, _)| code);
+        for (x, y) in cases {
+            let mut runtime = Executor::new(wide_alu_program(x, y, &codes), SP1CoreOpts::default());
+            runtime.run().unwrap();
+            for (i, (_, op)) in OPS.iter().enumerate() {
+                let ptr = 1000 + 8 * i as u32;
+                let result =
+                    u64::from(runtime.word(ptr)) | (u64::from(runtime.word(ptr + 4)) << 32);
+                assert_eq!(result, wide_alu(*op, x, y), "{op:?}({x}, {y})");
+            }
+        }
+    }
+
+    #[test]
+    fn test_wide_alu_prove() {
+        utils::setup_logger();
+        let codes = OPS.map(|(code, _)| code);
+        run_test::<CpuProver<_, _>>(wide_alu_program(3, u64::MAX - 1, &codes)).unwrap();
+        run_test::<CpuProver<_, _>>(wide_alu_program(0x0123_4567_89ab_cdef, 5, &codes)).unwrap();
+    }
+}
@@ -0,0 +1,397 @@
+use core::borrow::Borrow;
+
+use num::BigUint;
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::AbstractField;
+use p3_matrix::Matrix;
+use sp1_core_executor::{
+    events::FieldOperation,
+    syscalls::{SyscallCode, X25519_A24, X25519_LADDER_STEPS, X25519_WORDS},
+};
+use sp1_curves::{edwards::ed25519::Ed25519BaseField, params::FieldParameters};
+use sp1_stark::air::{recompose_bits, BaseAirBuilder, SP1AirBuilder};
+
+use super::{
+    columns::{X25519Cols, NUM_X25519_COLS},
+    X25519Chip, CURRENT_BIT, SCALAR_BITS,
+};
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::{value_as_limbs, MemoryCols},
+};
+
+impl<F> BaseAir<F> for X25519Chip {
+    fn width(&self) -> usize {
+        NUM_X25519_COLS
+    }
+}
+
+/// Returns `a` if `condition` is zero and `b` if it is one.
+fn select<AB: SP1AirBuilder>(
+    condition: AB::Var,
+    a: impl Into<AB::Expr>,
+    b: impl Into<AB::Expr>,
+) -> AB::Expr {
+    let a: AB::Expr = a.into();
+    a.clone() + condition.into() * (b.into() - a)
+}
+
+impl<AB> Air<AB> for X25519Chip
+where
+    AB: SP1AirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &X25519Cols<AB::Var> = (*local).borrow();
+        let next: &X25519Cols<AB::Var> = (*next).borrow();
+
+        // Constrain the incrementing nonce.
+        builder.when_first_row().assert_zero(local.nonce);
+        builder.when_transition().assert_eq(local.nonce + AB::Expr::one(), next.nonce);
+
+        self.eval_flags(builder, local, next);
+
+        // Constrain memory in the first and last rows. The u-coordinate is written one cycle
+        // after it is read.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.k_ptr,
+            &local.k_access,
+            local.is_first,
+        );
+        for (i, access) in local.u_access.iter().enumerate() {
+            builder.eval_memory_access(
+                local.shard,
+                local.channel,
+                local.clk + local.is_last,
+                local.u_ptr + AB::F::from_canonical_u32(4 * i as u32),
+                access,
+                local.is_first + local.is_last,
+            );
+        }
+        for i in 0..X25519_WORDS {
+            builder
+                .when(local.is_first)
+                .assert_word_eq(*local.u_access[i].value(), *local.u_access[i].prev_value());
+        }
+
+        // Receive the syscall in the first row of each scalar multiplication.
+        builder.receive_syscall(
+            local.shard,
+            local.channel,
+            local.clk,
+            local.nonce,
+            AB::F::from_canonical_u32(SyscallCode::X25519_SCALAR_MULT.syscall_id()),
+            local.u_ptr,
+            local.k_ptr,
+            local.is_first,
+        );
+
+        // The u-coordinate is read in the first row, and kept throughout the ladder.
+        builder.when(local.is_first).assert_all_eq(local.x1, value_as_limbs(&local.u_access));
+        builder.slice_range_check_u8(&local.x1.0, local.shard, local.channel, local.is_first);
+
+        self.eval_scalar_bits(builder, local, next);
+
+        // The ladder starts from `(x2 : z2) = (1 : 0)` and `(x3 : z3) = (x1 : 1)`.
+        let one = Ed25519BaseField::to_limbs_field::<AB::Expr, _>(&BigUint::from(1u32));
+        for i in 0..one.0.len() {
+            let (one, zero) = (one[i].clone(), AB::Expr::zero());
+            let mut first = builder.when(local.is_first);
+            first.assert_eq(local.x2[i], select::<AB>(local.swap, one.clone(), local.x1[i]));
+            first.assert_eq(local.z2[i], select::<AB>(local.swap, zero.clone(), one.clone()));
+            first.assert_eq(local.x3[i], select::<AB>(local.swap, local.x1[i], one.clone()));
+            first.assert_eq(local.z3[i], select::<AB>(local.swap, one, zero));
+        }
+
+        self.eval_ladder_step(builder, local);
+
+        // The next row swaps the new state if the bit of its step differs from the current one.
+        let x2_out = local.x2_out.result;
+        let z2_out = local.z2_out.result;
+        let x3_out = local.x3_out.result;
+        let z3_out = local.z3_out.result;
+        for i in 0..x2_out.0.len() {
+            let mut continues = builder.when(local.continues);
+            continues.assert_eq(next.x2[i], select::<AB>(next.swap, x2_out[i], x3_out[i]));
+            continues.assert_eq(next.z2[i], select::<AB>(next.swap, z2_out[i], z3_out[i]));
+            continues.assert_eq(next.x3[i], select::<AB>(next.swap, x3_out[i], x2_out[i]));
+            continues.assert_eq(next.z3[i], select::<AB>(next.swap, z3_out[i], z2_out[i]));
+        }
+
+        // The last step undoes its swap to get the final state.
+        let current_bit = local.scalar_bits[CURRENT_BIT];
+        for i in 0..x2_out.0.len() {
+            let mut last = builder.when(local.is_last);
+            last.assert_eq(local.final_x[i], select::<AB>(current_bit, x2_out[i], x3_out[i]));
+            last.assert_eq(local.final_z[i], select::<AB>(current_bit, z2_out[i], z3_out[i]));
+        }
+
+        self.eval_result(builder, local);
+    }
+}
+
+impl X25519Chip {
+    /// Constrains that each scalar multiplication takes exactly `X25519_LADDER_STEPS` rows, and
+    /// that the syscall arguments are the same in all of them.
+    fn eval_flags<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &X25519Cols<AB::Var>,
+        next: &X25519Cols<AB::Var>,
+    ) {
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_first);
+        builder.assert_bool(local.is_last);
+        builder.assert_eq(local.continues, local.is_real - local.is_last);
+        builder.assert_bool(local.continues);
+        builder.when(local.is_first).assert_one(local.is_real);
+
+        builder.when(local.is_first).assert_zero(local.step);
+        builder
+            .when(local.is_last)
+            .assert_eq(local.step, AB::F::from_canonical_usize(X25519_LADDER_STEPS - 1));
+
+        // A scalar multiplication starts in the first row or after the last row of another one,
+        // and continues until its last row.
+        builder.when_first_row().assert_eq(local.is_first, local.is_real);
+        builder.when_transition().assert_eq(local.continues, next.is_real - next.is_first);
+        builder.when_last_row().assert_zero(local.continues);
+
+        let mut continues = builder.when(local.continues);
+        continues.assert_eq(next.step, local.step + AB::Expr::one());
+        continues.assert_eq(next.shard, local.shard);
+        continues.assert_eq(next.channel, local.channel);
+        continues.assert_eq(next.clk, local.clk);
+        continues.assert_eq(next.u_ptr, local.u_ptr);
+        continues.assert_eq(next.k_ptr, local.k_ptr);
+        continues.assert_all_eq(next.x1, local.x1);
+    }
+
+    /// Constrains the scalar bits to the scalar read in the first row, shifted by one each row.
+    fn eval_scalar_bits<AB: SP1AirBuilder>(
+        &self,
+        builder: &mut AB,
+        local: &X25519Cols<AB::Var>,
+        next: &X25519Cols<AB::Var>,
+    ) {
+        for bit in local.scalar_bits {
+            builder.assert_bool(bit);
+        }
+
+        // In the first row, the bits are those of the scalar, whose top bit is zero.
+        for (i, access) in local.k_access.iter().enumerate() {
+            for (j, byte) in access.value().0.iter().enumerate() {
+                let bits = &local.scalar_bits[32 * i + 8 * j..32 * i + 8 * j + 8];
+                let value = recompose_bits::<AB, _>(bits.iter().copied());
+                builder.when(local.is_first).assert_eq(*byte, value);
+            }
+        }
+        builder.when(local.is_first).assert_zero(local.scalar_bits[SCALAR_BITS - 1]);
+
+        // The bits move up by one each row.
+        let mut continues = builder.when(local.continues);
+        continues.assert_zero(next.scalar_bits[0]);
+        for i in 1..SCALAR_BITS {
+            continues.assert_eq(next.scalar_bits[i], local.scalar_bits[i - 1]);
+        }
+
+        // The state is swapped if the bit of this step differs from the bit of the previous one.
+        let current_bit = local.scalar_bits[CURRENT_BIT];
+        let previous_bit = local.scalar_bits[CURRENT_BIT + 1];
+        builder.when(local.is_real).assert_eq(
+            local.swap,
+            current_bit + previous_bit - AB::Expr::two() * current_bit * previous_bit,
+        );
+    }
+
+    /// Constrains the ladder step of RFC 7748 on `(x2 : z2)` and `(x3 : z3)`.
+    fn eval_ladder_step<AB: SP1AirBuilder>(&self, builder: &mut AB, local: &X25519Cols<AB::Var>) {
+        let (shard, channel, is_real) = (local.shard, local.channel, local.is_real);
+        let a24 = Ed25519BaseField::to_limbs_field::<AB::Expr, _>(&BigUint::from(X25519_A24));
+
+        // A = x2 + z2, B = x2 - z2, and E = A^2 - B^2.
+        local.a.eval(builder, &local.x2, &local.z2, FieldOperation::Add, shard, channel, is_real);
+        local.aa.eval(
+            builder,
+            &local.a.result,
+            &local.a.result,
+            FieldOperation::Mul,
+            shard,
+            channel,
+            is_real,
+        );
+        local.b.eval(builder, &local.x2, &local.z2, FieldOperation::Sub, shard, channel, is_real);
+        local.bb.eval(
+            builder,
+            &local.b.result,
+            &local.b.result,
+            FieldOperation::Mul,
+            shard,
+            channel,
+            is_real,
+        );
+        local.e.eval(
+            builder,
+            &local.aa.result,
+            &local.bb.result,
+            FieldOperation::Sub,
+            shard,
+            channel,
+            is_real,
+        );
+
+        // C = x3 + z3, D = x3 - z3, and the new x3 = (DA + CB)^2.
+        local.c.eval(builder, &local.x3, &local.z3, FieldOperation::Add, shard, channel, is_real);
+        local.d.eval(builder, &local.x3, &local.z3, FieldOperation::Sub, shard, channel, is_real);
+        local.da.eval(
+            builder,
+            &local.d.result,
+            &local.a.result,
+            FieldOperation::Mul,
+            shard,
+            channel,
+            is_real,
+        );
+        local.cb.eval(
+            builder,
+            &local.c.result,
+            &local.b.result,
+            FieldOperation::Mul,
+            shard,
+            channel,
+            is_real,
+        );
+        local.da_plus_cb.eval(
+            builder,
+            &local.da.result,
+            &local.cb.result,
+            FieldOperation::Add,
+            shard,
+            channel,
+            is_real,
+        );
+        local.x3_out.eval(
+            builder,
+            &local.da_plus_cb.result,
+            &local.da_plus_cb.result,
+            FieldOperation::Mul,
+            shard,
+            channel,
+            is_real,
+        );
+
+        // The new z3 = x1 * (DA - CB)^2.
+        local.da_minus_cb.eval(
+            builder,
+            &local.da.result,
+            &local.cb.result,
+            FieldOperation::Sub,
+            shard,
+            channel,
+            is_real,
+        );
+        local.da_minus_cb_squared.eval(
+            builder,
+            &local.da_minus_cb.result,
+            &local.da_minus_cb.result,
+            FieldOperation::Mul,
+            shard,
+            channel,
+            is_real,
+        );
+        local.z3_out.eval(
+            builder,
+            &local.x1,
+            &local.da_minus_cb_squared.result,
+            FieldOperation::Mul,
+            shard,
+            channel,
+            is_real,
+        );
+
+        // The new x2 = A^2 * B^2 and z2 = E * (A^2 + a24 * E).
+        local.x2_out.eval(
+            builder,
+            &local.aa.result,
+            &local.bb.result,
+            FieldOperation::Mul,
+            shard,
+            channel,
+            is_real,
+        );
+        local.a24_e.eval(
+            builder,
+            &a24,
+            &local.e.result,
+            FieldOperation::Mul,
+            shard,
+            channel,
+            is_real,
+        );
+        local.aa_plus_a24_e.eval(
+            builder,
+            &local.aa.result,
+            &local.a24_e.result,
+            FieldOperation::Add,
+            shard,
+            channel,
+            is_real,
+        );
+        local.z2_out.eval(
+            builder,
+            &local.e.result,
+            &local.aa_plus_a24_e.result,
+            FieldOperation::Mul,
+            shard,
+            channel,
+            is_real,
+        );
+    }
+
+    /// Constrains the result written in the last row to `final_x / final_z`, or zero if `final_z`
+    /// is zero.
+    fn eval_result<AB: SP1AirBuilder>(&self, builder: &mut AB, local: &X25519Cols<AB::Var>) {
+        let (shard, channel, is_real) = (local.shard, local.channel, local.is_real);
+
+        builder.assert_bool(local.z_is_zero);
+        builder.slice_range_check_u8(&local.z_inverse.0, shard, channel, is_real);
+        local.z_mul_inverse.eval(
+            builder,
+            &local.final_z,
+            &local.z_inverse,
+            FieldOperation::Mul,
+            shard,
+            channel,
+            is_real,
+        );
+        local.result.eval(
+            builder,
+            &local.final_x,
+            &local.z_inverse,
+            FieldOperation::Mul,
+            shard,
+            channel,
+            is_real,
+        );
+
+        // If `final_z` is not zero, then `z_inverse` is its inverse. Otherwise, both are zero, so
+        // the result is zero.
+        let mut last = builder.when(local.is_last);
+        last.assert_eq(local.z_mul_inverse.result[0], AB::Expr::one() - local.z_is_zero);
+        for limb in local.z_mul_inverse.result.0.iter().skip(1) {
+            last.assert_zero(*limb);
+        }
+        let mut zero = last.when(local.z_is_zero);
+        zero.assert_all_zero(local.final_z);
+        zero.assert_all_zero(local.z_inverse);
+
+        // The result is written to the u-coordinate.
+        builder
+            .when(local.is_last)
+            .assert_all_eq(local.result.result, value_as_limbs(&local.u_access));
+    }
+}
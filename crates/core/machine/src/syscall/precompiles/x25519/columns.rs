@@ -0,0 +1,100 @@
+use core::mem::size_of;
+
+use sp1_core_executor::syscalls::X25519_WORDS;
+use sp1_curves::{
+    edwards::ed25519::Ed25519BaseField,
+    params::{Limbs, NumLimbs},
+};
+use sp1_derive::AlignedBorrow;
+
+use crate::{
+    memory::{MemoryReadCols, MemoryReadWriteCols},
+    operations::field::field_op::FieldOpCols,
+};
+
+use super::SCALAR_BITS;
+
+pub const NUM_X25519_COLS: usize = size_of::<X25519Cols<u8>>();
+
+/// The limbs of an element of the curve25519 base field.
+pub type FieldLimbs<T> = Limbs<T, <Ed25519BaseField as NumLimbs>::Limbs>;
+
+/// X25519Cols is the column layout for one step of the Montgomery ladder.
+///
+/// Each scalar multiplication takes 255 rows, one per bit of the scalar from the most significant
+/// one. The ladder state is passed from each row to the next, and the memory is accessed in the
+/// first and last rows.
+#[derive(AlignedBorrow)]
+#[repr(C)]
+pub struct X25519Cols<T> {
+    pub shard: T,
+    pub channel: T,
+    pub clk: T,
+    pub nonce: T,
+    pub u_ptr: T,
+    pub k_ptr: T,
+
+    /// The index of the ladder step, from 0 to 254.
+    pub step: T,
+
+    /// The u-coordinate is read in the first row and written in the last row.
+    pub u_access: [MemoryReadWriteCols<T>; X25519_WORDS],
+    pub k_access: [MemoryReadCols<T>; X25519_WORDS],
+
+    /// The u-coordinate read in the first row.
+    pub x1: FieldLimbs<T>,
+
+    /// The bits of the scalar, shifted up by one each row so that the bit of the current step is
+    /// at index 254 and the bit of the previous step at index 255.
+    pub scalar_bits: [T; SCALAR_BITS],
+
+    /// Whether the ladder state is swapped before this step, i.e. the xor of the bits of this step
+    /// and of the previous one.
+    pub swap: T,
+
+    /// The swapped ladder state `(x2 : z2)` and `(x3 : z3)` the step is applied to.
+    pub x2: FieldLimbs<T>,
+    pub z2: FieldLimbs<T>,
+    pub x3: FieldLimbs<T>,
+    pub z3: FieldLimbs<T>,
+
+    // The ladder step of RFC 7748.
+    pub a: FieldOpCols<T, Ed25519BaseField>,
+    pub aa: FieldOpCols<T, Ed25519BaseField>,
+    pub b: FieldOpCols<T, Ed25519BaseField>,
+    pub bb: FieldOpCols<T, Ed25519BaseField>,
+    pub e: FieldOpCols<T, Ed25519BaseField>,
+    pub c: FieldOpCols<T, Ed25519BaseField>,
+    pub d: FieldOpCols<T, Ed25519BaseField>,
+    pub da: FieldOpCols<T, Ed25519BaseField>,
+    pub cb: FieldOpCols<T, Ed25519BaseField>,
+    pub da_plus_cb: FieldOpCols<T, Ed25519BaseField>,
+    pub x3_out: FieldOpCols<T, Ed25519BaseField>,
+    pub da_minus_cb: FieldOpCols<T, Ed25519BaseField>,
+    pub da_minus_cb_squared: FieldOpCols<T, Ed25519BaseField>,
+    pub z3_out: FieldOpCols<T, Ed25519BaseField>,
+    pub x2_out: FieldOpCols<T, Ed25519BaseField>,
+    pub a24_e: FieldOpCols<T, Ed25519BaseField>,
+    pub aa_plus_a24_e: FieldOpCols<T, Ed25519BaseField>,
+    pub z2_out: FieldOpCols<T, Ed25519BaseField>,
+
+    /// The unswapped ladder state `(final_x : final_z)` after the last step.
+    pub final_x: FieldLimbs<T>,
+    pub final_z: FieldLimbs<T>,
+
+    /// Whether `final_z` is zero, in which case the result is zero.
+    pub z_is_zero: T,
+
+    /// The inverse of `final_z`, or zero if `final_z` is zero.
+    pub z_inverse: FieldLimbs<T>,
+    pub z_mul_inverse: FieldOpCols<T, Ed25519BaseField>,
+
+    /// The resulting u-coordinate, `final_x / final_z`.
+    pub result: FieldOpCols<T, Ed25519BaseField>,
+
+    pub is_first: T,
+    pub is_last: T,
+    /// Whether the next row continues the same scalar multiplication, i.e. `is_real - is_last`.
+    pub continues: T,
+    pub is_real: T,
+}
@@ -0,0 +1,123 @@
+mod air;
+pub mod columns;
+mod trace;
+
+/// The number of scalar bits tracked by the chip. The top bit of the scalar must be zero.
+pub(crate) const SCALAR_BITS: usize = 256;
+
+/// The index of the scalar bit of the current step in the shifted scalar bits.
+pub(crate) const CURRENT_BIT: usize = SCALAR_BITS - 2;
+
+/// A chip for X25519 scalar multiplication with the Montgomery ladder of RFC 7748.
+#[derive(Default)]
+pub struct X25519Chip;
+
+impl X25519Chip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+#[cfg(test)]
+pub mod x25519_tests {
+    use sp1_core_executor::{
+        syscalls::{x25519_scalar_mult, SyscallCode, X25519_WORDS},
+        ExecutionError, Executor, Instruction, Opcode, Program,
+    };
+    use sp1_stark::{CpuProver, SP1CoreOpts};
+
+    use crate::utils::{self, run_test};
+
+    /// The scalar and u-coordinate of the first test vector of RFC 7748, clamped and masked.
+    const K: [u32; X25519_WORDS] = [
+        0x6be346a0, 0x9d7c52f0, 0x4b15163b, 0xdd5e4682, 0x0a4c1462, 0x185afcc1, 0x44226a50,
+        0x449a44ba,
+    ];
+    const U: [u32; X25519_WORDS] = [
+        0x6768dbe6, 0xdb303058, 0xa4c19435, 0x7c5fb124, 0xec246672, 0x3b35b326, 0xa603a910,
+        0x4c1cabd0,
+    ];
+
+    /// Builds a program that multiplies the point with u-coordinate `u` by `k` in place of `u`.
+    pub fn x25519_program(k: [u32; X25519_WORDS], u: [u32; X25519_WORDS]) -> Program {
+        let u_ptr = 100;
+        let k_ptr = 200;
+        let mut instructions = vec![];
+        for (ptr, words) in [(u_ptr, u), (k_ptr, k)] {
+            for (i, word) in words.into_iter().enumerate() {
+                instructions.extend(vec![
+                    Instruction::new(Opcode::ADD, 29, 0, word, false, true),
+                    Instruction::new(Opcode::ADD, 30, 0, ptr + 4 * i as u32, false, true),
+                    Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+                ]);
+            }
+        }
+        instructions.extend(vec![
+            Instruction::new(
+                Opcode::ADD,
+                5,
+                0,
+                SyscallCode::X25519_SCALAR_MULT as u32,
+                false,
+                true,
+            ),
+            Instruction::new(Opcode::ADD, 10, 0, u_ptr, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, k_ptr, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ]);
+        Program::new(instructions, 0, 0)
+    }
+
+    #[test]
+    fn test_x25519_execute() {
+        let mut runtime = Executor::new(x25519_program(K, U), SP1CoreOpts::default());
+        runtime.run().unwrap();
+        let expected = [
+            0x3755dac3, 0x90c6e99d, 0x4dea948e, 0x4f088df2, 0x03cfec32, 0xf7711c49, 0x5507b454,
+            0x5285a277,
+        ];
+        assert_eq!(x25519_scalar_mult(&K, &U), expected);
+        for (i, word) in expected.iter().enumerate() {
+            assert_eq!(runtime.word(100 + 4 * i as u32), *word);
+        }
+    }
+
+    #[test]
+    fn test_x25519_prove() {
+        utils::setup_logger();
+        run_test::<CpuProver<_, _>>(x25519_program(K, U)).unwrap();
+    }
+
+    /// Each scalar multiplication takes a row per ladder step, so the nonce of the second one is
+    /// the index of its first row.
+    #[test]
+    fn test_x25519_prove_twice() {
+        utils::setup_logger();
+        let mut program = x25519_program(K, U);
+        program.instructions.extend(x25519_program(K, [9, 0, 0, 0, 0, 0, 0, 0]).instructions);
+        run_test::<CpuProver<_, _>>(program).unwrap();
+    }
+
+    #[test]
+    fn test_x25519_scalar_top_bit_set() {
+        let mut k = K;
+        k[X25519_WORDS - 1] |= 1 << 31;
+        let result = Executor::new(x25519_program(k, U), SP1CoreOpts::default()).run();
+        assert!(matches!(
+            result,
+            Err(ExecutionError::InvalidSyscallArguments {
+                syscall: SyscallCode::X25519_SCALAR_MULT,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_x25519_low_order_point_prove() {
+        // The ladder ends at the point at infinity, whose denominator is zero.
+        utils::setup_logger();
+        let u = [1, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(x25519_scalar_mult(&K, &u), [0; X25519_WORDS]);
+        run_test::<CpuProver<_, _>>(x25519_program(K, u)).unwrap();
+    }
+}
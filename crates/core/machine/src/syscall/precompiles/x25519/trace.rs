@@ -0,0 +1,252 @@
+use std::borrow::BorrowMut;
+
+use num::{BigUint, One, Zero};
+use p3_field::PrimeField32;
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use p3_maybe_rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+use sp1_core_executor::{
+    events::{ByteRecord, FieldOperation, X25519Event},
+    syscalls::{X25519_A24, X25519_LADDER_STEPS, X25519_WORDS},
+    ExecutionRecord, Program,
+};
+use sp1_curves::{edwards::ed25519::Ed25519BaseField, params::FieldParameters};
+use sp1_stark::air::MachineAir;
+
+use super::{
+    columns::{X25519Cols, NUM_X25519_COLS},
+    X25519Chip, CURRENT_BIT, SCALAR_BITS,
+};
+use crate::utils::pad_rows;
+
+impl<F: PrimeField32> MachineAir<F> for X25519Chip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "X25519".to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let rows_and_records = input
+            .x25519_events
+            .par_iter()
+            .map(|event| {
+                let mut new_byte_lookup_events = Vec::new();
+                let rows = Self::event_to_rows(event, &mut new_byte_lookup_events);
+                (rows, new_byte_lookup_events)
+            })
+            .collect::<Vec<_>>();
+
+        let mut rows = Vec::new();
+        for (mut event_rows, new_byte_lookup_events) in rows_and_records {
+            rows.append(&mut event_rows);
+            output.add_byte_lookup_events(new_byte_lookup_events);
+        }
+
+        pad_rows(&mut rows, || {
+            let mut row = [F::zero(); NUM_X25519_COLS];
+            let cols: &mut X25519Cols<F> = row.as_mut_slice().borrow_mut();
+            let zero = BigUint::zero();
+            Self::populate_ladder_step(&mut vec![], 0, 0, cols, &zero, [&zero; 4]);
+            Self::populate_result(&mut vec![], 0, 0, cols, &zero, &zero);
+            row
+        });
+
+        // Convert the trace to a row major matrix.
+        let mut trace =
+            RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_X25519_COLS);
+
+        // Write the nonces to the trace.
+        for i in 0..trace.height() {
+            let cols: &mut X25519Cols<F> =
+                trace.values[i * NUM_X25519_COLS..(i + 1) * NUM_X25519_COLS].borrow_mut();
+            cols.nonce = F::from_canonical_usize(i);
+        }
+
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        !shard.x25519_events.is_empty()
+    }
+}
+
+impl X25519Chip {
+    /// Creates the rows of the ladder steps of a scalar multiplication.
+    fn event_to_rows<F: PrimeField32>(
+        event: &X25519Event,
+        blu: &mut impl ByteRecord,
+    ) -> Vec<[F; NUM_X25519_COLS]> {
+        let (shard, channel) = (event.shard, event.channel);
+        let x1 = BigUint::from_slice(&event.u);
+        let k = BigUint::from_slice(&event.k);
+        let mut bits = (0..SCALAR_BITS).map(|i| k.bit(i as u64)).collect::<Vec<_>>();
+
+        // The ladder state, which is only swapped when the next step is applied.
+        let (mut x2, mut z2, mut x3, mut z3) =
+            (BigUint::one(), BigUint::zero(), x1.clone(), BigUint::one());
+
+        let mut rows = Vec::with_capacity(X25519_LADDER_STEPS);
+        for step in 0..X25519_LADDER_STEPS {
+            let is_first = step == 0;
+            let is_last = step == X25519_LADDER_STEPS - 1;
+
+            let mut row = [F::zero(); NUM_X25519_COLS];
+            let cols: &mut X25519Cols<F> = row.as_mut_slice().borrow_mut();
+            cols.is_real = F::one();
+            cols.is_first = F::from_bool(is_first);
+            cols.is_last = F::from_bool(is_last);
+            cols.continues = F::from_bool(!is_last);
+            cols.shard = F::from_canonical_u32(shard);
+            cols.channel = F::from_canonical_u8(channel);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.u_ptr = F::from_canonical_u32(event.u_ptr);
+            cols.k_ptr = F::from_canonical_u32(event.k_ptr);
+            cols.step = F::from_canonical_usize(step);
+            cols.x1 = Ed25519BaseField::to_limbs_field::<F, _>(&x1);
+
+            // Read the u-coordinate and the scalar in the first row.
+            if is_first {
+                for i in 0..X25519_WORDS {
+                    cols.u_access[i].populate_read(channel, event.u_read_records[i], blu);
+                    cols.k_access[i].populate(channel, event.k_read_records[i], blu);
+                }
+                blu.add_u8_range_checks_field(shard, channel, &cols.x1.0);
+            }
+
+            for (col, bit) in cols.scalar_bits.iter_mut().zip(bits.iter()) {
+                *col = F::from_bool(*bit);
+            }
+            let swap = bits[CURRENT_BIT] ^ bits[CURRENT_BIT + 1];
+            cols.swap = F::from_bool(swap);
+            if swap {
+                std::mem::swap(&mut x2, &mut x3);
+                std::mem::swap(&mut z2, &mut z3);
+            }
+
+            [x2, z2, x3, z3] =
+                Self::populate_ladder_step(blu, shard, channel, cols, &x1, [&x2, &z2, &x3, &z3]);
+
+            // Undo the last swap and divide in the last row.
+            let (final_x, final_z) = match (is_last, bits[CURRENT_BIT]) {
+                (false, _) => (BigUint::zero(), BigUint::zero()),
+                (true, false) => (x2.clone(), z2.clone()),
+                (true, true) => (x3.clone(), z3.clone()),
+            };
+            let result = Self::populate_result(blu, shard, channel, cols, &final_x, &final_z);
+
+            // Write the result in the last row.
+            if is_last {
+                for i in 0..X25519_WORDS {
+                    cols.u_access[i].populate_write(channel, event.u_write_records[i], blu);
+                }
+                debug_assert_eq!(
+                    BigUint::from_slice(
+                        &event.u_write_records.iter().map(|r| r.value).collect::<Vec<_>>()
+                    ),
+                    result
+                );
+            }
+
+            // Shift the scalar bits so that the next bit is at `CURRENT_BIT`.
+            bits.rotate_right(1);
+            bits[0] = false;
+
+            rows.push(row);
+        }
+        rows
+    }
+
+    /// Populates the columns of a ladder step on `[x2, z2, x3, z3]` and returns the new state.
+    fn populate_ladder_step<F: PrimeField32>(
+        blu: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        cols: &mut X25519Cols<F>,
+        x1: &BigUint,
+        [x2, z2, x3, z3]: [&BigUint; 4],
+    ) -> [BigUint; 4] {
+        cols.x2 = Ed25519BaseField::to_limbs_field::<F, _>(x2);
+        cols.z2 = Ed25519BaseField::to_limbs_field::<F, _>(z2);
+        cols.x3 = Ed25519BaseField::to_limbs_field::<F, _>(x3);
+        cols.z3 = Ed25519BaseField::to_limbs_field::<F, _>(z3);
+
+        let a = cols.a.populate(blu, shard, channel, x2, z2, FieldOperation::Add);
+        let aa = cols.aa.populate(blu, shard, channel, &a, &a, FieldOperation::Mul);
+        let b = cols.b.populate(blu, shard, channel, x2, z2, FieldOperation::Sub);
+        let bb = cols.bb.populate(blu, shard, channel, &b, &b, FieldOperation::Mul);
+        let e = cols.e.populate(blu, shard, channel, &aa, &bb, FieldOperation::Sub);
+        let c = cols.c.populate(blu, shard, channel, x3, z3, FieldOperation::Add);
+        let d = cols.d.populate(blu, shard, channel, x3, z3, FieldOperation::Sub);
+        let da = cols.da.populate(blu, shard, channel, &d, &a, FieldOperation::Mul);
+        let cb = cols.cb.populate(blu, shard, channel, &c, &b, FieldOperation::Mul);
+        let da_plus_cb =
+            cols.da_plus_cb.populate(blu, shard, channel, &da, &cb, FieldOperation::Add);
+        let x3_out = cols.x3_out.populate(
+            blu,
+            shard,
+            channel,
+            &da_plus_cb,
+            &da_plus_cb,
+            FieldOperation::Mul,
+        );
+        let da_minus_cb =
+            cols.da_minus_cb.populate(blu, shard, channel, &da, &cb, FieldOperation::Sub);
+        let da_minus_cb_squared = cols.da_minus_cb_squared.populate(
+            blu,
+            shard,
+            channel,
+            &da_minus_cb,
+            &da_minus_cb,
+            FieldOperation::Mul,
+        );
+        let z3_out = cols.z3_out.populate(
+            blu,
+            shard,
+            channel,
+            x1,
+            &da_minus_cb_squared,
+            FieldOperation::Mul,
+        );
+        let x2_out = cols.x2_out.populate(blu, shard, channel, &aa, &bb, FieldOperation::Mul);
+        let a24 = BigUint::from(X25519_A24);
+        let a24_e = cols.a24_e.populate(blu, shard, channel, &a24, &e, FieldOperation::Mul);
+        let aa_plus_a24_e =
+            cols.aa_plus_a24_e.populate(blu, shard, channel, &aa, &a24_e, FieldOperation::Add);
+        let z2_out =
+            cols.z2_out.populate(blu, shard, channel, &e, &aa_plus_a24_e, FieldOperation::Mul);
+
+        [x2_out, z2_out, x3_out, z3_out]
+    }
+
+    /// Populates the columns dividing `final_x` by `final_z` and returns the quotient, which is
+    /// zero if `final_z` is zero.
+    fn populate_result<F: PrimeField32>(
+        blu: &mut impl ByteRecord,
+        shard: u32,
+        channel: u8,
+        cols: &mut X25519Cols<F>,
+        final_x: &BigUint,
+        final_z: &BigUint,
+    ) -> BigUint {
+        let modulus = Ed25519BaseField::modulus();
+        let z_inverse = if final_z.is_zero() {
+            BigUint::zero()
+        } else {
+            final_z.modpow(&(&modulus - 2u32), &modulus)
+        };
+
+        cols.final_x = Ed25519BaseField::to_limbs_field::<F, _>(final_x);
+        cols.final_z = Ed25519BaseField::to_limbs_field::<F, _>(final_z);
+        cols.z_is_zero = F::from_bool(final_z.is_zero());
+        cols.z_inverse = Ed25519BaseField::to_limbs_field::<F, _>(&z_inverse);
+        blu.add_u8_range_checks_field(shard, channel, &cols.z_inverse.0);
+
+        cols.z_mul_inverse.populate(blu, shard, channel, final_z, &z_inverse, FieldOperation::Mul);
+        cols.result.populate(blu, shard, channel, final_x, &z_inverse, FieldOperation::Mul)
+    }
+}
@@ -22,6 +22,17 @@ impl Buffer {
         self.ptr = 0;
     }
 
+    /// Set the position ptr to `offset`, panicking if it is past the end of the buffer.
+    pub fn seek(&mut self, offset: usize) {
+        assert!(offset <= self.data.len(), "offset {} is past the end of the buffer", offset);
+        self.ptr = offset;
+    }
+
+    /// The bytes of the buffer that have not been read yet.
+    pub fn remaining(&self) -> &[u8] {
+        &self.data[self.ptr..]
+    }
+
     /// Read the serializable object from the buffer.
     pub fn read<T: Serialize + DeserializeOwned>(&mut self) -> T {
         let result: T =
@@ -0,0 +1,191 @@
+//! A harness for chip-level unit tests against golden trace files.
+//!
+//! A test feeds synthetic events to a chip through an [ExecutionRecord], checks the constraints
+//! of the chip on the generated trace and compares the trace with a golden file in the `golden`
+//! directory of this crate. Set `SP1_UPDATE_GOLDEN=1` to rewrite the golden files after an
+//! intended change of a trace layout, or to write the golden file of a new test. A missing golden
+//! file fails the test otherwise, so that a file lost from the repository is not silently
+//! replaced by the current output.
+//!
+//! The same directory holds the lockfiles of the constraint systems of the machines, checked by
+//! [assert_constraint_lock], which catch changes to the circuits that break the verifying keys.
+
+use std::{
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use p3_air::Air;
+use p3_baby_bear::BabyBear;
+use p3_field::{extension::BinomialExtensionField, AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use sp1_core_executor::{ExecutionRecord, Program};
+use sp1_stark::{air::MachineAir, debug_air_constraints, ConstraintLock, DebugConstraintBuilder};
+
+type Challenge = BinomialExtensionField<BabyBear, 4>;
+
+/// Generates the trace of `chip` for the events of `record`.
+pub fn generate_trace<A>(chip: &A, record: &ExecutionRecord) -> RowMajorMatrix<BabyBear>
+where
+    A: MachineAir<BabyBear, Record = ExecutionRecord, Program = Program>,
+{
+    chip.generate_trace(record, &mut ExecutionRecord::default())
+}
+
+/// Asserts that `trace` satisfies the constraints of `chip`, ignoring interactions.
+pub fn assert_constraints<A>(chip: &A, trace: &RowMajorMatrix<BabyBear>)
+where
+    A: MachineAir<BabyBear> + for<'a> Air<DebugConstraintBuilder<'a, BabyBear, Challenge>>,
+{
+    if let Err(row) = debug_air_constraints::<BabyBear, Challenge, _>(chip, trace, &[]) {
+        panic!("constraints of chip {} failed at row {}", chip.name(), row);
+    }
+}
+
+/// Asserts that `trace` matches the golden file `golden/<name>.trace`.
+pub fn assert_golden(name: &str, trace: &RowMajorMatrix<BabyBear>) {
+    let path = golden_path(name, "trace");
+    let actual = encode(trace);
+    if update_golden() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+    assert_exists(&path);
+
+    let expected = decode(&fs::read_to_string(&path).unwrap());
+    assert_eq!(
+        (expected.width(), expected.height()),
+        (trace.width(), trace.height()),
+        "trace {name} changed shape (width, height)"
+    );
+    let mismatches = expected
+        .values
+        .iter()
+        .zip(trace.values.iter())
+        .enumerate()
+        .filter(|(_, (expected, actual))| expected != actual)
+        .map(|(i, _)| (i / trace.width(), i % trace.width()))
+        .collect::<Vec<_>>();
+    if let Some((row, col)) = mismatches.first() {
+        panic!(
+            "trace {name} differs from {} in {} cells, first at row {row} column {col}: \
+             expected {}, got {}; set SP1_UPDATE_GOLDEN=1 if the change is intended",
+            path.display(),
+            mismatches.len(),
+            expected.get(*row, *col),
+            trace.get(*row, *col),
+        );
+    }
+}
+
+/// Generates the trace of `chip` for `record`, checks its constraints and compares it with the
+/// golden file `golden/<name>.trace`.
+pub fn check_chip<A>(chip: &A, record: &ExecutionRecord, name: &str)
+where
+    A: MachineAir<BabyBear, Record = ExecutionRecord, Program = Program>
+        + for<'a> Air<DebugConstraintBuilder<'a, BabyBear, Challenge>>,
+{
+    let trace = generate_trace(chip, record);
+    assert_constraints(chip, &trace);
+    assert_golden(name, &trace);
+}
+
+/// The comment at the top of the lockfiles.
+const LOCK_HEADER: &str =
+    "# The shape of the constraint system, which determines the verifying keys.\n\
+     # Regenerate with SP1_UPDATE_GOLDEN=1 after an intended change.\n";
+
+/// Asserts that `lock` matches the lockfile `golden/<name>.lock`, listing the changes otherwise.
+///
+/// A change of the lockfile changes the verifying keys, so it must come with new circuit
+/// artifacts and verifiers.
+pub fn assert_constraint_lock(name: &str, lock: &ConstraintLock) {
+    let path = golden_path(name, "lock");
+    if update_golden() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, format!("{LOCK_HEADER}{lock}")).unwrap();
+        return;
+    }
+    assert_exists(&path);
+
+    let expected: ConstraintLock = fs::read_to_string(&path).unwrap().parse().unwrap();
+    let changes = expected.diff(lock);
+    if !changes.is_empty() {
+        let changes = changes.iter().map(|change| format!("  {change}\n")).collect::<String>();
+        panic!(
+            "constraint system {name} differs from {}:\n{changes}set SP1_UPDATE_GOLDEN=1 if the \
+             change is intended",
+            path.display()
+        );
+    }
+}
+
+fn assert_exists(path: &Path) {
+    assert!(
+        path.exists(),
+        "{} is missing; set SP1_UPDATE_GOLDEN=1 to write it and check it in",
+        path.display()
+    );
+}
+
+fn update_golden() -> bool {
+    std::env::var("SP1_UPDATE_GOLDEN").map_or(false, |v| v == "1")
+}
+
+fn golden_path(name: &str, extension: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("golden").join(format!("{name}.{extension}"))
+}
+
+/// Encodes a trace as a `width height` header followed by one line of values per row.
+fn encode(trace: &RowMajorMatrix<BabyBear>) -> String {
+    let mut out = format!("{} {}\n", trace.width(), trace.height());
+    for row in trace.values.chunks(trace.width()) {
+        let row = row.iter().map(|value| value.as_canonical_u32().to_string()).collect::<Vec<_>>();
+        writeln!(out, "{}", row.join(" ")).unwrap();
+    }
+    out
+}
+
+fn decode(text: &str) -> RowMajorMatrix<BabyBear> {
+    let mut lines = text.lines();
+    let header = lines.next().expect("golden file is empty");
+    let (width, _) = header.split_once(' ').expect("golden file has no header");
+    let values = lines
+        .flat_map(|line| line.split_whitespace())
+        .map(|value| BabyBear::from_canonical_u32(value.parse().unwrap()))
+        .collect::<Vec<_>>();
+    RowMajorMatrix::new(values, width.parse().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::BorrowMut;
+
+    use sp1_core_executor::{events::AluEvent, Opcode};
+
+    use super::*;
+    use crate::alu::{AddSubChip, AddSubCols, NUM_ADD_SUB_COLS};
+
+    #[test]
+    fn test_encode_decode() {
+        let values = (0..12).map(BabyBear::from_canonical_u32).collect::<Vec<_>>();
+        let trace = RowMajorMatrix::new(values, 3);
+        let decoded = decode(&encode(&trace));
+        assert_eq!(decoded.width, 3);
+        assert_eq!(decoded.values, trace.values);
+    }
+
+    #[test]
+    #[should_panic(expected = "constraints of chip AddSub failed at row 0")]
+    fn test_assert_constraints_detects_corruption() {
+        let mut record = ExecutionRecord::default();
+        record.add_events = vec![AluEvent::new(0, 0, 0, Opcode::ADD, 14, 8, 6)];
+        let chip = AddSubChip::default();
+        let mut trace = generate_trace(&chip, &record);
+        let cols: &mut AddSubCols<BabyBear> = trace.values[..NUM_ADD_SUB_COLS].borrow_mut();
+        cols.add_operation.value[0] += BabyBear::one();
+        assert_constraints(&chip, &trace);
+    }
+}
@@ -1,9 +1,12 @@
 mod buffer;
 pub mod concurrency;
+#[cfg(test)]
+pub mod golden;
 mod logger;
 #[cfg(any(test, feature = "programs"))]
 mod programs;
 mod prove;
+pub mod shapes;
 mod span;
 mod tracer;
 
@@ -2,9 +2,13 @@ use std::{
     collections::VecDeque,
     fs::File,
     io::{
-        Seek, {self},
+        Read, Seek, Write, {self},
+    },
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{sync_channel, Sender},
+        Arc, Mutex,
     },
-    sync::{mpsc::sync_channel, Arc, Mutex},
 };
 use web_time::Instant;
 
@@ -29,14 +33,14 @@ use sp1_core_executor::events::sorted_table_lines;
 
 use sp1_core_executor::{
     subproof::NoOpSubproofVerifier, ExecutionError, ExecutionRecord, ExecutionReport, Executor,
-    Program, SP1Context,
+    HookRegistry, Program, SP1Context, ShardProgress,
 };
 use sp1_stark::{
     air::{MachineAir, PublicValues},
     Com, CpuProver, DebugConstraintBuilder, InteractionBuilder, MachineProof, MachineProver,
-    MachineRecord, OpeningProof, PcsProverData, ProverConstraintFolder, SP1CoreOpts,
-    StarkGenericConfig, StarkMachine, StarkProvingKey, StarkVerifyingKey, UniConfig, Val,
-    VerifierConstraintFolder,
+    MachineRecord, OpeningProof, PcsProverData, ProverConstraintFolder, SP1CoreOpts, ShardProof,
+    ShardWitness, StarkGenericConfig, StarkMachine, StarkProvingKey, StarkVerifyingKey, UniConfig,
+    Val, VerifierConstraintFolder,
 };
 
 #[derive(Error, Debug)]
@@ -47,6 +51,102 @@ pub enum SP1CoreProverError {
     IoError(io::Error),
     #[error("serialization error: {0}")]
     SerializationError(bincode::Error),
+    #[error("the resumed proof does not match the execution of the program")]
+    ResumeMismatch,
+    #[error("the progress is of a proof of another program, inputs or proving key")]
+    ProgressMismatch,
+}
+
+/// The progress of a core proof that was interrupted through the cancel flag of its context.
+///
+/// Pass it to [prove_with_context_resumable] to resume the proof with the same program, inputs and
+/// options. The shards committed or proven before the interruption are not committed or proven
+/// again, and if phase 1 had completed, the program is not executed again.
+///
+/// The progress holds a digest of the program, the inputs and the proving key of the proof, and
+/// resuming it with others fails with [SP1CoreProverError::ProgressMismatch].
+#[derive(Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "Com<SC>: Serialize, Val<SC>: Serialize, ShardProof<SC>: Serialize",
+    deserialize = "Com<SC>: DeserializeOwned, Val<SC>: DeserializeOwned, \
+                   ShardProof<SC>: DeserializeOwned"
+))]
+pub struct CoreProofProgress<SC: StarkGenericConfig> {
+    /// The digest of the program, the inputs and the proving key of the proof.
+    pub digest: [u8; 32],
+    /// The main commitment and public values of each shard committed in phase 1, in order.
+    pub commitments: Vec<(Com<SC>, Vec<Val<SC>>)>,
+    /// The execution checkpoints and whether each is the last, once phase 1 has completed.
+    pub checkpoints: Vec<(Vec<u8>, bool)>,
+    /// The public values stream of the program, once phase 1 has completed.
+    pub public_values_stream: Vec<u8>,
+    /// The shard proofs generated in phase 2, in order.
+    pub shard_proofs: Vec<ShardProof<SC>>,
+}
+
+impl<SC: StarkGenericConfig> Default for CoreProofProgress<SC> {
+    fn default() -> Self {
+        Self {
+            digest: [0; 32],
+            commitments: Vec::new(),
+            checkpoints: Vec::new(),
+            public_values_stream: Vec::new(),
+            shard_proofs: Vec::new(),
+        }
+    }
+}
+
+impl<SC: StarkGenericConfig> CoreProofProgress<SC>
+where
+    Com<SC>: Serialize + DeserializeOwned,
+    Val<SC>: Serialize + DeserializeOwned,
+    ShardProof<SC>: Serialize + DeserializeOwned,
+{
+    /// Saves the progress to `path`.
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> Result<(), SP1CoreProverError> {
+        let file = File::create(path).map_err(SP1CoreProverError::IoError)?;
+        bincode::serialize_into(io::BufWriter::new(file), self)
+            .map_err(SP1CoreProverError::SerializationError)
+    }
+
+    /// Loads a progress saved with [CoreProofProgress::save].
+    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Self, SP1CoreProverError> {
+        let file = File::open(path).map_err(SP1CoreProverError::IoError)?;
+        bincode::deserialize_from(io::BufReader::new(file))
+            .map_err(SP1CoreProverError::SerializationError)
+    }
+}
+
+/// Returns the digest binding a [CoreProofProgress] to the program, the inputs and the proving key.
+///
+/// The key is bound by its preprocessed commitment and start pc, which the verifying key holds too.
+fn progress_digest<SC: StarkGenericConfig>(
+    program: &Program,
+    stdin: &SP1Stdin,
+    pk: &StarkProvingKey<SC>,
+) -> Result<[u8; 32], SP1CoreProverError>
+where
+    SC::Val: PrimeField32,
+    Com<SC>: Serialize,
+{
+    let mut hasher = blake3::Hasher::new();
+    for bytes in
+        [bincode::serialize(program), bincode::serialize(stdin), bincode::serialize(&pk.commit)]
+    {
+        let bytes = bytes.map_err(SP1CoreProverError::SerializationError)?;
+        hasher.update(&(bytes.len() as u64).to_le_bytes());
+        hasher.update(&bytes);
+    }
+    hasher.update(&pk.pc_start.as_canonical_u32().to_le_bytes());
+    Ok(hasher.finalize().into())
+}
+
+/// The outcome of [prove_with_context_resumable].
+pub enum CoreProofOutcome<SC: StarkGenericConfig> {
+    /// The proof, the public values stream and the number of cycles.
+    Done(MachineProof<SC>, Vec<u8>, u64),
+    /// The progress of the proof when it was interrupted.
+    Interrupted(Box<CoreProofProgress<SC>>),
 }
 
 pub fn prove_simple<SC: StarkGenericConfig, P: MachineProver<SC, RiscvAir<SC::Val>>>(
@@ -125,9 +225,133 @@ where
     Com<SC>: Send + Sync,
     PcsProverData<SC>: Send + Sync,
 {
+    prove_with_context_streaming(prover, pk, program, stdin, opts, context, None)
+}
+
+/// Like [prove_with_context], but also sends each shard proof to `shard_proof_tx`, in order, as
+/// soon as it is generated.
+pub fn prove_with_context_streaming<
+    SC: StarkGenericConfig,
+    P: MachineProver<SC, RiscvAir<SC::Val>>,
+>(
+    prover: &P,
+    pk: &StarkProvingKey<SC>,
+    program: Program,
+    stdin: &SP1Stdin,
+    opts: SP1CoreOpts,
+    context: SP1Context,
+    shard_proof_tx: Option<Sender<ShardProof<SC>>>,
+) -> Result<(MachineProof<SC>, Vec<u8>, u64), SP1CoreProverError>
+where
+    SC::Val: PrimeField32,
+    SC::Challenger: 'static + Clone + Send,
+    OpeningProof<SC>: Send,
+    Com<SC>: Send + Sync,
+    PcsProverData<SC>: Send + Sync,
+{
+    match prove_from_progress(
+        prover,
+        pk,
+        program,
+        stdin,
+        opts,
+        context,
+        shard_proof_tx,
+        CoreProofProgress::default(),
+    )? {
+        CoreProofOutcome::Done(proof, public_values_stream, cycles) => {
+            Ok((proof, public_values_stream, cycles))
+        }
+        CoreProofOutcome::Interrupted(_) => {
+            Err(SP1CoreProverError::ExecutionError(ExecutionError::Cancelled()))
+        }
+    }
+}
+
+/// Like [prove_with_context_streaming], but returns the progress of the proof instead of an error
+/// when the cancel flag of the context is set, and resumes from the progress of an interrupted
+/// proof.
+///
+/// Once the flag is set, the prover finishes the batch of shards in progress and stops. Resuming
+/// from the progress of a proof of another program, inputs or proving key fails with
+/// [SP1CoreProverError::ProgressMismatch].
+#[allow(clippy::too_many_arguments)]
+pub fn prove_with_context_resumable<
+    SC: StarkGenericConfig,
+    P: MachineProver<SC, RiscvAir<SC::Val>>,
+>(
+    prover: &P,
+    pk: &StarkProvingKey<SC>,
+    program: Program,
+    stdin: &SP1Stdin,
+    opts: SP1CoreOpts,
+    context: SP1Context,
+    shard_proof_tx: Option<Sender<ShardProof<SC>>>,
+    progress: Option<CoreProofProgress<SC>>,
+) -> Result<CoreProofOutcome<SC>, SP1CoreProverError>
+where
+    SC::Val: PrimeField32,
+    SC::Challenger: 'static + Clone + Send,
+    OpeningProof<SC>: Send,
+    Com<SC>: Send + Sync + Serialize,
+    PcsProverData<SC>: Send + Sync,
+{
+    // Only resume the progress of a proof of the same program, inputs and key.
+    let digest = progress_digest(&program, stdin, pk)?;
+    let progress = match progress {
+        Some(progress) if progress.digest != digest => {
+            return Err(SP1CoreProverError::ProgressMismatch)
+        }
+        Some(progress) => progress,
+        None => CoreProofProgress { digest, ..Default::default() },
+    };
+    prove_from_progress(prover, pk, program, stdin, opts, context, shard_proof_tx, progress)
+}
+
+/// Runs [prove_with_context_resumable] from `progress`, whose digest has been checked.
+#[allow(clippy::too_many_arguments)]
+fn prove_from_progress<SC: StarkGenericConfig, P: MachineProver<SC, RiscvAir<SC::Val>>>(
+    prover: &P,
+    pk: &StarkProvingKey<SC>,
+    program: Program,
+    stdin: &SP1Stdin,
+    opts: SP1CoreOpts,
+    context: SP1Context,
+    shard_proof_tx: Option<Sender<ShardProof<SC>>>,
+    progress: CoreProofProgress<SC>,
+) -> Result<CoreProofOutcome<SC>, SP1CoreProverError>
+where
+    SC::Val: PrimeField32,
+    SC::Challenger: 'static + Clone + Send,
+    OpeningProof<SC>: Send,
+    Com<SC>: Send + Sync,
+    PcsProverData<SC>: Send + Sync,
+{
+    // If phase 1 had completed, resume from the saved checkpoints instead of executing again.
+    let resume_phase_2 = !progress.checkpoints.is_empty();
+    let num_committed = progress.commitments.len();
+    let num_proven = progress.shard_proofs.len();
+    let CoreProofProgress {
+        digest,
+        commitments: prior_commitments,
+        checkpoints: stored_checkpoints,
+        public_values_stream: stored_public_values_stream,
+        shard_proofs: prior_shard_proofs,
+    } = progress;
+
     // Setup the runtime.
+    let cancel_flag = context.cancel_flag.clone();
+    let allow_failure = context.allow_failure;
+    let shard_progress_callback = context.shard_progress_callback.clone();
+    // The checkpoints are re-executed with the hooks of the context, so that the hooks answer the
+    // program the same way as during the first execution.
+    let hook_registry = context.hook_registry.clone();
+    let hook_registry = &hook_registry;
     let mut runtime = Executor::with_context(program.clone(), opts, context);
     runtime.write_vecs(&stdin.buffer);
+    for (stream_id, entries) in &stdin.streams {
+        runtime.write_stream(*stream_id, entries);
+    }
     for proof in stdin.proofs.iter() {
         runtime.write_proof(proof.0.clone(), proof.1.clone());
     }
@@ -149,6 +373,19 @@ where
             s.spawn(move || {
                 let _span = checkpoint_generator_span.enter();
                 tracing::debug_span!("checkpoint generator").in_scope(|| {
+                    if resume_phase_2 {
+                        for (index, (bytes, done)) in stored_checkpoints.into_iter().enumerate() {
+                            let mut checkpoint_file =
+                                tempfile::tempfile().map_err(SP1CoreProverError::IoError)?;
+                            checkpoint_file
+                                .write_all(&bytes)
+                                .map_err(SP1CoreProverError::IoError)?;
+                            reset_seek(&mut checkpoint_file);
+                            checkpoints_tx.send((index, checkpoint_file, done)).unwrap();
+                        }
+                        return Ok(stored_public_values_stream);
+                    }
+
                     let mut index = 0;
                     loop {
                         // Enter the span.
@@ -218,15 +455,33 @@ where
                         let received = { checkpoints_rx.lock().unwrap().recv() };
 
                         if let Ok((index, mut checkpoint, done)) = received {
+                            // Phase 1 has already been committed, only collect the checkpoint.
+                            if resume_phase_2 {
+                                checkpoints.lock().unwrap().push_back((index, checkpoint, done));
+                                continue;
+                            }
+
                             // Trace the checkpoint and reconstruct the execution records.
                             let (mut records, _) = tracing::debug_span!("trace checkpoint")
-                                .in_scope(|| trace_checkpoint(program.clone(), &checkpoint, opts));
+                                .in_scope(|| {
+                                    trace_checkpoint(
+                                        program.clone(),
+                                        &checkpoint,
+                                        opts,
+                                        allow_failure,
+                                        hook_registry.clone(),
+                                    )
+                                });
                             reset_seek(&mut checkpoint);
 
-                            // Generate the dependencies.
-                            tracing::debug_span!("generate dependencies").in_scope(|| {
-                                prover.machine().generate_dependencies(&mut records, &opts)
-                            });
+                            // Generate the dependencies, and the traces of the chips which do not
+                            // need to wait for the dependencies of the whole record.
+                            let mut traces = tracing::debug_span!("generate dependencies")
+                                .in_scope(|| {
+                                    prover
+                                        .machine()
+                                        .generate_dependencies_and_traces(&mut records, &opts)
+                                });
 
                             // Wait for our turn to update the state.
                             record_gen_sync.wait_for_turn(index);
@@ -239,6 +494,7 @@ where
                                 state.execution_shard = record.public_values.execution_shard;
                                 state.start_pc = record.public_values.start_pc;
                                 state.next_pc = record.public_values.next_pc;
+                                state.exit_code = record.public_values.exit_code;
                                 state.committed_value_digest =
                                     record.public_values.committed_value_digest;
                                 state.deferred_proofs_digest =
@@ -252,8 +508,15 @@ where
                                 deferred.append(&mut record.defer());
                             }
 
-                            // See if any deferred shards are ready to be commited to.
-                            let mut deferred = deferred.split(done, opts.split_opts);
+                            // See if any deferred shards are ready to be commited to, after the shards
+                            // receiving the byte lookups split from the shards which contain "cpu events".
+                            let mut deferred = records
+                                .iter_mut()
+                                .flat_map(|record| {
+                                    record.split_byte_lookups(opts.split_opts.byte_lookups)
+                                })
+                                .chain(deferred.split(done, opts.split_opts))
+                                .collect::<Vec<_>>();
 
                             // Update the public values & prover state for the shards which do not
                             // contain "cpu events" before committing to them.
@@ -274,6 +537,7 @@ where
                                 record.public_values = *state;
                             }
                             records.append(&mut deferred);
+                            traces.resize_with(records.len(), Vec::new);
 
                             // Collect the checkpoints to be used again in the phase 2 prover.
                             let mut checkpoints = checkpoints.lock().unwrap();
@@ -285,27 +549,46 @@ where
                             #[cfg(feature = "debug")]
                             all_records_tx.send(records.clone()).unwrap();
 
-                            // Generate the traces.
-                            let traces = records
-                                .par_iter()
-                                .map(|record| prover.generate_traces(record))
-                                .collect::<Vec<_>>();
-
-                            // Wait for our turn.
+                            // Generate the remaining traces batch by batch, so that the prover
+                            // works on a batch while the traces of the next one are generated.
+                            let mut batches = chunk_vec(records, opts.shard_batch_size)
+                                .into_iter()
+                                .zip(chunk_vec(traces, opts.shard_batch_size))
+                                .map(|(records, traces)| {
+                                    let traces = records
+                                        .par_iter()
+                                        .zip(traces.into_par_iter())
+                                        .map(|(record, traces)| {
+                                            // The shards committed before an interruption are
+                                            // not committed again.
+                                            if record.public_values.shard as usize <= num_committed
+                                            {
+                                                return Vec::new();
+                                            }
+                                            let mut traces = prover.complete_traces(record, traces);
+                                            for (_, trace) in traces.iter_mut() {
+                                                trace.values = opts
+                                                    .memory
+                                                    .place(std::mem::take(&mut trace.values));
+                                            }
+                                            traces
+                                        })
+                                        .collect::<Vec<_>>();
+                                    (records, traces)
+                                });
+
+                            // Generate the first batch before waiting for our turn.
+                            let first_batch = batches.next();
                             trace_gen_sync.wait_for_turn(index);
 
                             // Send the records to the phase 1 prover.
-                            let chunked_records = chunk_vec(records, opts.shard_batch_size);
-                            let chunked_traces = chunk_vec(traces, opts.shard_batch_size);
-                            chunked_records.into_iter().zip(chunked_traces).for_each(
-                                |(records, traces)| {
-                                    records_and_traces_tx
-                                        .lock()
-                                        .unwrap()
-                                        .send((records, traces))
-                                        .unwrap();
-                                },
-                            );
+                            for (records, traces) in first_batch.into_iter().chain(batches) {
+                                records_and_traces_tx
+                                    .lock()
+                                    .unwrap()
+                                    .send((records, traces))
+                                    .unwrap();
+                            }
 
                             trace_gen_sync.advance_turn();
                         } else {
@@ -327,10 +610,25 @@ where
 
         // Spawn the phase 1 prover thread.
         let phase_1_prover_span = tracing::Span::current().clone();
+        let phase_1_cancel_flag = cancel_flag.clone();
         let phase_1_prover_handle = s.spawn(move || {
             let _span = phase_1_prover_span.enter();
+            let mut commitments = Vec::new();
+            let mut interrupted = false;
+            let mut mismatch = false;
+            if resume_phase_2 {
+                for (commit, public_values) in prior_commitments.iter() {
+                    prover.observe(&mut challenger, commit.clone(), public_values);
+                }
+                commitments.clone_from(&prior_commitments);
+            }
             tracing::debug_span!("phase 1 prover").in_scope(|| {
                 for (records, traces) in p1_records_and_traces_rx.iter() {
+                    // Once interrupted, keep receiving until the workers are done.
+                    if interrupted || is_cancelled(&phase_1_cancel_flag) {
+                        interrupted = true;
+                        continue;
+                    }
                     tracing::debug_span!("batch").in_scope(|| {
                         let span = tracing::Span::current().clone();
 
@@ -344,10 +642,14 @@ where
                             .collect::<Vec<_>>();
 
                         // Commit to each shard.
-                        let commitments = records
+                        let batch_commitments = records
                             .into_par_iter()
                             .zip(traces.into_par_iter())
                             .map(|(record, traces)| {
+                                let shard = record.public_values.shard as usize;
+                                if let Some((commit, _)) = prior_commitments.get(shard - 1) {
+                                    return commit.clone();
+                                }
                                 let _span = span.enter();
                                 let data = prover.commit(record, traces);
                                 let main_commit = data.main_commit.clone();
@@ -358,25 +660,62 @@ where
 
                         // Observe the commitments.
                         for (commit, public_values) in
-                            commitments.into_iter().zip(public_values.into_iter())
+                            batch_commitments.into_iter().zip(public_values.into_iter())
                         {
-                            prover.observe(&mut challenger, commit, &public_values);
+                            if let Some((_, prior)) = prior_commitments.get(commitments.len()) {
+                                mismatch |= *prior != public_values;
+                            }
+                            prover.observe(&mut challenger, commit.clone(), &public_values);
+                            commitments.push((commit, public_values));
                         }
                     });
+                    if mismatch {
+                        interrupted = true;
+                    }
                 }
             });
 
-            challenger
+            if mismatch {
+                return Err(SP1CoreProverError::ResumeMismatch);
+            }
+            Ok((challenger, commitments, interrupted))
         });
 
         // Wait until the checkpoint generator handle has fully finished.
-        let public_values_stream = checkpoint_generator_handle.join().unwrap().unwrap();
+        let public_values_stream = match checkpoint_generator_handle.join().unwrap() {
+            Ok(public_values_stream) => Some(public_values_stream),
+            Err(SP1CoreProverError::ExecutionError(ExecutionError::Cancelled())) => None,
+            Err(e) => return Err(e),
+        };
 
         // Wait until the records and traces have been fully generated.
         p1_record_and_trace_gen_handles.into_iter().for_each(|handle| handle.join().unwrap());
 
         // Wait until the phase 1 prover has completely finished.
-        let challenger = phase_1_prover_handle.join().unwrap();
+        let (challenger, commitments, phase_1_interrupted) =
+            phase_1_prover_handle.join().unwrap()?;
+
+        // If execution or phase 1 was interrupted, keep the commitments and execute again on
+        // resume.
+        let public_values_stream = match public_values_stream {
+            Some(public_values_stream) if !phase_1_interrupted => public_values_stream,
+            _ => {
+                let progress = CoreProofProgress { digest, commitments, ..Default::default() };
+                return Ok(CoreProofOutcome::Interrupted(Box::new(progress)));
+            }
+        };
+
+        // Phase 2 takes the checkpoints in order. Keep handles to them, to save them if phase 2
+        // is interrupted.
+        checkpoints.lock().unwrap().make_contiguous().sort_by_key(|(index, _, _)| *index);
+        let saved_checkpoints = checkpoints
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(index, file, done)| Ok((*index, file.try_clone()?, *done)))
+            .collect::<io::Result<Vec<_>>>()
+            .map_err(SP1CoreProverError::IoError)?;
+        let phase_2_stopped = Arc::new(AtomicBool::new(false));
 
         // Spawn the phase 2 record generator thread.
         let p2_record_gen_sync = Arc::new(TurnBasedSync::new());
@@ -402,24 +741,45 @@ where
             let deferred = Arc::clone(&deferred);
             let program = program.clone();
 
+            let cancel_flag = cancel_flag.clone();
+            let phase_2_stopped = Arc::clone(&phase_2_stopped);
+
             let span = tracing::Span::current().clone();
             let handle = s.spawn(move || {
                 let _span = span.enter();
                 tracing::debug_span!("phase 2 trace generation").in_scope(|| {
                     loop {
+                        // Stop generating records once interrupted.
+                        if is_cancelled(&cancel_flag) {
+                            phase_2_stopped.store(true, Ordering::Relaxed);
+                            break;
+                        }
+
                         // Receive the latest checkpoint.
                         let received = { checkpoints.lock().unwrap().pop_front() };
                         if let Some((index, mut checkpoint, done)) = received {
                             // Trace the checkpoint and reconstruct the execution records.
                             let (mut records, report) = tracing::debug_span!("trace checkpoint")
-                                .in_scope(|| trace_checkpoint(program.clone(), &checkpoint, opts));
+                                .in_scope(|| {
+                                    trace_checkpoint(
+                                        program.clone(),
+                                        &checkpoint,
+                                        opts,
+                                        allow_failure,
+                                        hook_registry.clone(),
+                                    )
+                                });
                             *report_aggregate.lock().unwrap() += report;
                             reset_seek(&mut checkpoint);
 
-                            // Generate the dependencies.
-                            tracing::debug_span!("generate dependencies").in_scope(|| {
-                                prover.machine().generate_dependencies(&mut records, &opts)
-                            });
+                            // Generate the dependencies, and the traces of the chips which do not
+                            // need to wait for the dependencies of the whole record.
+                            let mut traces = tracing::debug_span!("generate dependencies")
+                                .in_scope(|| {
+                                    prover
+                                        .machine()
+                                        .generate_dependencies_and_traces(&mut records, &opts)
+                                });
 
                             // Wait for our turn to update the state.
                             record_gen_sync.wait_for_turn(index);
@@ -432,6 +792,7 @@ where
                                 state.execution_shard = record.public_values.execution_shard;
                                 state.start_pc = record.public_values.start_pc;
                                 state.next_pc = record.public_values.next_pc;
+                                state.exit_code = record.public_values.exit_code;
                                 state.committed_value_digest =
                                     record.public_values.committed_value_digest;
                                 state.deferred_proofs_digest =
@@ -445,8 +806,15 @@ where
                                 deferred.append(&mut record.defer());
                             }
 
-                            // See if any deferred shards are ready to be commited to.
-                            let mut deferred = deferred.split(done, opts.split_opts);
+                            // See if any deferred shards are ready to be commited to, after the shards
+                            // receiving the byte lookups split from the shards which contain "cpu events".
+                            let mut deferred = records
+                                .iter_mut()
+                                .flat_map(|record| {
+                                    record.split_byte_lookups(opts.split_opts.byte_lookups)
+                                })
+                                .chain(deferred.split(done, opts.split_opts))
+                                .collect::<Vec<_>>();
 
                             // Update the public values & prover state for the shards which do not
                             // contain "cpu events" before committing to them.
@@ -467,30 +835,50 @@ where
                                 record.public_values = *state;
                             }
                             records.append(&mut deferred);
+                            traces.resize_with(records.len(), Vec::new);
 
                             // Let another worker update the state.
                             record_gen_sync.advance_turn();
 
-                            // Generate the traces.
-                            let traces = records
-                                .par_iter()
-                                .map(|record| prover.generate_traces(record))
-                                .collect::<Vec<_>>();
-
+                            // Generate the remaining traces batch by batch, so that the prover
+                            // works on a batch while the traces of the next one are generated.
+                            let mut batches = chunk_vec(records, opts.shard_batch_size)
+                                .into_iter()
+                                .zip(chunk_vec(traces, opts.shard_batch_size))
+                                .map(|(records, traces)| {
+                                    let traces = records
+                                        .par_iter()
+                                        .zip(traces.into_par_iter())
+                                        .map(|(record, traces)| {
+                                            // The shards proven before an interruption are not
+                                            // proven again.
+                                            if record.public_values.shard as usize <= num_proven {
+                                                return Vec::new();
+                                            }
+                                            let mut traces = prover.complete_traces(record, traces);
+                                            for (_, trace) in traces.iter_mut() {
+                                                trace.values = opts
+                                                    .memory
+                                                    .place(std::mem::take(&mut trace.values));
+                                            }
+                                            traces
+                                        })
+                                        .collect::<Vec<_>>();
+                                    (records, traces)
+                                });
+
+                            // Generate the first batch before waiting for our turn.
+                            let first_batch = batches.next();
                             trace_gen_sync.wait_for_turn(index);
 
-                            // Send the records to the phase 1 prover.
-                            let chunked_records = chunk_vec(records, opts.shard_batch_size);
-                            let chunked_traces = chunk_vec(traces, opts.shard_batch_size);
-                            chunked_records.into_iter().zip(chunked_traces).for_each(
-                                |(records, traces)| {
-                                    records_and_traces_tx
-                                        .lock()
-                                        .unwrap()
-                                        .send((records, traces))
-                                        .unwrap();
-                                },
-                            );
+                            // Send the records to the phase 2 prover.
+                            for (records, traces) in first_batch.into_iter().chain(batches) {
+                                records_and_traces_tx
+                                    .lock()
+                                    .unwrap()
+                                    .send((records, traces))
+                                    .unwrap();
+                            }
 
                             trace_gen_sync.advance_turn();
                         } else {
@@ -505,33 +893,86 @@ where
 
         // Spawn the phase 2 prover thread.
         let p2_prover_span = tracing::Span::current().clone();
+        let p2_cancel_flag = cancel_flag.clone();
+        let num_shards = commitments.len();
         let p2_prover_handle = s.spawn(move || {
             let _span = p2_prover_span.enter();
             let mut shard_proofs = Vec::new();
+            let mut interrupted = false;
+            let report_progress = |proven| {
+                if let Some(callback) = &shard_progress_callback {
+                    callback(ShardProgress { proven, total: num_shards });
+                }
+            };
+            report_progress(0);
             tracing::debug_span!("phase 2 prover").in_scope(|| {
                 for (records, traces) in p2_records_and_traces_rx.into_iter() {
+                    // Once interrupted, keep receiving until the workers are done.
+                    if interrupted || is_cancelled(&p2_cancel_flag) {
+                        interrupted = true;
+                        continue;
+                    }
+                    let batch_start = shard_proofs.len();
                     tracing::debug_span!("batch").in_scope(|| {
+                        // Reuse the shard proofs generated before an interruption.
+                        let (proven, unproven): (Vec<_>, Vec<_>) =
+                            records.into_iter().zip(traces).partition(|(record, _)| {
+                                record.public_values.shard as usize <= num_proven
+                            });
+                        for (record, _) in proven {
+                            let shard = record.public_values.shard as usize;
+                            shard_proofs.push(prior_shard_proofs[shard - 1].clone());
+                        }
+
                         let span = tracing::Span::current().clone();
-                        shard_proofs.par_extend(
-                            records.into_par_iter().zip(traces.into_par_iter()).map(
-                                |(record, traces)| {
-                                    let _span = span.enter();
-                                    let data = prover.commit(record, traces);
-                                    prover.open(pk, data, &mut challenger.clone()).unwrap()
-                                },
-                            ),
-                        );
+                        shard_proofs.par_extend(unproven.into_par_iter().map(
+                            |(record, traces)| {
+                                let _span = span.enter();
+                                let data = prover.commit(record, traces);
+                                prover.open(pk, data, &mut challenger.clone()).unwrap()
+                            },
+                        ));
                     });
+
+                    // Stream the new shard proofs. A dropped receiver only stops the streaming.
+                    if let Some(tx) = &shard_proof_tx {
+                        for proof in &shard_proofs[batch_start..] {
+                            let _ = tx.send(proof.clone());
+                        }
+                    }
+                    report_progress(shard_proofs.len());
                 }
             });
-            shard_proofs
+            (shard_proofs, interrupted)
         });
 
         // Wait until the records and traces have been fully generated for phase 2.
         p2_record_and_trace_gen_handles.into_iter().for_each(|handle| handle.join().unwrap());
 
         // Wait until the phase 2 prover has finished.
-        let shard_proofs = p2_prover_handle.join().unwrap();
+        let (shard_proofs, phase_2_interrupted) = p2_prover_handle.join().unwrap();
+
+        // If phase 2 was interrupted, save the checkpoints with the shard proofs so far.
+        if phase_2_interrupted || phase_2_stopped.load(Ordering::Relaxed) {
+            let checkpoints = saved_checkpoints
+                .into_iter()
+                .map(|(_, mut file, done)| {
+                    reset_seek(&mut file);
+                    let mut bytes = Vec::new();
+                    file.read_to_end(&mut bytes)?;
+                    Ok((bytes, done))
+                })
+                .collect::<io::Result<Vec<_>>>()
+                .map_err(SP1CoreProverError::IoError)?;
+            let progress = CoreProofProgress {
+                digest,
+                commitments,
+                checkpoints,
+                public_values_stream,
+                shard_proofs,
+            };
+            return Ok(CoreProofOutcome::Interrupted(Box::new(progress)));
+        }
 
         // Log some of the `ExecutionReport` information.
         let report_aggregate = report_aggregate.lock().unwrap();
@@ -548,6 +989,10 @@ where
         for line in sorted_table_lines(report_aggregate.opcode_counts.as_ref()) {
             tracing::info!("  {line}");
         }
+        tracing::info!("execution report (cycles by opcode class):");
+        for line in sorted_table_lines(report_aggregate.opcode_class_counts()) {
+            tracing::info!("  {line}");
+        }
         tracing::info!("execution report (syscall counts):");
         for line in sorted_table_lines(report_aggregate.syscall_counts.as_ref()) {
             tracing::info!("  {line}");
@@ -574,10 +1019,14 @@ where
             prover.machine().debug_constraints(pk, all_records, &mut challenger);
         }
 
-        Ok((proof, public_values_stream, cycles))
+        Ok(CoreProofOutcome::Done(proof, public_values_stream, cycles))
     })
 }
 
+fn is_cancelled(cancel_flag: &Option<Arc<AtomicBool>>) -> bool {
+    cancel_flag.as_ref().is_some_and(|flag| flag.load(Ordering::Relaxed))
+}
+
 /// Runs a program and returns the public values stream.
 pub fn run_test_io<P: MachineProver<BabyBearPoseidon2, RiscvAir<BabyBear>>>(
     program: Program,
@@ -586,6 +1035,9 @@ pub fn run_test_io<P: MachineProver<BabyBearPoseidon2, RiscvAir<BabyBear>>>(
     let runtime = tracing::debug_span!("runtime.run(...)").in_scope(|| {
         let mut runtime = Executor::new(program, SP1CoreOpts::default());
         runtime.write_vecs(&inputs.buffer);
+        for (stream_id, entries) in &inputs.streams {
+            runtime.write_stream(*stream_id, entries);
+        }
         runtime.run().unwrap();
         runtime
     });
@@ -619,7 +1071,7 @@ pub fn run_test_core<P: MachineProver<BabyBearPoseidon2, RiscvAir<BabyBear>>>(
         &pk,
         Program::clone(&runtime.program),
         &inputs,
-        SP1CoreOpts::default(),
+        runtime.opts,
         SP1Context::default(),
     )
     .unwrap();
@@ -690,14 +1142,144 @@ where
     run_test_machine_with_prover::<SC, A, CpuProver<_, _>>(records, machine, pk, vk)
 }
 
+/// Generates the witness of each shard of the execution of a program, without proving it.
+///
+/// The shards are the same as the ones proven by [prove_with_context], and `on_shard` is called
+/// with the witness of each of them, in order. The permutation traces are only generated if the
+/// `permutation_challenges` are given, see [ShardWitness] for the layout of the witnesses.
+///
+/// Returns the public values stream of the execution.
+#[allow(clippy::too_many_arguments)]
+pub fn generate_witness<SC: StarkGenericConfig, P: MachineProver<SC, RiscvAir<SC::Val>>>(
+    prover: &P,
+    pk: &StarkProvingKey<SC>,
+    program: Program,
+    stdin: &SP1Stdin,
+    opts: SP1CoreOpts,
+    context: SP1Context,
+    permutation_challenges: Option<&[SC::Challenge]>,
+    mut on_shard: impl FnMut(ShardWitness),
+) -> Result<Vec<u8>, SP1CoreProverError>
+where
+    SC::Val: PrimeField32,
+{
+    generate_shards(prover, program, stdin, opts, context, |record, traces| {
+        on_shard(prover.machine().shard_witness(pk, record, traces, permutation_challenges));
+    })
+}
+
+/// Returns the height of the trace of each chip in each shard of the execution of a program.
+///
+/// The shards are the same as the ones proven by [prove_with_context]. The heights are those of
+/// the padded traces, so each of them is a power of two.
+pub fn shard_heights(
+    program: Program,
+    stdin: &SP1Stdin,
+    opts: SP1CoreOpts,
+    context: SP1Context,
+) -> Result<Vec<Vec<(String, usize)>>, SP1CoreProverError> {
+    let prover = CpuProver::new(RiscvAir::machine(BabyBearPoseidon2::new()));
+    let mut heights = Vec::new();
+    generate_shards(&prover, program, stdin, opts, context, |_, traces| {
+        let shard = traces.iter().map(|(name, trace)| (name.clone(), trace.height()));
+        heights.push(shard.collect());
+    })?;
+    Ok(heights)
+}
+
+/// Executes a program and generates the traces of each of its shards, in order.
+///
+/// Returns the public values stream of the execution.
+fn generate_shards<SC: StarkGenericConfig, P: MachineProver<SC, RiscvAir<SC::Val>>>(
+    prover: &P,
+    program: Program,
+    stdin: &SP1Stdin,
+    opts: SP1CoreOpts,
+    context: SP1Context,
+    mut on_shard: impl FnMut(&ExecutionRecord, Vec<(String, RowMajorMatrix<Val<SC>>)>),
+) -> Result<Vec<u8>, SP1CoreProverError>
+where
+    SC::Val: PrimeField32,
+{
+    let mut runtime = Executor::with_context(program.clone(), opts, context);
+    runtime.write_vecs(&stdin.buffer);
+    for (stream_id, entries) in &stdin.streams {
+        runtime.write_stream(*stream_id, entries);
+    }
+    for proof in stdin.proofs.iter() {
+        runtime.write_proof(proof.0.clone(), proof.1.clone());
+    }
+
+    let mut state = PublicValues::<u32, u32>::default().reset();
+    let mut deferred = ExecutionRecord::new(program.into());
+    loop {
+        let (mut records, done) =
+            runtime.execute_record().map_err(SP1CoreProverError::ExecutionError)?;
+        let mut traces = prover.machine().generate_dependencies_and_traces(&mut records, &opts);
+
+        // Update the public values of the shards which contain "cpu events".
+        for record in records.iter_mut() {
+            state.shard += 1;
+            state.execution_shard = record.public_values.execution_shard;
+            state.start_pc = record.public_values.start_pc;
+            state.next_pc = record.public_values.next_pc;
+            state.exit_code = record.public_values.exit_code;
+            state.committed_value_digest = record.public_values.committed_value_digest;
+            state.deferred_proofs_digest = record.public_values.deferred_proofs_digest;
+            record.public_values = state;
+        }
+
+        // Defer the events that are too expensive to include in every shard, and split the byte
+        // lookups which the byte table of a single shard cannot receive.
+        for record in records.iter_mut() {
+            deferred.append(&mut record.defer());
+        }
+        let mut split = records
+            .iter_mut()
+            .flat_map(|record| record.split_byte_lookups(opts.split_opts.byte_lookups))
+            .chain(deferred.split(done, opts.split_opts))
+            .collect::<Vec<_>>();
+
+        // Update the public values of the shards which do not contain "cpu events".
+        if !done {
+            state.execution_shard += 1;
+        }
+        for record in split.iter_mut() {
+            state.shard += 1;
+            state.previous_init_addr_bits = record.public_values.previous_init_addr_bits;
+            state.last_init_addr_bits = record.public_values.last_init_addr_bits;
+            state.previous_finalize_addr_bits = record.public_values.previous_finalize_addr_bits;
+            state.last_finalize_addr_bits = record.public_values.last_finalize_addr_bits;
+            state.start_pc = state.next_pc;
+            record.public_values = state;
+        }
+        records.append(&mut split);
+        traces.resize_with(records.len(), Vec::new);
+
+        for (record, traces) in records.iter().zip(traces) {
+            on_shard(record, prover.complete_traces(record, traces));
+        }
+
+        if done {
+            return Ok(std::mem::take(&mut runtime.state.public_values_stream));
+        }
+    }
+}
+
 fn trace_checkpoint(
     program: Program,
     file: &File,
     opts: SP1CoreOpts,
+    allow_failure: bool,
+    hook_registry: Option<HookRegistry>,
 ) -> (Vec<ExecutionRecord>, ExecutionReport) {
     let mut reader = std::io::BufReader::new(file);
     let state = bincode::deserialize_from(&mut reader).expect("failed to deserialize state");
     let mut runtime = Executor::recover(program.clone(), state, opts);
+    runtime.allow_failure = allow_failure;
+    if let Some(hook_registry) = hook_registry {
+        runtime.hook_registry = hook_registry;
+    }
     // We already passed the deferred proof verifier when creating checkpoints, so the proofs were
     // already verified. So here we use a noop verifier to not print any warnings.
     runtime.subproof_verifier = Arc::new(NoOpSubproofVerifier);
@@ -774,5 +1356,5 @@ where
 }
 
 use p3_air::Air;
-use p3_matrix::dense::RowMajorMatrix;
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
 use p3_uni_stark::Proof;
@@ -0,0 +1,132 @@
+//! Statistics of the trace heights of the shards of a corpus of executions.
+//!
+//! The heights are collected with [super::shard_heights] and summarized into a recommendation of
+//! the log heights to allow for each chip, so that a small set of shard shapes covers most of the
+//! workloads.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::log2_strict_usize;
+
+/// The distribution of the log heights of each chip over the shards of a corpus of executions.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ShapeStats {
+    /// The number of shards recorded.
+    pub num_shards: usize,
+    /// For each chip, the number of shards in which its trace has each log height.
+    ///
+    /// A shard in which a chip is not included is not counted for that chip.
+    pub log_heights: BTreeMap<String, BTreeMap<usize, usize>>,
+}
+
+impl ShapeStats {
+    /// Records the heights of the chips of a shard, as returned by [super::shard_heights].
+    pub fn record(&mut self, heights: &[(String, usize)]) {
+        self.num_shards += 1;
+        for (name, height) in heights {
+            *self
+                .log_heights
+                .entry(name.clone())
+                .or_default()
+                .entry(log2_strict_usize(*height))
+                .or_default() += 1;
+        }
+    }
+
+    /// Recommends the log heights to allow for each chip so that at least a fraction `coverage` of
+    /// the shards in which the chip is included fits, when padded up to the next allowed height.
+    ///
+    /// The largest allowed height of a chip is the smallest one covering the `coverage` quantile
+    /// of its heights. The smaller heights are only kept if they are observed in at least a
+    /// fraction `1 - coverage` of the shards, since the other ones pay little for being padded
+    /// up to the next allowed height.
+    pub fn recommend(&self, coverage: f64) -> BTreeMap<String, Vec<usize>> {
+        assert!((0.0..=1.0).contains(&coverage), "coverage must be between 0 and 1");
+        self.log_heights
+            .iter()
+            .map(|(name, counts)| {
+                let total = counts.values().sum::<usize>();
+                let quantile = (coverage * total as f64).ceil() as usize;
+                let mut seen = 0;
+                let mut allowed = Vec::new();
+                for (&log_height, &count) in counts {
+                    seen += count;
+                    if seen >= quantile.max(1) {
+                        allowed.push(log_height);
+                        break;
+                    }
+                    if count as f64 >= (1.0 - coverage) * total as f64 {
+                        allowed.push(log_height);
+                    }
+                }
+                (name.clone(), allowed)
+            })
+            .collect()
+    }
+
+    /// Returns the fraction of the `shards` which fit in the `allowed` log heights, that is in which
+    /// each chip is at most as high as its largest allowed height.
+    pub fn coverage(
+        shards: &[Vec<(String, usize)>],
+        allowed: &BTreeMap<String, Vec<usize>>,
+    ) -> f64 {
+        if shards.is_empty() {
+            return 1.0;
+        }
+        let fits = shards
+            .iter()
+            .filter(|shard| {
+                shard.iter().all(|(name, height)| {
+                    allowed
+                        .get(name)
+                        .and_then(|heights| heights.iter().max())
+                        .is_some_and(|&max| log2_strict_usize(*height) <= max)
+                })
+            })
+            .count();
+        fits as f64 / shards.len() as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shard(heights: &[(&str, usize)]) -> Vec<(String, usize)> {
+        heights.iter().map(|(name, height)| (name.to_string(), 1 << height)).collect()
+    }
+
+    #[test]
+    fn test_recommend() {
+        let mut shards = Vec::new();
+        for _ in 0..90 {
+            shards.push(shard(&[("Cpu", 20), ("Add", 18)]));
+        }
+        for _ in 0..9 {
+            shards.push(shard(&[("Cpu", 21), ("Add", 16)]));
+        }
+        shards.push(shard(&[("Cpu", 22)]));
+
+        let mut stats = ShapeStats::default();
+        shards.iter().for_each(|heights| stats.record(heights));
+        assert_eq!(stats.num_shards, 100);
+
+        // All the observed heights are frequent enough to be kept.
+        let allowed = stats.recommend(0.99);
+        assert_eq!(allowed["Cpu"], vec![20, 21]);
+        assert_eq!(allowed["Add"], vec![16, 18]);
+        assert_eq!(ShapeStats::coverage(&shards, &allowed), 0.99);
+
+        // The rare heights are padded up to the next allowed one.
+        let allowed = stats.recommend(0.9);
+        assert_eq!(allowed["Cpu"], vec![20]);
+        assert_eq!(allowed["Add"], vec![18]);
+        assert_eq!(ShapeStats::coverage(&shards, &allowed), 0.9);
+
+        let allowed = stats.recommend(1.0);
+        assert_eq!(allowed["Cpu"], vec![20, 21, 22]);
+        assert_eq!(ShapeStats::coverage(&shards, &allowed), 1.0);
+    }
+}
@@ -0,0 +1,246 @@
+//! Decoding of BN254 points in the EVM and gnark encodings.
+
+use core::ops::Mul;
+
+use bn::{AffineG1, AffineG2, Fq, Fq2, Group, G1, G2};
+
+use crate::Error;
+
+/// The mask of the flags in the first byte of a point in gnark's encoding.
+const MASK: u8 = 0b11 << 6;
+/// The flag of an uncompressed point.
+const UNCOMPRESSED: u8 = 0b00 << 6;
+/// The flag of the compressed point at infinity.
+const COMPRESSED_INFINITY: u8 = 0b01 << 6;
+/// The flag of a compressed point whose `y` is the lexicographically largest root.
+const COMPRESSED_LARGEST: u8 = 0b11 << 6;
+
+/// `(q - 1) / 2`, where `q` is the modulus of the base field.
+const HALF_MODULUS: [u8; 32] = [
+    0x18, 0x32, 0x27, 0x39, 0x70, 0x98, 0xd0, 0x14, 0xdc, 0x28, 0x22, 0xdb, 0x40, 0xc0, 0xac, 0x2e,
+    0xcb, 0xc0, 0xb5, 0x48, 0xb4, 0x38, 0xe5, 0x46, 0x9e, 0x10, 0x46, 0x0b, 0x6c, 0x3e, 0x7e, 0xa3,
+];
+
+/// `(q + 1) / 4`, the exponent of the square root in the base field, since `q = 3 mod 4`.
+const FQ_SQRT_EXP: [u8; 32] = [
+    0x0c, 0x19, 0x13, 0x9c, 0xb8, 0x4c, 0x68, 0x0a, 0x6e, 0x14, 0x11, 0x6d, 0xa0, 0x60, 0x56, 0x17,
+    0x65, 0xe0, 0x5a, 0xa4, 0x5a, 0x1c, 0x72, 0xa3, 0x4f, 0x08, 0x23, 0x05, 0xb6, 0x1f, 0x3f, 0x52,
+];
+
+/// `(q - 3) / 4`, the first exponent of the square root in the quadratic extension.
+const FQ2_SQRT_EXP: [u8; 32] = [
+    0x0c, 0x19, 0x13, 0x9c, 0xb8, 0x4c, 0x68, 0x0a, 0x6e, 0x14, 0x11, 0x6d, 0xa0, 0x60, 0x56, 0x17,
+    0x65, 0xe0, 0x5a, 0xa4, 0x5a, 0x1c, 0x72, 0xa3, 0x4f, 0x08, 0x23, 0x05, 0xb6, 0x1f, 0x3f, 0x51,
+];
+
+/// The real part of `b' = 3 / (9 + u)`, the constant of the twisted curve of G2.
+const TWIST_B_REAL: [u8; 32] = [
+    0x2b, 0x14, 0x9d, 0x40, 0xce, 0xb8, 0xaa, 0xae, 0x81, 0xbe, 0x18, 0x99, 0x1b, 0xe0, 0x6a, 0xc3,
+    0xb5, 0xb4, 0xc5, 0xe5, 0x59, 0xdb, 0xef, 0xa3, 0x32, 0x67, 0xe6, 0xdc, 0x24, 0xa1, 0x38, 0xe5,
+];
+
+/// The imaginary part of `b'`.
+const TWIST_B_IMAGINARY: [u8; 32] = [
+    0x00, 0x97, 0x13, 0xb0, 0x3a, 0xf0, 0xfe, 0xd4, 0xcd, 0x2c, 0xaf, 0xad, 0xee, 0xd8, 0xfd, 0xf4,
+    0xa7, 0x4f, 0xa0, 0x84, 0xe5, 0x2d, 0x18, 0x52, 0xe4, 0xa2, 0xbd, 0x06, 0x85, 0xc3, 0x15, 0xd2,
+];
+
+/// Reads a big-endian element of the base field.
+fn fq(bytes: &[u8]) -> Result<Fq, Error> {
+    Fq::from_slice(bytes).map_err(|_| Error::InvalidPoint)
+}
+
+fn fq_bytes(x: Fq) -> [u8; 32] {
+    let mut bytes = [0; 32];
+    x.to_big_endian(&mut bytes).expect("the buffer holds a field element");
+    bytes
+}
+
+/// Reads a G1 point encoded as `x || y`, as the EVM precompiles do, with `(0, 0)` for the point
+/// at infinity.
+pub(crate) fn g1_from_evm(bytes: &[u8; 64]) -> Result<G1, Error> {
+    let (x, y) = (fq(&bytes[..32])?, fq(&bytes[32..])?);
+    if x.is_zero() && y.is_zero() {
+        return Ok(G1::zero());
+    }
+    AffineG1::new(x, y).map(Into::into).map_err(|_| Error::InvalidPoint)
+}
+
+/// Reads a G2 point encoded as `x.imaginary || x.real || y.imaginary || y.real`, as the EVM
+/// precompiles do, with zero for the point at infinity. The point must be in the prime order
+/// subgroup.
+pub(crate) fn g2_from_evm(bytes: &[u8; 128]) -> Result<G2, Error> {
+    let x = Fq2::new(fq(&bytes[32..64])?, fq(&bytes[..32])?);
+    let y = Fq2::new(fq(&bytes[96..])?, fq(&bytes[64..96])?);
+    if x.is_zero() && y.is_zero() {
+        return Ok(G2::zero());
+    }
+    AffineG2::new(x, y).map(Into::into).map_err(|_| Error::InvalidPoint)
+}
+
+/// Encodes a G1 point as read by [g1_from_evm].
+pub(crate) fn g1_to_evm(point: G1) -> [u8; 64] {
+    let mut bytes = [0; 64];
+    if let Some(point) = AffineG1::from_jacobian(point) {
+        bytes[..32].copy_from_slice(&fq_bytes(point.x()));
+        bytes[32..].copy_from_slice(&fq_bytes(point.y()));
+    }
+    bytes
+}
+
+/// Encodes a G2 point as read by [g2_from_evm].
+pub(crate) fn g2_to_evm(point: G2) -> [u8; 128] {
+    let mut bytes = [0; 128];
+    if let Some(point) = AffineG2::from_jacobian(point) {
+        bytes[..32].copy_from_slice(&fq_bytes(point.x().imaginary()));
+        bytes[32..64].copy_from_slice(&fq_bytes(point.x().real()));
+        bytes[64..96].copy_from_slice(&fq_bytes(point.y().imaginary()));
+        bytes[96..].copy_from_slice(&fq_bytes(point.y().real()));
+    }
+    bytes
+}
+
+/// Reads a G1 point in gnark's encoding, compressed or not, from the start of `bytes`. Returns the
+/// point and the number of bytes read.
+pub(crate) fn g1_from_gnark(bytes: &[u8]) -> Result<(G1, usize), Error> {
+    let flag = bytes.first().ok_or(Error::InvalidVerifyingKey)? & MASK;
+    if flag == UNCOMPRESSED {
+        return Ok((g1_from_evm(take(bytes)?)?, 64));
+    }
+    if flag == COMPRESSED_INFINITY {
+        return Ok((G1::zero(), 32));
+    }
+
+    let mut x: [u8; 32] = *take(bytes)?;
+    x[0] &= !MASK;
+    let x = fq(&x)?;
+    let three = Fq::one() + Fq::one() + Fq::one();
+    let y = fq_sqrt(x * x * x + three).ok_or(Error::InvalidPoint)?;
+    let y = if is_largest(y) == (flag == COMPRESSED_LARGEST) { y } else { -y };
+    let point = AffineG1::new(x, y).map_err(|_| Error::InvalidPoint)?;
+    Ok((point.into(), 32))
+}
+
+/// Reads a G2 point in gnark's encoding, compressed or not, from the start of `bytes`. Returns the
+/// point and the number of bytes read.
+pub(crate) fn g2_from_gnark(bytes: &[u8]) -> Result<(G2, usize), Error> {
+    let flag = bytes.first().ok_or(Error::InvalidVerifyingKey)? & MASK;
+    if flag == UNCOMPRESSED {
+        return Ok((g2_from_evm(take(bytes)?)?, 128));
+    }
+    if flag == COMPRESSED_INFINITY {
+        return Ok((G2::zero(), 64));
+    }
+
+    let mut x: [u8; 64] = *take(bytes)?;
+    x[0] &= !MASK;
+    let x = Fq2::new(fq(&x[32..])?, fq(&x[..32])?);
+    let b = Fq2::new(fq(&TWIST_B_REAL)?, fq(&TWIST_B_IMAGINARY)?);
+    let y = fq2_sqrt(x * x * x + b).ok_or(Error::InvalidPoint)?;
+    let largest =
+        if y.imaginary().is_zero() { is_largest(y.real()) } else { is_largest(y.imaginary()) };
+    let y = if largest == (flag == COMPRESSED_LARGEST) { y } else { -y };
+    let point = AffineG2::new(x, y).map_err(|_| Error::InvalidPoint)?;
+    Ok((point.into(), 64))
+}
+
+/// Returns the first `N` bytes.
+fn take<const N: usize>(bytes: &[u8]) -> Result<&[u8; N], Error> {
+    bytes.get(..N).and_then(|bytes| bytes.try_into().ok()).ok_or(Error::InvalidVerifyingKey)
+}
+
+/// Whether `y` is larger than `-y`, comparing their canonical values.
+fn is_largest(y: Fq) -> bool {
+    fq_bytes(y) > HALF_MODULUS
+}
+
+/// Raises `base` to a big-endian exponent, by square and multiply.
+fn pow<T: Copy + Mul<Output = T>>(base: T, one: T, exp: &[u8; 32]) -> T {
+    let mut acc = one;
+    for byte in exp {
+        for i in (0..8).rev() {
+            acc = acc * acc;
+            if (byte >> i) & 1 == 1 {
+                acc = acc * base;
+            }
+        }
+    }
+    acc
+}
+
+/// Returns a square root of `a`, if it is a square.
+fn fq_sqrt(a: Fq) -> Option<Fq> {
+    let root = pow(a, Fq::one(), &FQ_SQRT_EXP);
+    (root * root == a).then_some(root)
+}
+
+/// Returns a square root of `a`, if it is a square, with algorithm 9 of
+/// <https://eprint.iacr.org/2012/685.pdf>.
+fn fq2_sqrt(a: Fq2) -> Option<Fq2> {
+    let a1 = pow(a, Fq2::one(), &FQ2_SQRT_EXP);
+    let x0 = a1 * a;
+    let alpha = a1 * x0;
+    let root = if alpha == -Fq2::one() {
+        // The root is `i * x0`.
+        Fq2::new(-x0.imaginary(), x0.real())
+    } else {
+        pow(Fq2::one() + alpha, Fq2::one(), &HALF_MODULUS) * x0
+    };
+    (root * root == a).then_some(root)
+}
+
+#[cfg(test)]
+mod tests {
+    use bn::Fr;
+
+    use super::*;
+
+    const COMPRESSED_SMALLEST: u8 = 0b10 << 6;
+
+    fn g1_to_gnark(point: G1) -> [u8; 32] {
+        let point = AffineG1::from_jacobian(point).unwrap();
+        let mut bytes = fq_bytes(point.x());
+        bytes[0] |= if is_largest(point.y()) { COMPRESSED_LARGEST } else { COMPRESSED_SMALLEST };
+        bytes
+    }
+
+    fn g2_to_gnark(point: G2) -> [u8; 64] {
+        let point = AffineG2::from_jacobian(point).unwrap();
+        let mut bytes = [0; 64];
+        bytes[..32].copy_from_slice(&fq_bytes(point.x().imaginary()));
+        bytes[32..].copy_from_slice(&fq_bytes(point.x().real()));
+        let y = point.y();
+        let largest =
+            if y.imaginary().is_zero() { is_largest(y.real()) } else { is_largest(y.imaginary()) };
+        bytes[0] |= if largest { COMPRESSED_LARGEST } else { COMPRESSED_SMALLEST };
+        bytes
+    }
+
+    #[test]
+    fn test_gnark_decoding() {
+        for scalar in ["1", "2", "12345", "987654321987654321"] {
+            let scalar = Fr::from_str(scalar).unwrap();
+            let (g1, g2) = (G1::one() * scalar, G2::one() * scalar);
+
+            assert_eq!(g1_from_gnark(&g1_to_gnark(g1)).unwrap(), (g1, 32));
+            assert_eq!(g1_from_gnark(&g1_to_evm(g1)).unwrap(), (g1, 64));
+            assert_eq!(g1_from_gnark(&g1_to_gnark(-g1)).unwrap(), (-g1, 32));
+            assert_eq!(g2_from_gnark(&g2_to_gnark(g2)).unwrap(), (g2, 64));
+            assert_eq!(g2_from_gnark(&g2_to_evm(g2)).unwrap(), (g2, 128));
+            assert_eq!(g2_from_gnark(&g2_to_gnark(-g2)).unwrap(), (-g2, 64));
+        }
+        assert_eq!(g1_from_gnark(&[COMPRESSED_INFINITY; 32]).unwrap().0, G1::zero());
+        assert_eq!(g1_from_evm(&[0; 64]).unwrap(), G1::zero());
+        assert_eq!(g1_from_gnark(&[0; 16]), Err(Error::InvalidVerifyingKey));
+    }
+
+    #[test]
+    fn test_invalid_points() {
+        let mut bytes = g1_to_evm(G1::one());
+        bytes[63] ^= 1;
+        assert_eq!(g1_from_evm(&bytes), Err(Error::InvalidPoint));
+        let mut bytes = g2_to_evm(G2::one());
+        bytes[127] ^= 1;
+        assert_eq!(g2_from_evm(&bytes), Err(Error::InvalidPoint));
+    }
+}
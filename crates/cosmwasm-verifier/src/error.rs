@@ -0,0 +1,44 @@
+use core::fmt;
+
+/// The error returned when a proof or verifying key is rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The proof does not have the size of an encoded Groth16 proof.
+    InvalidProofLength(usize),
+    /// The proof was generated for another Groth16 verifying key.
+    WrongVerifierSelector { received: [u8; 4], expected: [u8; 4] },
+    /// A point of the proof is not on the curve or not in the prime order subgroup.
+    InvalidPoint,
+    /// A public input is not an element of the scalar field.
+    InvalidPublicInput,
+    /// The verifying key is malformed.
+    InvalidVerifyingKey,
+    /// The proof does not verify.
+    InvalidProof,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidProofLength(len) => write!(f, "invalid proof length {len}"),
+            Self::WrongVerifierSelector { received, expected } => write!(
+                f,
+                "wrong verifier selector 0x{:08x}, expected 0x{:08x}",
+                u32::from_be_bytes(*received),
+                u32::from_be_bytes(*expected)
+            ),
+            Self::InvalidPoint => f.write_str("invalid curve point"),
+            Self::InvalidPublicInput => f.write_str("invalid public input"),
+            Self::InvalidVerifyingKey => f.write_str("invalid verifying key"),
+            Self::InvalidProof => f.write_str("invalid proof"),
+        }
+    }
+}
+
+#[cfg(feature = "cosmwasm")]
+impl From<Error> for cosmwasm_std::StdError {
+    fn from(err: Error) -> Self {
+        use alloc::string::ToString;
+        cosmwasm_std::StdError::generic_err(err.to_string())
+    }
+}
@@ -0,0 +1,268 @@
+use bn::{pairing_batch, Fr, Group, Gt, G1, G2};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    bn254::{g1_from_evm, g1_from_gnark, g1_to_evm, g2_from_evm, g2_from_gnark, g2_to_evm},
+    Error,
+};
+
+/// The size of an encoded proof: the 4-byte selector and the points `A`, `B` and `C`.
+pub const GROTH16_PROOF_SIZE: usize = 4 + 64 + 128 + 64;
+
+/// The size of a verifying key encoded with [Groth16VerifyingKey::to_bytes].
+pub const GROTH16_VK_SIZE: usize = 32 + 64 + 3 * 128 + NUM_PUBLIC_INPUTS_K * 64;
+
+/// The number of points of the public input commitment: a constant and one per public input, the
+/// program verification key digest and the public values digest.
+const NUM_PUBLIC_INPUTS_K: usize = 3;
+
+/// The verifying key of the SP1 Groth16 circuit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Groth16VerifyingKey {
+    /// The SHA-256 hash of the gnark verifying key, whose first 4 bytes prefix the proofs.
+    hash: [u8; 32],
+    alpha_g1: G1,
+    beta_g2: G2,
+    gamma_g2: G2,
+    delta_g2: G2,
+    k: [G1; NUM_PUBLIC_INPUTS_K],
+}
+
+impl Groth16VerifyingKey {
+    /// Decodes the verifying key written by gnark, the `groth16_vk.bin` of the circuit artifacts.
+    ///
+    /// The points are usually compressed, so decoding them computes a square root per point, which
+    /// is expensive in a contract. See [Self::to_bytes] for a cheaper encoding to store.
+    pub fn from_gnark_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut reader = GnarkReader { bytes, offset: 0 };
+        let alpha_g1 = reader.g1()?;
+        let _beta_g1 = reader.g1()?;
+        let beta_g2 = reader.g2()?;
+        let gamma_g2 = reader.g2()?;
+        let _delta_g1 = reader.g1()?;
+        let delta_g2 = reader.g2()?;
+        if reader.u32()? as usize != NUM_PUBLIC_INPUTS_K {
+            return Err(Error::InvalidVerifyingKey);
+        }
+        let mut k = [G1::zero(); NUM_PUBLIC_INPUTS_K];
+        for point in k.iter_mut() {
+            *point = reader.g1()?;
+        }
+
+        let hash = Sha256::digest(bytes).into();
+        Ok(Self { hash, alpha_g1, beta_g2, gamma_g2, delta_g2, k })
+    }
+
+    /// Encodes the verifying key with uncompressed points, in [GROTH16_VK_SIZE] bytes.
+    pub fn to_bytes(&self) -> [u8; GROTH16_VK_SIZE] {
+        let mut bytes = [0; GROTH16_VK_SIZE];
+        let (hash, rest) = bytes.split_at_mut(32);
+        hash.copy_from_slice(&self.hash);
+        let (alpha_g1, rest) = rest.split_at_mut(64);
+        alpha_g1.copy_from_slice(&g1_to_evm(self.alpha_g1));
+        let (g2s, rest) = rest.split_at_mut(3 * 128);
+        for (bytes, point) in
+            g2s.chunks_exact_mut(128).zip([self.beta_g2, self.gamma_g2, self.delta_g2])
+        {
+            bytes.copy_from_slice(&g2_to_evm(point));
+        }
+        for (bytes, point) in rest.chunks_exact_mut(64).zip(self.k) {
+            bytes.copy_from_slice(&g1_to_evm(point));
+        }
+        bytes
+    }
+
+    /// Decodes a verifying key encoded with [Self::to_bytes].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != GROTH16_VK_SIZE {
+            return Err(Error::InvalidVerifyingKey);
+        }
+        let (hash, rest) = bytes.split_at(32);
+        let (alpha_g1, rest) = rest.split_at(64);
+        let (g2s, rest) = rest.split_at(3 * 128);
+        let g2 = |i: usize| g2_from_evm(g2s[128 * i..128 * (i + 1)].try_into().unwrap());
+        let mut k = [G1::zero(); NUM_PUBLIC_INPUTS_K];
+        for (point, bytes) in k.iter_mut().zip(rest.chunks_exact(64)) {
+            *point = g1_from_evm(bytes.try_into().unwrap())?;
+        }
+        Ok(Self {
+            hash: hash.try_into().unwrap(),
+            alpha_g1: g1_from_evm(alpha_g1.try_into().unwrap())?,
+            beta_g2: g2(0)?,
+            gamma_g2: g2(1)?,
+            delta_g2: g2(2)?,
+            k,
+        })
+    }
+
+    /// Returns the 4-byte selector that prefixes the proofs of this verifying key.
+    pub fn selector(&self) -> [u8; 4] {
+        self.hash[..4].try_into().unwrap()
+    }
+}
+
+/// A reader of the fields of a gnark verifying key, in the order they are written.
+struct GnarkReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl GnarkReader<'_> {
+    fn rest(&self) -> &[u8] {
+        self.bytes.get(self.offset..).unwrap_or_default()
+    }
+
+    fn g1(&mut self) -> Result<G1, Error> {
+        let (point, read) = g1_from_gnark(self.rest())?;
+        self.offset += read;
+        Ok(point)
+    }
+
+    fn g2(&mut self) -> Result<G2, Error> {
+        let (point, read) = g2_from_gnark(self.rest())?;
+        self.offset += read;
+        Ok(point)
+    }
+
+    fn u32(&mut self) -> Result<u32, Error> {
+        let bytes = self.rest().get(..4).ok_or(Error::InvalidVerifyingKey)?;
+        self.offset += 4;
+        Ok(u32::from_be_bytes(bytes.try_into().unwrap()))
+    }
+}
+
+/// Hashes the public values to an element of the BN254 scalar field, as the `hashPublicValues` of
+/// the Solidity verifier: their SHA-256 hash with the top 3 bits cleared.
+pub fn hash_public_values(public_values: &[u8]) -> [u8; 32] {
+    let mut digest: [u8; 32] = Sha256::digest(public_values).into();
+    digest[0] &= 0x1f;
+    digest
+}
+
+/// Verifies a Groth16 proof of a program, given by the bytes32 digest of its verification key,
+/// with the given public values.
+pub fn verify_groth16(
+    vk: &Groth16VerifyingKey,
+    program_vkey_hash: &[u8; 32],
+    public_values: &[u8],
+    proof: &[u8],
+) -> Result<(), Error> {
+    verify_groth16_digest(vk, program_vkey_hash, &hash_public_values(public_values), proof)
+}
+
+/// Like [verify_groth16], but with the digest of the public values, for programs that hash them
+/// with another function than [hash_public_values].
+pub fn verify_groth16_digest(
+    vk: &Groth16VerifyingKey,
+    program_vkey_hash: &[u8; 32],
+    public_values_digest: &[u8; 32],
+    proof: &[u8],
+) -> Result<(), Error> {
+    if proof.len() != GROTH16_PROOF_SIZE {
+        return Err(Error::InvalidProofLength(proof.len()));
+    }
+    let received: [u8; 4] = proof[..4].try_into().unwrap();
+    if received != vk.selector() {
+        return Err(Error::WrongVerifierSelector { received, expected: vk.selector() });
+    }
+
+    // Reject malformed inputs before the curve operations, which dominate the cost.
+    let program_vkey_hash =
+        Fr::from_slice(program_vkey_hash).map_err(|_| Error::InvalidPublicInput)?;
+    let public_values_digest =
+        Fr::from_slice(public_values_digest).map_err(|_| Error::InvalidPublicInput)?;
+    let a = g1_from_evm(proof[4..68].try_into().unwrap())?;
+    let b = g2_from_evm(proof[68..196].try_into().unwrap())?;
+    let c = g1_from_evm(proof[196..].try_into().unwrap())?;
+
+    // e(A, B) = e(alpha, beta) * e(L, gamma) * e(C, delta), checked with a single batched pairing
+    // that shares the final exponentiation.
+    let l = vk.k[0] + vk.k[1] * program_vkey_hash + vk.k[2] * public_values_digest;
+    let pairing =
+        pairing_batch(&[(-a, b), (vk.alpha_g1, vk.beta_g2), (l, vk.gamma_g2), (c, vk.delta_g2)]);
+    if pairing == Gt::one() {
+        Ok(())
+    } else {
+        Err(Error::InvalidProof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scalar(n: u64) -> Fr {
+        Fr::from_str(&n.to_string()).unwrap()
+    }
+
+    /// A verifying key with known trapdoors, and a proof of the public inputs forged with them.
+    fn forge(program_vkey_hash: &[u8; 32], public_values: &[u8]) -> (Groth16VerifyingKey, Vec<u8>) {
+        let (alpha, beta, gamma, delta) = (scalar(3), scalar(5), scalar(7), scalar(11));
+        let k = [scalar(13), scalar(17), scalar(19)];
+        let vk = Groth16VerifyingKey {
+            hash: [0xab; 32],
+            alpha_g1: G1::one() * alpha,
+            beta_g2: G2::one() * beta,
+            gamma_g2: G2::one() * gamma,
+            delta_g2: G2::one() * delta,
+            k: k.map(|k| G1::one() * k),
+        };
+
+        let l = k[0] +
+            k[1] * Fr::from_slice(program_vkey_hash).unwrap() +
+            k[2] * Fr::from_slice(&hash_public_values(public_values)).unwrap();
+        let (x, y) = (scalar(23), scalar(29));
+        let c = (x * y - alpha * beta - l * gamma) * delta.inverse().unwrap();
+
+        let mut proof = vk.selector().to_vec();
+        proof.extend_from_slice(&g1_to_evm(G1::one() * x));
+        proof.extend_from_slice(&g2_to_evm(G2::one() * y));
+        proof.extend_from_slice(&g1_to_evm(G1::one() * c));
+        (vk, proof)
+    }
+
+    #[test]
+    fn test_verify_groth16() {
+        let mut program_vkey_hash = [0; 32];
+        program_vkey_hash[31] = 42;
+        let (vk, proof) = forge(&program_vkey_hash, b"public values");
+        assert_eq!(verify_groth16(&vk, &program_vkey_hash, b"public values", &proof), Ok(()));
+
+        let vk = Groth16VerifyingKey::from_bytes(&vk.to_bytes()).unwrap();
+        assert_eq!(verify_groth16(&vk, &program_vkey_hash, b"public values", &proof), Ok(()));
+
+        assert_eq!(
+            verify_groth16(&vk, &program_vkey_hash, b"other values", &proof),
+            Err(Error::InvalidProof)
+        );
+        program_vkey_hash[31] = 43;
+        assert_eq!(
+            verify_groth16(&vk, &program_vkey_hash, b"public values", &proof),
+            Err(Error::InvalidProof)
+        );
+    }
+
+    #[test]
+    fn test_rejected_proofs() {
+        let program_vkey_hash = [0; 32];
+        let (vk, mut proof) = forge(&program_vkey_hash, b"");
+        assert_eq!(
+            verify_groth16(&vk, &program_vkey_hash, b"", &proof[..100]),
+            Err(Error::InvalidProofLength(100))
+        );
+        assert_eq!(verify_groth16(&vk, &[0xff; 32], b"", &proof), Err(Error::InvalidPublicInput));
+        proof[0] ^= 1;
+        assert!(matches!(
+            verify_groth16(&vk, &program_vkey_hash, b"", &proof),
+            Err(Error::WrongVerifierSelector { .. })
+        ));
+    }
+
+    #[test]
+    fn test_hash_public_values() {
+        // The digest is reduced to 253 bits, so it is always a valid scalar.
+        let digest = hash_public_values(b"");
+        assert_eq!(digest[0], 0xe3 & 0x1f);
+        assert!(Fr::from_slice(&digest).is_ok());
+    }
+}
@@ -0,0 +1,40 @@
+//! Verification of SP1 Groth16 proofs in CosmWasm contracts.
+//!
+//! The verifier mirrors the `SP1Verifier` Solidity contract: a proof is the 4-byte selector of the
+//! Groth16 verifying key followed by the encoded proof, as returned by
+//! `SP1ProofWithPublicValues::bytes`, and is verified against the bytes32 verification key digest
+//! of the program and its public values.
+//!
+//! The crate is `no_std` and only depends on pure Rust BN254 arithmetic, so it compiles to
+//! `wasm32-unknown-unknown` without host functions. Decoding the gnark verifying key involves
+//! square roots, so contracts should decode it once with
+//! [Groth16VerifyingKey::from_gnark_bytes] when instantiated, store it with
+//! [Groth16VerifyingKey::to_bytes], and load it with [Groth16VerifyingKey::from_bytes] to verify.
+//!
+//! Without the `cosmwasm` feature, the crate also builds for embedded targets such as
+//! `thumbv7em-none-eabi`, for example to verify proofs in secure elements. Its own code does not
+//! allocate, but the pairing of `substrate-bn` collects its inputs into a `Vec`, so these targets
+//! must provide a `#[global_allocator]`.
+//!
+//! ### Examples
+//! ```ignore
+//! use sp1_cosmwasm_verifier::{verify_groth16, Groth16VerifyingKey};
+//!
+//! let vk = Groth16VerifyingKey::from_gnark_bytes(&groth16_vk_bin)?;
+//! verify_groth16(&vk, &program_vkey_hash, &public_values, &proof)?;
+//! ```
+
+#![cfg_attr(not(test), no_std)]
+
+#[cfg(feature = "cosmwasm")]
+extern crate alloc;
+
+mod bn254;
+mod error;
+mod groth16;
+
+pub use error::Error;
+pub use groth16::{
+    hash_public_values, verify_groth16, verify_groth16_digest, Groth16VerifyingKey,
+    GROTH16_PROOF_SIZE, GROTH16_VK_SIZE,
+};
@@ -0,0 +1,164 @@
+use std::str::FromStr;
+
+use generic_array::GenericArray;
+use num::{BigUint, Num};
+use serde::{Deserialize, Serialize};
+use typenum::{U32, U62};
+
+use crate::{
+    edwards::{EdwardsCurve, EdwardsParameters},
+    params::{FieldParameters, NumLimbs},
+    AffinePoint, CurveType, EllipticCurveParameters,
+};
+
+/// Baby Jubjub in the reduced twisted Edwards form `-x^2 + y^2 = 1 + d' x^2 y^2`.
+///
+/// The circom ecosystem (circomlib, iden3, Semaphore) uses the form
+/// `168700 x^2 + y^2 = 1 + 168696 x^2 y^2` of EIP-2494. The map `(x, y) -> (x * sqrt(-168700), y)`
+/// is an isomorphism to the reduced form, whose `a = -1` lets Baby Jubjub share the constraints
+/// of Ed25519. Use [to_reduced] and [from_reduced] to convert between the two.
+pub type BabyJubjub = EdwardsCurve<BabyJubjubParameters>;
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BabyJubjubParameters;
+
+/// The scalar field of BN254, which is the base field of Baby Jubjub.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Bn254ScalarField;
+
+impl FieldParameters for Bn254ScalarField {
+    const MODULUS: &'static [u8] = &[
+        1, 0, 0, 240, 147, 245, 225, 67, 145, 112, 185, 121, 72, 232, 51, 40, 93, 88, 129, 129,
+        182, 69, 80, 184, 41, 160, 49, 225, 114, 78, 100, 48,
+    ];
+
+    const WITNESS_OFFSET: usize = 1usize << 14;
+
+    fn modulus() -> BigUint {
+        BigUint::from_str_radix(
+            "21888242871839275222246405745257275088548364400416034343698204186575808495617",
+            10,
+        )
+        .unwrap()
+    }
+}
+
+impl NumLimbs for Bn254ScalarField {
+    type Limbs = U32;
+    type Witness = U62;
+}
+
+impl EllipticCurveParameters for BabyJubjubParameters {
+    type BaseField = Bn254ScalarField;
+    const CURVE_TYPE: CurveType = CurveType::BabyJubjub;
+}
+
+impl EdwardsParameters for BabyJubjubParameters {
+    /// `d' = -168696 / 168700`.
+    const D: GenericArray<u8, U32> = GenericArray::from_array([
+        142, 235, 215, 244, 140, 202, 117, 208, 103, 200, 183, 235, 89, 41, 155, 3, 252, 17, 253,
+        153, 215, 114, 240, 61, 105, 137, 33, 95, 241, 144, 238, 26,
+    ]);
+
+    /// The order of the prime subgroup, `l` in EIP-2494.
+    fn prime_group_order() -> BigUint {
+        BigUint::from_str(
+            "2736030358979909402780800718157159386076813972158567259200215660948447373041",
+        )
+        .unwrap()
+    }
+
+    /// The generator of the prime subgroup, `Base8` in circomlib, in the reduced form.
+    fn generator() -> (BigUint, BigUint) {
+        let x = BigUint::from_str(
+            "12216525397769193039033285140139874868932027386087289415053270333399021305954",
+        )
+        .unwrap();
+        let y = BigUint::from_str(
+            "16950150798460657717958625567821834550301663161624707787222815936182638968203",
+        )
+        .unwrap();
+        (x, y)
+    }
+}
+
+/// The coefficient `a` of the EIP-2494 form of Baby Jubjub.
+pub const BABYJUBJUB_A: u32 = 168700;
+
+/// The coefficient `d` of the EIP-2494 form of Baby Jubjub.
+pub const BABYJUBJUB_D: u32 = 168696;
+
+/// The square root of `-168700` that maps the EIP-2494 form to the reduced form.
+fn sqrt_neg_a() -> BigUint {
+    BigUint::from_str(
+        "6360561867910373094066688120553762416144456282423235903351243436111059670888",
+    )
+    .unwrap()
+}
+
+/// Maps a point in the EIP-2494 form used by circom to the reduced form.
+pub fn to_reduced(x: &BigUint, y: &BigUint) -> AffinePoint<BabyJubjub> {
+    let modulus = Bn254ScalarField::modulus();
+    AffinePoint::new(x * sqrt_neg_a() % &modulus, y % &modulus)
+}
+
+/// Maps a point in the reduced form to the EIP-2494 form used by circom.
+pub fn from_reduced(point: &AffinePoint<BabyJubjub>) -> (BigUint, BigUint) {
+    let modulus = Bn254ScalarField::modulus();
+    let inverse = sqrt_neg_a().modpow(&(&modulus - 2u32), &modulus);
+    (&point.x * inverse % &modulus, point.y.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use num::{One, Zero};
+
+    use super::*;
+    use crate::EllipticCurve;
+
+    fn is_on_eip2494_curve(x: &BigUint, y: &BigUint) -> bool {
+        let p = Bn254ScalarField::modulus();
+        let xx = x * x % &p;
+        let yy = y * y % &p;
+        let lhs = (xx.clone() * BABYJUBJUB_A + &yy) % &p;
+        let rhs = (BigUint::one() + xx * yy % &p * BABYJUBJUB_D) % &p;
+        lhs == rhs
+    }
+
+    #[test]
+    fn test_babyjubjub_reduced_form() {
+        let p = Bn254ScalarField::modulus();
+        let d = BabyJubjubParameters::d_biguint();
+        assert_eq!(d * BABYJUBJUB_A % &p, (&p - BABYJUBJUB_D) % &p);
+        assert_eq!(sqrt_neg_a().pow(2) % &p, &p - BABYJUBJUB_A);
+
+        // Base8 of circomlib.
+        let base8_x = BigUint::from_str(
+            "5299619240641551281634865583518297030282874472190772894086521144482721001553",
+        )
+        .unwrap();
+        let (_, base8_y) = BabyJubjubParameters::generator();
+        assert!(is_on_eip2494_curve(&base8_x, &base8_y));
+        let generator = BabyJubjub::ec_generator();
+        assert_eq!(to_reduced(&base8_x, &base8_y), generator);
+        assert_eq!(from_reduced(&generator), (base8_x, base8_y));
+    }
+
+    #[test]
+    fn test_babyjubjub_group_order() {
+        let generator = BabyJubjub::ec_generator();
+        let identity = &generator * &BabyJubjubParameters::prime_group_order();
+        assert_eq!(identity, BabyJubjub::neutral());
+        assert!(!(&generator * &BigUint::from(12345u32)).x.is_zero());
+    }
+
+    #[test]
+    fn test_babyjubjub_add_matches_eip2494() {
+        let generator = BabyJubjub::ec_generator();
+        let point = &generator * &BigUint::from(7u32);
+        let sum = &generator + &point;
+        let (x, y) = from_reduced(&sum);
+        assert!(is_on_eip2494_curve(&x, &y));
+        assert_eq!(sum, &generator * &BigUint::from(8u32));
+    }
+}
@@ -1,3 +1,4 @@
+pub mod babyjubjub;
 pub mod ed25519;
 
 use generic_array::GenericArray;
@@ -42,6 +42,7 @@ pub enum CurveType {
     Bn254,
     Ed25519,
     Bls12381,
+    BabyJubjub,
 }
 
 impl Display for CurveType {
@@ -51,6 +52,7 @@ impl Display for CurveType {
             CurveType::Bn254 => write!(f, "Bn254"),
             CurveType::Ed25519 => write!(f, "Ed25519"),
             CurveType::Bls12381 => write!(f, "Bls12381"),
+            CurveType::BabyJubjub => write!(f, "BabyJubjub"),
         }
     }
 }
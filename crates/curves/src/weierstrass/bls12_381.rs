@@ -1,10 +1,11 @@
 use amcl::bls381::{big::Big, bls381::utils::deserialize_g1, fp::FP};
 use generic_array::GenericArray;
+use itertools::Itertools;
 use num::{BigUint, Num, Zero};
 use serde::{Deserialize, Serialize};
 use typenum::{U48, U94};
 
-use super::{FieldType, FpOpField, SwCurve, WeierstrassParameters};
+use super::{sswu::SswuParameters, FieldType, FpOpField, SwCurve, WeierstrassParameters};
 use crate::{
     params::{FieldParameters, NumLimbs},
     CurveType, EllipticCurveParameters,
@@ -111,6 +112,30 @@ impl WeierstrassParameters for Bls12381Parameters {
     }
 }
 
+/// The parameters of the map to the curve `E'` 11-isogenous to BLS12-381 G1, from RFC 9380,
+/// section 8.8.1.
+impl SswuParameters for Bls12381Parameters {
+    fn iso_a() -> BigUint {
+        BigUint::from_str_radix(
+            "144698a3b8e9433d693a02c96d4982b0ea985383ee66a8d8e8981aefd881ac98936f8da0e0f97f5cf428082d584c1d",
+            16,
+        )
+        .unwrap()
+    }
+
+    fn iso_b() -> BigUint {
+        BigUint::from_str_radix(
+            "12e2908d11688030018b12e8753eee3b2016c1f0f24f4070a0b9c14fcef35ef55a23215a316ceaa5d1cc48e98e172be0",
+            16,
+        )
+        .unwrap()
+    }
+
+    fn z() -> BigUint {
+        BigUint::from(11u32)
+    }
+}
+
 pub fn bls12381_decompress<E: EllipticCurve>(bytes_be: &[u8], sign_bit: u32) -> AffinePoint<E> {
     let mut g1_bytes_be: [u8; 48] = bytes_be.try_into().unwrap();
     let mut flags = COMPRESION_FLAG;
@@ -138,6 +163,101 @@ pub fn bls12381_sqrt(a: &BigUint) -> BigUint {
     BigUint::from_str_radix(a_sqrt.to_string().as_str(), 16).unwrap()
 }
 
+/// The number of words of a point of the BLS12-381 G2 curve, in affine coordinates
+/// `(x.c0, x.c1, y.c0, y.c1)` over Fp2.
+pub const BLS12381_G2_POINT_WORDS: usize = 48;
+
+/// An element `c0 + c1 * u` of Fp2 = Fp[u] / (u^2 + 1), with both coefficients reduced.
+type Fp2 = [BigUint; 2];
+
+fn fp2_add(a: &Fp2, b: &Fp2) -> Fp2 {
+    let modulus = Bls12381BaseField::modulus();
+    [(&a[0] + &b[0]) % &modulus, (&a[1] + &b[1]) % &modulus]
+}
+
+fn fp2_sub(a: &Fp2, b: &Fp2) -> Fp2 {
+    let modulus = Bls12381BaseField::modulus();
+    [(&modulus + &a[0] - &b[0]) % &modulus, (&modulus + &a[1] - &b[1]) % &modulus]
+}
+
+fn fp2_mul(a: &Fp2, b: &Fp2) -> Fp2 {
+    let modulus = Bls12381BaseField::modulus();
+    let c0 = (&a[0] * &b[0] + &modulus * &modulus - &a[1] * &b[1]) % &modulus;
+    let c1 = (&a[0] * &b[1] + &a[1] * &b[0]) % &modulus;
+    [c0, c1]
+}
+
+/// Computes `a / b` as `a * conj(b) / (b.c0^2 + b.c1^2)`.
+fn fp2_div(a: &Fp2, b: &Fp2) -> Fp2 {
+    let modulus = Bls12381BaseField::modulus();
+    let norm = (&b[0] * &b[0] + &b[1] * &b[1]) % &modulus;
+    assert!(!norm.is_zero(), "division by zero in Fp2");
+    let norm_inv = norm.modpow(&(&modulus - 2u32), &modulus);
+    let conj = [b[0].clone(), (&modulus - &b[1]) % &modulus];
+    fp2_mul(a, &conj).map(|c| c * &norm_inv % &modulus)
+}
+
+/// Returns the generator of G2, as `[x.c0, x.c1, y.c0, y.c1]`.
+pub fn bls12381_g2_generator() -> [BigUint; 4] {
+    [
+        "024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8",
+        "13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e",
+        "0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801",
+        "0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be",
+    ]
+    .map(|hex| BigUint::from_str_radix(hex, 16).unwrap())
+}
+
+/// Splits the words of a G2 point into its coordinates `[x.c0, x.c1, y.c0, y.c1]`.
+pub fn bls12381_g2_from_words_le(words: &[u32]) -> [BigUint; 4] {
+    assert_eq!(words.len(), BLS12381_G2_POINT_WORDS);
+    core::array::from_fn(|i| {
+        let bytes = words[i * 12..(i + 1) * 12].iter().flat_map(|w| w.to_le_bytes()).collect_vec();
+        BigUint::from_bytes_le(&bytes)
+    })
+}
+
+/// Writes the coordinates `[x.c0, x.c1, y.c0, y.c1]` of a G2 point as words.
+pub fn bls12381_g2_to_words_le(point: &[BigUint; 4]) -> Vec<u32> {
+    point
+        .iter()
+        .flat_map(|coordinate| {
+            let mut bytes = coordinate.to_bytes_le();
+            bytes.resize(48, 0);
+            bytes.chunks_exact(4).map(|b| u32::from_le_bytes(b.try_into().unwrap())).collect_vec()
+        })
+        .collect()
+}
+
+/// Adds two points of the BLS12-381 G2 curve `y^2 = x^3 + 4 (1 + u)` in affine coordinates.
+///
+/// The points must be distinct, not opposite, and not the point at infinity.
+pub fn bls12381_g2_add(p: &[BigUint; 4], q: &[BigUint; 4]) -> [BigUint; 4] {
+    let (px, py) = ([p[0].clone(), p[1].clone()], [p[2].clone(), p[3].clone()]);
+    let (qx, qy) = ([q[0].clone(), q[1].clone()], [q[2].clone(), q[3].clone()]);
+    let slope = fp2_div(&fp2_sub(&qy, &py), &fp2_sub(&qx, &px));
+    let x = fp2_sub(&fp2_sub(&fp2_mul(&slope, &slope), &px), &qx);
+    let y = fp2_sub(&fp2_mul(&slope, &fp2_sub(&px, &x)), &py);
+    let [x0, x1] = x;
+    let [y0, y1] = y;
+    [x0, x1, y0, y1]
+}
+
+/// Doubles a point of the BLS12-381 G2 curve in affine coordinates.
+///
+/// The point must not be the point at infinity.
+pub fn bls12381_g2_double(p: &[BigUint; 4]) -> [BigUint; 4] {
+    let (px, py) = ([p[0].clone(), p[1].clone()], [p[2].clone(), p[3].clone()]);
+    let px_squared = fp2_mul(&px, &px);
+    let numerator = fp2_add(&fp2_add(&px_squared, &px_squared), &px_squared);
+    let slope = fp2_div(&numerator, &fp2_add(&py, &py));
+    let x = fp2_sub(&fp2_sub(&fp2_mul(&slope, &slope), &px), &px);
+    let y = fp2_sub(&fp2_mul(&slope, &fp2_sub(&px, &x)), &py);
+    let [x0, x1] = x;
+    let [y0, y1] = y;
+    [x0, x1, y0, y1]
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -205,4 +325,30 @@ mod tests {
             assert_eq!(sqrt_2, x_2);
         }
     }
+
+    fn assert_on_g2_curve(point: &[BigUint; 4]) {
+        let x = [point[0].clone(), point[1].clone()];
+        let y = [point[2].clone(), point[3].clone()];
+        let b = [BigUint::from(4u32), BigUint::from(4u32)];
+        let rhs = fp2_add(&fp2_mul(&fp2_mul(&x, &x), &x), &b);
+        assert_eq!(fp2_mul(&y, &y), rhs);
+    }
+
+    #[test]
+    fn test_bls12381_g2_add_double() {
+        let g = bls12381_g2_generator();
+        assert_on_g2_curve(&g);
+
+        let g2 = bls12381_g2_double(&g);
+        let g3 = bls12381_g2_add(&g2, &g);
+        let g4 = bls12381_g2_double(&g2);
+        assert_on_g2_curve(&g2);
+        assert_on_g2_curve(&g3);
+        assert_eq!(bls12381_g2_add(&g3, &g), g4);
+        assert_eq!(bls12381_g2_add(&g, &g3), g4);
+
+        let words = bls12381_g2_to_words_le(&g3);
+        assert_eq!(words.len(), BLS12381_G2_POINT_WORDS);
+        assert_eq!(bls12381_g2_from_words_le(&words), g3);
+    }
 }
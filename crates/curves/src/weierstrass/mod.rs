@@ -11,7 +11,9 @@ use crate::{
 
 pub mod bls12_381;
 pub mod bn254;
+pub mod registered;
 pub mod secp256k1;
+pub mod sswu;
 
 /// Parameters that specify a short Weierstrass curve : y^2 = x^3 + ax + b.
 pub trait WeierstrassParameters: EllipticCurveParameters {
@@ -0,0 +1,182 @@
+//! Short Weierstrass curves whose parameters are values of a registry instead of types.
+//!
+//! Each registered curve gets its own add and double chips at machine setup, built from the same
+//! generic chip with the parameters of the curve, so supporting a new curve only requires a new
+//! entry in [REGISTERED_CURVES].
+
+use num::{BigUint, Num, One, Zero};
+
+/// The number of bytes of a field element of a registered curve.
+pub const REGISTERED_CURVE_NB_LIMBS: usize = 32;
+
+/// The number of words of a point of a registered curve, without its curve id.
+pub const REGISTERED_CURVE_POINT_WORDS: usize = 2 * REGISTERED_CURVE_NB_LIMBS / 4;
+
+/// A short Weierstrass curve `y^2 = x^3 + ax + b` over a prime field of at most 256 bits.
+///
+/// The parameters are big endian hexadecimal strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegisteredCurve {
+    /// The id of the curve, which prefixes the points passed to the syscalls.
+    pub id: u32,
+    /// The name of the curve, which prefixes the names of its chips.
+    pub name: &'static str,
+    /// The modulus of the base field.
+    pub modulus: &'static str,
+    /// The `a` coefficient of the curve.
+    pub a: &'static str,
+    /// The `b` coefficient of the curve.
+    pub b: &'static str,
+    /// The x coordinate of the generator.
+    pub generator_x: &'static str,
+    /// The y coordinate of the generator.
+    pub generator_y: &'static str,
+}
+
+/// The brainpoolP256r1 curve of RFC 5639.
+pub const BRAINPOOL_P256R1: RegisteredCurve = RegisteredCurve {
+    id: 1,
+    name: "BrainpoolP256r1",
+    modulus: "a9fb57dba1eea9bc3e660a909d838d726e3bf623d52620282013481d1f6e5377",
+    a: "7d5a0975fc2c3057eef67530417affe7fb8055c126dc5c6ce94a4b44f330b5d9",
+    b: "26dc5c6ce94a4b44f330b5d9bbd77cbf958416295cf7e1ce6bccdc18ff8c07b6",
+    generator_x: "8bd2aeb9cb7e57cb2c4b482ffc81b7afb9de27e1e3bd23c23a4453bd9ace3262",
+    generator_y: "547ef835c3dac4fd97f8461a14611dc9c27745132ded8e545c1d54c72f046997",
+};
+
+/// The STARK curve used by Starknet, over the prime `2^251 + 17 * 2^192 + 1`.
+pub const STARK_CURVE: RegisteredCurve = RegisteredCurve {
+    id: 2,
+    name: "StarkCurve",
+    modulus: "0800000000000011000000000000000000000000000000000000000000000001",
+    a: "01",
+    b: "06f21413efbe40de150e596d72f7a8c5609ad26c15c915c1f4cdfcb99cee9e89",
+    generator_x: "01ef15c18599971b7beced415a40f0c7deacfd9b0d1819e03d723d8bc943cfca",
+    generator_y: "005668060aa49730b7be4801df46ec62de53ecd11abe43a32873000c36e8dc1f",
+};
+
+/// The registered curves. The ids must be unique.
+pub const REGISTERED_CURVES: &[RegisteredCurve] = &[BRAINPOOL_P256R1, STARK_CURVE];
+
+/// Returns the registered curve with the given id.
+pub fn registered_curve(id: u32) -> Option<&'static RegisteredCurve> {
+    REGISTERED_CURVES.iter().find(|curve| curve.id == id)
+}
+
+fn parse_hex(hex: &str) -> BigUint {
+    BigUint::from_str_radix(hex, 16).expect("invalid hex parameter")
+}
+
+impl RegisteredCurve {
+    /// The modulus of the base field.
+    pub fn modulus_int(&self) -> BigUint {
+        parse_hex(self.modulus)
+    }
+
+    /// The `a` coefficient of the curve.
+    pub fn a_int(&self) -> BigUint {
+        parse_hex(self.a)
+    }
+
+    /// The `b` coefficient of the curve.
+    pub fn b_int(&self) -> BigUint {
+        parse_hex(self.b)
+    }
+
+    /// The generator of the curve.
+    pub fn generator(&self) -> (BigUint, BigUint) {
+        (parse_hex(self.generator_x), parse_hex(self.generator_y))
+    }
+
+    /// Whether the point is on the curve.
+    pub fn is_on_curve(&self, (x, y): &(BigUint, BigUint)) -> bool {
+        let p = self.modulus_int();
+        (y * y) % &p == (x * x * x + self.a_int() * x + self.b_int()) % &p
+    }
+
+    /// Adds two distinct points whose x coordinates differ.
+    pub fn add(&self, p: &(BigUint, BigUint), q: &(BigUint, BigUint)) -> (BigUint, BigUint) {
+        let modulus = self.modulus_int();
+        let numerator = (&modulus + &q.1 - &p.1) % &modulus;
+        let denominator = (&modulus + &q.0 - &p.0) % &modulus;
+        assert!(!denominator.is_zero(), "the points must have distinct x coordinates");
+        let slope = (numerator * inverse(&denominator, &modulus)) % &modulus;
+        self.finish(p, &q.0, &slope)
+    }
+
+    /// Doubles a point whose y coordinate is not zero.
+    pub fn double(&self, p: &(BigUint, BigUint)) -> (BigUint, BigUint) {
+        let modulus = self.modulus_int();
+        let numerator = (self.a_int() + &p.0 * &p.0 * 3u32) % &modulus;
+        let denominator = (&p.1 * 2u32) % &modulus;
+        assert!(!denominator.is_zero(), "the point must not be of order two");
+        let slope = (numerator * inverse(&denominator, &modulus)) % &modulus;
+        self.finish(p, &p.0, &slope)
+    }
+
+    /// Computes the sum of `p` and the point with x coordinate `q_x` on the line through `p` with
+    /// the given slope.
+    fn finish(&self, p: &(BigUint, BigUint), q_x: &BigUint, slope: &BigUint) -> (BigUint, BigUint) {
+        let modulus = self.modulus_int();
+        let x = (slope * slope + &modulus * 2u32 - &p.0 - q_x) % &modulus;
+        let y = (slope * ((&modulus + &p.0 - &x) % &modulus) + &modulus - &p.1) % &modulus;
+        (x, y)
+    }
+
+    /// Decodes a point from its little endian words, x first.
+    pub fn point_from_words_le(words: &[u32]) -> (BigUint, BigUint) {
+        assert_eq!(words.len(), REGISTERED_CURVE_POINT_WORDS);
+        let (x, y) = words.split_at(REGISTERED_CURVE_POINT_WORDS / 2);
+        (BigUint::from_slice(x), BigUint::from_slice(y))
+    }
+
+    /// Encodes a point as its little endian words, x first.
+    pub fn point_to_words_le((x, y): &(BigUint, BigUint)) -> Vec<u32> {
+        let mut words = Vec::with_capacity(REGISTERED_CURVE_POINT_WORDS);
+        for coordinate in [x, y] {
+            let mut digits = coordinate.to_u32_digits();
+            digits.resize(REGISTERED_CURVE_POINT_WORDS / 2, 0);
+            words.extend(digits);
+        }
+        words
+    }
+}
+
+/// Inverts `x` modulo the prime `modulus` with Fermat's little theorem.
+fn inverse(x: &BigUint, modulus: &BigUint) -> BigUint {
+    let inverse = x.modpow(&(modulus - 2u32), modulus);
+    debug_assert!((x * &inverse) % modulus == BigUint::one());
+    inverse
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registered_curves() {
+        for (i, curve) in REGISTERED_CURVES.iter().enumerate() {
+            assert!(REGISTERED_CURVES[..i].iter().all(|other| other.id != curve.id));
+            assert!(curve.modulus_int().bits() <= 8 * REGISTERED_CURVE_NB_LIMBS as u64);
+            assert!(curve.is_on_curve(&curve.generator()));
+        }
+    }
+
+    #[test]
+    fn test_registered_curve_arithmetic() {
+        for curve in REGISTERED_CURVES {
+            let g = curve.generator();
+            let g2 = curve.double(&g);
+            let g3 = curve.add(&g2, &g);
+            let g4 = curve.double(&g2);
+            assert!(curve.is_on_curve(&g2));
+            assert!(curve.is_on_curve(&g3));
+            assert_eq!(curve.add(&g3, &g), g4);
+
+            let words = RegisteredCurve::point_to_words_le(&g3);
+            assert_eq!(RegisteredCurve::point_from_words_le(&words), g3);
+        }
+        assert_eq!(registered_curve(STARK_CURVE.id), Some(&STARK_CURVE));
+        assert_eq!(registered_curve(0), None);
+    }
+}
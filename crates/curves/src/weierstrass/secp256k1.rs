@@ -13,7 +13,7 @@ use num::{
 use serde::{Deserialize, Serialize};
 use typenum::{U32, U62};
 
-use super::{SwCurve, WeierstrassParameters};
+use super::{sswu::SswuParameters, SwCurve, WeierstrassParameters};
 use crate::{
     params::{FieldParameters, NumLimbs},
     AffinePoint, CurveType, EllipticCurve, EllipticCurveParameters,
@@ -92,6 +92,26 @@ impl WeierstrassParameters for Secp256k1Parameters {
     }
 }
 
+/// The parameters of the map to the curve `E'` 3-isogenous to secp256k1, from RFC 9380, section
+/// 8.7.
+impl SswuParameters for Secp256k1Parameters {
+    fn iso_a() -> BigUint {
+        BigUint::parse_bytes(
+            b"3f8731abdd661adca08a5558f0f5d272e953d363cb6f0e5d405447c01a444533",
+            16,
+        )
+        .unwrap()
+    }
+
+    fn iso_b() -> BigUint {
+        BigUint::from(1771u32)
+    }
+
+    fn z() -> BigUint {
+        Secp256k1BaseField::modulus() - 11u32
+    }
+}
+
 pub fn secp256k1_decompress<E: EllipticCurve>(bytes_be: &[u8], sign: u32) -> AffinePoint<E> {
     let computed_point =
         k256::AffinePoint::decompress(bytes_be.into(), Choice::from(sign as u8)).unwrap();
@@ -0,0 +1,117 @@
+//! The simplified Shallue-van de Woestijne-Ulas (SSWU) map of RFC 9380, section 6.6.2, which maps
+//! a field element to a point of a curve `E'` isogenous to the target curve.
+//!
+//! Hash-to-curve then applies the isogeny from `E'` to the target curve and clears the cofactor,
+//! which are left to the caller.
+
+use num::{BigUint, One, Zero};
+
+use super::{SwCurve, WeierstrassParameters};
+use crate::params::FieldParameters;
+
+/// Parameters of the SSWU map to a curve whose `a` or `b` coefficient is zero, through the curve
+/// `E': y^2 = x^3 + A' * x + B'` isogenous to it.
+pub trait SswuParameters: WeierstrassParameters {
+    /// The coefficient `A'` of the isogenous curve.
+    fn iso_a() -> BigUint;
+
+    /// The coefficient `B'` of the isogenous curve.
+    fn iso_b() -> BigUint;
+
+    /// The non-square `Z` of the map, reduced modulo the base field.
+    fn z() -> BigUint;
+}
+
+impl<E: SswuParameters> SswuParameters for SwCurve<E> {
+    fn iso_a() -> BigUint {
+        E::iso_a()
+    }
+
+    fn iso_b() -> BigUint {
+        E::iso_b()
+    }
+
+    fn z() -> BigUint {
+        E::z()
+    }
+}
+
+/// The square root of `a` in the base field of `E`, if `a` is a square.
+///
+/// The root is `a^((p + 1) / 4)`, since the modulus `p` of the supported fields is 3 modulo 4.
+pub fn sswu_sqrt<E: SswuParameters>(a: &BigUint) -> Option<BigUint> {
+    let modulus = E::BaseField::modulus();
+    debug_assert_eq!(&modulus % 4u32, BigUint::from(3u32));
+    let sqrt = a.modpow(&((&modulus + 1u32) >> 2), &modulus);
+    (&sqrt * &sqrt % &modulus == a % &modulus).then_some(sqrt)
+}
+
+/// Evaluates `x^3 + A' * x + B'`, the right-hand side of the equation of the isogenous curve.
+pub fn sswu_iso_rhs<E: SswuParameters>(x: &BigUint) -> BigUint {
+    let modulus = E::BaseField::modulus();
+    (x * x * x + E::iso_a() * x + E::iso_b()) % &modulus
+}
+
+/// Maps the reduced field element `u` to a point `(x, y)` of the isogenous curve `E'`.
+///
+/// Returns `None` for the exceptional inputs for which `Z^2 * u^4 + Z * u^2 = 0`: zero and the two
+/// square roots of `-1 / Z`.
+pub fn sswu_map<E: SswuParameters>(u: &BigUint) -> Option<(BigUint, BigUint)> {
+    let modulus = E::BaseField::modulus();
+    assert!(u < &modulus, "u must be reduced");
+    let inverse = |a: &BigUint| a.modpow(&(&modulus - 2u32), &modulus);
+
+    let z_u2 = E::z() * u * u % &modulus;
+    let den = (&z_u2 * &z_u2 + &z_u2) % &modulus;
+    if den.is_zero() {
+        return None;
+    }
+
+    // x1 = (-B' / A') * (1 + 1 / (Z^2 * u^4 + Z * u^2)).
+    let c1 = (&modulus - E::iso_b()) * inverse(&E::iso_a()) % &modulus;
+    let x1 = c1 * (inverse(&den) + BigUint::one()) % &modulus;
+    let gx1 = sswu_iso_rhs::<E>(&x1);
+
+    // Exactly one of g(x1) and g(x2) = Z^3 * u^6 * g(x1) is a square, with x2 = Z * u^2 * x1.
+    let (x, y) = match sswu_sqrt::<E>(&gx1) {
+        Some(y) => (x1, y),
+        None => {
+            let x2 = z_u2 * x1 % &modulus;
+            let y = sswu_sqrt::<E>(&sswu_iso_rhs::<E>(&x2)).expect("g(x2) should be a square");
+            (x2, y)
+        }
+    };
+
+    // The sign of y matches the sign of u.
+    let y = if y.bit(0) == u.bit(0) { y } else { (&modulus - y) % &modulus };
+    Some((x, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use num::bigint::RandBigInt;
+    use rand::thread_rng;
+
+    use super::*;
+    use crate::weierstrass::{bls12_381::Bls12381Parameters, secp256k1::Secp256k1Parameters};
+
+    fn check_sswu_map<E: SswuParameters>() {
+        let modulus = E::BaseField::modulus();
+        assert!(sswu_sqrt::<E>(&E::z()).is_none(), "Z should be a non-square");
+        assert!(sswu_map::<E>(&BigUint::zero()).is_none());
+
+        let mut rng = thread_rng();
+        for _ in 0..10 {
+            let u = rng.gen_biguint_below(&modulus);
+            let (x, y) = sswu_map::<E>(&u).unwrap();
+            assert_eq!(&y * &y % &modulus, sswu_iso_rhs::<E>(&x));
+            assert_eq!(y.bit(0), u.bit(0));
+        }
+    }
+
+    #[test]
+    fn test_sswu_map() {
+        check_sswu_map::<Secp256k1Parameters>();
+        check_sswu_map::<Bls12381Parameters>();
+    }
+}
@@ -177,6 +177,13 @@ pub fn machine_air_derive(input: TokenStream) -> TokenStream {
                 }
             });
 
+            let single_pass_arms = variants.iter().map(|(variant_name, field)| {
+                let field_ty = &field.ty;
+                quote! {
+                    #name::#variant_name(x) => <#field_ty as sp1_stark::air::MachineAir<F>>::single_pass(x)
+                }
+            });
+
             let included_arms = variants.iter().map(|(variant_name, field)| {
                 let field_ty = &field.ty;
                 quote! {
@@ -231,6 +238,12 @@ pub fn machine_air_derive(input: TokenStream) -> TokenStream {
                         }
                     }
 
+                    fn single_pass(&self) -> bool {
+                        match self {
+                            #(#single_pass_arms,)*
+                        }
+                    }
+
                     fn included(&self, shard: &Self::Record) -> bool {
                         match self {
                             #(#included_arms,)*
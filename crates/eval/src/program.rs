@@ -32,6 +32,6 @@ pub const PROGRAMS: &[TesterProgram] = &[
 ];
 
 pub fn load_program(elf: &[u8], input: &[u8]) -> (Vec<u8>, SP1Stdin) {
-    let stdin: SP1Stdin = bincode::deserialize(input).expect("failed to deserialize input");
+    let stdin = SP1Stdin::from_bytes(input).expect("failed to deserialize input");
     (elf.to_vec(), stdin)
 }
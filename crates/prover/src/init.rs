@@ -17,7 +17,7 @@ use sp1_recursion_program::machine::{
 use sp1_stark::{MachineProver, StarkProvingKey, StarkVerifyingKey};
 use tracing::debug_span;
 
-use crate::{InnerSC, OuterSC, SP1Prover};
+use crate::{metrics, InnerSC, OuterSC, SP1Prover};
 
 impl<C: SP1ProverComponents> SP1Prover<C> {
     /// The program that can recursively verify a set of proofs into a single proof.
@@ -79,6 +79,7 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
 
     /// The proving and verifying keys for the recursion step.
     pub fn recursion_keys(&self) -> &(StarkProvingKey<InnerSC>, StarkVerifyingKey<InnerSC>) {
+        metrics::record_key_cache("recursion", self.recursion_keys.get().is_some());
         self.recursion_keys.get_or_init(|| {
             debug_span!("init recursion keys")
                 .in_scope(|| self.compress_prover.setup(self.recursion_program()))
@@ -97,6 +98,7 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
 
     /// The proving and verifying keys for the deferred step.
     pub fn deferred_keys(&self) -> &(StarkProvingKey<InnerSC>, StarkVerifyingKey<InnerSC>) {
+        metrics::record_key_cache("deferred", self.deferred_keys.get().is_some());
         self.deferred_keys.get_or_init(|| {
             debug_span!("init deferred keys")
                 .in_scope(|| self.compress_prover.setup(self.deferred_program()))
@@ -115,6 +117,7 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
 
     /// The proving and verifying keys for the compress step.
     pub fn compress_keys(&self) -> &(StarkProvingKey<InnerSC>, StarkVerifyingKey<InnerSC>) {
+        metrics::record_key_cache("compress", self.compress_keys.get().is_some());
         self.compress_keys.get_or_init(|| {
             debug_span!("init compress keys")
                 .in_scope(|| self.compress_prover.setup(self.compress_program()))
@@ -133,6 +136,7 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
 
     /// The proving and verifying keys for the shrink step.
     pub fn shrink_keys(&self) -> &(StarkProvingKey<InnerSC>, StarkVerifyingKey<InnerSC>) {
+        metrics::record_key_cache("shrink", self.shrink_keys.get().is_some());
         self.shrink_keys.get_or_init(|| {
             debug_span!("init shrink keys")
                 .in_scope(|| self.shrink_prover.setup(self.shrink_program()))
@@ -151,6 +155,7 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
 
     /// The proving and verifying keys for the wrap step.
     pub fn wrap_keys(&self) -> &(StarkProvingKey<OuterSC>, StarkVerifyingKey<OuterSC>) {
+        metrics::record_key_cache("wrap", self.wrap_keys.get().is_some());
         self.wrap_keys.get_or_init(|| {
             debug_span!("init wrap keys").in_scope(|| self.wrap_prover.setup(self.wrap_program()))
         })
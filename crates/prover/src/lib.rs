@@ -14,6 +14,11 @@
 pub mod build;
 pub mod components;
 pub mod init;
+pub mod metrics;
+pub mod shutdown;
+pub mod throughput;
+pub mod tiny;
+pub mod tree;
 pub mod types;
 pub mod utils;
 pub mod verify;
@@ -23,6 +28,7 @@ use std::{
     path::Path,
     sync::{mpsc::sync_channel, Arc, Mutex, OnceLock},
     thread,
+    time::Instant,
 };
 
 use crate::init::SP1PublicValues;
@@ -32,10 +38,11 @@ use p3_challenger::CanObserve;
 use p3_field::{AbstractField, PrimeField};
 use p3_matrix::dense::RowMajorMatrix;
 use sp1_core_executor::{ExecutionError, ExecutionReport, Executor, Program, SP1Context};
-pub use sp1_core_machine::io::SP1Stdin;
+pub use sp1_core_machine::io::{PublicValuesHash, SP1Stdin};
 use sp1_core_machine::{
+    io::max_stdin_size_from_elf,
     riscv::RiscvAir,
-    utils::{concurrency::TurnBasedSync, SP1CoreProverError},
+    utils::{CoreProofOutcome, CoreProofProgress, SP1CoreProverError},
 };
 use sp1_primitives::hash_deferred_proof;
 use sp1_recursion_circuit::witness::Witnessable;
@@ -54,11 +61,12 @@ pub use sp1_recursion_program::machine::{
 };
 use sp1_stark::{
     air::PublicValues, baby_bear_poseidon2::BabyBearPoseidon2, Challenge, Challenger,
-    MachineProver, MachineVerificationError, SP1CoreOpts, SP1ProverOpts, ShardProof,
+    MachineProver, MachineVerificationError, SP1CoreOpts, SP1ProverOpts, ShardProof, ShardWitness,
     StarkGenericConfig, StarkProvingKey, StarkVerifyingKey, Val, Word, DIGEST_SIZE,
 };
 
 use tracing::instrument;
+use tree::{ReduceScheduler, ReduceTree, TreeNode};
 pub use types::*;
 use utils::words_to_bytes;
 
@@ -193,15 +201,33 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
     }
 
     /// Creates a proving key and a verifying key for a given RISC-V ELF.
+    ///
+    /// The verifying key records the hash of the public values the program was built with, and
+    /// the maximum size of its input if it declares one.
     #[instrument(name = "setup", level = "debug", skip_all)]
     pub fn setup(&self, elf: &[u8]) -> (SP1ProvingKey, SP1VerifyingKey) {
         let program = Program::from(elf).unwrap();
+        let public_values_hash = PublicValuesHash::from_elf(elf).unwrap();
+        let max_stdin_size = max_stdin_size_from_elf(elf).unwrap();
         let (pk, vk) = self.core_prover.setup(&program);
-        let vk = SP1VerifyingKey { vk };
+        let vk = SP1VerifyingKey { vk, public_values_hash, max_stdin_size };
         let pk = SP1ProvingKey { pk, elf: elf.to_vec(), vk: vk.clone() };
         (pk, vk)
     }
 
+    /// Computes the verifying key of a given RISC-V ELF, without creating its proving key.
+    ///
+    /// Only the core machine is used, so this works on an [uninitialized](SP1Prover::uninitialized)
+    /// prover.
+    #[instrument(name = "setup_vk", level = "debug", skip_all)]
+    pub fn setup_vk(&self, elf: &[u8]) -> SP1VerifyingKey {
+        let program = Program::from(elf).unwrap();
+        let public_values_hash = PublicValuesHash::from_elf(elf).unwrap();
+        let max_stdin_size = max_stdin_size_from_elf(elf).unwrap();
+        let vk = self.core_prover.machine().setup_vk(&program);
+        SP1VerifyingKey { vk, public_values_hash, max_stdin_size }
+    }
+
     /// Generate a proof of an SP1 program with the specified inputs.
     #[instrument(name = "execute", level = "info", skip_all)]
     pub fn execute<'a>(
@@ -211,10 +237,13 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
         mut context: SP1Context<'a>,
     ) -> Result<(SP1PublicValues, ExecutionReport), ExecutionError> {
         context.subproof_verifier.replace(Arc::new(self));
-        let program = Program::from(elf).unwrap();
+        let program = Program::from(elf)?;
         let opts = SP1CoreOpts::default();
         let mut runtime = Executor::with_context(program, opts, context);
         runtime.write_vecs(&stdin.buffer);
+        for (stream_id, entries) in &stdin.streams {
+            runtime.write_stream(*stream_id, entries);
+        }
         for (proof, vkey) in stdin.proofs.iter() {
             runtime.write_proof(proof.clone(), vkey.clone());
         }
@@ -224,33 +253,102 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
 
     /// Generate shard proofs which split up and prove the valid execution of a RISC-V program with
     /// the core prover. Uses the provided context.
-    #[instrument(name = "prove_core", level = "info", skip_all)]
     pub fn prove_core<'a>(
         &'a self,
         pk: &SP1ProvingKey,
         stdin: &SP1Stdin,
         opts: SP1ProverOpts,
-        mut context: SP1Context<'a>,
+        context: SP1Context<'a>,
     ) -> Result<SP1CoreProof, SP1CoreProverError> {
+        match self.prove_core_resumable(pk, stdin, opts, context, None)? {
+            SP1CoreProofOutcome::Done(proof) => Ok(proof),
+            SP1CoreProofOutcome::Interrupted(_) => {
+                Err(SP1CoreProverError::ExecutionError(ExecutionError::Cancelled()))
+            }
+        }
+    }
+
+    /// Like [SP1Prover::prove_core], but returns the progress of the proof when the cancel flag of
+    /// the context is set, and resumes from the `progress` of an interrupted proof.
+    ///
+    /// With the flag of [shutdown::shutdown_flag], a preempted prover finishes the shards in
+    /// progress on SIGINT or SIGTERM and can save the progress with [CoreProofProgress::save].
+    #[instrument(name = "prove_core", level = "info", skip_all)]
+    pub fn prove_core_resumable<'a>(
+        &'a self,
+        pk: &SP1ProvingKey,
+        stdin: &SP1Stdin,
+        opts: SP1ProverOpts,
+        mut context: SP1Context<'a>,
+        progress: Option<CoreProofProgress<CoreSC>>,
+    ) -> Result<SP1CoreProofOutcome, SP1CoreProverError> {
+        let start = Instant::now();
         context.subproof_verifier.replace(Arc::new(self));
-        let program = Program::from(&pk.elf).unwrap();
-        let (proof, public_values_stream, cycles) =
-            sp1_core_machine::utils::prove_with_context::<_, C::CoreProver>(
-                &self.core_prover,
-                &pk.pk,
-                program,
-                stdin,
-                opts.core_opts,
-                context,
-            )?;
+        let shard_proof_tx = context.shard_proof_sender.take();
+        let program =
+            Program::from(&pk.elf).map_err(|err| SP1CoreProverError::ExecutionError(err.into()))?;
+        let outcome = sp1_core_machine::utils::prove_with_context_resumable::<_, C::CoreProver>(
+            &self.core_prover,
+            &pk.pk,
+            program,
+            stdin,
+            opts.core_opts,
+            context,
+            shard_proof_tx,
+            progress,
+        )?;
+        let (proof, public_values_stream, cycles) = match outcome {
+            CoreProofOutcome::Done(proof, public_values_stream, cycles) => {
+                (proof, public_values_stream, cycles)
+            }
+            CoreProofOutcome::Interrupted(progress) => {
+                tracing::info!(
+                    "core proof interrupted after {} shard proofs",
+                    progress.shard_proofs.len()
+                );
+                return Ok(SP1CoreProofOutcome::Interrupted(progress));
+            }
+        };
         Self::check_for_high_cycles(cycles);
+        metrics::record_core_proof(proof.shard_proofs.len(), cycles);
+        metrics::record_stage("core", start);
         let public_values = SP1PublicValues::from(&public_values_stream);
-        Ok(SP1CoreProof {
+        Ok(SP1CoreProofOutcome::Done(SP1CoreProof {
             proof: SP1CoreProofData(proof.shard_proofs),
             stdin: stdin.clone(),
             public_values,
             cycles,
-        })
+        }))
+    }
+
+    /// Generate the witness of each shard of the core proof, without proving it.
+    ///
+    /// `on_shard` is called with the witness of each shard in order. The witnesses can be
+    /// serialized in the layout documented in [`ShardWitness`], to prove them with another
+    /// polynomial commitment scheme or on other hardware.
+    pub fn generate_witness<'a>(
+        &'a self,
+        pk: &SP1ProvingKey,
+        stdin: &SP1Stdin,
+        opts: SP1ProverOpts,
+        mut context: SP1Context<'a>,
+        permutation_challenges: Option<&[Challenge<CoreSC>]>,
+        on_shard: impl FnMut(ShardWitness),
+    ) -> Result<SP1PublicValues, SP1CoreProverError> {
+        context.subproof_verifier.replace(Arc::new(self));
+        let program =
+            Program::from(&pk.elf).map_err(|err| SP1CoreProverError::ExecutionError(err.into()))?;
+        let public_values_stream = sp1_core_machine::utils::generate_witness(
+            &self.core_prover,
+            &pk.pk,
+            program,
+            stdin,
+            opts.core_opts,
+            context,
+            permutation_challenges,
+            on_shard,
+        )?;
+        Ok(SP1PublicValues::from(&public_values_stream))
     }
 
     pub fn get_recursion_core_inputs<'a>(
@@ -372,6 +470,8 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
         deferred_proofs: Vec<ShardProof<InnerSC>>,
         opts: SP1ProverOpts,
     ) -> Result<SP1ReduceProof<InnerSC>, SP1RecursionProverError> {
+        let start = Instant::now();
+
         // Set the batch size for the reduction tree.
         let batch_size = 2;
         let shard_proofs = &proof.proof.0;
@@ -393,66 +493,35 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
             batch_size,
         );
 
-        // Calculate the expected height of the tree.
-        let mut expected_height = 1;
-        let num_first_layer_inputs = first_layer_inputs.len();
-        let mut num_layer_inputs = num_first_layer_inputs;
-        while num_layer_inputs > batch_size {
-            num_layer_inputs = (num_layer_inputs + 1) / 2;
-            expected_height += 1;
-        }
+        // Schedule the nodes of the tree, which are proven as soon as their children are.
+        let tree = ReduceTree::new(first_layer_inputs.len(), batch_size);
+        let scheduler = ReduceScheduler::new(tree, first_layer_inputs);
 
         // Generate the proofs.
         let span = tracing::Span::current().clone();
-        let proof = thread::scope(|s| {
+        thread::scope(|s| {
             let _span = span.enter();
 
-            // Spawn a worker that sends the first layer inputs to a bounded channel.
-            let input_sync = Arc::new(TurnBasedSync::new());
-            let (input_tx, input_rx) = sync_channel::<(usize, usize, SP1CompressMemoryLayouts)>(
-                opts.recursion_opts.checkpoints_channel_capacity,
-            );
-            let input_tx = Arc::new(Mutex::new(input_tx));
-            {
-                let input_tx = Arc::clone(&input_tx);
-                let input_sync = Arc::clone(&input_sync);
-                s.spawn(move || {
-                    for (index, input) in first_layer_inputs.into_iter().enumerate() {
-                        input_sync.wait_for_turn(index);
-                        input_tx.lock().unwrap().send((index, 0, input)).unwrap();
-                        input_sync.advance_turn();
-                    }
-                });
-            }
-
-            // Spawn workers who generate the records and traces.
-            let record_and_trace_sync = Arc::new(TurnBasedSync::new());
+            // Spawn workers who generate the records and traces of the ready nodes.
             let (record_and_trace_tx, record_and_trace_rx) =
                 sync_channel::<(
-                    usize,
-                    usize,
+                    TreeNode,
                     ExecutionRecord<BabyBear>,
                     Vec<(String, RowMajorMatrix<BabyBear>)>,
                     ReduceProgramType,
                 )>(opts.recursion_opts.records_and_traces_channel_capacity);
-            let record_and_trace_tx = Arc::new(Mutex::new(record_and_trace_tx));
             let record_and_trace_rx = Arc::new(Mutex::new(record_and_trace_rx));
-            let input_rx = Arc::new(Mutex::new(input_rx));
             for _ in 0..opts.recursion_opts.trace_gen_workers {
-                let record_and_trace_sync = Arc::clone(&record_and_trace_sync);
-                let record_and_trace_tx = Arc::clone(&record_and_trace_tx);
-                let input_rx = Arc::clone(&input_rx);
+                let record_and_trace_tx = record_and_trace_tx.clone();
+                let scheduler = &scheduler;
                 let span = tracing::debug_span!("generate records and traces");
                 s.spawn(move || {
                     let _span = span.enter();
-                    loop {
-                        let received = { input_rx.lock().unwrap().recv() };
-                        if let Ok((index, height, input)) = received {
-                            // Get the program and witness stream.
-                            let (program, witness_stream, program_type) = tracing::debug_span!(
-                                "write witness stream"
-                            )
-                            .in_scope(|| match input {
+                    let _abort = scheduler.abort_on_panic();
+                    while let Some((node, input)) = scheduler.next() {
+                        // Get the program and witness stream.
+                        let (program, witness_stream, program_type) =
+                            tracing::debug_span!("write witness stream").in_scope(|| match input {
                                 SP1CompressMemoryLayouts::Core(input) => {
                                     let mut witness_stream = Vec::new();
                                     witness_stream.extend(input.write());
@@ -482,218 +551,116 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
                                 }
                             });
 
-                            // Execute the runtime.
-                            let record = tracing::debug_span!("execute runtime").in_scope(|| {
-                                let mut runtime =
-                                    RecursionRuntime::<Val<InnerSC>, Challenge<InnerSC>, _>::new(
-                                        program,
-                                        self.compress_prover.config().perm.clone(),
-                                    );
-                                runtime.witness_stream = witness_stream.into();
-                                runtime
-                                    .run()
-                                    .map_err(|e| {
-                                        SP1RecursionProverError::RuntimeError(e.to_string())
-                                    })
-                                    .unwrap();
-                                runtime.record
-                            });
-
-                            // Generate the dependencies.
-                            let mut records = vec![record];
-                            tracing::debug_span!("generate dependencies").in_scope(|| {
-                                self.compress_prover
-                                    .machine()
-                                    .generate_dependencies(&mut records, &opts.recursion_opts)
-                            });
-
-                            // Generate the traces.
-                            let record = records.into_iter().next().unwrap();
-                            let traces = tracing::debug_span!("generate traces")
-                                .in_scope(|| self.compress_prover.generate_traces(&record));
-
-                            // Wait for our turn to update the state.
-                            record_and_trace_sync.wait_for_turn(index);
-
-                            // Send the record and traces to the worker.
-                            record_and_trace_tx
-                                .lock()
-                                .unwrap()
-                                .send((index, height, record, traces, program_type))
+                        // Execute the runtime.
+                        let record = tracing::debug_span!("execute runtime").in_scope(|| {
+                            let mut runtime =
+                                RecursionRuntime::<Val<InnerSC>, Challenge<InnerSC>, _>::new(
+                                    program,
+                                    self.compress_prover.config().perm.clone(),
+                                );
+                            runtime.witness_stream = witness_stream.into();
+                            runtime
+                                .run()
+                                .map_err(|e| SP1RecursionProverError::RuntimeError(e.to_string()))
                                 .unwrap();
-
-                            // Advance the turn.
-                            record_and_trace_sync.advance_turn();
-                        } else {
-                            break;
+                            runtime.record
+                        });
+
+                        // Generate the dependencies.
+                        let mut records = vec![record];
+                        tracing::debug_span!("generate dependencies").in_scope(|| {
+                            self.compress_prover
+                                .machine()
+                                .generate_dependencies(&mut records, &opts.recursion_opts)
+                        });
+
+                        // Generate the traces.
+                        let record = records.into_iter().next().unwrap();
+                        let mut traces = tracing::debug_span!("generate traces")
+                            .in_scope(|| self.compress_prover.generate_traces(&record));
+                        for (_, trace) in traces.iter_mut() {
+                            trace.values =
+                                opts.recursion_opts.memory.place(std::mem::take(&mut trace.values));
                         }
+
+                        // Send the record and traces to the provers.
+                        record_and_trace_tx.send((node, record, traces, program_type)).unwrap();
                     }
                 });
             }
+            drop(record_and_trace_tx);
 
-            // Spawn workers who generate the compress proofs.
-            let proofs_sync = Arc::new(TurnBasedSync::new());
-            let (proofs_tx, proofs_rx) =
-                sync_channel::<(usize, usize, ShardProof<BabyBearPoseidon2>, ReduceProgramType)>(
-                    num_first_layer_inputs * 2,
-                );
-            let proofs_tx = Arc::new(Mutex::new(proofs_tx));
-            let proofs_rx = Arc::new(Mutex::new(proofs_rx));
-            let mut prover_handles = Vec::new();
+            // Spawn workers who generate the compress proofs, and schedule the parent of each
+            // node once all of its children are proven.
             for _ in 0..opts.recursion_opts.shard_batch_size {
-                let prover_sync = Arc::clone(&proofs_sync);
                 let record_and_trace_rx = Arc::clone(&record_and_trace_rx);
-                let proofs_tx = Arc::clone(&proofs_tx);
+                let scheduler = &scheduler;
                 let span = tracing::debug_span!("prove");
-                let handle = s.spawn(move || {
+                s.spawn(move || {
                     let _span = span.enter();
+                    let _abort = scheduler.abort_on_panic();
                     loop {
                         let received = { record_and_trace_rx.lock().unwrap().recv() };
-                        if let Ok((index, height, record, traces, program_type)) = received {
-                            tracing::debug_span!("batch").in_scope(|| {
-                                // Get the proving key.
-                                let pk = if program_type == ReduceProgramType::Core {
-                                    self.recursion_pk()
-                                } else if program_type == ReduceProgramType::Deferred {
-                                    self.deferred_pk()
-                                } else {
-                                    self.compress_pk()
-                                };
-
-                                // Observe the proving key.
-                                let mut challenger = self.compress_prover.config().challenger();
-                                tracing::debug_span!("observe proving key").in_scope(|| {
-                                    pk.observe_into(&mut challenger);
-                                });
-
-                                // Commit to the record and traces.
-                                let data = tracing::debug_span!("commit")
-                                    .in_scope(|| self.compress_prover.commit(record, traces));
-
-                                // Observe the commitment.
-                                tracing::debug_span!("observe commitment").in_scope(|| {
-                                    challenger.observe(data.main_commit);
-                                    challenger.observe_slice(
-                                        &data.public_values[0..self.compress_prover.num_pv_elts()],
-                                    );
-                                });
+                        let Ok((node, record, traces, program_type)) = received else {
+                            break;
+                        };
+                        tracing::debug_span!("batch").in_scope(|| {
+                            // Get the proving key.
+                            let pk = if program_type == ReduceProgramType::Core {
+                                self.recursion_pk()
+                            } else if program_type == ReduceProgramType::Deferred {
+                                self.deferred_pk()
+                            } else {
+                                self.compress_pk()
+                            };
 
-                                // Generate the proof.
-                                let proof = tracing::debug_span!("open").in_scope(|| {
-                                    self.compress_prover.open(pk, data, &mut challenger).unwrap()
-                                });
+                            // Observe the proving key.
+                            let mut challenger = self.compress_prover.config().challenger();
+                            tracing::debug_span!("observe proving key").in_scope(|| {
+                                pk.observe_into(&mut challenger);
+                            });
 
-                                // Wait for our turn to update the state.
-                                prover_sync.wait_for_turn(index);
+                            // Commit to the record and traces.
+                            let data = tracing::debug_span!("commit")
+                                .in_scope(|| self.compress_prover.commit(record, traces));
 
-                                // Send the proof.
-                                proofs_tx
-                                    .lock()
-                                    .unwrap()
-                                    .send((index, height, proof, program_type))
-                                    .unwrap();
+                            // Observe the commitment.
+                            tracing::debug_span!("observe commitment").in_scope(|| {
+                                challenger.observe(data.main_commit);
+                                challenger.observe_slice(
+                                    &data.public_values[0..self.compress_prover.num_pv_elts()],
+                                );
+                            });
 
-                                // Advance the turn.
-                                prover_sync.advance_turn();
+                            // Generate the proof.
+                            let proof = tracing::debug_span!("open").in_scope(|| {
+                                self.compress_prover.open(pk, data, &mut challenger).unwrap()
                             });
-                        } else {
-                            break;
-                        }
-                    }
-                });
-                prover_handles.push(handle);
-            }
 
-            // Spawn a worker that generates inputs for the next layer.
-            let handle = {
-                let input_tx = Arc::clone(&input_tx);
-                let proofs_rx = Arc::clone(&proofs_rx);
-                let span = tracing::debug_span!("generate next layer inputs");
-                s.spawn(move || {
-                    let _span = span.enter();
-                    let mut count = num_first_layer_inputs;
-                    let mut batch: Vec<(
-                        usize,
-                        usize,
-                        ShardProof<BabyBearPoseidon2>,
-                        ReduceProgramType,
-                    )> = Vec::new();
-                    loop {
-                        let received = { proofs_rx.lock().unwrap().recv() };
-                        if let Ok((index, height, proof, program_type)) = received {
-                            batch.push((index, height, proof, program_type));
-
-                            // Compute whether we've reached the root of the tree.
-                            let is_complete = height == expected_height;
-
-                            // If it's not complete, and we haven't reached the batch size,
-                            // continue.
-                            if !is_complete && batch.len() < batch_size {
-                                continue;
-                            }
-
-                            // Compute whether we're at the last input of a layer.
-                            let mut is_last = false;
-                            if let Some(first) = batch.first() {
-                                is_last = first.1 != height;
-                            }
-
-                            // If we're at the last input of a layer, we need to only include the
-                            // first input, otherwise we include all inputs.
-                            let inputs =
-                                if is_last { vec![batch[0].clone()] } else { batch.clone() };
-                            let shard_proofs =
-                                inputs.iter().map(|(_, _, proof, _)| proof.clone()).collect();
-                            let kinds = inputs
-                                .iter()
-                                .map(|(_, _, _, program_type)| *program_type)
-                                .collect();
+                            // If this was the last child of its parent, the parent is ready.
+                            let Some((parent, children)) =
+                                scheduler.finish(node, (proof, program_type))
+                            else {
+                                return;
+                            };
+                            let (shard_proofs, kinds) = children.into_iter().unzip();
                             let input =
                                 SP1CompressMemoryLayouts::Compress(SP1CompressMemoryLayout {
                                     compress_vk: self.compress_vk(),
                                     recursive_machine: self.compress_prover.machine(),
                                     shard_proofs,
                                     kinds,
-                                    is_complete,
+                                    is_complete: parent == scheduler.tree().root(),
                                 });
-
-                            input_sync.wait_for_turn(count);
-                            input_tx.lock().unwrap().send((count, inputs[0].1 + 1, input)).unwrap();
-                            input_sync.advance_turn();
-                            count += 1;
-
-                            // If we're at the root of the tree, stop generating inputs.
-                            if is_complete {
-                                break;
-                            }
-
-                            // If we were at the last input of a layer, we keep everything but the
-                            // first input. Otherwise, we empty the batch.
-                            if is_last {
-                                batch = vec![batch[1].clone()];
-                            } else {
-                                batch = Vec::new();
-                            }
-                        } else {
-                            break;
-                        }
+                            scheduler.schedule(parent, input);
+                        });
                     }
-                })
-            };
-
-            // Wait for all the provers to finish.
-            drop(input_tx);
-            drop(record_and_trace_tx);
-            drop(proofs_tx);
-            for handle in prover_handles {
-                handle.join().unwrap();
+                });
             }
-            handle.join().unwrap();
-
-            let output = proofs_rx.lock().unwrap().recv().unwrap();
-            output.2
         });
+        let (proof, _) = scheduler.into_root().expect("the root of the tree was not proven");
 
+        metrics::record_stage("compress", start);
         Ok(SP1ReduceProof { proof })
     }
 
@@ -741,6 +708,8 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
         reduced_proof: SP1ReduceProof<InnerSC>,
         opts: SP1ProverOpts,
     ) -> Result<SP1ReduceProof<InnerSC>, SP1RecursionProverError> {
+        let start = Instant::now();
+
         // Make the compress proof.
         let input = SP1RootMemoryLayout {
             machine: self.compress_prover.machine(),
@@ -776,6 +745,7 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
             )
             .unwrap();
 
+        metrics::record_stage("shrink", start);
         Ok(SP1ReduceProof { proof: compress_proof.shard_proofs.pop().unwrap() })
     }
 
@@ -786,6 +756,7 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
         compressed_proof: SP1ReduceProof<InnerSC>,
         opts: SP1ProverOpts,
     ) -> Result<SP1ReduceProof<OuterSC>, SP1RecursionProverError> {
+        let start = Instant::now();
         let input = SP1RootMemoryLayout {
             machine: self.shrink_prover.machine(),
             proof: compressed_proof.proof,
@@ -828,6 +799,7 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
             e => panic!("Proof verification failed: {:?}", e),
         }
         tracing::info!("Wrapping successful");
+        metrics::record_stage("wrap_bn254", start);
 
         Ok(SP1ReduceProof { proof: wrap_proof.shard_proofs.pop().unwrap() })
     }
@@ -839,6 +811,7 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
         proof: SP1ReduceProof<OuterSC>,
         build_dir: &Path,
     ) -> PlonkBn254Proof {
+        let start = Instant::now();
         let vkey_digest = proof.sp1_vkey_digest_bn254();
         let commited_values_digest = proof.sp1_commited_values_digest_bn254();
 
@@ -858,6 +831,7 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
             build_dir,
         );
 
+        metrics::record_stage("plonk_bn254", start);
         proof
     }
 
@@ -868,6 +842,7 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
         proof: SP1ReduceProof<OuterSC>,
         build_dir: &Path,
     ) -> Groth16Bn254Proof {
+        let start = Instant::now();
         let vkey_digest = proof.sp1_vkey_digest_bn254();
         let commited_values_digest = proof.sp1_commited_values_digest_bn254();
 
@@ -887,6 +862,7 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
             build_dir,
         );
 
+        metrics::record_stage("groth16_bn254", start);
         proof
     }
 
@@ -1138,6 +1114,50 @@ pub mod tests {
         test_e2e_prover::<DefaultProverComponents>(elf, opts, Test::Wrap)
     }
 
+    /// Tests that proving and verifying keys round trip through their key files.
+    #[test]
+    fn test_key_files() {
+        let elf = include_bytes!("../../../tests/fibonacci/elf/riscv32im-succinct-zkvm-elf");
+        let prover = SP1Prover::<DefaultProverComponents>::uninitialized();
+        let (pk, vk) = prover.setup(elf);
+        let dir = tempfile::tempdir().unwrap();
+
+        pk.save(dir.path().join("pk.bin")).unwrap();
+        let loaded = SP1ProvingKey::load(dir.path().join("pk.bin")).unwrap();
+        assert_eq!(loaded.elf, pk.elf);
+        assert_eq!(loaded.pk.commit, pk.pk.commit);
+        assert_eq!(loaded.pk.pc_start, pk.pk.pc_start);
+        assert_eq!(loaded.pk.chip_ordering, pk.pk.chip_ordering);
+        assert_eq!(loaded.pk.traces, pk.pk.traces);
+        assert_eq!(
+            bincode::serialize(&loaded.pk.data).unwrap(),
+            bincode::serialize(&pk.pk.data).unwrap()
+        );
+        assert_eq!(loaded.vk.hash_bn254(), vk.hash_bn254());
+
+        vk.save(dir.path().join("vk.bin")).unwrap();
+        let loaded = SP1VerifyingKey::load(dir.path().join("vk.bin")).unwrap();
+        assert_eq!(loaded.hash_bn254(), vk.hash_bn254());
+        assert!(SP1VerifyingKey::load(dir.path().join("pk.bin")).is_err());
+    }
+
+    /// Tests that verifying keys round trip through their versioned encoding, and that keys encoded
+    /// without a version are rejected.
+    #[test]
+    fn test_verifying_key_encoding() {
+        let elf = include_bytes!("../../../tests/fibonacci/elf/riscv32im-succinct-zkvm-elf");
+        let vk = SP1Prover::<DefaultProverComponents>::uninitialized().setup_vk(elf);
+        assert_eq!(vk.public_values_hash, PublicValuesHash::Sha256);
+
+        let bytes = bincode::serialize(&vk).unwrap();
+        let decoded: SP1VerifyingKey = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(decoded.hash_bn254(), vk.hash_bn254());
+        assert_eq!(decoded.public_values_hash, vk.public_values_hash);
+
+        let legacy = bincode::serialize(&vk.vk).unwrap();
+        assert!(bincode::deserialize::<SP1VerifyingKey>(&legacy).is_err());
+    }
+
     /// Tests an end-to-end workflow of proving a program across the entire proof generation
     /// pipeline in addition to verifying deferred proofs.
     #[test]
@@ -0,0 +1,245 @@
+//! Prometheus metrics of the proving pipeline.
+//!
+//! With the `metrics` feature, the prover records the shards proven, the cycles executed, the
+//! duration of each proving stage, the hits of the recursion key cache and the peak resident
+//! memory of the process in the registry returned by [registry]. The metrics can be exposed to
+//! Prometheus with [serve], or gathered into an existing registry of the application.
+//!
+//! Without the feature, recording the metrics does nothing.
+
+use std::time::Instant;
+
+#[cfg(feature = "metrics")]
+pub use enabled::{encode, registry, serve};
+
+/// Records a core proof of `shards` shards and `cycles` cycles.
+pub(crate) fn record_core_proof(shards: usize, cycles: u64) {
+    #[cfg(feature = "metrics")]
+    {
+        let metrics = enabled::metrics();
+        metrics.shards_proven.inc_by(shards as u64);
+        metrics.cycles_executed.inc_by(cycles);
+    }
+    #[cfg(not(feature = "metrics"))]
+    let _ = (shards, cycles);
+}
+
+/// Records the duration of a proving stage that started at `start`.
+pub(crate) fn record_stage(stage: &str, start: Instant) {
+    #[cfg(feature = "metrics")]
+    {
+        let metrics = enabled::metrics();
+        metrics
+            .stage_duration_seconds
+            .with_label_values(&[stage])
+            .observe(start.elapsed().as_secs_f64());
+        if let Some(hwm) = enabled::rss_high_water_mark() {
+            metrics.rss_high_water_mark_bytes.set(hwm as i64);
+        }
+    }
+    #[cfg(not(feature = "metrics"))]
+    let _ = (stage, start);
+}
+
+/// Records an access to the recursion keys of `step`, which are generated on a miss.
+pub(crate) fn record_key_cache(step: &str, hit: bool) {
+    #[cfg(feature = "metrics")]
+    {
+        let result = if hit { "hit" } else { "miss" };
+        enabled::metrics().key_cache_accesses.with_label_values(&[step, result]).inc();
+    }
+    #[cfg(not(feature = "metrics"))]
+    let _ = (step, hit);
+}
+
+#[cfg(feature = "metrics")]
+mod enabled {
+    use std::{
+        io::{self, Read, Write},
+        net::{TcpListener, TcpStream, ToSocketAddrs},
+        sync::OnceLock,
+        thread::JoinHandle,
+        time::{Duration, Instant},
+    };
+
+    use prometheus::{
+        Encoder, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge, Opts, Registry,
+        TextEncoder,
+    };
+
+    /// The longest a read or a write of a scrape may block.
+    const READ_WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+
+    /// The longest a client may take to send the request line and headers of a scrape.
+    const HEADER_TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// The maximum size of the request line and headers of a scrape.
+    const MAX_HEADER_BYTES: usize = 8192;
+
+    pub(super) struct Metrics {
+        registry: Registry,
+        pub(super) shards_proven: IntCounter,
+        pub(super) cycles_executed: IntCounter,
+        pub(super) stage_duration_seconds: HistogramVec,
+        pub(super) key_cache_accesses: IntCounterVec,
+        pub(super) rss_high_water_mark_bytes: IntGauge,
+    }
+
+    pub(super) fn metrics() -> &'static Metrics {
+        static METRICS: OnceLock<Metrics> = OnceLock::new();
+        METRICS.get_or_init(|| {
+            let registry = Registry::new_custom(Some("sp1_prover".into()), None).unwrap();
+            let shards_proven =
+                IntCounter::new("shards_proven_total", "Core shards proven.").unwrap();
+            let cycles_executed =
+                IntCounter::new("cycles_executed_total", "Cycles executed in core proofs.")
+                    .unwrap();
+            // Stages last from seconds to hours.
+            let buckets = prometheus::exponential_buckets(1.0, 2.0, 16).unwrap();
+            let stage_duration_seconds = HistogramVec::new(
+                HistogramOpts::new("stage_duration_seconds", "Duration of the proving stages.")
+                    .buckets(buckets),
+                &["stage"],
+            )
+            .unwrap();
+            let key_cache_accesses = IntCounterVec::new(
+                Opts::new("key_cache_accesses_total", "Accesses to the recursion keys."),
+                &["step", "result"],
+            )
+            .unwrap();
+            let rss_high_water_mark_bytes = IntGauge::new(
+                "rss_high_water_mark_bytes",
+                "Peak resident memory of the process at the end of the last stage.",
+            )
+            .unwrap();
+
+            registry.register(Box::new(shards_proven.clone())).unwrap();
+            registry.register(Box::new(cycles_executed.clone())).unwrap();
+            registry.register(Box::new(stage_duration_seconds.clone())).unwrap();
+            registry.register(Box::new(key_cache_accesses.clone())).unwrap();
+            registry.register(Box::new(rss_high_water_mark_bytes.clone())).unwrap();
+
+            Metrics {
+                registry,
+                shards_proven,
+                cycles_executed,
+                stage_duration_seconds,
+                key_cache_accesses,
+                rss_high_water_mark_bytes,
+            }
+        })
+    }
+
+    /// The registry of the prover metrics, whose names are prefixed with `sp1_prover_`.
+    pub fn registry() -> &'static Registry {
+        &metrics().registry
+    }
+
+    /// Encodes the prover metrics in the Prometheus text format.
+    pub fn encode() -> String {
+        let mut buffer = Vec::new();
+        TextEncoder::new().encode(&registry().gather(), &mut buffer).unwrap();
+        String::from_utf8(buffer).unwrap()
+    }
+
+    /// Serves the prover metrics over HTTP at `addr` on a background thread.
+    ///
+    /// Every request is answered with the metrics, whatever its path, so that the endpoint can
+    /// be scraped as `http://<addr>/metrics`. Requests are answered one at a time, so a client
+    /// which is slow to send its headers is disconnected after a few seconds.
+    pub fn serve(addr: impl ToSocketAddrs) -> std::io::Result<JoinHandle<()>> {
+        let listener = TcpListener::bind(addr)?;
+        std::thread::Builder::new().name("sp1-prover-metrics".into()).spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { continue };
+                if stream.set_write_timeout(Some(READ_WRITE_TIMEOUT)).is_err()
+                    || read_request_head(&stream, HEADER_TIMEOUT).is_err()
+                {
+                    continue;
+                }
+                let body = encode();
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\n\
+                     Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+            }
+        })
+    }
+
+    /// Skips the request line and headers of a request, failing if they are not received within
+    /// `timeout` or are larger than [MAX_HEADER_BYTES].
+    fn read_request_head(mut stream: &TcpStream, timeout: Duration) -> io::Result<()> {
+        let deadline = Instant::now() + timeout;
+        let mut head = Vec::new();
+        let mut buf = [0u8; 1024];
+        while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+            if head.len() > MAX_HEADER_BYTES {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, "headers too large"));
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(io::ErrorKind::TimedOut.into());
+            }
+            stream.set_read_timeout(Some(remaining.min(READ_WRITE_TIMEOUT)))?;
+            let n = stream.read(&mut buf)?;
+            if n == 0 {
+                return Err(io::ErrorKind::UnexpectedEof.into());
+            }
+            head.extend_from_slice(&buf[..n]);
+        }
+        Ok(())
+    }
+
+    /// The peak resident memory of the process in bytes, read from `VmHWM` on Linux.
+    pub(super) fn rss_high_water_mark() -> Option<u64> {
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+        let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+        let kb = line.trim_start_matches("VmHWM:").trim().trim_end_matches("kB").trim();
+        kb.parse::<u64>().ok().map(|kb| kb * 1024)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use std::time::Instant;
+
+        use super::*;
+
+        #[test]
+        fn test_encode() {
+            crate::metrics::record_core_proof(3, 1000);
+            crate::metrics::record_stage("core", Instant::now());
+            crate::metrics::record_key_cache("compress", false);
+            let text = encode();
+            assert!(text.contains("sp1_prover_shards_proven_total"));
+            assert!(text.contains("sp1_prover_stage_duration_seconds_bucket{stage=\"core\""));
+            assert!(text.contains("step=\"compress\""));
+        }
+
+        #[test]
+        fn test_read_request_head_timeout() {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let mut client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+            let (stream, _) = listener.accept().unwrap();
+
+            // A client that never finishes its headers is cut off.
+            client.write_all(b"GET /metrics HTTP/1.1\r\nHost: ").unwrap();
+            let start = Instant::now();
+            assert!(read_request_head(&stream, Duration::from_millis(200)).is_err());
+            assert!(start.elapsed() < Duration::from_secs(2));
+
+            let mut client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+            let (stream, _) = listener.accept().unwrap();
+            client.write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n").unwrap();
+            read_request_head(&stream, Duration::from_secs(1)).unwrap();
+        }
+
+        #[cfg(target_os = "linux")]
+        #[test]
+        fn test_rss_high_water_mark() {
+            assert!(rss_high_water_mark().unwrap() > 0);
+        }
+    }
+}
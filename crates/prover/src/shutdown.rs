@@ -0,0 +1,49 @@
+//! Graceful shutdown of the prover on SIGINT and SIGTERM.
+//!
+//! Spot instances and preemptible machines get a termination signal shortly before they are
+//! reclaimed. Passing [shutdown_flag] as the cancel flag of the context of
+//! [SP1Prover::prove_core_resumable](crate::SP1Prover::prove_core_resumable) makes the prover
+//! finish the shards in progress and return the progress of the proof, which can be saved and
+//! resumed on another machine.
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+};
+
+/// The exit code of a process interrupted by a second signal, as for SIGINT.
+const FORCED_EXIT_CODE: i32 = 130;
+
+/// Returns a flag that is set when the process receives SIGINT or SIGTERM.
+///
+/// The signal handler is installed on the first call, and a second signal exits the process
+/// immediately. Fails if the application has already installed its own handler.
+///
+/// ### Examples
+/// ```ignore
+/// let flag = sp1_prover::shutdown::shutdown_flag()?;
+/// let context = SP1Context::builder().cancel_flag(flag).build();
+/// match prover.prove_core_resumable(&pk, &stdin, opts, context, None)? {
+///     SP1CoreProofOutcome::Done(proof) => { /* ... */ }
+///     SP1CoreProofOutcome::Interrupted(progress) => progress.save("progress.bin")?,
+/// }
+/// ```
+pub fn shutdown_flag() -> Result<Arc<AtomicBool>, ctrlc::Error> {
+    static FLAG: Mutex<Option<Arc<AtomicBool>>> = Mutex::new(None);
+
+    let mut installed = FLAG.lock().unwrap();
+    if let Some(flag) = installed.as_ref() {
+        return Ok(Arc::clone(flag));
+    }
+
+    let flag = Arc::new(AtomicBool::new(false));
+    let handler_flag = Arc::clone(&flag);
+    ctrlc::set_handler(move || {
+        if handler_flag.swap(true, Ordering::Relaxed) {
+            std::process::exit(FORCED_EXIT_CODE);
+        }
+        tracing::warn!("received a shutdown signal, finishing the shards in progress");
+    })?;
+    *installed = Some(Arc::clone(&flag));
+    Ok(flag)
+}
@@ -0,0 +1,237 @@
+//! Throughput mode, which proves many programs at once to maximize the proofs per hour rather than
+//! the latency of each proof.
+//!
+//! The core stage of a proof (execution, trace generation and commitment) is bound by memory
+//! bandwidth, while its recursion stage is bound by the cores. Proving a single program at a time
+//! leaves one of these resources idle for most of the proof. [SP1Prover::prove_many] instead runs
+//! the core stage of some jobs while others are in recursion, with a fixed number of slots per
+//! stage, so that both resources stay saturated. A job enters the recursion stage as soon as its
+//! core proof is done and a recursion slot is free.
+
+use std::{
+    collections::VecDeque,
+    sync::{mpsc::sync_channel, Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use sp1_core_executor::SP1Context;
+use sp1_core_machine::{io::SP1Stdin, utils::SP1CoreProverError};
+use sp1_stark::SP1ProverOpts;
+use thiserror::Error;
+
+use crate::{
+    components::SP1ProverComponents, InnerSC, SP1CoreProof, SP1Prover, SP1ProvingKey,
+    SP1RecursionProverError, SP1ReduceProof,
+};
+
+/// The options of [SP1Prover::prove_many].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThroughputOpts {
+    /// The options of each proof. The trace generation workers of the core stage are split
+    /// between the core slots.
+    pub prover_opts: SP1ProverOpts,
+    /// The number of jobs in the core stage at once.
+    pub core_slots: usize,
+    /// The number of jobs in the recursion stage at once.
+    pub recursion_slots: usize,
+}
+
+impl Default for ThroughputOpts {
+    fn default() -> Self {
+        Self { prover_opts: SP1ProverOpts::default(), core_slots: 2, recursion_slots: 1 }
+    }
+}
+
+/// The last stage a job of [SP1Prover::prove_many] is proven to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThroughputTarget {
+    /// A core proof.
+    Core,
+    /// A compressed proof.
+    Compressed,
+}
+
+/// A program to prove with [SP1Prover::prove_many].
+pub struct ThroughputJob<'a> {
+    /// The proving key of the program.
+    pub pk: &'a SP1ProvingKey,
+    /// The stdin of the program.
+    pub stdin: SP1Stdin,
+    /// The stage to prove the program to.
+    pub target: ThroughputTarget,
+}
+
+/// A proof produced by [SP1Prover::prove_many].
+#[allow(clippy::large_enum_variant)]
+pub enum ThroughputProof {
+    Core(SP1CoreProof),
+    Compressed(SP1ReduceProof<InnerSC>),
+}
+
+/// The error of a job of [SP1Prover::prove_many].
+#[derive(Error, Debug)]
+pub enum ThroughputError {
+    #[error("core proof failed: {0}")]
+    Core(#[from] SP1CoreProverError),
+    #[error("recursion failed: {0}")]
+    Recursion(#[from] SP1RecursionProverError),
+}
+
+/// The outcome of [SP1Prover::prove_many].
+pub struct ThroughputReport {
+    /// The result of each job, in the order of the jobs.
+    pub results: Vec<Result<ThroughputProof, ThroughputError>>,
+    /// The time taken to prove all the jobs.
+    pub elapsed: Duration,
+}
+
+impl ThroughputReport {
+    /// Returns the number of successful proofs per hour.
+    pub fn proofs_per_hour(&self) -> f64 {
+        let proofs = self.results.iter().filter(|result| result.is_ok()).count();
+        proofs as f64 * 3600.0 / self.elapsed.as_secs_f64().max(f64::EPSILON)
+    }
+}
+
+impl<C: SP1ProverComponents> SP1Prover<C> {
+    /// Proves many programs at once, interleaving the core and recursion stages of different jobs
+    /// to maximize the throughput, see [crate::throughput].
+    ///
+    /// A failed job does not stop the others. The proofs take longer each than when proven alone.
+    pub fn prove_many(
+        &self,
+        jobs: Vec<ThroughputJob<'_>>,
+        opts: ThroughputOpts,
+    ) -> ThroughputReport {
+        let start = Instant::now();
+        let mut job_opts = opts.prover_opts;
+        job_opts.core_opts.trace_gen_workers =
+            (job_opts.core_opts.trace_gen_workers / opts.core_slots.max(1)).max(1);
+
+        let results = run_pipeline(
+            jobs,
+            opts.core_slots,
+            opts.recursion_slots,
+            |job| -> Result<_, ThroughputError> {
+                let proof = self.prove_core(job.pk, &job.stdin, job_opts, SP1Context::default())?;
+                Ok(match job.target {
+                    ThroughputTarget::Core => Stage::Done(ThroughputProof::Core(proof)),
+                    ThroughputTarget::Compressed => Stage::Next((job.pk, proof)),
+                })
+            },
+            |(pk, proof): (&SP1ProvingKey, SP1CoreProof)| -> Result<_, ThroughputError> {
+                let deferred_proofs = proof.stdin.proofs.iter().map(|p| p.0.clone()).collect();
+                let proof = self.compress(&pk.vk, proof, deferred_proofs, job_opts)?;
+                Ok(ThroughputProof::Compressed(proof))
+            },
+        );
+        ThroughputReport { results, elapsed: start.elapsed() }
+    }
+}
+
+/// The output of the first stage of a job in [run_pipeline].
+enum Stage<M, O> {
+    /// The job is done after the first stage.
+    Done(O),
+    /// The job continues to the second stage with this input.
+    Next(M),
+}
+
+/// Runs the jobs through two stages, with `first_slots` jobs in the first stage and
+/// `second_slots` jobs in the second stage at once. Returns the outputs in the order of the jobs.
+///
+/// The jobs done with the first stage wait for a free slot of the second stage in a queue of
+/// `second_slots` jobs, so that the first stage does not run ahead by more than that.
+fn run_pipeline<J, M, O, E>(
+    jobs: Vec<J>,
+    first_slots: usize,
+    second_slots: usize,
+    first: impl Fn(J) -> Result<Stage<M, O>, E> + Sync,
+    second: impl Fn(M) -> Result<O, E> + Sync,
+) -> Vec<Result<O, E>>
+where
+    J: Send,
+    M: Send,
+    O: Send,
+    E: Send,
+{
+    let num_jobs = jobs.len();
+    let queue = Mutex::new(jobs.into_iter().enumerate().collect::<VecDeque<_>>());
+    let results = Mutex::new((0..num_jobs).map(|_| None).collect::<Vec<_>>());
+    let (next_tx, next_rx) = sync_channel::<(usize, M)>(second_slots.max(1));
+    let next_rx = Arc::new(Mutex::new(next_rx));
+
+    thread::scope(|s| {
+        for _ in 0..first_slots.max(1) {
+            let next_tx = next_tx.clone();
+            let (queue, results, first) = (&queue, &results, &first);
+            s.spawn(move || loop {
+                let Some((index, job)) = queue.lock().unwrap().pop_front() else {
+                    break;
+                };
+                match first(job) {
+                    Ok(Stage::Next(input)) => next_tx.send((index, input)).unwrap(),
+                    Ok(Stage::Done(output)) => results.lock().unwrap()[index] = Some(Ok(output)),
+                    Err(err) => results.lock().unwrap()[index] = Some(Err(err)),
+                }
+            });
+        }
+        drop(next_tx);
+
+        for _ in 0..second_slots.max(1) {
+            let next_rx = Arc::clone(&next_rx);
+            let (results, second) = (&results, &second);
+            s.spawn(move || loop {
+                let received = next_rx.lock().unwrap().recv();
+                let Ok((index, input)) = received else {
+                    break;
+                };
+                results.lock().unwrap()[index] = Some(second(input));
+            });
+        }
+    });
+
+    results.into_inner().unwrap().into_iter().map(|result| result.unwrap()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    #[test]
+    fn test_run_pipeline() {
+        // Even jobs go through both stages, odd jobs stop after the first and job 3 fails.
+        let in_second = AtomicUsize::new(0);
+        let max_in_second = AtomicUsize::new(0);
+        let results = run_pipeline(
+            (0..10).collect(),
+            3,
+            2,
+            |job: usize| match job {
+                3 => Err("failed"),
+                job if job % 2 == 0 => Ok(Stage::Next(job * 10)),
+                job => Ok(Stage::Done(job)),
+            },
+            |input: usize| {
+                let running = in_second.fetch_add(1, Ordering::SeqCst) + 1;
+                max_in_second.fetch_max(running, Ordering::SeqCst);
+                thread::sleep(Duration::from_millis(5));
+                in_second.fetch_sub(1, Ordering::SeqCst);
+                Ok(input + 1)
+            },
+        );
+
+        let expected = (0..10)
+            .map(|job| match job {
+                3 => Err("failed"),
+                job if job % 2 == 0 => Ok(job * 10 + 1),
+                job => Ok(job),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(results, expected);
+        assert!(max_in_second.load(Ordering::SeqCst) <= 2);
+    }
+}
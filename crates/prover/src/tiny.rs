@@ -0,0 +1,160 @@
+//! Tiny proofs: a single core shard proof of a short execution, without any recursion.
+//!
+//! A tiny proof is larger than a compressed proof, but it skips the recursion entirely, which makes
+//! it the lowest-latency proof of a program that fits in [TINY_MAX_CYCLES] cycles. It is verified
+//! with a [SP1TinyVerifier], which only needs the core machine.
+
+use std::borrow::Borrow;
+
+use itertools::Itertools;
+use p3_baby_bear::BabyBear;
+use p3_field::{AbstractField, PrimeField32};
+use sp1_core_executor::SP1Context;
+use sp1_core_machine::{io::SP1PublicValues, riscv::RiscvAir, utils::SP1CoreProverError};
+use sp1_stark::{
+    air::{PublicValues, POSEIDON_NUM_WORDS},
+    MachineProof, MachineVerificationError, SP1ProverOpts, ShardProof, StarkGenericConfig,
+    StarkMachine, Word,
+};
+use thiserror::Error;
+
+use crate::{
+    components::SP1ProverComponents, verify::verify_core_public_values, CoreSC, SP1CoreProof,
+    SP1Prover, SP1ProvingKey, SP1Stdin, SP1VerifyingKey,
+};
+
+/// The maximum number of cycles of the execution of a tiny proof, which is also its shard size.
+pub const TINY_MAX_CYCLES: u64 = 1 << 16;
+
+#[derive(Error, Debug)]
+pub enum SP1TinyProverError {
+    #[error(transparent)]
+    Core(#[from] SP1CoreProverError),
+    #[error("tiny proofs can not verify deferred proofs, but {0} were given")]
+    DeferredProofs(usize),
+    #[error("the execution was split into {0} shards, but a tiny proof must fit in one")]
+    TooManyShards(usize),
+}
+
+impl<C: SP1ProverComponents> SP1Prover<C> {
+    /// Generate a tiny proof: a single core shard proof of an execution of at most
+    /// [TINY_MAX_CYCLES] cycles.
+    ///
+    /// The shard size of `opts` is overridden, and the execution fails once it exceeds the cycle
+    /// limit, or the limit of the context if it is lower. The proof can be verified with
+    /// [SP1TinyVerifier::verify] without initializing a [SP1Prover].
+    pub fn prove_tiny<'a>(
+        &'a self,
+        pk: &SP1ProvingKey,
+        stdin: &SP1Stdin,
+        mut opts: SP1ProverOpts,
+        mut context: SP1Context<'a>,
+    ) -> Result<SP1CoreProof, SP1TinyProverError> {
+        if !stdin.proofs.is_empty() {
+            return Err(SP1TinyProverError::DeferredProofs(stdin.proofs.len()));
+        }
+        opts.core_opts.shard_size = TINY_MAX_CYCLES as usize;
+        context.max_cycles =
+            Some(context.max_cycles.map_or(TINY_MAX_CYCLES, |max| max.min(TINY_MAX_CYCLES)));
+
+        let proof = self.prove_core(pk, stdin, opts, context)?;
+        if proof.proof.0.len() != 1 {
+            return Err(SP1TinyProverError::TooManyShards(proof.proof.0.len()));
+        }
+        Ok(proof)
+    }
+}
+
+/// A verifier of tiny proofs, which only holds the core machine.
+pub struct SP1TinyVerifier {
+    machine: StarkMachine<CoreSC, RiscvAir<BabyBear>>,
+}
+
+impl SP1TinyVerifier {
+    /// Creates a new [SP1TinyVerifier].
+    pub fn new() -> Self {
+        Self { machine: RiscvAir::machine(CoreSC::default()) }
+    }
+
+    /// Verify a tiny proof of a program with the verifying key `vk`, committing to
+    /// `public_values`.
+    pub fn verify(
+        &self,
+        proof: &ShardProof<CoreSC>,
+        public_values: &SP1PublicValues,
+        vk: &SP1VerifyingKey,
+    ) -> Result<(), MachineVerificationError<CoreSC>> {
+        let shard_public_values: &PublicValues<Word<_>, _> =
+            proof.public_values.as_slice().borrow();
+
+        // A tiny proof has no recursion to verify deferred proofs in.
+        if shard_public_values.deferred_proofs_digest != [BabyBear::zero(); POSEIDON_NUM_WORDS] {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "deferred_proofs_digest != 0: tiny proofs can not verify deferred proofs",
+            ));
+        }
+
+        // Make sure the committed value digest matches the public values hash.
+        let committed_value_digest_bytes = shard_public_values
+            .committed_value_digest
+            .iter()
+            .flat_map(|w| w.0.iter().map(|x| x.as_canonical_u32() as u8))
+            .collect_vec();
+        if committed_value_digest_bytes != public_values.hash_with(vk.public_values_hash) {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "committed_value_digest does not match the public values",
+            ));
+        }
+
+        verify_core_public_values(std::slice::from_ref(proof), vk)?;
+
+        let mut challenger = self.machine.config().challenger();
+        let machine_proof = MachineProof { shard_proofs: vec![proof.clone()] };
+        self.machine.verify(&vk.vk, &machine_proof, &mut challenger)
+    }
+}
+
+impl Default for SP1TinyVerifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sp1_core_machine::utils::setup_logger;
+
+    use super::*;
+    use crate::components::DefaultProverComponents;
+
+    const FIBONACCI_ELF: &[u8] =
+        include_bytes!("../../../tests/fibonacci/elf/riscv32im-succinct-zkvm-elf");
+
+    #[test]
+    fn test_tiny_prove_and_verify() {
+        setup_logger();
+        let prover = SP1Prover::<DefaultProverComponents>::uninitialized();
+        let (pk, vk) = prover.setup(FIBONACCI_ELF);
+        let proof = prover
+            .prove_tiny(&pk, &SP1Stdin::new(), SP1ProverOpts::default(), SP1Context::default())
+            .unwrap();
+        assert_eq!(proof.proof.0.len(), 1);
+
+        let verifier = SP1TinyVerifier::new();
+        verifier.verify(&proof.proof.0[0], &proof.public_values, &vk).unwrap();
+
+        let mut tampered = proof.public_values.clone();
+        tampered.write(&0u32);
+        assert!(verifier.verify(&proof.proof.0[0], &tampered, &vk).is_err());
+    }
+
+    #[test]
+    fn test_tiny_exceeds_cycle_limit() {
+        setup_logger();
+        let prover = SP1Prover::<DefaultProverComponents>::uninitialized();
+        let (pk, _) = prover.setup(FIBONACCI_ELF);
+        let context = SP1Context::builder().max_cycles(10).build();
+        let result = prover.prove_tiny(&pk, &SP1Stdin::new(), SP1ProverOpts::default(), context);
+        assert!(matches!(result, Err(SP1TinyProverError::Core(_))));
+    }
+}
@@ -0,0 +1,232 @@
+//! Scheduling of the recursion tree that compresses the proofs of a program.
+//!
+//! The leaves of the tree are the first layer of recursion proofs. Each node of the next layers
+//! compresses up to `arity` consecutive nodes of the layer below, until a layer has at most `arity`
+//! nodes, which the root compresses and verifies as complete. A node is proven as soon as its
+//! children are, regardless of the progress of the rest of its layer, so that the workers stay busy
+//! up to the root.
+
+use std::{
+    cmp::Reverse,
+    collections::BTreeMap,
+    ops::Range,
+    sync::{Condvar, Mutex},
+    thread,
+};
+
+/// A node of the recursion tree, given by its layer and its index in the layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TreeNode {
+    /// The layer of the node, starting from 0 for the leaves.
+    pub layer: usize,
+    /// The index of the node in its layer.
+    pub index: usize,
+}
+
+/// The shape of a recursion tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReduceTree {
+    arity: usize,
+    layer_sizes: Vec<usize>,
+}
+
+impl ReduceTree {
+    /// Creates the tree compressing `num_leaves` leaves, `arity` nodes at a time.
+    pub fn new(num_leaves: usize, arity: usize) -> Self {
+        assert!(num_leaves > 0, "the tree must have at least one leaf");
+        assert!(arity > 1, "the arity of the tree must be at least 2");
+        let mut layer_sizes = vec![num_leaves];
+        while *layer_sizes.last().unwrap() > arity {
+            layer_sizes.push(layer_sizes.last().unwrap().div_ceil(arity));
+        }
+        // The root verifies the nodes of the last layer as complete.
+        layer_sizes.push(1);
+        Self { arity, layer_sizes }
+    }
+
+    /// Returns the root of the tree.
+    pub fn root(&self) -> TreeNode {
+        TreeNode { layer: self.layer_sizes.len() - 1, index: 0 }
+    }
+
+    /// Returns the number of nodes in the tree.
+    pub fn num_nodes(&self) -> usize {
+        self.layer_sizes.iter().sum()
+    }
+
+    /// Returns the parent of a node, or `None` for the root.
+    pub fn parent(&self, node: TreeNode) -> Option<TreeNode> {
+        (node != self.root())
+            .then(|| TreeNode { layer: node.layer + 1, index: node.index / self.arity })
+    }
+
+    /// Returns the indices of the children of a node in the layer below it.
+    pub fn children(&self, node: TreeNode) -> Range<usize> {
+        assert!(node.layer > 0, "leaves have no children");
+        let start = node.index * self.arity;
+        let end = (start + self.arity).min(self.layer_sizes[node.layer - 1]);
+        start..end
+    }
+}
+
+/// A scheduler of the nodes of a [ReduceTree], handing out each node once its children are done.
+///
+/// Workers take the ready nodes with [Self::next] and report their outputs with [Self::finish].
+/// Nodes of higher layers are handed out first, so that the root is reached as soon as possible.
+pub struct ReduceScheduler<I, T> {
+    tree: ReduceTree,
+    state: Mutex<SchedulerState<I, T>>,
+    ready: Condvar,
+}
+
+struct SchedulerState<I, T> {
+    /// The inputs of the nodes that are ready, ordered by decreasing layer and increasing index.
+    ready: BTreeMap<(Reverse<usize>, usize), I>,
+    /// The outputs of the nodes whose parents are not ready yet.
+    done: BTreeMap<TreeNode, T>,
+    /// The output of the root, once it is done.
+    root: Option<T>,
+    /// Whether a worker panicked, in which case the others stop.
+    aborted: bool,
+}
+
+impl<I, T> ReduceScheduler<I, T> {
+    /// Creates a scheduler for `tree`, whose leaves are ready with the given inputs.
+    pub fn new(tree: ReduceTree, leaves: Vec<I>) -> Self {
+        assert_eq!(leaves.len(), tree.layer_sizes[0], "there must be one input per leaf");
+        let ready = leaves.into_iter().enumerate().map(|(i, input)| ((Reverse(0), i), input));
+        let state = SchedulerState {
+            ready: ready.collect(),
+            done: BTreeMap::new(),
+            root: None,
+            aborted: false,
+        };
+        Self { tree, state: Mutex::new(state), ready: Condvar::new() }
+    }
+
+    /// Returns the tree being scheduled.
+    pub fn tree(&self) -> &ReduceTree {
+        &self.tree
+    }
+
+    /// Waits for a node to be ready and takes it, or returns `None` once the root is done.
+    pub fn next(&self) -> Option<(TreeNode, I)> {
+        let mut state = self.state.lock().unwrap();
+        loop {
+            if state.root.is_some() || state.aborted {
+                return None;
+            }
+            if let Some(((Reverse(layer), index), input)) = state.ready.pop_first() {
+                return Some((TreeNode { layer, index }, input));
+            }
+            state = self.ready.wait(state).unwrap();
+        }
+    }
+
+    /// Records the output of a node.
+    ///
+    /// Returns the parent of the node with the outputs of its children, in order, once they are
+    /// all done. The caller then builds the input of the parent and passes it to [Self::schedule].
+    pub fn finish(&self, node: TreeNode, output: T) -> Option<(TreeNode, Vec<T>)> {
+        let mut state = self.state.lock().unwrap();
+        let Some(parent) = self.tree.parent(node) else {
+            state.root = Some(output);
+            self.ready.notify_all();
+            return None;
+        };
+        state.done.insert(node, output);
+        let children = self.tree.children(parent);
+        let layer = node.layer;
+        if !children.clone().all(|index| state.done.contains_key(&TreeNode { layer, index })) {
+            return None;
+        }
+        let outputs =
+            children.map(|index| state.done.remove(&TreeNode { layer, index }).unwrap()).collect();
+        Some((parent, outputs))
+    }
+
+    /// Makes a node ready with the given input.
+    pub fn schedule(&self, node: TreeNode, input: I) {
+        let mut state = self.state.lock().unwrap();
+        state.ready.insert((Reverse(node.layer), node.index), input);
+        self.ready.notify_one();
+    }
+
+    /// Returns a guard that stops the other workers if the current thread panics while holding it.
+    ///
+    /// Workers hold the guard while they run, so that a panic is propagated instead of leaving the
+    /// others waiting for nodes that will never be ready.
+    pub fn abort_on_panic(&self) -> AbortOnPanic<'_, I, T> {
+        AbortOnPanic(self)
+    }
+
+    /// Returns the output of the root, if it is done.
+    pub fn into_root(self) -> Option<T> {
+        self.state.into_inner().unwrap().root
+    }
+}
+
+/// A guard returned by [ReduceScheduler::abort_on_panic].
+pub struct AbortOnPanic<'a, I, T>(&'a ReduceScheduler<I, T>);
+
+impl<I, T> Drop for AbortOnPanic<'_, I, T> {
+    fn drop(&mut self) {
+        if thread::panicking() {
+            let mut state = self.0.state.lock().unwrap_or_else(|err| err.into_inner());
+            state.aborted = true;
+            self.0.ready.notify_all();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[test]
+    fn test_reduce_tree() {
+        let tree = ReduceTree::new(5, 2);
+        assert_eq!(tree.layer_sizes, vec![5, 3, 2, 1]);
+        assert_eq!(tree.root(), TreeNode { layer: 3, index: 0 });
+        assert_eq!(tree.num_nodes(), 11);
+        assert_eq!(tree.children(TreeNode { layer: 1, index: 2 }), 4..5);
+        assert_eq!(tree.children(tree.root()), 0..2);
+        assert_eq!(
+            tree.parent(TreeNode { layer: 0, index: 3 }),
+            Some(TreeNode { layer: 1, index: 1 })
+        );
+        assert_eq!(tree.parent(tree.root()), None);
+
+        assert_eq!(ReduceTree::new(1, 2).layer_sizes, vec![1, 1]);
+        assert_eq!(ReduceTree::new(2, 2).layer_sizes, vec![2, 1]);
+    }
+
+    #[test]
+    fn test_reduce_scheduler() {
+        // Each node outputs the leaves below it, so the root outputs all of them in order.
+        let tree = ReduceTree::new(7, 2);
+        let num_nodes = tree.num_nodes();
+        let leaves = (0..7).map(|i| vec![i]).collect();
+        let scheduler = Arc::new(ReduceScheduler::<Vec<usize>, Vec<usize>>::new(tree, leaves));
+        let proven = Arc::new(Mutex::new(Vec::new()));
+        thread::scope(|s| {
+            for _ in 0..4 {
+                let scheduler = Arc::clone(&scheduler);
+                let proven = Arc::clone(&proven);
+                s.spawn(move || {
+                    while let Some((node, input)) = scheduler.next() {
+                        proven.lock().unwrap().push(node);
+                        if let Some((parent, outputs)) = scheduler.finish(node, input) {
+                            scheduler.schedule(parent, outputs.concat());
+                        }
+                    }
+                });
+            }
+        });
+        assert_eq!(proven.lock().unwrap().len(), num_nodes);
+        let scheduler = Arc::into_inner(scheduler).unwrap();
+        assert_eq!(scheduler.into_root(), Some((0..7).collect()));
+    }
+}
@@ -1,14 +1,24 @@
-use std::{borrow::Borrow, fs::File, path::Path};
+use std::{
+    borrow::Borrow,
+    fs::File,
+    io::{BufWriter, Write},
+    ops::Range,
+    path::Path,
+};
 
-use anyhow::Result;
+use anyhow::{bail, ensure, Result};
+use memmap2::Mmap;
 use p3_baby_bear::BabyBear;
 use p3_bn254_fr::Bn254Fr;
 use p3_commit::{Pcs, TwoAdicMultiplicativeCoset};
 use p3_field::{AbstractField, PrimeField, PrimeField32, TwoAdicField};
+use p3_matrix::dense::RowMajorMatrix;
+use rayon::prelude::*;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use sp1_core_machine::{
-    io::{SP1PublicValues, SP1Stdin},
+    io::{PublicValuesHash, SP1PublicValues, SP1Stdin},
     riscv::RiscvAir,
+    utils::CoreProofProgress,
 };
 use sp1_primitives::poseidon2_hash;
 use sp1_recursion_core::{air::RecursionPublicValues, stark::config::BabyBearPoseidon2Outer};
@@ -21,7 +31,7 @@ use thiserror::Error;
 
 use crate::{
     utils::{babybear_bytes_to_bn254, babybears_to_bn254, words_to_bytes_be},
-    words_to_bytes, CompressAir, CoreSC, InnerSC,
+    words_to_bytes, CompressAir, CoreSC, InnerSC, SP1_CIRCUIT_VERSION,
 };
 
 /// The information necessary to generate a proof for a given RISC-V program.
@@ -34,9 +44,298 @@ pub struct SP1ProvingKey {
 }
 
 /// The information necessary to verify a proof for a given RISC-V program.
+///
+/// The key is encoded with a version, see [VerifyingKeyEncoding].
 #[derive(Clone, Serialize, Deserialize)]
+#[serde(into = "VerifyingKeyEncoding", try_from = "VerifyingKeyEncoding")]
 pub struct SP1VerifyingKey {
     pub vk: StarkVerifyingKey<CoreSC>,
+    /// The hash function the program computes the digest of its public values with.
+    ///
+    /// It is read from the program at setup, so [HashableKey::hash_bn254] binds it through the
+    /// preprocessed commitment of [SP1VerifyingKey::vk].
+    pub public_values_hash: PublicValuesHash,
+    /// The largest number of bytes the program reads from its input buffer, if it declares one
+    /// with `entrypoint!(main, max_stdin_size = N)`.
+    ///
+    /// It is read from the program at setup, like [SP1VerifyingKey::public_values_hash], and the
+    /// program halts with exit code 1 when it reads more, so its proofs never come from a larger
+    /// input.
+    pub max_stdin_size: Option<u64>,
+}
+
+/// The first word of the encoding of an [SP1VerifyingKey].
+///
+/// Keys encoded before their encoding was versioned start with the words of the preprocessed
+/// commitment, which are BabyBear elements and thus never equal to this tag, so decoding them fails
+/// instead of misreading their fields.
+const VERIFYING_KEY_ENCODING_TAG: u32 = u32::MAX;
+
+/// The version of the encoding of [SP1VerifyingKey], to bump whenever its fields change.
+const VERIFYING_KEY_ENCODING_VERSION: u32 = 2;
+
+/// The encoding of an [SP1VerifyingKey], which starts with [VERIFYING_KEY_ENCODING_TAG] and
+/// [VERIFYING_KEY_ENCODING_VERSION].
+///
+/// Formats such as bincode are not self-describing, so a field with a default value cannot be
+/// added to the key without breaking the decoding of the keys encoded before it; the version
+/// makes such keys fail to decode with a clear error instead.
+#[derive(Serialize, Deserialize)]
+struct VerifyingKeyEncoding {
+    tag: u32,
+    version: u32,
+    vk: StarkVerifyingKey<CoreSC>,
+    public_values_hash: PublicValuesHash,
+    max_stdin_size: Option<u64>,
+}
+
+impl From<SP1VerifyingKey> for VerifyingKeyEncoding {
+    fn from(vk: SP1VerifyingKey) -> Self {
+        Self {
+            tag: VERIFYING_KEY_ENCODING_TAG,
+            version: VERIFYING_KEY_ENCODING_VERSION,
+            vk: vk.vk,
+            public_values_hash: vk.public_values_hash,
+            max_stdin_size: vk.max_stdin_size,
+        }
+    }
+}
+
+impl TryFrom<VerifyingKeyEncoding> for SP1VerifyingKey {
+    type Error = String;
+
+    fn try_from(encoding: VerifyingKeyEncoding) -> Result<Self, Self::Error> {
+        if encoding.tag != VERIFYING_KEY_ENCODING_TAG {
+            return Err("the verifying key was encoded by an older version of SP1, set up the \
+                        program again"
+                .to_string());
+        }
+        if encoding.version != VERIFYING_KEY_ENCODING_VERSION {
+            return Err(format!(
+                "unsupported verifying key encoding version {}, expected {}",
+                encoding.version, VERIFYING_KEY_ENCODING_VERSION
+            ));
+        }
+        Ok(Self {
+            vk: encoding.vk,
+            public_values_hash: encoding.public_values_hash,
+            max_stdin_size: encoding.max_stdin_size,
+        })
+    }
+}
+
+/// The magic bytes at the start of every key file.
+const KEY_FILE_MAGIC: [u8; 8] = *b"SP1KEYS\0";
+
+/// The version of the key file layout.
+const KEY_FILE_FORMAT_VERSION: u32 = 5;
+
+/// The alignment of every section in a key file.
+///
+/// Sections start on page boundaries so that each of them is read in whole pages and so that the
+/// layout does not depend on the machine that wrote the file.
+const KEY_FILE_SECTION_ALIGN: u64 = 4096;
+
+/// The number of sections of a proving key file before its traces, which are, in order: the
+/// commitment, the start pc, the pcs data and the chip ordering of [SP1ProvingKey::pk], the ELF,
+/// the verifying key and the widths of the traces.
+const PROVING_KEY_FILE_SECTIONS: usize = 7;
+
+impl SP1ProvingKey {
+    /// Saves the proving key to a path in a sectioned key file.
+    ///
+    /// Each field of the key is written to its own section, so that the layout does not depend on
+    /// the order of the fields of [StarkProvingKey]. The preprocessed traces are written as raw
+    /// sections of canonical little-endian words, one per trace, and the other fields with bincode.
+    /// The file can be loaded back with [SP1ProvingKey::load].
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let commit = bincode::serialize(&self.pk.commit)?;
+        let pc_start = bincode::serialize(&self.pk.pc_start)?;
+        let data = bincode::serialize(&self.pk.data)?;
+        let chip_ordering = bincode::serialize(&self.pk.chip_ordering)?;
+        let vk = bincode::serialize(&self.vk)?;
+        let widths = bincode::serialize(
+            &self.pk.traces.iter().map(|trace| trace.width).collect::<Vec<_>>(),
+        )?;
+        let traces = self
+            .pk
+            .traces
+            .par_iter()
+            .map(|trace| {
+                trace
+                    .values
+                    .iter()
+                    .flat_map(|value| value.as_canonical_u32().to_le_bytes())
+                    .collect()
+            })
+            .collect::<Vec<Vec<u8>>>();
+
+        let mut sections: Vec<&[u8]> =
+            vec![&commit, &pc_start, &data, &chip_ordering, &self.elf, &vk, &widths];
+        sections.extend(traces.iter().map(Vec::as_slice));
+        write_key_file(path, &sections)
+    }
+
+    /// Loads a proving key previously written with [SP1ProvingKey::save].
+    ///
+    /// The file is read through a memory map, so keys that are already in the page cache (e.g.
+    /// loaded by another worker process) are not read from disk again. The key owns its data, so
+    /// the traces are still copied out of the file, decoding their raw sections in parallel
+    /// without going through bincode.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let (mmap, sections) = map_key_file(path, PROVING_KEY_FILE_SECTIONS)?;
+        let section = |i: usize| &mmap[sections[i].clone()];
+        let widths: Vec<usize> = bincode::deserialize(section(6))?;
+        ensure!(
+            sections.len() == PROVING_KEY_FILE_SECTIONS + widths.len(),
+            "expected {} trace sections, found {}",
+            widths.len(),
+            sections.len() - PROVING_KEY_FILE_SECTIONS
+        );
+        let traces = widths
+            .iter()
+            .zip(&sections[PROVING_KEY_FILE_SECTIONS..])
+            .map(|(&width, range)| read_trace(&mmap[range.clone()], width))
+            .collect::<Result<_>>()?;
+        let pk = StarkProvingKey {
+            commit: bincode::deserialize(section(0))?,
+            pc_start: bincode::deserialize(section(1))?,
+            traces,
+            data: bincode::deserialize(section(2))?,
+            chip_ordering: bincode::deserialize(section(3))?,
+        };
+        let elf = section(4).to_vec();
+        let vk = bincode::deserialize(section(5))?;
+        Ok(Self { pk, elf, vk })
+    }
+}
+
+/// Reads a trace of `width` columns from its raw section of canonical little-endian words.
+fn read_trace(bytes: &[u8], width: usize) -> Result<RowMajorMatrix<BabyBear>> {
+    ensure!(bytes.len() % 4 == 0, "truncated trace section");
+    let values = bytes
+        .par_chunks_exact(4)
+        .map(|word| {
+            let word = u32::from_le_bytes(word.try_into().unwrap());
+            ensure!(word < BabyBear::ORDER_U32, "non-canonical trace value {}", word);
+            Ok(BabyBear::from_canonical_u32(word))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    ensure!(width > 0 && values.len() % width == 0, "invalid trace width {}", width);
+    Ok(RowMajorMatrix::new(values, width))
+}
+
+impl SP1VerifyingKey {
+    /// Saves the verifying key to a path in a key file.
+    ///
+    /// The file can be loaded back with [SP1VerifyingKey::load].
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let vk = bincode::serialize(self)?;
+        write_key_file(path, &[&vk])
+    }
+
+    /// Loads a verifying key previously written with [SP1VerifyingKey::save].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let (mmap, sections) = map_key_file(path, 1)?;
+        ensure!(sections.len() == 1, "expected 1 section, found {}", sections.len());
+        Ok(bincode::deserialize(&mmap[sections[0].clone()])?)
+    }
+}
+
+/// Writes a key file consisting of a header followed by the given sections.
+///
+/// The header is laid out as follows (all integers little-endian):
+/// - 8 bytes of [KEY_FILE_MAGIC].
+/// - 4 bytes of [KEY_FILE_FORMAT_VERSION].
+/// - 4 bytes holding the number of sections `n`.
+/// - 32 bytes holding the zero-padded [SP1_CIRCUIT_VERSION] the key was generated with.
+/// - `n` pairs of 8 byte `(offset, length)` entries, one per section.
+///
+/// Each section starts at a multiple of [KEY_FILE_SECTION_ALIGN].
+fn write_key_file(path: impl AsRef<Path>, sections: &[&[u8]]) -> Result<()> {
+    let version = SP1_CIRCUIT_VERSION.as_bytes();
+    ensure!(version.len() <= 32, "circuit version {} is too long", SP1_CIRCUIT_VERSION);
+
+    let header_len = 48 + 16 * sections.len() as u64;
+    let mut offsets = Vec::with_capacity(sections.len());
+    let mut offset = header_len.next_multiple_of(KEY_FILE_SECTION_ALIGN);
+    for section in sections {
+        offsets.push(offset);
+        offset = (offset + section.len() as u64).next_multiple_of(KEY_FILE_SECTION_ALIGN);
+    }
+
+    let mut writer = BufWriter::new(File::create(path)?);
+    writer.write_all(&KEY_FILE_MAGIC)?;
+    writer.write_all(&KEY_FILE_FORMAT_VERSION.to_le_bytes())?;
+    writer.write_all(&(sections.len() as u32).to_le_bytes())?;
+    let mut version_bytes = [0u8; 32];
+    version_bytes[..version.len()].copy_from_slice(version);
+    writer.write_all(&version_bytes)?;
+    for (section, offset) in sections.iter().zip(offsets.iter()) {
+        writer.write_all(&offset.to_le_bytes())?;
+        writer.write_all(&(section.len() as u64).to_le_bytes())?;
+    }
+
+    let mut position = header_len;
+    for (section, offset) in sections.iter().zip(offsets.iter()) {
+        writer.write_all(&vec![0u8; (offset - position) as usize])?;
+        writer.write_all(section)?;
+        position = offset + section.len() as u64;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Memory-maps a key file written with [write_key_file] and returns the byte ranges of its
+/// sections after validating the header, which must have at least `min_sections` sections.
+fn map_key_file(path: impl AsRef<Path>, min_sections: usize) -> Result<(Mmap, Vec<Range<usize>>)> {
+    let file = File::open(path)?;
+    // SAFETY: The mapping is read-only. Key files are written once and are not expected to be
+    // modified while they are mapped.
+    let mmap = unsafe { Mmap::map(&file)? };
+
+    ensure!(mmap.len() >= 48 && mmap[..8] == KEY_FILE_MAGIC, "not an SP1 key file");
+    let read_u32 = |at: usize| u32::from_le_bytes(mmap[at..at + 4].try_into().unwrap());
+    let read_u64 = |at: usize| u64::from_le_bytes(mmap[at..at + 8].try_into().unwrap());
+
+    let format_version = read_u32(8);
+    if format_version != KEY_FILE_FORMAT_VERSION {
+        bail!("unsupported key file format version {}", format_version);
+    }
+    let version = String::from_utf8_lossy(&mmap[16..48]);
+    let version = version.trim_end_matches('\0');
+    if version != SP1_CIRCUIT_VERSION {
+        bail!(
+            "key file was generated with circuit version {}, expected {}",
+            version,
+            SP1_CIRCUIT_VERSION
+        );
+    }
+
+    let num_sections = read_u32(12) as usize;
+    ensure!(
+        num_sections >= min_sections,
+        "expected at least {} sections, found {}",
+        min_sections,
+        num_sections
+    );
+    ensure!(
+        num_sections.checked_mul(16).is_some_and(|len| mmap.len() >= 48 + len),
+        "truncated key file header"
+    );
+    let sections = (0..num_sections)
+        .map(|i| {
+            let offset = read_u64(48 + 16 * i) as usize;
+            let len = read_u64(56 + 16 * i) as usize;
+            ensure!(
+                offset.checked_add(len).is_some_and(|end| end <= mmap.len()),
+                "truncated key file"
+            );
+            Ok(offset..offset + len)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((mmap, sections))
 }
 
 /// A trait for keys that can be hashed into a digest.
@@ -153,6 +452,14 @@ pub type SP1Proof = SP1ProofWithMetadata<SP1Bn254ProofData>;
 #[derive(Serialize, Deserialize, Clone)]
 pub struct SP1CoreProofData(pub Vec<ShardProof<CoreSC>>);
 
+/// The outcome of [SP1Prover::prove_core_resumable](crate::SP1Prover::prove_core_resumable).
+pub enum SP1CoreProofOutcome {
+    /// The core proof.
+    Done(SP1CoreProof),
+    /// The progress of the core proof when it was interrupted, from which it can be resumed.
+    Interrupted(Box<CoreProofProgress<CoreSC>>),
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct SP1ReducedProofData(pub ShardProof<InnerSC>);
 
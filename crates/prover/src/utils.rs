@@ -25,6 +25,9 @@ pub fn get_cycles(elf: &[u8], stdin: &SP1Stdin) -> u64 {
     let program = Program::from(elf).unwrap();
     let mut runtime = Executor::new(program, SP1CoreOpts::default());
     runtime.write_vecs(&stdin.buffer);
+    for (stream_id, entries) in &stdin.streams {
+        runtime.write_stream(*stream_id, entries);
+    }
     runtime.run_fast().unwrap();
     runtime.state.global_clk
 }
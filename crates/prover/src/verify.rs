@@ -14,7 +14,7 @@ use sp1_recursion_gnark_ffi::{
 use sp1_stark::{
     air::{PublicValues, POSEIDON_NUM_WORDS, PV_DIGEST_NUM_WORDS},
     baby_bear_poseidon2::BabyBearPoseidon2,
-    MachineProof, MachineProver, MachineVerificationError, StarkGenericConfig, Word,
+    MachineProof, MachineProver, MachineVerificationError, ShardProof, StarkGenericConfig, Word,
 };
 use thiserror::Error;
 
@@ -47,234 +47,278 @@ pub enum Groth16VerificationError {
     InvalidPublicValues,
 }
 
-impl<C: SP1ProverComponents> SP1Prover<C> {
-    /// Verify a core proof by verifying the shards, verifying lookup bus, verifying that the
-    /// shards are contiguous and complete.
-    pub fn verify(
-        &self,
-        proof: &SP1CoreProofData,
-        vk: &SP1VerifyingKey,
-    ) -> Result<(), MachineVerificationError<CoreSC>> {
-        // First shard has a "CPU" constraint.
-        //
-        // Assert that the first shard has a "CPU".
-        let first_shard = proof.0.first().unwrap();
-        if !first_shard.contains_cpu() {
-            return Err(MachineVerificationError::MissingCpuInFirstShard);
-        }
+/// Verify the public values of the shards of a core proof: that the shards are contiguous, start
+/// at the program's entrypoint and end with the program halting successfully.
+///
+/// The shard proofs themselves are not verified.
+pub fn verify_core_public_values(
+    shard_proofs: &[ShardProof<CoreSC>],
+    vk: &SP1VerifyingKey,
+) -> Result<(), MachineVerificationError<CoreSC>> {
+    verify_core_public_values_with_exit_code(shard_proofs, vk, 0)
+}
 
-        // CPU log degree bound constraints.
-        //
-        // Assert that the CPU log degree does not exceed `MAX_CPU_LOG_DEGREE`. This is to ensure
-        // that the lookup argument's multiplicities do not overflow.
-        for shard_proof in proof.0.iter() {
-            if shard_proof.contains_cpu() {
-                let log_degree_cpu = shard_proof.log_degree_cpu();
-                if log_degree_cpu > MAX_CPU_LOG_DEGREE {
-                    return Err(MachineVerificationError::CpuLogDegreeTooLarge(log_degree_cpu));
-                }
-            }
-        }
+/// Like [verify_core_public_values], but checks that the program halts with `exit_code`, which
+/// is non-zero for a proof that the program fails.
+pub fn verify_core_public_values_with_exit_code(
+    shard_proofs: &[ShardProof<CoreSC>],
+    vk: &SP1VerifyingKey,
+    exit_code: u32,
+) -> Result<(), MachineVerificationError<CoreSC>> {
+    // First shard has a "CPU" constraint.
+    //
+    // Assert that the first shard has a "CPU".
+    let first_shard = shard_proofs.first().unwrap();
+    if !first_shard.contains_cpu() {
+        return Err(MachineVerificationError::MissingCpuInFirstShard);
+    }
 
-        // Shard constraints.
-        //
-        // Initialization:
-        // - Shard should start at one.
-        //
-        // Transition:
-        // - Shard should increment by one for each shard.
-        let mut current_shard = BabyBear::zero();
-        for shard_proof in proof.0.iter() {
-            let public_values: &PublicValues<Word<_>, _> =
-                shard_proof.public_values.as_slice().borrow();
-            current_shard += BabyBear::one();
-            if public_values.shard != current_shard {
-                return Err(MachineVerificationError::InvalidPublicValues(
-                    "shard index should be the previous shard index + 1 and start at 1",
-                ));
+    // CPU log degree bound constraints.
+    //
+    // Assert that the CPU log degree does not exceed `MAX_CPU_LOG_DEGREE`. This is to ensure
+    // that the lookup argument's multiplicities do not overflow.
+    for shard_proof in shard_proofs.iter() {
+        if shard_proof.contains_cpu() {
+            let log_degree_cpu = shard_proof.log_degree_cpu();
+            if log_degree_cpu > MAX_CPU_LOG_DEGREE {
+                return Err(MachineVerificationError::CpuLogDegreeTooLarge(log_degree_cpu));
             }
         }
+    }
 
-        // Execution shard constraints.
-        //
-        // Initialization:
-        // - Execution shard should start at one.
-        //
-        // Transition:
-        // - Execution shard should increment by one for each shard with "CPU".
-        // - Execution shard should stay the same for non-CPU shards.
-        // - For the other shards, execution shard does not matter.
-        let mut current_execution_shard = BabyBear::zero();
-        for shard_proof in proof.0.iter() {
-            let public_values: &PublicValues<Word<_>, _> =
-                shard_proof.public_values.as_slice().borrow();
-            if shard_proof.contains_cpu() {
-                current_execution_shard += BabyBear::one();
-                if public_values.execution_shard != current_execution_shard {
-                    return Err(MachineVerificationError::InvalidPublicValues(
-                        "execution shard index should be the previous execution shard index + 1 if cpu exists and start at 1",
-                    ));
-                }
-            }
+    // Shard constraints.
+    //
+    // Initialization:
+    // - Shard should start at one.
+    //
+    // Transition:
+    // - Shard should increment by one for each shard.
+    let mut current_shard = BabyBear::zero();
+    for shard_proof in shard_proofs.iter() {
+        let public_values: &PublicValues<Word<_>, _> =
+            shard_proof.public_values.as_slice().borrow();
+        current_shard += BabyBear::one();
+        if public_values.shard != current_shard {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "shard index should be the previous shard index + 1 and start at 1",
+            ));
         }
+    }
 
-        // Program counter constraints.
-        //
-        // Initialization:
-        // - `start_pc` should start as `vk.start_pc`.
-        //
-        // Transition:
-        // - `next_pc` of the previous shard should equal `start_pc`.
-        // - If it's not a shard with "CPU", then `start_pc` equals `next_pc`.
-        // - If it's a shard with "CPU", then `start_pc` should never equal zero.
-        //
-        // Finalization:
-        // - `next_pc` should equal zero.
-        let mut prev_next_pc = BabyBear::zero();
-        for (i, shard_proof) in proof.0.iter().enumerate() {
-            let public_values: &PublicValues<Word<_>, _> =
-                shard_proof.public_values.as_slice().borrow();
-            if i == 0 && public_values.start_pc != vk.vk.pc_start {
-                return Err(MachineVerificationError::InvalidPublicValues(
-                    "start_pc != vk.start_pc: program counter should start at vk.start_pc",
-                ));
-            } else if i != 0 && public_values.start_pc != prev_next_pc {
-                return Err(MachineVerificationError::InvalidPublicValues(
-                    "start_pc != next_pc_prev: start_pc should equal next_pc_prev for all shards",
-                ));
-            } else if !shard_proof.contains_cpu() && public_values.start_pc != public_values.next_pc
-            {
-                return Err(MachineVerificationError::InvalidPublicValues(
-                    "start_pc != next_pc: start_pc should equal next_pc for non-cpu shards",
-                ));
-            } else if shard_proof.contains_cpu() && public_values.start_pc == BabyBear::zero() {
-                return Err(MachineVerificationError::InvalidPublicValues(
-                    "start_pc == 0: execution should never start at halted state",
-                ));
-            } else if i == proof.0.len() - 1 && public_values.next_pc != BabyBear::zero() {
+    // Execution shard constraints.
+    //
+    // Initialization:
+    // - Execution shard should start at one.
+    //
+    // Transition:
+    // - Execution shard should increment by one for each shard with "CPU".
+    // - Execution shard should stay the same for non-CPU shards.
+    // - For the other shards, execution shard does not matter.
+    let mut current_execution_shard = BabyBear::zero();
+    for shard_proof in shard_proofs.iter() {
+        let public_values: &PublicValues<Word<_>, _> =
+            shard_proof.public_values.as_slice().borrow();
+        if shard_proof.contains_cpu() {
+            current_execution_shard += BabyBear::one();
+            if public_values.execution_shard != current_execution_shard {
                 return Err(MachineVerificationError::InvalidPublicValues(
-                    "next_pc != 0: execution should have halted",
+                    "execution shard index should be the previous execution shard index + 1 if cpu exists and start at 1",
                 ));
             }
-            prev_next_pc = public_values.next_pc;
         }
+    }
 
-        // Exit code constraints.
-        //
-        // - In every shard, the exit code should be zero.
-        for shard_proof in proof.0.iter() {
-            let public_values: &PublicValues<Word<_>, _> =
-                shard_proof.public_values.as_slice().borrow();
-            if public_values.exit_code != BabyBear::zero() {
-                return Err(MachineVerificationError::InvalidPublicValues(
-                    "exit_code != 0: exit code should be zero for all shards",
-                ));
-            }
+    // Program counter constraints.
+    //
+    // Initialization:
+    // - `start_pc` should start as `vk.start_pc`.
+    //
+    // Transition:
+    // - `next_pc` of the previous shard should equal `start_pc`.
+    // - If it's not a shard with "CPU", then `start_pc` equals `next_pc`.
+    // - If it's a shard with "CPU", then `start_pc` should never equal zero.
+    //
+    // Finalization:
+    // - `next_pc` should equal zero.
+    let mut prev_next_pc = BabyBear::zero();
+    for (i, shard_proof) in shard_proofs.iter().enumerate() {
+        let public_values: &PublicValues<Word<_>, _> =
+            shard_proof.public_values.as_slice().borrow();
+        if i == 0 && public_values.start_pc != vk.vk.pc_start {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "start_pc != vk.start_pc: program counter should start at vk.start_pc",
+            ));
+        } else if i != 0 && public_values.start_pc != prev_next_pc {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "start_pc != next_pc_prev: start_pc should equal next_pc_prev for all shards",
+            ));
+        } else if !shard_proof.contains_cpu() && public_values.start_pc != public_values.next_pc {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "start_pc != next_pc: start_pc should equal next_pc for non-cpu shards",
+            ));
+        } else if shard_proof.contains_cpu() && public_values.start_pc == BabyBear::zero() {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "start_pc == 0: execution should never start at halted state",
+            ));
+        } else if i == shard_proofs.len() - 1 && public_values.next_pc != BabyBear::zero() {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "next_pc != 0: execution should have halted",
+            ));
         }
+        prev_next_pc = public_values.next_pc;
+    }
 
-        // Memory initialization & finalization constraints.
-        //
-        // Initialization:
-        // - `previous_init_addr_bits` should be zero.
-        // - `previous_finalize_addr_bits` should be zero.
-        //
-        // Transition:
-        // - For all shards, `previous_init_addr_bits` should equal `last_init_addr_bits` of the
-        //   previous shard.
-        // - For all shards, `previous_finalize_addr_bits` should equal `last_finalize_addr_bits` of
-        //   the previous shard.
-        // - For shards without "MemoryInit", `previous_init_addr_bits` should equal
-        //   `last_init_addr_bits`.
-        // - For shards without "MemoryFinalize", `previous_finalize_addr_bits` should equal
-        //   `last_finalize_addr_bits`.
-        let mut last_init_addr_bits_prev = [BabyBear::zero(); 32];
-        let mut last_finalize_addr_bits_prev = [BabyBear::zero(); 32];
-        for shard_proof in proof.0.iter() {
-            let public_values: &PublicValues<Word<_>, _> =
-                shard_proof.public_values.as_slice().borrow();
-            if public_values.previous_init_addr_bits != last_init_addr_bits_prev {
-                return Err(MachineVerificationError::InvalidPublicValues(
-                    "previous_init_addr_bits != last_init_addr_bits_prev",
-                ));
-            } else if public_values.previous_finalize_addr_bits != last_finalize_addr_bits_prev {
-                return Err(MachineVerificationError::InvalidPublicValues(
-                    "last_init_addr_bits != last_finalize_addr_bits_prev",
-                ));
-            } else if !shard_proof.contains_memory_init()
-                && public_values.previous_init_addr_bits != public_values.last_init_addr_bits
-            {
-                return Err(MachineVerificationError::InvalidPublicValues(
-                    "previous_init_addr_bits != last_init_addr_bits",
-                ));
-            } else if !shard_proof.contains_memory_finalize()
-                && public_values.previous_finalize_addr_bits
-                    != public_values.last_finalize_addr_bits
-            {
-                return Err(MachineVerificationError::InvalidPublicValues(
-                    "previous_finalize_addr_bits != last_finalize_addr_bits",
-                ));
-            }
-            last_init_addr_bits_prev = public_values.last_init_addr_bits;
-            last_finalize_addr_bits_prev = public_values.last_finalize_addr_bits;
+    // Exit code constraints.
+    //
+    // - In every shard before the last shard with a "CPU", the exit code should be zero.
+    // - In the last shard with a "CPU", which halts, and in the shards after it, the exit code
+    //   should be `exit_code`.
+    let halt_shard = shard_proofs.iter().rposition(|shard_proof| shard_proof.contains_cpu());
+    for (i, shard_proof) in shard_proofs.iter().enumerate() {
+        let public_values: &PublicValues<Word<_>, _> =
+            shard_proof.public_values.as_slice().borrow();
+        if Some(i) < halt_shard && public_values.exit_code != BabyBear::zero() {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "exit_code != 0: exit code should be zero for all shards before the halt",
+            ));
+        } else if Some(i) >= halt_shard
+            && public_values.exit_code != BabyBear::from_canonical_u32(exit_code)
+        {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "exit_code mismatch: the program should halt with the expected exit code",
+            ));
         }
+    }
 
-        // Digest constraints.
-        //
-        // Initialization:
-        // - `committed_value_digest` should be zero.
-        // - `deferred_proofs_digest` should be zero.
-        //
-        // Transition:
-        // - If `commited_value_digest_prev` is not zero, then `committed_value_digest` should equal
-        //  `commited_value_digest_prev`. Otherwise, `committed_value_digest` should equal zero.
-        // - If `deferred_proofs_digest_prev` is not zero, then `deferred_proofs_digest` should
-        //   equal
-        //  `deferred_proofs_digest_prev`. Otherwise, `deferred_proofs_digest` should equal zero.
-        // - If it's not a shard with "CPU", then `commited_value_digest` should not change from the
-        //  previous shard.
-        // - If it's not a shard with "CPU", then `deferred_proofs_digest` should not change from
-        //   the
-        //  previous shard.
-        let zero_commited_value_digest = [Word([BabyBear::zero(); WORD_SIZE]); PV_DIGEST_NUM_WORDS];
-        let zero_deferred_proofs_digest = [BabyBear::zero(); POSEIDON_NUM_WORDS];
-        let mut commited_value_digest_prev = zero_commited_value_digest;
-        let mut deferred_proofs_digest_prev = zero_deferred_proofs_digest;
-        for shard_proof in proof.0.iter() {
-            let public_values: &PublicValues<Word<_>, _> =
-                shard_proof.public_values.as_slice().borrow();
-            if commited_value_digest_prev != zero_commited_value_digest
-                && public_values.committed_value_digest != commited_value_digest_prev
-            {
-                return Err(MachineVerificationError::InvalidPublicValues(
-                    "committed_value_digest != commited_value_digest_prev",
-                ));
-            } else if deferred_proofs_digest_prev != zero_deferred_proofs_digest
-                && public_values.deferred_proofs_digest != deferred_proofs_digest_prev
-            {
-                return Err(MachineVerificationError::InvalidPublicValues(
-                    "deferred_proofs_digest != deferred_proofs_digest_prev",
-                ));
-            } else if !shard_proof.contains_cpu()
-                && public_values.committed_value_digest != commited_value_digest_prev
-            {
-                return Err(MachineVerificationError::InvalidPublicValues(
-                    "committed_value_digest != commited_value_digest_prev",
-                ));
-            } else if !shard_proof.contains_cpu()
-                && public_values.deferred_proofs_digest != deferred_proofs_digest_prev
-            {
-                return Err(MachineVerificationError::InvalidPublicValues(
-                    "deferred_proofs_digest != deferred_proofs_digest_prev",
-                ));
-            }
-            commited_value_digest_prev = public_values.committed_value_digest;
-            deferred_proofs_digest_prev = public_values.deferred_proofs_digest;
+    // Memory initialization & finalization constraints.
+    //
+    // Initialization:
+    // - `previous_init_addr_bits` should be zero.
+    // - `previous_finalize_addr_bits` should be zero.
+    //
+    // Transition:
+    // - For all shards, `previous_init_addr_bits` should equal `last_init_addr_bits` of the
+    //   previous shard.
+    // - For all shards, `previous_finalize_addr_bits` should equal `last_finalize_addr_bits` of
+    //   the previous shard.
+    // - For shards without "MemoryInit", `previous_init_addr_bits` should equal
+    //   `last_init_addr_bits`.
+    // - For shards without "MemoryFinalize", `previous_finalize_addr_bits` should equal
+    //   `last_finalize_addr_bits`.
+    let mut last_init_addr_bits_prev = [BabyBear::zero(); 32];
+    let mut last_finalize_addr_bits_prev = [BabyBear::zero(); 32];
+    for shard_proof in shard_proofs.iter() {
+        let public_values: &PublicValues<Word<_>, _> =
+            shard_proof.public_values.as_slice().borrow();
+        if public_values.previous_init_addr_bits != last_init_addr_bits_prev {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "previous_init_addr_bits != last_init_addr_bits_prev",
+            ));
+        } else if public_values.previous_finalize_addr_bits != last_finalize_addr_bits_prev {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "last_init_addr_bits != last_finalize_addr_bits_prev",
+            ));
+        } else if !shard_proof.contains_memory_init()
+            && public_values.previous_init_addr_bits != public_values.last_init_addr_bits
+        {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "previous_init_addr_bits != last_init_addr_bits",
+            ));
+        } else if !shard_proof.contains_memory_finalize()
+            && public_values.previous_finalize_addr_bits != public_values.last_finalize_addr_bits
+        {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "previous_finalize_addr_bits != last_finalize_addr_bits",
+            ));
         }
+        last_init_addr_bits_prev = public_values.last_init_addr_bits;
+        last_finalize_addr_bits_prev = public_values.last_finalize_addr_bits;
+    }
 
-        // Verify that the number of shards is not too large.
-        if proof.0.len() > 1 << 16 {
-            return Err(MachineVerificationError::TooManyShards);
+    // Digest constraints.
+    //
+    // Initialization:
+    // - `committed_value_digest` should be zero.
+    // - `deferred_proofs_digest` should be zero.
+    //
+    // Transition:
+    // - If `commited_value_digest_prev` is not zero, then `committed_value_digest` should equal
+    //  `commited_value_digest_prev`. Otherwise, `committed_value_digest` should equal zero.
+    // - If `deferred_proofs_digest_prev` is not zero, then `deferred_proofs_digest` should
+    //   equal
+    //  `deferred_proofs_digest_prev`. Otherwise, `deferred_proofs_digest` should equal zero.
+    // - If it's not a shard with "CPU", then `commited_value_digest` should not change from the
+    //  previous shard.
+    // - If it's not a shard with "CPU", then `deferred_proofs_digest` should not change from
+    //   the
+    //  previous shard.
+    let zero_commited_value_digest = [Word([BabyBear::zero(); WORD_SIZE]); PV_DIGEST_NUM_WORDS];
+    let zero_deferred_proofs_digest = [BabyBear::zero(); POSEIDON_NUM_WORDS];
+    let mut commited_value_digest_prev = zero_commited_value_digest;
+    let mut deferred_proofs_digest_prev = zero_deferred_proofs_digest;
+    for shard_proof in shard_proofs.iter() {
+        let public_values: &PublicValues<Word<_>, _> =
+            shard_proof.public_values.as_slice().borrow();
+        if commited_value_digest_prev != zero_commited_value_digest
+            && public_values.committed_value_digest != commited_value_digest_prev
+        {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "committed_value_digest != commited_value_digest_prev",
+            ));
+        } else if deferred_proofs_digest_prev != zero_deferred_proofs_digest
+            && public_values.deferred_proofs_digest != deferred_proofs_digest_prev
+        {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "deferred_proofs_digest != deferred_proofs_digest_prev",
+            ));
+        } else if !shard_proof.contains_cpu()
+            && public_values.committed_value_digest != commited_value_digest_prev
+        {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "committed_value_digest != commited_value_digest_prev",
+            ));
+        } else if !shard_proof.contains_cpu()
+            && public_values.deferred_proofs_digest != deferred_proofs_digest_prev
+        {
+            return Err(MachineVerificationError::InvalidPublicValues(
+                "deferred_proofs_digest != deferred_proofs_digest_prev",
+            ));
         }
+        commited_value_digest_prev = public_values.committed_value_digest;
+        deferred_proofs_digest_prev = public_values.deferred_proofs_digest;
+    }
+
+    // Verify that the number of shards is not too large.
+    if shard_proofs.len() > 1 << 16 {
+        return Err(MachineVerificationError::TooManyShards);
+    }
+
+    Ok(())
+}
+
+impl<C: SP1ProverComponents> SP1Prover<C> {
+    /// Verify a core proof by verifying the shards, verifying lookup bus, verifying that the
+    /// shards are contiguous and complete.
+    pub fn verify(
+        &self,
+        proof: &SP1CoreProofData,
+        vk: &SP1VerifyingKey,
+    ) -> Result<(), MachineVerificationError<CoreSC>> {
+        self.verify_with_exit_code(proof, vk, 0)
+    }
+
+    /// Verify a core proof of an execution that halts with `exit_code`.
+    ///
+    /// With a non-zero exit code, this proves that the program fails on the input it was proven
+    /// with, such as by panicking. Such proofs are generated with
+    /// [SP1ContextBuilder::allow_failure](sp1_core_executor::SP1ContextBuilder::allow_failure).
+    pub fn verify_with_exit_code(
+        &self,
+        proof: &SP1CoreProofData,
+        vk: &SP1VerifyingKey,
+        exit_code: u32,
+    ) -> Result<(), MachineVerificationError<CoreSC>> {
+        verify_core_public_values_with_exit_code(&proof.0, vk, exit_code)?;
 
         // Verify the shard proof.
         let mut challenger = self.core_prover.config().challenger();
@@ -424,6 +468,79 @@ impl<C: SP1ProverComponents> SP1Prover<C> {
 
         Ok(())
     }
+
+    /// Verifies a PLONK proof of the program whose vkey hashes to `vkey_hash`, using the circuit
+    /// artifacts in the build directory.
+    ///
+    /// This verifies the proofs of older circuit versions, whose vkeys hash differently than the
+    /// vkeys of the current version. Their public values are hashed with SHA-256.
+    pub fn verify_plonk_bn254_with_vkey_hash(
+        &self,
+        proof: &PlonkBn254Proof,
+        vkey_hash: &BigUint,
+        public_values: &SP1PublicValues,
+        build_dir: &Path,
+    ) -> Result<()> {
+        if BigUint::from_str(&proof.public_inputs[0])? != *vkey_hash {
+            return Err(PlonkVerificationError::InvalidVerificationKey.into());
+        }
+        let committed_values_digest = BigUint::from_str(&proof.public_inputs[1])?;
+        if public_values.hash_bn254() != committed_values_digest {
+            return Err(PlonkVerificationError::InvalidPublicValues.into());
+        }
+
+        PlonkBn254Prover::new().verify(proof, vkey_hash, &committed_values_digest, build_dir);
+
+        Ok(())
+    }
+
+    /// Verifies a Groth16 proof of the program whose vkey hashes to `vkey_hash`, using the circuit
+    /// artifacts in the build directory.
+    ///
+    /// This verifies the proofs of older circuit versions, whose vkeys hash differently than the
+    /// vkeys of the current version. Their public values are hashed with SHA-256.
+    pub fn verify_groth16_bn254_with_vkey_hash(
+        &self,
+        proof: &Groth16Bn254Proof,
+        vkey_hash: &BigUint,
+        public_values: &SP1PublicValues,
+        build_dir: &Path,
+    ) -> Result<()> {
+        if BigUint::from_str(&proof.public_inputs[0])? != *vkey_hash {
+            return Err(Groth16VerificationError::InvalidVerificationKey.into());
+        }
+        let committed_values_digest = BigUint::from_str(&proof.public_inputs[1])?;
+        if public_values.hash_bn254() != committed_values_digest {
+            return Err(Groth16VerificationError::InvalidPublicValues.into());
+        }
+
+        Groth16Bn254Prover::new().verify(proof, vkey_hash, &committed_values_digest, build_dir);
+
+        Ok(())
+    }
+
+    /// Verifies several Groth16 proofs with a single pairing check, using the circuit artifacts in
+    /// the build directory.
+    ///
+    /// Each proof is given with the verifying key of its program and its public values.
+    pub fn verify_groth16_bn254_batch(
+        &self,
+        proofs: &[(&Groth16Bn254Proof, &SP1VerifyingKey, &SP1PublicValues)],
+        build_dir: &Path,
+    ) -> Result<()> {
+        let mut inputs = Vec::with_capacity(proofs.len());
+        for (proof, vk, public_values) in proofs {
+            verify_groth16_bn254_public_inputs(vk, public_values, &proof.public_inputs)?;
+            let vkey_hash = BigUint::from_str(&proof.public_inputs[0])?;
+            let committed_values_digest = BigUint::from_str(&proof.public_inputs[1])?;
+            inputs.push((*proof, vkey_hash, committed_values_digest));
+        }
+
+        // Verify the proofs with the corresponding public inputs.
+        Groth16Bn254Prover::new().verify_batch(&inputs, build_dir);
+
+        Ok(())
+    }
 }
 
 /// Verify the vk_hash and public_values_hash in the public inputs of the PlonkBn254Proof match the
@@ -441,7 +558,7 @@ pub fn verify_plonk_bn254_public_inputs(
         return Err(PlonkVerificationError::InvalidVerificationKey.into());
     }
 
-    let public_values_hash = public_values.hash_bn254();
+    let public_values_hash = public_values.hash_bn254_with(vk.public_values_hash);
     if public_values_hash != expected_public_values_hash {
         return Err(PlonkVerificationError::InvalidPublicValues.into());
     }
@@ -464,7 +581,7 @@ pub fn verify_groth16_bn254_public_inputs(
         return Err(Groth16VerificationError::InvalidVerificationKey.into());
     }
 
-    let public_values_hash = public_values.hash_bn254();
+    let public_values_hash = public_values.hash_bn254_with(vk.public_values_hash);
     if public_values_hash != expected_public_values_hash {
         return Err(Groth16VerificationError::InvalidPublicValues.into());
     }
@@ -489,7 +606,11 @@ impl<C: SP1ProverComponents> SubproofVerifier for &SP1Prover<C> {
         // Check that proof is valid.
         self.verify_compressed(
             &SP1ReduceProof { proof: proof.clone() },
-            &SP1VerifyingKey { vk: vk.clone() },
+            &SP1VerifyingKey {
+                vk: vk.clone(),
+                public_values_hash: Default::default(),
+                max_stdin_size: None,
+            },
         )?;
         // Check that the committed value digest matches the one from syscall
         let public_values: &RecursionPublicValues<_> = proof.public_values.as_slice().borrow();
@@ -0,0 +1,328 @@
+//! A typed builder for writing custom recursion programs.
+//!
+//! The recursion programs of the prover are written against the compiler IR directly, whose
+//! variables can be used with any builder and whose instructions change between releases. This
+//! module wraps the IR in a small, stable API for programs such as custom aggregation logic:
+//!
+//! - Programs are written in a closure passed to [build_program], which receives a [RecursionDsl].
+//!   The closure is generic over the lifetime `'b` of the builder, and every value it creates is a
+//!   handle branded with that lifetime, so a handle can neither escape the closure nor be used with
+//!   the builder of another program. Misuse is a compile error.
+//! - Inputs are given when the program is built. Each input is written to the witness stream and
+//!   read by the program, so the program and its witness are always consistent.
+//!
+//! ```ignore
+//! let program = build_program(|b| {
+//!     let x = b.input_felt(BabyBear::from_canonical_u32(3));
+//!     let y = b.mul(x, x);
+//!     let nine = b.felt(BabyBear::from_canonical_u32(9));
+//!     b.assert_eq(y, nine);
+//! });
+//! ```
+
+use std::marker::PhantomData;
+
+use p3_air::Air;
+use p3_baby_bear::BabyBear;
+use p3_field::AbstractField;
+use sp1_recursion_compiler::{
+    circuit::{AsmCompiler, CircuitV2Builder},
+    config::InnerConfig,
+    ir::{Builder, Ext, Felt},
+};
+use sp1_recursion_core_v2::{RecursionProgram, DIGEST_SIZE};
+use sp1_stark::{
+    air::MachineAir, baby_bear_poseidon2::BabyBearPoseidon2, InnerChallenge, ShardCommitment,
+    ShardProof, StarkMachine, StarkVerifyingKey,
+};
+
+use crate::{
+    challenger::{CanObserveVariable, DuplexChallengerVariable},
+    constraints::RecursiveVerifierConstraintFolder,
+    stark::StarkVerifier,
+    witness::{WitnessBlock, Witnessable},
+};
+
+/// The brand of the handles of a builder, invariant in its lifetime.
+type Brand<'b> = PhantomData<fn(&'b ()) -> &'b ()>;
+
+/// A field element of the program being built by a [RecursionDsl].
+#[derive(Debug, Clone, Copy)]
+pub struct DslFelt<'b>(Felt<BabyBear>, Brand<'b>);
+
+/// An extension field element of the program being built by a [RecursionDsl].
+#[derive(Debug, Clone, Copy)]
+pub struct DslExt<'b>(Ext<BabyBear, InnerChallenge>, Brand<'b>);
+
+/// A compiled recursion program with the witness stream of its inputs.
+#[derive(Debug, Clone)]
+pub struct DslProgram {
+    /// The compiled program.
+    pub program: RecursionProgram<BabyBear>,
+    /// The witness stream to run the program with.
+    pub witness: Vec<WitnessBlock<InnerConfig>>,
+}
+
+/// A builder of a recursion program, handed out by [build_program].
+pub struct RecursionDsl<'b> {
+    builder: Builder<InnerConfig>,
+    witness: Vec<WitnessBlock<InnerConfig>>,
+    _brand: Brand<'b>,
+}
+
+/// Builds and compiles a recursion program.
+pub fn build_program(f: impl for<'b> FnOnce(&mut RecursionDsl<'b>)) -> DslProgram {
+    let mut dsl =
+        RecursionDsl { builder: Builder::default(), witness: Vec::new(), _brand: PhantomData };
+    f(&mut dsl);
+    let program = AsmCompiler::<InnerConfig>::default().compile(dsl.builder.operations);
+    DslProgram { program, witness: dsl.witness }
+}
+
+impl<'b> RecursionDsl<'b> {
+    fn felt_handle(&self, felt: Felt<BabyBear>) -> DslFelt<'b> {
+        DslFelt(felt, PhantomData)
+    }
+
+    fn ext_handle(&self, ext: Ext<BabyBear, InnerChallenge>) -> DslExt<'b> {
+        DslExt(ext, PhantomData)
+    }
+
+    /// Returns a constant field element.
+    pub fn felt(&mut self, value: BabyBear) -> DslFelt<'b> {
+        let felt = self.builder.constant(value);
+        self.felt_handle(felt)
+    }
+
+    /// Returns a constant extension field element.
+    pub fn ext(&mut self, value: InnerChallenge) -> DslExt<'b> {
+        let ext = self.builder.constant(value);
+        self.ext_handle(ext)
+    }
+
+    /// Reads a field element input of the program.
+    pub fn input_felt(&mut self, value: BabyBear) -> DslFelt<'b> {
+        Witnessable::<InnerConfig>::write(&value, &mut self.witness);
+        let felt = value.read(&mut self.builder);
+        self.felt_handle(felt)
+    }
+
+    /// Reads field element inputs of the program.
+    pub fn input_felts(&mut self, values: &[BabyBear]) -> Vec<DslFelt<'b>> {
+        values.iter().map(|value| self.input_felt(*value)).collect()
+    }
+
+    /// Reads an extension field element input of the program.
+    pub fn input_ext(&mut self, value: InnerChallenge) -> DslExt<'b> {
+        Witnessable::<InnerConfig>::write(&value, &mut self.witness);
+        let ext = value.read(&mut self.builder);
+        self.ext_handle(ext)
+    }
+
+    /// Returns `a + b`.
+    pub fn add(&mut self, a: DslFelt<'b>, b: DslFelt<'b>) -> DslFelt<'b> {
+        let felt = self.builder.eval(a.0 + b.0);
+        self.felt_handle(felt)
+    }
+
+    /// Returns `a - b`.
+    pub fn sub(&mut self, a: DslFelt<'b>, b: DslFelt<'b>) -> DslFelt<'b> {
+        let felt = self.builder.eval(a.0 - b.0);
+        self.felt_handle(felt)
+    }
+
+    /// Returns `a * b`.
+    pub fn mul(&mut self, a: DslFelt<'b>, b: DslFelt<'b>) -> DslFelt<'b> {
+        let felt = self.builder.eval(a.0 * b.0);
+        self.felt_handle(felt)
+    }
+
+    /// Returns `a / b`. The program fails if `b` is zero.
+    pub fn div(&mut self, a: DslFelt<'b>, b: DslFelt<'b>) -> DslFelt<'b> {
+        let felt = self.builder.eval(a.0 / b.0);
+        self.felt_handle(felt)
+    }
+
+    /// Returns `-a`.
+    pub fn neg(&mut self, a: DslFelt<'b>) -> DslFelt<'b> {
+        let felt = self.builder.eval(-a.0);
+        self.felt_handle(felt)
+    }
+
+    /// Returns `a + b`.
+    pub fn ext_add(&mut self, a: DslExt<'b>, b: DslExt<'b>) -> DslExt<'b> {
+        let ext = self.builder.eval(a.0 + b.0);
+        self.ext_handle(ext)
+    }
+
+    /// Returns `a - b`.
+    pub fn ext_sub(&mut self, a: DslExt<'b>, b: DslExt<'b>) -> DslExt<'b> {
+        let ext = self.builder.eval(a.0 - b.0);
+        self.ext_handle(ext)
+    }
+
+    /// Returns `a * b`.
+    pub fn ext_mul(&mut self, a: DslExt<'b>, b: DslExt<'b>) -> DslExt<'b> {
+        let ext = self.builder.eval(a.0 * b.0);
+        self.ext_handle(ext)
+    }
+
+    /// Returns `a / b`. The program fails if `b` is zero.
+    pub fn ext_div(&mut self, a: DslExt<'b>, b: DslExt<'b>) -> DslExt<'b> {
+        let ext = self.builder.eval(a.0 / b.0);
+        self.ext_handle(ext)
+    }
+
+    /// Returns `-a`.
+    pub fn ext_neg(&mut self, a: DslExt<'b>) -> DslExt<'b> {
+        let ext = self.builder.eval(-a.0);
+        self.ext_handle(ext)
+    }
+
+    /// Returns `a * b` for an extension element `a` and a field element `b`.
+    pub fn ext_mul_felt(&mut self, a: DslExt<'b>, b: DslFelt<'b>) -> DslExt<'b> {
+        let ext = self.builder.eval(a.0 * b.0);
+        self.ext_handle(ext)
+    }
+
+    /// Returns the coordinates of an extension element in the base field.
+    pub fn ext_to_felts(&mut self, a: DslExt<'b>) -> Vec<DslFelt<'b>> {
+        let felts = self.builder.ext2felt_v2(a.0);
+        felts.into_iter().map(|felt| self.felt_handle(felt)).collect()
+    }
+
+    /// Constrains `a` and `b` to be equal.
+    pub fn assert_eq(&mut self, a: DslFelt<'b>, b: DslFelt<'b>) {
+        self.builder.assert_felt_eq(a.0, b.0);
+    }
+
+    /// Constrains `a` and `b` to be different.
+    pub fn assert_ne(&mut self, a: DslFelt<'b>, b: DslFelt<'b>) {
+        self.builder.assert_felt_ne(a.0, b.0);
+    }
+
+    /// Constrains `a` and `b` to be equal.
+    pub fn assert_ext_eq(&mut self, a: DslExt<'b>, b: DslExt<'b>) {
+        self.builder.assert_ext_eq(a.0, b.0);
+    }
+
+    /// Constrains `a` and `b` to be different.
+    pub fn assert_ext_ne(&mut self, a: DslExt<'b>, b: DslExt<'b>) {
+        self.builder.assert_ext_ne(a.0, b.0);
+    }
+
+    /// Returns the Poseidon2 hash of the inputs.
+    pub fn poseidon2_hash(&mut self, inputs: &[DslFelt<'b>]) -> [DslFelt<'b>; DIGEST_SIZE] {
+        let inputs = inputs.iter().map(|input| input.0).collect::<Vec<_>>();
+        let digest = self.builder.poseidon2_hash_v2(&inputs);
+        digest.map(|felt| self.felt_handle(felt))
+    }
+
+    /// Returns the Poseidon2 compression of two digests, as in the nodes of a Merkle tree.
+    pub fn poseidon2_compress(
+        &mut self,
+        left: [DslFelt<'b>; DIGEST_SIZE],
+        right: [DslFelt<'b>; DIGEST_SIZE],
+    ) -> [DslFelt<'b>; DIGEST_SIZE] {
+        let inputs = left.iter().chain(right.iter()).map(|input| input.0).collect::<Vec<_>>();
+        let digest = self.builder.poseidon2_compress_v2(inputs);
+        digest.map(|felt| self.felt_handle(felt))
+    }
+
+    /// Verifies a shard proof of `machine` against `vk`, and returns its public values.
+    ///
+    /// The verifying key and the proof are inputs of the program. The challenger observes the
+    /// verifying key, the main commitment and the public values of the shard before verifying it,
+    /// as for a program made of a single shard.
+    pub fn verify_shard<A>(
+        &mut self,
+        machine: &StarkMachine<BabyBearPoseidon2, A>,
+        vk: &StarkVerifyingKey<BabyBearPoseidon2>,
+        proof: &ShardProof<BabyBearPoseidon2>,
+    ) -> Vec<DslFelt<'b>>
+    where
+        A: MachineAir<BabyBear> + for<'a> Air<RecursiveVerifierConstraintFolder<'a, InnerConfig>>,
+    {
+        let builder = &mut self.builder;
+        Witnessable::<InnerConfig>::write(vk, &mut self.witness);
+        let vk = vk.read(builder);
+        Witnessable::<InnerConfig>::write(proof, &mut self.witness);
+        let proof = proof.read(builder);
+
+        let mut challenger = DuplexChallengerVariable::new(builder);
+        vk.observe_into(builder, &mut challenger);
+        let ShardCommitment { main_commit, .. } = proof.commitment;
+        challenger.observe(builder, main_commit);
+        let public_values = &proof.public_values[..machine.num_pv_elts()];
+        challenger.observe_slice(builder, public_values.iter().copied());
+
+        StarkVerifier::verify_shard(builder, &vk, machine, &mut challenger, &proof);
+        public_values.iter().map(|felt| DslFelt(*felt, PhantomData)).collect()
+    }
+
+    /// Returns the field element one.
+    pub fn one(&mut self) -> DslFelt<'b> {
+        self.felt(BabyBear::one())
+    }
+
+    /// Returns the field element zero.
+    pub fn zero(&mut self) -> DslFelt<'b> {
+        self.felt(BabyBear::zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use p3_baby_bear::DiffusionMatrixBabyBear;
+    use p3_field::AbstractExtensionField;
+    use sp1_recursion_core_v2::Runtime;
+
+    use super::*;
+
+    fn run(
+        program: DslProgram,
+    ) -> Result<(), sp1_recursion_core_v2::RuntimeError<BabyBear, InnerChallenge>> {
+        let perm = BabyBearPoseidon2::new().perm;
+        let mut runtime = Runtime::<BabyBear, InnerChallenge, DiffusionMatrixBabyBear>::new(
+            Arc::new(program.program),
+            perm,
+        );
+        runtime.witness_stream.extend(program.witness);
+        runtime.run()
+    }
+
+    #[test]
+    fn test_dsl_arithmetic() {
+        let program = build_program(|b| {
+            let inputs = b.input_felts(&[BabyBear::from_canonical_u32(3), BabyBear::two()]);
+            let product = b.mul(inputs[0], inputs[1]);
+            let quotient = b.div(product, inputs[1]);
+            b.assert_eq(quotient, inputs[0]);
+            let six = b.felt(BabyBear::from_canonical_u32(6));
+            b.assert_eq(product, six);
+            b.assert_ne(product, inputs[0]);
+
+            let x = b.input_ext(InnerChallenge::from_base_slice(&[BabyBear::two(); 4]));
+            let y = b.ext_mul_felt(x, inputs[0]);
+            let z = b.ext_add(x, x);
+            let w = b.ext_add(z, x);
+            b.assert_ext_eq(y, w);
+
+            let digest = b.poseidon2_hash(&inputs);
+            let again = b.poseidon2_hash(&inputs);
+            for (a, b_) in digest.into_iter().zip(again) {
+                b.assert_eq(a, b_);
+            }
+        });
+        run(program).unwrap();
+
+        let program = build_program(|b| {
+            let x = b.input_felt(BabyBear::one());
+            let y = b.zero();
+            b.assert_eq(x, y);
+        });
+        assert!(run(program).is_err());
+    }
+}
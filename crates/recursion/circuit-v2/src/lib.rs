@@ -25,6 +25,7 @@ pub mod build_wrap_v2;
 pub mod challenger;
 pub mod constraints;
 pub mod domain;
+pub mod dsl;
 pub mod fri;
 pub mod hash;
 pub mod machine;
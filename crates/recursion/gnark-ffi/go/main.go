@@ -20,6 +20,7 @@ import (
 	"encoding/json"
 	"fmt"
 	"os"
+	"strings"
 	"sync"
 
 	"github.com/consensys/gnark-crypto/ecc"
@@ -138,6 +139,21 @@ func VerifyGroth16Bn254(dataDir *C.char, proof *C.char, vkeyHash *C.char, commit
 	return nil
 }
 
+//export VerifyGroth16BatchBn254
+func VerifyGroth16BatchBn254(dataDir *C.char, proofs *C.char, vkeyHashes *C.char, committedValuesDigests *C.char) *C.char {
+	// The proofs, vkey hashes and digests are passed as comma-separated lists.
+	dataDirString := C.GoString(dataDir)
+	proofStrings := strings.Split(C.GoString(proofs), ",")
+	vkeyHashStrings := strings.Split(C.GoString(vkeyHashes), ",")
+	committedValuesDigestStrings := strings.Split(C.GoString(committedValuesDigests), ",")
+
+	err := sp1.VerifyGroth16Batch(dataDirString, proofStrings, vkeyHashStrings, committedValuesDigestStrings)
+	if err != nil {
+		return C.CString(err.Error())
+	}
+	return nil
+}
+
 //export TestGroth16Bn254
 func TestGroth16Bn254(witnessJson *C.char, constraintsJson *C.char) *C.char {
 	// Because of the global env variables used here, we need to lock this function
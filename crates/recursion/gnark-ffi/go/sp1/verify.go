@@ -2,11 +2,17 @@ package sp1
 
 import (
 	"bytes"
+	"crypto/rand"
 	"encoding/hex"
+	"fmt"
+	"math/big"
 	"os"
 
 	"github.com/consensys/gnark-crypto/ecc"
+	curve "github.com/consensys/gnark-crypto/ecc/bn254"
+	"github.com/consensys/gnark-crypto/ecc/bn254/fr"
 	"github.com/consensys/gnark/backend/groth16"
+	groth16_bn254 "github.com/consensys/gnark/backend/groth16/bn254"
 	"github.com/consensys/gnark/backend/plonk"
 	"github.com/consensys/gnark/frontend"
 	"github.com/succinctlabs/sp1-recursion-gnark/sp1/babybear"
@@ -103,3 +109,122 @@ func VerifyGroth16(verifyCmdDataDir string, verifyCmdProof string, verifyCmdVkey
 	err = groth16.Verify(proof, vk, publicWitness)
 	return err
 }
+
+// VerifyGroth16Batch verifies several Groth16 proofs of the SP1 circuit with a single pairing check.
+//
+// Each proof i is weighted by a random scalar r_i, and the verification equations
+//
+//	e(A_i, B_i) = e(alpha, beta) * e(L_i, gamma) * e(C_i, delta)
+//
+// are combined into one product of len(proofs) + 3 pairings, where L_i is the commitment to the
+// public inputs of proof i. A batch of invalid proofs passes with negligible probability.
+func VerifyGroth16Batch(dataDir string, proofs []string, vkeyHashes []string, committedValuesDigests []string) error {
+	if len(proofs) != len(vkeyHashes) || len(proofs) != len(committedValuesDigests) {
+		return fmt.Errorf("got %d proofs, %d vkey hashes and %d committed values digests", len(proofs), len(vkeyHashes), len(committedValuesDigests))
+	}
+	if len(proofs) == 0 {
+		return nil
+	}
+
+	// Read the verifier key.
+	vkFile, err := os.Open(dataDir + "/" + groth16VkPath)
+	if err != nil {
+		panic(err)
+	}
+	vk := groth16.NewVerifyingKey(ecc.BN254)
+	vk.ReadFrom(vkFile)
+	bn254Vk := vk.(*groth16_bn254.VerifyingKey)
+	if len(bn254Vk.G1.K) != 3 || len(bn254Vk.PublicAndCommitmentCommitted) != 0 {
+		return fmt.Errorf("unexpected verifying key with %d public inputs", len(bn254Vk.G1.K)-1)
+	}
+
+	g1Points := make([]curve.G1Affine, 0, len(proofs)+3)
+	g2Points := make([]curve.G2Affine, 0, len(proofs)+3)
+	var rSum fr.Element
+	var lSum, cSum curve.G1Jac
+	for i := range proofs {
+		proofDecodedBytes, err := hex.DecodeString(proofs[i])
+		if err != nil {
+			return err
+		}
+		proof := groth16.NewProof(ecc.BN254)
+		if _, err := proof.ReadFrom(bytes.NewReader(proofDecodedBytes)); err != nil {
+			return err
+		}
+		p := proof.(*groth16_bn254.Proof)
+		if len(p.Commitments) != 0 {
+			return fmt.Errorf("proof %d has commitments, which are not supported in batches", i)
+		}
+		if !p.Ar.IsInSubGroup() || !p.Bs.IsInSubGroup() || !p.Krs.IsInSubGroup() {
+			return fmt.Errorf("proof %d has a point outside of the subgroup", i)
+		}
+
+		var vkeyHash, committedValuesDigest fr.Element
+		if _, err := vkeyHash.SetString(vkeyHashes[i]); err != nil {
+			return err
+		}
+		if _, err := committedValuesDigest.SetString(committedValuesDigests[i]); err != nil {
+			return err
+		}
+
+		// Sample the weight of the proof. 128 bits are enough for the soundness of the batch.
+		var rBytes [16]byte
+		if _, err := rand.Read(rBytes[:]); err != nil {
+			return err
+		}
+		r := new(big.Int).SetBytes(rBytes[:])
+		var rFr fr.Element
+		rFr.SetBigInt(r)
+		rSum.Add(&rSum, &rFr)
+
+		// r_i * A_i, paired with B_i.
+		var rA curve.G1Affine
+		rA.ScalarMultiplication(&p.Ar, r)
+		g1Points = append(g1Points, rA)
+		g2Points = append(g2Points, p.Bs)
+
+		// r_i * L_i, where L_i = K_0 + vkeyHash * K_1 + committedValuesDigest * K_2.
+		var l, term curve.G1Jac
+		l.FromAffine(&bn254Vk.G1.K[0])
+		term.ScalarMultiplication(new(curve.G1Jac).FromAffine(&bn254Vk.G1.K[1]), vkeyHash.BigInt(new(big.Int)))
+		l.AddAssign(&term)
+		term.ScalarMultiplication(new(curve.G1Jac).FromAffine(&bn254Vk.G1.K[2]), committedValuesDigest.BigInt(new(big.Int)))
+		l.AddAssign(&term)
+		l.ScalarMultiplication(&l, r)
+		lSum.AddAssign(&l)
+
+		// r_i * C_i.
+		var c curve.G1Jac
+		c.FromAffine(&p.Krs)
+		c.ScalarMultiplication(&c, r)
+		cSum.AddAssign(&c)
+	}
+
+	// The right-hand side, negated so that the whole product must be one.
+	var alphaSum curve.G1Jac
+	alphaSum.FromAffine(&bn254Vk.G1.Alpha)
+	alphaSum.ScalarMultiplication(&alphaSum, rSum.BigInt(new(big.Int)))
+	for _, pair := range []struct {
+		g1 *curve.G1Jac
+		g2 curve.G2Affine
+	}{
+		{&alphaSum, bn254Vk.G2.Beta},
+		{&lSum, bn254Vk.G2.Gamma},
+		{&cSum, bn254Vk.G2.Delta},
+	} {
+		var neg curve.G1Affine
+		neg.FromJacobian(pair.g1)
+		neg.Neg(&neg)
+		g1Points = append(g1Points, neg)
+		g2Points = append(g2Points, pair.g2)
+	}
+
+	ok, err := curve.PairingCheck(g1Points, g2Points)
+	if err != nil {
+		return err
+	}
+	if !ok {
+		return fmt.Errorf("batch verification failed")
+	}
+	return nil
+}
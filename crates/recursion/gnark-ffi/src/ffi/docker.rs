@@ -161,6 +161,23 @@ pub fn verify_groth16_bn254(
     verify(ProofSystem::Groth16, data_dir, proof, vkey_hash, committed_values_digest)
 }
 
+/// Verifies several Groth16 proofs.
+///
+/// The docker image only verifies single proofs, so the proofs are verified one by one.
+pub fn verify_groth16_bn254_batch(
+    data_dir: &str,
+    proofs: &[&str],
+    vkey_hashes: &[&str],
+    committed_values_digests: &[&str],
+) -> Result<()> {
+    for ((proof, vkey_hash), committed_values_digest) in
+        proofs.iter().zip(vkey_hashes).zip(committed_values_digests)
+    {
+        verify_groth16_bn254(data_dir, proof, vkey_hash, committed_values_digest)?;
+    }
+    Ok(())
+}
+
 fn test(system: ProofSystem, witness_json: &str, constraints_json: &str) -> Result<()> {
     let mounts = [(constraints_json, "/constraints"), (witness_json, "/witness")];
     assert_docker();
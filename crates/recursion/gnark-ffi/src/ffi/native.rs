@@ -41,10 +41,7 @@ impl ProofSystem {
         }
     }
 
-    fn verify_fn(
-        &self,
-    ) -> unsafe extern "C" fn(*mut c_char, *mut c_char, *mut c_char, *mut c_char) -> *mut c_char
-    {
+    fn verify_fn(&self) -> VerifyFunction {
         match self {
             ProofSystem::Plonk => bind::VerifyPlonkBn254,
             ProofSystem::Groth16 => bind::VerifyGroth16Bn254,
@@ -97,6 +94,19 @@ fn verify(
     proof: &str,
     vkey_hash: &str,
     committed_values_digest: &str,
+) -> Result<(), String> {
+    call_verify_fn(system.verify_fn(), data_dir, proof, vkey_hash, committed_values_digest)
+}
+
+type VerifyFunction =
+    unsafe extern "C" fn(*mut c_char, *mut c_char, *mut c_char, *mut c_char) -> *mut c_char;
+
+fn call_verify_fn(
+    verify_fn: VerifyFunction,
+    data_dir: &str,
+    proof: &str,
+    vkey_hash: &str,
+    committed_values_digest: &str,
 ) -> Result<(), String> {
     let data_dir = CString::new(data_dir).expect("CString::new failed");
     let proof = CString::new(proof).expect("CString::new failed");
@@ -105,7 +115,7 @@ fn verify(
         CString::new(committed_values_digest).expect("CString::new failed");
 
     let err_ptr = unsafe {
-        (system.verify_fn())(
+        (verify_fn)(
             data_dir.as_ptr() as *mut c_char,
             proof.as_ptr() as *mut c_char,
             vkey_hash.as_ptr() as *mut c_char,
@@ -183,6 +193,22 @@ pub fn verify_groth16_bn254(
     verify(ProofSystem::Groth16, data_dir, proof, vkey_hash, committed_values_digest)
 }
 
+/// Verifies several Groth16 proofs with a single pairing check.
+pub fn verify_groth16_bn254_batch(
+    data_dir: &str,
+    proofs: &[&str],
+    vkey_hashes: &[&str],
+    committed_values_digests: &[&str],
+) -> Result<(), String> {
+    call_verify_fn(
+        bind::VerifyGroth16BatchBn254,
+        data_dir,
+        &proofs.join(","),
+        &vkey_hashes.join(","),
+        &committed_values_digests.join(","),
+    )
+}
+
 pub fn test_groth16_bn254(witness_json: &str, constraints_json: &str) {
     test(ProofSystem::Groth16, witness_json, constraints_json)
 }
@@ -5,7 +5,10 @@ use std::{
 };
 
 use crate::{
-    ffi::{build_groth16_bn254, prove_groth16_bn254, test_groth16_bn254, verify_groth16_bn254},
+    ffi::{
+        build_groth16_bn254, prove_groth16_bn254, test_groth16_bn254, verify_groth16_bn254,
+        verify_groth16_bn254_batch,
+    },
     witness::GnarkWitness,
     Groth16Bn254Proof,
 };
@@ -124,6 +127,36 @@ impl Groth16Bn254Prover {
         .expect("failed to verify proof")
     }
 
+    /// Verify several Groth16 proofs at once, each with its vkey_hash and committed_values_digest.
+    ///
+    /// With the native bindings, the proofs share a single pairing check, which is much cheaper
+    /// than verifying them one by one.
+    pub fn verify_batch(
+        &self,
+        proofs: &[(&Groth16Bn254Proof, BigUint, BigUint)],
+        build_dir: &Path,
+    ) {
+        let groth16_vkey_hash = Self::get_vkey_hash(build_dir);
+        if proofs.iter().any(|(proof, _, _)| proof.groth16_vkey_hash != groth16_vkey_hash) {
+            panic!(
+                "Proof vkey hash does not match circuit vkey hash, it was generated with a different circuit."
+            );
+        }
+        let raw_proofs =
+            proofs.iter().map(|(proof, _, _)| proof.raw_proof.as_str()).collect::<Vec<_>>();
+        let vkey_hashes =
+            proofs.iter().map(|(_, vkey_hash, _)| vkey_hash.to_string()).collect::<Vec<_>>();
+        let committed_values_digests =
+            proofs.iter().map(|(_, _, digest)| digest.to_string()).collect::<Vec<_>>();
+        verify_groth16_bn254_batch(
+            build_dir.to_str().unwrap(),
+            &raw_proofs,
+            &vkey_hashes.iter().map(String::as_str).collect::<Vec<_>>(),
+            &committed_values_digests.iter().map(String::as_str).collect::<Vec<_>>(),
+        )
+        .expect("failed to verify proofs")
+    }
+
     /// Modify the Groth16Verifier so that it works with the SP1Verifier.
     fn modify_groth16_verifier(file_path: &Path) {
         let mut content = String::new();
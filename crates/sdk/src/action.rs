@@ -1,18 +1,40 @@
-use sp1_core_executor::{ExecutionReport, HookEnv, SP1ContextBuilder};
+use sp1_core_executor::{
+    ExecutionProgress, ExecutionReport, HookEnv, MemoTable, ProfilerOpts, ProgressControl,
+    SP1ContextBuilder, SyscallAuditOpts,
+};
 use sp1_core_machine::io::{SP1PublicValues, SP1Stdin};
-use sp1_prover::{components::DefaultProverComponents, SP1ProvingKey};
+use sp1_prover::{components::DefaultProverComponents, CoreSC, SP1ProvingKey};
 
-use anyhow::{Ok, Result};
-use sp1_stark::{SP1CoreOpts, SP1ProverOpts};
-use std::time::Duration;
+use anyhow::{anyhow, bail, ensure, Ok, Result};
+use sp1_stark::{SP1CoreOpts, SP1ProverOpts, ShardProof};
+use std::{
+    num::NonZeroU64,
+    path::PathBuf,
+    sync::{
+        mpsc::{self, Sender},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
 
-use crate::{provers::ProofOpts, Prover, SP1ProofKind, SP1ProofWithPublicValues};
+use crate::{
+    commitment::ExecutionCommitment,
+    determinism::{self, DeterminismReport},
+    install::ArtifactsOpts,
+    pool::ProveFuture,
+    provers::ProofOpts,
+    report::ProveReport,
+    session::Session,
+    Prover, SP1ProofKind, SP1ProofWithPublicValues,
+};
 
 /// Builder to prepare and configure execution of a program on an input.
 /// May be run with [Self::run].
 pub struct Execute<'a> {
     prover: &'a dyn Prover<DefaultProverComponents>,
     context_builder: SP1ContextBuilder<'a>,
+    memo_table: Option<MemoTable>,
     elf: &'a [u8],
     stdin: SP1Stdin,
 }
@@ -27,14 +49,44 @@ impl<'a> Execute<'a> {
         elf: &'a [u8],
         stdin: SP1Stdin,
     ) -> Self {
-        Self { prover, elf, stdin, context_builder: Default::default() }
+        Self { prover, elf, stdin, context_builder: Default::default(), memo_table: None }
     }
 
     /// Execute the program on the input, consuming the built action `self`.
     pub fn run(self) -> Result<(SP1PublicValues, ExecutionReport)> {
-        let Self { prover, elf, stdin, mut context_builder } = self;
+        let Self { prover, elf, stdin, mut context_builder, memo_table } = self;
         let context = context_builder.build();
-        Ok(prover.sp1_prover().execute(elf, &stdin, context)?)
+        let output = prover.sp1_prover().execute(elf, &stdin, context)?;
+        if let Some(table) = memo_table {
+            table.commit();
+        }
+        Ok(output)
+    }
+
+    /// Start an interactive [Session] that pauses each time the program yields to the host,
+    /// instead of running the program to completion.
+    pub fn interactive(self) -> Result<Session<'a>> {
+        let Self { prover, elf, stdin, mut context_builder, .. } = self;
+        Session::new(prover, elf, stdin, context_builder.build())
+    }
+
+    /// Execute the program twice, perturbing the host in the second execution, and report any
+    /// difference between them, see [crate::determinism].
+    ///
+    /// Both executions are served by the memo table, if any, and thus look up the same committed
+    /// values. The values they store are committed only if the executions agree.
+    pub fn check_determinism(self) -> Result<DeterminismReport> {
+        let Self { prover, elf, stdin, context_builder, memo_table } = self;
+        let report = determinism::check_determinism(prover, elf, &stdin, context_builder)?;
+        if let Some(table) = memo_table.filter(|_| report.is_deterministic()) {
+            table.commit();
+        }
+        Ok(report)
+    }
+
+    /// Also compute an [ExecutionCommitment] to the execution when running.
+    pub fn with_commitment(self) -> ExecuteWithCommitment<'a> {
+        ExecuteWithCommitment { execute: self }
     }
 
     /// Add a runtime [Hook](super::Hook) into the context.
@@ -51,6 +103,46 @@ impl<'a> Execute<'a> {
         self
     }
 
+    /// Add an asynchronous runtime [Hook](super::Hook) into the context.
+    ///
+    /// The hook runs on a separate thread when the program calls `sp1_zkvm::io::hint_request`,
+    /// and its result is read with `sp1_zkvm::io::hint_await`.
+    pub fn with_async_hook(
+        mut self,
+        fd: u32,
+        f: impl Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static,
+    ) -> Self {
+        self.context_builder.async_hook(fd, f);
+        self
+    }
+
+    /// Serve the memo lookups and stores of the program from `table`.
+    ///
+    /// The values stored by the program are committed to the table once the execution succeeds,
+    /// so that later executions can look them up. Values stored during an interactive session are
+    /// left pending.
+    pub fn with_memo_table(mut self, table: &MemoTable) -> Self {
+        self.context_builder.memo_table(table);
+        self.memo_table = Some(table.clone());
+        self
+    }
+
+    /// Record every syscall made by the program, with its arguments and a bounded dump of the
+    /// memory they reference, to the JSON lines log described by `opts`.
+    pub fn with_syscall_audit(mut self, opts: SyscallAuditOpts) -> Self {
+        self.context_builder.syscall_audit(opts);
+        self
+    }
+
+    /// Sample the call stack of the program every `sample_rate` cycles, and write the samples to
+    /// `path` as folded stacks, which `inferno-flamegraph` or `flamegraph.pl` render as a
+    /// flamegraph.
+    pub fn with_profiler(mut self, path: impl Into<PathBuf>, sample_rate: NonZeroU64) -> Self {
+        self.context_builder
+            .profiler(ProfilerOpts::new(self.elf, path).with_sample_rate(sample_rate));
+        self
+    }
+
     /// Avoid registering the default hooks in the runtime.
     ///
     /// It is not necessary to call this to override hooks --- instead, simply
@@ -68,6 +160,73 @@ impl<'a> Execute<'a> {
         self.context_builder.max_cycles(max_cycles);
         self
     }
+
+    /// Set the maximum number of bytes the program may commit to its public values.
+    ///
+    /// If the limit is exceeded, execution will return
+    /// [sp1_core_machine::runtime::ExecutionError::PublicValuesTooLarge] with the program counter
+    /// of the commit.
+    pub fn max_public_values_size(mut self, max: usize) -> Self {
+        self.context_builder.max_public_values_size(max);
+        self
+    }
+
+    /// Set the maximum number of bytes of input, including the hints and hook results added
+    /// during the execution.
+    ///
+    /// If the limit is exceeded, execution will return
+    /// [sp1_core_machine::runtime::ExecutionError::InputTooLarge].
+    pub fn max_input_size(mut self, max: usize) -> Self {
+        self.context_builder.max_input_size(max);
+        self
+    }
+
+    /// Compile the hot blocks of the program to native code while executing it.
+    ///
+    /// Requires the `jit` feature, and is ignored otherwise.
+    pub fn jit(mut self) -> Self {
+        self.context_builder.jit();
+        self
+    }
+
+    /// Invoke `f` every `interval` cycles with the program counter, clock and number of committed
+    /// bytes of the execution.
+    ///
+    /// Returning [ProgressControl::Abort] stops the execution, which then returns
+    /// [sp1_core_machine::runtime::ExecutionError::Aborted].
+    pub fn progress_callback(
+        mut self,
+        interval: NonZeroU64,
+        f: impl Fn(ExecutionProgress) -> ProgressControl + Send + Sync + 'a,
+    ) -> Self {
+        self.context_builder.progress_callback(interval, f);
+        self
+    }
+}
+
+/// Builder to execute a program and commit to the execution, created by
+/// [Execute::with_commitment]. May be run with [Self::run].
+pub struct ExecuteWithCommitment<'a> {
+    execute: Execute<'a>,
+}
+
+impl<'a> ExecuteWithCommitment<'a> {
+    /// Execute the program on the input, returning an [ExecutionCommitment] to the program, the
+    /// input, the public values and the cycle count alongside the usual outputs.
+    ///
+    /// The commitment can be signed by the host with [ExecutionCommitment::sign].
+    pub fn run(self) -> Result<(SP1PublicValues, ExecutionReport, ExecutionCommitment)> {
+        let Self { execute: Execute { prover, elf, stdin, mut context_builder, memo_table } } =
+            self;
+        let context = context_builder.build();
+        let (public_values, report) = prover.sp1_prover().execute(elf, &stdin, context)?;
+        if let Some(table) = memo_table {
+            table.commit();
+        }
+        let commitment =
+            ExecutionCommitment::new(elf, &stdin, &public_values, report.total_instruction_count());
+        Ok((public_values, report, commitment))
+    }
 }
 
 /// Builder to prepare and configure proving execution of a program on an input.
@@ -76,15 +235,22 @@ pub struct Prove<'a> {
     prover: &'a dyn Prover<DefaultProverComponents>,
     kind: SP1ProofKind,
     context_builder: SP1ContextBuilder<'a>,
+    memo_table: Option<MemoTable>,
     pk: &'a SP1ProvingKey,
     stdin: SP1Stdin,
     core_opts: SP1CoreOpts,
     recursion_opts: SP1CoreOpts,
     timeout: Option<Duration>,
+    artifacts_opts: ArtifactsOpts,
+    checkpoint_dir: Option<PathBuf>,
+    allow_failure: bool,
+    shard_proof_sender: Option<Sender<ShardProof<CoreSC>>>,
+    shared_prover: Option<Arc<dyn Prover<DefaultProverComponents>>>,
 }
 
 impl<'a> Prove<'a> {
-    /// Prepare to prove the execution of the given program with the given input.
+    /// Prepare to prove the execution of the given program with the given input, starting from the
+    /// [Prover::default_opts] of the prover.
     ///
     /// Prefer using [ProverClient::prove](super::ProverClient::prove).
     /// See there for more documentation.
@@ -93,35 +259,166 @@ impl<'a> Prove<'a> {
         pk: &'a SP1ProvingKey,
         stdin: SP1Stdin,
     ) -> Self {
+        let opts = prover.default_opts();
         Self {
             prover,
             kind: Default::default(),
             pk,
             stdin,
             context_builder: Default::default(),
-            core_opts: SP1CoreOpts::default(),
-            recursion_opts: SP1CoreOpts::recursion(),
-            timeout: None,
+            memo_table: None,
+            core_opts: opts.sp1_prover_opts.core_opts,
+            recursion_opts: opts.sp1_prover_opts.recursion_opts,
+            timeout: opts.timeout,
+            artifacts_opts: opts.artifacts_opts,
+            checkpoint_dir: opts.checkpoint_dir,
+            allow_failure: false,
+            shard_proof_sender: None,
+            shared_prover: None,
+        }
+    }
+
+    /// Keep a shared reference to the prover, which lets [Self::run_async] move the proof to
+    /// another thread.
+    pub(crate) fn with_shared_prover(
+        mut self,
+        prover: Arc<dyn Prover<DefaultProverComponents>>,
+    ) -> Self {
+        self.shared_prover = Some(prover);
+        self
+    }
+
+    /// Check the options and apply them to the context, returning the options of the prover.
+    ///
+    /// The memo table is registered by the caller, once the context is on the proving thread.
+    fn prepare(&mut self) -> Result<ProofOpts> {
+        ensure!(
+            !self.allow_failure || self.kind == SP1ProofKind::Core,
+            "only core proofs can prove that a program fails"
+        );
+        if let Some(max_stdin_size) = self.pk.vk.max_stdin_size {
+            let size = self.stdin.buffer_size() as u64;
+            ensure!(
+                size <= max_stdin_size,
+                "the input of {} bytes exceeds the maximum of {} bytes declared by the program",
+                size,
+                max_stdin_size
+            );
+        }
+        if self.allow_failure {
+            self.context_builder.allow_failure();
+        }
+        if let Some(sender) = self.shard_proof_sender.take() {
+            self.context_builder.shard_proof_sender(sender);
         }
+        let opts = SP1ProverOpts { core_opts: self.core_opts, recursion_opts: self.recursion_opts };
+        Ok(ProofOpts {
+            sp1_prover_opts: opts,
+            timeout: self.timeout,
+            artifacts_opts: self.artifacts_opts.clone(),
+            checkpoint_dir: self.checkpoint_dir.clone(),
+        })
     }
 
     /// Prove the execution of the program on the input, consuming the built action `self`.
-    pub fn run(self) -> Result<SP1ProofWithPublicValues> {
-        let Self {
-            prover,
-            kind,
-            pk,
-            stdin,
-            mut context_builder,
-            core_opts,
-            recursion_opts,
-            timeout,
-        } = self;
-        let opts = SP1ProverOpts { core_opts, recursion_opts };
-        let proof_opts = ProofOpts { sp1_prover_opts: opts, timeout };
+    pub fn run(mut self) -> Result<SP1ProofWithPublicValues> {
+        let proof_opts = self.prepare()?;
+        let Self { prover, kind, pk, stdin, mut context_builder, memo_table, .. } = self;
+        if let Some(table) = &memo_table {
+            context_builder.memo_table(table);
+        }
         let context = context_builder.build();
 
-        prover.prove(pk, stdin, proof_opts, context, kind)
+        let proof = prover.prove(pk, stdin, proof_opts, context, kind)?;
+        if let Some(table) = memo_table {
+            table.commit();
+        }
+        Ok(proof)
+    }
+
+    /// Like [Self::run], but proves on the thread pool of
+    /// [ProverClient::prove_async](super::ProverClient::prove_async) instead of blocking the
+    /// calling thread, and returns a [ProveFuture] that resolves to the proof.
+    ///
+    /// [ProveFuture::handle] returns a [ProofHandle](crate::ProofHandle) to poll the status of
+    /// the proof, including the number of core shards proven so far, or to cancel it. Dropping
+    /// the future also cancels the proof.
+    ///
+    /// The action must come from [ProverClient::prove](super::ProverClient::prove), and its
+    /// context can't borrow from the caller: hooks must be added with [Self::with_async_hook]
+    /// rather than [Self::with_hook].
+    ///
+    /// ### Examples
+    /// ```no_run
+    /// use sp1_sdk::{ProveStatus, ProverClient, SP1Stdin};
+    ///
+    /// # async fn prove() {
+    /// let elf = include_bytes!("../../../examples/fibonacci/program/elf/riscv32im-succinct-zkvm-elf");
+    /// let client = ProverClient::new();
+    /// let (pk, vk) = client.setup(elf);
+    /// let mut stdin = SP1Stdin::new();
+    /// stdin.write(&10usize);
+    /// let future = client.prove(&pk, stdin).compressed().run_async().unwrap();
+    /// let handle = future.handle();
+    ///
+    /// // The handle can be sent to another task to report the progress or cancel the proof.
+    /// if let ProveStatus::Proving(progress) = handle.status() {
+    ///     println!("proved {} of {} shards", progress.proven, progress.total);
+    /// }
+    /// let proof = future.await.unwrap();
+    /// # }
+    /// ```
+    pub fn run_async(mut self) -> Result<ProveFuture> {
+        let proof_opts = self.prepare()?;
+        let Self { kind, pk, stdin, context_builder, memo_table, shared_prover, .. } = self;
+        let prover = shared_prover.ok_or_else(|| {
+            anyhow!("only the actions created by ProverClient::prove can run asynchronously")
+        })?;
+        let Some(mut context_builder) = context_builder.into_owned() else {
+            bail!(
+                "an asynchronous proof can't use hooks added with `with_hook`, use \
+                 `with_async_hook` instead"
+            );
+        };
+        let pk = pk.clone();
+        Ok(ProveFuture::spawn(move |handle| {
+            if let Some(table) = &memo_table {
+                context_builder.memo_table(table);
+            }
+            let context = context_builder
+                .cancel_flag(handle.cancel_flag())
+                .shard_progress_callback(handle.shard_progress_callback())
+                .build();
+            let proof = prover.prove(&pk, stdin, proof_opts, context, kind)?;
+            if let Some(table) = memo_table {
+                table.commit();
+            }
+            Ok(proof)
+        }))
+    }
+
+    /// Like [Self::run], but also returns a [ProveReport] of the trace cells proven by each chip.
+    ///
+    /// The report is built from the core shard proofs as they are streamed, so it is empty unless
+    /// the proof is generated by the local CPU prover.
+    pub fn run_with_report(mut self) -> Result<(SP1ProofWithPublicValues, ProveReport)> {
+        let (tx, rx) = mpsc::channel();
+        let forward = self.shard_proof_sender.replace(tx);
+        thread::scope(|s| {
+            let report = s.spawn(move || {
+                let mut report = ProveReport::default();
+                for shard_proof in rx {
+                    report.add_shard(&shard_proof);
+                    if let Some(forward) = &forward {
+                        let _ = forward.send(shard_proof);
+                    }
+                }
+                report
+            });
+            let proof = self.run();
+            let report = report.join().expect("the report thread panicked");
+            Ok((proof?, report))
+        })
     }
 
     /// Set the proof kind to the core mode. This is the default.
@@ -148,6 +445,25 @@ impl<'a> Prove<'a> {
         self
     }
 
+    /// Set the proof kind to the tiny mode: a single core shard proof, without recursion, of a
+    /// program that runs in at most [`TINY_MAX_CYCLES`](sp1_prover::tiny::TINY_MAX_CYCLES)
+    /// cycles.
+    pub fn tiny(mut self) -> Self {
+        self.kind = SP1ProofKind::Tiny;
+        self
+    }
+
+    /// Prove the execution even if the program fails, such as by panicking, instead of returning
+    /// [sp1_core_machine::runtime::ExecutionError::HaltWithNonZeroExitCode].
+    ///
+    /// The proof is then verified with the exit code of the failure by
+    /// [ProverClient::verify_failure](super::ProverClient::verify_failure). Only core proofs can
+    /// prove that a program fails.
+    pub fn allow_failure(mut self) -> Self {
+        self.allow_failure = true;
+        self
+    }
+
     /// Add a runtime [Hook](super::Hook) into the context.
     ///
     /// Hooks may be invoked from within SP1 by writing to the specified file descriptor `fd`
@@ -162,6 +478,40 @@ impl<'a> Prove<'a> {
         self
     }
 
+    /// Add an asynchronous runtime [Hook](super::Hook) into the context.
+    ///
+    /// The hook runs on a separate thread when the program calls `sp1_zkvm::io::hint_request`,
+    /// and its result is read with `sp1_zkvm::io::hint_await`.
+    pub fn with_async_hook(
+        mut self,
+        fd: u32,
+        f: impl Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static,
+    ) -> Self {
+        self.context_builder.async_hook(fd, f);
+        self
+    }
+
+    /// Serve the memo lookups and stores of the program from `table`.
+    ///
+    /// The values stored by the program are committed to the table once the proof is generated,
+    /// so that later executions can look them up.
+    ///
+    /// The table is only served by the local provers.
+    pub fn with_memo_table(mut self, table: &MemoTable) -> Self {
+        self.context_builder.memo_table(table);
+        self.memo_table = Some(table.clone());
+        self
+    }
+
+    /// Record every syscall made by the program, with its arguments and a bounded dump of the
+    /// memory they reference, to the JSON lines log described by `opts`.
+    ///
+    /// The log is only written by the local provers.
+    pub fn with_syscall_audit(mut self, opts: SyscallAuditOpts) -> Self {
+        self.context_builder.syscall_audit(opts);
+        self
+    }
+
     /// Avoid registering the default hooks in the runtime.
     ///
     /// It is not necessary to call this to override hooks --- instead, simply
@@ -205,4 +555,30 @@ impl<'a> Prove<'a> {
         self.timeout = Some(timeout);
         self
     }
+
+    /// Set where the circuit artifacts of PLONK and Groth16 proofs are downloaded from.
+    ///
+    /// By default, the artifacts are downloaded from the mirrors, with the headers and the proxy
+    /// of the environment, as described in [ArtifactsOpts].
+    pub fn artifacts_opts(mut self, value: ArtifactsOpts) -> Self {
+        self.artifacts_opts = value;
+        self
+    }
+
+    /// Save the shrink proof of a PLONK or Groth16 proof to `dir` before wrapping it, instead of
+    /// to `SP1_SHRINK_CHECKPOINT_DIR`, see [ShrinkCheckpoint](crate::ShrinkCheckpoint).
+    pub fn checkpoint_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.checkpoint_dir = Some(dir.into());
+        self
+    }
+
+    /// Stream the core shard proofs to `sender`, in order, as soon as each one is generated.
+    ///
+    /// This lets the caller upload or aggregate shard proofs while proving is still in progress,
+    /// instead of waiting for [Self::run] to return. The channel is closed once all core shard
+    /// proofs have been sent. Shard proofs are only streamed by the local CPU prover.
+    pub fn shard_proof_sender(mut self, sender: Sender<ShardProof<CoreSC>>) -> Self {
+        self.shard_proof_sender = Some(sender);
+        self
+    }
 }
@@ -0,0 +1,176 @@
+use std::{fs::File, path::Path};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sp1_prover::{HashableKey, SP1VerifyingKey};
+
+use crate::SP1ProofWithPublicValues;
+
+/// The domain separator of the attestation digest.
+const ATTESTATION_DOMAIN: &[u8] = b"SP1_ATTESTATION_V1";
+
+/// A proof packaged with the digest of its verifying key and a signature of the prover over both,
+/// and over the time it was signed at.
+///
+/// The signature identifies who produced the proof and when, which audit and legal contexts may
+/// require on top of the validity of the proof itself. It is not part of the proof: anyone can
+/// strip it or sign the same proof again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SP1Attestation {
+    /// The attested proof, with its public values.
+    pub proof: SP1ProofWithPublicValues,
+    /// The digest of the verifying key of the program, as returned by [HashableKey::bytes32].
+    pub vkey_digest: String,
+    /// The time of the attestation, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// The address of the secp256k1 key that signed the attestation digest.
+    pub signer: [u8; 20],
+    /// The 65-byte recoverable secp256k1 signature of [Self::signer] over [Self::digest].
+    pub signature: Vec<u8>,
+}
+
+impl SP1Attestation {
+    /// Attests to `proof` of the program with verifying key `vk` at the current time, signing with
+    /// the given secp256k1 private key.
+    #[cfg(feature = "network")]
+    pub fn sign(
+        proof: SP1ProofWithPublicValues,
+        vk: &SP1VerifyingKey,
+        private_key: &str,
+    ) -> Result<Self> {
+        let timestamp =
+            std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?.as_secs();
+        Self::sign_at(proof, vk.bytes32(), timestamp, private_key)
+    }
+
+    #[cfg(feature = "network")]
+    fn sign_at(
+        proof: SP1ProofWithPublicValues,
+        vkey_digest: String,
+        timestamp: u64,
+        private_key: &str,
+    ) -> Result<Self> {
+        use std::str::FromStr;
+
+        use ethers::{
+            signers::{LocalWallet, Signer},
+            types::H256,
+        };
+
+        let wallet = LocalWallet::from_str(private_key)?;
+        let digest = attestation_digest(&proof, &vkey_digest, timestamp)?;
+        let signature = wallet.sign_hash(H256(digest))?;
+        Ok(Self {
+            proof,
+            vkey_digest,
+            timestamp,
+            signer: wallet.address().0,
+            signature: signature.to_vec(),
+        })
+    }
+
+    /// Returns the digest signed by the attestation.
+    ///
+    /// The digest commits to the serialized proof and stdin, the public values, the SP1 version,
+    /// the verifying key digest and the timestamp.
+    pub fn digest(&self) -> Result<[u8; 32]> {
+        attestation_digest(&self.proof, &self.vkey_digest, self.timestamp)
+    }
+
+    /// Verifies that the signature over the attestation digest was produced by [Self::signer].
+    ///
+    /// This does not verify the proof, see
+    /// [ProverClient::verify_attestation](crate::ProverClient::verify_attestation).
+    #[cfg(feature = "network")]
+    pub fn verify_signature(&self) -> Result<()> {
+        use ethers::types::{Signature, H160, H256};
+
+        let signature = Signature::try_from(self.signature.as_slice())?;
+        signature.verify(H256(self.digest()?), H160(self.signer))?;
+        Ok(())
+    }
+
+    /// Saves the attestation to a path.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        bincode::serialize_into(File::create(path)?, self).map_err(Into::into)
+    }
+
+    /// Loads an attestation from a path.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        bincode::deserialize_from(File::open(path)?).map_err(Into::into)
+    }
+}
+
+fn attestation_digest(
+    proof: &SP1ProofWithPublicValues,
+    vkey_digest: &str,
+    timestamp: u64,
+) -> Result<[u8; 32]> {
+    let proof_bytes = bincode::serialize(&proof.proof)?;
+    let stdin_bytes = bincode::serialize(&proof.stdin)?;
+    let mut hasher = Sha256::new();
+    for field in [
+        ATTESTATION_DOMAIN,
+        vkey_digest.as_bytes(),
+        &proof_bytes,
+        &stdin_bytes,
+        proof.public_values.as_slice(),
+        proof.sp1_version.as_bytes(),
+        &timestamp.to_le_bytes(),
+    ] {
+        hasher.update((field.len() as u64).to_le_bytes());
+        hasher.update(field);
+    }
+    Ok(hasher.finalize().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use sp1_core_machine::io::{SP1PublicValues, SP1Stdin};
+    use sp1_prover::PlonkBn254Proof;
+
+    use super::*;
+    use crate::SP1Proof;
+
+    fn proof() -> SP1ProofWithPublicValues {
+        SP1ProofWithPublicValues {
+            proof: SP1Proof::Plonk(PlonkBn254Proof {
+                public_inputs: ["1".to_string(), "2".to_string()],
+                encoded_proof: "00".to_string(),
+                raw_proof: "00".to_string(),
+                plonk_vkey_hash: [0; 32],
+            }),
+            stdin: SP1Stdin::new(),
+            public_values: SP1PublicValues::from(&[1, 2, 3]),
+            sp1_version: "v1".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_attestation_digest_binds_fields() {
+        let digest = attestation_digest(&proof(), "0x01", 100).unwrap();
+        assert_ne!(digest, attestation_digest(&proof(), "0x02", 100).unwrap());
+        assert_ne!(digest, attestation_digest(&proof(), "0x01", 101).unwrap());
+
+        let mut other = proof();
+        other.public_values = SP1PublicValues::from(&[1, 2, 4]);
+        assert_ne!(digest, attestation_digest(&other, "0x01", 100).unwrap());
+
+        let mut other = proof();
+        other.stdin.write(&1u32);
+        assert_ne!(digest, attestation_digest(&other, "0x01", 100).unwrap());
+    }
+
+    #[cfg(feature = "network")]
+    #[test]
+    fn test_signed_attestation_verifies() {
+        let private_key = "0x0000000000000000000000000000000000000000000000000000000000000001";
+        let mut attestation =
+            SP1Attestation::sign_at(proof(), "0x01".to_string(), 100, private_key).unwrap();
+        attestation.verify_signature().unwrap();
+
+        attestation.timestamp += 1;
+        assert!(attestation.verify_signature().is_err());
+    }
+}
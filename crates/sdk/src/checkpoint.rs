@@ -0,0 +1,73 @@
+use std::{
+    env,
+    fs::{self, File},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sp1_core_machine::io::{SP1PublicValues, SP1Stdin};
+use sp1_prover::{HashableKey, InnerSC, SP1ReduceProof, SP1VerifyingKey};
+
+use crate::SP1ProofKind;
+
+/// The output of the shrink stage of a Plonk or Groth16 proof, from which the wrap stage can be
+/// run again without proving the earlier stages.
+///
+/// If [ProofOpts::checkpoint_dir](crate::provers::ProofOpts::checkpoint_dir) is set, which defaults
+/// to [ShrinkCheckpoint::dir], the local provers save it there before wrapping a proof, and remove
+/// it once the proof is done. If the wrap stage fails, the proof can be finished with
+/// [ProverClient::wrap_from](crate::ProverClient::wrap_from). The checkpoint holds the stdin of the
+/// proof, which may be private, so checkpoints are only saved to a directory chosen by the user.
+#[derive(Serialize, Deserialize)]
+pub struct ShrinkCheckpoint {
+    /// The shrink proof.
+    pub proof: SP1ReduceProof<InnerSC>,
+    /// The stdin of the proof.
+    pub stdin: SP1Stdin,
+    /// The public values of the proof.
+    pub public_values: SP1PublicValues,
+    /// The kind of proof to wrap the shrink proof into, either Plonk or Groth16.
+    pub kind: SP1ProofKind,
+}
+
+impl ShrinkCheckpoint {
+    /// Returns the directory of the checkpoints, `SP1_SHRINK_CHECKPOINT_DIR`, if it is set.
+    pub fn dir() -> Option<PathBuf> {
+        env::var_os("SP1_SHRINK_CHECKPOINT_DIR").map(PathBuf::from)
+    }
+
+    /// Returns a new path for the checkpoint of a proof in `dir`, named after its vkey and public
+    /// values. The path ends with a random suffix, so that concurrent proofs of the same program
+    /// and public values do not overwrite the checkpoints of each other.
+    pub fn new_path(&self, dir: &Path, vk: &SP1VerifyingKey) -> Result<PathBuf> {
+        let public_values = hex::encode(&self.public_values.hash()[..8]);
+        let mut suffix = [0u8; 8];
+        getrandom::getrandom(&mut suffix).context("failed to generate a checkpoint name")?;
+        Ok(dir.join(format!(
+            "shrink-{}-{}-{}.bin",
+            vk.bytes32(),
+            public_values,
+            hex::encode(suffix)
+        )))
+    }
+
+    /// Saves the checkpoint to a path.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let file =
+            File::create(path).with_context(|| format!("failed to create {}", path.display()))?;
+        bincode::serialize_into(file, self).map_err(Into::into)
+    }
+
+    /// Loads a checkpoint from a path.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let file =
+            File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+        bincode::deserialize_from(file).map_err(Into::into)
+    }
+}
@@ -0,0 +1,141 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sp1_core_machine::io::{SP1PublicValues, SP1Stdin};
+
+/// A hash commitment to an execution of a program, produced without generating a proof.
+///
+/// The commitment is the root of a Merkle tree whose leaves are the program digest, the input
+/// digest, the public values digest and the cycle count. It can be used as a cheap receipt in
+/// optimistic pipelines that only generate a full proof of the execution when it is challenged.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionCommitment {
+    /// The SHA-256 digest of the program ELF.
+    pub program_digest: [u8; 32],
+    /// The SHA-256 digest of the bincode-serialized stdin.
+    pub stdin_digest: [u8; 32],
+    /// The SHA-256 digest of the committed public values.
+    pub public_values_digest: [u8; 32],
+    /// The number of cycles the execution took.
+    pub cycles: u64,
+}
+
+impl ExecutionCommitment {
+    /// Computes the commitment to an execution of `elf` on `stdin`.
+    pub fn new(elf: &[u8], stdin: &SP1Stdin, public_values: &SP1PublicValues, cycles: u64) -> Self {
+        let stdin_bytes = bincode::serialize(stdin).expect("failed to serialize stdin");
+        Self {
+            program_digest: Sha256::digest(elf).into(),
+            stdin_digest: Sha256::digest(stdin_bytes).into(),
+            public_values_digest: Sha256::digest(public_values.as_slice()).into(),
+            cycles,
+        }
+    }
+
+    /// Returns the Merkle root over (program, input digest, public values, cycle count).
+    pub fn root(&self) -> [u8; 32] {
+        let leaves = [
+            leaf_hash(&self.program_digest),
+            leaf_hash(&self.stdin_digest),
+            leaf_hash(&self.public_values_digest),
+            leaf_hash(&self.cycles.to_le_bytes()),
+        ];
+        node_hash(&node_hash(&leaves[0], &leaves[1]), &node_hash(&leaves[2], &leaves[3]))
+    }
+
+    /// Returns whether this commitment matches the execution of `elf` on `stdin`, yielding
+    /// `public_values` in `cycles` cycles.
+    pub fn matches(
+        &self,
+        elf: &[u8],
+        stdin: &SP1Stdin,
+        public_values: &SP1PublicValues,
+        cycles: u64,
+    ) -> bool {
+        self == &Self::new(elf, stdin, public_values, cycles)
+    }
+
+    /// Signs the commitment root with the given secp256k1 private key.
+    #[cfg(feature = "network")]
+    pub fn sign(self, private_key: &str) -> anyhow::Result<SignedExecutionCommitment> {
+        use std::str::FromStr;
+
+        use ethers::{
+            signers::{LocalWallet, Signer},
+            types::H256,
+        };
+
+        let wallet = LocalWallet::from_str(private_key)?;
+        let signature = wallet.sign_hash(H256(self.root()))?;
+        Ok(SignedExecutionCommitment {
+            commitment: self,
+            signer: wallet.address().0,
+            signature: signature.to_vec(),
+        })
+    }
+}
+
+/// An [ExecutionCommitment] signed by the host that executed the program.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedExecutionCommitment {
+    pub commitment: ExecutionCommitment,
+    /// The address of the secp256k1 key that signed the commitment root.
+    pub signer: [u8; 20],
+    pub signature: Vec<u8>,
+}
+
+impl SignedExecutionCommitment {
+    /// Verifies that the signature over the commitment root was produced by [Self::signer].
+    #[cfg(feature = "network")]
+    pub fn verify(&self) -> anyhow::Result<()> {
+        use ethers::types::{Signature, H160, H256};
+
+        let signature = Signature::try_from(self.signature.as_slice())?;
+        signature.verify(H256(self.commitment.root()), H160(self.signer))?;
+        Ok(())
+    }
+}
+
+fn leaf_hash(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([0u8]);
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([1u8]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commitment_root_binds_fields() {
+        let mut stdin = SP1Stdin::new();
+        stdin.write(&10usize);
+        let public_values = SP1PublicValues::from(&[1, 2, 3]);
+        let commitment = ExecutionCommitment::new(b"elf", &stdin, &public_values, 100);
+        assert!(commitment.matches(b"elf", &stdin, &public_values, 100));
+
+        let other = ExecutionCommitment { cycles: 101, ..commitment.clone() };
+        assert_ne!(commitment.root(), other.root());
+    }
+
+    #[cfg(feature = "network")]
+    #[test]
+    fn test_signed_commitment_verifies() {
+        let private_key = "0x0000000000000000000000000000000000000000000000000000000000000001";
+        let public_values = SP1PublicValues::from(&[1, 2, 3]);
+        let commitment = ExecutionCommitment::new(b"elf", &SP1Stdin::new(), &public_values, 100);
+        let mut signed = commitment.sign(private_key).unwrap();
+        signed.verify().unwrap();
+
+        signed.commitment.cycles += 1;
+        assert!(signed.verify().is_err());
+    }
+}
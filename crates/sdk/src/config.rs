@@ -0,0 +1,347 @@
+//! A file configuring the prover, for
+//! [ProverClient::from_config](crate::ProverClient::from_config).
+//!
+//! The prover is otherwise configured with a growing number of environment variables, read in
+//! different places, which are easy to set differently on two machines. The configuration file
+//! gathers them in one place:
+//!
+//! ```toml
+//! backend = "network"
+//! pool_threads = 2
+//!
+//! [artifacts]
+//! circuits_dir = "/var/lib/sp1/circuits"
+//! checkpoint_dir = "/var/lib/sp1/checkpoints"
+//! mirrors = ["https://mirror.example.com/sp1-circuits"]
+//!
+//! [core]
+//! shard_size = 2097152
+//! shard_batch_size = 4
+//!
+//! [network]
+//! rpc = ["https://rpc.example.com", "https://rpc-backup.example.com"]
+//! private_key_env = "PROVER_KEY"
+//! max_retries = 3
+//! ```
+//!
+//! The settings are passed to the prover created from the file, without being written to the
+//! environment, so they only apply to that prover; `pool_threads` is the exception, as a process
+//! has a single prover pool. A setting left out of the file falls back to the environment variable
+//! it replaces, as for [ProverClient::new](crate::ProverClient::new).
+
+use std::{collections::BTreeMap, env, fs, path::Path};
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde::Deserialize;
+use sp1_stark::{SP1CoreOpts, SP1ProverOpts, SplitOpts};
+
+use crate::{checkpoint::ShrinkCheckpoint, install::ArtifactsOpts, provers::ProofOpts};
+#[cfg(feature = "network")]
+use {
+    crate::{
+        network::{client::NetworkClient, inflight::InflightJournal, retry::RetryConfig},
+        NetworkProver,
+    },
+    std::time::Duration,
+};
+
+/// The prover backend to use.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Backend {
+    /// Proves on this machine, with [CudaProver](crate::CudaProver) if the `cuda` feature is
+    /// enabled and [CpuProver](crate::CpuProver) otherwise.
+    #[default]
+    Local,
+    /// Generates mock proofs with [MockProver](crate::MockProver).
+    Mock,
+    /// Outsources the proofs to the prover network with [NetworkProver](crate::NetworkProver).
+    Network,
+}
+
+/// The configuration of the prover, usually read from a `sp1.toml` file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SP1Config {
+    /// The backend, see `SP1_PROVER`.
+    pub backend: Option<Backend>,
+    /// The number of proofs generated at once by the asynchronous methods, see
+    /// `SP1_PROVER_POOL_THREADS`.
+    pub pool_threads: Option<usize>,
+    /// Where the circuit artifacts and checkpoints are stored, and how they are downloaded.
+    #[serde(default)]
+    pub artifacts: ArtifactsConfig,
+    /// The options of the core prover.
+    #[serde(default)]
+    pub core: CoreConfig,
+    /// The options of the network backend.
+    #[serde(default)]
+    pub network: NetworkConfig,
+}
+
+/// The `[artifacts]` section of an [SP1Config].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ArtifactsConfig {
+    /// The directory of the circuit artifacts, see `SP1_CIRCUITS_DIR`.
+    pub circuits_dir: Option<String>,
+    /// The directory of the shrink checkpoints, see `SP1_SHRINK_CHECKPOINT_DIR`.
+    pub checkpoint_dir: Option<String>,
+    /// The base URLs the circuit artifacts are downloaded from, see `SP1_ARTIFACTS_MIRRORS`.
+    #[serde(default)]
+    pub mirrors: Vec<String>,
+    /// The headers sent when downloading the artifacts, see `SP1_ARTIFACTS_HEADERS`.
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// The proxy the artifacts are downloaded through, see `SP1_ARTIFACTS_PROXY`.
+    pub proxy: Option<String>,
+}
+
+/// The `[core]` section of an [SP1Config], which sets the
+/// [SP1CoreOpts](sp1_stark::SP1CoreOpts) and thus the shapes of the shards.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CoreConfig {
+    /// The number of cycles of a shard, see `SHARD_SIZE`.
+    pub shard_size: Option<usize>,
+    /// The number of shards executed at once, see `SHARD_BATCH_SIZE`.
+    pub shard_batch_size: Option<usize>,
+    /// The threshold above which deferred events are split, see `SPLIT_THRESHOLD`.
+    pub split_threshold: Option<usize>,
+    /// The number of workers generating traces, see `TRACE_GEN_WORKERS`.
+    pub trace_gen_workers: Option<usize>,
+    /// The maximum number of deferred proofs verified in a shard, see
+    /// `MAX_DEFERRED_PROOFS_PER_SHARD`.
+    pub max_deferred_proofs_per_shard: Option<usize>,
+}
+
+/// The `[network]` section of an [SP1Config].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NetworkConfig {
+    /// The RPC endpoints, tried in order, see `PROVER_NETWORK_RPC`.
+    #[serde(default)]
+    pub rpc: Vec<String>,
+    /// The private key requesting the proofs.
+    ///
+    /// Prefer [Self::private_key_env] to keep the key out of the file.
+    pub private_key: Option<String>,
+    /// The environment variable holding the private key, `SP1_PRIVATE_KEY` by default.
+    pub private_key_env: Option<String>,
+    /// The maximum number of retries of a failed request, see `PROVER_NETWORK_MAX_RETRIES`.
+    pub max_retries: Option<usize>,
+    /// The delay after which a request is hedged, see `PROVER_NETWORK_HEDGE_AFTER_MS`.
+    pub hedge_after_ms: Option<u64>,
+    /// The directory of the in-flight requests, see `PROVER_NETWORK_INFLIGHT_DIR`.
+    pub inflight_dir: Option<String>,
+    /// Whether to skip the local execution of the program before requesting a proof, see
+    /// `SKIP_SIMULATION`.
+    pub skip_simulation: Option<bool>,
+}
+
+impl Backend {
+    /// Reads the backend from `SP1_PROVER`, [Backend::Local] if it is not set.
+    pub fn from_env() -> Result<Self> {
+        match env::var("SP1_PROVER").unwrap_or_default().to_lowercase().as_str() {
+            "" | "local" => Ok(Self::Local),
+            "mock" => Ok(Self::Mock),
+            "network" => Ok(Self::Network),
+            _ => bail!(
+                "invalid value for SP1_PROVER enviroment variable: expected 'local', 'mock', or 'network'"
+            ),
+        }
+    }
+}
+
+impl SP1Config {
+    /// Reads the configuration from a TOML file.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("failed to read the configuration {}", path.display()))?;
+        Self::from_toml(&contents)
+            .with_context(|| format!("invalid configuration {}", path.display()))
+    }
+
+    /// Parses the configuration from a TOML string.
+    pub fn from_toml(contents: &str) -> Result<Self> {
+        Ok(toml::from_str(contents)?)
+    }
+
+    /// Returns the backend of the file, or of `SP1_PROVER` if the file does not set it.
+    pub fn resolve_backend(&self) -> Result<Backend> {
+        self.backend.map_or_else(Backend::from_env, Ok)
+    }
+
+    /// Returns the options of the core prover, from the environment for the unset settings.
+    pub fn core_opts(&self) -> SP1CoreOpts {
+        let core = &self.core;
+        let mut opts = SP1CoreOpts::default();
+        if let Some(shard_size) = core.shard_size {
+            opts.shard_size = shard_size;
+        }
+        if let Some(shard_batch_size) = core.shard_batch_size {
+            opts.shard_batch_size = shard_batch_size;
+        }
+        if let Some(split_threshold) = core.split_threshold {
+            opts.split_opts = SplitOpts::new(split_threshold);
+        }
+        if let Some(trace_gen_workers) = core.trace_gen_workers {
+            opts.trace_gen_workers = trace_gen_workers;
+        }
+        if let Some(max_deferred_proofs_per_shard) = core.max_deferred_proofs_per_shard {
+            opts.max_deferred_proofs_per_shard = max_deferred_proofs_per_shard;
+        }
+        opts
+    }
+
+    /// Returns the options of the circuit artifacts, from the environment for the unset settings.
+    pub fn artifacts_opts(&self) -> ArtifactsOpts {
+        let artifacts = &self.artifacts;
+        let mut opts = ArtifactsOpts::default();
+        if let Some(circuits_dir) = &artifacts.circuits_dir {
+            opts.dir = Path::new(circuits_dir).join(crate::SP1_CIRCUIT_VERSION);
+        }
+        if !artifacts.mirrors.is_empty() {
+            opts.mirrors = artifacts.mirrors.clone();
+        }
+        if !artifacts.headers.is_empty() {
+            opts.headers = artifacts.headers.clone().into_iter().collect();
+        }
+        if let Some(proxy) = &artifacts.proxy {
+            opts.proxy = Some(proxy.clone());
+        }
+        opts
+    }
+
+    /// Returns the options the proofs of the local backend start from.
+    pub fn proof_opts(&self) -> ProofOpts {
+        ProofOpts {
+            sp1_prover_opts: SP1ProverOpts {
+                core_opts: self.core_opts(),
+                recursion_opts: SP1CoreOpts::recursion(),
+            },
+            timeout: None,
+            artifacts_opts: self.artifacts_opts(),
+            checkpoint_dir: self
+                .artifacts
+                .checkpoint_dir
+                .as_ref()
+                .map(Into::into)
+                .or_else(ShrinkCheckpoint::dir),
+        }
+    }
+
+    /// Returns the retry configuration of the network backend, from the environment for the unset
+    /// settings.
+    #[cfg(feature = "network")]
+    pub fn retry_config(&self) -> RetryConfig {
+        let mut config = RetryConfig::from_env();
+        if let Some(max_retries) = self.network.max_retries {
+            config.max_retries = max_retries;
+        }
+        if let Some(hedge_after_ms) = self.network.hedge_after_ms {
+            config.hedge_after = Some(Duration::from_millis(hedge_after_ms));
+        }
+        config
+    }
+
+    /// Creates the prover of the network backend, from the environment for the unset settings.
+    #[cfg(feature = "network")]
+    pub fn network_prover(&self) -> Result<NetworkProver> {
+        let network = &self.network;
+        let rpc_urls =
+            if network.rpc.is_empty() { NetworkClient::rpc_urls() } else { network.rpc.clone() };
+        let mut prover = NetworkProver::new_from_key_with_rpc_urls(
+            &self.private_key()?,
+            rpc_urls,
+            self.retry_config(),
+        )?;
+        if let Some(inflight_dir) = &network.inflight_dir {
+            prover = prover.with_inflight_journal(InflightJournal::new(inflight_dir));
+        }
+        if let Some(skip_simulation) = network.skip_simulation {
+            prover = prover.with_skip_simulation(skip_simulation);
+        }
+        Ok(prover)
+    }
+
+    /// Returns the private key of the network backend: [NetworkConfig::private_key], or the
+    /// content of [NetworkConfig::private_key_env].
+    pub fn private_key(&self) -> Result<String> {
+        if let Some(private_key) = &self.network.private_key {
+            return Ok(private_key.clone());
+        }
+        let name = self.network.private_key_env.as_deref().unwrap_or("SP1_PRIVATE_KEY");
+        env::var(name).map_err(|_| anyhow!("{} must be set for remote proving", name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    #[test]
+    fn test_from_toml() {
+        let config = SP1Config::from_toml(
+            r#"
+            backend = "network"
+            pool_threads = 2
+
+            [artifacts]
+            circuits_dir = "/data/circuits"
+            checkpoint_dir = "/data/checkpoints"
+            mirrors = ["https://a.example.com", "https://b.example.com"]
+            headers = { Authorization = "Bearer token", Accept = "text/plain, application/gzip" }
+
+            [core]
+            shard_size = 1048576
+            split_threshold = 4096
+
+            [network]
+            rpc = ["https://rpc.example.com"]
+            private_key = "0x01"
+            skip_simulation = true
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.resolve_backend().unwrap(), Backend::Network);
+        assert_eq!(config.pool_threads, Some(2));
+        assert_eq!(config.private_key().unwrap(), "0x01");
+        assert_eq!(config.network.rpc, vec!["https://rpc.example.com".to_string()]);
+        assert_eq!(config.network.skip_simulation, Some(true));
+
+        let core_opts = config.core_opts();
+        assert_eq!(core_opts.shard_size, 1048576);
+        assert_eq!(core_opts.split_opts, SplitOpts::new(4096));
+
+        let opts = config.proof_opts();
+        assert_eq!(opts.checkpoint_dir, Some(PathBuf::from("/data/checkpoints")));
+        let artifacts_opts = opts.artifacts_opts;
+        assert_eq!(
+            artifacts_opts.dir,
+            Path::new("/data/circuits").join(crate::SP1_CIRCUIT_VERSION)
+        );
+        assert_eq!(
+            artifacts_opts.mirrors,
+            vec!["https://a.example.com".to_string(), "https://b.example.com".to_string()]
+        );
+        // The header values are passed as they are, commas included.
+        assert_eq!(
+            artifacts_opts.headers,
+            vec![
+                ("Accept".to_string(), "text/plain, application/gzip".to_string()),
+                ("Authorization".to_string(), "Bearer token".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        assert_eq!(SP1Config::from_toml("").unwrap(), SP1Config::default());
+        assert!(SP1Config::from_toml("backend = \"gpu\"").is_err());
+        assert!(SP1Config::from_toml("[core]\nshard_sise = 1").is_err());
+    }
+}
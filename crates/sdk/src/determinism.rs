@@ -0,0 +1,221 @@
+//! Replay checks that the execution of a program does not depend on the host.
+//!
+//! A program is executed several times on its way to a proof: once to find the shard boundaries,
+//! again to generate the traces of each shard, and on every machine that proves or re-proves it.
+//! Any input that is not fixed by the program and its stdin, such as a hook that reads the time,
+//! draws randomness, leaks a host address, or whose answer depends on the order in which
+//! asynchronous hooks complete, makes these executions diverge, which fails the proof or makes it
+//! irreproducible.
+//!
+//! [Execute::check_determinism](crate::action::Execute::check_determinism) executes the program
+//! twice. The second execution runs on another thread, with the host heap shifted by a live
+//! allocation and the asynchronous hooks delayed by [ASYNC_HOOK_JITTER], and the two executions
+//! are compared in a [DeterminismReport].
+
+use std::{fmt, hint::black_box, thread, time::Duration};
+
+use anyhow::{bail, Result};
+use sp1_core_executor::{syscalls::SyscallCode, ExecutionReport, HookRegistry, SP1ContextBuilder};
+use sp1_core_machine::io::{SP1PublicValues, SP1Stdin};
+use sp1_prover::components::DefaultProverComponents;
+
+use crate::Prover;
+
+/// The largest delay added to a call of an asynchronous hook in the second execution.
+pub const ASYNC_HOOK_JITTER: Duration = Duration::from_millis(5);
+
+/// The size of the allocation held during the second execution, so that the allocations of the
+/// hooks land at other host addresses. It is not a multiple of the page size on purpose.
+const HEAP_PADDING: usize = (1 << 20) + 3 * 4096 + 40;
+
+/// A difference between two executions of the same program on the same stdin.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Divergence {
+    /// The second execution failed, while the first one succeeded.
+    Failed(String),
+    /// The public values differ, from the given byte offset on.
+    PublicValues { offset: usize },
+    /// The executions ran a different number of cycles.
+    Cycles { first: u64, second: u64 },
+    /// A syscall was made a different number of times.
+    Syscall { code: SyscallCode, first: u64, second: u64 },
+    /// The instruction at `pc` was executed a different number of times. Only the first such
+    /// instruction in program order is reported.
+    Pc { pc: u32, first: u64, second: u64 },
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Divergence::Failed(err) => write!(f, "the second execution failed: {}", err),
+            Divergence::PublicValues { offset } => {
+                write!(f, "the public values differ from byte {}", offset)
+            }
+            Divergence::Cycles { first, second } => {
+                write!(f, "the executions ran {} and {} cycles", first, second)
+            }
+            Divergence::Syscall { code, first, second } => {
+                write!(f, "{:?} was called {} and {} times", code, first, second)
+            }
+            Divergence::Pc { pc, first, second } => {
+                write!(f, "the instruction at 0x{:08x} ran {} and {} times", pc, first, second)
+            }
+        }
+    }
+}
+
+/// The outcome of [Execute::check_determinism](crate::action::Execute::check_determinism).
+#[derive(Debug, Clone)]
+pub struct DeterminismReport {
+    /// The public values of the first execution.
+    pub public_values: SP1PublicValues,
+    /// The report of the first execution.
+    pub report: ExecutionReport,
+    /// The differences between the two executions, empty if they agree.
+    pub divergences: Vec<Divergence>,
+}
+
+impl DeterminismReport {
+    /// Returns whether the two executions agree.
+    pub fn is_deterministic(&self) -> bool {
+        self.divergences.is_empty()
+    }
+
+    /// Returns an error listing the divergences, if any.
+    pub fn ensure_deterministic(&self) -> Result<()> {
+        if self.is_deterministic() {
+            return Ok(());
+        }
+        let divergences =
+            self.divergences.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ");
+        bail!("the execution is not deterministic: {}", divergences)
+    }
+}
+
+/// Executes the program twice, perturbing the host in the second execution, and compares them.
+///
+/// Fails if the first execution fails.
+pub(crate) fn check_determinism<'a>(
+    prover: &'a dyn Prover<DefaultProverComponents>,
+    elf: &[u8],
+    stdin: &SP1Stdin,
+    mut context_builder: SP1ContextBuilder<'a>,
+) -> Result<DeterminismReport> {
+    // Without hooks of its own, the context leaves the default registry to the executor, whose
+    // asynchronous hooks must be jittered as well.
+    let mut second_context = context_builder.clone().build();
+    second_context
+        .hook_registry
+        .get_or_insert_with(HookRegistry::default)
+        .jitter_async_hooks(ASYNC_HOOK_JITTER);
+
+    let (public_values, report) =
+        prover.sp1_prover().execute(elf, stdin, context_builder.build())?;
+
+    let second = thread::scope(|s| {
+        s.spawn(|| {
+            let padding = black_box(vec![1u8; HEAP_PADDING]);
+            let output = prover.sp1_prover().execute(elf, stdin, second_context);
+            drop(padding);
+            output
+        })
+        .join()
+        .expect("the second execution panicked")
+    });
+
+    let divergences = match second {
+        Ok((second_public_values, second_report)) => {
+            compare((&public_values, &report), (&second_public_values, &second_report))
+        }
+        Err(err) => vec![Divergence::Failed(err.to_string())],
+    };
+    Ok(DeterminismReport { public_values, report, divergences })
+}
+
+/// Returns the differences between two successful executions.
+fn compare(
+    (first_pv, first): (&SP1PublicValues, &ExecutionReport),
+    (second_pv, second): (&SP1PublicValues, &ExecutionReport),
+) -> Vec<Divergence> {
+    let mut divergences = Vec::new();
+
+    let (first_pv, second_pv) = (first_pv.as_slice(), second_pv.as_slice());
+    if first_pv != second_pv {
+        let offset = first_pv
+            .iter()
+            .zip(second_pv)
+            .position(|(a, b)| a != b)
+            .unwrap_or(first_pv.len().min(second_pv.len()));
+        divergences.push(Divergence::PublicValues { offset });
+    }
+
+    let (first_cycles, second_cycles) =
+        (first.total_instruction_count(), second.total_instruction_count());
+    if first_cycles != second_cycles {
+        divergences.push(Divergence::Cycles { first: first_cycles, second: second_cycles });
+    }
+
+    for (code, &count) in first.syscall_counts.iter() {
+        let other = second.syscall_counts[code];
+        if count != other {
+            divergences.push(Divergence::Syscall { code, first: count, second: other });
+        }
+    }
+
+    let len = first.pc_counts.len().max(second.pc_counts.len());
+    let count = |counts: &[u64], i: usize| counts.get(i).copied().unwrap_or_default();
+    if let Some(i) = (0..len).find(|&i| count(&first.pc_counts, i) != count(&second.pc_counts, i)) {
+        divergences.push(Divergence::Pc {
+            pc: first.pc_base + 4 * i as u32,
+            first: count(&first.pc_counts, i),
+            second: count(&second.pc_counts, i),
+        });
+    }
+
+    divergences
+}
+
+#[cfg(test)]
+mod tests {
+    use sp1_core_executor::Opcode;
+
+    use super::*;
+    use crate::{utils, ProverClient};
+
+    #[test]
+    fn test_compare() {
+        let mut report = ExecutionReport { pc_base: 0x1000, ..Default::default() };
+        report.opcode_counts[Opcode::ADD] = 10;
+        report.syscall_counts[SyscallCode::WRITE] = 2;
+        report.pc_counts = vec![1, 4, 5];
+        let public_values = SP1PublicValues::from(&[1, 2, 3]);
+        assert!(compare((&public_values, &report), (&public_values, &report)).is_empty());
+
+        let mut other = report.clone();
+        other.opcode_counts[Opcode::ADD] = 12;
+        other.syscall_counts[SyscallCode::WRITE] = 3;
+        other.pc_counts = vec![1, 4, 6, 1];
+        let other_public_values = SP1PublicValues::from(&[1, 2, 4]);
+        assert_eq!(
+            compare((&public_values, &report), (&other_public_values, &other)),
+            vec![
+                Divergence::PublicValues { offset: 2 },
+                Divergence::Cycles { first: 10, second: 12 },
+                Divergence::Syscall { code: SyscallCode::WRITE, first: 2, second: 3 },
+                Divergence::Pc { pc: 0x1008, first: 5, second: 6 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_determinism() {
+        utils::setup_logger();
+        let elf =
+            include_bytes!("../../../examples/fibonacci/program/elf/riscv32im-succinct-zkvm-elf");
+        let client = ProverClient::mock();
+        let mut stdin = SP1Stdin::new();
+        stdin.write(&10u32);
+        let report = client.execute(elf, stdin).check_determinism().unwrap();
+        report.ensure_deterministic().unwrap();
+    }
+}
@@ -0,0 +1,218 @@
+use std::{fs, path::Path};
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use sp1_prover::{HashableKey, SP1VerifyingKey};
+
+use crate::{SP1Proof, SP1ProofKind, SP1ProofWithPublicValues, SP1_CIRCUIT_VERSION};
+
+/// A Plonk or Groth16 proof in the form expected by the onchain verifier, for use as a test
+/// fixture in EVM projects.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofFixture {
+    /// The verification key digest, as a 0x-prefixed bytes32 hex string.
+    pub vkey: String,
+    /// The public values committed by the program.
+    pub public_values: Vec<u8>,
+    /// The proof, encoded for the onchain verifier.
+    pub proof: Vec<u8>,
+}
+
+/// The JSON layout of a [ProofFixture], as read by `stdJson` in Foundry tests.
+///
+/// The keys are in alphabetical order, since `vm.parseJson` decodes objects into structs in that
+/// order.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FoundryFixture {
+    proof: String,
+    public_values: String,
+    vkey: String,
+}
+
+impl ProofFixture {
+    /// Creates a fixture from a Plonk or Groth16 proof and the verifying key of its program.
+    pub fn new(proof: &SP1ProofWithPublicValues, vk: &SP1VerifyingKey) -> Result<Self> {
+        if !matches!(proof.proof, SP1Proof::Plonk(_) | SP1Proof::Groth16(_)) {
+            return Err(anyhow!("only Plonk and Groth16 proofs are verifiable onchain"));
+        }
+        Ok(Self {
+            vkey: vk.bytes32(),
+            public_values: proof.public_values.to_vec(),
+            proof: proof.bytes(),
+        })
+    }
+
+    /// Returns the fixture as the JSON object expected by Foundry tests, with the fields `proof`,
+    /// `publicValues` and `vkey`.
+    pub fn to_foundry_json(&self) -> String {
+        let fixture = FoundryFixture {
+            proof: format!("0x{}", hex::encode(&self.proof)),
+            public_values: format!("0x{}", hex::encode(&self.public_values)),
+            vkey: self.vkey.clone(),
+        };
+        serde_json::to_string_pretty(&fixture).expect("failed to serialize fixture")
+    }
+
+    /// Parses a fixture from the JSON produced by [Self::to_foundry_json].
+    pub fn from_foundry_json(json: &str) -> Result<Self> {
+        let fixture: FoundryFixture = serde_json::from_str(json)?;
+        Ok(Self {
+            vkey: fixture.vkey,
+            public_values: hex::decode(fixture.public_values.trim_start_matches("0x"))?,
+            proof: hex::decode(fixture.proof.trim_start_matches("0x"))?,
+        })
+    }
+
+    /// Writes the Foundry JSON fixture to a path, creating its parent directories.
+    pub fn save_foundry_json(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, self.to_foundry_json()).map_err(Into::into)
+    }
+
+    /// Returns a Foundry test contract named `contract_name` that loads the fixture at
+    /// `fixture_path`, relative to the project root, and verifies it with the `kind` verifier of
+    /// the current circuit version from `sp1-contracts`.
+    ///
+    /// The vkey and public values of this fixture are embedded in the contract, which checks that
+    /// the fixture file holds them, that the proof verifies against them, and that it does not
+    /// verify against another vkey, other public values, or once tampered with.
+    pub fn solidity_test(
+        &self,
+        contract_name: &str,
+        fixture_path: &str,
+        kind: SP1ProofKind,
+    ) -> Result<String> {
+        let verifier = match kind {
+            SP1ProofKind::Plonk => "SP1VerifierPlonk",
+            SP1ProofKind::Groth16 => "SP1VerifierGroth16",
+            _ => return Err(anyhow!("only Plonk and Groth16 proofs are verifiable onchain")),
+        };
+        Ok(SOLIDITY_TEST_TEMPLATE
+            .replace("{CONTRACT_NAME}", contract_name)
+            .replace("{FIXTURE_PATH}", fixture_path)
+            .replace("{VERSION}", SP1_CIRCUIT_VERSION)
+            .replace("{VERIFIER}", verifier)
+            .replace("{VKEY}", &self.vkey)
+            .replace("{PUBLIC_VALUES}", &hex::encode(&self.public_values)))
+    }
+}
+
+const SOLIDITY_TEST_TEMPLATE: &str = r#"// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.20;
+
+import {Test} from "forge-std/Test.sol";
+import {stdJson} from "forge-std/StdJson.sol";
+import {SP1Verifier} from "@sp1-contracts/{VERSION}/{VERIFIER}.sol";
+
+struct SP1ProofFixtureJson {
+    bytes proof;
+    bytes publicValues;
+    bytes32 vkey;
+}
+
+contract {CONTRACT_NAME} is Test {
+    using stdJson for string;
+
+    /// @notice The verification key of the program the fixture was generated for.
+    bytes32 constant PROGRAM_VKEY = {VKEY};
+
+    /// @notice The public values the fixture was generated with.
+    bytes constant PUBLIC_VALUES = hex"{PUBLIC_VALUES}";
+
+    SP1Verifier verifier;
+
+    function loadFixture() public view returns (SP1ProofFixtureJson memory) {
+        string memory path = string.concat(vm.projectRoot(), "/{FIXTURE_PATH}");
+        string memory json = vm.readFile(path);
+        bytes memory jsonBytes = json.parseRaw(".");
+        return abi.decode(jsonBytes, (SP1ProofFixtureJson));
+    }
+
+    function setUp() public {
+        verifier = new SP1Verifier();
+    }
+
+    function test_FixtureMatchesProgram() public view {
+        SP1ProofFixtureJson memory fixture = loadFixture();
+        assertEq(fixture.vkey, PROGRAM_VKEY);
+        assertEq(fixture.publicValues, PUBLIC_VALUES);
+    }
+
+    function test_ValidProof() public view {
+        SP1ProofFixtureJson memory fixture = loadFixture();
+        verifier.verifyProof(PROGRAM_VKEY, PUBLIC_VALUES, fixture.proof);
+    }
+
+    function test_RevertWhen_WrongVkey() public {
+        SP1ProofFixtureJson memory fixture = loadFixture();
+        bytes32 vkey = bytes32(uint256(PROGRAM_VKEY) ^ 1);
+        vm.expectRevert();
+        verifier.verifyProof(vkey, PUBLIC_VALUES, fixture.proof);
+    }
+
+    function test_RevertWhen_WrongPublicValues() public {
+        SP1ProofFixtureJson memory fixture = loadFixture();
+        bytes memory publicValues = bytes.concat(PUBLIC_VALUES, hex"00");
+        vm.expectRevert();
+        verifier.verifyProof(PROGRAM_VKEY, publicValues, fixture.proof);
+    }
+
+    function test_RevertWhen_InvalidProof() public {
+        SP1ProofFixtureJson memory fixture = loadFixture();
+        bytes memory proof = fixture.proof;
+        proof[proof.length - 1] ^= 0x01;
+        vm.expectRevert();
+        verifier.verifyProof(PROGRAM_VKEY, PUBLIC_VALUES, proof);
+    }
+}
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_foundry_json_roundtrip() {
+        let fixture = ProofFixture {
+            vkey: format!("0x{}", "ab".repeat(32)),
+            public_values: vec![1, 2, 3],
+            proof: vec![4, 5],
+        };
+        let json = fixture.to_foundry_json();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["publicValues"], "0x010203");
+        assert_eq!(value["proof"], "0x0405");
+        assert_eq!(ProofFixture::from_foundry_json(&json).unwrap(), fixture);
+    }
+
+    #[test]
+    fn test_solidity_test_template() {
+        let fixture = ProofFixture {
+            vkey: format!("0x{}", "ab".repeat(32)),
+            public_values: vec![1, 2, 3],
+            proof: vec![4, 5],
+        };
+        let source = fixture
+            .solidity_test("FibonacciTest", "src/fixtures/plonk-fixture.json", SP1ProofKind::Plonk)
+            .unwrap();
+        assert!(source.contains("contract FibonacciTest is Test"));
+        assert!(source.contains("\"/src/fixtures/plonk-fixture.json\""));
+        assert!(source.contains(&format!(
+            "import {{SP1Verifier}} from \"@sp1-contracts/{SP1_CIRCUIT_VERSION}/SP1VerifierPlonk.sol\""
+        )));
+        assert!(source.contains(&format!("bytes32 constant PROGRAM_VKEY = 0x{};", "ab".repeat(32))));
+        assert!(source.contains("bytes constant PUBLIC_VALUES = hex\"010203\";"));
+        for placeholder in
+            ["{CONTRACT_NAME}", "{FIXTURE_PATH}", "{VERSION}", "{VERIFIER}", "{VKEY}"]
+        {
+            assert!(!source.contains(placeholder), "{placeholder}");
+        }
+
+        let err = fixture.solidity_test("FibonacciTest", "fixture.json", SP1ProofKind::Compressed);
+        assert!(err.unwrap_err().to_string().contains("verifiable onchain"));
+    }
+}
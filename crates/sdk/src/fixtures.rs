@@ -0,0 +1,270 @@
+//! Canonical test vectors and golden proofs, so that verifier implementations in other languages
+//! can be checked against the proofs of this SDK.
+//!
+//! Each [TestVector] is a small program, its stdin, and the public values it commits. The golden
+//! proofs of a vector are stored in [fixtures_dir], under `<version>/<name>/`:
+//!
+//! - `vk.bin`: the bincode-encoded [SP1VerifyingKey] of the program.
+//! - `<kind>.bin`: the bincode-encoded [SP1ProofWithPublicValues] of each proof kind.
+//! - `<kind>.json`: the Plonk and Groth16 proofs as a [ProofFixture], for onchain verifiers.
+//!
+//! Each version directory also has a `manifest.json`, see [FixtureManifest], which lists the
+//! vectors with their stdin, public values and proofs for readers that do not use this crate. The
+//! fixtures are generated with [generate_fixtures].
+
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, ensure, Context, Result};
+use serde::{Deserialize, Serialize};
+use sp1_core_machine::io::{SP1PublicValues, SP1Stdin};
+use sp1_prover::{HashableKey, SP1VerifyingKey};
+
+use crate::{CircuitVersion, ProofFixture, ProverClient, SP1ProofKind, SP1ProofWithPublicValues};
+
+/// The proof kinds for which golden proofs are generated.
+pub const FIXTURE_PROOF_KINDS: &[SP1ProofKind] = &[
+    SP1ProofKind::Core,
+    SP1ProofKind::Compressed,
+    SP1ProofKind::Plonk,
+    SP1ProofKind::Groth16,
+    SP1ProofKind::Tiny,
+];
+
+/// A canonical program with its stdin and the public values it commits.
+#[derive(Debug, Clone)]
+pub struct TestVector {
+    /// The name of the vector, which is also the name of its fixture directory.
+    pub name: &'static str,
+    /// The program.
+    pub elf: &'static [u8],
+    /// The stdin of the program.
+    pub stdin: SP1Stdin,
+    /// The public values the program commits when executed with [Self::stdin].
+    pub public_values: SP1PublicValues,
+}
+
+/// Returns the canonical test vectors.
+pub fn test_vectors() -> Vec<TestVector> {
+    let mut fibonacci_stdin = SP1Stdin::new();
+    fibonacci_stdin.write(&10u32);
+    vec![
+        TestVector {
+            name: "fibonacci",
+            elf: include_bytes!(
+                "../../../examples/fibonacci/program/elf/riscv32im-succinct-zkvm-elf"
+            ),
+            stdin: fibonacci_stdin,
+            public_values: SP1PublicValues::from(&words(&[10, 55, 89])),
+        },
+        TestVector {
+            name: "fibonacci-no-input",
+            elf: include_bytes!("../../../tests/fibonacci/elf/riscv32im-succinct-zkvm-elf"),
+            stdin: SP1Stdin::new(),
+            public_values: SP1PublicValues::from(&words(&[55, 89])),
+        },
+    ]
+}
+
+/// Returns the canonical test vector with the given name.
+pub fn test_vector(name: &str) -> Option<TestVector> {
+    test_vectors().into_iter().find(|vector| vector.name == name)
+}
+
+/// Returns the directory of the fixtures, `SP1_FIXTURES_DIR` or the `fixtures` directory of this
+/// crate by default.
+pub fn fixtures_dir() -> PathBuf {
+    env::var("SP1_FIXTURES_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures"))
+}
+
+impl TestVector {
+    /// Returns the fixture directory of this vector for a circuit version.
+    pub fn dir(&self, version: CircuitVersion) -> PathBuf {
+        fixtures_dir().join(version.as_str()).join(self.name)
+    }
+
+    /// Loads the verifying key of the program, as generated for a circuit version.
+    pub fn load_vk(&self, version: CircuitVersion) -> Result<SP1VerifyingKey> {
+        let bytes = read_fixture(&self.dir(version).join("vk.bin"))?;
+        bincode::deserialize(&bytes).map_err(Into::into)
+    }
+
+    /// Loads the golden proof of a kind, as generated for a circuit version.
+    pub fn load_proof(
+        &self,
+        version: CircuitVersion,
+        kind: SP1ProofKind,
+    ) -> Result<SP1ProofWithPublicValues> {
+        let path = self.dir(version).join(format!("{}.bin", kind_name(kind)));
+        let bytes = read_fixture(&path)?;
+        bincode::deserialize(&bytes).map_err(Into::into)
+    }
+
+    /// Loads the golden Plonk or Groth16 proof of a circuit version, in the form expected by the
+    /// onchain verifier.
+    pub fn load_onchain_fixture(
+        &self,
+        version: CircuitVersion,
+        kind: SP1ProofKind,
+    ) -> Result<ProofFixture> {
+        ensure!(
+            matches!(kind, SP1ProofKind::Plonk | SP1ProofKind::Groth16),
+            "only Plonk and Groth16 proofs are verifiable onchain"
+        );
+        let path = self.dir(version).join(format!("{}.json", kind_name(kind)));
+        let json = String::from_utf8(read_fixture(&path)?)?;
+        ProofFixture::from_foundry_json(&json)
+    }
+}
+
+/// The `manifest.json` of the fixtures of a circuit version.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixtureManifest {
+    /// The circuit version the proofs were generated with.
+    pub version: String,
+    /// The test vectors.
+    pub vectors: Vec<FixtureManifestEntry>,
+}
+
+/// A test vector in a [FixtureManifest].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixtureManifestEntry {
+    /// The name of the vector.
+    pub name: String,
+    /// The verification key digest, as a 0x-prefixed bytes32 hex string.
+    pub vkey: String,
+    /// The hex-encoded buffers of the stdin.
+    pub stdin: Vec<String>,
+    /// The hex-encoded public values.
+    pub public_values: String,
+    /// The proof files of each kind, relative to the directory of the manifest.
+    pub proofs: BTreeMap<SP1ProofKind, String>,
+}
+
+impl FixtureManifest {
+    /// Loads the manifest of a circuit version from [fixtures_dir].
+    pub fn load(version: CircuitVersion) -> Result<Self> {
+        let path = fixtures_dir().join(version.as_str()).join("manifest.json");
+        serde_json::from_slice(&read_fixture(&path)?).map_err(Into::into)
+    }
+}
+
+/// Generates the fixtures of the [CURRENT](CircuitVersion::CURRENT) circuit version in `dir`, with
+/// a proof of each of the given kinds for every test vector.
+///
+/// The fixtures shipped with this crate are regenerated in `crates/sdk/fixtures` on each circuit
+/// release, with [FIXTURE_PROOF_KINDS].
+pub fn generate_fixtures(
+    client: &ProverClient,
+    dir: impl AsRef<Path>,
+    kinds: &[SP1ProofKind],
+) -> Result<FixtureManifest> {
+    let version = CircuitVersion::CURRENT;
+    let version_dir = dir.as_ref().join(version.as_str());
+    let mut manifest = FixtureManifest { version: version.to_string(), vectors: Vec::new() };
+    for vector in test_vectors() {
+        let vector_dir = version_dir.join(vector.name);
+        fs::create_dir_all(&vector_dir)?;
+
+        let (public_values, _) = client.execute(vector.elf, vector.stdin.clone()).run()?;
+        ensure!(
+            public_values.as_slice() == vector.public_values.as_slice(),
+            "the public values of {} do not match the test vector",
+            vector.name
+        );
+
+        let (pk, vk) = client.setup(vector.elf);
+        fs::write(vector_dir.join("vk.bin"), bincode::serialize(&vk)?)?;
+
+        let mut proofs = BTreeMap::new();
+        for &kind in kinds {
+            let prove = client.prove(&pk, vector.stdin.clone());
+            let proof = match kind {
+                SP1ProofKind::Core => prove.core(),
+                SP1ProofKind::Compressed => prove.compressed(),
+                SP1ProofKind::Plonk => prove.plonk(),
+                SP1ProofKind::Groth16 => prove.groth16(),
+                SP1ProofKind::Tiny => prove.tiny(),
+            }
+            .run()
+            .with_context(|| format!("failed to prove {} as {:?}", vector.name, kind))?;
+            client.verify(&proof, &vk)?;
+
+            let file = format!("{}/{}.bin", vector.name, kind_name(kind));
+            proof.save(version_dir.join(&file))?;
+            if matches!(kind, SP1ProofKind::Plonk | SP1ProofKind::Groth16) {
+                let fixture = ProofFixture::new(&proof, &vk)?;
+                let json = vector_dir.join(format!("{}.json", kind_name(kind)));
+                fs::write(json, fixture.to_foundry_json())?;
+            }
+            proofs.insert(kind, file);
+        }
+
+        manifest.vectors.push(FixtureManifestEntry {
+            name: vector.name.to_string(),
+            vkey: vk.bytes32(),
+            stdin: vector.stdin.buffer.iter().map(hex::encode).collect(),
+            public_values: hex::encode(vector.public_values.as_slice()),
+            proofs,
+        });
+    }
+    fs::write(version_dir.join("manifest.json"), serde_json::to_string_pretty(&manifest)?)?;
+    Ok(manifest)
+}
+
+/// The name of the fixture files of a proof kind.
+fn kind_name(kind: SP1ProofKind) -> String {
+    format!("{:?}", kind).to_lowercase()
+}
+
+fn read_fixture(path: &Path) -> Result<Vec<u8>> {
+    fs::read(path).map_err(|err| {
+        anyhow!(
+            "failed to read the fixture {}: {}, the fixtures are generated with \
+             `sp1_sdk::fixtures::generate_fixtures`",
+            path.display(),
+            err
+        )
+    })
+}
+
+/// Encodes little-endian `u32` words, as committed by `sp1_zkvm::io::commit`.
+fn words(values: &[u32]) -> Vec<u8> {
+    values.iter().flat_map(|value| value.to_le_bytes()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils;
+
+    #[test]
+    fn test_vectors_public_values() {
+        utils::setup_logger();
+        let client = ProverClient::mock();
+        for vector in test_vectors() {
+            let (public_values, _) =
+                client.execute(vector.elf, vector.stdin.clone()).run().unwrap();
+            assert_eq!(
+                public_values.as_slice(),
+                vector.public_values.as_slice(),
+                "{}",
+                vector.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_onchain_fixture_kinds() {
+        let vector = test_vector("fibonacci").unwrap();
+        let err = vector.load_onchain_fixture(CircuitVersion::CURRENT, SP1ProofKind::Compressed);
+        assert!(err.unwrap_err().to_string().contains("verifiable onchain"));
+        assert_eq!(kind_name(SP1ProofKind::Groth16), "groth16");
+        assert!(test_vector("unknown").is_none());
+    }
+}
@@ -0,0 +1,175 @@
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use sp1_stark::SP1CoreOpts;
+use sysinfo::{Disks, System};
+
+use crate::{install::install_circuit_artifacts_dir, provers::ProverType};
+
+/// The files that must be present in the circuit artifacts directory to wrap proofs into Plonk
+/// and Groth16 proofs.
+const CIRCUIT_ARTIFACTS: [&str; 6] = [
+    "plonk_circuit.bin",
+    "plonk_pk.bin",
+    "plonk_vk.bin",
+    "groth16_circuit.bin",
+    "groth16_pk.bin",
+    "groth16_vk.bin",
+];
+
+/// The minimum free disk space, in bytes, required next to the circuit artifacts.
+const MIN_FREE_DISK: u64 = 10 * 1024 * 1024 * 1024;
+
+/// The outcome of a single readiness check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    /// The check passed.
+    Ok,
+    /// The check found a problem that only affects some proof kinds.
+    Warning,
+    /// The check found a problem that prevents proving.
+    Failed,
+}
+
+/// A single readiness check performed by [crate::ProverClient::healthcheck].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthCheck {
+    pub name: String,
+    pub status: HealthStatus,
+    pub message: String,
+}
+
+/// A structured readiness report of the prover, suitable for readiness probes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthReport {
+    /// Whether none of the checks failed.
+    pub ready: bool,
+    pub checks: Vec<HealthCheck>,
+}
+
+impl HealthReport {
+    /// Runs the readiness checks relevant to the given prover type with the given options.
+    pub fn new(prover_type: ProverType, opts: &SP1CoreOpts) -> Self {
+        let mut checks = Vec::new();
+        if matches!(prover_type, ProverType::Cpu | ProverType::Cuda) {
+            let artifacts_dir = install_circuit_artifacts_dir();
+            checks.push(check_circuit_artifacts(&artifacts_dir));
+            checks.push(check_memory(opts));
+            checks.push(check_disk(&artifacts_dir));
+        }
+        let ready = checks.iter().all(|check| check.status != HealthStatus::Failed);
+        Self { ready, checks }
+    }
+}
+
+fn check_circuit_artifacts(dir: &Path) -> HealthCheck {
+    let missing: Vec<_> =
+        CIRCUIT_ARTIFACTS.iter().filter(|file| !dir.join(file).exists()).copied().collect();
+    let (status, message) = if missing.is_empty() {
+        (HealthStatus::Ok, format!("circuit artifacts found at {}", dir.display()))
+    } else {
+        // The artifacts are only needed for Plonk and Groth16 proofs, and are downloaded on
+        // demand, so a missing artifact does not prevent proving.
+        (
+            HealthStatus::Warning,
+            format!("missing circuit artifacts in {}: {}", dir.display(), missing.join(", ")),
+        )
+    };
+    HealthCheck { name: "circuit_artifacts".to_string(), status, message }
+}
+
+/// Returns the memory, in GB, needed to prove with the given shard size.
+///
+/// This inverts the formula used to pick the default shard size from the available memory.
+fn required_memory_gb(shard_size: usize) -> u64 {
+    let log_shard_size = (shard_size.max(1) as f64).log2();
+    if log_shard_size <= 18.0 {
+        return 0;
+    }
+    2f64.powf((log_shard_size - 17.2) / 0.619).floor() as u64
+}
+
+fn check_memory(opts: &SP1CoreOpts) -> HealthCheck {
+    let sys = System::new_all();
+    let total_gb = sys.total_memory() / (1024 * 1024 * 1024);
+    let required_gb = required_memory_gb(opts.shard_size);
+    let (status, message) = if total_gb >= required_gb {
+        (
+            HealthStatus::Ok,
+            format!("{} GB of memory available, {} GB required", total_gb, required_gb),
+        )
+    } else {
+        (
+            HealthStatus::Failed,
+            format!(
+                "{} GB of memory available, but a shard size of {} requires {} GB",
+                total_gb, opts.shard_size, required_gb
+            ),
+        )
+    };
+    HealthCheck { name: "memory".to_string(), status, message }
+}
+
+fn check_disk(dir: &Path) -> HealthCheck {
+    // The artifacts directory may not exist yet, so look at its closest existing ancestor.
+    let path: PathBuf =
+        dir.ancestors().find(|p| p.exists()).unwrap_or(Path::new("/")).to_path_buf();
+    let disks = Disks::new_with_refreshed_list();
+    let disk = disks
+        .iter()
+        .filter(|disk| path.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().as_os_str().len());
+    let (status, message) = match disk {
+        Some(disk) if disk.available_space() >= MIN_FREE_DISK => (
+            HealthStatus::Ok,
+            format!("{} bytes free at {}", disk.available_space(), disk.mount_point().display()),
+        ),
+        Some(disk) => (
+            HealthStatus::Failed,
+            format!(
+                "only {} bytes free at {}, {} required",
+                disk.available_space(),
+                disk.mount_point().display(),
+                MIN_FREE_DISK
+            ),
+        ),
+        None => (HealthStatus::Warning, format!("no disk found for {}", path.display())),
+    };
+    HealthCheck { name: "disk".to_string(), status, message }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_required_memory_gb() {
+        assert_eq!(required_memory_gb(1 << 18), 0);
+        assert!(required_memory_gb(1 << 21) > 0);
+        assert!(required_memory_gb(1 << 22) > required_memory_gb(1 << 21));
+    }
+
+    #[test]
+    fn test_check_circuit_artifacts() {
+        let dir = tempfile::tempdir().unwrap();
+        for file in ["plonk_pk.bin", "plonk_vk.bin", "groth16_pk.bin", "groth16_vk.bin"] {
+            std::fs::write(dir.path().join(file), []).unwrap();
+        }
+        let check = check_circuit_artifacts(dir.path());
+        assert_eq!(check.status, HealthStatus::Warning);
+        assert!(check.message.ends_with("plonk_circuit.bin, groth16_circuit.bin"));
+
+        for file in ["plonk_circuit.bin", "groth16_circuit.bin"] {
+            std::fs::write(dir.path().join(file), []).unwrap();
+        }
+        assert_eq!(check_circuit_artifacts(dir.path()).status, HealthStatus::Ok);
+    }
+
+    #[test]
+    fn test_mock_prover_is_ready() {
+        let report = HealthReport::new(ProverType::Mock, &SP1CoreOpts::default());
+        assert!(report.ready);
+        assert!(report.checks.is_empty());
+    }
+}
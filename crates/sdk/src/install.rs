@@ -6,7 +6,10 @@ use {
     crate::block_on,
     futures::StreamExt,
     indicatif::{ProgressBar, ProgressStyle},
-    reqwest::Client,
+    reqwest::{
+        header::{HeaderMap, HeaderName, HeaderValue},
+        Client, Proxy,
+    },
     std::{cmp::min, io::Write, process::Command},
 };
 
@@ -15,14 +18,127 @@ use crate::SP1_CIRCUIT_VERSION;
 /// The base URL for the S3 bucket containing the ciruit artifacts.
 pub const CIRCUIT_ARTIFACTS_URL_BASE: &str = "https://sp1-circuits.s3-us-east-2.amazonaws.com";
 
+/// Options to configure where the circuit artifacts are installed and downloaded from.
+///
+/// The defaults are read from the environment:
+/// - `SP1_CIRCUITS_DIR`: the directory of the artifacts of every version, `~/.sp1/circuits` by
+///   default.
+/// - `SP1_ARTIFACTS_MIRRORS`: comma separated base URLs, tried in order.
+/// - `SP1_ARTIFACTS_HEADERS`: `Name: value` headers sent with every request, separated by newlines
+///   or commas. A comma which is not followed by a header name and a colon is part of the value.
+/// - `SP1_ARTIFACTS_PROXY`: the URL of the proxy all requests go through.
+///
+/// Without `SP1_ARTIFACTS_PROXY`, the usual `HTTPS_PROXY`, `HTTP_PROXY` and `NO_PROXY` variables
+/// are honored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArtifactsOpts {
+    /// The directory the artifacts of [SP1_CIRCUIT_VERSION] are installed in.
+    pub dir: PathBuf,
+    /// The base URLs to download the artifacts from, tried in order until one succeeds.
+    pub mirrors: Vec<String>,
+    /// The headers sent with every request, for example to authenticate to a private mirror.
+    pub headers: Vec<(String, String)>,
+    /// The URL of the proxy all requests go through.
+    pub proxy: Option<String>,
+}
+
+impl Default for ArtifactsOpts {
+    fn default() -> Self {
+        let mirrors = std::env::var("SP1_ARTIFACTS_MIRRORS")
+            .map(|s| parse_list(&s).map(str::to_string).collect::<Vec<_>>())
+            .ok()
+            .filter(|mirrors| !mirrors.is_empty())
+            .unwrap_or_else(|| vec![CIRCUIT_ARTIFACTS_URL_BASE.to_string()]);
+        let headers =
+            std::env::var("SP1_ARTIFACTS_HEADERS").map(|s| parse_headers(&s)).unwrap_or_default();
+        let proxy = std::env::var("SP1_ARTIFACTS_PROXY").ok().filter(|s| !s.is_empty());
+        Self { dir: install_circuit_artifacts_dir(), mirrors, headers, proxy }
+    }
+}
+
+impl ArtifactsOpts {
+    /// Builds the client which downloads the artifacts with the headers and the proxy.
+    #[cfg(feature = "network")]
+    fn client(&self) -> Result<Client, String> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|e| format!("invalid header name '{name}': {e}"))?;
+            let value = HeaderValue::from_str(value)
+                .map_err(|e| format!("invalid value for header '{name}': {e}"))?;
+            headers.insert(name, value);
+        }
+        let mut builder = Client::builder().default_headers(headers);
+        if let Some(proxy) = &self.proxy {
+            let proxy = Proxy::all(proxy).map_err(|e| format!("invalid proxy '{proxy}': {e}"))?;
+            builder = builder.proxy(proxy);
+        }
+        builder.build().map_err(|e| format!("failed to create reqwest client: {e}"))
+    }
+}
+
+/// The non-empty trimmed items of a comma separated list.
+fn parse_list(value: &str) -> impl Iterator<Item = &str> {
+    value.split(',').map(str::trim).filter(|item| !item.is_empty())
+}
+
+/// Parses `Name: value` headers separated by newlines or commas.
+///
+/// Header values may contain commas, so an item which does not start with a header name and a
+/// colon continues the value of the previous header on its line. Other items are skipped.
+fn parse_headers(value: &str) -> Vec<(String, String)> {
+    let mut headers = Vec::<(String, String)>::new();
+    for line in value.lines() {
+        let mut in_header = false;
+        for item in line.split(',').filter(|item| !item.trim().is_empty()) {
+            match item.split_once(':') {
+                Some((name, value)) if is_header_name(name.trim()) => {
+                    headers.push((name.trim().to_string(), value.trim().to_string()));
+                    in_header = true;
+                }
+                _ if in_header => {
+                    let value = &mut headers.last_mut().unwrap().1;
+                    value.push(',');
+                    value.push_str(item.trim_end());
+                }
+                _ => {}
+            }
+        }
+    }
+    headers
+}
+
+/// Returns whether `name` is a valid HTTP header name (a token of RFC 9110).
+fn is_header_name(name: &str) -> bool {
+    !name.is_empty() &&
+        name.bytes().all(|b| b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b))
+}
+
 /// The directory where the circuit artifacts will be stored.
 pub fn install_circuit_artifacts_dir() -> PathBuf {
-    dirs::home_dir().unwrap().join(".sp1").join("circuits").join(SP1_CIRCUIT_VERSION)
+    circuit_artifacts_dir(SP1_CIRCUIT_VERSION)
+}
+
+/// The directory where the circuit artifacts of the given version are stored, under
+/// `SP1_CIRCUITS_DIR` or `~/.sp1/circuits` by default.
+pub fn circuit_artifacts_dir(version: &str) -> PathBuf {
+    std::env::var("SP1_CIRCUITS_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| dirs::home_dir().unwrap().join(".sp1").join("circuits"))
+        .join(version)
 }
 
 /// Tries to install the circuit artifacts if they are not already installed.
+///
+/// The artifacts are downloaded with the [ArtifactsOpts] of the environment.
 pub fn try_install_circuit_artifacts() -> PathBuf {
-    let build_dir = install_circuit_artifacts_dir();
+    try_install_circuit_artifacts_with(&ArtifactsOpts::default())
+}
+
+/// Tries to install the circuit artifacts in the directory of `opts` if they are not already
+/// installed, downloading them with `opts`.
+pub fn try_install_circuit_artifacts_with(opts: &ArtifactsOpts) -> PathBuf {
+    let build_dir = opts.dir.clone();
 
     if build_dir.exists() {
         println!(
@@ -37,7 +153,7 @@ pub fn try_install_circuit_artifacts() -> PathBuf {
                     SP1_CIRCUIT_VERSION,
                     build_dir.display()
                 );
-                install_circuit_artifacts(build_dir.clone());
+                install_circuit_artifacts_with(build_dir.clone(), opts);
             }
         }
     }
@@ -46,20 +162,43 @@ pub fn try_install_circuit_artifacts() -> PathBuf {
 
 /// Install the latest circuit artifacts.
 ///
-/// This function will download the latest circuit artifacts from the S3 bucket and extract them
-/// to the directory specified by [plonk_bn254_artifacts_dir()].
+/// This function will download the latest circuit artifacts from the S3 bucket, or the mirrors of
+/// the environment, and extract them to `build_dir`.
 #[cfg(feature = "network")]
 pub fn install_circuit_artifacts(build_dir: PathBuf) {
+    install_circuit_artifacts_with(build_dir, &ArtifactsOpts::default());
+}
+
+/// Install the latest circuit artifacts, downloading them from the first mirror of `opts` that
+/// serves them.
+#[cfg(feature = "network")]
+pub fn install_circuit_artifacts_with(build_dir: PathBuf, opts: &ArtifactsOpts) {
     // Create the build directory.
     std::fs::create_dir_all(&build_dir).expect("failed to create build directory");
 
-    // Download the artifacts.
-    let download_url = format!("{}/{}.tar.gz", CIRCUIT_ARTIFACTS_URL_BASE, SP1_CIRCUIT_VERSION);
-    let mut artifacts_tar_gz_file =
-        tempfile::NamedTempFile::new().expect("failed to create tempfile");
-    let client = Client::builder().build().expect("failed to create reqwest client");
-    block_on(download_file(&client, &download_url, &mut artifacts_tar_gz_file))
-        .expect("failed to download file");
+    // Download the artifacts, falling back to the next mirror on failure.
+    let client = opts.client().expect("failed to create reqwest client");
+    let mut errors = Vec::new();
+    let mut downloaded = None;
+    for mirror in &opts.mirrors {
+        let download_url =
+            format!("{}/{}.tar.gz", mirror.trim_end_matches('/'), SP1_CIRCUIT_VERSION);
+        let mut artifacts_tar_gz_file =
+            tempfile::NamedTempFile::new().expect("failed to create tempfile");
+        match block_on(download_file(&client, &download_url, &mut artifacts_tar_gz_file)) {
+            Ok(()) => {
+                downloaded = Some((download_url, artifacts_tar_gz_file));
+                break;
+            }
+            Err(err) => {
+                println!("[sp1] failed to download circuit artifacts: {err}");
+                errors.push(err);
+            }
+        }
+    }
+    let Some((download_url, artifacts_tar_gz_file)) = downloaded else {
+        panic!("failed to download circuit artifacts from every mirror: {}", errors.join("; "));
+    };
 
     // Extract the tarball to the build directory.
     let mut res = Command::new("tar")
@@ -84,6 +223,8 @@ pub async fn download_file(
     file: &mut tempfile::NamedTempFile,
 ) -> std::result::Result<(), String> {
     let res = client.get(url).send().await.or(Err(format!("Failed to GET from '{}'", &url)))?;
+    let res =
+        res.error_for_status().map_err(|e| format!("Failed to GET from '{}': {}", &url, e))?;
 
     let total_size =
         res.content_length().ok_or(format!("Failed to get content length from '{}'", &url))?;
@@ -106,3 +247,38 @@ pub async fn download_file(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_headers() {
+        let headers = parse_headers("invalid, Authorization: Bearer abc, X-Mirror:eu ,");
+        assert_eq!(
+            headers,
+            vec![
+                ("Authorization".to_string(), "Bearer abc".to_string()),
+                ("X-Mirror".to_string(), "eu".to_string())
+            ]
+        );
+
+        // Commas which do not start a header are part of the value.
+        let headers =
+            parse_headers("Accept: text/plain, application/json\nCookie: a=1, b=2, X-Mirror: eu");
+        assert_eq!(
+            headers,
+            vec![
+                ("Accept".to_string(), "text/plain, application/json".to_string()),
+                ("Cookie".to_string(), "a=1, b=2".to_string()),
+                ("X-Mirror".to_string(), "eu".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_list() {
+        let mirrors = parse_list(" https://a.example/ ,,https://b.example").collect::<Vec<_>>();
+        assert_eq!(mirrors, vec!["https://a.example/", "https://b.example"]);
+    }
+}
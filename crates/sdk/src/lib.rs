@@ -12,9 +12,19 @@ pub mod proto {
 }
 pub mod action;
 pub mod artifacts;
+pub mod attestation;
+pub mod checkpoint;
+pub mod commitment;
+pub mod config;
+pub mod determinism;
+pub mod fixture;
+pub mod fixtures;
+pub mod health;
 pub mod install;
+pub mod limits;
 #[cfg(feature = "network")]
 pub mod network;
+pub mod pool;
 #[cfg(feature = "network")]
 pub use crate::network::prover::NetworkProver;
 #[cfg(feature = "cuda")]
@@ -22,35 +32,59 @@ pub use crate::provers::CudaProver;
 
 pub mod proof;
 pub mod provers;
+pub mod report;
+pub mod session;
+pub mod shards;
+pub mod version;
 pub mod utils {
     pub use sp1_core_machine::utils::setup_logger;
 }
 
+pub use attestation::SP1Attestation;
 use cfg_if::cfg_if;
+pub use checkpoint::ShrinkCheckpoint;
+pub use commitment::{ExecutionCommitment, SignedExecutionCommitment};
+pub use config::SP1Config;
+pub use determinism::{DeterminismReport, Divergence};
+pub use fixture::ProofFixture;
+pub use limits::VerifierLimits;
+pub use pool::{ProofHandle, ProveFuture, ProveStatus};
 pub use proof::*;
 pub use provers::SP1VerificationError;
+pub use report::{ChipCells, ProveReport};
+pub use session::{Session, SessionStatus};
+pub use shards::SP1ShardBoundary;
 use sp1_prover::components::DefaultProverComponents;
+pub use version::CircuitVersion;
 
-use std::env;
+use sp1_stark::SP1CoreOpts;
+use std::{env, fs, path::Path, sync::Arc};
 
 #[cfg(feature = "network")]
 use {std::future::Future, tokio::task::block_in_place};
 
 pub use provers::{CpuProver, MockProver, Prover};
+use provers::ProofOpts;
 
-pub use sp1_core_executor::{ExecutionReport, HookEnv, SP1Context, SP1ContextBuilder};
+pub use sp1_core_executor::{
+    ExecutionProgress, ExecutionReport, HookEnv, MemoTable, ProfilerOpts, ProgressControl,
+    SP1Context, SP1ContextBuilder, ShardProgress, SyscallAuditOpts,
+};
 pub use sp1_core_machine::{
-    io::SP1PublicValues, io::SP1Stdin, riscv::cost::CostEstimator, SP1_CIRCUIT_VERSION,
+    io::PublicValuesHash, io::SP1PublicValues, io::SP1Stdin, riscv::cost::CostEstimator,
+    SP1_CIRCUIT_VERSION,
 };
 pub use sp1_prover::{
+    tiny::{SP1TinyVerifier, TINY_MAX_CYCLES},
     CoreSC, HashableKey, InnerSC, OuterSC, PlonkBn254Proof, SP1Prover, SP1ProvingKey,
     SP1VerifyingKey,
 };
 
 /// A client for interacting with SP1.
 pub struct ProverClient {
-    /// The underlying prover implementation.
-    pub prover: Box<dyn Prover<DefaultProverComponents>>,
+    /// The underlying prover implementation, shared with the proofs started by
+    /// [Self::prove_async].
+    prover: Arc<dyn Prover<DefaultProverComponents>>,
 }
 
 impl ProverClient {
@@ -76,18 +110,18 @@ impl ProverClient {
 
         #[allow(unreachable_code)]
         match env::var("SP1_PROVER").unwrap_or("local".to_string()).to_lowercase().as_str() {
-            "mock" => Self { prover: Box::new(MockProver::new()) },
+            "mock" => Self { prover: Arc::new(MockProver::new()) },
             "local" => Self {
                 #[cfg(not(feature = "cuda"))]
-                prover: Box::new(CpuProver::new()),
+                prover: Arc::new(CpuProver::new()),
                 #[cfg(feature = "cuda")]
-                prover: Box::new(CudaProver::new()),
+                prover: Arc::new(CudaProver::new()),
             },
             "network" => {
                 cfg_if! {
                     if #[cfg(feature = "network")] {
                         Self {
-                            prover: Box::new(NetworkProver::new()),
+                            prover: Arc::new(NetworkProver::new()),
                         }
                     } else {
                         panic!("network feature is not enabled")
@@ -113,7 +147,7 @@ impl ProverClient {
     /// let client = ProverClient::mock();
     /// ```
     pub fn mock() -> Self {
-        Self { prover: Box::new(MockProver::new()) }
+        Self { prover: Arc::new(MockProver::new()) }
     }
 
     /// Creates a new [ProverClient] with the local prover.
@@ -129,7 +163,7 @@ impl ProverClient {
     /// let client = ProverClient::local();
     /// ```
     pub fn local() -> Self {
-        Self { prover: Box::new(CpuProver::new()) }
+        Self { prover: Arc::new(CpuProver::new()) }
     }
 
     /// Creates a new [ProverClient] with the network prover.
@@ -148,7 +182,7 @@ impl ProverClient {
         cfg_if! {
             if #[cfg(feature = "network")] {
                 Self {
-                    prover: Box::new(NetworkProver::new()),
+                    prover: Arc::new(NetworkProver::new()),
                 }
             } else {
                 panic!("network feature is not enabled")
@@ -156,6 +190,71 @@ impl ProverClient {
         }
     }
 
+    /// Creates a new [ProverClient] with the network prover, which sends its requests to `rpc_url`
+    /// with the given private key instead of reading them from the environment.
+    ///
+    /// The endpoint may be the prover network or any prover serving its API, such as a proving
+    /// machine shared by a team. The ELF and the stdin are uploaded with each request, and
+    /// [action::Prove::run] waits for the proof, so scripts prove and verify as they do locally.
+    /// Fails if an endpoint is not a valid URL.
+    ///
+    /// ### Examples
+    ///
+    /// ```no_run
+    /// use sp1_sdk::ProverClient;
+    ///
+    /// let client = ProverClient::network_with_rpc("https://prover.internal:3000", "0x...").unwrap();
+    /// ```
+    pub fn network_with_rpc(rpc_url: &str, private_key: &str) -> anyhow::Result<Self> {
+        cfg_if! {
+            if #[cfg(feature = "network")] {
+                let prover = NetworkProver::new_from_key_with_rpc(private_key, rpc_url)?;
+                Ok(Self { prover: Arc::new(prover) })
+            } else {
+                let _ = (rpc_url, private_key);
+                anyhow::bail!("network feature is not enabled")
+            }
+        }
+    }
+
+    /// Creates a new [ProverClient] from a TOML configuration file, see [config].
+    ///
+    /// The settings of the file are passed to the prover, and those it leaves out are read from
+    /// the environment as for [ProverClient::new]. The environment is not modified.
+    ///
+    /// ### Examples
+    ///
+    /// ```no_run
+    /// use sp1_sdk::ProverClient;
+    ///
+    /// let client = ProverClient::from_config("sp1.toml").unwrap();
+    /// ```
+    pub fn from_config(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let config = SP1Config::from_file(path)?;
+        if let Some(threads) = config.pool_threads {
+            pool::set_num_threads(threads)?;
+        }
+
+        match config.resolve_backend()? {
+            config::Backend::Mock => Ok(Self::mock()),
+            config::Backend::Local => Ok(Self {
+                #[cfg(not(feature = "cuda"))]
+                prover: Arc::new(CpuProver::new().with_opts(config.proof_opts())),
+                #[cfg(feature = "cuda")]
+                prover: Arc::new(CudaProver::new().with_opts(config.proof_opts())),
+            }),
+            config::Backend::Network => {
+                cfg_if! {
+                    if #[cfg(feature = "network")] {
+                        Ok(Self { prover: Arc::new(config.network_prover()?) })
+                    } else {
+                        anyhow::bail!("network feature is not enabled")
+                    }
+                }
+            }
+        }
+    }
+
     /// Prepare to execute the given program on the given input (without generating a proof).
     /// The returned [action::Execute] may be configured via its methods before running.
     /// For example, calling [action::Execute::with_hook] registers hooks for execution.
@@ -215,6 +314,66 @@ impl ProverClient {
     /// ```
     pub fn prove<'a>(&'a self, pk: &'a SP1ProvingKey, stdin: SP1Stdin) -> action::Prove<'a> {
         action::Prove::new(self.prover.as_ref(), pk, stdin)
+            .with_shared_prover(Arc::clone(&self.prover))
+    }
+
+    /// Proves the execution of the given program with the given input on a dedicated thread pool,
+    /// returning a future that resolves to the proof.
+    ///
+    /// Unlike wrapping [action::Prove::run] in `spawn_blocking`, dropping the returned
+    /// [ProveFuture] cancels the proof, for example when a request times out. The proof is
+    /// generated with the default options and context.
+    ///
+    /// ### Examples
+    /// ```no_run
+    /// use sp1_sdk::{ProverClient, SP1ProofKind, SP1Stdin};
+    ///
+    /// # async fn prove() {
+    /// let elf = include_bytes!("../../../examples/fibonacci/program/elf/riscv32im-succinct-zkvm-elf");
+    /// let client = ProverClient::new();
+    /// let (pk, vk) = client.setup(elf);
+    /// let mut stdin = SP1Stdin::new();
+    /// stdin.write(&10usize);
+    /// let proof = client.prove_async(&pk, stdin, SP1ProofKind::Compressed).await.unwrap();
+    /// # }
+    /// ```
+    pub fn prove_async(
+        &self,
+        pk: &SP1ProvingKey,
+        stdin: SP1Stdin,
+        kind: SP1ProofKind,
+    ) -> ProveFuture {
+        let prover = Arc::clone(&self.prover);
+        let pk = pk.clone();
+        ProveFuture::spawn(move |handle| {
+            let context = SP1Context::builder()
+                .cancel_flag(handle.cancel_flag())
+                .shard_progress_callback(handle.shard_progress_callback())
+                .build();
+            prover.prove(&pk, stdin, Default::default(), context, kind)
+        })
+    }
+
+    /// Finishes a Plonk or Groth16 proof whose wrap stage failed, from the [ShrinkCheckpoint] the
+    /// prover saved at `path` before wrapping it, with the options the proof was requested with.
+    /// The checkpoint is removed once the proof is done.
+    ///
+    /// ### Examples
+    /// ```no_run
+    /// use sp1_sdk::ProverClient;
+    ///
+    /// let client = ProverClient::local();
+    /// let proof = client.wrap_from("shrink.bin", Default::default()).unwrap();
+    /// ```
+    pub fn wrap_from(
+        &self,
+        path: impl AsRef<Path>,
+        opts: ProofOpts,
+    ) -> anyhow::Result<SP1ProofWithPublicValues> {
+        let checkpoint = ShrinkCheckpoint::load(&path)?;
+        let proof = self.prover.wrap(checkpoint, opts)?;
+        fs::remove_file(&path)?;
+        Ok(proof)
     }
 
     /// Verifies that the given proof is valid and matches the given verification key produced by
@@ -240,6 +399,197 @@ impl ProverClient {
         self.prover.verify(proof, vk)
     }
 
+    /// Verifies a proof from an untrusted source, after checking that it is within the given
+    /// limits, so that the resources spent verifying it are bounded.
+    ///
+    /// ### Examples
+    /// ```no_run
+    /// use sp1_sdk::{ProverClient, SP1ProofKind, SP1ProofWithPublicValues, VerifierLimits};
+    ///
+    /// let elf = include_bytes!("../../../examples/fibonacci/program/elf/riscv32im-succinct-zkvm-elf");
+    /// let client = ProverClient::new();
+    /// let (_, vk) = client.setup(elf);
+    /// let proof = SP1ProofWithPublicValues::load("proof-with-pis.bin").unwrap();
+    /// let limits = VerifierLimits::new()
+    ///     .max_public_values_size(1024)
+    ///     .allowed_kinds([SP1ProofKind::Compressed, SP1ProofKind::Groth16]);
+    /// client.verify_with_limits(&proof, &vk, &limits).unwrap();
+    /// ```
+    pub fn verify_with_limits(
+        &self,
+        proof: &SP1ProofWithPublicValues,
+        vk: &SP1VerifyingKey,
+        limits: &VerifierLimits,
+    ) -> Result<(), SP1VerificationError> {
+        limits.check(proof)?;
+        self.prover.verify(proof, vk)
+    }
+
+    /// Verifies that a proof, generated with [action::Prove::allow_failure], proves that the
+    /// program halts with the non-zero `exit_code` on the input of the proof. A program that
+    /// panics halts with the exit code 1.
+    ///
+    /// ### Examples
+    /// ```no_run
+    /// use sp1_sdk::{ProverClient, SP1Stdin};
+    ///
+    /// let elf = include_bytes!("../../../examples/fibonacci/program/elf/riscv32im-succinct-zkvm-elf");
+    /// let client = ProverClient::new();
+    /// let (pk, vk) = client.setup(elf);
+    /// let stdin = SP1Stdin::new();
+    /// let proof = client.prove(&pk, stdin).allow_failure().run().unwrap();
+    /// client.verify_failure(&proof, &vk, 1).unwrap();
+    /// ```
+    pub fn verify_failure(
+        &self,
+        proof: &SP1ProofWithPublicValues,
+        vk: &SP1VerifyingKey,
+        exit_code: u32,
+    ) -> Result<(), SP1VerificationError> {
+        self.prover.verify_failure(proof, vk, exit_code)
+    }
+
+    /// Verifies a proof generated with the given circuit version, so that proofs generated before
+    /// an upgrade of the SDK stay verifiable.
+    ///
+    /// Groth16 and PLONK proofs of older versions are verified with the circuit artifacts of their
+    /// version, which must be installed, see [CircuitVersion::artifacts_installed], and with the
+    /// vkey of the program at their version, which must be pinned, see [CircuitVersion::pin_vkey].
+    ///
+    /// ### Examples
+    /// ```no_run
+    /// use sp1_sdk::{CircuitVersion, ProverClient, SP1ProofWithPublicValues};
+    ///
+    /// let elf = include_bytes!("../../../examples/fibonacci/program/elf/riscv32im-succinct-zkvm-elf");
+    /// let client = ProverClient::new();
+    /// let (_, vk) = client.setup(elf);
+    /// // The vkey of the program at v1.2.0, saved with the SDK of that version by running
+    /// // `std::fs::write("vkey-v1.2.0.txt", vk.bytes32())` after `client.setup(elf)`.
+    /// let vkey = std::fs::read_to_string("vkey-v1.2.0.txt").unwrap();
+    /// CircuitVersion::V1_2_0.pin_vkey(&vk, vkey.trim()).unwrap();
+    /// let proof = SP1ProofWithPublicValues::load("proof-with-pis.bin").unwrap();
+    /// client.verify_with_version(&proof, &vk, CircuitVersion::V1_2_0).unwrap();
+    /// ```
+    pub fn verify_with_version(
+        &self,
+        proof: &SP1ProofWithPublicValues,
+        vk: &SP1VerifyingKey,
+        version: CircuitVersion,
+    ) -> Result<(), SP1VerificationError> {
+        self.prover.verify_with_version(proof, vk, version)
+    }
+
+    /// Gets the circuit versions known to this SDK, from the oldest to the newest.
+    pub fn supported_circuit_versions(&self) -> &'static [CircuitVersion] {
+        CircuitVersion::ALL
+    }
+
+    /// Gets the circuit versions whose Groth16 and PLONK proofs can be verified right away, which
+    /// are the current version and the older versions with installed artifacts.
+    pub fn verifiable_circuit_versions(&self) -> Vec<CircuitVersion> {
+        CircuitVersion::ALL
+            .iter()
+            .copied()
+            .filter(|version| version.is_current() || version.artifacts_installed())
+            .collect()
+    }
+
+    /// Verifies several proofs, each with the verification key of its program.
+    ///
+    /// Groth16 proofs are verified together with a single pairing check, which is much cheaper
+    /// than verifying them one by one, for example when checking the proofs of a rollup batch.
+    ///
+    /// ### Examples
+    /// ```no_run
+    /// use sp1_sdk::{ProverClient, SP1Stdin};
+    ///
+    /// let elf = include_bytes!("../../../examples/fibonacci/program/elf/riscv32im-succinct-zkvm-elf");
+    /// let client = ProverClient::new();
+    /// let (pk, vk) = client.setup(elf);
+    /// let proofs = (0..4usize)
+    ///     .map(|n| {
+    ///         let mut stdin = SP1Stdin::new();
+    ///         stdin.write(&n);
+    ///         client.prove(&pk, stdin).groth16().run().unwrap()
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// client.verify_batch(&proofs.iter().map(|proof| (proof, &vk)).collect::<Vec<_>>()).unwrap();
+    /// ```
+    pub fn verify_batch(
+        &self,
+        proofs: &[(&SP1ProofWithPublicValues, &SP1VerifyingKey)],
+    ) -> Result<(), SP1VerificationError> {
+        self.prover.verify_batch(proofs)
+    }
+
+    /// Verifies an attestation: that it was signed by `signer` over the digest of `vk`, and that
+    /// its proof is valid for `vk`.
+    ///
+    /// ### Examples
+    /// ```no_run
+    /// use sp1_sdk::{ProverClient, SP1Attestation, SP1Stdin};
+    ///
+    /// let elf = include_bytes!("../../../examples/fibonacci/program/elf/riscv32im-succinct-zkvm-elf");
+    /// let client = ProverClient::new();
+    /// let (pk, vk) = client.setup(elf);
+    /// let proof = client.prove(&pk, SP1Stdin::new()).run().unwrap();
+    /// let private_key = std::env::var("SP1_ATTESTATION_KEY").unwrap();
+    /// let attestation = SP1Attestation::sign(proof, &vk, &private_key).unwrap();
+    ///
+    /// // The address of the key the prover is known to sign with.
+    /// let prover: [u8; 20] = hex::decode("7e5f4552091a69125d5dfcb7b8c2659029395bdf")
+    ///     .unwrap()
+    ///     .try_into()
+    ///     .unwrap();
+    /// client.verify_attestation(&attestation, &vk, prover).unwrap();
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn verify_attestation(
+        &self,
+        attestation: &SP1Attestation,
+        vk: &SP1VerifyingKey,
+        signer: [u8; 20],
+    ) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            attestation.signer == signer,
+            "the attestation was signed by 0x{}, expected 0x{}",
+            hex::encode(attestation.signer),
+            hex::encode(signer)
+        );
+        anyhow::ensure!(
+            attestation.vkey_digest == vk.bytes32(),
+            "the attestation is for the vkey {}, expected {}",
+            attestation.vkey_digest,
+            vk.bytes32()
+        );
+        attestation.verify_signature()?;
+        self.verify(&attestation.proof, vk)?;
+        Ok(())
+    }
+
+    /// Checks whether the prover is ready to generate proofs with the default options.
+    ///
+    /// The returned [health::HealthReport] checks that the circuit artifacts are installed and
+    /// that the machine has enough memory and disk for the configured shard size. It is suitable
+    /// for serving readiness probes.
+    ///
+    /// ### Examples
+    /// ```no_run
+    /// use sp1_sdk::ProverClient;
+    ///
+    /// let client = ProverClient::new();
+    /// let report = client.healthcheck();
+    /// assert!(report.ready);
+    /// ```
+    pub fn healthcheck(&self) -> health::HealthReport {
+        health::HealthReport::new(self.prover.id(), &SP1CoreOpts::default())
+    }
+
+    /// Returns the underlying prover implementation.
+    pub fn prover(&self) -> &dyn Prover<DefaultProverComponents> {
+        self.prover.as_ref()
+    }
+
     /// Gets the current version of the SP1 zkVM.
     ///
     /// Note: This is not the same as the version of the SP1 SDK.
@@ -266,6 +616,24 @@ impl ProverClient {
     pub fn setup(&self, elf: &[u8]) -> (SP1ProvingKey, SP1VerifyingKey) {
         self.prover.setup(elf)
     }
+
+    /// Computes the verifying key of a program, without computing its proving key.
+    ///
+    /// This is much cheaper than [ProverClient::setup] and does not need a prover, which makes it
+    /// suitable for pinning or comparing the verifying keys of programs, for example in CI. The key
+    /// is the same as the one returned by [ProverClient::setup].
+    ///
+    /// ### Examples
+    /// ```no_run
+    /// use sp1_sdk::{HashableKey, ProverClient};
+    ///
+    /// let elf = include_bytes!("../../../examples/fibonacci/program/elf/riscv32im-succinct-zkvm-elf");
+    /// let vk = ProverClient::vkey_only(elf);
+    /// println!("{}", vk.bytes32());
+    /// ```
+    pub fn vkey_only(elf: &[u8]) -> SP1VerifyingKey {
+        SP1Prover::<DefaultProverComponents>::uninitialized().setup_vk(elf)
+    }
 }
 
 impl Default for ProverClient {
@@ -274,6 +642,29 @@ impl Default for ProverClient {
     }
 }
 
+/// Includes the ELF of the program built by `sp1_build::build_program` with the given package name.
+///
+/// The build script of the crate exposes the path of the ELF as the `SP1_ELF_<package name>`
+/// environment variable, so the program is found by its name instead of by a path relative to the
+/// source file.
+///
+/// ```rust,ignore
+/// const ELF: &[u8] = sp1_sdk::include_elf!("fibonacci-program");
+/// ```
+#[macro_export]
+macro_rules! include_elf {
+    ($name:tt) => {{
+        include_bytes!(env!(
+            concat!("SP1_ELF_", $name),
+            concat!(
+                "the ELF of `",
+                $name,
+                "` was not found, build it with `sp1_build::build_program` in the build script"
+            )
+        ))
+    }};
+}
+
 /// Utility method for blocking on an async function.
 ///
 /// If we're already in a tokio runtime, we'll block in place. Otherwise, we'll create a new
@@ -295,7 +686,7 @@ mod tests {
 
     use sp1_prover::init::SP1PublicValues;
 
-    use crate::{utils, CostEstimator, ProverClient, SP1Stdin};
+    use crate::{utils, CostEstimator, HashableKey, ProveStatus, ProverClient, SP1Proof, SP1Stdin};
 
     #[test]
     fn test_execute() {
@@ -309,6 +700,19 @@ mod tests {
         tracing::info!("gas = {}", report.estimate_gas());
     }
 
+    #[test]
+    fn test_execute_with_commitment() {
+        utils::setup_logger();
+        let client = ProverClient::local();
+        let elf =
+            include_bytes!("../../../examples/fibonacci/program/elf/riscv32im-succinct-zkvm-elf");
+        let mut stdin = SP1Stdin::new();
+        stdin.write(&10usize);
+        let (public_values, report, commitment) =
+            client.execute(elf, stdin.clone()).with_commitment().run().unwrap();
+        assert!(commitment.matches(elf, &stdin, &public_values, report.total_instruction_count()));
+    }
+
     #[test]
     #[should_panic]
     fn test_execute_panic() {
@@ -331,6 +735,16 @@ mod tests {
         client.execute(elf, stdin).max_cycles(1).run().unwrap();
     }
 
+    #[test]
+    fn test_vkey_only() {
+        utils::setup_logger();
+        let client = ProverClient::mock();
+        let elf =
+            include_bytes!("../../../examples/fibonacci/program/elf/riscv32im-succinct-zkvm-elf");
+        let (_, vk) = client.setup(elf);
+        assert_eq!(ProverClient::vkey_only(elf).bytes32(), vk.bytes32());
+    }
+
     #[test]
     fn test_e2e_core() {
         utils::setup_logger();
@@ -352,6 +766,54 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_e2e_core_streaming() {
+        utils::setup_logger();
+        let client = ProverClient::local();
+        let elf =
+            include_bytes!("../../../examples/fibonacci/program/elf/riscv32im-succinct-zkvm-elf");
+        let (pk, _) = client.setup(elf);
+        let mut stdin = SP1Stdin::new();
+        stdin.write(&10usize);
+
+        // The streamed shard proofs should match the shards of the final proof.
+        let (tx, rx) = std::sync::mpsc::channel();
+        let proof = client.prove(&pk, stdin).shard_proof_sender(tx).run().unwrap();
+        let streamed: Vec<_> = rx.into_iter().collect();
+        let SP1Proof::Core(shard_proofs) = proof.proof else { panic!("expected a core proof") };
+        assert_eq!(streamed.len(), shard_proofs.len());
+        for (streamed, shard_proof) in streamed.iter().zip(shard_proofs.iter()) {
+            assert_eq!(streamed.public_values, shard_proof.public_values);
+        }
+    }
+
+    #[test]
+    fn test_e2e_core_async() {
+        utils::setup_logger();
+        let client = ProverClient::local();
+        let elf =
+            include_bytes!("../../../examples/fibonacci/program/elf/riscv32im-succinct-zkvm-elf");
+        let (pk, vk) = client.setup(elf);
+        let mut stdin = SP1Stdin::new();
+        stdin.write(&10usize);
+
+        // The handle should report the proof as finished once the future resolves.
+        let (tx, rx) = std::sync::mpsc::channel();
+        let future = client.prove(&pk, stdin).shard_proof_sender(tx).run_async().unwrap();
+        let handle = future.handle();
+        let proof = futures::executor::block_on(future).unwrap();
+        assert_eq!(handle.status(), ProveStatus::Finished);
+        assert!(!handle.is_cancelled());
+        let SP1Proof::Core(shard_proofs) = &proof.proof else { panic!("expected a core proof") };
+        assert_eq!(rx.into_iter().count(), shard_proofs.len());
+        client.verify(&proof, &vk).unwrap();
+
+        // Hooks borrowing from the caller can't be moved to the prover pool.
+        let mut stdin = SP1Stdin::new();
+        stdin.write(&10usize);
+        assert!(client.prove(&pk, stdin).with_hook(30, |_, _| vec![]).run_async().is_err());
+    }
+
     #[test]
     fn test_e2e_compressed() {
         utils::setup_logger();
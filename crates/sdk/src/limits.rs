@@ -0,0 +1,116 @@
+use std::collections::BTreeSet;
+
+use crate::{SP1Proof, SP1ProofKind, SP1ProofWithPublicValues, SP1VerificationError};
+
+/// Limits on the proofs accepted by
+/// [ProverClient::verify_with_limits](crate::ProverClient::verify_with_limits).
+///
+/// Services verifying proofs from untrusted sources use them to bound the resources spent on each
+/// proof. The limits are checked before the proof is verified, so a proof that exceeds them is
+/// rejected without running the verifier. No limit is set by default.
+///
+/// ### Examples
+/// ```
+/// use sp1_sdk::{limits::VerifierLimits, SP1ProofKind};
+///
+/// let limits = VerifierLimits::new()
+///     .max_public_values_size(1024)
+///     .allowed_kinds([SP1ProofKind::Compressed, SP1ProofKind::Groth16]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VerifierLimits {
+    /// The maximum size of the public values, in bytes.
+    pub max_public_values_size: Option<usize>,
+    /// The maximum number of shard proofs of a core proof. Other proofs have a single shard.
+    pub max_shards: Option<usize>,
+    /// The kinds of proofs that are accepted, or all of them if `None`.
+    pub allowed_kinds: Option<BTreeSet<SP1ProofKind>>,
+}
+
+impl VerifierLimits {
+    /// Creates limits that accept every proof.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum size of the public values, in bytes.
+    pub fn max_public_values_size(mut self, size: usize) -> Self {
+        self.max_public_values_size = Some(size);
+        self
+    }
+
+    /// Sets the maximum number of shard proofs of a core proof.
+    pub fn max_shards(mut self, shards: usize) -> Self {
+        self.max_shards = Some(shards);
+        self
+    }
+
+    /// Sets the kinds of proofs that are accepted.
+    pub fn allowed_kinds(mut self, kinds: impl IntoIterator<Item = SP1ProofKind>) -> Self {
+        self.allowed_kinds = Some(kinds.into_iter().collect());
+        self
+    }
+
+    /// Checks that a proof is within the limits.
+    pub fn check(&self, bundle: &SP1ProofWithPublicValues) -> Result<(), SP1VerificationError> {
+        let kind = SP1ProofKind::from(&bundle.proof);
+        if let Some(allowed_kinds) = &self.allowed_kinds {
+            if !allowed_kinds.contains(&kind) {
+                return Err(SP1VerificationError::DisallowedProofKind(kind));
+            }
+        }
+        if let Some(max_size) = self.max_public_values_size {
+            let size = bundle.public_values.as_slice().len();
+            if size > max_size {
+                return Err(SP1VerificationError::PublicValuesTooLarge(size, max_size));
+            }
+        }
+        if let Some(max_shards) = self.max_shards {
+            let shards = match &bundle.proof {
+                SP1Proof::Core(shard_proofs) => shard_proofs.len(),
+                _ => 1,
+            };
+            if shards > max_shards {
+                return Err(SP1VerificationError::TooManyShards(shards, max_shards));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sp1_core_machine::io::{SP1PublicValues, SP1Stdin};
+
+    use super::*;
+    use crate::provers::mock_shard_proof;
+
+    #[test]
+    fn test_verifier_limits() {
+        let bundle = SP1ProofWithPublicValues {
+            proof: SP1Proof::Core(vec![mock_shard_proof(); 3]),
+            stdin: SP1Stdin::new(),
+            public_values: SP1PublicValues::from(&[0; 64]),
+            sp1_version: String::new(),
+        };
+        assert!(VerifierLimits::new().check(&bundle).is_ok());
+        assert!(VerifierLimits::new()
+            .max_public_values_size(64)
+            .max_shards(3)
+            .check(&bundle)
+            .is_ok());
+
+        assert!(matches!(
+            VerifierLimits::new().max_public_values_size(63).check(&bundle),
+            Err(SP1VerificationError::PublicValuesTooLarge(64, 63))
+        ));
+        assert!(matches!(
+            VerifierLimits::new().max_shards(2).check(&bundle),
+            Err(SP1VerificationError::TooManyShards(3, 2))
+        ));
+        assert!(matches!(
+            VerifierLimits::new().allowed_kinds([SP1ProofKind::Groth16]).check(&bundle),
+            Err(SP1VerificationError::DisallowedProofKind(SP1ProofKind::Core))
+        ));
+    }
+}
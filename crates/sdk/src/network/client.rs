@@ -1,26 +1,27 @@
 use std::{env, time::Duration};
 
 use crate::{
-    network::auth::NetworkAuth,
+    network::{
+        auth::NetworkAuth,
+        inflight::new_idempotency_key,
+        retry::{classify, retry, RateLimiter, RetryConfig},
+    },
     proto::network::{UnclaimProofRequest, UnclaimReason},
 };
 use anyhow::{Context, Ok, Result};
 use futures::{future::join_all, Future};
-use reqwest::{Client as HttpClient, Url};
+use reqwest::{header::CONTENT_ENCODING, Client as HttpClient, Url};
 use reqwest_middleware::ClientWithMiddleware as HttpClientWithMiddleware;
 use serde::de::DeserializeOwned;
 use sp1_core_machine::io::SP1Stdin;
-use std::{
-    result::Result::Ok as StdOk,
-    time::{SystemTime, UNIX_EPOCH},
-};
+use std::time::{SystemTime, UNIX_EPOCH};
 use twirp::{Client as TwirpClient, ClientError};
 
 use crate::proto::network::{
-    ClaimProofRequest, ClaimProofResponse, CreateProofRequest, FulfillProofRequest,
-    FulfillProofResponse, GetNonceRequest, GetProofRequestsRequest, GetProofRequestsResponse,
-    GetProofStatusRequest, GetProofStatusResponse, NetworkServiceClient, ProofMode, ProofStatus,
-    SubmitProofRequest,
+    ClaimProofRequest, ClaimProofResponse, CreateProofRequest, CreateProofResponse,
+    FulfillProofRequest, FulfillProofResponse, GetNonceRequest, GetProofRequestsRequest,
+    GetProofRequestsResponse, GetProofStatusRequest, GetProofStatusResponse, NetworkServiceClient,
+    ProofMode, ProofStatus, SubmitProofRequest,
 };
 
 /// The default RPC endpoint for the Succinct prover network.
@@ -30,9 +31,18 @@ pub const DEFAULT_PROVER_NETWORK_RPC: &str = "https://rpc.succinct.xyz/";
 const TIMEOUT: Duration = Duration::from_secs(60 * 60);
 
 pub struct NetworkClient {
-    pub rpc: TwirpClient,
+    /// One client per configured RPC endpoint. Requests fail over between them in order.
+    pub rpcs: Vec<TwirpClient>,
+    /// The URLs of the RPC endpoints, in order.
+    pub rpc_urls: Vec<String>,
     pub http: HttpClientWithMiddleware,
     pub auth: NetworkAuth,
+    /// Whether the stdin is uploaded compressed with zstd, with `Content-Encoding: zstd`. The
+    /// endpoint must decode it, which the hosted prover network does not, so this is off by
+    /// default.
+    pub compress_stdin: bool,
+    retry_config: RetryConfig,
+    limiter: RateLimiter,
 }
 
 impl NetworkClient {
@@ -41,8 +51,52 @@ impl NetworkClient {
         env::var("PROVER_NETWORK_RPC").unwrap_or_else(|_| DEFAULT_PROVER_NETWORK_RPC.to_string())
     }
 
+    /// Returns the configured RPC endpoints. `PROVER_NETWORK_RPC` may contain a comma-separated
+    /// list of relay URLs, which are used for failover.
+    pub fn rpc_urls() -> Vec<String> {
+        Self::parse_rpc_urls(&Self::rpc_url())
+    }
+
+    /// Splits a comma-separated list of RPC endpoints, falling back to the default endpoint if it
+    /// is empty.
+    pub fn parse_rpc_urls(urls: &str) -> Vec<String> {
+        let urls: Vec<String> = urls
+            .split(',')
+            .map(|url| url.trim().to_string())
+            .filter(|url| !url.is_empty())
+            .collect();
+        if urls.is_empty() {
+            vec![DEFAULT_PROVER_NETWORK_RPC.to_string()]
+        } else {
+            urls
+        }
+    }
+
     /// Create a new NetworkClient with the given private key for authentication.
+    ///
+    /// Retries are configured from the environment, see [RetryConfig::from_env].
     pub fn new(private_key: &str) -> Self {
+        Self::with_retry_config(private_key, RetryConfig::from_env())
+    }
+
+    /// Create a new NetworkClient with the given private key and retry configuration.
+    ///
+    /// Panics if an endpoint of `PROVER_NETWORK_RPC` is not a valid URL.
+    pub fn with_retry_config(private_key: &str, retry_config: RetryConfig) -> Self {
+        Self::with_rpc_urls(private_key, Self::rpc_urls(), retry_config)
+            .expect("invalid PROVER_NETWORK_RPC")
+    }
+
+    /// Create a new NetworkClient with the given private key, RPC endpoints and retry
+    /// configuration, ignoring `PROVER_NETWORK_RPC`.
+    ///
+    /// Fails if there is no endpoint, or if an endpoint is not a valid URL.
+    pub fn with_rpc_urls(
+        private_key: &str,
+        rpc_urls: Vec<String>,
+        retry_config: RetryConfig,
+    ) -> Result<Self> {
+        anyhow::ensure!(!rpc_urls.is_empty(), "no RPC endpoint was given");
         let auth = NetworkAuth::new(private_key);
 
         let twirp_http_client = HttpClient::builder()
@@ -51,9 +105,15 @@ impl NetworkClient {
             .build()
             .unwrap();
 
-        let rpc_url = Self::rpc_url();
-        let rpc =
-            TwirpClient::new(Url::parse(&rpc_url).unwrap(), twirp_http_client, vec![]).unwrap();
+        let rpcs = rpc_urls
+            .iter()
+            .map(|rpc_url| {
+                let url = Url::parse(rpc_url)
+                    .with_context(|| format!("invalid RPC endpoint '{}'", rpc_url))?;
+                TwirpClient::new(url, twirp_http_client.clone(), vec![])
+                    .with_context(|| format!("invalid RPC endpoint '{}'", rpc_url))
+            })
+            .collect::<Result<_>>()?;
 
         let http_client = HttpClient::builder()
             .pool_max_idle_per_host(0)
@@ -61,22 +121,41 @@ impl NetworkClient {
             .build()
             .unwrap();
 
-        Self { auth, rpc, http: http_client.into() }
+        let limiter = RateLimiter::new(retry_config.max_requests_per_second);
+        Ok(Self {
+            auth,
+            rpcs,
+            rpc_urls,
+            http: http_client.into(),
+            compress_stdin: false,
+            retry_config,
+            limiter,
+        })
     }
 
     /// Gets the latest nonce for this auth's account.
     pub async fn get_nonce(&self) -> Result<u64> {
+        let address = self.auth.get_address().to_vec();
         let res = self
-            .with_error_handling(
-                self.rpc.get_nonce(GetNonceRequest { address: self.auth.get_address().to_vec() }),
-            )
+            .with_error_handling("get_nonce", |rpc| {
+                rpc.get_nonce(GetNonceRequest { address: address.clone() })
+            })
             .await?;
         Ok(res.nonce)
     }
 
-    /// Upload a file to the specified url.
-    async fn upload_file(&self, url: &str, data: Vec<u8>) -> Result<()> {
-        self.http.put(url).body(data).send().await?;
+    /// Upload a file to the specified url, with the given content encoding if any.
+    async fn upload_file(
+        &self,
+        url: &str,
+        data: Vec<u8>,
+        content_encoding: Option<&str>,
+    ) -> Result<()> {
+        let mut request = self.http.put(url).body(data);
+        if let Some(encoding) = content_encoding {
+            request = request.header(CONTENT_ENCODING, encoding);
+        }
+        request.send().await?;
         Ok(())
     }
 
@@ -87,9 +166,9 @@ impl NetworkClient {
         proof_id: &str,
     ) -> Result<(GetProofStatusResponse, Option<P>)> {
         let res = self
-            .with_error_handling(
-                self.rpc.get_proof_status(GetProofStatusRequest { proof_id: proof_id.to_string() }),
-            )
+            .with_error_handling("get_proof_status", |rpc| {
+                rpc.get_proof_status(GetProofStatusRequest { proof_id: proof_id.to_string() })
+            })
             .await
             .context("Failed to get proof status")?;
 
@@ -120,14 +199,22 @@ impl NetworkClient {
         status: ProofStatus,
         circuit_version: Option<&str>,
     ) -> Result<GetProofRequestsResponse> {
-        self.with_error_handling(self.rpc.get_proof_requests(GetProofRequestsRequest {
-            status: status.into(),
-            circuit_version: circuit_version.map(|v| v.to_owned()),
-        }))
+        self.with_error_handling("get_proof_requests", |rpc| {
+            rpc.get_proof_requests(GetProofRequestsRequest {
+                status: status.into(),
+                circuit_version: circuit_version.map(|v| v.to_owned()),
+            })
+        })
         .await
     }
 
     /// Creates a proof request for the given ELF and stdin.
+    ///
+    /// The request is created with a new idempotency key. Its creation is not retried once it
+    /// reached the prover network, use [NetworkProver::recover_inflight] to retry it with the same
+    /// key.
+    ///
+    /// [NetworkProver::recover_inflight]: crate::NetworkProver::recover_inflight
     pub async fn create_proof(
         &self,
         elf: &[u8],
@@ -135,6 +222,21 @@ impl NetworkClient {
         mode: ProofMode,
         circuit_version: &str,
     ) -> Result<String> {
+        let res = self.create_proof_request(mode, circuit_version, &new_idempotency_key()?).await?;
+        self.submit_proof_request(&res, elf, stdin).await?;
+        Ok(res.proof_id)
+    }
+
+    /// Creates a proof request, the first step in requesting a proof.
+    ///
+    /// If the account already created a request with the same idempotency key, the prover network
+    /// returns the existing request instead of creating a new one.
+    pub async fn create_proof_request(
+        &self,
+        mode: ProofMode,
+        circuit_version: &str,
+        idempotency_key: &str,
+    ) -> Result<CreateProofResponse> {
         let start = SystemTime::now();
         let since_the_epoch = start.duration_since(UNIX_EPOCH).expect("Invalid start time");
         let deadline = since_the_epoch.as_secs() + TIMEOUT.as_secs();
@@ -145,20 +247,36 @@ impl NetworkClient {
             .sign_create_proof_message(nonce, deadline, mode.into(), circuit_version)
             .await?;
 
-        let res = self
-            .with_error_handling(self.rpc.create_proof(CreateProofRequest {
+        self.with_error_handling_once("create_proof", |rpc| {
+            rpc.create_proof(CreateProofRequest {
                 signature: create_proof_signature.to_vec(),
                 nonce,
                 deadline,
                 mode: mode.into(),
                 circuit_version: circuit_version.to_string(),
-            }))
-            .await?;
+                idempotency_key: idempotency_key.to_string(),
+            })
+        })
+        .await
+    }
 
+    /// Uploads the ELF and stdin of a created proof request and submits it, the second step in
+    /// requesting a proof.
+    pub async fn submit_proof_request(
+        &self,
+        res: &CreateProofResponse,
+        elf: &[u8],
+        stdin: &SP1Stdin,
+    ) -> Result<()> {
         let program_bytes = bincode::serialize(elf)?;
-        let stdin_bytes = bincode::serialize(&stdin)?;
-        let program_promise = self.upload_file(&res.program_url, program_bytes);
-        let stdin_promise = self.upload_file(&res.stdin_url, stdin_bytes);
+        // The compressed stdin decodes to the same bincode bytes, so the format is unchanged.
+        let (stdin_bytes, stdin_encoding) = if self.compress_stdin {
+            (stdin.to_zstd_bytes()?, Some("zstd"))
+        } else {
+            (bincode::serialize(&stdin)?, None)
+        };
+        let program_promise = self.upload_file(&res.program_url, program_bytes, None);
+        let stdin_promise = self.upload_file(&res.stdin_url, stdin_bytes, stdin_encoding);
         let v = vec![program_promise, stdin_promise];
         let mut results = join_all(v).await;
         results.pop().expect("Failed to upload stdin")?;
@@ -168,14 +286,16 @@ impl NetworkClient {
         let submit_proof_signature =
             self.auth.sign_submit_proof_message(nonce, &res.proof_id).await?;
 
-        self.with_error_handling(self.rpc.submit_proof(SubmitProofRequest {
-            signature: submit_proof_signature.to_vec(),
-            nonce,
-            proof_id: res.proof_id.clone(),
-        }))
+        self.with_error_handling_once("submit_proof", |rpc| {
+            rpc.submit_proof(SubmitProofRequest {
+                signature: submit_proof_signature.to_vec(),
+                nonce,
+                proof_id: res.proof_id.clone(),
+            })
+        })
         .await?;
 
-        Ok(res.proof_id)
+        Ok(())
     }
 
     /// Claim a proof that was requested. This commits to generating a proof and fulfilling it.
@@ -184,11 +304,13 @@ impl NetworkClient {
         let nonce = self.get_nonce().await?;
         let signature = self.auth.sign_claim_proof_message(nonce, proof_id).await?;
 
-        self.with_error_handling(self.rpc.claim_proof(ClaimProofRequest {
-            signature,
-            nonce,
-            proof_id: proof_id.to_string(),
-        }))
+        self.with_error_handling_once("claim_proof", |rpc| {
+            rpc.claim_proof(ClaimProofRequest {
+                signature: signature.clone(),
+                nonce,
+                proof_id: proof_id.to_string(),
+            })
+        })
         .await
     }
 
@@ -207,13 +329,15 @@ impl NetworkClient {
             .sign_unclaim_proof_message(nonce, proof_id.clone(), reason, description.clone())
             .await?;
 
-        self.with_error_handling(self.rpc.unclaim_proof(UnclaimProofRequest {
-            signature,
-            nonce,
-            proof_id,
-            reason: reason.into(),
-            description,
-        }))
+        self.with_error_handling_once("unclaim_proof", |rpc| {
+            rpc.unclaim_proof(UnclaimProofRequest {
+                signature: signature.clone(),
+                nonce,
+                proof_id: proof_id.clone(),
+                reason: reason.into(),
+                description: description.clone(),
+            })
+        })
         .await?;
 
         Ok(())
@@ -225,34 +349,76 @@ impl NetworkClient {
         let nonce = self.get_nonce().await?;
         let signature = self.auth.sign_fulfill_proof_message(nonce, proof_id).await?;
         let res = self
-            .with_error_handling(self.rpc.fulfill_proof(FulfillProofRequest {
-                signature,
-                nonce,
-                proof_id: proof_id.to_string(),
-            }))
+            .with_error_handling_once("fulfill_proof", |rpc| {
+                rpc.fulfill_proof(FulfillProofRequest {
+                    signature: signature.clone(),
+                    nonce,
+                    proof_id: proof_id.to_string(),
+                })
+            })
             .await?;
 
         Ok(res)
     }
 
-    /// Awaits the future, then handles Succinct prover network errors.
-    async fn with_error_handling<T, F>(&self, future: F) -> Result<T>
+    /// Sends a read-only request built by `request` to the configured RPC endpoints, retrying
+    /// and hedging failures according to the [RetryConfig], then handles Succinct prover network
+    /// errors.
+    async fn with_error_handling<'a, T, F>(
+        &'a self,
+        operation: &'static str,
+        request: impl Fn(&'a TwirpClient) -> F,
+    ) -> Result<T>
     where
         F: Future<Output = std::result::Result<T, ClientError>>,
     {
-        let result = future.await;
-        self.handle_twirp_error(result)
+        self.send(operation, true, request).await
+    }
+
+    /// Like [Self::with_error_handling], for a request that changes the state of the prover
+    /// network. It is only retried if it did not reach an endpoint, since it may have been applied
+    /// otherwise.
+    async fn with_error_handling_once<'a, T, F>(
+        &'a self,
+        operation: &'static str,
+        request: impl Fn(&'a TwirpClient) -> F,
+    ) -> Result<T>
+    where
+        F: Future<Output = std::result::Result<T, ClientError>>,
+    {
+        self.send(operation, false, request).await
+    }
+
+    async fn send<'a, T, F>(
+        &'a self,
+        operation: &'static str,
+        idempotent: bool,
+        request: impl Fn(&'a TwirpClient) -> F,
+    ) -> Result<T>
+    where
+        F: Future<Output = std::result::Result<T, ClientError>>,
+    {
+        let res = retry(
+            &self.retry_config,
+            &self.limiter,
+            operation,
+            self.rpcs.len(),
+            idempotent,
+            |endpoint| request(&self.rpcs[endpoint]),
+            classify,
+            Self::handle_twirp_error,
+        )
+        .await?;
+        Ok(res)
     }
 
     /// Handles Twirp errors by formatting them into more readable error messages.
-    fn handle_twirp_error<T>(&self, result: std::result::Result<T, ClientError>) -> Result<T> {
-        match result {
-            StdOk(response) => StdOk(response),
-            Err(ClientError::TwirpError(err)) => {
-                let display_err = format!("error: \"{:?}\" message: {:?}", err.code, err.msg);
-                Err(anyhow::anyhow!(display_err))
+    fn handle_twirp_error(err: ClientError) -> anyhow::Error {
+        match err {
+            ClientError::TwirpError(err) => {
+                anyhow::anyhow!("error: \"{:?}\" message: {:?}", err.code, err.msg)
             }
-            Err(err) => Err(err.into()),
+            err => err.into(),
         }
     }
 }
@@ -0,0 +1,170 @@
+use std::{
+    env, fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sp1_core_machine::io::SP1Stdin;
+
+/// A proof request that was sent to the prover network but whose proof has not been received yet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InflightRequest {
+    /// The key that deduplicates the creation of the request, see [new_idempotency_key].
+    pub idempotency_key: String,
+    /// The proof identifier, once the prover network has created the request.
+    pub proof_id: Option<String>,
+    /// The [ProofMode](crate::proto::network::ProofMode) of the request.
+    pub mode: i32,
+    /// The SP1 circuit version of the request.
+    pub circuit_version: String,
+    /// Whether the program and stdin were uploaded and the request submitted.
+    pub submitted: bool,
+}
+
+/// Returns a new random idempotency key.
+///
+/// A key is generated once per proof request and reused when the creation of the request is
+/// retried, so that the prover network does not create a duplicate request.
+pub fn new_idempotency_key() -> Result<String> {
+    let mut bytes = [0u8; 16];
+    getrandom::getrandom(&mut bytes).context("failed to generate an idempotency key")?;
+    Ok(hex::encode(bytes))
+}
+
+/// A directory recording the proof requests that are in flight, so that a process can re-attach to
+/// the requests of a process that crashed.
+///
+/// Each request is recorded in `<key>.json`. Until it is submitted, the program and stdin are kept
+/// in `<key>.bin` so that the submission can be completed. Since they may be private, requests are
+/// only recorded in a directory chosen by the user.
+#[derive(Debug, Clone)]
+pub struct InflightJournal {
+    dir: PathBuf,
+}
+
+impl InflightJournal {
+    /// Opens the journal in `PROVER_NETWORK_INFLIGHT_DIR`, if it is set.
+    pub fn from_env() -> Option<Self> {
+        env::var_os("PROVER_NETWORK_INFLIGHT_DIR").map(Self::new)
+    }
+
+    /// Opens the journal in the given directory.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Records a new request, with the program and stdin needed to submit it.
+    pub fn insert(&self, request: &InflightRequest, elf: &[u8], stdin: &SP1Stdin) -> Result<()> {
+        fs::create_dir_all(&self.dir)
+            .context("failed to create the in-flight request directory")?;
+        let bytes = bincode::serialize(&(elf, stdin))?;
+        self.write(&self.path(&request.idempotency_key, "bin"), &bytes)?;
+        self.update(request)
+    }
+
+    /// Records the new state of a request. Once it is submitted, its program and stdin are removed.
+    pub fn update(&self, request: &InflightRequest) -> Result<()> {
+        let bytes = serde_json::to_vec_pretty(request)?;
+        self.write(&self.path(&request.idempotency_key, "json"), &bytes)?;
+        if request.submitted {
+            remove_if_exists(&self.path(&request.idempotency_key, "bin"))?;
+        }
+        Ok(())
+    }
+
+    /// Removes a request, once its proof was received or it failed.
+    pub fn remove(&self, idempotency_key: &str) -> Result<()> {
+        remove_if_exists(&self.path(idempotency_key, "bin"))?;
+        remove_if_exists(&self.path(idempotency_key, "json"))
+    }
+
+    /// Returns the request with the given proof identifier, if it is recorded.
+    pub fn find(&self, proof_id: &str) -> Result<Option<InflightRequest>> {
+        Ok(self.list()?.into_iter().find(|request| request.proof_id.as_deref() == Some(proof_id)))
+    }
+
+    /// Returns all the recorded requests.
+    pub fn list(&self) -> Result<Vec<InflightRequest>> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err.into()),
+        };
+        let mut requests = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                match serde_json::from_slice(&fs::read(&path)?) {
+                    Ok(request) => requests.push(request),
+                    Err(err) => log::warn!("skipping corrupted {}: {}", path.display(), err),
+                }
+            }
+        }
+        requests.sort_by(|a: &InflightRequest, b| a.idempotency_key.cmp(&b.idempotency_key));
+        Ok(requests)
+    }
+
+    /// Returns the program and stdin of a request that was not submitted yet.
+    pub fn load_inputs(&self, idempotency_key: &str) -> Result<(Vec<u8>, SP1Stdin)> {
+        let bytes = fs::read(self.path(idempotency_key, "bin"))
+            .context("the program and stdin of the request are missing")?;
+        Ok(bincode::deserialize(&bytes)?)
+    }
+
+    fn path(&self, idempotency_key: &str, extension: &str) -> PathBuf {
+        self.dir.join(format!("{idempotency_key}.{extension}"))
+    }
+
+    /// Writes a file atomically, so that a crash never leaves a partially written record.
+    fn write(&self, path: &Path, bytes: &[u8]) -> Result<()> {
+        let mut file = tempfile::NamedTempFile::new_in(&self.dir)?;
+        file.write_all(bytes)?;
+        file.persist(path)?;
+        Ok(())
+    }
+}
+
+fn remove_if_exists(path: &Path) -> Result<()> {
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err.into()),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inflight_journal() {
+        let dir = tempfile::tempdir().unwrap();
+        let journal = InflightJournal::new(dir.path());
+        assert!(journal.list().unwrap().is_empty());
+
+        let mut request = InflightRequest {
+            idempotency_key: new_idempotency_key().unwrap(),
+            proof_id: None,
+            mode: 1,
+            circuit_version: "v1".to_string(),
+            submitted: false,
+        };
+        let mut stdin = SP1Stdin::new();
+        stdin.write(&42u32);
+        journal.insert(&request, b"elf", &stdin).unwrap();
+        assert_eq!(journal.list().unwrap(), vec![request.clone()]);
+        let (elf, loaded) = journal.load_inputs(&request.idempotency_key).unwrap();
+        assert_eq!(elf, b"elf");
+        assert_eq!(loaded.buffer, stdin.buffer);
+
+        request.proof_id = Some("proofrequest_1".to_string());
+        request.submitted = true;
+        journal.update(&request).unwrap();
+        assert_eq!(journal.find("proofrequest_1").unwrap(), Some(request.clone()));
+        assert!(journal.load_inputs(&request.idempotency_key).is_err());
+
+        journal.remove(&request.idempotency_key).unwrap();
+        assert!(journal.list().unwrap().is_empty());
+    }
+}
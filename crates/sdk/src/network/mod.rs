@@ -1,3 +1,5 @@
 pub mod auth;
 pub mod client;
+pub mod inflight;
 pub mod prover;
+pub mod retry;
@@ -4,7 +4,11 @@ use std::{
 };
 
 use crate::{
-    network::client::{NetworkClient, DEFAULT_PROVER_NETWORK_RPC},
+    network::{
+        client::{NetworkClient, DEFAULT_PROVER_NETWORK_RPC},
+        inflight::{new_idempotency_key, InflightJournal, InflightRequest},
+        retry::{NetworkError, RetryConfig},
+    },
     proto::network::{ProofMode, ProofStatus},
     Prover, SP1Context, SP1ProofKind, SP1ProofWithPublicValues, SP1ProvingKey, SP1VerifyingKey,
 };
@@ -23,6 +27,8 @@ use crate::provers::{CpuProver, ProofOpts, ProverType};
 pub struct NetworkProver {
     client: NetworkClient,
     local_prover: CpuProver,
+    journal: Option<InflightJournal>,
+    skip_simulation: bool,
 }
 
 impl NetworkProver {
@@ -38,8 +44,70 @@ impl NetworkProver {
         let version = SP1_CIRCUIT_VERSION;
         log::info!("Client circuit version: {}", version);
 
+        Self::from_client(NetworkClient::new(private_key))
+    }
+
+    /// Creates a new [NetworkProver] with the given private key and the given configuration for
+    /// retrying failed requests to the prover network.
+    pub fn new_from_key_with_retry_config(private_key: &str, retry_config: RetryConfig) -> Self {
+        Self::from_client(NetworkClient::with_retry_config(private_key, retry_config))
+    }
+
+    /// Creates a new [NetworkProver] with the given private key, which sends its requests to
+    /// `rpc_url` instead of the endpoints set in `PROVER_NETWORK_RPC`.
+    ///
+    /// `rpc_url` may be a comma-separated list of endpoints, which are used for failover. The
+    /// endpoints must serve the API of the prover network, for example a self-hosted prover.
+    /// Fails if an endpoint is not a valid URL.
+    pub fn new_from_key_with_rpc(private_key: &str, rpc_url: &str) -> Result<Self> {
+        Self::new_from_key_with_rpc_urls(
+            private_key,
+            NetworkClient::parse_rpc_urls(rpc_url),
+            RetryConfig::from_env(),
+        )
+    }
+
+    /// Creates a new [NetworkProver] with the given private key, which sends its requests to the
+    /// endpoints `rpc_urls`, tried in order, and retries them with `retry_config`. Fails if there
+    /// is no endpoint, or if an endpoint is not a valid URL.
+    pub fn new_from_key_with_rpc_urls(
+        private_key: &str,
+        rpc_urls: Vec<String>,
+        retry_config: RetryConfig,
+    ) -> Result<Self> {
+        Ok(Self::from_client(NetworkClient::with_rpc_urls(private_key, rpc_urls, retry_config)?))
+    }
+
+    /// Creates a [NetworkProver] sending its requests with `client`, which records them in
+    /// `PROVER_NETWORK_INFLIGHT_DIR` and simulates them unless `SKIP_SIMULATION` is `true`.
+    fn from_client(client: NetworkClient) -> Self {
         let local_prover = CpuProver::new();
-        Self { client: NetworkClient::new(private_key), local_prover }
+        let journal = InflightJournal::from_env();
+        let skip_simulation = env::var("SKIP_SIMULATION").map(|val| val == "true").unwrap_or(false);
+        Self { client, local_prover, journal, skip_simulation }
+    }
+
+    /// Uploads the stdin of the proof requests compressed with zstd, sent with
+    /// `Content-Encoding: zstd`. Only use it with endpoints that decode the uploads, such as
+    /// self-hosted provers. The hosted prover network does not decode them, so the option has no
+    /// effect there.
+    pub fn with_compressed_stdin(mut self) -> Self {
+        self.client.compress_stdin = true;
+        self
+    }
+
+    /// Records the proof requests in flight in `journal`, so that they can be recovered with
+    /// [Self::recover_inflight], instead of in `PROVER_NETWORK_INFLIGHT_DIR`.
+    pub fn with_inflight_journal(mut self, journal: InflightJournal) -> Self {
+        self.journal = Some(journal);
+        self
+    }
+
+    /// Whether to skip the local execution of the programs before requesting their proofs,
+    /// instead of `SKIP_SIMULATION`.
+    pub fn with_skip_simulation(mut self, skip_simulation: bool) -> Self {
+        self.skip_simulation = skip_simulation;
+        self
     }
 
     /// Requests a proof from the prover network, returning the proof ID.
@@ -49,11 +117,7 @@ impl NetworkProver {
         stdin: SP1Stdin,
         mode: ProofMode,
     ) -> Result<String> {
-        let client = &self.client;
-
-        let skip_simulation = env::var("SKIP_SIMULATION").map(|val| val == "true").unwrap_or(false);
-
-        if !skip_simulation {
+        if !self.skip_simulation {
             let (_, report) =
                 self.local_prover.sp1_prover().execute(elf, &stdin, Default::default())?;
             log::info!("Simulation complete, cycles: {}", report.total_instruction_count());
@@ -61,10 +125,10 @@ impl NetworkProver {
             log::info!("Skipping simulation");
         }
 
-        let proof_id = client.create_proof(elf, &stdin, mode, SP1_CIRCUIT_VERSION).await?;
+        let proof_id = self.submit_inflight(elf, &stdin, mode).await?;
         log::info!("Created {}", proof_id);
 
-        if NetworkClient::rpc_url() == DEFAULT_PROVER_NETWORK_RPC {
+        if self.client.rpc_urls[0] == DEFAULT_PROVER_NETWORK_RPC {
             log::info!("View in explorer: https://explorer.succinct.xyz/{}", proof_id);
         }
         Ok(proof_id)
@@ -91,6 +155,7 @@ impl NetworkProver {
 
             match status.status() {
                 ProofStatus::ProofFulfilled => {
+                    self.forget_inflight(proof_id);
                     return Ok(maybe_proof.unwrap());
                 }
                 ProofStatus::ProofClaimed => {
@@ -100,6 +165,7 @@ impl NetworkProver {
                     }
                 }
                 ProofStatus::ProofUnclaimed => {
+                    self.forget_inflight(proof_id);
                     return Err(anyhow::anyhow!(
                         "Proof generation failed: {}",
                         status.unclaim_description()
@@ -111,6 +177,122 @@ impl NetworkProver {
         }
     }
 
+    /// Re-attaches to the proof requests that were sent by a process that crashed before receiving
+    /// their proofs, and returns their proof IDs, which can then be passed to [Self::wait_proof].
+    ///
+    /// Requests are only recorded if `PROVER_NETWORK_INFLIGHT_DIR` is set, or a journal is passed
+    /// to [Self::with_inflight_journal], until their proof is received or they fail. Requests
+    /// whose submission was interrupted are submitted again with the same idempotency key, so
+    /// the prover network does not create duplicate requests. Processes proving at the same
+    /// time should use different directories, or they will also re-attach to the requests of
+    /// each other.
+    pub async fn recover_inflight(&self) -> Result<Vec<String>> {
+        let Some(journal) = &self.journal else {
+            return Err(anyhow::anyhow!(
+                "in-flight requests are not recorded, set PROVER_NETWORK_INFLIGHT_DIR to record them"
+            ));
+        };
+        let mut proof_ids = Vec::new();
+        for mut request in journal.list()? {
+            if !request.submitted {
+                let (elf, stdin) = match journal.load_inputs(&request.idempotency_key) {
+                    Ok(inputs) => inputs,
+                    Err(err) => {
+                        log::warn!("dropping in-flight request that cannot be submitted: {}", err);
+                        journal.remove(&request.idempotency_key)?;
+                        continue;
+                    }
+                };
+                let mode = ProofMode::try_from(request.mode).unwrap_or(ProofMode::Unspecified);
+                let res = self
+                    .client
+                    .create_proof_request(mode, &request.circuit_version, &request.idempotency_key)
+                    .await?;
+                request.proof_id = Some(res.proof_id.clone());
+                self.record_inflight(|journal| journal.update(&request));
+                let (status, _) =
+                    self.client.get_proof_status::<SP1ProofWithPublicValues>(&res.proof_id).await?;
+                if status.status() == ProofStatus::ProofPreparing {
+                    self.client.submit_proof_request(&res, &elf, &stdin).await?;
+                }
+                request.submitted = true;
+                self.record_inflight(|journal| journal.update(&request));
+            }
+            let Some(proof_id) = request.proof_id else {
+                return Err(anyhow::anyhow!(
+                    "in-flight request {} was submitted without a proof id",
+                    request.idempotency_key
+                ));
+            };
+            log::info!("Recovered {}", proof_id);
+            proof_ids.push(proof_id);
+        }
+        Ok(proof_ids)
+    }
+
+    /// Creates and submits a proof request, recording it until its proof is received so that it
+    /// can be recovered with [Self::recover_inflight].
+    async fn submit_inflight(
+        &self,
+        elf: &[u8],
+        stdin: &SP1Stdin,
+        mode: ProofMode,
+    ) -> Result<String> {
+        let mut request = InflightRequest {
+            idempotency_key: new_idempotency_key()?,
+            proof_id: None,
+            mode: mode.into(),
+            circuit_version: SP1_CIRCUIT_VERSION.to_string(),
+            submitted: false,
+        };
+        self.record_inflight(|journal| journal.insert(&request, elf, stdin));
+
+        let res = match self
+            .client
+            .create_proof_request(mode, SP1_CIRCUIT_VERSION, &request.idempotency_key)
+            .await
+        {
+            Ok(res) => res,
+            Err(err) => {
+                // The request may have been created if the creation failed after reaching the
+                // network, so it is kept to be recovered. Otherwise there is nothing to recover.
+                if !matches!(
+                    err.downcast_ref::<NetworkError>(),
+                    Some(NetworkError::Interrupted { operation: "create_proof", .. })
+                ) {
+                    self.record_inflight(|journal| journal.remove(&request.idempotency_key));
+                }
+                return Err(err);
+            }
+        };
+        request.proof_id = Some(res.proof_id.clone());
+        self.record_inflight(|journal| journal.update(&request));
+
+        self.client.submit_proof_request(&res, elf, stdin).await?;
+        request.submitted = true;
+        self.record_inflight(|journal| journal.update(&request));
+        Ok(res.proof_id)
+    }
+
+    /// Removes a request from the in-flight requests, once its proof was received or it failed.
+    fn forget_inflight(&self, proof_id: &str) {
+        self.record_inflight(|journal| match journal.find(proof_id)? {
+            Some(request) => journal.remove(&request.idempotency_key),
+            None => Ok(()),
+        });
+    }
+
+    /// Updates the in-flight requests, if they are recorded. Failures are only logged, since the
+    /// requests can still be proven without being recoverable.
+    fn record_inflight(&self, update: impl FnOnce(&InflightJournal) -> Result<()>) {
+        let Some(journal) = &self.journal else {
+            return;
+        };
+        if let Err(err) = update(journal) {
+            log::warn!("failed to record in-flight proof request: {}", err);
+        }
+    }
+
     /// Requests a proof from the prover network and waits for it to be generated.
     pub async fn prove(
         &self,
@@ -146,6 +328,12 @@ impl Prover<DefaultProverComponents> for NetworkProver {
         kind: SP1ProofKind,
     ) -> Result<SP1ProofWithPublicValues> {
         warn_if_not_default(&opts.sp1_prover_opts, &context);
+        if kind == SP1ProofKind::Tiny {
+            anyhow::bail!("tiny proofs are not supported by the network prover");
+        }
+        if context.allow_failure {
+            anyhow::bail!("proofs of failure are not supported by the network prover");
+        }
         block_on(self.prove(&pk.elf, stdin, kind.into(), opts.timeout))
     }
 }
@@ -164,7 +352,7 @@ fn warn_if_not_default(opts: &SP1ProverOpts, context: &SP1Context) {
         tracing::warn!("custom SP1ProverOpts are currently unsupported by the network prover");
     }
     // Exhaustive match is done to ensure we update the warnings if the types change.
-    let SP1Context { hook_registry, subproof_verifier, .. } = context;
+    let SP1Context { hook_registry, subproof_verifier, shard_proof_sender, .. } = context;
     if hook_registry.is_some() {
         tracing::warn!("non-default context.hook_registry will be ignored: {:?}", hook_registry);
         tracing::warn!("custom runtime hooks are currently unsupported by the network prover");
@@ -174,6 +362,10 @@ fn warn_if_not_default(opts: &SP1ProverOpts, context: &SP1Context) {
         tracing::warn!("non-default context.subproof_verifier will be ignored");
         tracing::warn!("custom subproof verifiers are currently unsupported by the network prover");
     }
+    if shard_proof_sender.is_some() {
+        tracing::warn!("non-default context.shard_proof_sender will be ignored");
+        tracing::warn!("streaming shard proofs is currently unsupported by the network prover");
+    }
 }
 
 impl From<SP1ProofKind> for ProofMode {
@@ -183,6 +375,8 @@ impl From<SP1ProofKind> for ProofMode {
             SP1ProofKind::Compressed => Self::Compressed,
             SP1ProofKind::Plonk => Self::Plonk,
             SP1ProofKind::Groth16 => Self::Groth16,
+            // The network has no tiny proof mode.
+            SP1ProofKind::Tiny => Self::Unspecified,
         }
     }
 }
@@ -0,0 +1,360 @@
+use std::{future::Future, time::Duration};
+
+use futures::future::select_ok;
+use thiserror::Error;
+use tokio::{
+    sync::Mutex,
+    time::{sleep, sleep_until, timeout, Instant},
+};
+use twirp::{ClientError, TwirpErrorCode};
+
+/// Configuration for how requests to the prover network are retried, hedged, and rate limited.
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    /// The maximum number of times a failed request is retried before giving up.
+    pub max_retries: usize,
+    /// The delay before the first retry. Each subsequent retry doubles the delay.
+    pub initial_backoff: Duration,
+    /// The maximum delay between two retries.
+    pub max_backoff: Duration,
+    /// If set, a second request is sent to the next endpoint when the first one has not
+    /// completed within this duration, and whichever succeeds first is used. Only idempotent
+    /// requests are hedged.
+    pub hedge_after: Option<Duration>,
+    /// If set, the maximum number of requests sent to the prover network per second.
+    pub max_requests_per_second: Option<u32>,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(30),
+            hedge_after: None,
+            max_requests_per_second: None,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Creates a [RetryConfig] with the defaults, overridden by `PROVER_NETWORK_MAX_RETRIES` and
+    /// `PROVER_NETWORK_HEDGE_AFTER_MS` if they are set.
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Some(max_retries) =
+            std::env::var("PROVER_NETWORK_MAX_RETRIES").ok().and_then(|v| v.parse().ok())
+        {
+            config.max_retries = max_retries;
+        }
+        if let Some(hedge_after) =
+            std::env::var("PROVER_NETWORK_HEDGE_AFTER_MS").ok().and_then(|v| v.parse().ok())
+        {
+            config.hedge_after = Some(Duration::from_millis(hedge_after));
+        }
+        config
+    }
+}
+
+/// An error returned by the prover network after retries have been exhausted or the request was
+/// rejected.
+#[derive(Error, Debug)]
+pub enum NetworkError {
+    #[error(
+        "{operation} failed after {attempts} attempts across {endpoints} endpoint(s): {source}"
+    )]
+    RetriesExhausted {
+        operation: &'static str,
+        attempts: usize,
+        endpoints: usize,
+        #[source]
+        source: anyhow::Error,
+    },
+    #[error("{operation} was rejected by the prover network: {source}")]
+    Rejected {
+        operation: &'static str,
+        #[source]
+        source: anyhow::Error,
+    },
+    #[error(
+        "{operation} failed after reaching the prover network, it may have been applied: {source}"
+    )]
+    Interrupted {
+        operation: &'static str,
+        #[source]
+        source: anyhow::Error,
+    },
+}
+
+/// How a failed request is handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Failure {
+    /// The request did not reach the endpoint, so it can be sent again.
+    Unsent,
+    /// The request reached the endpoint but failed for a reason that may not happen again, such as
+    /// a 502. Only idempotent requests are sent again, since the request may have been applied.
+    Transient,
+    /// The request was rejected, so sending it again would fail the same way.
+    Rejected,
+}
+
+/// Spaces requests out so that at most a fixed number are sent per second.
+pub(crate) struct RateLimiter {
+    interval: Option<Duration>,
+    next: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(max_requests_per_second: Option<u32>) -> Self {
+        let interval =
+            max_requests_per_second.filter(|&n| n > 0).map(|n| Duration::from_secs(1) / n);
+        Self { interval, next: Mutex::new(Instant::now()) }
+    }
+
+    /// Waits until the next request is allowed to be sent.
+    pub(crate) async fn acquire(&self) {
+        let Some(interval) = self.interval else {
+            return;
+        };
+        let mut next = self.next.lock().await;
+        let now = Instant::now();
+        if *next > now {
+            sleep_until(*next).await;
+        }
+        *next = (*next).max(now) + interval;
+    }
+}
+
+/// Classifies a failed Twirp request.
+///
+/// Failures to connect are unsent. Server-side errors such as 502s, rate limiting and timeouts
+/// are transient. Errors that indicate a problem with the request itself, and responses that
+/// cannot be decoded, are rejected.
+pub(crate) fn classify(err: &ClientError) -> Failure {
+    match err {
+        ClientError::TwirpError(err) => match err.code {
+            TwirpErrorCode::Unknown |
+            TwirpErrorCode::Internal |
+            TwirpErrorCode::Unavailable |
+            TwirpErrorCode::DeadlineExceeded |
+            TwirpErrorCode::ResourceExhausted |
+            TwirpErrorCode::Aborted => Failure::Transient,
+            _ => Failure::Rejected,
+        },
+        ClientError::HttpError { status, .. } => {
+            if status.is_server_error() || status.as_u16() == 429 {
+                Failure::Transient
+            } else {
+                Failure::Rejected
+            }
+        }
+        ClientError::ReqwestError(err) if err.is_connect() => Failure::Unsent,
+        ClientError::ReqwestError(err) if err.is_timeout() || err.is_request() => {
+            Failure::Transient
+        }
+        // Invalid URLs and headers, undecodable responses and other failures of the client.
+        _ => Failure::Rejected,
+    }
+}
+
+/// Runs `attempt` until it succeeds, retrying failures with exponential backoff and rotating
+/// through `endpoints` endpoints.
+///
+/// `attempt` is called with the index of the endpoint to send the request to, `classify` tells
+/// which failures can be retried, and `into_error` converts the final failure into the error
+/// reported to the caller. Requests that are not `idempotent` are neither hedged nor retried once
+/// they reached an endpoint.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn retry<T, E, Fut>(
+    config: &RetryConfig,
+    limiter: &RateLimiter,
+    operation: &'static str,
+    endpoints: usize,
+    idempotent: bool,
+    mut attempt: impl FnMut(usize) -> Fut,
+    classify: impl Fn(&E) -> Failure,
+    into_error: impl Fn(E) -> anyhow::Error,
+) -> Result<T, NetworkError>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    let endpoints = endpoints.max(1);
+    let mut backoff = config.initial_backoff;
+    let mut attempts = 0;
+    loop {
+        let endpoint = attempts % endpoints;
+        attempts += 1;
+        limiter.acquire().await;
+
+        let mut primary = Box::pin(attempt(endpoint));
+        let result = match config.hedge_after {
+            Some(hedge_after) if idempotent && endpoints > 1 => {
+                match timeout(hedge_after, &mut primary).await {
+                    Ok(result) => result,
+                    Err(_) => {
+                        log::debug!("{} is slow, hedging to another endpoint", operation);
+                        limiter.acquire().await;
+                        let hedge = Box::pin(attempt((endpoint + 1) % endpoints));
+                        select_ok([primary, hedge]).await.map(|(value, _)| value)
+                    }
+                }
+            }
+            _ => primary.await,
+        };
+
+        let err = match result {
+            Ok(value) => return Ok(value),
+            Err(err) => err,
+        };
+        match classify(&err) {
+            Failure::Rejected => {
+                return Err(NetworkError::Rejected { operation, source: into_error(err) });
+            }
+            Failure::Transient if !idempotent => {
+                return Err(NetworkError::Interrupted { operation, source: into_error(err) });
+            }
+            Failure::Unsent | Failure::Transient => {}
+        }
+        if attempts > config.max_retries {
+            return Err(NetworkError::RetriesExhausted {
+                operation,
+                attempts,
+                endpoints,
+                source: into_error(err),
+            });
+        }
+
+        let err = into_error(err);
+        log::warn!(
+            "{} failed (attempt {}), retrying in {:?}: {}",
+            operation,
+            attempts,
+            backoff,
+            err
+        );
+        sleep(backoff).await;
+        backoff = (backoff * 2).min(config.max_backoff);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    fn config() -> RetryConfig {
+        RetryConfig {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(2),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_fails_over_to_next_endpoint() {
+        let limiter = RateLimiter::new(None);
+        let endpoints = Mutex::new(Vec::new());
+        let result = retry(
+            &config(),
+            &limiter,
+            "test",
+            2,
+            true,
+            |endpoint| {
+                let endpoints = &endpoints;
+                async move {
+                    endpoints.lock().await.push(endpoint);
+                    if endpoint == 0 {
+                        Err("bad gateway")
+                    } else {
+                        Ok(endpoint)
+                    }
+                }
+            },
+            |_| Failure::Transient,
+            anyhow::Error::msg,
+        )
+        .await;
+        assert_eq!(result.unwrap(), 1);
+        assert_eq!(*endpoints.lock().await, vec![0, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_retry_exhausted() {
+        let limiter = RateLimiter::new(None);
+        let attempts = AtomicUsize::new(0);
+        let result: Result<(), _> = retry(
+            &config(),
+            &limiter,
+            "test",
+            1,
+            true,
+            |_| {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                async { Err("bad gateway") }
+            },
+            |_| Failure::Transient,
+            anyhow::Error::msg,
+        )
+        .await;
+        assert!(matches!(result, Err(NetworkError::RetriesExhausted { attempts: 4, .. })));
+        assert_eq!(attempts.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn test_retry_does_not_retry_rejected_requests() {
+        let limiter = RateLimiter::new(None);
+        let attempts = AtomicUsize::new(0);
+        let result: Result<(), _> = retry(
+            &config(),
+            &limiter,
+            "test",
+            2,
+            true,
+            |_| {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                async { Err("invalid signature") }
+            },
+            |_| Failure::Rejected,
+            anyhow::Error::msg,
+        )
+        .await;
+        assert!(matches!(result, Err(NetworkError::Rejected { .. })));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_non_idempotent_requests_only_when_unsent() {
+        let limiter = RateLimiter::new(None);
+        let attempts = AtomicUsize::new(0);
+        let result = retry(
+            &config(),
+            &limiter,
+            "test",
+            2,
+            false,
+            |endpoint| {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    match endpoint {
+                        0 => Err("connection refused"),
+                        _ => Err("bad gateway"),
+                    }
+                }
+            },
+            |err: &&str| {
+                if *err == "connection refused" {
+                    Failure::Unsent
+                } else {
+                    Failure::Transient
+                }
+            },
+            anyhow::Error::msg,
+        )
+        .await;
+        assert!(matches!(result, Err::<(), _>(NetworkError::Interrupted { .. })));
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+}
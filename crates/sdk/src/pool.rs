@@ -0,0 +1,217 @@
+//! A dedicated thread pool to generate proofs from async code.
+//!
+//! Proving blocks its thread for a long time, so async services shouldn't prove on their
+//! runtime's worker threads. The pool runs the proofs on its own threads and hands out a
+//! [ProveFuture] for each of them, which cancels the proof when it is dropped, and a [ProofHandle]
+//! to follow the proof from elsewhere.
+
+use std::{
+    future::Future,
+    panic::AssertUnwindSafe,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc, Mutex, OnceLock,
+    },
+    task::{Context, Poll},
+};
+
+use anyhow::{anyhow, Result};
+use futures::channel::oneshot;
+use sp1_core_executor::{ExecutionError, ShardProgress};
+
+use crate::SP1ProofWithPublicValues;
+
+/// The number of threads of the pool, fixed when the pool starts.
+static NUM_THREADS: OnceLock<usize> = OnceLock::new();
+
+/// The number of threads of the pool, which can be set with [set_num_threads] or
+/// `SP1_PROVER_POOL_THREADS`.
+///
+/// Each proof already uses all the cores of the machine, so proofs run one at a time by default.
+fn num_threads() -> usize {
+    *NUM_THREADS.get_or_init(|| {
+        std::env::var("SP1_PROVER_POOL_THREADS")
+            .ok()
+            .and_then(|threads| threads.parse().ok())
+            .filter(|threads| *threads > 0)
+            .unwrap_or(1)
+    })
+}
+
+/// Sets the number of threads of the pool, instead of `SP1_PROVER_POOL_THREADS`.
+///
+/// The pool starts with the first asynchronous proof, after which its number of threads can no
+/// longer be changed: this fails if the pool already has a different number of threads.
+pub fn set_num_threads(threads: usize) -> Result<()> {
+    anyhow::ensure!(threads > 0, "the prover pool needs at least one thread");
+    let current = *NUM_THREADS.get_or_init(|| threads);
+    anyhow::ensure!(
+        current == threads,
+        "the prover pool already has {} threads, it cannot be changed to {}",
+        current,
+        threads
+    );
+    Ok(())
+}
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Runs `job` on the pool, starting the pool on the first call.
+pub(crate) fn spawn(job: impl FnOnce() + Send + 'static) {
+    static JOBS: OnceLock<Mutex<mpsc::Sender<Job>>> = OnceLock::new();
+    let jobs = JOBS.get_or_init(|| {
+        let (tx, rx) = mpsc::channel::<Job>();
+        let rx = Arc::new(Mutex::new(rx));
+        for i in 0..num_threads() {
+            let rx = Arc::clone(&rx);
+            std::thread::Builder::new()
+                .name(format!("sp1-prover-{i}"))
+                .spawn(move || loop {
+                    let job = { rx.lock().unwrap().recv() };
+                    match job {
+                        // A panicking proof only drops its result, which fails its future.
+                        Ok(job) => {
+                            let _ = std::panic::catch_unwind(AssertUnwindSafe(job));
+                        }
+                        Err(_) => break,
+                    }
+                })
+                .expect("failed to spawn prover thread");
+        }
+        Mutex::new(tx)
+    });
+    jobs.lock().unwrap().send(Box::new(job)).expect("prover pool is shut down");
+}
+
+/// The status of a proof running on the prover pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProveStatus {
+    /// The proof waits for a thread of the pool.
+    Queued,
+    /// The program is executed and its shards are committed to.
+    Executing,
+    /// The core shards are proven. Compressed, Plonk and Groth16 proofs keep recursing once all
+    /// of them are proven.
+    Proving(ShardProgress),
+    /// The proof succeeded, failed or was cancelled.
+    Finished,
+}
+
+/// A handle to a proof running on the prover pool, to poll its status or cancel it.
+///
+/// The handle can be cloned and sent to other tasks, and outlives the [ProveFuture] it came from.
+#[derive(Clone)]
+pub struct ProofHandle {
+    cancel_flag: Arc<AtomicBool>,
+    status: Arc<Mutex<ProveStatus>>,
+}
+
+impl ProofHandle {
+    fn new() -> Self {
+        Self {
+            cancel_flag: Arc::new(AtomicBool::new(false)),
+            status: Arc::new(Mutex::new(ProveStatus::Queued)),
+        }
+    }
+
+    /// The current status of the proof.
+    ///
+    /// Only the local CPU prover reports the shards proven so far. Other provers stay
+    /// [ProveStatus::Executing] until they finish.
+    pub fn status(&self) -> ProveStatus {
+        *self.status.lock().unwrap()
+    }
+
+    /// Cancels the proof, which then resolves to [ExecutionError::Cancelled].
+    pub fn cancel(&self) {
+        self.cancel_flag.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether the proof was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel_flag.load(Ordering::Relaxed)
+    }
+
+    /// The cancellation flag of the proof, to pass to its context.
+    pub(crate) fn cancel_flag(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.cancel_flag)
+    }
+
+    /// A callback updating the status as the shards are proven, to pass to the context.
+    pub(crate) fn shard_progress_callback(&self) -> impl Fn(ShardProgress) + Send + Sync {
+        let status = Arc::clone(&self.status);
+        move |progress| *status.lock().unwrap() = ProveStatus::Proving(progress)
+    }
+
+    fn set_status(&self, status: ProveStatus) {
+        *self.status.lock().unwrap() = status;
+    }
+}
+
+/// A proof being generated on the prover pool, returned by [crate::ProverClient::prove_async] and
+/// [crate::action::Prove::run_async].
+///
+/// Dropping the future cancels the proof: a queued proof is skipped, and a running proof stops at
+/// the next shard of the execution or between two proving stages. The future doesn't depend on a
+/// specific async runtime.
+#[must_use = "dropping the future cancels the proof"]
+pub struct ProveFuture {
+    result: oneshot::Receiver<Result<SP1ProofWithPublicValues>>,
+    handle: ProofHandle,
+}
+
+impl ProveFuture {
+    /// Runs `prove` on the prover pool with a new handle.
+    pub(crate) fn spawn(
+        prove: impl FnOnce(&ProofHandle) -> Result<SP1ProofWithPublicValues> + Send + 'static,
+    ) -> Self {
+        let (tx, result) = oneshot::channel();
+        let handle = ProofHandle::new();
+        let job_handle = handle.clone();
+        spawn(move || {
+            let result = if job_handle.is_cancelled() {
+                Err(ExecutionError::Cancelled().into())
+            } else {
+                job_handle.set_status(ProveStatus::Executing);
+                prove(&job_handle)
+            };
+            job_handle.set_status(ProveStatus::Finished);
+            // The receiver is gone if the future was dropped.
+            let _ = tx.send(result);
+        });
+        Self { result, handle }
+    }
+
+    /// A handle to poll the status of the proof or cancel it, which stays valid once the future is
+    /// dropped.
+    pub fn handle(&self) -> ProofHandle {
+        self.handle.clone()
+    }
+
+    /// Cancels the proof, which then resolves to [ExecutionError::Cancelled].
+    pub fn cancel(&self) {
+        self.handle.cancel();
+    }
+
+    /// Whether the proof was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.handle.is_cancelled()
+    }
+}
+
+impl Future for ProveFuture {
+    type Output = Result<SP1ProofWithPublicValues>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.result)
+            .poll(cx)
+            .map(|result| result.unwrap_or_else(|_| Err(anyhow!("prover thread panicked"))))
+    }
+}
+
+impl Drop for ProveFuture {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
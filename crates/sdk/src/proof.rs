@@ -10,7 +10,7 @@ use sp1_stark::{MachineVerificationError, ShardProof};
 
 /// A proof generated with SP1 of a particular proof mode.
 #[derive(Debug, Clone, Serialize, Deserialize, EnumDiscriminants, EnumTryAs)]
-#[strum_discriminants(derive(Default, Hash, PartialOrd, Ord))]
+#[strum_discriminants(derive(Default, Hash, PartialOrd, Ord, Serialize, Deserialize))]
 #[strum_discriminants(name(SP1ProofKind))]
 pub enum SP1Proof {
     #[strum_discriminants(default)]
@@ -18,6 +18,9 @@ pub enum SP1Proof {
     Compressed(ShardProof<InnerSC>),
     Plonk(PlonkBn254Proof),
     Groth16(Groth16Bn254Proof),
+    /// A single core shard proof of a short execution, without recursion. See
+    /// [sp1_prover::tiny].
+    Tiny(ShardProof<CoreSC>),
 }
 
 /// A proof generated with SP1, bundled together with stdin, public values, and the SP1 version.
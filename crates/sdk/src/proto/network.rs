@@ -19,6 +19,10 @@ pub struct CreateProofRequest {
     /// The SP1 circuit version to use for the proof.
     #[prost(string, tag = "5")]
     pub circuit_version: ::prost::alloc::string::String,
+    /// A key chosen by the client to deduplicate retries. If the account already created a proof
+    /// request with the same key, the existing request is returned instead of creating a new one.
+    #[prost(string, tag = "6")]
+    pub idempotency_key: ::prost::alloc::string::String,
 }
 /// The response for creating a proof.
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -1,29 +1,40 @@
+use std::sync::atomic::Ordering;
+
 use anyhow::Result;
-use sp1_core_executor::SP1Context;
+use sp1_core_executor::{ExecutionError, SP1Context};
 use sp1_prover::{components::DefaultProverComponents, SP1Prover, SP1Stdin};
 
 use crate::{
-    install::try_install_circuit_artifacts, provers::ProofOpts, Prover, SP1Proof, SP1ProofKind,
-    SP1ProofWithPublicValues, SP1ProvingKey, SP1VerifyingKey,
+    checkpoint::ShrinkCheckpoint,
+    install::{try_install_circuit_artifacts_with, ArtifactsOpts},
+    provers::ProofOpts,
+    Prover, SP1Proof, SP1ProofKind, SP1ProofWithPublicValues, SP1ProvingKey, SP1VerifyingKey,
 };
 
-use super::ProverType;
+use super::{wrap_with_checkpoint, ProverType};
 
 /// An implementation of [crate::ProverClient] that can generate end-to-end proofs locally.
 pub struct CpuProver {
     prover: SP1Prover<DefaultProverComponents>,
+    opts: Option<ProofOpts>,
 }
 
 impl CpuProver {
     /// Creates a new [LocalProver].
     pub fn new() -> Self {
         let prover = SP1Prover::new();
-        Self { prover }
+        Self { prover, opts: None }
     }
 
     /// Creates a new [LocalProver] from an existing [SP1Prover].
     pub fn from_prover(prover: SP1Prover<DefaultProverComponents>) -> Self {
-        Self { prover }
+        Self { prover, opts: None }
+    }
+
+    /// Starts the proofs from `opts` instead of the options of the environment.
+    pub fn with_opts(mut self, opts: ProofOpts) -> Self {
+        self.opts = Some(opts);
+        self
     }
 }
 
@@ -40,6 +51,14 @@ impl Prover<DefaultProverComponents> for CpuProver {
         &self.prover
     }
 
+    fn default_opts(&self) -> ProofOpts {
+        self.opts.clone().unwrap_or_default()
+    }
+
+    fn artifacts_opts(&self) -> ArtifactsOpts {
+        self.opts.as_ref().map(|opts| opts.artifacts_opts.clone()).unwrap_or_default()
+    }
+
     fn prove<'a>(
         &'a self,
         pk: &SP1ProvingKey,
@@ -48,6 +67,24 @@ impl Prover<DefaultProverComponents> for CpuProver {
         context: SP1Context<'a>,
         kind: SP1ProofKind,
     ) -> Result<SP1ProofWithPublicValues> {
+        // Stop between the stages once the proof is cancelled.
+        let cancel_flag = context.cancel_flag.clone();
+        let check_cancelled = || match &cancel_flag {
+            Some(flag) if flag.load(Ordering::Relaxed) => Err(ExecutionError::Cancelled()),
+            _ => Ok(()),
+        };
+
+        // Generate the tiny proof, which skips the recursion.
+        if kind == SP1ProofKind::Tiny {
+            let mut proof = self.prover.prove_tiny(pk, &stdin, opts.sp1_prover_opts, context)?;
+            return Ok(SP1ProofWithPublicValues {
+                proof: SP1Proof::Tiny(proof.proof.0.remove(0)),
+                stdin: proof.stdin,
+                public_values: proof.public_values,
+                sp1_version: self.version().to_string(),
+            });
+        }
+
         // Generate the core proof.
         let proof = self.prover.prove_core(pk, &stdin, opts.sp1_prover_opts, context)?;
         if kind == SP1ProofKind::Core {
@@ -63,6 +100,7 @@ impl Prover<DefaultProverComponents> for CpuProver {
         let public_values = proof.public_values.clone();
 
         // Generate the compressed proof.
+        check_cancelled()?;
         let reduce_proof =
             self.prover.compress(&pk.vk, proof, deferred_proofs, opts.sp1_prover_opts)?;
         if kind == SP1ProofKind::Compressed {
@@ -75,10 +113,40 @@ impl Prover<DefaultProverComponents> for CpuProver {
         }
 
         // Generate the shrink proof.
-        let compress_proof = self.prover.shrink(reduce_proof, opts.sp1_prover_opts)?;
+        check_cancelled()?;
+        let shrink_proof = self.prover.shrink(reduce_proof, opts.sp1_prover_opts)?;
+
+        // Wrap the shrink proof, from a checkpoint that is kept if the wrap fails.
+        let checkpoint = ShrinkCheckpoint { proof: shrink_proof, stdin, public_values, kind };
+        let checkpoint_dir = opts.checkpoint_dir.clone();
+        wrap_with_checkpoint(checkpoint_dir.as_deref(), &pk.vk, checkpoint, |checkpoint| {
+            self.wrap_checked(checkpoint, opts, check_cancelled)
+        })
+    }
+
+    fn wrap(
+        &self,
+        checkpoint: ShrinkCheckpoint,
+        opts: ProofOpts,
+    ) -> Result<SP1ProofWithPublicValues> {
+        self.wrap_checked(checkpoint, opts, || Ok(()))
+    }
+}
+
+impl CpuProver {
+    /// Wraps the shrink proof of a checkpoint, stopping after the wrap stage if `check_cancelled`
+    /// fails.
+    fn wrap_checked(
+        &self,
+        checkpoint: ShrinkCheckpoint,
+        opts: ProofOpts,
+        check_cancelled: impl Fn() -> Result<(), ExecutionError>,
+    ) -> Result<SP1ProofWithPublicValues> {
+        let ShrinkCheckpoint { proof, stdin, public_values, kind } = checkpoint;
 
         // Genenerate the wrap proof.
-        let outer_proof = self.prover.wrap_bn254(compress_proof, opts.sp1_prover_opts)?;
+        let outer_proof = self.prover.wrap_bn254(proof, opts.sp1_prover_opts)?;
+        check_cancelled()?;
 
         if kind == SP1ProofKind::Plonk {
             let plonk_bn254_aritfacts = if sp1_prover::build::sp1_dev_mode() {
@@ -87,7 +155,7 @@ impl Prover<DefaultProverComponents> for CpuProver {
                     &outer_proof.proof,
                 )
             } else {
-                try_install_circuit_artifacts()
+                try_install_circuit_artifacts_with(&opts.artifacts_opts)
             };
             let proof = self.prover.wrap_plonk_bn254(outer_proof, &plonk_bn254_aritfacts);
 
@@ -104,7 +172,7 @@ impl Prover<DefaultProverComponents> for CpuProver {
                     &outer_proof.proof,
                 )
             } else {
-                try_install_circuit_artifacts()
+                try_install_circuit_artifacts_with(&opts.artifacts_opts)
             };
 
             let proof = self.prover.wrap_groth16_bn254(outer_proof, &groth16_bn254_artifacts);
@@ -116,7 +184,7 @@ impl Prover<DefaultProverComponents> for CpuProver {
             });
         }
 
-        unreachable!()
+        anyhow::bail!("a shrink proof can only be wrapped into a Plonk or Groth16 proof")
     }
 }
 
@@ -2,9 +2,11 @@ use anyhow::Result;
 use sp1_cuda::SP1CudaProver;
 use sp1_prover::{components::DefaultProverComponents, SP1Prover, SP1Stdin};
 
-use super::ProverType;
+use super::{wrap_with_checkpoint, ProverType};
 use crate::{
-    provers::{try_install_circuit_artifacts, ProofOpts},
+    checkpoint::ShrinkCheckpoint,
+    install::{try_install_circuit_artifacts_with, ArtifactsOpts},
+    provers::ProofOpts,
     Prover, SP1Context, SP1Proof, SP1ProofKind, SP1ProofWithPublicValues, SP1ProvingKey,
     SP1VerifyingKey,
 };
@@ -13,6 +15,7 @@ use crate::{
 pub struct CudaProver {
     prover: SP1Prover<DefaultProverComponents>,
     cuda_prover: SP1CudaProver,
+    opts: Option<ProofOpts>,
 }
 
 impl CudaProver {
@@ -20,7 +23,13 @@ impl CudaProver {
     pub fn new() -> Self {
         let prover = SP1Prover::new();
         let cuda_prover = SP1CudaProver::new();
-        Self { prover, cuda_prover }
+        Self { prover, cuda_prover, opts: None }
+    }
+
+    /// Starts the proofs from `opts` instead of the options of the environment.
+    pub fn with_opts(mut self, opts: ProofOpts) -> Self {
+        self.opts = Some(opts);
+        self
     }
 }
 
@@ -37,15 +46,29 @@ impl Prover<DefaultProverComponents> for CudaProver {
         &self.prover
     }
 
+    fn default_opts(&self) -> ProofOpts {
+        self.opts.clone().unwrap_or_default()
+    }
+
+    fn artifacts_opts(&self) -> ArtifactsOpts {
+        self.opts.as_ref().map(|opts| opts.artifacts_opts.clone()).unwrap_or_default()
+    }
+
     fn prove<'a>(
         &'a self,
         pk: &SP1ProvingKey,
         stdin: SP1Stdin,
-        _opts: ProofOpts,
-        _context: SP1Context<'a>,
+        opts: ProofOpts,
+        context: SP1Context<'a>,
         kind: SP1ProofKind,
     ) -> Result<SP1ProofWithPublicValues> {
-        tracing::warn!("opts and context are ignored for the cuda prover");
+        tracing::warn!("the prover opts and the context are ignored for the cuda prover");
+        if kind == SP1ProofKind::Tiny {
+            anyhow::bail!("tiny proofs are not supported by the cuda prover");
+        }
+        if context.allow_failure {
+            anyhow::bail!("proofs of failure are not supported by the cuda prover");
+        }
 
         // Generate the core proof.
         let proof = self.cuda_prover.prove_core(pk, &stdin)?;
@@ -73,10 +96,28 @@ impl Prover<DefaultProverComponents> for CudaProver {
         }
 
         // Generate the shrink proof.
-        let compress_proof = self.cuda_prover.shrink(reduce_proof)?;
+        let shrink_proof = self.cuda_prover.shrink(reduce_proof)?;
+
+        // Wrap the shrink proof, from a checkpoint that is kept if the wrap fails.
+        let checkpoint = ShrinkCheckpoint { proof: shrink_proof, stdin, public_values, kind };
+        let checkpoint_dir = opts.checkpoint_dir.clone();
+        wrap_with_checkpoint(checkpoint_dir.as_deref(), &pk.vk, checkpoint, |checkpoint| {
+            self.wrap(checkpoint, opts)
+        })
+    }
+
+    fn wrap(
+        &self,
+        checkpoint: ShrinkCheckpoint,
+        opts: ProofOpts,
+    ) -> Result<SP1ProofWithPublicValues> {
+        let ShrinkCheckpoint { proof, stdin, public_values, kind } = checkpoint;
+        if kind != SP1ProofKind::Plonk {
+            anyhow::bail!("the cuda prover can only wrap a shrink proof into a Plonk proof");
+        }
 
         // Genenerate the wrap proof.
-        let outer_proof = self.cuda_prover.wrap_bn254(compress_proof)?;
+        let outer_proof = self.cuda_prover.wrap_bn254(proof)?;
 
         let plonk_bn254_aritfacts = if sp1_prover::build::sp1_dev_mode() {
             sp1_prover::build::try_build_plonk_bn254_artifacts_dev(
@@ -84,19 +125,15 @@ impl Prover<DefaultProverComponents> for CudaProver {
                 &outer_proof.proof,
             )
         } else {
-            try_install_circuit_artifacts()
+            try_install_circuit_artifacts_with(&opts.artifacts_opts)
         };
         let proof = self.prover.wrap_plonk_bn254(outer_proof, &plonk_bn254_aritfacts);
-        if kind == SP1ProofKind::Plonk {
-            return Ok(SP1ProofWithPublicValues {
-                proof: SP1Proof::Plonk(proof),
-                stdin,
-                public_values,
-                sp1_version: self.version().to_string(),
-            });
-        }
-
-        unreachable!()
+        Ok(SP1ProofWithPublicValues {
+            proof: SP1Proof::Plonk(proof),
+            stdin,
+            public_values,
+            sp1_version: self.version().to_string(),
+        })
     }
 }
 
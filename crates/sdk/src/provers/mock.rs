@@ -5,8 +5,8 @@ use sp1_core_machine::io::SP1Stdin;
 use sp1_stark::{ShardCommitment, ShardOpenedValues, ShardProof};
 
 use crate::{
-    Prover, SP1Proof, SP1ProofKind, SP1ProofWithPublicValues, SP1ProvingKey, SP1VerificationError,
-    SP1VerifyingKey,
+    CircuitVersion, Prover, SP1Proof, SP1ProofKind, SP1ProofWithPublicValues, SP1ProvingKey,
+    SP1VerificationError, SP1VerifyingKey,
 };
 use anyhow::Result;
 use p3_baby_bear::BabyBear;
@@ -15,10 +15,10 @@ use p3_fri::{FriProof, TwoAdicFriPcsProof};
 use sp1_prover::{
     components::DefaultProverComponents,
     verify::{verify_groth16_bn254_public_inputs, verify_plonk_bn254_public_inputs},
-    Groth16Bn254Proof, HashableKey, PlonkBn254Proof, SP1Prover,
+    CoreSC, Groth16Bn254Proof, HashableKey, PlonkBn254Proof, SP1Prover,
 };
 
-use super::{ProofOpts, ProverType};
+use super::{verify_stdin_size, ProofOpts, ProverType};
 
 /// An implementation of [crate::ProverClient] that can generate mock proofs.
 pub struct MockProver {
@@ -67,25 +67,7 @@ impl Prover<DefaultProverComponents> for MockProver {
             SP1ProofKind::Compressed => {
                 let (public_values, _) = self.prover.execute(&pk.elf, &stdin, context)?;
                 Ok(SP1ProofWithPublicValues {
-                    proof: SP1Proof::Compressed(ShardProof {
-                        commitment: ShardCommitment {
-                            main_commit: [BabyBear::zero(); 8].into(),
-                            permutation_commit: [BabyBear::zero(); 8].into(),
-                            quotient_commit: [BabyBear::zero(); 8].into(),
-                        },
-                        opened_values: ShardOpenedValues { chips: vec![] },
-                        opening_proof: TwoAdicFriPcsProof {
-                            fri_proof: FriProof {
-                                commit_phase_commits: vec![],
-                                query_proofs: vec![],
-                                final_poly: Default::default(),
-                                pow_witness: BabyBear::zero(),
-                            },
-                            query_openings: vec![],
-                        },
-                        chip_ordering: HashMap::new(),
-                        public_values: vec![],
-                    }),
+                    proof: SP1Proof::Compressed(mock_shard_proof()),
                     stdin,
                     public_values,
                     sp1_version: self.version().to_string(),
@@ -97,7 +79,7 @@ impl Prover<DefaultProverComponents> for MockProver {
                     proof: SP1Proof::Plonk(PlonkBn254Proof {
                         public_inputs: [
                             pk.vk.hash_bn254().as_canonical_biguint().to_string(),
-                            public_values.hash_bn254().to_string(),
+                            public_values.hash_bn254_with(pk.vk.public_values_hash).to_string(),
                         ],
                         encoded_proof: "".to_string(),
                         raw_proof: "".to_string(),
@@ -114,7 +96,7 @@ impl Prover<DefaultProverComponents> for MockProver {
                     proof: SP1Proof::Groth16(Groth16Bn254Proof {
                         public_inputs: [
                             pk.vk.hash_bn254().as_canonical_biguint().to_string(),
-                            public_values.hash_bn254().to_string(),
+                            public_values.hash_bn254_with(pk.vk.public_values_hash).to_string(),
                         ],
                         encoded_proof: "".to_string(),
                         raw_proof: "".to_string(),
@@ -125,14 +107,45 @@ impl Prover<DefaultProverComponents> for MockProver {
                     sp1_version: self.version().to_string(),
                 })
             }
+            SP1ProofKind::Tiny => {
+                let (public_values, _) = self.prover.execute(&pk.elf, &stdin, context)?;
+                Ok(SP1ProofWithPublicValues {
+                    proof: SP1Proof::Tiny(mock_shard_proof()),
+                    stdin,
+                    public_values,
+                    sp1_version: self.version().to_string(),
+                })
+            }
         }
     }
 
+    fn verify_batch(
+        &self,
+        bundles: &[(&SP1ProofWithPublicValues, &SP1VerifyingKey)],
+    ) -> Result<(), SP1VerificationError> {
+        // Mock proofs have no pairings to share, so they are verified one by one.
+        bundles.iter().try_for_each(|(bundle, vkey)| self.verify(bundle, vkey))
+    }
+
+    fn verify_with_version(
+        &self,
+        bundle: &SP1ProofWithPublicValues,
+        vkey: &SP1VerifyingKey,
+        version: CircuitVersion,
+    ) -> Result<(), SP1VerificationError> {
+        // Mock proofs are not bound to any circuit artifacts, whatever their version.
+        if bundle.sp1_version != version.as_str() {
+            return Err(SP1VerificationError::VersionMismatch(bundle.sp1_version.clone()));
+        }
+        self.verify(bundle, vkey)
+    }
+
     fn verify(
         &self,
         bundle: &SP1ProofWithPublicValues,
         vkey: &SP1VerifyingKey,
     ) -> Result<(), SP1VerificationError> {
+        verify_stdin_size(bundle, vkey)?;
         match &bundle.proof {
             SP1Proof::Plonk(PlonkBn254Proof { public_inputs, .. }) => {
                 verify_plonk_bn254_public_inputs(vkey, &bundle.public_values, public_inputs)
@@ -145,6 +158,22 @@ impl Prover<DefaultProverComponents> for MockProver {
             _ => Ok(()),
         }
     }
+
+    fn verify_failure(
+        &self,
+        bundle: &SP1ProofWithPublicValues,
+        vkey: &SP1VerifyingKey,
+        exit_code: u32,
+    ) -> Result<(), SP1VerificationError> {
+        if exit_code == 0 {
+            return Err(SP1VerificationError::InvalidExitCode(exit_code));
+        }
+        verify_stdin_size(bundle, vkey)?;
+        match &bundle.proof {
+            SP1Proof::Core(_) => Ok(()),
+            _ => Err(SP1VerificationError::UnsupportedFailureProof),
+        }
+    }
 }
 
 impl Default for MockProver {
@@ -152,3 +181,26 @@ impl Default for MockProver {
         Self::new()
     }
 }
+
+/// A shard proof with no content, standing in for compressed and tiny mock proofs.
+pub(crate) fn mock_shard_proof() -> ShardProof<CoreSC> {
+    ShardProof {
+        commitment: ShardCommitment {
+            main_commit: [BabyBear::zero(); 8].into(),
+            permutation_commit: [BabyBear::zero(); 8].into(),
+            quotient_commit: [BabyBear::zero(); 8].into(),
+        },
+        opened_values: ShardOpenedValues { chips: vec![] },
+        opening_proof: TwoAdicFriPcsProof {
+            fri_proof: FriProof {
+                commit_phase_commits: vec![],
+                query_proofs: vec![],
+                final_poly: Default::default(),
+                pow_witness: BabyBear::zero(),
+            },
+            query_openings: vec![],
+        },
+        chip_ordering: HashMap::new(),
+        public_values: vec![],
+    }
+}
@@ -6,26 +6,31 @@ mod mock;
 pub use cpu::CpuProver;
 #[cfg(feature = "cuda")]
 pub use cuda::CudaProver;
+#[cfg(test)]
+pub(crate) use mock::mock_shard_proof;
 pub use mock::MockProver;
 
 use itertools::Itertools;
 use p3_field::PrimeField32;
 use std::borrow::Borrow;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use sp1_core_executor::SP1Context;
 use sp1_core_machine::{io::SP1Stdin, SP1_CIRCUIT_VERSION};
 use sp1_prover::{
-    components::SP1ProverComponents, CoreSC, InnerSC, SP1CoreProofData, SP1Prover, SP1ProvingKey,
-    SP1ReduceProof, SP1VerifyingKey,
+    components::SP1ProverComponents, tiny::SP1TinyVerifier, CoreSC, InnerSC, SP1CoreProofData,
+    SP1Prover, SP1ProvingKey, SP1ReduceProof, SP1VerifyingKey,
 };
 use sp1_stark::{air::PublicValues, MachineVerificationError, SP1ProverOpts, Word};
 use strum_macros::EnumString;
 use thiserror::Error;
 
 use crate::{
-    install::try_install_circuit_artifacts, SP1Proof, SP1ProofKind, SP1ProofWithPublicValues,
+    checkpoint::ShrinkCheckpoint,
+    install::{try_install_circuit_artifacts_with, ArtifactsOpts},
+    CircuitVersion, SP1Proof, SP1ProofKind, SP1ProofWithPublicValues,
 };
 
 /// The type of prover.
@@ -38,12 +43,28 @@ pub enum ProverType {
 }
 
 /// Options to configure proof generation.
-#[derive(Clone, Default)]
+#[derive(Clone)]
 pub struct ProofOpts {
     /// Options to configure the SP1 prover.
     pub sp1_prover_opts: SP1ProverOpts,
     /// Optional timeout duration for proof generation.
     pub timeout: Option<Duration>,
+    /// Options to configure where the circuit artifacts are downloaded from.
+    pub artifacts_opts: ArtifactsOpts,
+    /// The directory the shrink proofs are saved to before they are wrapped, see
+    /// [ShrinkCheckpoint]. Defaults to [ShrinkCheckpoint::dir].
+    pub checkpoint_dir: Option<PathBuf>,
+}
+
+impl Default for ProofOpts {
+    fn default() -> Self {
+        Self {
+            sp1_prover_opts: SP1ProverOpts::default(),
+            timeout: None,
+            artifacts_opts: ArtifactsOpts::default(),
+            checkpoint_dir: ShrinkCheckpoint::dir(),
+        }
+    }
 }
 
 #[derive(Error, Debug)]
@@ -52,6 +73,12 @@ pub enum SP1VerificationError {
     InvalidPublicValues,
     #[error("Version mismatch")]
     VersionMismatch(String),
+    #[error("Only Groth16 and PLONK proofs of circuit version {0} can be verified")]
+    UnsupportedVersion(CircuitVersion),
+    #[error("The circuit artifacts of version {0} are not installed at {1}")]
+    MissingArtifacts(CircuitVersion, PathBuf),
+    #[error("The vkey of the program at version {0} is not pinned at {1}")]
+    MissingPinnedVkey(CircuitVersion, PathBuf),
     #[error("Core machine verification error: {0}")]
     Core(MachineVerificationError<CoreSC>),
     #[error("Recursion verification error: {0}")]
@@ -60,6 +87,72 @@ pub enum SP1VerificationError {
     Plonk(anyhow::Error),
     #[error("Groth16 verification error: {0}")]
     Groth16(anyhow::Error),
+    #[error("The input of {0} bytes exceeds the maximum of {1} bytes declared by the program")]
+    StdinTooLarge(u64, u64),
+    #[error("Only core proofs can prove that a program fails")]
+    UnsupportedFailureProof,
+    #[error("A program fails with a non-zero exit code, not {0}")]
+    InvalidExitCode(u32),
+    #[error("The public values of {0} bytes exceed the maximum of {1} bytes")]
+    PublicValuesTooLarge(usize, usize),
+    #[error("The proof has {0} shards, more than the maximum of {1}")]
+    TooManyShards(usize, usize),
+    #[error("{0:?} proofs are not accepted")]
+    DisallowedProofKind(SP1ProofKind),
+}
+
+/// Checks that the input carried by the proof is within the maximum size recorded in the vkey, if
+/// any.
+///
+/// The program itself halts on a larger input, so this only rejects proofs that carry another
+/// input than the one they were generated from, before running the verifier.
+pub(crate) fn verify_stdin_size(
+    bundle: &SP1ProofWithPublicValues,
+    vkey: &SP1VerifyingKey,
+) -> Result<(), SP1VerificationError> {
+    let Some(max_stdin_size) = vkey.max_stdin_size else {
+        return Ok(());
+    };
+    let size = bundle.stdin.buffer_size() as u64;
+    if size > max_stdin_size {
+        return Err(SP1VerificationError::StdinTooLarge(size, max_stdin_size));
+    }
+    Ok(())
+}
+
+/// Saves the checkpoint of a proof in `dir` if it is set, then wraps it with `wrap` and removes
+/// the checkpoint once the proof is done, so that a proof whose wrap stage fails can be finished
+/// from the checkpoint.
+pub(crate) fn wrap_with_checkpoint(
+    dir: Option<&Path>,
+    vk: &SP1VerifyingKey,
+    checkpoint: ShrinkCheckpoint,
+    wrap: impl FnOnce(ShrinkCheckpoint) -> Result<SP1ProofWithPublicValues>,
+) -> Result<SP1ProofWithPublicValues> {
+    let Some(dir) = dir else {
+        return wrap(checkpoint);
+    };
+    let path = match checkpoint.new_path(dir, vk).and_then(|path| {
+        checkpoint.save(&path)?;
+        Ok(path)
+    }) {
+        Ok(path) => path,
+        Err(err) => {
+            tracing::warn!("failed to save the shrink proof: {:?}", err);
+            return wrap(checkpoint);
+        }
+    };
+    tracing::info!("saved the shrink proof to {}", path.display());
+    let proof = wrap(checkpoint).with_context(|| {
+        format!(
+            "failed to wrap the shrink proof, finish it with `ProverClient::wrap_from({:?}, opts)`",
+            path
+        )
+    })?;
+    if let Err(err) = std::fs::remove_file(&path) {
+        tracing::warn!("failed to remove {}: {}", path.display(), err);
+    }
+    Ok(proof)
 }
 
 /// An implementation of [crate::ProverClient].
@@ -74,6 +167,17 @@ pub trait Prover<C: SP1ProverComponents>: Send + Sync {
 
     fn setup(&self, elf: &[u8]) -> (SP1ProvingKey, SP1VerifyingKey);
 
+    /// The options that the [crate::action::Prove] builder starts from, read from the environment
+    /// unless the prover was given its own.
+    fn default_opts(&self) -> ProofOpts {
+        ProofOpts::default()
+    }
+
+    /// The options of the circuit artifacts which verify Plonk and Groth16 proofs.
+    fn artifacts_opts(&self) -> ArtifactsOpts {
+        ArtifactsOpts::default()
+    }
+
     /// Prove the execution of a RISCV ELF with the given inputs, according to the given proof mode.
     fn prove<'a>(
         &'a self,
@@ -84,6 +188,16 @@ pub trait Prover<C: SP1ProverComponents>: Send + Sync {
         kind: SP1ProofKind,
     ) -> Result<SP1ProofWithPublicValues>;
 
+    /// Wrap the shrink proof of a checkpoint into a Plonk or Groth16 proof, running only the last
+    /// stage of [Self::prove].
+    fn wrap(
+        &self,
+        _checkpoint: ShrinkCheckpoint,
+        _opts: ProofOpts,
+    ) -> Result<SP1ProofWithPublicValues> {
+        anyhow::bail!("the {:?} prover cannot wrap a shrink proof", self.id())
+    }
+
     /// Verify that an SP1 proof is valid given its vkey and metadata.
     /// For Plonk proofs, verifies that the public inputs of the PlonkBn254 proof match
     /// the hash of the VK and the committed public values of the SP1ProofWithPublicValues.
@@ -95,6 +209,7 @@ pub trait Prover<C: SP1ProverComponents>: Send + Sync {
         if bundle.sp1_version != self.version() {
             return Err(SP1VerificationError::VersionMismatch(bundle.sp1_version.clone()));
         }
+        verify_stdin_size(bundle, vkey)?;
         match &bundle.proof {
             SP1Proof::Core(proof) => {
                 let public_values: &PublicValues<Word<_>, _> =
@@ -108,7 +223,9 @@ pub trait Prover<C: SP1ProverComponents>: Send + Sync {
                     .collect_vec();
 
                 // Make sure the commited value digest matches the public values hash.
-                for (a, b) in commited_value_digest_bytes.iter().zip_eq(bundle.public_values.hash())
+                for (a, b) in commited_value_digest_bytes
+                    .iter()
+                    .zip_eq(bundle.public_values.hash_with(vkey.public_values_hash))
                 {
                     if *a != b {
                         return Err(SP1VerificationError::InvalidPublicValues);
@@ -132,7 +249,9 @@ pub trait Prover<C: SP1ProverComponents>: Send + Sync {
                     .collect_vec();
 
                 // Make sure the commited value digest matches the public values hash.
-                for (a, b) in commited_value_digest_bytes.iter().zip_eq(bundle.public_values.hash())
+                for (a, b) in commited_value_digest_bytes
+                    .iter()
+                    .zip_eq(bundle.public_values.hash_with(vkey.public_values_hash))
                 {
                     if *a != b {
                         return Err(SP1VerificationError::InvalidPublicValues);
@@ -152,7 +271,7 @@ pub trait Prover<C: SP1ProverComponents>: Send + Sync {
                     &if sp1_prover::build::sp1_dev_mode() {
                         sp1_prover::build::plonk_bn254_artifacts_dev_dir()
                     } else {
-                        try_install_circuit_artifacts()
+                        try_install_circuit_artifacts_with(&self.artifacts_opts())
                     },
                 )
                 .map_err(SP1VerificationError::Plonk),
@@ -165,10 +284,151 @@ pub trait Prover<C: SP1ProverComponents>: Send + Sync {
                     &if sp1_prover::build::sp1_dev_mode() {
                         sp1_prover::build::groth16_bn254_artifacts_dev_dir()
                     } else {
-                        try_install_circuit_artifacts()
+                        try_install_circuit_artifacts_with(&self.artifacts_opts())
                     },
                 )
                 .map_err(SP1VerificationError::Groth16),
+            SP1Proof::Tiny(proof) => SP1TinyVerifier::new()
+                .verify(proof, &bundle.public_values, vkey)
+                .map_err(SP1VerificationError::Core),
+        }
+    }
+
+    /// Verify that a core proof, generated with
+    /// [Prove::allow_failure](crate::action::Prove::allow_failure), proves that the program halts
+    /// with the non-zero `exit_code` on the input of the proof, such as by panicking.
+    fn verify_failure(
+        &self,
+        bundle: &SP1ProofWithPublicValues,
+        vkey: &SP1VerifyingKey,
+        exit_code: u32,
+    ) -> Result<(), SP1VerificationError> {
+        if exit_code == 0 {
+            return Err(SP1VerificationError::InvalidExitCode(exit_code));
+        }
+        if bundle.sp1_version != self.version() {
+            return Err(SP1VerificationError::VersionMismatch(bundle.sp1_version.clone()));
+        }
+        verify_stdin_size(bundle, vkey)?;
+        let SP1Proof::Core(proof) = &bundle.proof else {
+            return Err(SP1VerificationError::UnsupportedFailureProof);
+        };
+        let public_values: &PublicValues<Word<_>, _> = proof
+            .last()
+            .ok_or(SP1VerificationError::InvalidPublicValues)?
+            .public_values
+            .as_slice()
+            .borrow();
+
+        // Make sure the commited value digest matches the public values hash.
+        let commited_value_digest_bytes = public_values
+            .committed_value_digest
+            .iter()
+            .flat_map(|w| w.0.iter().map(|x| x.as_canonical_u32() as u8))
+            .collect_vec();
+        for (a, b) in commited_value_digest_bytes
+            .iter()
+            .zip_eq(bundle.public_values.hash_with(vkey.public_values_hash))
+        {
+            if *a != b {
+                return Err(SP1VerificationError::InvalidPublicValues);
+            }
+        }
+
+        // Verify the core proof, which halts with the exit code.
+        self.sp1_prover()
+            .verify_with_exit_code(&SP1CoreProofData(proof.clone()), vkey, exit_code)
+            .map_err(SP1VerificationError::Core)
+    }
+
+    /// Verify that an SP1 proof generated with the given circuit version is valid.
+    ///
+    /// Proofs of the current version are verified with [Prover::verify]. Groth16 and PLONK proofs
+    /// of older versions are verified against the circuit artifacts of their version, which must
+    /// already be installed, and the vkey of the program at their version, which must be pinned
+    /// with [CircuitVersion::pin_vkey]. Their core and compressed proofs cannot be verified.
+    fn verify_with_version(
+        &self,
+        bundle: &SP1ProofWithPublicValues,
+        vkey: &SP1VerifyingKey,
+        version: CircuitVersion,
+    ) -> Result<(), SP1VerificationError> {
+        if bundle.sp1_version != version.as_str() {
+            return Err(SP1VerificationError::VersionMismatch(bundle.sp1_version.clone()));
+        }
+        if version.is_current() {
+            return self.verify(bundle, vkey);
+        }
+
+        let build_dir = version.artifacts_dir();
+        if !matches!(bundle.proof, SP1Proof::Plonk(_) | SP1Proof::Groth16(_)) {
+            return Err(SP1VerificationError::UnsupportedVersion(version));
+        }
+        if !build_dir.exists() {
+            return Err(SP1VerificationError::MissingArtifacts(version, build_dir));
+        }
+        // The proof is bound to the vkey of the program at its version, not to `vkey`.
+        let vkey_hash = version.load_vkey_hash(vkey).ok_or_else(|| {
+            SP1VerificationError::MissingPinnedVkey(version, version.pinned_vkey_path(vkey))
+        })?;
+        match &bundle.proof {
+            SP1Proof::Plonk(proof) => self
+                .sp1_prover()
+                .verify_plonk_bn254_with_vkey_hash(
+                    proof,
+                    &vkey_hash,
+                    &bundle.public_values,
+                    &build_dir,
+                )
+                .map_err(SP1VerificationError::Plonk),
+            SP1Proof::Groth16(proof) => self
+                .sp1_prover()
+                .verify_groth16_bn254_with_vkey_hash(
+                    proof,
+                    &vkey_hash,
+                    &bundle.public_values,
+                    &build_dir,
+                )
+                .map_err(SP1VerificationError::Groth16),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Verify several SP1 proofs, each with the vkey of its program.
+    ///
+    /// The Groth16 proofs of the batch are verified together with a single pairing check, which is
+    /// much cheaper than verifying them one by one. The other proofs are verified individually.
+    fn verify_batch(
+        &self,
+        bundles: &[(&SP1ProofWithPublicValues, &SP1VerifyingKey)],
+    ) -> Result<(), SP1VerificationError> {
+        let mut groth16_proofs = Vec::new();
+        for (bundle, vkey) in bundles {
+            match &bundle.proof {
+                SP1Proof::Groth16(proof) => {
+                    if bundle.sp1_version != self.version() {
+                        return Err(SP1VerificationError::VersionMismatch(
+                            bundle.sp1_version.clone(),
+                        ));
+                    }
+                    verify_stdin_size(bundle, vkey)?;
+                    groth16_proofs.push((proof, *vkey, &bundle.public_values));
+                }
+                _ => self.verify(bundle, vkey)?,
+            }
+        }
+        if groth16_proofs.is_empty() {
+            return Ok(());
         }
+        self.sp1_prover()
+            .verify_groth16_bn254_batch(
+                &groth16_proofs,
+                &if sp1_prover::build::sp1_dev_mode() {
+                    sp1_prover::build::groth16_bn254_artifacts_dev_dir()
+                } else {
+                    try_install_circuit_artifacts_with(&self.artifacts_opts())
+                },
+            )
+            .map_err(SP1VerificationError::Groth16)
     }
 }
@@ -0,0 +1,117 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use sp1_prover::CoreSC;
+use sp1_stark::{baby_bear_poseidon2::default_fri_config, ShardProof};
+
+/// The cells of the traces of a chip, summed over the core shards of a proof.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChipCells {
+    /// The number of rows of the traces, which are padded to a power of two in every shard.
+    pub rows: u64,
+    /// The number of columns of the chip, counting the preprocessed, main and permutation columns
+    /// in base field elements.
+    pub cols: u64,
+    /// The number of cells of the traces, `rows * cols`.
+    pub cells: u64,
+    /// The number of cells of the low-degree extension of the traces, which is what the prover
+    /// evaluates and commits to.
+    pub padded_cells: u64,
+}
+
+/// A report of the trace cells proven by each chip, returned by
+/// [Prove::run_with_report](crate::action::Prove::run_with_report).
+///
+/// The cells are read from the core shard proofs, so they account exactly for the work of the core
+/// prover, which dominates the cost of a proof. The recursion that compresses the shards depends
+/// only on their number.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProveReport {
+    /// The number of core shards.
+    pub shards: usize,
+    /// The cells of each chip, keyed by the name of the chip.
+    pub chips: BTreeMap<String, ChipCells>,
+}
+
+impl ProveReport {
+    /// Builds the report of the given core shard proofs.
+    pub fn new<'a>(shard_proofs: impl IntoIterator<Item = &'a ShardProof<CoreSC>>) -> Self {
+        let mut report = Self::default();
+        for shard_proof in shard_proofs {
+            report.add_shard(shard_proof);
+        }
+        report
+    }
+
+    /// Adds the cells of a core shard proof to the report.
+    pub fn add_shard(&mut self, shard_proof: &ShardProof<CoreSC>) {
+        let log_blowup = default_fri_config().log_blowup;
+        self.shards += 1;
+        for (name, &index) in shard_proof.chip_ordering.iter() {
+            let values = &shard_proof.opened_values.chips[index];
+            let rows = 1u64 << values.log_degree;
+            let cols = (values.preprocessed.local.len() +
+                values.main.local.len() +
+                values.permutation.local.len()) as u64;
+            let chip = self.chips.entry(name.clone()).or_default();
+            chip.rows += rows;
+            chip.cols = cols;
+            chip.cells += rows * cols;
+            chip.padded_cells += (rows * cols) << log_blowup;
+        }
+    }
+
+    /// Returns the number of cells of the traces of all the chips.
+    pub fn total_cells(&self) -> u64 {
+        self.chips.values().map(|chip| chip.cells).sum()
+    }
+
+    /// Returns the number of cells of the low-degree extensions of the traces of all the chips.
+    pub fn total_padded_cells(&self) -> u64 {
+        self.chips.values().map(|chip| chip.padded_cells).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sp1_stark::{AirOpenedValues, Challenge, ChipOpenedValues};
+
+    use super::*;
+    use crate::provers::mock_shard_proof;
+
+    fn opened_values(
+        main: usize,
+        permutation: usize,
+        log_degree: usize,
+    ) -> ChipOpenedValues<Challenge<CoreSC>> {
+        let values = |width| AirOpenedValues {
+            local: vec![Default::default(); width],
+            next: vec![Default::default(); width],
+        };
+        ChipOpenedValues {
+            preprocessed: values(0),
+            main: values(main),
+            permutation: values(permutation),
+            quotient: vec![],
+            cumulative_sum: Default::default(),
+            log_degree,
+        }
+    }
+
+    #[test]
+    fn test_prove_report() {
+        let mut shard = mock_shard_proof();
+        shard.opened_values.chips = vec![opened_values(10, 8, 4), opened_values(3, 4, 2)];
+        shard.chip_ordering = [("CPU".to_string(), 0), ("Program".to_string(), 1)].into();
+
+        let report = ProveReport::new([&shard, &shard]);
+        assert_eq!(report.shards, 2);
+        let log_blowup = default_fri_config().log_blowup;
+        assert_eq!(
+            report.chips["CPU"],
+            ChipCells { rows: 32, cols: 18, cells: 32 * 18, padded_cells: (32 * 18) << log_blowup }
+        );
+        assert_eq!(report.chips["Program"].cells, 8 * 7);
+        assert_eq!(report.total_cells(), 32 * 18 + 8 * 7);
+    }
+}
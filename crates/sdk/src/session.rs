@@ -0,0 +1,109 @@
+//! Interactive execution of programs that yield to the host.
+//!
+//! A program calls `sp1_zkvm::io::yield_to_host` to pause after committing an intermediate
+//! output. The host inspects that output, writes more input, and resumes the program. Once the
+//! program halts, the whole session can be proven at once with the input recorded by the
+//! [Session], since yielding is a no-op when the program is not executed interactively.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use serde::Serialize;
+use sp1_core_executor::{ExecutionReport, Executor, Program, SP1Context};
+use sp1_core_machine::io::{SP1PublicValues, SP1Stdin};
+use sp1_prover::components::DefaultProverComponents;
+use sp1_stark::SP1CoreOpts;
+
+use crate::Prover;
+
+/// The status of a [Session] after it was resumed.
+#[derive(Debug, Clone)]
+pub enum SessionStatus {
+    /// The program yielded to the host, with the public values committed since it was resumed.
+    Yielded(SP1PublicValues),
+    /// The program halted, with the public values committed since it was resumed.
+    Halted(SP1PublicValues),
+}
+
+/// An execution of a program that pauses each time the program yields to the host.
+///
+/// Created with [Execute::interactive](crate::action::Execute::interactive).
+pub struct Session<'a> {
+    runtime: Executor<'a>,
+    stdin: SP1Stdin,
+    public_values_ptr: usize,
+    halted: bool,
+}
+
+impl<'a> Session<'a> {
+    pub(crate) fn new(
+        prover: &'a dyn Prover<DefaultProverComponents>,
+        elf: &[u8],
+        stdin: SP1Stdin,
+        mut context: SP1Context<'a>,
+    ) -> Result<Self> {
+        context.subproof_verifier.replace(Arc::new(prover.sp1_prover()));
+        let program = Program::from(elf)?;
+        let mut runtime = Executor::with_context(program, SP1CoreOpts::default(), context);
+        runtime.write_vecs(&stdin.buffer);
+        for (stream_id, entries) in &stdin.streams {
+            runtime.write_stream(*stream_id, entries);
+        }
+        for (proof, vkey) in stdin.proofs.iter() {
+            runtime.write_proof(proof.clone(), vkey.clone());
+        }
+        Ok(Self { runtime, stdin, public_values_ptr: 0, halted: false })
+    }
+
+    /// Write a serializable value to the input of the program.
+    pub fn write<T: Serialize>(&mut self, data: &T) {
+        self.stdin.write(data);
+        self.runtime.write_stdin(data);
+    }
+
+    /// Write a slice of bytes to the input of the program.
+    pub fn write_slice(&mut self, slice: &[u8]) {
+        self.stdin.write_slice(slice);
+        self.runtime.write_stdin_slice(slice);
+    }
+
+    /// Run the program until it yields to the host or halts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the execution fails, or if the program has already halted.
+    pub fn resume(&mut self) -> Result<SessionStatus> {
+        if self.halted {
+            anyhow::bail!("the program has already halted");
+        }
+        self.halted = self.runtime.run_until_yield()?;
+
+        let stream = &self.runtime.state.public_values_stream;
+        let output = SP1PublicValues::from(&stream[self.public_values_ptr..]);
+        self.public_values_ptr = stream.len();
+
+        Ok(if self.halted { SessionStatus::Halted(output) } else { SessionStatus::Yielded(output) })
+    }
+
+    /// Whether the program has halted.
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    /// All the public values committed by the program so far.
+    pub fn public_values(&self) -> SP1PublicValues {
+        SP1PublicValues::from(&self.runtime.state.public_values_stream)
+    }
+
+    /// The input supplied to the program so far, including the input written between yields.
+    ///
+    /// Proving the program on this input proves the whole session.
+    pub fn stdin(&self) -> &SP1Stdin {
+        &self.stdin
+    }
+
+    /// The report of the execution so far.
+    pub fn report(&self) -> &ExecutionReport {
+        &self.runtime.report
+    }
+}
@@ -0,0 +1,118 @@
+use std::borrow::Borrow;
+
+use p3_field::PrimeField32;
+use serde::{Deserialize, Serialize};
+use sp1_prover::CoreSC;
+use sp1_stark::{air::PublicValues, ShardProof, Word};
+
+use crate::{SP1Proof, SP1ProofWithPublicValues};
+
+/// The public boundaries of a shard of a core or tiny proof.
+///
+/// They are read from the public values of the shard proof, which the verifier checks to chain up
+/// across the shards, so they can only be trusted once the proof is verified. The clock restarts
+/// at zero in every shard and is not a public value, so the proof only bounds the number of cycles
+/// of a shard by the height of its CPU trace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SP1ShardBoundary {
+    /// The shard number.
+    pub shard: u32,
+    /// The execution shard number.
+    pub execution_shard: u32,
+    /// The program counter at the start of the shard.
+    pub start_pc: u32,
+    /// The program counter at the start of the next shard, or 0 once the program halted.
+    pub next_pc: u32,
+    /// A bound on the number of cycles executed in the shard, or `None` if the shard does not
+    /// execute any instruction.
+    pub max_cycles: Option<u64>,
+    /// The exit code of the program, only meaningful once it halted.
+    pub exit_code: u32,
+    /// The digest of the public values committed up to the end of the shard.
+    pub committed_value_digest: [u8; 32],
+    /// The digest of the proofs deferred up to the end of the shard.
+    pub deferred_proofs_digest: [u32; 8],
+}
+
+impl SP1ShardBoundary {
+    /// Reads the boundaries of a core shard proof.
+    pub fn new(shard: &ShardProof<CoreSC>) -> Self {
+        let public_values: &PublicValues<Word<_>, _> = shard.public_values.as_slice().borrow();
+        Self {
+            shard: public_values.shard.as_canonical_u32(),
+            execution_shard: public_values.execution_shard.as_canonical_u32(),
+            start_pc: public_values.start_pc.as_canonical_u32(),
+            next_pc: public_values.next_pc.as_canonical_u32(),
+            max_cycles: shard.contains_cpu().then(|| 1 << shard.log_degree_cpu()),
+            exit_code: public_values.exit_code.as_canonical_u32(),
+            committed_value_digest: public_values
+                .commit_digest_bytes()
+                .try_into()
+                .expect("the committed value digest is 32 bytes"),
+            deferred_proofs_digest: public_values
+                .deferred_proofs_digest
+                .map(|word| word.as_canonical_u32()),
+        }
+    }
+}
+
+impl SP1ProofWithPublicValues {
+    /// Returns the boundaries of the shards of a core or tiny proof, in order.
+    ///
+    /// Returns `None` for the other proof kinds, whose recursion folds the shards away.
+    pub fn shard_boundaries(&self) -> Option<Vec<SP1ShardBoundary>> {
+        match &self.proof {
+            SP1Proof::Core(shards) => Some(shards.iter().map(SP1ShardBoundary::new).collect()),
+            SP1Proof::Tiny(shard) => Some(vec![SP1ShardBoundary::new(shard)]),
+            SP1Proof::Compressed(_) | SP1Proof::Plonk(_) | SP1Proof::Groth16(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_baby_bear::BabyBear;
+
+    use super::*;
+    use crate::{provers::mock_shard_proof, SP1PublicValues, SP1Stdin};
+
+    #[test]
+    fn test_shard_boundaries() {
+        let public_values = PublicValues::<u32, u32> {
+            committed_value_digest: [0x04030201; 8],
+            deferred_proofs_digest: [7; 8],
+            start_pc: 0x200800,
+            next_pc: 0x200c00,
+            exit_code: 0,
+            shard: 2,
+            execution_shard: 2,
+            ..Default::default()
+        };
+        let mut shard = mock_shard_proof();
+        shard.public_values = public_values.to_vec::<BabyBear>();
+        let proof = SP1ProofWithPublicValues {
+            proof: SP1Proof::Core(vec![shard.clone()]),
+            stdin: SP1Stdin::new(),
+            public_values: SP1PublicValues::new(),
+            sp1_version: String::new(),
+        };
+
+        let boundaries = proof.shard_boundaries().unwrap();
+        assert_eq!(
+            boundaries,
+            vec![SP1ShardBoundary {
+                shard: 2,
+                execution_shard: 2,
+                start_pc: 0x200800,
+                next_pc: 0x200c00,
+                max_cycles: None,
+                exit_code: 0,
+                committed_value_digest: [1, 2, 3, 4].repeat(8).try_into().unwrap(),
+                deferred_proofs_digest: [7; 8],
+            }]
+        );
+
+        let compressed = SP1ProofWithPublicValues { proof: SP1Proof::Compressed(shard), ..proof };
+        assert!(compressed.shard_boundaries().is_none());
+    }
+}
@@ -0,0 +1,155 @@
+//! The versions of the SP1 circuits that proofs can be verified against.
+
+use std::{fmt, fs, path::PathBuf, str::FromStr};
+
+use num_bigint::BigUint;
+use p3_field::PrimeField;
+use sp1_prover::{HashableKey, SP1VerifyingKey};
+use thiserror::Error;
+
+use crate::{install::circuit_artifacts_dir, SP1_CIRCUIT_VERSION};
+
+/// A version of the SP1 circuits, which the proofs generated with it are bound to.
+///
+/// Proofs of the [current](CircuitVersion::CURRENT) version can be verified in any form. Groth16
+/// and PLONK proofs of older versions can be verified as long as the circuit artifacts of their
+/// version are installed, see [CircuitVersion::artifacts_installed], and the vkey of the program at
+/// their version is pinned, see [CircuitVersion::pin_vkey].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CircuitVersion {
+    V1_1_0,
+    V1_2_0,
+    V1_3_0,
+}
+
+/// The error returned when parsing an unknown circuit version.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("unknown circuit version {0}")]
+pub struct UnknownCircuitVersion(pub String);
+
+impl CircuitVersion {
+    /// The version of the circuits compiled into this SDK, [SP1_CIRCUIT_VERSION].
+    pub const CURRENT: Self = Self::V1_3_0;
+
+    /// All the versions known to this SDK, from the oldest to the newest.
+    pub const ALL: &'static [Self] = &[Self::V1_1_0, Self::V1_2_0, Self::V1_3_0];
+
+    /// The version string, as written in the proofs.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::V1_1_0 => "v1.1.0",
+            Self::V1_2_0 => "v1.2.0",
+            Self::V1_3_0 => "v1.3.0",
+        }
+    }
+
+    /// Whether this is the version of the circuits compiled into this SDK.
+    pub fn is_current(&self) -> bool {
+        *self == Self::CURRENT
+    }
+
+    /// The directory where the circuit artifacts of this version are installed.
+    pub fn artifacts_dir(&self) -> PathBuf {
+        circuit_artifacts_dir(self.as_str())
+    }
+
+    /// Whether the circuit artifacts of this version are installed.
+    pub fn artifacts_installed(&self) -> bool {
+        self.artifacts_dir().exists()
+    }
+
+    /// The file where the vkey at this version of the program with the verifying key `vk` is
+    /// pinned, named after the vkey of the program at the current version.
+    pub fn pinned_vkey_path(&self, vk: &SP1VerifyingKey) -> PathBuf {
+        self.artifacts_dir().join("vkeys").join(vk.bytes32())
+    }
+
+    /// Pins `vkey` as the vkey at this version of the program with the verifying key `vk`.
+    ///
+    /// The vkey of a program changes with the circuits, so the proofs of an older version are bound
+    /// to the vkey of the program at that version, which the current SDK cannot compute. `vkey` is
+    /// the `bytes32` hex string that `vk.bytes32()` returned with the SDK of this version, which is
+    /// also the `programVKey` of the contracts verifying the proofs of that version.
+    pub fn pin_vkey(&self, vk: &SP1VerifyingKey, vkey: &str) -> Result<(), InvalidVkey> {
+        parse_vkey(vkey)?;
+        let path = self.pinned_vkey_path(vk);
+        let pin = || {
+            fs::create_dir_all(path.parent().unwrap())?;
+            fs::write(&path, vkey)
+        };
+        pin().map_err(|err| InvalidVkey(format!("failed to write {}: {err}", path.display())))
+    }
+
+    /// Loads the hash of the vkey at this version of the program with the verifying key `vk`,
+    /// which was pinned with [CircuitVersion::pin_vkey]. For the current version, this is the hash
+    /// of `vk` itself.
+    pub fn load_vkey_hash(&self, vk: &SP1VerifyingKey) -> Option<BigUint> {
+        if self.is_current() {
+            return Some(vk.hash_bn254().as_canonical_biguint());
+        }
+        let vkey = fs::read_to_string(self.pinned_vkey_path(vk)).ok()?;
+        parse_vkey(vkey.trim()).ok()
+    }
+}
+
+/// The error returned when pinning a vkey which is not a `bytes32` hex string.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("invalid vkey: {0}")]
+pub struct InvalidVkey(pub String);
+
+/// Parses a vkey, as the `0x` prefixed hex string returned by `bytes32`, into its hash.
+fn parse_vkey(vkey: &str) -> Result<BigUint, InvalidVkey> {
+    vkey.strip_prefix("0x")
+        .filter(|hex| hex.len() == 64)
+        .and_then(|hex| BigUint::parse_bytes(hex.as_bytes(), 16))
+        .ok_or_else(|| InvalidVkey(vkey.to_string()))
+}
+
+impl fmt::Display for CircuitVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for CircuitVersion {
+    type Err = UnknownCircuitVersion;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|version| version.as_str() == s)
+            .copied()
+            .ok_or_else(|| UnknownCircuitVersion(s.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_current_version() {
+        assert_eq!(CircuitVersion::CURRENT.as_str(), SP1_CIRCUIT_VERSION);
+        assert_eq!(CircuitVersion::ALL.last(), Some(&CircuitVersion::CURRENT));
+    }
+
+    #[test]
+    fn test_parse_version() {
+        for version in CircuitVersion::ALL {
+            assert_eq!(version.to_string().parse::<CircuitVersion>(), Ok(*version));
+        }
+        assert_eq!(
+            "v0.0.1".parse::<CircuitVersion>(),
+            Err(UnknownCircuitVersion("v0.0.1".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_vkey() {
+        let vkey = format!("0x{:0>64}", "1f");
+        assert_eq!(parse_vkey(&vkey), Ok(BigUint::from(31u32)));
+        assert!(parse_vkey("1f").is_err());
+        assert!(parse_vkey("0x1f").is_err());
+        assert!(parse_vkey(&format!("0x{:0>64}", "zz")).is_err());
+    }
+}
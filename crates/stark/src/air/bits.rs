@@ -0,0 +1,183 @@
+use p3_air::AirBuilder;
+use p3_field::AbstractField;
+
+use super::BaseAirBuilder;
+use crate::Word;
+
+/// The largest number of bits whose decomposition of a BabyBear element is unique, since
+/// `2^30 < p < 2^31`.
+pub const MAX_UNIQUE_DECOMPOSITION_BITS: usize = 30;
+
+/// Returns the value of little-endian bits, `sum(bits[i] * 2^i)`.
+///
+/// With more than [MAX_UNIQUE_DECOMPOSITION_BITS] bits, the value wraps around the field modulus.
+pub fn recompose_bits<AB: AirBuilder, I: Into<AB::Expr>>(
+    bits: impl IntoIterator<Item = I>,
+) -> AB::Expr {
+    bits.into_iter()
+        .enumerate()
+        .map(|(i, bit)| {
+            assert!(i < 32, "cannot recompose more than 32 bits");
+            bit.into() * AB::F::from_wrapped_u32(1 << i)
+        })
+        .sum()
+}
+
+/// A trait which contains methods for range checking values through their bit decomposition.
+pub trait BitsAirBuilder: BaseAirBuilder {
+    /// Asserts that every element is a bit.
+    fn assert_bits<I: Into<Self::Expr>>(&mut self, bits: impl IntoIterator<Item = I>) {
+        for bit in bits {
+            self.assert_bool(bit);
+        }
+    }
+
+    /// Asserts that `bits` are the little-endian bits of `value`, which is then less than
+    /// `2^bits.len()`.
+    ///
+    /// Panics with more than [MAX_UNIQUE_DECOMPOSITION_BITS] bits, for which the decomposition of
+    /// a field element is not unique. Larger values are decomposed into limbs, see
+    /// [Self::assert_word_decomposition].
+    fn assert_bit_decomposition<I: Into<Self::Expr> + Clone>(
+        &mut self,
+        value: impl Into<Self::Expr>,
+        bits: &[I],
+    ) {
+        assert!(
+            bits.len() <= MAX_UNIQUE_DECOMPOSITION_BITS,
+            "the decomposition into {} bits is not unique",
+            bits.len()
+        );
+        self.assert_bits(bits.iter().cloned());
+        self.assert_eq(value, recompose_bits::<Self, _>(bits.iter().cloned()));
+    }
+
+    /// Asserts that `bits` are the little-endian bits of a byte.
+    fn assert_byte_decomposition<I: Into<Self::Expr> + Clone>(
+        &mut self,
+        byte: impl Into<Self::Expr>,
+        bits: &[I; 8],
+    ) {
+        self.assert_bit_decomposition(byte, bits);
+    }
+
+    /// Asserts that `bits` are the little-endian bits of a `u16`.
+    fn assert_u16_decomposition<I: Into<Self::Expr> + Clone>(
+        &mut self,
+        value: impl Into<Self::Expr>,
+        bits: &[I; 16],
+    ) {
+        self.assert_bit_decomposition(value, bits);
+    }
+
+    /// Asserts that `bits` are the little-endian bits of a `u29`, such as the offset of an
+    /// aligned address.
+    fn assert_u29_decomposition<I: Into<Self::Expr> + Clone>(
+        &mut self,
+        value: impl Into<Self::Expr>,
+        bits: &[I; 29],
+    ) {
+        self.assert_bit_decomposition(value, bits);
+    }
+
+    /// Asserts that `bits` are the little-endian bits of the `u32` whose little-endian bytes are
+    /// `word`.
+    fn assert_word_decomposition<I: Into<Self::Expr> + Clone>(
+        &mut self,
+        word: Word<impl Into<Self::Expr>>,
+        bits: &[I; 32],
+    ) {
+        for (byte, bits) in word.0.into_iter().zip(bits.chunks_exact(8)) {
+            self.assert_bit_decomposition(byte, bits);
+        }
+    }
+}
+
+impl<AB: BaseAirBuilder> BitsAirBuilder for AB {}
+
+#[cfg(test)]
+mod tests {
+    use p3_baby_bear::BabyBear;
+    use p3_matrix::dense::RowMajorMatrix;
+
+    use super::*;
+    use crate::air::EmptyMessageBuilder;
+
+    /// A builder evaluating constraints on concrete values, which counts the failed ones.
+    ///
+    /// The gadgets take their values as arguments, so the builder has an empty trace, and
+    /// evaluates the constraints on a single row, which is both the first and the last row.
+    #[derive(Default)]
+    struct CheckBuilder {
+        failures: usize,
+    }
+
+    impl AirBuilder for CheckBuilder {
+        type F = BabyBear;
+        type Expr = BabyBear;
+        type Var = BabyBear;
+        type M = RowMajorMatrix<BabyBear>;
+
+        fn main(&self) -> Self::M {
+            RowMajorMatrix::new(Vec::new(), 1)
+        }
+
+        fn is_first_row(&self) -> Self::Expr {
+            BabyBear::one()
+        }
+
+        fn is_last_row(&self) -> Self::Expr {
+            BabyBear::one()
+        }
+
+        fn is_transition_window(&self, _size: usize) -> Self::Expr {
+            BabyBear::zero()
+        }
+
+        fn assert_zero<I: Into<Self::Expr>>(&mut self, x: I) {
+            if x.into() != BabyBear::zero() {
+                self.failures += 1;
+            }
+        }
+    }
+
+    impl EmptyMessageBuilder for CheckBuilder {}
+
+    fn bits<const N: usize>(value: u32) -> [BabyBear; N] {
+        std::array::from_fn(|i| BabyBear::from_canonical_u32((value >> i) & 1))
+    }
+
+    fn failures(f: impl FnOnce(&mut CheckBuilder)) -> usize {
+        let mut builder = CheckBuilder::default();
+        f(&mut builder);
+        builder.failures
+    }
+
+    #[test]
+    fn test_bit_decompositions() {
+        let f = BabyBear::from_canonical_u32;
+        assert_eq!(failures(|b| b.assert_byte_decomposition(f(0xa5), &bits(0xa5))), 0);
+        assert_eq!(failures(|b| b.assert_u16_decomposition(f(0xbeef), &bits(0xbeef))), 0);
+        let value = (1 << 29) - 3;
+        assert_eq!(failures(|b| b.assert_u29_decomposition(f(value), &bits(value))), 0);
+        let word = Word::from(0xdeadbeef_u32);
+        assert_eq!(failures(|b| b.assert_word_decomposition(word, &bits(0xdeadbeef))), 0);
+
+        // A wrong value, and a non-boolean bit recomposing to the right value.
+        assert_eq!(failures(|b| b.assert_byte_decomposition(f(0xa4), &bits(0xa5))), 1);
+        let mut wrong = bits::<8>(0);
+        wrong[0] = f(2);
+        assert_eq!(failures(|b| b.assert_byte_decomposition(f(2), &wrong)), 1);
+
+        assert_eq!(
+            recompose_bits::<CheckBuilder, _>(bits::<32>(u32::MAX)),
+            f(u32::MAX % 0x78000001)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "not unique")]
+    fn test_bit_decomposition_too_long() {
+        failures(|b| b.assert_bit_decomposition(BabyBear::zero(), &bits::<31>(0)));
+    }
+}
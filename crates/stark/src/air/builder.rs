@@ -7,7 +7,7 @@ use p3_uni_stark::{
     ProverConstraintFolder, StarkGenericConfig, SymbolicAirBuilder, VerifierConstraintFolder,
 };
 
-use super::{interaction::AirInteraction, BinomialExtension};
+use super::{interaction::AirInteraction, BinomialExtension, BitsAirBuilder};
 use crate::{lookup::InteractionKind, Word};
 
 /// A builder that can send and receive messages (or interactions) with other AIRs.
@@ -333,7 +333,10 @@ pub trait MachineAirBuilder:
 }
 
 /// A trait which contains all helper methods for building SP1 machine AIRs.
-pub trait SP1AirBuilder: MachineAirBuilder + ByteAirBuilder + AluAirBuilder {}
+pub trait SP1AirBuilder:
+    MachineAirBuilder + ByteAirBuilder + AluAirBuilder + BitsAirBuilder
+{
+}
 
 impl<'a, AB: AirBuilder + MessageBuilder<M>, M> MessageBuilder<M> for FilteredAirBuilder<'a, AB> {
     fn send(&mut self, message: M) {
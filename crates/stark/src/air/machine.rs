@@ -29,6 +29,16 @@ pub trait MachineAir<F: Field>: BaseAir<F> + 'static + Send + Sync {
         self.generate_trace(input, output);
     }
 
+    /// Whether the trace of this air is generated in the same pass as its dependencies.
+    ///
+    /// This requires that the events read by [`MachineAir::generate_trace`] are final once the
+    /// dependencies of the airs preceding this one have been generated, and that it adds the same
+    /// events to `output` as [`MachineAir::generate_dependencies`]. The trace then does not need a
+    /// second pass once the dependencies of the whole record are known.
+    fn single_pass(&self) -> bool {
+        false
+    }
+
     /// Whether this execution record contains events for this air.
     fn included(&self, shard: &Self::Record) -> bool;
 
@@ -1,5 +1,6 @@
 //! Building blocks for defining AIRs.
 
+mod bits;
 mod builder;
 mod extension;
 mod interaction;
@@ -8,6 +9,7 @@ mod polynomial;
 mod public_values;
 mod sub_builder;
 
+pub use bits::*;
 pub use builder::*;
 pub use extension::*;
 pub use interaction::*;
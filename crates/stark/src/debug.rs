@@ -109,6 +109,51 @@ pub fn debug_constraints<SC, A>(
     });
 }
 
+/// Checks that the constraints of the given AIR are satisfied by `main`, ignoring interactions.
+///
+/// Returns the index of the first row that fails a constraint.
+pub fn debug_air_constraints<F, EF, A>(
+    air: &A,
+    main: &RowMajorMatrix<F>,
+    public_values: &[F],
+) -> Result<(), usize>
+where
+    F: Field,
+    EF: ExtensionField<F>,
+    A: for<'a> Air<DebugConstraintBuilder<'a, F, EF>>,
+{
+    let height = main.height();
+    for i in 0..height {
+        let i_next = (i + 1) % height;
+        let main_local = main.row_slice(i);
+        let main_next = main.row_slice(i_next);
+        let mut builder = DebugConstraintBuilder {
+            preprocessed: VerticalPair::new(
+                RowMajorMatrixView::new_row(&[]),
+                RowMajorMatrixView::new_row(&[]),
+            ),
+            main: VerticalPair::new(
+                RowMajorMatrixView::new_row(&*main_local),
+                RowMajorMatrixView::new_row(&*main_next),
+            ),
+            perm: VerticalPair::new(
+                RowMajorMatrixView::new_row(&[]),
+                RowMajorMatrixView::new_row(&[]),
+            ),
+            perm_challenges: &[],
+            cumulative_sum: EF::zero(),
+            is_first_row: F::from_bool(i == 0),
+            is_last_row: F::from_bool(i == height - 1),
+            is_transition: F::from_bool(i != height - 1),
+            public_values,
+        };
+        if catch_unwind_silent(AssertUnwindSafe(|| air.eval(&mut builder))).is_err() {
+            return Err(i);
+        }
+    }
+    Ok(())
+}
+
 fn catch_unwind_silent<F: FnOnce() -> R + panic::UnwindSafe, R>(f: F) -> std::thread::Result<R> {
     let prev_hook = panic::take_hook();
     panic::set_hook(Box::new(|_| {}));
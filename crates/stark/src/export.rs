@@ -0,0 +1,354 @@
+//! Export of the constraint system of a machine, for audit and formal verification tooling.
+//!
+//! The constraints and interactions of every chip are exported as expressions over the columns of
+//! its traces. The expressions of a chip are stored as a graph in [`ChipConstraintSystem::nodes`],
+//! where each node refers to its operands by their index in the list, so that subexpressions shared
+//! by several constraints are only exported once. A constraint asserts that its expression is zero
+//! on every row of the trace. The constraints of the permutation argument are not exported, since
+//! they are derived from the interactions in the same way for every chip.
+
+use std::{collections::HashMap, fmt::Write, rc::Rc};
+
+use p3_air::{Air, BaseAir, VirtualPairCol};
+use p3_field::{Field, PrimeField32};
+use p3_uni_stark::{
+    get_symbolic_constraints, Entry, SymbolicAirBuilder, SymbolicExpression, SymbolicVariable,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    air::MachineAir, Interaction, StarkGenericConfig, StarkMachine, Val, PROOF_MAX_NUM_PVS,
+};
+
+/// The constraint system of a machine.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConstraintSystem {
+    /// The modulus of the prime field the constraints are defined over.
+    pub field_modulus: u32,
+    /// The constraint systems of the chips of the machine, in order.
+    pub chips: Vec<ChipConstraintSystem>,
+}
+
+/// The constraints and interactions of a chip.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChipConstraintSystem {
+    /// The name of the chip.
+    pub name: String,
+    /// The number of preprocessed columns.
+    pub preprocessed_width: usize,
+    /// The number of main columns.
+    pub main_width: usize,
+    /// The log of the degree of the quotient polynomial, relative to the trace.
+    pub log_quotient_degree: usize,
+    /// The nodes of the expressions of the chip.
+    pub nodes: Vec<ExprNode>,
+    /// The constraints, as indices of the nodes that must be zero.
+    pub constraints: Vec<usize>,
+    /// The interactions the chip sends.
+    pub sends: Vec<InteractionExpr>,
+    /// The interactions the chip receives.
+    pub receives: Vec<InteractionExpr>,
+}
+
+/// An interaction, as indices of the nodes of its expressions.
+///
+/// Interactions only read the current row of the preprocessed and main traces.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InteractionExpr {
+    /// The kind of the interaction.
+    pub kind: String,
+    /// The values of the interaction.
+    pub values: Vec<usize>,
+    /// The multiplicity of the interaction.
+    pub multiplicity: usize,
+}
+
+/// A node of an expression.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum ExprNode {
+    /// A field element, in canonical form.
+    Constant {
+        /// The value of the constant.
+        value: u32,
+    },
+    /// A column of a trace, at the current row (offset 0) or the next one (offset 1).
+    Variable {
+        /// The trace or the values the variable is read from.
+        entry: VariableEntry,
+        /// The row offset of the variable, only meaningful for traces.
+        offset: usize,
+        /// The index of the column, public value or challenge.
+        index: usize,
+    },
+    /// The selector of the first row.
+    IsFirstRow,
+    /// The selector of the last row.
+    IsLastRow,
+    /// The selector of every row but the last.
+    IsTransition,
+    /// The sum of two nodes.
+    Add {
+        /// The first operand.
+        x: usize,
+        /// The second operand.
+        y: usize,
+    },
+    /// The difference of two nodes.
+    Sub {
+        /// The first operand.
+        x: usize,
+        /// The second operand.
+        y: usize,
+    },
+    /// The negation of a node.
+    Neg {
+        /// The operand.
+        x: usize,
+    },
+    /// The product of two nodes.
+    Mul {
+        /// The first operand.
+        x: usize,
+        /// The second operand.
+        y: usize,
+    },
+}
+
+/// What a variable of an expression is read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VariableEntry {
+    /// The preprocessed trace.
+    Preprocessed,
+    /// The main trace.
+    Main,
+    /// The permutation trace.
+    Permutation,
+    /// The public values.
+    Public,
+    /// The challenges of the permutation argument.
+    Challenge,
+}
+
+impl<SC: StarkGenericConfig, A> StarkMachine<SC, A>
+where
+    Val<SC>: PrimeField32,
+    A: MachineAir<Val<SC>> + Air<SymbolicAirBuilder<Val<SC>>>,
+{
+    /// Returns the constraint system of the machine.
+    #[must_use]
+    pub fn constraint_system(&self) -> ConstraintSystem {
+        let chips = self
+            .chips()
+            .iter()
+            .map(|chip| {
+                let air = chip.air();
+                let (preprocessed_width, main_width) = (air.preprocessed_width(), air.width());
+                let mut graph = ExprGraph::default();
+                let constraints =
+                    get_symbolic_constraints(air, preprocessed_width, PROOF_MAX_NUM_PVS)
+                        .iter()
+                        .map(|constraint| graph.insert(constraint))
+                        .collect();
+                let mut interactions = |interactions: &[Interaction<Val<SC>>]| -> Vec<_> {
+                    interactions
+                        .iter()
+                        .map(|interaction| {
+                            graph.insert_interaction(interaction, preprocessed_width, main_width)
+                        })
+                        .collect()
+                };
+                let sends = interactions(chip.sends());
+                let receives = interactions(chip.receives());
+                ChipConstraintSystem {
+                    name: chip.name(),
+                    preprocessed_width,
+                    main_width,
+                    log_quotient_degree: chip.log_quotient_degree(),
+                    nodes: graph.nodes,
+                    constraints,
+                    sends,
+                    receives,
+                }
+            })
+            .collect();
+        ConstraintSystem { field_modulus: Val::<SC>::ORDER_U32, chips }
+    }
+}
+
+impl ConstraintSystem {
+    /// Returns the interactions of all the chips as CSV, with one interaction per line.
+    ///
+    /// The columns are the chip, the direction (`send` or `receive`), the kind, the multiplicity
+    /// and the values separated by `;`, with the expressions written in infix notation.
+    #[must_use]
+    pub fn interactions_csv(&self) -> String {
+        let mut csv = String::from("chip,direction,kind,multiplicity,values\n");
+        for chip in &self.chips {
+            let directions = [("send", &chip.sends), ("receive", &chip.receives)];
+            for (direction, interactions) in directions {
+                for interaction in interactions {
+                    let values = interaction
+                        .values
+                        .iter()
+                        .map(|&value| chip.render(value))
+                        .collect::<Vec<_>>()
+                        .join(";");
+                    writeln!(
+                        csv,
+                        "{},{},{},\"{}\",\"{}\"",
+                        chip.name,
+                        direction,
+                        interaction.kind,
+                        chip.render(interaction.multiplicity),
+                        values
+                    )
+                    .unwrap();
+                }
+            }
+        }
+        csv
+    }
+}
+
+impl ChipConstraintSystem {
+    /// Writes the expression of a node in infix notation.
+    ///
+    /// Variables are written as `main[i]` for the current row and `main'[i]` for the next one.
+    #[must_use]
+    pub fn render(&self, node: usize) -> String {
+        match &self.nodes[node] {
+            ExprNode::Constant { value } => value.to_string(),
+            ExprNode::Variable { entry, offset, index } => {
+                let name = match entry {
+                    VariableEntry::Preprocessed => "preprocessed",
+                    VariableEntry::Main => "main",
+                    VariableEntry::Permutation => "permutation",
+                    VariableEntry::Public => "public",
+                    VariableEntry::Challenge => "challenge",
+                };
+                let next = if *offset == 1 { "'" } else { "" };
+                format!("{name}{next}[{index}]")
+            }
+            ExprNode::IsFirstRow => "is_first_row".to_string(),
+            ExprNode::IsLastRow => "is_last_row".to_string(),
+            ExprNode::IsTransition => "is_transition".to_string(),
+            ExprNode::Add { x, y } => format!("({} + {})", self.render(*x), self.render(*y)),
+            ExprNode::Sub { x, y } => format!("({} - {})", self.render(*x), self.render(*y)),
+            ExprNode::Neg { x } => format!("-{}", self.render(*x)),
+            ExprNode::Mul { x, y } => format!("({} * {})", self.render(*x), self.render(*y)),
+        }
+    }
+}
+
+/// The graph of the expressions of a chip, which deduplicates identical nodes.
+#[derive(Default)]
+struct ExprGraph {
+    nodes: Vec<ExprNode>,
+    indices: HashMap<ExprNode, usize>,
+}
+
+impl ExprGraph {
+    /// Inserts an expression and returns the index of its root node.
+    fn insert<F: PrimeField32>(&mut self, expr: &SymbolicExpression<F>) -> usize {
+        let node = match expr {
+            SymbolicExpression::Constant(c) => ExprNode::Constant { value: c.as_canonical_u32() },
+            SymbolicExpression::Variable(v) => Self::variable(v),
+            SymbolicExpression::IsFirstRow => ExprNode::IsFirstRow,
+            SymbolicExpression::IsLastRow => ExprNode::IsLastRow,
+            SymbolicExpression::IsTransition => ExprNode::IsTransition,
+            SymbolicExpression::Add { x, y, .. } => {
+                ExprNode::Add { x: self.insert_rc(x), y: self.insert_rc(y) }
+            }
+            SymbolicExpression::Sub { x, y, .. } => {
+                ExprNode::Sub { x: self.insert_rc(x), y: self.insert_rc(y) }
+            }
+            SymbolicExpression::Neg { x, .. } => ExprNode::Neg { x: self.insert_rc(x) },
+            SymbolicExpression::Mul { x, y, .. } => {
+                ExprNode::Mul { x: self.insert_rc(x), y: self.insert_rc(y) }
+            }
+        };
+        *self.indices.entry(node).or_insert_with_key(|node| {
+            self.nodes.push(node.clone());
+            self.nodes.len() - 1
+        })
+    }
+
+    fn insert_rc<F: PrimeField32>(&mut self, expr: &Rc<SymbolicExpression<F>>) -> usize {
+        self.insert(expr.as_ref())
+    }
+
+    /// Inserts the expressions of an interaction, which read the current row of the traces.
+    fn insert_interaction<F: PrimeField32>(
+        &mut self,
+        interaction: &Interaction<F>,
+        preprocessed_width: usize,
+        main_width: usize,
+    ) -> InteractionExpr {
+        let preprocessed = (0..preprocessed_width)
+            .map(|column| SymbolicVariable::new(Entry::Preprocessed { offset: 0 }, column))
+            .collect::<Vec<_>>();
+        let main = (0..main_width)
+            .map(|column| SymbolicVariable::new(Entry::Main { offset: 0 }, column))
+            .collect::<Vec<_>>();
+        let mut insert = |column: &VirtualPairCol<F>| {
+            let expr =
+                column.apply::<SymbolicExpression<F>, SymbolicVariable<F>>(&preprocessed, &main);
+            self.insert(&expr)
+        };
+        InteractionExpr {
+            kind: interaction.kind.to_string(),
+            values: interaction.values.iter().map(&mut insert).collect(),
+            multiplicity: insert(&interaction.multiplicity),
+        }
+    }
+
+    fn variable<F: Field>(variable: &SymbolicVariable<F>) -> ExprNode {
+        let (entry, offset) = match variable.entry {
+            Entry::Preprocessed { offset } => (VariableEntry::Preprocessed, offset),
+            Entry::Main { offset } => (VariableEntry::Main, offset),
+            Entry::Permutation { offset } => (VariableEntry::Permutation, offset),
+            Entry::Public => (VariableEntry::Public, 0),
+            Entry::Challenge => (VariableEntry::Challenge, 0),
+        };
+        ExprNode::Variable { entry, offset, index: variable.index }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_baby_bear::BabyBear;
+    use p3_field::AbstractField;
+
+    use super::*;
+
+    #[test]
+    fn test_expr_graph() {
+        let x = SymbolicExpression::from(SymbolicVariable::<BabyBear>::new(
+            Entry::Main { offset: 0 },
+            0,
+        ));
+        let y = SymbolicExpression::from(SymbolicVariable::<BabyBear>::new(
+            Entry::Main { offset: 1 },
+            2,
+        ));
+        let constraint = x.clone() * y + x * BabyBear::from_canonical_u32(3);
+
+        let mut graph = ExprGraph::default();
+        let root = graph.insert(&constraint);
+        // `x` is only exported once.
+        assert_eq!(graph.nodes.len(), 6);
+        let chip = ChipConstraintSystem {
+            name: "Test".to_string(),
+            preprocessed_width: 0,
+            main_width: 3,
+            log_quotient_degree: 1,
+            nodes: graph.nodes,
+            constraints: vec![root],
+            sends: vec![],
+            receives: vec![],
+        };
+        assert_eq!(chip.render(root), "((main[0] * main'[2]) + (main[0] * 3))");
+    }
+}
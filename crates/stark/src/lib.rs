@@ -25,10 +25,14 @@ mod bb31_poseidon2;
 mod chip;
 mod config;
 mod debug;
+mod export;
 mod folder;
+mod lock;
 mod lookup;
 mod machine;
+mod memory;
 mod opts;
+mod pcs;
 mod permutation;
 mod prover;
 mod quotient;
@@ -36,20 +40,26 @@ mod record;
 mod types;
 mod util;
 mod verifier;
+mod witness;
 mod word;
 
 pub use bb31_poseidon2::*;
 pub use chip::*;
 pub use config::*;
 pub use debug::*;
+pub use export::*;
 pub use folder::*;
+pub use lock::*;
 pub use lookup::*;
 pub use machine::*;
+pub use memory::*;
 pub use opts::*;
+pub use pcs::*;
 pub use permutation::*;
 pub use prover::*;
 pub use quotient::*;
 pub use record::*;
 pub use types::*;
 pub use verifier::*;
+pub use witness::*;
 pub use word::*;
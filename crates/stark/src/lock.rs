@@ -0,0 +1,417 @@
+//! A lockfile of the shape of the constraint system of a machine.
+//!
+//! The lockfile records, for every chip, its column counts, its number of constraints, the kind
+//! and arity of each of its interactions, and a digest of the expressions of its constraints and
+//! interactions. Any change to these changes the verifying keys and the recursion and on-chain
+//! verifiers, so tests compare the constraint system of a machine with a checked-in lockfile and
+//! report the differences with [ConstraintLock::diff].
+
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use crate::{ChipConstraintSystem, ConstraintSystem, ExprNode, InteractionExpr, VariableEntry};
+
+/// The shape of the constraint system of a machine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConstraintLock {
+    /// The modulus of the prime field the constraints are defined over.
+    pub field_modulus: u32,
+    /// The shapes of the chips of the machine, in order.
+    pub chips: Vec<ChipLock>,
+}
+
+/// The shape of the constraints and interactions of a chip.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChipLock {
+    /// The name of the chip.
+    pub name: String,
+    /// The number of preprocessed columns.
+    pub preprocessed_width: usize,
+    /// The number of main columns.
+    pub main_width: usize,
+    /// The log of the degree of the quotient polynomial, relative to the trace.
+    pub log_quotient_degree: usize,
+    /// The number of constraints.
+    pub num_constraints: usize,
+    /// The interactions the chip sends, in order.
+    pub sends: Vec<InteractionSignature>,
+    /// The interactions the chip receives, in order.
+    pub receives: Vec<InteractionSignature>,
+    /// The FNV-1a digest of the expressions of the constraints and interactions.
+    pub digest: u64,
+}
+
+/// The kind and the number of values of an interaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InteractionSignature {
+    /// The kind of the interaction.
+    pub kind: String,
+    /// The number of values of the interaction.
+    pub num_values: usize,
+}
+
+/// A difference between two [ConstraintLock]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LockChange {
+    /// The field modulus changed.
+    FieldModulus {
+        /// The modulus of the old lock.
+        old: u32,
+        /// The modulus of the new lock.
+        new: u32,
+    },
+    /// A chip was added.
+    ChipAdded(String),
+    /// A chip was removed.
+    ChipRemoved(String),
+    /// The chips present in both locks are in a different order.
+    ChipsReordered,
+    /// A property of a chip changed.
+    ChipChanged {
+        /// The name of the chip.
+        name: String,
+        /// The property that changed.
+        property: String,
+        /// The value of the old lock.
+        old: String,
+        /// The value of the new lock.
+        new: String,
+    },
+}
+
+impl fmt::Display for LockChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FieldModulus { old, new } => {
+                write!(f, "the field modulus changed from {old} to {new}")
+            }
+            Self::ChipAdded(name) => write!(f, "chip {name} was added"),
+            Self::ChipRemoved(name) => write!(f, "chip {name} was removed"),
+            Self::ChipsReordered => write!(f, "the chips were reordered"),
+            Self::ChipChanged { name, property, old, new } => {
+                write!(f, "chip {name}: {property} changed from {old} to {new}")
+            }
+        }
+    }
+}
+
+impl ConstraintSystem {
+    /// Returns the lock of the shape of the constraint system.
+    #[must_use]
+    pub fn lock(&self) -> ConstraintLock {
+        ConstraintLock {
+            field_modulus: self.field_modulus,
+            chips: self.chips.iter().map(ChipLock::new).collect(),
+        }
+    }
+}
+
+impl ChipLock {
+    fn new(chip: &ChipConstraintSystem) -> Self {
+        let signatures = |interactions: &[InteractionExpr]| {
+            interactions
+                .iter()
+                .map(|interaction| InteractionSignature {
+                    kind: interaction.kind.clone(),
+                    num_values: interaction.values.len(),
+                })
+                .collect()
+        };
+        Self {
+            name: chip.name.clone(),
+            preprocessed_width: chip.preprocessed_width,
+            main_width: chip.main_width,
+            log_quotient_degree: chip.log_quotient_degree,
+            num_constraints: chip.constraints.len(),
+            sends: signatures(&chip.sends),
+            receives: signatures(&chip.receives),
+            digest: digest(chip),
+        }
+    }
+
+    /// Compares the properties of two versions of the chip.
+    fn diff(&self, new: &Self, changes: &mut Vec<LockChange>) {
+        let mut changed = |property: &str, old: String, new: String| {
+            if old != new {
+                changes.push(LockChange::ChipChanged {
+                    name: self.name.clone(),
+                    property: property.to_string(),
+                    old,
+                    new,
+                });
+            }
+        };
+        changed(
+            "preprocessed width",
+            self.preprocessed_width.to_string(),
+            new.preprocessed_width.to_string(),
+        );
+        changed("main width", self.main_width.to_string(), new.main_width.to_string());
+        changed(
+            "log quotient degree",
+            self.log_quotient_degree.to_string(),
+            new.log_quotient_degree.to_string(),
+        );
+        changed("constraints", self.num_constraints.to_string(), new.num_constraints.to_string());
+        for (direction, old, new) in
+            [("send", &self.sends, &new.sends), ("receive", &self.receives, &new.receives)]
+        {
+            // Only the first differing interaction is reported, since the following ones are
+            // usually shifted by it.
+            let render = |signature: Option<&InteractionSignature>| {
+                signature
+                    .map_or_else(|| "none".to_string(), |s| format!("{} {}", s.kind, s.num_values))
+            };
+            if let Some(i) = (0..old.len().max(new.len())).find(|&i| old.get(i) != new.get(i)) {
+                changed(&format!("{direction} {i}"), render(old.get(i)), render(new.get(i)));
+            }
+        }
+        changed("digest", format!("{:016x}", self.digest), format!("{:016x}", new.digest));
+    }
+}
+
+impl ConstraintLock {
+    /// Returns the changes from `self` to `new`, or nothing if the locks are the same.
+    #[must_use]
+    pub fn diff(&self, new: &Self) -> Vec<LockChange> {
+        let mut changes = Vec::new();
+        if self.field_modulus != new.field_modulus {
+            changes
+                .push(LockChange::FieldModulus { old: self.field_modulus, new: new.field_modulus });
+        }
+
+        let old_chips = self.chips.iter().map(|chip| (&chip.name, chip)).collect::<HashMap<_, _>>();
+        let new_chips = new.chips.iter().map(|chip| (&chip.name, chip)).collect::<HashMap<_, _>>();
+        for chip in &self.chips {
+            match new_chips.get(&chip.name) {
+                Some(new_chip) => chip.diff(new_chip, &mut changes),
+                None => changes.push(LockChange::ChipRemoved(chip.name.clone())),
+            }
+        }
+        for chip in &new.chips {
+            if !old_chips.contains_key(&chip.name) {
+                changes.push(LockChange::ChipAdded(chip.name.clone()));
+            }
+        }
+
+        let common = |chips: &[ChipLock], others: &HashMap<&String, &ChipLock>| {
+            chips
+                .iter()
+                .filter(|chip| others.contains_key(&chip.name))
+                .map(|chip| chip.name.clone())
+                .collect::<Vec<_>>()
+        };
+        if common(&self.chips, &new_chips) != common(&new.chips, &old_chips) {
+            changes.push(LockChange::ChipsReordered);
+        }
+        changes
+    }
+}
+
+/// Writes the lock as one `chip` line per chip, followed by one line per interaction.
+impl fmt::Display for ConstraintLock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "field {}", self.field_modulus)?;
+        for chip in &self.chips {
+            writeln!(
+                f,
+                "chip {} preprocessed={} main={} log_quotient_degree={} constraints={} \
+                 digest={:016x}",
+                chip.name,
+                chip.preprocessed_width,
+                chip.main_width,
+                chip.log_quotient_degree,
+                chip.num_constraints,
+                chip.digest
+            )?;
+            for (direction, interactions) in [("send", &chip.sends), ("receive", &chip.receives)] {
+                for interaction in interactions {
+                    writeln!(f, "  {direction} {} {}", interaction.kind, interaction.num_values)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An error parsing a [ConstraintLock].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockParseError {
+    /// The line of the error, starting from 1.
+    pub line: usize,
+    /// What is wrong with the line.
+    pub message: String,
+}
+
+impl fmt::Display for LockParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid constraint lock at line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for LockParseError {}
+
+/// Parses a lock written by its [fmt::Display] implementation, skipping empty lines and comments
+/// starting with `#`.
+impl FromStr for ConstraintLock {
+    type Err = LockParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut field_modulus = None;
+        let mut chips: Vec<ChipLock> = Vec::new();
+        for (i, line) in s.lines().enumerate() {
+            let error =
+                |message: &str| LockParseError { line: i + 1, message: message.to_string() };
+            let words = line.split_whitespace().collect::<Vec<_>>();
+            match words.as_slice() {
+                [] => {}
+                [first, ..] if first.starts_with('#') => {}
+                ["field", modulus] => {
+                    field_modulus = Some(modulus.parse().map_err(|_| error("invalid modulus"))?);
+                }
+                ["chip", name, properties @ ..] => {
+                    let property = |key: &str| {
+                        properties
+                            .iter()
+                            .find_map(|property| property.strip_prefix(key)?.strip_prefix('='))
+                            .ok_or_else(|| error(&format!("missing {key}")))
+                    };
+                    let number = |key: &str| -> Result<usize, LockParseError> {
+                        property(key)?.parse().map_err(|_| error(&format!("invalid {key}")))
+                    };
+                    chips.push(ChipLock {
+                        name: name.to_string(),
+                        preprocessed_width: number("preprocessed")?,
+                        main_width: number("main")?,
+                        log_quotient_degree: number("log_quotient_degree")?,
+                        num_constraints: number("constraints")?,
+                        sends: Vec::new(),
+                        receives: Vec::new(),
+                        digest: u64::from_str_radix(property("digest")?, 16)
+                            .map_err(|_| error("invalid digest"))?,
+                    });
+                }
+                [direction @ ("send" | "receive"), kind, num_values] => {
+                    let chip =
+                        chips.last_mut().ok_or_else(|| error("interaction before a chip"))?;
+                    let signature = InteractionSignature {
+                        kind: kind.to_string(),
+                        num_values: num_values.parse().map_err(|_| error("invalid arity"))?,
+                    };
+                    match *direction {
+                        "send" => chip.sends.push(signature),
+                        _ => chip.receives.push(signature),
+                    }
+                }
+                _ => return Err(error("unexpected line")),
+            }
+        }
+        let field_modulus = field_modulus
+            .ok_or(LockParseError { line: 0, message: "missing field".to_string() })?;
+        Ok(Self { field_modulus, chips })
+    }
+}
+
+/// The offset basis of 64-bit FNV-1a.
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// The prime of 64-bit FNV-1a.
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Computes the FNV-1a digest of a canonical encoding of the expressions of a chip, which doesn't
+/// depend on the version of Rust or of serde.
+fn digest(chip: &ChipConstraintSystem) -> u64 {
+    let mut words = Vec::new();
+    words.push(chip.nodes.len() as u64);
+    for node in &chip.nodes {
+        match node {
+            ExprNode::Constant { value } => words.extend([0, *value as u64]),
+            ExprNode::Variable { entry, offset, index } => {
+                let entry = match entry {
+                    VariableEntry::Preprocessed => 0,
+                    VariableEntry::Main => 1,
+                    VariableEntry::Permutation => 2,
+                    VariableEntry::Public => 3,
+                    VariableEntry::Challenge => 4,
+                };
+                words.extend([1, entry, *offset as u64, *index as u64]);
+            }
+            ExprNode::IsFirstRow => words.push(2),
+            ExprNode::IsLastRow => words.push(3),
+            ExprNode::IsTransition => words.push(4),
+            ExprNode::Add { x, y } => words.extend([5, *x as u64, *y as u64]),
+            ExprNode::Sub { x, y } => words.extend([6, *x as u64, *y as u64]),
+            ExprNode::Neg { x } => words.extend([7, *x as u64]),
+            ExprNode::Mul { x, y } => words.extend([8, *x as u64, *y as u64]),
+        }
+    }
+    words.push(chip.constraints.len() as u64);
+    words.extend(chip.constraints.iter().map(|&c| c as u64));
+
+    let mut bytes = words.iter().flat_map(|word| word.to_le_bytes()).collect::<Vec<_>>();
+    for interactions in [&chip.sends, &chip.receives] {
+        bytes.extend((interactions.len() as u64).to_le_bytes());
+        for interaction in interactions {
+            bytes.extend((interaction.kind.len() as u64).to_le_bytes());
+            bytes.extend(interaction.kind.as_bytes());
+            bytes.extend((interaction.values.len() as u64).to_le_bytes());
+            for &value in interaction.values.iter().chain([&interaction.multiplicity]) {
+                bytes.extend((value as u64).to_le_bytes());
+            }
+        }
+    }
+
+    bytes.iter().fold(FNV_OFFSET, |hash, &byte| (hash ^ byte as u64).wrapping_mul(FNV_PRIME))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chip(name: &str, main_width: usize) -> ChipLock {
+        ChipLock {
+            name: name.to_string(),
+            preprocessed_width: 0,
+            main_width,
+            log_quotient_degree: 1,
+            num_constraints: 3,
+            sends: vec![InteractionSignature { kind: "Byte".to_string(), num_values: 4 }],
+            receives: vec![],
+            digest: 0x0123_4567_89ab_cdef,
+        }
+    }
+
+    #[test]
+    fn test_lock_round_trip() {
+        let lock = ConstraintLock { field_modulus: 2013265921, chips: vec![chip("Cpu", 20)] };
+        let text = format!("# comment\n{lock}");
+        assert_eq!(text.parse::<ConstraintLock>().unwrap(), lock);
+        assert_eq!("chip Cpu main=1".parse::<ConstraintLock>().unwrap_err().line, 1);
+    }
+
+    #[test]
+    fn test_lock_diff() {
+        let old = ConstraintLock {
+            field_modulus: 2013265921,
+            chips: vec![chip("Cpu", 20), chip("Add", 8), chip("Mul", 9)],
+        };
+        assert!(old.diff(&old).is_empty());
+
+        let mut changed = chip("Add", 9);
+        changed.sends[0].num_values = 5;
+        let new = ConstraintLock {
+            field_modulus: 2013265921,
+            chips: vec![changed, chip("Cpu", 20), chip("Sub", 8)],
+        };
+        let changes = old.diff(&new).iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            changes,
+            [
+                "chip Add: main width changed from 8 to 9",
+                "chip Add: send 0 changed from Byte 4 to Byte 5",
+                "chip Mul was removed",
+                "chip Sub was added",
+                "the chips were reordered",
+            ]
+        );
+    }
+}
@@ -22,7 +22,7 @@ pub enum InteractionKind {
     /// Interaction with the program table, loading an instruction at a given pc address.
     Program = 2,
 
-    /// Interaction with instruction oracle.
+    /// Interaction between the CPU and the chips proving some of its instructions.
     Instruction = 3,
 
     /// Interaction with the ALU operations.
@@ -39,6 +39,9 @@ pub enum InteractionKind {
 
     /// Interaction with a syscall.
     Syscall = 8,
+
+    /// Interaction with the fixed-point table for fixed-point function evaluations.
+    FixedPoint = 9,
 }
 
 impl InteractionKind {
@@ -54,6 +57,7 @@ impl InteractionKind {
             InteractionKind::Range,
             InteractionKind::Field,
             InteractionKind::Syscall,
+            InteractionKind::FixedPoint,
         ]
     }
 }
@@ -91,6 +95,7 @@ impl Display for InteractionKind {
             InteractionKind::Range => write!(f, "Range"),
             InteractionKind::Field => write!(f, "Field"),
             InteractionKind::Syscall => write!(f, "Syscall"),
+            InteractionKind::FixedPoint => write!(f, "FixedPoint"),
         }
     }
 }
@@ -153,37 +153,8 @@ impl<SC: StarkGenericConfig, A: MachineAir<Val<SC>>> StarkMachine<SC, A> {
     /// Given a program, this function generates the proving and verifying keys. The keys correspond
     /// to the program code and other preprocessed colunms such as lookup tables.
     #[instrument("setup machine", level = "debug", skip_all)]
-    #[allow(clippy::map_unwrap_or)]
-    #[allow(clippy::redundant_closure_for_method_calls)]
     pub fn setup(&self, program: &A::Program) -> (StarkProvingKey<SC>, StarkVerifyingKey<SC>) {
-        let parent_span = tracing::debug_span!("generate preprocessed traces");
-        let mut named_preprocessed_traces = parent_span.in_scope(|| {
-            self.chips()
-                .par_iter()
-                .filter_map(|chip| {
-                    let chip_name = chip.name();
-                    let begin = Instant::now();
-                    let prep_trace = chip.generate_preprocessed_trace(program);
-                    tracing::debug!(
-                        parent: &parent_span,
-                        "generated preprocessed trace for chip {} in {:?}",
-                        chip_name,
-                        begin.elapsed()
-                    );
-                    // Assert that the chip width data is correct.
-                    let expected_width = prep_trace.as_ref().map(|t| t.width()).unwrap_or(0);
-                    assert_eq!(
-                        expected_width,
-                        chip.preprocessed_width(),
-                        "Incorrect number of preprocessed columns for chip {chip_name}"
-                    );
-                    prep_trace.map(move |t| (chip_name, t))
-                })
-                .collect::<Vec<_>>()
-        });
-
-        // Order the chips and traces by trace size (biggest first), and get the ordering map.
-        named_preprocessed_traces.sort_by_key(|(_, trace)| Reverse(trace.height()));
+        let named_preprocessed_traces = self.generate_preprocessed_traces(program);
 
         let pcs = self.config.pcs();
 
@@ -224,6 +195,74 @@ impl<SC: StarkGenericConfig, A: MachineAir<Val<SC>>> StarkMachine<SC, A> {
         )
     }
 
+    /// Computes the verifying key of a program, without keeping the data of the proving key.
+    ///
+    /// The preprocessed traces are moved into the commitment instead of being copied into the
+    /// proving key, and the prover data of the commitment is dropped right away.
+    #[instrument("setup machine vk", level = "debug", skip_all)]
+    pub fn setup_vk(&self, program: &A::Program) -> StarkVerifyingKey<SC> {
+        let named_preprocessed_traces = self.generate_preprocessed_traces(program);
+
+        let pcs = self.config.pcs();
+
+        let chip_ordering = named_preprocessed_traces
+            .iter()
+            .enumerate()
+            .map(|(i, (name, _))| (name.to_owned(), i))
+            .collect::<HashMap<_, _>>();
+
+        let (chip_information, domains_and_traces): (Vec<_>, Vec<_>) = named_preprocessed_traces
+            .into_iter()
+            .map(|(name, trace)| {
+                let domain = pcs.natural_domain_for_degree(trace.height());
+                ((name, domain, trace.dimensions()), (domain, trace))
+            })
+            .unzip();
+
+        let (commit, _) = tracing::debug_span!("commit to preprocessed traces")
+            .in_scope(|| pcs.commit(domains_and_traces));
+
+        StarkVerifyingKey { commit, pc_start: program.pc_start(), chip_information, chip_ordering }
+    }
+
+    /// Generates the preprocessed traces of the chips, ordered by trace size (biggest first).
+    #[allow(clippy::map_unwrap_or)]
+    #[allow(clippy::redundant_closure_for_method_calls)]
+    fn generate_preprocessed_traces(
+        &self,
+        program: &A::Program,
+    ) -> Vec<(String, RowMajorMatrix<Val<SC>>)> {
+        let parent_span = tracing::debug_span!("generate preprocessed traces");
+        let mut named_preprocessed_traces = parent_span.in_scope(|| {
+            self.chips()
+                .par_iter()
+                .filter_map(|chip| {
+                    let chip_name = chip.name();
+                    let begin = Instant::now();
+                    let prep_trace = chip.generate_preprocessed_trace(program);
+                    tracing::debug!(
+                        parent: &parent_span,
+                        "generated preprocessed trace for chip {} in {:?}",
+                        chip_name,
+                        begin.elapsed()
+                    );
+                    // Assert that the chip width data is correct.
+                    let expected_width = prep_trace.as_ref().map(|t| t.width()).unwrap_or(0);
+                    assert_eq!(
+                        expected_width,
+                        chip.preprocessed_width(),
+                        "Incorrect number of preprocessed columns for chip {chip_name}"
+                    );
+                    prep_trace.map(move |t| (chip_name, t))
+                })
+                .collect::<Vec<_>>()
+        });
+
+        // Order the chips and traces by trace size (biggest first), and get the ordering map.
+        named_preprocessed_traces.sort_by_key(|(_, trace)| Reverse(trace.height()));
+        named_preprocessed_traces
+    }
+
     /// Generates the dependencies of the given records.
     #[allow(clippy::needless_for_each)]
     pub fn generate_dependencies(
@@ -244,6 +283,39 @@ impl<SC: StarkGenericConfig, A: MachineAir<Val<SC>>> StarkMachine<SC, A> {
         });
     }
 
+    /// Generates the dependencies of the given records, together with the traces of the chips which
+    /// are [single pass](MachineAir::single_pass).
+    ///
+    /// The traces of the other chips are generated once the dependencies of the whole record are
+    /// known, see [`crate::MachineProver::complete_traces`].
+    #[allow(clippy::type_complexity)]
+    pub fn generate_dependencies_and_traces(
+        &self,
+        records: &mut [A::Record],
+        opts: &<A::Record as MachineRecord>::Config,
+    ) -> Vec<Vec<(String, RowMajorMatrix<Val<SC>>)>> {
+        let chips = self.chips();
+        records
+            .iter_mut()
+            .map(|record| {
+                let mut traces = Vec::new();
+                for chip in chips.iter() {
+                    tracing::debug_span!("chip dependencies", chip = chip.name()).in_scope(|| {
+                        let mut output = A::Record::default();
+                        if chip.single_pass() && chip.included(record) {
+                            traces.push((chip.name(), chip.generate_trace(record, &mut output)));
+                        } else {
+                            chip.generate_dependencies(record, &mut output);
+                        }
+                        record.append(&mut output);
+                    });
+                }
+                tracing::debug_span!("register nonces").in_scope(|| record.register_nonces(opts));
+                traces
+            })
+            .collect()
+    }
+
     /// Returns the config of the machine.
     pub const fn config(&self) -> &SC {
         &self.config
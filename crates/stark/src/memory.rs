@@ -0,0 +1,184 @@
+//! Memory placement policies for the trace matrices of the prover.
+
+use serde::{Deserialize, Serialize};
+
+/// How the pages of trace matrices are placed on the NUMA nodes of the machine.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NumaPolicy {
+    /// Keep the placement chosen by the kernel, usually the node that first touched each page.
+    #[default]
+    Default,
+    /// Prefer the node of the thread that touches each page.
+    Local,
+    /// Interleave the pages across all online nodes, which spreads the memory traffic of the
+    /// commit phase across sockets.
+    Interleave,
+}
+
+impl NumaPolicy {
+    /// Parses a policy from `default`, `local` or `interleave`.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "default" => Some(Self::Default),
+            "local" => Some(Self::Local),
+            "interleave" => Some(Self::Interleave),
+            _ => None,
+        }
+    }
+}
+
+/// Options for the memory backing the trace matrices.
+///
+/// The options are hints to the kernel: they are applied to the memory of a trace before it is
+/// first touched, and are silently ignored on platforms or kernels that don't support them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryOpts {
+    /// Whether to back traces with transparent hugepages, even if the system setting is `madvise`.
+    pub hugepages: bool,
+    /// The NUMA placement of traces.
+    pub numa: NumaPolicy,
+}
+
+impl MemoryOpts {
+    /// Whether the options leave the placement of traces to the kernel.
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        !self.hugepages && self.numa == NumaPolicy::Default
+    }
+
+    /// Moves `values` into memory placed according to the options.
+    ///
+    /// The options are applied to a new allocation before it is first touched, and `values` are
+    /// then copied into it, so that its pages are faulted in as hugepages and on the nodes of the
+    /// policy, rather than migrated once they hold the trace. This costs a copy of the values,
+    /// which are returned as they are if the options are the default.
+    #[must_use]
+    pub fn place<T: Copy>(&self, values: Vec<T>) -> Vec<T> {
+        if self.is_default() || !cfg!(target_os = "linux") {
+            return values;
+        }
+        let mut placed = Vec::with_capacity(values.len());
+        #[cfg(target_os = "linux")]
+        linux::apply(self, placed.spare_capacity_mut());
+        placed.extend_from_slice(&values);
+        placed
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use std::sync::OnceLock;
+
+    use super::{MemoryOpts, NumaPolicy};
+
+    // The constants of `mbind(2)`, which are not exported by `libc`.
+    const MPOL_PREFERRED: libc::c_long = 1;
+    const MPOL_INTERLEAVE: libc::c_long = 3;
+    const MPOL_MF_MOVE: libc::c_long = 1 << 1;
+
+    /// Applies the options to the pages entirely contained in `values`, so that the placement of
+    /// neighbouring allocations is left unchanged.
+    pub(super) fn apply<T>(opts: &MemoryOpts, values: &mut [T]) {
+        // SAFETY: `sysconf` has no preconditions.
+        let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize;
+        let start = values.as_mut_ptr() as usize;
+        let end = start + std::mem::size_of_val(values);
+        let aligned_start = start.next_multiple_of(page_size);
+        let aligned_end = end / page_size * page_size;
+        if aligned_end <= aligned_start {
+            return;
+        }
+        let addr = aligned_start as *mut libc::c_void;
+        let len = aligned_end - aligned_start;
+
+        if opts.hugepages {
+            // SAFETY: the range is page-aligned and lies within `values`, which is borrowed
+            // mutably, and `MADV_HUGEPAGE` doesn't change its contents.
+            if unsafe { libc::madvise(addr, len, libc::MADV_HUGEPAGE) } != 0 {
+                tracing::debug!("madvise failed: {}", std::io::Error::last_os_error());
+            }
+        }
+
+        let (mode, mask) = match opts.numa {
+            NumaPolicy::Default => return,
+            // An empty mask with `MPOL_PREFERRED` selects the local node.
+            NumaPolicy::Local => (MPOL_PREFERRED, Vec::new()),
+            NumaPolicy::Interleave => match online_nodes() {
+                Some(mask) => (MPOL_INTERLEAVE, mask.clone()),
+                None => return,
+            },
+        };
+        let max_node = (mask.len() * libc::c_ulong::BITS as usize) as libc::c_ulong;
+        let mask_ptr = if mask.is_empty() { std::ptr::null() } else { mask.as_ptr() };
+        // SAFETY: the range is as above, and `mask_ptr` points to `max_node` bits or is null.
+        // Moving the pages already touched with `MPOL_MF_MOVE` preserves their contents.
+        let result = unsafe {
+            libc::syscall(libc::SYS_mbind, addr, len, mode, mask_ptr, max_node, MPOL_MF_MOVE)
+        };
+        if result != 0 {
+            tracing::debug!("mbind failed: {}", std::io::Error::last_os_error());
+        }
+    }
+
+    /// The mask of the online NUMA nodes, or `None` if the machine has a single node.
+    fn online_nodes() -> Option<&'static Vec<libc::c_ulong>> {
+        static NODES: OnceLock<Option<Vec<libc::c_ulong>>> = OnceLock::new();
+        NODES
+            .get_or_init(|| {
+                let online = std::fs::read_to_string("/sys/devices/system/node/online").ok()?;
+                let nodes = parse_node_list(online.trim())?;
+                (nodes.len() > 1).then(|| {
+                    let bits = libc::c_ulong::BITS as usize;
+                    let mut mask = vec![0; nodes.iter().max().unwrap() / bits + 1];
+                    for node in nodes {
+                        mask[node / bits] |= 1 << (node % bits);
+                    }
+                    mask
+                })
+            })
+            .as_ref()
+    }
+
+    /// Parses a node list in the format of `/sys/devices/system/node/online`, like `0-1,4`.
+    pub(super) fn parse_node_list(list: &str) -> Option<Vec<usize>> {
+        let mut nodes = Vec::new();
+        for range in list.split(',') {
+            match range.split_once('-') {
+                Some((first, last)) => {
+                    nodes.extend(first.parse::<usize>().ok()?..=last.parse().ok()?)
+                }
+                None => nodes.push(range.parse().ok()?),
+            }
+        }
+        Some(nodes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numa_policy_from_name() {
+        assert_eq!(NumaPolicy::from_name("Interleave"), Some(NumaPolicy::Interleave));
+        assert_eq!(NumaPolicy::from_name("local"), Some(NumaPolicy::Local));
+        assert_eq!(NumaPolicy::from_name("spread"), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_parse_node_list() {
+        assert_eq!(linux::parse_node_list("0"), Some(vec![0]));
+        assert_eq!(linux::parse_node_list("0-2,5"), Some(vec![0, 1, 2, 5]));
+        assert_eq!(linux::parse_node_list("0-x"), None);
+    }
+
+    #[test]
+    fn test_place_preserves_values() {
+        let opts = MemoryOpts { hugepages: true, numa: NumaPolicy::Interleave };
+        let values = opts.place((0..1u32 << 20).collect::<Vec<_>>());
+        assert_eq!(values.len(), 1 << 20);
+        assert!(values.iter().enumerate().all(|(i, v)| *v == i as u32));
+    }
+}
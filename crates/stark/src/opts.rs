@@ -3,11 +3,14 @@ use std::env;
 use serde::{Deserialize, Serialize};
 use sysinfo::System;
 
+use crate::{MemoryOpts, NumaPolicy};
+
 const MAX_SHARD_SIZE: usize = 1 << 22;
 const MAX_SHARD_BATCH_SIZE: usize = 8;
 const DEFAULT_TRACE_GEN_WORKERS: usize = 1;
 const DEFAULT_CHECKPOINTS_CHANNEL_CAPACITY: usize = 128;
 const DEFAULT_RECORDS_AND_TRACES_CHANNEL_CAPACITY: usize = 1;
+const DEFAULT_MAX_DEFERRED_PROOFS_PER_SHARD: usize = 64;
 
 /// Options to configure the SP1 prover for core and recursive proofs.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -41,6 +44,19 @@ pub struct SP1CoreOpts {
     pub checkpoints_channel_capacity: usize,
     /// The capacity of the channel for records and traces.
     pub records_and_traces_channel_capacity: usize,
+    /// The maximum number of deferred proofs verified in a single shard.
+    ///
+    /// Once a shard has verified this many proofs, the executor starts a new shard, so that the
+    /// deferred proof digest accumulation of programs verifying many proofs is spread across
+    /// shards. A limit of 0 disables it.
+    pub max_deferred_proofs_per_shard: usize,
+    /// Options for the memory backing the trace matrices.
+    pub memory: MemoryOpts,
+    /// Whether the fixed-point precompiles may be called, instead of failing the execution.
+    ///
+    /// Their results are checked against preprocessed tables which are left out of the default
+    /// machine, so their executions must be proven with `RiscvAir::machine_with_fixed_point_tables`.
+    pub fixed_point_tables: bool,
 }
 
 /// Calculate the default shard size using an empirically determined formula.
@@ -109,6 +125,18 @@ impl Default for SP1CoreOpts {
                     |_| DEFAULT_RECORDS_AND_TRACES_CHANNEL_CAPACITY,
                     |s| s.parse::<usize>().unwrap_or(DEFAULT_RECORDS_AND_TRACES_CHANNEL_CAPACITY),
                 ),
+            max_deferred_proofs_per_shard: env::var("MAX_DEFERRED_PROOFS_PER_SHARD").map_or_else(
+                |_| DEFAULT_MAX_DEFERRED_PROOFS_PER_SHARD,
+                |s| s.parse::<usize>().unwrap_or(DEFAULT_MAX_DEFERRED_PROOFS_PER_SHARD),
+            ),
+            memory: MemoryOpts {
+                hugepages: env::var("TRACE_HUGEPAGES").is_ok_and(|s| s == "true"),
+                numa: env::var("TRACE_NUMA_POLICY")
+                    .ok()
+                    .and_then(|s| NumaPolicy::from_name(&s))
+                    .unwrap_or_default(),
+            },
+            fixed_point_tables: env::var("FIXED_POINT_TABLES").is_ok_and(|s| s == "true"),
         }
     }
 }
@@ -139,6 +167,11 @@ pub struct SplitOpts {
     pub sha_compress: usize,
     /// The threshold for memory events.
     pub memory: usize,
+    /// The threshold for the multiplicity of an entry of the byte table in a shard.
+    ///
+    /// The lookups of a shard which repeat an entry more often are received by the byte tables of
+    /// extra shards, see `ExecutionRecord::split_byte_lookups`.
+    pub byte_lookups: usize,
 }
 
 impl SplitOpts {
@@ -151,9 +184,14 @@ impl SplitOpts {
             sha_extend: deferred_shift_threshold / 48,
             sha_compress: deferred_shift_threshold / 80,
             memory: deferred_shift_threshold * 4,
+            byte_lookups: BYTE_LOOKUP_SPLIT_THRESHOLD,
         }
     }
 }
 
 /// The threshold for splitting deferred events.
 pub const DEFERRED_SPLIT_THRESHOLD: usize = 1 << 19;
+
+/// The threshold for splitting the byte lookups of a shard, which is the largest multiplicity
+/// below the order of the BabyBear field.
+pub const BYTE_LOOKUP_SPLIT_THRESHOLD: usize = (1 << 31) - (1 << 27);
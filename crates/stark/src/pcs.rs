@@ -0,0 +1,412 @@
+//! An object-safe interface to the polynomial commitment scheme (PCS) of the STARK prover.
+//!
+//! The prover and verifier of a [`crate::StarkMachine`] only use the PCS through the
+//! [`StarkGenericConfig`], so a [`PluginConfig`] lets any [`PcsPlugin`] be benchmarked on a machine
+//! without changing it. Plugins commit to BabyBear traces over two-adic cosets, to a digest of the
+//! same size as the Poseidon2 Merkle roots of the core prover, and use its challenger.
+
+use std::{any::Any, fmt::Debug, sync::Arc};
+
+use p3_commit::{OpenedValues, Pcs, TwoAdicMultiplicativeCoset};
+use p3_fri::FriConfig;
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    inner_perm, InnerChallenge, InnerChallengeMmcs, InnerChallenger, InnerCompress, InnerDft,
+    InnerDigestHash, InnerHash, InnerPcs, InnerPerm, InnerVal, InnerValMmcs, StarkGenericConfig,
+};
+
+/// The domain of the traces committed by a [`PcsPlugin`].
+pub type PluginDomain = TwoAdicMultiplicativeCoset<InnerVal>;
+
+/// The commitment of a [`PcsPlugin`].
+pub type PluginCommitment = InnerDigestHash;
+
+/// A round of the opening of a [`PcsPlugin`]: a commitment with the domain of each of its matrices,
+/// the points they are opened at and their values at these points.
+pub type PluginRound =
+    (PluginCommitment, Vec<(PluginDomain, Vec<(InnerChallenge, Vec<InnerChallenge>)>)>);
+
+/// The prover data of a commitment made by a [`PcsPlugin`], which the plugin downcasts back to its
+/// own type.
+///
+/// The prover data cannot be serialized, so the proving keys of a [`PluginConfig`] cannot be saved.
+#[derive(Clone)]
+pub struct PluginProverData(Arc<dyn Any + Send + Sync>);
+
+impl PluginProverData {
+    /// Wraps the prover data of a plugin.
+    #[must_use]
+    pub fn new<T: Any + Send + Sync>(data: T) -> Self {
+        Self(Arc::new(data))
+    }
+
+    /// Returns the prover data of a plugin, which must be of the type it was created with.
+    #[must_use]
+    pub fn downcast_ref<T: Any>(&self) -> &T {
+        self.0.downcast_ref().expect("prover data of a different PCS plugin")
+    }
+}
+
+impl Serialize for PluginProverData {
+    fn serialize<S>(&self, _: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        Err(serde::ser::Error::custom("the prover data of a PCS plugin cannot be serialized"))
+    }
+}
+
+impl<'de> Deserialize<'de> for PluginProverData {
+    fn deserialize<D>(_: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Err(serde::de::Error::custom("the prover data of a PCS plugin cannot be deserialized"))
+    }
+}
+
+/// A polynomial commitment scheme that can be used behind a trait object.
+///
+/// The methods are the ones of [`Pcs`], with the prover data and the opening proofs erased to
+/// [`PluginProverData`] and bytes. [`PcsAdapter`] implements it for any [`Pcs`] with the same
+/// domain, commitment and challenger.
+pub trait PcsPlugin: Send + Sync {
+    /// The name of the scheme and its parameters, used to tell benchmarks apart.
+    fn name(&self) -> String;
+
+    /// Returns the domain of the traces of the given height.
+    fn natural_domain_for_degree(&self, degree: usize) -> PluginDomain;
+
+    /// Commits to the evaluations of traces over their domain.
+    fn commit(
+        &self,
+        evaluations: Vec<(PluginDomain, RowMajorMatrix<InnerVal>)>,
+    ) -> (PluginCommitment, PluginProverData);
+
+    /// Returns the evaluations of the committed trace at index `idx` over another domain.
+    fn get_evaluations_on_domain(
+        &self,
+        prover_data: &PluginProverData,
+        idx: usize,
+        domain: PluginDomain,
+    ) -> RowMajorMatrix<InnerVal>;
+
+    /// Opens the committed traces of each round at the given points, returning the opened values
+    /// and the serialized opening proof.
+    fn open(
+        &self,
+        rounds: Vec<(&PluginProverData, Vec<Vec<InnerChallenge>>)>,
+        challenger: &mut InnerChallenger,
+    ) -> (OpenedValues<InnerChallenge>, Vec<u8>);
+
+    /// Verifies a serialized opening proof of the given rounds.
+    fn verify(
+        &self,
+        rounds: Vec<PluginRound>,
+        proof: &[u8],
+        challenger: &mut InnerChallenger,
+    ) -> Result<(), String>;
+}
+
+/// A [`PcsPlugin`] wrapping a [`Pcs`], whose opening proofs are serialized with bincode.
+pub struct PcsAdapter<P> {
+    name: String,
+    pcs: P,
+}
+
+impl<P> PcsAdapter<P> {
+    /// Creates a plugin with the given name from a PCS.
+    #[must_use]
+    pub fn new(name: impl Into<String>, pcs: P) -> Self {
+        Self { name: name.into(), pcs }
+    }
+}
+
+impl<P> PcsPlugin for PcsAdapter<P>
+where
+    P: Pcs<InnerChallenge, InnerChallenger, Domain = PluginDomain, Commitment = PluginCommitment>
+        + Send
+        + Sync,
+    P::ProverData: Send + Sync + 'static,
+{
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn natural_domain_for_degree(&self, degree: usize) -> PluginDomain {
+        self.pcs.natural_domain_for_degree(degree)
+    }
+
+    fn commit(
+        &self,
+        evaluations: Vec<(PluginDomain, RowMajorMatrix<InnerVal>)>,
+    ) -> (PluginCommitment, PluginProverData) {
+        let (commitment, prover_data) = self.pcs.commit(evaluations);
+        (commitment, PluginProverData::new(prover_data))
+    }
+
+    fn get_evaluations_on_domain(
+        &self,
+        prover_data: &PluginProverData,
+        idx: usize,
+        domain: PluginDomain,
+    ) -> RowMajorMatrix<InnerVal> {
+        self.pcs
+            .get_evaluations_on_domain(prover_data.downcast_ref::<P::ProverData>(), idx, domain)
+            .to_row_major_matrix()
+    }
+
+    fn open(
+        &self,
+        rounds: Vec<(&PluginProverData, Vec<Vec<InnerChallenge>>)>,
+        challenger: &mut InnerChallenger,
+    ) -> (OpenedValues<InnerChallenge>, Vec<u8>) {
+        let rounds = rounds
+            .into_iter()
+            .map(|(prover_data, points)| (prover_data.downcast_ref::<P::ProverData>(), points))
+            .collect();
+        let (values, proof) = self.pcs.open(rounds, challenger);
+        (values, bincode::serialize(&proof).expect("failed to serialize the opening proof"))
+    }
+
+    fn verify(
+        &self,
+        rounds: Vec<PluginRound>,
+        proof: &[u8],
+        challenger: &mut InnerChallenger,
+    ) -> Result<(), String> {
+        let proof: P::Proof =
+            bincode::deserialize(proof).map_err(|e| format!("invalid opening proof: {e}"))?;
+        self.pcs.verify(rounds, &proof, challenger).map_err(|e| format!("{e:?}"))
+    }
+}
+
+/// The parameters of a FRI plugin created by [`fri_plugin`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FriParameters {
+    /// The logarithm of the blowup factor of the low-degree extension.
+    pub log_blowup: usize,
+    /// The number of queries.
+    pub num_queries: usize,
+    /// The number of bits of proof of work before the queries.
+    pub proof_of_work_bits: usize,
+}
+
+impl FriParameters {
+    /// The parameters of the core prover, without the `FRI_QUERIES` override.
+    pub const CORE: Self = Self { log_blowup: 1, num_queries: 100, proof_of_work_bits: 16 };
+
+    /// A blowup of 4 with half of the queries, which has the same conjectured security as
+    /// [`Self::CORE`] with smaller proofs and a slower commitment.
+    pub const BLOWUP_4: Self = Self { log_blowup: 2, num_queries: 50, proof_of_work_bits: 16 };
+
+    /// The conjectured security of the parameters, in bits.
+    #[must_use]
+    pub const fn conjectured_security_bits(&self) -> usize {
+        self.log_blowup * self.num_queries + self.proof_of_work_bits
+    }
+}
+
+/// Returns a FRI plugin with the given parameters and the Poseidon2 Merkle trees of the core
+/// prover.
+#[must_use]
+pub fn fri_plugin(params: FriParameters) -> PcsAdapter<InnerPcs> {
+    let perm = inner_perm();
+    let hash = InnerHash::new(perm.clone());
+    let compress = InnerCompress::new(perm.clone());
+    let val_mmcs = InnerValMmcs::new(hash.clone(), compress.clone());
+    let fri_config = FriConfig {
+        log_blowup: params.log_blowup,
+        num_queries: params.num_queries,
+        proof_of_work_bits: params.proof_of_work_bits,
+        mmcs: InnerChallengeMmcs::new(InnerValMmcs::new(hash, compress)),
+    };
+    let name = format!(
+        "fri(log_blowup={}, num_queries={}, proof_of_work_bits={})",
+        params.log_blowup, params.num_queries, params.proof_of_work_bits
+    );
+    PcsAdapter::new(name, InnerPcs::new(27, InnerDft {}, val_mmcs, fri_config))
+}
+
+/// A [`Pcs`] that forwards to a shared [`PcsPlugin`].
+#[derive(Clone)]
+pub struct DynPcs(Arc<dyn PcsPlugin>);
+
+impl DynPcs {
+    /// Returns the plugin.
+    #[must_use]
+    pub fn plugin(&self) -> &dyn PcsPlugin {
+        self.0.as_ref()
+    }
+}
+
+impl Pcs<InnerChallenge, InnerChallenger> for DynPcs {
+    type Domain = PluginDomain;
+    type Commitment = PluginCommitment;
+    type ProverData = PluginProverData;
+    type Proof = Vec<u8>;
+    type Error = String;
+
+    fn natural_domain_for_degree(&self, degree: usize) -> Self::Domain {
+        self.0.natural_domain_for_degree(degree)
+    }
+
+    fn commit(
+        &self,
+        evaluations: Vec<(Self::Domain, RowMajorMatrix<InnerVal>)>,
+    ) -> (Self::Commitment, Self::ProverData) {
+        self.0.commit(evaluations)
+    }
+
+    fn get_evaluations_on_domain<'a>(
+        &self,
+        prover_data: &'a Self::ProverData,
+        idx: usize,
+        domain: Self::Domain,
+    ) -> impl Matrix<InnerVal> + 'a {
+        self.0.get_evaluations_on_domain(prover_data, idx, domain)
+    }
+
+    fn open(
+        &self,
+        rounds: Vec<(&Self::ProverData, Vec<Vec<InnerChallenge>>)>,
+        challenger: &mut InnerChallenger,
+    ) -> (OpenedValues<InnerChallenge>, Self::Proof) {
+        self.0.open(rounds, challenger)
+    }
+
+    fn verify(
+        &self,
+        rounds: Vec<PluginRound>,
+        proof: &Self::Proof,
+        challenger: &mut InnerChallenger,
+    ) -> Result<(), Self::Error> {
+        self.0.verify(rounds, proof, challenger)
+    }
+}
+
+/// A STARK configuration over BabyBear whose PCS is a [`PcsPlugin`].
+///
+/// Plugins cannot be serialized, so a deserialized configuration uses the FRI plugin with
+/// [`FriParameters::CORE`].
+#[derive(Clone, Deserialize)]
+#[serde(from = "std::marker::PhantomData<PluginConfig>")]
+pub struct PluginConfig {
+    perm: InnerPerm,
+    pcs: DynPcs,
+}
+
+impl PluginConfig {
+    /// Creates a configuration with the given plugin.
+    #[must_use]
+    pub fn new(plugin: impl PcsPlugin + 'static) -> Self {
+        Self { perm: inner_perm(), pcs: DynPcs(Arc::new(plugin)) }
+    }
+
+    /// Returns the plugin of the configuration.
+    #[must_use]
+    pub fn plugin(&self) -> &dyn PcsPlugin {
+        self.pcs.plugin()
+    }
+}
+
+impl Default for PluginConfig {
+    fn default() -> Self {
+        Self::new(fri_plugin(FriParameters::CORE))
+    }
+}
+
+impl Debug for PluginConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PluginConfig").field("pcs", &self.plugin().name()).finish()
+    }
+}
+
+impl Serialize for PluginConfig {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        std::marker::PhantomData::<PluginConfig>.serialize(serializer)
+    }
+}
+
+impl From<std::marker::PhantomData<PluginConfig>> for PluginConfig {
+    fn from(_: std::marker::PhantomData<PluginConfig>) -> Self {
+        Self::default()
+    }
+}
+
+impl StarkGenericConfig for PluginConfig {
+    type Val = InnerVal;
+    type Domain = PluginDomain;
+    type Pcs = DynPcs;
+    type Challenge = InnerChallenge;
+    type Challenger = InnerChallenger;
+
+    fn pcs(&self) -> &Self::Pcs {
+        &self.pcs
+    }
+
+    fn challenger(&self) -> Self::Challenger {
+        InnerChallenger::new(self.perm.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_challenger::{CanObserve, FieldChallenger};
+    use p3_commit::Pcs;
+    use p3_field::AbstractField;
+    use p3_matrix::dense::RowMajorMatrix;
+
+    use super::{fri_plugin, FriParameters, PluginConfig};
+    use crate::{InnerChallenge, InnerVal, StarkGenericConfig};
+
+    /// Commits to a trace, opens it at a random point and verifies the opening, returning the
+    /// size of the opening proof.
+    fn open_and_verify(config: &PluginConfig, tamper: bool) -> Result<usize, String> {
+        let pcs = config.pcs();
+        let trace = RowMajorMatrix::new(
+            (0..64u32).map(|i| InnerVal::from_canonical_u32(i * i + 7)).collect(),
+            4,
+        );
+        let domain = pcs.natural_domain_for_degree(16);
+        let (commit, data) = pcs.commit(vec![(domain, trace)]);
+
+        let mut challenger = config.challenger();
+        challenger.observe(commit);
+        let zeta: InnerChallenge = challenger.sample_ext_element();
+        let (values, proof) = pcs.open(vec![(&data, vec![vec![zeta]])], &mut challenger);
+
+        let mut values = values[0][0][0].clone();
+        if tamper {
+            values[0] += InnerChallenge::one();
+        }
+        let mut challenger = config.challenger();
+        challenger.observe(commit);
+        let zeta: InnerChallenge = challenger.sample_ext_element();
+        pcs.verify(vec![(commit, vec![(domain, vec![(zeta, values)])])], &proof, &mut challenger)?;
+        Ok(proof.len())
+    }
+
+    #[test]
+    fn test_fri_plugins() {
+        let core = PluginConfig::new(fri_plugin(FriParameters::CORE));
+        let blowup_4 = PluginConfig::new(fri_plugin(FriParameters::BLOWUP_4));
+        assert_eq!(
+            FriParameters::CORE.conjectured_security_bits(),
+            FriParameters::BLOWUP_4.conjectured_security_bits()
+        );
+        assert_ne!(core.plugin().name(), blowup_4.plugin().name());
+
+        let core_size = open_and_verify(&core, false).unwrap();
+        let blowup_4_size = open_and_verify(&blowup_4, false).unwrap();
+        assert!(blowup_4_size < core_size);
+
+        assert!(open_and_verify(&core, true).is_err());
+        assert!(open_and_verify(&blowup_4, true).is_err());
+    }
+}
@@ -1,4 +1,5 @@
 use core::fmt::Display;
+use hashbrown::HashMap;
 use itertools::Itertools;
 use serde::{de::DeserializeOwned, Serialize};
 use std::{cmp::Reverse, error::Error, time::Instant};
@@ -72,6 +73,47 @@ pub trait MachineProver<SC: StarkGenericConfig, A: MachineAir<SC::Val>>:
         })
     }
 
+    /// Generate the main traces which were not already generated together with the dependencies.
+    ///
+    /// The `traces` are the ones returned by [`StarkMachine::generate_dependencies_and_traces`] for
+    /// this record, they are reused instead of being generated a second time.
+    fn complete_traces(
+        &self,
+        record: &A::Record,
+        traces: Vec<(String, RowMajorMatrix<Val<SC>>)>,
+    ) -> Vec<(String, RowMajorMatrix<Val<SC>>)> {
+        let mut generated = traces.into_iter().collect::<HashMap<_, _>>();
+        let shard_chips = self
+            .shard_chips(record)
+            .map(|chip| {
+                let trace = generated.remove(&chip.name());
+                (chip, trace)
+            })
+            .collect::<Vec<_>>();
+
+        let parent_span = tracing::debug_span!("generate traces for shard");
+        parent_span.in_scope(|| {
+            shard_chips
+                .into_par_iter()
+                .map(|(chip, trace)| {
+                    let chip_name = chip.name();
+                    let trace = trace.unwrap_or_else(|| {
+                        let begin = Instant::now();
+                        let trace = chip.generate_trace(record, &mut A::Record::default());
+                        tracing::debug!(
+                            parent: &parent_span,
+                            "generated trace for chip {} in {:?}",
+                            chip_name,
+                            begin.elapsed()
+                        );
+                        trace
+                    });
+                    (chip_name, trace)
+                })
+                .collect::<Vec<_>>()
+        })
+    }
+
     /// Commit to the main traces.
     fn commit(
         &self,
@@ -0,0 +1,196 @@
+//! The witness of a shard, for proving with an external backend.
+//!
+//! A [`ShardWitness`] holds everything a polynomial commitment scheme needs to prove a shard: the
+//! public values, and for each chip its preprocessed, main and permutation traces. It is
+//! serialized with `bincode` in the following layout, field by field:
+//!
+//! - Field elements are their canonical `u32` values.
+//! - Extension field elements are their base field coefficients, lowest degree first.
+//! - A [`WitnessMatrix`] is its width followed by its values in row-major order. The rows of a
+//!   matrix over the extension field are flattened, so its width is the number of columns times
+//!   the extension degree.
+//!
+//! The chips are ordered as SP1 commits to them, by decreasing trace height. The permutation
+//! traces depend on the challenges sampled once the main traces are committed to, so they are
+//! only generated when the caller provides these challenges.
+
+use std::cmp::Reverse;
+
+use hashbrown::HashMap;
+use p3_field::{AbstractExtensionField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use p3_maybe_rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    air::MachineAir, record::MachineRecord, StarkGenericConfig, StarkMachine, StarkProvingKey, Val,
+};
+
+/// A trace in row-major order, with its elements as canonical `u32` values.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WitnessMatrix {
+    /// The number of values in each row.
+    pub width: usize,
+    /// The values, row by row.
+    pub values: Vec<u32>,
+}
+
+impl WitnessMatrix {
+    /// Converts a trace over the base field.
+    pub fn from_base<F: PrimeField32>(trace: &RowMajorMatrix<F>) -> Self {
+        Self {
+            width: trace.width(),
+            values: trace.values.iter().map(PrimeField32::as_canonical_u32).collect(),
+        }
+    }
+
+    /// Converts a trace over an extension field, flattening each element into its coefficients.
+    pub fn from_extension<F: PrimeField32, EF: AbstractExtensionField<F>>(
+        trace: &RowMajorMatrix<EF>,
+    ) -> Self {
+        Self {
+            width: trace.width() * EF::D,
+            values: trace
+                .values
+                .iter()
+                .flat_map(|value| extension_to_u32s::<F, EF>(value))
+                .collect(),
+        }
+    }
+
+    /// The number of rows of the matrix.
+    #[must_use]
+    pub fn height(&self) -> usize {
+        if self.width == 0 {
+            0
+        } else {
+            self.values.len() / self.width
+        }
+    }
+}
+
+/// The traces of a chip in a shard.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChipWitness {
+    /// The name of the chip.
+    pub name: String,
+    /// The preprocessed trace, if the chip has one.
+    pub preprocessed: Option<WitnessMatrix>,
+    /// The main trace.
+    pub main: WitnessMatrix,
+    /// The permutation trace, if the permutation challenges were provided.
+    pub permutation: Option<WitnessMatrix>,
+    /// The cumulative sum of the permutation trace, as extension field coefficients.
+    pub cumulative_sum: Option<Vec<u32>>,
+}
+
+/// The witness of a shard.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShardWitness {
+    /// The public values of the shard.
+    pub public_values: Vec<u32>,
+    /// The challenges the permutation traces were generated with, as extension field
+    /// coefficients. Empty if the permutation traces were not generated.
+    pub permutation_challenges: Vec<Vec<u32>>,
+    /// The traces of the chips used in the shard.
+    pub chips: Vec<ChipWitness>,
+}
+
+impl<SC: StarkGenericConfig, A: MachineAir<Val<SC>>> StarkMachine<SC, A>
+where
+    Val<SC>: PrimeField32,
+{
+    /// Collects the witness of a shard from its record and main traces.
+    ///
+    /// The `traces` are the main traces of the chips used by the record, as returned by
+    /// [`crate::MachineProver::generate_traces`]. The permutation traces are generated if the
+    /// `permutation_challenges` are given.
+    pub fn shard_witness(
+        &self,
+        pk: &StarkProvingKey<SC>,
+        record: &A::Record,
+        mut traces: Vec<(String, RowMajorMatrix<Val<SC>>)>,
+        permutation_challenges: Option<&[SC::Challenge]>,
+    ) -> ShardWitness {
+        traces.sort_by_key(|(_, trace)| Reverse(trace.height()));
+        let chips = self.chips().iter().map(|chip| (chip.name(), chip)).collect::<HashMap<_, _>>();
+
+        let chip_witnesses = traces
+            .par_iter()
+            .map(|(name, main)| {
+                let chip = chips[name];
+                let preprocessed = pk.chip_ordering.get(name).map(|&index| &pk.traces[index]);
+                let (permutation, cumulative_sum) = match permutation_challenges {
+                    Some(challenges) => {
+                        let trace = chip.generate_permutation_trace(preprocessed, main, challenges);
+                        let cumulative_sum =
+                            trace.row_slice(main.height() - 1).last().copied().unwrap();
+                        (
+                            Some(WitnessMatrix::from_extension::<Val<SC>, _>(&trace)),
+                            Some(extension_to_u32s::<Val<SC>, _>(&cumulative_sum)),
+                        )
+                    }
+                    None => (None, None),
+                };
+                ChipWitness {
+                    name: name.clone(),
+                    preprocessed: preprocessed.map(WitnessMatrix::from_base),
+                    main: WitnessMatrix::from_base(main),
+                    permutation,
+                    cumulative_sum,
+                }
+            })
+            .collect();
+
+        ShardWitness {
+            public_values: record
+                .public_values::<Val<SC>>()
+                .iter()
+                .map(PrimeField32::as_canonical_u32)
+                .collect(),
+            permutation_challenges: permutation_challenges
+                .unwrap_or_default()
+                .iter()
+                .map(extension_to_u32s::<Val<SC>, SC::Challenge>)
+                .collect(),
+            chips: chip_witnesses,
+        }
+    }
+}
+
+fn extension_to_u32s<F: PrimeField32, EF: AbstractExtensionField<F>>(value: &EF) -> Vec<u32> {
+    value.as_base_slice().iter().map(PrimeField32::as_canonical_u32).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use p3_baby_bear::BabyBear;
+    use p3_field::{extension::BinomialExtensionField, AbstractExtensionField, AbstractField};
+    use p3_matrix::dense::RowMajorMatrix;
+
+    use super::WitnessMatrix;
+
+    type EF = BinomialExtensionField<BabyBear, 4>;
+
+    #[test]
+    fn from_base() {
+        let trace = RowMajorMatrix::new(
+            vec![BabyBear::zero(), BabyBear::one(), BabyBear::neg_one(), BabyBear::two()],
+            2,
+        );
+        let matrix = WitnessMatrix::from_base(&trace);
+        assert_eq!(matrix.width, 2);
+        assert_eq!(matrix.height(), 2);
+        assert_eq!(matrix.values, vec![0, 1, 0x7800_0000, 2]);
+    }
+
+    #[test]
+    fn from_extension() {
+        let value = EF::from_base_slice(&[1, 2, 3, 4].map(BabyBear::from_canonical_u32));
+        let trace = RowMajorMatrix::new(vec![value, EF::one()], 1);
+        let matrix = WitnessMatrix::from_extension::<BabyBear, EF>(&trace);
+        assert_eq!(matrix.width, 4);
+        assert_eq!(matrix.height(), 2);
+        assert_eq!(matrix.values, vec![1, 2, 3, 4, 1, 0, 0, 0]);
+    }
+}
@@ -0,0 +1,66 @@
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, Ident, ItemFn, LitStr, Token,
+};
+
+/// The arguments of `#[sp1_test(elf = "...")]`.
+struct Sp1TestArgs {
+    elf: LitStr,
+}
+
+impl Parse for Sp1TestArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key: Ident = input.parse()?;
+        if key != "elf" {
+            return Err(syn::Error::new(key.span(), "expected `elf = \"...\"`"));
+        }
+        input.parse::<Token![=]>()?;
+        let elf = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self { elf })
+    }
+}
+
+/// Turns a function taking an `SP1Stdin` and a `TestClient` into a test of an SP1 program.
+///
+/// The `elf` path is relative to the crate being tested, and points either to a compiled ELF or
+/// to the directory of a guest program, which is built once per test binary. See the `sp1-test`
+/// crate for details.
+#[proc_macro_attribute]
+pub fn sp1_test(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as Sp1TestArgs);
+    let function = parse_macro_input!(item as ItemFn);
+
+    if function.sig.inputs.len() != 2 {
+        return syn::Error::new_spanned(
+            &function.sig,
+            "expected a function of the form `fn(SP1Stdin, TestClient)`",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let elf = &args.elf;
+    let attrs = &function.attrs;
+    let vis = &function.vis;
+    let name = &function.sig.ident;
+    let output = &function.sig.output;
+    let inputs = &function.sig.inputs;
+    let block = &function.block;
+
+    let result = quote! {
+        #[test]
+        #(#attrs)*
+        #vis fn #name() #output {
+            fn inner(#inputs) #output #block
+
+            let elf = ::sp1_test::load_elf(env!("CARGO_MANIFEST_DIR"), #elf);
+            inner(::sp1_test::SP1Stdin::new(), ::sp1_test::TestClient::new(elf))
+        }
+    };
+    result.into()
+}
@@ -0,0 +1,195 @@
+//! Cycle budgets of programs, checked against a TOML baseline.
+//!
+//! The baseline maps the name of each budget to the maximum number of cycles of the execution, and
+//! optionally of the regions tracked with `cycle-tracker-report-start` and `-end`:
+//!
+//! ```toml
+//! [fibonacci]
+//! total = 6000
+//! regions = { compute = 5000 }
+//! ```
+//!
+//! Set `SP1_UPDATE_CYCLES=true` to record the current cycle counts as the new budgets, after an
+//! intended change of the program or of the zkVM.
+
+use std::{
+    collections::BTreeMap,
+    path::Path,
+    sync::{Mutex, OnceLock},
+};
+
+use serde::{Deserialize, Serialize};
+use sp1_sdk::ExecutionReport;
+
+/// The cycle budget of an execution.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CycleBudget {
+    /// The maximum number of cycles of the whole execution.
+    pub total: u64,
+    /// The maximum number of cycles of each tracked region.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub regions: BTreeMap<String, u64>,
+}
+
+impl CycleBudget {
+    /// The cycle counts of an execution, as a budget.
+    pub fn from_report(report: &ExecutionReport) -> Self {
+        Self {
+            total: report.total_instruction_count(),
+            regions: report
+                .cycle_tracker
+                .iter()
+                .map(|(name, &cycles)| (name.clone(), cycles))
+                .collect(),
+        }
+    }
+}
+
+/// Returns whether `SP1_UPDATE_CYCLES` is set to `true`.
+pub fn update_cycles_enabled() -> bool {
+    std::env::var("SP1_UPDATE_CYCLES").map(|v| v.eq_ignore_ascii_case("true")).unwrap_or(false)
+}
+
+/// Asserts that the execution of `report` fits in the budget `name` of the baseline at
+/// `baseline_path`, or records it as the new budget if `SP1_UPDATE_CYCLES=true`.
+///
+/// Prefer using [assert_max_cycles](crate::assert_max_cycles).
+pub fn check_max_cycles(baseline_path: &Path, name: &str, report: &ExecutionReport) {
+    // Tests of the same crate share the baseline, so they update it one at a time.
+    static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+    let _guard = LOCK.get_or_init(Default::default).lock().unwrap_or_else(|e| e.into_inner());
+
+    let mut baseline = read_baseline(baseline_path);
+    let actual = CycleBudget::from_report(report);
+
+    if update_cycles_enabled() {
+        baseline.insert(name.to_string(), actual);
+        let contents = toml::to_string(&baseline).expect("failed to serialize cycle baseline");
+        std::fs::write(baseline_path, contents).unwrap_or_else(|e| {
+            panic!("failed to write cycle baseline {}: {}", baseline_path.display(), e)
+        });
+        return;
+    }
+
+    let Some(budget) = baseline.get(name) else {
+        panic!(
+            "no cycle budget {name} in {}; set SP1_UPDATE_CYCLES=true to record it",
+            baseline_path.display()
+        );
+    };
+
+    let mut exceeded = Vec::new();
+    if actual.total > budget.total {
+        exceeded.push(format!("total: {} > {}", actual.total, budget.total));
+    }
+    for (region, &max) in budget.regions.iter() {
+        match actual.regions.get(region) {
+            Some(&cycles) if cycles > max => {
+                exceeded.push(format!("region {region}: {cycles} > {max}"))
+            }
+            Some(_) => {}
+            None => exceeded.push(format!("region {region} was not tracked")),
+        }
+    }
+    if !exceeded.is_empty() {
+        panic!(
+            "cycle budget {name} of {} exceeded:\n  {}\nset SP1_UPDATE_CYCLES=true if the increase \
+             is intended",
+            baseline_path.display(),
+            exceeded.join("\n  ")
+        );
+    }
+}
+
+fn read_baseline(path: &Path) -> BTreeMap<String, CycleBudget> {
+    if !path.exists() {
+        return BTreeMap::new();
+    }
+    let contents = std::fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("failed to read cycle baseline {}: {}", path.display(), e));
+    toml::from_str(&contents)
+        .unwrap_or_else(|e| panic!("invalid cycle baseline {}: {}", path.display(), e))
+}
+
+/// Asserts that the cycles of an [ExecutionReport] fit in a budget of the `cycles.toml` baseline
+/// at the root of the crate being tested.
+///
+/// Fails if the total cycles, or the cycles of a region listed in the budget, exceed it. Set
+/// `SP1_UPDATE_CYCLES=true` to record the current cycles instead.
+///
+/// ```ignore
+/// #[sp1_test(elf = "../program")]
+/// fn test_fibonacci_cycles(mut stdin: SP1Stdin, client: TestClient) {
+///     stdin.write(&1000u32);
+///     let (_, report) = client.execute(stdin);
+///     assert_max_cycles!(report, "fibonacci");
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_max_cycles {
+    ($report:expr, $name:expr) => {
+        $crate::check_max_cycles(
+            ::std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/cycles.toml")),
+            $name,
+            &$report,
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn report(total: u64, regions: &[(&str, u64)]) -> ExecutionReport {
+        let mut report = ExecutionReport::default();
+        report.opcode_counts[sp1_core_executor::Opcode::ADD] = total;
+        for (name, cycles) in regions {
+            report.cycle_tracker.insert(name.to_string(), *cycles);
+        }
+        report
+    }
+
+    fn baseline(name: &str, contents: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("sp1-test-cycles-{name}.toml"));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn within_budget() {
+        let path = baseline("within", "[prog]\ntotal = 100\nregions = { hash = 50 }\n");
+        check_max_cycles(&path, "prog", &report(100, &[("hash", 40), ("other", 1000)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "total: 101 > 100")]
+    fn total_exceeded() {
+        let path = baseline("total", "[prog]\ntotal = 100\n");
+        check_max_cycles(&path, "prog", &report(101, &[]));
+    }
+
+    #[test]
+    #[should_panic(expected = "region hash: 51 > 50")]
+    fn region_exceeded() {
+        let path = baseline("region", "[prog]\ntotal = 100\nregions = { hash = 50 }\n");
+        check_max_cycles(&path, "prog", &report(100, &[("hash", 51)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "no cycle budget missing")]
+    fn missing_budget() {
+        let path = baseline("missing", "[prog]\ntotal = 100\n");
+        check_max_cycles(&path, "missing", &report(100, &[]));
+    }
+
+    #[test]
+    fn budget_roundtrip() {
+        let budget =
+            CycleBudget { total: 10, regions: [("hash".to_string(), 5)].into_iter().collect() };
+        let baseline = [("prog".to_string(), budget)].into_iter().collect::<BTreeMap<_, _>>();
+        let contents = toml::to_string(&baseline).unwrap();
+        assert_eq!(toml::from_str::<BTreeMap<String, CycleBudget>>(&contents).unwrap(), baseline);
+    }
+}
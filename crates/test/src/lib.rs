@@ -0,0 +1,151 @@
+//! End-to-end tests of SP1 programs together with their host code.
+//!
+//! The [sp1_test] attribute turns a function taking an [SP1Stdin] and a [TestClient] into a
+//! test of the guest program at the given path:
+//!
+//! ```ignore
+//! use sp1_test::{sp1_test, SP1Stdin, TestClient};
+//!
+//! #[sp1_test(elf = "../program")]
+//! fn test_fibonacci(mut stdin: SP1Stdin, client: TestClient) {
+//!     stdin.write(&10u32);
+//!     let mut public_values = client.run(stdin);
+//!     assert_eq!(public_values.read::<u32>(), 10);
+//! }
+//! ```
+//!
+//! The path is relative to the crate containing the test. If it is a directory, the guest program
+//! in it is built the first time a test of the binary needs it. Otherwise, it is read as an ELF.
+//!
+//! The [assert_max_cycles] macro checks the cycles of an execution against the budgets committed
+//! in the `cycles.toml` file of the crate, see the [cycles] module.
+//!
+//! Tests only execute the program by default. Set `SP1_TEST_PROVE=true` to also generate and
+//! verify a core proof of every execution with the prover selected by `SP1_PROVER`, which
+//! requires compiling the tests in release mode.
+
+// Lets the tests of this crate use the `::sp1_test` paths emitted by the macro.
+extern crate self as sp1_test;
+
+pub mod cycles;
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, OnceLock},
+};
+
+pub use cycles::check_max_cycles;
+pub use sp1_sdk::{ProverClient, SP1PublicValues, SP1Stdin};
+pub use sp1_test_macro::sp1_test;
+
+use sp1_build::{execute_build_program, BuildArgs};
+use sp1_sdk::ExecutionReport;
+
+/// The name of the ELF produced by building a guest program with the default arguments.
+const DEFAULT_ELF_PATH: &str = "elf/riscv32im-succinct-zkvm-elf";
+
+/// Returns whether `SP1_TEST_PROVE` is set to `true`.
+pub fn prove_enabled() -> bool {
+    std::env::var("SP1_TEST_PROVE").map(|v| v.eq_ignore_ascii_case("true")).unwrap_or(false)
+}
+
+/// Loads the ELF at `path`, relative to `manifest_dir`, building the guest program first if `path`
+/// is a directory.
+///
+/// Each program is only loaded once per process, so tests sharing a program only build it once.
+/// Set `SP1_SKIP_PROGRAM_BUILD=true` to use the previously built ELF of a program instead.
+pub fn load_elf(manifest_dir: &str, path: &str) -> Arc<[u8]> {
+    static ELFS: OnceLock<Mutex<HashMap<PathBuf, Arc<[u8]>>>> = OnceLock::new();
+
+    let path = Path::new(manifest_dir).join(path);
+    // Hold the lock while building, so that concurrent tests don't build the same program twice.
+    let mut elfs = ELFS.get_or_init(Default::default).lock().unwrap();
+    elfs.entry(path.clone())
+        .or_insert_with(|| {
+            let elf_path = if path.is_dir() { build_program(&path) } else { path.clone() };
+            std::fs::read(&elf_path)
+                .unwrap_or_else(|e| panic!("failed to read ELF at {}: {}", elf_path.display(), e))
+                .into()
+        })
+        .clone()
+}
+
+/// Builds the guest program in `program_dir` and returns the path to its ELF.
+fn build_program(program_dir: &Path) -> PathBuf {
+    let skip_program_build = std::env::var("SP1_SKIP_PROGRAM_BUILD")
+        .map(|v| v.eq_ignore_ascii_case("true"))
+        .unwrap_or(false);
+    if skip_program_build {
+        return program_dir.join(DEFAULT_ELF_PATH);
+    }
+    execute_build_program(&BuildArgs::default(), Some(program_dir.to_path_buf()))
+        .unwrap_or_else(|e| panic!("failed to build {}: {}", program_dir.display(), e))
+        .into()
+}
+
+/// Runs the program under test, passed to the functions annotated with [sp1_test].
+pub struct TestClient {
+    elf: Arc<[u8]>,
+    prover: ProverClient,
+}
+
+impl TestClient {
+    /// Creates a client for the given program.
+    ///
+    /// The client uses the mock prover unless `SP1_TEST_PROVE=true`, in which case it uses the
+    /// prover selected by `SP1_PROVER`.
+    pub fn new(elf: Arc<[u8]>) -> Self {
+        let prover = if prove_enabled() { ProverClient::new() } else { ProverClient::mock() };
+        Self { elf, prover }
+    }
+
+    /// The ELF of the program under test.
+    pub fn elf(&self) -> &[u8] {
+        &self.elf
+    }
+
+    /// The underlying prover client, for tests that need more control over proving.
+    pub fn prover(&self) -> &ProverClient {
+        &self.prover
+    }
+
+    /// Executes the program on `stdin`, panicking if the execution fails.
+    pub fn execute(&self, stdin: SP1Stdin) -> (SP1PublicValues, ExecutionReport) {
+        self.prover.execute(&self.elf, stdin).run().expect("failed to execute program")
+    }
+
+    /// Executes the program on `stdin` and returns its public values, panicking if the execution
+    /// fails.
+    ///
+    /// If `SP1_TEST_PROVE=true`, this also proves the execution and verifies the proof.
+    pub fn run(&self, stdin: SP1Stdin) -> SP1PublicValues {
+        if !prove_enabled() {
+            return self.execute(stdin).0;
+        }
+        let (pk, vk) = self.prover.setup(&self.elf);
+        let proof = self.prover.prove(&pk, stdin).run().expect("failed to prove program");
+        self.prover.verify(&proof, &vk).expect("failed to verify proof");
+        proof.public_values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sp1_test(elf = "../../examples/fibonacci/program/elf/riscv32im-succinct-zkvm-elf")]
+    fn test_fibonacci(mut stdin: SP1Stdin, client: TestClient) {
+        stdin.write(&10u32);
+        let mut public_values = client.run(stdin);
+        assert_eq!(public_values.read::<u32>(), 10);
+        assert_eq!(public_values.read::<u32>(), 55);
+    }
+
+    #[test]
+    fn test_load_elf_is_cached() {
+        let path = "../../examples/fibonacci/program/elf/riscv32im-succinct-zkvm-elf";
+        let elf = load_elf(env!("CARGO_MANIFEST_DIR"), path);
+        assert!(Arc::ptr_eq(&elf, &load_elf(env!("CARGO_MANIFEST_DIR"), path)));
+    }
+}
@@ -0,0 +1,116 @@
+//! Inline assembly helpers for hand-optimized guest code.
+//!
+//! Every instruction of the zkVM takes one cycle, so inner loops written in assembly cost exactly
+//! the instructions they contain. These macros cover the pieces such loops usually need:
+//!
+//! - [syscall] issues a raw `ecall` with a syscall code and two arguments, and returns the value
+//!   the syscall wrote to `t0`.
+//! - [read_reg] reads a register, such as `sp` or `gp`, without moving it through memory.
+//! - [nops] pads the code with a fixed number of `nop` instructions, one cycle each, for example
+//!   to align the cycle counts of two branches.
+//!
+//! Outside the zkVM, [syscall] and [read_reg] panic and [nops] does nothing, so that the program
+//! still builds natively.
+//!
+//! ### Examples
+//! ```ignore
+//! use sp1_zkvm::asm::{nops, read_reg, syscall};
+//!
+//! let sp = read_reg!(sp);
+//! nops!(4);
+//! let len = unsafe { syscall!(sp1_zkvm::syscalls::HINT_LEN) };
+//! ```
+
+/// Issues the syscall `code` with the arguments `arg1` in `a0` and `arg2` in `a1`, returning the
+/// value of `t0` after the syscall.
+///
+/// ### Safety
+///
+/// The arguments must be valid for the syscall, in particular the pointers it reads or writes
+/// through must be aligned and valid for the number of words the syscall accesses.
+#[allow(unused_variables)]
+#[inline(always)]
+pub unsafe fn syscall_raw(code: u32, arg1: u32, arg2: u32) -> u32 {
+    #[cfg(target_os = "zkvm")]
+    {
+        let result;
+        core::arch::asm!(
+            "ecall",
+            inlateout("t0") code => result,
+            in("a0") arg1,
+            in("a1") arg2,
+            options(nostack),
+        );
+        result
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Issues a raw syscall and returns the value of `t0` after it.
+///
+/// The syscall code is followed by up to two arguments, passed in `a0` and `a1` as `u32`. Missing
+/// arguments are zero. The macro expands to a call of the unsafe [syscall_raw], so it must be used
+/// inside an `unsafe` block, with the same safety requirements.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __asm_syscall {
+    ($code:expr $(,)?) => {
+        $crate::asm::syscall_raw($code, 0, 0)
+    };
+    ($code:expr, $arg1:expr $(,)?) => {
+        $crate::asm::syscall_raw($code, $arg1 as u32, 0)
+    };
+    ($code:expr, $arg1:expr, $arg2:expr $(,)?) => {
+        $crate::asm::syscall_raw($code, $arg1 as u32, $arg2 as u32)
+    };
+}
+
+/// Reads a register, given by its ABI name such as `sp`, `gp` or `a0`, as a `u32`.
+///
+/// The read has no side effect, so the macro is safe to use. Outside the zkVM, it panics.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __asm_read_reg {
+    ($reg:ident) => {{
+        #[cfg(target_os = "zkvm")]
+        {
+            let value: u32;
+            // SAFETY: the instruction only copies the register to an output register.
+            unsafe {
+                ::core::arch::asm!(
+                    concat!("mv {0}, ", stringify!($reg)),
+                    out(reg) value,
+                    options(nomem, nostack, preserves_flags),
+                );
+            }
+            value
+        }
+        #[cfg(not(target_os = "zkvm"))]
+        {
+            unreachable!()
+        }
+    }};
+}
+
+/// Emits the given number of `nop` instructions, which take one cycle each.
+///
+/// The count must be an integer literal, so that the padding is known when the program is built.
+/// Outside the zkVM, the macro does nothing.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __asm_nops {
+    ($count:literal) => {{
+        #[cfg(target_os = "zkvm")]
+        // SAFETY: `nop` has no effect but to advance the program counter.
+        unsafe {
+            ::core::arch::asm!(
+                concat!(".rept ", stringify!($count), "\nnop\n.endr"),
+                options(nomem, nostack, preserves_flags),
+            );
+        }
+    }};
+}
+
+pub use crate::{__asm_nops as nops, __asm_read_reg as read_reg, __asm_syscall as syscall};
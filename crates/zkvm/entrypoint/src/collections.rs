@@ -0,0 +1,178 @@
+//! Hash maps and sets with a hasher tuned for the zkVM.
+//!
+//! The default hasher of `std`, SipHash-1-3 with random keys, spends most of a lookup on 64-bit
+//! arithmetic, which the zkVM emulates with several 32-bit instructions. [ZkHasher] instead mixes
+//! one 32-bit word per multiplication. The [HashMap] and [HashSet] of this module use it by default
+//! inside the zkVM, and keep the hasher of `std` elsewhere, so that the same program can be tested
+//! natively.
+//!
+//! [ZkHasher] is not collision resistant, so these maps and sets should only hold keys which the
+//! program or the prover chooses, and not keys chosen by third parties.
+//!
+//! Unlike the types of `std`, these maps and sets must be created with `default` or
+//! `with_capacity_and_hasher`, since `new` and `with_capacity` only exist for the hasher of `std`.
+
+use std::hash::{BuildHasherDefault, Hasher};
+
+pub use std::collections::{hash_map, hash_set};
+
+/// The multiplier of [ZkHasher], an odd constant with well distributed bits.
+const K: u32 = 0x93d7_65dd;
+
+/// A fast, deterministic hasher for the zkVM, mixing one 32-bit word at a time.
+///
+/// The hasher is not resistant to collisions chosen by an adversary, and has no key to hide its
+/// collisions. Keys which come from third parties, such as the transactions of a block or the
+/// messages of users, can be chosen to land in the same bucket, so that every insertion scans the
+/// colliding keys and building a map takes a number of cycles quadratic in its size, enough to
+/// make the program too expensive to prove. Store such keys in a [std::collections::BTreeMap]
+/// instead.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ZkHasher {
+    hash: u32,
+}
+
+impl ZkHasher {
+    /// Mixes a word into the state.
+    #[inline]
+    fn add(&mut self, word: u32) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(K);
+    }
+}
+
+impl Hasher for ZkHasher {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        let words = bytes.chunks_exact(4);
+        let tail = words.remainder();
+        for word in words {
+            self.add(u32::from_le_bytes(word.try_into().unwrap()));
+        }
+        // The length of the tail goes in its unused top byte, so that trailing zeros still change
+        // the hash.
+        if !tail.is_empty() {
+            let mut word = [0, 0, 0, tail.len() as u8];
+            word[..tail.len()].copy_from_slice(tail);
+            self.add(u32::from_le_bytes(word));
+        }
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.add(i as u32);
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.add(i as u32);
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.add(i);
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.add(i as u32);
+        self.add((i >> 32) as u32);
+    }
+
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        self.write_u64(i as u64);
+        self.write_u64((i >> 64) as u64);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        if usize::BITS == 32 {
+            self.add(i as u32);
+        } else {
+            self.write_u64(i as u64);
+        }
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        // The multiplication leaves the low bits poorly mixed, and hash tables index their buckets
+        // with them, so rotate the well mixed high bits down. Both halves are filled, since tables
+        // on 64-bit hosts take their tags from the top bits.
+        let hash = self.hash.rotate_left(15);
+        ((hash as u64) << 32) | hash as u64
+    }
+}
+
+/// The builder of [ZkHasher].
+pub type ZkBuildHasher = BuildHasherDefault<ZkHasher>;
+
+/// The default hasher of [HashMap] and [HashSet]: [ZkBuildHasher] inside the zkVM, and the hasher
+/// of `std` elsewhere.
+#[cfg(target_os = "zkvm")]
+pub type DefaultHashBuilder = ZkBuildHasher;
+
+/// The default hasher of [HashMap] and [HashSet]: [ZkBuildHasher] inside the zkVM, and the hasher
+/// of `std` elsewhere.
+#[cfg(not(target_os = "zkvm"))]
+pub type DefaultHashBuilder = std::collections::hash_map::RandomState;
+
+/// A [std::collections::HashMap] hashing with [DefaultHashBuilder].
+pub type HashMap<K, V, S = DefaultHashBuilder> = std::collections::HashMap<K, V, S>;
+
+/// A [std::collections::HashSet] hashing with [DefaultHashBuilder].
+pub type HashSet<T, S = DefaultHashBuilder> = std::collections::HashSet<T, S>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hash::{BuildHasher, Hash};
+
+    fn hash<T: Hash + ?Sized>(value: &T) -> u64 {
+        ZkBuildHasher::default().hash_one(value)
+    }
+
+    #[test]
+    fn test_zk_hasher_deterministic() {
+        assert_eq!(hash(&42u32), hash(&42u32));
+        assert_eq!(hash("sp1"), hash("sp1"));
+        let h = hash(&0xdead_beefu32);
+        assert_eq!(h >> 32, h & 0xffff_ffff);
+    }
+
+    #[test]
+    fn test_zk_hasher_tail() {
+        // Trailing zeros and the length of the tail change the hash.
+        let mut a = ZkHasher::default();
+        a.write(&[1, 2, 3, 4, 5]);
+        let mut b = ZkHasher::default();
+        b.write(&[1, 2, 3, 4, 5, 0]);
+        assert_ne!(a.finish(), b.finish());
+
+        let mut c = ZkHasher::default();
+        c.write(&[1, 2, 3, 4]);
+        let mut d = ZkHasher::default();
+        d.write(&[1, 2, 3, 4, 0, 0, 0, 0]);
+        assert_ne!(c.finish(), d.finish());
+    }
+
+    #[test]
+    fn test_zk_hasher_wide_integers() {
+        // Both halves of a 64-bit integer are mixed in.
+        assert_ne!(hash(&1u64), hash(&(1u64 << 32 | 1)));
+        assert_ne!(hash(&1u128), hash(&(1u128 << 64 | 1)));
+    }
+
+    #[test]
+    fn test_zk_hash_map() {
+        let mut map = HashMap::<u32, u32, ZkBuildHasher>::default();
+        for i in 0..1000 {
+            map.insert(i, i * i);
+        }
+        assert_eq!(map.len(), 1000);
+        assert!((0..1000).all(|i| map[&i] == i * i));
+
+        let set = (0..1000u64).map(|i| i << 32).collect::<HashSet<_, ZkBuildHasher>>();
+        assert_eq!(set.len(), 1000);
+        assert!(set.contains(&(999 << 32)));
+    }
+}
@@ -0,0 +1,86 @@
+//! Hash functions backed by the zkVM's precompiles.
+
+use crate::syscalls::syscall_keccak_permute;
+
+/// The number of bytes absorbed into the Keccak-256 state between two permutations.
+const KECCAK256_RATE: usize = 136;
+
+/// A Keccak-256 hasher that absorbs input directly into the state of the `KECCAK_PERMUTE`
+/// precompile.
+///
+/// The state is kept in place across calls to [`Keccak256::update`], so input is XORed into it as
+/// it arrives and the precompile is only invoked once a full block has been absorbed. Unlike the
+/// patched `tiny-keccak` crate, no intermediate buffer is copied into the state on each
+/// permutation.
+///
+/// This hasher is only available inside the zkVM.
+#[derive(Clone)]
+pub struct Keccak256 {
+    state: [u64; 25],
+    /// The number of bytes absorbed into the current block.
+    offset: usize,
+}
+
+impl Keccak256 {
+    /// Creates a new hasher with an empty state.
+    pub const fn new() -> Self {
+        Self { state: [0; 25], offset: 0 }
+    }
+
+    /// Absorbs `data` into the state.
+    pub fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            // Absorb whole lanes at once when the block offset is aligned.
+            if self.offset % 8 == 0 && data.len() >= 8 {
+                let lanes = ((KECCAK256_RATE - self.offset) / 8).min(data.len() / 8);
+                let start = self.offset / 8;
+                for (lane, chunk) in
+                    self.state[start..start + lanes].iter_mut().zip(data.chunks_exact(8))
+                {
+                    *lane ^= u64::from_le_bytes(chunk.try_into().unwrap());
+                }
+                self.offset += lanes * 8;
+                data = &data[lanes * 8..];
+            } else {
+                self.xor_byte(self.offset, data[0]);
+                self.offset += 1;
+                data = &data[1..];
+            }
+
+            if self.offset == KECCAK256_RATE {
+                syscall_keccak_permute(&mut self.state);
+                self.offset = 0;
+            }
+        }
+    }
+
+    /// Pads the input, and returns the 32 byte digest.
+    pub fn finalize(mut self) -> [u8; 32] {
+        self.xor_byte(self.offset, 0x01);
+        self.xor_byte(KECCAK256_RATE - 1, 0x80);
+        syscall_keccak_permute(&mut self.state);
+
+        let mut digest = [0u8; 32];
+        for (chunk, lane) in digest.chunks_exact_mut(8).zip(self.state.iter()) {
+            chunk.copy_from_slice(&lane.to_le_bytes());
+        }
+        digest
+    }
+
+    /// Hashes `data` in one call.
+    pub fn digest(data: &[u8]) -> [u8; 32] {
+        let mut hasher = Self::new();
+        hasher.update(data);
+        hasher.finalize()
+    }
+
+    fn xor_byte(&mut self, index: usize, byte: u8) {
+        self.state[index / 8] ^= (byte as u64) << (8 * (index % 8));
+    }
+}
+
+impl Default for Keccak256 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -2,6 +2,24 @@ use core::alloc::{GlobalAlloc, Layout};
 
 use crate::syscalls::sys_alloc_aligned;
 
+/// The heap usage of the program, as tracked by [SimpleAlloc].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HeapStats {
+    /// The size of the heap at its peak, in bytes.
+    pub peak_heap_bytes: usize,
+    /// The total number of bytes allocated.
+    pub total_allocated_bytes: usize,
+    /// The number of allocations.
+    pub allocation_count: usize,
+}
+
+/// The heap usage so far.
+static mut STATS: HeapStats =
+    HeapStats { peak_heap_bytes: 0, total_allocated_bytes: 0, allocation_count: 0 };
+
+/// The address of the first allocation, or 0 if nothing was allocated yet.
+static mut HEAP_START: usize = 0;
+
 /// A simple heap allocator.
 ///
 /// Allocates memory from left to right, without any deallocation.
@@ -9,8 +27,48 @@ pub struct SimpleAlloc;
 
 unsafe impl GlobalAlloc for SimpleAlloc {
     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
-        sys_alloc_aligned(layout.size(), layout.align())
+        let ptr = sys_alloc_aligned(layout.size(), layout.align());
+
+        // SAFETY: Single threaded, so nothing else can touch the stats while we're working.
+        let stats = &mut *core::ptr::addr_of_mut!(STATS);
+        if HEAP_START == 0 {
+            HEAP_START = ptr as usize;
+        }
+        let heap_size = ptr as usize + layout.size() - HEAP_START;
+        stats.peak_heap_bytes = stats.peak_heap_bytes.max(heap_size);
+        stats.total_allocated_bytes += layout.size();
+        stats.allocation_count += 1;
+
+        ptr
     }
 
     unsafe fn dealloc(&self, _: *mut u8, _: Layout) {}
 }
+
+/// Returns the heap usage of the program so far.
+///
+/// Only the allocations made through the allocator declared by [crate::entrypoint] are tracked.
+///
+/// ### Examples
+/// ```ignore
+/// let stats = sp1_zkvm::heap_stats();
+/// println!("peak heap size: {} bytes", stats.peak_heap_bytes);
+/// ```
+pub fn heap_stats() -> HeapStats {
+    // SAFETY: Single threaded, so nothing else can touch the stats while we're reading them.
+    unsafe { *core::ptr::addr_of!(STATS) }
+}
+
+/// Reports the heap usage of the program to the host, if it allocated anything.
+#[cfg(target_os = "zkvm")]
+pub(crate) fn report_heap_stats() {
+    let stats = heap_stats();
+    if stats.allocation_count > 0 {
+        let words = [
+            stats.peak_heap_bytes as u32,
+            stats.total_allocated_bytes as u32,
+            stats.allocation_count as u32,
+        ];
+        crate::syscalls::syscall_heap_stats(&words);
+    }
+}
@@ -0,0 +1,379 @@
+//! Password-based key derivation backed by the zkVM's precompiles.
+//!
+//! Verifying a password or recovering a wallet inside the zkVM means recomputing its key derivation
+//! function, which is designed to be slow. PBKDF2-HMAC-SHA256 runs every iteration as two SHA-256
+//! compressions on the `SHA_EXTEND` and `SHA_COMPRESS` precompiles, from the inner and outer HMAC
+//! states computed once per password, and keeps the intermediate values as words so that no bytes
+//! are converted between iterations. scrypt uses the same PBKDF2 for its input and output, and runs
+//! its memory-hard loop, [scrypt_romix], on words in a single allocation.
+//!
+//! These functions are only available inside the zkVM.
+
+use crate::{
+    hash::Keccak256,
+    syscalls::{syscall_sha256_compress, syscall_sha256_extend},
+};
+
+/// The initial state of SHA-256.
+const SHA256_IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// The number of bytes in a block of SHA-256.
+const SHA256_BLOCK_SIZE: usize = 64;
+
+/// Compresses a block, given as big endian words, into a SHA-256 state.
+fn sha256_compress(state: &mut [u32; 8], block: &[u32; 16]) {
+    let mut w = [0u32; 64];
+    w[..16].copy_from_slice(block);
+    syscall_sha256_extend(&mut w);
+    syscall_sha256_compress(&mut w, state);
+}
+
+/// A SHA-256 hasher on the precompiles, which can start from the state of a hashed prefix.
+#[derive(Clone)]
+struct Sha256 {
+    state: [u32; 8],
+    block: [u8; SHA256_BLOCK_SIZE],
+    /// The number of bytes in `block`.
+    offset: usize,
+    /// The number of bytes hashed, including the prefix of the state.
+    len: u64,
+}
+
+impl Sha256 {
+    fn new() -> Self {
+        Self::resume(SHA256_IV, 0)
+    }
+
+    /// Resumes hashing from the state after `len` bytes, a whole number of blocks.
+    fn resume(state: [u32; 8], len: u64) -> Self {
+        Self { state, block: [0; SHA256_BLOCK_SIZE], offset: 0, len }
+    }
+
+    fn update(&mut self, mut data: &[u8]) {
+        self.len += data.len() as u64;
+        while !data.is_empty() {
+            let n = (SHA256_BLOCK_SIZE - self.offset).min(data.len());
+            self.block[self.offset..self.offset + n].copy_from_slice(&data[..n]);
+            self.offset += n;
+            data = &data[n..];
+            if self.offset == SHA256_BLOCK_SIZE {
+                sha256_compress(&mut self.state, &be_words(&self.block));
+                self.offset = 0;
+            }
+        }
+    }
+
+    /// Pads the input, and returns the digest as big endian words.
+    fn finalize(mut self) -> [u32; 8] {
+        let bit_len = self.len * 8;
+        self.update(&[0x80]);
+        let zeros = (SHA256_BLOCK_SIZE + 56 - self.offset) % SHA256_BLOCK_SIZE;
+        self.update(&[0; SHA256_BLOCK_SIZE][..zeros]);
+        self.update(&bit_len.to_be_bytes());
+        self.state
+    }
+}
+
+/// HMAC-SHA256 with a fixed key, whose inner and outer padded keys are hashed once.
+#[derive(Clone)]
+pub struct HmacSha256 {
+    inner: [u32; 8],
+    outer: [u32; 8],
+}
+
+impl HmacSha256 {
+    /// Hashes the padded keys of `key`.
+    pub fn new(key: &[u8]) -> Self {
+        let mut padded = [0u8; SHA256_BLOCK_SIZE];
+        if key.len() > SHA256_BLOCK_SIZE {
+            let mut hasher = Sha256::new();
+            hasher.update(key);
+            padded[..32].copy_from_slice(&be_bytes(&hasher.finalize()));
+        } else {
+            padded[..key.len()].copy_from_slice(key);
+        }
+
+        let pad = |byte: u8| {
+            let mut state = SHA256_IV;
+            sha256_compress(&mut state, &be_words(&padded.map(|b| b ^ byte)));
+            state
+        };
+        Self { inner: pad(0x36), outer: pad(0x5c) }
+    }
+
+    /// Returns the MAC of the concatenation of `parts`.
+    pub fn mac(&self, parts: &[&[u8]]) -> [u8; 32] {
+        be_bytes(&self.mac_words(parts))
+    }
+
+    fn mac_words(&self, parts: &[&[u8]]) -> [u32; 8] {
+        let mut inner = Sha256::resume(self.inner, SHA256_BLOCK_SIZE as u64);
+        for part in parts {
+            inner.update(part);
+        }
+        self.finalize_words(&inner.finalize())
+    }
+
+    /// Returns the MAC of a 32-byte message given as big endian words, in two compressions.
+    fn mac_digest(&self, message: &[u32; 8]) -> [u32; 8] {
+        let mut state = self.inner;
+        sha256_compress(&mut state, &digest_block(message));
+        self.finalize_words(&state)
+    }
+
+    /// Hashes the inner digest with the outer padded key.
+    fn finalize_words(&self, inner: &[u32; 8]) -> [u32; 8] {
+        let mut state = self.outer;
+        sha256_compress(&mut state, &digest_block(inner));
+        state
+    }
+}
+
+/// The last block of a hash of a 32-byte message after a block of padded key: the message, then
+/// the padding of a 96-byte input.
+fn digest_block(message: &[u32; 8]) -> [u32; 16] {
+    let mut block = [0u32; 16];
+    block[..8].copy_from_slice(message);
+    block[8] = 0x8000_0000;
+    block[15] = ((SHA256_BLOCK_SIZE + 32) * 8) as u32;
+    block
+}
+
+/// Derives `out.len()` bytes from a password with PBKDF2-HMAC-SHA256 (RFC 8018).
+///
+/// Each iteration costs two SHA-256 precompile calls of each kind.
+pub fn pbkdf2_hmac_sha256(password: &[u8], salt: &[u8], rounds: u32, out: &mut [u8]) {
+    assert!(rounds > 0, "PBKDF2 needs at least one round");
+    let hmac = HmacSha256::new(password);
+    for (i, chunk) in out.chunks_mut(32).enumerate() {
+        let mut u = hmac.mac_words(&[salt, &(i as u32 + 1).to_be_bytes()]);
+        let mut t = u;
+        for _ in 1..rounds {
+            u = hmac.mac_digest(&u);
+            for (t, u) in t.iter_mut().zip(u) {
+                *t ^= u;
+            }
+        }
+        chunk.copy_from_slice(&be_bytes(&t)[..chunk.len()]);
+    }
+}
+
+/// Returns whether `expected` is the PBKDF2-HMAC-SHA256 output of a password, see
+/// [pbkdf2_hmac_sha256].
+pub fn verify_pbkdf2_hmac_sha256(
+    password: &[u8],
+    salt: &[u8],
+    rounds: u32,
+    expected: &[u8],
+) -> bool {
+    let mut out = vec![0u8; expected.len()];
+    pbkdf2_hmac_sha256(password, salt, rounds, &mut out);
+    out == expected
+}
+
+/// The parameters of scrypt (RFC 7914).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScryptParams {
+    /// The base 2 logarithm of the CPU/memory cost `N`.
+    pub log_n: u8,
+    /// The block size `r`.
+    pub r: u32,
+    /// The parallelization `p`.
+    pub p: u32,
+}
+
+impl ScryptParams {
+    /// Returns the number of bytes [scrypt_romix] allocates, `128 * r * N`.
+    pub fn memory(&self) -> usize {
+        (128 * self.r as usize) << self.log_n
+    }
+}
+
+/// Derives `out.len()` bytes from a password with scrypt (RFC 7914).
+///
+/// The `p` blocks are mixed one after the other, so the memory used is [ScryptParams::memory]
+/// regardless of `p`.
+pub fn scrypt(password: &[u8], salt: &[u8], params: ScryptParams, out: &mut [u8]) {
+    assert!(params.r > 0 && params.p > 0, "invalid scrypt parameters");
+    assert!(params.log_n > 0 && params.log_n < 32, "invalid scrypt parameters");
+    let block_len = 128 * params.r as usize;
+    let mut blocks = vec![0u8; block_len * params.p as usize];
+    pbkdf2_hmac_sha256(password, salt, 1, &mut blocks);
+
+    let mut words = vec![0u32; block_len / 4];
+    for block in blocks.chunks_exact_mut(block_len) {
+        for (word, chunk) in words.iter_mut().zip(block.chunks_exact(4)) {
+            *word = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        scrypt_romix(&mut words, params.log_n, params.r);
+        for (chunk, word) in block.chunks_exact_mut(4).zip(&words) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+    }
+
+    pbkdf2_hmac_sha256(password, &blocks, 1, out);
+}
+
+/// Returns whether `expected` is the scrypt output of a password, see [scrypt].
+pub fn verify_scrypt(password: &[u8], salt: &[u8], params: ScryptParams, expected: &[u8]) -> bool {
+    let mut out = vec![0u8; expected.len()];
+    scrypt(password, salt, params, &mut out);
+    out == expected
+}
+
+/// The memory-hard loop of scrypt, `scryptROMix` of RFC 7914, on a block of `32 * r` little endian
+/// words with `N = 2^log_n`.
+///
+/// The `N` blocks of the table are kept in one allocation of [ScryptParams::memory] bytes, and the
+/// block mix alternates between two buffers instead of copying its output.
+pub fn scrypt_romix(block: &mut [u32], log_n: u8, r: u32) {
+    let len = 32 * r as usize;
+    assert_eq!(block.len(), len, "the scrypt block must have 32 * r words");
+    let n = 1usize << log_n;
+
+    let mut table = vec![0u32; n * len];
+    let mut scratch = vec![0u32; len];
+    for entry in table.chunks_exact_mut(len) {
+        entry.copy_from_slice(block);
+        block_mix_salsa8(block, &mut scratch);
+        block.copy_from_slice(&scratch);
+    }
+    for _ in 0..n {
+        // Integerify: the first word of the last 64-byte chunk, reduced modulo N.
+        let j = block[len - 16] as usize & (n - 1);
+        for (x, v) in block.iter_mut().zip(&table[j * len..(j + 1) * len]) {
+            *x ^= v;
+        }
+        block_mix_salsa8(block, &mut scratch);
+        block.copy_from_slice(&scratch);
+    }
+}
+
+/// `scryptBlockMix` with Salsa20/8, from `input` to `output`.
+fn block_mix_salsa8(input: &[u32], output: &mut [u32]) {
+    let chunks = input.len() / 16;
+    let mut x: [u32; 16] = input[input.len() - 16..].try_into().unwrap();
+    for (i, chunk) in input.chunks_exact(16).enumerate() {
+        for (x, b) in x.iter_mut().zip(chunk) {
+            *x ^= b;
+        }
+        salsa20_8(&mut x);
+        // The even chunks go to the first half of the output, and the odd ones to the second.
+        let position = i / 2 + (i % 2) * chunks / 2;
+        output[16 * position..16 * (position + 1)].copy_from_slice(&x);
+    }
+}
+
+/// The Salsa20/8 core.
+fn salsa20_8(b: &mut [u32; 16]) {
+    let mut x = *b;
+    for _ in 0..4 {
+        for [a, b, c, d] in [[0, 4, 8, 12], [5, 9, 13, 1], [10, 14, 2, 6], [15, 3, 7, 11]] {
+            quarter_round(&mut x, a, b, c, d);
+        }
+        for [a, b, c, d] in [[0, 1, 2, 3], [5, 6, 7, 4], [10, 11, 8, 9], [15, 12, 13, 14]] {
+            quarter_round(&mut x, a, b, c, d);
+        }
+    }
+    for (b, x) in b.iter_mut().zip(x) {
+        *b = b.wrapping_add(x);
+    }
+}
+
+fn quarter_round(x: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    x[b] ^= x[a].wrapping_add(x[d]).rotate_left(7);
+    x[c] ^= x[b].wrapping_add(x[a]).rotate_left(9);
+    x[d] ^= x[c].wrapping_add(x[b]).rotate_left(13);
+    x[a] ^= x[d].wrapping_add(x[c]).rotate_left(18);
+}
+
+/// Returns whether `mac` is the MAC of an Ethereum keystore (version 3) with the key derived from
+/// its password: the Keccak-256 hash of the second half of the key and the ciphertext.
+pub fn verify_keystore_mac(derived_key: &[u8; 32], ciphertext: &[u8], mac: &[u8; 32]) -> bool {
+    let mut hasher = Keccak256::new();
+    hasher.update(&derived_key[16..]);
+    hasher.update(ciphertext);
+    &hasher.finalize() == mac
+}
+
+fn be_words(bytes: &[u8; SHA256_BLOCK_SIZE]) -> [u32; 16] {
+    core::array::from_fn(|i| u32::from_be_bytes(bytes[4 * i..4 * i + 4].try_into().unwrap()))
+}
+
+fn be_bytes(words: &[u32; 8]) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (chunk, word) in bytes.chunks_exact_mut(4).zip(words) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parses the little endian words of a hex string, ignoring whitespace.
+    fn words(hex: &str) -> Vec<u32> {
+        let digits = hex.split_whitespace().collect::<String>();
+        let bytes = (0..digits.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&digits[i..i + 2], 16).unwrap())
+            .collect::<Vec<_>>();
+        bytes.chunks_exact(4).map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap())).collect()
+    }
+
+    /// The input of the `scryptBlockMix` and `scryptROMix` vectors of RFC 7914, with `r = 1`.
+    const MIX_INPUT: &str = "
+        f7ce0b65 3d2d72a4 108cf5ab e912ffdd 777616db bb27a70e 8204f3ae 2d0f6fad
+        89f68f48 11d1e87b cc3bd740 0a9ffd29 094f0184 639574f3 9ae5a131 5217bcd7
+        89499144 7213bb22 6c25b54d a86370fb cd984380 374666bb 8ffcb5bf 40c254b0
+        67d27c51 ce4ad5fe d829c90b 505a571b 7f4d1cad 6a523cda 770e67bc eaaf7e89";
+
+    /// RFC 7914, section 8.
+    #[test]
+    fn test_salsa20_8() {
+        let mut b: [u32; 16] = words(
+            "7e879a21 4f3ec986 7ca940e6 41718f26 baee555b 8c61c1b5 0df84611 6dcd3b1d
+             ee24f319 df9b3d85 14121e4b 5ac5aa32 76021d29 09c74829 edebc68d b8b8c25e",
+        )
+        .try_into()
+        .unwrap();
+        salsa20_8(&mut b);
+        let expected = words(
+            "a41f859c 6608cc99 3b81cacb 020cef05 044b2181 a2fd337d fd7b1c63 96682f29
+             b4393168 e3c9e6bc fe6bc5b7 a06d96ba e424cc10 2c91745c 24ad673d c7618f81",
+        );
+        assert_eq!(b.as_slice(), expected);
+    }
+
+    /// RFC 7914, section 9.
+    #[test]
+    fn test_block_mix_salsa8() {
+        let input = words(MIX_INPUT);
+        let mut output = vec![0u32; input.len()];
+        block_mix_salsa8(&input, &mut output);
+        let expected = words(
+            "a41f859c 6608cc99 3b81cacb 020cef05 044b2181 a2fd337d fd7b1c63 96682f29
+             b4393168 e3c9e6bc fe6bc5b7 a06d96ba e424cc10 2c91745c 24ad673d c7618f81
+             20edc975 323881a8 0540f64c 162dcd3c 21077cfe 5f8d5fe2 b1a4168f 953678b7
+             7d3b3d80 3b60e4ab 920996e5 9b4d53b6 5d2a2258 77d5edf5 842cb9f1 4eefe425",
+        );
+        assert_eq!(output, expected);
+    }
+
+    /// RFC 7914, section 10, with `N = 16`.
+    #[test]
+    fn test_scrypt_romix() {
+        let mut block = words(MIX_INPUT);
+        scrypt_romix(&mut block, 4, 1);
+        let expected = words(
+            "79ccc193 629debca 047f0b70 604bf6b6 2ce3dd4a 9626e355 fafc6198 e6ea2b46
+             d5841367 3b99b029 d665c357 601fb426 a0b2f4bb a200ee9f 0a43d19b 571a9c71
+             ef1142e6 5d5a266f ddca832c e59faa7c ac0b9cf1 be2bffca 300d01ee 387619c4
+             ae12fd44 38f203a0 e4e1c47e c314861f 4e9087cb 33396a68 73e8f9d2 539a4b8e",
+        );
+        assert_eq!(block, expected);
+    }
+}
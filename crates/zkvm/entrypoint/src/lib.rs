@@ -1,8 +1,14 @@
 extern crate alloc;
 
+pub mod asm;
+pub mod collections;
+pub mod hash;
 pub mod heap;
+pub mod kdf;
 pub mod syscalls;
 
+pub use heap::{heap_stats, HeapStats};
+
 #[cfg(feature = "lib")]
 pub mod io {
     pub use sp1_lib::io::*;
@@ -16,16 +22,28 @@ pub mod lib {
 #[cfg(all(target_os = "zkvm", feature = "libm"))]
 mod libm;
 
+#[cfg(target_os = "zkvm")]
+mod public_values;
+
+// Provides the `_Unwind_*` routines that the standard library's panic runtime calls into when the
+// program is built with `panic=unwind`.
+#[cfg(all(target_os = "zkvm", feature = "unwind"))]
+extern crate unwinding;
+
 /// The number of 32 bit words that the public values digest is composed of.
 pub const PV_DIGEST_NUM_WORDS: usize = 8;
 pub const POSEIDON_NUM_WORDS: usize = 8;
 
 #[cfg(target_os = "zkvm")]
 mod zkvm {
-    use crate::syscalls::syscall_halt;
+    use crate::{
+        public_values::{PublicValuesHasher, PUBLIC_VALUES_HASH_ID},
+        syscalls::syscall_halt,
+    };
 
+    use alloc::{collections::BTreeMap, vec::Vec};
     use cfg_if::cfg_if;
-    use sha2::{Digest, Sha256};
+    use sha2::Sha256;
 
     cfg_if! {
         if #[cfg(feature = "verify")] {
@@ -36,27 +54,54 @@ mod zkvm {
         }
     }
 
-    pub static mut PUBLIC_VALUES_HASHER: Option<Sha256> = None;
+    pub static mut PUBLIC_VALUES_HASHER: Option<PublicValuesHasher> = None;
+
+    /// The hasher of the inputs read from stdin, if the program commits to their digest.
+    pub static mut INPUT_HASHER: Option<Sha256> = None;
+
+    /// The largest number of bytes the program reads from stdin, if it declares one.
+    pub static mut MAX_STDIN_SIZE: Option<u64> = None;
+
+    /// The number of bytes read from stdin so far.
+    pub static mut STDIN_READ: u64 = 0;
+
+    /// The values committed by name, which are written to the public values when the program
+    /// halts.
+    pub static mut NAMED_PUBLIC_VALUES: BTreeMap<Vec<u8>, Vec<u8>> = BTreeMap::new();
+
+    /// The functions registered with `on_exit`, which are run when the program halts.
+    pub static mut EXIT_HOOKS: Vec<fn()> = Vec::new();
 
     #[cfg(not(feature = "interface"))]
     #[no_mangle]
     unsafe extern "C" fn __start() {
         {
-            PUBLIC_VALUES_HASHER = Some(Sha256::new());
+            core::ptr::read_volatile(&PUBLIC_VALUES_HASH_ID);
+            PUBLIC_VALUES_HASHER = Some(PublicValuesHasher::new());
             #[cfg(feature = "verify")]
             {
                 DEFERRED_PROOFS_DIGEST = Some([BabyBear::zero(); 8]);
             }
 
-            extern "C" {
+            extern "C-unwind" {
                 fn main();
             }
+
+            // With `panic=unwind`, a panic that is not caught by the program unwinds up to here,
+            // and halts with the same exit code as an aborting panic.
+            #[cfg(feature = "unwind")]
+            if std::panic::catch_unwind(|| main()).is_err() {
+                syscall_halt(1);
+            }
+            #[cfg(not(feature = "unwind"))]
             main()
         }
 
         syscall_halt(0);
     }
 
+    // The stack grows down from here. The executor reserves the addresses below `0x0001_0000` as
+    // a guard region, and traps with a stack overflow error when the stack reaches it.
     static STACK_TOP: u32 = 0x0020_0400;
 
     core::arch::global_asm!(include_str!("memset.s"));
@@ -89,6 +134,84 @@ mod zkvm {
     getrandom::register_custom_getrandom!(zkvm_getrandom);
 }
 
+/// Commits the SHA-256 digest of the inputs read by the program to the public values when it halts.
+///
+/// Called by `entrypoint!(main, commit_input_digest)` before the program starts.
+#[doc(hidden)]
+pub fn enable_input_digest() {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        use sha2::Digest;
+        zkvm::INPUT_HASHER = Some(sha2::Sha256::new());
+    }
+}
+
+/// Halts the program with exit code 1 when it reads more than `max` bytes from stdin.
+///
+/// Called by `entrypoint!(main, max_stdin_size = N)` before the program starts.
+#[doc(hidden)]
+#[allow(unused_variables)]
+pub fn enable_max_stdin_size(max: u64) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        zkvm::MAX_STDIN_SIZE = Some(max);
+    }
+}
+
+/// Registers a function to run when the program halts.
+///
+/// The hooks run before the public values are finalized, so they can still commit values, whether
+/// the program returns from `main` or panics, including with `panic=abort`. They run in the reverse
+/// order of their registration. A hook that panics halts the program with exit code 1, and the
+/// hooks registered before it still run.
+#[allow(unused_variables)]
+pub fn on_exit(hook: fn()) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        (*core::ptr::addr_of_mut!(zkvm::EXIT_HOOKS)).push(hook);
+    }
+}
+
+/// Asserts that a condition holds, reporting the failure to the host when the program is executed.
+///
+/// When the condition is false, the location and message of the assertion are sent to the host
+/// before the program panics. Executing the program then fails with
+/// `ExecutionError::HostAssertionFailed` and the message, instead of a bare non-zero exit code.
+/// The prover ignores the report, so a proof sees the same panic as with `assert!`, and the
+/// assertion costs nothing more than `assert!` while it holds.
+///
+/// ### Examples
+/// ```ignore
+/// sp1_zkvm::assert_host!(balance >= amount, "balance {} is below {}", balance, amount);
+/// ```
+#[cfg(feature = "lib")]
+#[macro_export]
+macro_rules! assert_host {
+    ($cond:expr $(,)?) => {
+        $crate::assert_host!($cond, "{}", stringify!($cond))
+    };
+    ($cond:expr, $($arg:tt)+) => {
+        if !$cond {
+            let message = format!($($arg)+);
+            $crate::io::report_host_assertion(concat!(file!(), ":", line!()), &message);
+            panic!("assertion failed: {}", message);
+        }
+    };
+}
+
+/// Declares the entrypoint of the program.
+///
+/// With `entrypoint!(main, commit_input_digest)`, the program also commits the SHA-256 digest of
+/// the inputs it read from stdin as the last 32 bytes of its public values, so that a verifier can
+/// bind the proof to the exact inputs with `SP1Stdin::input_digest`. Each input is hashed as its
+/// length in 8 little-endian bytes followed by its bytes. Read values hinted by the program itself
+/// with `io::read_hint`, which leaves them out of the digest.
+///
+/// With `entrypoint!(main, max_stdin_size = N)`, which can follow `commit_input_digest`, the
+/// program halts with exit code 1 as soon as it reads more than `N` bytes from stdin, so that it
+/// cannot be proven on a larger input. `N` is written to the `.sp1.max_stdin_size` section, from
+/// which the prover records it in the verifying key at setup. The values hinted by the program
+/// itself and the numbered input streams are not counted.
 #[macro_export]
 macro_rules! entrypoint {
     ($path:path) => {
@@ -107,4 +230,74 @@ macro_rules! entrypoint {
             }
         }
     };
+    ($path:path, commit_input_digest) => {
+        const ZKVM_ENTRY: fn() = $path;
+
+        use $crate::heap::SimpleAlloc;
+
+        #[global_allocator]
+        static HEAP: SimpleAlloc = SimpleAlloc;
+
+        mod zkvm_generated_main {
+
+            #[no_mangle]
+            fn main() {
+                $crate::enable_input_digest();
+                super::ZKVM_ENTRY()
+            }
+        }
+    };
+    ($path:path, max_stdin_size = $max:expr) => {
+        const ZKVM_ENTRY: fn() = $path;
+
+        use $crate::heap::SimpleAlloc;
+
+        #[global_allocator]
+        static HEAP: SimpleAlloc = SimpleAlloc;
+
+        #[cfg(target_os = "zkvm")]
+        #[used]
+        #[link_section = ".sp1.max_stdin_size"]
+        static ZKVM_MAX_STDIN_SIZE: u64 = $max;
+
+        mod zkvm_generated_main {
+
+            #[no_mangle]
+            fn main() {
+                // Read the maximum from its section, so that the program enforces the value that
+                // the verifying key records.
+                #[cfg(target_os = "zkvm")]
+                $crate::enable_max_stdin_size(unsafe {
+                    core::ptr::read_volatile(&super::ZKVM_MAX_STDIN_SIZE)
+                });
+                super::ZKVM_ENTRY()
+            }
+        }
+    };
+    ($path:path, commit_input_digest, max_stdin_size = $max:expr) => {
+        const ZKVM_ENTRY: fn() = $path;
+
+        use $crate::heap::SimpleAlloc;
+
+        #[global_allocator]
+        static HEAP: SimpleAlloc = SimpleAlloc;
+
+        #[cfg(target_os = "zkvm")]
+        #[used]
+        #[link_section = ".sp1.max_stdin_size"]
+        static ZKVM_MAX_STDIN_SIZE: u64 = $max;
+
+        mod zkvm_generated_main {
+
+            #[no_mangle]
+            fn main() {
+                $crate::enable_input_digest();
+                #[cfg(target_os = "zkvm")]
+                $crate::enable_max_stdin_size(unsafe {
+                    core::ptr::read_volatile(&super::ZKVM_MAX_STDIN_SIZE)
+                });
+                super::ZKVM_ENTRY()
+            }
+        }
+    };
 }
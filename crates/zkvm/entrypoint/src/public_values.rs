@@ -0,0 +1,104 @@
+//! The hasher of the bytes written to the public values, whose digest the program commits to when
+//! it halts.
+//!
+//! SHA-256 is used by default. The `public-values-keccak` and `public-values-poseidon2` features
+//! select Keccak-256 or Poseidon2 instead. The id of the hash is written to the
+//! `.sp1.public_values_hash` section, from which the prover reads the `PublicValuesHash` of the
+//! verifying key at setup.
+
+#[cfg(all(feature = "public-values-keccak", feature = "public-values-poseidon2"))]
+compile_error!("only one of `public-values-keccak` and `public-values-poseidon2` can be enabled");
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "public-values-keccak")] {
+        /// The hasher of the public values.
+        pub struct PublicValuesHasher(crate::hash::Keccak256);
+
+        impl PublicValuesHasher {
+            pub const ID: u8 = 1;
+
+            pub const fn new() -> Self {
+                Self(crate::hash::Keccak256::new())
+            }
+
+            pub fn update(&mut self, data: &[u8]) {
+                self.0.update(data);
+            }
+
+            pub fn finalize(self) -> [u8; 32] {
+                self.0.finalize()
+            }
+        }
+    } else if #[cfg(feature = "public-values-poseidon2")] {
+        use alloc::vec::Vec;
+
+        use p3_baby_bear::BabyBear;
+        use p3_field::{AbstractField, PrimeField32};
+
+        /// The hasher of the public values.
+        ///
+        /// The sponge needs the whole input at once, so the bytes are buffered until the program
+        /// halts.
+        pub struct PublicValuesHasher(Vec<u8>);
+
+        impl PublicValuesHasher {
+            pub const ID: u8 = 2;
+
+            pub const fn new() -> Self {
+                Self(Vec::new())
+            }
+
+            pub fn update(&mut self, data: &[u8]) {
+                self.0.extend_from_slice(data);
+            }
+
+            /// Absorbs each byte as a field element, followed by the 4 little-endian bytes of the
+            /// length, and returns the 8 output elements as little-endian `u32`s.
+            pub fn finalize(self) -> [u8; 32] {
+                let len = (self.0.len() as u32).to_le_bytes();
+                let input = self
+                    .0
+                    .iter()
+                    .chain(len.iter())
+                    .map(|&byte| BabyBear::from_canonical_u8(byte))
+                    .collect();
+                let mut digest = [0u8; 32];
+                for (chunk, element) in
+                    digest.chunks_exact_mut(4).zip(sp1_primitives::poseidon2_hash(input))
+                {
+                    chunk.copy_from_slice(&element.as_canonical_u32().to_le_bytes());
+                }
+                digest
+            }
+        }
+    } else {
+        use sha2::{Digest, Sha256};
+
+        /// The hasher of the public values.
+        pub struct PublicValuesHasher(Sha256);
+
+        impl PublicValuesHasher {
+            pub const ID: u8 = 0;
+
+            pub fn new() -> Self {
+                Self(Sha256::new())
+            }
+
+            pub fn update(&mut self, data: &[u8]) {
+                Digest::update(&mut self.0, data);
+            }
+
+            pub fn finalize(self) -> [u8; 32] {
+                self.0.finalize().into()
+            }
+        }
+    }
+}
+
+/// The id of the hash of the public values, read by the prover at setup.
+///
+/// The section is allocated, so it is loaded with the program and the verifying key commits to it.
+/// Nothing else references it, so `__start` reads it to keep the linker from discarding it.
+#[used]
+#[link_section = ".sp1.public_values_hash"]
+pub static PUBLIC_VALUES_HASH_ID: u8 = PublicValuesHasher::ID;
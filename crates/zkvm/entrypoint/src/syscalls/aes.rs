@@ -0,0 +1,29 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// AES block encryption operation.
+///
+/// Encrypts `block` with the expanded key at `key_schedule`, laid out as `[rounds, round_keys..]`
+/// with 10 rounds for AES-128 and 14 rounds for AES-256, and writes the ciphertext to `block`.
+///
+/// ### Safety
+///
+/// The caller must ensure that `block` and `key_schedule` are valid pointers to data that is
+/// aligned along a four byte boundary, that `key_schedule` holds `rounds + 1` round keys, and that
+/// the two buffers do not overlap.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_aes_encrypt_block(block: *mut [u32; 4], key_schedule: *const u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::AES_ENCRYPT_BLOCK,
+            in("a0") block,
+            in("a1") key_schedule,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
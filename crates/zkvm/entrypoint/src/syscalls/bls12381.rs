@@ -79,3 +79,82 @@ pub extern "C" fn syscall_bls12381_decompress(point: &mut [u8; 96], sign_bit: bo
     #[cfg(not(target_os = "zkvm"))]
     unreachable!()
 }
+
+/// Adds two points of the BLS12-381 G2 curve.
+///
+/// The points are in affine coordinates `(x.c0, x.c1, y.c0, y.c1)`, each coordinate being 12
+/// little endian words. The result is stored in the first point. The points must be distinct, not
+/// opposite, and not the point at infinity.
+///
+/// ### Safety
+///
+/// The caller must ensure that `p` and `q` are valid pointers to data that is aligned along a four
+/// byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bls12381_g2_add(p: *mut [u32; 48], q: *const [u32; 48]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BLS12381_G2_ADD,
+            in("a0") p,
+            in("a1") q,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Doubles a point of the BLS12-381 G2 curve.
+///
+/// The result is stored in the point, which must not be the point at infinity.
+///
+/// ### Safety
+///
+/// The caller must ensure that `p` is valid pointer to data that is aligned along a four byte
+/// boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bls12381_g2_double(p: *mut [u32; 48]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BLS12381_G2_DOUBLE,
+            in("a0") p,
+            in("a1") 0,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Maps a field element to a point of the curve isogenous to BLS12-381 with the simplified SWU
+/// map of RFC 9380.
+///
+/// The first half of `p` holds the reduced field element in little endian words, and `p` is
+/// overwritten with the affine point. The exceptional inputs of the map are not supported.
+///
+/// ### Safety
+///
+/// The caller must ensure that `p` is valid pointer to data that is aligned along a four byte
+/// boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bls12381_map_to_curve(p: *mut [u32; 24]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BLS12381_MAP_TO_CURVE,
+            in("a0") p,
+            in("a1") 0,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
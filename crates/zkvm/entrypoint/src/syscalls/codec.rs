@@ -0,0 +1,146 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Encodes 4 bytes to 8 lowercase hexadecimal digits.
+///
+/// ### Safety
+///
+/// The caller must ensure that `output` and `input` are valid pointers to data that is aligned
+/// along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_hex_encode(output: *mut [u32; 2], input: *const [u32; 1]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::HEX_ENCODE,
+            in("a0") output,
+            in("a1") input,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Decodes 8 hexadecimal digits, in either case, to 4 bytes.
+///
+/// Halts the program if `input` holds a character that is not a hexadecimal digit.
+///
+/// ### Safety
+///
+/// The caller must ensure that `output` and `input` are valid pointers to data that is aligned
+/// along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_hex_decode(output: *mut [u32; 1], input: *const [u32; 2]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::HEX_DECODE,
+            in("a0") output,
+            in("a1") input,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Encodes 12 bytes to 16 characters of the standard base64 alphabet.
+///
+/// ### Safety
+///
+/// The caller must ensure that `output` and `input` are valid pointers to data that is aligned
+/// along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_base64_encode(output: *mut [u32; 4], input: *const [u32; 3]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BASE64_ENCODE,
+            in("a0") output,
+            in("a1") input,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Encodes 12 bytes to 16 characters of the URL-safe base64 alphabet.
+///
+/// ### Safety
+///
+/// The caller must ensure that `output` and `input` are valid pointers to data that is aligned
+/// along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_base64_url_encode(output: *mut [u32; 4], input: *const [u32; 3]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BASE64_URL_ENCODE,
+            in("a0") output,
+            in("a1") input,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Decodes 16 characters of the standard base64 alphabet to 12 bytes.
+///
+/// Halts the program if `input` holds a character outside of the alphabet, including padding.
+///
+/// ### Safety
+///
+/// The caller must ensure that `output` and `input` are valid pointers to data that is aligned
+/// along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_base64_decode(output: *mut [u32; 3], input: *const [u32; 4]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BASE64_DECODE,
+            in("a0") output,
+            in("a1") input,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Decodes 16 characters of the URL-safe base64 alphabet to 12 bytes.
+///
+/// Halts the program if `input` holds a character outside of the alphabet, including padding.
+///
+/// ### Safety
+///
+/// The caller must ensure that `output` and `input` are valid pointers to data that is aligned
+/// along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_base64_url_decode(output: *mut [u32; 3], input: *const [u32; 4]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BASE64_URL_DECODE,
+            in("a0") output,
+            in("a1") input,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
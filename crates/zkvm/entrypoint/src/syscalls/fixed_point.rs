@@ -0,0 +1,108 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Fixed-point reciprocal, writing `2^29 / x` rounded down, or 0 for `x = 0`.
+///
+/// The input `x` is read from the low 16 bits of the word at `ptr`, and the result is written over
+/// the word.
+///
+/// ### Safety
+///
+/// The caller must ensure that `ptr` is a valid pointer to a word aligned along a four byte
+/// boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_fixed_point_recip(ptr: *mut u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::FIXED_POINT_RECIP,
+            in("a0") ptr,
+            in("a1") 0,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Fixed-point square root, writing `sqrt(x)` in 16.16 fixed point, rounded down.
+///
+/// The input `x` is read from the low 16 bits of the word at `ptr`, and the result is written over
+/// the word.
+///
+/// ### Safety
+///
+/// The caller must ensure that `ptr` is a valid pointer to a word aligned along a four byte
+/// boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_fixed_point_sqrt(ptr: *mut u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::FIXED_POINT_SQRT,
+            in("a0") ptr,
+            in("a1") 0,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Fixed-point sine of the angle `x * 2π / 2^16`, writing it in signed 2.29 fixed point offset by
+/// `2^29`.
+///
+/// The input `x` is read from the low 16 bits of the word at `ptr`, and the result is written over
+/// the word.
+///
+/// ### Safety
+///
+/// The caller must ensure that `ptr` is a valid pointer to a word aligned along a four byte
+/// boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_fixed_point_sin(ptr: *mut u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::FIXED_POINT_SIN,
+            in("a0") ptr,
+            in("a1") 0,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Fixed-point cosine of the angle `x * 2π / 2^16`, writing it in signed 2.29 fixed point offset by
+/// `2^29`.
+///
+/// The input `x` is read from the low 16 bits of the word at `ptr`, and the result is written over
+/// the word.
+///
+/// ### Safety
+///
+/// The caller must ensure that `ptr` is a valid pointer to a word aligned along a four byte
+/// boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_fixed_point_cos(ptr: *mut u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::FIXED_POINT_COS,
+            in("a0") ptr,
+            in("a1") 0,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
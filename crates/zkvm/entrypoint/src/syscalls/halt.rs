@@ -15,11 +15,35 @@ cfg_if::cfg_if! {
 
 /// Halts the program with the given exit code.
 ///
-/// Before halting, the syscall will commit to the public values.
+/// Before halting, the syscall runs the hooks registered with `on_exit` and commits to the public
+/// values.
 #[allow(unused_variables)]
 pub extern "C" fn syscall_halt(exit_code: u8) -> ! {
     #[cfg(target_os = "zkvm")]
     unsafe {
+        // Run the hooks registered with `on_exit`. Each hook is removed before it runs, so that a
+        // hook that panics halts again with the remaining hooks only.
+        while let Some(hook) = (*core::ptr::addr_of_mut!(zkvm::EXIT_HOOKS)).pop() {
+            hook();
+        }
+
+        crate::heap::report_heap_stats();
+
+        // Write the values committed by name after the other public values, and before the digest
+        // of the inputs so that it stays the last 32 bytes.
+        let named = core::mem::take(&mut *core::ptr::addr_of_mut!(zkvm::NAMED_PUBLIC_VALUES));
+        if !named.is_empty() {
+            let section = crate::syscalls::encode_named_public_values(&named);
+            crate::syscalls::syscall_write(3, section.as_ptr(), section.len());
+        }
+
+        // If the program commits to the digest of its inputs, write it to the public values
+        // before they are finalized.
+        if let Some(hasher) = core::mem::take(&mut *core::ptr::addr_of_mut!(zkvm::INPUT_HASHER)) {
+            let input_digest = hasher.finalize();
+            crate::syscalls::syscall_write(3, input_digest.as_ptr(), input_digest.len());
+        }
+
         // When we halt, we retrieve the public values finalized digest.  This is the hash of all
         // the bytes written to the public values fd.
         let pv_digest_bytes =
@@ -0,0 +1,22 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Reports the heap usage of the program to the host, which includes it in the execution report.
+///
+/// `stats` holds the peak heap size in bytes, the total number of bytes allocated and the number of
+/// allocations.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_heap_stats(stats: *const [u32; 3]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::HEAP_STATS,
+            in("a0") stats,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -1,11 +1,18 @@
 cfg_if::cfg_if! {
     if #[cfg(target_os = "zkvm")] {
+        use alloc::{collections::BTreeMap, vec::Vec};
         use core::arch::asm;
         use crate::zkvm;
-        use sha2::digest::Update;
     }
 }
 
+/// The bytes that end the section of the public values holding the values committed by name.
+pub const NAMED_PUBLIC_VALUES_MAGIC: [u8; 8] = *b"SP1NAMED";
+
+/// The prefix of the keys under which the numbered output streams are committed by name, followed
+/// by the id of the stream in decimal.
+pub const OUTPUT_STREAM_KEY_PREFIX: &str = "sp1.stream.";
+
 /// Write `nbytes` of data to the prover to a given file descriptor `fd` from `write_buf`.
 #[allow(unused_variables)]
 #[no_mangle]
@@ -56,9 +63,38 @@ pub extern "C" fn syscall_hint_len() -> usize {
 }
 
 /// Reads the next element in the hint stream into the given buffer.
+///
+/// If the program commits to the digest of its inputs, the element is added to the digest. If it
+/// declares a maximum input size, the program halts with exit code 1 instead of reading past it.
 #[allow(unused_variables)]
 #[no_mangle]
 pub extern "C" fn syscall_hint_read(ptr: *mut u8, len: usize) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        if let Some(max) = zkvm::MAX_STDIN_SIZE {
+            zkvm::STDIN_READ += len as u64;
+            if zkvm::STDIN_READ > max {
+                crate::syscalls::syscall_halt(1);
+            }
+        }
+    }
+
+    syscall_hint_read_untracked(ptr, len);
+
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        if let Some(hasher) = zkvm::INPUT_HASHER.as_mut() {
+            hasher.update(&(len as u64).to_le_bytes());
+            hasher.update(core::slice::from_raw_parts(ptr, len));
+        }
+    }
+}
+
+/// Reads the next element in the hint stream into the given buffer, leaving it out of the digest
+/// of the inputs.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_hint_read_untracked(ptr: *mut u8, len: usize) {
     #[cfg(target_os = "zkvm")]
     unsafe {
         asm!(
@@ -72,3 +108,78 @@ pub extern "C" fn syscall_hint_read(ptr: *mut u8, len: usize) {
     #[cfg(not(target_os = "zkvm"))]
     unreachable!()
 }
+
+/// Commits the `value_len` bytes at `value_ptr` to the public values under the `key_len` bytes of
+/// the key at `key_ptr`.
+///
+/// The values are buffered and written, sorted by key, when the program halts. Committing two
+/// values under the same key panics.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_commit_named(
+    key_ptr: *const u8,
+    key_len: usize,
+    value_ptr: *const u8,
+    value_len: usize,
+) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        let key = core::slice::from_raw_parts(key_ptr, key_len).to_vec();
+        let value = core::slice::from_raw_parts(value_ptr, value_len).to_vec();
+        assert!(
+            !key.starts_with(OUTPUT_STREAM_KEY_PREFIX.as_bytes()),
+            "the keys starting with `{OUTPUT_STREAM_KEY_PREFIX}` are reserved for output streams"
+        );
+        let named = &mut *core::ptr::addr_of_mut!(zkvm::NAMED_PUBLIC_VALUES);
+        assert!(
+            named.insert(key, value).is_none(),
+            "a public value is already committed under this key"
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Writes the `len` bytes at `ptr` as the next entry of the numbered output stream `stream_id`.
+///
+/// The entries of a stream are committed by name, under [OUTPUT_STREAM_KEY_PREFIX] followed by the
+/// id of the stream, as the concatenation of each entry prefixed by its length as 4 little-endian
+/// bytes.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_write_stream(stream_id: u32, ptr: *const u8, len: usize) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        let key = alloc::format!("{OUTPUT_STREAM_KEY_PREFIX}{stream_id}").into_bytes();
+        let named = &mut *core::ptr::addr_of_mut!(zkvm::NAMED_PUBLIC_VALUES);
+        let stream = named.entry(key).or_default();
+        stream.extend_from_slice(&(len as u32).to_le_bytes());
+        stream.extend_from_slice(core::slice::from_raw_parts(ptr, len));
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Encodes the values committed by name as the last section of the public values.
+///
+/// Each entry, in increasing order of the keys, is the length of its key as 4 little-endian
+/// bytes, the key, the length of its value as 4 little-endian bytes and the value. The entries are
+/// followed by their number and their total length in bytes, both as 4 little-endian bytes, and by
+/// [NAMED_PUBLIC_VALUES_MAGIC].
+#[cfg(target_os = "zkvm")]
+pub(crate) fn encode_named_public_values(named: &BTreeMap<Vec<u8>, Vec<u8>>) -> Vec<u8> {
+    let mut section = Vec::new();
+    for (key, value) in named {
+        section.extend_from_slice(&(key.len() as u32).to_le_bytes());
+        section.extend_from_slice(key);
+        section.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        section.extend_from_slice(value);
+    }
+    let len = section.len() as u32;
+    section.extend_from_slice(&(named.len() as u32).to_le_bytes());
+    section.extend_from_slice(&len.to_le_bytes());
+    section.extend_from_slice(&NAMED_PUBLIC_VALUES_MAGIC);
+    section
+}
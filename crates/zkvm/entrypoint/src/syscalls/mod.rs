@@ -1,12 +1,21 @@
+mod aes;
+mod babyjubjub;
 mod bigint;
 mod bls12381;
 mod bn254;
+mod clmul;
+mod codec;
 mod ed25519;
+mod fixed_point;
 mod fptower;
 mod halt;
+mod heap_stats;
 mod io;
 mod keccak_permute;
 mod memory;
+mod poseidon2_merkle;
+mod range_check;
+mod registered_curve;
 mod secp256k1;
 mod sha_compress;
 mod sha_extend;
@@ -15,16 +24,28 @@ mod uint256_mul;
 mod unconstrained;
 #[cfg(feature = "verify")]
 mod verify;
+mod wide_alu;
+mod x25519;
+mod yield_to_host;
 
+pub use aes::*;
+pub use babyjubjub::*;
 pub use bigint::*;
 pub use bls12381::*;
 pub use bn254::*;
+pub use clmul::*;
+pub use codec::*;
 pub use ed25519::*;
+pub use fixed_point::*;
 pub use fptower::*;
 pub use halt::*;
+pub use heap_stats::*;
 pub use io::*;
 pub use keccak_permute::*;
 pub use memory::*;
+pub use poseidon2_merkle::*;
+pub use range_check::*;
+pub use registered_curve::*;
 pub use secp256k1::*;
 pub use sha_compress::*;
 pub use sha_extend::*;
@@ -33,6 +54,9 @@ pub use uint256_mul::*;
 pub use unconstrained::*;
 #[cfg(feature = "verify")]
 pub use verify::*;
+pub use wide_alu::*;
+pub use x25519::*;
+pub use yield_to_host::*;
 
 /// These codes MUST match the codes in `core/src/runtime/syscall.rs`. There is a derived test
 /// that checks that the enum is consistent with the syscalls.
@@ -141,3 +165,87 @@ pub const BN254_FP2_SUB: u32 = 0x00_01_01_2A;
 
 /// Executes the `BN254_FP2_MUL` precompile.
 pub const BN254_FP2_MUL: u32 = 0x00_01_01_2B;
+
+/// Executes the `POSEIDON2_MERKLE_VERIFY` precompile.
+pub const POSEIDON2_MERKLE_VERIFY: u32 = 0x00_01_01_2C;
+
+/// Executes the `CLMUL` precompile.
+pub const CLMUL: u32 = 0x00_01_01_2D;
+
+/// Executes the `X25519_SCALAR_MULT` precompile.
+pub const X25519_SCALAR_MULT: u32 = 0x00_01_01_2E;
+
+/// Executes the `BABYJUBJUB_ADD` precompile.
+pub const BABYJUBJUB_ADD: u32 = 0x00_01_01_2F;
+
+/// Executes the `AES_ENCRYPT_BLOCK` precompile.
+pub const AES_ENCRYPT_BLOCK: u32 = 0x00_01_01_30;
+
+/// Executes the `RANGE_CHECK` precompile.
+pub const RANGE_CHECK: u32 = 0x00_00_01_31;
+
+/// Executes the `REGISTERED_CURVE_ADD` precompile.
+pub const REGISTERED_CURVE_ADD: u32 = 0x00_01_01_32;
+
+/// Executes the `REGISTERED_CURVE_DOUBLE` precompile.
+pub const REGISTERED_CURVE_DOUBLE: u32 = 0x00_00_01_33;
+
+/// Yields to the host.
+pub const YIELD: u32 = 0x00_00_00_34;
+
+/// Reports the heap usage of the program to the host.
+pub const HEAP_STATS: u32 = 0x00_00_00_35;
+
+/// Executes the `U64_ADD` precompile.
+pub const U64_ADD: u32 = 0x00_01_01_36;
+
+/// Executes the `U64_SUB` precompile.
+pub const U64_SUB: u32 = 0x00_01_01_37;
+
+/// Executes the `U64_MUL` precompile.
+pub const U64_MUL: u32 = 0x00_01_01_38;
+
+/// Executes the `U64_LTU` precompile.
+pub const U64_LTU: u32 = 0x00_01_01_39;
+
+/// Executes the `FIXED_POINT_RECIP` precompile.
+pub const FIXED_POINT_RECIP: u32 = 0x00_01_01_3A;
+
+/// Executes the `FIXED_POINT_SQRT` precompile.
+pub const FIXED_POINT_SQRT: u32 = 0x00_01_01_3B;
+
+/// Executes the `FIXED_POINT_SIN` precompile.
+pub const FIXED_POINT_SIN: u32 = 0x00_01_01_3C;
+
+/// Executes the `FIXED_POINT_COS` precompile.
+pub const FIXED_POINT_COS: u32 = 0x00_01_01_3D;
+
+/// Executes the `BLS12381_G2_ADD` precompile.
+pub const BLS12381_G2_ADD: u32 = 0x00_01_01_3E;
+
+/// Executes the `BLS12381_G2_DOUBLE` precompile.
+pub const BLS12381_G2_DOUBLE: u32 = 0x00_00_01_3F;
+
+/// Executes the `SECP256K1_MAP_TO_CURVE` precompile.
+pub const SECP256K1_MAP_TO_CURVE: u32 = 0x00_00_01_40;
+
+/// Executes the `BLS12381_MAP_TO_CURVE` precompile.
+pub const BLS12381_MAP_TO_CURVE: u32 = 0x00_00_01_41;
+
+/// Executes the `HEX_ENCODE` precompile.
+pub const HEX_ENCODE: u32 = 0x00_01_01_42;
+
+/// Executes the `HEX_DECODE` precompile.
+pub const HEX_DECODE: u32 = 0x00_01_01_43;
+
+/// Executes the `BASE64_ENCODE` precompile.
+pub const BASE64_ENCODE: u32 = 0x00_01_01_44;
+
+/// Executes the `BASE64_URL_ENCODE` precompile.
+pub const BASE64_URL_ENCODE: u32 = 0x00_01_01_45;
+
+/// Executes the `BASE64_DECODE` precompile.
+pub const BASE64_DECODE: u32 = 0x00_01_01_46;
+
+/// Executes the `BASE64_URL_DECODE` precompile.
+pub const BASE64_URL_DECODE: u32 = 0x00_01_01_47;
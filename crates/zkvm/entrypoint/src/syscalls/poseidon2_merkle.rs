@@ -0,0 +1,30 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Poseidon2 Merkle path verification operation.
+///
+/// Hashes the leaf in `state`, laid out as `[leaf, root]`, up the path at `path`, laid out as
+/// `[index, depth, siblings..]`, and checks that the computed root is the root in `state`. The
+/// execution fails if it is not.
+///
+/// ### Safety
+///
+/// The caller must ensure that `state` and `path` are valid pointers to data that is aligned
+/// along a four byte boundary, that `path` holds `depth` siblings, and that the two buffers do not
+/// overlap.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_poseidon2_merkle_verify(state: *const [u32; 16], path: *const u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::POSEIDON2_MERKLE_VERIFY,
+            in("a0") state,
+            in("a1") path,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
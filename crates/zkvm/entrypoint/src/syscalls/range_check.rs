@@ -0,0 +1,27 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Range check operation.
+///
+/// Checks that the word at `value` fits in `bits` bits, halting the program otherwise.
+///
+/// ### Safety
+///
+/// The caller must ensure that `value` is a valid pointer to a word aligned along a four byte
+/// boundary, and that `bits` is at most 32.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_range_check(value: *const u32, bits: u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::RANGE_CHECK,
+            in("a0") value,
+            in("a1") bits,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,54 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Adds two points of a registered Weierstrass curve.
+///
+/// The first point is prefixed by the id of the curve, followed by its coordinates, while the second
+/// point only holds its coordinates. The result is stored in the first point.
+///
+/// ### Safety
+///
+/// The caller must ensure that `p` and `q` are valid pointers to data that is aligned along a four
+/// byte boundary, and that `p` is prefixed by the id of a registered curve.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_registered_curve_add(p: *mut [u32; 17], q: *const [u32; 16]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::REGISTERED_CURVE_ADD,
+            in("a0") p,
+            in("a1") q,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Doubles a point of a registered Weierstrass curve.
+///
+/// The point is prefixed by the id of its curve, followed by its coordinates. The result is stored
+/// in the point.
+///
+/// ### Safety
+///
+/// The caller must ensure that `p` is valid pointer to data that is aligned along a four byte
+/// boundary, and that `p` is prefixed by the id of a registered curve.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_registered_curve_double(p: *mut [u32; 17]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::REGISTERED_CURVE_DOUBLE,
+            in("a0") p,
+            in("a1") 0,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,30 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// X25519 scalar multiplication operation.
+///
+/// Multiplies the point with u-coordinate `u` by the scalar `k` with the Montgomery ladder of
+/// RFC 7748, and writes the resulting u-coordinate to `u`. All values are little endian words.
+///
+/// The scalar is not clamped, and its top bit must be clear. The top bit of `u` is not masked.
+///
+/// ### Safety
+///
+/// The caller must ensure that `u` and `k` are valid pointers to data that is aligned along a four
+/// byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_x25519_scalar_mult(u: *mut [u32; 8], k: *const [u32; 8]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::X25519_SCALAR_MULT,
+            in("a0") u,
+            in("a1") k,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,20 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Yields control to the host, which may read the public values committed so far and write more
+/// input before the program is resumed.
+///
+/// Unless the host executes the program interactively, this is a no-op.
+#[no_mangle]
+pub extern "C" fn syscall_yield() {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::YIELD,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,168 @@
+use crate::{bn254_fr, poseidon_bn254::poseidon_bn254, syscall_babyjubjub_add, utils::AffinePoint};
+
+/// The number of limbs in [BabyJubjubAffinePoint].
+pub const N: usize = 16;
+
+/// An affine point on the Baby Jubjub curve in the reduced form `-x^2 + y^2 = 1 + d' x^2 y^2`.
+///
+/// Points in the EIP-2494 form used by circom are converted with
+/// [BabyJubjubAffinePoint::from_circom] and [BabyJubjubAffinePoint::to_circom].
+#[derive(Copy, Clone)]
+#[repr(align(4))]
+pub struct BabyJubjubAffinePoint(pub [u32; N]);
+
+impl AffinePoint<N> for BabyJubjubAffinePoint {
+    /// `Base8` of circomlib in the reduced form.
+    const GENERATOR: [u32; N] = [
+        4054499426, 1374215224, 2462502425, 4038554541, 3219615504, 771853841, 1951400692,
+        453136111, 2267905419, 1262232954, 3118543735, 4242539807, 3513514477, 626470428,
+        4154503753, 628716035,
+    ];
+
+    fn new(limbs: [u32; N]) -> Self {
+        Self(limbs)
+    }
+
+    fn limbs_ref(&self) -> &[u32; N] {
+        &self.0
+    }
+
+    fn limbs_mut(&mut self) -> &mut [u32; N] {
+        &mut self.0
+    }
+
+    fn add_assign(&mut self, other: &Self) {
+        let a = self.limbs_mut();
+        let b = other.limbs_ref();
+        unsafe {
+            syscall_babyjubjub_add(a, b);
+        }
+    }
+
+    /// In Edwards curves, doubling is the same as adding a point to itself.
+    fn double(&mut self) {
+        let a = self.limbs_mut();
+        unsafe {
+            syscall_babyjubjub_add(a, a);
+        }
+    }
+}
+
+/// The order `l` of the prime subgroup of Baby Jubjub.
+const SUBGROUP_ORDER: [u32; 8] =
+    [958473969, 1735563228, 958459402, 2873028024, 3492817675, 923404470, 1546007557, 101485006];
+
+/// The square root of `-168700` that maps the EIP-2494 form to the reduced form.
+const SQRT_NEG_A: [u32; 8] =
+    [3635599208, 1820058305, 840868119, 1957033674, 2229670263, 746242254, 97969483, 235926352];
+
+/// The inverse of [SQRT_NEG_A].
+const SQRT_NEG_A_INV: [u32; 8] =
+    [267815615, 569743552, 229069303, 1711555046, 3158793753, 1492801180, 1063799618, 740960665];
+
+/// The coefficient `a` of the EIP-2494 form.
+const A: [u32; 8] = [168700, 0, 0, 0, 0, 0, 0, 0];
+
+/// The coefficient `d` of the EIP-2494 form.
+const D: [u32; 8] = [168696, 0, 0, 0, 0, 0, 0, 0];
+
+impl BabyJubjubAffinePoint {
+    const IDENTITY: [u32; N] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0];
+
+    pub fn identity() -> Self {
+        Self(Self::IDENTITY)
+    }
+
+    /// Maps a point in the EIP-2494 form used by circom to the reduced form.
+    ///
+    /// Returns `None` if a coordinate is not reduced or the point is not on the curve.
+    pub fn from_circom(x: &[u32; 8], y: &[u32; 8]) -> Option<Self> {
+        if !is_on_eip2494_curve(x, y) {
+            return None;
+        }
+        let mut limbs = [0u32; N];
+        limbs[..8].copy_from_slice(&bn254_fr::mul(x, &SQRT_NEG_A));
+        limbs[8..].copy_from_slice(y);
+        Some(Self(limbs))
+    }
+
+    /// Maps the point to the EIP-2494 form used by circom.
+    pub fn to_circom(&self) -> ([u32; 8], [u32; 8]) {
+        let x: [u32; 8] = self.0[..8].try_into().unwrap();
+        let y: [u32; 8] = self.0[8..].try_into().unwrap();
+        (bn254_fr::mul(&x, &SQRT_NEG_A_INV), y)
+    }
+
+    /// Multiplies the point by `scalar`, which may be zero.
+    fn mul(&self, scalar: &[u32; 8]) -> Self {
+        let mut result = *self;
+        match result.mul_assign(scalar) {
+            Ok(()) => result,
+            Err(_) => Self::identity(),
+        }
+    }
+}
+
+/// Whether `(x, y)` is a point of the EIP-2494 form `168700 x^2 + y^2 = 1 + 168696 x^2 y^2`.
+fn is_on_eip2494_curve(x: &[u32; 8], y: &[u32; 8]) -> bool {
+    if !bn254_fr::is_canonical(x) || !bn254_fr::is_canonical(y) {
+        return false;
+    }
+    let xx = bn254_fr::mul(x, x);
+    let yy = bn254_fr::mul(y, y);
+    let lhs = bn254_fr::add(&bn254_fr::mul(&A, &xx), &yy);
+    let one = [1, 0, 0, 0, 0, 0, 0, 0];
+    let rhs = bn254_fr::add(&one, &bn254_fr::mul(&D, &bn254_fr::mul(&xx, &yy)));
+    lhs == rhs
+}
+
+/// Whether `a < b` for little-endian words.
+fn less_than(a: &[u32; 8], b: &[u32; 8]) -> bool {
+    for (x, y) in a.iter().zip(b.iter()).rev() {
+        if x != y {
+            return x < y;
+        }
+    }
+    false
+}
+
+/// Verifies an EdDSA-Poseidon signature like `EdDSAPoseidonVerifier` of circomlib.
+///
+/// The public key `(Ax, Ay)`, the message and the signature `(R8x, R8y, S)` are elements of the
+/// scalar field of BN254 in little-endian words, with points in the EIP-2494 form used by circom.
+/// The signature is valid if `S < l` and
+/// `S * Base8 == R8 + Poseidon(R8x, R8y, Ax, Ay, msg) * 8 * A`.
+///
+/// ### Examples
+/// ```ignore
+/// let valid = sp1_lib::babyjubjub::verify_eddsa_poseidon(&public_key, &msg, &r8, &s);
+/// ```
+pub fn verify_eddsa_poseidon(
+    public_key: &([u32; 8], [u32; 8]),
+    msg: &[u32; 8],
+    r8: &([u32; 8], [u32; 8]),
+    s: &[u32; 8],
+) -> bool {
+    if !less_than(s, &SUBGROUP_ORDER) || !bn254_fr::is_canonical(msg) {
+        return false;
+    }
+    let (Some(a), Some(r8_point)) = (
+        BabyJubjubAffinePoint::from_circom(&public_key.0, &public_key.1),
+        BabyJubjubAffinePoint::from_circom(&r8.0, &r8.1),
+    ) else {
+        return false;
+    };
+
+    let hm = poseidon_bn254(&[r8.0, r8.1, public_key.0, public_key.1, *msg]);
+
+    let mut a8 = a;
+    a8.double();
+    a8.double();
+    a8.double();
+
+    let generator = BabyJubjubAffinePoint(BabyJubjubAffinePoint::GENERATOR);
+    let left = generator.mul(s);
+    let mut right = a8.mul(&hm);
+    right.add_assign(&r8_point);
+    left.0 == right.0
+}
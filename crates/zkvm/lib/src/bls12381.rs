@@ -1,7 +1,9 @@
 use std::io::ErrorKind;
 
 use crate::{
-    syscall_bls12381_add, syscall_bls12381_decompress, syscall_bls12381_double, utils::AffinePoint,
+    syscall_bls12381_add, syscall_bls12381_decompress, syscall_bls12381_double,
+    syscall_bls12381_g2_add, syscall_bls12381_g2_double, syscall_bls12381_map_to_curve,
+    utils::AffinePoint,
 };
 
 /// The number of limbs in [Bls12381AffinePoint].
@@ -51,6 +53,58 @@ impl AffinePoint<N> for Bls12381AffinePoint {
     }
 }
 
+/// The number of limbs in [Bls12381G2AffinePoint].
+pub const G2_N: usize = 48;
+
+/// An affine point on the G2 subgroup of the BLS12-381 curve, defined over Fp2.
+///
+/// The limbs are the coordinates `(x.c0, x.c1, y.c0, y.c1)`, each one in 12 little endian words.
+/// Adding a point to itself or to its opposite is not supported, so aggregating signatures should
+/// only add distinct points. [AffinePoint::mul_assign] expects a scalar of `G2_N / 2` words.
+#[derive(Copy, Clone)]
+#[repr(align(4))]
+pub struct Bls12381G2AffinePoint(pub [u32; G2_N]);
+
+impl AffinePoint<G2_N> for Bls12381G2AffinePoint {
+    /// The standard generator of G2, the same one as in the `bls12_381` crate.
+    const GENERATOR: [u32; G2_N] = [
+        3240213944, 3565180616, 2818948079, 195822374, 2061750647, 3025210212, 4198513410,
+        3336862420, 767889489, 638059815, 4035906193, 38445746, 1560554366, 3853286661, 328490327,
+        860680466, 3699331145, 3050987963, 2569057818, 1500238032, 2284277605, 2108478368,
+        1383178080, 333458272, 146286593, 3784529030, 1001169545, 2453326284, 1365299500,
+        1833081449, 2361250727, 2919078826, 3660461338, 2362035654, 1920822801, 216388903,
+        4032788926, 2863204191, 1558977953, 1060572455, 1462671787, 645173931, 2242339759,
+        3409848446, 734170009, 850186928, 782709964, 101106848,
+    ];
+
+    fn new(limbs: [u32; G2_N]) -> Self {
+        Self(limbs)
+    }
+
+    fn limbs_ref(&self) -> &[u32; G2_N] {
+        &self.0
+    }
+
+    fn limbs_mut(&mut self) -> &mut [u32; G2_N] {
+        &mut self.0
+    }
+
+    fn add_assign(&mut self, other: &Self) {
+        let a = self.limbs_mut();
+        let b = other.limbs_ref();
+        unsafe {
+            syscall_bls12381_g2_add(a, b);
+        }
+    }
+
+    fn double(&mut self) {
+        let a = self.limbs_mut();
+        unsafe {
+            syscall_bls12381_g2_double(a);
+        }
+    }
+}
+
 /// Decompresses a compressed public key using bls12381_decompress precompile.
 pub fn decompress_pubkey(compressed_key: &[u8; 48]) -> Result<[u8; 96], ErrorKind> {
     let mut decompressed_key = [0u8; 96];
@@ -64,3 +118,19 @@ pub fn decompress_pubkey(compressed_key: &[u8; 48]) -> Result<[u8; 96], ErrorKin
 
     Ok(decompressed_key)
 }
+
+/// Maps a field element to a point of the curve `E'` 11-isogenous to BLS12-381 G1, using the
+/// bls12381_map_to_curve precompile.
+///
+/// This is the simplified SWU step of the `BLS12381G1_XMD:SHA-256_SSWU_RO_` suite of RFC 9380. `u`
+/// must be reduced and in little endian words, and the result is the affine point `(x, y)` of `E'`,
+/// which the caller maps to G1 with the 11-isogeny before clearing the cofactor. The exceptional
+/// inputs of the map, such as zero, make the precompile fail.
+pub fn map_to_curve(u: &[u32; N / 2]) -> [u32; N] {
+    let mut point = [0u32; N];
+    point[..N / 2].copy_from_slice(u);
+    unsafe {
+        syscall_bls12381_map_to_curve(&mut point);
+    }
+    point
+}
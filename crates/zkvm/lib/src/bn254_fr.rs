@@ -0,0 +1,60 @@
+//! Arithmetic in the scalar field of BN254, with elements as little-endian words.
+
+use crate::syscall_uint256_mulmod;
+
+/// The modulus `r` of the scalar field of BN254.
+pub(crate) const MODULUS: [u32; 8] = [
+    0xf0000001, 0x43e1f593, 0x79b97091, 0x2833e848, 0x8181585d, 0xb85045b6, 0xe131a029, 0x30644e72,
+];
+
+/// Whether `a` is reduced modulo `r`.
+pub(crate) fn is_canonical(a: &[u32; 8]) -> bool {
+    for (limb, modulus_limb) in a.iter().zip(MODULUS.iter()).rev() {
+        if limb != modulus_limb {
+            return limb < modulus_limb;
+        }
+    }
+    false
+}
+
+/// Computes `a + b mod r` for reduced `a` and `b`.
+pub(crate) fn add(a: &[u32; 8], b: &[u32; 8]) -> [u32; 8] {
+    // The sum of two reduced elements fits in 256 bits since `r < 2^254`.
+    let mut sum = [0u32; 8];
+    let mut carry = 0u64;
+    for ((limb, x), y) in sum.iter_mut().zip(a).zip(b) {
+        let wide = *x as u64 + *y as u64 + carry;
+        *limb = wide as u32;
+        carry = wide >> 32;
+    }
+    if is_canonical(&sum) {
+        return sum;
+    }
+    let mut borrow = 0i64;
+    for (limb, modulus_limb) in sum.iter_mut().zip(MODULUS) {
+        let wide = *limb as i64 - modulus_limb as i64 - borrow;
+        *limb = wide as u32;
+        borrow = (wide < 0) as i64;
+    }
+    sum
+}
+
+/// Computes `a * b mod r` with the `UINT256_MUL` precompile.
+pub(crate) fn mul(a: &[u32; 8], b: &[u32; 8]) -> [u32; 8] {
+    let mut result = *a;
+    // The precompile reads the modulus right after the second operand.
+    let mut b_and_modulus = [0u32; 16];
+    b_and_modulus[..8].copy_from_slice(b);
+    b_and_modulus[8..].copy_from_slice(&MODULUS);
+    unsafe {
+        syscall_uint256_mulmod(&mut result, b_and_modulus.as_ptr() as *const [u32; 8]);
+    }
+    result
+}
+
+/// Computes `a^5 mod r`.
+pub(crate) fn pow5(a: &[u32; 8]) -> [u32; 8] {
+    let a2 = mul(a, a);
+    let a4 = mul(&a2, &a2);
+    mul(&a4, a)
+}
@@ -0,0 +1,18 @@
+use crate::syscall_clmul;
+
+/// Computes the 256-bit carry-less product of two 128-bit values, as `(low, high)` halves.
+///
+/// This is the multiplication of polynomials over GF(2) used by GHASH in AES-GCM, before the
+/// reduction modulo the field polynomial.
+pub fn clmul128(x: u128, y: u128) -> (u128, u128) {
+    let mut buf = [0u32; 8];
+    for i in 0..4 {
+        buf[i] = (x >> (32 * i)) as u32;
+    }
+    let y: [u32; 4] = core::array::from_fn(|i| (y >> (32 * i)) as u32);
+    unsafe {
+        syscall_clmul(&mut buf, &y);
+    }
+    let half = |words: &[u32]| words.iter().rev().fold(0u128, |acc, w| (acc << 32) | *w as u128);
+    (half(&buf[..4]), half(&buf[4..]))
+}
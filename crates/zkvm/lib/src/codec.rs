@@ -0,0 +1,239 @@
+//! Hex and base64 encoding with the `HEX_*` and `BASE64_*` precompiles.
+//!
+//! Full blocks of 4 bytes for hex and 12 bytes for base64 are converted by a single syscall each,
+//! and the tail of the input is converted in software. The text is validated before it is
+//! decoded, since the decoding syscalls halt the program on an invalid character. Patched
+//! versions of the `hex` and `base64` crates call these functions.
+
+use core::fmt;
+
+use crate::{
+    syscall_base64_decode, syscall_base64_encode, syscall_base64_url_decode,
+    syscall_base64_url_encode, syscall_hex_decode, syscall_hex_encode,
+};
+
+/// The number of bytes of a hex block.
+const HEX_BLOCK_BYTES: usize = 4;
+
+/// The number of bytes of a base64 block.
+const BASE64_BLOCK_BYTES: usize = 12;
+
+/// The lowercase hexadecimal digits.
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// The padding character of base64.
+const PAD: u8 = b'=';
+
+/// A base64 alphabet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base64Alphabet {
+    /// The standard alphabet, ending with `+` and `/`.
+    Standard,
+    /// The URL-safe alphabet, ending with `-` and `_`.
+    UrlSafe,
+}
+
+impl Base64Alphabet {
+    /// The characters of the alphabet.
+    fn chars(self) -> &'static [u8; 64] {
+        match self {
+            Self::Standard => b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+            Self::UrlSafe => b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_",
+        }
+    }
+
+    /// The value of a character of the alphabet.
+    fn value(self, char: u8) -> Option<u8> {
+        match char {
+            b'A'..=b'Z' => Some(char - b'A'),
+            b'a'..=b'z' => Some(char - b'a' + 26),
+            b'0'..=b'9' => Some(char - b'0' + 52),
+            b'+' if self == Self::Standard => Some(62),
+            b'/' if self == Self::Standard => Some(63),
+            b'-' if self == Self::UrlSafe => Some(62),
+            b'_' if self == Self::UrlSafe => Some(63),
+            _ => None,
+        }
+    }
+}
+
+/// An error decoding hex or base64 text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The character at the given index is not part of the alphabet, or is a base64 character
+    /// whose unused low bits are not zero.
+    InvalidCharacter { index: usize },
+    /// The length of the text does not match a whole number of bytes.
+    InvalidLength,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCharacter { index } => write!(f, "invalid character at index {}", index),
+            Self::InvalidLength => write!(f, "invalid length"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Converts a block of bytes to little endian words.
+fn to_words<const N: usize>(bytes: &[u8]) -> [u32; N] {
+    core::array::from_fn(|i| u32::from_le_bytes(bytes[4 * i..4 * i + 4].try_into().unwrap()))
+}
+
+/// Appends the little endian bytes of words to `out`.
+fn extend_words(out: &mut Vec<u8>, words: &[u32]) {
+    for word in words {
+        out.extend_from_slice(&word.to_le_bytes());
+    }
+}
+
+/// Returns the value of a hexadecimal digit, in either case.
+fn hex_value(char: u8) -> Option<u8> {
+    match char {
+        b'0'..=b'9' => Some(char - b'0'),
+        b'a'..=b'f' => Some(char - b'a' + 10),
+        b'A'..=b'F' => Some(char - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Encodes bytes to lowercase hexadecimal digits.
+pub fn hex_encode(bytes: &[u8]) -> String {
+    let mut out = Vec::with_capacity(2 * bytes.len());
+    let blocks = bytes.chunks_exact(HEX_BLOCK_BYTES);
+    let tail = blocks.remainder();
+    for block in blocks {
+        let mut text = [0u32; 2];
+        unsafe {
+            syscall_hex_encode(&mut text, &to_words::<1>(block));
+        }
+        extend_words(&mut out, &text);
+    }
+    for byte in tail {
+        out.extend([HEX_DIGITS[(byte >> 4) as usize], HEX_DIGITS[(byte & 0xf) as usize]]);
+    }
+    // SAFETY: the digits are ASCII.
+    unsafe { String::from_utf8_unchecked(out) }
+}
+
+/// Decodes hexadecimal digits, in either case.
+pub fn hex_decode(text: impl AsRef<[u8]>) -> Result<Vec<u8>, DecodeError> {
+    let text = text.as_ref();
+    if text.len() % 2 != 0 {
+        return Err(DecodeError::InvalidLength);
+    }
+    if let Some(index) = text.iter().position(|&c| hex_value(c).is_none()) {
+        return Err(DecodeError::InvalidCharacter { index });
+    }
+
+    let mut out = Vec::with_capacity(text.len() / 2);
+    let blocks = text.chunks_exact(2 * HEX_BLOCK_BYTES);
+    let tail = blocks.remainder();
+    for block in blocks {
+        let mut bytes = [0u32; 1];
+        unsafe {
+            syscall_hex_decode(&mut bytes, &to_words::<2>(block));
+        }
+        extend_words(&mut out, &bytes);
+    }
+    for pair in tail.chunks_exact(2) {
+        out.push((hex_value(pair[0]).unwrap() << 4) | hex_value(pair[1]).unwrap());
+    }
+    Ok(out)
+}
+
+/// Encodes bytes to base64, padding the last quantum with `=` if `pad` is set.
+pub fn base64_encode(bytes: &[u8], alphabet: Base64Alphabet, pad: bool) -> String {
+    let mut out = Vec::with_capacity(bytes.len().div_ceil(3) * 4);
+    let blocks = bytes.chunks_exact(BASE64_BLOCK_BYTES);
+    let tail = blocks.remainder();
+    for block in blocks {
+        let mut text = [0u32; 4];
+        let block = to_words::<3>(block);
+        unsafe {
+            match alphabet {
+                Base64Alphabet::Standard => syscall_base64_encode(&mut text, &block),
+                Base64Alphabet::UrlSafe => syscall_base64_url_encode(&mut text, &block),
+            }
+        }
+        extend_words(&mut out, &text);
+    }
+
+    let chars = alphabet.chars();
+    for quantum in tail.chunks(3) {
+        let x = [quantum[0], *quantum.get(1).unwrap_or(&0), *quantum.get(2).unwrap_or(&0)];
+        let values = [
+            x[0] >> 2,
+            ((x[0] & 3) << 4) | (x[1] >> 4),
+            ((x[1] & 0xf) << 2) | (x[2] >> 6),
+            x[2] & 0x3f,
+        ];
+        let len = quantum.len() + 1;
+        out.extend(values[..len].iter().map(|&v| chars[v as usize]));
+        if pad {
+            out.extend(core::iter::repeat(PAD).take(4 - len));
+        }
+    }
+    // SAFETY: the alphabet and the padding are ASCII.
+    unsafe { String::from_utf8_unchecked(out) }
+}
+
+/// Decodes base64, with or without padding.
+pub fn base64_decode(
+    text: impl AsRef<[u8]>,
+    alphabet: Base64Alphabet,
+) -> Result<Vec<u8>, DecodeError> {
+    let mut text = text.as_ref();
+    if text.last() == Some(&PAD) {
+        if text.len() % 4 != 0 {
+            return Err(DecodeError::InvalidLength);
+        }
+        text = text.strip_suffix(&[PAD, PAD]).or(text.strip_suffix(&[PAD])).unwrap();
+    }
+    if text.len() % 4 == 1 {
+        return Err(DecodeError::InvalidLength);
+    }
+    if let Some(index) = text.iter().position(|&c| alphabet.value(c).is_none()) {
+        return Err(DecodeError::InvalidCharacter { index });
+    }
+
+    let mut out = Vec::with_capacity(text.len() / 4 * 3 + 2);
+    let blocks = text.chunks_exact(BASE64_BLOCK_BYTES / 3 * 4);
+    let tail = blocks.remainder();
+    for block in blocks {
+        let mut bytes = [0u32; 3];
+        let block = to_words::<4>(block);
+        unsafe {
+            match alphabet {
+                Base64Alphabet::Standard => syscall_base64_decode(&mut bytes, &block),
+                Base64Alphabet::UrlSafe => syscall_base64_url_decode(&mut bytes, &block),
+            }
+        }
+        extend_words(&mut out, &bytes);
+    }
+
+    let offset = text.len() - tail.len();
+    for (i, quantum) in tail.chunks(4).enumerate() {
+        let mut v = [0u8; 4];
+        for (v, &c) in v.iter_mut().zip(quantum) {
+            *v = alphabet.value(c).unwrap();
+        }
+        let bytes = [(v[0] << 2) | (v[1] >> 4), (v[1] << 4) | (v[2] >> 2), (v[2] << 6) | v[3]];
+        let len = quantum.len() - 1;
+        // The bits of the last character that do not fit in a byte must be zero, so that every
+        // byte string has a single encoding.
+        let unused_bits = match len {
+            1 => v[1] & 0xf,
+            2 => v[2] & 0x3,
+            _ => 0,
+        };
+        if unused_bits != 0 {
+            return Err(DecodeError::InvalidCharacter { index: offset + 4 * i + len });
+        }
+        out.extend_from_slice(&bytes[..len]);
+    }
+    Ok(out)
+}
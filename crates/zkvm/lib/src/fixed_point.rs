@@ -0,0 +1,45 @@
+//! Fixed-point math on the fixed-point precompile.
+//!
+//! Each function is evaluated on a 16-bit input by a lookup into a preprocessed table of its
+//! values, so a call costs a single row of the fixed-point chip. Proofs of programs calling these
+//! functions must be generated with a machine that includes the tables.
+
+use crate::{
+    syscall_fixed_point_cos, syscall_fixed_point_recip, syscall_fixed_point_sin,
+    syscall_fixed_point_sqrt,
+};
+
+/// The scale of the results of [recip], [sin] and [cos].
+pub const FIXED_POINT_ONE: i32 = 1 << 29;
+
+type Syscall = unsafe extern "C" fn(*mut u32);
+
+fn apply(syscall: Syscall, x: u16) -> u32 {
+    let mut word = x as u32;
+    unsafe {
+        syscall(&mut word);
+    }
+    word
+}
+
+/// Computes `2^29 / x`, rounded down, or 0 if `x` is 0.
+pub fn recip(x: u16) -> u32 {
+    apply(syscall_fixed_point_recip, x)
+}
+
+/// Computes the square root of `x` in 16.16 fixed point, rounded down.
+pub fn sqrt(x: u16) -> u32 {
+    apply(syscall_fixed_point_sqrt, x)
+}
+
+/// Computes the sine of the angle `x * 2π / 2^16`, scaled by [FIXED_POINT_ONE] and rounded to
+/// nearest.
+pub fn sin(x: u16) -> i32 {
+    apply(syscall_fixed_point_sin, x) as i32 - FIXED_POINT_ONE
+}
+
+/// Computes the cosine of the angle `x * 2π / 2^16`, scaled by [FIXED_POINT_ONE] and rounded to
+/// nearest.
+pub fn cos(x: u16) -> i32 {
+    apply(syscall_fixed_point_cos, x) as i32 - FIXED_POINT_ONE
+}
@@ -1,5 +1,8 @@
 #![allow(unused_unsafe)]
-use crate::{syscall_hint_len, syscall_hint_read, syscall_write};
+use crate::{
+    syscall_commit_named, syscall_hint_len, syscall_hint_read, syscall_hint_read_untracked,
+    syscall_write, syscall_write_stream, syscall_yield,
+};
 use serde::{de::DeserializeOwned, Serialize};
 use std::{
     alloc::Layout,
@@ -15,6 +18,32 @@ pub const FD_HINT: u32 = 4;
 /// The file descriptor for the `ecreover` hook.
 pub const FD_ECRECOVER_HOOK: u32 = 5;
 
+/// The file descriptor for requesting a hint from an asynchronous hook.
+pub const FD_HINT_REQUEST: u32 = 6;
+
+/// The file descriptor for awaiting a requested hint.
+pub const FD_HINT_AWAIT: u32 = 7;
+
+/// The file descriptor for looking up a value in the host's memo table.
+pub const FD_MEMO_LOOKUP: u32 = 8;
+
+/// The file descriptor for storing a value in the host's memo table.
+pub const FD_MEMO_STORE: u32 = 9;
+
+/// The file descriptor for reporting a failed `assert_host!` to the host.
+pub const FD_HOST_ASSERT: u32 = 10;
+
+/// The file descriptor for reading from a numbered input stream.
+pub const FD_STREAM_READ: u32 = 11;
+
+/// The handle of the next hint requested with [`hint_request`].
+static mut NEXT_HINT_HANDLE: u32 = 0;
+
+/// A handle to a hint requested from an asynchronous hook, to be passed to [`hint_await`].
+#[must_use = "a requested hint should be awaited"]
+#[derive(Debug)]
+pub struct HintHandle(u32);
+
 /// A writer that writes to a file descriptor inside the zkVM.
 struct SyscallWriter {
     fd: u32,
@@ -42,6 +71,21 @@ impl Write for SyscallWriter {
 /// let data: Vec<u8> = sp1_zkvm::io::read_vec();
 /// ```
 pub fn read_vec() -> Vec<u8> {
+    read_vec_with(syscall_hint_read)
+}
+
+/// Read a buffer hinted by the program itself, leaving it out of the digest of the inputs that
+/// `entrypoint!(main, commit_input_digest)` commits to.
+///
+/// ### Examples
+/// ```ignore
+/// let data: Vec<u8> = sp1_zkvm::io::read_hint_vec();
+/// ```
+pub fn read_hint_vec() -> Vec<u8> {
+    read_vec_with(syscall_hint_read_untracked)
+}
+
+fn read_vec_with(hint_read: unsafe extern "C" fn(*mut u8, usize)) -> Vec<u8> {
     // Round up to the nearest multiple of 4 so that the memory allocated is in whole words
     let len = unsafe { syscall_hint_len() };
     let capacity = (len + 3) / 4 * 4;
@@ -61,7 +105,7 @@ pub fn read_vec() -> Vec<u8> {
     // Read the vec into uninitialized memory. The syscall assumes the memory is uninitialized,
     // which should be true because the allocator does not dealloc, so a new alloc should be fresh.
     unsafe {
-        syscall_hint_read(ptr, len);
+        hint_read(ptr, len);
         vec.set_len(len);
     }
     vec
@@ -86,6 +130,21 @@ pub fn read<T: DeserializeOwned>() -> T {
     bincode::deserialize(&vec).expect("deserialization failed")
 }
 
+/// Read a deserializable object hinted by the program itself with [hint], leaving it out of the
+/// digest of the inputs.
+///
+/// ### Examples
+/// ```ignore
+/// sp1_zkvm::lib::unconstrained! {
+///     sp1_zkvm::io::hint(&42u32);
+/// }
+/// let data: u32 = sp1_zkvm::io::read_hint();
+/// ```
+pub fn read_hint<T: DeserializeOwned>() -> T {
+    let vec = read_hint_vec();
+    bincode::deserialize(&vec).expect("deserialization failed")
+}
+
 /// Commit a serializable object to the public values stream.
 ///
 /// ### Examples
@@ -121,6 +180,114 @@ pub fn commit_slice(buf: &[u8]) {
     my_writer.write_all(buf).unwrap();
 }
 
+/// Commit a serializable object to the public values under `key`, so that the host can read it
+/// with `SP1PublicValues::get` regardless of the order of the commits.
+///
+/// The values committed by name are written, sorted by key, after the other public values when
+/// the program halts. Committing two values under the same key panics.
+///
+/// ### Examples
+/// ```ignore
+/// sp1_zkvm::io::commit_named("state_root", &state_root);
+/// sp1_zkvm::io::commit_named("block_number", &block_number);
+/// ```
+pub fn commit_named<T: Serialize>(key: &str, value: &T) {
+    let value = bincode::serialize(value).expect("serialization failed");
+    unsafe { syscall_commit_named(key.as_ptr(), key.len(), value.as_ptr(), value.len()) }
+}
+
+/// Read a buffer from the numbered input stream `stream_id`, which the host writes with
+/// `SP1Stdin::write_to`. Each stream is read in order, independently of the others.
+///
+/// The stream 0 is the input stream read by [read_vec]. The other streams are left out of the
+/// digest of the inputs that `entrypoint!(main, commit_input_digest)` commits to.
+///
+/// ### Examples
+/// ```ignore
+/// let config: Vec<u8> = sp1_zkvm::io::read_vec_from(1);
+/// ```
+pub fn read_vec_from(stream_id: u32) -> Vec<u8> {
+    if stream_id == 0 {
+        return read_vec();
+    }
+    write(FD_STREAM_READ, &stream_id.to_le_bytes());
+    read_hint_vec()
+}
+
+/// Read a deserializable object from the numbered input stream `stream_id`, see
+/// [read_vec_from].
+///
+/// ### Examples
+/// ```ignore
+/// const CONFIG: u32 = 1;
+/// const WITNESS: u32 = 2;
+///
+/// let config: Config = sp1_zkvm::io::read_from(CONFIG);
+/// for _ in 0..config.num_blocks {
+///     let block: Block = sp1_zkvm::io::read_from(WITNESS);
+/// }
+/// ```
+pub fn read_from<T: DeserializeOwned>(stream_id: u32) -> T {
+    let vec = read_vec_from(stream_id);
+    bincode::deserialize(&vec).expect("deserialization failed")
+}
+
+/// Write bytes as the next entry of the numbered output stream `stream_id`, which the host reads
+/// with `SP1PublicValues::stream`.
+///
+/// The stream 0 is the public values stream written by [commit_slice]. The other streams are
+/// committed by name when the program halts, see [commit_named].
+///
+/// ### Examples
+/// ```ignore
+/// sp1_zkvm::io::write_slice_to(1, &receipt);
+/// ```
+pub fn write_slice_to(stream_id: u32, buf: &[u8]) {
+    if stream_id == 0 {
+        commit_slice(buf);
+    } else {
+        unsafe { syscall_write_stream(stream_id, buf.as_ptr(), buf.len()) }
+    }
+}
+
+/// Write a serializable object as the next entry of the numbered output stream `stream_id`, see
+/// [write_slice_to].
+///
+/// ### Examples
+/// ```ignore
+/// for receipt in receipts {
+///     sp1_zkvm::io::write_to(1, &receipt);
+/// }
+/// ```
+pub fn write_to<T: Serialize>(stream_id: u32, value: &T) {
+    if stream_id == 0 {
+        commit(value);
+    } else {
+        let buf = bincode::serialize(value).expect("serialization failed");
+        write_slice_to(stream_id, &buf);
+    }
+}
+
+/// Yield to the host, which may read the values committed so far and write more input to stdin
+/// before the program resumes.
+///
+/// Unless the host runs the program as an interactive session, this is a no-op.
+///
+/// ### Examples
+/// ```ignore
+/// loop {
+///     let query: u32 = sp1_zkvm::io::read();
+///     if query == 0 {
+///         break;
+///     }
+///     sp1_zkvm::io::commit(&answer(query));
+///     sp1_zkvm::io::yield_to_host();
+/// }
+/// ```
+pub fn yield_to_host() {
+    unsafe { syscall_yield() }
+}
+
 /// Hint a serializable object to the hint stream.
 ///
 /// ### Examples
@@ -156,6 +323,100 @@ pub fn hint_slice(buf: &[u8]) {
     my_reader.write_all(buf).unwrap();
 }
 
+/// Request a hint from the asynchronous hook registered at `fd` on the host, passing it `key`.
+///
+/// The host resolves the hint concurrently while the program keeps executing, so slow lookups can
+/// be requested early and awaited with [`hint_await`] once their result is needed.
+///
+/// ### Examples
+/// ```ignore
+/// let handle = sp1_zkvm::io::hint_request(30, b"account");
+/// // ... other work ...
+/// let account: Vec<u8> = sp1_zkvm::io::hint_await(handle);
+/// ```
+pub fn hint_request(fd: u32, key: &[u8]) -> HintHandle {
+    // SAFETY: The zkVM is single-threaded.
+    let handle = unsafe {
+        let handle = NEXT_HINT_HANDLE;
+        NEXT_HINT_HANDLE = handle.wrapping_add(1);
+        handle
+    };
+    let mut buf = Vec::with_capacity(8 + key.len());
+    buf.extend_from_slice(&handle.to_le_bytes());
+    buf.extend_from_slice(&fd.to_le_bytes());
+    buf.extend_from_slice(key);
+    write(FD_HINT_REQUEST, &buf);
+    HintHandle(handle)
+}
+
+/// Wait for a hint requested with [`hint_request`] and read it.
+///
+/// Like [`read_hint_vec`], the hint is left out of the digest of the inputs.
+pub fn hint_await(handle: HintHandle) -> Vec<u8> {
+    write(FD_HINT_AWAIT, &handle.0.to_le_bytes());
+    read_hint_vec()
+}
+
+/// Look up the value memoized under `key` in the host's memo table, if any.
+///
+/// The value is an untrusted hint, left out of the digest of the inputs: the program must verify
+/// it before using it, and compute it otherwise. Prefer [`memoize`], which does both.
+///
+/// ### Examples
+/// ```ignore
+/// if let Some(root) = sp1_zkvm::io::memo_lookup(b"state_root") {
+///     // ... verify the root ...
+/// }
+/// ```
+pub fn memo_lookup(key: &[u8]) -> Option<Vec<u8>> {
+    write(FD_MEMO_LOOKUP, key);
+    let response = read_hint_vec();
+    match response.split_first() {
+        Some((1, value)) => Some(value.to_vec()),
+        _ => None,
+    }
+}
+
+/// Store `value` under `key` in the host's memo table, for later executions to look up.
+///
+/// The host only makes the value visible to lookups once the execution is done, so a program
+/// never reads the values it stored itself.
+pub fn memo_store(key: &[u8], value: &[u8]) {
+    let mut buf = Vec::with_capacity(4 + key.len() + value.len());
+    buf.extend_from_slice(&(key.len() as u32).to_le_bytes());
+    buf.extend_from_slice(key);
+    buf.extend_from_slice(value);
+    write(FD_MEMO_STORE, &buf);
+}
+
+/// Get the value memoized under `key` if `verify` accepts it, or else compute it with `compute`
+/// and store it for later executions.
+///
+/// This lets repeated executions over overlapping data, like successive light client updates,
+/// replace an expensive computation with a cheaper verification of its memoized result.
+///
+/// ### Examples
+/// ```ignore
+/// let root: [u8; 32] = sp1_zkvm::io::memoize(
+///     &block_hash,
+///     |root| verify_root(&block_hash, root),
+///     || compute_root(&block_hash),
+/// );
+/// ```
+pub fn memoize<T: Serialize + DeserializeOwned>(
+    key: &[u8],
+    verify: impl FnOnce(&T) -> bool,
+    compute: impl FnOnce() -> T,
+) -> T {
+    let memoized = memo_lookup(key).and_then(|bytes| bincode::deserialize::<T>(&bytes).ok());
+    if let Some(value) = memoized.filter(|value| verify(value)) {
+        return value;
+    }
+    let value = compute();
+    memo_store(key, &bincode::serialize(&value).expect("serialization failed"));
+    value
+}
+
 /// Write the data `buf` to the file descriptor `fd`.
 ///
 /// ### Examples
@@ -166,3 +427,10 @@ pub fn hint_slice(buf: &[u8]) {
 pub fn write(fd: u32, buf: &[u8]) {
     SyscallWriter { fd }.write_all(buf).unwrap();
 }
+
+/// Report a failed `assert_host!` at `location` to the host, which fails an execution that is not
+/// proven with the message instead of the exit code of the panic that follows.
+#[doc(hidden)]
+pub fn report_host_assertion(location: &str, message: &str) {
+    write(FD_HOST_ASSERT, format!("{location}: {message}").as_bytes());
+}
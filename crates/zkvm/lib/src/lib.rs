@@ -3,15 +3,30 @@
 //! Documentation for these syscalls can be found in the zkVM entrypoint
 //! `sp1_zkvm::syscalls` module.
 
+pub mod aes;
+pub mod babyjubjub;
 pub mod bls12381;
 pub mod bn254;
+mod bn254_fr;
+pub mod clmul;
+pub mod codec;
 pub mod ed25519;
+pub mod fixed_point;
 pub mod io;
+pub mod poseidon2;
+pub mod poseidon_bn254;
+pub mod range_check;
+pub mod registered_curve;
 pub mod secp256k1;
+pub mod sort;
 pub mod unconstrained;
 pub mod utils;
 #[cfg(feature = "verify")]
 pub mod verify;
+pub mod wide_alu;
+pub mod x25519;
+
+pub use sort::{sort_dedup_hinted, sort_hinted};
 
 extern "C" {
     /// Halts the program with the given exit code.
@@ -23,6 +38,9 @@ extern "C" {
     /// Reads the bytes from the given file descriptor into the given buffer.
     pub fn syscall_read(fd: u32, read_buf: *mut u8, nbytes: usize);
 
+    /// Yields control to the host.
+    pub fn syscall_yield();
+
     /// Executes the SHA-256 extend operation on the given word array.
     pub fn syscall_sha256_extend(w: *mut [u32; 64]);
 
@@ -44,6 +62,9 @@ extern "C" {
     /// Executes an Secp256k1 curve decompression on the given point.
     pub fn syscall_secp256k1_decompress(point: &mut [u8; 64], is_odd: bool);
 
+    /// Executes the simplified SWU map to the curve isogenous to Secp256k1 on the given element.
+    pub fn syscall_secp256k1_map_to_curve(p: *mut [u32; 16]);
+
     /// Executes a Bn254 curve addition on the given points.
     pub fn syscall_bn254_add(p: *mut [u32; 16], q: *const [u32; 16]);
 
@@ -56,6 +77,15 @@ extern "C" {
     /// Executes a BLS12-381 curve doubling on the given point.
     pub fn syscall_bls12381_double(p: *mut [u32; 24]);
 
+    /// Executes the simplified SWU map to the curve isogenous to BLS12-381 on the given element.
+    pub fn syscall_bls12381_map_to_curve(p: *mut [u32; 24]);
+
+    /// Executes a BLS12-381 G2 curve addition on the given points.
+    pub fn syscall_bls12381_g2_add(p: *mut [u32; 48], q: *const [u32; 48]);
+
+    /// Executes a BLS12-381 G2 curve doubling on the given point.
+    pub fn syscall_bls12381_g2_double(p: *mut [u32; 48]);
+
     /// Executes the Keccak-256 permutation on the given state.
     pub fn syscall_keccak_permute(state: *mut [u64; 25]);
 
@@ -77,6 +107,21 @@ extern "C" {
     /// Reads the next element in the hint stream into the given buffer.
     pub fn syscall_hint_read(ptr: *mut u8, len: usize);
 
+    /// Reads the next element in the hint stream into the given buffer, leaving it out of the
+    /// digest of the inputs.
+    pub fn syscall_hint_read_untracked(ptr: *mut u8, len: usize);
+
+    /// Commits a value to the public values under a key.
+    pub fn syscall_commit_named(
+        key_ptr: *const u8,
+        key_len: usize,
+        value_ptr: *const u8,
+        value_len: usize,
+    );
+
+    /// Writes an entry to a numbered output stream.
+    pub fn syscall_write_stream(stream_id: u32, ptr: *const u8, len: usize);
+
     /// Allocates a buffer aligned to the given alignment.
     pub fn sys_alloc_aligned(bytes: usize, align: usize) -> *mut u8;
 
@@ -128,4 +173,77 @@ extern "C" {
     /// Executes a BN254 Fp2 multiplication on the given inputs.
     pub fn syscall_bn254_fp2_mulmod(p: *mut u32, q: *const u32);
 
+    /// Hashes a leaf up a Merkle path with Poseidon2 and checks the root against the expected one.
+    pub fn syscall_poseidon2_merkle_verify(state: *const [u32; 16], path: *const u32);
+
+    /// Computes the 256-bit carry-less product of the low half of `x` and `y`, writing it to `x`.
+    pub fn syscall_clmul(x: *mut [u32; 8], y: *const [u32; 4]);
+
+    /// Multiplies the point with u-coordinate `u` by the scalar `k` on curve25519, writing the
+    /// result to `u`.
+    pub fn syscall_x25519_scalar_mult(u: *mut [u32; 8], k: *const [u32; 8]);
+
+    /// Adds two points on Baby Jubjub in the reduced twisted Edwards form, writing the result to
+    /// `p`.
+    pub fn syscall_babyjubjub_add(p: *mut [u32; 16], q: *const [u32; 16]);
+
+    /// Encrypts the block with AES using the key schedule `[rounds, round_keys..]`, writing the
+    /// ciphertext to `block`.
+    pub fn syscall_aes_encrypt_block(block: *mut [u32; 4], key_schedule: *const u32);
+
+    /// Checks that the word at `value` fits in `bits` bits, halting the program otherwise.
+    pub fn syscall_range_check(value: *const u32, bits: u32);
+
+    /// Encodes the 4 bytes at `input` to 8 lowercase hexadecimal digits at `output`.
+    pub fn syscall_hex_encode(output: *mut [u32; 2], input: *const [u32; 1]);
+
+    /// Decodes the 8 hexadecimal digits at `input` to 4 bytes at `output`.
+    pub fn syscall_hex_decode(output: *mut [u32; 1], input: *const [u32; 2]);
+
+    /// Encodes the 12 bytes at `input` to 16 standard base64 characters at `output`.
+    pub fn syscall_base64_encode(output: *mut [u32; 4], input: *const [u32; 3]);
+
+    /// Encodes the 12 bytes at `input` to 16 URL-safe base64 characters at `output`.
+    pub fn syscall_base64_url_encode(output: *mut [u32; 4], input: *const [u32; 3]);
+
+    /// Decodes the 16 standard base64 characters at `input` to 12 bytes at `output`.
+    pub fn syscall_base64_decode(output: *mut [u32; 3], input: *const [u32; 4]);
+
+    /// Decodes the 16 URL-safe base64 characters at `input` to 12 bytes at `output`.
+    pub fn syscall_base64_url_decode(output: *mut [u32; 3], input: *const [u32; 4]);
+
+    /// Adds the coordinates `q` to the point `p` prefixed by the id of its registered curve,
+    /// writing the result to `p`.
+    pub fn syscall_registered_curve_add(p: *mut [u32; 17], q: *const [u32; 16]);
+
+    /// Doubles the point `p` prefixed by the id of its registered curve, writing the result to `p`.
+    pub fn syscall_registered_curve_double(p: *mut [u32; 17]);
+
+    /// Adds `y` to `x` modulo 2^64, writing the result to `x`.
+    pub fn syscall_u64_add(x: *mut [u32; 2], y: *const [u32; 2]);
+
+    /// Subtracts `y` from `x` modulo 2^64, writing the result to `x`.
+    pub fn syscall_u64_sub(x: *mut [u32; 2], y: *const [u32; 2]);
+
+    /// Multiplies `x` by `y` modulo 2^64, writing the result to `x`.
+    pub fn syscall_u64_mul(x: *mut [u32; 2], y: *const [u32; 2]);
+
+    /// Writes 1 to `x` if `x < y` as unsigned 64-bit values, and 0 otherwise.
+    pub fn syscall_u64_ltu(x: *mut [u32; 2], y: *const [u32; 2]);
+
+    /// Writes `2^29 / x` to `ptr`, where `x` is the low 16 bits of the word at `ptr`.
+    pub fn syscall_fixed_point_recip(ptr: *mut u32);
+
+    /// Writes the square root of `x` in 16.16 fixed point to `ptr`, where `x` is the low 16 bits of
+    /// the word at `ptr`.
+    pub fn syscall_fixed_point_sqrt(ptr: *mut u32);
+
+    /// Writes the sine of the angle `x * 2π / 2^16` in offset 2.29 fixed point to `ptr`, where `x`
+    /// is the low 16 bits of the word at `ptr`.
+    pub fn syscall_fixed_point_sin(ptr: *mut u32);
+
+    /// Writes the cosine of the angle `x * 2π / 2^16` in offset 2.29 fixed point to `ptr`, where
+    /// `x` is the low 16 bits of the word at `ptr`.
+    pub fn syscall_fixed_point_cos(ptr: *mut u32);
+
 }
@@ -0,0 +1,37 @@
+use crate::syscall_poseidon2_merkle_verify;
+
+/// A Poseidon2 digest, as eight canonical BabyBear elements.
+pub type Poseidon2Digest = [u32; 8];
+
+/// The maximum number of levels of a Merkle path.
+pub const MAX_DEPTH: usize = 32;
+
+/// Verifies that `leaf` is at `index` in the Merkle tree with the given `root`, where `siblings`
+/// are ordered from the leaf level upwards, using the Poseidon2 compression function.
+///
+/// The root is compared with the computed root by the precompile, so the execution fails, and no
+/// proof can be generated, if the leaf is not in the tree.
+///
+/// ### Panics
+///
+/// Panics if the path is empty or deeper than [MAX_DEPTH], or if `index` does not fit in the
+/// depth of the path.
+pub fn poseidon2_merkle_verify(
+    leaf: &Poseidon2Digest,
+    index: u32,
+    siblings: &[Poseidon2Digest],
+    root: &Poseidon2Digest,
+) {
+    assert!(!siblings.is_empty() && siblings.len() <= MAX_DEPTH, "invalid merkle path depth");
+    assert!(u64::from(index) >> siblings.len() == 0, "index out of range for path");
+    let mut state = [0; 16];
+    state[..8].copy_from_slice(leaf);
+    state[8..].copy_from_slice(root);
+    let mut path = Vec::with_capacity(2 + 8 * siblings.len());
+    path.push(index);
+    path.push(siblings.len() as u32);
+    path.extend(siblings.iter().flatten());
+    unsafe {
+        syscall_poseidon2_merkle_verify(&state, path.as_ptr());
+    }
+}
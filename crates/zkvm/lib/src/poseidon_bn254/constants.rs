@@ -0,0 +1,1470 @@
+//! The round constants and MDS matrices of the circomlib Poseidon instances, generated with the
+//! Grain LFSR of the reference implementation for `t = 2..=6`, 8 full rounds and the partial
+//! rounds of circomlib. Each constant is a field element in little-endian words.
+
+/// The round constants for `t = 2`, with 56 partial rounds.
+#[rustfmt::skip]
+pub(super) const C2: [[u32; 8]; 128] = [
+    [0xd0abd7a7, 0x6c7dc0db, 0x534cdd1b, 0xa71aa177, 0x294cba38, 0xfe1faaba, 0xc68e9bd4, 0x09c46e9e],
+    [0xa604cb81, 0x3c1d83ff, 0xe405b834, 0xc5142b3a, 0x7f3135cf, 0x2a97ed93, 0x0896eec4, 0x0c035653],
+    [0xcc154a30, 0x317ea977, 0xbd6268bd, 0xa00ea5aa, 0x2bb54cf4, 0x142e5118, 0x35698ad1, 0x1e28a1d9],
+    [0x2b91251f, 0x4cf9e2b1, 0xe008db96, 0x0e5757c3, 0x30e298e4, 0x080965db, 0x1a9d2748, 0x27af2d83],
+    [0x45b74e03, 0x79aaf435, 0xf214cd08, 0x41291462, 0x16ae175a, 0x3a6a3cfe, 0x60fc8f51, 0x1e6f11ce],
+    [0x68d142d3, 0xf7192062, 0x4c3613d8, 0x04462ed1, 0xb681f0be, 0x8541819c, 0x3bbd5e43, 0x2a67384d],
+    [0x5c9f45d0, 0x36408f5d, 0xf0251889, 0xb985e381, 0x2fbfecf0, 0x1609f8e1, 0x56093a61, 0x0b66fdf3],
+    [0xbdb09e21, 0xdaa6852d, 0xc5cebeed, 0x0b26c83c, 0x3c2ade37, 0x830c6109, 0x1e78d470, 0x012ee3ec],
+    [0x445bb1b9, 0x2d108e7b, 0xb099b6bb, 0x6cd1c431, 0x8175e3fd, 0xfd88f67f, 0x6760bfbd, 0x0252ba5f],
+    [0x7ca932f1, 0xef5aeaad, 0x35ff71d6, 0x54391a89, 0xef542963, 0x6c6bec3c, 0xeca5ff67, 0x179474cc],
+    [0xbbed2b91, 0x7e1a2589, 0x264969b3, 0x9c1f974a, 0x503fd4ed, 0x9228ff4a, 0x79a51bfa, 0x2c242613],
+    [0x51801b05, 0x53e66c05, 0x01fc0fc5, 0xc2f63f50, 0xd0695b43, 0xac2f288b, 0x2692e63e, 0x1cc1d7b6],
+    [0xd9c91b56, 0x5d9eff5f, 0x17fbacd0, 0x00784dbf, 0x52979e96, 0xb2ed55f8, 0x1aada98b, 0x25505930],
+    [0x7e59efd9, 0xb11c29ce, 0x970a8193, 0xaea24234, 0xeccd32b3, 0x79e1f5c0, 0xac1cb2e4, 0x28437be3],
+    [0x7f5f2043, 0x338762c3, 0xfb4f78d4, 0x18548da8, 0x53766eb1, 0x1ca4fa6b, 0x2f2e1f71, 0x28216a44],
+    [0x323482c9, 0x131f2377, 0x03094581, 0xeee1efce, 0x056dd03f, 0x1f39f4e7, 0x17fa5adf, 0x2c1f47cd],
+    [0xa621afc9, 0x646b8566, 0x76638a63, 0xd9dafca2, 0x356ceb7d, 0x8632bcc9, 0xb7a5ebc4, 0x07abad02],
+    [0x15f96c3c, 0x37da0c4d, 0x80a69cd1, 0x9429f908, 0xaab51dfe, 0x275b33ff, 0x01ffdf29, 0x02302646],
+    [0x899aa0a9, 0x717e5d66, 0x57ee289e, 0xa8644145, 0x6497ca40, 0xa0f16865, 0x4e51d905, 0x1bc97305],
+    [0x8f0a67fc, 0x2a6b2228, 0xc2d8421f, 0xd249aff5, 0xe86341ed, 0x206c3157, 0x64435008, 0x2e1c22f9],
+    [0x2bba86b8, 0xa70452bc, 0x8e46c9f7, 0x9e8ea159, 0x461bbc50, 0x121c1d5f, 0xf67c5378, 0x1224f38d],
+    [0x86cde20e, 0x69d29891, 0x9dfeda19, 0xd7bfe8cd, 0x9ed0068f, 0x9280b4bd, 0x8ba59e51, 0x02e4e69d],
+    [0x5d98018e, 0x6d47e973, 0x4e653f07, 0x4f19ee36, 0xc04ff3ee, 0x7f5df81f, 0x4aaba013, 0x1f1eccc3],
+    [0x4284f582, 0xeacb8a4d, 0x32cd1819, 0x14244480, 0x39a9a731, 0x74266c30, 0x709a3539, 0x1672ad3d],
+    [0xdf8c8fc7, 0x1d2ed602, 0x284d2499, 0xcda6961f, 0x192b4ae9, 0x56f44af5, 0x2c6e420c, 0x283e3fdc],
+    [0xff394bcc, 0x614fbd69, 0xfdff59d6, 0x683751f8, 0x170fa013, 0xd0db0957, 0x0c550ecf, 0x1c2a3d12],
+    [0x77653fbd, 0x96cb6b81, 0x773ea6f2, 0x143a9a43, 0x23456efe, 0xf7897a73, 0x7aac6172, 0x216f8487],
+    [0x52f94788, 0x11a1f515, 0x61ca59a4, 0xceaa47ea, 0x3e28d12b, 0x64ba7e8e, 0xecf2a757, 0x2c0d272b],
+    [0x89546f43, 0xcb4a6c3d, 0xabe0508f, 0x170a5480, 0x4c454e9f, 0x484ee7a7, 0x865c0e28, 0x16e34299],
+    [0x55488fc5, 0x48cd9397, 0x5802290f, 0x77204776, 0x6fb9cc71, 0x375a232a, 0x99e96f5b, 0x175ceba5],
+    [0x44a1ee32, 0xd8c5ffbb, 0xbc354f54, 0x6aa410bf, 0x58b02806, 0xfead9e17, 0x0dc48c16, 0x0c759444],
+    [0xd919fd8d, 0x92479882, 0x3ccf912c, 0x760e2001, 0x7eb6fd8f, 0xc466db7d, 0x39f21bb5, 0x1a3c29bc],
+    [0xcd22e68f, 0x95c8eeab, 0x074f5a66, 0x0855d349, 0x49b25340, 0xc0986ea0, 0x6f3426e5, 0x0ccfdd90],
+    [0x7dd9e796, 0xe0e699b6, 0xfd065b3c, 0x66a7a8a3, 0xe6c94118, 0x2bdb475c, 0xd9f186f6, 0x14f6bc81],
+    [0x86b97052, 0x88edeb73, 0xc9c495c8, 0xcc099810, 0xb2f6c5aa, 0x9702ca70, 0x89fb3d12, 0x0962b827],
+    [0x6a31a86d, 0xafef0c8f, 0x1ef02575, 0x13284ab0, 0xe25127bc, 0xbf20c79d, 0x074d18b3, 0x1a880af7],
+    [0x7ae9311b, 0x4c3012bb, 0xfc20ff3f, 0x20af2924, 0x211c154b, 0xcd5e77f0, 0x19a6a332, 0x10cba184],
+    [0xf302f10d, 0x756a2849, 0x9cae3406, 0xfa27b731, 0x3a9eaca8, 0xbdc76ba6, 0xa8f89b3e, 0x057e62a9],
+    [0x44280cee, 0xafa0413b, 0xbf65cff5, 0xb961303b, 0x84b4988c, 0xd44adf53, 0xe91dc0ab, 0x287c971d],
+    [0xe306891d, 0x6f7f7960, 0x6d4aba4e, 0x1e562bc4, 0x0cca908f, 0xb3bca9da, 0xaf1687bb, 0x21df3388],
+    [0x0e1682b3, 0x3eff8b56, 0x0b498fd8, 0x789df8f7, 0x4d0934cd, 0x3e25cc97, 0xd25bce70, 0x1be5c887],
+    [0x06a0d5c1, 0x48d59c27, 0xfda5acea, 0xd2cb5d42, 0xcea2cd0d, 0x68117175, 0x76e568fb, 0x268da36f],
+    [0xc26a5ed6, 0xbd06460c, 0x135ebd05, 0xc5d8bb74, 0x5510ecec, 0xc609beaf, 0x1f6eae50, 0x0e17ab09],
+    [0x1f62af40, 0x040f5caa, 0xcf83d270, 0x91ef62d8, 0xb074a430, 0x7aee535a, 0x28ffa0a6, 0x04d727e7],
+    [0x7e39ca6e, 0x2b15417d, 0x0f1bfd86, 0x33702ac1, 0x2bc022ed, 0x81b54976, 0x9c293415, 0x0ddbd7bf],
+    [0x8789654b, 0x8a29c49c, 0xd3af9b58, 0x34f5b0d1, 0x2989c6c2, 0x768162e8, 0x51621752, 0x2790eb33],
+    [0x6142f9e9, 0x84b76420, 0xb8b2fd09, 0x395f3d9a, 0x93d8a570, 0x44719501, 0x1a63b73e, 0x1e457c60],
+    [0x46e091b0, 0xc4c686fc, 0xc43ff91f, 0xfa90ecd0, 0xbbe7135f, 0x638d6ab2, 0x1dca9625, 0x21ae6430],
+    [0xed8d350b, 0x5858534e, 0x432e0955, 0x854be9e3, 0x6f494928, 0x4da29316, 0x8ce3468d, 0x0379f63c],
+    [0x24c35049, 0x8c9f58a3, 0xa46686ac, 0xca0e4921, 0x0809e054, 0x6a744a08, 0x0359d026, 0x002d5642],
+    [0x963515a6, 0x0fc2c5af, 0x9e21f409, 0xda8d6245, 0x32e10bbe, 0x1d68b3cd, 0x965b5d9b, 0x123158e5],
+    [0x9cad9ec1, 0x60c80eb4, 0x52836d4e, 0x0fbb2b6f, 0xf6cbe042, 0x661d14bb, 0x0847a941, 0x0be29fc4],
+    [0x4fdf4c1a, 0x233802f2, 0x9cad5f9a, 0x36db9d85, 0x015a453c, 0x57716142, 0xdec2bb05, 0x1ac96991],
+    [0xbcb0627e, 0x51ca3355, 0x97f18a92, 0x5e12c9fa, 0x61d23b3e, 0x5f4964fc, 0x763dbcc2, 0x1596443f],
+    [0xe3ba3212, 0xd6d049ea, 0x17e715ae, 0xf1857d9f, 0xec3aeae0, 0x6b2861d4, 0x654bdfa7, 0x12e0bcd3],
+    [0x7cf964ba, 0x04e6c76c, 0x37154b19, 0xceabac7f, 0xf9af2a50, 0x9ea73d4a, 0x1bbea82b, 0x0fc92b4f],
+    [0x33872762, 0x9c7e9652, 0x22236f4f, 0xb14f7c77, 0xa8013f40, 0xd6f2e592, 0x10446442, 0x1f9c0b16],
+    [0x3dbbf4d3, 0x8d159f64, 0xa38b4c05, 0x050d914d, 0x57a782f4, 0xf8cde061, 0x4ae72675, 0x0ebd7424],
+    [0xdccfc6d1, 0x7a839839, 0x7346ee39, 0x3b0671e9, 0x4ab951c0, 0x69a9fafd, 0x39e16e9f, 0x2cb7f0ed],
+    [0x7352d9bf, 0x90c72bca, 0x14ce5266, 0xce761d05, 0xe41bab20, 0x5605443e, 0xcff022cc, 0x1a9d6e2e],
+    [0x648ec72f, 0x87da182d, 0xa9a7ba30, 0xd0c13326, 0xc44a9331, 0x5ea83c3b, 0x607f335a, 0x2a115439],
+    [0xc5a4c060, 0x9535c115, 0x05cd44f2, 0xe738b563, 0xee3e3410, 0x15b8fa7a, 0x9b5d040d, 0x23f9b652],
+    [0xf0e6e8a7, 0x260eb939, 0x6d58b68b, 0xa3ce97c1, 0x484bb9c3, 0x249ac6ba, 0xdb0f72a2, 0x05872c16],
+    [0xdeddf6a7, 0x2b624a7c, 0x1d55b5c5, 0x0219b615, 0x118075f4, 0xca20fb80, 0x08bb7824, 0x1300bdee],
+    [0x7d52b376, 0x072e4e7b, 0x16d98cb1, 0x8d7ad299, 0x3a8f6c28, 0xe6381786, 0x2f108e17, 0x19b9b63d],
+    [0x481b4f7f, 0x24a20128, 0x26b5ec42, 0x13d1c887, 0x668522f6, 0xb5bda237, 0x57e3c015, 0x015bee13],
+    [0xb128ffd1, 0xea92c785, 0xbab218cb, 0xfe1e1ce4, 0xf1615e4e, 0x1b9707a4, 0x94bb6b9f, 0x2953736e],
+    [0xd6608dfc, 0x4ce7266e, 0x72b45f54, 0x851b98d3, 0x80c0385f, 0x862f8061, 0xba091618, 0x0b069353],
+    [0x7d81f429, 0x4f588ac9, 0x9306b64e, 0x55aeb7eb, 0xfb93817e, 0x15e4e0bc, 0x61ccc131, 0x304f74d4],
+    [0xcde913fc, 0xb8ee5415, 0xa4617a4c, 0xaad2a164, 0x77dfe4f5, 0xe8a33f5e, 0xce9bca09, 0x15bbf146],
+    [0x9572c8c6, 0xa9ff2385, 0x0405c10c, 0x9b8f4b85, 0x487964ed, 0x44901031, 0xc2742cde, 0x0ab4dfe0],
+    [0x9639779a, 0x251de39f, 0xa546dea9, 0xef5eedfe, 0x49a19675, 0x97f45f76, 0x0a044e31, 0x0e32db32],
+    [0x516da016, 0xa3078efa, 0x82774896, 0x6797733a, 0x8b6888e6, 0xb27635a7, 0x1f378ca4, 0x0a1756aa],
+    [0xa25d93ef, 0x4254d6a2, 0x8f85efa9, 0x95e61d32, 0x7f952ef8, 0x47fd1717, 0xb10f6934, 0x044c4a33],
+    [0x466c4b8b, 0xd37b07b5, 0x9a496891, 0xfe0879d7, 0x7f66f700, 0xbe655b53, 0x725b8a70, 0x2ed3611b],
+    [0x120858aa, 0xd8339ea7, 0xfdd347b5, 0xadfdeb9c, 0x22aa2e0e, 0xc8ecc3d7, 0xbab7ce42, 0x1f9ba4e8],
+    [0x65c5d3da, 0xb74056f8, 0x4502066d, 0xa38e82ac, 0xa84e518a, 0x8f7ee907, 0x052e8c28, 0x1b233043],
+    [0x20875954, 0xca2f97b0, 0xc0f14db0, 0x902053bf, 0x2bd55b4c, 0x74031ab7, 0x164bb8d0, 0x2431e1cc],
+    [0x965801fd, 0xa791f273, 0x97583319, 0xa13e3220, 0xa0a7db45, 0x30cd6953, 0x91f5aac3, 0x082f934c],
+    [0x0c48997c, 0x9ad6bb93, 0xae7cbe99, 0xc77245e2, 0x315542a3, 0xa34be074, 0x3e7538b0, 0x2b9a0a22],
+    [0x70214e7d, 0xb0b589cc, 0x75a8a00e, 0x8164163e, 0xb887a9be, 0xceb85483, 0xdd2cfa2c, 0x0e1cd91e],
+    [0x1cebe2f9, 0x88d32460, 0x854d00f5, 0x99774f19, 0x77e36989, 0xc951f614, 0x2bfdfd63, 0x2e1eac0f],
+    [0x5b500b83, 0x23d74811, 0x8efdb33c, 0x7345784d, 0x769d6d15, 0x0c76158e, 0x37fb7406, 0x0cbfa95f],
+    [0xfa4a4f84, 0x980c232d, 0xa77513d9, 0x76d991e3, 0x8a61e9a6, 0xd65ad49d, 0xe923ed44, 0x08f05b3b],
+    [0x0c047ef6, 0x25a2dd51, 0xdc0758a3, 0xe7284925, 0x984d0443, 0x52bf8e21, 0x070bcd08, 0x22719e2a],
+    [0xf26830c0, 0xf41f62b2, 0x119982c0, 0x7bdbf036, 0xc3a1ab4c, 0xc060f7fc, 0x9ee1cb2b, 0x041f596a],
+    [0x86b10f89, 0x19fcdd09, 0xd0dc464a, 0x021be1c2, 0x6f6b1d4c, 0x87628eb0, 0xe1be520a, 0x233fd35d],
+    [0x61c9c267, 0xefcb453c, 0xa1b4707e, 0xd31e078a, 0x23ebc810, 0x4325e0a4, 0x1aa87a5e, 0x0524b46d],
+    [0x7c515211, 0xcc448623, 0x4b0f3199, 0x4227bb95, 0x894b8582, 0xce47fcac, 0xc81e5716, 0x2c34f424],
+    [0x7de4915e, 0xf3301032, 0x5457cc97, 0x2dd2025b, 0xb5541fb7, 0x207effc2, 0x63387819, 0x0b5f2a4b],
+    [0xe05c659f, 0xaefac41f, 0xf57af6ce, 0xc17435d2, 0x39d2cfd6, 0xc5b72fe4, 0x082ccc54, 0x22207856],
+    [0xce2c8171, 0x27854048, 0x94caf79f, 0xcdfb2101, 0x7f8950b5, 0x4e24159b, 0xf5da63fe, 0x24d57a8b],
+    [0x3b79396e, 0x73919bb2, 0x7bb01a85, 0x374ad709, 0xbd693f98, 0x3b371d75, 0xfdd5e058, 0x0afab181],
+    [0x2b1128ee, 0xf16290d6, 0x94c16c0b, 0x76c00571, 0xac7cbd56, 0x998a52ef, 0x8f208772, 0x2dba9b10],
+    [0xbce420b3, 0x5aff13e6, 0xbd592b25, 0xcbb83de0, 0x88f53f83, 0x56f881c7, 0xedb8b16f, 0x26349b66],
+    [0xe6f137db, 0x235288a3, 0x8ecc193b, 0xd81a56d2, 0x2339753a, 0x685e95f9, 0xe5e10357, 0x25af7ce0],
+    [0xfe35011f, 0x1f7c0187, 0xe88b2bff, 0x70eed7aa, 0x5edd68b9, 0xc094d6a5, 0xd2294390, 0x25b4ce7b],
+    [0x1e02b631, 0x8cb9d54c, 0xebdf30b1, 0xde9cef28, 0x908a88e5, 0x387e53f1, 0x0f6c89ec, 0x22c543f1],
+    [0x882f87a9, 0xdf668e74, 0x919d7a34, 0x425e906a, 0x9f191e1e, 0x4fc7908a, 0x7789c472, 0x0236f93e],
+    [0x980c4b52, 0x9cb497af, 0x14eb0165, 0x652bdae1, 0x7d05da99, 0x0e7d27e3, 0x1166ca01, 0x29350b40],
+    [0x6652363f, 0xee126091, 0x844ebb89, 0x65edb75d, 0xb547f75a, 0x6bd31bba, 0x65820d3f, 0x0eed787d],
+    [0xf4de6fad, 0x1906f656, 0xbd94297d, 0xfdcd0e99, 0x520b3291, 0x036a753f, 0xf13b46f2, 0x07cc1170],
+    [0x89e8acea, 0x20594356, 0xf9f5d10c, 0x908786d7, 0x3a3d30b1, 0xf49bcf61, 0x3b1d7205, 0x22b93923],
+    [0xe60ae5a6, 0xadd650ac, 0x5aa85438, 0x740f083a, 0xbc33e870, 0x8aad1dc8, 0xa0aab81c, 0x01451762],
+    [0x892fce89, 0xe704fec0, 0xdec7da57, 0xe32eaa61, 0x25d46d1f, 0x61fabf10, 0xd8727d44, 0x23506bb5],
+    [0x07355522, 0x7f8bd689, 0x1e1efea9, 0x2a370953, 0xf71bdd09, 0xbac06ae3, 0xe838aea0, 0x2e484c44],
+    [0x6835b54c, 0x45418da2, 0x45cedc25, 0xaf4a5945, 0x0bd2e42b, 0x379e78c5, 0x7ebafd64, 0x0f4bc7d0],
+    [0x50d8e74e, 0xe620996d, 0x725df460, 0x51582388, 0x62faaee8, 0xfa766378, 0x6583e9e5, 0x1f4d3c8f],
+    [0x48fe7389, 0x53eb9bcb, 0x7b681d91, 0xfae02abc, 0xe0e4a988, 0x2660d07b, 0xc70711f8, 0x093514e0],
+    [0x47e153d8, 0x4a58e0a3, 0xe47228f2, 0x43ee83ec, 0x5f3bc036, 0x46699a2b, 0xe2b3bad3, 0x1adab0c8],
+    [0x9e80dad2, 0x1a22dbef, 0xb8072bac, 0x378c1b94, 0xb474641a, 0xd14709eb, 0x6057d99d, 0x1672b172],
+    [0x9b479c14, 0x30d47b23, 0xe0acc4ee, 0xc5d8e2fa, 0xdcab468c, 0x8f44f53f, 0x576af2e3, 0x1dfd53d4],
+    [0x53205c60, 0xbc7f2077, 0x0f6fc3de, 0xe6d77d64, 0x3a37e17f, 0xa70a3626, 0x0b75b0f3, 0x0c6888a1],
+    [0xa9d1e508, 0x85091ecc, 0x0ee6848b, 0x611a61e0, 0x77d12fe8, 0x92b34a7e, 0xa65be770, 0x1addb933],
+    [0x299d1791, 0x7935628e, 0x25f0afff, 0xf638ff54, 0xd1de933c, 0x5c10ae18, 0xcd268a84, 0x00d7540d],
+    [0x20b82c0e, 0xd316939d, 0xacd99db1, 0x26fedde4, 0x5664ca9c, 0x01b2827a, 0x687e9ead, 0x140c0e42],
+    [0x56565984, 0xc091e2ae, 0x24f8c5ed, 0xc20a0f9b, 0xd13d1806, 0x91ba89b8, 0x5d4317d1, 0x2f0c3a11],
+    [0xdc958c61, 0xd8c538a1, 0x70b22b82, 0x08a0cff6, 0x0cf9c810, 0x3006ed22, 0xff7c1455, 0x0c4ee778],
+    [0x5de74c69, 0x27c3d748, 0xc0acc662, 0x9424ed26, 0x40ccc360, 0x3693f004, 0x6d46f82c, 0x1704f276],
+    [0x009c3cfa, 0x39b66fe9, 0x8544e402, 0xf0769c9f, 0x51d244ab, 0xa7a02c1b, 0x3ea5d78e, 0x2f2d19cc],
+    [0x06fc6629, 0xd6c766a8, 0x9cfed9c7, 0xdd7ee6cb, 0xe2a8e8dc, 0x5053f112, 0xb75fcaba, 0x1ae03853],
+    [0xdaf056d5, 0x4e41a86d, 0x2d6f014e, 0x3556921b, 0xfa61aa5f, 0x51d131d0, 0x795241df, 0x0971aabf],
+    [0xbfe2f646, 0x5f5c29f7, 0x80df1c87, 0xda624f83, 0x6e0de73e, 0x91d4cf6b, 0xe6014e1a, 0x1408c316],
+    [0x822ef5bd, 0x41691f39, 0x73ef2853, 0x6c89f1f7, 0xb543093b, 0x248abe42, 0x2edbe850, 0x1667f3fe],
+    [0x6500fe37, 0x424c6957, 0x09e5c133, 0x5b817184, 0x557cdf91, 0xa48b0a03, 0x0d2c4376, 0x13bf7c5d],
+    [0x43a62c2c, 0x19bc0ba7, 0x7856b797, 0x024b9534, 0xd3533c24, 0x3016adf3, 0xfb0b6cec, 0x07620a6d],
+    [0x1982b4d0, 0x1675de3e, 0x2f322b73, 0x75d2959e, 0xbdbdd8b0, 0x36a8ca08, 0x0c43545f, 0x1574c7ef],
+    [0xff8378f0, 0xc06e03a7, 0x71c254fd, 0x5bd41845, 0xa717ceec, 0xfd567970, 0x7a2eb21a, 0x269e4b5b],
+];
+
+/// The MDS matrix for `t = 2`, row by row.
+#[rustfmt::skip]
+pub(super) const M2: [[u32; 8]; 4] = [
+    [0xd2af7ad5, 0xbceca70b, 0xf8c952a7, 0xaf07f38a, 0x51a23a3a, 0xec103453, 0xd6f68a85, 0x066f6f85],
+    [0x8125b1e8, 0x05462b9f, 0xbd8bea73, 0x20a7c02b, 0x9b1d0fdb, 0x7782e150, 0x10c9ae99, 0x2b9d4b41],
+    [0x221f8ff9, 0xf573f431, 0x7013fff1, 0xb6c09d55, 0x93cc262f, 0x2bf67a44, 0xb08507d6, 0x0cc57cdb],
+    [0x943203c8, 0x21bcd147, 0x6eb5c3a1, 0xade857e8, 0x9724e1ad, 0xa31a6ed6, 0xa32ed355, 0x1274e649],
+];
+
+/// The round constants for `t = 3`, with 57 partial rounds.
+#[rustfmt::skip]
+pub(super) const C3: [[u32; 8]; 195] = [
+    [0x04cd8e6e, 0x8d21d473, 0x11bb2993, 0x14c4993c, 0x56f40c21, 0xd05986d6, 0xba9a9518, 0x0ee9a592],
+    [0x0956e864, 0x5696fff4, 0xd00868df, 0x887b08d4, 0x69fc1bcd, 0x59865871, 0x35f2148c, 0x00f14452],
+    [0x0ecf73f5, 0xe879f389, 0x0b7ab36c, 0x30c72873, 0xd0fa80b9, 0x1f29a058, 0x7e8ac99e, 0x08dff348],
+    [0xfadc01d0, 0x20966310, 0xc84bda6e, 0x56c35342, 0x532b13c8, 0xc3ce28f7, 0x0fdaee46, 0x2f27be69],
+    [0xbca16cf2, 0x8b8327be, 0xb8043ee4, 0xb763fe04, 0x3d4f6234, 0x2416bebf, 0xf68b7b8d, 0x2b2ae1ac],
+    [0xdbf11cfa, 0xe64b44c7, 0xab6b03ea, 0x5952c175, 0x6f97d4d5, 0xcca5eac0, 0x072bef7e, 0x0319d062],
+    [0xbf28526d, 0x8ef7b387, 0xad49c629, 0xc8b7bf27, 0x7af4a63b, 0x8a376df8, 0xebaeaa82, 0x28813dca],
+    [0xddf9cb78, 0x150928ad, 0x00c352bc, 0x20338652, 0xe1c1d40d, 0xf181bf38, 0x2ccbc903, 0x2727673b],
+    [0xe65cc632, 0xb8fb9e31, 0x340587d6, 0x6efbd43e, 0x2a1494cd, 0xe74abd2b, 0xa27727c2, 0x234ec45c],
+    [0x8797d428, 0xcd99ff6e, 0x0a337b1c, 0xab10a815, 0xcf7cf760, 0x7f862cb2, 0x031ae18f, 0x15b52534],
+    [0xcf68d1f6, 0xd701d4ee, 0x1b58b132, 0x8e0e8a8d, 0x86b79ce3, 0x5ed9a3d1, 0xd9e4b35f, 0x0dc8fad6],
+    [0xa47e4d9c, 0x97805518, 0xf62e1fec, 0xea4eb378, 0xad3fb567, 0x600f705f, 0xc211fbca, 0x1bcd95ff],
+    [0x069de559, 0x17cb978d, 0x25789378, 0xc76da36c, 0x016fc34d, 0xe9eff81b, 0xb721cadf, 0x10520b0a],
+    [0x1f5627f6, 0xe88a9eb8, 0x75fed0ac, 0x29324980, 0xd5fbbaf4, 0x9b257d8e, 0x9b8e7f7d, 0x1f6d4814],
+    [0x460c8705, 0xca34bdb5, 0x816f0dc9, 0xfff8dc1c, 0x35a2089b, 0xd29e00ef, 0x52309014, 0x1d9655f6],
+    [0x5697cc7d, 0x8fe3d418, 0xe4703205, 0xa731ff67, 0xcd43a99b, 0xb051f7b1, 0xff95bcaf, 0x04df5a56],
+    [0x6e4be828, 0xf6ec282b, 0x8c8424a7, 0x8690a10a, 0x0cedaf14, 0x151b3d29, 0xf8fff640, 0x0672d995],
+    [0x9b5c75b9, 0x9fc1d820, 0x06f2708e, 0x0c9a9dcc, 0xffafdd5f, 0xb21200d7, 0x14884454, 0x099952b4],
+    [0x3a6b9fa6, 0x83fd0e84, 0xa9b4cd91, 0x48e43586, 0xba8d4694, 0x7c483143, 0x55dfd00c, 0x052cba22],
+    [0x3c464ddc, 0x16077cb9, 0x7251ad77, 0x82de5570, 0x2b7999af, 0xb0bd7471, 0x690adb8e, 0x0b8badee],
+    [0xe4b2bdd1, 0xb963d0a8, 0x683a8050, 0x49c15d60, 0x020c07c7, 0x5a1ee651, 0xf13307af, 0x119b1590],
+    [0xfb4a8d09, 0xce15be0b, 0x84ef4ee5, 0x2c4acfc8, 0xe0f67b83, 0x2529d36b, 0xd6d5d17b, 0x03150b7c],
+    [0x7c9ea565, 0xbe69cb31, 0x3d80898a, 0x5374efb8, 0x17391235, 0x3cf1951f, 0x5e14546e, 0x2cc6182c],
+    [0x111bf0f9, 0x92d2cd73, 0xdac14e2b, 0x4218cade, 0xa404b376, 0x50cfe129, 0x1e6378c4, 0x00503255],
+    [0xc28276b5, 0x88f9da2c, 0xfcc069fb, 0x6469c399, 0x2ebcb951, 0xbb147e97, 0x289baa34, 0x233237e3],
+    [0x24d60280, 0xe80c2d4c, 0xb34ae5a4, 0x23037f21, 0x74bfbe63, 0xc980d316, 0xebd4a6e3, 0x05c8f4f4],
+    [0x590fc65b, 0xee1f09b2, 0xf3aeed91, 0x52bcf35e, 0xa319f252, 0xba05d818, 0x3042d396, 0x0a7b1db1],
+    [0x5a404ec0, 0x5df54236, 0x86ff47dc, 0xf156e2b0, 0x2c9d32db, 0xb1429657, 0x9b210cf5, 0x2a73b71f],
+    [0x5c50c460, 0x76a760bb, 0xdbe7f229, 0xec18f2c4, 0xffc91dc3, 0x935107e9, 0x7abcc9a1, 0x1ac9b041],
+    [0x6dc93fc0, 0x9015ee04, 0x6cb10434, 0x269f3e4d, 0x707ef479, 0x3fabb076, 0xe0837482, 0x12c0339a],
+    [0x56e9a28e, 0x8246682e, 0x253baac6, 0x52900aa3, 0x4e1e704f, 0x7f5b18db, 0x02a165ad, 0x0b7475b1],
+    [0x8d7f8448, 0x32ab3aa8, 0x9366f2ea, 0x7c843e37, 0xf6e8b891, 0xdb1c5e49, 0xe191ca3e, 0x037c2849],
+    [0xa716346f, 0x45fdb176, 0x93a07dc1, 0xd5206c5c, 0x1e217e9b, 0xe9267466, 0x8556f014, 0x05a6811f],
+    [0xf38bd66e, 0x7b675ef5, 0x7b2883b4, 0x4076e87a, 0xd54e9f04, 0x6e947b75, 0xd9802894, 0x29a795e7],
+    [0x981fd22f, 0x507be199, 0xc8a1585c, 0x6e8c7382, 0xfc18f582, 0x45a3857a, 0x84b322eb, 0x20439a0c],
+    [0x0982c887, 0x4a2a6f2a, 0x99a84b6d, 0xbb50f277, 0xc7371ff1, 0x94ec2050, 0x4d9ecf4a, 0x2e0ba8d9],
+    [0xa17d71c8, 0xe6d0ddcc, 0x109048d2, 0x17822cd2, 0xce822b45, 0xca38eb7c, 0xce08fb27, 0x143fd115],
+    [0x3be9caf1, 0xc8432362, 0x323dbcbf, 0xf8611659, 0xdcf813cd, 0x57968dbb, 0xb1c734b8, 0x0c64cbec],
+    [0x9403da53, 0xf1426cef, 0x62c2b670, 0xe74f348d, 0xc163ff5a, 0x46fca925, 0x47c683f6, 0x028a3058],
+    [0x5db9e30c, 0x24d6755b, 0xd89427b8, 0x6a6bcb64, 0x4c4380f2, 0x5fa940ab, 0xff0b6fda, 0x2e4ef510],
+    [0x0579400e, 0xb96384f5, 0xd033b078, 0x8925b4f6, 0xc956ce3b, 0x63d79270, 0xc43384e6, 0x0081c95b],
+    [0x23a0bb38, 0xba8a9f40, 0x9c039a0b, 0xe2491b34, 0xe687e05e, 0x187e2fad, 0x1cbd9749, 0x2ed5f0c9],
+    [0x3a735206, 0x990f01f3, 0x76234c8c, 0x3448a22c, 0xd5aae2f0, 0x4bbf374e, 0xf88da350, 0x30509991],
+    [0x424ec6ad, 0xa7529094, 0xb2067b41, 0xf0a1119f, 0x49a356b9, 0x221b7c4d, 0x55409a53, 0x1c3f20fd],
+    [0x7ddcb96c, 0x170887b4, 0x3e8e131e, 0xc46bb221, 0x9b6e18ee, 0x04951445, 0xab5df003, 0x10b4e7f3],
+    [0x2e43adef, 0x039aa350, 0xc077d775, 0xdd80f804, 0x891c2abd, 0x3ddd543d, 0x9c3ff7f4, 0x2a198297],
+    [0x15bd5c91, 0x5cad0f13, 0x396c9af9, 0xba431ebc, 0x56d6d55d, 0xfeddbead, 0xf15e1db6, 0x1c74ee64],
+    [0x0ae146a0, 0x9c2fe45a, 0x82708cfa, 0x9e4f2e8b, 0xace01b4b, 0xeab9303c, 0x50ba7f98, 0x07533ec8],
+    [0x764c0750, 0x8a11abf3, 0x2d42c180, 0x285c68f4, 0xaf17b154, 0xa151e4ee, 0x8e500449, 0x21576b43],
+    [0x836d4a9e, 0x743d6930, 0x815f0906, 0xbce8384c, 0x93d62f10, 0x08ad5ca1, 0x9b8fe796, 0x2f17c055],
+    [0xb7e2730e, 0xe665b0b1, 0x1318474a, 0x9775a420, 0x946170bc, 0xa79e8aae, 0x62d07708, 0x2d477e38],
+    [0xb2747eab, 0xd89be0f5, 0xc38f5abc, 0xafba2266, 0x7984f291, 0x90e09557, 0x967064c3, 0x162f5243],
+    [0x92393311, 0x7777a700, 0x87f29f8a, 0xd7a8596a, 0x8ae50d1a, 0x264ecd2c, 0xede9ba48, 0x2b4cb233],
+    [0x5e03b07a, 0x4254e7c3, 0x6d85c4cf, 0x6db2eece, 0x62285477, 0x1dbaf8f4, 0xdd8573dc, 0x2c8fbcb2],
+    [0x70949da9, 0xe5e88db8, 0xf601e9ad, 0x9e1b61e9, 0x0cd56b19, 0xf2ff453f, 0x25e4816a, 0x1d6f3477],
+    [0xc4565529, 0x4cd49af5, 0xb68d3132, 0xf9e6ac02, 0xdf5b913d, 0xebc2d8b3, 0x7f4ebe71, 0x204b0c39],
+    [0xbc79c502, 0x4ff8fb75, 0xd7dc2553, 0x9ecb827c, 0xc63c3c2f, 0x4f1149b3, 0x3c4fd817, 0x0c4cb9dc],
+    [0x45bc7b54, 0x9a616ddc, 0x5279e063, 0x1e5c4947, 0x4f493030, 0xa2541647, 0x1448c899, 0x174ad61a],
+    [0x9a38d2ef, 0x3a9816d4, 0x77cc0fa1, 0xeaaa28c1, 0xc2f3cde2, 0xf759df4e, 0xcf4d8d89, 0x1a96177b],
+    [0x60b8a30a, 0x8242ace3, 0x6a233c1a, 0x05202c12, 0xbc60c4ff, 0xd0ef8054, 0x4331d71c, 0x066d04b2],
+    [0xaa1bd804, 0x27037a62, 0x72e02ad5, 0x381cc65f, 0x71c6dd3b, 0x21957828, 0xec0b0cf5, 0x2a4c4fc6],
+    [0x219fd649, 0xe55afc01, 0x46f6d9d7, 0x5e727f84, 0x4a7cedc9, 0x47e9f2e1, 0x6ccf37d4, 0x13ab2d13],
+    [0x9acc6a9a, 0x4c2e3e86, 0xc26f5519, 0xc1b04fce, 0x3dc82769, 0x19d24d84, 0xca260616, 0x1121552f],
+    [0x7c97cff1, 0x09a5546c, 0x595c4a89, 0xa6cd267d, 0x15c37d77, 0x889bc817, 0x22b13d6c, 0x00ef6533],
+    [0xd8a397d3, 0x845aca35, 0x652595d9, 0x400c776d, 0xa74051e6, 0x8b261d8b, 0x45a66520, 0x0e25483e],
+    [0x79eeba89, 0x46448db9, 0xdde92d8c, 0x395ac3d4, 0x9e15d88e, 0x24526465, 0xb9dd7682, 0x29f536dc],
+    [0xce0fa5be, 0x0e456baa, 0x80bbea17, 0x5a124e27, 0x5dbdbd88, 0xdfda3357, 0x2c53feba, 0x2a56ef9f],
+    [0xa8cb9af1, 0xee416240, 0xa46762e8, 0xf2ae2999, 0x17b5c409, 0xecfb7a2d, 0x8eb5cf5d, 0x1c8361c7],
+    [0xe74319c5, 0xd3d0ab4b, 0x764507bf, 0x83e8e68a, 0xaaf0206b, 0xc0473089, 0x38b20a0f, 0x151aff5f],
+    [0x5b51f100, 0xe76e4761, 0xc8b6cdd1, 0xa9f52fc8, 0x8f7f9d43, 0xc1b239c8, 0x41ed881d, 0x04c6187e],
+    [0xdc9c2967, 0x9e801b7d, 0xd1577644, 0x4b81c61e, 0xf6fb6d53, 0x10d84331, 0x0f4d27fb, 0x13b37bd8],
+    [0xc4e8a8e4, 0x9321ceb1, 0x2a52032c, 0x2ce3664c, 0x32c17b7a, 0xf578bfbd, 0x273c2d9d, 0x01a5c536],
+    [0x5b7c3b02, 0x83223906, 0x6b9726da, 0x4a9a2c66, 0x7acb950b, 0x5ad05f5d, 0x34ca7383, 0x2ab35618],
+    [0xa597b646, 0x9f7ed516, 0xe95d3bf6, 0xacaf6af4, 0x86c0d613, 0x200fe6d6, 0x91e720db, 0x1d4d8ec2],
+    [0x0b65af1d, 0x1514c9c8, 0x40a04b71, 0xb9253512, 0x7919fd2b, 0x8f5784fe, 0xcc484d22, 0x041294d2],
+    [0x90e81fc6, 0x042971dd, 0xd126e392, 0x98f57939, 0x991f0048, 0x1c4fa715, 0x01708c61, 0x154ac98e],
+    [0xc6ea4da4, 0x4524563b, 0x88f8b0b0, 0x50b3684c, 0x93aef510, 0x3eedd840, 0xcca7d4f8, 0x0b339d8a],
+    [0x0839c82e, 0x81ed95b5, 0xaff4a7dd, 0x98f0e71e, 0xfbab3445, 0x54a4f84c, 0x6610c942, 0x0955e49e],
+    [0xa0654626, 0x3525401e, 0x6f535c6f, 0xa9a6f41e, 0x06f15abc, 0x26b9e222, 0x56eba544, 0x06746a61],
+    [0xf32077fb, 0xac917c7f, 0x2bd0a196, 0x38e5790e, 0xc549c278, 0x496f3820, 0xecd1423c, 0x0f18f5a0],
+    [0xd6f76e13, 0x2a738223, 0x3ede7bc9, 0x4bb56358, 0x5beb261e, 0x8ac59eff, 0x1751f730, 0x04f6eeca],
+    [0xfc0b3758, 0xc1768d26, 0x6fb3e45b, 0x8811eb11, 0xa3cdce03, 0xc1a3ec4d, 0x64c4c4f5, 0x2b569733],
+    [0x437f29ef, 0x83feb65d, 0x85716a5d, 0x8e1392b3, 0x804b1bcb, 0xdcd76b89, 0x49d5b054, 0x123769dd],
+    [0xb01c63e9, 0x94257b2f, 0x64711509, 0xa989f644, 0x1169aace, 0x88ee52b9, 0xfc48c80a, 0x2147b424],
+    [0x7cebe54d, 0xea54ad89, 0xad4243c2, 0x647e6f34, 0xea332a29, 0x1a6c5505, 0x548b8570, 0x0fdc1f58],
+    [0xc0a0b1f2, 0x944f685c, 0x5dbbe0c3, 0xbceff28c, 0x0f7786d4, 0xdf68abcf, 0x51fea004, 0x12373a82],
+    [0xc1a90035, 0xdd8a1f35, 0x6af44203, 0xa642756b, 0xf742c9e8, 0xad7ea52f, 0x5f35f85b, 0x21e4f4ea],
+    [0x1bc3b147, 0x8a81934f, 0x2f45e90d, 0xb5736649, 0x24d4c462, 0xdfb47222, 0xd69d2ca3, 0x16243916],
+    [0xcac04ac2, 0xa13a4159, 0xe1a0453c, 0xabc21566, 0xc88b4378, 0xf66f9adb, 0xd7a578b4, 0x1efbe46d],
+    [0x6a88969a, 0x3b672cc9, 0x5be66f85, 0xd468d552, 0x23a7f387, 0x8886020e, 0x37cf5dd0, 0x07ea5e85],
+    [0xb76c00bc, 0xa9fe16c0, 0x5e7ce11c, 0x650f19a7, 0x0f9a5b63, 0xb7b478a3, 0x968b8aa3, 0x05a8c4f9],
+    [0x2a32e83f, 0x2d9d57b7, 0x01b9c788, 0x3f7818c7, 0x345e8dac, 0xfbfe59bd, 0x2cc21654, 0x20f05771],
+    [0xeb33db69, 0x9bd90b33, 0xd01d4901, 0x6dcd8e88, 0x7fee3163, 0x9672f8c6, 0xda9dfd68, 0x04a12ede],
+    [0x4ccd101a, 0xe49ec954, 0x091a6767, 0xbd136ce5, 0x25a51dec, 0xe44f1e54, 0x15f37dce, 0x27e88d8c],
+    [0x433de4d1, 0x176c41ee, 0xa7703223, 0x6e096619, 0xe95a41f6, 0xb8a5c8c5, 0x84285ed9, 0x2feed17b],
+    [0x53aff2b8, 0x6972b8bd, 0x11312a0d, 0x94e59429, 0x0f729cf3, 0x40424142, 0xedf45c7c, 0x1ed7cc76],
+    [0x45466b1a, 0xdf2874be, 0x6144cdca, 0xac678347, 0x86f5660e, 0x157ff8c5, 0xb9bfa323, 0x15742e99],
+    [0x27d0c785, 0x284f033f, 0xc6ec0317, 0x77107454, 0x87ddf405, 0xc895fc68, 0x87f65e82, 0x1aac2853],
+    [0x54d67c77, 0xec75a965, 0x49775f71, 0x832e2e7a, 0xb6057357, 0xf9ddadbd, 0x845d4790, 0x25851c3c],
+    [0x9f146a67, 0x0ddccc3d, 0x2c552337, 0x53b7ebba, 0xb197edf3, 0xce78457d, 0x65cc2ec2, 0x15a58215],
+    [0x28c71727, 0x2f15485f, 0x04427750, 0xdcf64f36, 0xa1db5966, 0x0efa7e31, 0x4813b998, 0x2411d57a],
+    [0xf6cb4c9b, 0x58828b5e, 0x12f4cd25, 0x47e9a98e, 0xc0b6d2e6, 0x13e335b8, 0x6520cd47, 0x002e6f8d],
+    [0x9e0315d2, 0x39883460, 0xe2fe1ed7, 0xaf8f0e91, 0x16b0fcd1, 0x97da00b6, 0x4380cde9, 0x2ff7bc8f],
+    [0xbb0d3cbe, 0xe93be4fe, 0xb7bb68f1, 0x2e9521f6, 0x471bcd18, 0x5ee02724, 0x94852559, 0x00b9831b],
+    [0x0c9c3512, 0x7d77adbf, 0x8a4743a8, 0x1ca40864, 0x57c04e01, 0x86913b0e, 0x8b8ebf6a, 0x0a2f5376],
+    [0x05e1198d, 0x7f2a2903, 0xe94be69b, 0x0f599ff7, 0xff239e96, 0x3a479f91, 0x142fd037, 0x00248156],
+    [0x2b2bcda9, 0x50eb512a, 0x6a542c23, 0x397196aa, 0xab3f0c9a, 0x28cf8c02, 0xb87bfb13, 0x171d5620],
+    [0xec34a808, 0x9d1045e4, 0x2dd54dd9, 0x60c95217, 0x10d6fad7, 0x70087c7c, 0x536f7dc9, 0x170a4f55],
+    [0xe2dbfae1, 0x482eca17, 0x1cd211ba, 0xcc37e38c, 0xea04336e, 0x2ef3134a, 0x799fe66c, 0x29aba33f],
+    [0x69e64973, 0xb5ba6503, 0x03f6a0e8, 0xe70d114a, 0x45088d47, 0xfdd1bb19, 0xa4fdd758, 0x1e9bc179],
+    [0xbdaf8f09, 0x9c9e1c43, 0xa9c4b44f, 0xfeaad869, 0x2dfb0b5a, 0x58f7f489, 0x9b660fad, 0x1dd26979],
+    [0x0f24af38, 0x5d1dd2cb, 0xe869c7c9, 0x7ccd426f, 0x2e15459e, 0x401181d0, 0x70117ad1, 0x22cdbc8b],
+    [0xc7dacefd, 0xd5ba93b9, 0x2ed94a7c, 0xfd3150f5, 0x5c503fce, 0x3a9f57a5, 0x54771c53, 0x0ef042e4],
+    [0xa62e8284, 0x3b304ffc, 0x8a0359a0, 0x1318e8b0, 0x6037e885, 0xf287f303, 0xad6c8fe2, 0x11609e06],
+    [0x783aa9af, 0x08b08f5b, 0x76dfe427, 0xfecd58c0, 0x427c17b7, 0x9e753eea, 0x54616dba, 0x1166d9e5],
+    [0x357ee466, 0xf855a888, 0xd2ac0b56, 0x177fbf4c, 0x354413db, 0x93413026, 0x431a8595, 0x2de52989],
+    [0x5f71e9ad, 0x74bf01cf, 0x17b8e89d, 0xf51aee5b, 0xf3a8ac1d, 0x9a6da492, 0xfc7a8581, 0x3006eb4f],
+    [0x25145086, 0x62344c82, 0x0a4639f9, 0x2993fe8f, 0x9e3f6f42, 0xfdcf6fff, 0x61ba8a80, 0x2af41fbb],
+    [0xce4827c3, 0x81b214ba, 0x889e85e7, 0x8718ab27, 0x8ebc85db, 0xe5a6b41a, 0xe476155f, 0x119e684d],
+    [0x7b3fd800, 0xcff784b9, 0x3828f047, 0xb51248c2, 0xae363537, 0x188bea59, 0xe2e8925e, 0x1835b786],
+    [0xab32eeb6, 0x6c40e285, 0xa7905c92, 0xd152bac2, 0xc6433a20, 0x4d794996, 0xc594dfa3, 0x28201a34],
+    [0xc22cc4e7, 0x4a761f88, 0x57118772, 0x864c82eb, 0xaf78b000, 0x94e80fef, 0x27d17510, 0x083efd7a],
+    [0xf61fd13b, 0x9e079564, 0x774dd851, 0x11c16df7, 0xeea27be8, 0x6158e61c, 0x57719952, 0x0b6f88a3],
+    [0xe4254f5b, 0x14390e6e, 0x00d29e10, 0x589511ca, 0xd6471a94, 0x644f66e1, 0xd15e51d9, 0x0ec868e6],
+    [0x84c98591, 0x00d937ab, 0x939cd40d, 0xecd3e74b, 0xed2e1142, 0x1ac0c9b3, 0x86677127, 0x2af33e3f],
+    [0x7951f178, 0x364ce5e4, 0x7dd6858b, 0x34568c54, 0x1c6ace77, 0xd09b5d96, 0xf904b5e7, 0x0b520211],
+    [0x188a1d40, 0xca228620, 0x270e822c, 0xa0c56ac4, 0x0062a92e, 0xd8db58f1, 0x0919a1aa, 0x0b2d722d],
+    [0xd6e562d4, 0xe0061d1e, 0x91ca38bb, 0x57b54a99, 0x7c2453e9, 0xd980ceb3, 0x7f8cf094, 0x1f790d4d],
+    [0x1e504233, 0xda92ceb0, 0x35a2a6a8, 0x0885c162, 0x85f78015, 0xaea97cd3, 0xdfbf7d1e, 0x0171eb95],
+    [0x1b168873, 0x76230538, 0xfd2c8650, 0x790b40de, 0x5da66b9b, 0x329bf688, 0x5fd57549, 0x0c2d0e3b],
+    [0x4407a18d, 0x5d380305, 0x89e283c3, 0x7cbcafa5, 0xb4e72b37, 0x4e5a8228, 0x689c2715, 0x1162fb28],
+    [0x49711bc0, 0x1623ef82, 0xa89e1992, 0x282c5a92, 0x91e8310f, 0x64ad386a, 0x5dee441b, 0x2f1459b6],
+    [0x1b53cfbb, 0xc243f70d, 0x754eb712, 0xbc489d46, 0x7d5cd4c1, 0x996d7436, 0x6b688c3d, 0x1e6ff321],
+    [0x26478875, 0x76881f93, 0x6cdc2a05, 0xd741a6f3, 0x7d157802, 0x681487d2, 0x3832b8d0, 0x01ca8be7],
+    [0x15f9650e, 0x0b9b5de3, 0x0b10cea0, 0x68028608, 0xbdf223dc, 0x86f976d5, 0x6ffe9fc5, 0x1f773570],
+    [0x8285f019, 0x4745ca83, 0xd5f096ef, 0x21ac10a3, 0xe041fba9, 0x40a0c2dc, 0x4ea33076, 0x2522b60f],
+    [0x5572575b, 0x8ce16c23, 0xf52b6c3f, 0x3418cad4, 0xdc957f83, 0x5255075d, 0x01b1029d, 0x23f0bee0],
+    [0x93082d59, 0x66d94010, 0xe9df905f, 0x5d142633, 0x555ed568, 0xcaac2d44, 0x8ddbb81f, 0x2bc1ae8b],
+    [0xad72205f, 0x8011fcd6, 0xa07b1fc9, 0x62371273, 0x8dba3ed1, 0x7304507b, 0x296564a3, 0x0f9406b8],
+    [0xd995f0a8, 0xcb126c8c, 0x4a52ee4a, 0x17e75b17, 0xde90714e, 0x67b72998, 0x0cc7defa, 0x2360a8eb],
+    [0x67f88948, 0x6dcbbc27, 0x96df8b00, 0xb4815a5e, 0xbaef255e, 0x804c803c, 0xddead976, 0x15871a5c],
+    [0xeefb420f, 0x4f957ccd, 0xf7237954, 0x362f4f54, 0x2f3b1da0, 0x0a8652dd, 0x6998ee9e, 0x193a5676],
+    [0xe777ae0f, 0xe4309805, 0xad334834, 0x3b2e63c8, 0xf4fab170, 0x2f9be56f, 0x934f8698, 0x2a394a43],
+    [0x76c0d142, 0xb4166e88, 0x23443ba7, 0x892cd112, 0xcb345192, 0x3e8b635d, 0xfeb8695f, 0x1859954c],
+    [0xf4fed32b, 0x408d3819, 0xd90bbdca, 0x2b11bc25, 0xcb99f190, 0x013444db, 0x63050e58, 0x04e11817],
+    [0xbfd05f23, 0x1f5e5552, 0xb08b5e8b, 0xb10eb82d, 0x64de8c5b, 0x40c335ea, 0xee83869d, 0x0fdb253d],
+    [0xe9b4f1c0, 0xa9d7c5ba, 0xf1c08984, 0x75f08686, 0x3adead62, 0xaa4efb62, 0x9a5027bd, 0x058cbe8a],
+    [0xcceca59a, 0xd15228b4, 0xef023ab0, 0x23b4b83b, 0xaeb1f52b, 0x497eadb1, 0x9971e186, 0x1382edce],
+    [0x01d9e8b5, 0xe1e66346, 0x99f14b77, 0x7f61b8eb, 0xfd11b0be, 0x0819ca51, 0xf045c6ee, 0x03464990],
+    [0xaeb70a58, 0xaa5bc137, 0x5db2eb5a, 0x6fcab460, 0xf98ff83c, 0xfff33b41, 0x720dc296, 0x23f7bfc8],
+    [0xbbaf62f2, 0x19636158, 0xe1531a92, 0x18c3ffd5, 0xf0e9decf, 0x7e6e94e7, 0xe3eec211, 0x0a59a158],
+    [0x075fd07b, 0xf4c23ed0, 0x65420af8, 0xe2c4eba0, 0x312ffd3c, 0xb58bf23b, 0x0381c052, 0x06ec54c8],
+    [0xed1f9d01, 0x962f0ff9, 0xa7bcb1b4, 0xb09340f7, 0x8e867ec8, 0x476b5664, 0x832e0eb5, 0x118872dc],
+    [0x520921b1, 0x95e1906b, 0xe42d7fea, 0x52e0b0f0, 0xa7ad59ed, 0x5ad5c7cb, 0x27d83416, 0x13d69fa1],
+    [0x9f10c77b, 0xfd8a49f1, 0xfb71dc55, 0xde143942, 0x7a73d21b, 0x70b1c687, 0x63ea6812, 0x169a177f],
+    [0x7450544d, 0xfb7e9a5a, 0xb922f66f, 0x3abeb032, 0xadce40d9, 0xef42f287, 0x1c6ead97, 0x04ef5159],
+    [0xdd0f69ec, 0xd5f45ee6, 0x5d4f03ce, 0x19ec6180, 0x03fb2e3b, 0x0ecd7ca7, 0x1dc07939, 0x256e175a],
+    [0x3e2ceeb2, 0xa002813d, 0x3205dd2d, 0x75cc360d, 0x2ff6004f, 0xe5f2af41, 0x636abd5f, 0x30102d28],
+    [0x82fcc792, 0x1fd31be1, 0x99bef4a3, 0x0443a3fa, 0x73eb1bf4, 0x1c0714bc, 0xdfcd3bbf, 0x10998e42],
+    [0x79e36860, 0xecad76f8, 0xf4d582ef, 0x9f3362ea, 0xb598a1d8, 0x25fa7d24, 0x9fcf3d76, 0x193edd8e],
+    [0xa51f0b5d, 0xf2664d7a, 0xce611425, 0xd1c7a561, 0x0b7b3347, 0xd0368ce8, 0x34f2d915, 0x18168afd],
+    [0x33ea6111, 0x29e2e95b, 0xbc33626e, 0xa328ec77, 0xebe658b6, 0x0c017656, 0xebd3b6ab, 0x29383c01],
+    [0x9010c711, 0x00bf573f, 0x6fb76ab6, 0x702db6e8, 0x7771a64a, 0xa1f4ae5e, 0x2603de39, 0x10646d2f],
+    [0xcb1117fb, 0x64d0242d, 0x40da7b38, 0x2f90c25b, 0x5a55bf13, 0xf575f139, 0xd1b27145, 0x0beb5e07],
+    [0xd96fa336, 0xdffbf018, 0xe54b59ab, 0x30f95bb2, 0xd62b5c88, 0xdc0d3eca, 0x2078c133, 0x16d68525],
+    [0x2047f01a, 0xfd672dd6, 0xec21ddfa, 0x0a555bbb, 0x0404b4b4, 0x3c74154e, 0x833938f3, 0x0a6abd1d],
+    [0x34cf1860, 0x70a6f19b, 0xc4503172, 0xb12dffee, 0x2dedc8fe, 0x8ea12a4c, 0x36eb7b5c, 0x1a679f5d],
+    [0x3f1b93d6, 0xfbc7592e, 0xda4e8f6f, 0x26a423ea, 0x0ebfde47, 0x3974d50e, 0x3bd456c2, 0x0980fb23],
+    [0xc312c72b, 0x03ebacb5, 0x28c92820, 0xcece3d56, 0x93a38fc0, 0xbf1810af, 0x2e61b84c, 0x161b4223],
+    [0x47de1a0b, 0xd09203db, 0x7f1564e5, 0x493f0978, 0xa60d5e6a, 0x950f7d47, 0x0c7f0520, 0x0ada10a9],
+    [0xf407f451, 0xb50ddb9a, 0x2b4e121b, 0xd3f07a8a, 0x9ac4238e, 0x320345a2, 0x2310ba82, 0x1a730d37],
+    [0x58e8c556, 0xfbda10ef, 0xaba5c4df, 0x908377fe, 0x69dda7ea, 0x817064c3, 0x68ef054f, 0x2c8120f2],
+    [0xa4968f70, 0x6e7b8649, 0x13bcb73e, 0xb930e953, 0x9c684217, 0xa57c0078, 0xf758753f, 0x1c7c8824],
+    [0x3fd1cf77, 0xb47b27fa, 0x491eb3f7, 0xf400ad8b, 0x7a74faa0, 0x8e39e407, 0xf5f8691c, 0x2cd9ed31],
+    [0x18a22eea, 0x854ae239, 0x321ca550, 0xa5e022ac, 0x57618399, 0xcf60d92f, 0x46813457, 0x23ff4f9d],
+    [0xf180426d, 0xdff1ea58, 0x03529407, 0xaf5a2c51, 0x5dddd9d0, 0xceece640, 0x147a4f66, 0x09945a5d],
+    [0xec6fc630, 0x8a6dd223, 0xa29d3f26, 0x7c7da6ea, 0xb771b90f, 0xb67660c6, 0x8025d4c2, 0x188d9c52],
+    [0xf4f0f47f, 0xe0c0d8dd, 0xd3633595, 0xdba7d926, 0x431d8734, 0x81f68311, 0x96596b7f, 0x3050e379],
+    [0xd30afd78, 0x9d829518, 0x1e3f95d8, 0x6ceae546, 0x02c35c42, 0x1600ca81, 0x396830a9, 0x15af1169],
+    [0x320d8acc, 0x04284da3, 0x51466b29, 0xdae933e3, 0xf873d985, 0xa06d9f37, 0x85432ea9, 0x1da6d098],
+    [0x1ddaa9cb, 0xe546ee41, 0xbe658945, 0x4e4fad3d, 0x3921124e, 0xf5f8acf3, 0xd269af29, 0x2796ea90],
+    [0x01d32d60, 0x7cb0319e, 0xc8e9304a, 0x1e15612e, 0x307742f0, 0x0325c8b3, 0xda0f6b4b, 0x202d7dd1],
+    [0xc0f8be5f, 0xa29dace4, 0x88f4c831, 0xa2d7f9c7, 0xa263d672, 0x156a952b, 0xd05bb759, 0x096d6790],
+    [0x447d25a4, 0x63798cb1, 0xe5b13e19, 0x438da23c, 0x275d877b, 0x83808965, 0x65b0fce2, 0x054efa1f],
+    [0x8e4165f1, 0x64ccf6e1, 0x13b2e148, 0xd8aa6901, 0x9802deb9, 0xdb3308c2, 0xd917e93e, 0x1b162f83],
+    [0xa0506edc, 0xc5ceb745, 0x66cc568e, 0xedfefc14, 0x2a0de39e, 0xfd9f1cdd, 0x12564dd6, 0x21e5241e],
+    [0x0e4bdf08, 0x7b4349e1, 0x87e16192, 0xcb73ab5f, 0x17b36abe, 0x226a80ee, 0xe8cf5ac9, 0x1cfb5662],
+    [0x6eb24100, 0x29c53f66, 0x6220ac01, 0x2c99af34, 0xecb373b6, 0xbae6d8d1, 0x302a771b, 0x0f21177e],
+    [0x515c2320, 0xbcef7e1f, 0xe6290546, 0xc4236aed, 0xf71b12be, 0xaffb0dd7, 0x74606992, 0x16715223],
+    [0x92cad870, 0xd419d2a6, 0x2c5cc8cc, 0xbe2ec9e4, 0x501bfad9, 0x2eb4cf24, 0x9488259c, 0x0fa3ec5b],
+    [0x1ab54bba, 0x85e8c57b, 0x5c648cc0, 0xd36edce8, 0x1506080e, 0x57cb266c, 0xe0bd2983, 0x193c0e04],
+    [0xdaba68f8, 0xce14ea2a, 0xcd406578, 0x9f6f7291, 0x6dcbc3c9, 0x7e912830, 0xf74735a2, 0x102adf8e],
+    [0x70c3eab1, 0x40a6d0cb, 0xfbdd23ae, 0x316aa24b, 0x1ad945b1, 0xe2a54d6f, 0x58e49859, 0x0fe0af78],
+    [0x44798d22, 0xe8a5ea73, 0xa9ebdefd, 0x2da5f1da, 0x20843f4e, 0x08536a22, 0xbbc7dedb, 0x216f6717],
+    [0x28325161, 0xf88e2e42, 0x773c6b3e, 0x3c23b2ac, 0x91918a1b, 0x4a3e6943, 0x00f0d21f, 0x1da55cc9],
+];
+
+/// The MDS matrix for `t = 3`, row by row.
+#[rustfmt::skip]
+pub(super) const M3: [[u32; 8]; 9] = [
+    [0x2ba8118b, 0xfedb6859, 0xad24378b, 0x94be7c11, 0x5c36a7b1, 0xb2b70caf, 0x1ba0e4c9, 0x109b7f41],
+    [0xdc4903e0, 0xd6c64543, 0xdbdeea55, 0x9314dc9f, 0x4fddbcbc, 0x6ae11942, 0x3bb9c0c6, 0x16ed41e1],
+    [0x4e36736d, 0x791a93b7, 0x0ceb247b, 0xf706ab64, 0xbfe82e0d, 0xf617e7dc, 0x0fca0589, 0x2b90bba0],
+    [0xde0bd771, 0xd62940bc, 0x415c3dde, 0x2cc8fdd1, 0x4379dbca, 0xb9c36c76, 0xed31a480, 0x2969f27e],
+    [0x87b1fe23, 0x29b23116, 0x8c7b9640, 0xb89d743c, 0x32963dc1, 0x4c9871c8, 0xec02ec39, 0x2e2419f9],
+    [0xa0f89bfa, 0xc8aacc55, 0x9f5fb065, 0x148d4e10, 0x690f053d, 0x97315876, 0x032379b6, 0x101071f0],
+    [0x65a1b1a7, 0x326244ee, 0x8c5b3753, 0xe6cd79e2, 0x4638065c, 0x0d5f9e65, 0x686a3f33, 0x143021ec],
+    [0xc8ee2911, 0xb16cdfab, 0x58e7d7b6, 0xd057e12e, 0x08a6fd99, 0x82a70eff, 0x695ad025, 0x176cc029],
+    [0x1d25d5e0, 0x73279cd7, 0x07043f77, 0xa6444703, 0x3802593f, 0x17ba7fee, 0x56702bf4, 0x19a3fc0a],
+];
+
+/// The round constants for `t = 4`, with 56 partial rounds.
+#[rustfmt::skip]
+pub(super) const C4: [[u32; 8]; 256] = [
+    [0x69ed23e5, 0x8b0878e2, 0x4edc2623, 0x02bb8674, 0xbd5e4a43, 0x48da1d39, 0x9450b068, 0x19b849f6],
+    [0x8dcf34d6, 0xad47f80c, 0x450acc1d, 0x20eb2cc7, 0x758f0a13, 0x7239347b, 0x27dd51bd, 0x265ddfe1],
+    [0xb497d8aa, 0x3dfc36ba, 0x5015c2aa, 0x4108ac84, 0x5e1e5162, 0xe0f66a54, 0x472f1809, 0x199750ec],
+    [0xc7f1cdf8, 0xd032f787, 0x5067f0ff, 0x4d743ea2, 0xf74302b1, 0x110f06a5, 0x65ac7208, 0x157ff3fe],
+    [0x6ac94902, 0xfe18f489, 0x692f8bee, 0x0b15c590, 0x5fca33f1, 0x5fd35ac4, 0x4569dd9c, 0x2e49c43c],
+    [0xfa2d1f1e, 0x2731345f, 0x73c24fa8, 0xcb2f0b69, 0x6d6506c3, 0x0d4aef2b, 0x98189052, 0x0e35fb89],
+    [0x02e0b996, 0xc6fe7230, 0x6d667ffe, 0xa9d9e780, 0x5e944f1b, 0x05f109ae, 0xb15c4f11, 0x251ad47c],
+    [0x9c22df4e, 0x563fa39d, 0xdd05e5f3, 0xf8beb56f, 0x60234641, 0x9873e971, 0x64d42836, 0x13da07dc],
+    [0x55fd4738, 0x46e7b890, 0x89d350cd, 0xa5539396, 0xccef7483, 0x3dc00c7d, 0xe650e6d2, 0x0c009b84],
+    [0xbefdca06, 0x203dec74, 0x6d535eb0, 0x04eb650c, 0x56f42d8b, 0x01992e39, 0xc63a854f, 0x011f16b1],
+    [0x3f367549, 0x85df0709, 0x467ad454, 0x2f3f78d0, 0x1daa7961, 0x209d9a56, 0x383a688f, 0x0ed69e5e],
+    [0x4c9f789b, 0x46367226, 0x5eb3d33f, 0x3aec507f, 0x472b6bbe, 0x21acad41, 0x7b0ce9e2, 0x04dba94a],
+    [0xd4fa28e8, 0xce732ff1, 0x4bb50bf7, 0x6036757d, 0x1c9d237b, 0x6eb09427, 0xd840f3a1, 0x0a3f2637],
+    [0x1182323f, 0xe54a485d, 0x569564b6, 0x39b1f075, 0x2fdb38fa, 0x8f8a1c50, 0x129eea19, 0x259a666f],
+    [0xede0d6a1, 0x7a32fdf7, 0x1038e515, 0x7745d427, 0x4ee3a47f, 0xd8e7d06a, 0xc9b2f4c6, 0x28bf7459],
+    [0x41432447, 0xec91bd69, 0xcce6a2ae, 0xc37c85bb, 0x489be8d4, 0x26ea200f, 0xf0570375, 0x0a1ca941],
+    [0x926361cf, 0xb43a26fd, 0x39f051dc, 0x5535ed15, 0xc5451285, 0x53d7fd4f, 0x8be0e930, 0x0c6f8f95],
+    [0x9caaf811, 0x84dd57e6, 0x08e296e0, 0xa9e8a007, 0x8ac9d90a, 0xd426e812, 0x3cd17578, 0x123106a9],
+    [0xcd2dee75, 0x7b074867, 0xf1e8f187, 0x5e8fa83f, 0xf8e84008, 0x7dd3ab52, 0xad9285d9, 0x26e1ba52],
+    [0x6a4ae2c5, 0x4471537e, 0xf9e09586, 0xbe4d8b7b, 0x47b9c97c, 0x18a64c5c, 0x7bd133de, 0x1cb55cad],
+    [0x6e9055d0, 0x7143f08e, 0x5060a41c, 0x2a53043d, 0x4bde7f6d, 0x0e2c7ce0, 0x6acd8f8e, 0x1dcd73e4],
+    [0x512e5574, 0xb12b9bb4, 0x0eb4e9b9, 0x0cda294a, 0x474a4def, 0xf5852f05, 0x2f6d9c66, 0x011003e3],
+    [0x2287ae8c, 0xd7c508dd, 0x3f58bafe, 0xbadfe590, 0x03a57dfe, 0x9ad5f20d, 0xc1d10ab2, 0x2b1e809a],
+    [0x7bcec0a5, 0xeaa69ae8, 0xab2fc5fa, 0xef995d05, 0x5ee17ed0, 0x9fb4dac3, 0x85b73599, 0x2539de17],
+    [0x1d77951d, 0x43982cb1, 0x1c86d46e, 0xf4e1c3d4, 0x2b3e0a0e, 0x26497f22, 0x2ef8ee01, 0x0c246c5a],
+    [0xd03b527b, 0x3f0305f5, 0xad1a1c2f, 0xbb09e6a6, 0x7c0632ed, 0x5408148f, 0x974f68e9, 0x192089c4],
+    [0xb5a60d85, 0x6d8fdc2f, 0x91096b75, 0x8529097d, 0xeb0d0c05, 0x6a0ee36e, 0xab68b2f0, 0x1eae0ad8],
+    [0xc5d06bfb, 0x9768bd98, 0x0dee99e6, 0xdb6e2fdc, 0x872abc88, 0xe46f8282, 0xd0e22179, 0x179190e5],
+    [0xa9b3cd1c, 0x6cafe794, 0xb00f31bf, 0x14528f7d, 0x7ac4b832, 0x76e9a81c, 0x90767325, 0x29bb9e2c],
+    [0x6e691e08, 0xb10e590e, 0x882aac35, 0x52652645, 0x2464a90d, 0x403efd0c, 0x42207599, 0x225d394e],
+    [0x4b23fd59, 0xe09efd45, 0x451c087d, 0x2be13557, 0x55b44453, 0x753d2380, 0x3c25c8cf, 0x06476062],
+    [0x8f6b5b87, 0x922910a7, 0x42a75c10, 0x4d67f4bf, 0x716d8a39, 0x7f301c4b, 0x01df92e8, 0x10ba3a0e],
+    [0x3f21471c, 0x361b7769, 0xc242eb9d, 0xcb511bc0, 0xb0c2a801, 0x4f9c6e96, 0x3f8451b2, 0x0e070bf5],
+    [0x4de252fb, 0xa7f92101, 0xd2491d8a, 0xccd6cb11, 0x93821a73, 0xd39755ff, 0xb051b04d, 0x1b94cd61],
+    [0x7d74070b, 0x0487b5aa, 0x5713bb05, 0x9d4e917d, 0x2e70230f, 0xe148787a, 0xafb8c744, 0x1d7cb39b],
+    [0x303b17db, 0xbb74ac1f, 0x1829f701, 0x8785c296, 0x980c80ff, 0x9117d0fe, 0xbd1ab4f6, 0x2ec93189],
+    [0x83517926, 0x82ea46bd, 0x9ae07a90, 0xeac404a1, 0x5b86275b, 0xa692bb82, 0xdd36d277, 0x2db366bf],
+    [0x960711b8, 0xdc99cec6, 0x8450359a, 0x98527542, 0x86a68532, 0x69655cf1, 0x485db062, 0x062100eb],
+    [0x41f5a59b, 0x00c567bf, 0xfa59e4f9, 0x20243f92, 0x8244ca11, 0x570e7f1e, 0x66614aaa, 0x0761d33c],
+    [0x4855ad0d, 0xf7a72e49, 0x0f7de4cc, 0x5d78608a, 0x034e3f31, 0x2c2705aa, 0x114d1399, 0x20fc411a],
+    [0x7250bc5a, 0xc3a30f31, 0xb3effb5f, 0x102c67e8, 0x9ab219ba, 0xadd9ec4e, 0xa4bdfcb5, 0x25b5c004],
+    [0x62b37f4b, 0xd87e7dff, 0x8474155a, 0x038b186d, 0x6df6f5ed, 0xa494e58f, 0x278ed632, 0x23b1822d],
+    [0xcc2f69e0, 0x16102a29, 0xfcfcccaa, 0x0f14d13b, 0x012499bf, 0x606c4ba9, 0x5c3f9493, 0x22734b4c],
+    [0xad795ce5, 0x54413d3f, 0x9aa36102, 0xe5bdff40, 0x33492347, 0xe27a74dc, 0x09eb30b7, 0x26c0c8fe],
+    [0x348ccad9, 0xbbd626df, 0x3a809829, 0x196be308, 0xfa1fbb26, 0xe88eac03, 0xb6bd7bba, 0x070dd0cc],
+    [0xfd4250da, 0x6067c4eb, 0x46d8c5ad, 0xc2c0a6de, 0xbb28c3be, 0xb043ba78, 0xdb329b6f, 0x12b6595b],
+    [0xb7e8d729, 0x5e33d95b, 0x275c671c, 0xc06fca9b, 0xa5876c11, 0x3bec30e7, 0xf76283d6, 0x248d97d7],
+    [0xbd9baaaa, 0x106d15d9, 0x9ddde4aa, 0x8b45eb75, 0x4cc93931, 0x16fc6fd6, 0x9d463b08, 0x1a306d43],
+    [0xec7c56cf, 0x0d62d3d6, 0xdc27821b, 0xf4f1b54d, 0x21cb4621, 0xced7c004, 0x2e3c38da, 0x28a8f837],
+    [0xe1e2ce7e, 0xbc852183, 0xc829f388, 0x071ce320, 0x24d43294, 0xbb35152f, 0x17f9a8a8, 0x00949757],
+    [0xdb2e8d65, 0xf4103246, 0xf653ae83, 0x593f74d4, 0x716480d3, 0x80fde60d, 0x3aa78f7d, 0x04d5ee4c],
+    [0x2efde187, 0xd08495c1, 0x8822cc76, 0xc7bef54b, 0xb8ed2269, 0x6349ad6f, 0xaa03d433, 0x2a6cf5e9],
+    [0xefcba3f3, 0xbaae48d7, 0x08fd6e43, 0xf7921808, 0xe19ddeb7, 0x9274da43, 0xaab960ba, 0x2304d31e],
+    [0xd199f0b0, 0xe1c11d39, 0x0726fcb4, 0xbff08a7e, 0x85817249, 0xd5e70097, 0x65a4b2a6, 0x03fd9ac8],
+    [0xd63b0b64, 0x3f7954d4, 0x20919307, 0x798afc3a, 0x55ee5044, 0x2248404d, 0xed52bbda, 0x00b7258d],
+    [0x65e92d9a, 0x6272c5ca, 0xf3298db3, 0xb13d3a74, 0xd4bf65eb, 0xec38fca2, 0xa0771799, 0x159f81ad],
+    [0x4264431f, 0x71e144cf, 0xa25f0c54, 0x9000130e, 0xbc28e3bb, 0x50237a75, 0x437fbc85, 0x1ef90e67],
+    [0x2932e30d, 0x95a79ed8, 0x176b08ec, 0x8df739bc, 0x41a2d256, 0x196b49aa, 0x515e5ff0, 0x1e65f838],
+    [0x8c94c33f, 0x6575c106, 0x570e1f82, 0xb18c844e, 0xd079ba74, 0xec6ce768, 0xef3a166c, 0x2b1b045d],
+    [0x168bb173, 0xf1c6e07c, 0xbef715e3, 0x65dc2d73, 0x109229c1, 0x402543b1, 0x3ceb0ff6, 0x0832e575],
+    [0x90b6ad16, 0xc5a8e3c3, 0xe8b6451b, 0xb1b841c2, 0xa37d41ba, 0x6b762ae0, 0xcedfb3dc, 0x02f614e9],
+    [0x7e7ed705, 0x0f6a0be2, 0x77bedff4, 0x7370ebb7, 0x362cad96, 0xdd640b8e, 0x8bd46a60, 0x0e2427d3],
+    [0x9214a53a, 0x0768bbe2, 0x98c3c7c5, 0x049f0ec0, 0x14e7ce79, 0xeb7c84d4, 0x7c670b6d, 0x0493630b],
+    [0x5327cea9, 0x3dc06cc8, 0x55d5461a, 0x6bb15153, 0x7066c5a2, 0x4decdab1, 0xe8e48267, 0x22ead100],
+    [0x6d2a6f16, 0xe5084e0b, 0x5626d04d, 0x583f1ae3, 0xd2554d48, 0xaae2626e, 0x655b42cd, 0x25b3e56e],
+    [0x0cf6f9d0, 0x4b4fdc0a, 0x349e4c58, 0xb599c336, 0xe8ff13db, 0x5837a6cd, 0xda8836ef, 0x1e32752a],
+    [0x74d412e5, 0x72a98640, 0xf05078f6, 0x23c00995, 0xf3c3455b, 0xc50f68f6, 0xc15a387c, 0x2fa2a871],
+    [0xa7d83505, 0xcd18e7c7, 0x661bab7f, 0x54ccbf10, 0x311e889f, 0x278e1db7, 0x9a4424c9, 0x2f569b8a],
+    [0xb246b43d, 0x44165374, 0x332ffd21, 0xa7df93f7, 0x0234c518, 0x531ade53, 0x110a8fdd, 0x044cb455],
+    [0xa5319025, 0x78ddc723, 0xadfe1181, 0x91fe8c90, 0x7f2e42b1, 0x42024615, 0x93906d5d, 0x227808de],
+    [0xa6800355, 0x8579d2e7, 0xe090ad4a, 0x5d03781a, 0x87357986, 0x623adead, 0x34e046bc, 0x02fcca29],
+    [0x0d8befac, 0xcbec2e06, 0xab91a8dd, 0xbad3f3c5, 0x344a1d36, 0x6abccceb, 0xac120b87, 0x0ef915f0],
+    [0xb1405d38, 0xf3b16ef2, 0x6be63b09, 0xab0fb85f, 0xc6f287f6, 0x77eb757b, 0x4b7a3e17, 0x1797130f],
+    [0x5decc6e5, 0x36c66855, 0x20156d4d, 0x8c7f497c, 0xbab59e60, 0x3306c85a, 0xc04170ae, 0x0a76225d],
+    [0x26a31a5c, 0x96174b53, 0x8acb6647, 0xf8fa76d4, 0x93209af6, 0xa1e77a7b, 0x1992d66b, 0x1fffb9ec],
+    [0x797b9c5f, 0x0611889b, 0xc6b9c609, 0x5f8fbba6, 0x8fa538d8, 0x53b57c33, 0xc15a3f28, 0x25721c4f],
+    [0xbfcaf75a, 0xeb63b982, 0x0705da95, 0xadb4c379, 0xba197216, 0x215e3d07, 0x2d5f7a41, 0x0c817fd4],
+    [0xe52b5a96, 0x2bc15866, 0xe00a2200, 0xdf8cf86c, 0xc24970b6, 0x9f7e13c2, 0x239915d3, 0x13abe3f5],
+    [0xb4d391ce, 0x92cd60ac, 0x29bdbd7a, 0x5c1bc3dc, 0x987a46c8, 0x12ef7f39, 0x546224ea, 0x2106feea],
+    [0x5bb0f959, 0x57e1b334, 0xc748bc71, 0xf1ca5a28, 0xa37dab49, 0xaaa79474, 0x68a746b6, 0x21ca8594],
+    [0x9e34185b, 0x8f1a4899, 0x0321662a, 0x2911d14d, 0x934194c6, 0x5cf1f0df, 0x5c1e6f0c, 0x05ccd625],
+    [0xb09490a4, 0xea28678c, 0x7fe44fe6, 0x16c4fb26, 0x674c4c88, 0xe464d846, 0x4b70a626, 0x0f0e34a6],
+    [0x2de0d4bf, 0x8f5b1a8a, 0x350d6483, 0x47dbfcfe, 0xa36d0e96, 0x6157794c, 0x4e25470c, 0x0558531a],
+    [0x961f1455, 0xb72f5864, 0x3f655a60, 0x924cadad, 0x57683d18, 0xceea1251, 0x173ed2fa, 0x09d3dca9],
+    [0xe5bd4335, 0x17d4c722, 0x8aaec486, 0xf23f92d6, 0xd03d218b, 0x493f866e, 0x4e8c0913, 0x0328cbd5],
+    [0x5329d34b, 0xee3347dd, 0x9798c648, 0xe79e7bcc, 0xa7094e07, 0x23a487b1, 0xe2aff0a2, 0x2bf07216],
+    [0x3fe412df, 0x111e11a6, 0xa6dffc82, 0xd6f78ed6, 0xcb76c316, 0x6499c583, 0x58006b73, 0x1daf345a],
+    [0x93d2c404, 0x391e6f22, 0xb2edc7ff, 0x1ef39039, 0x0e182361, 0x46b694c6, 0x2456aaa7, 0x17656347],
+    [0x5bd3f8db, 0xfb022503, 0x7d1083d4, 0xca964d2b, 0xd7e33538, 0xa3bb5e47, 0xd9f08e87, 0x2ef1e0fa],
+    [0xc817ae2a, 0x1779ed36, 0xc5ae8f0a, 0x9c1803de, 0x7c731017, 0x17b2b1f5, 0xf95babcf, 0x226c9b1a],
+    [0xd4ad0def, 0x35734eb5, 0xf13fb35d, 0xf8148c89, 0x3a15ae0f, 0x28126b4c, 0x9cc3db74, 0x14bce354],
+    [0x034212c7, 0xe550cfd4, 0x01f372f8, 0xb8e923d3, 0xf2635b48, 0x742c3373, 0x6e276bb5, 0x2debff15],
+    [0x1d4760c7, 0xd7d0432d, 0xb29c47ad, 0x41afe1b6, 0x2e356b64, 0xfc2395b2, 0x5a87f5b6, 0x2d4083cf],
+    [0xd7161c29, 0x9c317c53, 0x0c0184d8, 0x91bf79a1, 0x2fdc9c1b, 0x34b91126, 0xcd04bf9c, 0x0c225b7b],
+    [0xf9c9c8f3, 0x7b835265, 0xdb66d5aa, 0x99aa0200, 0xac91a02c, 0xc33a79bf, 0xd4f3d06e, 0x03152169],
+    [0xa7d3199c, 0x7afe8b7a, 0xfdfebbb8, 0xddc8f51b, 0x7486d58b, 0xb0597458, 0x9210be78, 0x0b61811a],
+    [0x533b6f78, 0x046d637a, 0xf7048f16, 0xb8ae48ac, 0xc5921878, 0xf7eba6a5, 0xad298daa, 0x203e000c],
+    [0x1bfa9146, 0x0757143d, 0xfda1112c, 0xba7ee386, 0x9f6c9655, 0x376672b6, 0x37c722d1, 0x1a44bf09],
+    [0x611d4daa, 0x002f59c5, 0x5a2106d7, 0xb8e0fde7, 0x1a1f56ac, 0x3500afec, 0xe08cb03d, 0x0376b4fa],
+    [0x63182185, 0x3d553ef3, 0x3214177f, 0xd6fc241d, 0x50fdfc32, 0x65a21712, 0xca1cad64, 0x00780af2],
+    [0x9bdc31d5, 0xe9d85707, 0xd5221c87, 0xb75dbe18, 0xfd72a8d9, 0xeb808bed, 0xb80c25bd, 0x10774d9a],
+    [0x19d3b6e8, 0xb56821fd, 0x29ca1d7f, 0x0d03f989, 0x4bd9490c, 0x04b1e03b, 0x006ea38b, 0x10dc6e9c],
+    [0x141cac16, 0x70067d00, 0x60e35961, 0xb21f75bb, 0x50392798, 0xb2c7645a, 0x38791518, 0x00544b83],
+    [0x33ee428c, 0x13bc5344, 0xb8fa8526, 0x52e105a3, 0x122789e3, 0x2e2e82eb, 0x5718386f, 0x222c0117],
+    [0xf608e3c5, 0x151a1430, 0x7f7e2b46, 0xb77f7bdb, 0x1b1e0f45, 0x59cfb881, 0xe9bc22b2, 0x2840d045],
+    [0x5860186b, 0x508e01fa, 0xc2990196, 0x04554574, 0x468c335b, 0x009c937e, 0x6eebe11a, 0x062752f8],
+    [0xaaf04746, 0x55a8e83e, 0x2a80bc0a, 0x1c9950c1, 0x20a478a7, 0x87adb87c, 0xc48205ac, 0x06041bda],
+    [0x51f5000d, 0x2b1dcbbf, 0x92f308d8, 0x2c7a2ae0, 0x8949b002, 0xff900a36, 0x36c422d1, 0x04a533f2],
+    [0xb2d05b2a, 0x4bde50a2, 0x7dc33df0, 0xfe066d1e, 0xb3d4f25d, 0x11d6a955, 0x67232fd8, 0x13e31d7a],
+    [0xb13920f1, 0x2f79905b, 0x8ff2c95d, 0x9279d164, 0x57e8599a, 0xfbc13d63, 0xae91eb4d, 0x011c2683],
+    [0x15aaf6ae, 0xa1ecaed0, 0x3e2c2bd0, 0xd56c928e, 0xe0b4cba5, 0x25b1a270, 0x46b85745, 0x0b0d2193],
+    [0x6b5f1364, 0xd84c7a72, 0x1ef9fd13, 0xb6508078, 0x38690209, 0x70291ee6, 0xdb9c6dc9, 0x14abdec8],
+    [0x610be106, 0x988d0376, 0x2ef47ced, 0x01eb1220, 0xd2664788, 0xfcd32aa3, 0xb26fdc28, 0x1a0b70b4],
+    [0x7278b607, 0x2704882e, 0xef99c4d1, 0x6401deb2, 0x804e7fe5, 0x7b6943f9, 0x1f96d130, 0x27854372],
+    [0x11d58259, 0xa36535e0, 0x25638d8b, 0x3f0738a3, 0xdbd1473f, 0x57866214, 0x4a9776cf, 0x16eb5949],
+    [0xcf8c644a, 0x41c3479d, 0xab6b7f8c, 0x9a9e53ee, 0xfa5524c6, 0x4f240088, 0xa81ffb44, 0x2567a658],
+    [0x40bb13d8, 0xb882ade8, 0x5a5715a6, 0xab78e021, 0xabd9cf77, 0xa7ab39f1, 0x151e9ad0, 0x29aa1d7c],
+    [0x99f2c984, 0xe206b91f, 0x9a85388c, 0x6a4f017f, 0xb3641500, 0xd4bbfce2, 0x3e60efe0, 0x15c09123],
+    [0x15f014cf, 0xeb679a81, 0xf1915f9f, 0xe7673ad5, 0x99558d77, 0x0882c2c9, 0xff858e5e, 0x16bd7d22],
+    [0x50042025, 0xffe67692, 0x668b8e08, 0xc0182d9b, 0xd6ef4074, 0xb2c2e13e, 0x0a07be0e, 0x02db5048],
+    [0x43b73119, 0x13ba8663, 0xbf7adb4c, 0x86330ef2, 0x9d6cdba1, 0x7b6806ec, 0xe6a3bc9f, 0x05e4a220],
+    [0xcbcf7a42, 0x104d37f1, 0x24d39fa4, 0xb5f70bc4, 0xee3b50e8, 0x98cbf2a5, 0xc30170bc, 0x1dda05eb],
+    [0xb0de8990, 0xcd301f22, 0x14d89ba5, 0x91da2144, 0xe3667f3c, 0xf645b6fe, 0x21888187, 0x0184bef7],
+    [0x341b78ec, 0xad1a6d64, 0x494e1577, 0x37414b84, 0xf62aef1c, 0x5f5e8276, 0xe6890006, 0x1498a307],
+    [0xd46bd49d, 0xfe33548a, 0xfab1f864, 0xcef737b8, 0xb9d2c3ea, 0xf4939800, 0xb31dacc4, 0x25f40f82],
+    [0xe5bb9650, 0xcb1ff31c, 0x4907bfbb, 0xe83056ce, 0xa30d2ea9, 0x3f6f5862, 0x67025194, 0x09d317cc],
+    [0xcf3149d0, 0x29b913b6, 0x467a86ab, 0xa41132cd, 0x4c1b3bd0, 0x3ba4ce4a, 0x86d979b2, 0x2f77d777],
+    [0x5f729bbf, 0x52f89e78, 0x63f254c1, 0x1bbd3369, 0x6fccc684, 0x73dc266b, 0x535a9f44, 0x0f53dafd],
+    [0xdeef2fa2, 0xde96de85, 0xc00baf16, 0x0e6976e1, 0xe17526fa, 0x65c3a099, 0xe2230452, 0x25c1fd72],
+    [0xce4a8f62, 0x893e65d6, 0x4eaea3cf, 0x41af95c8, 0xd52d16be, 0xe368d385, 0x80c17faa, 0x2a902c89],
+    [0x62f83529, 0x55274057, 0x4d1dc8d2, 0x6676dd11, 0x76b82be9, 0x02878c89, 0x3452ecf3, 0x1ce1580a],
+    [0x0f4d0056, 0x2fc50f7f, 0x9609034d, 0x01c5ec56, 0x06df0088, 0xa49a1fa3, 0x91addc33, 0x24a6073f],
+    [0xb0be2b74, 0x7f256c68, 0x54b5d157, 0x83e07ca5, 0x06d71d45, 0x9fc27fe3, 0x6124530d, 0x25e52dbd],
+    [0xcd70f15d, 0x6796e5b6, 0x0a7b2994, 0x5974be4d, 0xcfb02985, 0x93468dbc, 0xc423fa7a, 0x23dffae3],
+    [0x924ed6f5, 0x99591bc9, 0xbe36243a, 0x80615d50, 0xf6b027c4, 0x49b77594, 0x70cc0d8c, 0x06342da3],
+    [0xe9f63925, 0xcc7df0d8, 0x0405c1b4, 0x4778303d, 0x15fc751b, 0xb75f09f1, 0x81286546, 0x27541142],
+    [0xf8187cf5, 0xb59ee197, 0x3833d7bd, 0xabf21415, 0xd119edde, 0x862c2bc1, 0x34c5c1a8, 0x15c19e85],
+    [0xd77d5f3e, 0x79b4b3d2, 0xa8210616, 0x366f3be0, 0x9ef3cabe, 0xb4c78d0d, 0x766d08fa, 0x265fe062],
+    [0x8d3ec7be, 0x8debfd09, 0xd0146f04, 0xd377ac5c, 0x0ac3a327, 0xf22cb7cd, 0xd67a3ec9, 0x13ccf689],
+    [0x524aaebd, 0x9fbccca4, 0xbdf3fe6b, 0xd92a5e05, 0x4827a887, 0xf81cd397, 0x56789739, 0x17662f74],
+    [0x4be7ad5d, 0xe809fd62, 0xca70cee4, 0x82ca6a5c, 0x515f7f2f, 0xef18631e, 0x329b31c8, 0x21b29c76],
+    [0x01fa975c, 0x939eb17b, 0x65215319, 0x9c067381, 0xe2790198, 0x441eb97f, 0x382aadba, 0x18137478],
+    [0x68f37e88, 0x39ceec46, 0x35ffd3b7, 0xd34f7619, 0xef2b37c2, 0xdc724f5f, 0xbfad68e8, 0x2bc07ea2],
+    [0xaef9a03e, 0x0e602077, 0xc2bd94ad, 0xb4173203, 0x0df993fe, 0x56384048, 0x6f54d64a, 0x2ddb2e37],
+    [0x3596c3f7, 0x8adb2537, 0x72f61370, 0xe8a20f8d, 0x4c602609, 0x06b41cb2, 0x2baa7061, 0x277eb50f],
+    [0x4c9a1fe5, 0xbb7f8773, 0x50c0db50, 0xb33fc4b4, 0x04f01a56, 0x9d0c6209, 0x1aba3426, 0x0d4de47e],
+    [0x79a29f0c, 0xae908d02, 0x058f134a, 0x9f445697, 0xbd3eea6f, 0x428673b6, 0xe9e4a1b4, 0x0b8442bf],
+    [0xb720f8f5, 0x74247fdd, 0x5945e965, 0x26e186a6, 0xb89f7d4a, 0x6e06930c, 0xfbbea1a8, 0x11fe5b18],
+    [0x9c33a0d6, 0x170e4ad8, 0xcad4d883, 0xdf5b774d, 0xd9f90021, 0x4d25d8f6, 0xdfaf71e2, 0x224026f6],
+    [0x2bbeb824, 0x1bc9f9c6, 0x7d1091f6, 0xa96bc9e3, 0x58d03465, 0xe0704dad, 0x99fe6887, 0x0b2ca6a9],
+    [0x6529f421, 0xa1a7e0c9, 0x1d364797, 0x1d0a4ce4, 0x3a28a06b, 0xd40c5405, 0x6f0b45f9, 0x221b63d6],
+    [0x6bb84ad4, 0xdce2f483, 0x4d4d24ae, 0x7493bce6, 0x1b047d08, 0x3d412080, 0xb7b2f1d5, 0x30185c48],
+    [0x632a61f0, 0xf8267318, 0xfaa48f27, 0x533356f0, 0x056227d3, 0xa989e223, 0xa3f0e3cb, 0x23f5d372],
+    [0x328f3e3b, 0x8e6dfbe4, 0x0d06162e, 0x88e1e009, 0xa162b1f3, 0x1bf8235e, 0x32c755fd, 0x2716683b],
+    [0x48d5d4bc, 0xc930c697, 0xc80ac67d, 0x3d140770, 0x3ec0909e, 0x04ca1d85, 0x36866fa2, 0x09775458],
+    [0xf9434b31, 0xe81c43c0, 0x31472b05, 0x5f51682d, 0x4982dd42, 0x025d91ab, 0x92bdbfd8, 0x1444e8f5],
+    [0x7718fbe3, 0xa00f874e, 0x583f7012, 0xbe3ffbfe, 0x5cb8fee8, 0xbeb74a1c, 0xe9ca8270, 0x26e04b65],
+    [0xb1a914d2, 0xdf69816f, 0xebe29ad6, 0x00f48f4f, 0xcd9f8698, 0x34ee47a5, 0x860d11fe, 0x22a5c2fa],
+    [0x44c5c8d7, 0x9f7474dd, 0xa0964c62, 0x7ec338f3, 0x738f4273, 0x6afd672a, 0x907d8f5c, 0x174b54d9],
+    [0x07b39b87, 0xd56c8719, 0x7c8c8143, 0x8d2189b8, 0x694cf280, 0x1168fa66, 0xa45283f3, 0x1db1db8a],
+    [0x13d1bfd1, 0x387341d8, 0xcce0ab66, 0x6f65faf8, 0xb7dfde12, 0x9030b8c7, 0x46527e88, 0x1530bf0f],
+    [0x2bade457, 0x89330a2f, 0xc8f28148, 0x36ead9ed, 0xc8760e99, 0x9f01c1ce, 0x993229f5, 0x0b73f613],
+    [0x36bcf41e, 0x7bd2dc0f, 0xfc822778, 0x587ab977, 0x377f448d, 0x4552aaea, 0xfe216460, 0x29c25a22],
+    [0x7c875526, 0x77df57d7, 0x5dc272b3, 0x7abe8279, 0xc92cf407, 0x8503da66, 0xd1759bfb, 0x2b30d53e],
+    [0x16787cd2, 0xcf5f0a29, 0x5ede7227, 0x756c08c8, 0x359d53a2, 0x7b7b7e69, 0xb5702aab, 0x12f6d703],
+    [0x06364113, 0x1ffa9ac7, 0x1028d484, 0x55ad0107, 0x8837293a, 0x61a40a0b, 0x00afda3f, 0x2520e183],
+    [0x03b8b95f, 0xc68f09fa, 0x78277393, 0xac9bc592, 0x346fa967, 0xdda8ed4f, 0x860971ec, 0x1ec9daea],
+    [0x830ad725, 0x08aae24b, 0x70ed407c, 0x83bf5cbf, 0xbef8fe44, 0x432f5cd5, 0x78db2e2e, 0x0a99b3e1],
+    [0xc5778492, 0x317abad7, 0xc3c817a1, 0x07ee0aba, 0x01c2bbe4, 0x086b89b6, 0x3db6e39f, 0x07cda9e6],
+    [0x8f8fc3a3, 0x5d48aab3, 0x963203b3, 0x49bd8290, 0x91bb0adb, 0x52d571b1, 0x4f955e89, 0x08c9c65a],
+    [0x7bdd9c9e, 0x3801c9c1, 0x3f2719d3, 0x9af54a2a, 0xfbd709ed, 0x49590ddb, 0x1d5a67b3, 0x2737f8ce],
+    [0x627f7d6a, 0xa9f179ba, 0x0c129813, 0x909432bd, 0x2798e8b7, 0xd2877007, 0x5ff019f0, 0x1049a6c6],
+    [0x61daeddb, 0x60a51223, 0x4fdf64ee, 0xde886894, 0xeb27cecb, 0xc0ea5a9b, 0x8732c462, 0x18b4fe96],
+    [0x83885d19, 0xa4f74734, 0xcf11f1b2, 0xa6f478cf, 0xeefa8c02, 0x440b2eae, 0x22df49d2, 0x2ff2b6fd],
+    [0x5cef42ff, 0x8a1b352f, 0xcbd8dbd1, 0xe8be4057, 0x8f6bbcb3, 0xe56c789b, 0x928fe932, 0x2ec5f2f1],
+    [0x378e545e, 0x08c1d100, 0x7794ee3f, 0x424a4c6a, 0x5bf3426d, 0xe33ad9f7, 0xd8b92975, 0x265a5ecc],
+    [0xdfd4279c, 0x20517da1, 0xfcb366bf, 0x778e656c, 0x5ada0e68, 0x9d6242bb, 0xc0bde112, 0x2405eaa4],
+    [0xdbf60417, 0x76dd98a2, 0xd8b2d66b, 0xfdb51955, 0xcbbf2bc5, 0x88018004, 0xc194c42e, 0x094c97d8],
+    [0xc2afe0b8, 0x330c9625, 0x21e6a686, 0x508b7052, 0x605bf64d, 0x22b9979a, 0x3bb32c5c, 0x2c30d5f3],
+    [0x6fdb6cf5, 0x6aa2fc71, 0x3e87299e, 0x4886ea58, 0xdcb1622d, 0x25d01cc6, 0xf6241f68, 0x01a75666],
+    [0x7d382fcb, 0xf47bf2e8, 0xa66979fc, 0x6d359ab9, 0x1e87be7c, 0x4d12ac09, 0x398113ea, 0x0a3290e8],
+    [0x9aa0cc74, 0xecd21bf6, 0xfa0dfc75, 0xc31219d8, 0x425bb0d8, 0xfeb38461, 0xa36e268d, 0x154ade9c],
+    [0x5d028772, 0x13a4b509, 0xdc69d8dc, 0x99231ef5, 0xc6f22eee, 0x1b172d79, 0x25380c0b, 0x27aa8d3e],
+    [0xbd806461, 0x9d395bbc, 0x5afa1f54, 0x56bbdf48, 0xca6099d7, 0x1a8b2e3b, 0x6cab4830, 0x2cf4051e],
+    [0x84b23e71, 0xb0843d7f, 0x8afa5eeb, 0x5131feab, 0xdff9f201, 0x1d3f517d, 0x29f3c94b, 0x301e70f7],
+    [0xc91f83bc, 0x17a8d7a4, 0x113ae60d, 0x32dc4cef, 0x347ab023, 0x8b4d9620, 0xf812d25d, 0x298beb64],
+    [0x2e46aa95, 0xcf11a3f0, 0xb221680a, 0xd1c14a15, 0x1c3c471e, 0x4d03fd29, 0xa5f847f8, 0x1b362e72],
+    [0x1dc1c737, 0xbc1d9ba4, 0x8e1e5ebc, 0xaa1ef6e7, 0x999223d5, 0x75432902, 0x6110c0b3, 0x0dc8a214],
+    [0x922ff279, 0x08afa1eb, 0x72ddc03a, 0xcb21729a, 0x2cb69778, 0x05dc9309, 0x34ce5e1c, 0x0a48663b],
+    [0x881098ee, 0x545bb314, 0x3b702d74, 0x0fe46f14, 0x82f9e95f, 0x6096b64a, 0xb1cd8cdf, 0x0a87391f],
+    [0x0892fd5d, 0x82ba8a2a, 0xea9c29f3, 0x8826edd7, 0xe6ca362f, 0xf0512ff8, 0xf7c28975, 0x1b5b2946],
+    [0x33315b6b, 0xb4eac1f5, 0xb8a5b987, 0x173a8bbc, 0x9219bc6a, 0x47ebe223, 0x12ac241d, 0x01001cf5],
+    [0xd8358a32, 0xc72beb17, 0xfb5f5feb, 0x7ac093d3, 0x7693da72, 0xf704fa7d, 0x0f645db4, 0x2fd977c7],
+    [0x7793a9c4, 0x9be763a9, 0xc05444d9, 0x761d5355, 0x8164f39e, 0xc2d7cc68, 0x3fab4ad3, 0x23c0039a],
+    [0xf03fa25d, 0x9f27f22f, 0x435888e7, 0xaec356cf, 0x161eaac1, 0x2c9c0df6, 0xc6081c05, 0x19d43ee0],
+    [0xa1cefe59, 0x919f9d5c, 0x6d020830, 0x8bf29b64, 0x94a56302, 0xfddccffd, 0xf2e7ac1a, 0x2d9b10c2],
+    [0xf83e4267, 0xdae2f2b9, 0x166fc81c, 0x2799283e, 0x5a66f5ce, 0xc47e4aff, 0x2f2aa30e, 0x2457ca6c],
+    [0xa7c10b35, 0x044dfb54, 0x6ed6f0c3, 0x811ee867, 0x45094022, 0x58205924, 0xe85eda85, 0x0abc392f],
+    [0x0eac1eb0, 0x1d2c2bc3, 0x93acf310, 0x1161ac39, 0xf3ea54f3, 0x0cebcd37, 0xa549d1d4, 0x19d2cc5c],
+    [0x6e98c9c8, 0xa3d3ab54, 0x041ba644, 0x3ee0e4ec, 0xae13cd39, 0x08aafb26, 0x33ffa016, 0x0f97ae30],
+    [0xe88cfeca, 0x8a166496, 0xa4e168e8, 0xfa15537e, 0xf1d427a7, 0x260e404c, 0xd28b7fb8, 0x16dbc78f],
+    [0x66d5e07b, 0x18278203, 0x76f8ad3d, 0xef8344e5, 0x3bc4f22e, 0x16f085f7, 0xf11499b9, 0x240faf28],
+    [0x8d9ef1af, 0x46f8cab5, 0x8fdb6dbf, 0xeaba808c, 0xe55e1770, 0xfe6c8531, 0xaa37ff0c, 0x0a1bb075],
+    [0xce089f4d, 0xc4a705a7, 0xac1042fd, 0x38d5b085, 0xf3a644ca, 0xa6a853aa, 0xa4a47ff1, 0x2e47e15e],
+    [0x375772b6, 0x5fb14528, 0x935f4df3, 0x673ab059, 0xc09d39e1, 0x860ca4a9, 0x73378348, 0x166e5bf0],
+    [0x38f0916f, 0xed10f965, 0x27233001, 0x0cacccd0, 0xf057a274, 0xaf235902, 0xfdd2ea4f, 0x18b42d7f],
+    [0x51c37702, 0x21deab10, 0x0b3ed382, 0x4fc36802, 0x3e3c7ead, 0x4914788e, 0x32238f5e, 0x089cb1b0],
+    [0x451dd8d1, 0xd9e70863, 0x7b971921, 0x89f9339c, 0xdd165adf, 0xaf7c7076, 0xb3a2f72b, 0x242acd3e],
+    [0x57245c3b, 0x74af8604, 0x283f3264, 0xeac9a068, 0x82fce896, 0xbf47f2bd, 0x4a4ee302, 0x174fbb10],
+    [0xe1116c6b, 0x780c275f, 0xb318613f, 0x2891fb2b, 0xe092c67d, 0x61f3058c, 0xd96f466d, 0x17340e71],
+    [0x4df7f6b2, 0xa2fd380c, 0xfd455953, 0xf098b9f8, 0x3982d024, 0xf00f2e38, 0x853b7d42, 0x1e8e40ac],
+    [0x38e2e961, 0xbf40f929, 0xad66e8a9, 0x5198c55c, 0x84b8d107, 0xe1d4d5e2, 0xc0649907, 0x0529898d],
+    [0xd4e5df8f, 0xf65f21c4, 0x17ee1d7b, 0xe8c77aa0, 0x797364dc, 0xbf7de5bb, 0xb0baa030, 0x2162754d],
+    [0x41752ec6, 0x21bef447, 0xfbde4c84, 0xa9f9291e, 0xe00c58c2, 0x3ceb250a, 0x98c4bf6f, 0x12c75536],
+    [0x6526508e, 0x9cb72313, 0x53e9d9c7, 0xa733c933, 0x9313bd51, 0xfcb8c527, 0xba2026af, 0x292643e3],
+    [0x5b87d158, 0x1db6e74d, 0x98e66ff7, 0xb6c07c5d, 0xea990bd5, 0x1d52951b, 0x0cb6f9d8, 0x00ccf13e],
+    [0xab645b4b, 0xb0f86c15, 0xcb30fc22, 0xb6723873, 0xcf2f3aaa, 0xdd654128, 0xe23b0917, 0x185d1e20],
+    [0xd8764e1f, 0x13fe53f8, 0x0f024c0f, 0x6778e3de, 0xc60efa18, 0x742bdf11, 0x6d55d3df, 0x14c61c83],
+    [0x5d72449e, 0xd03ee119, 0x53008184, 0x2919e2af, 0x0457691c, 0xe5dbe468, 0xb3f556fc, 0x0f356841],
+    [0x8fa5b3e8, 0x0c0a6b6e, 0xfd2080ba, 0x83143374, 0x87bf40b3, 0x5df124f8, 0x39714e07, 0x1b8fd9ff],
+    [0x796f6024, 0xe9103418, 0x4e9df0b3, 0xfc3c8ae0, 0x4e2aaa14, 0xa3f87392, 0x009c140c, 0x0e86a8c2],
+    [0xe79155c8, 0xb0861421, 0x20ca2b16, 0x373fc438, 0x932fcdd2, 0x0e5462ad, 0x8f554777, 0x2e6c5e89],
+    [0x0c014604, 0x2ce5fd5a, 0xecd37797, 0xff9fe1a0, 0xdf032bc9, 0x7c14f9d1, 0xab364723, 0x05d797f1],
+    [0x1da8c008, 0xca892985, 0x65519ef5, 0x1daf2dcd, 0x75981d0c, 0x6c3d1528, 0x63a5aae7, 0x29a31104],
+    [0x63883314, 0x4b732f81, 0x8bbd1f86, 0xdc71640a, 0x1c05354c, 0x73c3a4b9, 0xc0743222, 0x2974da7b],
+    [0x889b47dc, 0xcce9c522, 0xa082c8bf, 0xa29cb91a, 0xc05eb12c, 0xb2a30621, 0x699ba249, 0x1ed0fb06],
+    [0x6e40fe9b, 0xd80c8ae3, 0x72eca912, 0xae29e8c5, 0x8d863fee, 0x654ff26d, 0xdcc51123, 0x1c793ef0],
+    [0x0977dc2f, 0xfbb4a877, 0x89a78169, 0x8c91e825, 0x3d234ef1, 0x7956257d, 0x6d3dd315, 0x1e6aac1c],
+    [0x56fb6bf5, 0x8fcda332, 0x80a47d94, 0xd0377480, 0xfa98b25e, 0xe6273dd6, 0x576234ee, 0x1a20ada7],
+    [0x728af96c, 0x35d49306, 0x45ece513, 0x642d7720, 0xa6fd9996, 0xfc7a9a23, 0xd85ceaa6, 0x191033d6],
+    [0x5d06297b, 0x32ef481f, 0x3740b8b2, 0xc76f200b, 0xfddc3abf, 0x3a825aa6, 0xda7e7ef5, 0x006e5979],
+    [0x74cdb06d, 0x1eff8c01, 0x6c8f2983, 0xfbd57f59, 0x417e9fa0, 0xbef3e68d, 0xc651910b, 0x0b0d7e69],
+    [0x5c9cdf9d, 0x2c4b20a2, 0xb033c511, 0x4ac46dbb, 0x84e2ecd4, 0x16435ec0, 0xc1b93bc5, 0x25caf5b0],
+    [0x0f72472a, 0x085b2f15, 0xd62fd4c8, 0xf7f77442, 0xd9645872, 0x9af8b796, 0x2cc31e0d, 0x12c1ea89],
+    [0x78c32aae, 0x1de6dadc, 0xf928b9b8, 0xe5a929d9, 0xeb245fee, 0xb8bbe3af, 0x5157aba9, 0x16af2969],
+    [0x256b67dc, 0x68d31084, 0x5a4cfdc1, 0x705b87ec, 0x3be18691, 0xd687fb2f, 0x7c80588d, 0x0136df45],
+    [0x60e7b089, 0xb95a2850, 0xbc74434d, 0x9e07b1ef, 0xba6e7147, 0x6aea984f, 0x5b4c8116, 0x1639a28c],
+    [0xb5ca9b76, 0x7e232bd9, 0x00bdc50f, 0x816c28b7, 0xf587ec06, 0x13f8e650, 0x82fd1d43, 0x03d62fbf],
+    [0x1edfde54, 0x249830de, 0xfc6da97c, 0xf77a1e40, 0xdca3cfe2, 0xb4d14aad, 0x7dc8ce44, 0x11aeeb52],
+    [0x07bfc824, 0x642b6458, 0xc68c7a49, 0x6a670e6b, 0x8c6c8ee3, 0x79c5e613, 0x12741294, 0x13f9b9a4],
+    [0x7ebcd15b, 0x506cae8b, 0xa939440c, 0x5ddeeed7, 0x6c7c1f63, 0xc8484cd2, 0x3d75179d, 0x0e4772fa],
+    [0x8c5d4db4, 0x39fc46a6, 0x067a612b, 0xb5971752, 0x8febe8d8, 0xde4bdec5, 0xbc81e427, 0x1b39a00c],
+    [0x3a25707e, 0x444d1c0a, 0xeb54a245, 0xf66463c2, 0x53f48731, 0x71e16e29, 0x1ad5a1d5, 0x2bedb66e],
+    [0xda915dec, 0x7379ce35, 0x608582a2, 0xb08b193b, 0x06a7287f, 0x8abd068f, 0x55ca93af, 0x2cf0a09a],
+    [0x63efb387, 0x753c8fb8, 0x50ba7db0, 0x7d1a5120, 0xfef2f8d2, 0x88830cab, 0xa90e77aa, 0x2d1bd78f],
+    [0x83dc3394, 0x630d7fd2, 0x1387062e, 0xf7c0d49c, 0x1eb83539, 0xf423d307, 0xf4f92491, 0x065610c6],
+    [0xbd607368, 0x642fb464, 0x033f15ec, 0xcc5f9969, 0x73452beb, 0x5013b128, 0x9217a554, 0x2d933ff1],
+    [0x99e0258b, 0x3c49c8aa, 0x4e79508c, 0x00dae535, 0xe13b30d5, 0xf76b92b3, 0x4c644910, 0x1aa9d3fe],
+    [0xc8450bdd, 0x78cea1f1, 0x73e1aca0, 0x27095fa7, 0x59111c6b, 0xc748638c, 0x69e482b1, 0x027ef048],
+    [0x6ad0b8fb, 0x02e3fa13, 0x5d9ec038, 0x9f67a260, 0x668a8c44, 0x15db4e00, 0x5172cbbb, 0x2b7d524c],
+    [0xd735db0f, 0x3f7c3c1d, 0xb1e55df1, 0x4693ae25, 0x6747c7f7, 0x7c8718d8, 0x43c6aa78, 0x0c7c3824],
+    [0x9bd79078, 0xa627dcdd, 0xd30d0fe4, 0x7a1f43c2, 0xcf4f7620, 0x62a7b56a, 0x86bc3f7c, 0x00b45671],
+    [0x83db4fd5, 0x03374908, 0xe4c1e61d, 0xb07fe739, 0xfe08b47f, 0xe6d61737, 0xb825454f, 0x1e41fc29],
+    [0xba0653b6, 0x002ae8d3, 0x2d8c0e89, 0x21e1af87, 0xc6165844, 0x72ee6daf, 0x56b7bbcc, 0x12507cd5],
+    [0xf71cb5db, 0xd77d3e97, 0x7ef36fe4, 0x97eb3661, 0xe6f52a5d, 0xcef312e5, 0x3553006b, 0x13d43708],
+    [0x6a4486d5, 0x4686077c, 0x2f0b3866, 0x467d90b2, 0x7dda9a65, 0x68722248, 0x51f85443, 0x163ec732],
+];
+
+/// The MDS matrix for `t = 4`, row by row.
+#[rustfmt::skip]
+pub(super) const M4: [[u32; 8]; 16] = [
+    [0xae5108ad, 0x87947223, 0x296127fd, 0xe5e39942, 0x86dd7a1d, 0x8a351dd7, 0x3ef85cc4, 0x236d1339],
+    [0xb2a7ae19, 0x3cedc821, 0x8718e59e, 0x967f1dc5, 0xe10724eb, 0xc4a9b194, 0x4f7644bb, 0x27768649],
+    [0x66b09c62, 0x84a4529e, 0x79973b0a, 0x5129c164, 0x26a9b350, 0x0b856188, 0x84e3f0cc, 0x023db687],
+    [0x6ff382c1, 0x7b3a7564, 0xd63017c5, 0x8af08cdb, 0xae733f97, 0xd50d663b, 0x5f286c12, 0x1d359d24],
+    [0x0e841a0c, 0xf049bc97, 0x1f70943f, 0xfe9bc7fb, 0x9699ab28, 0xb525be25, 0x563b807d, 0x2a75a171],
+    [0x57b6770e, 0x6f38ce41, 0x15ccc370, 0x08b4dd3e, 0x092e1ae8, 0x78e2827d, 0xe10051f0, 0x083abff5],
+    [0x53a1eb6f, 0x68a9ff82, 0x1eab8b75, 0x24d5c474, 0xbae303ad, 0x7dc49cfd, 0xbbecd8a9, 0x1a5ad71b],
+    [0x5d84f0af, 0x790f725c, 0xbc2c59e8, 0x945004a7, 0x640f02ce, 0x86772133, 0x0dd167fb, 0x0d745fd0],
+    [0x21c4ed42, 0xf366b3e5, 0xcbaa7e42, 0x497ad2ee, 0x9cef820d, 0x592a52ca, 0x798782ef, 0x2070679e],
+    [0x9e2d7eab, 0xb3a2be97, 0xcd224ab6, 0x06ece318, 0x53da75d9, 0xf800739a, 0x0d20bf5d, 0x2e18c857],
+    [0xa723b608, 0xfa283c6a, 0x3e5b9f38, 0xf2e4386d, 0x86f684f1, 0x7f3367ce, 0x27e4d3dd, 0x0fa86f0f],
+    [0xe0b403eb, 0x3f0c2491, 0xda6b2ca8, 0x57035ee3, 0x14dbaeb6, 0x28168e4b, 0xb791f166, 0x03f3e6fa],
+    [0x958e7677, 0xba8b3d30, 0xd79375f8, 0x8ff0613f, 0x41f783b6, 0x2488540e, 0x8202c973, 0x2f545e57],
+    [0x3d01476e, 0x596a1562, 0xba4cd701, 0xb8104c32, 0xae3faf4b, 0xbff7eefe, 0x2877fc19, 0x23810bf8],
+    [0x2a34cdd6, 0x207ed58d, 0x30f10be2, 0x1c068ef9, 0x4034cf32, 0xeeafc494, 0xb0be6d5b, 0x014fcd5e],
+    [0xd20fb52a, 0xbb661c25, 0x27627cc2, 0x8ba4a8b6, 0x823e377f, 0xd835eae0, 0xa1d5733d, 0x00c15fc3],
+];
+
+/// The round constants for `t = 5`, with 60 partial rounds.
+#[rustfmt::skip]
+pub(super) const C5: [[u32; 8]; 340] = [
+    [0x1a6da891, 0x4f3c2bd8, 0xbd47c386, 0xd889bb4e, 0xcac98ed7, 0x7f53e29c, 0xe2815dda, 0x0eb544fe],
+    [0x475128cb, 0xba733f28, 0x2ea64713, 0xa197aeb1, 0xdd737fbc, 0xf02fdba7, 0x315b8662, 0x0554d736],
+    [0xd5e8c2d4, 0xf5087c58, 0xc0fb0035, 0x54907df0, 0x6307c377, 0xbcd74805, 0x259b2b68, 0x2f83b9df],
+    [0x5aba9683, 0x1915208f, 0x41a75ef3, 0x61f15f8b, 0x052451b4, 0x2447ac83, 0x8d7f39a1, 0x2ca70e2e],
+    [0x83ecfea9, 0x4ec719cb, 0x2ed22c5d, 0x994196f1, 0x22271c94, 0x91b04d72, 0x9be6a45e, 0x1cb5f931],
+    [0x8446c122, 0xb9ea08d1, 0xb93750c2, 0x21c7bb47, 0xde7ff616, 0xf8a42192, 0x69f966eb, 0x2eb4f99c],
+    [0xe299520e, 0xd5f7d099, 0x93ee13b6, 0x0fc7da8b, 0xe405d9ea, 0xc5198169, 0xa35385a7, 0x224a28e5],
+    [0x3bbd82f7, 0x23796b99, 0xd9a0f978, 0x36f33ecb, 0xfca49c30, 0xd8afdd6a, 0x65e600ee, 0x0f7411b4],
+    [0x5d90e4e3, 0x0626f9ff, 0x3370f99a, 0x19b208ae, 0x392d8d98, 0xa2be7150, 0xad2c9555, 0x0f9d0d5a],
+    [0x02d6fba0, 0x6a12d307, 0x59cf744b, 0x9732b252, 0x38d32922, 0x6f52a595, 0x8292bb59, 0x1e9a96dc],
+    [0x8fb3fe3c, 0xc2cd4d52, 0xb4b945c6, 0x3cfe52ea, 0x45555e59, 0x887d578c, 0xccd90380, 0x08780514],
+    [0x29ccb5fc, 0x59f119d6, 0xe3556d5a, 0xced64717, 0xf73ef8c2, 0xc8149fa3, 0xed686c7a, 0x272498fc],
+    [0x782ef7df, 0xe3d0ef8a, 0xff585f10, 0x45bd350a, 0x30bd06eb, 0x4b7cb809, 0xd7c93aac, 0x01ef8f9d],
+    [0x54934d30, 0x97ea0ae7, 0xbf3c40aa, 0x8e886e64, 0x2b469b13, 0x4dc08f22, 0xb6595e61, 0x045b9f59],
+    [0x95d61c66, 0xadf25842, 0xe247a387, 0xa3e41e24, 0x2a40ff8e, 0x9fd6f59d, 0x57d9da91, 0x0ac1e91c],
+    [0x4e62d134, 0xd12e68f0, 0xaee99979, 0x0fd67061, 0x353cd89d, 0xc7f9a421, 0xa94054b0, 0x028a1621],
+    [0x0dcd9dbc, 0xd02aae2a, 0xb3c96d09, 0x50c19c3f, 0xd059236e, 0x9632647e, 0xc071ea4c, 0x26b41802],
+    [0xaedb462b, 0x4af34384, 0x758db6a9, 0x05c9de06, 0xe468215e, 0xbaac2f63, 0x072bb72c, 0x2fb5dda8],
+    [0xe894d850, 0xdd05c56e, 0x2a18d383, 0xad8ab8ba, 0xfd823249, 0x44ff3547, 0xf5fccaf2, 0x2212d3a0],
+    [0xd465e2e3, 0x5cd0c7ee, 0x19f44c01, 0xa3276fdb, 0xea09be56, 0x58e4dfae, 0xb2f06842, 0x1b041ad5],
+    [0xb1500eff, 0xa91e64af, 0xac14e846, 0x144fb7e3, 0xe76fded3, 0x8eccff33, 0xb22f4b6b, 0x0a01776b],
+    [0x810ace43, 0xf7936440, 0xc15cf827, 0xa4f33ae8, 0x5066d549, 0xf34d3f27, 0xaaecc3cb, 0x2b7b5674],
+    [0x81a3bdfd, 0x4c1b8033, 0x7b78fc00, 0x60b04225, 0xed54b48c, 0x4cf75779, 0x0cd4489e, 0x29d299b8],
+    [0x24be401e, 0x19dde304, 0x32b5e1dd, 0xa4271100, 0x9d721a74, 0x57641c21, 0x9a745293, 0x1c46831d],
+    [0x83e91269, 0x739ae1d8, 0x57674f80, 0x36296c06, 0x34d578e0, 0xf37141dc, 0x953ccb72, 0x06d7626c],
+    [0xcda2930c, 0x0f108c71, 0x3022cd96, 0xdc5c440a, 0x8e0c410e, 0xc5400274, 0x6f18c136, 0x28ffddc8],
+    [0xf6fbc5e9, 0xb8ec0619, 0xed2ab6ad, 0xbe67f1b7, 0x9e400b17, 0xf85deed0, 0x5e4aa295, 0x2e67f7ee],
+    [0x24e80f27, 0xe7abf22c, 0xf759e53c, 0xca56859e, 0x14a79a2d, 0x0e97f251, 0x636c9063, 0x26ce38fa],
+    [0xcfd35db1, 0xafacb4c4, 0xa1f72721, 0x42cb3d16, 0x00a7ffec, 0x4dd7a01d, 0xc95bf7c3, 0x2e6e07c3],
+    [0xda24d8cc, 0x384b1470, 0x612e1246, 0x8890d276, 0x1c3a54fb, 0x5f91d796, 0x97f0c9f4, 0x2aa74f75],
+    [0x9b7f73fe, 0x2ec0ca63, 0x1509183e, 0x8a71313c, 0x668ab45b, 0x2c7c090f, 0x46a2faae, 0x287d681a],
+    [0x05e73c04, 0x11e29f33, 0xf565aa3b, 0xa5d3106f, 0x528f3d7d, 0xf4a40600, 0xf812eaae, 0x212bd19d],
+    [0x04713504, 0x3809c284, 0x0926dab9, 0xf97fd974, 0x350eb860, 0x1aafb14b, 0x519186bf, 0x1154f7cf],
+    [0x661fc207, 0xda747e79, 0x5be36583, 0xbf515290, 0x4bd1b16f, 0x4637810a, 0xcb31f1c2, 0x1dff6385],
+    [0x6b551e5c, 0x63ea3c60, 0x54762528, 0x4011a34d, 0x44c18e42, 0xc081d34c, 0xd22b4e76, 0x0e444582],
+    [0xb7e4eff8, 0xff72d3aa, 0xc2846323, 0x815773e9, 0x328f02f1, 0xabab6638, 0x33ba66c4, 0x0323c9e4],
+    [0x76a9c29d, 0xb70f2c68, 0x2740112d, 0x25b8cf00, 0xcdec448f, 0x193bba79, 0x71791059, 0x12746bbd],
+    [0x438a2240, 0xfd049eb4, 0xcf50d73e, 0xd466c837, 0x51842c75, 0xfd9b9d37, 0x12c2a798, 0x1173b7d1],
+    [0x703243da, 0x5fdb4808, 0x25026ebe, 0xa8e5713b, 0xd7fed13d, 0x76d1e555, 0x90a1ad48, 0x13d51c10],
+    [0xb956baa1, 0xf4a58ebe, 0x0394efe7, 0xcb72743f, 0xbd2d9743, 0xff8dcb7c, 0x44a4ad51, 0x00874c13],
+    [0x74fef08f, 0xa32a5620, 0x546e97d6, 0x0eea48d3, 0x07f244fa, 0x65ce236b, 0x1aaab858, 0x22df2213],
+    [0xa919f155, 0x8bf5eb05, 0x9101e6c1, 0x984524a5, 0xa445fc3e, 0x8708b437, 0xdbd25b90, 0x0bf964d2],
+    [0xfc502f32, 0x69b435b5, 0xa9ab298c, 0x640b9d73, 0x7f181e0e, 0xa302be1f, 0x917a55bc, 0x09b18d9b],
+    [0x67289e43, 0xd6745a50, 0x6365366d, 0xfbbbc70a, 0xbf3dc05b, 0xa4bfc1d5, 0x444fae36, 0x094f5534],
+    [0xdc23f01f, 0x784fddc0, 0xda5701cb, 0xa3e240c0, 0x2af53a15, 0x519fa662, 0xa5f25210, 0x2999bab1],
+    [0xb03a3d23, 0x69575a11, 0x8a93d136, 0x84301bce, 0x3710e880, 0x1ca94db7, 0x7581f637, 0x2f6898c0],
+    [0x6866fccb, 0x62174b1a, 0x8e5742f8, 0x565deb1e, 0x318a4740, 0xec16d7e1, 0xba08bc19, 0x07268eaa],
+    [0x41a0529f, 0xa6c9582c, 0x8e1c6689, 0x62603e07, 0x113bc9eb, 0x1339ff77, 0x03454db0, 0x186279b0],
+    [0x4737172c, 0x0768e552, 0xde9a2275, 0xb67e2cc5, 0x04d3e5dd, 0xe4915bdd, 0x509197d6, 0x18a3f736],
+    [0x7085671d, 0x7b5987b8, 0xcb2d3a00, 0x725e2d4b, 0xd24c808c, 0x77cc1e2e, 0x88cf38d8, 0x0a21fa19],
+    [0xe785fba2, 0x0b36a135, 0xa2c43bc0, 0x8328c184, 0xa6462522, 0x1faf5ef6, 0xe26c467f, 0x15b285cb],
+    [0xe779f161, 0x8cd28de3, 0xc902f578, 0x0b7775b7, 0xf9806d56, 0x8c08b8c3, 0xc4671cf0, 0x164b7062],
+    [0x249baa70, 0x7a24f651, 0x43b6e65d, 0x61c61d3d, 0xe7e50ef3, 0x86d9865f, 0x19ac0a6f, 0x0890ba08],
+    [0xd543e922, 0xcc2ee0ae, 0xb0eb878c, 0xa627ac5c, 0xa721e4ea, 0xa42712e5, 0x5d7ed425, 0x2fbea4d6],
+    [0x379abaaa, 0x103d7f5f, 0x854ab9b9, 0x7b70a58e, 0xb536f85e, 0x540303a3, 0x3c36fa55, 0x0492bf38],
+    [0x292dc24e, 0x6a969d56, 0xce85675f, 0x1d6185a9, 0x565142d6, 0x4e20251c, 0x44e94410, 0x05e91fe9],
+    [0xe36e6c1c, 0x7e380a76, 0xe6e49c3b, 0x995b9621, 0x041acad0, 0x93d463cb, 0x29e4b338, 0x12fe5c20],
+    [0x6fa0784e, 0x06cd01dc, 0xdc894969, 0x1f2629fa, 0x92147413, 0x958f7723, 0xf0255d47, 0x024154ad],
+    [0x57a45550, 0x8813c134, 0x44f2bbde, 0xd7988156, 0xa86bd0ba, 0x36ed2462, 0xe6afaf4a, 0x18824a09],
+    [0x3d5d58a5, 0xbb06983e, 0xaf68d50b, 0xdf84a630, 0x0c3dbddd, 0xe9f255de, 0xba0ad51b, 0x0c8b482d],
+    [0x8fdb05e6, 0x44139287, 0xcd6aaf86, 0x5a4fa67f, 0x67d3b67c, 0x363e0a16, 0xab635871, 0x17325fd0],
+    [0x11fa402e, 0x1cc6d0e9, 0xa8d18bf7, 0xe550773f, 0x7b690f31, 0x122f5af6, 0x6d2f1519, 0x050ae95f],
+    [0xedc50c40, 0x5771ec84, 0xf0756988, 0x4bbb6295, 0xd6263676, 0x038cb288, 0x0e81e943, 0x0f0d139a],
+    [0x8e747cd6, 0x9dbbca7b, 0x7a1b1c54, 0x79b39ebc, 0xc0f93d1a, 0xf70fd2f2, 0x795689cd, 0x1c0f8697],
+    [0xdc1d81e6, 0xfe536a16, 0xb13598f9, 0x49be23a4, 0x48bc979e, 0x6d2bc2e0, 0xad936b79, 0x2bd0f940],
+    [0x96ee62cb, 0x7ce54d1e, 0x5e096d16, 0xa06ebb27, 0x0053337f, 0x4778c09a, 0x7c9c4e93, 0x27eb1be2],
+    [0x548e2aea, 0xf151be62, 0x7d1f71b0, 0x284fbd30, 0x155a7ca3, 0x8f96bdd3, 0x30a67e5a, 0x2e4889d8],
+    [0xfcc1e305, 0x6064bbe6, 0xadc165db, 0xc9891f2c, 0xc5bd9983, 0x5d2ec5e9, 0x0ab47d3c, 0x193fe3db],
+    [0x7c83e525, 0x37cb1302, 0x09679e4e, 0x6e9661c0, 0xad0c40ed, 0xce415907, 0x96c36c7b, 0x2bf3086e],
+    [0x6e59bb28, 0x9ff2ea48, 0xf741ccf2, 0xd5e7e413, 0x7c6cad5d, 0xa98cdb69, 0xe6d4ad46, 0x12f16e2d],
+    [0x43b92d2f, 0xad919b03, 0xec6c4bf0, 0x33f3d5d6, 0x53ddd19f, 0xa0262e36, 0x230119f3, 0x2a72147d],
+    [0xc10ba974, 0x77df79ac, 0x26412e29, 0x0a2d9bcc, 0x7806dc5f, 0x6dc47f95, 0x4bfd64e5, 0x21be0e2c],
+    [0xc21b444a, 0x137fd4b6, 0x11aa57ae, 0x71b84fb9, 0x4367b25a, 0x2749a3b5, 0xc946d70b, 0x0e2d7e1d],
+    [0xb92b3e2e, 0x9f3f07d4, 0xae3325dc, 0xc31adb0e, 0xd8a4188c, 0x6170a745, 0x5a4fa124, 0x2667f7fb],
+    [0xa78e3405, 0x830e107d, 0x08dfeb72, 0x1550c12b, 0x6064697a, 0x30a783b6, 0x1fb74007, 0x2ccc6f43],
+    [0xe38e6e5d, 0x2ec50621, 0xe8ca0c24, 0xae6dbee9, 0x2420001f, 0x4f020146, 0xfc5a2ca3, 0x08888a94],
+    [0xcbc53f2a, 0x6acfcd3c, 0x53af88b3, 0xa0d2fbe7, 0xc9b6fdd7, 0xad40dd42, 0xeeaa3cb6, 0x02977b34],
+    [0x71e16b9a, 0x10b5e9f9, 0xe6ff8e96, 0xbfcfe0d7, 0xea13a648, 0xfd6fb6c9, 0x3d28b75c, 0x120ccce1],
+    [0x2f86ff3c, 0xa0bf2af3, 0x75b2bd73, 0x92787a45, 0x70ea098c, 0xc81e1b97, 0x9c4a8e93, 0x09fad226],
+    [0x60092d6f, 0xf630cffb, 0x775eeb8a, 0x0debdb70, 0x4ac6f0fa, 0x0a4b310e, 0x3d4c44d5, 0x026091fd],
+    [0xca893994, 0xb904fd2b, 0xafad6afc, 0x2543cc56, 0xfb6fc321, 0xbb7fba9d, 0xba565b77, 0x29404aa2],
+    [0xb7c883f9, 0xe7201351, 0x90e0827d, 0xf1ffd865, 0x48695b4e, 0xd4e87c25, 0x399aaf39, 0x2749475c],
+    [0x65ffa222, 0xc7557dab, 0x2e4da70a, 0xa2ebe2dc, 0x424685cb, 0x39912b50, 0x22479f72, 0x098c8423],
+    [0x53b51936, 0x0191d0c0, 0xc93c4da4, 0x758ace14, 0xfead7d5c, 0x31238e57, 0x222b647e, 0x18cef581],
+    [0x1698de60, 0x63b2aa68, 0xa108f98d, 0x3cbc0ca4, 0x5e43711d, 0xd4e74674, 0xc68a5080, 0x13177839],
+    [0x5f0deffd, 0xa907d88e, 0x12c0e5f0, 0x26cc4df7, 0x4b743256, 0xc088f56f, 0xf531e43e, 0x020ca696],
+    [0x7c7ac022, 0x16e02838, 0xc646841d, 0x93d13708, 0xfc548db6, 0xfa805a30, 0xe9cccfc9, 0x27230eed],
+    [0x75bb281c, 0x9057d2fe, 0x69a01543, 0x6497c059, 0xa342a178, 0xd64fde34, 0xc1198b01, 0x01645911],
+    [0x1a5d8643, 0xfdca4a45, 0x4487056c, 0x198971e1, 0x341ce25f, 0x6e439c88, 0x6481bf49, 0x2c323fe1],
+    [0xdb5085c8, 0x5af88ae6, 0xd3bffe8b, 0xb022c124, 0x4c3e22e1, 0x450bd207, 0xe70728e8, 0x0fc082df],
+    [0x423521ee, 0xfe8a488b, 0x6ac4c77e, 0xbe964211, 0x8dcc25b3, 0xd8cdca56, 0x800db209, 0x2052c174],
+    [0xb3ece9c0, 0x8dd9fd05, 0x8129065a, 0x0be351ce, 0xd5542319, 0xaf96d621, 0x0df2fbb5, 0x28e420e1],
+    [0x3ae5069a, 0x49d6e0bc, 0xdacd1cb5, 0x55d6ae1b, 0x62a24db6, 0x9f783c44, 0xe24a1b79, 0x25698ca5],
+    [0xd6984d35, 0x34d9b857, 0x74422ac1, 0xa297b610, 0x57d51049, 0xcf8ffbfa, 0xa5c89a57, 0x160a9981],
+    [0x9997f3d4, 0x64642b06, 0x9ddbb0a0, 0x8b7cebf5, 0xb843f3bf, 0x34d9b694, 0xe145c3bc, 0x21c91a39],
+    [0x1e41734c, 0xd1097546, 0xd93f03b6, 0xd6eaa029, 0xef112345, 0xd2b09345, 0xcd5ee876, 0x1ac8d80d],
+    [0x657ce02c, 0xb8dbcea5, 0xe2755544, 0x5d822895, 0x174c5222, 0x7c1662a4, 0x0ecf8b8e, 0x0ab3e6ad],
+    [0xbdab3377, 0xc5b2237c, 0x5909b4e1, 0xa52ef3ef, 0x17b3a21c, 0x27e3b0b9, 0x512620ae, 0x1c675182],
+    [0xac2bed45, 0x0c8a9092, 0x07a7d75d, 0xe37a4a3e, 0x85bad2e2, 0x3d948d0c, 0xdfd7affd, 0x2cdbc998],
+    [0x3bb63b2e, 0x3e491a13, 0x309dff83, 0x24337350, 0xc0dee333, 0x774bf67c, 0x6e2117b0, 0x23b584a5],
+    [0x1465e9a5, 0x2f3d20de, 0xba4ec115, 0x0d133bc6, 0xa3c9d2a1, 0x8cb73030, 0x0f60ba9f, 0x1e9e2b31],
+    [0xfa5b22db, 0x60bcd4f1, 0x987520c4, 0xc9ab5dab, 0x140ae746, 0xabc3e720, 0xba5b3031, 0x0e01e365],
+    [0xc1264e68, 0x1387d85b, 0xb61c9a4b, 0x43382011, 0x0498d5c4, 0x7b712734, 0xcfc64bfc, 0x040884cd],
+    [0x11ef9668, 0xa0a17de3, 0xd6067ed0, 0x353f1724, 0x8f2bea36, 0x0c74a399, 0x205eb950, 0x190b1ee1],
+    [0x32bd3b2c, 0xfc28d1f8, 0xce61e165, 0xc1dfcf65, 0x23cd9c08, 0xd04f52fc, 0xec6c4388, 0x1647c72a],
+    [0x71e4541d, 0x842e595b, 0x9fb48b02, 0x9f5494d8, 0x4c873626, 0x799880cc, 0x46a0145f, 0x24300063],
+    [0x52b757af, 0x32218502, 0xbb16bacb, 0x9d853902, 0xda3ae7f6, 0x365107a3, 0x343917e1, 0x177b9a08],
+    [0x666df4f8, 0x39b11d04, 0xae294234, 0x09cd53d0, 0x68f5e326, 0x4e58862a, 0x42b11ae9, 0x04a420e6],
+    [0xb6a25c8f, 0xcdfabedd, 0x8e956ccc, 0xde246185, 0xb0afd810, 0x105a88fa, 0x39fb39fc, 0x25d0e0f7],
+    [0x0c740a27, 0x2c4d4ffa, 0x10eaed45, 0x320cb156, 0x58651edc, 0x85905cbf, 0xb7eff2fd, 0x04476d91],
+    [0xca286fae, 0xe9780fd9, 0xe1250cb5, 0x1c28f6d5, 0x19eb8dea, 0x8bc9ca24, 0x8b3d7d7b, 0x1090c0b6],
+    [0x5c6617be, 0xbf2899cb, 0x5855c10e, 0x376f2d43, 0x5c7cd5ad, 0x448a725c, 0xb9256d50, 0x25393ce3],
+    [0xfab46630, 0x97d144f0, 0xd6b93426, 0xed824388, 0x6e6e5830, 0xfc862f30, 0x7371f4f1, 0x25931c0c],
+    [0xafbf62f6, 0x03f73f22, 0x85d58082, 0x8a4d3531, 0xb0fb79cf, 0xc82aad51, 0x1700bbe6, 0x2396cb50],
+    [0xb8724884, 0xb3f0ce03, 0xc3cca7b5, 0xa3dc9068, 0x7129a7b0, 0x54ea748a, 0x3348b589, 0x26a36348],
+    [0x5fcd6897, 0xa7a0e5b4, 0x34215f6b, 0xc99b8933, 0xb92c5478, 0x8d6f1535, 0xa204f2a1, 0x27ca107c],
+    [0xb2d91056, 0x8d2d02b3, 0x1178581d, 0xac15f730, 0x326b2cce, 0xe4662bde, 0x097ed77c, 0x26da28fc],
+    [0x6ee0cb89, 0x72971ba5, 0xbb35d575, 0x655774c1, 0x070ac9cc, 0x703e3055, 0x691d8bb3, 0x056ab351],
+    [0x32376a90, 0x2d729af6, 0x1ffc5015, 0xc22547a1, 0x2f481aa3, 0xc76d109a, 0x23b754ae, 0x2638b57f],
+    [0x9ede7cef, 0xea7bc551, 0x32bdec80, 0xc33e46a5, 0x605184fd, 0x32f492c2, 0x8c57d607, 0x304754bb],
+    [0x9b7619ac, 0x752ac93a, 0xfc554010, 0x48ec6857, 0x5806cbf7, 0x514f155b, 0x8457ee03, 0x00d1727f],
+    [0x47761fa3, 0xc588e509, 0x05ec9419, 0xb5bca868, 0x303c72fa, 0x43ba295a, 0x66fbc05c, 0x00ee1f3c],
+    [0xb675d972, 0x61f9297e, 0xfc59078c, 0x10a19fbc, 0xd82415fd, 0x4a76b5a1, 0xf5b4dd4a, 0x0afafadc],
+    [0x4dd04d0a, 0xa8996d12, 0x5a0a6a5e, 0x65a23483, 0xeed108ee, 0x86ce45e8, 0x9746085e, 0x0b2449f3],
+    [0x23dd5dd6, 0x6a7ddf48, 0xec3bdda7, 0xf6c6f071, 0x45227095, 0xc9f37b00, 0xf1b2c5b7, 0x206b0ce2],
+    [0x9459409c, 0x52c7e102, 0xef20fea8, 0xcaffc3a4, 0x33628cd6, 0xb696e674, 0x87834c7c, 0x0feba4fb],
+    [0x336e55d3, 0x8173e972, 0x5e6d70e1, 0x3b06f131, 0x084e0251, 0x8926752e, 0x74c49b0b, 0x254dbfac],
+    [0xa0ca8762, 0x37719edf, 0x17910aeb, 0x9606c5bd, 0x367559e1, 0x4655168c, 0x2cee4e16, 0x0addb137],
+    [0x8831d759, 0x21a589d6, 0x1b1ae162, 0x6ca4d8d8, 0xb019f65c, 0xc799024f, 0x257f3e97, 0x26b25b7e],
+    [0x9e113ba3, 0x1960b105, 0xb00bdb5b, 0xa4657b9a, 0x658787e5, 0x413b8d4c, 0x9acec240, 0x090995b7],
+    [0x44f73b7b, 0x2974178d, 0xe99131f4, 0xeb0d8e40, 0x7843cea3, 0xc5729968, 0x21ef11f2, 0x08dbdc2e],
+    [0x9785179f, 0x58f17093, 0x1596ab67, 0x42fe9c49, 0x2a0f78e3, 0x679faf75, 0x71481197, 0x09e8aba6],
+    [0xf1363d5c, 0x7bc7c683, 0xf9eec94b, 0x7efd12a7, 0xebaf816c, 0x659052a7, 0x0e833e45, 0x1deb0518],
+    [0xe5250879, 0x35fd6523, 0xb2c24af3, 0x248997e8, 0xc04aa9ee, 0xa926efbc, 0xbdfc9098, 0x19a70ec6],
+    [0x0bbf1f64, 0xeb93abe1, 0x77d8a3f1, 0x66353a37, 0xaab48905, 0x879986f9, 0x0adafb8a, 0x21d77366],
+    [0x841c3901, 0xf6f46617, 0xcd667347, 0x97a6b01f, 0x7b89d5d3, 0x3e20ba63, 0x72e9dc71, 0x09f1890f],
+    [0x33b111c3, 0x08c2145c, 0x7bf219d6, 0x48fa1f89, 0xe446998d, 0x2a300c61, 0x61eb454d, 0x05af4593],
+    [0x0cc8b513, 0xfa12fc85, 0xf340756c, 0x5f336f15, 0x75a65733, 0x664a66dc, 0x829f0345, 0x0fa1a1d6],
+    [0x7a628bb0, 0x53655cf9, 0x87f636f8, 0xf4280fcf, 0x307ad543, 0xbda0b1c0, 0xbcc0c3a0, 0x02e47a35],
+    [0x204d0f12, 0x36621895, 0x01124910, 0x20341146, 0xbf4c24b7, 0xb8f90e78, 0x834c6bde, 0x14f773e9],
+    [0x2fc8f7cc, 0xffb09196, 0xfd639992, 0x642abe7c, 0x29bc7d8e, 0x255cf19d, 0x502ed843, 0x102d98cf],
+    [0xe91373c2, 0xcba686a7, 0xd3294738, 0x20d4c73a, 0x5da7ca23, 0x4c47f6c6, 0xaa5a76dd, 0x043dd5f4],
+    [0x6bd11efa, 0x4cfdb2b2, 0x743a306f, 0x6f0e7c79, 0x48d4f267, 0xa6c0d29a, 0xc3337194, 0x21833819],
+    [0x3cff2b53, 0xdc53262a, 0x6c311eca, 0xeb4eca24, 0x9d116ca3, 0xb474a681, 0xcf5ee649, 0x0f281925],
+    [0x15c238d6, 0x93ce662f, 0x0504d5dc, 0xdf625dd6, 0x46d6824e, 0x44709c77, 0xa7b10beb, 0x0d3e2477],
+    [0xf5aefc44, 0xacbfc3ef, 0x51e73b49, 0xd80f80ab, 0xbe9cde35, 0x56ff8a01, 0xbedbf669, 0x2cd7f641],
+    [0x10463cf8, 0x86dd8613, 0x45811d7a, 0x9149d240, 0x98b74e38, 0x4d09380f, 0x2bf2f95f, 0x29e95b49],
+    [0xf83cc13e, 0xf8b3c50d, 0xf6ffeb57, 0x9ae4c51a, 0x6a6c810f, 0x266efca8, 0x62e8f011, 0x22da66bc],
+    [0x1925554a, 0x43072d84, 0x8129df36, 0x220db79e, 0x94f4aca3, 0x30234917, 0xe7a82d16, 0x0fe6d30d],
+    [0x298fd67f, 0xc7e07ecb, 0xef1adfec, 0xd312d03f, 0xf185c23a, 0x123c46ef, 0xa1299909, 0x0050e842],
+    [0x63108c21, 0x885cbbdb, 0x714ed7c5, 0x666f9ddf, 0xa42d7733, 0xbe34cc53, 0xb3221222, 0x2130a3a7],
+    [0x7b33c9fa, 0xe1d328e6, 0x34a93280, 0xaa66731f, 0xe0566c24, 0xd8d5883f, 0x4edf99e3, 0x2df9ee29],
+    [0xc3372762, 0xa4b6fc5f, 0xdc250aeb, 0x58132396, 0xc21ff541, 0xf26eb68c, 0x89ad8c0c, 0x1bf7d6e4],
+    [0x11780e54, 0x5d4778d3, 0x28bf7fe3, 0xcf7b8077, 0x617ab136, 0x61eaf739, 0x55be9587, 0x0c602fa1],
+    [0x21ed7f21, 0xb63faf01, 0x24972fae, 0x5154080a, 0xb8d22d7d, 0x32407d86, 0xb36aa205, 0x2e50e2c5],
+    [0x180332e1, 0xb459123b, 0xe8409b42, 0xf674995e, 0x0ec4f782, 0x5710d629, 0x82a7b582, 0x17c25109],
+    [0x376b42a8, 0xb2ab255f, 0x8eabd2f6, 0x21337b53, 0x465b885b, 0x6803ecf2, 0x3c8af727, 0x0b0d52f0],
+    [0xfab8bd14, 0x61972dd8, 0xc0f85d45, 0xa9ac77c6, 0x63f80647, 0x5953d88a, 0x1972b945, 0x0f5633df],
+    [0x0a7f1572, 0x03549813, 0x3e76e929, 0x24780ff4, 0x96811551, 0xe1422e93, 0x9ca13804, 0x0ebf7ad2],
+    [0xa720ffe2, 0x661b1103, 0xe27c8a57, 0xe18f94be, 0x73bba343, 0x0b029621, 0x1bda47e8, 0x1aff13c8],
+    [0xefac6c07, 0x5713be57, 0xa3b58ff3, 0xd3f31de1, 0x85505862, 0xda2465be, 0xf5cf3061, 0x210449db],
+    [0xee629817, 0xb93ad1c3, 0xd3001044, 0x19d1e2f1, 0x3c7b9dbe, 0x7d75cd6d, 0x794e50c5, 0x088230c2],
+    [0xb20e7e3a, 0x17a4d6db, 0x16c77056, 0x8cce9a9e, 0x4138dfc7, 0xda08dc46, 0x6490b0a1, 0x1c408c25],
+    [0x7e9e3903, 0x90dd6f55, 0x4d584404, 0x8f7c7765, 0x00fb0765, 0x22d17712, 0x81eb4c1f, 0x074517e0],
+    [0xe12fdcbf, 0xa05adf61, 0x59d6cf0f, 0xcee55305, 0x203691b4, 0x88524bdb, 0x21df1dbd, 0x02d04e9c],
+    [0xf1b67b13, 0xa81db32c, 0xdae9f11a, 0xeb9b4650, 0x75de3b58, 0x82e13ebd, 0xb8bce910, 0x2eb7a011],
+    [0xf0cf17b5, 0xe6030c18, 0x95368304, 0x4d2ac6bf, 0xe8a849b5, 0x299f75d6, 0xd35f4af0, 0x2efda77e],
+    [0x192ae064, 0xc51b2440, 0x852b8114, 0x61a73d10, 0x65206d4f, 0x2eddbeda, 0xfd50ce64, 0x09199dca],
+    [0x216e1562, 0x76181cb4, 0x1797e9fa, 0xcb655d8c, 0x666a75b5, 0x4dd319db, 0x446d399c, 0x268c5cfc],
+    [0xa11a18dc, 0x012854ed, 0x2cce6687, 0x97b44e91, 0x6c805786, 0x26b0e9a3, 0xc9490718, 0x2303a652],
+    [0xfb35fbdf, 0x37073f4e, 0x110868d2, 0xbc5353eb, 0x1dc45922, 0xc3f041f3, 0xb12a6ee2, 0x27c53563],
+    [0x615d2b0e, 0x2c278f22, 0x8cfe90f4, 0x9969b524, 0x0a510904, 0xf02bd82d, 0xaf4ae618, 0x1201a87e],
+    [0xab2a443d, 0x6cb4b43d, 0x2c51e570, 0xcafcb1ba, 0x7bb069be, 0xad821499, 0x39fcd69e, 0x2c431694],
+    [0x6304d944, 0x4fb9847e, 0x6d702e3c, 0x4f46cbb3, 0x6984c689, 0xea03c45d, 0x15359040, 0x06835973],
+    [0xa798d183, 0x77795ad3, 0x8314197b, 0xb0425e15, 0x8febd16f, 0xafb93b12, 0x706eab36, 0x03545706],
+    [0x1a0c0e6b, 0xedb393b7, 0xa6a37870, 0x740bed23, 0x51b31728, 0xd35f1fc0, 0xec117619, 0x1a33c254],
+    [0xf1690c78, 0xf338e517, 0x759b87e6, 0x6e88f71e, 0x281caf99, 0x67b0c002, 0xa4470cd5, 0x1ffe6968],
+    [0xe355b393, 0x107f4e02, 0x2a5c4483, 0x140ddd5d, 0x99fd80f4, 0xecb059c8, 0xba8808ff, 0x0fd66e03],
+    [0x7fe013ab, 0x7be3396b, 0xbdd5d74a, 0x6c8617a7, 0x2906b17e, 0x19739455, 0x13b966f8, 0x263ab69f],
+    [0x39ba4046, 0x6eb7e03e, 0xa3958fdd, 0x3bd87d5a, 0x65de413e, 0x5054d5a1, 0x7d111062, 0x16a425e4],
+    [0xf9138d9a, 0xfcc5f73a, 0x3e39e909, 0x53cc31d1, 0xc673f0e2, 0xad752f03, 0x719ec10c, 0x2dc510a4],
+    [0xa94576e5, 0xcb4aa709, 0x329b7a82, 0x423c5179, 0x3d07dda3, 0x1bd1cad2, 0x856c5b5e, 0x24df8e8d],
+    [0xdce5620f, 0xbdf24a6c, 0x01b09561, 0x7628249a, 0x15a042e8, 0x3cd5c689, 0x4fc3c76f, 0x2bcc94ff],
+    [0x52da97df, 0xb48be868, 0xc38cffe6, 0xd3295f52, 0x3df7c429, 0x8de54e34, 0xdc540c8d, 0x076c1e88],
+    [0x12cb46e1, 0xa990fb8e, 0x1120947d, 0xe40ee160, 0x2d9a5e4f, 0x1c051fb1, 0xa451ac43, 0x09b5f209],
+    [0xeb677c07, 0x0683af75, 0x55b0f1ea, 0x64e9424f, 0x4135a6ab, 0xaa88d6a4, 0xd8729e2e, 0x205f17b0],
+    [0x9f237029, 0x0cdd1edf, 0xa4176172, 0x091681f0, 0x8be046cd, 0x912638c3, 0x8836f6cf, 0x281c5c68],
+    [0x592621f5, 0x4afa44f2, 0xa02ea50e, 0x009a44e7, 0x8c471cf3, 0x5f4d6744, 0x78e900f4, 0x1a053e68],
+    [0xd59e064c, 0x81742839, 0x7e7bbee9, 0x68efcb89, 0xc84e4f54, 0x07fb7cea, 0x26debe30, 0x100dc7d4],
+    [0x411e49c8, 0x0b1cb31b, 0xb62c82c8, 0xb28905bd, 0xadc8b75f, 0xbb87e2cf, 0xa016a957, 0x17022672],
+    [0xf954f4d2, 0xe0c27203, 0x182b170d, 0x9fb8b547, 0xebe15123, 0x71053a87, 0x2760fc8b, 0x1086db7e],
+    [0xd7584064, 0xb85dd24f, 0xa185536f, 0x2b41fb65, 0xc2942fac, 0x02460ae4, 0x9d73b633, 0x15384fe3],
+    [0x57709008, 0x519e5513, 0x205fcfb5, 0x47b1a853, 0x342c6c74, 0x4bf4abc5, 0xe9136d42, 0x2ebb599f],
+    [0xf4d37288, 0xd996fac6, 0x2b4ce3ee, 0x4cf27829, 0x542e4c5a, 0xfec3c0f0, 0xcfb9262c, 0x1b4b5e87],
+    [0x659e5057, 0xb637febe, 0xbb4fba60, 0xbb4a7581, 0xe302cafb, 0x801f3f82, 0xe50b6885, 0x2465053a],
+    [0xd54859ef, 0x6e0ab9d3, 0x8b3708bf, 0xda9f05e1, 0xd38f1b97, 0x095c5bb5, 0xcdea09cd, 0x114f32ed],
+    [0x543c7148, 0xee28d8c2, 0xf3d18123, 0x2e5e5519, 0x7be779ac, 0x6b387cd7, 0xb2baab2f, 0x2bc70dfe],
+    [0x32c05415, 0x12e49bfe, 0xf6ec68a4, 0xa78348b9, 0xad7e77b6, 0x775e3a61, 0x203ce22b, 0x01c9bf7a],
+    [0xcb57ee9b, 0x9f4ad00c, 0xff6a97cd, 0x55cfb575, 0x5fbbcec3, 0x7bedb029, 0x5909ea88, 0x0514b0fe],
+    [0x7b6c1b7e, 0x03e3202d, 0x12a201af, 0x2092520b, 0x058910a1, 0x1a132a8b, 0x81934cc8, 0x267c76ec],
+    [0x57bde5d6, 0xfb151cf7, 0xce83e95c, 0xf1622493, 0xbbb470ad, 0xc78c84ba, 0x22b3d8d5, 0x29170e33],
+    [0xa41d9519, 0x36dd596c, 0x5486cab5, 0x5c6f09a4, 0x873f9c33, 0xaf33e5d3, 0x24b19e33, 0x019f6a81],
+    [0x242b60e9, 0x5405d036, 0x171518ae, 0x009ed8ea, 0xb1981c27, 0x8b348e9d, 0x6908544a, 0x1904aa4d],
+    [0x6455ff1a, 0x3b932a47, 0x9dd6f647, 0xb3cee1de, 0x6694e422, 0xf7f04395, 0x949bc679, 0x26f17873],
+    [0x8bc48f75, 0xbdcac9b1, 0x1197131e, 0x40c47603, 0x0b8aa540, 0x193b3372, 0x12b8243c, 0x1ac668f6],
+    [0x8ef0663d, 0xa52f8156, 0x5be9796d, 0xccfbf855, 0xbf624766, 0x196dae45, 0xa75c0d07, 0x0996d961],
+    [0xc24fc819, 0x5425b395, 0x9c63febb, 0x74d171f9, 0x383fbe66, 0xfd50d1b4, 0xb8cad1d4, 0x030c97e1],
+    [0x8bd0848f, 0xee226f2d, 0xfe502642, 0xb3e523f1, 0x55b68f89, 0x39533702, 0x46900e2d, 0x06e3ad6a],
+    [0x88cc916e, 0xa3298905, 0xe0455153, 0x65cb94b0, 0x2f9880f5, 0x6b6880e4, 0x331cd021, 0x1d6b3755],
+    [0xbae531f2, 0x3dd38c08, 0x0b85ac03, 0xc35189dc, 0x5e730ac8, 0x59b04153, 0x4b96f12a, 0x28e4dcba],
+    [0xc4a47a14, 0x7a3b3e4b, 0x6260376c, 0xb0712a47, 0x2974b6a6, 0x8ccf484f, 0x46a83550, 0x08b60860],
+    [0xb9bb3b52, 0x89643e15, 0xa6a0536a, 0xb6fd85fb, 0x7812019b, 0x444bcec9, 0x7fe3b5f1, 0x162cd2ca],
+    [0x4d483a76, 0x998c01c6, 0x192063d1, 0xb5c9a9c1, 0x937e4f5c, 0x05af5b11, 0xaaea9bbc, 0x28f1e03b],
+    [0x6791ce10, 0x6031a0bb, 0xb4aa7136, 0x0127d2aa, 0x34c25faa, 0xa395af27, 0x78d7c15d, 0x1bdb0627],
+    [0xd24749cb, 0x50329ebd, 0xb2324a8b, 0x0fc98870, 0x29627e0e, 0xcb2914e8, 0x02e09890, 0x23758395],
+    [0x1ed7bb50, 0xe92d3361, 0xfb2df456, 0x332dc87c, 0x8dc9423a, 0xad7c5566, 0xbcb61fb3, 0x1fa8662f],
+    [0x079d7694, 0x3f7f9736, 0x47a5138f, 0x46e2fb2c, 0x1716c8a4, 0xf8707f72, 0xd6b0a6f1, 0x1e4fad2d],
+    [0x3731ffe7, 0x61b0f5f1, 0x059f53d2, 0xba3bd050, 0xdd1fa788, 0x6df6f5fc, 0x6c7269fd, 0x211256d1],
+    [0x12bcc5a5, 0xf4a1079b, 0x74dda341, 0xf42060e5, 0x6af08318, 0x4f8e2a2e, 0x336eceaa, 0x2e49084b],
+    [0x1fb00bb9, 0x758d2589, 0xdfe14cab, 0x1aecea08, 0xac680821, 0x3bf35192, 0xcdc39f7f, 0x0ce19f54],
+    [0xab2c14cb, 0x84a8468b, 0xe4ae4e1c, 0x8dc60451, 0x1261d874, 0x3cc39422, 0x6c390e89, 0x0011c5d5],
+    [0x51579b4c, 0x14a8cd80, 0xfa39b475, 0xca60e17b, 0x6af3dbb7, 0x8a9e05ee, 0x6b63ac2a, 0x17d79ff0],
+    [0x639015fa, 0xcab00173, 0xd6416a2e, 0xb1a8b35c, 0x93592b06, 0xdc745600, 0x46cb5393, 0x19a7d3a4],
+    [0x237037a6, 0xaa6c3c97, 0xd8928da7, 0x31f04596, 0x1b9282f3, 0x2a808b2e, 0x933dcdba, 0x030c00a0],
+    [0xab64139c, 0x9370be8a, 0x184c4acc, 0x935d2d00, 0x0695382e, 0xae25ad08, 0xce2d50f3, 0x16bcb447],
+    [0xd66feb1d, 0x39255b7c, 0x7cab7b6d, 0xe62124f3, 0x15312997, 0x5ea4ec87, 0xb0150aa2, 0x12341b46],
+    [0xb6a49ff4, 0xdc89212d, 0x296d1044, 0x002fc28e, 0xbf610c84, 0xb72a97b2, 0x17f44050, 0x0e86d139],
+    [0x110c7262, 0xd059025b, 0xf8f2eb14, 0x1052a181, 0x53d7f30d, 0xd357e00b, 0x89d37d66, 0x08e6eb40],
+    [0x97ca7b18, 0xe10c48ce, 0x51a1e0ce, 0x0415ccb3, 0xd1481a0c, 0x4738d15d, 0x6245f6c8, 0x2ea12385],
+    [0xc84dfa6f, 0x664db0f9, 0x25413abf, 0x41957890, 0x30b16310, 0x3446e003, 0xebcab8c2, 0x2dca72b2],
+    [0xd42b6a6b, 0xc64a26cb, 0x5f036fa4, 0xf2f6b423, 0x5ec924b3, 0x63329f58, 0x0d327e84, 0x06ff9ed5],
+    [0x5afbf730, 0xc630a453, 0xca84ea2a, 0xe2a60e0c, 0xa3d54df8, 0x47f7c9bd, 0xe3e00899, 0x246a10b7],
+    [0x688b6691, 0x5c96b39d, 0x5ae67ad7, 0x2f846a71, 0x9d700ee5, 0x18719ed9, 0xc5f04b90, 0x22a63501],
+    [0x14fae937, 0x40ec07e5, 0x351d8aa1, 0xb9164f58, 0xd2e224cd, 0x71799ac5, 0x7f7fd9c6, 0x2f4c5047],
+    [0xd851567e, 0x0cda32ca, 0x4a23d4b5, 0x38f0ba8a, 0x876d9a1e, 0x13b17f4d, 0xd1f51c7d, 0x10ffb7aa],
+    [0xc35803ae, 0x1a056935, 0x2187e7af, 0x78402735, 0x532d5420, 0xa4d39722, 0xc3c2d3be, 0x0e9cefdd],
+    [0x4ba62b41, 0xa3e83bdd, 0xa33c8978, 0xa1656f96, 0xdc6ea4af, 0xc23352e6, 0xd3141e7a, 0x07af84a4],
+    [0x8a22c3c5, 0xe940f3ec, 0x6e89b638, 0x1a39323d, 0x4b1e566d, 0xf8de00d1, 0x0aebc761, 0x2d9e31a1],
+    [0xe27e64c7, 0x7656747b, 0x14dad844, 0xd36034b3, 0x592f66f1, 0x33db1afd, 0x32e66b53, 0x27f19a65],
+    [0x47d07ffd, 0x7e4d617d, 0x8f3155cd, 0x180ed99f, 0x3b4a577a, 0x54b2024c, 0x8454d633, 0x0058fa3c],
+    [0x6816a5d5, 0x70896495, 0x5d3a175d, 0xb0414a20, 0x0699343e, 0x67957c08, 0x715b7809, 0x041627b6],
+    [0xb727c52d, 0x89888f12, 0x1cf1f665, 0xd9820147, 0xb958ccec, 0x7f632e57, 0xd9253edc, 0x006ac49d],
+    [0x18301398, 0xec46a6bf, 0x6d25a9a4, 0xec0c9c0d, 0x6bbe3386, 0xb1d8c361, 0xd8bd7254, 0x0131adff],
+    [0xd8f9f5b6, 0xcf030e1c, 0xe25e49a3, 0x77977ad7, 0xef62a283, 0x4138e413, 0xc9fccf7a, 0x1c4a6f52],
+    [0x2c2645f6, 0xd1f7958d, 0x2078aeb7, 0xfee048ae, 0x860ea479, 0x946551b3, 0x51ec677f, 0x03f2a6be],
+    [0x6b8ddc62, 0x4d737639, 0x54311956, 0x8a1372d8, 0x8ef3a964, 0x391a0cb7, 0xd2c2eb09, 0x2da770aa],
+    [0x75d64a03, 0x38062afb, 0x66c359c7, 0xc9d45fe8, 0xbfab02ce, 0xdc1802fe, 0x665f74cd, 0x15278463],
+    [0xac54c773, 0x8d580638, 0x66a08a6f, 0x34e38ea9, 0x090518d4, 0xc9731027, 0xa36544ea, 0x12fe278a],
+    [0x0b6a23b6, 0x21b06ff3, 0xca4f0d6e, 0xd8587604, 0xd3f4cc7f, 0x4c45d119, 0x2182558a, 0x149b9c80],
+    [0xb691dfe4, 0x0adc4959, 0x60dbac9a, 0x55e044fd, 0x772f3c98, 0x17d19319, 0xd847bc85, 0x0812e7b4],
+    [0x03a0c61b, 0x2a669737, 0xe7503aea, 0x555813c7, 0xa0bfaa7f, 0xd8df7f28, 0xdeafe3d9, 0x02ed8d8d],
+    [0x662d4db8, 0xa1304038, 0xa03d6b6b, 0x55e5e4d9, 0xf921029e, 0x4deb6029, 0xa0537b51, 0x0ebd073b],
+    [0x2eff9f59, 0x44ee75b6, 0xc6afa08b, 0x55b3e792, 0x2ccd0282, 0x05c6ba8d, 0xb14b2c42, 0x15c754d5],
+    [0x30e8732a, 0xc29e7cbc, 0x70f4cbc5, 0x1cc12352, 0xfa311b39, 0xb0ed8fa6, 0x9ac5479d, 0x169515c8],
+    [0xcd9a4fef, 0x6d9161f5, 0x6ff03769, 0x08bdc29f, 0x10011016, 0x2388f262, 0xb3a68f98, 0x25479fbf],
+    [0x94be6071, 0xce09486e, 0x72182196, 0xa7f8e6e9, 0x311a578c, 0x3c852cb0, 0xd520451f, 0x14475c4b],
+    [0x3d097799, 0x5c4e7336, 0x2aec8d5f, 0xd3a84750, 0x8833a1df, 0xc9baf279, 0x66cc66be, 0x045a6910],
+    [0x37eba683, 0x013b8bcb, 0x37221b90, 0x147a8ca0, 0x11e3a3f0, 0xb833ac8a, 0x267c799f, 0x26029c0c],
+    [0x948d1641, 0xb8101d5b, 0xa94a3e45, 0x73ce12a6, 0x9c1c2608, 0xf7c94696, 0x4ff572fb, 0x163facb3],
+    [0x4240ee28, 0xaf8ee00c, 0x75e58688, 0x13e06a62, 0xcc69d5ec, 0x1d969320, 0xe1913b35, 0x2c714e96],
+    [0xff1d5fa6, 0xd52b804e, 0x06debfe2, 0xddd6268f, 0x5ecd2bf9, 0x5aba8466, 0xa7ce74b7, 0x1c1661e2],
+    [0xb7dc2c71, 0xa8c3c068, 0x2a0889fe, 0x6635b34c, 0x619a47d2, 0x5e5af3e6, 0x95ee9bfe, 0x06a69ae7],
+    [0xd731d46a, 0x31416c85, 0xf54195c7, 0x74dbdbad, 0x19a36093, 0xc5d28b4c, 0x5d892115, 0x113d5853],
+    [0x63b46101, 0x2ca1a740, 0x47f7917a, 0xe534f1ff, 0x61da6042, 0x38ff97d7, 0xe2b8d5e6, 0x2ab89102],
+    [0xa15ad1b3, 0x39804fd6, 0x3da2b4fd, 0x34903189, 0x5e699546, 0x962730c4, 0x9e41fdfe, 0x03c11ca7],
+    [0xaaf2669c, 0x6adf040f, 0x4cfe846c, 0xf7f67b4d, 0xbbfc9da1, 0x88014ddb, 0x26214038, 0x27096c67],
+    [0x7ef5be0d, 0xaa9dc2b5, 0xe486130c, 0x66db790c, 0xeb53b13c, 0xd504d4de, 0x5497aef4, 0x2de32ad1],
+    [0x8eefd001, 0x25575444, 0xdaec9f3d, 0x28c738dd, 0x10722a2d, 0xfd5d3413, 0xb0a280d2, 0x0dc108f2],
+    [0x365d832f, 0x7d34ca50, 0xc409be7c, 0xbcdc3eeb, 0xbb9efad5, 0xc96858a1, 0x63fe8164, 0x1869f3b7],
+    [0x7129682d, 0x8c6d3376, 0x16a568d4, 0xb616945e, 0x6a911843, 0xf82559fe, 0xd9ff31cb, 0x022ed3a2],
+    [0x77d026bc, 0x10c6aec0, 0x7c19c266, 0x92fca1f2, 0x65bd0e72, 0x3944ed13, 0x5210169e, 0x2155d600],
+    [0x8caeae85, 0x3ea5928c, 0xc91af3db, 0xec0c0556, 0xf5f1b4bb, 0xcae93263, 0x562a8f7a, 0x0de1ba7a],
+    [0xda902563, 0x6351dfa7, 0xce8d637b, 0x126f740b, 0x6ec7da38, 0xcfce5bf4, 0x6024beab, 0x05dbb440],
+    [0xa368fd5e, 0xc97e5427, 0xfcd72dcc, 0x00e789e3, 0xd778d32c, 0xd4d8dc8a, 0xaac413be, 0x05d4149b],
+    [0x72c27b2e, 0x9212e221, 0xa941c787, 0xf0b6802f, 0x7397e76f, 0x9be5046e, 0x52d97c2b, 0x01cdf8b4],
+    [0x9d46c07c, 0x1b0a2227, 0x5eced8c7, 0xc4d7c501, 0x81adce33, 0xaf8085ff, 0x67027f56, 0x1fc6a718],
+    [0x96aaafc2, 0x568e0fda, 0x5306d9c3, 0xa9e185b7, 0xa7a3a42b, 0x5d4d59a5, 0xc642d034, 0x1040bef4],
+    [0xab8fad0d, 0xa8081475, 0x76431918, 0x2b21e956, 0x89334a4d, 0xff2c91b2, 0x6bf316e0, 0x16b79c3a],
+    [0xd59f474e, 0x3cbe1cdc, 0x2b601fc1, 0xa34070e5, 0x87e3c8c6, 0x434b3a13, 0x30f6db6b, 0x20dff1bc],
+    [0x12ae060d, 0xdfb7fd85, 0x1a726a8b, 0x062dd417, 0x30a970f8, 0xc2549550, 0xb7a6eaae, 0x0212ac2a],
+    [0x6996ae09, 0x13c8119f, 0x34f00442, 0xb02dc031, 0x9215637c, 0x869a109c, 0x91474442, 0x2f293774],
+    [0x2d814bfa, 0x5837d9f3, 0x66aa284c, 0x73be9f38, 0x3fea6032, 0x25ec93c3, 0x5f9185d5, 0x0984ca6a],
+    [0x4b24f028, 0xeb0ee929, 0x8a6bd19c, 0xe491361c, 0xfa81220d, 0x0d299bd6, 0x6b3b6070, 0x0d080a6b],
+    [0xc85a4544, 0xce929ed7, 0x6400264d, 0x21acc85b, 0x38cb0ad8, 0x67895306, 0xe84b052f, 0x0e65cd99],
+    [0x2736920b, 0x55975da1, 0x380ff6a6, 0xe30e3d20, 0x716cd05e, 0x4808f72c, 0xbc7ac122, 0x2e208875],
+    [0x8592bad5, 0x4a60d1aa, 0x4afd29f6, 0xae2e3b89, 0x3d7dfac1, 0x76a0b0ff, 0x477c2fd3, 0x2989f3ae],
+    [0xa7189459, 0x3a74c3ce, 0x63290a3e, 0x918106a4, 0xe6fac0ce, 0x9222d101, 0x44e94137, 0x11361ce5],
+    [0xa2061438, 0x34c8bd05, 0xb8fb366c, 0xd122a822, 0x173f6a75, 0xa539e10c, 0x86cb5a7d, 0x1e8d014b],
+    [0x8ee98733, 0x212ee2c2, 0x67ef6c91, 0xa0c23241, 0x29558e23, 0x7ba812ad, 0xec8deee2, 0x173f65ad],
+    [0x310eddd9, 0x991bd695, 0xd45499d0, 0x5da5df7a, 0xc779ac3e, 0xafee8bd0, 0xf9f01f1b, 0x01c36daa],
+    [0xf381e562, 0x4a6f5741, 0xf2fc8994, 0xf277d1a3, 0x85bb85ff, 0xaa9ab1c4, 0x8c05adb4, 0x1353acb0],
+    [0x3b064d34, 0x20f1c87a, 0xb4f93aeb, 0x009d33de, 0xa1188ee4, 0x1860e71e, 0x37207cad, 0x2e5abd25],
+    [0x8f69455c, 0x421726ba, 0x34913a13, 0x13deb4eb, 0x7ab85625, 0xd02eedbb, 0xdaef42d3, 0x191d5c5e],
+    [0x2dcd089e, 0xee7939dd, 0xc127de9d, 0x82a04c74, 0xa6d7599d, 0x82a263fe, 0xf2692642, 0x11d7f8d1],
+    [0x86cac971, 0x1bad0852, 0xd6bc6f95, 0x3445cb4c, 0xe6799797, 0x90f79ad5, 0x366829ed, 0x04218fde],
+    [0x0d76d6dd, 0x27b71c73, 0x89301c32, 0x027b73b4, 0x5f47a202, 0x048397ca, 0x7cf52453, 0x0070772f],
+    [0xe3d2b861, 0xc1dc04db, 0xcd051634, 0x67681a98, 0x687a1d9b, 0xc865b065, 0xaef5d9a7, 0x038a389b],
+    [0x9015a6e5, 0xf1986078, 0x66a50bea, 0x0f39d009, 0xb4b59ccd, 0xcda446b2, 0xb8b36a80, 0x09a5eefa],
+    [0x7584cd8c, 0x9d16212c, 0xdfacb748, 0x3d9ec99e, 0x109b4b58, 0xb969c145, 0x8b8b47c8, 0x01b58884],
+    [0xd42954a6, 0xfed91e30, 0xa323d817, 0x545e5abf, 0xc3341419, 0x6e1af6df, 0x390e560f, 0x0b846e4a],
+    [0x5ce72fe3, 0x85cabfe8, 0x394fc4f9, 0xeb1513bc, 0xdb900040, 0x0d43a02d, 0x7d9adb66, 0x23a6679c],
+    [0xfe089254, 0xf72f31d6, 0x7c85eccc, 0x4c3402fb, 0xf1351e9f, 0x3e5caa35, 0x99197e34, 0x2e0374a6],
+    [0xa7a27b48, 0x37d41913, 0x176bb9ec, 0x4b8cc0b1, 0x4cde3ff6, 0x7f7a08af, 0x9e52dc4d, 0x0752cd89],
+    [0xd7ae55a7, 0x99e2873d, 0x664b802c, 0x0275142b, 0xd57397a5, 0xc349a2b6, 0x127299da, 0x068f8813],
+    [0x2da202b5, 0xb374330f, 0xa295b5b8, 0x86872d04, 0x7434b3f9, 0x67757416, 0x2355d549, 0x2ba70a10],
+    [0xa0b50828, 0xb8609e70, 0x825dd289, 0x09f9099b, 0x03b55213, 0xa334d1df, 0x8748abf6, 0x2c467af8],
+    [0x63e8f497, 0x96d29e57, 0x1a319572, 0xe3a52c2d, 0x448ae881, 0x01009a2b, 0xef1bd827, 0x05c5f20b],
+    [0x61b73871, 0x3ac8c9fe, 0xc649d9f2, 0x0cd083a2, 0x6006e2c6, 0x842a381f, 0xdc567be5, 0x0dc6385f],
+    [0x114b369c, 0xd255baa8, 0x4150d25e, 0x0378d5b8, 0xb8670fa7, 0x19d49911, 0xf3dc7f7e, 0x142d3983],
+    [0xb2df67bf, 0x00fa18c1, 0xa99aa35c, 0xf36f86a7, 0xc96a0fa0, 0xfd7e6d98, 0x2f6aa894, 0x29a01efb],
+    [0xeb613a76, 0xd2a1af04, 0xc64555b7, 0x0ab9e8af, 0x6644ec63, 0x38c4a506, 0x737d6051, 0x0525ffee],
+    [0x38301dc8, 0xf177cf42, 0x4910264e, 0x7e164f61, 0xa0e82276, 0xf076677c, 0x81d79581, 0x1e807dca],
+    [0xdbd8757c, 0xdba56082, 0xf733a52f, 0x4c0223e0, 0x472474d3, 0x93510816, 0x89c74dc9, 0x0385fb3f],
+    [0xbab0ea01, 0xf558f337, 0x6fd6ef1a, 0xcfabd701, 0x53cae59f, 0xe1a6298e, 0x1afc0143, 0x037640dc],
+    [0x896074b4, 0xb7e4c0de, 0x31da8050, 0x5fd56cf0, 0x60829eee, 0xf12a6c52, 0x1ed86919, 0x1341999a],
+    [0xbed8ece7, 0x7b244f65, 0xefdcc565, 0x73afd642, 0xafb10ad7, 0x56906d4b, 0x866b0af3, 0x069eb075],
+    [0x19033696, 0xea1909a6, 0xd3afaa02, 0xd2726101, 0x08b3e646, 0x95b38e8e, 0xe62136e3, 0x171c0b81],
+    [0x02acced8, 0x55751075, 0xbfe696f9, 0xcbbdcb39, 0x11753e84, 0xb6eb55c3, 0x9453f51c, 0x2c81814c],
+    [0xa7c3a604, 0xe39b8a86, 0x2adca92c, 0xb04b3903, 0xcf274b2a, 0xe3b33fad, 0x415d35d9, 0x29d843c0],
+    [0xfae7728b, 0xa7669cf3, 0x947f2cda, 0xd8e15ea5, 0xabb78750, 0x8436bccd, 0x70f3513d, 0x085d6a10],
+    [0x412fc394, 0x9e2a5cda, 0x6706b02c, 0xdbf1dd4e, 0x65bf302c, 0x10a44ba6, 0xed541daa, 0x11820363],
+    [0x51a16317, 0x105840b7, 0x05f1df5d, 0xdfd3150e, 0xa83785bd, 0x02b60d61, 0x8f5c57fc, 0x201935a5],
+    [0x87c02e9a, 0xcfed610e, 0xd686cd8e, 0x46794eed, 0xbd33a03d, 0xe27a952a, 0xc56971aa, 0x0a8c2820],
+    [0x3a281877, 0xd50dbc16, 0x33e6be1f, 0x650b65ff, 0xbd7500b6, 0x04abd6d0, 0x301a64ca, 0x180638ff],
+    [0x812dd36b, 0x3e9b321a, 0x37cfbec6, 0xf85ca8a9, 0x4a4158a3, 0x044f9711, 0x66f1de59, 0x095c7162],
+    [0x97d73490, 0x8d4087a4, 0xc1a68781, 0x83b618c5, 0xd6c7ca15, 0x20d86ffe, 0x2fbc3783, 0x17c31ea0],
+    [0xf192a91b, 0xe4d50a77, 0x4ccd660d, 0x3c17a976, 0x192d149d, 0x6a7227e4, 0xb8ef318b, 0x05b86c4b],
+    [0x986c7a7b, 0xd6ce74ba, 0xae0d237c, 0xab15f4a6, 0xa2fde2c7, 0x6ff70d7e, 0xf4a4c487, 0x265bc95d],
+    [0x715c7929, 0xd6c886d4, 0xc5f5b4b3, 0x08701739, 0x5fef2f69, 0xd9bbe48f, 0xbc6c6bc8, 0x24752b47],
+    [0x4d974efe, 0xe583b932, 0x8da96ee5, 0x78d624b9, 0x7a964821, 0xa0d86e52, 0x0f492a4e, 0x14814a1e],
+    [0x906f00bd, 0xc7523bca, 0x1d3cfb3d, 0x997c8e04, 0x78f29381, 0xbd605773, 0x073b6479, 0x10def931],
+    [0x8610175f, 0x1b4592c9, 0x28c69d03, 0xfa0b880d, 0x8ab4ed03, 0x7f706c0d, 0x0bf7e95f, 0x14f7ae77],
+    [0xce1ce589, 0x75c5cc9d, 0x28ebe4e7, 0x9decc9d4, 0x0e8035d1, 0x9f926af4, 0xcee751b5, 0x1aef50a0],
+    [0x7e73cf5a, 0x951214b5, 0x57d09602, 0x086c2a2d, 0x068dfe3b, 0xba65ca60, 0x7172f68e, 0x04193560],
+    [0xbf452cb8, 0x37a151d3, 0xe83c8198, 0x0493fbef, 0x959b856c, 0x573bd083, 0xd24255d1, 0x26863e9d],
+    [0x3ff8b3af, 0x652738e6, 0xba62052c, 0xf5adf251, 0x8bd087c9, 0xeb3d7a06, 0xf9830965, 0x2036efb6],
+    [0xb4f4ee9b, 0xb00fa4f1, 0xa753f854, 0x30be4f75, 0x029969ca, 0x766b639a, 0x5b74dc9d, 0x0c712a97],
+    [0x87539699, 0x375731d3, 0x56492ca3, 0x7afdff64, 0xfac1e680, 0x27afc99b, 0x3cd1667e, 0x08014dab],
+    [0x9b34e6a4, 0xb53009f7, 0x37b4e8f3, 0x97c4dd4d, 0x839d6a2b, 0xa82a4a79, 0x2db4fac2, 0x198d0719],
+    [0x1f63e572, 0xfd15784d, 0x53bb946d, 0x2709b29d, 0x426897a3, 0xb23b4131, 0x2a3ad381, 0x29eb1de4],
+];
+
+/// The MDS matrix for `t = 5`, row by row.
+#[rustfmt::skip]
+pub(super) const M5: [[u32; 8]; 25] = [
+    [0xf38d7da1, 0x4fc6fdbc, 0xce3cd7f6, 0x9f22e57a, 0x33b9e436, 0x080b0af1, 0x99591080, 0x251e7fdf],
+    [0xe91c7765, 0x48550486, 0xa238d6e3, 0xfe26ea9c, 0xc17d97c7, 0x47cbd3b1, 0x5acf4fb0, 0x25fb50b6],
+    [0xd5767833, 0x6ea1e31e, 0xf367a455, 0x6deb5325, 0xf62f91b0, 0x355f39eb, 0x7da72102, 0x293d617d],
+    [0xa6d3c1c4, 0x6145f14b, 0x5a9b7edf, 0x66599e57, 0x25da4743, 0x960111ac, 0xab00c85e, 0x104d0295],
+    [0x2f69c977, 0x3ed5b90f, 0x954fe9bf, 0x792b3813, 0x6cd96a39, 0x7dea3e33, 0xc84baf11, 0x0aaa35e2],
+    [0xeb74c839, 0x69f661b2, 0x6609ea69, 0x052903dc, 0xd1dcdb02, 0xc03e17c1, 0xd4bbccdb, 0x2a70b9f1],
+    [0x991ccd7a, 0x115b8e2e, 0x8ef4b979, 0xba9f92ad, 0x4f1b8a1b, 0x6315a993, 0x1c921e74, 0x28115465],
+    [0x7db36c37, 0xb86a894f, 0x9ee2b45f, 0x8ccd8fdb, 0x134efa33, 0x0b53c732, 0x8264f95f, 0x28c2be2f],
+    [0x5e530a13, 0xc4ec8fa7, 0xb4dc18dc, 0x9b626d8c, 0x0b1883bb, 0x6d427c89, 0xe6febd54, 0x21888041],
+    [0xcbff4941, 0x350d65e2, 0x3ea4574a, 0xe810930e, 0x92d8cf2b, 0x80195b95, 0xda0171db, 0x14ddb5fa],
+    [0x970d401d, 0xa4fa8478, 0x652ad69a, 0x55b91bff, 0x306a37ed, 0xdea43265, 0x8e1fbcc7, 0x2f69a719],
+    [0x594d3052, 0x6d6a3747, 0x34140e71, 0x67ba312b, 0x0e37bbb2, 0xad931ab8, 0x62645b73, 0x001c1edd],
+    [0x28bfc32b, 0xa7f9a4b2, 0x3049466f, 0x39c40c60, 0xc69663c4, 0xce2f2c96, 0x3e47bc64, 0x15b98ce9],
+    [0x3163cf9c, 0x65051de3, 0x28e44d92, 0xcba8458b, 0xfbac809f, 0x58f65be2, 0xfa524e59, 0x12c7e2ad],
+    [0x53bbd167, 0x531ec2de, 0x816ef468, 0xaf67ce79, 0xe7b8922e, 0x49018222, 0xd6881348, 0x2efc2b90],
+    [0xf721f830, 0xf1afd6c5, 0x50bd2514, 0x3c3ffa45, 0x3e1a29a1, 0x1981e55e, 0x6bf5af15, 0x0c3f050a],
+    [0x20a1a36e, 0xeda77843, 0xcd424a73, 0x8b2efe2e, 0x92bd34f0, 0xfa75ba79, 0xdbf75205, 0x0dec54e6],
+    [0x2d8136fa, 0x7cc75cf3, 0xf4d988fb, 0x98364a11, 0x034b1960, 0x20225815, 0xa729f5df, 0x1c482a25],
+    [0x9fd8514a, 0xb8b607ae, 0xc9a14a5f, 0x0812ac2f, 0xe4ab9436, 0x52732624, 0xa7b39a42, 0x2625ce48],
+    [0xf710251f, 0x9fb9f28a, 0x00b9a8bb, 0xed7ef8e3, 0xd710c509, 0x86f7cd4f, 0xebd56dd0, 0x07f017a7],
+    [0xcc2e3595, 0xe2f78c2c, 0x0146259b, 0x7c5e55c2, 0x86c6c3ea, 0xf97c9d61, 0xa0e57d92, 0x2a20e3a4],
+    [0xb727a549, 0x403b01fe, 0xd820d9c4, 0x701673ae, 0x5d63c0ee, 0xaafb1e9a, 0x0566b51f, 0x1049f821],
+    [0x82d69c8e, 0x61b9299b, 0xe3e86b55, 0xef357a69, 0xd1b96b4b, 0x68002bd9, 0x7ef5b4b5, 0x02ecac68],
+    [0xbbe1e37b, 0xd4ebcf11, 0xba40441e, 0xbdcb6b58, 0x9ba903d3, 0x6808f88c, 0x2e6d4446, 0x2d3a1aea],
+    [0xfed65e64, 0xc98d803b, 0x72dbb6d9, 0xb39c4a7a, 0x4f35fe49, 0xc9ad171e, 0x4c982c81, 0x14074bb1],
+];
+
+/// The round constants for `t = 6`, with 60 partial rounds.
+#[rustfmt::skip]
+pub(super) const C6: [[u32; 8]; 408] = [
+    [0xad40c651, 0x2e86b706, 0xf8390cde, 0xd968653e, 0x45fbd83b, 0xe7ae7dea, 0x98e00f98, 0x14486145],
+    [0xc1dff7c3, 0x3b8a7b40, 0xe1b70af4, 0xcdb65022, 0x591fb83e, 0x43c0dc1f, 0x388e5c9e, 0x0ab7b291],
+    [0x612d6adf, 0x5c4d5466, 0xa3cef5b1, 0x84cde754, 0x654af21e, 0x9a8c088e, 0x7896f52c, 0x2b7cbb21],
+    [0x6e67a65c, 0x2bc71c0c, 0xbbbb95fc, 0x0da59ff3, 0xdc1ca1bf, 0x6570428b, 0xbe1d701b, 0x2bc6b0dd],
+    [0x05eefd3c, 0x1efab9a9, 0x7d20e927, 0x0c38c11f, 0xcbc44ed6, 0x3d20b2ce, 0x1980384f, 0x123a55a3],
+    [0xe9fb8c53, 0x79e2883b, 0xbc8eb7e2, 0x88b01858, 0x4df098e5, 0x309a769f, 0x8c9dc819, 0x037501cc],
+    [0x3fd47d51, 0x1c259eca, 0xeb951ab0, 0xb9a460b1, 0xa5f6dab6, 0xb11695b0, 0x7e03a86b, 0x1c2116e4],
+    [0x54f56f93, 0x9dbdaebf, 0x7bc0fae4, 0xfaed13e5, 0x9a65839b, 0xa9c8cb8e, 0x89032e85, 0x2c182134],
+    [0x35b9e031, 0xad8703c8, 0x7985fdf2, 0x8f29e222, 0x8e5db171, 0x0429eafd, 0xd4d2c71a, 0x2ee8fed3],
+    [0x0a0c1714, 0x16d111c7, 0xcce94b93, 0xba9436d1, 0xd9e9bf91, 0x04c92029, 0x5ed7aac0, 0x28c64d8f],
+    [0x478665e0, 0x34254a03, 0x5abf571b, 0x682c84f1, 0x07f8b24f, 0xde3e83c7, 0xfb747ae0, 0x18a01d9f],
+    [0xcde72bd3, 0x54e1ac71, 0xee71515d, 0xe642a56c, 0x7b60db14, 0xb234e477, 0xef197e73, 0x1c21d92b],
+    [0x62d807b1, 0x8b6455fe, 0x7dbbedad, 0x9d66a667, 0x80981ebb, 0x897cb60c, 0xbcb1e195, 0x0ad404cc],
+    [0xcc1fb387, 0x97af8b60, 0x7bd14c8b, 0x5cb654cb, 0xf6c00594, 0xb6adb99a, 0x33064f93, 0x0a9b6de8],
+    [0xc3aa3a6f, 0x180d45be, 0xdce2c3e6, 0x60efa58d, 0xff09dc51, 0x47690331, 0x930aed6d, 0x13129e3f],
+    [0x839387f1, 0xcca18079, 0xfc9e280c, 0x2f18bf7b, 0x813bb1c8, 0xb1ce4895, 0x8991508a, 0x0d7a614c],
+    [0x094ffea5, 0x8c31f2f4, 0x5af5febe, 0x49bddf2f, 0x875c913a, 0xb0486986, 0x36e30041, 0x0532f7ec],
+    [0x155cb892, 0x84d9d8ae, 0x9d64263c, 0xbbee808c, 0x4950f1b0, 0x293e712f, 0x8e180201, 0x06bbcb8e],
+    [0x8a9dcb33, 0x687ca567, 0x211ceb4f, 0x586bf40f, 0xee947f73, 0xf61e2e6b, 0xb1a3ac07, 0x0f558a4d],
+    [0xe7acfe98, 0x7c24a0d0, 0x9bcf0b57, 0x0c643e41, 0x18a969b2, 0x8edd78a8, 0x0b5b5f2f, 0x2be140a6],
+    [0x9b96f631, 0x0dea06e8, 0xbace27cb, 0x12b8e867, 0xbacc7105, 0x9ad5f74e, 0xa9f09f7b, 0x1c49c4b9],
+    [0x597652db, 0x3847b00d, 0x6e2eac50, 0xa77a81c0, 0x76a39a1a, 0xde7f33e4, 0x2721b12c, 0x170c1a73],
+    [0x15cf27f1, 0xc3020416, 0x9241f9d4, 0xc6bfc39d, 0x68e49331, 0x4f4e31a0, 0x52f65ca3, 0x19c27d0e],
+    [0x2543ba08, 0xbed9960e, 0xa40b6b04, 0xb26b385f, 0x276964da, 0x1a731fc5, 0x54f9220c, 0x2f1bdc52],
+    [0x8d4a1cf0, 0x54b639d2, 0x855cd911, 0xdc6d921e, 0x9cabe9d0, 0x9d2be933, 0xbccbf4d3, 0x05b42d2f],
+    [0xf61e0935, 0x88188d71, 0x2fe9d06f, 0x5fd32ac5, 0x0c05a42c, 0x9f4f410e, 0x15a41ad5, 0x12200407],
+    [0xece1343c, 0x9144ebb4, 0x1dee916d, 0x4925ef87, 0xbb9507dd, 0x946609f7, 0x26155b83, 0x25f95265],
+    [0x34742444, 0x7978a95b, 0x45241914, 0x14a8a5d0, 0x204951e3, 0x740b6d0e, 0x84299ae7, 0x017bfe42],
+    [0xeef089cf, 0x1cf8b359, 0x3578c411, 0x88d2ceaf, 0xb6708f15, 0x78a9674c, 0x021ca71d, 0x2a5d4764],
+    [0xcc0815da, 0x2a7b7d89, 0x2514d29b, 0x7b8d070b, 0x5cef845e, 0x081321fe, 0x745fbe3c, 0x17f02dab],
+    [0xc89ef013, 0x8ee6f378, 0x7bd15570, 0xbaa1764a, 0x57fa4a3d, 0x65f4adcf, 0x6db7199b, 0x19da6262],
+    [0x38829e05, 0xd266ec40, 0xbf18634c, 0xedf75f39, 0x69366a73, 0x426c91fa, 0xfa2ed81b, 0x0f88e295],
+    [0x66148017, 0x85f49f27, 0x20cb09aa, 0x280eff7d, 0xbd7412e3, 0x48fe4ee1, 0x48546c79, 0x1fe31c55],
+    [0xdbfe126e, 0x8fdc0afa, 0x98d64363, 0xcc48b59f, 0x1b2f91e4, 0x38bdde56, 0x3bdbf67f, 0x10fdc161],
+    [0x93127bef, 0xa6ef7f00, 0x03fdd6bc, 0xbfddad1a, 0x0000e33e, 0x08b9c52f, 0xebe95745, 0x1f2618c2],
+    [0x2a745dd3, 0x269a0dba, 0x3cf98e28, 0xda11f595, 0xd886f394, 0xab23dba6, 0x3efac6a8, 0x129fe7fc],
+    [0x102448e3, 0xfae60523, 0x72beade9, 0x3bafcfee, 0xb85ca4ba, 0xc1631d4a, 0xf1e4f820, 0x15afd4cd],
+    [0xac99b012, 0x34956079, 0x45528cb7, 0x51a7bb8b, 0xe15b2688, 0x0d728e71, 0x5c367e37, 0x1f2c74ba],
+    [0x786dad3c, 0x6e09def6, 0x9c9c36b2, 0x19f01fa8, 0x95edf9ce, 0x369cf59b, 0x2d76f2f9, 0x1130e187],
+    [0x5c1b1dca, 0x3327533a, 0xc6865649, 0xe242c61b, 0x1c9bbdbe, 0xb73b63fc, 0x3f7e6bad, 0x13523d17],
+    [0x7a574cd4, 0xddadbbad, 0x54cb5667, 0x53368f72, 0xdc965386, 0xf1841b5a, 0xaf427a65, 0x14da40d0],
+    [0xa5dd8b86, 0x315976e1, 0xfd631ff6, 0x82638e57, 0xc17b304e, 0xa85bb186, 0x00e4297e, 0x0091f964],
+    [0xa002cdcb, 0x5c450277, 0x54a0deca, 0x0267bc7b, 0x9a64bb6a, 0x5b9a34d4, 0x9031c551, 0x303329bf],
+    [0x83fa821b, 0x737916e2, 0x6ecfe9db, 0x509fc833, 0x1b2e6c18, 0xf05d3c1a, 0x5c1da1c2, 0x14ed47e5],
+    [0x65e58d6b, 0x04ee94c5, 0x1c747deb, 0xdd2b0325, 0xc4a20d5a, 0x10ad53bc, 0x357775d8, 0x1161f10b],
+    [0x14eb8221, 0xbb8a96a7, 0x4cc68eaf, 0xca914eb9, 0xeb992851, 0xf22bc070, 0xe72ce707, 0x17a8a50a],
+    [0x250e4f62, 0x12b932dc, 0x3791db63, 0x645a1c15, 0x7ec5df88, 0xf99250b3, 0x95dbaf62, 0x1a6c61d7],
+    [0xf447a133, 0xa6722b15, 0xb2a0869d, 0x4b05772b, 0x8c4684dc, 0x4c4eee19, 0x8aa84066, 0x1f8bd2ab],
+    [0xc8fb3e2f, 0xfcc62f10, 0x6349d8fe, 0xcfb8cbc0, 0xcd74b98c, 0x9799f131, 0xa4f0027a, 0x1ffcb852],
+    [0x21f79f18, 0xb246b230, 0xc2db5ddd, 0xd77cd53b, 0x0f467ffa, 0x6d489c72, 0xc52f19b3, 0x035e742e],
+    [0x5f2e3d86, 0x42d175a0, 0x18d305fd, 0x55673aa8, 0x62b7ec7b, 0x83aa29fc, 0x1bdf94d7, 0x1dfaaee4],
+    [0xc4796b3a, 0x38a219b8, 0x2d1455f0, 0x45bd333f, 0x50885409, 0x5005a563, 0x77a02e99, 0x28213784],
+    [0x04897421, 0x174f238d, 0x7c19e763, 0x31ffdd4a, 0xec81c02f, 0xb1061c6e, 0xf238a570, 0x1db4a4d0],
+    [0x3b1784ca, 0xa4bb7289, 0x426aff40, 0x7d693b52, 0xa3a53821, 0xa1367b34, 0x457b20b7, 0x14bf7889],
+    [0xa9c08465, 0x109ff0d3, 0xf6049127, 0xa796d164, 0xec9a9483, 0xe57410c3, 0xbf296f87, 0x2ced52c2],
+    [0x66eea334, 0xdfd46d67, 0x4f49ae5b, 0x9d4ce658, 0xd108e314, 0xa4d0441e, 0x05a7f4ad, 0x1ddeac58],
+    [0xf042ae70, 0x3f911123, 0x1ba04067, 0xe1c9f216, 0xb5f9dc88, 0x2304e8e2, 0xe9c97b46, 0x2e36b4e5],
+    [0x2c238d5e, 0xcaee6350, 0x5ed64347, 0x164b447c, 0x65275d8a, 0x59e89b18, 0xcb0666dc, 0x0c6840d1],
+    [0x1238f58c, 0x2caeaac0, 0xb55c589b, 0x262a4d1d, 0xe8f12183, 0x1f4c9bc3, 0x89e71d46, 0x137e2e3e],
+    [0x081c8aad, 0x1d45794b, 0x4751157b, 0x82e55aa9, 0x60a36b6b, 0x84ed4bfb, 0xb0adcf2c, 0x250932e7],
+    [0x1f16b42d, 0x2b455539, 0xe2f35f97, 0xd4b00d5c, 0xb5c9c4ec, 0xdd3bf09a, 0xf5634c06, 0x170a7292],
+    [0x2a4f3b81, 0x10b2c50f, 0x7bd0149d, 0xa215df4e, 0x70f1b75b, 0xd5cbf51d, 0x77289e78, 0x0d68cbbe],
+    [0x49973178, 0x4ed24c97, 0x0b49d9ce, 0x6fa1dc68, 0x36ecd130, 0x645a6d20, 0x3b90525f, 0x0caf7456],
+    [0x7d30d42a, 0x255e395b, 0xed46cdeb, 0x166f9456, 0x4c58306b, 0x8ffe372f, 0xa27fcce7, 0x20a7d1c0],
+    [0x8a468465, 0xd3501515, 0x9cfa0be4, 0x3385e96b, 0x040e4444, 0x789b8a53, 0x6b5470b2, 0x0623f322],
+    [0x8cdcb91f, 0x009ce46b, 0x347de4a5, 0x42a56305, 0x53502412, 0x0f57d68a, 0x88c25e79, 0x16323086],
+    [0x9076f47f, 0xcb5a567e, 0x97c544ff, 0xbcfa3615, 0x11f4d0b7, 0xe3f66422, 0x6f99cd14, 0x2de4793a],
+    [0x3d728c66, 0xf09f3bb7, 0x99559ca2, 0x25a554fa, 0x51bde117, 0xad79086d, 0x9ea1b09c, 0x1d4d06d1],
+    [0xd130c144, 0xcd75e33d, 0xc4aafc18, 0x908350ab, 0x28974389, 0x9ea61c8b, 0x9a66a7cd, 0x0480e747],
+    [0x35dac24d, 0x44bfb443, 0x63e2e7d7, 0x7d786814, 0x490bcf91, 0x91246960, 0x368ebcaa, 0x30430b03],
+    [0x89c0c742, 0x103283e0, 0x241a5d12, 0x2b1f1352, 0x4b0dbdcb, 0x50f26912, 0x20127d4c, 0x0b57b373],
+    [0xdf6d2b8f, 0xb50178d2, 0x14b23376, 0x249b8dd6, 0x4f698eda, 0xe195f60a, 0x50d27240, 0x2cf48906],
+    [0x11c64184, 0x9b7d3b69, 0x9008ab5f, 0x751a2f23, 0x1a0d9703, 0x12de8685, 0x26898bfd, 0x1e221c55],
+    [0xf2eebda5, 0x2e54d339, 0xbddbb295, 0xe4ec693e, 0x9ca12adc, 0xd1a58f32, 0xad7d992e, 0x28e07485],
+    [0x79acd4e0, 0xdd5ef3a0, 0x20c12854, 0x8381c845, 0x51efb1dc, 0x67bd5ead, 0x84de16dc, 0x2f44d64f],
+    [0xf0d4fac2, 0x4021c62c, 0x7ba1516f, 0xce7167ab, 0x304edc7a, 0xe2be330f, 0x32ebd1df, 0x050a76bc],
+    [0x270594a9, 0x7d29421c, 0xd97b2894, 0x8cb46361, 0x41f6c35d, 0xd7813672, 0x5d659a67, 0x2f58c45e],
+    [0x60c66a74, 0x6fc7e5e1, 0x44325ba3, 0xdb675348, 0x370e782b, 0x0e04b230, 0xe0e42e84, 0x25e8da9a],
+    [0x96040f7f, 0x44252be7, 0xba10c295, 0x4f38a368, 0x27f84472, 0x03ea04f1, 0xa20fe320, 0x2fec734d],
+    [0xfffd5eef, 0xd6d1faca, 0xa3bbb91d, 0xcb14f4e3, 0xf15e93b4, 0xa8ed1727, 0xf3a83988, 0x288a6778],
+    [0xac922c84, 0xb9175390, 0x2715d245, 0x9a25a8cd, 0x50b3db67, 0xbe7f4067, 0x5fd89259, 0x20dcc6c7],
+    [0x4dea5dd3, 0xb04ac45b, 0xd11178c7, 0xd31cf965, 0x82a18b6f, 0xcb8a5417, 0x0942df25, 0x17f42ba1],
+    [0x55986ba5, 0x764aafd4, 0xc664abee, 0x3e9cedab, 0x48eec660, 0x020e0c61, 0xd115a904, 0x028eeb85],
+    [0x38d59d40, 0x9f8c9c3d, 0x9ecc918e, 0x5d80c029, 0x6cea7ae9, 0xd3fa298f, 0xf3a79b2a, 0x0b1d7cec],
+    [0x1ff4cf07, 0x2d23c0e1, 0x2ce1cf72, 0x1db8e3f1, 0x834a2626, 0x9c16efdb, 0x9764cec7, 0x0440339c],
+    [0xbbbdad5c, 0xb88e6b7a, 0x7eb57c79, 0xf4b07a5f, 0x7da2d504, 0x62a00217, 0x29727c19, 0x06ca647c],
+    [0x619ba9e3, 0xfc3cab28, 0x04d2ba5b, 0xb8ef778b, 0xe8f013c7, 0x3dd1a962, 0x64f5c409, 0x2ea120a8],
+    [0x697633fd, 0xdbb39cf8, 0xfb5dc76a, 0x77c479eb, 0xc1157e2a, 0x0cc2ba87, 0x6c4aee7c, 0x2bb73754],
+    [0x86803a93, 0x1a25d156, 0x449a2bd5, 0xa2c28c2f, 0x76f568f9, 0xe79cd501, 0x90625d33, 0x0e30da64],
+    [0xb7f57521, 0x131d903a, 0xc20430d4, 0xf71035a7, 0x97b2ebb0, 0xb919d854, 0x78a13650, 0x0df7ca72],
+    [0xf870e2d5, 0xa6944097, 0x90b80fca, 0x2f784c09, 0x9fb8a74a, 0x4abace58, 0x5bf58579, 0x27cc589f],
+    [0x21b325bf, 0x43c74554, 0x2f78e60c, 0x332d3304, 0x52afa842, 0xe45cedf4, 0x38c8735d, 0x2255c36a],
+    [0x5784d087, 0x60caed48, 0xca63d73a, 0x98b7d86c, 0xb05bdffc, 0x9f681c27, 0xbd3378d6, 0x133d9602],
+    [0x1dc3d78a, 0xaccd981b, 0x16c93ef4, 0x17a99486, 0xb93727c8, 0x26860699, 0x42ae9d3e, 0x0e1548e9],
+    [0x84e8a6ae, 0x8e2c8d6e, 0xe0a1602a, 0x7482e4bd, 0xf6af4762, 0xe09ec312, 0x5d93689f, 0x0f20f0e5],
+    [0xbfa51bb9, 0x54c0a9f6, 0x17b72929, 0x6232c9bc, 0xb12e51b2, 0xf2eb4e45, 0x83cb5b7f, 0x2e523284],
+    [0x660804f4, 0x4abfc957, 0xea95dd8a, 0x3a7860be, 0x74712edb, 0x6eda2abb, 0x533e059a, 0x02b2162d],
+    [0x19516c74, 0x5b3d4325, 0xc6b2e13e, 0xad733974, 0x7fb3737d, 0x38942994, 0x15d1cc6d, 0x19e09277],
+    [0xee573705, 0x74ef7f27, 0x8872ac9f, 0xffcb4604, 0xb94f17dc, 0x636303b8, 0x57d77785, 0x0d3a8004],
+    [0xfb559e32, 0x4dc8fe9c, 0x441c4e50, 0xa619b73f, 0x4996616d, 0xac5f7bae, 0x52557a1a, 0x2c974d19],
+    [0xd5d97bef, 0x14babc2d, 0xdbe8f555, 0x2c1d434c, 0xf30714c2, 0x708e1b4f, 0xede2ccf3, 0x0766bfee],
+    [0xf191e62c, 0x9c1e74e7, 0x1b2a0a47, 0xd4ca7c87, 0x55e4630c, 0x31e173ae, 0x54082fc1, 0x23dac8ea],
+    [0x340a8d7f, 0x9ad3b47e, 0x86b8eae4, 0x870ed331, 0x2d575376, 0xe3e358ab, 0x2cb37010, 0x17d5fb6c],
+    [0x178c0023, 0x3ec8007f, 0x783fa690, 0x0246e43e, 0xe3885329, 0x139b583a, 0x6d8a8126, 0x175dcac7],
+    [0xc2e71655, 0x11bf182c, 0xbf1fd2a6, 0xe5be7e85, 0x49898c91, 0xadb7abf5, 0xede5d221, 0x0c4fd08f],
+    [0x5bdfe729, 0x002f02a6, 0x28303fc5, 0x38ad59b1, 0xc766ab7a, 0x3475bb1e, 0x09e72d3a, 0x277934b9],
+    [0x0b078b68, 0x96e8cfdb, 0xf9cb9528, 0x6004ccac, 0x4f9c394d, 0xf1b18452, 0x98dfe703, 0x0e883499],
+    [0x8cbb9942, 0x45710cc7, 0xb0070a8b, 0xee673514, 0x2bc8cae8, 0x07824e2a, 0xb60b0fce, 0x1f1b2078],
+    [0x0ed334d1, 0x846e812a, 0x700f5b8a, 0x40557657, 0x82d51fa3, 0xbc316f64, 0x66c536dd, 0x2eb15595],
+    [0xbeaf6773, 0xc3ba066b, 0xc669dec2, 0x4541d623, 0xc9d243d3, 0x355208b4, 0x35cf6764, 0x1c4dbdc3],
+    [0x1241e3b4, 0x574021fd, 0x7eba5dc8, 0xdfca1e8b, 0x805dd3a2, 0x8e5cfe8d, 0xda6f8cab, 0x2374a6b2],
+    [0x733072f4, 0x874f6161, 0xe49f90fb, 0x94ef71b3, 0xfb5a569b, 0xa99738e3, 0x33ccc603, 0x19dd3425],
+    [0x2705cccf, 0xcce7f35f, 0x85132f2f, 0xc6d03d83, 0x587d2eb3, 0xfa508800, 0x6c7fb3ef, 0x217d66db],
+    [0x96c7e64f, 0x2a7fa892, 0x8d3bfc6c, 0xfca77c61, 0x8b236f9e, 0x8cd3a3b3, 0x91fe0103, 0x0815fb85],
+    [0xbf63a16f, 0x5f5093e9, 0x74b2ff9a, 0x0ff36f59, 0xb1ca88eb, 0xa6c17853, 0x0c2bd456, 0x2bb943b4],
+    [0xd3fc82e9, 0x09fec3d8, 0x14570c57, 0x81e3b4cd, 0x4ae6cba5, 0xee7cb997, 0xce659513, 0x11a5153f],
+    [0x26836df4, 0x5c9f1444, 0x3cf90e6d, 0xae74c765, 0x85a2495b, 0x1b2eff87, 0x7635d850, 0x1b72bfd0],
+    [0x1fee233d, 0xea39cf6b, 0x12bea0ec, 0x93f1d875, 0xcaf850d7, 0xae178ba8, 0x00eec897, 0x14902c07],
+    [0xd84cb3f4, 0xc1a45e2f, 0x65856dc4, 0xb745b788, 0xa6b043f3, 0x9ff90d43, 0xe0a616a4, 0x09c138c6],
+    [0x35ec10bc, 0xe024a61a, 0x783e8317, 0x7458a715, 0x95052d88, 0xa271d698, 0xce57b281, 0x05b58a3d],
+    [0x5474d037, 0x457a9676, 0xe867f024, 0x558e55fb, 0xe3bde3bf, 0x5e6b3125, 0x25c0cfdd, 0x2be8d295],
+    [0x0523d9ad, 0x69f9c056, 0x5ad6c243, 0x81bdfc1b, 0x84225d6a, 0x6b4d7f77, 0x8f1ba9dc, 0x061d72f7],
+    [0x1a0692e9, 0x9516db50, 0xb36145bb, 0x738b4803, 0xadca6145, 0xf4112edd, 0xcacffabd, 0x0bf18aef],
+    [0x55f03bc6, 0xea306592, 0x3071a7d5, 0x3a9801a9, 0x29ec679e, 0x931d8cdf, 0x5fa8b2ec, 0x2e73dd10],
+    [0x5d3598ef, 0xad1ecbfb, 0x860b211c, 0x47de7296, 0x8c927a53, 0x1337ba5d, 0x667c9ae8, 0x0f8440ef],
+    [0x95eb118f, 0x8ca73963, 0xa1380055, 0x7d14d178, 0xf93ac34e, 0x2731be83, 0x2dea627b, 0x004d303b],
+    [0xbc3d37c1, 0x0508008c, 0x313a4d48, 0x94699eef, 0x5269230c, 0xda2996a3, 0x72040a70, 0x234541ad],
+    [0x4409e174, 0x08c15710, 0xacb46b2e, 0x12ad4c23, 0xd14286c3, 0xbdd8fd73, 0x72d26b92, 0x0d123f1e],
+    [0x138545bb, 0x4f3a048e, 0xbe460a77, 0xee6b05a8, 0xa4123c05, 0x7098ee7a, 0x6f0de79d, 0x2fb36077],
+    [0x56a55326, 0xb7f99b69, 0x156ea1e8, 0x7dfdc41c, 0xcc79703b, 0x276c57d3, 0x9434e167, 0x03685c07],
+    [0x3705c1c7, 0x34c6f75c, 0x3681eb90, 0xb47aaada, 0x79344566, 0x2c1631b1, 0xfffcc977, 0x260af0e0],
+    [0x63f62767, 0x5fc2f495, 0x9ff67254, 0x916e2bff, 0x773f2554, 0x5274b33b, 0x74f89b69, 0x2862b413],
+    [0x13eba8d5, 0x192ca595, 0x400b0eff, 0x2f18b38b, 0xe691d036, 0x27189ea1, 0xe1703102, 0x02a9912f],
+    [0x281f4a6f, 0xe070348c, 0x058e1224, 0x32c713d5, 0x1a96440d, 0xac1f3ebf, 0xe694a0d8, 0x08e513ad],
+    [0x673d09ab, 0x21ac7a6b, 0x264ff80f, 0x3213c628, 0x978d8477, 0x00ed7465, 0x1e2ee794, 0x140a4a43],
+    [0x43377927, 0xee979ed1, 0xa8b53dc5, 0x1202b4eb, 0x549c3f07, 0xd959b29d, 0x19cb5df7, 0x296af4d0],
+    [0xb812b349, 0x5765470b, 0x023e3e50, 0x85573bd2, 0x939fc0f1, 0x614882b6, 0x4a7f4c81, 0x01832e28],
+    [0xd66d7260, 0x3861fd19, 0x496f2d10, 0x4fe93434, 0x329aa78d, 0x965abdcd, 0x67bfdd3d, 0x1a84d56a],
+    [0x9dbde143, 0xb0dda0cb, 0xeca75c9f, 0x9d1f7631, 0x7489037a, 0xd2aefdc0, 0x47773927, 0x040cb828],
+    [0xd3fabe2f, 0xb1a78f89, 0x2887cee7, 0xed2b019f, 0xa633bce4, 0xcaecf26a, 0x4cc29cb7, 0x010dcf08],
+    [0xbd9afa83, 0x72ca98aa, 0x7810d40c, 0x3b0fe581, 0x2b776db2, 0x4425ef54, 0x0911ea21, 0x07edc22a],
+    [0x73aaabe7, 0xd3acd481, 0xa48bd1d2, 0x936da58b, 0xa36fbb96, 0x2148479e, 0x8aec775f, 0x2eea4ab0],
+    [0x18245f19, 0x27386955, 0xfddfaa08, 0xf47d1b5c, 0xad148cf7, 0x1005cdcf, 0x257fe4a6, 0x1e40c0e8],
+    [0x4980b876, 0x3e21948b, 0xf51dc1f0, 0xf69d44cd, 0x5443e108, 0x51f436de, 0x583bd1ea, 0x23a27809],
+    [0xd49250a9, 0xdc754080, 0x051ee68a, 0x39abfbd2, 0x2fcd5f3f, 0xe63b6b23, 0x44dbfe40, 0x2e4652b0],
+    [0x485775f2, 0xf7f7d67f, 0x5db3c2ca, 0x0475eb47, 0xe9c7b9c5, 0xe7d8cdef, 0x6ecbafc2, 0x11e7abdb],
+    [0xac5cf8e7, 0x99396f63, 0xb27bf6b8, 0x47a13551, 0x2096f0e5, 0x73821f80, 0x0cc30e8c, 0x199d5235],
+    [0x78484101, 0xc949cd09, 0xf30b3fdd, 0x859a7cbe, 0xe032a37c, 0x98345624, 0xe67cbecd, 0x0f575d6e],
+    [0x772ff057, 0xf3475ae7, 0x8e5fb784, 0x184d0702, 0xa9c96b06, 0xe6265acb, 0x2ae2b418, 0x1c4b6f9a],
+    [0xabf0c925, 0x3db20360, 0x22520638, 0x626fb9bc, 0x0c5593f4, 0x228e157c, 0x896de39f, 0x2dcb5cf8],
+    [0xf870f8e1, 0x44575e3c, 0x7f863ca5, 0x0b531a10, 0xdf4bcca5, 0x6bdc26cb, 0xf4e11318, 0x1340abb9],
+    [0x3b1d1a5b, 0xc48a8cc7, 0x460cfb74, 0x6f59d2b4, 0x8e714e00, 0x8870ea88, 0xb72787cb, 0x2368e692],
+    [0x756f54b6, 0x4b7dc852, 0xa94f9637, 0x922a7632, 0x775c6f2d, 0x56f23165, 0x9baa4a4f, 0x1fab9add],
+    [0x6ab8680f, 0x220571c1, 0x20d9765d, 0x4c4c015e, 0xa1e4add5, 0xe3f8957b, 0x300d3c6c, 0x0c7f7b82],
+    [0x922eff03, 0x578a4c3a, 0x9f1f99ab, 0x6947929f, 0x5d9c8f3c, 0x6083688e, 0xbeacd93c, 0x15d63e86],
+    [0x74023d9e, 0x929355ea, 0xc0607862, 0x05d36b12, 0xf7dae8ed, 0x2572178a, 0x5f9b07e5, 0x0be843ae],
+    [0x2a7a0c9a, 0x31058ec9, 0xd950c0b7, 0x05967ce1, 0x3a07e81b, 0x6935e096, 0x523694cb, 0x1332749c],
+    [0x5b9b9682, 0xf009bffa, 0xa51029ec, 0x892bb95c, 0xb9815fee, 0x0bdd4598, 0x810e074c, 0x25439408],
+    [0x55f9a742, 0xdff67feb, 0xb0f64996, 0x9fd15db0, 0x40b383ba, 0x8da29b09, 0xdd999a91, 0x057e8d19],
+    [0x1e70a29d, 0x57fa43f0, 0xbe98e9e3, 0x3534d0a6, 0x99f2b55d, 0x3b4870d9, 0xe9b117cf, 0x1e014e37],
+    [0x084c6479, 0x63f6e889, 0xc851b4b8, 0x597afe15, 0x533dc966, 0xd6bdb100, 0x6e03aebc, 0x1a4ed24e],
+    [0x3ce36a90, 0x710452f8, 0x9f053f27, 0x9ed4111c, 0x0905c103, 0x863ad476, 0x7083f1aa, 0x25342000],
+    [0x2759ab06, 0xeecac0e3, 0x55c575dd, 0xc45ec721, 0x9320001e, 0xffe6d2a9, 0x1971709a, 0x2276a144],
+    [0x31dfa7b0, 0x3302fe7d, 0xe1281f75, 0xadf7ae8a, 0x19325853, 0xfd3411eb, 0x218ea799, 0x28957dd1],
+    [0x7902a008, 0x7ddf01e3, 0x4cf80226, 0x6692f291, 0xaa3e7f1e, 0x8bd75796, 0x6ab794c8, 0x2fd92572],
+    [0x4705fd9a, 0x636a2867, 0xd82966c4, 0xb47c0c43, 0x9253cd55, 0x47c8238d, 0xc76a84b1, 0x1cf8a5c9],
+    [0xa41e9108, 0x74b8c359, 0x4a1db9c6, 0x0b4b2e2e, 0x8715e623, 0x9e707736, 0x306e1bab, 0x0373cbbc],
+    [0x23290a40, 0xab5920bf, 0x31ce0c1e, 0x48bc48f5, 0x0b3dc624, 0xf513d911, 0xfe7f23df, 0x060283d2],
+    [0xaa40263c, 0xc8bbfadf, 0xd5a3527d, 0xf8119bdf, 0x0eadc71b, 0xf33ef6cc, 0x6d910740, 0x0dab465d],
+    [0x2439c4c3, 0x6f3446b6, 0xdc0ca534, 0x86cd6421, 0x977230a6, 0x8e4aba17, 0xc8224b2a, 0x0cba7bcb],
+    [0xdea6911d, 0xbd68bcb4, 0xb4076ba8, 0x1db96c38, 0x3c466ff2, 0xf445b065, 0x0790c9c4, 0x1e4365db],
+    [0x43800a8d, 0x8de98fc6, 0xab42ced6, 0xca13d60e, 0x9de7f6c5, 0xbc86ef5f, 0x199a9ab3, 0x1bb2dba2],
+    [0x2021f9ca, 0xae812e08, 0xe80bfbac, 0x913d9d62, 0x123cd1a2, 0x0eebad52, 0x0c6d6ef4, 0x0ad3c187],
+    [0x27245308, 0xc0ce3a26, 0x14935630, 0x5300ddf6, 0x77c0ddf9, 0x5c345880, 0x1e7b0cbb, 0x01b098c9],
+    [0xe55b9c3f, 0xae41b576, 0x493b6d72, 0x98b0c168, 0xea3b1e29, 0x598bd4ce, 0xac14fae7, 0x19fd5c0e],
+    [0x95bd7e18, 0x4ccfa6ab, 0xe67b7e7b, 0xc6434f3f, 0xa46fe427, 0x7110a404, 0x9cc163f1, 0x0d4749d7],
+    [0x40dbfdcf, 0x6d40ada4, 0x728c8a51, 0xeb940ad8, 0x99241232, 0x09e0b333, 0x14a41bb8, 0x1ebbfe81],
+    [0xf4b4c1b8, 0xbbfba9ba, 0x0eb9faea, 0x3e3937f8, 0x49d83322, 0xd6d3f17d, 0x133d9764, 0x2704e5b6],
+    [0x14d035e3, 0x90b6913f, 0x10c3f9a3, 0x540d13d7, 0x71635e5d, 0xae0e3235, 0x027305b1, 0x2165e1c8],
+    [0x63aa8b8a, 0xe65081ad, 0xa4aceb60, 0xe55d4eeb, 0x3bc3a0f2, 0x6c06afa6, 0xd35fda59, 0x2e3497e4],
+    [0xaa841edf, 0x79ab5c57, 0x6d738f4d, 0xdd1fe054, 0xc7815d7a, 0x6c0f7b07, 0x5eecd6db, 0x031da434],
+    [0xc4a20876, 0xc9cd19ea, 0x006acab1, 0x36967089, 0x08737c14, 0x08e43e5f, 0xe47aa5c9, 0x089ece54],
+    [0x9d10d847, 0x7d0552ed, 0xf8aa4067, 0x7f3821fb, 0x0483e6cc, 0x47f55a07, 0x2aded33c, 0x2f53c15e],
+    [0xed077f93, 0xafd72bef, 0x094801ea, 0xb0aa2857, 0xe39c00c8, 0xdf7a21b3, 0x4b2e8ad0, 0x142aa34f],
+    [0x7c7ff2bf, 0x33198246, 0xda4e6ace, 0x9dd2725e, 0xeb8b4097, 0x7588b014, 0x4c7bcf0d, 0x17aea4da],
+    [0xdc0d1727, 0x27742b20, 0x86f02f18, 0x63f9a417, 0x8f022003, 0xc465104d, 0xd19748d8, 0x0e970c19],
+    [0x476df9b9, 0xba3a9823, 0xa0b77f1a, 0x32fc45a0, 0x14a6bdb4, 0x2f59baf7, 0x55379564, 0x04bcad9e],
+    [0x0315d0ce, 0x84c87f81, 0xb1901fa4, 0xfed671c1, 0x825c71fc, 0x15dbd4ba, 0xcdaa76f7, 0x242c0bfb],
+    [0x4fa9e790, 0xa11b1911, 0xefe85af0, 0x078311e1, 0xd34d9909, 0x1023fb6e, 0xc2410407, 0x25db1343],
+    [0x86036a80, 0xdeb36777, 0x898a3942, 0x7ca147cb, 0xb2bf3518, 0xcdc7c31a, 0x420a59e9, 0x2ffe4d9c],
+    [0xc7cd07a3, 0xe98f283b, 0xbe696483, 0xd0d1ba63, 0x90eb3bf8, 0xbbe6f5b5, 0xf3e2cf18, 0x125bb03a],
+    [0x8745c6bc, 0xac7b7594, 0xeba50037, 0xfd8b719d, 0x8e24ea60, 0x4ceffe5b, 0x745b7dbb, 0x0816be42],
+    [0xdedb35d4, 0x1d2ee4c5, 0x42005547, 0xcbe23e64, 0x2313a0db, 0x0d1b6a71, 0xacf6ec36, 0x111160f9],
+    [0x435c9391, 0x283d8203, 0x42342419, 0xfb59ab95, 0x7941bdf8, 0x25fa8e7b, 0x1b1f6a89, 0x1377978e],
+    [0x3d0d3397, 0x7e421a51, 0x1937cee5, 0x83d38462, 0x4f236fba, 0x3ccde53a, 0x882166ef, 0x0defc1d8],
+    [0xa0f0debf, 0x50e67b5b, 0xb88a7ef3, 0x46d7e94c, 0xa7a39530, 0x5d4a03f2, 0x8c706e3a, 0x2f8fa5c7],
+    [0x8da94827, 0x30dcb9e5, 0xd84690c2, 0xeb00e6dc, 0xab0bf17b, 0x61c2134b, 0xc0a723da, 0x1a2a957e],
+    [0x805addef, 0x1ddf37e2, 0x167a8dfc, 0xfd01f0ea, 0x699532f9, 0x412b4a7f, 0x995f5e03, 0x1cdf8710],
+    [0xecc18946, 0xe3765eeb, 0x9b301699, 0x0c4fe523, 0x48054b0c, 0xe36c27b7, 0x1828c36a, 0x26fd3147],
+    [0x9d2fa0be, 0x926143b9, 0x9fea3f7b, 0xe11a921d, 0xf9007647, 0xf303a2c1, 0xcc2c4456, 0x0775d996],
+    [0x18bb8900, 0x2addd1bd, 0x7b206267, 0xb97224ef, 0xbd0daea6, 0x8cdfda91, 0x7708ca63, 0x016fb933],
+    [0x054117d7, 0x6f76f119, 0x56f184af, 0x12582553, 0x8bb43933, 0xfca4f947, 0x7d3fde42, 0x2c392fbe],
+    [0xb486bcb1, 0x28aa3de1, 0xece77aa2, 0xdb47c55e, 0x9f1fd56f, 0x3e5089ef, 0xf79a69fa, 0x187a2a3b],
+    [0xfd5d20ce, 0xe506b275, 0x359532f3, 0x78c98630, 0x10b2edd2, 0x41ffa335, 0xa280a326, 0x0271a863],
+    [0x6516f2ab, 0x718958d9, 0xe897ec0c, 0xfb69537c, 0x9a1e3112, 0xa00e5af6, 0x9c74c94a, 0x1557459c],
+    [0x0f109f21, 0x50c6dae3, 0x58168c2a, 0x083d539e, 0xa9dcff89, 0x6388516e, 0x8d647d9a, 0x2a8e26ca],
+    [0xf26832cd, 0x7924b710, 0xc2a3fae9, 0xde2bcfb1, 0x0040eba9, 0xb51942eb, 0x94cf43f3, 0x21cb7521],
+    [0x484377ec, 0xc137e7cb, 0x22188d02, 0x5a471e63, 0xad60d38b, 0xcd6dd4ac, 0x96be247a, 0x2c26daf9],
+    [0xa2d26f1b, 0xf8c137bc, 0x0f5d058c, 0x26821acc, 0xd3e3a38c, 0xebe92a68, 0xe0e7982e, 0x0240176e],
+    [0x117318f2, 0xf2a0d18f, 0xe1ad47ec, 0x84cdaf6b, 0xdaaa8d0a, 0xd974dd78, 0x3c865c1b, 0x2636e097],
+    [0xe1744ac0, 0x7be5d9fd, 0x44cee89b, 0x81f6e067, 0xc5d975b6, 0x6041661d, 0x25a79949, 0x19e84f4f],
+    [0x454ca110, 0x664e89e1, 0x26c4fb26, 0xe271e0b3, 0xf3570188, 0xca1f36f6, 0x4a3af247, 0x0ebf8906],
+    [0xe22e71ec, 0x8f7fd73c, 0xecb8ac69, 0x4c9c531f, 0xc452364f, 0x8b559a38, 0x475be00e, 0x25c7e97b],
+    [0x598ad74f, 0xd4429ef5, 0x0a160afe, 0x4d8c5527, 0xadd901c1, 0xaecaa302, 0x592353e5, 0x0444c99e],
+    [0x4b5c994a, 0x519aba76, 0x0ba53b03, 0xe144967f, 0xf02e4e79, 0x2693d0e0, 0x7830565f, 0x138db888],
+    [0xb2f21edd, 0x2bb13cbb, 0xdc75c3b5, 0x4e1ae365, 0x74b4fca2, 0xe02aafcf, 0x2114fe9e, 0x24d40f46],
+    [0x61d68e8c, 0xb2951b4c, 0x2db148f4, 0xcf3a8a73, 0x0b5df4c4, 0xbca40e73, 0x8ee43760, 0x21e65d6d],
+    [0xd1ab6d1c, 0x00978f13, 0xca14d513, 0x9bdaacef, 0xd6c75c6a, 0xf0350a15, 0x69ec09db, 0x248dd796],
+    [0x924d5362, 0xe8d5aba7, 0x39edf69f, 0x0ebda7e6, 0x91e7b48f, 0x29fd35cf, 0x548f9cbe, 0x2b8238c1],
+    [0xcf6f62f7, 0xa94cbdde, 0x4f6b8942, 0x7235eba9, 0xf513f1bf, 0x81c7be3c, 0x9257f381, 0x2439fd23],
+    [0xe893dfec, 0xe33d2bb3, 0x2f5de7c2, 0xc74b6d26, 0xa3282a18, 0xa2c4be0a, 0x5281a61b, 0x20095823],
+    [0x90c17f12, 0x2fcc2331, 0x1fa9b2d0, 0x19fad821, 0xe5befbb7, 0x0cfa7e1f, 0xf88ee5f6, 0x0e1eca5d],
+    [0xb842ca24, 0x158ee4b1, 0x32d001b7, 0xa169c127, 0x0fc60881, 0x769d9c66, 0xf9b3ea2c, 0x26b53427],
+    [0x8db2b1b4, 0x67402cff, 0x22b9d70a, 0x8fa28125, 0x1b3a8f77, 0xaf3e0666, 0xacafe9f8, 0x20f3b3f4],
+    [0x7d463b17, 0x928ba6c2, 0xef4ec286, 0x9f97506d, 0xe8cacbac, 0xa7a627ec, 0x39d62520, 0x211e5d2b],
+    [0xe536b4b4, 0x42d13832, 0xce572f62, 0xcd085313, 0xed9d302d, 0xc556731a, 0x34802129, 0x0bb743ee],
+    [0x40eba5cb, 0x2977e221, 0x4f66b653, 0x5d8a4409, 0x23ae4bd2, 0xe4c753ff, 0xb488ee71, 0x23cb2661],
+    [0x807b8bc9, 0x35323a47, 0xc93ef31d, 0x6a5205b9, 0xd3359f5f, 0xb4535bae, 0x123911cd, 0x03a35aa3],
+    [0x2714b697, 0x2d791732, 0x4ade74a6, 0x217e137f, 0x6635e450, 0x93fa943b, 0xa0aed96a, 0x27803848],
+    [0x996d6f67, 0x31bd8162, 0x6d615382, 0xbe5e47c7, 0xc75f41e9, 0x79884cbe, 0xc2c9a7ff, 0x0cb37839],
+    [0xc00d2f93, 0xdbceef77, 0x4c3cb218, 0xdaa1ecdc, 0xfec1fb5c, 0xd5423cc2, 0xbf1f8e1d, 0x1f0026d0],
+    [0x708273c9, 0x8eef42f9, 0xf5b5fb92, 0x6da3b100, 0xbbba956b, 0xd2ee66fa, 0x970b8a6e, 0x02a7d7bb],
+    [0xbbc491fa, 0x51e4ee40, 0x90aa4943, 0x5ad1ab2e, 0x58b0bc60, 0xda17ec32, 0x15e434c8, 0x0cfd7f42],
+    [0x700a7cde, 0xed9ac339, 0x682230c5, 0x1659be69, 0x7f6e7f57, 0x6849dc6f, 0x20622a15, 0x180b11b7],
+    [0x94c65011, 0xabfe578a, 0xce7eee1a, 0x2c871349, 0x57c95158, 0x0a24a4a4, 0x5bce3d3a, 0x04e96a96],
+    [0x160df3ad, 0x6a1522ed, 0x1d30a5a4, 0x353a23fe, 0xd1f7705c, 0x656f2cdb, 0x2b45f7fb, 0x15931f78],
+    [0xb9dd38b1, 0x8a0d7b97, 0x5f703ad8, 0xa5444c1c, 0xf55ac963, 0xacd26feb, 0xc97a5309, 0x2e296e57],
+    [0xd13eaca8, 0x952f97ff, 0xce8f0419, 0x1bde5a5b, 0x1e06d427, 0xbb262f9a, 0xb78e846b, 0x26157bce],
+    [0x1c79ee43, 0x6e34fb9d, 0x8eff3ca3, 0xb61b1324, 0xf19c0c56, 0x1897f675, 0x847d6b0f, 0x2194eb89],
+    [0x8ffa274f, 0xb6b3d27c, 0x447008da, 0x57e15501, 0xde96ea78, 0x9151ad7d, 0x47765689, 0x2350bf35],
+    [0x10b7752b, 0x3f0469d1, 0x40819e00, 0x54054353, 0x49c4d179, 0xaf09c58a, 0xe591cacd, 0x1a486f0a],
+    [0x42a8c301, 0x9bc3105d, 0x7df309d5, 0x3571402b, 0xa4065e13, 0xa834d455, 0x6fb23cc4, 0x1b56dcf7],
+    [0xe3bb6925, 0x233ab59c, 0xeab7b685, 0xb4bfb65c, 0x04c653f2, 0x02913ef2, 0x64af0b72, 0x1a749d79],
+    [0xe3beadbb, 0xdd1f321b, 0x787e37cd, 0xbf97f8cc, 0xa4e8f0eb, 0x6af762ff, 0x73f96969, 0x18ae5900],
+    [0x7103f249, 0xde9cbb31, 0xda59294a, 0x44b1c5a9, 0x9a994211, 0x460d5e76, 0x5d82dde6, 0x21c47b27],
+    [0xcd1518fb, 0x2102b082, 0x6cc32b27, 0x5c38d5fd, 0xb6320797, 0x27364e8e, 0x52e737e5, 0x0473ddbd],
+    [0x02b26e02, 0xd5945743, 0x0515f902, 0x5ac4e6b0, 0xc8424e64, 0x1d892657, 0x5b6d3a88, 0x0b12fac9],
+    [0x3e3e2103, 0xa7cdcef6, 0x425d837a, 0x2c5f07ff, 0xc284588d, 0x57f8f73a, 0xa260cf66, 0x08ae7616],
+    [0xe926ce28, 0x888ef819, 0x7badd58a, 0xa83c6975, 0x32fd2834, 0xe873bf2a, 0x76280b80, 0x039daf68],
+    [0x869d130d, 0xf9c783ca, 0xf06ef766, 0xf2d8efef, 0x546c8e09, 0xf13f0b56, 0x470a3c75, 0x25e7b1d7],
+    [0x2c2a9e7a, 0x2da3f1ea, 0xeb9c7526, 0x11b5b15a, 0x5f98584b, 0x184d0343, 0x4c3ff764, 0x1e8fd363],
+    [0x0993f9a4, 0x7a35484e, 0xc0cafdd7, 0xff11789d, 0xc281f05a, 0x415dd1e3, 0xac37808a, 0x241dcc51],
+    [0xce9d7547, 0x4b8b4078, 0x4fa0781a, 0x9bdafe1c, 0xbe057374, 0x5932cea2, 0xc56ef975, 0x1ffc3153],
+    [0xfd3a17ee, 0x29bd9543, 0x6031adec, 0xc0fa00b6, 0xc360f92b, 0xc88a4d43, 0xd9a3e510, 0x17630d62],
+    [0x9ea4a6e9, 0x301a0ab5, 0xc16a82a4, 0xabdb236e, 0x8d2ccd5f, 0xd69db545, 0xdd1d74e3, 0x2980400e],
+    [0xfcd00cd4, 0xfa69439b, 0x36dcd648, 0xb16194c0, 0x35726dbf, 0x6dcafcad, 0x366123ec, 0x3034fb24],
+    [0xd7232df0, 0x680dca8a, 0x46041e04, 0x392995ce, 0x648be0a2, 0xf3db2ab7, 0x189ca9df, 0x1aa7e8f4],
+    [0xf5d7800b, 0x39dc5d11, 0x153d803b, 0x646eae14, 0x497da167, 0x372f60eb, 0x34a69e62, 0x1fa195f8],
+    [0x333036d3, 0xa0d7bafd, 0x453f5b93, 0x3c5477e8, 0xee5e5699, 0x95ad5a6a, 0x4d5fbf61, 0x0f23f1c7],
+    [0xa1515729, 0xd9370505, 0x20a34fb9, 0xb3caaf0a, 0x89c97151, 0x5ab50aa0, 0xc9348a73, 0x016556fa],
+    [0x45e5f70b, 0x6f6e5df4, 0xa1879b8a, 0xacb0dbbc, 0x33f0c77c, 0xc5aeef06, 0x3648110f, 0x23d92b79],
+    [0x23f23b73, 0x05d611b8, 0xd79a304b, 0x686ab40f, 0x13031df2, 0x39bbc43c, 0x5e65e2f2, 0x2e4c10ec],
+    [0x0a8d2953, 0x9329fe5e, 0xb78ec2b7, 0xfc5a3dd9, 0xd9c32ffb, 0x5e16864a, 0xd77b63ad, 0x12418bbf],
+    [0x5185696d, 0x93e6c58e, 0xb6bebb12, 0x06a78f72, 0x48431d2c, 0x5b76a9e8, 0xe15abc1d, 0x1e4a8aac],
+    [0x76deb755, 0x8092d961, 0x1d133148, 0xdf17a3ee, 0x09acf92c, 0x872c20ea, 0x7decdbd6, 0x0f3e9610],
+    [0xc2357067, 0x97a8f9f0, 0xddba6ce8, 0xeea32498, 0xbd951681, 0xc21deb0a, 0x207f395c, 0x012c3780],
+    [0xac221421, 0xe2477ff0, 0xea10c61d, 0xe5659d03, 0x769301f1, 0x1c1bb901, 0xe672ba1b, 0x13eab1b4],
+    [0xaa30323e, 0x8b9c4635, 0xb24e31b4, 0x1a27a4e8, 0xe37a1ecb, 0x6c06629c, 0xbb20b745, 0x20dc664a],
+    [0xb01e000f, 0x175c3dec, 0x24f5b391, 0x57e9471f, 0xa8cfb1b0, 0x36785e76, 0xfea78e2c, 0x2c6b1e2c],
+    [0x40e0c5ab, 0xb2ac9037, 0x70400481, 0x70ab4c2c, 0x729c2a86, 0x3da96355, 0x55f5b7af, 0x188c9262],
+    [0x30eb54f2, 0x0e0870bb, 0x2976e3e3, 0xab89f266, 0x384fd076, 0xe9e6fba6, 0x8e09e3d6, 0x2f991320],
+    [0x2b024086, 0x49a6ae91, 0xa1f3e9ce, 0xfb60b545, 0xb5628d26, 0xe714f720, 0x90889706, 0x2b33803d],
+    [0x7e748b09, 0x3c9f5707, 0x7e738c13, 0x097086c9, 0xe2abb36f, 0xcb5cc3dd, 0x0eb04327, 0x26ccabc1],
+    [0x22b4d5df, 0x2531b76c, 0x0d79e82a, 0x2b0c3dc2, 0xf246751f, 0xb75fd931, 0x7c54408c, 0x1b16ae0d],
+    [0x3d415ae4, 0x1f5438fe, 0x1344742d, 0x9518b251, 0x840c94ef, 0x44f49f0f, 0x1bd8af28, 0x11d0bb46],
+    [0xf655571e, 0xd647259d, 0x673e504a, 0x33ec420d, 0xb338e891, 0xd0118d3d, 0x7b476bea, 0x23303184],
+    [0xed8c4dca, 0xb6ed1841, 0x387b06ae, 0xad29c164, 0x9b1846c9, 0xeb3c92dc, 0x95bee438, 0x1f84e978],
+    [0x27f801d6, 0x22731933, 0x3cf9452d, 0x4536010c, 0x054f0962, 0xb5f45bed, 0x542f0c2b, 0x277f7f9b],
+    [0x82ee57a9, 0x1535c446, 0xc2307716, 0x7d27e80f, 0x6645641b, 0xaac13d2e, 0x69e5068b, 0x1efc9c90],
+    [0x0fe5ff44, 0xfaf988b2, 0xf25fe104, 0xa99e64c3, 0xbf3d5257, 0xac4f14b3, 0x761e2efb, 0x0d6ec477],
+    [0xccf93013, 0x51881439, 0x1aebf002, 0xf203bef2, 0x59b7cae1, 0x7d16173b, 0x5501999b, 0x0e0e7d7c],
+    [0xea326ea6, 0x6ea3d36f, 0x6e44dcdc, 0x77de53ca, 0xa23391cb, 0xc91c2058, 0x4f12c6dc, 0x217bef2f],
+    [0xacef68ba, 0xe8cf1226, 0x8a389c71, 0xd29b35aa, 0x3fe44447, 0xb50f817e, 0xadf01531, 0x05780c88],
+    [0x3d67b4bd, 0xfb89a3ee, 0xea5939eb, 0x445e47d7, 0x90196e23, 0xc90d8e49, 0xa6b2b3b4, 0x18733887],
+    [0xb231b07a, 0x20c4f15d, 0x37b09057, 0x85ba597d, 0x24b8e9da, 0x1c9f2bee, 0xacfcae0b, 0x20bace63],
+    [0x08b00d8f, 0xc92b42c8, 0x6abcaf3a, 0x6bf55087, 0xeced73ed, 0xac527ee9, 0x375a6786, 0x166ea595],
+    [0xf917c74e, 0xb00c488c, 0xc2e7a80d, 0xae2f853e, 0xd6f52676, 0xcf43e322, 0xeff4040a, 0x304262a9],
+    [0x57245b6b, 0xd7709e6f, 0xa055114a, 0x05b6e8c9, 0xb12d36f3, 0x6db78cd0, 0x50166e5f, 0x226bac70],
+    [0xdf2388cc, 0x582717fb, 0x1df1d638, 0xc5c3eb4e, 0xa954505b, 0x6aca91ba, 0xc573829f, 0x26b2f539],
+    [0xe769b551, 0xf1cff252, 0x8ad200f2, 0xee96297f, 0xaf65db02, 0xa5e4c6c4, 0xcd52e93b, 0x06a0fbf4],
+    [0x3368ff23, 0xfb29e6aa, 0xcac321b9, 0x24e58b6e, 0x60fb5289, 0xaceac883, 0x12d35341, 0x2cb9c241],
+    [0xd9301521, 0xac8fe8af, 0xfcc0b13a, 0x9129eb87, 0x552a4466, 0x07fe08a3, 0x607526dd, 0x20e88a4d],
+    [0x36ad94fc, 0xbaedb9d5, 0x51de325f, 0x01dd8faf, 0xf91dd654, 0xa72f396d, 0x2bd73e3b, 0x1544649a],
+    [0xf6960105, 0xeaf3ecba, 0xbfd80ed9, 0x3f8eb354, 0x7713258e, 0xc44da7e1, 0x57995712, 0x19800774],
+    [0x23765ef7, 0xec64dac4, 0xaa5d2091, 0x914b82ff, 0x7fc4e4f6, 0xd3c085f9, 0xf13e7705, 0x25d1d22f],
+    [0xe0d24cfc, 0xb8c0399f, 0x54abcaf6, 0x067445cd, 0xf5581e74, 0x035a464f, 0xf556efe1, 0x2fec990e],
+    [0x9bc4ec61, 0x416c4ae4, 0x2dcc1330, 0xc579379c, 0x13545012, 0xf3e4830e, 0x06d9544e, 0x1bd95635],
+    [0x14f23010, 0x7edec01f, 0xd480e7dd, 0x7b225a80, 0x71fc2973, 0x56dfa855, 0x7ba6003c, 0x00affcd1],
+    [0xc0dbc18b, 0xb2e0762b, 0x67a477b2, 0xc7b23cf7, 0x2d883128, 0xf07f919a, 0xef966881, 0x23670dba],
+    [0x947f0edd, 0x860f6d96, 0x2dd9f02f, 0xc66fd7ae, 0xbeea6389, 0xe22fd17a, 0x917394c7, 0x1f93a532],
+    [0xeb40be37, 0x734e2c5b, 0xb6a50934, 0x5512be07, 0xc0e74f55, 0xb0213771, 0x537b7d61, 0x2de42e9f],
+    [0x55e88404, 0x456bb96d, 0x782c885b, 0x67f78b37, 0x2931662a, 0xd37d3bb2, 0x5b99781c, 0x25c557f4],
+    [0x2be7662c, 0xfa74ea9f, 0x16f8e254, 0x647731bd, 0x8a3c4aff, 0x88538a7f, 0x09705c98, 0x2074c8b7],
+    [0xfd573acb, 0xfc3a0b60, 0x5740db7c, 0xb74ef206, 0x71d6a4e4, 0x949e4421, 0x56298138, 0x27383559],
+    [0x6a0caa56, 0xdb71a9ea, 0xc519871f, 0xb7d0c41c, 0x9659c65c, 0x96977864, 0xa4ebeb81, 0x13d36ad0],
+    [0x85e376b0, 0x43b79b80, 0x691bc2e3, 0x06b462a1, 0x96177168, 0x8c1acfbf, 0xa4138134, 0x08a2c18b],
+    [0xee7b612c, 0x8457882f, 0x9822b108, 0x41f59c07, 0x57ad9a10, 0x5807bbf5, 0x395aed28, 0x059092fc],
+    [0x71cc24e3, 0x67f6b283, 0x531dc705, 0x906f15e8, 0x522a991a, 0xd8cac119, 0x1b5a5ad2, 0x16192415],
+    [0x4ba87a1b, 0x854b309e, 0x4d72f3bb, 0x9a298b9a, 0x6341b8e6, 0x075405ef, 0x7aa17659, 0x1c68ca8f],
+    [0xc5ce5592, 0x3aab05ee, 0x7e7bdddf, 0x59a15aa3, 0x2cf73ae5, 0xf7239a4b, 0xca1c8207, 0x27f5d03b],
+    [0x9833c169, 0x564a7e63, 0x7622f2c2, 0xb4686155, 0xa8fc465f, 0x5d347ea9, 0x46962a97, 0x0ecbff48],
+    [0x1f3ac567, 0xd64bc052, 0x59855f5a, 0xf2ec8daa, 0x7ff8e46f, 0x456cfc5a, 0x363d8b5b, 0x277c4de2],
+    [0xd2fc08b2, 0x95d175c8, 0x2b63226f, 0xfec99f83, 0x3f4fbb57, 0xb7a46479, 0x52acd351, 0x1b11862c],
+    [0xaa51234e, 0xe1527201, 0x3dfb3c73, 0xbd86c92e, 0x565cb4c8, 0xdd7218eb, 0x84c74ffb, 0x06a719c5],
+    [0xc7d126bd, 0xa313abe1, 0x195c1d77, 0xd533584a, 0x8c836b99, 0x7f7ce9a5, 0xecb79987, 0x230e4ade],
+    [0xca7de522, 0xcca85830, 0xb8ea51e8, 0x88628173, 0xe6cbad6c, 0x67a133cc, 0x64809c47, 0x10b109b8],
+    [0xd14cc591, 0x24bf6200, 0xaaeb5a8b, 0x538d3b4f, 0x3636f377, 0xb1526b25, 0x70dcfbd4, 0x0e211179],
+    [0xe7ba4778, 0x1db46bb3, 0xdb35c707, 0xf55607eb, 0xeeb0e64f, 0xf6b17939, 0x78401362, 0x26673499],
+    [0x246be02e, 0xd3ff02ee, 0x039babf6, 0x0d86c72c, 0x304c267b, 0x29a13bfe, 0xfda505e0, 0x05000fa5],
+    [0xbe8c2e20, 0xe8174cc9, 0x436a9bfc, 0x089087b2, 0x0a34a284, 0xa6024222, 0x4aed5f41, 0x264d9e09],
+    [0x46315c47, 0xe7248917, 0xe114f3c5, 0xc92f24bf, 0xf401edd2, 0x30ff622c, 0x4743de61, 0x08076f9c],
+    [0x8e641b64, 0x2151d6d8, 0xb95bbf5c, 0xd7cede94, 0x0c25336b, 0xd57f2a52, 0xddbb0b1d, 0x132370ab],
+    [0xf602ddcb, 0xd24958ed, 0xc17f28d8, 0xb615c8c4, 0x67890882, 0xdfd4465a, 0xb7a227bf, 0x08ff1116],
+    [0x94597520, 0xd8a3e0ab, 0x2332e648, 0xc6cd9856, 0x9460fdd9, 0x2b7e9c1c, 0xb8b9e3e0, 0x2bcb0b0d],
+    [0xd21a943a, 0xbf230d20, 0x97f903b0, 0x76f7abd5, 0x17b03ab9, 0xd7f8a6b1, 0x6881beca, 0x12ea68ce],
+    [0x06fdda7f, 0xa5ea6b74, 0x74e0d8fe, 0x8d5f88e2, 0xb6fdd7d7, 0x7a097b19, 0xa7668806, 0x27439c98],
+    [0x02a99e36, 0x5f00499d, 0x493937f0, 0x8419e393, 0x0ad71ebc, 0xe31ef944, 0xd05f5652, 0x02f40d0a],
+    [0xcc7abff5, 0xb63059b8, 0x4b9c5b14, 0x0d13acc7, 0x390e309d, 0x680f06bd, 0x4327ee4f, 0x2fbf0428],
+    [0x21df86e7, 0xf6b6723c, 0x7edbf247, 0x6978c702, 0x51425cfc, 0x7a828b06, 0x3e2a8ad5, 0x1be686d5],
+    [0xa78bb57f, 0xe348c47c, 0x17bfefc0, 0xe9f7ad8b, 0xb4220fcf, 0x96852f14, 0xe85a508f, 0x2683b425],
+    [0x0a9dd951, 0xeddfc0de, 0x3e1693e0, 0xbe61a1aa, 0xe672d86b, 0x1db1c7eb, 0x2e8012e3, 0x16dace9b],
+    [0x545bcedb, 0xf30430fe, 0xb75aa139, 0x8b4539db, 0xef2e3c84, 0x2e962f7f, 0xc7d3c902, 0x27a321f8],
+    [0xed86a651, 0x95e7a774, 0x8d71007b, 0xd8078b78, 0x12d19082, 0x0e22b79e, 0x0dee1d6b, 0x06ccd721],
+    [0x978e4026, 0x66f4d0be, 0x76a3a121, 0xb6cbb1fd, 0x6d74a917, 0x2bef350f, 0x22165375, 0x0a41dd42],
+    [0x1298f165, 0x978deec9, 0xcbd0d845, 0xd1782e27, 0xf8386274, 0x94d950cd, 0x1e4d47ac, 0x220a0288],
+    [0x3408b4b9, 0x7298a4ad, 0x339c6b01, 0x9cd92639, 0x6589eac1, 0xbb639760, 0x45fe5f3c, 0x0e2155a5],
+    [0x4b847073, 0x891ec1af, 0xc530782d, 0xb9d088ba, 0xcc774e5f, 0x6a267c60, 0x291e5154, 0x0f0f19c6],
+    [0xd5943c9d, 0xb46795ae, 0x3e7aeef1, 0x0c53c0d3, 0x8deefbd4, 0x3a1c67ec, 0x1c6ddb4a, 0x0e925bcd],
+    [0x9eb5beeb, 0xe45ef05b, 0x3d259a36, 0x3ef0a5bd, 0x1286b9ff, 0x2cd6e541, 0x748abb81, 0x2ad000b1],
+    [0xff648652, 0x7e8cd3dc, 0x5c27aaf9, 0x3fa61646, 0xdffebc2f, 0x8de63d1c, 0x2d32ed6e, 0x0a65aa23],
+    [0x2c93d6a1, 0x3893d7cb, 0x7a3a0692, 0x261dcb16, 0x96242f4f, 0x1d74d8e8, 0x0ab4b1c6, 0x0263d847],
+    [0x60c2b3a5, 0xec1c58cb, 0xc7a8612c, 0xd1926540, 0x4d9a5f8c, 0x40fd5800, 0xaddc94b0, 0x2901d946],
+    [0x6321e693, 0x4937d0cd, 0xda41cee6, 0x717a0696, 0x9dc583b5, 0xdf9022db, 0x209f4952, 0x1889cfa8],
+    [0xb1f39aca, 0x1b85a116, 0x3bff8756, 0x1502e5f0, 0x5fcf8d88, 0x84747ac2, 0x1cb6c64c, 0x236064d7],
+    [0x8ced27ae, 0xc0c1f6cd, 0x33576290, 0x36615700, 0xbdc73ebf, 0x62e04f5d, 0xffcec298, 0x2ff7a174],
+    [0xdd4d6f5b, 0x22715450, 0x084c93e3, 0xa2696a71, 0x40edf745, 0x03455f00, 0xd742cab1, 0x19e724a1],
+    [0x87f161bf, 0x9904a94c, 0x3500a812, 0x5835aa28, 0x5f379398, 0x5da1059c, 0x2b6f0e6c, 0x03eed389],
+    [0x8463ba30, 0x80c68724, 0x2aee4cbc, 0x9e8d0509, 0xd91e468a, 0xac850306, 0x3bfa30c1, 0x08e2b827],
+    [0x58b28cec, 0xf416f2ec, 0x2af98f52, 0x2afb87d7, 0xac3b3ed4, 0x8839a1b6, 0x6d833772, 0x07adcca7],
+    [0x2fa19b40, 0x171169d3, 0x237d548c, 0x4ce11bb4, 0x39bd5178, 0x020a0a58, 0x96bae2b1, 0x171ef378],
+    [0xbc3a35fa, 0x056a26ab, 0xf1729de5, 0x2d32fbef, 0x96918c33, 0x064edbc2, 0x86f4d005, 0x20ffdfcb],
+    [0xca8e4647, 0x994383a0, 0x8fcfed4b, 0xf38dbb39, 0xe608f488, 0x86baa094, 0xf1735eed, 0x08ecd7a6],
+    [0x4c9f0ccb, 0x82ac4d5e, 0xe6928bc1, 0x54288600, 0x1d8c6187, 0x9890189f, 0xe5921fde, 0x1c3f5d86],
+    [0xd51eabf3, 0xbcad4a25, 0x4014ed8e, 0xe3c0975e, 0xaf639aa7, 0x337558a1, 0xed9d460f, 0x29c61184],
+    [0xb8ab3b56, 0x402b8cef, 0x902a734f, 0x5e9b1a64, 0xdbdaef6a, 0x228adbb2, 0x28958728, 0x0defd45b],
+    [0x3b0811a8, 0xea60e013, 0xf76deed4, 0xffa9492d, 0x9ac1d82f, 0x06061017, 0xd8a09336, 0x0a74ea22],
+    [0xcde9a680, 0xdeee4e0c, 0xb16c8523, 0x17dc262d, 0xc13ba24c, 0xd297ac4a, 0x2daf1400, 0x03a37bf1],
+    [0xb0a65368, 0x4f830eac, 0x2f9136b5, 0x69966ada, 0x8e4f7ffe, 0x35ff2231, 0xd5abbf59, 0x11fe1790],
+    [0x3de5f68f, 0x3bde0aba, 0x4c8858ee, 0xbf1fe056, 0x915afb14, 0xf2346686, 0x2f406375, 0x01816584],
+    [0x995b05f9, 0xd5c0e8b6, 0xd361b50f, 0x67300fb7, 0x1f9260b6, 0xb72f276b, 0x7cff5a9f, 0x261db25e],
+    [0x704ba7c4, 0x048826b6, 0xc43ec807, 0x6972320c, 0xf778c5e4, 0x96fbe36d, 0x4b2b66e7, 0x2a3ac331],
+    [0xfd8cd76b, 0x74061c30, 0x937d03d0, 0x69d1bfc8, 0xbc1dbbf3, 0x9d3fea2b, 0x0ecfa99e, 0x23caa4b8],
+    [0xb1dfe107, 0xc6261929, 0x24e581e8, 0x66d8e4e1, 0x3f635f20, 0x8df145f2, 0x85e22489, 0x27db2600],
+    [0x5618ec7f, 0x38b8167b, 0x69e79ab1, 0xc0babbf3, 0x5d424ee6, 0x6b915ef0, 0xd34a784d, 0x274f6c5f],
+    [0x96da6da1, 0x7e23dfc7, 0xda0701d5, 0xcf5840aa, 0x9daf1aa4, 0x0911c928, 0x3a84d26a, 0x2c3a29e1],
+    [0xa2282e6d, 0x82aa78ea, 0x7a553b42, 0xda7aaf31, 0x53270d42, 0x3e801f4e, 0x001a334d, 0x1ea210f2],
+    [0x024946e3, 0x351ccc3b, 0x3e509c36, 0x0903b18a, 0xd6e80d61, 0xc1ba7fa0, 0x2884b699, 0x254dbeb5],
+    [0xea31fd5d, 0x713d8505, 0xa4a2ca2c, 0xf47bcded, 0xc2f29907, 0x0e4ba26d, 0x65896ebe, 0x059e781d],
+    [0x15cc4ffb, 0x7cdbb3e9, 0x8c972dd1, 0xef303ec7, 0xb885a24c, 0x615dc269, 0x63d42d5e, 0x0b5b1cec],
+    [0x64ed0c92, 0x6d2a8f1d, 0xb7d9fbd6, 0x85d6ffac, 0xd39a1bcc, 0xca8b7d26, 0x9c3b2c57, 0x2a7c015e],
+    [0xe1e43b51, 0xa7c851ea, 0x161dc567, 0xd5abee24, 0xf786f11b, 0xcf63d8a6, 0x11d71a79, 0x29b736b9],
+    [0x596c4141, 0xcc1e265d, 0x73912df5, 0x6f1701e5, 0x704bc69c, 0x9af5a808, 0x0a7fe3d0, 0x285745a9],
+    [0x80826715, 0xda554e30, 0xe810bd30, 0xc2b8304a, 0xfec0134e, 0x8c36eb99, 0x95c3c96c, 0x2d901b81],
+    [0xba058392, 0x4d7a7782, 0x450dbdf5, 0x253980f0, 0xed7b8c9c, 0x7859b591, 0x8355eaba, 0x1905d351],
+    [0xdb7b4c9f, 0x095272bf, 0x0bfa8ec4, 0xdf7efea8, 0x5428942f, 0x64d19b5c, 0x6fc0b800, 0x23e81302],
+    [0xde9efb81, 0xe5568071, 0x3e3300e7, 0x00c3fbff, 0x1a798002, 0xb1c1c21b, 0x252c87e6, 0x23c0a19a],
+    [0x125b9e2a, 0x7ca60fab, 0xc0631b06, 0xed1fec6d, 0xaa2c2868, 0x13bf62cd, 0x7bae4924, 0x11c4ae60],
+    [0x1da0e3b9, 0x01707cf8, 0xb1afc9fb, 0x2405fae1, 0x0f046ff6, 0x365dea45, 0xb7ee4686, 0x2cd055eb],
+    [0x215b1452, 0x7dbf791b, 0x9bbb4733, 0x5b58a315, 0x2fdea150, 0xa20fada2, 0x2e021fa9, 0x053c9fef],
+    [0x97872a9f, 0x9a5395bf, 0x1c21938c, 0x863b4c63, 0x319d2c9e, 0xabe89ad1, 0xe87cbaba, 0x0a35bd74],
+    [0x6fc383fe, 0xf5ddc466, 0x5901be36, 0x0fbe83b2, 0x5c43e2b0, 0xd5a04d1a, 0x539ce20c, 0x1c115056],
+    [0x05c51a90, 0x4cdc4735, 0x4c8c3e28, 0x542f2e26, 0xec8beb4c, 0x3bded590, 0x7e5772fd, 0x24295404],
+    [0xb0174214, 0xb67edf7c, 0x00a6e7c2, 0x1cbdbdb2, 0xdf267e81, 0x93849c0c, 0x5b47c0dc, 0x0e2abd31],
+    [0x7da2ea75, 0x52d2de88, 0xae621dd5, 0x668e1dfe, 0x950d8068, 0x51c3fd76, 0x0c0890d7, 0x282b3702],
+    [0x4b643bd9, 0x07942c23, 0xc778e75e, 0x2e5a5be5, 0x23d9d94f, 0xea6b5bb9, 0x266b52d9, 0x28933852],
+    [0xc00da3cf, 0xce39c048, 0x56ce72ba, 0x4fbe79a0, 0x77b7954d, 0x98ef140e, 0x5505ba11, 0x099ab676],
+    [0xd85c57cb, 0xb2ae2d1d, 0x5762c7a7, 0x5c6186b5, 0x8f229a22, 0xda7f849b, 0xe0ac2d8f, 0x2af211d8],
+    [0x04f3facf, 0xaaae1ca1, 0xfedd99b6, 0x98a96322, 0x1c1da61e, 0x09ab90c7, 0x34014a28, 0x0cd070f2],
+    [0xb930256a, 0x8b116264, 0x15833d44, 0x3587d6bd, 0xaa158f26, 0xc60626fb, 0xbc8d3c30, 0x26793e2a],
+    [0xde60c19d, 0x056b6d8b, 0x13ff61b5, 0x66991521, 0x4e5748a5, 0xf845ada8, 0xd0ee85e1, 0x225be36e],
+    [0xea5a065e, 0x9e4b5ee3, 0xa1fde8f0, 0xc595383d, 0x2dc92b9c, 0x54d2beca, 0xedb02d51, 0x02174f49],
+    [0x869872c3, 0xfb41734a, 0xd0308816, 0xdcdbd09b, 0xb758f0bd, 0x46c3594c, 0x2c8b020e, 0x0f64891c],
+    [0x54576067, 0x56140e18, 0x39e10e9f, 0xd1c156c2, 0xdeff3989, 0x91e2d2b1, 0x2f99d369, 0x192a84ca],
+    [0x4a4d4787, 0x3320bba0, 0x4c9a1b8b, 0x0c6243b0, 0x41d6b7a6, 0x2753a8c3, 0x63f05abf, 0x29dfcd7b],
+    [0x819dba5b, 0xe91fc038, 0xd5e657ad, 0x52ba3e4b, 0xa2b3e76a, 0x733afc61, 0xb9b5a867, 0x1ee27ad6],
+    [0x217e64c7, 0xeb623a09, 0xae973fef, 0x5a7e3731, 0xf43cec39, 0xd3bc9538, 0x150c3f8a, 0x0ab4773f],
+    [0xf6d80f41, 0xd103deef, 0xb6d3a0af, 0x42ab67f9, 0x9fa58c55, 0x202cb0b9, 0x2f595186, 0x13c352a0],
+    [0x8246ce14, 0xdd4fe87c, 0xc56a41c4, 0x2c05b7da, 0x52a169f9, 0xd299f67c, 0x10c4bfbf, 0x2a97cf2c],
+    [0x285fe702, 0x6ab06a13, 0x482f7fd1, 0xed089a28, 0x22162d1a, 0x8c9f6bb4, 0x7042bd7f, 0x00becbb4],
+    [0xc2ca2176, 0x3290a92c, 0x4450fcf5, 0xc5bd9f7a, 0xef37ed29, 0x1b881757, 0x21d73869, 0x008e44da],
+    [0x27882a18, 0x7883f865, 0xbe4b83c1, 0x3843fe7f, 0x5d346405, 0xd931f3bb, 0x6d4b7063, 0x2b205a8b],
+    [0x298863fc, 0x9280d427, 0xb2bf84c1, 0x338fcd63, 0xc01e75df, 0x108d255a, 0xc90556fe, 0x2d9e32a7],
+    [0x38ceca97, 0xdbba5207, 0x02266ac1, 0xabd6349a, 0x00228d95, 0xdf6e2e42, 0x4c25bd2d, 0x29a322a8],
+    [0x6d828c43, 0x92089d04, 0x0d021527, 0x14ced1df, 0x33bb68ac, 0x2f4fe55e, 0xc6f2df01, 0x0678c9bf],
+    [0x9124db23, 0x6a3ae400, 0x7f230428, 0x1760394b, 0xeb615b8b, 0xfe20a7e0, 0xe7425794, 0x0faff3a5],
+    [0x2541c0d3, 0x5a41d1d0, 0x3aee213f, 0x53e40410, 0x40581205, 0xcea86c08, 0x1af9feb9, 0x1f8f5b61],
+    [0xc930ea07, 0x965a6dc6, 0x29de4284, 0xc1588b6a, 0xd042ee52, 0x6f40acc2, 0x479602f9, 0x160875d8],
+    [0x45f46d9e, 0xcbf5fe30, 0xfcfda458, 0x67c77328, 0x1efe2cd6, 0xd70afc95, 0x83a316a1, 0x16d87a51],
+];
+
+/// The MDS matrix for `t = 6`, row by row.
+#[rustfmt::skip]
+pub(super) const M6: [[u32; 8]; 36] = [
+    [0x692a4d18, 0x0c87b917, 0x273d956a, 0x6d53f44d, 0xb1bd248c, 0x16f2f070, 0x0561ed59, 0x124666f8],
+    [0xb0bd0b02, 0x9491011d, 0xf4742160, 0xe3d7bfee, 0x46ea0518, 0x55aaa1cf, 0xfd19b092, 0x11924f02],
+    [0xed9df845, 0xce446601, 0x9498d3ff, 0xf9b71736, 0x56c2201e, 0x94ff5054, 0x22304a19, 0x247fa7f0],
+    [0xdf051c3f, 0x986be3dc, 0x6c02d79d, 0x7bc94eb7, 0x10f54e27, 0x22f77ff8, 0xef2c861f, 0x03fd7b19],
+    [0x3c0c2a06, 0x8b67c856, 0xa69352a6, 0x5a81eeee, 0xecffbd55, 0x9a677443, 0x9c3e7157, 0x18bd4123],
+    [0xa8b4ebdc, 0x078cd75d, 0x40493277, 0x5730ca7f, 0x7635196e, 0x35bf0a25, 0xd28de9ff, 0x2d78c3a5],
+    [0x5015540a, 0x11f3780d, 0x6bb27c09, 0x34895aa4, 0xe8dd124c, 0x95dfa029, 0x227f4cec, 0x0a514a5c],
+    [0xcb1da06d, 0x775723d2, 0x5be80915, 0x09589eac, 0xeefdf2ee, 0x7b85a652, 0x7d956b25, 0x192e16d1],
+    [0x5ca3ac10, 0x8917e560, 0xc0926bdc, 0xb1aa9db0, 0x7772b533, 0x35c7058e, 0xe3113bb9, 0x298ce0c1],
+    [0xe05b7fc8, 0x8b17fe4b, 0x39ac9d69, 0x8ebd6122, 0x1727f015, 0x71e41bc7, 0x3621afd2, 0x094cb4e8],
+    [0x7d328791, 0x173bd5cf, 0x841d5a28, 0x33918cb8, 0xd142e76b, 0xd649af5f, 0x5be93c27, 0x03d88039],
+    [0xffceca81, 0x1b42cbcc, 0x7e38fef6, 0xd7e2b303, 0x91680db7, 0xe443bbaf, 0x5866ad68, 0x28eeae6b],
+    [0xcce4fe0a, 0xc63cd65c, 0xdd5b5c23, 0x01a6e60f, 0x82cb6928, 0xa46eb0f8, 0x7b4e44ae, 0x278bb49a],
+    [0xd9b674d7, 0x109035a1, 0x7e8bdcff, 0x14534588, 0x48d6fdea, 0x06af8db6, 0xbed831f5, 0x063edec1],
+    [0x068c475f, 0xbe8c940e, 0xe855192c, 0xb3232500, 0x53657f99, 0xa42061fb, 0x5509b526, 0x1baef1cb],
+    [0x0df2e5d4, 0xb67ee59c, 0xea3daf4e, 0x68b165ea, 0x8d7fa8e3, 0x2164e985, 0xc7bdf9e2, 0x1324564a],
+    [0x562ddc13, 0x10a55240, 0x04ceeda2, 0x064a4a80, 0xfc2c8376, 0x8ca4ea4d, 0xc6aecb1a, 0x005761b8],
+    [0x178a148d, 0x2a99f186, 0x2ed9963d, 0x1fdc2800, 0xde83a3ba, 0x4cb2e35f, 0x159d58cb, 0x10c9e283],
+    [0x2419f6a2, 0x253d026a, 0xca34901d, 0xbdadcec5, 0xfb0b958b, 0x7a203fee, 0x67aa4070, 0x0c399e9f],
+    [0x423449f6, 0x7184cd7b, 0xe762c710, 0xc353b7f6, 0xf602ca8c, 0x0330ec3f, 0xf1a0351d, 0x083f0df3],
+    [0x212cb263, 0x5f9f5d58, 0x87a58b9b, 0x19843072, 0x9fdb8c71, 0x20b4c0a1, 0x943fc4a7, 0x1a6764d5],
+    [0xeaed683a, 0x48f1537b, 0x76c6a7ff, 0x2c96382d, 0x6cc25a92, 0xfa66bde6, 0x6feabf87, 0x011a63a2],
+    [0x7e3e64d4, 0x7f943430, 0x51099ad2, 0x8a41c0b2, 0x63ad2428, 0xf32bef5b, 0x657c3548, 0x08ca7b64],
+    [0x507a4489, 0xdcb04303, 0xf1cd648d, 0x15ec0675, 0xcdb8bea9, 0x955446b0, 0x471e9361, 0x01998270],
+    [0x523e4432, 0xd9e01ca1, 0x0a77c1fb, 0x31ea35ec, 0x62933eaa, 0x6f825d23, 0x6ea369c2, 0x1d6b3d5f],
+    [0xa6f06d40, 0xa27da215, 0x7a69692d, 0x64be687e, 0x19941e86, 0x2306976c, 0xbb3dd0d3, 0x119ef188],
+    [0xa79f809e, 0x2c64b2d9, 0x5609d71f, 0x6e6292df, 0x381ba86b, 0xdfd03481, 0xc06893df, 0x2d9e0ab5],
+    [0xa529e658, 0x31419ee0, 0xf08cda2e, 0x5784319e, 0xc025bf13, 0x7ea34087, 0xbf77060f, 0x25f16631],
+    [0xedb554f1, 0x7ecb9894, 0x94c79a49, 0x86d18bc5, 0xfbd03cad, 0xabae3f33, 0xda5a7c5d, 0x144c7a11],
+    [0xf13de8ee, 0xd7c0db2a, 0x08752fa8, 0xb8f74de5, 0x8b0c104c, 0xfeadacb2, 0x627723f3, 0x0f971162],
+    [0x981a688d, 0xa69306ec, 0xa52598b1, 0x83ab304a, 0x0cbb95c8, 0xdd0abd0b, 0x95436206, 0x24be5100],
+    [0x70d0b9b9, 0xeaed9448, 0xc1c1a603, 0xc299a164, 0x68b0c1a6, 0x26fadf70, 0xad4a3774, 0x211610e2],
+    [0x50ec79fc, 0xc077505e, 0x8157786a, 0x779d3af0, 0xbefd864e, 0xf09f3f9e, 0x1041b1f6, 0x15a67d98],
+    [0xbe7c9598, 0x6877701d, 0x7468b313, 0x6775b028, 0x947f77f0, 0xa2c82406, 0x79d28c12, 0x049327fa],
+    [0x79f951aa, 0xdea0c42d, 0x98f1aa85, 0xd170e8c9, 0x7a3fd416, 0xff9c2969, 0xc5232658, 0x230940dc],
+    [0x2e656964, 0xc3972a8a, 0x26fb888e, 0xcc4bab4c, 0xc9da9995, 0x289007e0, 0x9cd1159e, 0x1b121c04],
+];
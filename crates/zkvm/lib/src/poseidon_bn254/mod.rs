@@ -0,0 +1,82 @@
+//! The Poseidon hash over the scalar field of BN254, compatible with circomlib.
+//!
+//! This is the hash used by circom circuits and the protocols built on them, such as the
+//! EdDSA-Poseidon signatures of iden3 and the Merkle trees of Semaphore. Field multiplications use
+//! the `UINT256_MUL` precompile.
+
+mod constants;
+
+use crate::bn254_fr;
+
+/// The number of full rounds, split evenly before and after the partial rounds.
+const FULL_ROUNDS: usize = 8;
+
+/// The parameters of the instance with `t` field elements of state.
+struct Instance {
+    t: usize,
+    partial_rounds: usize,
+    round_constants: &'static [[u32; 8]],
+    mds: &'static [[u32; 8]],
+}
+
+const INSTANCES: [Instance; 5] = [
+    Instance { t: 2, partial_rounds: 56, round_constants: &constants::C2, mds: &constants::M2 },
+    Instance { t: 3, partial_rounds: 57, round_constants: &constants::C3, mds: &constants::M3 },
+    Instance { t: 4, partial_rounds: 56, round_constants: &constants::C4, mds: &constants::M4 },
+    Instance { t: 5, partial_rounds: 60, round_constants: &constants::C5, mds: &constants::M5 },
+    Instance { t: 6, partial_rounds: 60, round_constants: &constants::C6, mds: &constants::M6 },
+];
+
+/// The maximum number of inputs of [poseidon_bn254].
+pub const MAX_INPUTS: usize = INSTANCES.len();
+
+/// Hashes 1 to [MAX_INPUTS] field elements like `poseidon` of circomlib.
+///
+/// The inputs and the output are elements of the scalar field of BN254 in little-endian words.
+///
+/// ### Panics
+///
+/// Panics if there are no inputs, too many inputs, or an input is not reduced.
+///
+/// ### Examples
+/// ```ignore
+/// let hash = sp1_lib::poseidon_bn254::poseidon_bn254(&[[1, 0, 0, 0, 0, 0, 0, 0]]);
+/// ```
+pub fn poseidon_bn254(inputs: &[[u32; 8]]) -> [u32; 8] {
+    assert!((1..=MAX_INPUTS).contains(&inputs.len()), "poseidon takes 1 to {MAX_INPUTS} inputs");
+    assert!(inputs.iter().all(bn254_fr::is_canonical), "poseidon input is not reduced");
+    let instance = &INSTANCES[inputs.len() - 1];
+    let t = instance.t;
+
+    // The first element of the state is the capacity, which starts at zero.
+    let mut state = [[0u32; 8]; MAX_INPUTS + 1];
+    state[1..t].copy_from_slice(inputs);
+
+    let rounds = FULL_ROUNDS + instance.partial_rounds;
+    for round in 0..rounds {
+        let constants = &instance.round_constants[round * t..(round + 1) * t];
+        for (element, constant) in state[..t].iter_mut().zip(constants) {
+            *element = bn254_fr::add(element, constant);
+        }
+
+        let is_full_round =
+            round < FULL_ROUNDS / 2 || round >= FULL_ROUNDS / 2 + instance.partial_rounds;
+        if is_full_round {
+            for element in state[..t].iter_mut() {
+                *element = bn254_fr::pow5(element);
+            }
+        } else {
+            state[0] = bn254_fr::pow5(&state[0]);
+        }
+
+        let mut mixed = [[0u32; 8]; MAX_INPUTS + 1];
+        for (i, output) in mixed[..t].iter_mut().enumerate() {
+            for (entry, element) in instance.mds[i * t..(i + 1) * t].iter().zip(&state[..t]) {
+                *output = bn254_fr::add(output, &bn254_fr::mul(entry, element));
+            }
+        }
+        state = mixed;
+    }
+
+    state[0]
+}
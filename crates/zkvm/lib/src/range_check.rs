@@ -0,0 +1,42 @@
+//! Range checks with the `RANGE_CHECK` precompile.
+//!
+//! Each checked word costs a single syscall, which the prover verifies with byte lookups instead
+//! of decomposing the value into bits in the program.
+
+use crate::syscall_range_check;
+
+/// Checks that `value` fits in `bits` bits, halting the program otherwise.
+///
+/// Panics if `bits` is larger than 32.
+pub fn range_check_u32(value: u32, bits: u32) {
+    assert!(bits <= 32, "cannot range check a u32 to {bits} bits");
+    unsafe {
+        syscall_range_check(&value, bits);
+    }
+}
+
+/// Checks that `value` fits in `bits` bits, halting the program otherwise.
+///
+/// Panics if `bits` is larger than 64.
+pub fn range_check_u64(value: u64, bits: u32) {
+    assert!(bits <= 64, "cannot range check a u64 to {bits} bits");
+    range_check_words(&[value as u32, (value >> 32) as u32], bits);
+}
+
+/// Checks that the little endian limbs of `words` form a value which fits in `bits` bits, halting
+/// the program otherwise.
+///
+/// Panics if `bits` is larger than the width of `words`.
+pub fn range_check_words(words: &[u32], bits: u32) {
+    assert!(
+        bits as usize <= 32 * words.len(),
+        "cannot range check {} words to {bits} bits",
+        words.len()
+    );
+    for (i, word) in words.iter().enumerate() {
+        let word_bits = bits.saturating_sub(32 * i as u32).min(32);
+        unsafe {
+            syscall_range_check(word, word_bits);
+        }
+    }
+}
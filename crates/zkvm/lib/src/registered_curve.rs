@@ -0,0 +1,58 @@
+//! Arithmetic on the Weierstrass curves registered in the zkVM, which share a single pair of
+//! syscalls and are told apart by the id prefixing each point.
+
+use crate::{syscall_registered_curve_add, syscall_registered_curve_double};
+
+/// The id of the brainpoolP256r1 curve of RFC 5639.
+pub const BRAINPOOL_P256R1_ID: u32 = 1;
+
+/// The id of the STARK curve used by Starknet.
+pub const STARK_CURVE_ID: u32 = 2;
+
+/// An affine point of a registered curve, laid out as the syscalls expect it.
+///
+/// The coordinates are little endian words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C, align(4))]
+pub struct RegisteredCurvePoint {
+    /// The id of the curve of the point.
+    pub curve_id: u32,
+    /// The x coordinate.
+    pub x: [u32; 8],
+    /// The y coordinate.
+    pub y: [u32; 8],
+}
+
+impl RegisteredCurvePoint {
+    /// Creates a point of the curve with the given id from its little endian coordinates.
+    pub const fn new(curve_id: u32, x: [u32; 8], y: [u32; 8]) -> Self {
+        Self { curve_id, x, y }
+    }
+
+    /// Adds `other` to the point in place.
+    ///
+    /// Panics if the points are on different curves. The points must have distinct x coordinates,
+    /// and the program halts otherwise.
+    pub fn add_assign(&mut self, other: &Self) {
+        assert_eq!(self.curve_id, other.curve_id, "the points are on different curves");
+        let mut q = [0u32; 16];
+        q[..8].copy_from_slice(&other.x);
+        q[8..].copy_from_slice(&other.y);
+        unsafe {
+            syscall_registered_curve_add(self.as_mut_words(), &q);
+        }
+    }
+
+    /// Doubles the point in place.
+    ///
+    /// The point must not be of order two, and the program halts otherwise.
+    pub fn double(&mut self) {
+        unsafe {
+            syscall_registered_curve_double(self.as_mut_words());
+        }
+    }
+
+    fn as_mut_words(&mut self) -> *mut [u32; 17] {
+        self as *mut Self as *mut [u32; 17]
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{syscall_secp256k1_add, syscall_secp256k1_double, utils::AffinePoint};
+use crate::{
+    syscall_secp256k1_add, syscall_secp256k1_double, syscall_secp256k1_map_to_curve,
+    utils::AffinePoint,
+};
 
 /// The number of limbs in [Secp256k1AffinePoint].
 pub const N: usize = 16;
@@ -43,3 +46,19 @@ impl AffinePoint<N> for Secp256k1AffinePoint {
         }
     }
 }
+
+/// Maps a field element to a point of the curve `E'` 3-isogenous to Secp256k1, using the
+/// secp256k1_map_to_curve precompile.
+///
+/// This is the simplified SWU step of the `secp256k1_XMD:SHA-256_SSWU_RO_` suite of RFC 9380. `u`
+/// must be reduced and in little endian words, and the result is the affine point `(x, y)` of `E'`,
+/// which the caller maps to Secp256k1 with the 3-isogeny. The exceptional inputs of the map, such
+/// as zero, make the precompile fail.
+pub fn map_to_curve(u: &[u32; N / 2]) -> [u32; N] {
+    let mut point = [0u32; N];
+    point[..N / 2].copy_from_slice(u);
+    unsafe {
+        syscall_secp256k1_map_to_curve(&mut point);
+    }
+    point
+}
@@ -0,0 +1,143 @@
+use crate::io;
+
+/// Sorts `values` with the help of a hint, in a number of cycles linear in their length.
+///
+/// The host sorts the values in unconstrained mode and hints the sorting permutation, and the
+/// program only checks that the hint is a permutation and that it sorts the values. The program
+/// also checks that equal values keep their order, so the sort is stable, like [`slice::sort`].
+///
+/// ### Examples
+/// ```ignore
+/// let mut values = vec![3, 1, 2];
+/// sp1_lib::sort_hinted(&mut values);
+/// assert_eq!(values, [1, 2, 3]);
+/// ```
+pub fn sort_hinted<T: Ord>(values: &mut [T]) {
+    crate::unconstrained! {
+        let mut permutation = (0..values.len() as u32).collect::<Vec<_>>();
+        permutation.sort_by(|i, j| values[*i as usize].cmp(&values[*j as usize]));
+        io::hint(&permutation);
+    }
+
+    let permutation: Vec<u32> = io::read_hint();
+    sort_by_permutation(values, &permutation);
+}
+
+/// Sorts `values` with [sort_hinted] and removes the consecutive duplicates, so that the values
+/// are strictly increasing.
+///
+/// ### Examples
+/// ```ignore
+/// let mut values = vec![3, 1, 3, 2, 1];
+/// sp1_lib::sort_dedup_hinted(&mut values);
+/// assert_eq!(values, [1, 2, 3]);
+/// ```
+pub fn sort_dedup_hinted<T: Ord>(values: &mut Vec<T>) {
+    sort_hinted(values);
+    values.dedup();
+}
+
+/// Applies `permutation` to `values`, panicking if it does not sort them stably.
+fn sort_by_permutation<T: Ord>(values: &mut [T], permutation: &[u32]) {
+    apply_permutation(values, permutation);
+    let stable = values
+        .windows(2)
+        .zip(permutation.windows(2))
+        .all(|(v, p)| v[0] < v[1] || (v[0] == v[1] && p[0] < p[1]));
+    assert!(stable, "hinted permutation does not sort stably");
+}
+
+/// Reorders `values` so that the value at index `i` is the one previously at `permutation[i]`,
+/// panicking if `permutation` is not a permutation of the indices of `values`.
+fn apply_permutation<T>(values: &mut [T], permutation: &[u32]) {
+    assert_eq!(permutation.len(), values.len(), "hinted permutation has the wrong length");
+
+    // Every index must appear exactly once: since there are as many indices as values, it is
+    // enough to check that they are in range and distinct.
+    let mut seen = vec![false; values.len()];
+    for index in permutation {
+        let index = *index as usize;
+        assert!(index < values.len() && !seen[index], "hinted permutation is invalid");
+        seen[index] = true;
+    }
+
+    // Apply the permutation cycle by cycle, reusing `seen` to mark the placed indices.
+    for start in 0..values.len() {
+        if !seen[start] {
+            continue;
+        }
+        let mut i = start;
+        loop {
+            seen[i] = false;
+            let next = permutation[i] as usize;
+            if next == start {
+                break;
+            }
+            values.swap(i, next);
+            i = next;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A value ordered by its key only, so that the order of equal values is observable.
+    #[derive(Debug, Clone, Copy)]
+    struct Keyed(u32, char);
+
+    impl PartialEq for Keyed {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for Keyed {}
+
+    impl PartialOrd for Keyed {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Keyed {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    fn keyed_values() -> Vec<Keyed> {
+        vec![Keyed(2, 'a'), Keyed(1, 'b'), Keyed(2, 'c'), Keyed(1, 'd')]
+    }
+
+    #[test]
+    fn test_sort_by_permutation_is_stable() {
+        let mut values = keyed_values();
+        sort_by_permutation(&mut values, &[1, 3, 0, 2]);
+        let tags = values.iter().map(|v| v.1).collect::<String>();
+        assert_eq!(tags, "bdac");
+
+        let mut expected = keyed_values();
+        expected.sort();
+        assert_eq!(tags, expected.iter().map(|v| v.1).collect::<String>());
+    }
+
+    #[test]
+    #[should_panic(expected = "does not sort stably")]
+    fn test_sort_by_permutation_rejects_unstable() {
+        sort_by_permutation(&mut keyed_values(), &[3, 1, 0, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not sort stably")]
+    fn test_sort_by_permutation_rejects_unsorted() {
+        sort_by_permutation(&mut keyed_values(), &[0, 1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "permutation is invalid")]
+    fn test_sort_by_permutation_rejects_repeated_index() {
+        sort_by_permutation(&mut keyed_values(), &[1, 1, 0, 2]);
+    }
+}
@@ -0,0 +1,38 @@
+//! 64-bit integer arithmetic on the wide ALU precompile.
+//!
+//! Each operation is proven in a single row of the wide ALU chip instead of the RV32 instructions
+//! the compiler emits for it. The operands are passed through memory, so a call costs a few
+//! instructions of its own and only pays off over the longer sequences, such as multiplication.
+
+use crate::{syscall_u64_add, syscall_u64_ltu, syscall_u64_mul, syscall_u64_sub};
+
+type Syscall = unsafe extern "C" fn(*mut [u32; 2], *const [u32; 2]);
+
+fn apply(syscall: Syscall, x: u64, y: u64) -> u64 {
+    let mut x = [x as u32, (x >> 32) as u32];
+    let y = [y as u32, (y >> 32) as u32];
+    unsafe {
+        syscall(&mut x, &y);
+    }
+    x[0] as u64 | ((x[1] as u64) << 32)
+}
+
+/// Computes `x + y`, wrapping around at the boundary of the type.
+pub fn u64_add(x: u64, y: u64) -> u64 {
+    apply(syscall_u64_add, x, y)
+}
+
+/// Computes `x - y`, wrapping around at the boundary of the type.
+pub fn u64_sub(x: u64, y: u64) -> u64 {
+    apply(syscall_u64_sub, x, y)
+}
+
+/// Computes `x * y`, wrapping around at the boundary of the type.
+pub fn u64_mul(x: u64, y: u64) -> u64 {
+    apply(syscall_u64_mul, x, y)
+}
+
+/// Returns whether `x < y`.
+pub fn u64_ltu(x: u64, y: u64) -> bool {
+    apply(syscall_u64_ltu, x, y) == 1
+}
@@ -0,0 +1,24 @@
+use crate::syscall_x25519_scalar_mult;
+
+/// The X25519 function of RFC 7748.
+///
+/// Clamps the scalar `k`, masks the top bit of the u-coordinate `u`, and returns the u-coordinate
+/// of their product on curve25519. All values are little endian bytes.
+pub fn x25519(k: [u8; 32], u: [u8; 32]) -> [u8; 32] {
+    let mut k: [u32; 8] =
+        core::array::from_fn(|i| u32::from_le_bytes(k[4 * i..4 * i + 4].try_into().unwrap()));
+    let mut u: [u32; 8] =
+        core::array::from_fn(|i| u32::from_le_bytes(u[4 * i..4 * i + 4].try_into().unwrap()));
+    k[0] &= !7;
+    k[7] &= 0x7fff_ffff;
+    k[7] |= 0x4000_0000;
+    u[7] &= 0x7fff_ffff;
+    unsafe {
+        syscall_x25519_scalar_mult(&mut u, &k);
+    }
+    let mut out = [0u8; 32];
+    for (chunk, word) in out.chunks_exact_mut(4).zip(u) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+    out
+}
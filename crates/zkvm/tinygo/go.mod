@@ -0,0 +1,3 @@
+module github.com/succinctlabs/sp1/crates/zkvm/tinygo
+
+go 1.22
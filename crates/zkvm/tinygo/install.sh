@@ -0,0 +1,11 @@
+#!/usr/bin/env sh
+# Installs the SP1 target and runtime into the TinyGo installation, so that Go programs can be
+# compiled for the zkVM with `tinygo build -target=sp1`.
+set -e
+
+root="$(tinygo env TINYGOROOT)"
+dir="$(cd "$(dirname "$0")" && pwd)"
+
+cp "$dir/targets/sp1.json" "$dir/targets/sp1.ld" "$dir/targets/sp1.S" "$root/targets/"
+cp "$dir/runtime/runtime_tinygoriscv_sp1.go" "$root/src/runtime/"
+echo "Installed the sp1 target into $root"
@@ -0,0 +1,92 @@
+//go:build tinygo.riscv && sp1
+
+// The runtime of Go programs running in the SP1 zkVM. This file is copied into the runtime of
+// TinyGo by install.sh, next to the runtimes of the other RISC-V targets.
+
+package runtime
+
+import "unsafe"
+
+type timeUnit int64
+
+const (
+	syscallWrite = 0x00_00_00_02
+	fdStdout     = 1
+)
+
+// Implemented in targets/sp1.S.
+//
+//export sp1_ecall
+func sp1Ecall(a0, a1, a2, id uint32) uint32
+
+// Implemented by the sp1 package, which commits to the public values before halting.
+//
+//export sp1_halt
+func sp1Halt(code uint32)
+
+//export main
+func main() {
+	preinit()
+	run()
+	exit(0)
+}
+
+// The output of the program is buffered by line, so that printing takes one system call per line
+// instead of one per byte.
+var (
+	stdout    [128]byte
+	stdoutLen int
+)
+
+func putchar(c byte) {
+	stdout[stdoutLen] = c
+	stdoutLen++
+	if c == '\n' || stdoutLen == len(stdout) {
+		flushStdout()
+	}
+}
+
+func flushStdout() {
+	if stdoutLen > 0 {
+		ptr := uint32(uintptr(unsafe.Pointer(&stdout[0])))
+		sp1Ecall(fdStdout, ptr, uint32(stdoutLen), syscallWrite)
+		stdoutLen = 0
+	}
+}
+
+func getchar() byte {
+	return 0
+}
+
+func buffered() int {
+	return 0
+}
+
+func exit(code int) {
+	flushStdout()
+	sp1Halt(uint32(code))
+}
+
+// A panic halts with the same exit code as a panic in a Rust program.
+func abort() {
+	exit(1)
+}
+
+// The zkVM has no clock, so time only advances when the program sleeps.
+var timestamp timeUnit
+
+func ticks() timeUnit {
+	return timestamp
+}
+
+func sleepTicks(d timeUnit) {
+	timestamp += d
+}
+
+func ticksToNanoseconds(ticks timeUnit) int64 {
+	return int64(ticks)
+}
+
+func nanosecondsToTicks(ns int64) timeUnit {
+	return timeUnit(ns)
+}
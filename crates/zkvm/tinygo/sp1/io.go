@@ -0,0 +1,48 @@
+package sp1
+
+import (
+	"crypto/sha256"
+	"unsafe"
+)
+
+const fdPublicValues = 3
+
+// The hints are read into a region above the heap, see targets/sp1.ld. The zkVM requires hints to
+// be read into memory that was never written, which the heap cannot guarantee since it zeroes the
+// memory it allocates.
+const (
+	hintStart = 0x7000_0000
+	hintEnd   = 0x7800_0000
+)
+
+var (
+	publicValuesHasher = sha256.New()
+	hintPos            = uintptr(hintStart)
+)
+
+// Read returns the next input written by the host with `SP1Stdin::write_slice`.
+//
+// Inputs written with `SP1Stdin::write` are serialized with bincode, in which case a u32 is its
+// four little endian bytes and a byte vector is prefixed with its length as a little endian u64.
+func Read() []byte {
+	n := uintptr(ecall(0, 0, 0, syscallHintLen))
+	if hintPos+n > hintEnd {
+		panic("sp1: the inputs exceed the memory reserved for them")
+	}
+	buf := unsafe.Slice((*byte)(unsafe.Pointer(hintPos)), n)
+	if n > 0 {
+		ecall(uint32(hintPos), uint32(n), 0, syscallHintRead)
+	}
+	// Keep the next hint aligned to a word.
+	hintPos += (n + 3) &^ 3
+	return buf
+}
+
+// Commit writes the given bytes to the public values of the proof.
+func Commit(b []byte) {
+	if len(b) == 0 {
+		return
+	}
+	ecall(fdPublicValues, ptr(&b[0]), uint32(len(b)), syscallWrite)
+	publicValuesHasher.Write(b)
+}
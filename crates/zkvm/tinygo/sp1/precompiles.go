@@ -0,0 +1,16 @@
+package sp1
+
+// KeccakPermute applies the Keccak-f[1600] permutation to the state.
+func KeccakPermute(state *[25]uint64) {
+	ecall(ptr(state), 0, 0, syscallKeccakPermute)
+}
+
+// SHA256Extend computes the message schedule of a SHA-256 block, whose first 16 words are set.
+func SHA256Extend(w *[64]uint32) {
+	ecall(ptr(w), 0, 0, syscallShaExtend)
+}
+
+// SHA256Compress compresses the message schedule of a SHA-256 block into the state.
+func SHA256Compress(w *[64]uint32, state *[8]uint32) {
+	ecall(ptr(w), ptr(state), 0, syscallShaCompress)
+}
@@ -0,0 +1,53 @@
+// Package sp1 exposes the SP1 zkVM to Go programs compiled with TinyGo for the sp1 target.
+//
+// Every program must import this package, which implements the halt of the program that the
+// runtime calls when main returns, when the program calls os.Exit, or when it panics.
+package sp1
+
+import (
+	"unsafe"
+)
+
+// The system calls of the zkVM, see crates/zkvm/entrypoint/src/syscalls/mod.rs.
+const (
+	syscallHalt                 = 0x00_00_00_00
+	syscallWrite                = 0x00_00_00_02
+	syscallShaExtend            = 0x00_30_01_05
+	syscallShaCompress          = 0x00_01_01_06
+	syscallKeccakPermute        = 0x00_01_01_09
+	syscallCommit               = 0x00_00_00_10
+	syscallCommitDeferredProofs = 0x00_00_00_1A
+	syscallHintLen              = 0x00_00_00_F0
+	syscallHintRead             = 0x00_00_00_F1
+)
+
+// The number of words of the digests committed when the program halts.
+const digestWords = 8
+
+// Implemented in targets/sp1.S.
+//
+//export sp1_ecall
+func ecall(a0, a1, a2, id uint32) uint32
+
+// halt commits to the public values and halts the program with the given exit code.
+//
+//export sp1_halt
+func halt(code uint32) {
+	digest := publicValuesHasher.Sum(nil)
+	for i := uint32(0); i < digestWords; i++ {
+		word := uint32(digest[4*i]) | uint32(digest[4*i+1])<<8 |
+			uint32(digest[4*i+2])<<16 | uint32(digest[4*i+3])<<24
+		ecall(i, word, 0, syscallCommit)
+	}
+	// Go programs cannot verify SP1 proofs, so the digest of the deferred proofs is zero.
+	for i := uint32(0); i < digestWords; i++ {
+		ecall(i, 0, 0, syscallCommitDeferredProofs)
+	}
+	ecall(code, 0, 0, syscallHalt)
+	for {
+	}
+}
+
+func ptr[T any](p *T) uint32 {
+	return uint32(uintptr(unsafe.Pointer(p)))
+}
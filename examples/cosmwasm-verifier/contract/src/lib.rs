@@ -0,0 +1,110 @@
+//! A CosmWasm contract that verifies SP1 Groth16 proofs of a single program.
+//!
+//! The contract is instantiated with the `groth16_vk.bin` of the SP1 circuit artifacts and the
+//! bytes32 verification key digest of the program, as returned by `vk.bytes32()`. Proofs are
+//! verified with [ExecuteMsg::Verify], which fails the transaction if the proof is invalid, or
+//! with the [QueryMsg::Verify] query.
+//!
+//! Build it with `cargo build --release --target wasm32-unknown-unknown --lib`.
+
+use cosmwasm_schema::{cw_serde, QueryResponses};
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult,
+};
+use sp1_cosmwasm_verifier::{verify_groth16, Groth16VerifyingKey};
+
+/// The storage key of the verifying key, decoded at instantiation.
+const GROTH16_VK_KEY: &[u8] = b"groth16_vk";
+/// The storage key of the verification key digest of the program.
+const PROGRAM_VKEY_KEY: &[u8] = b"program_vkey";
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The `groth16_vk.bin` of the SP1 circuit artifacts.
+    pub groth16_vk: Binary,
+    /// The verification key digest of the program, as a 0x-prefixed bytes32 hex string.
+    pub program_vkey: String,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Verifies a proof of the program, given by its public values and proof bytes.
+    Verify { public_values: Binary, proof: Binary },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Returns whether a proof of the program is valid.
+    #[returns(VerifyResponse)]
+    Verify { public_values: Binary, proof: Binary },
+}
+
+#[cw_serde]
+pub struct VerifyResponse {
+    pub valid: bool,
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> StdResult<Response> {
+    // Decompressing the points of the gnark verifying key is expensive, so it is done once here
+    // and the uncompressed key is stored.
+    let vk = Groth16VerifyingKey::from_gnark_bytes(&msg.groth16_vk)?;
+    let program_vkey = parse_program_vkey(&msg.program_vkey)?;
+    deps.storage.set(GROTH16_VK_KEY, &vk.to_bytes());
+    deps.storage.set(PROGRAM_VKEY_KEY, &program_vkey);
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("program_vkey", msg.program_vkey))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: ExecuteMsg,
+) -> StdResult<Response> {
+    match msg {
+        ExecuteMsg::Verify { public_values, proof } => {
+            verify(deps.as_ref(), &public_values, &proof)?;
+            Ok(Response::new()
+                .add_attribute("action", "verify")
+                .add_attribute("public_values", hex::encode(public_values.as_slice())))
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Verify { public_values, proof } => {
+            let valid = verify(deps, &public_values, &proof).is_ok();
+            to_json_binary(&VerifyResponse { valid })
+        }
+    }
+}
+
+fn verify(deps: Deps, public_values: &[u8], proof: &[u8]) -> StdResult<()> {
+    let vk = deps.storage.get(GROTH16_VK_KEY).ok_or_else(|| StdError::not_found("groth16_vk"))?;
+    let vk = Groth16VerifyingKey::from_bytes(&vk)?;
+    let program_vkey =
+        deps.storage.get(PROGRAM_VKEY_KEY).ok_or_else(|| StdError::not_found("program_vkey"))?;
+    let program_vkey =
+        program_vkey.try_into().map_err(|_| StdError::generic_err("corrupted program_vkey"))?;
+    verify_groth16(&vk, &program_vkey, public_values, proof)?;
+    Ok(())
+}
+
+fn parse_program_vkey(program_vkey: &str) -> StdResult<[u8; 32]> {
+    let bytes = hex::decode(program_vkey.trim_start_matches("0x"))
+        .map_err(|err| StdError::generic_err(format!("invalid program vkey: {err}")))?;
+    bytes.try_into().map_err(|_| StdError::generic_err("the program vkey must be 32 bytes"))
+}
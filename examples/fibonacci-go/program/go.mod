@@ -0,0 +1,7 @@
+module fibonacci-go
+
+go 1.22
+
+require github.com/succinctlabs/sp1/crates/zkvm/tinygo v0.0.0
+
+replace github.com/succinctlabs/sp1/crates/zkvm/tinygo => ../../../crates/zkvm/tinygo
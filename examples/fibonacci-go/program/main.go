@@ -0,0 +1,27 @@
+// A simple program that takes a number `n` as input, and writes the `n-1`th and `n`th fibonacci
+// number as an output.
+package main
+
+import (
+	"encoding/binary"
+
+	"github.com/succinctlabs/sp1/crates/zkvm/tinygo/sp1"
+)
+
+func main() {
+	// Read an input to the program, written by the host as four little endian bytes.
+	n := binary.LittleEndian.Uint32(sp1.Read())
+
+	// Write n to public input.
+	sp1.Commit(binary.LittleEndian.AppendUint32(nil, n))
+
+	// Compute the n'th fibonacci number, using normal Go code.
+	a, b := uint32(0), uint32(1)
+	for i := uint32(0); i < n; i++ {
+		a, b = b, (a+b)%7919 // Modulus to prevent overflow.
+	}
+
+	// Write the output of the program.
+	sp1.Commit(binary.LittleEndian.AppendUint32(nil, a))
+	sp1.Commit(binary.LittleEndian.AppendUint32(nil, b))
+}
@@ -0,0 +1,39 @@
+use sp1_sdk::{utils, ProverClient, SP1Stdin};
+
+/// The path of the ELF built by `make` in the program directory.
+const ELF_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../program/elf/fibonacci-go-program");
+
+fn main() {
+    // Setup logging.
+    utils::setup_logger();
+
+    // The program is built with TinyGo, so it is not built by a build script like Rust programs.
+    let elf = std::fs::read(ELF_PATH).expect("the program is not built, run `make` in ../program");
+
+    // The program reads the raw bytes of `n`, so they are written without serialization.
+    let n = 1000u32;
+    let mut stdin = SP1Stdin::new();
+    stdin.write_slice(&n.to_le_bytes());
+
+    let client = ProverClient::new();
+
+    // Execute the program, without generating a proof.
+    let (_, report) = client.execute(&elf, stdin.clone()).run().unwrap();
+    println!("executed program with {} cycles", report.total_instruction_count());
+
+    // Generate the proof for the given program and input.
+    let (pk, vk) = client.setup(&elf);
+    let mut proof = client.prove(&pk, stdin).run().unwrap();
+    println!("generated proof");
+
+    // Read the output, committed by the program as little endian u32s, which is how bincode
+    // serializes them.
+    let _ = proof.public_values.read::<u32>();
+    let a = proof.public_values.read::<u32>();
+    let b = proof.public_values.read::<u32>();
+    println!("a: {}", a);
+    println!("b: {}", b);
+
+    client.verify(&proof, &vk).expect("verification failed");
+    println!("successfully generated and verified proof for the Go program!")
+}
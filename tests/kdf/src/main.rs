@@ -0,0 +1,49 @@
+#![no_main]
+sp1_zkvm::entrypoint!(main);
+
+use hex_literal::hex;
+use sp1_zkvm::kdf::{pbkdf2_hmac_sha256, scrypt, verify_pbkdf2_hmac_sha256, ScryptParams};
+
+pub fn main() {
+    // RFC 7914, section 11.
+    let mut out = [0u8; 64];
+    pbkdf2_hmac_sha256(b"passwd", b"salt", 1, &mut out);
+    assert_eq!(
+        out,
+        hex!(
+            "55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc"
+            "49ca9cccf179b645991664b39d77ef317c71b845b1e30bd509112041d3a19783"
+        )
+    );
+    assert!(verify_pbkdf2_hmac_sha256(
+        b"Password",
+        b"NaCl",
+        80000,
+        &hex!(
+            "4ddcd8f60b98be21830cee5ef22701f9641a4418d04c0414aeff08876b34ab56"
+            "a1d425a1225833549adb841b51c9b3176a272bdebba1d078478f62b397f33c8d"
+        )
+    ));
+
+    // A password longer than a block is hashed into the HMAC key.
+    let mut out = [0u8; 40];
+    pbkdf2_hmac_sha256(&[b'k'; 100], b"salt", 2, &mut out);
+    assert_eq!(
+        out,
+        hex!(
+            "2c1357648009149f57e4d5544c3435bbca87a6b231300fa3abb2a89b50f56ec3"
+            "eb3e22ce8267e0fe"
+        )
+    );
+
+    // RFC 7914, section 12.
+    let mut out = [0u8; 64];
+    scrypt(b"", b"", ScryptParams { log_n: 4, r: 1, p: 1 }, &mut out);
+    assert_eq!(
+        out,
+        hex!(
+            "77d6576238657b203b19ca42c18a0497f16b4844e3074ae8dfdffa3fede21442"
+            "fcd0069ded0948f8326a753a0fc81f17e8d3e0fb2e0d3628cf35e20c38d18906"
+        )
+    );
+}